@@ -25,12 +25,16 @@ use egui::{
 };
 use gilrs::GamepadId;
 use nesium_core::{
-    audio::bus::AudioBusConfig, ppu::buffer::ColorFormat, ppu::palette::PaletteKind,
+    audio::{MixerSettings, bus::AudioBusConfig},
+    config::{overclock::Overclock, ram_init::RamInit},
+    controller::{ButtonRemap, MultitapKind},
+    ppu::buffer::ColorFormat,
+    ppu::palette::PaletteKind,
     reset_kind::ResetKind,
 };
 use nesium_runtime::{
-    AudioMode, DebugState, Event, EventTopic, NotificationEvent, Runtime, RuntimeConfig,
-    RuntimeEventSender, RuntimeHandle, VideoConfig,
+    AudioMode, DebugState, Event, EventTopic, GameProfile, NotificationEvent, Runtime,
+    RuntimeConfig, RuntimeEventSender, RuntimeHandle, VideoConfig,
 };
 
 use self::{
@@ -90,13 +94,27 @@ pub enum AspectRatio {
 pub(super) struct UiState {
     i18n: I18n,
     audio_cfg: AudioBusConfig,
+    mixer_settings: MixerSettings,
     controllers: [ControllerInput; 4],
     controller_devices: [ControllerDevice; 4],
     controller_presets: [InputPreset; 4],
+    /// Four-player adapter exposing ports 3/4 to the console.
+    multitap: Option<MultitapKind>,
+    /// Per-port A/B and Select/Start swaps for the loaded game, applied by
+    /// the core and stored in the game's runtime profile.
+    button_remaps: [ButtonRemap; 4],
+    zapper_lag_frames: u8,
     active_input_port: usize,
     pixel_perfect_scaling: bool,
     aspect_ratio: AspectRatio,
     integer_fps_mode: bool,
+    clone_ppu: bool,
+    overclock: Overclock,
+    /// Display-only layer toggles.
+    show_background: bool,
+    show_sprites: bool,
+    /// Draw all sprites on a line instead of the hardware's eight.
+    remove_sprite_limit: bool,
     palette_builtin_kind: PaletteKind,
     palette_use_external: bool,
     palette_external_path: Option<PathBuf>,
@@ -191,6 +209,10 @@ pub struct NesiumApp {
     cursor_hidden: bool,
     last_pad_masks: [u8; 4],
     last_turbo_masks: [u8; 4],
+    /// Port (0/1) the Zapper is plugged into, driven by the main view's pointer.
+    zapper_port: Option<usize>,
+    multitap: Option<MultitapKind>,
+    button_remaps: [ButtonRemap; 4],
     rom_path: Option<PathBuf>,
     paused: bool,
     error_dialog: Option<String>,
@@ -220,6 +242,8 @@ impl NesiumApp {
                     ..VideoConfig::default()
                 },
                 audio: AudioMode::Auto,
+                overclock: Overclock::default(),
+                ram_init: RamInit::default(),
             },
             sender,
         )
@@ -238,6 +262,7 @@ impl NesiumApp {
                 I18n::new(Language::English)
             },
             audio_cfg: AudioBusConfig::default(),
+            mixer_settings: MixerSettings::default(),
             controllers: std::array::from_fn(|_| ControllerInput::new_with_defaults()),
             controller_devices: [
                 ControllerDevice::Keyboard,
@@ -246,10 +271,18 @@ impl NesiumApp {
                 ControllerDevice::Disabled,
             ],
             controller_presets: [InputPreset::NesStandard; 4],
+            multitap: None,
+            button_remaps: [ButtonRemap::IDENTITY; 4],
+            zapper_lag_frames: 0,
             active_input_port: 0,
             pixel_perfect_scaling: false,
             aspect_ratio: AspectRatio::Square,
             integer_fps_mode: false,
+            clone_ppu: false,
+            overclock: Overclock::default(),
+            show_background: true,
+            show_sprites: true,
+            remove_sprite_limit: false,
             palette_builtin_kind: PaletteKind::default(),
             palette_use_external: false,
             palette_external_path: None,
@@ -274,6 +307,9 @@ impl NesiumApp {
             cursor_hidden: false,
             last_pad_masks: [0u8; 4],
             last_turbo_masks: [0u8; 4],
+            zapper_port: None,
+            multitap: None,
+            button_remaps: [ButtonRemap::IDENTITY; 4],
             rom_path: None,
             paused: false,
             error_dialog: None,
@@ -307,6 +343,15 @@ impl NesiumApp {
             .load_rom(path.to_path_buf())
             .map_err(|e| anyhow::anyhow!(e.to_string()))?;
         self.rom_path = Some(path.to_path_buf());
+        // Show the remaps saved for this game.
+        self.button_remaps = self
+            .runtime_handle
+            .rom_hash()
+            .and_then(|hash| self.runtime_handle.game_profile(&hash)?.button_remaps)
+            .unwrap_or([ButtonRemap::IDENTITY; 4]);
+        if let Ok(mut ui_state) = self.ui_state.lock() {
+            ui_state.button_remaps = self.button_remaps;
+        }
         self.paused = false;
         self.runtime_handle.set_paused(false);
         self.last_frame_seq = self.runtime_handle.frame_seq();
@@ -317,6 +362,26 @@ impl NesiumApp {
         Ok(())
     }
 
+    /// Saves the remaps in the loaded game's profile, or applies them
+    /// globally when no game is loaded.
+    fn store_button_remaps(&self) {
+        let handle = &self.runtime_handle;
+        match handle.rom_hash() {
+            Some(hash) => {
+                let profile = GameProfile {
+                    button_remaps: Some(self.button_remaps),
+                    ..handle.game_profile(&hash).unwrap_or_default()
+                };
+                handle.set_game_profile(hash, Some(profile));
+            }
+            None => {
+                for (port, remap) in self.button_remaps.iter().enumerate() {
+                    handle.set_button_remap(port, *remap);
+                }
+            }
+        }
+    }
+
     fn reset(&mut self) {
         self.reset_with(ResetKind::Soft);
     }
@@ -460,6 +525,30 @@ impl NesiumApp {
             .unwrap_or(false)
     }
 
+    fn overclock(&self) -> Overclock {
+        self.ui_state
+            .lock()
+            .ok()
+            .map(|s| s.overclock)
+            .unwrap_or_default()
+    }
+
+    fn layers_shown(&self) -> (bool, bool) {
+        self.ui_state
+            .lock()
+            .ok()
+            .map(|s| (s.show_background, s.show_sprites))
+            .unwrap_or((true, true))
+    }
+
+    fn remove_sprite_limit(&self) -> bool {
+        self.ui_state
+            .lock()
+            .ok()
+            .map(|s| s.remove_sprite_limit)
+            .unwrap_or(false)
+    }
+
     fn aspect_ratio(&self) -> AspectRatio {
         self.ui_state
             .lock()
@@ -635,6 +724,16 @@ impl eframe::App for NesiumApp {
                     tracing::error!("{msg}");
                     self.error_dialog = Some(msg);
                 }
+                NotificationEvent::RomLoadProgress { stage } => {
+                    tracing::debug!("ROM load: {stage}");
+                }
+                NotificationEvent::RomLoadWarning { warning } => {
+                    tracing::warn!("ROM load: {warning}");
+                }
+                // The failure itself is surfaced through the `load_rom` reply.
+                NotificationEvent::RomLoadFailed { stage, error } => {
+                    tracing::error!("ROM load failed at {stage}: {error}");
+                }
             }
         }
 
@@ -707,7 +806,7 @@ impl eframe::App for NesiumApp {
                             ctrl.release_all();
                         }
                     }
-                    ControllerDevice::Disabled => {
+                    ControllerDevice::Zapper | ControllerDevice::Disabled => {
                         ctrl.release_all();
                     }
                 }
@@ -716,6 +815,15 @@ impl eframe::App for NesiumApp {
             }
             self.last_pad_masks = pad_masks;
             self.last_turbo_masks = turbo_masks;
+            self.multitap = ui_state.multitap;
+            self.runtime_handle
+                .set_zapper_lag_compensation(ui_state.zapper_lag_frames);
+            if self.button_remaps != ui_state.button_remaps {
+                self.button_remaps = ui_state.button_remaps;
+                self.store_button_remaps();
+            }
+            self.zapper_port =
+                (0..2).find(|&port| ui_state.controller_devices[port] == ControllerDevice::Zapper);
         } else {
             pad_masks = self.last_pad_masks;
             turbo_masks = self.last_turbo_masks;
@@ -726,6 +834,8 @@ impl eframe::App for NesiumApp {
             self.runtime_handle.set_pad_mask(port, pad_masks[port]);
             self.runtime_handle.set_turbo_mask(port, turbo_masks[port]);
         }
+        self.runtime_handle.set_multitap(self.multitap);
+        self.runtime_handle.set_zapper_port(self.zapper_port);
 
         // 4. Handle Drag & Drop
         let dropped = ctx.input(|i| i.raw.dropped_files.clone());
@@ -13,6 +13,8 @@ pub enum ControllerDevice {
     #[default]
     Keyboard,
     Gamepad(GamepadId),
+    /// NES Zapper aimed with the mouse over the game view (ports 1/2 only).
+    Zapper,
 }
 
 #[derive(Default, Clone)]
@@ -6,7 +6,7 @@ use std::{
 
 pub fn pick_file_dialog() -> Option<PathBuf> {
     rfd::FileDialog::new()
-        .add_filter("NES ROM", &["nes", "fds"])
+        .add_filter("NES ROM", &["nes", "fds", "zip"])
         .pick_file()
 }
 
@@ -20,12 +20,19 @@ impl LanguagePack for En {
             MenuEmulation => "Emulation",
             MenuEmulationPause => "Pause",
             MenuEmulationResume => "Resume",
+            MenuEmulationOverclock => "Overclock",
+            MenuEmulationOverclockBeforeNmi => "Extra scanlines before NMI",
+            MenuEmulationOverclockAfterNmi => "Extra scanlines after NMI",
+            MenuEmulationOverclockOff => "Off",
 
             MenuView => "View",
             MenuViewScale => "Scale",
             MenuViewScaleSquare => "1:1 (Square pixels)",
             MenuViewScaleNtsc => "4:3 (NTSC)",
             MenuViewScaleStretch => "Stretch",
+            MenuViewShowBackground => "Show background",
+            MenuViewShowSprites => "Show sprites",
+            MenuViewRemoveSpriteLimit => "Remove sprite limit",
 
             MenuWindow => "Window",
             MenuWindowDebugger => "Debugger",
@@ -37,7 +44,7 @@ impl LanguagePack for En {
             MenuHelp => "Help",
             MenuHelpAbout => "About",
             MenuHelpLine1 => "Desktop frontend built with eframe + egui",
-            MenuHelpLine2 => "Drag a .nes/.fds file here, or use File → Load ROM",
+            MenuHelpLine2 => "Drag a .nes/.fds/.zip file here, or use File → Load ROM",
             MenuLanguage => "Language",
             AboutWindowTitle => "About Nesium",
             AboutLead => "Nesium: Rust NES/FC emulator frontend built on nesium-core.",
@@ -67,15 +74,23 @@ impl LanguagePack for En {
             InputControllerPortsLabel => "Controller ports:",
             InputDeviceKeyboard => "Keyboard",
             InputDeviceDisabled => "Disabled",
+            InputDeviceZapper => "Zapper (mouse)",
+            InputZapperLagFrames => "Display lag compensation (frames)",
             InputNoGamepads => "No gamepad connected",
             InputGamepadUnavailable => "Gamepad unavailable",
             InputPort34Notice => {
-                "Note: Ports 3 and 4 are not wired to the NES core yet; they are only for pre-configuring mappings."
+                "Note: Ports 3 and 4 are only seen by games when a four-player adapter is connected."
             }
+            InputMultitapLabel => "4-player adapter:",
+            InputMultitapNone => "None",
+            InputMultitapFourScore => "Four Score (NES)",
+            InputMultitapHori => "Hori adapter (Famicom)",
             InputPresetLabel => "Preset:",
             InputPresetNesStandard => "NES Standard Gamepad",
             InputPresetFightStick => "Fight Stick",
             InputPresetArcadeLayout => "Arcade Layout",
+            InputSwapAB => "Swap A/B",
+            InputSwapSelectStart => "Swap Select/Start",
             InputKeyboardMappingTitle => "Keyboard mapping → NES pad",
             InputKeyboardMappingHelp => {
                 "Click “Bind” then press a key; Esc clears binding. “Reset to defaults” restores factory mapping."
@@ -126,6 +141,13 @@ impl LanguagePack for En {
             AudioEqSection => "Equalizer (EQ)",
             AudioEnableEq => "Enable EQ",
             AudioEqGlobalGain => "Global gain (dB)",
+            AudioStereoSection => "Stereo",
+            AudioStereoPreset => "Channel panning",
+            AudioStereoPresetMono => "Mono",
+            AudioStereoPresetLight => "Light pan",
+            AudioStereoPresetMesenSpread => "Mesen-style spread",
+            AudioStereoPresetCustom => "Custom",
+            AudioExpansionBalance => "Expansion chip balance",
 
             // Debugger viewport
             DebuggerNoRomTitle => "No ROM Loaded",
@@ -32,11 +32,18 @@ pub enum TextId {
     MenuEmulation,
     MenuEmulationPause,
     MenuEmulationResume,
+    MenuEmulationOverclock,
+    MenuEmulationOverclockBeforeNmi,
+    MenuEmulationOverclockAfterNmi,
+    MenuEmulationOverclockOff,
     MenuView,
     MenuViewScale,
     MenuViewScaleSquare,
     MenuViewScaleNtsc,
     MenuViewScaleStretch,
+    MenuViewShowBackground,
+    MenuViewShowSprites,
+    MenuViewRemoveSpriteLimit,
     MenuWindow,
     MenuWindowDebugger,
     MenuWindowTools,
@@ -74,13 +81,21 @@ pub enum TextId {
     InputControllerPortsLabel,
     InputDeviceKeyboard,
     InputDeviceDisabled,
+    InputDeviceZapper,
+    InputZapperLagFrames,
     InputNoGamepads,
     InputGamepadUnavailable,
     InputPort34Notice,
+    InputMultitapLabel,
+    InputMultitapNone,
+    InputMultitapFourScore,
+    InputMultitapHori,
     InputPresetLabel,
     InputPresetNesStandard,
     InputPresetFightStick,
     InputPresetArcadeLayout,
+    InputSwapAB,
+    InputSwapSelectStart,
     InputKeyboardMappingTitle,
     InputKeyboardMappingHelp,
     InputGridHeaderCategory,
@@ -127,6 +142,13 @@ pub enum TextId {
     AudioEqSection,
     AudioEnableEq,
     AudioEqGlobalGain,
+    AudioStereoSection,
+    AudioStereoPreset,
+    AudioStereoPresetMono,
+    AudioStereoPresetLight,
+    AudioStereoPresetMesenSpread,
+    AudioStereoPresetCustom,
+    AudioExpansionBalance,
 
     // Debugger viewport
     DebuggerNoRomTitle,
@@ -20,12 +20,19 @@ impl LanguagePack for Zh {
             MenuEmulation => "模拟",
             MenuEmulationPause => "暂停",
             MenuEmulationResume => "继续",
+            MenuEmulationOverclock => "超频",
+            MenuEmulationOverclockBeforeNmi => "NMI 前额外扫描线",
+            MenuEmulationOverclockAfterNmi => "NMI 后额外扫描线",
+            MenuEmulationOverclockOff => "关闭",
 
             MenuView => "视图",
             MenuViewScale => "比例",
             MenuViewScaleSquare => "1:1 (方形像素)",
             MenuViewScaleNtsc => "4:3 (NTSC)",
             MenuViewScaleStretch => "拉伸",
+            MenuViewShowBackground => "显示背景",
+            MenuViewShowSprites => "显示精灵",
+            MenuViewRemoveSpriteLimit => "移除精灵数量限制",
 
             MenuWindow => "窗口",
 
@@ -38,7 +45,7 @@ impl LanguagePack for Zh {
             MenuHelp => "帮助",
             MenuHelpAbout => "关于",
             MenuHelpLine1 => "基于 eframe + egui 的桌面前端",
-            MenuHelpLine2 => "将 .nes/.fds 拖拽到窗口，或使用「文件 → 加载 ROM」",
+            MenuHelpLine2 => "将 .nes/.fds/.zip 拖拽到窗口，或使用「文件 → 加载 ROM」",
             MenuLanguage => "语言",
             AboutWindowTitle => "关于 Nesium",
             AboutLead => "Nesium：基于 nesium-core 的 Rust NES/FC 前端。",
@@ -68,13 +75,21 @@ impl LanguagePack for Zh {
             InputControllerPortsLabel => "控制器端口:",
             InputDeviceKeyboard => "键盘",
             InputDeviceDisabled => "禁用",
+            InputDeviceZapper => "光枪 (鼠标)",
+            InputZapperLagFrames => "显示延迟补偿 (帧)",
             InputNoGamepads => "无手柄连接",
             InputGamepadUnavailable => "手柄不可用",
-            InputPort34Notice => "注意：端口 3 和 4 当前尚未接入 NES 核心，仅用于预配置映射。",
+            InputPort34Notice => "注意：仅在连接四人适配器时，游戏才能读取端口 3 和 4。",
+            InputMultitapLabel => "四人适配器:",
+            InputMultitapNone => "无",
+            InputMultitapFourScore => "Four Score (NES)",
+            InputMultitapHori => "Hori 适配器 (FC)",
             InputPresetLabel => "预设:",
             InputPresetNesStandard => "NES 标准手柄",
             InputPresetFightStick => "Fight Stick",
             InputPresetArcadeLayout => "Arcade Layout",
+            InputSwapAB => "交换 A/B",
+            InputSwapSelectStart => "交换 Select/Start",
             InputKeyboardMappingTitle => "键盘映射 → NES 手柄",
             InputKeyboardMappingHelp => {
                 "点击“绑定”后按一个键，Esc 清除绑定；右下角“恢复默认”可还原出厂配置。"
@@ -123,6 +138,13 @@ impl LanguagePack for Zh {
             AudioEqSection => "均衡器 (EQ)",
             AudioEnableEq => "启用 EQ",
             AudioEqGlobalGain => "全局增益 (dB)",
+            AudioStereoSection => "立体声",
+            AudioStereoPreset => "声道声像",
+            AudioStereoPresetMono => "单声道",
+            AudioStereoPresetLight => "轻度分离",
+            AudioStereoPresetMesenSpread => "Mesen 风格展开",
+            AudioStereoPresetCustom => "自定义",
+            AudioExpansionBalance => "扩展音源平衡",
 
             // Debugger viewport
             DebuggerNoRomTitle => "未加载 ROM",
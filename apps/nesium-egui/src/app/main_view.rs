@@ -68,6 +68,11 @@ impl NesiumApp {
                         Color32::WHITE,
                     );
 
+                    if self.zapper_port.is_some() {
+                        self.update_zapper(ctx, image_rect);
+                        return;
+                    }
+
                     // Auto-hide cursor when hovering over the game image without moving.
                     let pointer_over_image = response.hovered()
                         && ctx
@@ -95,4 +100,33 @@ impl NesiumApp {
                 }
             });
     }
+
+    /// Feeds the mouse position/buttons to the Zapper. Left click fires at the
+    /// pointer; right click fires off-screen (used by games to reload).
+    fn update_zapper(&mut self, ctx: &EguiContext, image_rect: egui::Rect) {
+        let (hover, primary, secondary) = ctx.input(|i| {
+            (
+                i.pointer.hover_pos(),
+                i.pointer.primary_down(),
+                i.pointer.secondary_down(),
+            )
+        });
+
+        let aim = hover
+            .filter(|p| image_rect.contains(*p) && !secondary)
+            .map(|p| {
+                let rel = (p - image_rect.min) / image_rect.size();
+                (
+                    (rel.x * SCREEN_WIDTH as f32) as i32,
+                    (rel.y * SCREEN_HEIGHT as f32) as i32,
+                )
+            });
+        if aim.is_some() {
+            ctx.output_mut(|o| o.cursor_icon = egui::CursorIcon::Crosshair);
+        }
+
+        self.runtime_handle.set_zapper_pointer(aim);
+        self.runtime_handle
+            .set_zapper_trigger((primary && aim.is_some()) || secondary);
+    }
 }
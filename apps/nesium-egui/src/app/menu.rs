@@ -2,6 +2,7 @@ use std::path::PathBuf;
 
 use eframe::egui;
 use egui::{Context as EguiContext, MenuBar, TextWrapMode};
+use nesium_core::config::overclock::Overclock;
 
 use super::{AppViewport, AspectRatio, Language, NesiumApp, TextId, dialogs::pick_file_dialog};
 
@@ -15,7 +16,84 @@ pub(super) struct AppCommand {
     pub quit: bool,
 }
 
+/// Extra scanline presets: off, +25%, +50% and +100% of an NTSC frame.
+const OVERCLOCK_PRESETS: [u16; 4] = [0, 66, 131, 262];
+
 impl NesiumApp {
+    fn draw_layer_toggles(&mut self, ui: &mut egui::Ui) {
+        let (mut background, mut sprites) = self.layers_shown();
+        if ui
+            .checkbox(&mut background, self.t(TextId::MenuViewShowBackground))
+            .changed()
+        {
+            if let Ok(mut s) = self.ui_state.lock() {
+                s.show_background = background;
+            }
+            let _ = self.runtime_handle.set_background_layer_enabled(background);
+        }
+        if ui
+            .checkbox(&mut sprites, self.t(TextId::MenuViewShowSprites))
+            .changed()
+        {
+            if let Ok(mut s) = self.ui_state.lock() {
+                s.show_sprites = sprites;
+            }
+            let _ = self.runtime_handle.set_sprite_layer_enabled(sprites);
+        }
+
+        ui.separator();
+        let mut remove_limit = self.remove_sprite_limit();
+        if ui
+            .checkbox(&mut remove_limit, self.t(TextId::MenuViewRemoveSpriteLimit))
+            .changed()
+        {
+            if let Ok(mut s) = self.ui_state.lock() {
+                s.remove_sprite_limit = remove_limit;
+            }
+            let _ = self.runtime_handle.set_sprite_limit_removed(remove_limit);
+        }
+    }
+
+    fn draw_overclock_menu(&mut self, ui: &mut egui::Ui) {
+        let current = self.overclock();
+        let mut selected = current;
+
+        let sections = [
+            (
+                TextId::MenuEmulationOverclockBeforeNmi,
+                &mut selected.extra_scanlines_before_nmi,
+            ),
+            (
+                TextId::MenuEmulationOverclockAfterNmi,
+                &mut selected.extra_scanlines_after_nmi,
+            ),
+        ];
+        for (i, (heading, lines)) in sections.into_iter().enumerate() {
+            if i > 0 {
+                ui.separator();
+            }
+            ui.label(self.t(heading));
+            for preset in OVERCLOCK_PRESETS {
+                let label = if preset == 0 {
+                    self.t(TextId::MenuEmulationOverclockOff).to_owned()
+                } else {
+                    format!("+{preset} (+{}%)", u32::from(preset) * 100 / 262)
+                };
+                if ui.radio(*lines == preset, label).clicked() {
+                    *lines = preset;
+                }
+            }
+        }
+
+        if selected != current {
+            if let Ok(mut s) = self.ui_state.lock() {
+                s.overclock = selected;
+            }
+            let _ = self.runtime_handle.set_overclock(selected);
+            ui.close();
+        }
+    }
+
     pub(super) fn draw_menu(&mut self, ctx: &EguiContext) -> Option<AppCommand> {
         let mut cmd = AppCommand::default();
         let fullscreen = ctx.input(|i| i.viewport().fullscreen).unwrap_or(false);
@@ -128,6 +206,11 @@ impl NesiumApp {
                 cmd.power_off = true;
                 ui.close();
             }
+            ui.separator();
+            ui.menu_button(self.t(TextId::MenuEmulationOverclock), |ui| {
+                ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                self.draw_overclock_menu(ui);
+            });
         });
 
         ui.menu_button(self.t(TextId::MenuView), |ui| {
@@ -174,6 +257,8 @@ impl NesiumApp {
                     ui.close();
                 }
             });
+            ui.separator();
+            self.draw_layer_toggles(ui);
         });
 
         ui.menu_button(self.t(TextId::MenuWindow), |ui| {
@@ -6,7 +6,11 @@ use std::sync::{
 use eframe::egui;
 use egui::{Color32, Context as EguiContext, ViewportBuilder, ViewportClass, ViewportId};
 use gilrs::Button as GilrsButton;
-use nesium_core::{controller::Button, ppu::palette::PaletteKind};
+use nesium_core::{
+    audio::{AudioChannel, StereoPreset},
+    controller::{Button, ButtonRemap, MultitapKind},
+    ppu::palette::PaletteKind,
+};
 use nesium_runtime::DebugState;
 
 use super::{
@@ -361,10 +365,12 @@ impl NesiumApp {
                     let (
                         mut pixel_perfect,
                         mut integer_fps,
+                        mut clone_ppu,
                         title,
                         pixel_label,
                         fps_label,
                         fps_hint,
+                        clone_ppu_label,
                         heading,
                         placeholder,
                     ) = {
@@ -373,6 +379,7 @@ impl NesiumApp {
                         (
                             state.pixel_perfect_scaling,
                             state.integer_fps_mode,
+                            state.clone_ppu,
                             state.i18n.text(TextId::MenuWindowTools),
                             match lang {
                                 super::Language::English => "Pixel-perfect scaling (integer)",
@@ -386,6 +393,14 @@ impl NesiumApp {
                                 super::Language::English => "PAL (50Hz) will be added later.",
                                 super::Language::ChineseSimplified => "PAL（50Hz）后续再支持。",
                             },
+                            match lang {
+                                super::Language::English => {
+                                    "Famiclone PPU (no emphasis, approximate clone palette)"
+                                }
+                                super::Language::ChineseSimplified => {
+                                    "兼容机 PPU（无色彩强调，近似兼容机调色板）"
+                                }
+                            },
                             state.i18n.text(TextId::ToolsHeading),
                             state.i18n.text(TextId::ToolsPlaceholder),
                         )
@@ -393,6 +408,7 @@ impl NesiumApp {
 
                     let mut changed = false;
                     let mut fps_changed = false;
+                    let mut clone_ppu_changed = false;
                     let close_requested = show_viewport_content(ctx, class, title, |ui| {
                         ui.heading(heading);
                         if ui.checkbox(&mut pixel_perfect, pixel_label).changed() {
@@ -402,6 +418,9 @@ impl NesiumApp {
                             fps_changed = true;
                         }
                         ui.label(fps_hint);
+                        if ui.checkbox(&mut clone_ppu, clone_ppu_label).changed() {
+                            clone_ppu_changed = true;
+                        }
                         ui.add_space(6.0);
                         ui.label(placeholder);
                     });
@@ -417,6 +436,10 @@ impl NesiumApp {
                             None
                         });
                     }
+                    if clone_ppu_changed {
+                        ui_state_arc.lock().unwrap().clone_ppu = clone_ppu;
+                        let _ = runtime_handle.set_clone_ppu(clone_ppu);
+                    }
                     close_requested
                 },
             );
@@ -799,6 +822,8 @@ impl NesiumApp {
                                         ui_state.i18n.text(TextId::InputDeviceKeyboard);
                                     let disabled_label =
                                         ui_state.i18n.text(TextId::InputDeviceDisabled);
+                                    let zapper_label =
+                                        ui_state.i18n.text(TextId::InputDeviceZapper);
                                     let no_gamepads_label =
                                         ui_state.i18n.text(TextId::InputNoGamepads);
                                     let gamepad_unavailable_label =
@@ -838,6 +863,14 @@ impl NesiumApp {
                                         ui.label(gamepad_unavailable_label);
                                     }
 
+                                    if port < 2 {
+                                        ui.selectable_value(
+                                            &mut ui_state.controller_devices[port],
+                                            ControllerDevice::Zapper,
+                                            zapper_label,
+                                        );
+                                    }
+
                                     ui.selectable_value(
                                         &mut ui_state.controller_devices[port],
                                         ControllerDevice::Disabled,
@@ -845,7 +878,31 @@ impl NesiumApp {
                                     );
                                 });
 
-                                if port >= 2 {
+                                ui.horizontal(|ui| {
+                                    ui.label(ui_state.i18n.text(TextId::InputMultitapLabel));
+                                    let options = [
+                                        (None, TextId::InputMultitapNone),
+                                        (
+                                            Some(MultitapKind::FourScore),
+                                            TextId::InputMultitapFourScore,
+                                        ),
+                                        (Some(MultitapKind::Hori), TextId::InputMultitapHori),
+                                    ];
+                                    for (kind, text) in options {
+                                        let label = ui_state.i18n.text(text);
+                                        ui.selectable_value(&mut ui_state.multitap, kind, label);
+                                    }
+                                });
+
+                                if ui_state.controller_devices[port] == ControllerDevice::Zapper {
+                                    let label = ui_state.i18n.text(TextId::InputZapperLagFrames);
+                                    ui.add(
+                                        egui::Slider::new(&mut ui_state.zapper_lag_frames, 0..=8)
+                                            .text(label),
+                                    );
+                                }
+
+                                if port >= 2 && ui_state.multitap.is_none() {
                                     ui.colored_label(
                                         Color32::DARK_GRAY,
                                         ui_state.i18n.text(TextId::InputPort34Notice),
@@ -910,6 +967,28 @@ impl NesiumApp {
                                     ui_state.controller_presets[port] = preset_value;
                                 });
 
+                                ui.horizontal(|ui| {
+                                    let swap_ab_label = ui_state.i18n.text(TextId::InputSwapAB);
+                                    let swap_ss_label =
+                                        ui_state.i18n.text(TextId::InputSwapSelectStart);
+                                    let remap = ui_state.button_remaps[port];
+                                    let mut swap_ab = remap.target(Button::A) == Button::B;
+                                    let mut swap_ss = remap.target(Button::Select) == Button::Start;
+                                    let changed =
+                                        ui.checkbox(&mut swap_ab, swap_ab_label).changed()
+                                            | ui.checkbox(&mut swap_ss, swap_ss_label).changed();
+                                    if changed {
+                                        let mut remap = ButtonRemap::IDENTITY;
+                                        if swap_ab {
+                                            remap = remap.with_swap(Button::A, Button::B);
+                                        }
+                                        if swap_ss {
+                                            remap = remap.with_swap(Button::Select, Button::Start);
+                                        }
+                                        ui_state.button_remaps[port] = remap;
+                                    }
+                                });
+
                                 ui.separator();
                                 ui.label(ui_state.i18n.text(TextId::InputKeyboardMappingTitle));
                                 ui.small(ui_state.i18n.text(TextId::InputKeyboardMappingHelp));
@@ -1329,8 +1408,31 @@ impl NesiumApp {
                             ui_state.audio_cfg,
                         )
                     };
+                    let (
+                        stereo_section_label,
+                        stereo_preset_label,
+                        preset_labels,
+                        custom_label,
+                        balance_label,
+                        mut mixer,
+                    ) = {
+                        let ui_state = ui_state.lock().unwrap();
+                        (
+                            ui_state.i18n.text(TextId::AudioStereoSection),
+                            ui_state.i18n.text(TextId::AudioStereoPreset),
+                            [
+                                ui_state.i18n.text(TextId::AudioStereoPresetMono),
+                                ui_state.i18n.text(TextId::AudioStereoPresetLight),
+                                ui_state.i18n.text(TextId::AudioStereoPresetMesenSpread),
+                            ],
+                            ui_state.i18n.text(TextId::AudioStereoPresetCustom),
+                            ui_state.i18n.text(TextId::AudioExpansionBalance),
+                            ui_state.mixer_settings.clone(),
+                        )
+                    };
 
                     let mut changed = false;
+                    let mut mixer_changed = false;
                     let close_requested = show_viewport_content(ctx, class, title, |ui| {
                         ui.heading(heading);
                         ui.separator();
@@ -1455,14 +1557,67 @@ impl NesiumApp {
                             }
                         });
 
+                        ui.collapsing(stereo_section_label, |ui| {
+                            let current = mixer.stereo_preset();
+                            let mut selected = current;
+                            egui::ComboBox::from_label(stereo_preset_label)
+                                .selected_text(
+                                    current.map_or(custom_label, |p| preset_labels[p as usize]),
+                                )
+                                .show_ui(ui, |ui| {
+                                    for preset in StereoPreset::ALL {
+                                        ui.selectable_value(
+                                            &mut selected,
+                                            Some(preset),
+                                            preset_labels[preset as usize],
+                                        );
+                                    }
+                                });
+                            if let Some(preset) = selected
+                                && selected != current
+                            {
+                                mixer.apply_stereo_preset(preset);
+                                mixer_changed = true;
+                            }
+
+                            ui.label(balance_label);
+                            for (channel, name) in [
+                                (AudioChannel::Fds, "FDS"),
+                                (AudioChannel::Mmc5, "MMC5"),
+                                (AudioChannel::Vrc6, "VRC6"),
+                                (AudioChannel::Vrc7, "VRC7"),
+                                (AudioChannel::Namco163, "Namco 163"),
+                                (AudioChannel::Sunsoft5B, "Sunsoft 5B"),
+                            ] {
+                                if ui
+                                    .add(
+                                        egui::Slider::new(
+                                            &mut mixer.expansion_balance[channel.idx()],
+                                            -1.0..=1.0,
+                                        )
+                                        .text(name),
+                                    )
+                                    .changed()
+                                {
+                                    mixer_changed = true;
+                                }
+                            }
+                        });
+
                         if changed {
                             let _ = runtime_handle.set_audio_config(cfg);
                         }
+                        if mixer_changed {
+                            let _ = runtime_handle.set_mixer_settings(mixer.clone());
+                        }
                     });
 
                     if changed && let Ok(mut ui_state) = ui_state.lock() {
                         ui_state.audio_cfg = cfg;
                     }
+                    if mixer_changed && let Ok(mut ui_state) = ui_state.lock() {
+                        ui_state.mixer_settings = mixer;
+                    }
                     close_requested
                 },
             );
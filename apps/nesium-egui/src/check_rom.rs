@@ -0,0 +1,112 @@
+//! `check-rom` subcommand: runs a ROM headlessly and prints a compatibility
+//! report (mapper support, unmapped writes, frame/audio hashes) for triage.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use clap::Args;
+use nesium_core::{Nes, error::Error};
+
+/// Unmapped write addresses listed individually before summarizing.
+const MAX_LISTED_WRITES: usize = 16;
+
+#[derive(Debug, Args)]
+pub struct CheckRomArgs {
+    /// ROM to check.
+    #[arg(short = 'r', long = "rom", value_name = "PATH")]
+    pub rom_path: PathBuf,
+
+    /// Number of frames to emulate.
+    #[arg(short = 'f', long, default_value_t = 600)]
+    pub frames: u32,
+}
+
+/// Runs the check and returns `true` when the ROM loaded and ran without
+/// jamming the CPU.
+pub fn run(args: &CheckRomArgs) -> Result<bool> {
+    println!("rom: {}", args.rom_path.display());
+
+    let mut nes = Nes::builder().build();
+    match nes.load_cartridge_from_file(&args.rom_path) {
+        Ok(()) => {}
+        Err(Error::UnsupportedMapper(mapper)) => {
+            println!("mapper: {mapper} (not implemented)");
+            return Ok(false);
+        }
+        Err(err) => {
+            return Err(err).with_context(|| format!("loading {}", args.rom_path.display()));
+        }
+    }
+
+    if let Some(cart) = nes.get_cartridge() {
+        let info = cart.info();
+        println!(
+            "mapper: {}.{} ({}) supported",
+            info.mapper, info.submapper, info.board
+        );
+        println!(
+            "database: {}",
+            if info.database_match {
+                "match"
+            } else {
+                "no match"
+            }
+        );
+    }
+
+    nes.set_unmapped_write_tracking(true);
+    let mut frame_hash = Fnv1a::new();
+    let mut audio_hash = Fnv1a::new();
+    let mut frames_run = 0;
+    while frames_run < args.frames && nes.cpu_jam().is_none() {
+        let samples = nes.run_frame(true);
+        frame_hash.update(&nes.frame_crc().to_le_bytes());
+        for sample in samples {
+            let pcm = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+            audio_hash.update(&pcm.to_le_bytes());
+        }
+        frames_run += 1;
+    }
+
+    println!("frames: {frames_run}");
+    println!("last frame crc: {:08x}", nes.frame_crc());
+    println!("frame hash: {:016x}", frame_hash.finish());
+    println!("audio hash: {:016x}", audio_hash.finish());
+
+    let writes = nes.unmapped_writes();
+    println!("unmapped writes: {} address(es)", writes.len());
+    for (addr, count) in writes.iter().take(MAX_LISTED_WRITES) {
+        println!("  ${addr:04X} x{count}");
+    }
+    if writes.len() > MAX_LISTED_WRITES {
+        println!("  ... {} more", writes.len() - MAX_LISTED_WRITES);
+    }
+
+    if let Some(jam) = nes.cpu_jam() {
+        println!(
+            "cpu: jammed on ${:02X} at ${:04X} after {frames_run} frames",
+            jam.opcode, jam.pc
+        );
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+/// 64-bit FNV-1a, same constants as `Nes::frame_hash`.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
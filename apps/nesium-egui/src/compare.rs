@@ -0,0 +1,184 @@
+//! `compare-trace` subcommand: runs the core against a reference trace
+//! (Mesen2 execution log and/or WAV recording) and reports the first divergence.
+
+use std::{
+    fs::{self, File},
+    io::BufWriter,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use clap::Args;
+use nesium_core::{
+    Nes,
+    trace::{
+        AudioComparer, MESEN_TRACE_FORMAT, TraceCompareOutcome, TraceComparer, TraceFormat,
+        WriterSink, parse_reference_trace,
+    },
+};
+
+#[derive(Debug, Args)]
+pub struct CompareTraceArgs {
+    /// ROM to run.
+    #[arg(short = 'r', long = "rom", value_name = "PATH")]
+    pub rom_path: PathBuf,
+
+    /// Reference CPU trace log (Mesen2 or nestest format).
+    #[arg(short = 't', long = "trace", value_name = "PATH")]
+    pub trace_path: Option<PathBuf>,
+
+    /// Reference audio recording (16-bit PCM WAV at the core's output rate).
+    #[arg(short = 'a', long = "audio", value_name = "PATH")]
+    pub audio_path: Option<PathBuf>,
+
+    /// Also write the core's own trace log here while comparing.
+    #[arg(long = "log", value_name = "PATH")]
+    pub log_path: Option<PathBuf>,
+
+    /// Mesen2-style format string for the core's trace lines.
+    #[arg(long, default_value = MESEN_TRACE_FORMAT)]
+    pub format: String,
+
+    /// Number of matched lines printed before the divergence.
+    #[arg(long, default_value_t = 16)]
+    pub context: usize,
+
+    /// Maximum per-sample difference tolerated in audio comparison.
+    #[arg(long, default_value_t = 0)]
+    pub audio_tolerance: u16,
+}
+
+/// Runs the comparison and returns `true` when everything matched.
+pub fn run(args: &CompareTraceArgs) -> Result<bool> {
+    if args.trace_path.is_none() && args.audio_path.is_none() {
+        bail!("nothing to compare: pass --trace and/or --audio");
+    }
+
+    let mut matched = true;
+    if let Some(path) = &args.trace_path {
+        matched &= compare_execution(args, path)?;
+    }
+    if let Some(path) = &args.audio_path {
+        matched &= compare_audio(args, path)?;
+    }
+    Ok(matched)
+}
+
+fn load_nes(args: &CompareTraceArgs, sample_rate: Option<u32>) -> Result<Nes> {
+    let mut builder = Nes::builder();
+    if let Some(rate) = sample_rate {
+        builder = builder.sample_rate(rate);
+    }
+    let mut nes = builder.build();
+    nes.load_cartridge_from_file(&args.rom_path)
+        .with_context(|| format!("loading {}", args.rom_path.display()))?;
+    Ok(nes)
+}
+
+fn compare_execution(args: &CompareTraceArgs, path: &Path) -> Result<bool> {
+    let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let reference = parse_reference_trace(&text);
+    if reference.is_empty() {
+        bail!("{} contains no trace lines", path.display());
+    }
+
+    let format = TraceFormat::parse(&args.format).context("parsing --format")?;
+    let mut comparer = TraceComparer::new(args.context).with_format(format);
+    if let Some(log_path) = &args.log_path {
+        let file =
+            File::create(log_path).with_context(|| format!("creating {}", log_path.display()))?;
+        comparer = comparer.with_log(Box::new(WriterSink(BufWriter::new(file))));
+    }
+
+    let mut nes = load_nes(args, None)?;
+    let outcome = comparer.run(&mut nes, reference);
+    if let Some(err) = comparer.take_log_error() {
+        eprintln!("trace: log write failed: {err}");
+    }
+    match outcome {
+        TraceCompareOutcome::Matched { instructions } => {
+            println!("trace: {instructions} instructions matched");
+            Ok(true)
+        }
+        TraceCompareOutcome::Diverged(divergence) => {
+            println!("trace: {divergence}");
+            Ok(false)
+        }
+    }
+}
+
+fn compare_audio(args: &CompareTraceArgs, path: &Path) -> Result<bool> {
+    let bytes = fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+    let (sample_rate, samples) =
+        read_wav_stereo_i16(&bytes).with_context(|| format!("parsing {}", path.display()))?;
+
+    let mut nes = load_nes(args, Some(sample_rate))?;
+    let mut comparer = AudioComparer::new(&samples, args.audio_tolerance);
+    let mut frame = 0u64;
+    while !comparer.finished() {
+        let produced = nes.run_frame(true);
+        if let Err(divergence) = comparer.compare_frame(frame, &produced) {
+            println!("audio: {divergence}");
+            return Ok(false);
+        }
+        frame += 1;
+    }
+    println!(
+        "audio: {} samples matched over {frame} frames",
+        comparer.position()
+    );
+    Ok(true)
+}
+
+/// Minimal RIFF/WAVE reader for interleaved 16-bit stereo PCM.
+fn read_wav_stereo_i16(bytes: &[u8]) -> Result<(u32, Vec<i16>)> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        bail!("not a RIFF/WAVE file");
+    }
+
+    let mut offset = 12;
+    let mut format = None;
+    while offset + 8 <= bytes.len() {
+        let id = &bytes[offset..offset + 4];
+        let len = u32::from_le_bytes([
+            bytes[offset + 4],
+            bytes[offset + 5],
+            bytes[offset + 6],
+            bytes[offset + 7],
+        ]) as usize;
+        let body_start = offset + 8;
+        let body_end = body_start.saturating_add(len).min(bytes.len());
+        let body = &bytes[body_start..body_end];
+
+        match id {
+            b"fmt " if body.len() >= 16 => {
+                let tag = u16::from_le_bytes([body[0], body[1]]);
+                let channels = u16::from_le_bytes([body[2], body[3]]);
+                let rate = u32::from_le_bytes([body[4], body[5], body[6], body[7]]);
+                let bits = u16::from_le_bytes([body[14], body[15]]);
+                if tag != 1 || channels != 2 || bits != 16 {
+                    bail!(
+                        "expected 16-bit stereo PCM (tag={tag}, channels={channels}, bits={bits})"
+                    );
+                }
+                format = Some(rate);
+            }
+            b"data" => {
+                let Some(rate) = format else {
+                    bail!("data chunk before fmt chunk");
+                };
+                let samples = body
+                    .chunks_exact(2)
+                    .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+                    .collect();
+                return Ok((rate, samples));
+            }
+            _ => {}
+        }
+
+        // Chunks are padded to an even length.
+        offset = body_start.saturating_add(len + (len & 1));
+    }
+
+    bail!("missing data chunk")
+}
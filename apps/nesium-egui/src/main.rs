@@ -4,12 +4,14 @@
 )]
 
 mod app;
+mod check_rom;
+mod compare;
 
 use std::{fs, path::PathBuf};
 
 use anyhow::{Result, anyhow};
 use app::{AppConfig, NesiumApp};
-use clap::{CommandFactory, Parser, error::ErrorKind};
+use clap::{CommandFactory, Parser, Subcommand, error::ErrorKind};
 use eframe::egui;
 use nesium_core::ppu::{SCREEN_HEIGHT, SCREEN_WIDTH};
 #[cfg(all(windows, not(debug_assertions)))]
@@ -172,6 +174,23 @@ fn main() -> Result<()> {
     // let _guard = init_tracing();
     let args = parse_cli_args_or_exit();
 
+    if let Some(command) = &args.command {
+        match command {
+            CliCommand::CompareTrace(compare_args) => {
+                let matched = compare::run(compare_args)?;
+                std::process::exit(if matched { 0 } else { 1 });
+            }
+            CliCommand::CheckRom(check_args) => {
+                let ok = check_rom::run(check_args)?;
+                std::process::exit(if ok { 0 } else { 1 });
+            }
+        }
+    }
+
+    // Flush battery RAM and write an emergency state if the process is
+    // killed while a game is running.
+    nesium_runtime::runtime::install_termination_watchdog();
+
     let icon = egui::IconData {
         rgba: ICON_RGBA.to_vec(),
         width: ICON_WIDTH,
@@ -227,7 +246,7 @@ fn preferred_renderer() -> eframe::Renderer {
     version,
     color = clap::ColorChoice::Never,
     about = "Nesium (egui frontend)",
-    long_about = "Nesium - a cross-platform NES emulator.\n\nExamples:\n  nesium_egui --rom path/to/game.nes\n  nesium_egui -r path/to/game.nes --lua path/to/script.lua\n\nNote: Lua script execution is not implemented yet; this flag is accepted for future support."
+    long_about = "Nesium - a cross-platform NES emulator.\n\nExamples:\n  nesium_egui --rom path/to/game.nes\n  nesium_egui -r path/to/game.nes --lua path/to/script.lua\n  nesium_egui compare-trace -r path/to/game.nes --trace mesen.log\n  nesium_egui check-rom -r path/to/game.nes --frames 1800\n\nNote: Lua script execution is not implemented yet; this flag is accepted for future support."
 )]
 struct CliArgs {
     /// ROM path to load at startup.
@@ -237,4 +256,15 @@ struct CliArgs {
     /// Lua script path to execute when game starts (not implemented yet).
     #[arg(short = 'l', long = "lua", alias = "script", value_name = "PATH")]
     lua_script_path: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Option<CliCommand>,
+}
+
+#[derive(Debug, Subcommand)]
+enum CliCommand {
+    /// Run a ROM against a reference trace and stop at the first divergence.
+    CompareTrace(compare::CompareTraceArgs),
+    /// Run a ROM headlessly and print a compatibility report.
+    CheckRom(check_rom::CheckRomArgs),
 }
@@ -0,0 +1,65 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+/// Applies a stereo preset and per-chip expansion balance. Channel volumes
+/// are left at full scale.
+Future<void> setAudioStereo({
+  required StereoPreset preset,
+  required ExpansionBalance balance,
+}) => RustLib.instance.api.crateApiAudioSetAudioStereo(
+  preset: preset,
+  balance: balance,
+);
+
+/// Expansion chip levels against the 2A03, each in `[-1.0, 1.0]`
+/// (-1 = silent, 0 = default, 1 = twice as loud).
+class ExpansionBalance {
+  final double fds;
+  final double mmc5;
+  final double vrc6;
+  final double vrc7;
+  final double namco163;
+  final double sunsoft5B;
+
+  const ExpansionBalance({
+    required this.fds,
+    required this.mmc5,
+    required this.vrc6,
+    required this.vrc7,
+    required this.namco163,
+    required this.sunsoft5B,
+  });
+
+  static Future<ExpansionBalance> default_() =>
+      RustLib.instance.api.crateApiAudioExpansionBalanceDefault();
+
+  @override
+  int get hashCode =>
+      fds.hashCode ^
+      mmc5.hashCode ^
+      vrc6.hashCode ^
+      vrc7.hashCode ^
+      namco163.hashCode ^
+      sunsoft5B.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExpansionBalance &&
+          runtimeType == other.runtimeType &&
+          fds == other.fds &&
+          mmc5 == other.mmc5 &&
+          vrc6 == other.vrc6 &&
+          vrc7 == other.vrc7 &&
+          namco163 == other.namco163 &&
+          sunsoft5B == other.sunsoft5B;
+}
+
+enum StereoPreset { mono, light, mesenSpread }
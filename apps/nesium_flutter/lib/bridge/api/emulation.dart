@@ -9,6 +9,11 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 Future<void> setIntegerFpsMode({required bool enabled}) =>
     RustLib.instance.api.crateApiEmulationSetIntegerFpsMode(enabled: enabled);
 
+/// Emulates a famiclone PPU (no color emphasis, approximate clone palette,
+/// true sprite overflow).
+Future<void> setClonePpu({required bool enabled}) =>
+    RustLib.instance.api.crateApiEmulationSetClonePpu(enabled: enabled);
+
 Future<void> saveState({required String path}) =>
     RustLib.instance.api.crateApiEmulationSaveState(path: path);
 
@@ -21,6 +26,11 @@ Future<Uint8List> saveStateToMemory() =>
 Future<void> loadStateFromMemory({required List<int> data}) =>
     RustLib.instance.api.crateApiEmulationLoadStateFromMemory(data: data);
 
+/// Restores the emergency state offered by
+/// `RuntimeNotificationKind::EmergencyStateAvailable`.
+Future<void> loadEmergencyState() =>
+    RustLib.instance.api.crateApiEmulationLoadEmergencyState();
+
 Future<void> setRewindConfig({
   required bool enabled,
   required BigInt capacity,
@@ -391,7 +391,17 @@ class RuntimeNotification {
           error == other.error;
 }
 
-enum RuntimeNotificationKind { audioInitFailed }
+enum RuntimeNotificationKind {
+  audioInitFailed,
+
+  /// The runtime thread crashed or the app is being killed; battery RAM and
+  /// an emergency state were written. `error` holds the reason.
+  emergencySaved,
+
+  /// The game just loaded has an emergency state from a session that did
+  /// not end cleanly; restore it with `load_emergency_state`.
+  emergencyStateAvailable,
+}
 
 /// Information about a single OAM sprite.
 class SpriteInfo {
@@ -22,3 +22,38 @@ Future<void> setTurboTiming({required int onFrames, required int offFrames}) =>
       onFrames: onFrames,
       offFrames: offFrames,
     );
+
+/// Plugs the Zapper light gun into `port` (0/1), or restores the pad with `None`.
+Future<void> setZapperPort({int? port}) =>
+    RustLib.instance.api.crateApiInputSetZapperPort(port: port);
+
+/// Aims the Zapper at `(x, y)` in NES pixels (`256x240`). The Dart side maps
+/// pointer/touch positions from the game view into this space.
+Future<void> setZapperPointer({required int x, required int y}) =>
+    RustLib.instance.api.crateApiInputSetZapperPointer(x: x, y: y);
+
+/// Aims the Zapper away from the screen (pointer left the view / reload shot).
+Future<void> clearZapperPointer() =>
+    RustLib.instance.api.crateApiInputClearZapperPointer();
+
+/// Pulls (`true`) or releases the Zapper trigger.
+Future<void> setZapperTrigger({required bool pulled}) =>
+    RustLib.instance.api.crateApiInputSetZapperTrigger(pulled: pulled);
+
+/// Shifts the Zapper aim by `(offset_x, offset_y)` NES pixels to line the
+/// pointer up with the picture; `(0, 0)` disables calibration.
+Future<void> setZapperCalibration({
+  required int offsetX,
+  required int offsetY,
+}) => RustLib.instance.api.crateApiInputSetZapperCalibration(
+  offsetX: offsetX,
+  offsetY: offsetY,
+);
+
+/// Shows or hides the crosshair the core draws at the Zapper aim point.
+Future<void> setZapperCrosshair({required bool visible}) =>
+    RustLib.instance.api.crateApiInputSetZapperCrosshair(visible: visible);
+
+/// Rolls the Zapper trigger back by `frames` to offset display lag (0 disables).
+Future<void> setZapperLagCompensation({required int frames}) =>
+    RustLib.instance.api.crateApiInputSetZapperLagCompensation(frames: frames);
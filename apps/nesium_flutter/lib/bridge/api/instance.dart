@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Starts a runtime next to the default one and returns its instance id.
+/// Pass `audio = false` for instances that should stay silent.
+Future<int> startInstance({required bool audio}) =>
+    RustLib.instance.api.crateApiInstanceStartInstance(audio: audio);
+
+/// Stops an instance started with [`start_instance`]. The default instance
+/// cannot be stopped.
+Future<void> stopInstance({required int instance}) =>
+    RustLib.instance.api.crateApiInstanceStopInstance(instance: instance);
+
+Future<void> instanceLoadRom({required int instance, required String path}) =>
+    RustLib.instance.api.crateApiInstanceInstanceLoadRom(
+      instance: instance,
+      path: path,
+    );
+
+Future<void> instanceLoadRomFromBytes({
+  required int instance,
+  required List<int> bytes,
+}) => RustLib.instance.api.crateApiInstanceInstanceLoadRomFromBytes(
+  instance: instance,
+  bytes: bytes,
+);
+
+Future<void> instanceResetConsole({required int instance}) => RustLib
+    .instance
+    .api
+    .crateApiInstanceInstanceResetConsole(instance: instance);
+
+Future<void> instanceSetPaused({required int instance, required bool paused}) =>
+    RustLib.instance.api.crateApiInstanceInstanceSetPaused(
+      instance: instance,
+      paused: paused,
+    );
+
+/// Sets the pressed buttons of `pad` directly, bypassing the keyboard and
+/// gamepad state tracked for the default instance.
+Future<void> instanceSetPadMask({
+  required int instance,
+  required int pad,
+  required int mask,
+}) => RustLib.instance.api.crateApiInstanceInstanceSetPadMask(
+  instance: instance,
+  pad: pad,
+  mask: mask,
+);
+
+Future<Uint8List> instanceSaveStateToMemory({required int instance}) => RustLib
+    .instance
+    .api
+    .crateApiInstanceInstanceSaveStateToMemory(instance: instance);
+
+Future<void> instanceLoadStateFromMemory({
+  required int instance,
+  required List<int> data,
+}) => RustLib.instance.api.crateApiInstanceInstanceLoadStateFromMemory(
+  instance: instance,
+  data: data,
+);
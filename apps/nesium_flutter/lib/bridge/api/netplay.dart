@@ -10,7 +10,7 @@ part 'netplay.freezed.dart';
 
 // These functions are ignored because they are not marked as `pub`: `lock_unpoison`, `netplay_disconnect_inner`, `notify_status`, `resolve_addr`, `signaling_connect_and_handshake`, `signaling_request`, `start_netplay_session_with_client`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `NetplayManager`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 // These functions are ignored (category: IgnoreBecauseExplicitAttribute): `get_manager`
 
 /// Connect to netplay server and perform handshake.
@@ -70,13 +70,40 @@ Future<void> netplayConnectQuicPinned({
   playerName: playerName,
 );
 
-/// Create a new netplay room.
-Future<void> netplayCreateRoom() =>
-    RustLib.instance.api.crateApiNetplayNetplayCreateRoom();
+/// Connect to netplay server over TLS (platform root store) and perform handshake.
+Future<void> netplayConnectTls({
+  required String serverAddr,
+  required String serverName,
+  required String playerName,
+}) => RustLib.instance.api.crateApiNetplayNetplayConnectTls(
+  serverAddr: serverAddr,
+  serverName: serverName,
+  playerName: playerName,
+);
+
+/// Connect to netplay server over TLS (pinned SHA-256 fingerprint) and perform handshake.
+Future<void> netplayConnectTlsPinned({
+  required String serverAddr,
+  required String serverName,
+  required String pinnedSha256Fingerprint,
+  required String playerName,
+}) => RustLib.instance.api.crateApiNetplayNetplayConnectTlsPinned(
+  serverAddr: serverAddr,
+  serverName: serverName,
+  pinnedSha256Fingerprint: pinnedSha256Fingerprint,
+  playerName: playerName,
+);
+
+/// Create a new netplay room. A non-empty `password` makes it private.
+Future<void> netplayCreateRoom({String? password}) =>
+    RustLib.instance.api.crateApiNetplayNetplayCreateRoom(password: password);
 
-/// Join an existing netplay room by code.
-Future<void> netplayJoinRoom({required int roomCode}) =>
-    RustLib.instance.api.crateApiNetplayNetplayJoinRoom(roomCode: roomCode);
+/// Join an existing netplay room by code, with its password if private.
+Future<void> netplayJoinRoom({required int roomCode, String? password}) =>
+    RustLib.instance.api.crateApiNetplayNetplayJoinRoom(
+      roomCode: roomCode,
+      password: password,
+    );
 
 /// Switch player role (1P, 2P, Spectator).
 Future<void> netplaySwitchRole({required int role}) =>
@@ -226,6 +253,51 @@ Future<void> netplaySendRomLoaded() =>
 Future<void> netplaySendPause({required bool paused}) =>
     RustLib.instance.api.crateApiNetplayNetplaySendPause(paused: paused);
 
+/// Send a chat message to the room.
+Future<void> netplaySendChat({required String text}) =>
+    RustLib.instance.api.crateApiNetplayNetplaySendChat(text: text);
+
+/// Send a quick emote to the room.
+Future<void> netplaySendEmote({required int emote}) =>
+    RustLib.instance.api.crateApiNetplayNetplaySendEmote(emote: emote);
+
+/// Ask the server for public rooms; the answer arrives as a `RoomList` event.
+/// `None` filters match any game or region.
+Future<void> netplayListRooms({int? gameHash, String? region}) => RustLib
+    .instance
+    .api
+    .crateApiNetplayNetplayListRooms(gameHash: gameHash, region: region);
+
+/// Join a matching public room with a free player slot, or create one.
+Future<void> netplayQuickMatch({int? gameHash, String? region}) => RustLib
+    .instance
+    .api
+    .crateApiNetplayNetplayQuickMatch(gameHash: gameHash, region: region);
+
+/// Host-only: describe the current room in the lobby.
+Future<void> netplaySetRoomInfo({
+  required String name,
+  required String region,
+  required int gameHash,
+}) => RustLib.instance.api.crateApiNetplayNetplaySetRoomInfo(
+  name: name,
+  region: region,
+  gameHash: gameHash,
+);
+
+/// Host-only: let spectators watch `frames` behind live (e.g. 180 for three
+/// seconds), 0 for live.
+Future<void> netplaySetSpectatorDelay({required int frames}) => RustLib
+    .instance
+    .api
+    .crateApiNetplayNetplaySetSpectatorDelay(frames: frames);
+
+/// Set the identity token sent on future connections, e.g. one persisted
+/// from an earlier `NetplayGameEvent::Identity`. `None` connects anonymously
+/// and gets a new identity from servers that keep them.
+Future<void> netplaySetUserToken({List<int>? token}) =>
+    RustLib.instance.api.crateApiNetplayNetplaySetUserToken(token: token);
+
 /// Send reset to other players.
 Future<void> netplaySendReset({required int kind}) =>
     RustLib.instance.api.crateApiNetplayNetplaySendReset(kind: kind);
@@ -271,6 +343,80 @@ sealed class NetplayGameEvent with _$NetplayGameEvent {
     required int relayRoomCode,
     required String reason,
   }) = NetplayGameEvent_FallbackToRelay;
+
+  /// State hashes diverged from another room member at `frame`.
+  const factory NetplayGameEvent.desync({required int frame}) =
+      NetplayGameEvent_Desync;
+
+  /// Chat message from a room member; exactly one of `text`/`emote` is set.
+  const factory NetplayGameEvent.chat({
+    required int clientId,
+    required String name,
+    String? text,
+    int? emote,
+  }) = NetplayGameEvent_Chat;
+
+  /// Link quality to the server (peer id 0) and each room member.
+  const factory NetplayGameEvent.networkStats({
+    required List<NetplayPeerStats> peers,
+  }) = NetplayGameEvent_NetworkStats;
+
+  /// Recommended lockstep input delay changed (already applied).
+  const factory NetplayGameEvent.inputDelayRecommended({required int frames}) =
+      NetplayGameEvent_InputDelayRecommended;
+
+  /// Public rooms, in answer to `netplay_list_rooms`.
+  const factory NetplayGameEvent.roomList({
+    required List<NetplayRoomSummary> rooms,
+  }) = NetplayGameEvent_RoomList;
+
+  /// Spectators watching the room and their delay behind live, in frames.
+  const factory NetplayGameEvent.spectatorInfo({
+    required int count,
+    required int delayFrames,
+  }) = NetplayGameEvent_SpectatorInfo;
+
+  /// Persistent identity issued by the server; store `user_token` and hand
+  /// it back through `netplay_set_user_token` before connecting again.
+  const factory NetplayGameEvent.identity({
+    required BigInt userId,
+    required Uint8List userToken,
+  }) = NetplayGameEvent_Identity;
+
+  /// The server is shutting down and drops the connection after `grace_ms`.
+  const factory NetplayGameEvent.serverClosing({required int graceMs}) =
+      NetplayGameEvent_ServerClosing;
+}
+
+class NetplayPeerStats {
+  final int peerClientId;
+  final int rttMs;
+  final int jitterMs;
+  final int lossPercent;
+
+  const NetplayPeerStats({
+    required this.peerClientId,
+    required this.rttMs,
+    required this.jitterMs,
+    required this.lossPercent,
+  });
+
+  @override
+  int get hashCode =>
+      peerClientId.hashCode ^
+      rttMs.hashCode ^
+      jitterMs.hashCode ^
+      lossPercent.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NetplayPeerStats &&
+          runtimeType == other.runtimeType &&
+          peerClientId == other.peerClientId &&
+          rttMs == other.rttMs &&
+          jitterMs == other.jitterMs &&
+          lossPercent == other.lossPercent;
 }
 
 class NetplayPlayer {
@@ -297,6 +443,55 @@ class NetplayPlayer {
           playerIndex == other.playerIndex;
 }
 
+class NetplayRoomSummary {
+  final int roomCode;
+  final String name;
+  final String region;
+
+  /// Cartridge CRC32, 0 if the host hasn't picked a game.
+  final int gameHash;
+  final int playerCount;
+  final int spectatorCount;
+  final int openSlots;
+  final bool started;
+
+  const NetplayRoomSummary({
+    required this.roomCode,
+    required this.name,
+    required this.region,
+    required this.gameHash,
+    required this.playerCount,
+    required this.spectatorCount,
+    required this.openSlots,
+    required this.started,
+  });
+
+  @override
+  int get hashCode =>
+      roomCode.hashCode ^
+      name.hashCode ^
+      region.hashCode ^
+      gameHash.hashCode ^
+      playerCount.hashCode ^
+      spectatorCount.hashCode ^
+      openSlots.hashCode ^
+      started.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NetplayRoomSummary &&
+          runtimeType == other.runtimeType &&
+          roomCode == other.roomCode &&
+          name == other.name &&
+          region == other.region &&
+          gameHash == other.gameHash &&
+          playerCount == other.playerCount &&
+          spectatorCount == other.spectatorCount &&
+          openSlots == other.openSlots &&
+          started == other.started;
+}
+
 /// Netplay connection state.
 enum NetplayState { disconnected, connecting, connected, inRoom }
 
@@ -353,7 +548,7 @@ class NetplayStatus {
 }
 
 /// Actual transport used by the current netplay session.
-enum NetplayTransport { unknown, tcp, quic }
+enum NetplayTransport { unknown, tcp, quic, webRtc }
 
 enum P2PConnectMode { direct, relay }
 
@@ -55,7 +55,7 @@ extension NetplayGameEventPatterns on NetplayGameEvent {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( NetplayGameEvent_LoadRom value)?  loadRom,TResult Function( NetplayGameEvent_StartGame value)?  startGame,TResult Function( NetplayGameEvent_PauseSync value)?  pauseSync,TResult Function( NetplayGameEvent_ResetSync value)?  resetSync,TResult Function( NetplayGameEvent_SyncState value)?  syncState,TResult Function( NetplayGameEvent_PlayerLeft value)?  playerLeft,TResult Function( NetplayGameEvent_Error value)?  error,TResult Function( NetplayGameEvent_FallbackToRelay value)?  fallbackToRelay,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( NetplayGameEvent_LoadRom value)?  loadRom,TResult Function( NetplayGameEvent_StartGame value)?  startGame,TResult Function( NetplayGameEvent_PauseSync value)?  pauseSync,TResult Function( NetplayGameEvent_ResetSync value)?  resetSync,TResult Function( NetplayGameEvent_SyncState value)?  syncState,TResult Function( NetplayGameEvent_PlayerLeft value)?  playerLeft,TResult Function( NetplayGameEvent_Error value)?  error,TResult Function( NetplayGameEvent_FallbackToRelay value)?  fallbackToRelay,TResult Function( NetplayGameEvent_Desync value)?  desync,TResult Function( NetplayGameEvent_Chat value)?  chat,TResult Function( NetplayGameEvent_NetworkStats value)?  networkStats,TResult Function( NetplayGameEvent_InputDelayRecommended value)?  inputDelayRecommended,TResult Function( NetplayGameEvent_RoomList value)?  roomList,TResult Function( NetplayGameEvent_SpectatorInfo value)?  spectatorInfo,TResult Function( NetplayGameEvent_Identity value)?  identity,TResult Function( NetplayGameEvent_ServerClosing value)?  serverClosing,required TResult orElse(),}){
 final _that = this;
 switch (_that) {
 case NetplayGameEvent_LoadRom() when loadRom != null:
@@ -66,7 +66,15 @@ return resetSync(_that);case NetplayGameEvent_SyncState() when syncState != null
 return syncState(_that);case NetplayGameEvent_PlayerLeft() when playerLeft != null:
 return playerLeft(_that);case NetplayGameEvent_Error() when error != null:
 return error(_that);case NetplayGameEvent_FallbackToRelay() when fallbackToRelay != null:
-return fallbackToRelay(_that);case _:
+return fallbackToRelay(_that);case NetplayGameEvent_Desync() when desync != null:
+return desync(_that);case NetplayGameEvent_Chat() when chat != null:
+return chat(_that);case NetplayGameEvent_NetworkStats() when networkStats != null:
+return networkStats(_that);case NetplayGameEvent_InputDelayRecommended() when inputDelayRecommended != null:
+return inputDelayRecommended(_that);case NetplayGameEvent_RoomList() when roomList != null:
+return roomList(_that);case NetplayGameEvent_SpectatorInfo() when spectatorInfo != null:
+return spectatorInfo(_that);case NetplayGameEvent_Identity() when identity != null:
+return identity(_that);case NetplayGameEvent_ServerClosing() when serverClosing != null:
+return serverClosing(_that);case _:
   return orElse();
 
 }
@@ -84,7 +92,7 @@ return fallbackToRelay(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( NetplayGameEvent_LoadRom value)  loadRom,required TResult Function( NetplayGameEvent_StartGame value)  startGame,required TResult Function( NetplayGameEvent_PauseSync value)  pauseSync,required TResult Function( NetplayGameEvent_ResetSync value)  resetSync,required TResult Function( NetplayGameEvent_SyncState value)  syncState,required TResult Function( NetplayGameEvent_PlayerLeft value)  playerLeft,required TResult Function( NetplayGameEvent_Error value)  error,required TResult Function( NetplayGameEvent_FallbackToRelay value)  fallbackToRelay,}){
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( NetplayGameEvent_LoadRom value)  loadRom,required TResult Function( NetplayGameEvent_StartGame value)  startGame,required TResult Function( NetplayGameEvent_PauseSync value)  pauseSync,required TResult Function( NetplayGameEvent_ResetSync value)  resetSync,required TResult Function( NetplayGameEvent_SyncState value)  syncState,required TResult Function( NetplayGameEvent_PlayerLeft value)  playerLeft,required TResult Function( NetplayGameEvent_Error value)  error,required TResult Function( NetplayGameEvent_FallbackToRelay value)  fallbackToRelay,required TResult Function( NetplayGameEvent_Desync value)  desync,required TResult Function( NetplayGameEvent_Chat value)  chat,required TResult Function( NetplayGameEvent_NetworkStats value)  networkStats,required TResult Function( NetplayGameEvent_InputDelayRecommended value)  inputDelayRecommended,required TResult Function( NetplayGameEvent_RoomList value)  roomList,required TResult Function( NetplayGameEvent_SpectatorInfo value)  spectatorInfo,required TResult Function( NetplayGameEvent_Identity value)  identity,required TResult Function( NetplayGameEvent_ServerClosing value)  serverClosing,}){
 final _that = this;
 switch (_that) {
 case NetplayGameEvent_LoadRom():
@@ -95,7 +103,15 @@ return resetSync(_that);case NetplayGameEvent_SyncState():
 return syncState(_that);case NetplayGameEvent_PlayerLeft():
 return playerLeft(_that);case NetplayGameEvent_Error():
 return error(_that);case NetplayGameEvent_FallbackToRelay():
-return fallbackToRelay(_that);}
+return fallbackToRelay(_that);case NetplayGameEvent_Desync():
+return desync(_that);case NetplayGameEvent_Chat():
+return chat(_that);case NetplayGameEvent_NetworkStats():
+return networkStats(_that);case NetplayGameEvent_InputDelayRecommended():
+return inputDelayRecommended(_that);case NetplayGameEvent_RoomList():
+return roomList(_that);case NetplayGameEvent_SpectatorInfo():
+return spectatorInfo(_that);case NetplayGameEvent_Identity():
+return identity(_that);case NetplayGameEvent_ServerClosing():
+return serverClosing(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -109,7 +125,7 @@ return fallbackToRelay(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( NetplayGameEvent_LoadRom value)?  loadRom,TResult? Function( NetplayGameEvent_StartGame value)?  startGame,TResult? Function( NetplayGameEvent_PauseSync value)?  pauseSync,TResult? Function( NetplayGameEvent_ResetSync value)?  resetSync,TResult? Function( NetplayGameEvent_SyncState value)?  syncState,TResult? Function( NetplayGameEvent_PlayerLeft value)?  playerLeft,TResult? Function( NetplayGameEvent_Error value)?  error,TResult? Function( NetplayGameEvent_FallbackToRelay value)?  fallbackToRelay,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( NetplayGameEvent_LoadRom value)?  loadRom,TResult? Function( NetplayGameEvent_StartGame value)?  startGame,TResult? Function( NetplayGameEvent_PauseSync value)?  pauseSync,TResult? Function( NetplayGameEvent_ResetSync value)?  resetSync,TResult? Function( NetplayGameEvent_SyncState value)?  syncState,TResult? Function( NetplayGameEvent_PlayerLeft value)?  playerLeft,TResult? Function( NetplayGameEvent_Error value)?  error,TResult? Function( NetplayGameEvent_FallbackToRelay value)?  fallbackToRelay,TResult? Function( NetplayGameEvent_Desync value)?  desync,TResult? Function( NetplayGameEvent_Chat value)?  chat,TResult? Function( NetplayGameEvent_NetworkStats value)?  networkStats,TResult? Function( NetplayGameEvent_InputDelayRecommended value)?  inputDelayRecommended,TResult? Function( NetplayGameEvent_RoomList value)?  roomList,TResult? Function( NetplayGameEvent_SpectatorInfo value)?  spectatorInfo,TResult? Function( NetplayGameEvent_Identity value)?  identity,TResult? Function( NetplayGameEvent_ServerClosing value)?  serverClosing,}){
 final _that = this;
 switch (_that) {
 case NetplayGameEvent_LoadRom() when loadRom != null:
@@ -120,7 +136,15 @@ return resetSync(_that);case NetplayGameEvent_SyncState() when syncState != null
 return syncState(_that);case NetplayGameEvent_PlayerLeft() when playerLeft != null:
 return playerLeft(_that);case NetplayGameEvent_Error() when error != null:
 return error(_that);case NetplayGameEvent_FallbackToRelay() when fallbackToRelay != null:
-return fallbackToRelay(_that);case _:
+return fallbackToRelay(_that);case NetplayGameEvent_Desync() when desync != null:
+return desync(_that);case NetplayGameEvent_Chat() when chat != null:
+return chat(_that);case NetplayGameEvent_NetworkStats() when networkStats != null:
+return networkStats(_that);case NetplayGameEvent_InputDelayRecommended() when inputDelayRecommended != null:
+return inputDelayRecommended(_that);case NetplayGameEvent_RoomList() when roomList != null:
+return roomList(_that);case NetplayGameEvent_SpectatorInfo() when spectatorInfo != null:
+return spectatorInfo(_that);case NetplayGameEvent_Identity() when identity != null:
+return identity(_that);case NetplayGameEvent_ServerClosing() when serverClosing != null:
+return serverClosing(_that);case _:
   return null;
 
 }
@@ -137,7 +161,7 @@ return fallbackToRelay(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( Uint8List data)?  loadRom,TResult Function()?  startGame,TResult Function( bool paused)?  pauseSync,TResult Function( int kind)?  resetSync,TResult Function( int frame,  Uint8List data)?  syncState,TResult Function( int playerIndex)?  playerLeft,TResult Function( int errorCode)?  error,TResult Function( String relayAddr,  int relayRoomCode,  String reason)?  fallbackToRelay,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( Uint8List data)?  loadRom,TResult Function()?  startGame,TResult Function( bool paused)?  pauseSync,TResult Function( int kind)?  resetSync,TResult Function( int frame,  Uint8List data)?  syncState,TResult Function( int playerIndex)?  playerLeft,TResult Function( int errorCode)?  error,TResult Function( String relayAddr,  int relayRoomCode,  String reason)?  fallbackToRelay,TResult Function( int frame)?  desync,TResult Function( int clientId,  String name,  String? text,  int? emote)?  chat,TResult Function( List<NetplayPeerStats> peers)?  networkStats,TResult Function( int frames)?  inputDelayRecommended,TResult Function( List<NetplayRoomSummary> rooms)?  roomList,TResult Function( int count,  int delayFrames)?  spectatorInfo,TResult Function( BigInt userId,  Uint8List userToken)?  identity,TResult Function( int graceMs)?  serverClosing,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case NetplayGameEvent_LoadRom() when loadRom != null:
 return loadRom(_that.data);case NetplayGameEvent_StartGame() when startGame != null:
@@ -147,7 +171,15 @@ return resetSync(_that.kind);case NetplayGameEvent_SyncState() when syncState !=
 return syncState(_that.frame,_that.data);case NetplayGameEvent_PlayerLeft() when playerLeft != null:
 return playerLeft(_that.playerIndex);case NetplayGameEvent_Error() when error != null:
 return error(_that.errorCode);case NetplayGameEvent_FallbackToRelay() when fallbackToRelay != null:
-return fallbackToRelay(_that.relayAddr,_that.relayRoomCode,_that.reason);case _:
+return fallbackToRelay(_that.relayAddr,_that.relayRoomCode,_that.reason);case NetplayGameEvent_Desync() when desync != null:
+return desync(_that.frame);case NetplayGameEvent_Chat() when chat != null:
+return chat(_that.clientId,_that.name,_that.text,_that.emote);case NetplayGameEvent_NetworkStats() when networkStats != null:
+return networkStats(_that.peers);case NetplayGameEvent_InputDelayRecommended() when inputDelayRecommended != null:
+return inputDelayRecommended(_that.frames);case NetplayGameEvent_RoomList() when roomList != null:
+return roomList(_that.rooms);case NetplayGameEvent_SpectatorInfo() when spectatorInfo != null:
+return spectatorInfo(_that.count,_that.delayFrames);case NetplayGameEvent_Identity() when identity != null:
+return identity(_that.userId,_that.userToken);case NetplayGameEvent_ServerClosing() when serverClosing != null:
+return serverClosing(_that.graceMs);case _:
   return orElse();
 
 }
@@ -165,7 +197,7 @@ return fallbackToRelay(_that.relayAddr,_that.relayRoomCode,_that.reason);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( Uint8List data)  loadRom,required TResult Function()  startGame,required TResult Function( bool paused)  pauseSync,required TResult Function( int kind)  resetSync,required TResult Function( int frame,  Uint8List data)  syncState,required TResult Function( int playerIndex)  playerLeft,required TResult Function( int errorCode)  error,required TResult Function( String relayAddr,  int relayRoomCode,  String reason)  fallbackToRelay,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( Uint8List data)  loadRom,required TResult Function()  startGame,required TResult Function( bool paused)  pauseSync,required TResult Function( int kind)  resetSync,required TResult Function( int frame,  Uint8List data)  syncState,required TResult Function( int playerIndex)  playerLeft,required TResult Function( int errorCode)  error,required TResult Function( String relayAddr,  int relayRoomCode,  String reason)  fallbackToRelay,required TResult Function( int frame)  desync,required TResult Function( int clientId,  String name,  String? text,  int? emote)  chat,required TResult Function( List<NetplayPeerStats> peers)  networkStats,required TResult Function( int frames)  inputDelayRecommended,required TResult Function( List<NetplayRoomSummary> rooms)  roomList,required TResult Function( int count,  int delayFrames)  spectatorInfo,required TResult Function( BigInt userId,  Uint8List userToken)  identity,required TResult Function( int graceMs)  serverClosing,}) {final _that = this;
 switch (_that) {
 case NetplayGameEvent_LoadRom():
 return loadRom(_that.data);case NetplayGameEvent_StartGame():
@@ -175,7 +207,15 @@ return resetSync(_that.kind);case NetplayGameEvent_SyncState():
 return syncState(_that.frame,_that.data);case NetplayGameEvent_PlayerLeft():
 return playerLeft(_that.playerIndex);case NetplayGameEvent_Error():
 return error(_that.errorCode);case NetplayGameEvent_FallbackToRelay():
-return fallbackToRelay(_that.relayAddr,_that.relayRoomCode,_that.reason);}
+return fallbackToRelay(_that.relayAddr,_that.relayRoomCode,_that.reason);case NetplayGameEvent_Desync():
+return desync(_that.frame);case NetplayGameEvent_Chat():
+return chat(_that.clientId,_that.name,_that.text,_that.emote);case NetplayGameEvent_NetworkStats():
+return networkStats(_that.peers);case NetplayGameEvent_InputDelayRecommended():
+return inputDelayRecommended(_that.frames);case NetplayGameEvent_RoomList():
+return roomList(_that.rooms);case NetplayGameEvent_SpectatorInfo():
+return spectatorInfo(_that.count,_that.delayFrames);case NetplayGameEvent_Identity():
+return identity(_that.userId,_that.userToken);case NetplayGameEvent_ServerClosing():
+return serverClosing(_that.graceMs);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -189,7 +229,7 @@ return fallbackToRelay(_that.relayAddr,_that.relayRoomCode,_that.reason);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( Uint8List data)?  loadRom,TResult? Function()?  startGame,TResult? Function( bool paused)?  pauseSync,TResult? Function( int kind)?  resetSync,TResult? Function( int frame,  Uint8List data)?  syncState,TResult? Function( int playerIndex)?  playerLeft,TResult? Function( int errorCode)?  error,TResult? Function( String relayAddr,  int relayRoomCode,  String reason)?  fallbackToRelay,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( Uint8List data)?  loadRom,TResult? Function()?  startGame,TResult? Function( bool paused)?  pauseSync,TResult? Function( int kind)?  resetSync,TResult? Function( int frame,  Uint8List data)?  syncState,TResult? Function( int playerIndex)?  playerLeft,TResult? Function( int errorCode)?  error,TResult? Function( String relayAddr,  int relayRoomCode,  String reason)?  fallbackToRelay,TResult? Function( int frame)?  desync,TResult? Function( int clientId,  String name,  String? text,  int? emote)?  chat,TResult? Function( List<NetplayPeerStats> peers)?  networkStats,TResult? Function( int frames)?  inputDelayRecommended,TResult? Function( List<NetplayRoomSummary> rooms)?  roomList,TResult? Function( int count,  int delayFrames)?  spectatorInfo,TResult? Function( BigInt userId,  Uint8List userToken)?  identity,TResult? Function( int graceMs)?  serverClosing,}) {final _that = this;
 switch (_that) {
 case NetplayGameEvent_LoadRom() when loadRom != null:
 return loadRom(_that.data);case NetplayGameEvent_StartGame() when startGame != null:
@@ -199,7 +239,15 @@ return resetSync(_that.kind);case NetplayGameEvent_SyncState() when syncState !=
 return syncState(_that.frame,_that.data);case NetplayGameEvent_PlayerLeft() when playerLeft != null:
 return playerLeft(_that.playerIndex);case NetplayGameEvent_Error() when error != null:
 return error(_that.errorCode);case NetplayGameEvent_FallbackToRelay() when fallbackToRelay != null:
-return fallbackToRelay(_that.relayAddr,_that.relayRoomCode,_that.reason);case _:
+return fallbackToRelay(_that.relayAddr,_that.relayRoomCode,_that.reason);case NetplayGameEvent_Desync() when desync != null:
+return desync(_that.frame);case NetplayGameEvent_Chat() when chat != null:
+return chat(_that.clientId,_that.name,_that.text,_that.emote);case NetplayGameEvent_NetworkStats() when networkStats != null:
+return networkStats(_that.peers);case NetplayGameEvent_InputDelayRecommended() when inputDelayRecommended != null:
+return inputDelayRecommended(_that.frames);case NetplayGameEvent_RoomList() when roomList != null:
+return roomList(_that.rooms);case NetplayGameEvent_SpectatorInfo() when spectatorInfo != null:
+return spectatorInfo(_that.count,_that.delayFrames);case NetplayGameEvent_Identity() when identity != null:
+return identity(_that.userId,_that.userToken);case NetplayGameEvent_ServerClosing() when serverClosing != null:
+return serverClosing(_that.graceMs);case _:
   return null;
 
 }
@@ -705,6 +753,554 @@ as String,
 }
 
 
+}
+
+/// @nodoc
+
+
+class NetplayGameEvent_Desync extends NetplayGameEvent {
+  const NetplayGameEvent_Desync({required this.frame}): super._();
+  
+
+ final  int frame;
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NetplayGameEvent_DesyncCopyWith<NetplayGameEvent_Desync> get copyWith => _$NetplayGameEvent_DesyncCopyWithImpl<NetplayGameEvent_Desync>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NetplayGameEvent_Desync&&(identical(other.frame, frame) || other.frame == frame));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,frame);
+
+@override
+String toString() {
+  return 'NetplayGameEvent.desync(frame: $frame)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NetplayGameEvent_DesyncCopyWith<$Res> implements $NetplayGameEventCopyWith<$Res> {
+  factory $NetplayGameEvent_DesyncCopyWith(NetplayGameEvent_Desync value, $Res Function(NetplayGameEvent_Desync) _then) = _$NetplayGameEvent_DesyncCopyWithImpl;
+@useResult
+$Res call({
+ int frame
+});
+
+
+
+
+}
+/// @nodoc
+class _$NetplayGameEvent_DesyncCopyWithImpl<$Res>
+    implements $NetplayGameEvent_DesyncCopyWith<$Res> {
+  _$NetplayGameEvent_DesyncCopyWithImpl(this._self, this._then);
+
+  final NetplayGameEvent_Desync _self;
+  final $Res Function(NetplayGameEvent_Desync) _then;
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? frame = null,}) {
+  return _then(NetplayGameEvent_Desync(
+frame: null == frame ? _self.frame : frame // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NetplayGameEvent_Chat extends NetplayGameEvent {
+  const NetplayGameEvent_Chat({required this.clientId, required this.name, this.text, this.emote}): super._();
+  
+
+ final  int clientId;
+ final  String name;
+ final  String? text;
+ final  int? emote;
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NetplayGameEvent_ChatCopyWith<NetplayGameEvent_Chat> get copyWith => _$NetplayGameEvent_ChatCopyWithImpl<NetplayGameEvent_Chat>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NetplayGameEvent_Chat&&(identical(other.clientId, clientId) || other.clientId == clientId)&&(identical(other.name, name) || other.name == name)&&(identical(other.text, text) || other.text == text)&&(identical(other.emote, emote) || other.emote == emote));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,clientId,name,text,emote);
+
+@override
+String toString() {
+  return 'NetplayGameEvent.chat(clientId: $clientId, name: $name, text: $text, emote: $emote)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NetplayGameEvent_ChatCopyWith<$Res> implements $NetplayGameEventCopyWith<$Res> {
+  factory $NetplayGameEvent_ChatCopyWith(NetplayGameEvent_Chat value, $Res Function(NetplayGameEvent_Chat) _then) = _$NetplayGameEvent_ChatCopyWithImpl;
+@useResult
+$Res call({
+ int clientId, String name, String? text, int? emote
+});
+
+
+
+
+}
+/// @nodoc
+class _$NetplayGameEvent_ChatCopyWithImpl<$Res>
+    implements $NetplayGameEvent_ChatCopyWith<$Res> {
+  _$NetplayGameEvent_ChatCopyWithImpl(this._self, this._then);
+
+  final NetplayGameEvent_Chat _self;
+  final $Res Function(NetplayGameEvent_Chat) _then;
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? clientId = null,Object? name = null,Object? text = freezed,Object? emote = freezed,}) {
+  return _then(NetplayGameEvent_Chat(
+clientId: null == clientId ? _self.clientId : clientId // ignore: cast_nullable_to_non_nullable
+as int,name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,text: freezed == text ? _self.text : text // ignore: cast_nullable_to_non_nullable
+as String?,emote: freezed == emote ? _self.emote : emote // ignore: cast_nullable_to_non_nullable
+as int?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NetplayGameEvent_NetworkStats extends NetplayGameEvent {
+  const NetplayGameEvent_NetworkStats({required final  List<NetplayPeerStats> peers}): _peers = peers,super._();
+  
+
+ final  List<NetplayPeerStats> _peers;
+ List<NetplayPeerStats> get peers {
+  if (_peers is EqualUnmodifiableListView) return _peers;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_peers);
+}
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NetplayGameEvent_NetworkStatsCopyWith<NetplayGameEvent_NetworkStats> get copyWith => _$NetplayGameEvent_NetworkStatsCopyWithImpl<NetplayGameEvent_NetworkStats>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NetplayGameEvent_NetworkStats&&const DeepCollectionEquality().equals(other._peers, _peers));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_peers));
+
+@override
+String toString() {
+  return 'NetplayGameEvent.networkStats(peers: $peers)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NetplayGameEvent_NetworkStatsCopyWith<$Res> implements $NetplayGameEventCopyWith<$Res> {
+  factory $NetplayGameEvent_NetworkStatsCopyWith(NetplayGameEvent_NetworkStats value, $Res Function(NetplayGameEvent_NetworkStats) _then) = _$NetplayGameEvent_NetworkStatsCopyWithImpl;
+@useResult
+$Res call({
+ List<NetplayPeerStats> peers
+});
+
+
+
+
+}
+/// @nodoc
+class _$NetplayGameEvent_NetworkStatsCopyWithImpl<$Res>
+    implements $NetplayGameEvent_NetworkStatsCopyWith<$Res> {
+  _$NetplayGameEvent_NetworkStatsCopyWithImpl(this._self, this._then);
+
+  final NetplayGameEvent_NetworkStats _self;
+  final $Res Function(NetplayGameEvent_NetworkStats) _then;
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? peers = null,}) {
+  return _then(NetplayGameEvent_NetworkStats(
+peers: null == peers ? _self._peers : peers // ignore: cast_nullable_to_non_nullable
+as List<NetplayPeerStats>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NetplayGameEvent_InputDelayRecommended extends NetplayGameEvent {
+  const NetplayGameEvent_InputDelayRecommended({required this.frames}): super._();
+  
+
+ final  int frames;
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NetplayGameEvent_InputDelayRecommendedCopyWith<NetplayGameEvent_InputDelayRecommended> get copyWith => _$NetplayGameEvent_InputDelayRecommendedCopyWithImpl<NetplayGameEvent_InputDelayRecommended>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NetplayGameEvent_InputDelayRecommended&&(identical(other.frames, frames) || other.frames == frames));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,frames);
+
+@override
+String toString() {
+  return 'NetplayGameEvent.inputDelayRecommended(frames: $frames)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NetplayGameEvent_InputDelayRecommendedCopyWith<$Res> implements $NetplayGameEventCopyWith<$Res> {
+  factory $NetplayGameEvent_InputDelayRecommendedCopyWith(NetplayGameEvent_InputDelayRecommended value, $Res Function(NetplayGameEvent_InputDelayRecommended) _then) = _$NetplayGameEvent_InputDelayRecommendedCopyWithImpl;
+@useResult
+$Res call({
+ int frames
+});
+
+
+
+
+}
+/// @nodoc
+class _$NetplayGameEvent_InputDelayRecommendedCopyWithImpl<$Res>
+    implements $NetplayGameEvent_InputDelayRecommendedCopyWith<$Res> {
+  _$NetplayGameEvent_InputDelayRecommendedCopyWithImpl(this._self, this._then);
+
+  final NetplayGameEvent_InputDelayRecommended _self;
+  final $Res Function(NetplayGameEvent_InputDelayRecommended) _then;
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? frames = null,}) {
+  return _then(NetplayGameEvent_InputDelayRecommended(
+frames: null == frames ? _self.frames : frames // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NetplayGameEvent_RoomList extends NetplayGameEvent {
+  const NetplayGameEvent_RoomList({required final  List<NetplayRoomSummary> rooms}): _rooms = rooms,super._();
+  
+
+ final  List<NetplayRoomSummary> _rooms;
+ List<NetplayRoomSummary> get rooms {
+  if (_rooms is EqualUnmodifiableListView) return _rooms;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_rooms);
+}
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NetplayGameEvent_RoomListCopyWith<NetplayGameEvent_RoomList> get copyWith => _$NetplayGameEvent_RoomListCopyWithImpl<NetplayGameEvent_RoomList>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NetplayGameEvent_RoomList&&const DeepCollectionEquality().equals(other._rooms, _rooms));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_rooms));
+
+@override
+String toString() {
+  return 'NetplayGameEvent.roomList(rooms: $rooms)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NetplayGameEvent_RoomListCopyWith<$Res> implements $NetplayGameEventCopyWith<$Res> {
+  factory $NetplayGameEvent_RoomListCopyWith(NetplayGameEvent_RoomList value, $Res Function(NetplayGameEvent_RoomList) _then) = _$NetplayGameEvent_RoomListCopyWithImpl;
+@useResult
+$Res call({
+ List<NetplayRoomSummary> rooms
+});
+
+
+
+
+}
+/// @nodoc
+class _$NetplayGameEvent_RoomListCopyWithImpl<$Res>
+    implements $NetplayGameEvent_RoomListCopyWith<$Res> {
+  _$NetplayGameEvent_RoomListCopyWithImpl(this._self, this._then);
+
+  final NetplayGameEvent_RoomList _self;
+  final $Res Function(NetplayGameEvent_RoomList) _then;
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? rooms = null,}) {
+  return _then(NetplayGameEvent_RoomList(
+rooms: null == rooms ? _self._rooms : rooms // ignore: cast_nullable_to_non_nullable
+as List<NetplayRoomSummary>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NetplayGameEvent_SpectatorInfo extends NetplayGameEvent {
+  const NetplayGameEvent_SpectatorInfo({required this.count, required this.delayFrames}): super._();
+  
+
+ final  int count;
+ final  int delayFrames;
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NetplayGameEvent_SpectatorInfoCopyWith<NetplayGameEvent_SpectatorInfo> get copyWith => _$NetplayGameEvent_SpectatorInfoCopyWithImpl<NetplayGameEvent_SpectatorInfo>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NetplayGameEvent_SpectatorInfo&&(identical(other.count, count) || other.count == count)&&(identical(other.delayFrames, delayFrames) || other.delayFrames == delayFrames));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,count,delayFrames);
+
+@override
+String toString() {
+  return 'NetplayGameEvent.spectatorInfo(count: $count, delayFrames: $delayFrames)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NetplayGameEvent_SpectatorInfoCopyWith<$Res> implements $NetplayGameEventCopyWith<$Res> {
+  factory $NetplayGameEvent_SpectatorInfoCopyWith(NetplayGameEvent_SpectatorInfo value, $Res Function(NetplayGameEvent_SpectatorInfo) _then) = _$NetplayGameEvent_SpectatorInfoCopyWithImpl;
+@useResult
+$Res call({
+ int count, int delayFrames
+});
+
+
+
+
+}
+/// @nodoc
+class _$NetplayGameEvent_SpectatorInfoCopyWithImpl<$Res>
+    implements $NetplayGameEvent_SpectatorInfoCopyWith<$Res> {
+  _$NetplayGameEvent_SpectatorInfoCopyWithImpl(this._self, this._then);
+
+  final NetplayGameEvent_SpectatorInfo _self;
+  final $Res Function(NetplayGameEvent_SpectatorInfo) _then;
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? count = null,Object? delayFrames = null,}) {
+  return _then(NetplayGameEvent_SpectatorInfo(
+count: null == count ? _self.count : count // ignore: cast_nullable_to_non_nullable
+as int,delayFrames: null == delayFrames ? _self.delayFrames : delayFrames // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NetplayGameEvent_Identity extends NetplayGameEvent {
+  const NetplayGameEvent_Identity({required this.userId, required this.userToken}): super._();
+  
+
+ final  BigInt userId;
+ final  Uint8List userToken;
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NetplayGameEvent_IdentityCopyWith<NetplayGameEvent_Identity> get copyWith => _$NetplayGameEvent_IdentityCopyWithImpl<NetplayGameEvent_Identity>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NetplayGameEvent_Identity&&(identical(other.userId, userId) || other.userId == userId)&&const DeepCollectionEquality().equals(other.userToken, userToken));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,userId,const DeepCollectionEquality().hash(userToken));
+
+@override
+String toString() {
+  return 'NetplayGameEvent.identity(userId: $userId, userToken: $userToken)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NetplayGameEvent_IdentityCopyWith<$Res> implements $NetplayGameEventCopyWith<$Res> {
+  factory $NetplayGameEvent_IdentityCopyWith(NetplayGameEvent_Identity value, $Res Function(NetplayGameEvent_Identity) _then) = _$NetplayGameEvent_IdentityCopyWithImpl;
+@useResult
+$Res call({
+ BigInt userId, Uint8List userToken
+});
+
+
+
+
+}
+/// @nodoc
+class _$NetplayGameEvent_IdentityCopyWithImpl<$Res>
+    implements $NetplayGameEvent_IdentityCopyWith<$Res> {
+  _$NetplayGameEvent_IdentityCopyWithImpl(this._self, this._then);
+
+  final NetplayGameEvent_Identity _self;
+  final $Res Function(NetplayGameEvent_Identity) _then;
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? userId = null,Object? userToken = null,}) {
+  return _then(NetplayGameEvent_Identity(
+userId: null == userId ? _self.userId : userId // ignore: cast_nullable_to_non_nullable
+as BigInt,userToken: null == userToken ? _self.userToken : userToken // ignore: cast_nullable_to_non_nullable
+as Uint8List,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NetplayGameEvent_ServerClosing extends NetplayGameEvent {
+  const NetplayGameEvent_ServerClosing({required this.graceMs}): super._();
+  
+
+ final  int graceMs;
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NetplayGameEvent_ServerClosingCopyWith<NetplayGameEvent_ServerClosing> get copyWith => _$NetplayGameEvent_ServerClosingCopyWithImpl<NetplayGameEvent_ServerClosing>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NetplayGameEvent_ServerClosing&&(identical(other.graceMs, graceMs) || other.graceMs == graceMs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,graceMs);
+
+@override
+String toString() {
+  return 'NetplayGameEvent.serverClosing(graceMs: $graceMs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NetplayGameEvent_ServerClosingCopyWith<$Res> implements $NetplayGameEventCopyWith<$Res> {
+  factory $NetplayGameEvent_ServerClosingCopyWith(NetplayGameEvent_ServerClosing value, $Res Function(NetplayGameEvent_ServerClosing) _then) = _$NetplayGameEvent_ServerClosingCopyWithImpl;
+@useResult
+$Res call({
+ int graceMs
+});
+
+
+
+
+}
+/// @nodoc
+class _$NetplayGameEvent_ServerClosingCopyWithImpl<$Res>
+    implements $NetplayGameEvent_ServerClosingCopyWith<$Res> {
+  _$NetplayGameEvent_ServerClosingCopyWithImpl(this._self, this._then);
+
+  final NetplayGameEvent_ServerClosing _self;
+  final $Res Function(NetplayGameEvent_ServerClosing) _then;
+
+/// Create a copy of NetplayGameEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? graceMs = null,}) {
+  return _then(NetplayGameEvent_ServerClosing(
+graceMs: null == graceMs ? _self.graceMs : graceMs // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
 }
 
 // dart format on
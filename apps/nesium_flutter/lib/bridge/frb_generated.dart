@@ -3,10 +3,12 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/audio.dart';
 import 'api/emulation.dart';
 import 'api/events.dart';
 import 'api/gamepad.dart';
 import 'api/input.dart';
+import 'api/instance.dart';
 import 'api/load_rom.dart';
 import 'api/net_utils.dart';
 import 'api/netplay.dart';
@@ -78,7 +80,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.12.0';
 
   @override
-  int get rustContentHash => 1438646929;
+  int get rustContentHash => -582103215;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -94,10 +96,14 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiGamepadBindGamepad({required BigInt id, int? port});
 
+  Future<void> crateApiInputClearZapperPointer();
+
   Stream<DebugStateNotification> crateApiEventsDebugStateStream();
 
   Stream<EmulationStatusNotification> crateApiEventsEmulationStatusStream();
 
+  Future<ExpansionBalance> crateApiAudioExpansionBalanceDefault();
+
   Future<GamepadActionsFfi> crateApiGamepadGamepadActionsFfiDefault();
 
   Future<GamepadPollResultFfi> crateApiGamepadGamepadPollResultFfiDefault();
@@ -126,12 +132,46 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiGamepadInitGamepad();
 
+  Future<void> crateApiInstanceInstanceLoadRom({
+    required int instance,
+    required String path,
+  });
+
+  Future<void> crateApiInstanceInstanceLoadRomFromBytes({
+    required int instance,
+    required List<int> bytes,
+  });
+
+  Future<void> crateApiInstanceInstanceLoadStateFromMemory({
+    required int instance,
+    required List<int> data,
+  });
+
+  Future<void> crateApiInstanceInstanceResetConsole({required int instance});
+
+  Future<Uint8List> crateApiInstanceInstanceSaveStateToMemory({
+    required int instance,
+  });
+
+  Future<void> crateApiInstanceInstanceSetPadMask({
+    required int instance,
+    required int pad,
+    required int mask,
+  });
+
+  Future<void> crateApiInstanceInstanceSetPaused({
+    required int instance,
+    required bool paused,
+  });
+
   Future<bool> crateApiPauseIsPaused();
 
   Future<LcdGridOptions> crateApiVideoLcdGridOptionsDefault();
 
   Future<List<GamepadInfoFfi>> crateApiGamepadListGamepads();
 
+  Future<void> crateApiEmulationLoadEmergencyState();
+
   Future<void> crateApiLoadRomLoadRom({required String path});
 
   Future<void> crateApiLoadRomLoadRomFromBytes({required List<int> bytes});
@@ -173,7 +213,20 @@ abstract class RustLibApi extends BaseApi {
     required String playerName,
   });
 
-  Future<void> crateApiNetplayNetplayCreateRoom();
+  Future<void> crateApiNetplayNetplayConnectTls({
+    required String serverAddr,
+    required String serverName,
+    required String playerName,
+  });
+
+  Future<void> crateApiNetplayNetplayConnectTlsPinned({
+    required String serverAddr,
+    required String serverName,
+    required String pinnedSha256Fingerprint,
+    required String playerName,
+  });
+
+  Future<void> crateApiNetplayNetplayCreateRoom({String? password});
 
   Future<void> crateApiNetplayNetplayDisconnect();
 
@@ -181,7 +234,12 @@ abstract class RustLibApi extends BaseApi {
 
   Future<bool> crateApiNetplayNetplayIsConnected();
 
-  Future<void> crateApiNetplayNetplayJoinRoom({required int roomCode});
+  Future<void> crateApiNetplayNetplayJoinRoom({
+    required int roomCode,
+    String? password,
+  });
+
+  Future<void> crateApiNetplayNetplayListRooms({int? gameHash, String? region});
 
   Future<P2PConnectMode> crateApiNetplayNetplayP2PConnectJoinAuto({
     required String signalingAddr,
@@ -233,6 +291,11 @@ abstract class RustLibApi extends BaseApi {
     required List<int> data,
   });
 
+  Future<void> crateApiNetplayNetplayQuickMatch({
+    int? gameHash,
+    String? region,
+  });
+
   Future<void> crateApiNetplayNetplayRequestFallbackRelay({
     required String relayAddr,
     required int relayRoomCode,
@@ -241,6 +304,10 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiNetplayNetplayRequestState();
 
+  Future<void> crateApiNetplayNetplaySendChat({required String text});
+
+  Future<void> crateApiNetplayNetplaySendEmote({required int emote});
+
   Future<void> crateApiNetplayNetplaySendPause({required bool paused});
 
   Future<void> crateApiNetplayNetplaySendReset({required int kind});
@@ -249,6 +316,16 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiNetplayNetplaySendRomLoaded();
 
+  Future<void> crateApiNetplayNetplaySetRoomInfo({
+    required String name,
+    required String region,
+    required int gameHash,
+  });
+
+  Future<void> crateApiNetplayNetplaySetSpectatorDelay({required int frames});
+
+  Future<void> crateApiNetplayNetplaySetUserToken({List<int>? token});
+
   Stream<NetplayStatus> crateApiNetplayNetplayStatusStream();
 
   Future<void> crateApiNetplayNetplaySwitchRole({required int role});
@@ -295,6 +372,13 @@ abstract class RustLibApi extends BaseApi {
 
   Future<ScanlineOptions> crateApiVideoScanlineOptionsDefault();
 
+  Future<void> crateApiAudioSetAudioStereo({
+    required StereoPreset preset,
+    required ExpansionBalance balance,
+  });
+
+  Future<void> crateApiEmulationSetClonePpu({required bool enabled});
+
   Future<void> crateApiEmulationSetFastForwardSpeed({
     required int speedPercent,
   });
@@ -427,12 +511,31 @@ abstract class RustLibApi extends BaseApi {
     required VideoFilter filter,
   });
 
+  Future<void> crateApiInputSetZapperCalibration({
+    required int offsetX,
+    required int offsetY,
+  });
+
+  Future<void> crateApiInputSetZapperCrosshair({required bool visible});
+
+  Future<void> crateApiInputSetZapperLagCompensation({required int frames});
+
+  Future<void> crateApiInputSetZapperPointer({required int x, required int y});
+
+  Future<void> crateApiInputSetZapperPort({int? port});
+
+  Future<void> crateApiInputSetZapperTrigger({required bool pulled});
+
   Future<void> crateApiGamepadShutdownGamepad();
 
   Stream<SpriteSnapshot> crateApiEventsSpriteStateStream();
 
+  Future<int> crateApiInstanceStartInstance({required bool audio});
+
   Future<void> crateApiLoadRomStartNesRuntime();
 
+  Future<void> crateApiInstanceStopInstance({required int instance});
+
   Future<void> crateApiEventsSubscribeTilemapTexture();
 
   Stream<TileSnapshot> crateApiEventsTileStateStream();
@@ -521,6 +624,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiGamepadBindGamepadConstMeta =>
       const TaskConstMeta(debugName: "bind_gamepad", argNames: ["id", "port"]);
 
+  @override
+  Future<void> crateApiInputClearZapperPointer() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 3,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInputClearZapperPointerConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInputClearZapperPointerConstMeta =>
+      const TaskConstMeta(debugName: "clear_zapper_pointer", argNames: []);
+
   @override
   Stream<DebugStateNotification> crateApiEventsDebugStateStream() {
     final sink = RustStreamSink<DebugStateNotification>();
@@ -536,7 +666,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 3,
+              funcId: 4,
               port: port_,
             );
           },
@@ -571,7 +701,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 4,
+              funcId: 5,
               port: port_,
             );
           },
@@ -594,6 +724,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["sink"],
       );
 
+  @override
+  Future<ExpansionBalance> crateApiAudioExpansionBalanceDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_expansion_balance,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAudioExpansionBalanceDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioExpansionBalanceDefaultConstMeta =>
+      const TaskConstMeta(debugName: "expansion_balance_default", argNames: []);
+
   @override
   Future<GamepadActionsFfi> crateApiGamepadGamepadActionsFfiDefault() {
     return handler.executeNormal(
@@ -603,7 +760,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 5,
+            funcId: 7,
             port: port_,
           );
         },
@@ -633,7 +790,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 6,
+            funcId: 8,
             port: port_,
           );
         },
@@ -666,7 +823,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 7,
+            funcId: 9,
             port: port_,
           );
         },
@@ -696,7 +853,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 10,
             port: port_,
           );
         },
@@ -726,7 +883,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 9,
+            funcId: 11,
             port: port_,
           );
         },
@@ -753,7 +910,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 12,
             port: port_,
           );
         },
@@ -780,7 +937,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 13,
             port: port_,
           );
         },
@@ -807,7 +964,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 12,
+            funcId: 14,
             port: port_,
           );
         },
@@ -832,7 +989,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -857,7 +1014,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 14,
+            funcId: 16,
             port: port_,
           );
         },
@@ -882,7 +1039,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(dataDir, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -907,7 +1064,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 16,
+            funcId: 18,
             port: port_,
           );
         },
@@ -925,6 +1082,247 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiGamepadInitGamepadConstMeta =>
       const TaskConstMeta(debugName: "init_gamepad", argNames: []);
 
+  @override
+  Future<void> crateApiInstanceInstanceLoadRom({
+    required int instance,
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(instance, serializer);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInstanceInstanceLoadRomConstMeta,
+        argValues: [instance, path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInstanceInstanceLoadRomConstMeta =>
+      const TaskConstMeta(
+        debugName: "instance_load_rom",
+        argNames: ["instance", "path"],
+      );
+
+  @override
+  Future<void> crateApiInstanceInstanceLoadRomFromBytes({
+    required int instance,
+    required List<int> bytes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(instance, serializer);
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInstanceInstanceLoadRomFromBytesConstMeta,
+        argValues: [instance, bytes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInstanceInstanceLoadRomFromBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "instance_load_rom_from_bytes",
+        argNames: ["instance", "bytes"],
+      );
+
+  @override
+  Future<void> crateApiInstanceInstanceLoadStateFromMemory({
+    required int instance,
+    required List<int> data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(instance, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInstanceInstanceLoadStateFromMemoryConstMeta,
+        argValues: [instance, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInstanceInstanceLoadStateFromMemoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "instance_load_state_from_memory",
+        argNames: ["instance", "data"],
+      );
+
+  @override
+  Future<void> crateApiInstanceInstanceResetConsole({required int instance}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(instance, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInstanceInstanceResetConsoleConstMeta,
+        argValues: [instance],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInstanceInstanceResetConsoleConstMeta =>
+      const TaskConstMeta(
+        debugName: "instance_reset_console",
+        argNames: ["instance"],
+      );
+
+  @override
+  Future<Uint8List> crateApiInstanceInstanceSaveStateToMemory({
+    required int instance,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(instance, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInstanceInstanceSaveStateToMemoryConstMeta,
+        argValues: [instance],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInstanceInstanceSaveStateToMemoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "instance_save_state_to_memory",
+        argNames: ["instance"],
+      );
+
+  @override
+  Future<void> crateApiInstanceInstanceSetPadMask({
+    required int instance,
+    required int pad,
+    required int mask,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(instance, serializer);
+          sse_encode_u_8(pad, serializer);
+          sse_encode_u_8(mask, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInstanceInstanceSetPadMaskConstMeta,
+        argValues: [instance, pad, mask],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInstanceInstanceSetPadMaskConstMeta =>
+      const TaskConstMeta(
+        debugName: "instance_set_pad_mask",
+        argNames: ["instance", "pad", "mask"],
+      );
+
+  @override
+  Future<void> crateApiInstanceInstanceSetPaused({
+    required int instance,
+    required bool paused,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(instance, serializer);
+          sse_encode_bool(paused, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInstanceInstanceSetPausedConstMeta,
+        argValues: [instance, paused],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInstanceInstanceSetPausedConstMeta =>
+      const TaskConstMeta(
+        debugName: "instance_set_paused",
+        argNames: ["instance", "paused"],
+      );
+
   @override
   Future<bool> crateApiPauseIsPaused() {
     return handler.executeNormal(
@@ -934,7 +1332,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 26,
             port: port_,
           );
         },
@@ -961,7 +1359,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 27,
             port: port_,
           );
         },
@@ -988,7 +1386,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 28,
             port: port_,
           );
         },
@@ -1006,6 +1404,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiGamepadListGamepadsConstMeta =>
       const TaskConstMeta(debugName: "list_gamepads", argNames: []);
 
+  @override
+  Future<void> crateApiEmulationLoadEmergencyState() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiEmulationLoadEmergencyStateConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEmulationLoadEmergencyStateConstMeta =>
+      const TaskConstMeta(debugName: "load_emergency_state", argNames: []);
+
   @override
   Future<void> crateApiLoadRomLoadRom({required String path}) {
     return handler.executeNormal(
@@ -1016,7 +1441,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 30,
             port: port_,
           );
         },
@@ -1044,7 +1469,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 31,
             port: port_,
           );
         },
@@ -1075,7 +1500,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 32,
             port: port_,
           );
         },
@@ -1103,7 +1528,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 33,
             port: port_,
           );
         },
@@ -1134,7 +1559,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 34,
             port: port_,
           );
         },
@@ -1166,7 +1591,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 35,
             port: port_,
           );
         },
@@ -1203,7 +1628,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 36,
             port: port_,
           );
         },
@@ -1242,7 +1667,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 27,
+            funcId: 37,
             port: port_,
           );
         },
@@ -1289,7 +1714,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 28,
+            funcId: 38,
             port: port_,
           );
         },
@@ -1328,7 +1753,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 29,
+            funcId: 39,
             port: port_,
           );
         },
@@ -1360,15 +1785,102 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<void> crateApiNetplayNetplayCreateRoom() {
+  Future<void> crateApiNetplayNetplayConnectTls({
+    required String serverAddr,
+    required String serverName,
+    required String playerName,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(serverAddr, serializer);
+          sse_encode_String(serverName, serializer);
+          sse_encode_String(playerName, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 30,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNetplayNetplayConnectTlsConstMeta,
+        argValues: [serverAddr, serverName, playerName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetplayNetplayConnectTlsConstMeta =>
+      const TaskConstMeta(
+        debugName: "netplay_connect_tls",
+        argNames: ["serverAddr", "serverName", "playerName"],
+      );
+
+  @override
+  Future<void> crateApiNetplayNetplayConnectTlsPinned({
+    required String serverAddr,
+    required String serverName,
+    required String pinnedSha256Fingerprint,
+    required String playerName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(serverAddr, serializer);
+          sse_encode_String(serverName, serializer);
+          sse_encode_String(pinnedSha256Fingerprint, serializer);
+          sse_encode_String(playerName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNetplayNetplayConnectTlsPinnedConstMeta,
+        argValues: [
+          serverAddr,
+          serverName,
+          pinnedSha256Fingerprint,
+          playerName,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetplayNetplayConnectTlsPinnedConstMeta =>
+      const TaskConstMeta(
+        debugName: "netplay_connect_tls_pinned",
+        argNames: [
+          "serverAddr",
+          "serverName",
+          "pinnedSha256Fingerprint",
+          "playerName",
+        ],
+      );
+
+  @override
+  Future<void> crateApiNetplayNetplayCreateRoom({String? password}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(password, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
             port: port_,
           );
         },
@@ -1377,14 +1889,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: sse_decode_String,
         ),
         constMeta: kCrateApiNetplayNetplayCreateRoomConstMeta,
-        argValues: [],
+        argValues: [password],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta get kCrateApiNetplayNetplayCreateRoomConstMeta =>
-      const TaskConstMeta(debugName: "netplay_create_room", argNames: []);
+      const TaskConstMeta(
+        debugName: "netplay_create_room",
+        argNames: ["password"],
+      );
 
   @override
   Future<void> crateApiNetplayNetplayDisconnect() {
@@ -1395,7 +1910,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 31,
+            funcId: 43,
             port: port_,
           );
         },
@@ -1425,7 +1940,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 32,
+              funcId: 44,
               port: port_,
             );
           },
@@ -1457,7 +1972,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 33,
+            funcId: 45,
             port: port_,
           );
         },
@@ -1476,16 +1991,55 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "netplay_is_connected", argNames: []);
 
   @override
-  Future<void> crateApiNetplayNetplayJoinRoom({required int roomCode}) {
+  Future<void> crateApiNetplayNetplayJoinRoom({
+    required int roomCode,
+    String? password,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(roomCode, serializer);
+          sse_encode_opt_String(password, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNetplayNetplayJoinRoomConstMeta,
+        argValues: [roomCode, password],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetplayNetplayJoinRoomConstMeta =>
+      const TaskConstMeta(
+        debugName: "netplay_join_room",
+        argNames: ["roomCode", "password"],
+      );
+
+  @override
+  Future<void> crateApiNetplayNetplayListRooms({
+    int? gameHash,
+    String? region,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(roomCode, serializer);
+          sse_encode_opt_box_autoadd_u_32(gameHash, serializer);
+          sse_encode_opt_String(region, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 34,
+            funcId: 47,
             port: port_,
           );
         },
@@ -1493,17 +2047,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiNetplayNetplayJoinRoomConstMeta,
-        argValues: [roomCode],
+        constMeta: kCrateApiNetplayNetplayListRoomsConstMeta,
+        argValues: [gameHash, region],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNetplayNetplayJoinRoomConstMeta =>
+  TaskConstMeta get kCrateApiNetplayNetplayListRoomsConstMeta =>
       const TaskConstMeta(
-        debugName: "netplay_join_room",
-        argNames: ["roomCode"],
+        debugName: "netplay_list_rooms",
+        argNames: ["gameHash", "region"],
       );
 
   @override
@@ -1524,7 +2078,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 35,
+            funcId: 48,
             port: port_,
           );
         },
@@ -1567,7 +2121,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 36,
+            funcId: 49,
             port: port_,
           );
         },
@@ -1626,7 +2180,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 37,
+            funcId: 50,
             port: port_,
           );
         },
@@ -1681,7 +2235,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 38,
+            funcId: 51,
             port: port_,
           );
         },
@@ -1718,7 +2272,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 39,
+            funcId: 52,
             port: port_,
           );
         },
@@ -1757,7 +2311,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 40,
+            funcId: 53,
             port: port_,
           );
         },
@@ -1792,7 +2346,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 41,
+            funcId: 54,
             port: port_,
           );
         },
@@ -1813,6 +2367,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["frame", "data"],
       );
 
+  @override
+  Future<void> crateApiNetplayNetplayQuickMatch({
+    int? gameHash,
+    String? region,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_32(gameHash, serializer);
+          sse_encode_opt_String(region, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNetplayNetplayQuickMatchConstMeta,
+        argValues: [gameHash, region],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetplayNetplayQuickMatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "netplay_quick_match",
+        argNames: ["gameHash", "region"],
+      );
+
   @override
   Future<void> crateApiNetplayNetplayRequestFallbackRelay({
     required String relayAddr,
@@ -1829,7 +2418,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 42,
+            funcId: 56,
             port: port_,
           );
         },
@@ -1859,7 +2448,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 43,
+            funcId: 57,
             port: port_,
           );
         },
@@ -1877,6 +2466,62 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiNetplayNetplayRequestStateConstMeta =>
       const TaskConstMeta(debugName: "netplay_request_state", argNames: []);
 
+  @override
+  Future<void> crateApiNetplayNetplaySendChat({required String text}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNetplayNetplaySendChatConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetplayNetplaySendChatConstMeta =>
+      const TaskConstMeta(debugName: "netplay_send_chat", argNames: ["text"]);
+
+  @override
+  Future<void> crateApiNetplayNetplaySendEmote({required int emote}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_8(emote, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNetplayNetplaySendEmoteConstMeta,
+        argValues: [emote],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetplayNetplaySendEmoteConstMeta =>
+      const TaskConstMeta(debugName: "netplay_send_emote", argNames: ["emote"]);
+
   @override
   Future<void> crateApiNetplayNetplaySendPause({required bool paused}) {
     return handler.executeNormal(
@@ -1887,7 +2532,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 44,
+            funcId: 60,
             port: port_,
           );
         },
@@ -1918,7 +2563,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 45,
+            funcId: 61,
             port: port_,
           );
         },
@@ -1946,7 +2591,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 46,
+            funcId: 62,
             port: port_,
           );
         },
@@ -1973,7 +2618,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 47,
+            funcId: 63,
             port: port_,
           );
         },
@@ -1991,6 +2636,105 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiNetplayNetplaySendRomLoadedConstMeta =>
       const TaskConstMeta(debugName: "netplay_send_rom_loaded", argNames: []);
 
+  @override
+  Future<void> crateApiNetplayNetplaySetRoomInfo({
+    required String name,
+    required String region,
+    required int gameHash,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_String(region, serializer);
+          sse_encode_u_32(gameHash, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNetplayNetplaySetRoomInfoConstMeta,
+        argValues: [name, region, gameHash],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetplayNetplaySetRoomInfoConstMeta =>
+      const TaskConstMeta(
+        debugName: "netplay_set_room_info",
+        argNames: ["name", "region", "gameHash"],
+      );
+
+  @override
+  Future<void> crateApiNetplayNetplaySetSpectatorDelay({required int frames}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(frames, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNetplayNetplaySetSpectatorDelayConstMeta,
+        argValues: [frames],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetplayNetplaySetSpectatorDelayConstMeta =>
+      const TaskConstMeta(
+        debugName: "netplay_set_spectator_delay",
+        argNames: ["frames"],
+      );
+
+  @override
+  Future<void> crateApiNetplayNetplaySetUserToken({List<int>? token}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_prim_u_8_loose(token, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNetplayNetplaySetUserTokenConstMeta,
+        argValues: [token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetplayNetplaySetUserTokenConstMeta =>
+      const TaskConstMeta(
+        debugName: "netplay_set_user_token",
+        argNames: ["token"],
+      );
+
   @override
   Stream<NetplayStatus> crateApiNetplayNetplayStatusStream() {
     final sink = RustStreamSink<NetplayStatus>();
@@ -2003,7 +2747,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 48,
+              funcId: 67,
               port: port_,
             );
           },
@@ -2036,7 +2780,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 49,
+            funcId: 68,
             port: port_,
           );
         },
@@ -2063,7 +2807,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 50,
+            funcId: 69,
             port: port_,
           );
         },
@@ -2090,7 +2834,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 51,
+            funcId: 70,
             port: port_,
           );
         },
@@ -2118,7 +2862,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 52,
+            funcId: 71,
             port: port_,
           );
         },
@@ -2148,7 +2892,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 53,
+              funcId: 72,
               port: port_,
             );
           },
@@ -2180,7 +2924,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 54,
+            funcId: 73,
             port: port_,
           );
         },
@@ -2207,7 +2951,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 55,
+            funcId: 74,
             port: port_,
           );
         },
@@ -2237,7 +2981,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 56,
+            funcId: 75,
             port: port_,
           );
         },
@@ -2264,7 +3008,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 57,
+            funcId: 76,
             port: port_,
           );
         },
@@ -2294,7 +3038,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 58,
+              funcId: 77,
               port: port_,
             );
           },
@@ -2326,7 +3070,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 59,
+            funcId: 78,
             port: port_,
           );
         },
@@ -2353,7 +3097,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 60,
+            funcId: 79,
             port: port_,
           );
         },
@@ -2380,7 +3124,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 61,
+            funcId: 80,
             port: port_,
           );
         },
@@ -2413,7 +3157,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 62,
+              funcId: 81,
               port: port_,
             );
           },
@@ -2442,7 +3186,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 63,
+            funcId: 82,
             port: port_,
           );
         },
@@ -2476,7 +3220,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 64,
+            funcId: 83,
             port: port_,
           );
         },
@@ -2509,7 +3253,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 65,
+              funcId: 84,
               port: port_,
             );
           },
@@ -2542,7 +3286,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 66,
+            funcId: 85,
             port: port_,
           );
         },
@@ -2561,58 +3305,121 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "save_state", argNames: ["path"]);
 
   @override
-  Future<Uint8List> crateApiEmulationSaveStateToMemory() {
+  Future<Uint8List> crateApiEmulationSaveStateToMemory() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiEmulationSaveStateToMemoryConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEmulationSaveStateToMemoryConstMeta =>
+      const TaskConstMeta(debugName: "save_state_to_memory", argNames: []);
+
+  @override
+  Future<ScanlineOptions> crateApiVideoScanlineOptionsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_scanline_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiVideoScanlineOptionsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVideoScanlineOptionsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "scanline_options_default", argNames: []);
+
+  @override
+  Future<void> crateApiAudioSetAudioStereo({
+    required StereoPreset preset,
+    required ExpansionBalance balance,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_stereo_preset(preset, serializer);
+          sse_encode_box_autoadd_expansion_balance(balance, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 67,
+            funcId: 88,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiEmulationSaveStateToMemoryConstMeta,
-        argValues: [],
+        constMeta: kCrateApiAudioSetAudioStereoConstMeta,
+        argValues: [preset, balance],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiEmulationSaveStateToMemoryConstMeta =>
-      const TaskConstMeta(debugName: "save_state_to_memory", argNames: []);
+  TaskConstMeta get kCrateApiAudioSetAudioStereoConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_audio_stereo",
+        argNames: ["preset", "balance"],
+      );
 
   @override
-  Future<ScanlineOptions> crateApiVideoScanlineOptionsDefault() {
+  Future<void> crateApiEmulationSetClonePpu({required bool enabled}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 68,
+            funcId: 89,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_scanline_options,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiVideoScanlineOptionsDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiEmulationSetClonePpuConstMeta,
+        argValues: [enabled],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiVideoScanlineOptionsDefaultConstMeta =>
-      const TaskConstMeta(debugName: "scanline_options_default", argNames: []);
+  TaskConstMeta get kCrateApiEmulationSetClonePpuConstMeta =>
+      const TaskConstMeta(debugName: "set_clone_ppu", argNames: ["enabled"]);
 
   @override
   Future<void> crateApiEmulationSetFastForwardSpeed({
@@ -2626,7 +3433,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 69,
+            funcId: 90,
             port: port_,
           );
         },
@@ -2659,7 +3466,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 70,
+            funcId: 91,
             port: port_,
           );
         },
@@ -2694,7 +3501,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 71,
+            funcId: 92,
             port: port_,
           );
         },
@@ -2727,7 +3534,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 72,
+            funcId: 93,
             port: port_,
           );
         },
@@ -2758,7 +3565,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 73,
+            funcId: 94,
             port: port_,
           );
         },
@@ -2791,7 +3598,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 74,
+            funcId: 95,
             port: port_,
           );
         },
@@ -2824,7 +3631,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 75,
+            funcId: 96,
             port: port_,
           );
         },
@@ -2855,7 +3662,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 76,
+            funcId: 97,
             port: port_,
           );
         },
@@ -2884,7 +3691,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 77,
+            funcId: 98,
             port: port_,
           );
         },
@@ -2911,7 +3718,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 78,
+            funcId: 99,
             port: port_,
           );
         },
@@ -2946,7 +3753,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 79,
+            funcId: 100,
             port: port_,
           );
         },
@@ -2976,7 +3783,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 80,
+            funcId: 101,
             port: port_,
           );
         },
@@ -3007,7 +3814,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 81,
+            funcId: 102,
             port: port_,
           );
         },
@@ -3038,7 +3845,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 82,
+            funcId: 103,
             port: port_,
           );
         },
@@ -3066,7 +3873,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 83,
+            funcId: 104,
             port: port_,
           );
         },
@@ -3098,7 +3905,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 84,
+            funcId: 105,
             port: port_,
           );
         },
@@ -3129,7 +3936,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 85,
+            funcId: 106,
             port: port_,
           );
         },
@@ -3160,7 +3967,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 86,
+            funcId: 107,
             port: port_,
           );
         },
@@ -3190,7 +3997,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 87,
+            funcId: 108,
             port: port_,
           );
         },
@@ -3225,7 +4032,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 88,
+            funcId: 109,
             port: port_,
           );
         },
@@ -3256,7 +4063,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 89,
+            funcId: 110,
             port: port_,
           );
         },
@@ -3291,7 +4098,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 90,
+            funcId: 111,
             port: port_,
           );
         },
@@ -3322,7 +4129,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 91,
+            funcId: 112,
             port: port_,
           );
         },
@@ -3352,7 +4159,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 92,
+            funcId: 113,
             port: port_,
           );
         },
@@ -3387,7 +4194,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 93,
+            funcId: 114,
             port: port_,
           );
         },
@@ -3417,7 +4224,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 94,
+            funcId: 115,
             port: port_,
           );
         },
@@ -3450,7 +4257,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 95,
+            funcId: 116,
             port: port_,
           );
         },
@@ -3480,7 +4287,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 96,
+            funcId: 117,
             port: port_,
           );
         },
@@ -3515,7 +4322,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 97,
+            funcId: 118,
             port: port_,
           );
         },
@@ -3545,7 +4352,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 98,
+            funcId: 119,
             port: port_,
           );
         },
@@ -3576,7 +4383,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 99,
+            funcId: 120,
             port: port_,
           );
         },
@@ -3607,7 +4414,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 100,
+            funcId: 121,
             port: port_,
           );
         },
@@ -3638,7 +4445,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 101,
+            funcId: 122,
             port: port_,
           );
         },
@@ -3673,7 +4480,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 102,
+            funcId: 123,
             port: port_,
           );
         },
@@ -3704,7 +4511,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 103,
+            funcId: 124,
             port: port_,
           );
         },
@@ -3737,7 +4544,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 104,
+            funcId: 125,
             port: port_,
           );
         },
@@ -3767,7 +4574,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 105,
+            funcId: 126,
             port: port_,
           );
         },
@@ -3802,7 +4609,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 106,
+            funcId: 127,
             port: port_,
           );
         },
@@ -3832,7 +4639,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 107,
+            funcId: 128,
             port: port_,
           );
         },
@@ -3863,7 +4670,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 108,
+            funcId: 129,
             port: port_,
           );
         },
@@ -3894,7 +4701,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 109,
+            funcId: 130,
             port: port_,
           );
         },
@@ -3929,7 +4736,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 110,
+            funcId: 131,
             port: port_,
           );
         },
@@ -3963,7 +4770,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 111,
+            funcId: 132,
             port: port_,
           );
         },
@@ -3996,7 +4803,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 112,
+            funcId: 133,
             port: port_,
           );
         },
@@ -4014,6 +4821,194 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiVideoSetVideoFilterConstMeta =>
       const TaskConstMeta(debugName: "set_video_filter", argNames: ["filter"]);
 
+  @override
+  Future<void> crateApiInputSetZapperCalibration({
+    required int offsetX,
+    required int offsetY,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_32(offsetX, serializer);
+          sse_encode_i_32(offsetY, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 134,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInputSetZapperCalibrationConstMeta,
+        argValues: [offsetX, offsetY],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInputSetZapperCalibrationConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_zapper_calibration",
+        argNames: ["offsetX", "offsetY"],
+      );
+
+  @override
+  Future<void> crateApiInputSetZapperCrosshair({required bool visible}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(visible, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 135,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInputSetZapperCrosshairConstMeta,
+        argValues: [visible],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInputSetZapperCrosshairConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_zapper_crosshair",
+        argNames: ["visible"],
+      );
+
+  @override
+  Future<void> crateApiInputSetZapperLagCompensation({required int frames}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_8(frames, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 136,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInputSetZapperLagCompensationConstMeta,
+        argValues: [frames],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInputSetZapperLagCompensationConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_zapper_lag_compensation",
+        argNames: ["frames"],
+      );
+
+  @override
+  Future<void> crateApiInputSetZapperPointer({required int x, required int y}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_32(x, serializer);
+          sse_encode_i_32(y, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 137,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInputSetZapperPointerConstMeta,
+        argValues: [x, y],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInputSetZapperPointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_zapper_pointer",
+        argNames: ["x", "y"],
+      );
+
+  @override
+  Future<void> crateApiInputSetZapperPort({int? port}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_8(port, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 138,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInputSetZapperPortConstMeta,
+        argValues: [port],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInputSetZapperPortConstMeta =>
+      const TaskConstMeta(debugName: "set_zapper_port", argNames: ["port"]);
+
+  @override
+  Future<void> crateApiInputSetZapperTrigger({required bool pulled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(pulled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 139,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInputSetZapperTriggerConstMeta,
+        argValues: [pulled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInputSetZapperTriggerConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_zapper_trigger",
+        argNames: ["pulled"],
+      );
+
   @override
   Future<void> crateApiGamepadShutdownGamepad() {
     return handler.executeNormal(
@@ -4023,7 +5018,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 113,
+            funcId: 140,
             port: port_,
           );
         },
@@ -4053,7 +5048,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 114,
+              funcId: 141,
               port: port_,
             );
           },
@@ -4067,11 +5062,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         ),
       ),
     );
-    return sink.stream;
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiEventsSpriteStateStreamConstMeta =>
+      const TaskConstMeta(debugName: "sprite_state_stream", argNames: ["sink"]);
+
+  @override
+  Future<int> crateApiInstanceStartInstance({required bool audio}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(audio, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 142,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInstanceStartInstanceConstMeta,
+        argValues: [audio],
+        apiImpl: this,
+      ),
+    );
   }
 
-  TaskConstMeta get kCrateApiEventsSpriteStateStreamConstMeta =>
-      const TaskConstMeta(debugName: "sprite_state_stream", argNames: ["sink"]);
+  TaskConstMeta get kCrateApiInstanceStartInstanceConstMeta =>
+      const TaskConstMeta(debugName: "start_instance", argNames: ["audio"]);
 
   @override
   Future<void> crateApiLoadRomStartNesRuntime() {
@@ -4082,7 +5105,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 115,
+            funcId: 143,
             port: port_,
           );
         },
@@ -4100,6 +5123,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiLoadRomStartNesRuntimeConstMeta =>
       const TaskConstMeta(debugName: "start_nes_runtime", argNames: []);
 
+  @override
+  Future<void> crateApiInstanceStopInstance({required int instance}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(instance, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 144,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInstanceStopInstanceConstMeta,
+        argValues: [instance],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInstanceStopInstanceConstMeta =>
+      const TaskConstMeta(debugName: "stop_instance", argNames: ["instance"]);
+
   @override
   Future<void> crateApiEventsSubscribeTilemapTexture() {
     return handler.executeNormal(
@@ -4109,7 +5160,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 116,
+            funcId: 145,
             port: port_,
           );
         },
@@ -4139,7 +5190,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 117,
+              funcId: 146,
               port: port_,
             );
           },
@@ -4171,7 +5222,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 118,
+              funcId: 147,
               port: port_,
             );
           },
@@ -4203,7 +5254,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 119,
+            funcId: 148,
             port: port_,
           );
         },
@@ -4235,7 +5286,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 120,
+            funcId: 149,
             port: port_,
           );
         },
@@ -4265,7 +5316,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 121,
+            funcId: 150,
             port: port_,
           );
         },
@@ -4292,7 +5343,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 122,
+            funcId: 151,
             port: port_,
           );
         },
@@ -4319,7 +5370,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 123,
+            funcId: 152,
             port: port_,
           );
         },
@@ -4346,7 +5397,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 124,
+            funcId: 153,
             port: port_,
           );
         },
@@ -4373,7 +5424,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 125,
+            funcId: 154,
             port: port_,
           );
         },
@@ -4510,6 +5561,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as bool;
   }
 
+  @protected
+  ExpansionBalance dco_decode_box_autoadd_expansion_balance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_expansion_balance(raw);
+  }
+
   @protected
   GamepadButtonFfi dco_decode_box_autoadd_gamepad_button_ffi(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4546,6 +5603,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_scanline_options(raw);
   }
 
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   int dco_decode_box_autoadd_u_8(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4590,6 +5653,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ExpansionBalance dco_decode_expansion_balance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ExpansionBalance(
+      fds: dco_decode_f_32(arr[0]),
+      mmc5: dco_decode_f_32(arr[1]),
+      vrc6: dco_decode_f_32(arr[2]),
+      vrc7: dco_decode_f_32(arr[3]),
+      namco163: dco_decode_f_32(arr[4]),
+      sunsoft5B: dco_decode_f_32(arr[5]),
+    );
+  }
+
   @protected
   double dco_decode_f_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4716,12 +5795,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_gamepad_info_ffi).toList();
   }
 
+  @protected
+  List<NetplayPeerStats> dco_decode_list_netplay_peer_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_netplay_peer_stats).toList();
+  }
+
   @protected
   List<NetplayPlayer> dco_decode_list_netplay_player(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_netplay_player).toList();
   }
 
+  @protected
+  List<NetplayRoomSummary> dco_decode_list_netplay_room_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_netplay_room_summary).toList();
+  }
+
   @protected
   List<PalettePresetInfo> dco_decode_list_palette_preset_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4781,11 +5872,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           relayRoomCode: dco_decode_u_32(raw[2]),
           reason: dco_decode_String(raw[3]),
         );
+      case 8:
+        return NetplayGameEvent_Desync(frame: dco_decode_u_32(raw[1]));
+      case 9:
+        return NetplayGameEvent_Chat(
+          clientId: dco_decode_u_32(raw[1]),
+          name: dco_decode_String(raw[2]),
+          text: dco_decode_opt_String(raw[3]),
+          emote: dco_decode_opt_box_autoadd_u_8(raw[4]),
+        );
+      case 10:
+        return NetplayGameEvent_NetworkStats(
+          peers: dco_decode_list_netplay_peer_stats(raw[1]),
+        );
+      case 11:
+        return NetplayGameEvent_InputDelayRecommended(
+          frames: dco_decode_u_32(raw[1]),
+        );
+      case 12:
+        return NetplayGameEvent_RoomList(
+          rooms: dco_decode_list_netplay_room_summary(raw[1]),
+        );
+      case 13:
+        return NetplayGameEvent_SpectatorInfo(
+          count: dco_decode_u_16(raw[1]),
+          delayFrames: dco_decode_u_32(raw[2]),
+        );
+      case 14:
+        return NetplayGameEvent_Identity(
+          userId: dco_decode_u_64(raw[1]),
+          userToken: dco_decode_list_prim_u_8_strict(raw[2]),
+        );
+      case 15:
+        return NetplayGameEvent_ServerClosing(graceMs: dco_decode_u_32(raw[1]));
       default:
         throw Exception("unreachable");
     }
   }
 
+  @protected
+  NetplayPeerStats dco_decode_netplay_peer_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return NetplayPeerStats(
+      peerClientId: dco_decode_u_32(arr[0]),
+      rttMs: dco_decode_u_32(arr[1]),
+      jitterMs: dco_decode_u_32(arr[2]),
+      lossPercent: dco_decode_u_8(arr[3]),
+    );
+  }
+
   @protected
   NetplayPlayer dco_decode_netplay_player(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4799,6 +5937,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  NetplayRoomSummary dco_decode_netplay_room_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return NetplayRoomSummary(
+      roomCode: dco_decode_u_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      region: dco_decode_String(arr[2]),
+      gameHash: dco_decode_u_32(arr[3]),
+      playerCount: dco_decode_u_8(arr[4]),
+      spectatorCount: dco_decode_u_8(arr[5]),
+      openSlots: dco_decode_u_8(arr[6]),
+      started: dco_decode_bool(arr[7]),
+    );
+  }
+
   @protected
   NetplayState dco_decode_netplay_state(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4879,12 +6035,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_gamepad_button_ffi(raw);
   }
 
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_u_8(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw == null ? null : dco_decode_box_autoadd_u_8(raw);
   }
 
+  @protected
+  List<int>? dco_decode_opt_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_loose(raw);
+  }
+
   @protected
   Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -5059,6 +6227,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  StereoPreset dco_decode_stereo_preset(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StereoPreset.values[raw as int];
+  }
+
   @protected
   TileSnapshot dco_decode_tile_snapshot(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -5283,6 +6457,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8() != 0;
   }
 
+  @protected
+  ExpansionBalance sse_decode_box_autoadd_expansion_balance(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_expansion_balance(deserializer));
+  }
+
   @protected
   GamepadButtonFfi sse_decode_box_autoadd_gamepad_button_ffi(
     SseDeserializer deserializer,
@@ -5331,6 +6513,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_scanline_options(deserializer));
   }
 
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
   @protected
   int sse_decode_box_autoadd_u_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5387,6 +6575,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ExpansionBalance sse_decode_expansion_balance(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fds = sse_decode_f_32(deserializer);
+    var var_mmc5 = sse_decode_f_32(deserializer);
+    var var_vrc6 = sse_decode_f_32(deserializer);
+    var var_vrc7 = sse_decode_f_32(deserializer);
+    var var_namco163 = sse_decode_f_32(deserializer);
+    var var_sunsoft5B = sse_decode_f_32(deserializer);
+    return ExpansionBalance(
+      fds: var_fds,
+      mmc5: var_mmc5,
+      vrc6: var_vrc6,
+      vrc7: var_vrc7,
+      namco163: var_namco163,
+      sunsoft5B: var_sunsoft5B,
+    );
+  }
+
   @protected
   double sse_decode_f_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5573,6 +6780,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<NetplayPeerStats> sse_decode_list_netplay_peer_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NetplayPeerStats>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_netplay_peer_stats(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<NetplayPlayer> sse_decode_list_netplay_player(
     SseDeserializer deserializer,
@@ -5587,6 +6808,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<NetplayRoomSummary> sse_decode_list_netplay_room_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NetplayRoomSummary>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_netplay_room_summary(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<PalettePresetInfo> sse_decode_list_palette_preset_info(
     SseDeserializer deserializer,
@@ -5677,11 +6912,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           relayRoomCode: var_relayRoomCode,
           reason: var_reason,
         );
+      case 8:
+        var var_frame = sse_decode_u_32(deserializer);
+        return NetplayGameEvent_Desync(frame: var_frame);
+      case 9:
+        var var_clientId = sse_decode_u_32(deserializer);
+        var var_name = sse_decode_String(deserializer);
+        var var_text = sse_decode_opt_String(deserializer);
+        var var_emote = sse_decode_opt_box_autoadd_u_8(deserializer);
+        return NetplayGameEvent_Chat(
+          clientId: var_clientId,
+          name: var_name,
+          text: var_text,
+          emote: var_emote,
+        );
+      case 10:
+        var var_peers = sse_decode_list_netplay_peer_stats(deserializer);
+        return NetplayGameEvent_NetworkStats(peers: var_peers);
+      case 11:
+        var var_frames = sse_decode_u_32(deserializer);
+        return NetplayGameEvent_InputDelayRecommended(frames: var_frames);
+      case 12:
+        var var_rooms = sse_decode_list_netplay_room_summary(deserializer);
+        return NetplayGameEvent_RoomList(rooms: var_rooms);
+      case 13:
+        var var_count = sse_decode_u_16(deserializer);
+        var var_delayFrames = sse_decode_u_32(deserializer);
+        return NetplayGameEvent_SpectatorInfo(
+          count: var_count,
+          delayFrames: var_delayFrames,
+        );
+      case 14:
+        var var_userId = sse_decode_u_64(deserializer);
+        var var_userToken = sse_decode_list_prim_u_8_strict(deserializer);
+        return NetplayGameEvent_Identity(
+          userId: var_userId,
+          userToken: var_userToken,
+        );
+      case 15:
+        var var_graceMs = sse_decode_u_32(deserializer);
+        return NetplayGameEvent_ServerClosing(graceMs: var_graceMs);
       default:
         throw UnimplementedError('');
     }
   }
 
+  @protected
+  NetplayPeerStats sse_decode_netplay_peer_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerClientId = sse_decode_u_32(deserializer);
+    var var_rttMs = sse_decode_u_32(deserializer);
+    var var_jitterMs = sse_decode_u_32(deserializer);
+    var var_lossPercent = sse_decode_u_8(deserializer);
+    return NetplayPeerStats(
+      peerClientId: var_peerClientId,
+      rttMs: var_rttMs,
+      jitterMs: var_jitterMs,
+      lossPercent: var_lossPercent,
+    );
+  }
+
   @protected
   NetplayPlayer sse_decode_netplay_player(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5695,6 +6985,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  NetplayRoomSummary sse_decode_netplay_room_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_roomCode = sse_decode_u_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_region = sse_decode_String(deserializer);
+    var var_gameHash = sse_decode_u_32(deserializer);
+    var var_playerCount = sse_decode_u_8(deserializer);
+    var var_spectatorCount = sse_decode_u_8(deserializer);
+    var var_openSlots = sse_decode_u_8(deserializer);
+    var var_started = sse_decode_bool(deserializer);
+    return NetplayRoomSummary(
+      roomCode: var_roomCode,
+      name: var_name,
+      region: var_region,
+      gameHash: var_gameHash,
+      playerCount: var_playerCount,
+      spectatorCount: var_spectatorCount,
+      openSlots: var_openSlots,
+      started: var_started,
+    );
+  }
+
   @protected
   NetplayState sse_decode_netplay_state(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5808,6 +7123,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5819,6 +7145,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  List<int>? sse_decode_opt_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_loose(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6014,6 +7351,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  StereoPreset sse_decode_stereo_preset(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return StereoPreset.values[inner];
+  }
+
   @protected
   TileSnapshot sse_decode_tile_snapshot(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6352,6 +7696,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8(self ? 1 : 0);
   }
 
+  @protected
+  void sse_encode_box_autoadd_expansion_balance(
+    ExpansionBalance self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_expansion_balance(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_gamepad_button_ffi(
     GamepadButtonFfi self,
@@ -6406,6 +7759,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_scanline_options(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6444,6 +7803,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self.fastForwarding, serializer);
   }
 
+  @protected
+  void sse_encode_expansion_balance(
+    ExpansionBalance self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.fds, serializer);
+    sse_encode_f_32(self.mmc5, serializer);
+    sse_encode_f_32(self.vrc6, serializer);
+    sse_encode_f_32(self.vrc7, serializer);
+    sse_encode_f_32(self.namco163, serializer);
+    sse_encode_f_32(self.sunsoft5B, serializer);
+  }
+
   @protected
   void sse_encode_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6580,6 +7953,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_netplay_peer_stats(
+    List<NetplayPeerStats> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_netplay_peer_stats(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_netplay_player(
     List<NetplayPlayer> self,
@@ -6592,6 +7977,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_netplay_room_summary(
+    List<NetplayRoomSummary> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_netplay_room_summary(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_palette_preset_info(
     List<PalettePresetInfo> self,
@@ -6687,9 +8084,61 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(relayAddr, serializer);
         sse_encode_u_32(relayRoomCode, serializer);
         sse_encode_String(reason, serializer);
+      case NetplayGameEvent_Desync(frame: final frame):
+        sse_encode_i_32(8, serializer);
+        sse_encode_u_32(frame, serializer);
+      case NetplayGameEvent_Chat(
+        clientId: final clientId,
+        name: final name,
+        text: final text,
+        emote: final emote,
+      ):
+        sse_encode_i_32(9, serializer);
+        sse_encode_u_32(clientId, serializer);
+        sse_encode_String(name, serializer);
+        sse_encode_opt_String(text, serializer);
+        sse_encode_opt_box_autoadd_u_8(emote, serializer);
+      case NetplayGameEvent_NetworkStats(peers: final peers):
+        sse_encode_i_32(10, serializer);
+        sse_encode_list_netplay_peer_stats(peers, serializer);
+      case NetplayGameEvent_InputDelayRecommended(frames: final frames):
+        sse_encode_i_32(11, serializer);
+        sse_encode_u_32(frames, serializer);
+      case NetplayGameEvent_RoomList(rooms: final rooms):
+        sse_encode_i_32(12, serializer);
+        sse_encode_list_netplay_room_summary(rooms, serializer);
+      case NetplayGameEvent_SpectatorInfo(
+        count: final count,
+        delayFrames: final delayFrames,
+      ):
+        sse_encode_i_32(13, serializer);
+        sse_encode_u_16(count, serializer);
+        sse_encode_u_32(delayFrames, serializer);
+      case NetplayGameEvent_Identity(
+        userId: final userId,
+        userToken: final userToken,
+      ):
+        sse_encode_i_32(14, serializer);
+        sse_encode_u_64(userId, serializer);
+        sse_encode_list_prim_u_8_strict(userToken, serializer);
+      case NetplayGameEvent_ServerClosing(graceMs: final graceMs):
+        sse_encode_i_32(15, serializer);
+        sse_encode_u_32(graceMs, serializer);
     }
   }
 
+  @protected
+  void sse_encode_netplay_peer_stats(
+    NetplayPeerStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.peerClientId, serializer);
+    sse_encode_u_32(self.rttMs, serializer);
+    sse_encode_u_32(self.jitterMs, serializer);
+    sse_encode_u_8(self.lossPercent, serializer);
+  }
+
   @protected
   void sse_encode_netplay_player(NetplayPlayer self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6698,6 +8147,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_8(self.playerIndex, serializer);
   }
 
+  @protected
+  void sse_encode_netplay_room_summary(
+    NetplayRoomSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.roomCode, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.region, serializer);
+    sse_encode_u_32(self.gameHash, serializer);
+    sse_encode_u_8(self.playerCount, serializer);
+    sse_encode_u_8(self.spectatorCount, serializer);
+    sse_encode_u_8(self.openSlots, serializer);
+    sse_encode_bool(self.started, serializer);
+  }
+
   @protected
   void sse_encode_netplay_state(NetplayState self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6780,6 +8245,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6790,6 +8265,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_list_prim_u_8_loose(
+    List<int>? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_loose(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_list_prim_u_8_strict(
     Uint8List? self,
@@ -6953,6 +8441,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_prim_u_8_strict(self.rgbaPalette, serializer);
   }
 
+  @protected
+  void sse_encode_stereo_preset(StereoPreset self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_tile_snapshot(TileSnapshot self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3,10 +3,12 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/audio.dart';
 import 'api/emulation.dart';
 import 'api/events.dart';
 import 'api/gamepad.dart';
 import 'api/input.dart';
+import 'api/instance.dart';
 import 'api/load_rom.dart';
 import 'api/net_utils.dart';
 import 'api/netplay.dart';
@@ -93,6 +95,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  ExpansionBalance dco_decode_box_autoadd_expansion_balance(dynamic raw);
+
   @protected
   GamepadButtonFfi dco_decode_box_autoadd_gamepad_button_ffi(dynamic raw);
 
@@ -111,6 +116,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ScanlineOptions dco_decode_box_autoadd_scanline_options(dynamic raw);
 
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_8(dynamic raw);
 
@@ -122,6 +130,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  ExpansionBalance dco_decode_expansion_balance(dynamic raw);
+
   @protected
   double dco_decode_f_32(dynamic raw);
 
@@ -161,9 +172,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<GamepadInfoFfi> dco_decode_list_gamepad_info_ffi(dynamic raw);
 
+  @protected
+  List<NetplayPeerStats> dco_decode_list_netplay_peer_stats(dynamic raw);
+
   @protected
   List<NetplayPlayer> dco_decode_list_netplay_player(dynamic raw);
 
+  @protected
+  List<NetplayRoomSummary> dco_decode_list_netplay_room_summary(dynamic raw);
+
   @protected
   List<PalettePresetInfo> dco_decode_list_palette_preset_info(dynamic raw);
 
@@ -182,9 +199,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   NetplayGameEvent dco_decode_netplay_game_event(dynamic raw);
 
+  @protected
+  NetplayPeerStats dco_decode_netplay_peer_stats(dynamic raw);
+
   @protected
   NetplayPlayer dco_decode_netplay_player(dynamic raw);
 
+  @protected
+  NetplayRoomSummary dco_decode_netplay_room_summary(dynamic raw);
+
   @protected
   NetplayState dco_decode_netplay_state(dynamic raw);
 
@@ -206,9 +229,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   GamepadButtonFfi? dco_decode_opt_box_autoadd_gamepad_button_ffi(dynamic raw);
 
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
   @protected
   int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
 
+  @protected
+  List<int>? dco_decode_opt_list_prim_u_8_loose(dynamic raw);
+
   @protected
   Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
 
@@ -254,6 +283,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SpriteSnapshot dco_decode_sprite_snapshot(dynamic raw);
 
+  @protected
+  StereoPreset dco_decode_stereo_preset(dynamic raw);
+
   @protected
   TileSnapshot dco_decode_tile_snapshot(dynamic raw);
 
@@ -353,6 +385,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  ExpansionBalance sse_decode_box_autoadd_expansion_balance(
+    SseDeserializer deserializer,
+  );
+
   @protected
   GamepadButtonFfi sse_decode_box_autoadd_gamepad_button_ffi(
     SseDeserializer deserializer,
@@ -381,6 +418,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
   @protected
   int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
 
@@ -394,6 +434,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ExpansionBalance sse_decode_expansion_balance(SseDeserializer deserializer);
+
   @protected
   double sse_decode_f_32(SseDeserializer deserializer);
 
@@ -443,11 +486,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<NetplayPeerStats> sse_decode_list_netplay_peer_stats(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<NetplayPlayer> sse_decode_list_netplay_player(
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<NetplayRoomSummary> sse_decode_list_netplay_room_summary(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<PalettePresetInfo> sse_decode_list_palette_preset_info(
     SseDeserializer deserializer,
@@ -470,9 +523,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   NetplayGameEvent sse_decode_netplay_game_event(SseDeserializer deserializer);
 
+  @protected
+  NetplayPeerStats sse_decode_netplay_peer_stats(SseDeserializer deserializer);
+
   @protected
   NetplayPlayer sse_decode_netplay_player(SseDeserializer deserializer);
 
+  @protected
+  NetplayRoomSummary sse_decode_netplay_room_summary(
+    SseDeserializer deserializer,
+  );
+
   @protected
   NetplayState sse_decode_netplay_state(SseDeserializer deserializer);
 
@@ -498,9 +559,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
   @protected
   int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
 
+  @protected
+  List<int>? sse_decode_opt_list_prim_u_8_loose(SseDeserializer deserializer);
+
   @protected
   Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
 
@@ -554,6 +621,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SpriteSnapshot sse_decode_sprite_snapshot(SseDeserializer deserializer);
 
+  @protected
+  StereoPreset sse_decode_stereo_preset(SseDeserializer deserializer);
+
   @protected
   TileSnapshot sse_decode_tile_snapshot(SseDeserializer deserializer);
 
@@ -665,6 +735,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_expansion_balance(
+    ExpansionBalance self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_gamepad_button_ffi(
     GamepadButtonFfi self,
@@ -701,6 +777,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
 
@@ -716,6 +795,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_expansion_balance(
+    ExpansionBalance self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_f_32(double self, SseSerializer serializer);
 
@@ -779,12 +864,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_netplay_peer_stats(
+    List<NetplayPeerStats> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_netplay_player(
     List<NetplayPlayer> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_netplay_room_summary(
+    List<NetplayRoomSummary> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_palette_preset_info(
     List<PalettePresetInfo> self,
@@ -818,9 +915,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_netplay_peer_stats(
+    NetplayPeerStats self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_netplay_player(NetplayPlayer self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_netplay_room_summary(
+    NetplayRoomSummary self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_netplay_state(NetplayState self, SseSerializer serializer);
 
@@ -851,9 +960,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_list_prim_u_8_loose(
+    List<int>? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_list_prim_u_8_strict(
     Uint8List? self,
@@ -932,6 +1050,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_stereo_preset(StereoPreset self, SseSerializer serializer);
+
   @protected
   void sse_encode_tile_snapshot(TileSnapshot self, SseSerializer serializer);
 
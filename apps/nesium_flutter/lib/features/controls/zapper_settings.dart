@@ -0,0 +1,224 @@
+import 'dart:async';
+
+import 'package:flutter/foundation.dart';
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+
+import '../../platform/nes_input.dart' as nes_input;
+import '../../persistence/app_storage.dart';
+import '../../persistence/keys.dart';
+import '../../persistence/storage_codec.dart';
+import '../../persistence/storage_key.dart';
+import '../../logging/app_logger.dart';
+
+final StorageKey<JsonMap> _zapperSettingsKey = StorageKey(
+  StorageKeys.settingsZapper,
+  jsonMapStringCodec(storageKey: StorageKeys.settingsZapper),
+);
+
+/// Matches the runtime's clamp for display-lag compensation.
+const int zapperMaxLagFrames = 8;
+
+/// Largest aim calibration offset offered in settings, in NES pixels.
+const int zapperMaxCalibrationOffset = 16;
+
+@immutable
+class ZapperSettings {
+  const ZapperSettings({
+    required this.port,
+    required this.lagFrames,
+    required this.offsetX,
+    required this.offsetY,
+    required this.crosshair,
+  });
+
+  /// Port (0/1) the Zapper is plugged into, or `null` to keep the pad.
+  final int? port;
+
+  /// Frames the trigger is rolled back to offset display lag (0 disables).
+  final int lagFrames;
+
+  /// Aim calibration added to the pointer position, in NES pixels.
+  final int offsetX;
+  final int offsetY;
+
+  /// Whether the core draws a crosshair at the aim point.
+  final bool crosshair;
+
+  bool get enabled => port != null;
+
+  ZapperSettings copyWith({
+    int? Function()? port,
+    int? lagFrames,
+    int? offsetX,
+    int? offsetY,
+    bool? crosshair,
+  }) => ZapperSettings(
+    port: port != null ? port() : this.port,
+    lagFrames: lagFrames ?? this.lagFrames,
+    offsetX: offsetX ?? this.offsetX,
+    offsetY: offsetY ?? this.offsetY,
+    crosshair: crosshair ?? this.crosshair,
+  );
+
+  @override
+  bool operator ==(Object other) =>
+      other is ZapperSettings &&
+      other.port == port &&
+      other.lagFrames == lagFrames &&
+      other.offsetX == offsetX &&
+      other.offsetY == offsetY &&
+      other.crosshair == crosshair;
+
+  @override
+  int get hashCode => Object.hash(port, lagFrames, offsetX, offsetY, crosshair);
+
+  static const ZapperSettings defaults = ZapperSettings(
+    port: null,
+    lagFrames: 0,
+    offsetX: 0,
+    offsetY: 0,
+    crosshair: false,
+  );
+}
+
+class ZapperSettingsController extends Notifier<ZapperSettings> {
+  @override
+  ZapperSettings build() {
+    final storage = ref.read(appStorageProvider);
+    final subscription = storage.onKeyChanged.listen((event) {
+      if (event.key == StorageKeys.settingsZapper) {
+        unawaitedLogged(
+          _reloadFromStorage(),
+          logger: 'zapper_settings',
+          message: 'Reloading zapper settings from stream',
+        );
+      }
+    });
+    ref.onDispose(() => subscription.cancel());
+
+    final settings =
+        _zapperFromStorage(storage.read(_zapperSettingsKey)) ??
+        ZapperSettings.defaults;
+
+    scheduleMicrotask(applyToRuntime);
+
+    return settings;
+  }
+
+  Future<void> _reloadFromStorage() async {
+    final value = ref.read(appStorageProvider).read(_zapperSettingsKey);
+    final newState = _zapperFromStorage(value) ?? ZapperSettings.defaults;
+    if (newState != state) {
+      state = newState;
+      applyToRuntime();
+    }
+  }
+
+  void applyToRuntime() {
+    // Best-effort apply; callers may invoke before the runtime is initialized.
+    unawaited(nes_input.setZapperPort(port: state.port).catchError((_) {}));
+    unawaited(
+      nes_input
+          .setZapperLagCompensation(frames: state.lagFrames)
+          .catchError((_) {}),
+    );
+    unawaited(
+      nes_input
+          .setZapperCalibration(offsetX: state.offsetX, offsetY: state.offsetY)
+          .catchError((_) {}),
+    );
+    unawaited(
+      nes_input.setZapperCrosshair(visible: state.crosshair).catchError((_) {}),
+    );
+  }
+
+  void setPort(int? port) {
+    if (port == state.port) return;
+    state = state.copyWith(port: () => port);
+    _persist(state);
+    applyToRuntime();
+  }
+
+  void setLagFrames(int value) {
+    final next = value.clamp(0, zapperMaxLagFrames);
+    if (next == state.lagFrames) return;
+    state = state.copyWith(lagFrames: next);
+    _persist(state);
+    applyToRuntime();
+  }
+
+  void setCalibration({int? offsetX, int? offsetY}) {
+    final next = state.copyWith(
+      offsetX: offsetX?.clamp(
+        -zapperMaxCalibrationOffset,
+        zapperMaxCalibrationOffset,
+      ),
+      offsetY: offsetY?.clamp(
+        -zapperMaxCalibrationOffset,
+        zapperMaxCalibrationOffset,
+      ),
+    );
+    if (next == state) return;
+    state = next;
+    _persist(state);
+    applyToRuntime();
+  }
+
+  void setCrosshair(bool visible) {
+    if (visible == state.crosshair) return;
+    state = state.copyWith(crosshair: visible);
+    _persist(state);
+    applyToRuntime();
+  }
+
+  void _persist(ZapperSettings value) {
+    final payload = Map<String, dynamic>.from(_zapperToStorage(value));
+    unawaitedLogged(
+      Future<void>.sync(
+        () => ref.read(appStorageProvider).write(_zapperSettingsKey, payload),
+      ),
+      message: 'Persist zapper settings',
+      logger: 'zapper_settings',
+    );
+  }
+}
+
+final zapperSettingsProvider =
+    NotifierProvider<ZapperSettingsController, ZapperSettings>(
+      ZapperSettingsController.new,
+    );
+
+Map<String, Object?> _zapperToStorage(ZapperSettings value) =>
+    <String, Object?>{
+      'port': value.port,
+      'lagFrames': value.lagFrames,
+      'offsetX': value.offsetX,
+      'offsetY': value.offsetY,
+      'crosshair': value.crosshair,
+    };
+
+int _offsetFromStorage(Object? value) => value is num
+    ? value.toInt().clamp(
+        -zapperMaxCalibrationOffset,
+        zapperMaxCalibrationOffset,
+      )
+    : 0;
+
+ZapperSettings? _zapperFromStorage(Map<String, dynamic>? map) {
+  if (map == null) return null;
+
+  final port = map['port'];
+  final lagFrames = map['lagFrames'];
+  final crosshair = map['crosshair'];
+  return ZapperSettings(
+    port: port is num && port >= 0 && port < 2 ? port.toInt() : null,
+    lagFrames: lagFrames is num
+        ? lagFrames.toInt().clamp(0, zapperMaxLagFrames)
+        : ZapperSettings.defaults.lagFrames,
+    offsetX: _offsetFromStorage(map['offsetX']),
+    offsetY: _offsetFromStorage(map['offsetY']),
+    crosshair: crosshair is bool
+        ? crosshair
+        : ZapperSettings.defaults.crosshair,
+  );
+}
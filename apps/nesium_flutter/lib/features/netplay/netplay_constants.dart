@@ -4,3 +4,6 @@
 
 /// Player index value representing a spectator (matches the wire protocol).
 const int spectatorPlayerIndex = 0xFF;
+
+/// Spectator delays the host can pick, in frames (0 = live).
+const List<int> spectatorDelayFrames = [0, 60, 180, 300];
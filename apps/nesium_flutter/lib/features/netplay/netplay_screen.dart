@@ -11,10 +11,11 @@ import '../../l10n/app_localizations.dart';
 import '../../logging/app_logger.dart';
 import '../../bridge/api/netplay.dart';
 import 'netplay_constants.dart';
+import 'netplay_state.dart';
 import '../../persistence/app_storage.dart';
 import '../../persistence/keys.dart';
 
-enum _NetplayTransportOption { auto, tcp, quic }
+enum _NetplayTransportOption { auto, tcp, tls, quic }
 
 class NetplayScreen extends ConsumerStatefulWidget {
   const NetplayScreen({super.key});
@@ -28,6 +29,7 @@ class _NetplayScreenState extends ConsumerState<NetplayScreen> {
   final _pinnedFingerprintController = TextEditingController();
   final _playerNameController = TextEditingController(text: 'Player');
   final _roomCodeController = TextEditingController();
+  final _roomPasswordController = TextEditingController();
   final _p2pRoomCodeController = TextEditingController();
   final _p2pServerAddrController = TextEditingController(
     text: 'nesium.mikai.link:5233',
@@ -51,6 +53,7 @@ class _NetplayScreenState extends ConsumerState<NetplayScreen> {
     _pinnedFingerprintController.dispose();
     _playerNameController.dispose();
     _roomCodeController.dispose();
+    _roomPasswordController.dispose();
     _p2pRoomCodeController.dispose();
     _p2pServerAddrController.dispose();
     super.dispose();
@@ -174,6 +177,22 @@ class _NetplayScreenState extends ConsumerState<NetplayScreen> {
         case _NetplayTransportOption.tcp:
           await netplayConnect(serverAddr: serverAddr, playerName: playerName);
           break;
+        case _NetplayTransportOption.tls:
+          if (usePinned) {
+            await netplayConnectTlsPinned(
+              serverAddr: serverAddr,
+              serverName: serverName,
+              pinnedSha256Fingerprint: pinned,
+              playerName: playerName,
+            );
+          } else {
+            await netplayConnectTls(
+              serverAddr: serverAddr,
+              serverName: serverName,
+              playerName: playerName,
+            );
+          }
+          break;
         case _NetplayTransportOption.quic:
           if (usePinned) {
             await netplayConnectQuicPinned(
@@ -272,6 +291,8 @@ class _NetplayScreenState extends ConsumerState<NetplayScreen> {
         return l10n.netplayTransportTcp;
       case NetplayTransport.quic:
         return l10n.netplayTransportQuic;
+      case NetplayTransport.webRtc:
+        return l10n.netplayTransportWebRtc;
     }
   }
 
@@ -288,10 +309,16 @@ class _NetplayScreenState extends ConsumerState<NetplayScreen> {
     }
   }
 
+  /// Empty means a public room.
+  String? get _roomPassword {
+    final password = _roomPasswordController.text;
+    return password.isEmpty ? null : password;
+  }
+
   Future<void> _createRoom() async {
     final l10n = AppLocalizations.of(context)!;
     try {
-      await netplayCreateRoom();
+      await netplayCreateRoom(password: _roomPassword);
 
       // If we have a ROM loaded, send it to the server so late joiners can sync
       final nesState = ref.read(nesControllerProvider);
@@ -324,7 +351,7 @@ class _NetplayScreenState extends ConsumerState<NetplayScreen> {
       return;
     }
     try {
-      await netplayJoinRoom(roomCode: code);
+      await netplayJoinRoom(roomCode: code, password: _roomPassword);
     } catch (e) {
       if (mounted) {
         ScaffoldMessenger.of(context).showSnackBar(
@@ -334,6 +361,52 @@ class _NetplayScreenState extends ConsumerState<NetplayScreen> {
     }
   }
 
+  Future<void> _refreshRooms() async {
+    final l10n = AppLocalizations.of(context)!;
+    try {
+      await netplayListRooms();
+    } catch (e) {
+      if (mounted) {
+        ScaffoldMessenger.of(context).showSnackBar(
+          SnackBar(content: Text(l10n.netplayListRoomsFailed(e.toString()))),
+        );
+      }
+    }
+  }
+
+  Future<void> _quickMatch() async {
+    final l10n = AppLocalizations.of(context)!;
+    try {
+      await netplayQuickMatch();
+    } catch (e) {
+      if (mounted) {
+        ScaffoldMessenger.of(context).showSnackBar(
+          SnackBar(content: Text(l10n.netplayJoinRoomFailed(e.toString()))),
+        );
+      }
+    }
+  }
+
+  Future<void> _joinListedRoom(NetplayRoomSummary room) async {
+    _roomCodeController.text = room.roomCode.toString();
+    await _joinRoom();
+  }
+
+  Future<void> _setSpectatorDelay(int frames) async {
+    final l10n = AppLocalizations.of(context)!;
+    try {
+      await netplaySetSpectatorDelay(frames: frames);
+    } catch (e) {
+      if (mounted) {
+        ScaffoldMessenger.of(context).showSnackBar(
+          SnackBar(
+            content: Text(l10n.netplaySpectatorDelayFailed(e.toString())),
+          ),
+        );
+      }
+    }
+  }
+
   Future<void> _switchRole(int role) async {
     final l10n = AppLocalizations.of(context)!;
     try {
@@ -461,6 +534,10 @@ class _NetplayScreenState extends ConsumerState<NetplayScreen> {
     final state = status?.state ?? NetplayState.disconnected;
     final theme = Theme.of(context);
     final colorScheme = theme.colorScheme;
+    final serverStats = ref
+        .watch(netplayNetworkStatsProvider)
+        .where((p) => p.peerClientId == 0)
+        .firstOrNull;
 
     Color statusColor;
     String statusText;
@@ -547,6 +624,21 @@ class _NetplayScreenState extends ConsumerState<NetplayScreen> {
                       ],
                     ],
                   ),
+                  if (serverStats != null &&
+                      state != NetplayState.disconnected &&
+                      state != NetplayState.connecting) ...[
+                    const SizedBox(height: 4),
+                    Text(
+                      l10n.netplayServerLatency(
+                        serverStats.rttMs,
+                        serverStats.jitterMs,
+                        serverStats.lossPercent,
+                      ),
+                      style: theme.textTheme.bodySmall?.copyWith(
+                        color: theme.colorScheme.onSurfaceVariant,
+                      ),
+                    ),
+                  ],
                   if (status?.tcpFallbackFromQuic == true) ...[
                     const SizedBox(height: 4),
                     Text(
@@ -601,6 +693,10 @@ class _NetplayScreenState extends ConsumerState<NetplayScreen> {
                       value: _NetplayTransportOption.tcp,
                       label: l10n.netplayTransportTcp,
                     ),
+                    DropdownMenuEntry(
+                      value: _NetplayTransportOption.tls,
+                      label: l10n.netplayTransportTls,
+                    ),
                     DropdownMenuEntry(
                       value: _NetplayTransportOption.quic,
                       label: l10n.netplayTransportQuic,
@@ -625,7 +721,9 @@ class _NetplayScreenState extends ConsumerState<NetplayScreen> {
                     controller: _pinnedFingerprintController,
                     enabled: !directConnectDisabled,
                     decoration: _roundedInputDecoration(
-                      labelText: l10n.netplayQuicFingerprintLabel,
+                      labelText: _transport == _NetplayTransportOption.tls
+                          ? l10n.netplayTlsFingerprintLabel
+                          : l10n.netplayQuicFingerprintLabel,
                       hintText: l10n.netplayQuicFingerprintHint,
                       prefixIcon: const Icon(Icons.key_rounded),
                       suffixIcon: IconButton(
@@ -637,7 +735,9 @@ class _NetplayScreenState extends ConsumerState<NetplayScreen> {
                   ),
                   const SizedBox(height: 8),
                   Text(
-                    l10n.netplayQuicFingerprintHelper,
+                    _transport == _NetplayTransportOption.tls
+                        ? l10n.netplayTlsFingerprintHelper
+                        : l10n.netplayQuicFingerprintHelper,
                     style: Theme.of(context).textTheme.bodySmall,
                   ),
                 ],
@@ -747,6 +847,16 @@ class _NetplayScreenState extends ConsumerState<NetplayScreen> {
     return Column(
       crossAxisAlignment: CrossAxisAlignment.stretch,
       children: [
+        TextField(
+          controller: _roomPasswordController,
+          decoration: _roundedInputDecoration(
+            labelText: l10n.netplayRoomPassword,
+            hintText: l10n.netplayRoomPasswordHint,
+            prefixIcon: const Icon(Icons.lock_outline_rounded),
+          ),
+          obscureText: true,
+        ),
+        const SizedBox(height: 16),
         FilledButton.icon(
           onPressed: _createRoom,
           icon: const Icon(Icons.add_circle_outline_rounded),
@@ -783,11 +893,74 @@ class _NetplayScreenState extends ConsumerState<NetplayScreen> {
           icon: const Icon(Icons.meeting_room_rounded),
           label: Text(l10n.netplayJoinRoom),
         ),
+        const SizedBox(height: 24),
+        _buildPublicRooms(l10n),
+      ],
+    );
+  }
+
+  Widget _buildPublicRooms(AppLocalizations l10n) {
+    final theme = Theme.of(context);
+    final rooms = ref.watch(netplayRoomListProvider);
+
+    return Column(
+      crossAxisAlignment: CrossAxisAlignment.stretch,
+      children: [
+        Row(
+          children: [
+            Expanded(
+              child: Text(
+                l10n.netplayPublicRooms,
+                style: theme.textTheme.titleSmall,
+              ),
+            ),
+            IconButton(
+              tooltip: l10n.netplayRefreshRooms,
+              onPressed: _refreshRooms,
+              icon: const Icon(Icons.refresh_rounded),
+            ),
+          ],
+        ),
+        if (rooms.isEmpty)
+          Padding(
+            padding: const EdgeInsets.symmetric(vertical: 8),
+            child: Text(
+              l10n.netplayNoPublicRooms,
+              style: theme.textTheme.bodySmall?.copyWith(
+                color: theme.colorScheme.onSurfaceVariant,
+              ),
+            ),
+          )
+        else
+          for (final room in rooms)
+            ListTile(
+              contentPadding: EdgeInsets.zero,
+              leading: const Icon(Icons.public_rounded),
+              title: Text(room.name.isEmpty ? '#${room.roomCode}' : room.name),
+              subtitle: Text(
+                l10n.netplayRoomSummary(
+                  room.playerCount,
+                  room.openSlots,
+                  room.spectatorCount,
+                ),
+              ),
+              trailing: room.region.isEmpty ? null : Text(room.region),
+              enabled: room.openSlots > 0,
+              onTap: () => _joinListedRoom(room),
+            ),
+        const SizedBox(height: 8),
+        OutlinedButton.icon(
+          onPressed: _quickMatch,
+          icon: const Icon(Icons.bolt_rounded),
+          label: Text(l10n.netplayQuickMatch),
+        ),
       ],
     );
   }
 
   Widget _buildInRoomInfo(AppLocalizations l10n, NetplayStatus status) {
+    final spectators = ref.watch(netplaySpectatorInfoProvider);
+
     return Card(
       elevation: 0,
       color: Theme.of(context).colorScheme.surfaceContainerLow,
@@ -857,6 +1030,32 @@ class _NetplayScreenState extends ConsumerState<NetplayScreen> {
               _transportName(l10n, status.transport),
               icon: Icons.swap_horiz_rounded,
             ),
+            const Padding(
+              padding: EdgeInsets.symmetric(vertical: 12),
+              child: Divider(),
+            ),
+            _buildInfoRow(
+              l10n.netplaySpectatorCount,
+              (spectators?.count ?? 0).toString(),
+              icon: Icons.visibility_rounded,
+            ),
+            const SizedBox(height: 12),
+            AnimatedDropdownMenu<int>(
+              labelText: l10n.netplaySpectatorDelayLabel,
+              value: spectatorDelayFrames.contains(spectators?.delayFrames)
+                  ? spectators!.delayFrames
+                  : 0,
+              entries: [
+                for (final frames in spectatorDelayFrames)
+                  DropdownMenuEntry(
+                    value: frames,
+                    label: frames == 0
+                        ? l10n.netplaySpectatorDelayLive
+                        : l10n.netplaySpectatorDelaySeconds(frames ~/ 60),
+                  ),
+              ],
+              onSelected: (value) => _setSpectatorDelay(value),
+            ),
           ],
         ),
       ),
@@ -1,5 +1,10 @@
+import 'dart:convert';
+import 'dart:typed_data';
+
 import 'package:flutter_riverpod/flutter_riverpod.dart';
 import '../../bridge/api/netplay.dart';
+import '../../persistence/app_storage.dart';
+import '../../persistence/keys.dart';
 
 final netplayStatusProvider = StreamProvider<NetplayStatus>((ref) {
   return netplayStatusStream();
@@ -26,3 +31,61 @@ final netplayProvider = Provider<NetplayAppState>((ref) {
       );
   return NetplayAppState(status: status);
 });
+
+/// Latest link quality report from the session; the server is peer id 0.
+class NetplayNetworkStatsNotifier extends Notifier<List<NetplayPeerStats>> {
+  @override
+  List<NetplayPeerStats> build() => const [];
+
+  void set(List<NetplayPeerStats> peers) => state = peers;
+}
+
+final netplayNetworkStatsProvider =
+    NotifierProvider<NetplayNetworkStatsNotifier, List<NetplayPeerStats>>(
+      NetplayNetworkStatsNotifier.new,
+    );
+
+/// Public rooms from the most recent `netplayListRooms` answer.
+class NetplayRoomListNotifier extends Notifier<List<NetplayRoomSummary>> {
+  @override
+  List<NetplayRoomSummary> build() => const [];
+
+  void set(List<NetplayRoomSummary> rooms) => state = rooms;
+}
+
+final netplayRoomListProvider =
+    NotifierProvider<NetplayRoomListNotifier, List<NetplayRoomSummary>>(
+      NetplayRoomListNotifier.new,
+    );
+
+class NetplaySpectatorInfo {
+  const NetplaySpectatorInfo({required this.count, required this.delayFrames});
+
+  final int count;
+
+  /// How far spectators trail live play, in frames (0 = live).
+  final int delayFrames;
+}
+
+class NetplaySpectatorInfoNotifier extends Notifier<NetplaySpectatorInfo?> {
+  @override
+  NetplaySpectatorInfo? build() => null;
+
+  void set(NetplaySpectatorInfo info) => state = info;
+}
+
+final netplaySpectatorInfoProvider =
+    NotifierProvider<NetplaySpectatorInfoNotifier, NetplaySpectatorInfo?>(
+      NetplaySpectatorInfoNotifier.new,
+    );
+
+/// Stores the identity token a server issued so later sessions keep it.
+Future<void> persistNetplayUserToken(AppStorage storage, Uint8List token) =>
+    storage.put(StorageKeys.settingsNetplayUserToken, base64Encode(token));
+
+/// Hands a previously stored identity token back to the runtime.
+Future<void> restoreNetplayUserToken(AppStorage storage) async {
+  final stored = storage.get(StorageKeys.settingsNetplayUserToken);
+  if (stored is! String || stored.isEmpty) return;
+  await netplaySetUserToken(token: base64Decode(stored));
+}
@@ -2,12 +2,15 @@ import 'dart:async';
 import 'dart:math' as math;
 
 import 'package:flutter/foundation.dart';
+import 'package:flutter/gestures.dart' show PointerDeviceKind;
 import 'package:flutter/material.dart';
 import 'package:flutter/services.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
 
 import '../../domain/nes_controller.dart';
+import '../../platform/nes_input.dart' as nes_input;
 import '../../platform/nes_video.dart' show VideoFilter;
+import '../controls/zapper_settings.dart';
 import '../settings/video_settings.dart';
 import '../settings/windows_shader_settings.dart';
 import '../settings/apple_shader_settings.dart';
@@ -208,16 +211,60 @@ class _NesScreenViewState extends ConsumerState<NesScreenView> with RouteAware {
     );
   }
 
-  Widget _wrapWithMouseRegion(Widget child) {
+  Widget _wrapWithMouseRegion(Widget child, {required bool zapper}) {
     return MouseRegion(
-      cursor: _cursorHidden ? SystemMouseCursors.none : MouseCursor.defer,
+      cursor: zapper
+          ? SystemMouseCursors.precise
+          : _cursorHidden
+          ? SystemMouseCursors.none
+          : MouseCursor.defer,
       onEnter: (_) => _showCursorAndArmTimer(),
       onHover: (_) => _showCursorAndArmTimer(),
-      onExit: (_) => _showCursorAndCancelTimer(),
+      onExit: (_) {
+        _showCursorAndCancelTimer();
+        if (zapper) _clearZapperAim();
+      },
       child: child,
     );
   }
 
+  /// Feeds pointer/touch input on the game view to the Zapper.
+  Widget _wrapWithZapper(Widget child, Size viewport) {
+    return Listener(
+      onPointerDown: (event) {
+        _aimZapper(event.localPosition, viewport);
+        _setZapperTrigger(true);
+      },
+      onPointerMove: (event) => _aimZapper(event.localPosition, viewport),
+      onPointerHover: (event) => _aimZapper(event.localPosition, viewport),
+      onPointerUp: (event) {
+        _setZapperTrigger(false);
+        // A lifted finger no longer points at the screen.
+        if (event.kind == PointerDeviceKind.touch) _clearZapperAim();
+      },
+      onPointerCancel: (_) {
+        _setZapperTrigger(false);
+        _clearZapperAim();
+      },
+      child: child,
+    );
+  }
+
+  void _aimZapper(Offset local, Size viewport) {
+    // Map the view position into NES pixels (256x240).
+    final x = (local.dx / viewport.width * NesScreenView.nesWidth).floor();
+    final y = (local.dy / viewport.height * NesScreenView.nesHeight).floor();
+    unawaited(nes_input.setZapperPointer(x: x, y: y).catchError((_) {}));
+  }
+
+  void _clearZapperAim() {
+    unawaited(nes_input.clearZapperPointer().catchError((_) {}));
+  }
+
+  void _setZapperTrigger(bool pulled) {
+    unawaited(nes_input.setZapperTrigger(pulled: pulled).catchError((_) {}));
+  }
+
   Widget _buildAndroidContent(
     Size viewport,
     bool hasRom,
@@ -435,6 +482,7 @@ class _NesScreenViewState extends ConsumerState<NesScreenView> with RouteAware {
     );
     final settings = ref.watch(videoSettingsProvider);
     final androidBackend = ref.watch(androidVideoBackendSettingsProvider);
+    final zapper = hasRom && ref.watch(zapperSettingsProvider).enabled;
     final integerScaling = settings.integerScaling;
     final aspectRatio = settings.aspectRatio;
 
@@ -466,7 +514,8 @@ class _NesScreenViewState extends ConsumerState<NesScreenView> with RouteAware {
               content = _buildTextureContent(viewport, hasRom, settings);
             }
 
-            return _wrapWithMouseRegion(content);
+            if (zapper) content = _wrapWithZapper(content, viewport);
+            return _wrapWithMouseRegion(content, zapper: zapper);
           },
         ),
       ),
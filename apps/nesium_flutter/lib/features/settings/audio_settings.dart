@@ -0,0 +1,165 @@
+import 'dart:async';
+
+import 'package:flutter/foundation.dart';
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+
+import '../../platform/nes_audio.dart' as nes_audio;
+import '../../persistence/app_storage.dart';
+import '../../persistence/keys.dart';
+import '../../persistence/storage_codec.dart';
+import '../../persistence/storage_key.dart';
+import '../../logging/app_logger.dart';
+
+final StorageKey<JsonMap> _audioSettingsKey = StorageKey(
+  StorageKeys.settingsAudio,
+  jsonMapStringCodec(storageKey: StorageKeys.settingsAudio),
+);
+
+/// Expansion chips in the order they appear in [nes_audio.ExpansionBalance].
+enum ExpansionChip { fds, mmc5, vrc6, vrc7, namco163, sunsoft5B }
+
+extension ExpansionChipLabel on ExpansionChip {
+  String get label => switch (this) {
+    ExpansionChip.fds => 'FDS',
+    ExpansionChip.mmc5 => 'MMC5',
+    ExpansionChip.vrc6 => 'VRC6',
+    ExpansionChip.vrc7 => 'VRC7',
+    ExpansionChip.namco163 => 'Namco 163',
+    ExpansionChip.sunsoft5B => 'Sunsoft 5B',
+  };
+}
+
+@immutable
+class AudioSettings {
+  const AudioSettings({required this.stereoPreset, required this.balance});
+
+  final nes_audio.StereoPreset stereoPreset;
+
+  /// Per-chip levels in `[-1.0, 1.0]`, indexed by [ExpansionChip].
+  final List<double> balance;
+
+  double balanceOf(ExpansionChip chip) => balance[chip.index];
+
+  nes_audio.ExpansionBalance toExpansionBalance() => nes_audio.ExpansionBalance(
+    fds: balanceOf(ExpansionChip.fds),
+    mmc5: balanceOf(ExpansionChip.mmc5),
+    vrc6: balanceOf(ExpansionChip.vrc6),
+    vrc7: balanceOf(ExpansionChip.vrc7),
+    namco163: balanceOf(ExpansionChip.namco163),
+    sunsoft5B: balanceOf(ExpansionChip.sunsoft5B),
+  );
+
+  AudioSettings copyWith({
+    nes_audio.StereoPreset? stereoPreset,
+    List<double>? balance,
+  }) => AudioSettings(
+    stereoPreset: stereoPreset ?? this.stereoPreset,
+    balance: balance ?? this.balance,
+  );
+
+  static final AudioSettings defaults = AudioSettings(
+    stereoPreset: nes_audio.StereoPreset.mono,
+    balance: List.filled(ExpansionChip.values.length, 0.0),
+  );
+}
+
+class AudioSettingsController extends Notifier<AudioSettings> {
+  @override
+  AudioSettings build() {
+    final storage = ref.read(appStorageProvider);
+    final subscription = storage.onKeyChanged.listen((event) {
+      if (event.key == StorageKeys.settingsAudio) {
+        unawaitedLogged(
+          _reloadFromStorage(),
+          logger: 'audio_settings',
+          message: 'Reloading audio settings from stream',
+        );
+      }
+    });
+    ref.onDispose(() => subscription.cancel());
+
+    final settings =
+        _audioFromStorage(storage.read(_audioSettingsKey)) ??
+        AudioSettings.defaults;
+
+    scheduleMicrotask(applyToRuntime);
+
+    return settings;
+  }
+
+  Future<void> _reloadFromStorage() async {
+    final value = ref.read(appStorageProvider).read(_audioSettingsKey);
+    state = _audioFromStorage(value) ?? AudioSettings.defaults;
+    applyToRuntime();
+  }
+
+  void applyToRuntime() {
+    // Best-effort apply; callers may invoke before the runtime is initialized.
+    unawaited(
+      nes_audio
+          .setAudioStereo(
+            preset: state.stereoPreset,
+            balance: state.toExpansionBalance(),
+          )
+          .catchError((_) {}),
+    );
+  }
+
+  void setStereoPreset(nes_audio.StereoPreset preset) {
+    if (preset == state.stereoPreset) return;
+    state = state.copyWith(stereoPreset: preset);
+    _persist(state);
+    applyToRuntime();
+  }
+
+  void setBalance(ExpansionChip chip, double value) {
+    final next = value.clamp(-1.0, 1.0);
+    if (next == state.balanceOf(chip)) return;
+    final balance = List<double>.of(state.balance)..[chip.index] = next;
+    state = state.copyWith(balance: balance);
+    _persist(state);
+    applyToRuntime();
+  }
+
+  void _persist(AudioSettings value) {
+    final payload = Map<String, dynamic>.from(_audioToStorage(value));
+    unawaitedLogged(
+      Future<void>.sync(
+        () => ref.read(appStorageProvider).write(_audioSettingsKey, payload),
+      ),
+      message: 'Persist audio settings',
+      logger: 'audio_settings',
+    );
+  }
+}
+
+final audioSettingsProvider =
+    NotifierProvider<AudioSettingsController, AudioSettings>(
+      AudioSettingsController.new,
+    );
+
+Map<String, Object?> _audioToStorage(AudioSettings value) => <String, Object?>{
+  'stereoPreset': value.stereoPreset.name,
+  'balance': {
+    for (final chip in ExpansionChip.values) chip.name: value.balanceOf(chip),
+  },
+};
+
+AudioSettings? _audioFromStorage(Map<String, dynamic>? map) {
+  if (map == null) return null;
+
+  final defaults = AudioSettings.defaults;
+  final preset = nes_audio.StereoPreset.values.firstWhere(
+    (p) => p.name == map['stereoPreset'],
+    orElse: () => defaults.stereoPreset,
+  );
+  final stored = map['balance'];
+  final balance = [
+    for (final chip in ExpansionChip.values)
+      switch (stored is Map ? stored[chip.name] : null) {
+        final num v => v.toDouble().clamp(-1.0, 1.0),
+        _ => defaults.balanceOf(chip),
+      },
+  ];
+  return AudioSettings(stereoPreset: preset, balance: balance);
+}
@@ -35,6 +35,7 @@ sealed class EmulationSettings with _$EmulationSettings {
     @Default(60) int rewindSeconds,
     @Default(100) int rewindSpeedPercent,
     @Default(true) bool showEmulationStatusOverlay,
+    @Default(false) bool clonePpu,
   }) = _EmulationSettings;
 
   factory EmulationSettings.defaults() =>
@@ -128,6 +129,11 @@ class EmulationSettingsController extends Notifier<EmulationSettings> {
       message: 'setIntegerFpsMode (apply)',
       logger: 'emulation_settings',
     );
+    unawaitedLogged(
+      nes_emulation.setClonePpu(enabled: state.clonePpu),
+      message: 'setClonePpu (apply)',
+      logger: 'emulation_settings',
+    );
     _applyFastForwardSpeed();
     _applyRewindConfig();
     _applyRewindSpeed();
@@ -207,6 +213,17 @@ class EmulationSettingsController extends Notifier<EmulationSettings> {
     _persist(state);
   }
 
+  void setClonePpu(bool enabled) {
+    if (enabled == state.clonePpu) return;
+    state = state.copyWith(clonePpu: enabled);
+    unawaitedLogged(
+      nes_emulation.setClonePpu(enabled: enabled),
+      message: 'setClonePpu',
+      logger: 'emulation_settings',
+    );
+    _persist(state);
+  }
+
   void _applyRewindConfig() {
     unawaitedLogged(
       nes_emulation.setRewindConfig(
@@ -15,7 +15,7 @@ T _$identity<T>(T value) => value;
 /// @nodoc
 mixin _$EmulationSettings implements DiagnosticableTreeMixin {
 
- bool get integerFpsMode; bool get pauseInBackground; bool get autoSaveEnabled; int get autoSaveIntervalInMinutes; int get quickSaveSlot; int get fastForwardSpeedPercent; bool get rewindEnabled; int get rewindSeconds; int get rewindSpeedPercent; bool get showEmulationStatusOverlay;
+ bool get integerFpsMode; bool get pauseInBackground; bool get autoSaveEnabled; int get autoSaveIntervalInMinutes; int get quickSaveSlot; int get fastForwardSpeedPercent; bool get rewindEnabled; int get rewindSeconds; int get rewindSpeedPercent; bool get showEmulationStatusOverlay; bool get clonePpu;
 /// Create a copy of EmulationSettings
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
@@ -29,21 +29,21 @@ $EmulationSettingsCopyWith<EmulationSettings> get copyWith => _$EmulationSetting
 void debugFillProperties(DiagnosticPropertiesBuilder properties) {
   properties
     ..add(DiagnosticsProperty('type', 'EmulationSettings'))
-    ..add(DiagnosticsProperty('integerFpsMode', integerFpsMode))..add(DiagnosticsProperty('pauseInBackground', pauseInBackground))..add(DiagnosticsProperty('autoSaveEnabled', autoSaveEnabled))..add(DiagnosticsProperty('autoSaveIntervalInMinutes', autoSaveIntervalInMinutes))..add(DiagnosticsProperty('quickSaveSlot', quickSaveSlot))..add(DiagnosticsProperty('fastForwardSpeedPercent', fastForwardSpeedPercent))..add(DiagnosticsProperty('rewindEnabled', rewindEnabled))..add(DiagnosticsProperty('rewindSeconds', rewindSeconds))..add(DiagnosticsProperty('rewindSpeedPercent', rewindSpeedPercent))..add(DiagnosticsProperty('showEmulationStatusOverlay', showEmulationStatusOverlay));
+    ..add(DiagnosticsProperty('integerFpsMode', integerFpsMode))..add(DiagnosticsProperty('pauseInBackground', pauseInBackground))..add(DiagnosticsProperty('autoSaveEnabled', autoSaveEnabled))..add(DiagnosticsProperty('autoSaveIntervalInMinutes', autoSaveIntervalInMinutes))..add(DiagnosticsProperty('quickSaveSlot', quickSaveSlot))..add(DiagnosticsProperty('fastForwardSpeedPercent', fastForwardSpeedPercent))..add(DiagnosticsProperty('rewindEnabled', rewindEnabled))..add(DiagnosticsProperty('rewindSeconds', rewindSeconds))..add(DiagnosticsProperty('rewindSpeedPercent', rewindSpeedPercent))..add(DiagnosticsProperty('showEmulationStatusOverlay', showEmulationStatusOverlay))..add(DiagnosticsProperty('clonePpu', clonePpu));
 }
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmulationSettings&&(identical(other.integerFpsMode, integerFpsMode) || other.integerFpsMode == integerFpsMode)&&(identical(other.pauseInBackground, pauseInBackground) || other.pauseInBackground == pauseInBackground)&&(identical(other.autoSaveEnabled, autoSaveEnabled) || other.autoSaveEnabled == autoSaveEnabled)&&(identical(other.autoSaveIntervalInMinutes, autoSaveIntervalInMinutes) || other.autoSaveIntervalInMinutes == autoSaveIntervalInMinutes)&&(identical(other.quickSaveSlot, quickSaveSlot) || other.quickSaveSlot == quickSaveSlot)&&(identical(other.fastForwardSpeedPercent, fastForwardSpeedPercent) || other.fastForwardSpeedPercent == fastForwardSpeedPercent)&&(identical(other.rewindEnabled, rewindEnabled) || other.rewindEnabled == rewindEnabled)&&(identical(other.rewindSeconds, rewindSeconds) || other.rewindSeconds == rewindSeconds)&&(identical(other.rewindSpeedPercent, rewindSpeedPercent) || other.rewindSpeedPercent == rewindSpeedPercent)&&(identical(other.showEmulationStatusOverlay, showEmulationStatusOverlay) || other.showEmulationStatusOverlay == showEmulationStatusOverlay));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmulationSettings&&(identical(other.integerFpsMode, integerFpsMode) || other.integerFpsMode == integerFpsMode)&&(identical(other.pauseInBackground, pauseInBackground) || other.pauseInBackground == pauseInBackground)&&(identical(other.autoSaveEnabled, autoSaveEnabled) || other.autoSaveEnabled == autoSaveEnabled)&&(identical(other.autoSaveIntervalInMinutes, autoSaveIntervalInMinutes) || other.autoSaveIntervalInMinutes == autoSaveIntervalInMinutes)&&(identical(other.quickSaveSlot, quickSaveSlot) || other.quickSaveSlot == quickSaveSlot)&&(identical(other.fastForwardSpeedPercent, fastForwardSpeedPercent) || other.fastForwardSpeedPercent == fastForwardSpeedPercent)&&(identical(other.rewindEnabled, rewindEnabled) || other.rewindEnabled == rewindEnabled)&&(identical(other.rewindSeconds, rewindSeconds) || other.rewindSeconds == rewindSeconds)&&(identical(other.rewindSpeedPercent, rewindSpeedPercent) || other.rewindSpeedPercent == rewindSpeedPercent)&&(identical(other.showEmulationStatusOverlay, showEmulationStatusOverlay) || other.showEmulationStatusOverlay == showEmulationStatusOverlay)&&(identical(other.clonePpu, clonePpu) || other.clonePpu == clonePpu));
 }
 
 @JsonKey(includeFromJson: false, includeToJson: false)
 @override
-int get hashCode => Object.hash(runtimeType,integerFpsMode,pauseInBackground,autoSaveEnabled,autoSaveIntervalInMinutes,quickSaveSlot,fastForwardSpeedPercent,rewindEnabled,rewindSeconds,rewindSpeedPercent,showEmulationStatusOverlay);
+int get hashCode => Object.hash(runtimeType,integerFpsMode,pauseInBackground,autoSaveEnabled,autoSaveIntervalInMinutes,quickSaveSlot,fastForwardSpeedPercent,rewindEnabled,rewindSeconds,rewindSpeedPercent,showEmulationStatusOverlay,clonePpu);
 
 @override
 String toString({ DiagnosticLevel minLevel = DiagnosticLevel.info }) {
-  return 'EmulationSettings(integerFpsMode: $integerFpsMode, pauseInBackground: $pauseInBackground, autoSaveEnabled: $autoSaveEnabled, autoSaveIntervalInMinutes: $autoSaveIntervalInMinutes, quickSaveSlot: $quickSaveSlot, fastForwardSpeedPercent: $fastForwardSpeedPercent, rewindEnabled: $rewindEnabled, rewindSeconds: $rewindSeconds, rewindSpeedPercent: $rewindSpeedPercent, showEmulationStatusOverlay: $showEmulationStatusOverlay)';
+  return 'EmulationSettings(integerFpsMode: $integerFpsMode, pauseInBackground: $pauseInBackground, autoSaveEnabled: $autoSaveEnabled, autoSaveIntervalInMinutes: $autoSaveIntervalInMinutes, quickSaveSlot: $quickSaveSlot, fastForwardSpeedPercent: $fastForwardSpeedPercent, rewindEnabled: $rewindEnabled, rewindSeconds: $rewindSeconds, rewindSpeedPercent: $rewindSpeedPercent, showEmulationStatusOverlay: $showEmulationStatusOverlay, clonePpu: $clonePpu)';
 }
 
 
@@ -54,7 +54,7 @@ abstract mixin class $EmulationSettingsCopyWith<$Res>  {
   factory $EmulationSettingsCopyWith(EmulationSettings value, $Res Function(EmulationSettings) _then) = _$EmulationSettingsCopyWithImpl;
 @useResult
 $Res call({
- bool integerFpsMode, bool pauseInBackground, bool autoSaveEnabled, int autoSaveIntervalInMinutes, int quickSaveSlot, int fastForwardSpeedPercent, bool rewindEnabled, int rewindSeconds, int rewindSpeedPercent, bool showEmulationStatusOverlay
+ bool integerFpsMode, bool pauseInBackground, bool autoSaveEnabled, int autoSaveIntervalInMinutes, int quickSaveSlot, int fastForwardSpeedPercent, bool rewindEnabled, int rewindSeconds, int rewindSpeedPercent, bool showEmulationStatusOverlay, bool clonePpu
 });
 
 
@@ -71,7 +71,7 @@ class _$EmulationSettingsCopyWithImpl<$Res>
 
 /// Create a copy of EmulationSettings
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? integerFpsMode = null,Object? pauseInBackground = null,Object? autoSaveEnabled = null,Object? autoSaveIntervalInMinutes = null,Object? quickSaveSlot = null,Object? fastForwardSpeedPercent = null,Object? rewindEnabled = null,Object? rewindSeconds = null,Object? rewindSpeedPercent = null,Object? showEmulationStatusOverlay = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? integerFpsMode = null,Object? pauseInBackground = null,Object? autoSaveEnabled = null,Object? autoSaveIntervalInMinutes = null,Object? quickSaveSlot = null,Object? fastForwardSpeedPercent = null,Object? rewindEnabled = null,Object? rewindSeconds = null,Object? rewindSpeedPercent = null,Object? showEmulationStatusOverlay = null,Object? clonePpu = null,}) {
   return _then(_self.copyWith(
 integerFpsMode: null == integerFpsMode ? _self.integerFpsMode : integerFpsMode // ignore: cast_nullable_to_non_nullable
 as bool,pauseInBackground: null == pauseInBackground ? _self.pauseInBackground : pauseInBackground // ignore: cast_nullable_to_non_nullable
@@ -83,6 +83,7 @@ as int,rewindEnabled: null == rewindEnabled ? _self.rewindEnabled : rewindEnable
 as bool,rewindSeconds: null == rewindSeconds ? _self.rewindSeconds : rewindSeconds // ignore: cast_nullable_to_non_nullable
 as int,rewindSpeedPercent: null == rewindSpeedPercent ? _self.rewindSpeedPercent : rewindSpeedPercent // ignore: cast_nullable_to_non_nullable
 as int,showEmulationStatusOverlay: null == showEmulationStatusOverlay ? _self.showEmulationStatusOverlay : showEmulationStatusOverlay // ignore: cast_nullable_to_non_nullable
+as bool,clonePpu: null == clonePpu ? _self.clonePpu : clonePpu // ignore: cast_nullable_to_non_nullable
 as bool,
   ));
 }
@@ -165,10 +166,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( bool integerFpsMode,  bool pauseInBackground,  bool autoSaveEnabled,  int autoSaveIntervalInMinutes,  int quickSaveSlot,  int fastForwardSpeedPercent,  bool rewindEnabled,  int rewindSeconds,  int rewindSpeedPercent,  bool showEmulationStatusOverlay)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( bool integerFpsMode,  bool pauseInBackground,  bool autoSaveEnabled,  int autoSaveIntervalInMinutes,  int quickSaveSlot,  int fastForwardSpeedPercent,  bool rewindEnabled,  int rewindSeconds,  int rewindSpeedPercent,  bool showEmulationStatusOverlay,  bool clonePpu)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case _EmulationSettings() when $default != null:
-return $default(_that.integerFpsMode,_that.pauseInBackground,_that.autoSaveEnabled,_that.autoSaveIntervalInMinutes,_that.quickSaveSlot,_that.fastForwardSpeedPercent,_that.rewindEnabled,_that.rewindSeconds,_that.rewindSpeedPercent,_that.showEmulationStatusOverlay);case _:
+return $default(_that.integerFpsMode,_that.pauseInBackground,_that.autoSaveEnabled,_that.autoSaveIntervalInMinutes,_that.quickSaveSlot,_that.fastForwardSpeedPercent,_that.rewindEnabled,_that.rewindSeconds,_that.rewindSpeedPercent,_that.showEmulationStatusOverlay,_that.clonePpu);case _:
   return orElse();
 
 }
@@ -186,10 +187,10 @@ return $default(_that.integerFpsMode,_that.pauseInBackground,_that.autoSaveEnabl
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( bool integerFpsMode,  bool pauseInBackground,  bool autoSaveEnabled,  int autoSaveIntervalInMinutes,  int quickSaveSlot,  int fastForwardSpeedPercent,  bool rewindEnabled,  int rewindSeconds,  int rewindSpeedPercent,  bool showEmulationStatusOverlay)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( bool integerFpsMode,  bool pauseInBackground,  bool autoSaveEnabled,  int autoSaveIntervalInMinutes,  int quickSaveSlot,  int fastForwardSpeedPercent,  bool rewindEnabled,  int rewindSeconds,  int rewindSpeedPercent,  bool showEmulationStatusOverlay,  bool clonePpu)  $default,) {final _that = this;
 switch (_that) {
 case _EmulationSettings():
-return $default(_that.integerFpsMode,_that.pauseInBackground,_that.autoSaveEnabled,_that.autoSaveIntervalInMinutes,_that.quickSaveSlot,_that.fastForwardSpeedPercent,_that.rewindEnabled,_that.rewindSeconds,_that.rewindSpeedPercent,_that.showEmulationStatusOverlay);}
+return $default(_that.integerFpsMode,_that.pauseInBackground,_that.autoSaveEnabled,_that.autoSaveIntervalInMinutes,_that.quickSaveSlot,_that.fastForwardSpeedPercent,_that.rewindEnabled,_that.rewindSeconds,_that.rewindSpeedPercent,_that.showEmulationStatusOverlay,_that.clonePpu);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -203,10 +204,10 @@ return $default(_that.integerFpsMode,_that.pauseInBackground,_that.autoSaveEnabl
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( bool integerFpsMode,  bool pauseInBackground,  bool autoSaveEnabled,  int autoSaveIntervalInMinutes,  int quickSaveSlot,  int fastForwardSpeedPercent,  bool rewindEnabled,  int rewindSeconds,  int rewindSpeedPercent,  bool showEmulationStatusOverlay)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( bool integerFpsMode,  bool pauseInBackground,  bool autoSaveEnabled,  int autoSaveIntervalInMinutes,  int quickSaveSlot,  int fastForwardSpeedPercent,  bool rewindEnabled,  int rewindSeconds,  int rewindSpeedPercent,  bool showEmulationStatusOverlay,  bool clonePpu)?  $default,) {final _that = this;
 switch (_that) {
 case _EmulationSettings() when $default != null:
-return $default(_that.integerFpsMode,_that.pauseInBackground,_that.autoSaveEnabled,_that.autoSaveIntervalInMinutes,_that.quickSaveSlot,_that.fastForwardSpeedPercent,_that.rewindEnabled,_that.rewindSeconds,_that.rewindSpeedPercent,_that.showEmulationStatusOverlay);case _:
+return $default(_that.integerFpsMode,_that.pauseInBackground,_that.autoSaveEnabled,_that.autoSaveIntervalInMinutes,_that.quickSaveSlot,_that.fastForwardSpeedPercent,_that.rewindEnabled,_that.rewindSeconds,_that.rewindSpeedPercent,_that.showEmulationStatusOverlay,_that.clonePpu);case _:
   return null;
 
 }
@@ -218,7 +219,7 @@ return $default(_that.integerFpsMode,_that.pauseInBackground,_that.autoSaveEnabl
 @JsonSerializable()
 
 class _EmulationSettings extends EmulationSettings with DiagnosticableTreeMixin {
-  const _EmulationSettings({this.integerFpsMode = false, this.pauseInBackground = false, this.autoSaveEnabled = true, this.autoSaveIntervalInMinutes = 1, this.quickSaveSlot = 1, this.fastForwardSpeedPercent = 300, this.rewindEnabled = true, this.rewindSeconds = 60, this.rewindSpeedPercent = 100, this.showEmulationStatusOverlay = true}): super._();
+  const _EmulationSettings({this.integerFpsMode = false, this.pauseInBackground = false, this.autoSaveEnabled = true, this.autoSaveIntervalInMinutes = 1, this.quickSaveSlot = 1, this.fastForwardSpeedPercent = 300, this.rewindEnabled = true, this.rewindSeconds = 60, this.rewindSpeedPercent = 100, this.showEmulationStatusOverlay = true, this.clonePpu = false}): super._();
   factory _EmulationSettings.fromJson(Map<String, dynamic> json) => _$EmulationSettingsFromJson(json);
 
 @override@JsonKey() final  bool integerFpsMode;
@@ -231,6 +232,7 @@ class _EmulationSettings extends EmulationSettings with DiagnosticableTreeMixin
 @override@JsonKey() final  int rewindSeconds;
 @override@JsonKey() final  int rewindSpeedPercent;
 @override@JsonKey() final  bool showEmulationStatusOverlay;
+@override@JsonKey() final  bool clonePpu;
 
 /// Create a copy of EmulationSettings
 /// with the given fields replaced by the non-null parameter values.
@@ -246,21 +248,21 @@ Map<String, dynamic> toJson() {
 void debugFillProperties(DiagnosticPropertiesBuilder properties) {
   properties
     ..add(DiagnosticsProperty('type', 'EmulationSettings'))
-    ..add(DiagnosticsProperty('integerFpsMode', integerFpsMode))..add(DiagnosticsProperty('pauseInBackground', pauseInBackground))..add(DiagnosticsProperty('autoSaveEnabled', autoSaveEnabled))..add(DiagnosticsProperty('autoSaveIntervalInMinutes', autoSaveIntervalInMinutes))..add(DiagnosticsProperty('quickSaveSlot', quickSaveSlot))..add(DiagnosticsProperty('fastForwardSpeedPercent', fastForwardSpeedPercent))..add(DiagnosticsProperty('rewindEnabled', rewindEnabled))..add(DiagnosticsProperty('rewindSeconds', rewindSeconds))..add(DiagnosticsProperty('rewindSpeedPercent', rewindSpeedPercent))..add(DiagnosticsProperty('showEmulationStatusOverlay', showEmulationStatusOverlay));
+    ..add(DiagnosticsProperty('integerFpsMode', integerFpsMode))..add(DiagnosticsProperty('pauseInBackground', pauseInBackground))..add(DiagnosticsProperty('autoSaveEnabled', autoSaveEnabled))..add(DiagnosticsProperty('autoSaveIntervalInMinutes', autoSaveIntervalInMinutes))..add(DiagnosticsProperty('quickSaveSlot', quickSaveSlot))..add(DiagnosticsProperty('fastForwardSpeedPercent', fastForwardSpeedPercent))..add(DiagnosticsProperty('rewindEnabled', rewindEnabled))..add(DiagnosticsProperty('rewindSeconds', rewindSeconds))..add(DiagnosticsProperty('rewindSpeedPercent', rewindSpeedPercent))..add(DiagnosticsProperty('showEmulationStatusOverlay', showEmulationStatusOverlay))..add(DiagnosticsProperty('clonePpu', clonePpu));
 }
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _EmulationSettings&&(identical(other.integerFpsMode, integerFpsMode) || other.integerFpsMode == integerFpsMode)&&(identical(other.pauseInBackground, pauseInBackground) || other.pauseInBackground == pauseInBackground)&&(identical(other.autoSaveEnabled, autoSaveEnabled) || other.autoSaveEnabled == autoSaveEnabled)&&(identical(other.autoSaveIntervalInMinutes, autoSaveIntervalInMinutes) || other.autoSaveIntervalInMinutes == autoSaveIntervalInMinutes)&&(identical(other.quickSaveSlot, quickSaveSlot) || other.quickSaveSlot == quickSaveSlot)&&(identical(other.fastForwardSpeedPercent, fastForwardSpeedPercent) || other.fastForwardSpeedPercent == fastForwardSpeedPercent)&&(identical(other.rewindEnabled, rewindEnabled) || other.rewindEnabled == rewindEnabled)&&(identical(other.rewindSeconds, rewindSeconds) || other.rewindSeconds == rewindSeconds)&&(identical(other.rewindSpeedPercent, rewindSpeedPercent) || other.rewindSpeedPercent == rewindSpeedPercent)&&(identical(other.showEmulationStatusOverlay, showEmulationStatusOverlay) || other.showEmulationStatusOverlay == showEmulationStatusOverlay));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _EmulationSettings&&(identical(other.integerFpsMode, integerFpsMode) || other.integerFpsMode == integerFpsMode)&&(identical(other.pauseInBackground, pauseInBackground) || other.pauseInBackground == pauseInBackground)&&(identical(other.autoSaveEnabled, autoSaveEnabled) || other.autoSaveEnabled == autoSaveEnabled)&&(identical(other.autoSaveIntervalInMinutes, autoSaveIntervalInMinutes) || other.autoSaveIntervalInMinutes == autoSaveIntervalInMinutes)&&(identical(other.quickSaveSlot, quickSaveSlot) || other.quickSaveSlot == quickSaveSlot)&&(identical(other.fastForwardSpeedPercent, fastForwardSpeedPercent) || other.fastForwardSpeedPercent == fastForwardSpeedPercent)&&(identical(other.rewindEnabled, rewindEnabled) || other.rewindEnabled == rewindEnabled)&&(identical(other.rewindSeconds, rewindSeconds) || other.rewindSeconds == rewindSeconds)&&(identical(other.rewindSpeedPercent, rewindSpeedPercent) || other.rewindSpeedPercent == rewindSpeedPercent)&&(identical(other.showEmulationStatusOverlay, showEmulationStatusOverlay) || other.showEmulationStatusOverlay == showEmulationStatusOverlay)&&(identical(other.clonePpu, clonePpu) || other.clonePpu == clonePpu));
 }
 
 @JsonKey(includeFromJson: false, includeToJson: false)
 @override
-int get hashCode => Object.hash(runtimeType,integerFpsMode,pauseInBackground,autoSaveEnabled,autoSaveIntervalInMinutes,quickSaveSlot,fastForwardSpeedPercent,rewindEnabled,rewindSeconds,rewindSpeedPercent,showEmulationStatusOverlay);
+int get hashCode => Object.hash(runtimeType,integerFpsMode,pauseInBackground,autoSaveEnabled,autoSaveIntervalInMinutes,quickSaveSlot,fastForwardSpeedPercent,rewindEnabled,rewindSeconds,rewindSpeedPercent,showEmulationStatusOverlay,clonePpu);
 
 @override
 String toString({ DiagnosticLevel minLevel = DiagnosticLevel.info }) {
-  return 'EmulationSettings(integerFpsMode: $integerFpsMode, pauseInBackground: $pauseInBackground, autoSaveEnabled: $autoSaveEnabled, autoSaveIntervalInMinutes: $autoSaveIntervalInMinutes, quickSaveSlot: $quickSaveSlot, fastForwardSpeedPercent: $fastForwardSpeedPercent, rewindEnabled: $rewindEnabled, rewindSeconds: $rewindSeconds, rewindSpeedPercent: $rewindSpeedPercent, showEmulationStatusOverlay: $showEmulationStatusOverlay)';
+  return 'EmulationSettings(integerFpsMode: $integerFpsMode, pauseInBackground: $pauseInBackground, autoSaveEnabled: $autoSaveEnabled, autoSaveIntervalInMinutes: $autoSaveIntervalInMinutes, quickSaveSlot: $quickSaveSlot, fastForwardSpeedPercent: $fastForwardSpeedPercent, rewindEnabled: $rewindEnabled, rewindSeconds: $rewindSeconds, rewindSpeedPercent: $rewindSpeedPercent, showEmulationStatusOverlay: $showEmulationStatusOverlay, clonePpu: $clonePpu)';
 }
 
 
@@ -271,7 +273,7 @@ abstract mixin class _$EmulationSettingsCopyWith<$Res> implements $EmulationSett
   factory _$EmulationSettingsCopyWith(_EmulationSettings value, $Res Function(_EmulationSettings) _then) = __$EmulationSettingsCopyWithImpl;
 @override @useResult
 $Res call({
- bool integerFpsMode, bool pauseInBackground, bool autoSaveEnabled, int autoSaveIntervalInMinutes, int quickSaveSlot, int fastForwardSpeedPercent, bool rewindEnabled, int rewindSeconds, int rewindSpeedPercent, bool showEmulationStatusOverlay
+ bool integerFpsMode, bool pauseInBackground, bool autoSaveEnabled, int autoSaveIntervalInMinutes, int quickSaveSlot, int fastForwardSpeedPercent, bool rewindEnabled, int rewindSeconds, int rewindSpeedPercent, bool showEmulationStatusOverlay, bool clonePpu
 });
 
 
@@ -288,7 +290,7 @@ class __$EmulationSettingsCopyWithImpl<$Res>
 
 /// Create a copy of EmulationSettings
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? integerFpsMode = null,Object? pauseInBackground = null,Object? autoSaveEnabled = null,Object? autoSaveIntervalInMinutes = null,Object? quickSaveSlot = null,Object? fastForwardSpeedPercent = null,Object? rewindEnabled = null,Object? rewindSeconds = null,Object? rewindSpeedPercent = null,Object? showEmulationStatusOverlay = null,}) {
+@override @pragma('vm:prefer-inline') $Res call({Object? integerFpsMode = null,Object? pauseInBackground = null,Object? autoSaveEnabled = null,Object? autoSaveIntervalInMinutes = null,Object? quickSaveSlot = null,Object? fastForwardSpeedPercent = null,Object? rewindEnabled = null,Object? rewindSeconds = null,Object? rewindSpeedPercent = null,Object? showEmulationStatusOverlay = null,Object? clonePpu = null,}) {
   return _then(_EmulationSettings(
 integerFpsMode: null == integerFpsMode ? _self.integerFpsMode : integerFpsMode // ignore: cast_nullable_to_non_nullable
 as bool,pauseInBackground: null == pauseInBackground ? _self.pauseInBackground : pauseInBackground // ignore: cast_nullable_to_non_nullable
@@ -300,6 +302,7 @@ as int,rewindEnabled: null == rewindEnabled ? _self.rewindEnabled : rewindEnable
 as bool,rewindSeconds: null == rewindSeconds ? _self.rewindSeconds : rewindSeconds // ignore: cast_nullable_to_non_nullable
 as int,rewindSpeedPercent: null == rewindSpeedPercent ? _self.rewindSpeedPercent : rewindSpeedPercent // ignore: cast_nullable_to_non_nullable
 as int,showEmulationStatusOverlay: null == showEmulationStatusOverlay ? _self.showEmulationStatusOverlay : showEmulationStatusOverlay // ignore: cast_nullable_to_non_nullable
+as bool,clonePpu: null == clonePpu ? _self.clonePpu : clonePpu // ignore: cast_nullable_to_non_nullable
 as bool,
   ));
 }
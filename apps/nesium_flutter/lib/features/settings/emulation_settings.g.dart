@@ -21,6 +21,7 @@ _EmulationSettings _$EmulationSettingsFromJson(Map<String, dynamic> json) =>
       rewindSpeedPercent: (json['rewindSpeedPercent'] as num?)?.toInt() ?? 100,
       showEmulationStatusOverlay:
           json['showEmulationStatusOverlay'] as bool? ?? true,
+      clonePpu: json['clonePpu'] as bool? ?? false,
     );
 
 Map<String, dynamic> _$EmulationSettingsToJson(_EmulationSettings instance) =>
@@ -35,4 +36,5 @@ Map<String, dynamic> _$EmulationSettingsToJson(_EmulationSettings instance) =>
       'rewindSeconds': instance.rewindSeconds,
       'rewindSpeedPercent': instance.rewindSpeedPercent,
       'showEmulationStatusOverlay': instance.showEmulationStatusOverlay,
+      'clonePpu': instance.clonePpu,
     };
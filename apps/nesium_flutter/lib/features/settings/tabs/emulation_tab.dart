@@ -4,6 +4,8 @@ import 'package:flutter_riverpod/flutter_riverpod.dart';
 import '../../../l10n/app_localizations.dart';
 import '../../../widgets/animated_dropdown_menu.dart';
 import '../../../widgets/animated_settings_widgets.dart';
+import '../../../platform/nes_audio.dart' show StereoPreset;
+import '../audio_settings.dart';
 import '../emulation_settings.dart';
 
 class EmulationTab extends ConsumerWidget {
@@ -15,6 +17,8 @@ class EmulationTab extends ConsumerWidget {
     final colorScheme = Theme.of(context).colorScheme;
     final emulationSettings = ref.watch(emulationSettingsProvider);
     final emulationController = ref.read(emulationSettingsProvider.notifier);
+    final audioSettings = ref.watch(audioSettingsProvider);
+    final audioController = ref.read(audioSettingsProvider.notifier);
 
     return ListView(
       padding: const EdgeInsets.all(20),
@@ -57,6 +61,15 @@ class EmulationTab extends ConsumerWidget {
                   value: emulationSettings.showEmulationStatusOverlay,
                   onChanged: emulationController.setShowEmulationStatusOverlay,
                 ),
+                const Divider(height: 1),
+                SwitchListTile(
+                  contentPadding: EdgeInsets.zero,
+                  secondary: const Icon(Icons.memory),
+                  title: Text(l10n.clonePpuTitle),
+                  subtitle: Text(l10n.clonePpuSubtitle),
+                  value: emulationSettings.clonePpu,
+                  onChanged: emulationController.setClonePpu,
+                ),
               ],
             ),
           ),
@@ -258,6 +271,56 @@ class EmulationTab extends ConsumerWidget {
             ),
           ),
         ),
+        const SizedBox(height: 12),
+        AnimatedSettingsCard(
+          index: 5,
+          child: Padding(
+            padding: const EdgeInsets.all(12),
+            child: Column(
+              crossAxisAlignment: CrossAxisAlignment.start,
+              children: [
+                AnimatedDropdownMenu<StereoPreset>(
+                  labelText: l10n.audioStereoTitle,
+                  helperText: l10n.audioStereoSubtitle,
+                  value: audioSettings.stereoPreset,
+                  entries: [
+                    DropdownMenuEntry(
+                      value: StereoPreset.mono,
+                      label: l10n.stereoPresetMono,
+                    ),
+                    DropdownMenuEntry(
+                      value: StereoPreset.light,
+                      label: l10n.stereoPresetLight,
+                    ),
+                    DropdownMenuEntry(
+                      value: StereoPreset.mesenSpread,
+                      label: l10n.stereoPresetMesenSpread,
+                    ),
+                  ],
+                  onSelected: audioController.setStereoPreset,
+                ),
+                const SizedBox(height: 16),
+                Text(
+                  l10n.audioExpansionBalanceTitle,
+                  style: Theme.of(context).textTheme.titleSmall,
+                ),
+                const SizedBox(height: 4),
+                for (final chip in ExpansionChip.values)
+                  AnimatedSliderTile(
+                    label: chip.label,
+                    value: audioSettings.balanceOf(chip),
+                    min: -1,
+                    max: 1,
+                    divisions: 20,
+                    onChanged: (v) => audioController.setBalance(chip, v),
+                    valueLabel: audioSettings
+                        .balanceOf(chip)
+                        .toStringAsFixed(1),
+                  ),
+              ],
+            ),
+          ),
+        ),
       ],
     );
   }
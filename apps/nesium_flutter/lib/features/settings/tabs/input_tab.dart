@@ -9,6 +9,7 @@ import '../../../../features/controls/input_settings.dart';
 import '../../../../features/controls/turbo_settings.dart';
 import '../../../../features/controls/virtual_controls_editor.dart';
 import '../../../../features/controls/virtual_controls_settings.dart';
+import '../../../../features/controls/zapper_settings.dart';
 import '../../../platform/platform_capabilities.dart';
 import '../../../domain/connected_gamepads_provider.dart';
 import '../../../platform/nes_gamepad.dart' as nes_gamepad;
@@ -28,6 +29,8 @@ class InputTab extends ConsumerWidget {
     final inputController = ref.read(inputSettingsProvider.notifier);
     final turboSettings = ref.watch(turboSettingsProvider);
     final turboController = ref.read(turboSettingsProvider.notifier);
+    final zapperSettings = ref.watch(zapperSettingsProvider);
+    final zapperController = ref.read(zapperSettingsProvider.notifier);
     final settings = ref.watch(virtualControlsSettingsProvider);
     final controller = ref.read(virtualControlsSettingsProvider.notifier);
     final editor = ref.watch(virtualControlsEditorProvider);
@@ -305,6 +308,91 @@ class InputTab extends ConsumerWidget {
             ],
           ),
         ),
+        // Zapper Settings
+        if (supportsZapper)
+          AnimatedSettingsCard(
+            animateSize: false,
+            index: 1,
+            child: Column(
+              crossAxisAlignment: CrossAxisAlignment.start,
+              children: [
+                Padding(
+                  padding: const EdgeInsets.fromLTRB(16, 12, 16, 4),
+                  child: Text(
+                    l10n.zapperTitle,
+                    style: Theme.of(context).textTheme.titleSmall?.copyWith(
+                      fontWeight: FontWeight.w600,
+                    ),
+                  ),
+                ),
+                ListTile(
+                  title: Text(l10n.zapperPortLabel),
+                  subtitle: Text(l10n.zapperHint),
+                  trailing: SizedBox(
+                    width: 200,
+                    child: AnimatedDropdownMenu<int>(
+                      density: AnimatedDropdownMenuDensity.compact,
+                      value: zapperSettings.port ?? -1,
+                      entries: [
+                        DropdownMenuEntry(value: -1, label: l10n.zapperPortOff),
+                        DropdownMenuEntry(value: 0, label: l10n.player1),
+                        DropdownMenuEntry(value: 1, label: l10n.player2),
+                      ],
+                      onSelected: (value) {
+                        zapperController.setPort(value < 0 ? null : value);
+                      },
+                    ),
+                  ),
+                ),
+                Padding(
+                  padding: const EdgeInsets.symmetric(horizontal: 16),
+                  child: AnimatedSliderTile(
+                    label: l10n.zapperLagCompensation,
+                    value: zapperSettings.lagFrames.toDouble(),
+                    min: 0,
+                    max: zapperMaxLagFrames.toDouble(),
+                    divisions: zapperMaxLagFrames,
+                    onChanged: (v) => zapperController.setLagFrames(v.round()),
+                    valueLabel: l10n.framesValue(zapperSettings.lagFrames),
+                  ),
+                ),
+                SwitchListTile(
+                  secondary: const Icon(Icons.gps_fixed),
+                  title: Text(l10n.zapperCrosshairTitle),
+                  value: zapperSettings.crosshair,
+                  onChanged: zapperController.setCrosshair,
+                ),
+                Padding(
+                  padding: const EdgeInsets.symmetric(horizontal: 16),
+                  child: Column(
+                    children: [
+                      AnimatedSliderTile(
+                        label: l10n.zapperCalibrationX,
+                        value: zapperSettings.offsetX.toDouble(),
+                        min: -zapperMaxCalibrationOffset.toDouble(),
+                        max: zapperMaxCalibrationOffset.toDouble(),
+                        divisions: zapperMaxCalibrationOffset * 2,
+                        onChanged: (v) =>
+                            zapperController.setCalibration(offsetX: v.round()),
+                        valueLabel: '${zapperSettings.offsetX} px',
+                      ),
+                      AnimatedSliderTile(
+                        label: l10n.zapperCalibrationY,
+                        value: zapperSettings.offsetY.toDouble(),
+                        min: -zapperMaxCalibrationOffset.toDouble(),
+                        max: zapperMaxCalibrationOffset.toDouble(),
+                        divisions: zapperMaxCalibrationOffset * 2,
+                        onChanged: (v) =>
+                            zapperController.setCalibration(offsetY: v.round()),
+                        valueLabel: '${zapperSettings.offsetY} px',
+                      ),
+                    ],
+                  ),
+                ),
+                const SizedBox(height: 8),
+              ],
+            ),
+          ),
         // Keyboard Settings (shown when keyboard is selected)
         if (inputSettings.device == InputDevice.keyboard) ...[
           AnimatedSettingsCard(
@@ -296,6 +296,54 @@ abstract class AppLocalizations {
   /// **'Link press/release'**
   String get turboLinkPressRelease;
 
+  /// No description provided for @zapperTitle.
+  ///
+  /// In en, this message translates to:
+  /// **'Zapper'**
+  String get zapperTitle;
+
+  /// No description provided for @zapperPortLabel.
+  ///
+  /// In en, this message translates to:
+  /// **'Zapper port'**
+  String get zapperPortLabel;
+
+  /// No description provided for @zapperPortOff.
+  ///
+  /// In en, this message translates to:
+  /// **'Off'**
+  String get zapperPortOff;
+
+  /// No description provided for @zapperHint.
+  ///
+  /// In en, this message translates to:
+  /// **'Aim with the mouse or touch; click or tap to fire.'**
+  String get zapperHint;
+
+  /// No description provided for @zapperLagCompensation.
+  ///
+  /// In en, this message translates to:
+  /// **'Display lag compensation'**
+  String get zapperLagCompensation;
+
+  /// No description provided for @zapperCrosshairTitle.
+  ///
+  /// In en, this message translates to:
+  /// **'Show crosshair'**
+  String get zapperCrosshairTitle;
+
+  /// No description provided for @zapperCalibrationX.
+  ///
+  /// In en, this message translates to:
+  /// **'Aim offset X'**
+  String get zapperCalibrationX;
+
+  /// No description provided for @zapperCalibrationY.
+  ///
+  /// In en, this message translates to:
+  /// **'Aim offset Y'**
+  String get zapperCalibrationY;
+
   /// No description provided for @inputDeviceLabel.
   ///
   /// In en, this message translates to:
@@ -722,6 +770,54 @@ abstract class AppLocalizations {
   /// **'Show pause/rewind/fast-forward indicators on screen.'**
   String get showOverlaySubtitle;
 
+  /// No description provided for @clonePpuTitle.
+  ///
+  /// In en, this message translates to:
+  /// **'Famiclone PPU'**
+  String get clonePpuTitle;
+
+  /// No description provided for @clonePpuSubtitle.
+  ///
+  /// In en, this message translates to:
+  /// **'Emulates a clone PPU: no color emphasis, clone palette, true sprite overflow.'**
+  String get clonePpuSubtitle;
+
+  /// No description provided for @audioStereoTitle.
+  ///
+  /// In en, this message translates to:
+  /// **'Stereo'**
+  String get audioStereoTitle;
+
+  /// No description provided for @audioStereoSubtitle.
+  ///
+  /// In en, this message translates to:
+  /// **'Channel panning'**
+  String get audioStereoSubtitle;
+
+  /// No description provided for @stereoPresetMono.
+  ///
+  /// In en, this message translates to:
+  /// **'Mono'**
+  String get stereoPresetMono;
+
+  /// No description provided for @stereoPresetLight.
+  ///
+  /// In en, this message translates to:
+  /// **'Light pan'**
+  String get stereoPresetLight;
+
+  /// No description provided for @stereoPresetMesenSpread.
+  ///
+  /// In en, this message translates to:
+  /// **'Mesen-style spread'**
+  String get stereoPresetMesenSpread;
+
+  /// No description provided for @audioExpansionBalanceTitle.
+  ///
+  /// In en, this message translates to:
+  /// **'Expansion chip balance'**
+  String get audioExpansionBalanceTitle;
+
   /// No description provided for @pauseInBackgroundTitle.
   ///
   /// In en, this message translates to:
@@ -1136,12 +1232,30 @@ abstract class AppLocalizations {
   /// **'QUIC'**
   String get netplayTransportQuic;
 
+  /// No description provided for @netplayTransportWebRtc.
+  ///
+  /// In en, this message translates to:
+  /// **'WebRTC'**
+  String get netplayTransportWebRtc;
+
+  /// No description provided for @netplayTransportTls.
+  ///
+  /// In en, this message translates to:
+  /// **'TLS'**
+  String get netplayTransportTls;
+
   /// No description provided for @netplayUsingTcpFallback.
   ///
   /// In en, this message translates to:
   /// **'QUIC failed, using TCP'**
   String get netplayUsingTcpFallback;
 
+  /// No description provided for @netplayServerLatency.
+  ///
+  /// In en, this message translates to:
+  /// **'Ping {rtt} ms, jitter {jitter} ms, loss {loss}%'**
+  String netplayServerLatency(int rtt, int jitter, int loss);
+
   /// No description provided for @netplayStatusDisconnected.
   ///
   /// In en, this message translates to:
@@ -1214,6 +1328,18 @@ abstract class AppLocalizations {
   /// **'Enter this to use pinned QUIC. Leave empty to use system trust (QUIC) or fallback to TCP.'**
   String get netplayQuicFingerprintHelper;
 
+  /// No description provided for @netplayTlsFingerprintLabel.
+  ///
+  /// In en, this message translates to:
+  /// **'TLS cert fingerprint (optional)'**
+  String get netplayTlsFingerprintLabel;
+
+  /// No description provided for @netplayTlsFingerprintHelper.
+  ///
+  /// In en, this message translates to:
+  /// **'Enter this to accept only that certificate, e.g. a self-signed server. Leave empty to use system trust.'**
+  String get netplayTlsFingerprintHelper;
+
   /// No description provided for @netplayConnect.
   ///
   /// In en, this message translates to:
@@ -1244,6 +1370,36 @@ abstract class AppLocalizations {
   /// **'Join Game'**
   String get netplayJoinRoom;
 
+  /// No description provided for @netplayPublicRooms.
+  ///
+  /// In en, this message translates to:
+  /// **'Public Rooms'**
+  String get netplayPublicRooms;
+
+  /// No description provided for @netplayRefreshRooms.
+  ///
+  /// In en, this message translates to:
+  /// **'Refresh'**
+  String get netplayRefreshRooms;
+
+  /// No description provided for @netplayNoPublicRooms.
+  ///
+  /// In en, this message translates to:
+  /// **'No public rooms found.'**
+  String get netplayNoPublicRooms;
+
+  /// No description provided for @netplayRoomSummary.
+  ///
+  /// In en, this message translates to:
+  /// **'{players} players, {open} open slots, {spectators} watching'**
+  String netplayRoomSummary(int players, int open, int spectators);
+
+  /// No description provided for @netplayQuickMatch.
+  ///
+  /// In en, this message translates to:
+  /// **'Quick Match'**
+  String get netplayQuickMatch;
+
   /// No description provided for @netplayAddressOrRoomCode.
   ///
   /// In en, this message translates to:
@@ -1292,6 +1448,18 @@ abstract class AppLocalizations {
   /// **'Room Code'**
   String get netplayRoomCode;
 
+  /// No description provided for @netplayRoomPassword.
+  ///
+  /// In en, this message translates to:
+  /// **'Room Password'**
+  String get netplayRoomPassword;
+
+  /// No description provided for @netplayRoomPasswordHint.
+  ///
+  /// In en, this message translates to:
+  /// **'Optional; leave empty for a public room'**
+  String get netplayRoomPasswordHint;
+
   /// No description provided for @netplayRoleLabel.
   ///
   /// In en, this message translates to:
@@ -1310,6 +1478,30 @@ abstract class AppLocalizations {
   /// **'Spectator'**
   String get netplaySpectator;
 
+  /// No description provided for @netplaySpectatorCount.
+  ///
+  /// In en, this message translates to:
+  /// **'Spectators'**
+  String get netplaySpectatorCount;
+
+  /// No description provided for @netplaySpectatorDelayLabel.
+  ///
+  /// In en, this message translates to:
+  /// **'Spectator delay (host)'**
+  String get netplaySpectatorDelayLabel;
+
+  /// No description provided for @netplaySpectatorDelayLive.
+  ///
+  /// In en, this message translates to:
+  /// **'Live'**
+  String get netplaySpectatorDelayLive;
+
+  /// No description provided for @netplaySpectatorDelaySeconds.
+  ///
+  /// In en, this message translates to:
+  /// **'{seconds} s behind'**
+  String netplaySpectatorDelaySeconds(int seconds);
+
   /// No description provided for @netplayClientId.
   ///
   /// In en, this message translates to:
@@ -1358,12 +1550,24 @@ abstract class AppLocalizations {
   /// **'Join room failed: {error}'**
   String netplayJoinRoomFailed(String error);
 
+  /// No description provided for @netplayListRoomsFailed.
+  ///
+  /// In en, this message translates to:
+  /// **'Failed to list rooms: {error}'**
+  String netplayListRoomsFailed(String error);
+
   /// No description provided for @netplaySwitchRoleFailed.
   ///
   /// In en, this message translates to:
   /// **'Switch role failed: {error}'**
   String netplaySwitchRoleFailed(String error);
 
+  /// No description provided for @netplaySpectatorDelayFailed.
+  ///
+  /// In en, this message translates to:
+  /// **'Set spectator delay failed: {error}'**
+  String netplaySpectatorDelayFailed(String error);
+
   /// No description provided for @netplayInvalidRoomCode.
   ///
   /// In en, this message translates to:
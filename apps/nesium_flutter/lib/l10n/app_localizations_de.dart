@@ -112,6 +112,31 @@ class AppLocalizationsDe extends AppLocalizations {
   String get turboLinkPressRelease =>
       'Pressemitteilung/Pressemitteilung verlinken';
 
+  @override
+  String get zapperTitle => 'Zapper';
+
+  @override
+  String get zapperPortLabel => 'Zapper-Anschluss';
+
+  @override
+  String get zapperPortOff => 'Aus';
+
+  @override
+  String get zapperHint =>
+      'Mit Maus oder Touch zielen; Klicken oder Tippen zum Schießen.';
+
+  @override
+  String get zapperLagCompensation => 'Verzögerungsausgleich der Anzeige';
+
+  @override
+  String get zapperCrosshairTitle => 'Fadenkreuz anzeigen';
+
+  @override
+  String get zapperCalibrationX => 'Zielversatz X';
+
+  @override
+  String get zapperCalibrationY => 'Zielversatz Y';
+
   @override
   String get inputDeviceLabel => 'Eingabegerät';
 
@@ -341,6 +366,31 @@ class AppLocalizationsDe extends AppLocalizations {
   String get showOverlaySubtitle =>
       'Anzeigen von Pause-/Rücklauf-/Schnellvorlauf-Anzeigen auf dem Bildschirm.';
 
+  @override
+  String get clonePpuTitle => 'Famiclone-PPU';
+
+  @override
+  String get clonePpuSubtitle =>
+      'Emuliert einen Klon-PPU: keine Farbbetonung, Klon-Palette, echter Sprite-Überlauf.';
+
+  @override
+  String get audioStereoTitle => 'Stereo';
+
+  @override
+  String get audioStereoSubtitle => 'Kanal-Panorama';
+
+  @override
+  String get stereoPresetMono => 'Mono';
+
+  @override
+  String get stereoPresetLight => 'Leichtes Panorama';
+
+  @override
+  String get stereoPresetMesenSpread => 'Mesen-Stil';
+
+  @override
+  String get audioExpansionBalanceTitle => 'Balance der Erweiterungschips';
+
   @override
   String get pauseInBackgroundTitle => 'Pause im Hintergrund';
 
@@ -580,10 +630,21 @@ class AppLocalizationsDe extends AppLocalizations {
   @override
   String get netplayTransportQuic => 'QUIC';
 
+  @override
+  String get netplayTransportWebRtc => 'WebRTC';
+
+  @override
+  String get netplayTransportTls => 'TLS';
+
   @override
   String get netplayUsingTcpFallback =>
       'QUIC ist bei Verwendung von TCP fehlgeschlagen';
 
+  @override
+  String netplayServerLatency(int rtt, int jitter, int loss) {
+    return 'Ping $rtt ms, Jitter $jitter ms, Verlust $loss %';
+  }
+
   @override
   String get netplayStatusDisconnected => 'Getrennt';
 
@@ -622,6 +683,14 @@ class AppLocalizationsDe extends AppLocalizations {
   String get netplayQuicFingerprintHelper =>
       'Geben Sie dies ein, um angeheftetes QUIC zu verwenden. Lassen Sie das Feld leer, um die Systemvertrauensstellung (QUIC) oder den Fallback auf TCP zu verwenden.';
 
+  @override
+  String get netplayTlsFingerprintLabel =>
+      'TLS-Zertifikat-Fingerabdruck (optional)';
+
+  @override
+  String get netplayTlsFingerprintHelper =>
+      'Geben Sie dies ein, um nur dieses Zertifikat zu akzeptieren, z. B. bei einem selbstsignierten Server. Leer lassen, um die Systemvertrauensstellung zu verwenden.';
+
   @override
   String get netplayConnect => 'Dem Spiel beitreten';
 
@@ -637,6 +706,23 @@ class AppLocalizationsDe extends AppLocalizations {
   @override
   String get netplayJoinRoom => 'Dem Spiel beitreten';
 
+  @override
+  String get netplayPublicRooms => 'Öffentliche Räume';
+
+  @override
+  String get netplayRefreshRooms => 'Aktualisieren';
+
+  @override
+  String get netplayNoPublicRooms => 'Keine öffentlichen Räume gefunden.';
+
+  @override
+  String netplayRoomSummary(int players, int open, int spectators) {
+    return '$players Spieler, $open freie Plätze, $spectators Zuschauer';
+  }
+
+  @override
+  String get netplayQuickMatch => 'Schnelles Spiel';
+
   @override
   String get netplayAddressOrRoomCode => 'Raumcode oder Serveradresse';
 
@@ -661,6 +747,13 @@ class AppLocalizationsDe extends AppLocalizations {
   @override
   String get netplayRoomCode => 'Zimmercode';
 
+  @override
+  String get netplayRoomPassword => 'Raumpasswort';
+
+  @override
+  String get netplayRoomPasswordHint =>
+      'Optional; leer lassen für einen öffentlichen Raum';
+
   @override
   String get netplayRoleLabel => 'Rolle';
 
@@ -672,6 +765,20 @@ class AppLocalizationsDe extends AppLocalizations {
   @override
   String get netplaySpectator => 'Zuschauer';
 
+  @override
+  String get netplaySpectatorCount => 'Zuschauer';
+
+  @override
+  String get netplaySpectatorDelayLabel => 'Zuschauerverzögerung (Host)';
+
+  @override
+  String get netplaySpectatorDelayLive => 'Live';
+
+  @override
+  String netplaySpectatorDelaySeconds(int seconds) {
+    return '$seconds s Verzögerung';
+  }
+
   @override
   String get netplayClientId => 'Kunden-ID';
 
@@ -704,11 +811,21 @@ class AppLocalizationsDe extends AppLocalizations {
     return 'Dem Raum beitreten fehlgeschlagen: $error';
   }
 
+  @override
+  String netplayListRoomsFailed(String error) {
+    return 'Räume konnten nicht geladen werden: $error';
+  }
+
   @override
   String netplaySwitchRoleFailed(String error) {
     return 'Rollenwechsel fehlgeschlagen: $error';
   }
 
+  @override
+  String netplaySpectatorDelayFailed(String error) {
+    return 'Zuschauerverzögerung konnte nicht gesetzt werden: $error';
+  }
+
   @override
   String get netplayInvalidRoomCode => 'Ungültiger Zimmercode';
 
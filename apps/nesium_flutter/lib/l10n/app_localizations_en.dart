@@ -111,6 +111,30 @@ class AppLocalizationsEn extends AppLocalizations {
   @override
   String get turboLinkPressRelease => 'Link press/release';
 
+  @override
+  String get zapperTitle => 'Zapper';
+
+  @override
+  String get zapperPortLabel => 'Zapper port';
+
+  @override
+  String get zapperPortOff => 'Off';
+
+  @override
+  String get zapperHint => 'Aim with the mouse or touch; click or tap to fire.';
+
+  @override
+  String get zapperLagCompensation => 'Display lag compensation';
+
+  @override
+  String get zapperCrosshairTitle => 'Show crosshair';
+
+  @override
+  String get zapperCalibrationX => 'Aim offset X';
+
+  @override
+  String get zapperCalibrationY => 'Aim offset Y';
+
   @override
   String get inputDeviceLabel => 'Input device';
 
@@ -340,6 +364,31 @@ class AppLocalizationsEn extends AppLocalizations {
   String get showOverlaySubtitle =>
       'Show pause/rewind/fast-forward indicators on screen.';
 
+  @override
+  String get clonePpuTitle => 'Famiclone PPU';
+
+  @override
+  String get clonePpuSubtitle =>
+      'Emulates a clone PPU: no color emphasis, clone palette, true sprite overflow.';
+
+  @override
+  String get audioStereoTitle => 'Stereo';
+
+  @override
+  String get audioStereoSubtitle => 'Channel panning';
+
+  @override
+  String get stereoPresetMono => 'Mono';
+
+  @override
+  String get stereoPresetLight => 'Light pan';
+
+  @override
+  String get stereoPresetMesenSpread => 'Mesen-style spread';
+
+  @override
+  String get audioExpansionBalanceTitle => 'Expansion chip balance';
+
   @override
   String get pauseInBackgroundTitle => 'Pause in background';
 
@@ -573,9 +622,20 @@ class AppLocalizationsEn extends AppLocalizations {
   @override
   String get netplayTransportQuic => 'QUIC';
 
+  @override
+  String get netplayTransportWebRtc => 'WebRTC';
+
+  @override
+  String get netplayTransportTls => 'TLS';
+
   @override
   String get netplayUsingTcpFallback => 'QUIC failed, using TCP';
 
+  @override
+  String netplayServerLatency(int rtt, int jitter, int loss) {
+    return 'Ping $rtt ms, jitter $jitter ms, loss $loss%';
+  }
+
   @override
   String get netplayStatusDisconnected => 'Disconnected';
 
@@ -613,6 +673,13 @@ class AppLocalizationsEn extends AppLocalizations {
   String get netplayQuicFingerprintHelper =>
       'Enter this to use pinned QUIC. Leave empty to use system trust (QUIC) or fallback to TCP.';
 
+  @override
+  String get netplayTlsFingerprintLabel => 'TLS cert fingerprint (optional)';
+
+  @override
+  String get netplayTlsFingerprintHelper =>
+      'Enter this to accept only that certificate, e.g. a self-signed server. Leave empty to use system trust.';
+
   @override
   String get netplayConnect => 'Join Game';
 
@@ -628,6 +695,23 @@ class AppLocalizationsEn extends AppLocalizations {
   @override
   String get netplayJoinRoom => 'Join Game';
 
+  @override
+  String get netplayPublicRooms => 'Public Rooms';
+
+  @override
+  String get netplayRefreshRooms => 'Refresh';
+
+  @override
+  String get netplayNoPublicRooms => 'No public rooms found.';
+
+  @override
+  String netplayRoomSummary(int players, int open, int spectators) {
+    return '$players players, $open open slots, $spectators watching';
+  }
+
+  @override
+  String get netplayQuickMatch => 'Quick Match';
+
   @override
   String get netplayAddressOrRoomCode => 'Room Code or Server Address';
 
@@ -652,6 +736,13 @@ class AppLocalizationsEn extends AppLocalizations {
   @override
   String get netplayRoomCode => 'Room Code';
 
+  @override
+  String get netplayRoomPassword => 'Room Password';
+
+  @override
+  String get netplayRoomPasswordHint =>
+      'Optional; leave empty for a public room';
+
   @override
   String get netplayRoleLabel => 'Role';
 
@@ -663,6 +754,20 @@ class AppLocalizationsEn extends AppLocalizations {
   @override
   String get netplaySpectator => 'Spectator';
 
+  @override
+  String get netplaySpectatorCount => 'Spectators';
+
+  @override
+  String get netplaySpectatorDelayLabel => 'Spectator delay (host)';
+
+  @override
+  String get netplaySpectatorDelayLive => 'Live';
+
+  @override
+  String netplaySpectatorDelaySeconds(int seconds) {
+    return '$seconds s behind';
+  }
+
   @override
   String get netplayClientId => 'Client ID';
 
@@ -695,11 +800,21 @@ class AppLocalizationsEn extends AppLocalizations {
     return 'Join room failed: $error';
   }
 
+  @override
+  String netplayListRoomsFailed(String error) {
+    return 'Failed to list rooms: $error';
+  }
+
   @override
   String netplaySwitchRoleFailed(String error) {
     return 'Switch role failed: $error';
   }
 
+  @override
+  String netplaySpectatorDelayFailed(String error) {
+    return 'Set spectator delay failed: $error';
+  }
+
   @override
   String get netplayInvalidRoomCode => 'Invalid room code';
 
@@ -111,6 +111,31 @@ class AppLocalizationsEs extends AppLocalizations {
   @override
   String get turboLinkPressRelease => 'Enlace de prensa/comunicado';
 
+  @override
+  String get zapperTitle => 'Zapper';
+
+  @override
+  String get zapperPortLabel => 'Puerto de la Zapper';
+
+  @override
+  String get zapperPortOff => 'Desactivado';
+
+  @override
+  String get zapperHint =>
+      'Apunta con el ratón o el dedo; haz clic o toca para disparar.';
+
+  @override
+  String get zapperLagCompensation => 'Compensación del retardo de pantalla';
+
+  @override
+  String get zapperCrosshairTitle => 'Mostrar punto de mira';
+
+  @override
+  String get zapperCalibrationX => 'Desplazamiento de mira X';
+
+  @override
+  String get zapperCalibrationY => 'Desplazamiento de mira Y';
+
   @override
   String get inputDeviceLabel => 'Dispositivo de entrada';
 
@@ -341,6 +366,31 @@ class AppLocalizationsEs extends AppLocalizations {
   String get showOverlaySubtitle =>
       'Muestra indicadores de pausa/rebobinado/avance rápido en la pantalla.';
 
+  @override
+  String get clonePpuTitle => 'PPU de famiclon';
+
+  @override
+  String get clonePpuSubtitle =>
+      'Emula una PPU clónica: sin énfasis de color, paleta clónica y desbordamiento real de sprites.';
+
+  @override
+  String get audioStereoTitle => 'Estéreo';
+
+  @override
+  String get audioStereoSubtitle => 'Panoramización de canales';
+
+  @override
+  String get stereoPresetMono => 'Mono';
+
+  @override
+  String get stereoPresetLight => 'Panorámica ligera';
+
+  @override
+  String get stereoPresetMesenSpread => 'Estilo Mesen';
+
+  @override
+  String get audioExpansionBalanceTitle => 'Balance de chips de expansión';
+
   @override
   String get pauseInBackgroundTitle => 'Pausa en segundo plano';
 
@@ -578,9 +628,20 @@ class AppLocalizationsEs extends AppLocalizations {
   @override
   String get netplayTransportQuic => 'QUIC';
 
+  @override
+  String get netplayTransportWebRtc => 'WebRTC';
+
+  @override
+  String get netplayTransportTls => 'TLS';
+
   @override
   String get netplayUsingTcpFallback => 'QUIC falló al usar TCP';
 
+  @override
+  String netplayServerLatency(int rtt, int jitter, int loss) {
+    return 'Ping $rtt ms, jitter $jitter ms, pérdida $loss %';
+  }
+
   @override
   String get netplayStatusDisconnected => 'Desconectado';
 
@@ -619,6 +680,14 @@ class AppLocalizationsEs extends AppLocalizations {
   String get netplayQuicFingerprintHelper =>
       'Ingrese esto para usar QUIC anclado. Déjelo vacío para utilizar la confianza del sistema (QUIC) o recurrir a TCP.';
 
+  @override
+  String get netplayTlsFingerprintLabel =>
+      'Huella digital del certificado TLS (opcional)';
+
+  @override
+  String get netplayTlsFingerprintHelper =>
+      'Ingrese esto para aceptar solo ese certificado, p. ej. un servidor autofirmado. Déjelo vacío para usar la confianza del sistema.';
+
   @override
   String get netplayConnect => 'Unirse al juego';
 
@@ -634,6 +703,23 @@ class AppLocalizationsEs extends AppLocalizations {
   @override
   String get netplayJoinRoom => 'Unirse al juego';
 
+  @override
+  String get netplayPublicRooms => 'Salas públicas';
+
+  @override
+  String get netplayRefreshRooms => 'Actualizar';
+
+  @override
+  String get netplayNoPublicRooms => 'No se encontraron salas públicas.';
+
+  @override
+  String netplayRoomSummary(int players, int open, int spectators) {
+    return '$players jugadores, $open plazas libres, $spectators espectadores';
+  }
+
+  @override
+  String get netplayQuickMatch => 'Partida rápida';
+
   @override
   String get netplayAddressOrRoomCode =>
       'Código de habitación o dirección del servidor';
@@ -659,6 +745,13 @@ class AppLocalizationsEs extends AppLocalizations {
   @override
   String get netplayRoomCode => 'Código de habitación';
 
+  @override
+  String get netplayRoomPassword => 'Contraseña de la sala';
+
+  @override
+  String get netplayRoomPasswordHint =>
+      'Opcional; déjala vacía para una sala pública';
+
   @override
   String get netplayRoleLabel => 'Role';
 
@@ -670,6 +763,21 @@ class AppLocalizationsEs extends AppLocalizations {
   @override
   String get netplaySpectator => 'Espectador';
 
+  @override
+  String get netplaySpectatorCount => 'Espectadores';
+
+  @override
+  String get netplaySpectatorDelayLabel =>
+      'Retraso de espectadores (anfitrión)';
+
+  @override
+  String get netplaySpectatorDelayLive => 'En directo';
+
+  @override
+  String netplaySpectatorDelaySeconds(int seconds) {
+    return '$seconds s de retraso';
+  }
+
   @override
   String get netplayClientId => 'ID de cliente';
 
@@ -702,11 +810,21 @@ class AppLocalizationsEs extends AppLocalizations {
     return 'Error al unirse a la sala: $error';
   }
 
+  @override
+  String netplayListRoomsFailed(String error) {
+    return 'No se pudieron listar las salas: $error';
+  }
+
   @override
   String netplaySwitchRoleFailed(String error) {
     return 'Error al cambiar de rol: $error';
   }
 
+  @override
+  String netplaySpectatorDelayFailed(String error) {
+    return 'No se pudo establecer el retraso de espectadores: $error';
+  }
+
   @override
   String get netplayInvalidRoomCode => 'Código de habitación no válido';
 
@@ -111,6 +111,31 @@ class AppLocalizationsFr extends AppLocalizations {
   @override
   String get turboLinkPressRelease => 'Lien presse/communiqué';
 
+  @override
+  String get zapperTitle => 'Zapper';
+
+  @override
+  String get zapperPortLabel => 'Port du Zapper';
+
+  @override
+  String get zapperPortOff => 'Désactivé';
+
+  @override
+  String get zapperHint =>
+      'Visez avec la souris ou au toucher ; cliquez ou touchez pour tirer.';
+
+  @override
+  String get zapperLagCompensation => 'Compensation de la latence d’affichage';
+
+  @override
+  String get zapperCrosshairTitle => 'Afficher le viseur';
+
+  @override
+  String get zapperCalibrationX => 'Décalage de visée X';
+
+  @override
+  String get zapperCalibrationY => 'Décalage de visée Y';
+
   @override
   String get inputDeviceLabel => 'Périphérique d\'entrée';
 
@@ -341,6 +366,32 @@ class AppLocalizationsFr extends AppLocalizations {
   String get showOverlaySubtitle =>
       'Afficher les indicateurs pause/rembobinage/avance rapide à l’écran.';
 
+  @override
+  String get clonePpuTitle => 'PPU de famiclone';
+
+  @override
+  String get clonePpuSubtitle =>
+      'Émule un PPU de clone : sans accentuation des couleurs, palette clone, vrai débordement de sprites.';
+
+  @override
+  String get audioStereoTitle => 'Stéréo';
+
+  @override
+  String get audioStereoSubtitle => 'Panoramique des canaux';
+
+  @override
+  String get stereoPresetMono => 'Mono';
+
+  @override
+  String get stereoPresetLight => 'Panoramique léger';
+
+  @override
+  String get stereoPresetMesenSpread => 'Style Mesen';
+
+  @override
+  String get audioExpansionBalanceTitle =>
+      'Balance des puces d\'extension';
+
   @override
   String get pauseInBackgroundTitle => 'Pause en arrière-plan';
 
@@ -579,9 +630,20 @@ class AppLocalizationsFr extends AppLocalizations {
   @override
   String get netplayTransportQuic => 'QUIC';
 
+  @override
+  String get netplayTransportWebRtc => 'WebRTC';
+
+  @override
+  String get netplayTransportTls => 'TLS';
+
   @override
   String get netplayUsingTcpFallback => 'QUIC a échoué, en utilisant TCP';
 
+  @override
+  String netplayServerLatency(int rtt, int jitter, int loss) {
+    return 'Ping $rtt ms, gigue $jitter ms, perte $loss %';
+  }
+
   @override
   String get netplayStatusDisconnected => 'Déconnecté';
 
@@ -620,6 +682,14 @@ class AppLocalizationsFr extends AppLocalizations {
   String get netplayQuicFingerprintHelper =>
       'Entrez ceci pour utiliser QUIC épinglé. Laissez vide pour utiliser la confiance du système (QUIC) ou revenir à TCP.';
 
+  @override
+  String get netplayTlsFingerprintLabel =>
+      'Empreinte digitale du certificat TLS (facultatif)';
+
+  @override
+  String get netplayTlsFingerprintHelper =>
+      'Entrez ceci pour accepter uniquement ce certificat, par ex. un serveur auto-signé. Laissez vide pour utiliser la confiance du système.';
+
   @override
   String get netplayConnect => 'Rejoindre le jeu';
 
@@ -635,6 +705,23 @@ class AppLocalizationsFr extends AppLocalizations {
   @override
   String get netplayJoinRoom => 'Rejoindre le jeu';
 
+  @override
+  String get netplayPublicRooms => 'Salles publiques';
+
+  @override
+  String get netplayRefreshRooms => 'Actualiser';
+
+  @override
+  String get netplayNoPublicRooms => 'Aucune salle publique trouvée.';
+
+  @override
+  String netplayRoomSummary(int players, int open, int spectators) {
+    return '$players joueurs, $open places libres, $spectators spectateurs';
+  }
+
+  @override
+  String get netplayQuickMatch => 'Partie rapide';
+
   @override
   String get netplayAddressOrRoomCode => 'Code de salle ou adresse du serveur';
 
@@ -659,6 +746,13 @@ class AppLocalizationsFr extends AppLocalizations {
   @override
   String get netplayRoomCode => 'Code de la chambre';
 
+  @override
+  String get netplayRoomPassword => 'Mot de passe de la salle';
+
+  @override
+  String get netplayRoomPasswordHint =>
+      'Facultatif ; laisser vide pour une salle publique';
+
   @override
   String get netplayRoleLabel => 'Rôle';
 
@@ -670,6 +764,20 @@ class AppLocalizationsFr extends AppLocalizations {
   @override
   String get netplaySpectator => 'Spectateur';
 
+  @override
+  String get netplaySpectatorCount => 'Spectateurs';
+
+  @override
+  String get netplaySpectatorDelayLabel => 'Délai des spectateurs (hôte)';
+
+  @override
+  String get netplaySpectatorDelayLive => 'En direct';
+
+  @override
+  String netplaySpectatorDelaySeconds(int seconds) {
+    return '$seconds s de retard';
+  }
+
   @override
   String get netplayClientId => 'Identifiant client';
 
@@ -702,11 +810,21 @@ class AppLocalizationsFr extends AppLocalizations {
     return 'Échec de la connexion au salon : $error';
   }
 
+  @override
+  String netplayListRoomsFailed(String error) {
+    return 'Impossible de lister les salles : $error';
+  }
+
   @override
   String netplaySwitchRoleFailed(String error) {
     return 'Échec du changement de rôle : $error';
   }
 
+  @override
+  String netplaySpectatorDelayFailed(String error) {
+    return 'Échec du réglage du délai des spectateurs : $error';
+  }
+
   @override
   String get netplayInvalidRoomCode => 'Code de chambre invalide';
 
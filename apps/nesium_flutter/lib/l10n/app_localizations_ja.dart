@@ -111,6 +111,30 @@ class AppLocalizationsJa extends AppLocalizations {
   @override
   String get turboLinkPressRelease => 'プレス/リリースをリンク';
 
+  @override
+  String get zapperTitle => '光線銃';
+
+  @override
+  String get zapperPortLabel => '光線銃のポート';
+
+  @override
+  String get zapperPortOff => 'オフ';
+
+  @override
+  String get zapperHint => 'マウスまたはタッチで狙い、クリックまたはタップで発射します。';
+
+  @override
+  String get zapperLagCompensation => '表示遅延の補正';
+
+  @override
+  String get zapperCrosshairTitle => '照準を表示';
+
+  @override
+  String get zapperCalibrationX => '照準オフセット X';
+
+  @override
+  String get zapperCalibrationY => '照準オフセット Y';
+
   @override
   String get inputDeviceLabel => '入力デバイス';
 
@@ -338,6 +362,31 @@ class AppLocalizationsJa extends AppLocalizations {
   @override
   String get showOverlaySubtitle => '画面上に一時停止/巻き戻し/早送りインジケーターを表示します。';
 
+  @override
+  String get clonePpuTitle => '互換機PPU';
+
+  @override
+  String get clonePpuSubtitle =>
+      '互換機のPPUをエミュレートします（カラー強調なし、互換機パレット、正確なスプライトオーバーフロー）。';
+
+  @override
+  String get audioStereoTitle => 'ステレオ';
+
+  @override
+  String get audioStereoSubtitle => 'チャンネルのパン';
+
+  @override
+  String get stereoPresetMono => 'モノラル';
+
+  @override
+  String get stereoPresetLight => '軽いパン';
+
+  @override
+  String get stereoPresetMesenSpread => 'Mesen 風の広がり';
+
+  @override
+  String get audioExpansionBalanceTitle => '拡張音源のバランス';
+
   @override
   String get pauseInBackgroundTitle => 'バックグラウンドで一時停止する';
 
@@ -566,9 +615,20 @@ class AppLocalizationsJa extends AppLocalizations {
   @override
   String get netplayTransportQuic => 'QUIC';
 
+  @override
+  String get netplayTransportWebRtc => 'WebRTC';
+
+  @override
+  String get netplayTransportTls => 'TLS';
+
   @override
   String get netplayUsingTcpFallback => 'QUIC が失敗しました。TCP を使用しています';
 
+  @override
+  String netplayServerLatency(int rtt, int jitter, int loss) {
+    return 'Ping $rtt ms、ジッター $jitter ms、損失 $loss%';
+  }
+
   @override
   String get netplayStatusDisconnected => '切断されました';
 
@@ -606,6 +666,13 @@ class AppLocalizationsJa extends AppLocalizations {
   String get netplayQuicFingerprintHelper =>
       'ピン留めされた QUIC を使用するにはこれを入力します。システムの信頼（QUIC）を使用するか TCP にフォールバックする場合は空のままにしてください。';
 
+  @override
+  String get netplayTlsFingerprintLabel => 'TLS 証明書フィンガープリント (オプション)';
+
+  @override
+  String get netplayTlsFingerprintHelper =>
+      'その証明書のみを受け入れる場合に入力します (自己署名サーバーなど)。システムの信頼を使用する場合は空のままにしてください。';
+
   @override
   String get netplayConnect => 'ゲームに参加する';
 
@@ -621,6 +688,23 @@ class AppLocalizationsJa extends AppLocalizations {
   @override
   String get netplayJoinRoom => 'ゲームに参加する';
 
+  @override
+  String get netplayPublicRooms => '公開ルーム';
+
+  @override
+  String get netplayRefreshRooms => '更新';
+
+  @override
+  String get netplayNoPublicRooms => '公開ルームが見つかりません。';
+
+  @override
+  String netplayRoomSummary(int players, int open, int spectators) {
+    return 'プレイヤー $players 人、空き $open、観戦 $spectators 人';
+  }
+
+  @override
+  String get netplayQuickMatch => 'クイックマッチ';
+
   @override
   String get netplayAddressOrRoomCode => 'ルームコードまたはサーバーアドレス';
 
@@ -645,6 +729,12 @@ class AppLocalizationsJa extends AppLocalizations {
   @override
   String get netplayRoomCode => 'ルームコード';
 
+  @override
+  String get netplayRoomPassword => 'ルームのパスワード';
+
+  @override
+  String get netplayRoomPasswordHint => '任意。空欄なら公開ルーム';
+
   @override
   String get netplayRoleLabel => '役割';
 
@@ -656,6 +746,20 @@ class AppLocalizationsJa extends AppLocalizations {
   @override
   String get netplaySpectator => '観客';
 
+  @override
+  String get netplaySpectatorCount => '観戦者';
+
+  @override
+  String get netplaySpectatorDelayLabel => '観戦の遅延 (ホスト)';
+
+  @override
+  String get netplaySpectatorDelayLive => 'ライブ';
+
+  @override
+  String netplaySpectatorDelaySeconds(int seconds) {
+    return '$seconds 秒遅れ';
+  }
+
   @override
   String get netplayClientId => 'クライアントID';
 
@@ -688,11 +792,21 @@ class AppLocalizationsJa extends AppLocalizations {
     return 'ルームへの参加に失敗しました: $error';
   }
 
+  @override
+  String netplayListRoomsFailed(String error) {
+    return 'ルーム一覧の取得に失敗しました: $error';
+  }
+
   @override
   String netplaySwitchRoleFailed(String error) {
     return '役割の切り替えに失敗しました: $error';
   }
 
+  @override
+  String netplaySpectatorDelayFailed(String error) {
+    return '観戦の遅延の設定に失敗しました: $error';
+  }
+
   @override
   String get netplayInvalidRoomCode => '部屋コードが無効です';
 
@@ -111,6 +111,31 @@ class AppLocalizationsPt extends AppLocalizations {
   @override
   String get turboLinkPressRelease => 'Imprensa/divulgação do link';
 
+  @override
+  String get zapperTitle => 'Zapper';
+
+  @override
+  String get zapperPortLabel => 'Porta da Zapper';
+
+  @override
+  String get zapperPortOff => 'Desligado';
+
+  @override
+  String get zapperHint =>
+      'Mire com o mouse ou toque; clique ou toque para disparar.';
+
+  @override
+  String get zapperLagCompensation => 'Compensação de atraso da tela';
+
+  @override
+  String get zapperCrosshairTitle => 'Mostrar mira';
+
+  @override
+  String get zapperCalibrationX => 'Deslocamento da mira X';
+
+  @override
+  String get zapperCalibrationY => 'Deslocamento da mira Y';
+
   @override
   String get inputDeviceLabel => 'Dispositivo de entrada';
 
@@ -340,6 +365,31 @@ class AppLocalizationsPt extends AppLocalizations {
   String get showOverlaySubtitle =>
       'Mostrar indicadores de pausa/retrocesso/avanço rápido na tela.';
 
+  @override
+  String get clonePpuTitle => 'PPU de famiclone';
+
+  @override
+  String get clonePpuSubtitle =>
+      'Emula uma PPU de clone: sem ênfase de cor, paleta de clone e overflow real de sprites.';
+
+  @override
+  String get audioStereoTitle => 'Estéreo';
+
+  @override
+  String get audioStereoSubtitle => 'Panorâmica dos canais';
+
+  @override
+  String get stereoPresetMono => 'Mono';
+
+  @override
+  String get stereoPresetLight => 'Panorâmica leve';
+
+  @override
+  String get stereoPresetMesenSpread => 'Estilo Mesen';
+
+  @override
+  String get audioExpansionBalanceTitle => 'Balanço dos chips de expansão';
+
   @override
   String get pauseInBackgroundTitle => 'Pausa em segundo plano';
 
@@ -578,9 +628,20 @@ class AppLocalizationsPt extends AppLocalizations {
   @override
   String get netplayTransportQuic => 'QUIC';
 
+  @override
+  String get netplayTransportWebRtc => 'WebRTC';
+
+  @override
+  String get netplayTransportTls => 'TLS';
+
   @override
   String get netplayUsingTcpFallback => 'QUIC falhou, usando TCP';
 
+  @override
+  String netplayServerLatency(int rtt, int jitter, int loss) {
+    return 'Ping $rtt ms, jitter $jitter ms, perda $loss%';
+  }
+
   @override
   String get netplayStatusDisconnected => 'Desconectado';
 
@@ -619,6 +680,14 @@ class AppLocalizationsPt extends AppLocalizations {
   String get netplayQuicFingerprintHelper =>
       'Insira isto para usar o QUIC fixado. Deixe em branco para usar a confiança do sistema (QUIC) ou fallback para TCP.';
 
+  @override
+  String get netplayTlsFingerprintLabel =>
+      'Impressão digital do certificado TLS (opcional)';
+
+  @override
+  String get netplayTlsFingerprintHelper =>
+      'Insira isto para aceitar apenas esse certificado, por ex. um servidor autoassinado. Deixe em branco para usar a confiança do sistema.';
+
   @override
   String get netplayConnect => 'Junte-se ao jogo';
 
@@ -634,6 +703,23 @@ class AppLocalizationsPt extends AppLocalizations {
   @override
   String get netplayJoinRoom => 'Junte-se ao jogo';
 
+  @override
+  String get netplayPublicRooms => 'Salas públicas';
+
+  @override
+  String get netplayRefreshRooms => 'Atualizar';
+
+  @override
+  String get netplayNoPublicRooms => 'Nenhuma sala pública encontrada.';
+
+  @override
+  String netplayRoomSummary(int players, int open, int spectators) {
+    return '$players jogadores, $open vagas livres, $spectators assistindo';
+  }
+
+  @override
+  String get netplayQuickMatch => 'Partida rápida';
+
   @override
   String get netplayAddressOrRoomCode =>
       'Código da sala ou endereço do servidor';
@@ -659,6 +745,13 @@ class AppLocalizationsPt extends AppLocalizations {
   @override
   String get netplayRoomCode => 'Código do quarto';
 
+  @override
+  String get netplayRoomPassword => 'Senha da sala';
+
+  @override
+  String get netplayRoomPasswordHint =>
+      'Opcional; deixe vazio para uma sala pública';
+
   @override
   String get netplayRoleLabel => 'Papel';
 
@@ -670,6 +763,21 @@ class AppLocalizationsPt extends AppLocalizations {
   @override
   String get netplaySpectator => 'Espectador';
 
+  @override
+  String get netplaySpectatorCount => 'Espectadores';
+
+  @override
+  String get netplaySpectatorDelayLabel =>
+      'Atraso dos espectadores (anfitrião)';
+
+  @override
+  String get netplaySpectatorDelayLive => 'Ao vivo';
+
+  @override
+  String netplaySpectatorDelaySeconds(int seconds) {
+    return '$seconds s de atraso';
+  }
+
   @override
   String get netplayClientId => 'ID do cliente';
 
@@ -702,11 +810,21 @@ class AppLocalizationsPt extends AppLocalizations {
     return 'Falha ao ingressar na sala: $error';
   }
 
+  @override
+  String netplayListRoomsFailed(String error) {
+    return 'Falha ao listar salas: $error';
+  }
+
   @override
   String netplaySwitchRoleFailed(String error) {
     return 'Falha na troca de função: $error';
   }
 
+  @override
+  String netplaySpectatorDelayFailed(String error) {
+    return 'Falha ao definir o atraso dos espectadores: $error';
+  }
+
   @override
   String get netplayInvalidRoomCode => 'Código de quarto inválido';
 
@@ -113,6 +113,31 @@ class AppLocalizationsRu extends AppLocalizations {
   @override
   String get turboLinkPressRelease => 'Link press/release';
 
+  @override
+  String get zapperTitle => 'Zapper';
+
+  @override
+  String get zapperPortLabel => 'Порт Zapper';
+
+  @override
+  String get zapperPortOff => 'Выкл.';
+
+  @override
+  String get zapperHint =>
+      'Цельтесь мышью или касанием; щелчок или касание — выстрел.';
+
+  @override
+  String get zapperLagCompensation => 'Компенсация задержки дисплея';
+
+  @override
+  String get zapperCrosshairTitle => 'Показывать прицел';
+
+  @override
+  String get zapperCalibrationX => 'Смещение прицела X';
+
+  @override
+  String get zapperCalibrationY => 'Смещение прицела Y';
+
   @override
   String get inputDeviceLabel => 'Устройство ввода';
 
@@ -342,6 +367,31 @@ class AppLocalizationsRu extends AppLocalizations {
   String get showOverlaySubtitle =>
       'Показывать индикаторы паузы/перемотки назад/вперед на экране.';
 
+  @override
+  String get clonePpuTitle => 'PPU фамиклона';
+
+  @override
+  String get clonePpuSubtitle =>
+      'Эмулирует PPU клона: без цветового акцента, палитра клона, честное переполнение спрайтов.';
+
+  @override
+  String get audioStereoTitle => 'Стерео';
+
+  @override
+  String get audioStereoSubtitle => 'Панорамирование каналов';
+
+  @override
+  String get stereoPresetMono => 'Моно';
+
+  @override
+  String get stereoPresetLight => 'Лёгкая панорама';
+
+  @override
+  String get stereoPresetMesenSpread => 'Разнос в стиле Mesen';
+
+  @override
+  String get audioExpansionBalanceTitle => 'Баланс чипов расширения';
+
   @override
   String get pauseInBackgroundTitle => 'Пауза в фоновом режиме';
 
@@ -579,9 +629,20 @@ class AppLocalizationsRu extends AppLocalizations {
   @override
   String get netplayTransportQuic => 'QUIC';
 
+  @override
+  String get netplayTransportWebRtc => 'WebRTC';
+
+  @override
+  String get netplayTransportTls => 'TLS';
+
   @override
   String get netplayUsingTcpFallback => 'Ошибка QUIC при использовании TCP';
 
+  @override
+  String netplayServerLatency(int rtt, int jitter, int loss) {
+    return 'Пинг $rtt мс, джиттер $jitter мс, потери $loss%';
+  }
+
   @override
   String get netplayStatusDisconnected => 'Отключено';
 
@@ -620,6 +681,14 @@ class AppLocalizationsRu extends AppLocalizations {
   String get netplayQuicFingerprintHelper =>
       'Введите это для использования привязанного QUIC. Оставьте пустым для использования системных сертификатов (QUIC) или отката на TCP.';
 
+  @override
+  String get netplayTlsFingerprintLabel =>
+      'Отпечаток сертификата TLS (необязательно)';
+
+  @override
+  String get netplayTlsFingerprintHelper =>
+      'Введите это, чтобы принимать только этот сертификат, например самоподписанный. Оставьте пустым для использования системных сертификатов.';
+
   @override
   String get netplayConnect => 'Присоединиться к игре';
 
@@ -635,6 +704,23 @@ class AppLocalizationsRu extends AppLocalizations {
   @override
   String get netplayJoinRoom => 'Присоединиться к игре';
 
+  @override
+  String get netplayPublicRooms => 'Открытые комнаты';
+
+  @override
+  String get netplayRefreshRooms => 'Обновить';
+
+  @override
+  String get netplayNoPublicRooms => 'Открытые комнаты не найдены.';
+
+  @override
+  String netplayRoomSummary(int players, int open, int spectators) {
+    return 'Игроков: $players, свободно: $open, зрителей: $spectators';
+  }
+
+  @override
+  String get netplayQuickMatch => 'Быстрая игра';
+
   @override
   String get netplayAddressOrRoomCode => 'Код комнаты или адрес сервера';
 
@@ -659,6 +745,13 @@ class AppLocalizationsRu extends AppLocalizations {
   @override
   String get netplayRoomCode => 'Код номера';
 
+  @override
+  String get netplayRoomPassword => 'Пароль комнаты';
+
+  @override
+  String get netplayRoomPasswordHint =>
+      'Необязательно; оставьте пустым для открытой комнаты';
+
   @override
   String get netplayRoleLabel => 'Роль';
 
@@ -670,6 +763,20 @@ class AppLocalizationsRu extends AppLocalizations {
   @override
   String get netplaySpectator => 'Зритель';
 
+  @override
+  String get netplaySpectatorCount => 'Зрители';
+
+  @override
+  String get netplaySpectatorDelayLabel => 'Задержка для зрителей (хост)';
+
+  @override
+  String get netplaySpectatorDelayLive => 'Без задержки';
+
+  @override
+  String netplaySpectatorDelaySeconds(int seconds) {
+    return 'Отставание $seconds с';
+  }
+
   @override
   String get netplayClientId => 'Идентификатор клиента';
 
@@ -702,11 +809,21 @@ class AppLocalizationsRu extends AppLocalizations {
     return 'Не удалось присоединиться к комнате: $error.';
   }
 
+  @override
+  String netplayListRoomsFailed(String error) {
+    return 'Не удалось получить список комнат: $error';
+  }
+
   @override
   String netplaySwitchRoleFailed(String error) {
     return 'Не удалось переключить роль: $error.';
   }
 
+  @override
+  String netplaySpectatorDelayFailed(String error) {
+    return 'Не удалось задать задержку для зрителей: $error';
+  }
+
   @override
   String get netplayInvalidRoomCode => 'Неверный код комнаты';
 
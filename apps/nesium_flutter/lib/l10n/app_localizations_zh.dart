@@ -111,6 +111,30 @@ class AppLocalizationsZh extends AppLocalizations {
   @override
   String get turboLinkPressRelease => '联动按下/抬起';
 
+  @override
+  String get zapperTitle => '光枪';
+
+  @override
+  String get zapperPortLabel => '光枪端口';
+
+  @override
+  String get zapperPortOff => '关闭';
+
+  @override
+  String get zapperHint => '用鼠标或触摸瞄准，点击或轻触射击。';
+
+  @override
+  String get zapperLagCompensation => '显示延迟补偿';
+
+  @override
+  String get zapperCrosshairTitle => '显示准星';
+
+  @override
+  String get zapperCalibrationX => '瞄准偏移 X';
+
+  @override
+  String get zapperCalibrationY => '瞄准偏移 Y';
+
   @override
   String get inputDeviceLabel => '输入设备';
 
@@ -336,6 +360,30 @@ class AppLocalizationsZh extends AppLocalizations {
   @override
   String get showOverlaySubtitle => '在屏幕上显示暂停/倒带/快进指示器。';
 
+  @override
+  String get clonePpuTitle => '兼容机 PPU';
+
+  @override
+  String get clonePpuSubtitle => '模拟兼容机 PPU：无颜色强调、兼容机调色板、真实的精灵溢出。';
+
+  @override
+  String get audioStereoTitle => '立体声';
+
+  @override
+  String get audioStereoSubtitle => '声道声像';
+
+  @override
+  String get stereoPresetMono => '单声道';
+
+  @override
+  String get stereoPresetLight => '轻度分离';
+
+  @override
+  String get stereoPresetMesenSpread => 'Mesen 风格展开';
+
+  @override
+  String get audioExpansionBalanceTitle => '扩展音源平衡';
+
   @override
   String get pauseInBackgroundTitle => '后台暂停';
 
@@ -562,9 +610,20 @@ class AppLocalizationsZh extends AppLocalizations {
   @override
   String get netplayTransportQuic => 'QUIC';
 
+  @override
+  String get netplayTransportWebRtc => 'WebRTC';
+
+  @override
+  String get netplayTransportTls => 'TLS';
+
   @override
   String get netplayUsingTcpFallback => 'QUIC 连接失败，已回退到 TCP';
 
+  @override
+  String netplayServerLatency(int rtt, int jitter, int loss) {
+    return '延迟 $rtt ms，抖动 $jitter ms，丢包 $loss%';
+  }
+
   @override
   String get netplayStatusDisconnected => '未连接';
 
@@ -602,6 +661,12 @@ class AppLocalizationsZh extends AppLocalizations {
   String get netplayQuicFingerprintHelper =>
       '填入此项以使用 Pinning。留空则使用系统信任 (QUIC) 或回退到 TCP。';
 
+  @override
+  String get netplayTlsFingerprintLabel => 'TLS 证书指纹 (可选)';
+
+  @override
+  String get netplayTlsFingerprintHelper => '填入此项则只接受该证书 (例如自签名服务器)。留空则使用系统信任。';
+
   @override
   String get netplayConnect => '加入游戏';
 
@@ -617,6 +682,23 @@ class AppLocalizationsZh extends AppLocalizations {
   @override
   String get netplayJoinRoom => '加入游戏';
 
+  @override
+  String get netplayPublicRooms => '公开房间';
+
+  @override
+  String get netplayRefreshRooms => '刷新';
+
+  @override
+  String get netplayNoPublicRooms => '未找到公开房间。';
+
+  @override
+  String netplayRoomSummary(int players, int open, int spectators) {
+    return '$players 名玩家，$open 个空位，$spectators 人观战';
+  }
+
+  @override
+  String get netplayQuickMatch => '快速匹配';
+
   @override
   String get netplayAddressOrRoomCode => '房间码或服务器地址';
 
@@ -641,6 +723,12 @@ class AppLocalizationsZh extends AppLocalizations {
   @override
   String get netplayRoomCode => '房间码';
 
+  @override
+  String get netplayRoomPassword => '房间密码';
+
+  @override
+  String get netplayRoomPasswordHint => '可选，留空则为公开房间';
+
   @override
   String get netplayRoleLabel => '角色';
 
@@ -652,6 +740,20 @@ class AppLocalizationsZh extends AppLocalizations {
   @override
   String get netplaySpectator => '旁观者';
 
+  @override
+  String get netplaySpectatorCount => '观战人数';
+
+  @override
+  String get netplaySpectatorDelayLabel => '观战延迟 (房主)';
+
+  @override
+  String get netplaySpectatorDelayLive => '实时';
+
+  @override
+  String netplaySpectatorDelaySeconds(int seconds) {
+    return '延迟 $seconds 秒';
+  }
+
   @override
   String get netplayClientId => '客户端 ID';
 
@@ -684,11 +786,21 @@ class AppLocalizationsZh extends AppLocalizations {
     return '加入房间失败: $error';
   }
 
+  @override
+  String netplayListRoomsFailed(String error) {
+    return '获取房间列表失败：$error';
+  }
+
   @override
   String netplaySwitchRoleFailed(String error) {
     return '切换角色失败: $error';
   }
 
+  @override
+  String netplaySpectatorDelayFailed(String error) {
+    return '设置观战延迟失败：$error';
+  }
+
   @override
   String get netplayInvalidRoomCode => '房间代码无效';
 
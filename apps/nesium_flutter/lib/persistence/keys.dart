@@ -2,6 +2,7 @@ final class StorageKeys {
   static const String settingsLanguage = 'settings.language.v1';
   static const String settingsTheme = 'settings.theme.v1';
   static const String settingsEmulation = 'settings.emulation.v2';
+  static const String settingsAudio = 'settings.audio.v1';
   static const String settingsVideo = 'settings.video.v1';
   static const String settingsVideoCustomPaletteBytes =
       'settings.video.custom_palette_bytes.v1';
@@ -33,11 +34,14 @@ final class StorageKeys {
       'settings.android.shader_preset_path.v1';
   static const String settingsInput = 'settings.input.v1';
   static const String settingsTurbo = 'settings.turbo.v1';
+  static const String settingsZapper = 'settings.zapper.v1';
   static const String settingsVirtualControls = 'settings.virtual_controls.v1';
   static const String settingsNetplayJoinP2PEnabled =
       'settings.netplay.join_p2p_enabled.v1';
   static const String settingsNetplayJoinP2PServerAddr =
       'settings.netplay.join_p2p_server_addr.v1';
+  static const String settingsNetplayUserToken =
+      'settings.netplay.user_token.v1';
   static const String settingsGamepad = 'settings.gamepad.v1';
   static const String settingsGamepadAssignments =
       'settings.gamepad.assignments.v1';
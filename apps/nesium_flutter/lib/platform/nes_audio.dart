@@ -0,0 +1,2 @@
+export 'nes_audio_io.dart' if (dart.library.js_interop) 'nes_audio_web.dart';
+export '../bridge/api/audio.dart' show ExpansionBalance, StereoPreset;
@@ -0,0 +1,6 @@
+import '../bridge/api/audio.dart' as frb_audio;
+
+Future<void> setAudioStereo({
+  required frb_audio.StereoPreset preset,
+  required frb_audio.ExpansionBalance balance,
+}) => frb_audio.setAudioStereo(preset: preset, balance: balance);
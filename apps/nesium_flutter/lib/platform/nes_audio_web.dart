@@ -0,0 +1,9 @@
+import '../bridge/api/audio.dart' as frb_audio;
+
+Future<void> setAudioStereo({
+  required frb_audio.StereoPreset preset,
+  required frb_audio.ExpansionBalance balance,
+}) {
+  // Stereo presets are not supported on web yet.
+  return Future.value();
+}
@@ -4,6 +4,9 @@ import '../bridge/api/emulation.dart' as frb_emulation;
 Future<void> setIntegerFpsMode({required bool enabled}) =>
     frb_emulation.setIntegerFpsMode(enabled: enabled);
 
+Future<void> setClonePpu({required bool enabled}) =>
+    frb_emulation.setClonePpu(enabled: enabled);
+
 Future<void> setRewindConfig({
   required bool enabled,
   required BigInt capacity,
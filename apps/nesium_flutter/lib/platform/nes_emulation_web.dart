@@ -6,6 +6,11 @@ Future<void> setIntegerFpsMode({required bool enabled}) async {
   webPostCmd('setIntegerFpsMode', {'enabled': enabled});
 }
 
+Future<void> setClonePpu({required bool enabled}) {
+  // The famiclone PPU mode is not supported on web yet.
+  return Future.value();
+}
+
 Future<void> setRewindConfig({
   required bool enabled,
   required BigInt capacity,
@@ -8,3 +8,24 @@ Future<void> setTurboMask({required int pad, required int mask}) =>
 
 Future<void> setTurboTiming({required int onFrames, required int offFrames}) =>
     frb_input.setTurboTiming(onFrames: onFrames, offFrames: offFrames);
+
+Future<void> setZapperPort({int? port}) => frb_input.setZapperPort(port: port);
+
+Future<void> setZapperPointer({required int x, required int y}) =>
+    frb_input.setZapperPointer(x: x, y: y);
+
+Future<void> clearZapperPointer() => frb_input.clearZapperPointer();
+
+Future<void> setZapperTrigger({required bool pulled}) =>
+    frb_input.setZapperTrigger(pulled: pulled);
+
+Future<void> setZapperCalibration({
+  required int offsetX,
+  required int offsetY,
+}) => frb_input.setZapperCalibration(offsetX: offsetX, offsetY: offsetY);
+
+Future<void> setZapperCrosshair({required bool visible}) =>
+    frb_input.setZapperCrosshair(visible: visible);
+
+Future<void> setZapperLagCompensation({required int frames}) =>
+    frb_input.setZapperLagCompensation(frames: frames);
@@ -19,6 +19,25 @@ Future<void> setTurboTiming({
   _flushTurboTiming();
 }
 
+// The Zapper is not supported on web yet.
+Future<void> setZapperPort({int? port}) => Future.value();
+
+Future<void> setZapperPointer({required int x, required int y}) =>
+    Future.value();
+
+Future<void> clearZapperPointer() => Future.value();
+
+Future<void> setZapperTrigger({required bool pulled}) => Future.value();
+
+Future<void> setZapperCalibration({
+  required int offsetX,
+  required int offsetY,
+}) => Future.value();
+
+Future<void> setZapperCrosshair({required bool visible}) => Future.value();
+
+Future<void> setZapperLagCompensation({required int frames}) => Future.value();
+
 final Map<int, int> _padMasks = <int, int>{};
 final Map<int, int> _turboMasks = <int, int>{};
 int _turboOnFrames = 2;
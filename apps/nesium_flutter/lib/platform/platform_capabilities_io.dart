@@ -22,5 +22,7 @@ bool get preferVirtualControlsByDefault => isNativeMobile;
 
 bool get supportsTcp => true;
 
+bool get supportsZapper => true;
+
 bool get useAndroidNativeGameView =>
     !kIsWeb && defaultTargetPlatform == TargetPlatform.android;
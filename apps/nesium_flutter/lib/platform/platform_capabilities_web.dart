@@ -18,4 +18,7 @@ bool get preferVirtualControlsByDefault => false;
 /// Web browsers do not support raw TCP sockets.
 bool get supportsTcp => false;
 
+/// The web core does not emulate the Zapper yet.
+bool get supportsZapper => false;
+
 bool get useAndroidNativeGameView => false;
@@ -23,9 +23,11 @@ import '../domain/emulation_status.dart';
 import '../features/controls/input_settings.dart';
 import '../domain/gamepad_service.dart';
 import '../features/controls/turbo_settings.dart';
+import '../features/controls/zapper_settings.dart';
 import '../features/save_state/auto_save_service.dart';
 import '../features/save_state/save_state_dialog.dart';
 import '../features/save_state/save_state_repository.dart';
+import '../features/settings/audio_settings.dart';
 import '../features/settings/emulation_settings.dart';
 import '../features/settings/language_settings.dart';
 import '../features/settings/settings_page.dart';
@@ -36,6 +38,7 @@ import '../features/netplay/netplay_screen.dart';
 import '../features/netplay/netplay_state.dart';
 import '../l10n/app_localizations.dart';
 import '../logging/app_logger.dart';
+import '../persistence/app_storage.dart';
 import '../platform/desktop_window_manager.dart';
 import '../platform/platform_capabilities.dart';
 import '../startup/launch_args.dart';
@@ -94,12 +97,19 @@ class _NesShellState extends ConsumerState<NesShell>
       _startEmulationStatusEvents();
       _startReplayEvents();
       _startNetplayEvents();
+      unawaitedLogged(
+        restoreNetplayUserToken(ref.read(appStorageProvider)),
+        message: 'Restore netplay identity',
+        logger: 'nes_shell',
+      );
       await ref.read(nesControllerProvider.notifier).initTexture();
 
       // Settings controllers will apply themselves to runtime via applyToRuntime()
       // in their build/init phases. We just need to ensure they are actually built.
       ref.read(videoSettingsProvider);
       ref.read(turboSettingsProvider);
+      ref.read(zapperSettingsProvider);
+      ref.read(audioSettingsProvider);
       ref.read(emulationSettingsProvider.notifier).applyToRuntime();
     });
     WidgetsBinding.instance.addPostFrameCallback((_) {
@@ -184,6 +194,30 @@ class _NesShellState extends ConsumerState<NesShell>
             final error = notification.error ?? 'unknown error';
             _showSnack('Audio init failed: $error');
             break;
+          case nes_events.RuntimeNotificationKind.emergencySaved:
+            final reason = notification.error ?? 'unknown reason';
+            _showSnack('Emergency save written ($reason)');
+            break;
+          case nes_events.RuntimeNotificationKind.emergencyStateAvailable:
+            ScaffoldMessenger.of(context).showSnackBar(
+              SnackBar(
+                content: const Text(
+                  'The last session did not end cleanly. Restore its state?',
+                ),
+                duration: const Duration(seconds: 10),
+                action: SnackBarAction(
+                  label: 'Restore',
+                  onPressed: () async {
+                    try {
+                      await nes_emulation.loadEmergencyState();
+                    } catch (e) {
+                      if (mounted) _showSnack('Restore failed: $e');
+                    }
+                  },
+                ),
+              ),
+            );
+            break;
         }
       },
       onError: (e, st) {
@@ -336,6 +370,46 @@ class _NesShellState extends ConsumerState<NesShell>
                     );
                   }
                 },
+                desync: (frame) async {
+                  if (mounted) _showSnack('Netplay: desync at frame $frame');
+                },
+                chat: (clientId, name, text, emote) async {
+                  if (mounted) _showSnack('$name: ${text ?? 'emote #$emote'}');
+                },
+                networkStats: (peers) async {
+                  ref.read(netplayNetworkStatsProvider.notifier).set(peers);
+                },
+                inputDelayRecommended: (frames) async {
+                  if (mounted) {
+                    _showSnack('Netplay: input delay set to $frames');
+                  }
+                },
+                roomList: (rooms) async {
+                  ref.read(netplayRoomListProvider.notifier).set(rooms);
+                },
+                spectatorInfo: (count, delayFrames) async {
+                  ref
+                      .read(netplaySpectatorInfoProvider.notifier)
+                      .set(
+                        NetplaySpectatorInfo(
+                          count: count,
+                          delayFrames: delayFrames,
+                        ),
+                      );
+                },
+                identity: (userId, userToken) async {
+                  await persistNetplayUserToken(
+                    ref.read(appStorageProvider),
+                    userToken,
+                  );
+                },
+                serverClosing: (graceMs) async {
+                  if (mounted) {
+                    _showSnack(
+                      'Netplay: server shutting down in ${graceMs ~/ 1000}s',
+                    );
+                  }
+                },
               );
             })
             .catchError((Object e, StackTrace st) {
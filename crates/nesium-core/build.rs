@@ -77,6 +77,8 @@ fn generate_cartridge_db() {
                 db_path.display()
             )
         });
+        let system = fields[1].trim();
+        let board = fields[2].trim();
         let mapper = parse_csv_u16(fields[5]);
         let submapper = parse_csv_u8(fields[15]);
         let prg_rom_size = parse_csv_usize(fields[6]) * 1024;
@@ -85,9 +87,14 @@ fn generate_cartridge_db() {
         let work_ram_size = parse_csv_usize(fields[9]) * 1024;
         let save_ram_size = parse_csv_usize(fields[10]) * 1024;
         let has_battery = parse_csv_u8(fields[11]) != 0;
+        let bus_conflicts = match fields[14].trim() {
+            "Y" => "Some(true)",
+            "N" => "Some(false)",
+            _ => "None",
+        };
 
         let value = format!(
-            "CartridgeDbEntry {{ rom_body_crc32: 0x{crc32:08X}, mapper: {mapper}, submapper: {submapper}, prg_rom_size: {prg_rom_size}, chr_rom_size: {chr_rom_size}, chr_ram_size: {chr_ram_size}, work_ram_size: {work_ram_size}, save_ram_size: {save_ram_size}, has_battery: {has_battery} }}"
+            "CartridgeDbEntry {{ rom_body_crc32: 0x{crc32:08X}, system: {system:?}, board: {board:?}, mapper: {mapper}, submapper: {submapper}, prg_rom_size: {prg_rom_size}, chr_rom_size: {chr_rom_size}, chr_ram_size: {chr_ram_size}, work_ram_size: {work_ram_size}, save_ram_size: {save_ram_size}, has_battery: {has_battery}, bus_conflicts: {bus_conflicts} }}"
         );
         entries.push((crc32, value));
     }
@@ -5,10 +5,10 @@
 //! the channel logic, frame sequencer, and mixer in well-scoped submodules so
 //! each hardware block is easy to follow and cross-reference against Nesdev.
 //!
-//! Remaining accuracy work:
-//! - TODO: Add PAL/Dendy timing tables (frame sequencer, noise/DMC rates) and a
-//!   region selector so PAL test ROMs can pass.
+//! Frame sequencer lengths and noise/DMC periods follow the region selected
+//! with [`Apu::set_region`]; CPU/PPU clocking is handled elsewhere.
 
+pub mod debug;
 mod dmc;
 mod envelope;
 pub mod expansion;
@@ -24,6 +24,7 @@ use core::fmt;
 use crate::{
     audio::{AudioChannel, NesSoundMixer},
     bus::CpuBus,
+    config::region::Region,
     context::Context,
     cpu::Cpu,
     mem_block::apu::RegisterRam,
@@ -56,6 +57,16 @@ struct StatusFlags {
 
 type LastLevels = crate::mem_block::MemBlock<f32, 5>;
 
+/// Optional, non-hardware channel tweaks that trade strict accuracy for
+/// cleaner audio. Both are disabled by default.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PopReduction {
+    /// Keep the triangle DAC frozen while the timer period is below 2.
+    pub silence_triangle_ultrasonic: bool,
+    /// Halve `$4011` direct-load jumps larger than 50 steps.
+    pub reduce_dmc_popping: bool,
+}
+
 /// Fully modelled NES APU with envelope, sweep, length/linear counters and the
 /// frame sequencer.
 #[cfg_attr(
@@ -77,6 +88,13 @@ pub struct Apu {
     /// power-on behaviour (acts as if `$00` were written) from warm resets,
     /// where hardware effectively re-applies the last written mode.
     last_frame_counter_value: u8,
+    /// Host-side audio options; not part of the emulated state.
+    #[cfg_attr(feature = "savestate-serde", serde(skip))]
+    pop_reduction: PopReduction,
+    /// Timing tables in use. Follows the inserted cartridge rather than the
+    /// snapshot, like the PPU's emphasis wiring.
+    #[cfg_attr(feature = "savestate-serde", serde(skip))]
+    region: Region,
 }
 
 impl fmt::Debug for Apu {
@@ -111,9 +129,33 @@ impl Apu {
             dmc: Dmc::default(),
             last_levels: LastLevels::new(),
             last_frame_counter_value: 0x00,
+            pop_reduction: PopReduction::default(),
+            region: Region::Ntsc,
         }
     }
 
+    /// Region whose timing tables are in use.
+    pub fn region(&self) -> Region {
+        self.region
+    }
+
+    /// Selects the frame sequencer and noise/DMC period tables. `Auto` is
+    /// treated as NTSC, and Dendy reuses the NTSC tables. Takes effect on the
+    /// next frame-counter step and period register write.
+    pub fn set_region(&mut self, region: Region) {
+        self.region = region;
+    }
+
+    /// Current pop-reduction options.
+    pub fn pop_reduction(&self) -> PopReduction {
+        self.pop_reduction
+    }
+
+    /// Enables/disables the optional pop-reduction tweaks.
+    pub fn set_pop_reduction(&mut self, pop_reduction: PopReduction) {
+        self.pop_reduction = pop_reduction;
+    }
+
     /// Applies either a power-on style reset or a warm reset to the APU.
     ///
     /// - `ResetKind::PowerOn` matches turning the console off and back on:
@@ -124,8 +166,9 @@ impl Apu {
     ///   `apu_reset` tests and implemented in Mesen2's
     ///   `ApuFrameCounter::Reset(softReset = true)`: channel registers are
     ///   preserved, channel state is rebuilt from the cached register RAM, and
-    ///   the frame counter is reconfigured as if the last value written to
-    ///   `$4017` were written again just before execution resumes.
+    ///   the frame counter is reconfigured as if the last mode written to
+    ///   `$4017` were written again just before execution resumes. IRQ
+    ///   inhibit is cleared and the frame IRQ flag is acknowledged.
     pub fn reset(&mut self, kind: ResetKind) {
         match kind {
             ResetKind::PowerOn => {
@@ -139,7 +182,7 @@ impl Apu {
                 ];
                 self.triangle = Triangle::default();
                 self.noise = Noise::default();
-                self.dmc = Dmc::default();
+                self.dmc = Dmc::new(tables::dmc_rate_table(self.region));
                 self.last_levels.fill(0.0);
                 self.last_frame_counter_value = 0x00;
 
@@ -156,9 +199,9 @@ impl Apu {
                 self.cycles = 0;
                 self.rebuild_channels_from_registers();
 
-                // Hardware reset re-applies the last written frame counter value just
-                // before execution resumes, with the same small latency as the
-                // implicit power-on write.
+                // Hardware reset re-applies the last written frame counter mode
+                // just before execution resumes, with the same small latency as
+                // the implicit power-on write. IRQ inhibit is not carried over.
                 let reset = self
                     .frame_counter
                     .configure_after_reset(self.last_frame_counter_value);
@@ -187,10 +230,18 @@ impl Apu {
                 apu_mem::Register::TriangleTimerLow => self.triangle.write_timer_low(value),
                 apu_mem::Register::TriangleTimerHigh => self.triangle.write_timer_high(value),
                 apu_mem::Register::NoiseControl => self.noise.write_control(value),
-                apu_mem::Register::NoiseModeAndPeriod => self.noise.write_mode_and_period(value),
+                apu_mem::Register::NoiseModeAndPeriod => self
+                    .noise
+                    .write_mode_and_period(value, tables::noise_period_table(self.region)),
                 apu_mem::Register::NoiseLength => self.noise.write_length(value),
-                apu_mem::Register::DmcControl => self.dmc.write_control(value, &mut self.status),
-                apu_mem::Register::DmcDirectLoad => self.dmc.write_direct_load(value),
+                apu_mem::Register::DmcControl => self.dmc.write_control(
+                    value,
+                    &mut self.status,
+                    tables::dmc_rate_table(self.region),
+                ),
+                apu_mem::Register::DmcDirectLoad => self
+                    .dmc
+                    .write_direct_load(value, self.pop_reduction.reduce_dmc_popping),
                 apu_mem::Register::DmcSampleAddress => self.dmc.write_sample_address(value),
                 apu_mem::Register::DmcSampleLength => self.dmc.write_sample_length(value),
                 apu_mem::Register::Status => self.write_status(value, cpu_cycle),
@@ -253,6 +304,21 @@ impl Apu {
         value
     }
 
+    /// Copies every channel's internal state for an APU viewer. Expansion
+    /// levels are left zeroed; [`Nes::apu_channel_state`](crate::Nes::apu_channel_state)
+    /// fills them in from the cartridge.
+    pub fn channel_state(&self) -> debug::ApuChannelState {
+        debug::ApuChannelState {
+            pulse: [self.pulse[0].state(), self.pulse[1].state()],
+            triangle: self.triangle.state(),
+            noise: self.noise.state(),
+            dmc: self.dmc.state(&self.status),
+            frame_counter_mode: self.frame_counter.mode(),
+            frame_irq_pending: self.status.frame_interrupt,
+            expansion: ExpansionAudioSnapshot::default(),
+        }
+    }
+
     /// Returns `true` when either the frame sequencer or DMC have latched an IRQ.
     pub fn irq_pending(&self) -> bool {
         self.status.frame_interrupt || self.status.dmc_interrupt
@@ -292,7 +358,7 @@ impl Apu {
         let apu = &mut bus.apu;
         apu.cycles = apu.cycles.wrapping_add(1);
 
-        let tick = apu.frame_counter.step();
+        let tick = apu.frame_counter.step(apu.region);
 
         if tick.quarter {
             apu.step_quarter_frame();
@@ -313,7 +379,8 @@ impl Apu {
         for pulse in &mut apu.pulse {
             pulse.step_timer();
         }
-        apu.triangle.step_timer();
+        apu.triangle
+            .step_timer(apu.pop_reduction.silence_triangle_ultrasonic);
         apu.noise.step_timer();
         apu.dmc.step(bus.pending_dma);
 
@@ -411,7 +478,7 @@ impl Apu {
         ];
         self.triangle = Triangle::default();
         self.noise = Noise::default();
-        self.dmc = Dmc::default();
+        self.dmc = Dmc::new(tables::dmc_rate_table(self.region));
         self.last_levels.fill(0.0);
 
         let reg = |r: apu_mem::Register| -> u8 {
@@ -442,16 +509,21 @@ impl Apu {
         // Noise configuration.
         self.noise
             .write_control(reg(apu_mem::Register::NoiseControl));
-        self.noise
-            .write_mode_and_period(reg(apu_mem::Register::NoiseModeAndPeriod));
+        self.noise.write_mode_and_period(
+            reg(apu_mem::Register::NoiseModeAndPeriod),
+            tables::noise_period_table(self.region),
+        );
         self.noise.write_length(reg(apu_mem::Register::NoiseLength));
 
         // DMC configuration registers are preserved across reset; enabling
         // still requires a post-reset `$4015` write.
+        self.dmc.write_control(
+            reg(apu_mem::Register::DmcControl),
+            &mut self.status,
+            tables::dmc_rate_table(self.region),
+        );
         self.dmc
-            .write_control(reg(apu_mem::Register::DmcControl), &mut self.status);
-        self.dmc
-            .write_direct_load(reg(apu_mem::Register::DmcDirectLoad));
+            .write_direct_load(reg(apu_mem::Register::DmcDirectLoad), false);
         self.dmc
             .write_sample_address(reg(apu_mem::Register::DmcSampleAddress));
         self.dmc
@@ -475,6 +547,48 @@ mod tests {
         assert_eq!(apu.registers[8], 0x0F);
     }
 
+    #[test]
+    fn channel_state_reflects_register_writes() {
+        let mut apu = Apu::new();
+        apu.cpu_write(apu_mem::STATUS, 0b0001_0011, 0);
+        apu.cpu_write(0x4004, 0b1011_0110, 0); // duty 2, constant volume 6
+        apu.cpu_write(0x4005, 0b1010_1011, 0); // sweep on, period 3, negate, shift 3
+        apu.cpu_write(0x4006, 0x34, 0);
+        apu.cpu_write(0x4007, 0b0000_1010, 0); // length index 1, period high 2
+        apu.cpu_write(0x400E, 0x83, 0);
+        apu.cpu_write(0x4010, 0xCF, 0);
+        apu.cpu_write(0x4012, 0x10, 0);
+        apu.cpu_write(0x4013, 0x02, 0);
+        apu.commit_length_halt_flags();
+
+        let state = apu.channel_state();
+        let pulse = state.pulse[1];
+        assert!(pulse.enabled && !state.pulse[0].length.halted);
+        assert_eq!((pulse.duty, pulse.period), (2, 0x234));
+        assert_eq!(pulse.envelope.volume, 6);
+        assert!(pulse.envelope.loop_flag && pulse.length.halted);
+        assert_eq!(pulse.length.value, 254);
+        assert_eq!(
+            (
+                pulse.sweep.enabled,
+                pulse.sweep.negate,
+                pulse.sweep.period,
+                pulse.sweep.shift
+            ),
+            (true, true, 3, 3)
+        );
+
+        assert!(state.noise.short_mode && !state.noise.enabled);
+        assert_eq!(state.noise.period, 32);
+        assert!(state.dmc.irq_enabled && state.dmc.loop_flag);
+        assert_eq!(state.dmc.rate_index, 15);
+        assert_eq!(
+            (state.dmc.sample_address, state.dmc.sample_length),
+            (0xC400, 33)
+        );
+        assert_eq!(state.frame_counter_mode, FrameCounterMode::FourStep);
+    }
+
     #[test]
     fn status_enables_channels_and_length_counters() {
         let mut apu = Apu::new();
@@ -501,13 +615,13 @@ mod tests {
         assert_eq!(apu.frame_counter.mode(), FrameCounterMode::FourStep);
     }
 
-    #[test]
-    fn frame_irq_flag_set_and_cleared() {
-        let mut apu = Apu::new();
-        apu.cpu_write(apu_mem::FRAME_COUNTER, 0, 0); // 4-step, IRQs enabled
+    /// Steps `apu` for `cycles` CPU cycles on a bare bus.
+    fn run_cycles(apu: &mut Apu, cycles: u32) {
         let mut ram = crate::mem_block::cpu::Ram::new();
         let mut ppu = crate::ppu::Ppu::default();
         let mut controllers = crate::controller::ControllerPorts::new();
+        let zappers = crate::controller::ZapperPorts::default();
+        let mut multitap = None;
         let mut pending_dma = crate::bus::PendingDma::default();
         let mut open_bus = crate::bus::OpenBus::new();
         let mut cpu_cycles = 0u64;
@@ -516,34 +630,101 @@ mod tests {
         let mut bus = CpuBus {
             ram: &mut ram,
             ppu: &mut ppu,
-            apu: &mut apu,
+            apu,
             cartridge: None,
             controllers: &mut controllers,
+            zappers: &zappers,
+            multitap: &mut multitap,
             serial_log: None,
             open_bus: &mut open_bus,
             mixer: None,
+            debugger: None,
+            unmapped_writes: None,
             cycles: &mut cpu_cycles,
             master_clock: &mut master_clock,
             ppu_offset: 0,
             clock_start_count: 0,
             clock_end_count: 0,
             pending_dma: &mut pending_dma,
+            famicom: false,
         };
         let mut cpu = Cpu::new();
         let mut ctx = Context::None;
 
-        for _ in 0..=frame_counter::FRAME_STEP_4_PERIOD as u64 {
+        for _ in 0..cycles {
             Apu::step(&mut bus, &mut cpu, &mut ctx);
         }
-        assert!(bus.apu.status.frame_interrupt);
+    }
+
+    #[test]
+    fn frame_irq_flag_set_and_cleared() {
+        let mut apu = Apu::new();
+        apu.cpu_write(apu_mem::FRAME_COUNTER, 0, 0); // 4-step, IRQs enabled
+
+        run_cycles(&mut apu, frame_counter::FRAME_STEP_4_PERIOD + 1);
+        assert!(apu.status.frame_interrupt);
 
-        let first = bus.apu.cpu_read(apu_mem::STATUS);
+        let first = apu.cpu_read(apu_mem::STATUS);
         assert_eq!(first & 0b0100_0000, 0b0100_0000);
 
-        let second = bus.apu.cpu_read(apu_mem::STATUS);
+        let second = apu.cpu_read(apu_mem::STATUS);
         assert_eq!(second & 0b0100_0000, 0);
     }
 
+    #[test]
+    fn pal_frame_irq_uses_longer_sequence() {
+        let mut apu = Apu::new();
+        apu.set_region(Region::Pal);
+        apu.cpu_write(apu_mem::FRAME_COUNTER, 0, 0);
+
+        run_cycles(&mut apu, frame_counter::FRAME_STEP_4_PERIOD + 1);
+        assert!(!apu.status.frame_interrupt);
+
+        run_cycles(
+            &mut apu,
+            frame_counter::FRAME_STEP_4_PAL[5] - frame_counter::FRAME_STEP_4_PERIOD,
+        );
+        assert!(apu.status.frame_interrupt);
+    }
+
+    #[test]
+    fn pal_region_selects_noise_and_dmc_periods() {
+        let mut apu = Apu::new();
+        apu.set_region(Region::Pal);
+        apu.cpu_write(0x400E, 0x03, 0);
+        apu.cpu_write(0x4010, 0x0F, 0);
+        let state = apu.channel_state();
+        assert_eq!(state.noise.period, 30);
+        assert_eq!(state.dmc.period, 50);
+
+        // Dendy keeps the NTSC 2A03 tables.
+        apu.set_region(Region::Dendy);
+        apu.cpu_write(0x400E, 0x03, 0);
+        apu.cpu_write(0x4010, 0x0F, 0);
+        let state = apu.channel_state();
+        assert_eq!(state.noise.period, 32);
+        assert_eq!(state.dmc.period, 54);
+    }
+
+    #[test]
+    fn soft_reset_keeps_frame_mode_and_clears_inhibit() {
+        let mut apu = Apu::new();
+        apu.cpu_write(apu_mem::FRAME_COUNTER, 0xC0, 0); // 5-step, IRQ inhibit
+        run_cycles(&mut apu, 4);
+        assert_eq!(apu.frame_counter.mode(), FrameCounterMode::FiveStep);
+
+        apu.status.frame_interrupt = true;
+        apu.reset(ResetKind::Soft);
+        assert!(!apu.status.frame_interrupt);
+        run_cycles(&mut apu, 4);
+        assert_eq!(apu.frame_counter.mode(), FrameCounterMode::FiveStep);
+
+        // With inhibit cleared, switching back to 4-step raises the IRQ.
+        apu.cpu_write(apu_mem::FRAME_COUNTER, 0x00, 0);
+        run_cycles(&mut apu, frame_counter::FRAME_STEP_4_PERIOD + 4);
+        assert!(apu.status.frame_interrupt);
+    }
+
     #[test]
     fn dmc_status_bit_and_irq_clear_on_write() {
         let mut apu = Apu::new();
@@ -566,4 +747,23 @@ mod tests {
         let third = apu.cpu_read(apu_mem::STATUS);
         assert_eq!(third & 0b1000_0000, 0);
     }
+
+    #[test]
+    fn dmc_direct_load_pop_reduction_halves_large_jumps() {
+        let mut apu = Apu::new();
+        apu.cpu_write(0x4011, 0x7F, 0);
+        assert_eq!(apu.dmc.output(), 0x7F);
+        apu.cpu_write(0x4011, 0x00, 0);
+        assert_eq!(apu.dmc.output(), 0x00);
+
+        apu.set_pop_reduction(PopReduction {
+            reduce_dmc_popping: true,
+            ..PopReduction::default()
+        });
+        apu.cpu_write(0x4011, 0x7F, 0);
+        assert_eq!(apu.dmc.output(), 64);
+        // Small jumps are unaffected.
+        apu.cpu_write(0x4011, 40, 0);
+        assert_eq!(apu.dmc.output(), 40);
+    }
 }
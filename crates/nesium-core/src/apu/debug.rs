@@ -0,0 +1,128 @@
+//! Read-only snapshots of APU channel state for an APU viewer.
+//!
+//! [`Nes::apu_channel_state`](crate::Nes::apu_channel_state) copies the
+//! internal counters of every channel, plus the cartridge's expansion audio
+//! levels, into plain structs. Taking a snapshot does not touch the APU, so
+//! frontends can poll it once per frame to drive an oscilloscope or register
+//! view without decoding `$4000-$4017` themselves.
+
+use super::{ExpansionAudioSnapshot, FrameCounterMode};
+
+/// Envelope generator shared by the pulse and noise channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct EnvelopeState {
+    /// Volume currently fed to the channel (constant volume or decay level).
+    pub volume: u8,
+    pub constant_volume: bool,
+    /// Also halts the length counter.
+    pub loop_flag: bool,
+    /// `$4000`/`$400C` bits 0-3: constant volume or divider period.
+    pub period: u8,
+    pub divider: u8,
+    pub decay_level: u8,
+}
+
+/// Length counter state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LengthState {
+    pub value: u8,
+    pub halted: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SweepState {
+    pub enabled: bool,
+    pub negate: bool,
+    pub shift: u8,
+    /// Divider period in half frames (register value + 1).
+    pub period: u8,
+    pub divider: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PulseState {
+    pub enabled: bool,
+    /// Duty cycle index (0-3: 12.5%, 25%, 50%, 75% negated).
+    pub duty: u8,
+    /// Position in the 8-step duty sequence.
+    pub duty_position: u8,
+    /// 11-bit timer period from `$4002/$4003`.
+    pub period: u16,
+    /// Current timer value, in CPU cycles.
+    pub timer: u16,
+    pub envelope: EnvelopeState,
+    pub length: LengthState,
+    pub sweep: SweepState,
+    /// Silenced by the sweep unit (period < 8 or target overflow).
+    pub sweep_muted: bool,
+    /// Current DAC input (0-15).
+    pub output: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TriangleState {
+    pub enabled: bool,
+    /// 11-bit timer period from `$400A/$400B`.
+    pub period: u16,
+    pub timer: u16,
+    /// Position in the 32-step triangle sequence.
+    pub sequence_position: u8,
+    pub linear_counter: u8,
+    pub linear_reload_value: u8,
+    /// `$4008` bit 7: linear counter control / length counter halt.
+    pub control: bool,
+    pub length: LengthState,
+    /// Current DAC input (0-15).
+    pub output: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NoiseState {
+    pub enabled: bool,
+    /// Short (93-step) LFSR mode.
+    pub short_mode: bool,
+    /// Timer period in CPU cycles, from the noise period table.
+    pub period: u16,
+    pub timer: u16,
+    pub shift_register: u16,
+    pub envelope: EnvelopeState,
+    pub length: LengthState,
+    /// Current DAC input (0-15).
+    pub output: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DmcState {
+    /// A sample is playing (bytes remain to be fetched).
+    pub active: bool,
+    pub irq_enabled: bool,
+    pub irq_pending: bool,
+    pub loop_flag: bool,
+    pub rate_index: u8,
+    /// Bit period in CPU cycles.
+    pub period: u16,
+    pub timer: u16,
+    /// 7-bit DAC level.
+    pub output: u8,
+    /// Sample start address and length as programmed via `$4012/$4013`.
+    pub sample_address: u16,
+    pub sample_length: u16,
+    /// Address of the next DMA fetch and bytes left in the sample.
+    pub current_address: u16,
+    pub bytes_remaining: u16,
+    pub buffer_empty: bool,
+    pub bits_remaining: u8,
+}
+
+/// Snapshot of every APU channel plus mapper expansion audio levels.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ApuChannelState {
+    pub pulse: [PulseState; 2],
+    pub triangle: TriangleState,
+    pub noise: NoiseState,
+    pub dmc: DmcState,
+    pub frame_counter_mode: FrameCounterMode,
+    pub frame_irq_pending: bool,
+    /// Expansion chip output levels; all zero when the cartridge has none.
+    pub expansion: ExpansionAudioSnapshot,
+}
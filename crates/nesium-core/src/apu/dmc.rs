@@ -2,6 +2,7 @@
 
 use super::{
     StatusFlags,
+    debug::DmcState,
     tables::{DMC_RATE_TABLE, DMC_SAMPLE_ADDR_STRIDE, DMC_SAMPLE_BASE, DMC_SAMPLE_LEN_STRIDE},
 };
 use crate::bus::{DmcDmaEvent, PendingDma};
@@ -46,6 +47,13 @@ pub(super) struct Dmc {
 
 impl Default for Dmc {
     fn default() -> Self {
+        Self::new(&DMC_RATE_TABLE)
+    }
+}
+
+impl Dmc {
+    /// Power-on DMC state using the given region's rate table.
+    pub(super) fn new(rates: &[u16; 16]) -> Self {
         Self {
             irq_enable: false,
             loop_flag: false,
@@ -65,19 +73,17 @@ impl Default for Dmc {
             // consumed over a full 8-bit period.
             bits_remaining: 8,
             silence: true,
-            // Default to the first (slowest) NTSC rate. The effective DMC
-            // bit period in CPU cycles is `timer_period + 1`, matching the
-            // lookup table entries.
-            timer: DMC_RATE_TABLE[0] - 1,
-            timer_period: DMC_RATE_TABLE[0] - 1,
+            // Default to the first (slowest) rate. The effective DMC bit
+            // period in CPU cycles is `timer_period + 1`, matching the lookup
+            // table entries.
+            timer: rates[0] - 1,
+            timer_period: rates[0] - 1,
             last_fetch_addr: 0,
             pending_fetch: None,
         }
     }
-}
 
-impl Dmc {
-    pub(super) fn write_control(&mut self, value: u8, status: &mut StatusFlags) {
+    pub(super) fn write_control(&mut self, value: u8, status: &mut StatusFlags, rates: &[u16; 16]) {
         self.irq_enable = value & 0b1000_0000 != 0;
         if !self.irq_enable {
             status.dmc_interrupt = false;
@@ -90,12 +96,18 @@ impl Dmc {
         // output tick occurs every `lookup[index]` CPU cycles. We mirror that
         // convention here so bit timing aligns with Mesen2's DMC behaviour.
         let idx = self.rate_index as usize;
-        self.timer_period = DMC_RATE_TABLE[idx].saturating_sub(1);
+        self.timer_period = rates[idx].saturating_sub(1);
     }
 
-    pub(super) fn write_direct_load(&mut self, value: u8) {
+    pub(super) fn write_direct_load(&mut self, value: u8, reduce_popping: bool) {
         // Hardware: direct 7-bit DAC load, no smoothing.
+        let previous = self.output_level;
         self.output_level = value & 0b0111_1111;
+        if reduce_popping && self.output_level.abs_diff(previous) > 50 {
+            // Mesen2's `ReduceDmcPopping`: only jump halfway to the new level.
+            let half = (self.output_level as i16 - previous as i16) / 2;
+            self.output_level = (self.output_level as i16 - half) as u8;
+        }
     }
 
     pub(super) fn write_sample_address(&mut self, value: u8) {
@@ -133,6 +145,25 @@ impl Dmc {
         self.output_level
     }
 
+    pub(super) fn state(&self, status: &StatusFlags) -> DmcState {
+        DmcState {
+            active: self.active(),
+            irq_enabled: self.irq_enable,
+            irq_pending: status.dmc_interrupt,
+            loop_flag: self.loop_flag,
+            rate_index: self.rate_index,
+            period: self.timer_period + 1,
+            timer: self.timer,
+            output: self.output_level,
+            sample_address: self.sample_address,
+            sample_length: self.sample_length,
+            current_address: self.current_address,
+            bytes_remaining: self.bytes_remaining,
+            buffer_empty: self.buffer_empty,
+            bits_remaining: self.bits_remaining,
+        }
+    }
+
     fn restart_sample(&mut self) {
         self.current_address = self.sample_address;
         self.bytes_remaining = self.sample_length;
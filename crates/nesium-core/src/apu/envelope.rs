@@ -1,5 +1,7 @@
 //! Envelope unit shared by pulse and noise channels.
 
+use super::debug::EnvelopeState;
+
 #[cfg_attr(
     feature = "savestate-serde",
     derive(serde::Serialize, serde::Deserialize)
@@ -56,4 +58,15 @@ impl Envelope {
     pub(super) fn halt_length(&self) -> bool {
         self.loop_flag
     }
+
+    pub(super) fn state(&self) -> EnvelopeState {
+        EnvelopeState {
+            volume: self.output(),
+            constant_volume: self.constant_volume,
+            loop_flag: self.loop_flag,
+            period: self.volume,
+            divider: self.divider,
+            decay_level: self.decay_level,
+        }
+    }
 }
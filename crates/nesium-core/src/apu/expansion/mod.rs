@@ -28,7 +28,7 @@ pub struct ExpansionAudioClockContext {
 }
 
 /// Debug/inspection snapshot of current expansion-audio levels.
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct ExpansionAudioSnapshot {
     pub fds: f32,
     pub mmc5: f32,
@@ -1,6 +1,9 @@
 //! Frame sequencer responsible for clocking envelopes, length counters, and
 //! sweep units at quarter- and half-frame intervals.
 
+use super::tables::uses_pal_tables;
+use crate::config::region::Region;
+
 /// Frame sequencer timing mode.
 #[cfg_attr(
     feature = "savestate-serde",
@@ -37,6 +40,10 @@ const FRAME_TYPES: [FrameType; 6] = [
 pub(super) const FRAME_STEP_4: [u32; 6] = [7457, 14913, 22371, 29828, 29829, 29830];
 /// Mesen2 NTSC 5-step timeline (CPU cycles).
 pub(super) const FRAME_STEP_5: [u32; 6] = [7457, 14913, 22371, 29829, 37281, 37282];
+/// Mesen2 PAL 4-step timeline (CPU cycles).
+pub(super) const FRAME_STEP_4_PAL: [u32; 6] = [8313, 16627, 24939, 33252, 33253, 33254];
+/// Mesen2 PAL 5-step timeline (CPU cycles).
+pub(super) const FRAME_STEP_5_PAL: [u32; 6] = [8313, 16627, 24939, 33253, 41565, 41566];
 
 pub(super) const FRAME_STEP_4_PERIOD: u32 = FRAME_STEP_4[5];
 pub(super) const FRAME_STEP_5_PERIOD: u32 = FRAME_STEP_5[5];
@@ -114,10 +121,12 @@ impl FrameCounter {
         self.mode
     }
 
-    fn step_cycles(&self) -> &'static [u32; 6] {
-        match self.mode {
-            FrameCounterMode::FourStep => &FRAME_STEP_4,
-            FrameCounterMode::FiveStep => &FRAME_STEP_5,
+    fn step_cycles(&self, region: Region) -> &'static [u32; 6] {
+        match (self.mode, uses_pal_tables(region)) {
+            (FrameCounterMode::FourStep, false) => &FRAME_STEP_4,
+            (FrameCounterMode::FiveStep, false) => &FRAME_STEP_5,
+            (FrameCounterMode::FourStep, true) => &FRAME_STEP_4_PAL,
+            (FrameCounterMode::FiveStep, true) => &FRAME_STEP_5_PAL,
         }
     }
 
@@ -142,12 +151,15 @@ impl FrameCounter {
     }
 
     /// Schedule the implicit `$4017` write performed during reset.
+    ///
+    /// Only the mode bit of `value` is honoured; reset always clears the IRQ
+    /// inhibit flag (blargg `apu_reset/4017_written`).
     pub(super) fn configure_after_reset(&mut self, value: u8) -> FrameResetAction {
         self.sequence_cycle = 0;
         self.current_step = 0;
         self.block_frame_counter_tick = 0;
         self.pending_write = Some(PendingWrite {
-            value,
+            value: value & 0x80,
             delay_cycles: RESET_WRITE_DELAY_CYCLES,
         });
         // Mesen reset path clears inhibit and applies pending mode later.
@@ -223,11 +235,11 @@ impl FrameCounter {
     }
 
     /// Advances the frame counter by one CPU cycle and reports frame events.
-    pub(super) fn step(&mut self) -> FrameTick {
+    pub(super) fn step(&mut self, region: Region) -> FrameTick {
         let mut tick = FrameTick::default();
 
         self.sequence_cycle = self.sequence_cycle.wrapping_add(1);
-        let step_cycles = self.step_cycles();
+        let step_cycles = self.step_cycles(region);
 
         if self.sequence_cycle == step_cycles[self.current_step as usize] {
             // In 4-step mode, frame IRQ is asserted on the last 3 cycles.
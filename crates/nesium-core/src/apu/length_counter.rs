@@ -1,6 +1,6 @@
 //! Shared length counter used by pulse, triangle, and noise channels.
 
-use super::tables::LENGTH_TABLE;
+use super::{debug::LengthState, tables::LENGTH_TABLE};
 
 #[cfg_attr(
     feature = "savestate-serde",
@@ -52,4 +52,11 @@ impl LengthCounter {
             self.value -= 1;
         }
     }
+
+    pub(super) fn state(&self) -> LengthState {
+        LengthState {
+            value: self.value,
+            halted: self.halt,
+        }
+    }
 }
@@ -1,6 +1,9 @@
 //! Noise channel state and linear feedback shift register (LFSR).
 
-use super::{envelope::Envelope, length_counter::LengthCounter, tables::NOISE_PERIOD_TABLE};
+use super::{
+    debug::NoiseState, envelope::Envelope, length_counter::LengthCounter,
+    tables::NOISE_PERIOD_TABLE,
+};
 
 #[cfg_attr(
     feature = "savestate-serde",
@@ -39,10 +42,10 @@ impl Noise {
         self.length.set_halt_pending(self.envelope.halt_length());
     }
 
-    pub(super) fn write_mode_and_period(&mut self, value: u8) {
+    pub(super) fn write_mode_and_period(&mut self, value: u8, periods: &[u16; 16]) {
         self.mode = value & 0b1000_0000 != 0;
         let idx = (value & 0b0000_1111) as usize;
-        self.timer_period = NOISE_PERIOD_TABLE[idx] - 1;
+        self.timer_period = periods[idx] - 1;
     }
 
     pub(super) fn write_length(&mut self, value: u8) {
@@ -95,6 +98,19 @@ impl Noise {
         self.last_output
     }
 
+    pub(super) fn state(&self) -> NoiseState {
+        NoiseState {
+            enabled: self.enabled,
+            short_mode: self.mode,
+            period: self.timer_period + 1,
+            timer: self.timer,
+            shift_register: self.shift_register,
+            envelope: self.envelope.state(),
+            length: self.length.state(),
+            output: self.last_output,
+        }
+    }
+
     pub(super) fn length_active(&self) -> bool {
         self.length.active()
     }
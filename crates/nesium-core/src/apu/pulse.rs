@@ -1,6 +1,11 @@
 //! Pulse channel state, including sweep and envelope units.
 
-use super::{envelope::Envelope, length_counter::LengthCounter, tables::PULSE_DUTY_TABLE};
+use super::{
+    debug::{PulseState, SweepState},
+    envelope::Envelope,
+    length_counter::LengthCounter,
+    tables::PULSE_DUTY_TABLE,
+};
 
 #[cfg_attr(
     feature = "savestate-serde",
@@ -72,6 +77,16 @@ impl Sweep {
         }
     }
 
+    fn state(&self) -> SweepState {
+        SweepState {
+            enabled: self.enabled,
+            negate: self.negate,
+            shift: self.shift,
+            period: self.period,
+            divider: self.divider,
+        }
+    }
+
     pub(super) fn clock(&mut self, timer_period: &mut u16) {
         let should_mutate = self.enabled && self.shift != 0 && !self.muted(*timer_period);
 
@@ -220,6 +235,21 @@ impl Pulse {
         self.current_output = self.compute_output();
     }
 
+    pub(super) fn state(&self) -> PulseState {
+        PulseState {
+            enabled: self.enabled,
+            duty: self.duty,
+            duty_position: self.duty_pos,
+            period: self.timer_period,
+            timer: self.timer,
+            envelope: self.envelope.state(),
+            length: self.length.state(),
+            sweep: self.sweep.state(),
+            sweep_muted: self.sweep.muted(self.timer_period),
+            output: self.current_output,
+        }
+    }
+
     pub(super) fn length_active(&self) -> bool {
         self.length.active()
     }
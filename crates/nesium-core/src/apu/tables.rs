@@ -1,6 +1,9 @@
 //! Lookup tables used by multiple APU blocks.
 //!
-//! TODO: Provide PAL/Dendy variants for region switching.
+//! Period tables come in NTSC and PAL flavours. Dendy clones keep the NTSC
+//! 2A03 tables and only differ in CPU clock rate, so they share the NTSC set.
+
+use crate::config::region::Region;
 
 /// Length counter reload values (indexed by the 5-bit length field).
 pub(super) const LENGTH_TABLE: [u8; 32] = [
@@ -27,11 +30,44 @@ pub(super) const NOISE_PERIOD_TABLE: [u16; 16] = [
     4, 8, 16, 32, 64, 96, 128, 160, 202, 254, 380, 508, 762, 1016, 2034, 4068,
 ];
 
+/// Noise periods in CPU cycles (PAL).
+pub(super) const NOISE_PERIOD_TABLE_PAL: [u16; 16] = [
+    4, 8, 14, 30, 60, 88, 118, 148, 188, 236, 354, 472, 708, 944, 1890, 3778,
+];
+
 /// DMC bit rates in CPU cycles (NTSC).
 pub(super) const DMC_RATE_TABLE: [u16; 16] = [
     428, 380, 340, 320, 286, 254, 226, 214, 190, 160, 142, 128, 106, 84, 72, 54,
 ];
 
+/// DMC bit rates in CPU cycles (PAL).
+pub(super) const DMC_RATE_TABLE_PAL: [u16; 16] = [
+    398, 354, 316, 298, 276, 236, 210, 198, 176, 148, 132, 118, 98, 78, 66, 50,
+];
+
+/// Whether `region` runs the PAL 2A07 APU timing tables.
+pub(super) fn uses_pal_tables(region: Region) -> bool {
+    region == Region::Pal
+}
+
+/// Noise period table for `region`.
+pub(super) fn noise_period_table(region: Region) -> &'static [u16; 16] {
+    if uses_pal_tables(region) {
+        &NOISE_PERIOD_TABLE_PAL
+    } else {
+        &NOISE_PERIOD_TABLE
+    }
+}
+
+/// DMC rate table for `region`.
+pub(super) fn dmc_rate_table(region: Region) -> &'static [u16; 16] {
+    if uses_pal_tables(region) {
+        &DMC_RATE_TABLE_PAL
+    } else {
+        &DMC_RATE_TABLE
+    }
+}
+
 pub(super) const DMC_SAMPLE_BASE: u16 = 0xC000;
 pub(super) const DMC_SAMPLE_ADDR_STRIDE: u16 = 64;
 pub(super) const DMC_SAMPLE_LEN_STRIDE: u16 = 16;
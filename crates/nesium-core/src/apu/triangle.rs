@@ -1,6 +1,6 @@
 //! Triangle channel state and linear counter.
 
-use super::{length_counter::LengthCounter, tables::TRIANGLE_SEQUENCE};
+use super::{debug::TriangleState, length_counter::LengthCounter, tables::TRIANGLE_SEQUENCE};
 
 #[cfg_attr(
     feature = "savestate-serde",
@@ -57,12 +57,19 @@ impl Triangle {
         }
     }
 
-    pub(super) fn step_timer(&mut self) {
+    /// Clocks the triangle timer.
+    ///
+    /// With `silence_ultrasonic` set, the sequencer keeps advancing for
+    /// periods below 2 but the DAC output is left untouched (Mesen2's
+    /// `SilenceTriangleHighFreq`).
+    pub(super) fn step_timer(&mut self, silence_ultrasonic: bool) {
         if self.timer == 0 {
             self.timer = self.timer_period;
             if self.length.active() && self.linear_counter > 0 {
                 self.sequence_pos = (self.sequence_pos + 1) & 0b1_1111;
-                self.last_output = TRIANGLE_SEQUENCE[self.sequence_pos as usize];
+                if self.timer_period >= 2 || !silence_ultrasonic {
+                    self.last_output = TRIANGLE_SEQUENCE[self.sequence_pos as usize];
+                }
             }
         } else {
             self.timer = self.timer.saturating_sub(1);
@@ -83,6 +90,20 @@ impl Triangle {
         self.last_output
     }
 
+    pub(super) fn state(&self) -> TriangleState {
+        TriangleState {
+            enabled: self.enabled,
+            period: self.timer_period,
+            timer: self.timer,
+            sequence_position: self.sequence_pos,
+            linear_counter: self.linear_counter,
+            linear_reload_value: self.linear_reload_value,
+            control: self.control_flag,
+            length: self.length.state(),
+            output: self.last_output,
+        }
+    }
+
     pub(super) fn length_active(&self) -> bool {
         self.length.active()
     }
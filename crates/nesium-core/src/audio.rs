@@ -3,12 +3,14 @@ pub mod channel;
 pub mod filters;
 pub mod mixer;
 pub mod settings;
+pub mod wav;
 
 pub use bus::SoundMixerBus;
 pub use channel::AudioChannel;
 pub use filters::StereoFilterType;
 pub use mixer::NesSoundMixer;
-pub use settings::MixerSettings;
+pub use settings::{MixerSettings, StereoPreset};
+pub use wav::{RecordSource, WavWriter};
 
 use crate::mem_block::MemBlock;
 
@@ -4,7 +4,8 @@
 //! This mirrors Mesen2's `Core/Shared/Audio/SoundMixer` at a high level but
 //! starts with a minimal feature set: fixed input rate (typically 96 kHz),
 //! configurable output rate, simple master volume/background attenuation, a
-//! Hermite resampler, and basic EQ/reverb/crossfeed support.
+//! Hermite resampler, optional NES analog output filters, and basic
+//! EQ/reverb/crossfeed support.
 
 /// Minimal audio bus configuration inspired by Mesen2's `AudioConfig`.
 ///
@@ -15,6 +16,8 @@
 ///   `in_background` is true.
 /// - `reduce_in_fast_forward` controls attenuation when `is_fast_forward`
 ///   is true.
+/// - `high_pass1_hz`, `high_pass2_hz` and `low_pass_hz` are cutoffs in Hz for
+///   the analog filter stages; `0.0` bypasses a single stage.
 #[derive(Debug, Clone, Copy)]
 pub struct AudioBusConfig {
     pub master_volume: f32,
@@ -24,6 +27,15 @@ pub struct AudioBusConfig {
     pub volume_reduction: f32,
     pub in_background: bool,
     pub is_fast_forward: bool,
+    /// Enable the NES analog output filter stages. Off by default to match
+    /// Mesen2, which mixes without them.
+    pub analog_filters_enabled: bool,
+    /// First high-pass cutoff (the console's ~90 Hz stage).
+    pub high_pass1_hz: f32,
+    /// Second high-pass cutoff (the console's ~440 Hz stage).
+    pub high_pass2_hz: f32,
+    /// Low-pass cutoff (the console's ~14 kHz stage).
+    pub low_pass_hz: f32,
     /// Enable the EQ stage (see `eq_band_gains`).
     pub enable_equalizer: bool,
     /// Per-band EQ gains in dB (20 bands, loosely mirroring Mesen2).
@@ -51,6 +63,12 @@ impl Default for AudioBusConfig {
             volume_reduction: 0.75,
             in_background: false,
             is_fast_forward: false,
+            analog_filters_enabled: false,
+            // Per NESdev: two first-order high-pass stages at ~90 Hz and
+            // ~440 Hz followed by a first-order low-pass at ~14 kHz.
+            high_pass1_hz: 90.0,
+            high_pass2_hz: 440.0,
+            low_pass_hz: 14_000.0,
             enable_equalizer: false,
             eq_band_gains: [0.0; 20],
             reverb_enabled: false,
@@ -62,6 +80,72 @@ impl Default for AudioBusConfig {
     }
 }
 
+/// One-pole approximation of the console's analog output path: two
+/// high-pass stages followed by a low-pass, each with per-channel state.
+#[derive(Debug, Default, Clone, Copy)]
+struct AnalogFilter {
+    high_pass1: [OnePole; 2],
+    high_pass2: [OnePole; 2],
+    low_pass: [OnePole; 2],
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct OnePole {
+    last_input: f32,
+    state: f32,
+}
+
+impl OnePole {
+    fn high_pass(&mut self, input: f32, coeff: f32) -> f32 {
+        self.state = coeff * (self.state + input - self.last_input);
+        self.last_input = input;
+        self.state
+    }
+
+    fn low_pass(&mut self, input: f32, alpha: f32) -> f32 {
+        self.state += alpha * (input - self.state);
+        self.state
+    }
+}
+
+impl AnalogFilter {
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn apply(&mut self, samples: &mut [f32], sample_rate: u32, config: &AudioBusConfig) {
+        let hp1 = pole_coeff(sample_rate, config.high_pass1_hz);
+        let hp2 = pole_coeff(sample_rate, config.high_pass2_hz);
+        let lp = pole_coeff(sample_rate, config.low_pass_hz).map(|c| 1.0 - c);
+
+        for frame in samples.chunks_exact_mut(2) {
+            for (ch, s) in frame.iter_mut().enumerate() {
+                let mut v = *s;
+                if let Some(c) = hp1 {
+                    v = self.high_pass1[ch].high_pass(v, c);
+                }
+                if let Some(c) = hp2 {
+                    v = self.high_pass2[ch].high_pass(v, c);
+                }
+                if let Some(a) = lp {
+                    v = self.low_pass[ch].low_pass(v, a);
+                }
+                *s = v;
+            }
+        }
+    }
+}
+
+/// Feedback coefficient of a one-pole filter at `cutoff_hz`, or `None` when
+/// the stage is bypassed (non-positive cutoff, or at/above Nyquist).
+fn pole_coeff(sample_rate: u32, cutoff_hz: f32) -> Option<f32> {
+    let sr = sample_rate as f32;
+    if !cutoff_hz.is_finite() || cutoff_hz <= 0.0 || cutoff_hz >= sr / 2.0 {
+        return None;
+    }
+    Some((-2.0 * std::f32::consts::PI * cutoff_hz / sr).exp())
+}
+
 #[derive(Debug, Default, Clone, Copy)]
 struct Equalizer {
     bands_db: [f32; 20],
@@ -123,10 +207,10 @@ impl ReverbFilter {
         let decay = strength.clamp(0.0, 1.0);
 
         if delay_samples != self.delay_samples {
-            self.left.clear();
-            self.right.clear();
-            self.left.resize(delay_samples, 0.0);
-            self.right.resize(delay_samples, 0.0);
+            // Stretch the pending tail onto the new length instead of
+            // dropping it, so rate or delay changes don't cut the reverb off.
+            self.left = Self::restretch(&self.left, self.index, delay_samples);
+            self.right = Self::restretch(&self.right, self.index, delay_samples);
             self.index = 0;
         }
 
@@ -134,6 +218,17 @@ impl ReverbFilter {
         self.decay = decay;
     }
 
+    /// Resamples the ring `line` (oldest sample at `index`) to `len` samples,
+    /// oldest first.
+    fn restretch(line: &[f32], index: usize, len: usize) -> Vec<f32> {
+        if line.is_empty() {
+            return vec![0.0; len];
+        }
+        (0..len)
+            .map(|i| line[(index + i * line.len() / len) % line.len()])
+            .collect()
+    }
+
     fn apply(&mut self, samples: &mut [f32], sample_rate: u32, strength: f32, delay_ms: f32) {
         if strength <= 0.0 || delay_ms <= 0.0 {
             // When disabled, keep any existing delay line but do not add new
@@ -302,6 +397,10 @@ impl HermiteResamplerStereo {
     }
 }
 
+/// Length of the fade from the last pre-switch frame into the new stream
+/// after [`SoundMixerBus::set_output_rate`].
+const RATE_SWITCH_CROSSFADE_MS: u32 = 5;
+
 #[derive(Debug, Clone)]
 pub struct SoundMixerBus {
     /// Base input sample rate used by the per-console mixer (e.g. 96 kHz).
@@ -316,6 +415,8 @@ pub struct SoundMixerBus {
     output_rate: u32,
     /// Master volume and attenuation configuration.
     config: AudioBusConfig,
+    /// NES analog high-pass/low-pass stages.
+    analog: AnalogFilter,
     /// Optional EQ applied at the bus level.
     eq: Equalizer,
     /// Simple stereo reverb.
@@ -330,6 +431,11 @@ pub struct SoundMixerBus {
     resample_scratch_i16: Vec<i16>,
     /// Mesen-style Hermite resampler state.
     resampler: HermiteResamplerStereo,
+    /// Last emitted stereo frame, held while fading in after a rate switch.
+    last_frame: [f32; 2],
+    /// Remaining and total frames of the rate-switch crossfade.
+    crossfade_remaining: usize,
+    crossfade_len: usize,
 }
 
 impl SoundMixerBus {
@@ -341,6 +447,7 @@ impl SoundMixerBus {
             input_rate,
             output_rate: output_rate.max(1),
             config: AudioBusConfig::default(),
+            analog: AnalogFilter::default(),
             eq: Equalizer::default(),
             reverb: ReverbFilter::default(),
             crossfeed: CrossFeedFilter,
@@ -353,6 +460,9 @@ impl SoundMixerBus {
                 r.set_sample_rates(input_rate as f64, output_rate as f64);
                 r
             },
+            last_frame: [0.0; 2],
+            crossfade_remaining: 0,
+            crossfade_len: 0,
         }
     }
 
@@ -361,10 +471,14 @@ impl SoundMixerBus {
         self.mix_scratch.clear();
         self.mix_scratch_i16.clear();
         self.resample_scratch_i16.clear();
+        self.analog.reset();
         self.reverb.reset();
         self.resampler.reset();
         self.resampler
             .set_sample_rates(self.input_rate as f64, self.output_rate as f64);
+        self.last_frame = [0.0; 2];
+        self.crossfade_remaining = 0;
+        self.crossfade_len = 0;
     }
 
     /// Adjusts the effective input sample rate used by the bus resampler.
@@ -386,10 +500,20 @@ impl SoundMixerBus {
     }
 
     /// Updates the output sample rate while keeping the input rate fixed.
+    ///
+    /// Resampler history, filter state and the reverb tail carry over to the
+    /// new rate, and the next few milliseconds fade in from the last emitted
+    /// frame, so switching devices mid-game neither clicks nor drops audio.
     pub fn set_output_rate(&mut self, output_rate: u32) {
-        self.output_rate = output_rate.max(1);
+        let output_rate = output_rate.max(1);
+        if output_rate == self.output_rate {
+            return;
+        }
+        self.output_rate = output_rate;
         self.resampler
             .set_sample_rates(self.input_rate as f64, self.output_rate as f64);
+        self.crossfade_len = (output_rate * RATE_SWITCH_CROSSFADE_MS / 1000) as usize;
+        self.crossfade_remaining = self.crossfade_len;
     }
 
     /// Updates the bus configuration (master volume and attenuation flags).
@@ -455,6 +579,10 @@ impl SoundMixerBus {
 
         let slice = &mut out[out_start..];
 
+        if self.config.analog_filters_enabled {
+            self.analog.apply(slice, self.output_rate, &self.config);
+        }
+
         // Apply EQ, reverb and crossfeed in the bus, mirroring Mesen2's
         // SoundMixer ordering (EQ → reverb → crossfeed → master volume).
         if self.config.enable_equalizer {
@@ -483,6 +611,22 @@ impl SoundMixerBus {
                 *s *= gain;
             }
         }
+
+        let slice = &mut out[out_start..];
+        for frame in slice.chunks_exact_mut(2) {
+            if self.crossfade_remaining == 0 {
+                break;
+            }
+            let t = 1.0 - self.crossfade_remaining as f32 / self.crossfade_len as f32;
+            frame[0] = self.last_frame[0] + (frame[0] - self.last_frame[0]) * t;
+            frame[1] = self.last_frame[1] + (frame[1] - self.last_frame[1]) * t;
+            self.crossfade_remaining -= 1;
+        }
+        if let Some(frame) = slice.chunks_exact(2).last()
+            && self.crossfade_remaining == 0
+        {
+            self.last_frame = [frame[0], frame[1]];
+        }
     }
 }
 
@@ -659,6 +803,37 @@ mod tests {
         assert!(out[1] < -0.9 && out[1] > -1.1);
     }
 
+    #[test]
+    fn analog_filters_remove_dc_only_when_enabled() {
+        let src = vec![0.5f32; 48_000];
+
+        let mut bus = SoundMixerBus::new(48_000, 48_000);
+        let mut out = Vec::new();
+        bus.mix_frame(&[&src], &mut out);
+        assert!((out[out.len() - 2] - 0.5).abs() < 5e-4);
+
+        bus.set_config(AudioBusConfig {
+            analog_filters_enabled: true,
+            ..Default::default()
+        });
+        out.clear();
+        bus.mix_frame(&[&src], &mut out);
+        assert!(out[out.len() - 2].abs() < 1e-3);
+
+        // With both high-pass stages bypassed only the low-pass remains,
+        // which passes DC through.
+        bus.reset();
+        bus.set_config(AudioBusConfig {
+            analog_filters_enabled: true,
+            high_pass1_hz: 0.0,
+            high_pass2_hz: 0.0,
+            ..Default::default()
+        });
+        out.clear();
+        bus.mix_frame(&[&src], &mut out);
+        assert!((out[out.len() - 2] - 0.5).abs() < 5e-4);
+    }
+
     #[test]
     fn crossfeed_blends_channels_when_enabled() {
         let mut bus = SoundMixerBus::new(48_000, 48_000);
@@ -680,6 +855,44 @@ mod tests {
         assert!((out[1] - 0.5).abs() < 5e-4);
     }
 
+    #[test]
+    fn output_rate_switch_fades_instead_of_stepping() {
+        let mut bus = SoundMixerBus::new(48_000, 48_000);
+        let mut out = Vec::new();
+        bus.mix_frame(&[&vec![0.5f32; 960]], &mut out);
+
+        bus.set_output_rate(44_100);
+        out.clear();
+        bus.mix_frame(&[&vec![-0.5f32; 960]], &mut out);
+
+        let lefts: Vec<f32> = out.iter().step_by(2).copied().collect();
+        assert!(lefts[0] > 0.45, "first frame {}", lefts[0]);
+        let max_step = lefts
+            .windows(2)
+            .map(|w| (w[1] - w[0]).abs())
+            .fold(0.0_f32, f32::max);
+        assert!(max_step < 0.05, "max step {max_step}");
+        assert!((lefts[lefts.len() - 1] + 0.5).abs() < 5e-3);
+    }
+
+    #[test]
+    fn output_rate_switch_keeps_reverb_tail() {
+        let mut bus = SoundMixerBus::new(48_000, 48_000);
+        bus.set_config(AudioBusConfig {
+            reverb_enabled: true,
+            reverb_strength: 0.5,
+            reverb_delay_ms: 10.0,
+            ..Default::default()
+        });
+        let mut out = Vec::new();
+        bus.mix_frame(&[&vec![0.5f32; 400]], &mut out);
+
+        bus.set_output_rate(44_100);
+        out.clear();
+        bus.mix_frame(&[&vec![0.0f32; 400]], &mut out);
+        assert!(out.iter().skip(300).any(|&v| v.abs() > 0.01));
+    }
+
     #[test]
     fn reverb_adds_delayed_energy_over_time() {
         let mut bus = SoundMixerBus::new(48_000, 48_000);
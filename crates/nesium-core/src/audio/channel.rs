@@ -16,7 +16,27 @@ pub enum AudioChannel {
 impl AudioChannel {
     pub const COUNT: usize = 11;
 
+    pub const ALL: [AudioChannel; Self::COUNT] = [
+        Self::Pulse1,
+        Self::Pulse2,
+        Self::Triangle,
+        Self::Noise,
+        Self::Dmc,
+        Self::Fds,
+        Self::Mmc5,
+        Self::Vrc6,
+        Self::Vrc7,
+        Self::Namco163,
+        Self::Sunsoft5B,
+    ];
+
     pub fn idx(self) -> usize {
         self as usize
     }
+
+    /// Whether the channel belongs to a cartridge/FDS expansion chip rather
+    /// than the 2A03.
+    pub fn is_expansion(self) -> bool {
+        self.idx() >= Self::Fds.idx()
+    }
 }
@@ -8,15 +8,134 @@ pub enum StereoFilterType {
     Comb,
 }
 
+/// Sample arithmetic used by the stereo filters, so the same filter code runs
+/// on the float output and on the integer samples of the fixed-point mixer.
+pub trait FilterSample: Copy + Default {
+    /// Filter coefficient: `f32`, or Q16 for integer samples.
+    type Coef: Copy + Default + std::fmt::Debug;
+
+    /// Converts a coefficient setting.
+    fn coef(value: f32) -> Self::Coef;
+    /// `(left, right)` factors of the panning filter for `angle_deg`.
+    fn panning_factors(angle_deg: f32) -> (Self::Coef, Self::Coef);
+    fn add(self, other: Self) -> Self;
+    fn sub(self, other: Self) -> Self;
+    fn scale(self, coef: Self::Coef) -> Self;
+    /// `(a + b) / 2`.
+    fn average(a: Self, b: Self) -> Self;
+}
+
+impl FilterSample for f32 {
+    type Coef = f32;
+
+    fn coef(value: f32) -> f32 {
+        value
+    }
+
+    fn panning_factors(angle_deg: f32) -> (f32, f32) {
+        const BASE: f32 = 0.707_106_77; // sqrt(2)/2
+        let angle_rad = angle_deg.to_radians();
+        let c = angle_rad.cos();
+        let s = angle_rad.sin();
+        (BASE * (c - s), BASE * (c + s))
+    }
+
+    fn add(self, other: f32) -> f32 {
+        self + other
+    }
+
+    fn sub(self, other: f32) -> f32 {
+        self - other
+    }
+
+    fn scale(self, coef: f32) -> f32 {
+        coef * self
+    }
+
+    fn average(a: f32, b: f32) -> f32 {
+        0.5 * (a + b)
+    }
+}
+
+impl FilterSample for i32 {
+    type Coef = i32;
+
+    fn coef(value: f32) -> i32 {
+        (value as f64 * 65_536.0).round() as i32
+    }
+
+    fn panning_factors(angle_deg: f32) -> (i32, i32) {
+        const BASE: i64 = 46_341; // sqrt(2)/2 in Q16
+        let (c, s) = fixed_cos_sin(angle_deg);
+        (
+            ((BASE * (c - s)) >> 16) as i32,
+            ((BASE * (c + s)) >> 16) as i32,
+        )
+    }
+
+    fn add(self, other: i32) -> i32 {
+        self.saturating_add(other)
+    }
+
+    fn sub(self, other: i32) -> i32 {
+        self.saturating_sub(other)
+    }
+
+    fn scale(self, coef: i32) -> i32 {
+        ((self as i64 * coef as i64) >> 16) as i32
+    }
+
+    fn average(a: i32, b: i32) -> i32 {
+        ((a as i64 + b as i64) >> 1) as i32
+    }
+}
+
+/// Cosine and sine of `angle_deg` in Q16, computed with integer math only so
+/// every platform gets the same result (`f32::sin` depends on the host libm).
+///
+/// The angle is quantized to 1/100 degree, folded into `[-90, 90]` degrees and
+/// fed to a Taylor series in Q30.
+fn fixed_cos_sin(angle_deg: f32) -> (i64, i64) {
+    const Q: u32 = 30;
+    /// pi / 18000 (radians per 1/100 degree) in Q30.
+    const RAD_PER_CENTIDEGREE: i128 = 187_403;
+
+    fn sin_centidegrees(angle: i64) -> i64 {
+        let angle = angle.rem_euclid(36_000);
+        let folded = if angle > 27_000 {
+            angle - 36_000
+        } else if angle > 9_000 {
+            18_000 - angle
+        } else {
+            angle
+        };
+        let x = folded as i128 * RAD_PER_CENTIDEGREE;
+        let x2 = (x * x) >> Q;
+        let mut term = x;
+        let mut sum = x;
+        for divisor in [2 * 3, 4 * 5, 6 * 7, 8 * 9, 10 * 11] {
+            term = -((term * x2) >> Q) / divisor;
+            sum += term;
+        }
+        (sum >> (Q - 16)) as i64
+    }
+
+    let centidegrees = (angle_deg as f64 * 100.0).round() as i64;
+    (
+        sin_centidegrees(centidegrees + 9_000),
+        sin_centidegrees(centidegrees),
+    )
+}
+
 #[derive(Debug, Default, Clone)]
-pub struct StereoDelayState {
+pub struct StereoDelayState<S = f32> {
     pub(crate) last_delay_samples: usize,
-    pub(crate) delayed_left: VecDeque<f32>,
-    pub(crate) delayed_right: VecDeque<f32>,
+    pub(crate) delayed_left: VecDeque<S>,
+    pub(crate) delayed_right: VecDeque<S>,
 }
 
-impl StereoDelayState {
-    pub(crate) fn apply(&mut self, samples: &mut [f32], sample_rate: f32, delay_ms: f32) {
+impl<S: FilterSample> StereoDelayState<S> {
+    pub(crate) fn apply(&mut self, samples: &mut [S], sample_rate: f32, delay_ms: f32) {
         if delay_ms <= 0.0 || sample_rate <= 0.0 {
             return;
         }
@@ -50,10 +169,10 @@ impl StereoDelayState {
 
             for i in start..frames {
                 let idx = i * 2;
-                let mono = 0.5 * (samples[idx] + samples[idx + 1]);
-                let dl = self.delayed_left.pop_front().unwrap_or(0.0);
-                let dr = self.delayed_right.pop_front().unwrap_or(0.0);
-                let delayed = 0.5 * (dl + dr);
+                let mono = S::average(samples[idx], samples[idx + 1]);
+                let dl = self.delayed_left.pop_front().unwrap_or_default();
+                let dr = self.delayed_right.pop_front().unwrap_or_default();
+                let delayed = S::average(dl, dr);
 
                 samples[idx] = mono;
                 samples[idx + 1] = delayed;
@@ -63,22 +182,14 @@ impl StereoDelayState {
 }
 
 #[derive(Debug, Default, Clone)]
-pub struct StereoPanningState {
-    left_factor: f32,
-    right_factor: f32,
-    last_angle_rad: f32,
+pub struct StereoPanningState<S: FilterSample = f32> {
+    left_factor: S::Coef,
+    right_factor: S::Coef,
+    last_angle_deg: f32,
 }
 
-impl StereoPanningState {
-    fn update_factors(&mut self, angle_rad: f32) {
-        const BASE: f32 = 0.707_106_77; // sqrt(2)/2
-        let c = angle_rad.cos();
-        let s = angle_rad.sin();
-        self.left_factor = BASE * (c - s);
-        self.right_factor = BASE * (c + s);
-    }
-
-    pub(crate) fn apply(&mut self, samples: &mut [f32], angle_deg: f32) {
+impl<S: FilterSample> StereoPanningState<S> {
+    pub(crate) fn apply(&mut self, samples: &mut [S], angle_deg: f32) {
         if angle_deg == 0.0 {
             return;
         }
@@ -87,10 +198,9 @@ impl StereoPanningState {
             return;
         }
 
-        let angle_rad = angle_deg.to_radians();
-        if (angle_rad - self.last_angle_rad).abs() > f32::EPSILON {
-            self.update_factors(angle_rad);
-            self.last_angle_rad = angle_rad;
+        if angle_deg != self.last_angle_deg {
+            (self.left_factor, self.right_factor) = S::panning_factors(angle_deg);
+            self.last_angle_deg = angle_deg;
         }
 
         for i in 0..frames {
@@ -98,8 +208,8 @@ impl StereoPanningState {
             let l = samples[idx];
             let r = samples[idx + 1];
 
-            let out_l = (self.left_factor * l + self.left_factor * r) * 0.5;
-            let out_r = (self.right_factor * r + self.right_factor * l) * 0.5;
+            let out_l = S::average(l.scale(self.left_factor), r.scale(self.left_factor));
+            let out_r = S::average(r.scale(self.right_factor), l.scale(self.right_factor));
 
             samples[idx] = out_l;
             samples[idx + 1] = out_r;
@@ -108,16 +218,16 @@ impl StereoPanningState {
 }
 
 #[derive(Debug, Default, Clone)]
-pub struct StereoCombState {
+pub struct StereoCombState<S = f32> {
     pub(crate) last_delay_samples: usize,
-    pub(crate) delayed_left: VecDeque<f32>,
-    pub(crate) delayed_right: VecDeque<f32>,
+    pub(crate) delayed_left: VecDeque<S>,
+    pub(crate) delayed_right: VecDeque<S>,
 }
 
-impl StereoCombState {
+impl<S: FilterSample> StereoCombState<S> {
     pub(crate) fn apply(
         &mut self,
-        samples: &mut [f32],
+        samples: &mut [S],
         sample_rate: f32,
         delay_ms: f32,
         strength: f32,
@@ -140,13 +250,13 @@ impl StereoCombState {
             self.delayed_left.clear();
             self.delayed_right.clear();
             for _ in 0..delay_samples {
-                self.delayed_left.push_back(0.0);
-                self.delayed_right.push_back(0.0);
+                self.delayed_left.push_back(S::default());
+                self.delayed_right.push_back(S::default());
             }
         }
         self.last_delay_samples = delay_samples;
 
-        let ratio = strength.clamp(0.0, 1.0);
+        let ratio = S::coef(strength.clamp(0.0, 1.0));
         for i in 0..frames {
             let idx = i * 2;
             let l = samples[idx];
@@ -155,16 +265,37 @@ impl StereoCombState {
             self.delayed_left.push_back(l);
             self.delayed_right.push_back(r);
 
-            let dl = self.delayed_left.front().copied().unwrap_or(0.0);
-            let dr = self.delayed_right.front().copied().unwrap_or(0.0);
-            let delayed = 0.5 * (dl + dr);
-            let mono = 0.5 * (l + r);
+            let dl = self.delayed_left.front().copied().unwrap_or_default();
+            let dr = self.delayed_right.front().copied().unwrap_or_default();
+            let delayed = S::average(dl, dr);
+            let mono = S::average(l, r);
 
-            samples[idx] = mono + delayed * ratio;
-            samples[idx + 1] = mono - delayed * ratio;
+            samples[idx] = mono.add(delayed.scale(ratio));
+            samples[idx + 1] = mono.sub(delayed.scale(ratio));
 
             self.delayed_left.pop_front();
             self.delayed_right.pop_front();
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_panning_factors_track_float_factors() {
+        for angle in [-180.0, -135.0, -90.0, -45.0, -10.5, 15.0, 30.0, 90.0, 170.0] {
+            let (fl, fr) = f32::panning_factors(angle);
+            let (il, ir) = i32::panning_factors(angle);
+            assert!(
+                (fl - il as f32 / 65_536.0).abs() < 1e-4,
+                "{angle}: {fl} vs {il}"
+            );
+            assert!(
+                (fr - ir as f32 / 65_536.0).abs() < 1e-4,
+                "{angle}: {fr} vs {ir}"
+            );
+        }
+    }
+}
@@ -1,16 +1,23 @@
-use std::f32::consts::PI;
-
 use nesium_blip::BlipBuf;
 
 use crate::audio::{
     AudioChannel, ChannelLevels, ChannelPanning, ChannelVolumes,
-    filters::{StereoCombState, StereoDelayState, StereoFilterType, StereoPanningState},
+    filters::{
+        FilterSample, StereoCombState, StereoDelayState, StereoFilterType, StereoPanningState,
+    },
     settings::MixerSettings,
 };
 
 #[cfg(feature = "savestate-serde")]
 use serde::{Deserialize, Serialize};
 
+/// Fractional bits of the fixed-point mix path (Q16).
+const FIXED_SHIFT: u32 = 16;
+const FIXED_ONE: i64 = 1 << FIXED_SHIFT;
+/// Mesen2's TND weights for triangle and noise, in Q16.
+const FIXED_TRIANGLE_WEIGHT: i64 = 180_334; // 2.7516713261
+const FIXED_NOISE_WEIGHT: i64 = 121_200; // 1.8493587125
+
 /// Serializable snapshot of the mixer state.
 #[cfg_attr(feature = "savestate-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
@@ -47,56 +54,78 @@ pub struct NesSoundMixer {
     /// Pending timestamp to mirror Mesen2's "per-timestamp commit" behaviour:
     /// multiple channel deltas at the same clock are coalesced and emitted once.
     pending_mix_clock: Option<i64>,
-    /// DC-block filter state (per channel).
-    dc_last_input_l: f32,
-    dc_last_output_l: f32,
-    dc_last_input_r: f32,
-    dc_last_output_r: f32,
-    /// Rumble high-pass state (~90 Hz, per channel).
-    rumble_last_input_l: f32,
-    rumble_state_l: f32,
-    rumble_last_input_r: f32,
-    rumble_state_r: f32,
-    /// Soft low-pass to tame aliasing and harsh edges (~12 kHz, per channel).
-    lowpass_state_l: f32,
-    lowpass_state_r: f32,
-    dc_coeff: f32,
-    rumble_coeff: f32,
-    lowpass_alpha: f32,
     master_gain: f32,
     has_panning: bool,
+    /// Mix with integer arithmetic only (see [`NesSoundMixer::set_fixed_point`]).
+    fixed_point: bool,
+
+    stereo: StereoFilterConfig,
+    stereo_state: StereoFilterState<f32>,
+    /// Filter history of the fixed-point path, which runs on integer samples.
+    stereo_state_fixed: StereoFilterState<i32>,
+    /// Isolated single-channel render used for per-channel recording.
+    tap: Option<ChannelTap>,
+}
+
+/// Stereo post-filter selection and parameters from [`MixerSettings`].
+#[derive(Debug, Clone, Copy)]
+struct StereoFilterConfig {
+    filter: StereoFilterType,
+    delay_ms: f32,
+    panning_angle_deg: f32,
+    comb_delay_ms: f32,
+    comb_strength: f32,
+}
+
+/// History of the stereo post-filters for one sample type.
+#[derive(Debug, Default, Clone)]
+struct StereoFilterState<S: FilterSample> {
+    delay: StereoDelayState<S>,
+    panning: StereoPanningState<S>,
+    comb: StereoCombState<S>,
+}
+
+impl<S: FilterSample> StereoFilterState<S> {
+    /// Runs the configured filter over interleaved stereo `samples`.
+    fn apply(&mut self, config: &StereoFilterConfig, sample_rate: f32, samples: &mut [S]) {
+        match config.filter {
+            StereoFilterType::None => {}
+            StereoFilterType::Delay => {
+                self.delay.apply(samples, sample_rate, config.delay_ms);
+            }
+            StereoFilterType::Panning => {
+                self.panning.apply(samples, config.panning_angle_deg);
+            }
+            StereoFilterType::Comb => {
+                self.comb.apply(
+                    samples,
+                    sample_rate,
+                    config.comb_delay_ms,
+                    config.comb_strength,
+                );
+            }
+        }
+    }
+}
 
-    stereo_filter: StereoFilterType,
-    stereo_delay_ms: f32,
-    stereo_panning_angle_deg: f32,
-    stereo_comb_delay_ms: f32,
-    stereo_comb_strength: f32,
-    stereo_delay_state: StereoDelayState,
-    stereo_panning_state: StereoPanningState,
-    stereo_comb_state: StereoCombState,
+/// Mono render of one channel through its own blip buffer, at full volume
+/// and ignoring panning and solo.
+#[derive(Debug)]
+struct ChannelTap {
+    channel: AudioChannel,
+    blip: BlipBuf,
+    mixed: f32,
+    samples: Vec<f32>,
 }
 
 impl NesSoundMixer {
     /// Construct a mixer for the given CPU/APU clock and host sample rate.
     pub fn new(clock_rate: f64, sample_rate: u32) -> Self {
-        let sr = sample_rate as f32;
-        // Approximate the NES analog output filters (per NESdev): two
-        // high-pass stages around 90 Hz and 440 Hz plus a low-pass around
-        // 14 kHz. The DC/rumble filters are implemented as simple one-pole
-        // IIRs; the exact poles are close but not cycle-perfect.
-        let dc_cut = 90.0_f32;
-        let rumble_cut = 440.0_f32;
-        let lowpass_cut = 14_000.0_f32;
-
-        let dc_coeff = pole_coeff(sr, dc_cut);
-        let rumble_coeff = pole_coeff(sr, rumble_cut);
-        let lowpass_alpha = pole_alpha(sr, lowpass_cut);
-
         Self {
             blip_left: BlipBuf::new(clock_rate, sample_rate as f64, 24),
             blip_right: BlipBuf::new(clock_rate, sample_rate as f64, 24),
             clock_rate,
-            sample_rate: sr,
+            sample_rate: sample_rate as f32,
             last_frame_clock: 0,
             channel_levels: ChannelLevels::new(),
             volumes: ChannelVolumes::filled(1.0),
@@ -104,31 +133,21 @@ impl NesSoundMixer {
             mixed_left: 0.0,
             mixed_right: 0.0,
             pending_mix_clock: None,
-            dc_last_input_l: 0.0,
-            dc_last_output_l: 0.0,
-            dc_last_input_r: 0.0,
-            dc_last_output_r: 0.0,
-            rumble_last_input_l: 0.0,
-            rumble_state_l: 0.0,
-            rumble_last_input_r: 0.0,
-            rumble_state_r: 0.0,
-            lowpass_state_l: 0.0,
-            lowpass_state_r: 0.0,
-            dc_coeff,
-            rumble_coeff,
-            lowpass_alpha,
             // Keep neutral gain here; output scaling is matched to Mesen2's
             // `GetOutputVolume() * 4` path in `mix_output_volume_stereo`.
             master_gain: 1.0,
             has_panning: false,
-            stereo_filter: StereoFilterType::None,
-            stereo_delay_ms: 0.0,
-            stereo_panning_angle_deg: 0.0,
-            stereo_comb_delay_ms: 0.0,
-            stereo_comb_strength: 0.0,
-            stereo_delay_state: StereoDelayState::default(),
-            stereo_panning_state: StereoPanningState::default(),
-            stereo_comb_state: StereoCombState::default(),
+            fixed_point: false,
+            stereo: StereoFilterConfig {
+                filter: StereoFilterType::None,
+                delay_ms: 0.0,
+                panning_angle_deg: 0.0,
+                comb_delay_ms: 0.0,
+                comb_strength: 0.0,
+            },
+            stereo_state: StereoFilterState::default(),
+            stereo_state_fixed: StereoFilterState::default(),
+            tap: None,
         }
     }
 
@@ -141,19 +160,13 @@ impl NesSoundMixer {
         self.mixed_left = 0.0;
         self.mixed_right = 0.0;
         self.pending_mix_clock = None;
-        self.dc_last_input_l = 0.0;
-        self.dc_last_output_l = 0.0;
-        self.dc_last_input_r = 0.0;
-        self.dc_last_output_r = 0.0;
-        self.rumble_last_input_l = 0.0;
-        self.rumble_state_l = 0.0;
-        self.rumble_last_input_r = 0.0;
-        self.rumble_state_r = 0.0;
-        self.lowpass_state_l = 0.0;
-        self.lowpass_state_r = 0.0;
-        self.stereo_delay_state = StereoDelayState::default();
-        self.stereo_panning_state = StereoPanningState::default();
-        self.stereo_comb_state = StereoCombState::default();
+        self.stereo_state = StereoFilterState::default();
+        self.stereo_state_fixed = StereoFilterState::default();
+        if let Some(tap) = self.tap.as_mut() {
+            tap.blip.clear();
+            tap.mixed = 0.0;
+            tap.samples.clear();
+        }
     }
 
     pub fn save_state(&self) -> MixerState {
@@ -180,8 +193,36 @@ impl NesSoundMixer {
         self.pending_mix_clock = None;
     }
 
+    /// Starts rendering `channel` on its own alongside the normal mix, or
+    /// stops with `None`. Samples are produced by [`Self::end_frame`] and
+    /// collected with [`Self::take_tap_samples`].
+    pub fn set_channel_tap(&mut self, channel: Option<AudioChannel>) {
+        self.tap = channel.map(|channel| ChannelTap {
+            channel,
+            blip: BlipBuf::new(self.clock_rate, self.sample_rate as f64, 24),
+            mixed: 0.0,
+            samples: Vec::new(),
+        });
+    }
+
+    /// Moves the tapped channel's mono samples into `out`.
+    pub fn take_tap_samples(&mut self, out: &mut Vec<f32>) {
+        if let Some(tap) = self.tap.as_mut() {
+            out.append(&mut tap.samples);
+        }
+    }
+
     #[inline]
     fn flush_pending_mix_at(&mut self, clock_time: i64) {
+        let rel_clock = clock_time - self.last_frame_clock;
+        if let Some(tap) = self.tap.as_mut() {
+            let level = isolated_output(tap.channel, self.channel_levels[tap.channel.idx()]);
+            if level != tap.mixed && rel_clock >= 0 {
+                tap.blip.add_delta(rel_clock, level - tap.mixed);
+                tap.mixed = level;
+            }
+        }
+
         let (left, right) = self.mix_output_volume_stereo();
         let delta_left = left - self.mixed_left;
         let delta_right = right - self.mixed_right;
@@ -189,7 +230,6 @@ impl NesSoundMixer {
             return;
         }
 
-        let rel_clock = clock_time - self.last_frame_clock;
         debug_assert!(
             rel_clock >= 0,
             "NesSoundMixer::flush_pending_mix_at requires non-decreasing clock within frame"
@@ -227,31 +267,35 @@ impl NesSoundMixer {
         self.sample_rate = sr;
         self.blip_left.set_rates(clock_rate, sample_rate as f64);
         self.blip_right.set_rates(clock_rate, sample_rate as f64);
-
-        // Recompute filter coefficients so the DC/rumble/low-pass behaviour
-        // stays approximately aligned with NES analog characteristics at the
-        // new sample rate.
-        let dc_cut = 90.0_f32;
-        let rumble_cut = 440.0_f32;
-        let lowpass_cut = 14_000.0_f32;
-        self.dc_coeff = pole_coeff(sr, dc_cut);
-        self.rumble_coeff = pole_coeff(sr, rumble_cut);
-        self.lowpass_alpha = pole_alpha(sr, lowpass_cut);
+        if let Some(tap) = self.tap.as_mut() {
+            tap.blip.set_rates(clock_rate, sample_rate as f64);
+        }
     }
 
     /// Apply per-channel volume and panning settings coming from the host.
     ///
     /// - `volume[i]` is expected in `[0.0, 1.0]` (0 = muted, 1 = full).
     /// - `panning[i]` is expected in `[-1.0, 1.0]` (-1 = hard left, 0 = center, 1 = hard right).
+    /// - if any `solo[i]` is set, channels that are not soloed are muted.
     pub fn apply_mixer_settings(&mut self, settings: &MixerSettings) {
         let mut has_panning = false;
+        let any_solo = settings.solo.iter().any(|&solo| solo);
         for (idx, (&vol, &pan)) in settings
             .volume
             .iter()
             .zip(settings.panning.iter())
             .enumerate()
         {
-            self.volumes[idx] = vol.clamp(0.0, 1.0);
+            let balance = if AudioChannel::ALL[idx].is_expansion() {
+                1.0 + settings.expansion_balance[idx].clamp(-1.0, 1.0)
+            } else {
+                1.0
+            };
+            self.volumes[idx] = if any_solo && !settings.solo[idx] {
+                0.0
+            } else {
+                vol.clamp(0.0, 1.0) * balance
+            };
             // Map [-1, 1] to [0, 2] like Mesen2's (ChannelPanning + 100) / 100.
             self.panning[idx] = (pan.clamp(-1.0, 1.0) + 1.0).clamp(0.0, 2.0);
             if self.panning[idx] != 1.0 {
@@ -268,11 +312,33 @@ impl NesSoundMixer {
         }
         self.has_panning = has_panning;
 
-        self.stereo_filter = settings.stereo_filter;
-        self.stereo_delay_ms = settings.stereo_delay_ms.max(0.0);
-        self.stereo_panning_angle_deg = settings.stereo_panning_angle_deg;
-        self.stereo_comb_delay_ms = settings.stereo_comb_delay_ms.max(0.0);
-        self.stereo_comb_strength = settings.stereo_comb_strength.clamp(0.0, 1.0);
+        self.stereo = StereoFilterConfig {
+            filter: settings.stereo_filter,
+            delay_ms: settings.stereo_delay_ms.max(0.0),
+            panning_angle_deg: settings.stereo_panning_angle_deg,
+            comb_delay_ms: settings.stereo_comb_delay_ms.max(0.0),
+            comb_strength: settings.stereo_comb_strength.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Switches the level mix to integer arithmetic.
+    ///
+    /// The float path depends on how each platform rounds `f64` division and
+    /// casts, which is fine for local play but makes resimulated audio after a
+    /// rollback, and any state derived from it, hard to compare across
+    /// machines. In fixed-point mode channel levels, volumes and panning are
+    /// quantized to Q16 and the nonlinear DAC curves are evaluated with `i64`
+    /// math, so the blip deltas are bit-identical everywhere. The stereo
+    /// post-filters then run on the integer blip samples, with panning
+    /// factors from an integer sine/cosine, before the master gain is
+    /// applied, so the PCM is bit-identical too. Their output can differ from
+    /// the float filters by a few LSBs.
+    pub fn set_fixed_point(&mut self, enabled: bool) {
+        self.fixed_point = enabled;
+    }
+
+    pub fn fixed_point(&self) -> bool {
+        self.fixed_point
     }
 
     /// Directly apply a channel delta at the given CPU/APU clock.
@@ -339,6 +405,14 @@ impl NesSoundMixer {
         if self.has_panning {
             self.blip_right.end_frame(duration);
         }
+        if let Some(tap) = self.tap.as_mut() {
+            tap.blip.end_frame(duration);
+            let mut mono = vec![0i16; tap.blip.samples_avail()];
+            let got = tap.blip.read_samples_i16(&mut mono);
+            let gain = self.master_gain;
+            tap.samples
+                .extend(mono[..got].iter().map(|&s| s as f32 / 32_768.0 * gain));
+        }
         self.last_frame_clock = frame_end_clock;
 
         let avail = self.blip_left.samples_avail();
@@ -352,42 +426,105 @@ impl NesSoundMixer {
             got_left, avail,
             "left blip should return all available samples"
         );
-        let mut stereo = Vec::with_capacity(got_left * 2);
-
+        // Interleaved stereo in the blip buffers' integer domain.
+        let mut pcm = Vec::with_capacity(got_left * 2);
         if self.has_panning {
             let mut right_i16 = vec![0i16; got_left];
             let got_right = self.blip_right.read_samples_i16(&mut right_i16[..]);
             let got = got_left.min(got_right);
-
             for i in 0..got {
-                let l_i16 = left_i16[i];
-                let r_i16 = right_i16[i];
-
-                // Keep this stage as close as possible to Mesen2's
-                // `NesSoundMixer::PlayAudioBuffer` path: no extra smoothing
-                // or soft-clip in this layer.
-                let l = l_i16 as f32 / 32_768.0;
-                let r = r_i16 as f32 / 32_768.0;
-                stereo.push(l * self.master_gain);
-                stereo.push(r * self.master_gain);
+                pcm.push(left_i16[i] as i32);
+                pcm.push(right_i16[i] as i32);
             }
         } else {
-            for i in 0..got_left {
-                let l_i16 = left_i16[i];
-
-                // Match Mesen2's no-panning path: copy left to right.
-                let l = l_i16 as f32 / 32_768.0;
-                let s = l * self.master_gain;
-                stereo.push(s);
-                stereo.push(s);
+            // Match Mesen2's no-panning path: copy left to right.
+            for &l in &left_i16[..got_left] {
+                pcm.push(l as i32);
+                pcm.push(l as i32);
             }
         }
 
-        self.apply_stereo_post_filters(&mut stereo);
-        out.extend_from_slice(&stereo);
+        if self.fixed_point {
+            self.stereo_state_fixed
+                .apply(&self.stereo, self.sample_rate, &mut pcm);
+        }
+        // Keep this stage as close as possible to Mesen2's
+        // `NesSoundMixer::PlayAudioBuffer` path: no extra smoothing or
+        // soft-clip in this layer.
+        let start = out.len();
+        out.extend(pcm.iter().map(|&s| s as f32 / 32_768.0 * self.master_gain));
+        if !self.fixed_point {
+            self.stereo_state
+                .apply(&self.stereo, self.sample_rate, &mut out[start..]);
+        }
     }
 
     fn mix_output_volume_stereo(&self) -> (f32, f32) {
+        if self.fixed_point {
+            self.mix_output_volume_stereo_fixed()
+        } else {
+            self.mix_output_volume_stereo_float()
+        }
+    }
+
+    /// Integer-only equivalent of [`Self::mix_output_volume_stereo_float`].
+    fn mix_output_volume_stereo_fixed(&self) -> (f32, f32) {
+        let to_fixed = |value: f32| (value as f64 * FIXED_ONE as f64).round() as i64;
+        let lr = |ch: AudioChannel| {
+            let idx = ch.idx();
+            let v =
+                (to_fixed(self.channel_levels[idx]) * to_fixed(self.volumes[idx])) >> FIXED_SHIFT;
+            let p = to_fixed(self.panning[idx]);
+            (
+                (v * (2 * FIXED_ONE - p)) >> FIXED_SHIFT,
+                (v * p) >> FIXED_SHIFT,
+            )
+        };
+
+        let (p1_l, p1_r) = lr(AudioChannel::Pulse1);
+        let (p2_l, p2_r) = lr(AudioChannel::Pulse2);
+        let (t_l, t_r) = lr(AudioChannel::Triangle);
+        let (n_l, n_r) = lr(AudioChannel::Noise);
+        let (d_l, d_r) = lr(AudioChannel::Dmc);
+        let (fds_l, fds_r) = lr(AudioChannel::Fds);
+        let (mmc5_l, mmc5_r) = lr(AudioChannel::Mmc5);
+        let (n163_l, n163_r) = lr(AudioChannel::Namco163);
+        let (s5b_l, s5b_r) = lr(AudioChannel::Sunsoft5B);
+        let (vrc6_l, vrc6_r) = lr(AudioChannel::Vrc6);
+        let (vrc7_l, vrc7_r) = lr(AudioChannel::Vrc7);
+
+        // (95.88 * 5000) / (8128 / x + 100), rearranged to avoid dividing by x.
+        let square = |x: i64| {
+            if x > 0 {
+                479_400 * x / (8128 * FIXED_ONE + 100 * x)
+            } else {
+                0
+            }
+        };
+        // (159.79 * 5000) / (22638 / x + 100), same rearrangement.
+        let tnd = |d: i64, t: i64, n: i64| {
+            let x = d + ((FIXED_TRIANGLE_WEIGHT * t + FIXED_NOISE_WEIGHT * n) >> FIXED_SHIFT);
+            if x > 0 {
+                798_950 * x / (22638 * FIXED_ONE + 100 * x)
+            } else {
+                0
+            }
+        };
+        let exp = |fds: i64, mmc5: i64, n163: i64, s5b: i64, vrc6: i64, vrc7: i64| {
+            (fds * 20 + mmc5 * 43 + n163 * 20 + s5b * 15 + vrc6 * 5 + vrc7) / FIXED_ONE
+        };
+
+        let mixed = |sum: i64| sum.clamp(i16::MIN as i64, i16::MAX as i64) as f32 * 4.0;
+        let left = square(p1_l + p2_l)
+            + tnd(d_l, t_l, n_l)
+            + exp(fds_l, mmc5_l, n163_l, s5b_l, vrc6_l, vrc7_l);
+        let right = square(p1_r + p2_r)
+            + tnd(d_r, t_r, n_r)
+            + exp(fds_r, mmc5_r, n163_r, s5b_r, vrc6_r, vrc7_r);
+        (mixed(left), mixed(right))
+    }
+
+    fn mix_output_volume_stereo_float(&self) -> (f32, f32) {
         let idx = |ch: AudioChannel| ch.idx();
         let base = |ch: AudioChannel| self.channel_levels[idx(ch)] as f64;
         let vol = |ch: AudioChannel| self.volumes[idx(ch)] as f64;
@@ -458,50 +595,38 @@ impl NesSoundMixer {
         let mixed_r = (square_vol_r as f64 + tnd_vol_r as f64 + exp_r) as i16;
         ((mixed_l as f32) * 4.0, (mixed_r as f32) * 4.0)
     }
-
-    fn apply_stereo_post_filters(&mut self, samples: &mut [f32]) {
-        match self.stereo_filter {
-            StereoFilterType::None => {}
-            StereoFilterType::Delay => {
-                self.stereo_delay_state
-                    .apply(samples, self.sample_rate, self.stereo_delay_ms);
-            }
-            StereoFilterType::Panning => {
-                self.stereo_panning_state
-                    .apply(samples, self.stereo_panning_angle_deg);
-            }
-            StereoFilterType::Comb => {
-                self.stereo_comb_state.apply(
-                    samples,
-                    self.sample_rate,
-                    self.stereo_comb_delay_ms,
-                    self.stereo_comb_strength,
-                );
-            }
-        }
-    }
-}
-
-fn pole_coeff(sample_rate: f32, cutoff_hz: f32) -> f32 {
-    (-2.0 * PI * cutoff_hz / sample_rate).exp()
-}
-
-fn pole_alpha(sample_rate: f32, cutoff_hz: f32) -> f32 {
-    1.0 - pole_coeff(sample_rate, cutoff_hz)
 }
 
-fn dc_block(input: f32, last_in: &mut f32, last_out: &mut f32, coeff: f32) -> f32 {
-    let out = input - *last_in + coeff * *last_out;
-    *last_in = input;
-    *last_out = out;
-    out
-}
-
-fn high_pass(input: f32, last_in: &mut f32, state: &mut f32, coeff: f32) -> f32 {
-    let out = coeff * (*state + input - *last_in);
-    *last_in = input;
-    *state = out;
-    out
+/// Output of `channel` alone at `level`: the same DAC curves and scaling as
+/// `mix_output_volume_stereo_float` with unit volume and centered panning.
+fn isolated_output(channel: AudioChannel, level: f32) -> f32 {
+    let level = level as f64;
+    let square = |x: f64| {
+        if x > 0.0 {
+            ((95.88 * 5000.0) / (8128.0 / x + 100.0)) as u16 as f64
+        } else {
+            0.0
+        }
+    };
+    let tnd = |x: f64| {
+        if x > 0.0 {
+            ((159.79 * 5000.0) / (22638.0 / x + 100.0)) as u16 as f64
+        } else {
+            0.0
+        }
+    };
+    let out = match channel {
+        AudioChannel::Pulse1 | AudioChannel::Pulse2 => square(level),
+        AudioChannel::Triangle => tnd(2.751_671_326_1 * level),
+        AudioChannel::Noise => tnd(1.849_358_712_5 * level),
+        AudioChannel::Dmc => tnd(level),
+        AudioChannel::Fds | AudioChannel::Namco163 => level * 20.0,
+        AudioChannel::Mmc5 => level * 43.0,
+        AudioChannel::Sunsoft5B => level * 15.0,
+        AudioChannel::Vrc6 => level * 5.0,
+        AudioChannel::Vrc7 => level,
+    };
+    (out as i16 as f32) * 4.0
 }
 
 #[cfg(test)]
@@ -691,13 +816,9 @@ mod tests {
     }
 
     #[test]
-    fn update_rates_changes_blip_and_filters() {
+    fn update_rates_changes_blip_rates() {
         let mut mixer = NesSoundMixer::new(CPU_CLOCK_NTSC, 44_100);
 
-        let old_dc = mixer.dc_coeff;
-        let old_rumble = mixer.rumble_coeff;
-        let old_lowpass = mixer.lowpass_alpha;
-
         let clocks_needed_before = mixer.blip_left.clocks_needed(100);
 
         mixer.update_rates(CPU_CLOCK_NTSC, 48_000);
@@ -705,9 +826,6 @@ mod tests {
         let clocks_needed_after = mixer.blip_left.clocks_needed(100);
 
         assert_ne!(clocks_needed_before, clocks_needed_after);
-        assert_ne!(old_dc, mixer.dc_coeff);
-        assert_ne!(old_rumble, mixer.rumble_coeff);
-        assert_ne!(old_lowpass, mixer.lowpass_alpha);
     }
 
     #[test]
@@ -737,6 +855,87 @@ mod tests {
         assert_eq!(mixer.blip_right.samples_avail(), 0);
     }
 
+    #[test]
+    fn fixed_point_mix_tracks_float_mix() {
+        let mut float = NesSoundMixer::new(CPU_CLOCK_NTSC, 48_000);
+        let mut fixed = NesSoundMixer::new(CPU_CLOCK_NTSC, 48_000);
+        fixed.set_fixed_point(true);
+
+        for (p, t, n, d) in [
+            (0, 0, 0, 0),
+            (15, 15, 15, 127),
+            (3, 9, 4, 64),
+            (30, 1, 0, 5),
+        ] {
+            for mixer in [&mut float, &mut fixed] {
+                mixer.set_channel_level(AudioChannel::Pulse1, 0, (p / 2) as f32);
+                mixer.set_channel_level(AudioChannel::Pulse2, 0, (p - p / 2) as f32);
+                mixer.set_channel_level(AudioChannel::Triangle, 0, t as f32);
+                mixer.set_channel_level(AudioChannel::Noise, 0, n as f32);
+                mixer.set_channel_level(AudioChannel::Dmc, 0, d as f32);
+                mixer.set_channel_level(AudioChannel::Vrc6, 0, 7.0);
+            }
+            let (float_l, float_r) = float.mix_output_volume_stereo();
+            let (fixed_l, fixed_r) = fixed.mix_output_volume_stereo();
+            // Both paths quantize to the same i16 DAC steps (x4); the
+            // rearranged integer curves may land one step apart.
+            assert!((float_l - fixed_l).abs() <= 8.0, "{float_l} vs {fixed_l}");
+            assert!((float_r - fixed_r).abs() <= 8.0, "{float_r} vs {fixed_r}");
+        }
+    }
+
+    /// Renders a few frames of a pulse/triangle pattern through `filter`.
+    fn render_filtered(filter: StereoFilterType, fixed_point: bool) -> Vec<f32> {
+        let mut mixer = NesSoundMixer::new(CPU_CLOCK_NTSC, 48_000);
+        mixer.set_fixed_point(fixed_point);
+        let mut settings = MixerSettings::default();
+        settings.panning[AudioChannel::Pulse1.idx()] = -0.5;
+        settings.stereo_filter = filter;
+        settings.stereo_delay_ms = 5.0;
+        settings.stereo_panning_angle_deg = 30.0;
+        settings.stereo_comb_delay_ms = 3.0;
+        settings.stereo_comb_strength = 0.7;
+        mixer.apply_mixer_settings(&settings);
+
+        let mut out = Vec::new();
+        for frame in 0..4_i64 {
+            let base = frame * 29_781;
+            for step in 0..60 {
+                let clock = base + step * 480;
+                mixer.set_channel_level(AudioChannel::Pulse1, clock, (step % 2 * 12) as f32);
+                mixer.set_channel_level(AudioChannel::Triangle, clock, (step % 16) as f32);
+            }
+            mixer.end_frame(base + 29_781, &mut out);
+        }
+        out
+    }
+
+    #[test]
+    fn fixed_point_stereo_filters_are_deterministic() {
+        for filter in [
+            StereoFilterType::Delay,
+            StereoFilterType::Panning,
+            StereoFilterType::Comb,
+        ] {
+            let first = render_filtered(filter, true);
+            let second = render_filtered(filter, true);
+            assert_eq!(
+                first.iter().map(|s| s.to_bits()).collect::<Vec<_>>(),
+                second.iter().map(|s| s.to_bits()).collect::<Vec<_>>(),
+                "{filter:?}"
+            );
+
+            // The filter runs rather than being bypassed, and stays close to
+            // the float path.
+            assert_ne!(first, render_filtered(StereoFilterType::None, true));
+            let float = render_filtered(filter, false);
+            assert_eq!(first.len(), float.len());
+            for (a, b) in first.iter().zip(&float) {
+                assert!((a - b).abs() < 0.01, "{filter:?}: {a} vs {b}");
+            }
+        }
+    }
+
     #[test]
     fn pending_mix_clock_in_future_is_flushed_on_later_end_frame() {
         let mut mixer = NesSoundMixer::new(CPU_CLOCK_NTSC, 48_000);
@@ -750,4 +949,46 @@ mod tests {
         mixer.end_frame(1_100, &mut out);
         assert_eq!(mixer.pending_mix_clock, None);
     }
+
+    #[test]
+    fn solo_mutes_other_channels_but_not_the_tap() {
+        let mut mixer = NesSoundMixer::new(CPU_CLOCK_NTSC, 48_000);
+        let mut settings = MixerSettings::default();
+        settings.solo[AudioChannel::Pulse1.idx()] = true;
+        mixer.apply_mixer_settings(&settings);
+        mixer.set_channel_tap(Some(AudioChannel::Triangle));
+
+        mixer.set_channel_level(AudioChannel::Pulse1, 0, 8.0);
+        mixer.set_channel_level(AudioChannel::Triangle, 0, 12.0);
+        let (left, _) = mixer.mix_output_volume_stereo();
+        assert_eq!(left, isolated_output(AudioChannel::Pulse1, 8.0));
+
+        let mut out = Vec::new();
+        mixer.end_frame(29_781, &mut out);
+        let mut tap = Vec::new();
+        mixer.take_tap_samples(&mut tap);
+        assert_eq!(tap.len() * 2, out.len());
+        let expected = isolated_output(AudioChannel::Triangle, 12.0) / 32_768.0;
+        // blip_buf lets DC drift back toward zero, so compare the step peak.
+        let peak = tap.iter().copied().fold(0.0_f32, f32::max);
+        assert!((peak - expected).abs() < 0.01, "{peak} vs {expected}");
+    }
+
+    #[test]
+    fn expansion_balance_scales_only_expansion_channels() {
+        let mut mixer = NesSoundMixer::new(CPU_CLOCK_NTSC, 48_000);
+        let mut settings = MixerSettings::default();
+        settings.expansion_balance[AudioChannel::Vrc6.idx()] = 1.0;
+        settings.expansion_balance[AudioChannel::Pulse1.idx()] = -1.0;
+        mixer.apply_mixer_settings(&settings);
+
+        mixer.set_channel_level(AudioChannel::Pulse1, 0, 8.0);
+        let (pulse, _) = mixer.mix_output_volume_stereo();
+        assert_eq!(pulse, isolated_output(AudioChannel::Pulse1, 8.0));
+
+        mixer.set_channel_level(AudioChannel::Pulse1, 0, 0.0);
+        mixer.set_channel_level(AudioChannel::Vrc6, 0, 10.0);
+        let (vrc6, _) = mixer.mix_output_volume_stereo();
+        assert_eq!(vrc6, isolated_output(AudioChannel::Vrc6, 20.0));
+    }
 }
@@ -1,4 +1,4 @@
-use crate::audio::{ChannelPanning, ChannelVolumes};
+use crate::audio::{AudioChannel, ChannelArray, ChannelPanning, ChannelVolumes};
 
 use super::filters::StereoFilterType;
 
@@ -13,6 +13,15 @@ pub struct MixerSettings {
     pub volume: ChannelVolumes,
     /// Per-channel panning in `[-1.0, 1.0]` (-1 = hard left, 0 = center, 1 = hard right).
     pub panning: ChannelPanning,
+    /// Level of each expansion chip against the 2A03 in `[-1.0, 1.0]`
+    /// (-1 = silent, 0 = Mesen2's weighting, 1 = twice as loud). Console
+    /// revisions and Famicom mods mix expansion audio at noticeably different
+    /// levels, so this lets users match their reference. Entries for the
+    /// 2A03 channels are ignored.
+    pub expansion_balance: ChannelArray<f32>,
+    /// Channels to solo. When any channel is soloed, every other channel is
+    /// muted regardless of its volume.
+    pub solo: ChannelArray<bool>,
     /// Optional stereo post-filter applied after mixing.
     pub stereo_filter: StereoFilterType,
     /// Delay (ms) for [`StereoFilterType::Delay`] and [`StereoFilterType::Comb`].
@@ -23,6 +32,14 @@ pub struct MixerSettings {
     pub stereo_comb_delay_ms: f32,
     /// Comb filter strength in `[0.0, 1.0]` for [`StereoFilterType::Comb`].
     pub stereo_comb_strength: f32,
+    /// Freezes the triangle output while its timer period is below 2.
+    ///
+    /// Such periods produce ultrasonic tones that real hardware smooths out
+    /// but that alias into audible pops here (Mesen2's `SilenceTriangleHighFreq`).
+    pub silence_triangle_ultrasonic: bool,
+    /// Halves large `$4011` DMC direct-load jumps to soften clicks
+    /// (Mesen2's `ReduceDmcPopping`).
+    pub reduce_dmc_popping: bool,
 }
 
 impl Default for MixerSettings {
@@ -30,11 +47,87 @@ impl Default for MixerSettings {
         Self {
             volume: ChannelVolumes::filled(1.0),
             panning: ChannelPanning::new(),
+            expansion_balance: ChannelArray::filled(0.0),
+            solo: ChannelArray::filled(false),
             stereo_filter: StereoFilterType::None,
             stereo_delay_ms: 0.0,
             stereo_panning_angle_deg: 0.0,
             stereo_comb_delay_ms: 0.0,
             stereo_comb_strength: 0.0,
+            silence_triangle_ultrasonic: false,
+            reduce_dmc_popping: false,
         }
     }
 }
+
+impl MixerSettings {
+    /// Replaces `panning` with the placement of `preset`.
+    pub fn apply_stereo_preset(&mut self, preset: StereoPreset) {
+        self.panning = preset.panning();
+    }
+
+    /// Returns the preset whose placement matches `panning`, if any.
+    pub fn stereo_preset(&self) -> Option<StereoPreset> {
+        StereoPreset::ALL
+            .into_iter()
+            .find(|preset| preset.panning().as_slice() == self.panning.as_slice())
+    }
+}
+
+/// Built-in stereo separation presets for [`MixerSettings::panning`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StereoPreset {
+    /// Every channel centered, as on hardware.
+    #[default]
+    Mono,
+    /// Slight separation of the 2A03 channels; expansion audio stays centered.
+    Light,
+    /// Wide per-channel spread in the style of Mesen2 stereo setups.
+    MesenSpread,
+}
+
+impl StereoPreset {
+    pub const ALL: [StereoPreset; 3] = [Self::Mono, Self::Light, Self::MesenSpread];
+
+    /// Per-channel panning in the same `[-1.0, 1.0]` units as
+    /// [`MixerSettings::panning`].
+    pub fn panning(self) -> ChannelPanning {
+        let mut panning = ChannelPanning::new();
+        for channel in AudioChannel::ALL {
+            panning[channel.idx()] = match (self, channel) {
+                (Self::Mono, _) => 0.0,
+                (Self::Light, AudioChannel::Pulse1) => -0.25,
+                (Self::Light, AudioChannel::Pulse2) => 0.25,
+                (Self::Light, AudioChannel::Noise) => 0.15,
+                (Self::Light, AudioChannel::Dmc) => -0.15,
+                (Self::Light, _) => 0.0,
+                (Self::MesenSpread, AudioChannel::Pulse1) => -0.6,
+                (Self::MesenSpread, AudioChannel::Pulse2) => 0.6,
+                (Self::MesenSpread, AudioChannel::Triangle) => -0.2,
+                (Self::MesenSpread, AudioChannel::Noise) => 0.4,
+                (Self::MesenSpread, AudioChannel::Dmc) => -0.4,
+                (Self::MesenSpread, AudioChannel::Vrc6 | AudioChannel::Namco163) => 0.3,
+                (Self::MesenSpread, AudioChannel::Mmc5 | AudioChannel::Sunsoft5B) => -0.3,
+                (Self::MesenSpread, _) => 0.0,
+            };
+        }
+        panning
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stereo_presets_round_trip_through_settings() {
+        let mut settings = MixerSettings::default();
+        assert_eq!(settings.stereo_preset(), Some(StereoPreset::Mono));
+        for preset in StereoPreset::ALL {
+            settings.apply_stereo_preset(preset);
+            assert_eq!(settings.stereo_preset(), Some(preset));
+        }
+        settings.panning[AudioChannel::Triangle.idx()] = 0.9;
+        assert_eq!(settings.stereo_preset(), None);
+    }
+}
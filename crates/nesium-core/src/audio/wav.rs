@@ -0,0 +1,189 @@
+//! Minimal 16-bit PCM WAV writer used for audio recording.
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+use crate::audio::{AudioChannel, NesSoundMixer};
+
+const HEADER_LEN: u32 = 44;
+const BYTES_PER_SAMPLE: u16 = 2;
+
+/// What an audio recording captures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordSource {
+    /// The stereo mixer output, after volume/panning/solo and stereo filters.
+    Mix,
+    /// A single channel rendered in mono at full volume, unaffected by the
+    /// mixer's volume, panning and solo settings.
+    Channel(AudioChannel),
+}
+
+impl RecordSource {
+    pub fn channels(self) -> u16 {
+        match self {
+            RecordSource::Mix => 2,
+            RecordSource::Channel(_) => 1,
+        }
+    }
+}
+
+/// Streams interleaved `f32` samples to a WAV container as signed 16-bit PCM.
+///
+/// The RIFF and data chunk sizes are written as zero up front and patched by
+/// [`WavWriter::finish`]; a file that is never finished is still playable by
+/// most tools but reports a zero length.
+#[derive(Debug)]
+pub struct WavWriter<W: Write + Seek> {
+    inner: W,
+    data_bytes: u32,
+}
+
+impl WavWriter<BufWriter<File>> {
+    pub fn create(path: impl AsRef<Path>, channels: u16, sample_rate: u32) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?), channels, sample_rate)
+    }
+}
+
+impl<W: Write + Seek> WavWriter<W> {
+    pub fn new(mut inner: W, channels: u16, sample_rate: u32) -> io::Result<Self> {
+        let block_align = channels * BYTES_PER_SAMPLE;
+        inner.write_all(b"RIFF")?;
+        inner.write_all(&0u32.to_le_bytes())?;
+        inner.write_all(b"WAVEfmt ")?;
+        inner.write_all(&16u32.to_le_bytes())?;
+        inner.write_all(&1u16.to_le_bytes())?; // PCM
+        inner.write_all(&channels.to_le_bytes())?;
+        inner.write_all(&sample_rate.to_le_bytes())?;
+        inner.write_all(&(sample_rate * u32::from(block_align)).to_le_bytes())?;
+        inner.write_all(&block_align.to_le_bytes())?;
+        inner.write_all(&(BYTES_PER_SAMPLE * 8).to_le_bytes())?;
+        inner.write_all(b"data")?;
+        inner.write_all(&0u32.to_le_bytes())?;
+        Ok(Self {
+            inner,
+            data_bytes: 0,
+        })
+    }
+
+    /// Appends samples in `[-1.0, 1.0]`; out-of-range values are clipped.
+    pub fn write_samples(&mut self, samples: &[f32]) -> io::Result<()> {
+        for &sample in samples {
+            let value = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+            self.inner.write_all(&value.to_le_bytes())?;
+        }
+        self.data_bytes = self
+            .data_bytes
+            .saturating_add(samples.len() as u32 * u32::from(BYTES_PER_SAMPLE));
+        Ok(())
+    }
+
+    /// Patches the chunk sizes and flushes, returning the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.inner.seek(SeekFrom::Start(4))?;
+        self.inner
+            .write_all(&(HEADER_LEN - 8 + self.data_bytes).to_le_bytes())?;
+        self.inner
+            .seek(SeekFrom::Start(u64::from(HEADER_LEN) - 4))?;
+        self.inner.write_all(&self.data_bytes.to_le_bytes())?;
+        self.inner.seek(SeekFrom::End(0))?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+/// An in-progress recording owned by [`crate::Nes`].
+///
+/// Write errors are latched rather than surfaced from `run_frame`; the first
+/// one stops further writes and is reported when the recording is finished.
+#[derive(Debug)]
+pub(crate) struct AudioRecorder {
+    source: RecordSource,
+    writer: WavWriter<BufWriter<File>>,
+    scratch: Vec<f32>,
+    error: Option<io::Error>,
+}
+
+impl AudioRecorder {
+    pub(crate) fn start(
+        path: &Path,
+        source: RecordSource,
+        sample_rate: u32,
+        mixer: &mut NesSoundMixer,
+    ) -> io::Result<Self> {
+        let writer = WavWriter::create(path, source.channels(), sample_rate)?;
+        mixer.set_channel_tap(match source {
+            RecordSource::Mix => None,
+            RecordSource::Channel(channel) => Some(channel),
+        });
+        Ok(Self {
+            source,
+            writer,
+            scratch: Vec::new(),
+            error: None,
+        })
+    }
+
+    /// Appends the audio produced by the latest `NesSoundMixer::end_frame`;
+    /// `mix` is that call's stereo output.
+    pub(crate) fn record(&mut self, mixer: &mut NesSoundMixer, mix: &[f32]) {
+        let samples = match self.source {
+            RecordSource::Mix => mix,
+            RecordSource::Channel(_) => {
+                self.scratch.clear();
+                mixer.take_tap_samples(&mut self.scratch);
+                &self.scratch
+            }
+        };
+        if self.error.is_none()
+            && let Err(err) = self.writer.write_samples(samples)
+        {
+            self.error = Some(err);
+        }
+    }
+
+    pub(crate) fn finish(self, mixer: &mut NesSoundMixer) -> io::Result<()> {
+        mixer.set_channel_tap(None);
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        self.writer.finish().map(drop)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn writes_header_and_clipped_pcm() {
+        let mut wav = WavWriter::new(Cursor::new(Vec::new()), 2, 48_000).expect("header");
+        wav.write_samples(&[0.0, 1.0, -2.0, 0.5]).expect("samples");
+        let bytes = wav.finish().expect("finish").into_inner();
+
+        assert_eq!(bytes.len(), 44 + 8);
+        assert_eq!(&bytes[..4], b"RIFF");
+        assert_eq!(
+            u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+            36 + 8
+        );
+        assert_eq!(u16::from_le_bytes([bytes[22], bytes[23]]), 2);
+        assert_eq!(
+            u32::from_le_bytes([bytes[24], bytes[25], bytes[26], bytes[27]]),
+            48_000
+        );
+        assert_eq!(
+            u32::from_le_bytes([bytes[40], bytes[41], bytes[42], bytes[43]]),
+            8
+        );
+        let pcm: Vec<i16> = bytes[44..]
+            .chunks_exact(2)
+            .map(|b| i16::from_le_bytes([b[0], b[1]]))
+            .collect();
+        assert_eq!(pcm, [0, i16::MAX, -i16::MAX, i16::MAX / 2]);
+    }
+}
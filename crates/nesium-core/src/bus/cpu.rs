@@ -1,11 +1,14 @@
+use std::collections::BTreeMap;
+
 use crate::{
     apu::{Apu, ExpansionAudioClockContext},
     audio::NesSoundMixer,
     bus::{BusDevices, BusDevicesMut, DmcDmaEvent, OpenBus, PendingDma},
     cartridge::{Cartridge, CpuBusAccessKind},
     context::Context,
-    controller::{ControllerPorts, SerialLogger},
+    controller::{ControllerPorts, Multitap, SerialLogger, ZapperPorts},
     cpu::Cpu,
+    debugger::{Debugger, FramePosition},
     mem_block::cpu as cpu_ram,
     memory::{apu as apu_mem, cpu as cpu_mem, ppu as ppu_mem},
     ppu::{Ppu, ppu_bus::PpuBus},
@@ -20,9 +23,16 @@ pub struct CpuBus<'a> {
     pub(crate) apu: &'a mut Apu,
     pub(crate) cartridge: Option<&'a mut Cartridge>,
     pub(crate) controllers: &'a mut ControllerPorts,
+    pub(crate) zappers: &'a ZapperPorts,
+    pub(crate) multitap: &'a mut Option<Multitap>,
     pub(crate) serial_log: Option<&'a mut SerialLogger>,
     pub(crate) open_bus: &'a mut OpenBus,
     pub(crate) mixer: Option<&'a mut NesSoundMixer>,
+    /// Breakpoint observer; `None` unless a debugger is attached.
+    pub(crate) debugger: Option<&'a mut Debugger>,
+    /// Per-address counts of writes nothing responded to; `None` unless
+    /// tracking is enabled (see [`crate::Nes::set_unmapped_write_tracking`]).
+    pub(crate) unmapped_writes: Option<&'a mut BTreeMap<u16, u32>>,
     /// Approximate CPU bus cycle counter (increments per bus access).
     pub(crate) cycles: &'a mut u64,
     /// Master clock in master cycles (PPU = 4 mc, CPU = 12 mc).
@@ -33,6 +43,9 @@ pub struct CpuBus<'a> {
     pub(crate) clock_start_count: u8,
     pub(crate) clock_end_count: u8,
     pub(crate) pending_dma: &'a mut PendingDma,
+    /// Famicom (HVC-001) controller ports: every DMA dummy read of
+    /// `$4016/$4017` clocks the pads, and `$4016` drives D0-D2.
+    pub(crate) famicom: bool,
 }
 
 impl<'a> CpuBus<'a> {
@@ -189,17 +202,33 @@ impl<'a> CpuBus<'a> {
         self.pending_dma.oam_page = Some(page);
     }
 
+    /// Bits of a controller port read left to open bus (Mesen's NES-001 and
+    /// HVC-001 masks).
     #[inline]
-    fn controller_open_bus_mask(_port: usize) -> u8 {
-        // Match Mesen's default NES-001 open-bus mask for standard pads.
-        0xE0
+    pub(crate) fn controller_open_bus_mask(&self, port: usize) -> u8 {
+        if self.famicom && port == 0 {
+            0xF8
+        } else {
+            0xE0
+        }
     }
 
     #[inline]
     fn read_controller_port(&mut self, port: usize) -> u8 {
-        let mask = Self::controller_open_bus_mask(port);
+        let mask = self.controller_open_bus_mask(port);
         let open_bus = self.open_bus.sample() & mask;
-        let data = self.controllers[port].read() & !mask;
+        let data = match (&self.zappers[port], self.multitap.as_mut()) {
+            (Some(zapper), _) => zapper.read(self.ppu),
+            (None, Some(tap)) => {
+                let pad = if tap.passes_standard_pads() {
+                    self.controllers[port].read()
+                } else {
+                    0
+                };
+                pad | tap.read(port, self.controllers)
+            }
+            (None, None) => self.controllers[port].read(),
+        } & !mask;
         open_bus | data
     }
 
@@ -245,7 +274,10 @@ impl<'a> CpuBus<'a> {
             }
             cpu_mem::PPU_REGISTER_BASE..=cpu_mem::PPU_REGISTER_END => {
                 let mut pattern = PpuBus::new(self.cartridge.as_deref_mut(), *self.cycles);
-                self.ppu.cpu_read(addr, &mut pattern)
+                let value = self.ppu.cpu_read(addr, &mut pattern);
+                // Peeks are invisible to watchpoints.
+                self.ppu.take_data_access();
+                value
             }
             cpu_mem::APU_REGISTER_BASE..=cpu_mem::APU_REGISTER_END => OpenBus::peek(addr),
             ppu_mem::OAM_DMA => OpenBus::peek(addr),
@@ -274,7 +306,9 @@ impl<'a> CpuBus<'a> {
             }
             cpu_mem::PPU_REGISTER_BASE..=cpu_mem::PPU_REGISTER_END => {
                 let mut pattern = PpuBus::new(self.cartridge.as_deref_mut(), *self.cycles);
-                self.ppu.cpu_read(addr, &mut pattern)
+                let value = self.ppu.cpu_read(addr, &mut pattern);
+                self.forward_ppu_data_access();
+                value
             }
             cpu_mem::APU_REGISTER_BASE..=cpu_mem::APU_REGISTER_END => {
                 driven = false;
@@ -328,6 +362,7 @@ impl<'a> CpuBus<'a> {
             cpu_mem::PPU_REGISTER_BASE..=cpu_mem::PPU_REGISTER_END => {
                 let mut pattern = PpuBus::new(self.cartridge.as_deref_mut(), *self.cycles);
                 self.ppu.cpu_write(addr, data, &mut pattern);
+                self.forward_ppu_data_access();
             }
             cpu_mem::APU_REGISTER_BASE..=cpu_mem::APU_REGISTER_END => {
                 self.apu.cpu_write(addr, data, *self.cycles);
@@ -355,14 +390,51 @@ impl<'a> CpuBus<'a> {
                 for ctrl in self.controllers.iter_mut() {
                     ctrl.write_strobe(data);
                 }
+                if let Some(tap) = self.multitap.as_mut() {
+                    tap.write_strobe(data, self.controllers);
+                }
             }
-            cpu_mem::TEST_MODE_BASE..=cpu_mem::TEST_MODE_END => {}
+            cpu_mem::TEST_MODE_BASE..=cpu_mem::TEST_MODE_END => self.note_unmapped_write(addr),
             cpu_mem::CARTRIDGE_SPACE_BASE..=cpu_mem::CPU_ADDR_END => {
-                self.write_cartridge(addr, data)
+                self.write_cartridge(addr, data);
+                if self.unmapped_writes.is_some() && self.read_cartridge(addr).is_none() {
+                    self.note_unmapped_write(addr);
+                }
             }
         }
     }
 
+    /// Counts a write that reached no register or memory. Cartridge writes
+    /// qualify when the address also floats on read, so write-only mapper
+    /// registers outside `$6000-$FFFF` are reported too.
+    fn note_unmapped_write(&mut self, addr: u16) {
+        if let Some(log) = self.unmapped_writes.as_deref_mut() {
+            *log.entry(addr).or_default() += 1;
+        }
+    }
+
+    /// Reports a CPU access to the debugger, if one is attached.
+    fn observe_access(&mut self, cpu: &Cpu, kind: CpuBusAccessKind, addr: u16, value: u8) {
+        if let Some(debugger) = self.debugger.as_deref_mut() {
+            debugger.on_cpu_access(cpu, kind, addr, value, *self.cycles);
+            let at = FramePosition {
+                frame: self.ppu.frame,
+                scanline: self.ppu.scanline,
+                dot: self.ppu.cycle,
+            };
+            debugger.log_event(cpu, kind, addr, value, at);
+        }
+    }
+
+    /// Hands the PPU's last `$2007` memory access to the debugger.
+    fn forward_ppu_data_access(&mut self) {
+        if let Some(access) = self.ppu.take_data_access()
+            && let Some(debugger) = self.debugger.as_deref_mut()
+        {
+            debugger.on_ppu_access(access, *self.cycles);
+        }
+    }
+
     #[inline]
     pub fn mem_read(&mut self, addr: u16, cpu: &mut Cpu, ctx: &mut Context) -> u8 {
         self.mem_read_with_kind(addr, cpu, ctx, CpuBusAccessKind::Read)
@@ -380,6 +452,7 @@ impl<'a> CpuBus<'a> {
         cpu.begin_cycle(true, self, ctx);
         let value = self.read(addr, cpu, ctx);
         self.notify_mapper_cpu_bus_access(kind, addr, value);
+        self.observe_access(cpu, kind, addr, value);
         cpu.end_cycle(true, self, ctx);
         value
     }
@@ -401,6 +474,7 @@ impl<'a> CpuBus<'a> {
         cpu.begin_cycle(false, self, ctx);
         self.write(addr, data, cpu, ctx);
         self.notify_mapper_cpu_bus_access(kind, addr, data);
+        self.observe_access(cpu, kind, addr, data);
         cpu.end_cycle(false, self, ctx);
     }
 
@@ -508,6 +582,135 @@ mod tests {
         Cartridge::new(header, Box::new(mapper))
     }
 
+    /// Owns everything a [`CpuBus`] borrows, for tests that run many accesses.
+    struct Console {
+        cpu: Cpu,
+        ppu: Ppu,
+        apu: Apu,
+        ram: cpu_ram::Ram,
+        cartridge: Cartridge,
+        controllers: ControllerPorts,
+        zappers: ZapperPorts,
+        multitap: Option<Multitap>,
+        pending_dma: PendingDma,
+        open_bus: OpenBus,
+        cycles: u64,
+        master_clock: u64,
+        famicom: bool,
+    }
+
+    impl Console {
+        fn new(famicom: bool) -> Self {
+            Self {
+                cpu: Cpu::new(),
+                ppu: Ppu::default(),
+                apu: Apu::new(),
+                ram: cpu_ram::Ram::new(),
+                cartridge: cartridge_with_pattern(0x4000, 0x2000),
+                controllers: ControllerPorts::new(),
+                zappers: ZapperPorts::default(),
+                multitap: None,
+                pending_dma: PendingDma::default(),
+                open_bus: OpenBus::new(),
+                cycles: 0,
+                master_clock: 0,
+                famicom,
+            }
+        }
+
+        /// Runs `f` with a bus over this console and the CPU.
+        fn with_bus<R>(&mut self, f: impl FnOnce(&mut CpuBus, &mut Cpu) -> R) -> R {
+            let mut bus = CpuBus {
+                ram: &mut self.ram,
+                ppu: &mut self.ppu,
+                apu: &mut self.apu,
+                cartridge: Some(&mut self.cartridge),
+                controllers: &mut self.controllers,
+                zappers: &self.zappers,
+                multitap: &mut self.multitap,
+                serial_log: None,
+                open_bus: &mut self.open_bus,
+                mixer: None,
+                debugger: None,
+                unmapped_writes: None,
+                cycles: &mut self.cycles,
+                master_clock: &mut self.master_clock,
+                ppu_offset: 1,
+                clock_start_count: 6,
+                clock_end_count: 6,
+                pending_dma: &mut self.pending_dma,
+                famicom: self.famicom,
+            };
+            f(&mut bus, &mut self.cpu)
+        }
+
+        fn read(&mut self, addr: u16) -> u8 {
+            self.with_bus(|bus, cpu| bus.mem_read(addr, cpu, &mut Context::None))
+        }
+
+        fn write(&mut self, addr: u16, value: u8) {
+            self.with_bus(|bus, cpu| bus.mem_write(addr, value, cpu, &mut Context::None));
+        }
+
+        /// Reads `addr` with a DMC sample fetch from `$C000` landing on it.
+        fn read_during_dmc_dma(&mut self, addr: u16) -> u8 {
+            self.with_bus(|bus, cpu| {
+                bus.pending_dma.dmc = Some(DmcDmaEvent::Request { addr: 0xC000 });
+                bus.mem_read(addr, cpu, &mut Context::None)
+            })
+        }
+
+        /// Latches pad 1 with `buttons` and returns the serial bits of the
+        /// next `N` reads, the first one hit by a DMC DMA.
+        fn pad_bits_with_dma<const N: usize>(&mut self, buttons: u8) -> [u8; N] {
+            self.controllers[0].set_state(buttons);
+            self.write(0x4016, 1);
+            self.write(0x4016, 0);
+            let mut bits = [0; N];
+            bits[0] = self.read_during_dmc_dma(0x4016) & 1;
+            for bit in &mut bits[1..] {
+                *bit = self.read(0x4016) & 1;
+            }
+            bits
+        }
+    }
+
+    #[test]
+    fn dmc_dma_on_a_controller_read_deletes_a_bit() {
+        // A, Select, Down, Right.
+        let buttons = 0b1010_0101;
+        // The halt cycle re-reads $4016; later dummy reads are hidden.
+        let bits = Console::new(false).pad_bits_with_dma::<6>(buttons);
+        assert_eq!(bits, [0, 1, 0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn famicom_pads_see_every_dma_dummy_read() {
+        let buttons = 0b1010_0101;
+        let bits = Console::new(true).pad_bits_with_dma::<5>(buttons);
+        assert_eq!(bits, [1, 0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn dmc_dma_on_a_2007_read_skips_buffered_bytes() {
+        let mut console = Console::new(false);
+        console.ppu.ciram.as_mut_slice()[..6]
+            .copy_from_slice(&[0x11, 0x22, 0x33, 0x44, 0x55, 0x66]);
+        console.write(0x2006, 0x20);
+        console.write(0x2006, 0x00);
+        // Prime the read buffer, leaving the CPU idle long enough for the
+        // PPU to accept another read.
+        console.read(0x2007);
+        console.read(0x0000);
+        assert_eq!(console.read(0x2007), 0x11);
+        console.read(0x0000);
+
+        // The halt cycle's read consumes $22 and the CPU sees the byte after.
+        assert_eq!(console.read_during_dmc_dma(0x2007), 0x33);
+        console.read(0x0000);
+        assert_eq!(console.read(0x2007), 0x44);
+    }
+
     #[test]
     fn mirrors_internal_ram() {
         let mut cpu = Cpu::new();
@@ -515,6 +718,8 @@ mod tests {
         let mut apu = Apu::new();
         let mut ram = cpu_ram::Ram::new();
         let mut controllers = ControllerPorts::new();
+        let zappers = ZapperPorts::default();
+        let mut multitap = None;
         let mut pending_dma = PendingDma::default();
         let mut open_bus = OpenBus::new();
         let mut cpu_bus_cycle = 0;
@@ -525,15 +730,20 @@ mod tests {
             apu: &mut apu,
             cartridge: None,
             controllers: &mut controllers,
+            zappers: &zappers,
+            multitap: &mut multitap,
             serial_log: None,
             open_bus: &mut open_bus,
             mixer: None,
+            debugger: None,
+            unmapped_writes: None,
             cycles: &mut cpu_bus_cycle,
             master_clock: &mut master_clock,
             ppu_offset: 1,
             clock_start_count: 6,
             clock_end_count: 6,
             pending_dma: &mut pending_dma,
+            famicom: false,
         };
         bus.mem_write(
             cpu_mem::INTERNAL_RAM_START + 0x0002,
@@ -562,6 +772,8 @@ mod tests {
         let mut ram = cpu_ram::Ram::new();
         let mut cartridge = cartridge_with_pattern(0x4000, 0x2000);
         let mut controllers = ControllerPorts::new();
+        let zappers = ZapperPorts::default();
+        let mut multitap = None;
         let mut pending_dma = PendingDma::default();
         let mut open_bus = OpenBus::new();
         let mut cpu_bus_cycle = 0;
@@ -572,15 +784,20 @@ mod tests {
             apu: &mut apu,
             cartridge: Some(&mut cartridge),
             controllers: &mut controllers,
+            zappers: &zappers,
+            multitap: &mut multitap,
             serial_log: None,
             open_bus: &mut open_bus,
             mixer: None,
+            debugger: None,
+            unmapped_writes: None,
             cycles: &mut cpu_bus_cycle,
             master_clock: &mut master_clock,
             ppu_offset: 1,
             clock_start_count: 6,
             clock_end_count: 6,
             pending_dma: &mut pending_dma,
+            famicom: false,
         };
         let first_bank = bus.mem_read(cpu_mem::PRG_ROM_START, &mut cpu, &mut Context::None);
         let mirrored_bank = bus.mem_read(
@@ -599,6 +816,8 @@ mod tests {
         let mut ram = cpu_ram::Ram::new();
         let mut cartridge = cartridge_with_pattern(0x4000, 0x2000);
         let mut controllers = ControllerPorts::new();
+        let zappers = ZapperPorts::default();
+        let mut multitap = None;
         let mut pending_dma = PendingDma::default();
         let mut open_bus = OpenBus::new();
         let mut cpu_bus_cycle = 0;
@@ -609,15 +828,20 @@ mod tests {
             apu: &mut apu,
             cartridge: Some(&mut cartridge),
             controllers: &mut controllers,
+            zappers: &zappers,
+            multitap: &mut multitap,
             serial_log: None,
             open_bus: &mut open_bus,
             mixer: None,
+            debugger: None,
+            unmapped_writes: None,
             cycles: &mut cpu_bus_cycle,
             master_clock: &mut master_clock,
             ppu_offset: 1,
             clock_start_count: 6,
             clock_end_count: 6,
             pending_dma: &mut pending_dma,
+            famicom: false,
         };
         bus.mem_write(cpu_mem::PRG_RAM_START, 0x42, &mut cpu, &mut Context::None);
         assert_eq!(
@@ -14,9 +14,9 @@ mod db;
 use self::db::lookup_override;
 use self::mapper::{
     Mapper0, Mapper1, Mapper2, Mapper3, Mapper4, Mapper5, Mapper6, Mapper7, Mapper8, Mapper9,
-    Mapper10, Mapper11, Mapper13, Mapper16, Mapper18, Mapper19, Mapper21, Mapper23, Mapper25,
-    Mapper26, Mapper34, Mapper66, Mapper69, Mapper71, Mapper78, Mapper85, Mapper90, Mapper119,
-    Mapper228, NametableTarget,
+    Mapper10, Mapper11, Mapper13, Mapper16, Mapper18, Mapper19, Mapper21, Mapper22, Mapper23,
+    Mapper25, Mapper26, Mapper34, Mapper66, Mapper69, Mapper71, Mapper78, Mapper85, Mapper90,
+    Mapper105, Mapper119, Mapper228, Mapper682, NametableTarget,
 };
 
 pub const TRAINER_SIZE: usize = 512;
@@ -75,29 +75,46 @@ impl<'a> From<&'a [u8]> for CartridgeImage {
 }
 
 pub mod a12_watcher;
+pub mod archive;
 pub mod header;
+mod info;
+mod loader;
 pub mod mapper;
+pub mod patch;
+pub use info::CartridgeInfo;
+pub use loader::{CartridgeLoader, LoadError, LoadProgress, LoadStage, LoadWarning};
 pub use mapper::{
-    CpuBusAccessKind, Mapper, MapperEvent, MapperHookMask, MapperMemoryOperation,
-    PpuRenderFetchInfo, PpuRenderFetchTarget, PpuRenderFetchType, PpuVramAccessContext,
-    PpuVramAccessSource, Provider, mapper_downcast_mut, mapper_downcast_ref,
+    CpuBusAccessKind, Mapper, MapperEvent, MapperFactory, MapperHookMask, MapperMemoryOperation,
+    MapperRegistry, Mmc3Revision, PpuRenderFetchInfo, PpuRenderFetchTarget, PpuRenderFetchType,
+    PpuVramAccessContext, PpuVramAccessSource, Provider, mapper_downcast_mut, mapper_downcast_ref,
 };
 
 #[derive(Debug)]
 pub struct Cartridge {
     header: Header,
     mapper: Box<dyn Mapper>,
+    info: CartridgeInfo,
 }
 
 impl Cartridge {
     pub fn new(header: Header, mapper: Box<dyn Mapper>) -> Self {
-        Self { header, mapper }
+        let info = CartridgeInfo::new(&header, mapper.as_ref());
+        Self {
+            header,
+            mapper,
+            info,
+        }
     }
 
     pub fn header(&self) -> &Header {
         &self.header
     }
 
+    /// Metadata gathered when the cartridge was built.
+    pub fn info(&self) -> &CartridgeInfo {
+        &self.info
+    }
+
     pub fn mapper(&self) -> &dyn Mapper {
         self.mapper.as_ref()
     }
@@ -110,6 +127,36 @@ impl Cartridge {
         self.mapper.mirroring()
     }
 
+    /// Battery-backed RAM as written to a save file: PRG RAM followed by any
+    /// CHR battery RAM. `None` for boards without a battery.
+    pub fn battery_ram(&self) -> Option<Vec<u8>> {
+        if !self.header.battery_backed_ram() {
+            return None;
+        }
+        let memory = self.mapper.memory_ref();
+        let prg = memory.prg_ram.unwrap_or_default();
+        let chr = memory.chr_battery_ram.unwrap_or_default();
+        (!prg.is_empty() || !chr.is_empty()).then(|| [prg, chr].concat())
+    }
+
+    /// Restores RAM saved by [`battery_ram`](Self::battery_ram). Returns
+    /// `false` and leaves RAM untouched when the size doesn't match the board.
+    pub fn load_battery_ram(&mut self, data: &[u8]) -> bool {
+        if !self.header.battery_backed_ram() {
+            return false;
+        }
+        let memory = self.mapper.memory_mut();
+        let prg = memory.prg_ram.unwrap_or_default();
+        let chr = memory.chr_battery_ram.unwrap_or_default();
+        if prg.len() + chr.len() != data.len() || data.is_empty() {
+            return false;
+        }
+        let (prg_data, chr_data) = data.split_at(prg.len());
+        prg.copy_from_slice(prg_data);
+        chr.copy_from_slice(chr_data);
+        true
+    }
+
     pub fn cpu_read(&self, addr: u16, open_bus: u8) -> Option<u8> {
         self.mapper.cpu_read(addr, open_bus)
     }
@@ -250,6 +297,7 @@ impl Clone for Cartridge {
         Self {
             header: self.header,
             mapper: dyn_clone::clone_box(self.mapper()),
+            info: self.info.clone(),
         }
     }
 }
@@ -268,7 +316,12 @@ pub fn load_cartridge_with_provider(
     provider: Option<&dyn Provider>,
 ) -> Result<Cartridge, Error> {
     match image.into() {
-        CartridgeImage::Owned(bytes) => load_cartridge_from_bytes(&bytes, provider),
+        CartridgeImage::Owned(bytes) => {
+            Ok(CartridgeLoader::new().provider(provider).load(bytes)?)
+        }
+        CartridgeImage::Static(bytes) if archive::is_zip(bytes) => Ok(CartridgeLoader::new()
+            .provider(provider)
+            .load(bytes.to_vec())?),
         CartridgeImage::Static(bytes) => load_cartridge_from_static_bytes(bytes, provider),
     }
 }
@@ -281,9 +334,9 @@ fn build_cartridge_from_sections<'a>(
     provider: Option<&dyn Provider>,
 ) -> Result<Cartridge, Error> {
     // 1) Give the external provider first chance when it explicitly
-    //    declares support for this mapper ID.
+    //    declares support for this mapper/submapper.
     if let Some(provider) = provider
-        && provider.supports_mapper(header.mapper())
+        && provider.supports_header(&header)
     {
         let mut mapper = provider
             .get_mapper(header, prg_rom, chr_rom, trainer)
@@ -312,6 +365,7 @@ fn build_cartridge_from_sections<'a>(
         18 => Box::new(Mapper18::new(header, prg_rom, chr_rom, trainer)),
         19 => Box::new(Mapper19::new(header, prg_rom, chr_rom, trainer)),
         21 => Box::new(Mapper21::new(header, prg_rom, chr_rom, trainer)),
+        22 => Box::new(Mapper22::new(header, prg_rom, chr_rom, trainer)),
         23 => Box::new(Mapper23::new(header, prg_rom, chr_rom, trainer)),
         25 => Box::new(Mapper25::new(header, prg_rom, chr_rom, trainer)),
         26 => Box::new(Mapper26::new(header, prg_rom, chr_rom, trainer)),
@@ -322,8 +376,10 @@ fn build_cartridge_from_sections<'a>(
         78 => Box::new(Mapper78::new(header, prg_rom, chr_rom, trainer)),
         85 => Box::new(Mapper85::new(header, prg_rom, chr_rom, trainer)),
         90 => Box::new(Mapper90::new(header, prg_rom, chr_rom, trainer)),
+        105 => Box::new(Mapper105::new(header, prg_rom, chr_rom, trainer)),
         119 => Box::new(Mapper119::new(header, prg_rom, chr_rom, trainer)),
         228 => Box::new(Mapper228::new(header, prg_rom, chr_rom, trainer)),
+        682 => Box::new(Mapper682::new(header, prg_rom, chr_rom, trainer)),
         // 3) Unknown to the core: let the provider try to supply a mapper
         //    implementation as a final fallback.
         other => provider
@@ -336,22 +392,6 @@ fn build_cartridge_from_sections<'a>(
     Ok(Cartridge::new(header, mapper))
 }
 
-fn load_cartridge_from_bytes(
-    bytes: &[u8],
-    provider: Option<&dyn Provider>,
-) -> Result<Cartridge, Error> {
-    let header_bytes = bytes.get(..NES_HEADER_LEN).ok_or(Error::TooShort {
-        actual: bytes.len(),
-    })?;
-    let parsed_header = Header::parse(header_bytes)?;
-    let (trainer, prg_rom, chr_rom) = slice_sections(bytes, &parsed_header)?;
-    let header = lookup_override(&parsed_header, prg_rom.as_ref(), chr_rom.as_ref())
-        .map(|info| parsed_header.with_runtime_mapper_submapper(info.mapper, info.submapper))
-        .unwrap_or(parsed_header);
-
-    build_cartridge_from_sections(header, trainer, prg_rom, chr_rom, provider)
-}
-
 fn load_cartridge_from_static_bytes(
     bytes: &'static [u8],
     provider: Option<&dyn Provider>,
@@ -368,7 +408,8 @@ fn load_cartridge_from_static_bytes(
     build_cartridge_from_sections(header, trainer, prg_rom, chr_rom, provider)
 }
 
-/// Load a cartridge directly from disk.
+/// Load a cartridge directly from disk. Zip archives are unpacked first (see
+/// [`archive`]).
 pub fn load_cartridge_from_file<P>(path: P) -> Result<Cartridge, Error>
 where
     P: AsRef<Path>,
@@ -477,7 +518,7 @@ fn slice_sections<'a>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::memory::cpu as cpu_mem;
+    use crate::{config::region::Region, memory::cpu as cpu_mem};
 
     fn base_header(prg_banks: u8, chr_banks: u8, flags6: u8) -> [u8; NES_HEADER_LEN] {
         [
@@ -499,6 +540,50 @@ mod tests {
         assert_eq!(cartridge.ppu_read(0x0000), Some(0x55));
     }
 
+    #[test]
+    fn battery_ram_round_trips() {
+        let mut rom = base_header(1, 1, 0b0000_0010).to_vec();
+        rom.extend(vec![0xAA; 16 * 1024]);
+        rom.extend(vec![0x55; 8 * 1024]);
+        let mut cartridge = load_cartridge(rom.clone()).expect("parse cartridge");
+        cartridge.cpu_write(0x6000, 0x42, 0);
+        let saved = cartridge.battery_ram().expect("battery ram");
+
+        let mut reloaded = load_cartridge(rom).expect("parse cartridge");
+        assert!(!reloaded.load_battery_ram(&saved[1..]));
+        assert!(reloaded.load_battery_ram(&saved));
+        assert_eq!(reloaded.cpu_read(0x6000, 0), Some(0x42));
+
+        let mut no_battery = base_header(1, 1, 0).to_vec();
+        no_battery.extend(vec![0xAA; 16 * 1024]);
+        no_battery.extend(vec![0x55; 8 * 1024]);
+        let cartridge = load_cartridge(no_battery).expect("parse cartridge");
+        assert_eq!(cartridge.battery_ram(), None);
+    }
+
+    #[test]
+    fn info_summarises_the_rom_body() {
+        let mut rom = base_header(1, 1, 0b0000_0011).to_vec();
+        rom.extend(vec![0xAA; 16 * 1024]);
+        rom.extend(vec![0x55; 8 * 1024]);
+        let body = rom[NES_HEADER_LEN..].to_vec();
+
+        let info = load_cartridge(rom).expect("parse cartridge").info().clone();
+
+        assert_eq!((info.mapper, info.submapper), (0, 0));
+        assert_eq!(info.board, "NROM");
+        assert_eq!(info.mirroring, Mirroring::Vertical);
+        assert_eq!(
+            (info.prg_rom_size, info.chr_rom_size),
+            (16 * 1024, 8 * 1024)
+        );
+        assert!(info.battery);
+        assert_eq!(info.crc32, crc32fast::hash(&body));
+        assert_eq!(info.sha1_hex().len(), 40);
+        assert_eq!(info.region, Region::Ntsc);
+        assert!(!info.database_match);
+    }
+
     #[test]
     fn loads_cartridge_with_trainer() {
         let mut rom = base_header(1, 0, 0b0000_0100).to_vec();
@@ -596,4 +681,35 @@ mod tests {
 
         assert_eq!(cartridge.mapper().mapper_id(), 999);
     }
+
+    #[test]
+    fn registry_dispatches_on_submapper() {
+        let nrom_with_submapper = |submapper: u8| {
+            let mut rom = base_header(1, 1, 0).to_vec();
+            rom[7] = 0x08; // NES 2.0
+            rom[8] = submapper << 4;
+            rom.extend(vec![0xAA; 16 * 1024]);
+            rom.extend(vec![0x55; 8 * 1024]);
+            rom
+        };
+
+        let mut registry = MapperRegistry::new();
+        registry.register_submapper(0, 1, |_, _, _, _| Box::new(DummyMapper));
+        assert!(!registry.supports_mapper(12));
+
+        let cartridge = load_cartridge_with_provider(nrom_with_submapper(1), Some(&registry))
+            .expect("registry supplies mapper");
+        assert_eq!(cartridge.mapper().mapper_id(), 999);
+
+        // Other submappers keep the built-in board.
+        let cartridge = load_cartridge_with_provider(nrom_with_submapper(0), Some(&registry))
+            .expect("built-in mapper");
+        assert_eq!(cartridge.mapper().mapper_id(), 0);
+
+        // A catch-all entry covers the remaining submappers.
+        registry.register(0, |_, _, _, _| Box::new(DummyMapper));
+        let cartridge = load_cartridge_with_provider(nrom_with_submapper(0), Some(&registry))
+            .expect("registry supplies mapper");
+        assert_eq!(cartridge.mapper().mapper_id(), 999);
+    }
 }
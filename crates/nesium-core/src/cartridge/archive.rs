@@ -0,0 +1,118 @@
+//! ROM images packed inside `.zip` archives.
+//!
+//! Archives are recognised by their local-file-header magic rather than the
+//! file extension, so a zipped ROM loads the same way whether it came from
+//! disk or from memory. Extraction requires the `archive` feature; without it
+//! a zip image is rejected with [`Error::InvalidArchive`].
+
+use crate::error::Error;
+
+/// Extensions (compared case-insensitively) of archive entries that are
+/// considered ROM images when no entry name is given.
+pub const ROM_EXTENSIONS: &[&str] = &["nes", "fds", "nsf"];
+
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+
+/// Returns true when `bytes` starts like a zip archive.
+pub fn is_zip(bytes: &[u8]) -> bool {
+    bytes.starts_with(ZIP_MAGIC)
+}
+
+/// Whether an archive entry name carries one of [`ROM_EXTENSIONS`].
+pub fn has_rom_extension(name: &str) -> bool {
+    name.rsplit_once('.').is_some_and(|(_, ext)| {
+        ROM_EXTENSIONS
+            .iter()
+            .any(|known| ext.eq_ignore_ascii_case(known))
+    })
+}
+
+/// Extracts a ROM image from a zip archive.
+///
+/// `entry` selects a file by its full path inside the archive or by its file
+/// name; without it the first entry (in archive order) with a ROM extension
+/// is used.
+#[cfg(feature = "archive")]
+pub fn extract_rom(archive: &[u8], entry: Option<&str>) -> Result<Vec<u8>, Error> {
+    use std::io::{Cursor, Read};
+
+    let invalid = |err: zip::result::ZipError| Error::InvalidArchive {
+        reason: err.to_string(),
+    };
+    let mut zip = zip::ZipArchive::new(Cursor::new(archive)).map_err(invalid)?;
+    for index in 0..zip.len() {
+        let mut file = zip.by_index(index).map_err(invalid)?;
+        if file.is_dir() {
+            continue;
+        }
+        let name = file.name();
+        let matches = match entry {
+            Some(wanted) => name == wanted || name.rsplit('/').next() == Some(wanted),
+            None => has_rom_extension(name),
+        };
+        if matches {
+            let mut rom = Vec::with_capacity(file.size() as usize);
+            file.read_to_end(&mut rom)?;
+            return Ok(rom);
+        }
+    }
+    Err(Error::RomNotInArchive {
+        entry: entry.map(str::to_owned),
+    })
+}
+
+#[cfg(not(feature = "archive"))]
+pub fn extract_rom(_archive: &[u8], _entry: Option<&str>) -> Result<Vec<u8>, Error> {
+    Err(Error::InvalidArchive {
+        reason: "zip support is disabled (build with the `archive` feature)".to_owned(),
+    })
+}
+
+#[cfg(all(test, feature = "archive"))]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use zip::{ZipWriter, write::SimpleFileOptions};
+
+    use super::*;
+
+    fn zip_of(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, data) in entries {
+            writer
+                .start_file(*name, SimpleFileOptions::default())
+                .expect("start entry");
+            writer.write_all(data).expect("write entry");
+        }
+        writer.finish().expect("finish zip").into_inner()
+    }
+
+    #[test]
+    fn picks_first_rom_entry_or_the_named_one() {
+        let archive = zip_of(&[
+            ("readme.txt", b"hello"),
+            ("roms/Game (U).NES", b"first"),
+            ("roms/Game (E).nes", b"second"),
+        ]);
+        assert!(is_zip(&archive));
+        assert_eq!(extract_rom(&archive, None).expect("rom"), b"first");
+        assert_eq!(
+            extract_rom(&archive, Some("Game (E).nes")).expect("by name"),
+            b"second"
+        );
+        assert_eq!(
+            extract_rom(&archive, Some("readme.txt")).expect("by path"),
+            b"hello"
+        );
+        assert!(matches!(
+            extract_rom(&archive, Some("missing.nes")),
+            Err(Error::RomNotInArchive { entry: Some(_) })
+        ));
+
+        let no_rom = zip_of(&[("notes.txt", b"")]);
+        assert!(matches!(
+            extract_rom(&no_rom, None),
+            Err(Error::RomNotInArchive { entry: None })
+        ));
+    }
+}
@@ -1,10 +1,14 @@
-use crate::cartridge::header::Header;
 #[cfg(feature = "cartridge-db")]
-use crate::cartridge::header::RomFormat;
+use crate::cartridge::{header::RomFormat, mapper::bus_conflict_submapper};
+use crate::{cartridge::header::Header, config::region::Region};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct CartridgeDbEntry {
     pub rom_body_crc32: u32,
+    /// Console family, e.g. `NesNtsc`, `NesPal`, `Famicom`, `Dendy`.
+    pub system: &'static str,
+    /// Board name such as `NES-TLROM`; empty when unknown.
+    pub board: &'static str,
     pub mapper: u16,
     pub submapper: u8,
     pub prg_rom_size: usize,
@@ -13,6 +17,21 @@ pub(crate) struct CartridgeDbEntry {
     pub work_ram_size: usize,
     pub save_ram_size: usize,
     pub has_battery: bool,
+    /// `Some` when the DB states whether the board has bus conflicts.
+    pub bus_conflicts: Option<bool>,
+}
+
+impl CartridgeDbEntry {
+    /// Timing implied by the console family, when it maps onto one.
+    pub(crate) fn region(&self) -> Option<Region> {
+        match self.system {
+            "NesNtsc" | "VsSystem" | "Playchoice" => Some(Region::Ntsc),
+            "Famicom" => Some(Region::NtscJp),
+            "NesPal" => Some(Region::Pal),
+            "Dendy" => Some(Region::Dendy),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -21,8 +40,7 @@ pub(crate) struct CartridgeDbOverride {
     pub submapper: u8,
 }
 
-#[cfg(feature = "cartridge-db")]
-fn compute_rom_body_crc32(prg_rom: &[u8], chr_rom: &[u8]) -> u32 {
+pub(crate) fn compute_rom_body_crc32(prg_rom: &[u8], chr_rom: &[u8]) -> u32 {
     use crc32fast::Hasher;
 
     let mut hasher = Hasher::new();
@@ -59,13 +77,19 @@ fn lookup_override_for_crc(header: &Header, rom_body_crc32: u32) -> Option<Cartr
         return None;
     }
 
-    if entry.mapper == header.mapper() && entry.submapper == header.submapper() {
+    // Discrete boards record their bus-conflict flag in the submapper.
+    let submapper = entry
+        .bus_conflicts
+        .and_then(|conflicts| bus_conflict_submapper(entry.mapper, conflicts))
+        .unwrap_or(entry.submapper);
+
+    if entry.mapper == header.mapper() && submapper == header.submapper() {
         return None;
     }
 
     Some(CartridgeDbOverride {
         mapper: entry.mapper,
-        submapper: entry.submapper,
+        submapper,
     })
 }
 
@@ -106,12 +130,23 @@ mod tests {
         let entry = lookup_entry(0x19E8_1461).expect("db entry expected");
         assert_eq!(entry.mapper, 157);
         assert_eq!(entry.submapper, 0);
+        assert_eq!(entry.region(), Some(Region::NtscJp));
         assert_eq!(entry.prg_rom_size, 256 * 1024);
         assert_eq!(entry.chr_rom_size, 0);
         assert_eq!(entry.chr_ram_size, 8 * 1024);
         assert_eq!(entry.work_ram_size, 0);
         assert_eq!(entry.save_ram_size, 0);
         assert!(!entry.has_battery);
+        assert_eq!(entry.bus_conflicts, None);
+    }
+
+    #[cfg(feature = "cartridge-db")]
+    #[test]
+    fn reads_bus_conflict_column() {
+        // Color Dreams board explicitly marked conflict-free.
+        let entry = lookup_entry(0x692F_2096).expect("db entry expected");
+        assert_eq!(entry.mapper, 11);
+        assert_eq!(entry.bus_conflicts, Some(false));
     }
 
     #[cfg(feature = "cartridge-db")]
@@ -141,7 +176,6 @@ mod tests {
         assert_eq!(lookup_override(&header, &[1, 2, 3, 4], &[5, 6]), None);
     }
 
-    #[cfg(feature = "cartridge-db")]
     #[test]
     fn computes_crc32_over_prg_and_chr_body() {
         let crc = compute_rom_body_crc32(&[1, 2, 3, 4], &[5, 6]);
@@ -0,0 +1,83 @@
+//! Descriptive metadata about a loaded cartridge.
+
+use sha1::{Digest, Sha1};
+
+use super::{
+    Mapper,
+    db::{compute_rom_body_crc32, lookup_entry},
+    header::{Header, Mirroring},
+};
+use crate::config::region::Region;
+
+/// What a frontend needs to describe a loaded ROM, and what two netplay peers
+/// compare to confirm they run the same game.
+///
+/// Hashes cover the PRG and CHR ROM bodies only, so the same dump with a
+/// cleaned-up header still matches (and matches the cartridge DB keys).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CartridgeInfo {
+    /// Mapper and submapper in effect, after any DB or loader override.
+    pub mapper: u16,
+    pub submapper: u8,
+    /// Board name from the cartridge DB (e.g. `NES-TLROM`), falling back to
+    /// the mapper's own name.
+    pub board: String,
+    pub mirroring: Mirroring,
+    pub prg_rom_size: usize,
+    pub chr_rom_size: usize,
+    pub chr_ram_size: usize,
+    /// Volatile plus battery-backed PRG RAM.
+    pub prg_ram_size: usize,
+    pub battery: bool,
+    pub crc32: u32,
+    pub sha1: [u8; 20],
+    /// Best guess at the intended timing: the DB's console family when the
+    /// ROM is known, otherwise the header's TV system.
+    pub region: Region,
+    /// Whether the ROM body matched a cartridge DB entry.
+    pub database_match: bool,
+}
+
+impl CartridgeInfo {
+    pub(crate) fn new(header: &Header, mapper: &dyn Mapper) -> Self {
+        let memory = mapper.memory_ref();
+        let prg_rom = memory.prg_rom.unwrap_or_default();
+        let chr_rom = memory.chr_rom.unwrap_or_default();
+
+        let crc32 = compute_rom_body_crc32(prg_rom, chr_rom);
+        let mut hasher = Sha1::new();
+        hasher.update(prg_rom);
+        hasher.update(chr_rom);
+        let sha1 = hasher.finalize().into();
+
+        let entry = lookup_entry(crc32);
+        let board = entry
+            .map(|entry| entry.board)
+            .filter(|board| !board.is_empty())
+            .map_or_else(|| mapper.name().into_owned(), str::to_owned);
+        let region = entry
+            .and_then(|entry| entry.region())
+            .unwrap_or_else(|| Region::resolve(Region::Auto, header.tv_system()));
+
+        Self {
+            mapper: header.mapper(),
+            submapper: header.submapper(),
+            board,
+            mirroring: header.mirroring(),
+            prg_rom_size: prg_rom.len(),
+            chr_rom_size: chr_rom.len(),
+            chr_ram_size: header.chr_ram_size() + header.chr_nvram_size(),
+            prg_ram_size: header.prg_ram_size() + header.prg_nvram_size(),
+            battery: header.battery_backed_ram(),
+            crc32,
+            sha1,
+            region,
+            database_match: entry.is_some(),
+        }
+    }
+
+    /// SHA-1 as lowercase hex.
+    pub fn sha1_hex(&self) -> String {
+        self.sha1.iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
@@ -0,0 +1,375 @@
+//! Staged cartridge loading with progress and diagnostics.
+//!
+//! [`load_cartridge`](super::load_cartridge) either succeeds or returns a bare
+//! [`Error`]. Frontends that want to tell the user *why* a ROM did not load
+//! use [`CartridgeLoader`] instead: it runs the same steps one at a time,
+//! reports each [`LoadStage`] as it starts, surfaces non-fatal
+//! [`LoadWarning`]s, and tags failures with the stage that produced them.
+
+use std::{fmt, fs, path::Path};
+
+use super::{
+    Cartridge, Mmc3Revision, Provider, TRAINER_SIZE, archive, build_cartridge_from_sections,
+    db::lookup_override,
+    header::{Header, NES_HEADER_LEN, RomFormat},
+    mapper::bus_conflict_submapper,
+    patch::apply_ips,
+    slice_sections,
+};
+use crate::error::Error;
+
+/// Steps of the loading pipeline, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoadStage {
+    /// Reading the image from disk.
+    Read,
+    /// Unpacking the ROM from a zip archive.
+    Extract,
+    /// Applying an IPS soft patch.
+    Patch,
+    /// Parsing the iNES / NES 2.0 header and slicing PRG/CHR.
+    Header,
+    /// Looking the ROM body up in the cartridge database.
+    Database,
+    /// Constructing and powering on the mapper.
+    Mapper,
+}
+
+impl fmt::Display for LoadStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LoadStage::Read => "read",
+            LoadStage::Extract => "extract",
+            LoadStage::Patch => "patch",
+            LoadStage::Header => "header",
+            LoadStage::Database => "database lookup",
+            LoadStage::Mapper => "mapper init",
+        })
+    }
+}
+
+/// Something suspicious about the image that did not stop it from loading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadWarning {
+    /// iNES header with junk in bytes 12-15 (e.g. a `DiskDude!` signature);
+    /// the mapper number may be wrong.
+    DirtyHeader,
+    /// Bytes left over after the sections the header declares.
+    TrailingData { bytes: usize },
+    /// The database corrected the mapper/submapper from the header.
+    DatabaseOverride {
+        header_mapper: u16,
+        header_submapper: u8,
+        mapper: u16,
+        submapper: u8,
+    },
+}
+
+impl fmt::Display for LoadWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadWarning::DirtyHeader => {
+                write!(f, "iNES header has garbage in bytes 12-15")
+            }
+            LoadWarning::TrailingData { bytes } => {
+                write!(f, "{bytes} bytes of trailing data ignored")
+            }
+            LoadWarning::DatabaseOverride {
+                header_mapper,
+                header_submapper,
+                mapper,
+                submapper,
+            } => write!(
+                f,
+                "header mapper {header_mapper}.{header_submapper} replaced by database entry {mapper}.{submapper}"
+            ),
+        }
+    }
+}
+
+/// Progress notification emitted by [`CartridgeLoader`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadProgress {
+    Stage(LoadStage),
+    Warning(LoadWarning),
+}
+
+/// A loading failure together with the stage it happened in.
+#[derive(Debug)]
+pub struct LoadError {
+    pub stage: LoadStage,
+    pub error: Error,
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} failed: {}", self.stage, self.error)
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<LoadError> for Error {
+    fn from(value: LoadError) -> Self {
+        value.error
+    }
+}
+
+/// Builder-style driver for the staged loading pipeline.
+#[derive(Default)]
+pub struct CartridgeLoader<'a> {
+    provider: Option<&'a dyn Provider>,
+    archive_entry: Option<&'a str>,
+    patch: Option<&'a [u8]>,
+    mmc3_revision: Option<Mmc3Revision>,
+    bus_conflicts: Option<bool>,
+    on_progress: Option<&'a mut dyn FnMut(LoadProgress)>,
+}
+
+impl<'a> CartridgeLoader<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn provider(mut self, provider: Option<&'a dyn Provider>) -> Self {
+        self.provider = provider;
+        self
+    }
+
+    /// Archive entry to load when the image is a zip; defaults to the first
+    /// `.nes`/`.fds`/`.nsf` entry.
+    pub fn archive_entry(mut self, entry: Option<&'a str>) -> Self {
+        self.archive_entry = entry;
+        self
+    }
+
+    /// IPS patch applied to the image before the header is parsed.
+    pub fn patch(mut self, patch: Option<&'a [u8]>) -> Self {
+        self.patch = patch;
+        self
+    }
+
+    /// Forces the IRQ behaviour of mapper 4 (MMC3) cartridges instead of
+    /// deriving it from the submapper. Other mappers are unaffected.
+    pub fn mmc3_revision(mut self, revision: Option<Mmc3Revision>) -> Self {
+        self.mmc3_revision = revision;
+        self
+    }
+
+    /// Forces bus conflicts on or off for discrete-logic boards (UxROM,
+    /// CNROM, AxROM), overriding the header and cartridge DB.
+    pub fn bus_conflicts(mut self, bus_conflicts: Option<bool>) -> Self {
+        self.bus_conflicts = bus_conflicts;
+        self
+    }
+
+    pub fn on_progress(mut self, on_progress: &'a mut dyn FnMut(LoadProgress)) -> Self {
+        self.on_progress = Some(on_progress);
+        self
+    }
+
+    pub fn load_file(mut self, path: impl AsRef<Path>) -> Result<Cartridge, LoadError> {
+        self.report(LoadProgress::Stage(LoadStage::Read));
+        let bytes = fs::read(path).map_err(|e| LoadError {
+            stage: LoadStage::Read,
+            error: e.into(),
+        })?;
+        self.load(bytes)
+    }
+
+    pub fn load(mut self, mut bytes: Vec<u8>) -> Result<Cartridge, LoadError> {
+        if archive::is_zip(&bytes) {
+            self.report(LoadProgress::Stage(LoadStage::Extract));
+            bytes =
+                archive::extract_rom(&bytes, self.archive_entry).map_err(at(LoadStage::Extract))?;
+        }
+
+        if let Some(patch) = self.patch {
+            self.report(LoadProgress::Stage(LoadStage::Patch));
+            apply_ips(&mut bytes, patch).map_err(at(LoadStage::Patch))?;
+        }
+
+        self.report(LoadProgress::Stage(LoadStage::Header));
+        let header_bytes = bytes.get(..NES_HEADER_LEN).ok_or(LoadError {
+            stage: LoadStage::Header,
+            error: Error::TooShort {
+                actual: bytes.len(),
+            },
+        })?;
+        let parsed_header = Header::parse(header_bytes).map_err(at(LoadStage::Header))?;
+        if parsed_header.format() == RomFormat::INes && header_bytes[12..].iter().any(|&b| b != 0) {
+            self.report(LoadProgress::Warning(LoadWarning::DirtyHeader));
+        }
+        let (trainer, prg_rom, chr_rom) =
+            slice_sections(&bytes, &parsed_header).map_err(at(LoadStage::Header))?;
+        let used = NES_HEADER_LEN
+            + trainer.map_or(0, |_| TRAINER_SIZE)
+            + parsed_header.prg_rom_size()
+            + parsed_header.chr_rom_size();
+        if bytes.len() > used {
+            self.report(LoadProgress::Warning(LoadWarning::TrailingData {
+                bytes: bytes.len() - used,
+            }));
+        }
+
+        self.report(LoadProgress::Stage(LoadStage::Database));
+        let header = match lookup_override(&parsed_header, prg_rom.as_ref(), chr_rom.as_ref()) {
+            Some(info) => {
+                self.report(LoadProgress::Warning(LoadWarning::DatabaseOverride {
+                    header_mapper: parsed_header.mapper(),
+                    header_submapper: parsed_header.submapper(),
+                    mapper: info.mapper,
+                    submapper: info.submapper,
+                }));
+                parsed_header.with_runtime_mapper_submapper(info.mapper, info.submapper)
+            }
+            None => parsed_header,
+        };
+        let header = match self.mmc3_revision {
+            Some(revision) if header.mapper() == 4 => {
+                header.with_runtime_mapper_submapper(4, revision.submapper())
+            }
+            _ => header,
+        };
+        let header = match self
+            .bus_conflicts
+            .and_then(|conflicts| bus_conflict_submapper(header.mapper(), conflicts))
+        {
+            Some(submapper) => header.with_runtime_mapper_submapper(header.mapper(), submapper),
+            None => header,
+        };
+
+        self.report(LoadProgress::Stage(LoadStage::Mapper));
+        build_cartridge_from_sections(header, trainer, prg_rom, chr_rom, self.provider)
+            .map_err(at(LoadStage::Mapper))
+    }
+
+    fn report(&mut self, progress: LoadProgress) {
+        if let Some(on_progress) = self.on_progress.as_mut() {
+            on_progress(progress);
+        }
+    }
+}
+
+fn at(stage: LoadStage) -> impl FnOnce(Error) -> LoadError {
+    move |error| LoadError { stage, error }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nrom(flags6: u8, extra: usize) -> Vec<u8> {
+        let mut rom = vec![b'N', b'E', b'S', 0x1A, 1, 1, flags6];
+        rom.resize(NES_HEADER_LEN, 0);
+        rom.resize(NES_HEADER_LEN + 24 * 1024 + extra, 0xEA);
+        rom
+    }
+
+    fn load(
+        rom: Vec<u8>,
+        patch: Option<&[u8]>,
+    ) -> (Result<Cartridge, LoadError>, Vec<LoadProgress>) {
+        let mut events = Vec::new();
+        let mut push = |p| events.push(p);
+        let result = CartridgeLoader::new()
+            .patch(patch)
+            .on_progress(&mut push)
+            .load(rom);
+        (result, events)
+    }
+
+    #[test]
+    fn reports_stages_and_warnings() {
+        let mut rom = nrom(0, 4);
+        rom[12] = b'D';
+        let (result, events) = load(rom, Some(b"PATCH\x00\x00\x10\x00\x01\x4CEOF"));
+
+        let cart = result.expect("loads");
+        assert_eq!(cart.cpu_read(0x8000, 0), Some(0x4C));
+        assert_eq!(
+            events,
+            [
+                LoadProgress::Stage(LoadStage::Patch),
+                LoadProgress::Stage(LoadStage::Header),
+                LoadProgress::Warning(LoadWarning::DirtyHeader),
+                LoadProgress::Warning(LoadWarning::TrailingData { bytes: 4 }),
+                LoadProgress::Stage(LoadStage::Database),
+                LoadProgress::Stage(LoadStage::Mapper),
+            ]
+        );
+    }
+
+    #[test]
+    fn bus_conflicts_can_be_forced_on() {
+        // UxROM: bank 0 is all $00, bank 1 is all $11 except a $00 at the
+        // written address, so a conflicting write of $01 selects bank 0.
+        let mut rom = nrom(0x20, 0);
+        rom.truncate(NES_HEADER_LEN);
+        rom[4] = 2;
+        rom[5] = 0;
+        rom.resize(NES_HEADER_LEN + 16 * 1024, 0x00);
+        rom.resize(NES_HEADER_LEN + 32 * 1024, 0x11);
+        rom[NES_HEADER_LEN + 16 * 1024] = 0x00;
+
+        for (forced, expected) in [(None, 0x11), (Some(false), 0x11), (Some(true), 0x00)] {
+            let mut cart = CartridgeLoader::new()
+                .bus_conflicts(forced)
+                .load(rom.clone())
+                .expect("loads");
+            cart.cpu_write(0xC000, 0x01, 0);
+            assert_eq!(cart.cpu_read(0x8001, 0), Some(expected), "{forced:?}");
+        }
+    }
+
+    #[cfg(feature = "archive")]
+    #[test]
+    fn unpacks_zipped_images() {
+        use std::io::{Cursor, Write};
+        use zip::{ZipWriter, write::SimpleFileOptions};
+
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer
+            .start_file("game.nes", SimpleFileOptions::default())
+            .expect("start entry");
+        writer.write_all(&nrom(0, 0)).expect("write entry");
+        let zipped = writer.finish().expect("finish zip").into_inner();
+
+        let (result, events) = load(zipped, None);
+        assert!(result.is_ok());
+        assert_eq!(events[0], LoadProgress::Stage(LoadStage::Extract));
+
+        let (result, _) = load(b"PK\x03\x04broken".to_vec(), None);
+        assert_eq!(
+            result.map(|_| ()).expect_err("bad zip").stage,
+            LoadStage::Extract
+        );
+    }
+
+    #[test]
+    fn failures_carry_their_stage() {
+        let (result, _) = load(nrom(0, 0), Some(b"IPS"));
+        assert_eq!(
+            result.map(|_| ()).expect_err("bad patch").stage,
+            LoadStage::Patch
+        );
+
+        let mut short = nrom(0, 0);
+        short.truncate(1024);
+        let (result, _) = load(short, None);
+        assert_eq!(
+            result.map(|_| ()).expect_err("short").stage,
+            LoadStage::Header
+        );
+
+        let (result, events) = load(nrom(0xC0, 0), None);
+        let err = result.map(|_| ()).expect_err("mapper 12");
+        assert_eq!(err.stage, LoadStage::Mapper);
+        assert!(matches!(err.error, Error::UnsupportedMapper(12)));
+        assert_eq!(events.last(), Some(&LoadProgress::Stage(LoadStage::Mapper)));
+    }
+}
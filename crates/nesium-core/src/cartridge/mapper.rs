@@ -15,6 +15,7 @@ pub mod core;
 pub mod mapper0;
 pub mod mapper1;
 pub mod mapper10;
+pub mod mapper105;
 pub mod mapper11;
 pub mod mapper119;
 pub mod mapper13;
@@ -24,8 +25,6 @@ pub mod mapper19;
 pub mod mapper2;
 pub mod mapper21;
 pub mod mapper228;
-pub mod mapper23;
-pub mod mapper25;
 pub mod mapper26;
 pub mod mapper3;
 pub mod mapper34;
@@ -33,6 +32,7 @@ pub mod mapper4;
 pub mod mapper5;
 pub mod mapper6;
 pub mod mapper66;
+pub mod mapper682;
 pub mod mapper69;
 pub mod mapper7;
 pub mod mapper71;
@@ -42,7 +42,9 @@ pub mod mapper85;
 pub mod mapper9;
 pub mod mapper90;
 pub mod provider;
+pub mod registry;
 
+pub use self::core::mmc3::Mmc3Revision;
 pub use chr_storage::{ChrStorage, select_chr_storage};
 pub use mapper0::Mapper0;
 pub use mapper1::Mapper1;
@@ -60,9 +62,7 @@ pub use mapper13::Mapper13;
 pub use mapper16::Mapper16;
 pub use mapper18::Mapper18;
 pub use mapper19::Mapper19;
-pub use mapper21::Mapper21;
-pub use mapper23::Mapper23;
-pub use mapper25::Mapper25;
+pub use mapper21::{Mapper21, Mapper22, Mapper23, Mapper25, Vrc2Vrc4};
 pub use mapper26::Mapper26;
 pub use mapper34::Mapper34;
 pub use mapper66::Mapper66;
@@ -71,9 +71,12 @@ pub use mapper71::Mapper71;
 pub use mapper78::Mapper78;
 pub use mapper85::Mapper85;
 pub use mapper90::Mapper90;
+pub use mapper105::Mapper105;
 pub use mapper119::Mapper119;
 pub use mapper228::Mapper228;
+pub use mapper682::Mapper682;
 pub use provider::Provider;
+pub use registry::{MapperFactory, MapperRegistry};
 
 use crate::{
     cartridge::{
@@ -430,6 +433,17 @@ pub trait Mapper: Debug + Send + DynClone + Any + 'static {
         MapperMemoryMut::default()
     }
 
+    /// Offset into the [`memory_ref`](Self::memory_ref) `prg_ram` region
+    /// that the CPU currently sees at `addr` (`$6000-$7FFF`), or `None` when
+    /// no PRG-RAM is mapped there.
+    ///
+    /// The default mirrors unbanked RAM across the window; boards that bank
+    /// PRG-RAM override this to follow their current RAM bank.
+    fn prg_ram_offset(&self, addr: u16) -> Option<usize> {
+        let len = self.memory_ref().prg_ram.map_or(0, <[u8]>::len);
+        (len != 0).then(|| addr.wrapping_sub(cpu_mem::PRG_RAM_START) as usize % len)
+    }
+
     /// Current nametable mirroring mode exposed by the mapper.
     fn mirroring(&self) -> Mirroring;
 
@@ -454,6 +468,23 @@ pub fn mapper_downcast_mut<T: Mapper + 'static>(mapper: &mut dyn Mapper) -> Opti
     (mapper as &mut dyn Any).downcast_mut::<T>()
 }
 
+/// NES 2.0 submapper recording whether a discrete-logic board (UxROM, CNROM,
+/// AxROM) has bus conflicts: 1 for none, 2 for AND-type. `None` for mappers
+/// that do not use submappers this way.
+pub(crate) fn bus_conflict_submapper(mapper: u16, bus_conflicts: bool) -> Option<u8> {
+    matches!(mapper, 2 | 3 | 7).then_some(if bus_conflicts { 2 } else { 1 })
+}
+
+/// Whether PRG-ROM writes on this board are ANDed with the ROM byte at the
+/// written address.
+///
+/// Only boards explicitly marked through the submapper (from the header, the
+/// cartridge DB or a loader override) emulate the conflict; unspecified
+/// dumps keep the conflict-free behaviour most games expect.
+pub fn has_bus_conflicts(header: &Header) -> bool {
+    bus_conflict_submapper(header.mapper(), true) == Some(header.submapper())
+}
+
 /// Allocate CPU‑visible PRG RAM according to the header hints.
 ///
 /// For NES 2.0 headers this picks the larger of volatile and battery‑backed
@@ -88,10 +88,44 @@ impl Mmc3CpuRegister {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub(crate) enum Mmc3IrqRevision {
-    RevA,
-    RevB,
+/// MMC3 IRQ counter variant.
+///
+/// The chips agree on banking but differ in when the scanline counter fires,
+/// and several test ROMs only pass on one of them. NES 2.0 submappers 0, 1/4
+/// and 3 select the variants; iNES dumps default to [`Mmc3Revision::Sharp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Mmc3Revision {
+    /// Sharp MMC3B/MMC3C ("new" behaviour): the IRQ fires whenever a clock
+    /// leaves the counter at 0, including a reload with a latch of 0.
+    #[default]
+    Sharp,
+    /// NEC MMC3, MMC3A and MMC6 ("old" behaviour): an automatic reload with a
+    /// latch of 0 does not fire; a decrement to 0 or a `$C001` reload does.
+    Nec,
+    /// Acclaim MC-ACC: counts falling edges of PPU A12 through a divide-by-8
+    /// prescaler instead of filtered rising edges, so the counter clocks at
+    /// the end of the sprite fetches rather than the start.
+    McAcc,
+}
+
+impl Mmc3Revision {
+    /// Revision implied by a mapper 4 NES 2.0 submapper.
+    pub fn from_submapper(submapper: u8) -> Self {
+        match submapper {
+            1 | 4 => Mmc3Revision::Nec,
+            3 => Mmc3Revision::McAcc,
+            _ => Mmc3Revision::Sharp,
+        }
+    }
+
+    /// Mapper 4 submapper that selects this revision.
+    pub fn submapper(self) -> u8 {
+        match self {
+            Mmc3Revision::Sharp => 0,
+            Mmc3Revision::Nec => 4,
+            Mmc3Revision::McAcc => 3,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -100,7 +134,7 @@ pub(crate) struct Mmc3CoreResetConfig {
     pub(crate) bank_regs: [u8; 8],
     pub(crate) prg_ram_enable: bool,
     pub(crate) prg_ram_write_protect: bool,
-    pub(crate) irq_revision: Mmc3IrqRevision,
+    pub(crate) irq_revision: Mmc3Revision,
 }
 
 #[derive(Debug, Clone)]
@@ -114,8 +148,10 @@ pub(crate) struct Mmc3Core {
     pub(crate) irq_reload: bool,
     pub(crate) irq_enabled: bool,
     pub(crate) irq_pending: bool,
-    pub(crate) irq_revision: Mmc3IrqRevision,
+    pub(crate) irq_revision: Mmc3Revision,
     pub(crate) a12_low_start_master_clock: Option<u64>,
+    /// MC-ACC A12 falling-edge prescaler (counts 0-7).
+    pub(crate) irq_prescaler: u8,
 }
 
 impl Mmc3Core {
@@ -132,6 +168,7 @@ impl Mmc3Core {
             irq_pending: false,
             irq_revision: reset.irq_revision,
             a12_low_start_master_clock: None,
+            irq_prescaler: 0,
         };
         core.reset(reset);
         core
@@ -151,6 +188,7 @@ impl Mmc3Core {
         self.irq_pending = false;
         self.irq_revision = reset.irq_revision;
         self.a12_low_start_master_clock = None;
+        self.irq_prescaler = 0;
     }
 
     #[inline]
@@ -249,6 +287,9 @@ impl Mmc3Core {
             self.irq_counter = 0;
         }
         self.irq_reload = true;
+        if self.irq_revision == Mmc3Revision::McAcc {
+            self.irq_prescaler = 0;
+        }
     }
 
     pub(crate) fn write_irq_disable(&mut self) {
@@ -309,11 +350,34 @@ impl Mmc3Core {
             return;
         }
 
-        if self.is_a12_rising_edge(addr, ctx.ppu_master_clock) {
+        if self.irq_revision == Mmc3Revision::McAcc {
+            if self.is_a12_falling_edge(addr, ctx.ppu_master_clock) {
+                self.irq_prescaler = (self.irq_prescaler + 1) & 0x07;
+                if self.irq_prescaler == 0 {
+                    self.clock_irq_counter();
+                }
+            }
+        } else if self.is_a12_rising_edge(addr, ctx.ppu_master_clock) {
             self.clock_irq_counter();
         }
     }
 
+    /// MC-ACC has no M2 low-time filter: every high-to-low transition counts.
+    /// `a12_low_start_master_clock` is `None` while A12 is high (or before the
+    /// first access after reset).
+    #[inline]
+    fn is_a12_falling_edge(&mut self, addr: u16, ppu_master_clock: u64) -> bool {
+        if addr & 0x1000 != 0 {
+            self.a12_low_start_master_clock = None;
+            return false;
+        }
+        let is_fall = self.a12_low_start_master_clock.is_none();
+        if is_fall {
+            self.a12_low_start_master_clock = Some(ppu_master_clock);
+        }
+        is_fall
+    }
+
     #[inline]
     fn is_a12_rising_edge(&mut self, addr: u16, ppu_master_clock: u64) -> bool {
         let a12_high = addr & 0x1000 != 0;
@@ -347,12 +411,12 @@ impl Mmc3Core {
 
         if self.irq_counter == 0 && self.irq_enabled {
             match self.irq_revision {
-                Mmc3IrqRevision::RevA => {
+                Mmc3Revision::Nec => {
                     if counter_before > 0 || reload_before {
                         self.irq_pending = true;
                     }
                 }
-                Mmc3IrqRevision::RevB => {
+                Mmc3Revision::Sharp | Mmc3Revision::McAcc => {
                     self.irq_pending = true;
                 }
             }
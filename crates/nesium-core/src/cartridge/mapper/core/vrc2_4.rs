@@ -120,6 +120,7 @@ pub struct Vrc2_4Banking {
     chr_high_regs: ByteBlock<8>,
     mirroring: Mirroring,
     base_mirroring: Mirroring,
+    chr_page_shift: u8,
 }
 
 impl Vrc2_4Banking {
@@ -133,9 +134,16 @@ impl Vrc2_4Banking {
             chr_high_regs: ByteBlock::new(),
             mirroring: base_mirroring,
             base_mirroring,
+            chr_page_shift: 0,
         }
     }
 
+    /// Right shift applied to CHR bank numbers (1 on VRC2a, whose CHR A10
+    /// comes from bank bit 1).
+    pub fn set_chr_page_shift(&mut self, shift: u8) {
+        self.chr_page_shift = shift;
+    }
+
     pub fn reset(&mut self) {
         self.prg_bank_8000 = 0;
         self.prg_bank_a000 = 1;
@@ -253,7 +261,7 @@ impl Vrc2_4Banking {
     fn chr_page_base(&self, bank: usize) -> usize {
         let lo = self.chr_low_regs.get(bank).copied().unwrap_or(0) & 0x0F;
         let hi = self.chr_high_regs.get(bank).copied().unwrap_or(0) & 0x1F;
-        let page = ((hi as usize) << 4) | lo as usize;
+        let page = (((hi as usize) << 4) | lo as usize) >> self.chr_page_shift;
         page * CHR_BANK_SIZE_1K
     }
 }
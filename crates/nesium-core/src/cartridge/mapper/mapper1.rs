@@ -11,6 +11,9 @@
 //! - CHR banking supports 8KiB and 4KiB modes.
 //! - Mirroring controlled by Control bits 0-1.
 //! - MMC1B-style PRG-RAM disable via PRG bank bit4.
+//! - SxROM boards reuse the CHR bank register lines: bit 4 selects the 256 KiB
+//!   PRG half on 512 KiB boards (SUROM/SXROM), bits 2-3 select the 8 KiB
+//!   PRG-RAM bank (SXROM: 32 KiB, SOROM: 16 KiB via bit 3).
 //!
 //! | Area | Address range     | Behaviour                                          | IRQ/Audio |
 //! |------|-------------------|----------------------------------------------------|-----------|
@@ -22,7 +25,9 @@
 //! TODOs (accuracy improvements):
 //! - Use CPU cycle information to implement precise consecutive-write ignore.
 //! - Return open-bus value when PRG-RAM is disabled (requires bus support).
-//! - Support SxROM outer banking variants (SUROM/SXROM/SOROM/SZROM/SNROM).
+//! - Support the remaining SxROM line variants (SZROM, SNROM's PRG-RAM disable).
+//! - Follow PPU A12 for the SxROM lines in 4 KiB CHR mode instead of the last
+//!   written CHR register.
 //! - Distinguish MMC1A/MMC1B revisions when submapper/board info is available.
 
 use std::borrow::Cow;
@@ -32,7 +37,7 @@ use crate::cartridge::mapper::{MapperMemoryMut, MapperMemoryRef};
 use crate::{
     cartridge::{
         ChrRom, Mapper, PrgRom, TrainerBytes,
-        header::{Header, Mirroring},
+        header::{Header, Mirroring, RomFormat},
         mapper::allocate_prg_ram_with_trainer,
     },
     memory::cpu as cpu_mem,
@@ -43,6 +48,9 @@ use crate::{
 use serde::{Deserialize, Serialize};
 
 const PRG_BANK_SIZE_16K: usize = 16 * 1024;
+const PRG_RAM_BANK_SIZE: usize = 8 * 1024;
+/// 16 KiB banks reachable through the 4-bit PRG register (256 KiB).
+const PRG_BANKS_PER_OUTER: usize = 16;
 const CHR_BANK_SIZE_4K: usize = 4 * 1024;
 
 /// CPU `$C000`: boundary between the lower and upper 16 KiB PRG windows.
@@ -71,6 +79,9 @@ pub struct Mapper1 {
     /// PRG bank register ($E000-$FFFF), 5 bits latched.
     /// On MMC1B, bit4 disables PRG-RAM when set.
     prg_bank: u8,
+    /// The last CHR bank write went to `$C000`. In 4 KiB CHR mode that
+    /// register drives the SxROM PRG/PRG-RAM lines (Mesen2 approximation).
+    last_chr_bank1: bool,
 
     /// Serial shift register. Initialized to 0x10 (bit4 set), then shifted right
     /// with each D0 write. After 5 writes, value is latched and this resets.
@@ -89,6 +100,7 @@ pub struct Mapper1State {
     pub chr_bank0: u8,
     pub chr_bank1: u8,
     pub prg_bank: u8,
+    pub last_chr_bank1: bool,
     pub shift_reg: u8,
     pub shift_count: u8,
     pub last_serial_cycle: Option<u64>,
@@ -96,7 +108,17 @@ pub struct Mapper1State {
 
 impl Mapper1 {
     pub fn new(header: Header, prg_rom: PrgRom, chr_rom: ChrRom, trainer: TrainerBytes) -> Self {
-        let prg_ram = allocate_prg_ram_with_trainer(&header, trainer);
+        let mut prg_ram = allocate_prg_ram_with_trainer(&header, trainer);
+        // SOROM declares its 8 KiB work RAM and 8 KiB battery RAM separately;
+        // both sit behind the same banked window.
+        if header.format() == RomFormat::Nes20 && header.prg_nvram_size() > 0 {
+            let banked = header.prg_ram_size() + header.prg_nvram_size();
+            if banked > prg_ram.len() {
+                let mut ram = prg_ram.into_vec();
+                ram.resize(banked, 0);
+                prg_ram = ram.into_boxed_slice();
+            }
+        }
 
         let chr_rom_present = header.chr_rom_size() > 0;
         let chr_ram = if chr_rom_present {
@@ -130,6 +152,7 @@ impl Mapper1 {
             chr_bank0: 0,
             chr_bank1: 0,
             prg_bank: 0,
+            last_chr_bank1: false,
             shift_reg: 0,
             shift_count: 0,
             last_serial_cycle: None,
@@ -142,6 +165,7 @@ impl Mapper1 {
             chr_bank0: self.chr_bank0,
             chr_bank1: self.chr_bank1,
             prg_bank: self.prg_bank,
+            last_chr_bank1: self.last_chr_bank1,
             shift_reg: self.shift_reg,
             shift_count: self.shift_count,
             last_serial_cycle: self.last_serial_cycle,
@@ -153,6 +177,7 @@ impl Mapper1 {
         self.chr_bank0 = state.chr_bank0;
         self.chr_bank1 = state.chr_bank1;
         self.prg_bank = state.prg_bank;
+        self.last_chr_bank1 = state.last_chr_bank1;
         self.shift_reg = state.shift_reg;
         self.shift_count = state.shift_count;
         self.last_serial_cycle = state.last_serial_cycle;
@@ -166,6 +191,30 @@ impl Mapper1 {
         (self.prg_bank & 0x10) == 0
     }
 
+    /// CHR bank register whose upper bits drive the SxROM board lines.
+    #[inline]
+    fn board_lines(&self) -> u8 {
+        let chr_mode_4k = (self.control >> 4) & 0b1 == 1;
+        if chr_mode_4k && self.last_chr_bank1 {
+            self.chr_bank1
+        } else {
+            self.chr_bank0
+        }
+    }
+
+    /// Offset into `prg_ram` for a CPU address in `$6000-$7FFF`.
+    fn prg_ram_index(&self, addr: u16) -> usize {
+        let bank = match self.prg_ram.len() {
+            // SXROM: 32 KiB, CHR bits 2-3.
+            len if len > 2 * PRG_RAM_BANK_SIZE => (self.board_lines() >> 2) & 0b11,
+            // SOROM: 16 KiB, CHR bit 3.
+            len if len > PRG_RAM_BANK_SIZE => (self.board_lines() >> 3) & 0b1,
+            _ => 0,
+        };
+        let offset = bank as usize * PRG_RAM_BANK_SIZE + (addr - cpu_mem::PRG_RAM_START) as usize;
+        offset % self.prg_ram.len()
+    }
+
     fn read_prg_rom(&self, addr: u16) -> u8 {
         if self.prg_rom.is_empty() {
             return 0;
@@ -183,8 +232,7 @@ impl Mapper1 {
         if self.prg_ram.is_empty() || !self.prg_ram_enabled() {
             return None;
         }
-        let idx = (addr - cpu_mem::PRG_RAM_START) as usize % self.prg_ram.len();
-        Some(self.prg_ram[idx])
+        Some(self.prg_ram[self.prg_ram_index(addr)])
     }
 
     fn write_prg_ram(&mut self, addr: u16, data: u8) {
@@ -192,7 +240,7 @@ impl Mapper1 {
         if self.prg_ram.is_empty() || !self.prg_ram_enabled() {
             return;
         }
-        let idx = (addr - cpu_mem::PRG_RAM_START) as usize % self.prg_ram.len();
+        let idx = self.prg_ram_index(addr);
         self.prg_ram[idx] = data;
     }
 
@@ -201,18 +249,26 @@ impl Mapper1 {
             return 0;
         }
 
+        // 512 KiB boards (SUROM/SXROM) pick the 256 KiB half with CHR bit 4;
+        // the fixed banks below stay inside the selected half.
+        let outer = if self.prg_bank_count > PRG_BANKS_PER_OUTER {
+            (self.board_lines() & 0x10) as usize
+        } else {
+            0
+        };
+        let inner_count = self.prg_bank_count.min(PRG_BANKS_PER_OUTER);
         let mode = (self.control >> 2) & 0b11;
         let bank = (self.prg_bank & 0x0F) as usize;
 
-        match mode {
+        let inner = match mode {
             // 32 KiB mode, ignore low bit of PRG bank.
             0 | 1 => {
-                if self.prg_bank_count == 1 {
+                if inner_count == 1 {
                     0
                 } else {
-                    let max_even_bank = self.prg_bank_count.saturating_sub(2);
+                    let max_even_bank = inner_count.saturating_sub(2);
                     let bank_even = (bank & !1).min(max_even_bank);
-                    if addr < 0xC000 {
+                    if addr < MMC1_PRG_UPPER_WINDOW_START {
                         bank_even
                     } else {
                         bank_even + 1
@@ -224,18 +280,19 @@ impl Mapper1 {
                 if addr < MMC1_PRG_UPPER_WINDOW_START {
                     0
                 } else {
-                    bank.min(self.prg_bank_count - 1)
+                    bank.min(inner_count - 1)
                 }
             }
             // Fix last 16 KiB at $C000, switch 16 KiB at $8000.
             _ => {
                 if addr < MMC1_PRG_UPPER_WINDOW_START {
-                    bank.min(self.prg_bank_count - 1)
+                    bank.min(inner_count - 1)
                 } else {
-                    self.prg_bank_count - 1
+                    inner_count - 1
                 }
             }
-        }
+        };
+        (outer + inner) % self.prg_bank_count
     }
 
     fn read_chr(&self, addr: u16) -> u8 {
@@ -252,7 +309,8 @@ impl Mapper1 {
         let offset_in_bank = (addr as usize) & 0x0FFF;
         let chr_mode_4k = (self.control >> 4) & 0b1 == 1;
 
-        // TODO(accuracy): SxROM variants use CHR bank high bits for outer PRG/PRG-RAM banking.
+        // With 8 KiB of CHR the bank's upper bits only drive the SxROM board
+        // lines; the modulo below drops them.
         let bank_index = if !chr_mode_4k {
             // 8 KiB CHR mode: ignore low bit of bank 0.
             let base_bank = (self.chr_bank0 & !1) as usize;
@@ -287,7 +345,8 @@ impl Mapper1 {
         let offset_in_bank = (addr as usize) & 0x0FFF;
         let chr_mode_4k = (self.control >> 4) & 0b1 == 1;
 
-        // TODO(accuracy): SxROM variants use CHR bank high bits for outer PRG/PRG-RAM banking.
+        // With 8 KiB of CHR the bank's upper bits only drive the SxROM board
+        // lines; the modulo below drops them.
         let bank_index = if !chr_mode_4k {
             let base_bank = (self.chr_bank0 & !1) as usize;
             if addr < MMC1_CHR_RIGHT_WINDOW_START {
@@ -359,10 +418,12 @@ impl Mapper1 {
                 1 => {
                     // CHR bank 0 (4 KiB)
                     self.chr_bank0 = value;
+                    self.last_chr_bank1 = false;
                 }
                 2 => {
                     // CHR bank 1 (4 KiB)
                     self.chr_bank1 = value;
+                    self.last_chr_bank1 = true;
                 }
                 3 => {
                     // PRG bank select
@@ -389,6 +450,7 @@ impl Mapper1 {
         self.chr_bank0 = 0;
         self.chr_bank1 = 0;
         self.prg_bank = 0;
+        self.last_chr_bank1 = false;
         self.shift_reg = 0x10;
         self.shift_count = 0;
         self.last_serial_cycle = None;
@@ -428,6 +490,10 @@ impl Mapper for Mapper1 {
     fn ppu_write(&mut self, addr: u16, data: u8) {
         self.write_chr(addr, data);
     }
+    fn prg_ram_offset(&self, addr: u16) -> Option<usize> {
+        (!self.prg_ram.is_empty()).then(|| self.prg_ram_index(addr))
+    }
+
     fn memory_ref(&self) -> MapperMemoryRef<'_> {
         MapperMemoryRef {
             prg_rom: Some(self.prg_rom.as_ref()),
@@ -483,11 +549,14 @@ mod tests {
     use crate::cartridge::header::Header;
 
     fn header(prg_rom_size: usize, chr_rom_size: usize) -> Header {
+        header_with_prg_ram(prg_rom_size, chr_rom_size, 1)
+    }
+
+    fn header_with_prg_ram(prg_rom_size: usize, chr_rom_size: usize, prg_ram_units: u8) -> Header {
         let prg_rom_units = (prg_rom_size / (16 * 1024)) as u8;
         let chr_rom_units = (chr_rom_size / (8 * 1024)) as u8;
 
         let flags6 = 0x10; // mapper 1 + horizontal mirroring
-        let prg_ram_units = 1; // 8 KiB
         let header_bytes = [
             b'N',
             b'E',
@@ -550,4 +619,61 @@ mod tests {
         // High bank should remain fixed to last bank.
         assert_eq!(cart.cpu_read(0xC000, 0), Some(3));
     }
+
+    #[test]
+    fn surom_selects_256k_half_with_chr_bit4() {
+        let mut cart = cart_with_prg_banks(32);
+        assert_eq!(cart.cpu_read(cpu_mem::PRG_ROM_START, 0), Some(0));
+        assert_eq!(cart.cpu_read(0xC000, 0), Some(15));
+
+        write_serial_reg(&mut cart, 0xA000, 0x10);
+        write_serial_reg(&mut cart, 0xE000, 0x02);
+        assert_eq!(cart.cpu_read(cpu_mem::PRG_ROM_START, 0), Some(18));
+        assert_eq!(cart.cpu_read(0xC000, 0), Some(31));
+
+        // In 4 KiB CHR mode the last written CHR register drives the line.
+        write_serial_reg(&mut cart, 0x8000, 0x1C);
+        write_serial_reg(&mut cart, 0xC000, 0x00);
+        assert_eq!(cart.cpu_read(0xC000, 0), Some(15));
+    }
+
+    #[test]
+    fn sxrom_banks_prg_ram_with_chr_bits_2_3() {
+        let prg = vec![0u8; 2 * PRG_BANK_SIZE_16K];
+        let mut cart = Mapper1::new(
+            header_with_prg_ram(prg.len(), 0, 4),
+            prg.into(),
+            vec![].into(),
+            None,
+        );
+        cart.reset(ResetKind::PowerOn);
+        assert_eq!(cart.memory_ref().prg_ram.map(<[u8]>::len), Some(32 * 1024));
+
+        for bank in 0..4u8 {
+            write_serial_reg(&mut cart, 0xA000, bank << 2);
+            cart.cpu_write(0x6000, 0xA0 + bank, 0);
+        }
+        for bank in 0..4u8 {
+            write_serial_reg(&mut cart, 0xA000, bank << 2);
+            assert_eq!(cart.cpu_read(0x6000, 0), Some(0xA0 + bank));
+        }
+        let ram = cart.memory_ref().prg_ram.expect("prg ram");
+        assert_eq!(ram[3 * PRG_RAM_BANK_SIZE], 0xA3);
+    }
+
+    #[test]
+    fn prg_ram_offset_follows_the_selected_bank() {
+        let prg = vec![0u8; 2 * PRG_BANK_SIZE_16K];
+        let mut cart = Mapper1::new(
+            header_with_prg_ram(prg.len(), 0, 4),
+            prg.into(),
+            vec![].into(),
+            None,
+        );
+        cart.reset(ResetKind::PowerOn);
+
+        assert_eq!(cart.prg_ram_offset(0x6010), Some(0x10));
+        write_serial_reg(&mut cart, 0xA000, 1 << 2);
+        assert_eq!(cart.prg_ram_offset(0x6010), Some(PRG_RAM_BANK_SIZE + 0x10));
+    }
 }
@@ -0,0 +1,423 @@
+//! Mapper 105 - NES-EVENT (Nintendo World Championships 1990).
+//!
+//! An MMC1 board with two 128 KiB PRG chips and a countdown timer used to end
+//! each competition round. Reference: https://www.nesdev.org/wiki/INES_Mapper_105
+//!
+//! - The MMC1 control and PRG registers keep their usual meaning, but only
+//!   bank the second PRG chip.
+//! - CHR bank 0 (`$A000`) is repurposed: bit 4 (`I`) holds the timer in reset
+//!   and acknowledges its IRQ, bit 3 selects the second PRG chip, bits 1-2 pick
+//!   a 32 KiB bank of the first chip.
+//! - PRG stays locked to the first 32 KiB until `I` has been cleared and set
+//!   again after power-on.
+//! - While `I` is clear the timer counts CPU cycles and raises an IRQ after
+//!   `(16 + dip) * 2^25` of them; the four DIP switches select 5:00 to 9:42.
+//! - 8 KiB CHR RAM, unbanked.
+
+use std::borrow::Cow;
+
+use crate::cartridge::mapper::{MapperMemoryMut, MapperMemoryRef};
+
+use crate::{
+    cartridge::{
+        ChrRom, Mapper, PrgRom, TrainerBytes,
+        header::{Header, Mirroring},
+        mapper::{
+            ChrStorage, MapperEvent, MapperHookMask, allocate_prg_ram_with_trainer,
+            select_chr_storage,
+        },
+    },
+    memory::cpu as cpu_mem,
+    reset_kind::ResetKind,
+};
+
+const PRG_BANK_SIZE_16K: usize = 16 * 1024;
+/// First 16 KiB bank of the second PRG chip.
+const SECOND_CHIP_FIRST_BANK: usize = 8;
+/// CPU `$C000`: boundary between the lower and upper 16 KiB PRG windows.
+const PRG_UPPER_WINDOW_START: u16 = 0xC000;
+
+/// DIP switch setting used at the 1990 competition (about 6:15).
+pub const NWC_DEFAULT_DIP_SWITCHES: u8 = 4;
+
+/// Timer length in CPU cycles for a 4-bit DIP switch setting.
+pub const fn nwc_timer_cycles(dip_switches: u8) -> u32 {
+    (16 + (dip_switches & 0x0F) as u32) << 25
+}
+
+/// Power-on lock sequence: `I` must go low, then high, before PRG banking
+/// is released.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InitState {
+    PowerOn,
+    Cleared,
+    Unlocked,
+}
+
+#[derive(Debug, Clone)]
+pub struct Mapper105 {
+    prg_rom: PrgRom,
+    prg_ram: Box<[u8]>,
+    chr: ChrStorage,
+    prg_bank_count: usize,
+
+    /// MMC1 control register: mirroring (bits 0-1), PRG mode (bits 2-3).
+    control: u8,
+    /// CHR bank 0 register, reused for the timer and the first PRG chip.
+    chr_bank0: u8,
+    /// PRG bank register for the second chip; bit 4 disables PRG RAM.
+    prg_bank: u8,
+    shift_reg: u8,
+    shift_count: u8,
+    last_serial_cycle: Option<u64>,
+
+    init_state: InitState,
+    irq_counter: u32,
+    irq_enabled: bool,
+    irq_pending: bool,
+    /// Four DIP switches selecting the timer length; board configuration,
+    /// not reset by the console.
+    dip_switches: u8,
+}
+
+impl Mapper105 {
+    pub fn new(header: Header, prg_rom: PrgRom, chr_rom: ChrRom, trainer: TrainerBytes) -> Self {
+        let prg_ram = allocate_prg_ram_with_trainer(&header, trainer);
+        let chr = select_chr_storage(&header, chr_rom);
+        let prg_bank_count = (prg_rom.len() / PRG_BANK_SIZE_16K).max(1);
+
+        Self {
+            prg_rom,
+            prg_ram,
+            chr,
+            prg_bank_count,
+            control: 0x0C,
+            chr_bank0: 0x10,
+            prg_bank: 0,
+            shift_reg: 0x10,
+            shift_count: 0,
+            last_serial_cycle: None,
+            init_state: InitState::PowerOn,
+            irq_counter: 0,
+            irq_enabled: false,
+            irq_pending: false,
+            dip_switches: NWC_DEFAULT_DIP_SWITCHES,
+        }
+    }
+
+    /// Current DIP switch setting (0-15).
+    pub fn dip_switches(&self) -> u8 {
+        self.dip_switches
+    }
+
+    /// Sets the timer DIP switches (0-15, higher is longer). Applies to the
+    /// running countdown as well.
+    pub fn set_dip_switches(&mut self, dip_switches: u8) {
+        self.dip_switches = dip_switches & 0x0F;
+    }
+
+    /// CPU cycles left before the timer IRQ, or `None` while the timer is
+    /// held in reset or has already fired.
+    pub fn timer_remaining_cycles(&self) -> Option<u32> {
+        self.irq_enabled
+            .then(|| nwc_timer_cycles(self.dip_switches).saturating_sub(self.irq_counter))
+    }
+
+    fn read_prg_rom(&self, addr: u16) -> u8 {
+        if self.prg_rom.is_empty() {
+            return 0;
+        }
+        let bank = self.prg_bank_for_cpu_addr(addr) % self.prg_bank_count;
+        let offset = (addr as usize - cpu_mem::PRG_ROM_START as usize) % PRG_BANK_SIZE_16K;
+        self.prg_rom
+            .get(bank * PRG_BANK_SIZE_16K + offset)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    fn prg_bank_for_cpu_addr(&self, addr: u16) -> usize {
+        let upper = addr >= PRG_UPPER_WINDOW_START;
+        if self.init_state != InitState::Unlocked {
+            return usize::from(upper);
+        }
+
+        if self.chr_bank0 & 0x08 == 0 {
+            // First chip: 32 KiB banks from CHR bank 0 bits 1-2.
+            return (self.chr_bank0 & 0x06) as usize + usize::from(upper);
+        }
+
+        // Second chip: regular MMC1 PRG banking over its eight 16 KiB banks.
+        let bank = SECOND_CHIP_FIRST_BANK + (self.prg_bank & 0x07) as usize;
+        let last = SECOND_CHIP_FIRST_BANK + 7;
+        match ((self.control >> 2) & 0b11, upper) {
+            (0 | 1, _) => (bank & !1) + usize::from(upper),
+            (2, false) => SECOND_CHIP_FIRST_BANK,
+            (2, true) => bank,
+            (_, false) => bank,
+            (_, true) => last,
+        }
+    }
+
+    fn prg_ram_enabled(&self) -> bool {
+        !self.prg_ram.is_empty() && self.prg_bank & 0x10 == 0
+    }
+
+    fn write_register(&mut self, addr: u16, data: u8, cpu_cycle: u64) {
+        if data & 0x80 != 0 {
+            self.shift_reg = 0x10;
+            self.shift_count = 0;
+            self.control |= 0x0C;
+            self.last_serial_cycle = None;
+            return;
+        }
+
+        // Same consecutive-cycle write ignore as MMC1 (see `Mapper1`).
+        if let Some(last) = self.last_serial_cycle
+            && cpu_cycle == last.wrapping_add(1)
+        {
+            self.last_serial_cycle = None;
+            return;
+        }
+        if self.last_serial_cycle == Some(cpu_cycle) {
+            return;
+        }
+
+        self.shift_reg = (self.shift_reg >> 1) | ((data & 1) << 4);
+        self.shift_count += 1;
+        self.last_serial_cycle = Some(cpu_cycle);
+
+        if self.shift_count == 5 {
+            let value = self.shift_reg & 0x1F;
+            match (addr >> 13) & 0b11 {
+                0 => self.control = value,
+                1 => {
+                    self.chr_bank0 = value;
+                    self.update_timer_control();
+                }
+                // CHR bank 1 is unused: CHR is a single unbanked 8 KiB RAM.
+                2 => {}
+                _ => self.prg_bank = value,
+            }
+            self.shift_reg = 0x10;
+            self.shift_count = 0;
+            self.last_serial_cycle = None;
+        }
+    }
+
+    fn update_timer_control(&mut self) {
+        let hold = self.chr_bank0 & 0x10 != 0;
+        self.init_state = match (self.init_state, hold) {
+            (InitState::PowerOn, false) => InitState::Cleared,
+            (InitState::Cleared, true) => InitState::Unlocked,
+            (state, _) => state,
+        };
+
+        if hold {
+            self.irq_enabled = false;
+            self.irq_counter = 0;
+            self.irq_pending = false;
+        } else {
+            self.irq_enabled = true;
+        }
+    }
+
+    fn clock_timer(&mut self) {
+        if !self.irq_enabled {
+            return;
+        }
+        self.irq_counter += 1;
+        if self.irq_counter >= nwc_timer_cycles(self.dip_switches) {
+            self.irq_pending = true;
+            self.irq_enabled = false;
+        }
+    }
+}
+
+impl Mapper for Mapper105 {
+    fn cpu_read(&self, addr: u16, _open_bus: u8) -> Option<u8> {
+        match addr {
+            cpu_mem::PRG_RAM_START..=cpu_mem::PRG_RAM_END => {
+                if !self.prg_ram_enabled() {
+                    return None;
+                }
+                let offset = (addr - cpu_mem::PRG_RAM_START) as usize % self.prg_ram.len();
+                Some(self.prg_ram[offset])
+            }
+            cpu_mem::PRG_ROM_START..=cpu_mem::CPU_ADDR_END => Some(self.read_prg_rom(addr)),
+            _ => None,
+        }
+    }
+
+    fn cpu_write(&mut self, addr: u16, data: u8, cpu_cycle: u64) {
+        match addr {
+            cpu_mem::PRG_RAM_START..=cpu_mem::PRG_RAM_END if self.prg_ram_enabled() => {
+                let offset = (addr - cpu_mem::PRG_RAM_START) as usize % self.prg_ram.len();
+                self.prg_ram[offset] = data;
+            }
+            cpu_mem::PRG_ROM_START..=cpu_mem::CPU_ADDR_END => {
+                self.write_register(addr, data, cpu_cycle)
+            }
+            _ => {}
+        }
+    }
+
+    fn hook_mask(&self) -> MapperHookMask {
+        MapperHookMask::CPU_CLOCK
+    }
+
+    fn on_mapper_event(&mut self, event: MapperEvent) {
+        if let MapperEvent::CpuClock { .. } = event {
+            self.clock_timer();
+        }
+    }
+
+    fn reset(&mut self, kind: ResetKind) {
+        if matches!(kind, ResetKind::PowerOn) {
+            self.control = 0x0C;
+            self.chr_bank0 = 0x10;
+            self.prg_bank = 0;
+            self.shift_reg = 0x10;
+            self.shift_count = 0;
+            self.last_serial_cycle = None;
+            self.init_state = InitState::PowerOn;
+            self.irq_counter = 0;
+            self.irq_enabled = false;
+            self.irq_pending = false;
+        }
+    }
+
+    fn ppu_read(&self, addr: u16) -> Option<u8> {
+        Some(self.chr.read_indexed(0, addr as usize & 0x1FFF))
+    }
+
+    fn ppu_write(&mut self, addr: u16, data: u8) {
+        self.chr.write_indexed(0, addr as usize & 0x1FFF, data);
+    }
+
+    fn irq_pending(&self) -> bool {
+        self.irq_pending
+    }
+
+    fn memory_ref(&self) -> MapperMemoryRef<'_> {
+        MapperMemoryRef {
+            prg_rom: Some(self.prg_rom.as_ref()),
+            prg_ram: (!self.prg_ram.is_empty()).then_some(self.prg_ram.as_ref()),
+            prg_work_ram: None,
+            mapper_ram: None,
+            chr_rom: self.chr.as_rom(),
+            chr_ram: self.chr.as_ram(),
+            chr_battery_ram: None,
+        }
+    }
+
+    fn memory_mut(&mut self) -> MapperMemoryMut<'_> {
+        MapperMemoryMut {
+            prg_ram: (!self.prg_ram.is_empty()).then_some(self.prg_ram.as_mut()),
+            prg_work_ram: None,
+            mapper_ram: None,
+            chr_ram: self.chr.as_ram_mut(),
+            chr_battery_ram: None,
+        }
+    }
+
+    fn mirroring(&self) -> Mirroring {
+        match self.control & 0b11 {
+            0 => Mirroring::SingleScreenLower,
+            1 => Mirroring::SingleScreenUpper,
+            2 => Mirroring::Vertical,
+            _ => Mirroring::Horizontal,
+        }
+    }
+
+    fn mapper_id(&self) -> u16 {
+        105
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("NES-EVENT")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_mapper() -> Mapper105 {
+        let mut rom = [0u8; 16];
+        rom[0..4].copy_from_slice(b"NES\x1A");
+        rom[4] = 16; // 256 KiB PRG
+        rom[5] = 0; // CHR RAM
+        // Mapper 105 = 0x69.
+        rom[6] = 0x90;
+        rom[7] = 0x60;
+        let header = Header::parse(&rom).expect("valid iNES header");
+
+        let mut prg = vec![0u8; 16 * PRG_BANK_SIZE_16K];
+        for (bank, chunk) in prg.chunks_exact_mut(PRG_BANK_SIZE_16K).enumerate() {
+            chunk.fill(bank as u8);
+        }
+        let mut mapper = Mapper105::new(header, prg.into(), vec![].into(), None);
+        mapper.reset(ResetKind::PowerOn);
+        mapper
+    }
+
+    fn write_serial_reg(mapper: &mut Mapper105, addr: u16, value: u8) {
+        for i in 0..5 {
+            mapper.cpu_write(addr, (value >> i) & 1, i as u64 * 2);
+        }
+    }
+
+    fn clock(mapper: &mut Mapper105, cycles: u32) {
+        for _ in 0..cycles {
+            mapper.on_mapper_event(MapperEvent::CpuClock {
+                cpu_cycle: 0,
+                master_clock: 0,
+            });
+        }
+    }
+
+    #[test]
+    fn prg_is_locked_until_timer_bit_toggles() {
+        let mut mapper = test_mapper();
+        assert_eq!(mapper.cpu_read(0x8000, 0), Some(0));
+        assert_eq!(mapper.cpu_read(0xC000, 0), Some(1));
+
+        // First chip, 32 KiB bank 1; still locked.
+        write_serial_reg(&mut mapper, 0xA000, 0x02);
+        assert_eq!(mapper.cpu_read(0x8000, 0), Some(0));
+
+        write_serial_reg(&mut mapper, 0xA000, 0x12);
+        assert_eq!(mapper.cpu_read(0x8000, 0), Some(2));
+        assert_eq!(mapper.cpu_read(0xC000, 0), Some(3));
+
+        // Second chip in 16 KiB mode with the last bank fixed at $C000.
+        write_serial_reg(&mut mapper, 0xE000, 0x03);
+        write_serial_reg(&mut mapper, 0xA000, 0x18);
+        assert_eq!(mapper.cpu_read(0x8000, 0), Some(11));
+        assert_eq!(mapper.cpu_read(0xC000, 0), Some(15));
+    }
+
+    #[test]
+    fn timer_fires_after_dip_switch_length() {
+        let mut mapper = test_mapper();
+        assert_eq!(nwc_timer_cycles(0), 0x2000_0000);
+        assert_eq!(nwc_timer_cycles(15), 31 << 25);
+
+        write_serial_reg(&mut mapper, 0xA000, 0x00);
+        mapper.set_dip_switches(0);
+        // Jump close to the end rather than clocking half a billion cycles.
+        mapper.irq_counter = nwc_timer_cycles(0) - 2;
+        assert_eq!(mapper.timer_remaining_cycles(), Some(2));
+        clock(&mut mapper, 1);
+        assert!(!mapper.irq_pending());
+        clock(&mut mapper, 1);
+        assert!(mapper.irq_pending());
+        assert_eq!(mapper.timer_remaining_cycles(), None);
+
+        // Setting `I` acknowledges the IRQ and holds the counter at zero.
+        write_serial_reg(&mut mapper, 0xA000, 0x10);
+        assert!(!mapper.irq_pending());
+        clock(&mut mapper, 10);
+        assert_eq!(mapper.irq_counter, 0);
+    }
+}
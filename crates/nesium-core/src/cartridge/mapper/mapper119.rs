@@ -25,7 +25,7 @@ use crate::{
             MapperEvent, MapperHookMask, PpuVramAccessContext, allocate_prg_ram_with_trainer,
             core::mmc3::{
                 MMC3_POWER_ON_BANK_REGS, Mmc3Core, Mmc3CoreResetConfig, Mmc3CpuRegister,
-                Mmc3IrqRevision, Mmc3WriteConfig, Mmc3WriteResult, PRG_BANK_SIZE_8K,
+                Mmc3Revision, Mmc3WriteConfig, Mmc3WriteResult, PRG_BANK_SIZE_8K,
                 resolve_mmc3_chr_bank,
             },
         },
@@ -70,7 +70,7 @@ impl Mapper119 {
             bank_regs: MMC3_POWER_ON_BANK_REGS,
             prg_ram_enable: false,
             prg_ram_write_protect: false,
-            irq_revision: Mmc3IrqRevision::RevB,
+            irq_revision: Mmc3Revision::Sharp,
         };
 
         Self {
@@ -7,6 +7,8 @@
 //! - Mirroring control via register `$x009`
 //! - CPU-cycle IRQ counter via `$x00A-$x00C`
 //! - Optional 24C02 serial EEPROM line at `$6000-$7FFF` / register `$x00D`
+//! - Mapper 157 (Datach Joint ROM System): extra 24C01 EEPROM and the
+//!   barcode reader on bit 3 of `$6000-$7FFF`, fed by [`Mapper16::insert_barcode`]
 //!
 //! Like Mesen, legacy iNES mapper 16 accepts register writes in both
 //! `$6000-$7FFF` and `$8000-$FFFF` because the board variant is ambiguous.
@@ -21,6 +23,7 @@ use crate::{
         header::{Header, Mirroring, RomFormat},
         mapper::{ChrStorage, MapperEvent, MapperHookMask, select_chr_storage},
     },
+    error::Error,
     reset_kind::ResetKind,
 };
 
@@ -340,23 +343,109 @@ impl Eeprom24c01 {
     }
 }
 
+/// CPU cycles each barcode module stays on the reader output.
+const BARCODE_CYCLES_PER_BIT: u64 = 1000;
+
+/// EAN "L" (odd parity) patterns, most significant module first. "G" patterns
+/// are the reversed "R" patterns and "R" patterns are the complemented "L"
+/// patterns.
+const EAN_L_CODES: [u8; 10] = [0x0D, 0x19, 0x13, 0x3D, 0x23, 0x31, 0x2F, 0x3B, 0x37, 0x0B];
+
+/// EAN-13 parity of the six left-hand digits, keyed by the leading digit;
+/// a set bit (MSB first) selects the "G" set.
+const EAN13_G_PARITY: [u8; 10] = [0x00, 0x0B, 0x0D, 0x0E, 0x13, 0x19, 0x1C, 0x15, 0x16, 0x1A];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EanSet {
+    L,
+    G,
+    R,
+}
+
+/// Serializes an EAN-13 or EAN-8 barcode into the reader's output stream, one
+/// entry per module: `0x08` for a light module and `0` for a bar, with quiet
+/// zones around the code. The check digit is recomputed from the other
+/// digits, as on Mesen.
+fn encode_barcode(digits: &[u8]) -> Vec<u8> {
+    fn push_digit(data: &mut Vec<u8>, digit: u8, set: EanSet) {
+        let l = EAN_L_CODES[digit as usize];
+        let pattern = match set {
+            EanSet::L => l,
+            EanSet::R => !l & 0x7F,
+            EanSet::G => (!l & 0x7F).reverse_bits() >> 1,
+        };
+        for bit in (0..7).rev() {
+            let bar = pattern & (1 << bit) != 0;
+            data.push(if bar { 0 } else { 0x08 });
+        }
+    }
+
+    let mut data = vec![0x08; 33];
+    data.extend_from_slice(&[0, 0x08, 0]);
+
+    let sum: u32 = if digits.len() == 13 {
+        let parity = EAN13_G_PARITY[digits[0] as usize];
+        for (i, &digit) in digits[1..7].iter().enumerate() {
+            let set = if parity & (0x20 >> i) != 0 {
+                EanSet::G
+            } else {
+                EanSet::L
+            };
+            push_digit(&mut data, digit, set);
+        }
+        data.extend_from_slice(&[0x08, 0, 0x08, 0, 0x08]);
+        for &digit in &digits[7..12] {
+            push_digit(&mut data, digit, EanSet::R);
+        }
+        digits[..12]
+            .iter()
+            .enumerate()
+            .map(|(i, &d)| u32::from(d) * if i & 1 != 0 { 3 } else { 1 })
+            .sum()
+    } else {
+        for &digit in &digits[..4] {
+            push_digit(&mut data, digit, EanSet::L);
+        }
+        data.extend_from_slice(&[0x08, 0, 0x08, 0, 0x08]);
+        for &digit in &digits[4..7] {
+            push_digit(&mut data, digit, EanSet::R);
+        }
+        digits[..7]
+            .iter()
+            .enumerate()
+            .map(|(i, &d)| u32::from(d) * if i & 1 != 0 { 1 } else { 3 })
+            .sum()
+    };
+    let check = ((10 - sum % 10) % 10) as u8;
+    push_digit(&mut data, check, EanSet::R);
+
+    data.extend_from_slice(&[0, 0x08, 0]);
+    data.extend(std::iter::repeat_n(0x08, 32));
+    data
+}
+
 #[derive(Debug, Clone)]
 struct DatachBarcodeReader {
     data: Vec<u8>,
-    insert_master_clock: u64,
+    insert_cpu_cycle: u64,
 }
 
 impl DatachBarcodeReader {
     fn new() -> Self {
         Self {
             data: Vec::new(),
-            insert_master_clock: 0,
+            insert_cpu_cycle: 0,
         }
     }
 
-    fn output(&self, master_clock: u64) -> u8 {
-        let elapsed = master_clock.saturating_sub(self.insert_master_clock);
-        let bit = (elapsed / 1000) as usize;
+    fn insert(&mut self, digits: &[u8], cpu_cycle: u64) {
+        self.data = encode_barcode(digits);
+        self.insert_cpu_cycle = cpu_cycle;
+    }
+
+    fn output(&self, cpu_cycle: u64) -> u8 {
+        let elapsed = cpu_cycle.saturating_sub(self.insert_cpu_cycle);
+        let bit = (elapsed / BARCODE_CYCLES_PER_BIT) as usize;
         self.data.get(bit).copied().unwrap_or(0)
     }
 }
@@ -384,7 +473,7 @@ pub struct Mapper16 {
     irq_counter: u16,
     irq_reload: u16,
     irq_pending: bool,
-    master_clock: u64,
+    cpu_cycle: u64,
     eeprom: Option<Eeprom24c02>,
     extra_eeprom: Option<Eeprom24c01>,
     barcode_reader: Option<DatachBarcodeReader>,
@@ -431,7 +520,7 @@ impl Mapper16 {
             irq_counter: 0,
             irq_reload: 0,
             irq_pending: false,
-            master_clock: 0,
+            cpu_cycle: 0,
             eeprom,
             extra_eeprom,
             barcode_reader,
@@ -670,6 +759,38 @@ impl Mapper16 {
         self.trace_seq = self.trace_seq.saturating_add(1);
     }
 
+    /// Returns `true` for Datach boards, which carry a barcode reader.
+    pub fn has_barcode_reader(&self) -> bool {
+        self.barcode_reader.is_some()
+    }
+
+    /// Swipes an EAN-13 or EAN-8 barcode through the Datach reader. `barcode`
+    /// must hold exactly 13 or 8 decimal digits; the final check digit is
+    /// recomputed rather than validated. The game sees the code over roughly
+    /// the next 130k CPU cycles.
+    pub fn insert_barcode(&mut self, barcode: &str) -> Result<(), Error> {
+        let cpu_cycle = self.cpu_cycle;
+        let Some(reader) = &mut self.barcode_reader else {
+            return Err(Error::InvalidBarcode {
+                reason: "cartridge has no barcode reader",
+            });
+        };
+        let digits: Vec<u8> = barcode
+            .bytes()
+            .map(|b| b.is_ascii_digit().then(|| b - b'0'))
+            .collect::<Option<_>>()
+            .ok_or(Error::InvalidBarcode {
+                reason: "barcodes may only contain digits",
+            })?;
+        if digits.len() != 13 && digits.len() != 8 {
+            return Err(Error::InvalidBarcode {
+                reason: "expected 13 (EAN-13) or 8 (EAN-8) digits",
+            });
+        }
+        reader.insert(&digits, cpu_cycle);
+        Ok(())
+    }
+
     #[doc(hidden)]
     pub fn debug_irq_state(&self) -> (bool, bool, u16, u16, u8) {
         (
@@ -702,16 +823,16 @@ impl Mapper for Mapper16 {
                 _ => return,
             };
             self.trace_cpu_bus_access(label, addr, value, cpu_cycle, master_clock);
-        } else if let MapperEvent::CpuClock { master_clock, .. } = event
+        } else if let MapperEvent::CpuClock { cpu_cycle, .. } = event
             && self.irq_enabled
         {
-            self.master_clock = master_clock;
+            self.cpu_cycle = cpu_cycle;
             if self.irq_counter == 0 {
                 self.irq_pending = true;
             }
             self.irq_counter = self.irq_counter.wrapping_sub(1);
-        } else if let MapperEvent::CpuClock { master_clock, .. } = event {
-            self.master_clock = master_clock;
+        } else if let MapperEvent::CpuClock { cpu_cycle, .. } = event {
+            self.cpu_cycle = cpu_cycle;
         }
     }
 
@@ -724,7 +845,6 @@ impl Mapper for Mapper16 {
         self.irq_counter = 0;
         self.irq_reload = 0;
         self.irq_pending = false;
-        self.master_clock = 0;
         self.mirroring = self.base_mirroring;
     }
 
@@ -733,7 +853,7 @@ impl Mapper for Mapper16 {
             0x6000..=0x7FFF if self.lower_read_port_enabled() => {
                 let mut output = open_bus & 0xE7;
                 if let Some(reader) = &self.barcode_reader {
-                    output |= reader.output(self.master_clock);
+                    output |= reader.output(self.cpu_cycle);
                 }
                 if let (Some(eeprom), Some(extra)) = (&self.eeprom, &self.extra_eeprom) {
                     output |= u8::from(eeprom.read_bit() != 0 && extra.read_bit() != 0) << 4;
@@ -912,4 +1032,52 @@ mod tests {
 
         assert!(mapper.irq_pending());
     }
+
+    fn reader_bits(mapper: &mut Mapper16, count: usize) -> Vec<u8> {
+        (0..count)
+            .map(|bit| {
+                mapper.on_mapper_event(MapperEvent::CpuClock {
+                    cpu_cycle: bit as u64 * BARCODE_CYCLES_PER_BIT,
+                    master_clock: 0,
+                });
+                mapper.cpu_read(0x6000, 0).expect("reader port") & 0x08
+            })
+            .collect()
+    }
+
+    #[test]
+    fn datach_reader_streams_ean13_modules() {
+        let header = ines_header(16, 0).with_mapper_submapper(157, 0);
+        let mut mapper = test_mapper(header, 16, 0);
+        mapper.reset(ResetKind::PowerOn);
+
+        assert!(mapper.insert_barcode("49012345").is_ok());
+        assert!(mapper.insert_barcode("4901234567894").is_ok());
+        let bits = reader_bits(&mut mapper, 33 + 95 + 32 + 1);
+
+        // Quiet zone, then the 101 start guard as bars on a light background.
+        assert!(bits[..33].iter().all(|&b| b == 0x08));
+        assert_eq!(bits[33..36], [0, 0x08, 0]);
+        // Leading 4 selects LGLLGG parity; digit 9 in the L set is 0001011.
+        assert_eq!(bits[36..43], [0x08, 0x08, 0x08, 0, 0x08, 0, 0]);
+        // Middle guard after six left-hand digits.
+        assert_eq!(bits[78..83], [0x08, 0, 0x08, 0, 0x08]);
+        // Check digit 4 in the R set is 1011100, then the end guard.
+        assert_eq!(bits[118..125], [0, 0x08, 0, 0, 0, 0x08, 0x08]);
+        assert_eq!(bits[125..128], [0, 0x08, 0]);
+        // Reader goes dark once the stream has been consumed.
+        assert_eq!(bits[160], 0);
+    }
+
+    #[test]
+    fn barcode_input_is_validated() {
+        let header = ines_header(16, 0).with_mapper_submapper(157, 0);
+        let mut datach = test_mapper(header, 16, 0);
+        assert!(datach.insert_barcode("12345").is_err());
+        assert!(datach.insert_barcode("490123456789x").is_err());
+
+        let mut plain = test_mapper(ines_header(16, 1), 16, 1);
+        assert!(!plain.has_barcode_reader());
+        assert!(plain.insert_barcode("4901234567894").is_err());
+    }
 }
@@ -6,7 +6,9 @@ use crate::{
     cartridge::{
         ChrRom, Mapper, PrgRom, TrainerBytes,
         header::{Header, Mirroring},
-        mapper::{ChrStorage, allocate_prg_ram_with_trainer, select_chr_storage},
+        mapper::{
+            ChrStorage, allocate_prg_ram_with_trainer, has_bus_conflicts, select_chr_storage,
+        },
     },
     memory::cpu as cpu_mem,
 };
@@ -23,6 +25,9 @@ use serde::{Deserialize, Serialize};
 // | CPU  | `$C000-$FFFF`     | 16 KiB fixed PRG-ROM bank (last)           | None      |
 // | PPU  | `$0000-$1FFF`     | CHR ROM/RAM (no mapper-side CHR banking)   | None      |
 // | PPU  | `$2000-$3EFF`     | Mirroring from iNES header (no registers)  | None      |
+//
+// Writes to the bank register are ANDed with the PRG-ROM byte when the board
+// is marked as having bus conflicts (see `has_bus_conflicts`).
 
 const PRG_BANK_SIZE: usize = 16 * 1024;
 
@@ -38,6 +43,8 @@ pub struct Mapper2 {
     selected_bank: usize,
     bank_count: usize,
     mirroring: Mirroring,
+    /// Register writes see the PRG-ROM byte on the data bus as well.
+    bus_conflicts: bool,
 }
 
 #[cfg_attr(feature = "savestate-serde", derive(Serialize, Deserialize))]
@@ -59,6 +66,7 @@ impl Mapper2 {
             selected_bank: 0,
             bank_count,
             mirroring: header.mirroring(),
+            bus_conflicts: has_bus_conflicts(&header),
         }
     }
 
@@ -140,7 +148,14 @@ impl Mapper for Mapper2 {
     fn cpu_write(&mut self, addr: u16, data: u8, _cpu_cycle: u64) {
         match addr {
             cpu_mem::PRG_RAM_START..=cpu_mem::PRG_RAM_END => self.write_prg_ram(addr, data),
-            cpu_mem::PRG_ROM_START..=cpu_mem::CPU_ADDR_END => self.write_bank_select(data),
+            cpu_mem::PRG_ROM_START..=cpu_mem::CPU_ADDR_END => {
+                let data = if self.bus_conflicts {
+                    data & self.read_prg_rom(addr)
+                } else {
+                    data
+                };
+                self.write_bank_select(data)
+            }
             _ => {}
         }
     }
@@ -1,20 +1,34 @@
-//! Mapper 21 – Konami VRC4 (VRC4a/VRC4c) implementation.
+//! Mappers 21/22/23/25 – Konami VRC2 / VRC4 family.
 //!
-//! This mapper provides:
-//! - Two switchable 8 KiB PRG banks and two fixed banks (second‑last and last).
-//! - Eight 1 KiB CHR banks with split low/high nibble registers.
-//! - Mapper‑controlled nametable mirroring.
-//! - An IRQ counter modelled after Mesen2's `VrcIrq` (prescaler + reloadable 8‑bit
-//!   counter with optional CPU‑cycle mode).
+//! All nine board variants share one implementation; they differ only in
+//! which CPU address lines reach the chip's A0/A1 register-select pins and in
+//! the VRC2/VRC4 feature split:
+//! - Two switchable 8 KiB PRG banks and two fixed banks (second‑last and last);
+//!   VRC4 can swap the `$8000`/`$C000` windows.
+//! - Eight 1 KiB CHR banks with split low/high nibble registers (VRC2a drops
+//!   the low bit of each bank number).
+//! - Mapper‑controlled nametable mirroring (one bit on VRC2, two on VRC4).
+//! - VRC4 only: an IRQ counter modelled after Mesen2's `VrcIrq` (341/3
+//!   prescaler + reloadable 8‑bit counter with optional CPU‑cycle mode).
+//! - VRC2 only: a one-bit latch at `$6000-$6FFF` when the board has no PRG RAM.
 //!
-//! | Area | Address range     | Behaviour                                          | IRQ/Audio   |
-//! |------|-------------------|----------------------------------------------------|-------------|
-//! | CPU  | `$6000-$7FFF`     | Optional PRG-RAM                                   | None        |
-//! | CPU  | `$8000-$DFFF`     | Two switchable 8 KiB PRG banks + one fixed window  | None        |
-//! | CPU  | `$8000-$E003`     | PRG/CHR/mirroring/IRQ registers (after translation)| VRC4 IRQ    |
-//! | CPU  | `$E000-$FFFF`     | Fixed 8 KiB PRG (last) + IRQ control/ack          | VRC4 IRQ    |
-//! | PPU  | `$0000-$1FFF`     | Eight 1 KiB CHR banks with split low/high nibbles  | None        |
-//! | PPU  | `$2000-$3EFF`     | Mirroring controlled by VRC4 register              | None        |
+//! The variant comes from the NES 2.0 submapper, which the cartridge DB also
+//! fills in for known iNES dumps:
+//!
+//! | Mapper | Submapper | Variant | A0  | A1  |
+//! |--------|-----------|---------|-----|-----|
+//! | 21     | 1         | VRC4a   | A1  | A2  |
+//! | 21     | 2         | VRC4c   | A6  | A7  |
+//! | 22     | any       | VRC2a   | A1  | A0  |
+//! | 23     | 1         | VRC4f   | A0  | A1  |
+//! | 23     | 2         | VRC4e   | A2  | A3  |
+//! | 23     | 3         | VRC2b   | A0  | A1  |
+//! | 25     | 1         | VRC4b   | A1  | A0  |
+//! | 25     | 2         | VRC4d   | A3  | A2  |
+//! | 25     | 3         | VRC2c   | A1  | A0  |
+//!
+//! Submapper 0 on mappers 21/23/25 is ambiguous: like Mesen2, both address
+//! layouts of that mapper are ORed together and the IRQ is kept available.
 
 use std::borrow::Cow;
 
@@ -41,83 +55,199 @@ use crate::{
     reset_kind::ResetKind,
 };
 
-/// CPU `$8000-$FFFF`: VRC4 register I/O window.
-const VRC4_IO_WINDOW_START: u16 = 0x8000;
-const VRC4_IO_WINDOW_END: u16 = 0xFFFF;
+/// CPU `$8000-$FFFF`: VRC2/VRC4 register I/O window.
+const VRC_IO_WINDOW_START: u16 = 0x8000;
+const VRC_IO_WINDOW_END: u16 = 0xFFFF;
 
+/// CPU `$6000-$6FFF`: VRC2 one-bit latch on boards without PRG RAM.
+const VRC2_LATCH_END: u16 = 0x6FFF;
+
+/// Konami VRC2/VRC4 board variant, named after the chip markings.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-enum Vrc4Variant {
-    /// Standard VRC4a address wiring (mapper 21 submapper 0/1).
+pub enum Vrc2_4Variant {
+    Vrc2a,
+    Vrc2b,
+    Vrc2c,
     Vrc4a,
-    /// VRC4c address wiring (mapper 21 submapper 2).
+    Vrc4b,
     Vrc4c,
+    Vrc4d,
+    Vrc4e,
+    Vrc4f,
+}
+
+impl Vrc2_4Variant {
+    /// Variant for an iNES mapper/submapper pair, and whether the submapper
+    /// left the address wiring ambiguous.
+    pub fn detect(mapper: u16, submapper: u8) -> (Self, bool) {
+        let variant = match (mapper, submapper) {
+            (22, _) => Self::Vrc2a,
+            (23, 1) => Self::Vrc4f,
+            (23, 2) => Self::Vrc4e,
+            (23, _) => Self::Vrc2b,
+            (25, 2) => Self::Vrc4d,
+            (25, 3) => Self::Vrc2c,
+            (25, _) => Self::Vrc4b,
+            (_, 2) => Self::Vrc4c,
+            _ => Self::Vrc4a,
+        };
+        (variant, mapper != 22 && submapper == 0)
+    }
+
+    pub fn is_vrc4(self) -> bool {
+        !matches!(self, Self::Vrc2a | Self::Vrc2b | Self::Vrc2c)
+    }
+
+    pub fn mapper_id(self) -> u16 {
+        match self {
+            Self::Vrc4a | Self::Vrc4c => 21,
+            Self::Vrc2a => 22,
+            Self::Vrc2b | Self::Vrc4e | Self::Vrc4f => 23,
+            Self::Vrc2c | Self::Vrc4b | Self::Vrc4d => 25,
+        }
+    }
+
+    /// CPU address lines wired to the chip's A0/A1 pins.
+    fn address_bits(self) -> VrcAddressBits {
+        match self {
+            Self::Vrc4a => VrcAddressBits::new(1, 2),
+            Self::Vrc4c => VrcAddressBits::new(6, 7),
+            Self::Vrc2a | Self::Vrc2c | Self::Vrc4b => VrcAddressBits::new(1, 0),
+            Self::Vrc2b | Self::Vrc4f => VrcAddressBits::new(0, 1),
+            Self::Vrc4e => VrcAddressBits::new(2, 3),
+            Self::Vrc4d => VrcAddressBits::new(3, 2),
+        }
+    }
+
+    /// The other wiring a submapper-0 dump of the same mapper may use.
+    fn heuristic_alt(self) -> Option<VrcAddressBits> {
+        match self {
+            Self::Vrc4a => Some(Self::Vrc4c.address_bits()),
+            Self::Vrc4c => Some(Self::Vrc4a.address_bits()),
+            Self::Vrc2b | Self::Vrc4f => Some(Self::Vrc4e.address_bits()),
+            Self::Vrc4e => Some(Self::Vrc2b.address_bits()),
+            Self::Vrc2c | Self::Vrc4b => Some(Self::Vrc4d.address_bits()),
+            Self::Vrc4d => Some(Self::Vrc4b.address_bits()),
+            Self::Vrc2a => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Vrc2a => "Konami VRC2a",
+            Self::Vrc2b => "Konami VRC2b",
+            Self::Vrc2c => "Konami VRC2c",
+            Self::Vrc4a => "Konami VRC4a",
+            Self::Vrc4b => "Konami VRC4b",
+            Self::Vrc4c => "Konami VRC4c",
+            Self::Vrc4d => "Konami VRC4d",
+            Self::Vrc4e => "Konami VRC4e",
+            Self::Vrc4f => "Konami VRC4f",
+        }
+    }
 }
 
+/// Konami VRC2/VRC4, serving every board variant of mappers 21, 22, 23 and
+/// 25; the variant is picked from the header in [`Vrc2Vrc4::new`].
 #[derive(Debug, Clone)]
-pub struct Mapper21 {
+pub struct Vrc2Vrc4 {
     prg_rom: PrgRom,
     prg_ram: Box<[u8]>,
     chr: ChrStorage,
     banking: Vrc2_4Banking,
 
     // IRQ state ------------------------------------------------------------
-    irq: VrcIrq,
+    /// Present on VRC4 boards and on ambiguous (submapper 0) dumps.
+    irq: Option<VrcIrq>,
 
-    /// Address decoding variant and optional heuristic mode that ORs both
-    /// VRC4a/VRC4c address line layouts (Mesen2 behaviour when submapper == 0).
-    variant: Vrc4Variant,
+    variant: Vrc2_4Variant,
+    /// ORs both address layouts of the mapper (Mesen2 behaviour when
+    /// submapper == 0).
     use_heuristics: bool,
+    /// VRC2 `$6000-$6FFF` latch, used by games for copy protection checks.
+    vrc2_latch: u8,
 }
 
-impl Mapper21 {
+/// iNES mapper 21 (VRC4a/VRC4c).
+pub type Mapper21 = Vrc2Vrc4;
+/// iNES mapper 22 (VRC2a).
+pub type Mapper22 = Vrc2Vrc4;
+/// iNES mapper 23 (VRC4e/VRC4f/VRC2b).
+pub type Mapper23 = Vrc2Vrc4;
+/// iNES mapper 25 (VRC4b/VRC4d/VRC2c).
+pub type Mapper25 = Vrc2Vrc4;
+
+impl Vrc2Vrc4 {
     pub fn new(header: Header, prg_rom: PrgRom, chr_rom: ChrRom, trainer: TrainerBytes) -> Self {
         let prg_ram = allocate_prg_ram_with_trainer(&header, trainer);
 
         let chr = select_chr_storage(&header, chr_rom);
-        let banking = Vrc2_4Banking::new(&prg_rom, header.mirroring());
+        let (variant, use_heuristics) = Vrc2_4Variant::detect(header.mapper(), header.submapper());
 
-        let variant = match header.submapper() {
-            2 => Vrc4Variant::Vrc4c,
-            _ => Vrc4Variant::Vrc4a,
-        };
-        let use_heuristics = header.submapper() == 0;
+        let mut banking = Vrc2_4Banking::new(&prg_rom, header.mirroring());
+        // VRC2a ignores the low bit of each CHR bank number.
+        if variant == Vrc2_4Variant::Vrc2a {
+            banking.set_chr_page_shift(1);
+        }
+        let irq = (variant.is_vrc4() || use_heuristics).then(VrcIrq::new);
 
         Self {
             prg_rom,
             prg_ram,
             chr,
             banking,
-            irq: VrcIrq::new(),
+            irq,
             variant,
             use_heuristics,
+            vrc2_latch: 0,
         }
     }
 
-    /// Translate the CPU address into the VRC4 register layout, emulating the
-    /// A0/A1 pin permutations documented on Nesdev and mirrored in Mesen2.
+    pub fn variant(&self) -> Vrc2_4Variant {
+        self.variant
+    }
+
+    /// Translate the CPU address into the VRC2/VRC4 register layout, emulating
+    /// the A0/A1 pin permutations documented on Nesdev and mirrored in Mesen2.
     fn translate_address(&self, addr: u16) -> u16 {
-        let config = match self.variant {
-            Vrc4Variant::Vrc4a => Vrc2_4AddressConfig {
-                primary: VrcAddressBits::new(1, 2),
-                heuristic_alt: Some(VrcAddressBits::new(6, 7)),
-            },
-            Vrc4Variant::Vrc4c => Vrc2_4AddressConfig {
-                primary: VrcAddressBits::new(6, 7),
-                heuristic_alt: Some(VrcAddressBits::new(1, 2)),
-            },
+        let config = Vrc2_4AddressConfig {
+            primary: self.variant.address_bits(),
+            heuristic_alt: self.variant.heuristic_alt(),
         };
         translate_vrc2_4_address(addr, config, self.use_heuristics)
     }
 
+    fn mirroring_mask(&self) -> u8 {
+        if self.variant.is_vrc4() || self.use_heuristics {
+            0x03
+        } else {
+            0x01
+        }
+    }
+
+    fn has_vrc2_latch(&self) -> bool {
+        !self.variant.is_vrc4() && self.prg_ram.is_empty()
+    }
+
     fn read_prg_rom(&self, addr: u16) -> u8 {
-        self.banking.read_prg_rom(&self.prg_rom, addr, true)
+        self.banking
+            .read_prg_rom(&self.prg_rom, addr, self.variant.is_vrc4())
     }
 
-    fn read_prg_ram(&self, addr: u16) -> Option<u8> {
+    fn read_prg_ram(&self, addr: u16, open_bus: u8) -> Option<u8> {
+        if self.has_vrc2_latch() {
+            return (addr <= VRC2_LATCH_END).then_some((open_bus & 0xFE) | self.vrc2_latch);
+        }
         read_prg_ram_window(&self.prg_ram, addr)
     }
 
     fn write_prg_ram(&mut self, addr: u16, data: u8) {
+        if self.has_vrc2_latch() {
+            if addr <= VRC2_LATCH_END {
+                self.vrc2_latch = data & 0x01;
+            }
+            return;
+        }
         write_prg_ram_window(&mut self.prg_ram, addr, data);
     }
 
@@ -130,45 +260,51 @@ impl Mapper21 {
     }
 
     fn write_register(&mut self, addr: u16, value: u8) {
-        if let Some(reg) = Vrc2_4Register::from_addr(addr, false) {
+        let is_vrc4 = self.variant.is_vrc4();
+        if let Some(reg) = Vrc2_4Register::from_addr(addr, !is_vrc4) {
+            let mirroring_mask = self.mirroring_mask();
             write_vrc2_4_register(
                 &mut self.banking,
-                Some(&mut self.irq),
+                self.irq.as_mut(),
                 reg,
                 addr,
                 value,
-                0x03,
-                true,
+                mirroring_mask,
+                is_vrc4,
             );
         }
     }
 }
 
-impl Mapper for Mapper21 {
+impl Mapper for Vrc2Vrc4 {
     fn hook_mask(&self) -> MapperHookMask {
-        MapperHookMask::CPU_BUS_ACCESS
+        if self.irq.is_some() {
+            MapperHookMask::CPU_CLOCK
+        } else {
+            MapperHookMask::NONE
+        }
     }
 
     fn on_mapper_event(&mut self, event: MapperEvent) {
-        if let MapperEvent::CpuBusAccess { .. } = event {
-            self.irq.clock();
+        if let MapperEvent::CpuClock { .. } = event
+            && let Some(irq) = &mut self.irq
+        {
+            irq.clock();
         }
     }
 
     fn reset(&mut self, _kind: ResetKind) {
-        // Basic VRC4 defaults: mirroring from header, PRG mode 0, IRQ disabled.
+        // PRG mode 0, IRQ disabled, mirroring back to the header value.
         self.banking.reset();
-        self.irq.reset();
-
-        // Keep the header-provided mirroring until the game selects otherwise.
-        // (Some dumps ship with single-screen headers even when they later
-        // configure mirroring via $9000.)
-        // Mirroring is left unchanged here.
+        if let Some(irq) = &mut self.irq {
+            irq.reset();
+        }
+        self.vrc2_latch = 0;
     }
 
-    fn cpu_read(&self, addr: u16, _open_bus: u8) -> Option<u8> {
+    fn cpu_read(&self, addr: u16, open_bus: u8) -> Option<u8> {
         match addr {
-            cpu_mem::PRG_RAM_START..=cpu_mem::PRG_RAM_END => self.read_prg_ram(addr),
+            cpu_mem::PRG_RAM_START..=cpu_mem::PRG_RAM_END => self.read_prg_ram(addr, open_bus),
             cpu_mem::PRG_ROM_START..=cpu_mem::CPU_ADDR_END => Some(self.read_prg_rom(addr)),
             _ => None,
         }
@@ -177,7 +313,7 @@ impl Mapper for Mapper21 {
     fn cpu_write(&mut self, addr: u16, data: u8, _cpu_cycle: u64) {
         match addr {
             cpu_mem::PRG_RAM_START..=cpu_mem::PRG_RAM_END => self.write_prg_ram(addr, data),
-            VRC4_IO_WINDOW_START..=VRC4_IO_WINDOW_END => {
+            VRC_IO_WINDOW_START..=VRC_IO_WINDOW_END => {
                 let translated = self.translate_address(addr) & 0xF00F;
                 self.write_register(translated, data);
             }
@@ -194,8 +330,9 @@ impl Mapper for Mapper21 {
     }
 
     fn irq_pending(&self) -> bool {
-        self.irq.pending()
+        self.irq.as_ref().is_some_and(VrcIrq::pending)
     }
+
     fn memory_ref(&self) -> MapperMemoryRef<'_> {
         MapperMemoryRef {
             prg_rom: Some(self.prg_rom.as_ref()),
@@ -223,10 +360,154 @@ impl Mapper for Mapper21 {
     }
 
     fn mapper_id(&self) -> u16 {
-        21
+        self.variant.mapper_id()
     }
 
     fn name(&self) -> Cow<'static, str> {
-        Cow::Borrowed("Konami VRC4")
+        Cow::Borrowed(self.variant.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nes20_header(mapper: u16, submapper: u8, prg_16k_units: u8, chr_8k_units: u8) -> Header {
+        let mut rom = [0u8; 16];
+        rom[0..4].copy_from_slice(b"NES\x1A");
+        rom[4] = prg_16k_units;
+        rom[5] = chr_8k_units;
+        rom[6] = ((mapper & 0x0F) as u8) << 4;
+        rom[7] = (mapper as u8 & 0xF0) | 0x08;
+        rom[8] = submapper << 4;
+        Header::parse(&rom).expect("valid NES 2.0 header")
+    }
+
+    /// 16 KiB PRG units of 8 KiB banks filled with their index, and 1 KiB CHR
+    /// pages filled with their index.
+    fn test_mapper(mapper: u16, submapper: u8) -> Vrc2Vrc4 {
+        let header = nes20_header(mapper, submapper, 8, 16);
+        let prg: Vec<u8> = (0..16u8)
+            .flat_map(|bank| std::iter::repeat_n(bank, 8 * 1024))
+            .collect();
+        let chr: Vec<u8> = (0..128u8)
+            .flat_map(|page| std::iter::repeat_n(page, 1024))
+            .collect();
+        let mut mapper = Vrc2Vrc4::new(header, prg.into(), chr.into(), None);
+        mapper.reset(ResetKind::PowerOn);
+        mapper
+    }
+
+    #[test]
+    fn submappers_select_every_variant() {
+        use Vrc2_4Variant::*;
+
+        let cases = [
+            (21, 1, Vrc4a),
+            (21, 2, Vrc4c),
+            (22, 0, Vrc2a),
+            (23, 1, Vrc4f),
+            (23, 2, Vrc4e),
+            (23, 3, Vrc2b),
+            (25, 1, Vrc4b),
+            (25, 2, Vrc4d),
+            (25, 3, Vrc2c),
+        ];
+        for (id, submapper, variant) in cases {
+            let mapper = test_mapper(id, submapper);
+            assert_eq!(mapper.variant(), variant, "mapper {id}.{submapper}");
+            assert_eq!(mapper.mapper_id(), id);
+            assert_eq!(mapper.irq.is_some(), variant.is_vrc4());
+        }
+    }
+
+    #[test]
+    fn chr_register_low_nibble_follows_variant_wiring() {
+        // Second CHR low-nibble register ($B002 in chip terms) for each wiring.
+        let cases = [
+            (21, 1, 0xB004),
+            (21, 2, 0xB080),
+            (22, 0, 0xB001),
+            (23, 1, 0xB002),
+            (23, 2, 0xB008),
+            (23, 3, 0xB002),
+            (25, 1, 0xB001),
+            (25, 2, 0xB004),
+            (25, 3, 0xB001),
+        ];
+        for (id, submapper, addr) in cases {
+            let mut mapper = test_mapper(id, submapper);
+            mapper.cpu_write(addr, 0x06, 0);
+            // VRC2a drops the low bit of the bank number.
+            let expected = if id == 22 { 0x03 } else { 0x06 };
+            assert_eq!(
+                mapper.ppu_read(0x0400),
+                Some(expected),
+                "mapper {id}.{submapper}"
+            );
+        }
+    }
+
+    #[test]
+    fn ambiguous_dumps_accept_both_wirings_and_keep_the_irq() {
+        let mut mapper = test_mapper(23, 0);
+        assert!(mapper.irq.is_some());
+
+        mapper.cpu_write(0xB002, 0x05, 0);
+        assert_eq!(mapper.ppu_read(0x0400), Some(0x05));
+        mapper.cpu_write(0xB008, 0x07, 0);
+        assert_eq!(mapper.ppu_read(0x0400), Some(0x07));
+    }
+
+    #[test]
+    fn vrc4_prg_mode_swaps_fixed_window() {
+        let mut mapper = test_mapper(25, 1);
+        mapper.cpu_write(0x8000, 0x03, 0);
+        assert_eq!(mapper.cpu_read(0x8000, 0), Some(3));
+        assert_eq!(mapper.cpu_read(0xC000, 0), Some(14));
+
+        // $9002 on VRC4b is CPU $9001 (A1 -> chip A0, A0 -> chip A1).
+        mapper.cpu_write(0x9001, 0x02, 0);
+        assert_eq!(mapper.cpu_read(0x8000, 0), Some(14));
+        assert_eq!(mapper.cpu_read(0xC000, 0), Some(3));
+
+        // VRC2 has no PRG mode: the same register only sets mirroring.
+        let mut vrc2 = test_mapper(25, 3);
+        vrc2.cpu_write(0x8000, 0x03, 0);
+        vrc2.cpu_write(0x9001, 0x03, 0);
+        assert_eq!(vrc2.cpu_read(0x8000, 0), Some(3));
+        assert_eq!(vrc2.mirroring(), Mirroring::Horizontal);
+    }
+
+    #[test]
+    fn vrc4_irq_prescaler_counts_scanlines() {
+        let mut mapper = test_mapper(21, 1);
+        // Reload $FE: two counter ticks until overflow. Chip $F000/$F001/$F002
+        // are CPU $F000/$F002/$F004 on VRC4a.
+        mapper.cpu_write(0xF000, 0x0E, 0);
+        mapper.cpu_write(0xF002, 0x0F, 0);
+        mapper.cpu_write(0xF004, 0x02, 0);
+
+        let clock = |mapper: &mut Vrc2Vrc4, cycles: u32| {
+            for _ in 0..cycles {
+                mapper.on_mapper_event(MapperEvent::CpuClock {
+                    cpu_cycle: 0,
+                    master_clock: 0,
+                });
+            }
+        };
+        // One scanline is 341 / 3 ≈ 113.67 CPU cycles.
+        clock(&mut mapper, 227);
+        assert!(!mapper.irq_pending());
+        clock(&mut mapper, 2);
+        assert!(mapper.irq_pending());
+    }
+
+    #[test]
+    fn vrc2_latch_stands_in_for_missing_prg_ram() {
+        let mut mapper = test_mapper(23, 3);
+        mapper.cpu_write(0x6000, 0xFF, 0);
+        assert_eq!(mapper.cpu_read(0x6000, 0xF0), Some(0xF1));
+        assert_eq!(mapper.cpu_read(0x7000, 0xF0), None);
     }
 }
@@ -6,7 +6,7 @@ use crate::{
     cartridge::{
         ChrRom, Mapper, PrgRom, TrainerBytes,
         header::{Header, Mirroring},
-        mapper::allocate_prg_ram_with_trainer,
+        mapper::{allocate_prg_ram_with_trainer, has_bus_conflicts},
     },
     memory::cpu as cpu_mem,
 };
@@ -22,6 +22,9 @@ use serde::{Deserialize, Serialize};
 // | CPU  | `$8000-$FFFF`     | Fixed 32 KiB PRG-ROM (mirrored if smaller) | None      |
 // | PPU  | `$0000-$1FFF`     | 8 KiB CHR ROM/RAM, banked via `$8000-$FFFF`| None      |
 // | PPU  | `$2000-$3EFF`     | Mirroring from header (no mapper control)  | None      |
+//
+// With bus conflicts enabled the CHR bank written is the value ANDed with the
+// PRG-ROM byte at the target address; Cybernoid relies on this.
 
 const CHR_BANK_SIZE: usize = 8 * 1024;
 
@@ -34,6 +37,8 @@ pub struct Mapper3 {
     chr_bank: usize,
     chr_bank_count: usize,
     mirroring: Mirroring,
+    /// Register writes see the PRG-ROM byte on the data bus as well.
+    bus_conflicts: bool,
 }
 
 #[cfg_attr(feature = "savestate-serde", derive(Serialize, Deserialize))]
@@ -60,6 +65,7 @@ impl Mapper3 {
             chr_bank: 0,
             chr_bank_count,
             mirroring: header.mirroring(),
+            bus_conflicts: has_bus_conflicts(&header),
         }
     }
 
@@ -155,7 +161,14 @@ impl Mapper for Mapper3 {
     fn cpu_write(&mut self, addr: u16, data: u8, _cpu_cycle: u64) {
         match addr {
             cpu_mem::PRG_RAM_START..=cpu_mem::PRG_RAM_END => self.write_prg_ram(addr, data),
-            cpu_mem::PRG_ROM_START..=cpu_mem::CPU_ADDR_END => self.write_chr_bank(data),
+            cpu_mem::PRG_ROM_START..=cpu_mem::CPU_ADDR_END => {
+                let data = if self.bus_conflicts {
+                    data & self.read_prg_rom(addr)
+                } else {
+                    data
+                };
+                self.write_chr_bank(data)
+            }
             _ => {}
         }
     }
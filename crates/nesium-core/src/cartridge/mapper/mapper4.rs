@@ -5,7 +5,10 @@
 //! - 8 KiB PRG-ROM banking with two switchable windows and two fixed windows.
 //! - Fine‑grained CHR banking using 2 KiB + 1 KiB pages with optional A12
 //!   inversion for better sprite/background layout.
-//! - A scanline IRQ counter driven by PPU A12 rising edges.
+//! - A scanline IRQ counter driven by PPU A12 rising edges. The IRQ quirks
+//!   differ between chip revisions (see [`Mmc3Revision`]); the revision is
+//!   taken from the NES 2.0 submapper or forced with
+//!   [`CartridgeLoader::mmc3_revision`](crate::cartridge::CartridgeLoader::mmc3_revision).
 //! - Mapper‑controlled mirroring and PRG‑RAM enable/write‑protect bits.
 //!
 //! Behaviour is modelled against the Nesdev MMC3 documentation and broadly
@@ -31,13 +34,13 @@ use crate::reset_kind::ResetKind;
 use crate::{
     cartridge::{
         ChrRom, Mapper, PrgRom, TrainerBytes,
-        header::{Header, Mirroring, RomFormat},
+        header::{Header, Mirroring},
         mapper::{
             ChrStorage, MapperEvent, MapperHookMask, PpuVramAccessContext,
             allocate_prg_ram_with_trainer,
             core::mmc3::{
                 MMC3_POWER_ON_BANK_REGS, Mmc3Core, Mmc3CoreResetConfig, Mmc3CpuRegister,
-                Mmc3IrqRevision, Mmc3WriteConfig, Mmc3WriteResult, PRG_BANK_SIZE_8K,
+                Mmc3Revision, Mmc3WriteConfig, Mmc3WriteResult, PRG_BANK_SIZE_8K,
                 resolve_mmc3_chr_bank,
             },
             select_chr_storage,
@@ -90,6 +93,7 @@ pub struct Mapper4State {
     pub irq_enabled: bool,
     pub irq_pending: bool,
     pub a12_low_start_master_clock: Option<u64>,
+    pub irq_prescaler: u8,
 }
 
 impl Mapper4 {
@@ -98,7 +102,7 @@ impl Mapper4 {
 
         let chr = select_chr_storage(&header, chr_rom);
         let prg_bank_count = (prg_rom.len() / PRG_BANK_SIZE_8K).max(1);
-        let irq_revision = detect_mmc3_irq_revision(header);
+        let irq_revision = Mmc3Revision::from_submapper(header.submapper());
         let mmc3_reset = Mmc3CoreResetConfig {
             bank_select: 0x00,
             bank_regs: MMC3_POWER_ON_BANK_REGS,
@@ -135,6 +139,7 @@ impl Mapper4 {
             irq_enabled: self.mmc3.irq_enabled,
             irq_pending: self.mmc3.irq_pending,
             a12_low_start_master_clock: self.mmc3.a12_low_start_master_clock,
+            irq_prescaler: self.mmc3.irq_prescaler,
         }
     }
 
@@ -154,6 +159,7 @@ impl Mapper4 {
         self.mmc3.irq_enabled = state.irq_enabled;
         self.mmc3.irq_pending = state.irq_pending;
         self.mmc3.a12_low_start_master_clock = state.a12_low_start_master_clock;
+        self.mmc3.irq_prescaler = state.irq_prescaler;
     }
 
     /// Returns true when CHR A12 inversion is active (bank select bit7 set).
@@ -310,33 +316,6 @@ impl Mapper for Mapper4 {
     }
 }
 
-fn detect_mmc3_irq_revision(header: Header) -> Mmc3IrqRevision {
-    if let Some(override_revision) = parse_mmc3_irq_revision_override() {
-        return override_revision;
-    }
-
-    // NES 2.0 submapper carries board-level identity that can distinguish
-    // MMC6-style boards. Legacy iNES mapper-4 dumps do not have enough data
-    // to disambiguate reliably, so default to RevB-like behavior.
-    if header.format() == RomFormat::Nes20 && header.submapper() == 1 {
-        Mmc3IrqRevision::RevA
-    } else {
-        Mmc3IrqRevision::RevB
-    }
-}
-
-fn parse_mmc3_irq_revision_override() -> Option<Mmc3IrqRevision> {
-    let value = std::env::var("NESIUM_MMC3_IRQ_REV").ok()?;
-    let normalized = value.trim().to_ascii_uppercase();
-
-    match normalized.as_str() {
-        "A" | "REVA" | "REV_A" | "MMC3A" | "MMC6" | "MMC6_STYLE" => Some(Mmc3IrqRevision::RevA),
-        "B" | "REVB" | "REV_B" | "MMC3B" => Some(Mmc3IrqRevision::RevB),
-        "AUTO" | "" => None,
-        _ => None,
-    }
-}
-
 fn mirroring_to_u8(m: Mirroring) -> u8 {
     match m {
         Mirroring::Horizontal => 0,
@@ -359,3 +338,79 @@ fn mirroring_from_u8(v: u8) -> Mirroring {
         _ => Mirroring::Horizontal,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartridge::{
+        Cartridge, CartridgeLoader,
+        header::NES_HEADER_LEN,
+        mapper::{Mmc3Revision, PpuVramAccessKind, PpuVramAccessSource},
+    };
+
+    /// NES 2.0 mapper 4 image with the given submapper.
+    fn image(submapper: u8) -> Vec<u8> {
+        let mut rom = vec![b'N', b'E', b'S', 0x1A, 2, 1, 0x40, 0x08, submapper << 4];
+        rom.resize(NES_HEADER_LEN + 2 * 16 * 1024 + 8 * 1024, 0);
+        rom
+    }
+
+    fn load(submapper: u8, revision: Option<Mmc3Revision>) -> Cartridge {
+        let mut cart = CartridgeLoader::new()
+            .mmc3_revision(revision)
+            .load(image(submapper))
+            .expect("loads");
+        cart.cpu_write(0xC000, 1, 0); // latch
+        cart.cpu_write(0xC001, 0, 0); // reload
+        cart.cpu_write(0xE001, 0, 0); // enable
+        cart
+    }
+
+    /// Drives A12 high then low; returns the number of pulses until the IRQ.
+    fn pulses_until_irq(cart: &mut Cartridge) -> Option<usize> {
+        for pulse in 1..=32u64 {
+            for (addr, clock) in [(0x1000, pulse * 200), (0x0000, pulse * 200 + 100)] {
+                let ctx = PpuVramAccessContext {
+                    ppu_cycle: clock / 4,
+                    cpu_cycle: clock / 12,
+                    kind: PpuVramAccessKind::RenderingFetch,
+                    source: PpuVramAccessSource::RenderingFetch,
+                    ppu_master_clock: clock,
+                    ppu_scanline: 0,
+                    ppu_dot: 0,
+                    render_fetch: None,
+                };
+                cart.ppu_vram_access(addr, ctx);
+            }
+            if cart.irq_pending() {
+                return Some(pulse as usize);
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn revision_follows_submapper_unless_forced() {
+        assert_eq!(Mmc3Revision::from_submapper(0), Mmc3Revision::Sharp);
+        assert_eq!(Mmc3Revision::from_submapper(1), Mmc3Revision::Nec);
+        assert_eq!(Mmc3Revision::from_submapper(4), Mmc3Revision::Nec);
+        assert_eq!(Mmc3Revision::from_submapper(3), Mmc3Revision::McAcc);
+
+        // The first pulse only establishes A12 low; the next two reload the
+        // counter to 1 and then decrement it to 0.
+        assert_eq!(pulses_until_irq(&mut load(0, None)), Some(3));
+        assert_eq!(
+            pulses_until_irq(&mut load(3, Some(Mmc3Revision::Sharp))),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn mc_acc_clocks_on_every_eighth_a12_fall() {
+        assert_eq!(pulses_until_irq(&mut load(3, None)), Some(16));
+        assert_eq!(
+            pulses_until_irq(&mut load(0, Some(Mmc3Revision::McAcc))),
+            Some(16)
+        );
+    }
+}
@@ -363,13 +363,16 @@ impl Mapper5 {
             return 0;
         }
 
+        self.prg_ram[self.prg_ram_page_index(addr, reg)]
+    }
+
+    /// Offset into `prg_ram` for `addr` in the 8 KiB page selected by `reg`.
+    fn prg_ram_page_index(&self, addr: u16, reg: u8) -> usize {
         // Use low 3 bits as 8 KiB page index (superset mapping from Nesdev).
         let page = (reg & 0x07) as usize;
         let base = page * PRG_BANK_SIZE_8K;
         let offset = (addr as usize) & (PRG_BANK_SIZE_8K - 1);
-        let idx = base + offset;
-        let len = self.prg_ram.len();
-        self.prg_ram[idx % len]
+        (base + offset) % self.prg_ram.len()
     }
 
     /// Write PRG-RAM through a bankswitched window, honoring write protection.
@@ -378,15 +381,8 @@ impl Mapper5 {
             return;
         }
 
-        let page = (reg & 0x07) as usize;
-        let base = page * PRG_BANK_SIZE_8K;
-        let offset = (addr as usize) & (PRG_BANK_SIZE_8K - 1);
-        let idx = base + offset;
-        let len = self.prg_ram.len();
-        if len != 0 {
-            let wrapped = idx % len;
-            self.prg_ram[wrapped] = value;
-        }
+        let idx = self.prg_ram_page_index(addr, reg);
+        self.prg_ram[idx] = value;
     }
 
     /// Helper for PRG-ROM/PRG-RAM switchable windows (modes 1–3).
@@ -1128,6 +1124,10 @@ impl Mapper for Mapper5 {
     fn irq_pending(&self) -> bool {
         self.irq_pending.get() && self.irq_enabled
     }
+    fn prg_ram_offset(&self, addr: u16) -> Option<usize> {
+        (!self.prg_ram.is_empty()).then(|| self.prg_ram_page_index(addr, self.prg_bank_6000_7fff))
+    }
+
     fn memory_ref(&self) -> MapperMemoryRef<'_> {
         MapperMemoryRef {
             prg_rom: Some(self.prg_rom.as_ref()),
@@ -0,0 +1,405 @@
+//! Mapper 682 - Rainbow (Broke Studio), homebrew board with an ESP8266 WiFi
+//! module. Reference: https://www.nesdev.org/wiki/NES_2.0_Mapper_682
+//!
+//! Only the subset homebrew needs to start talking to the network is
+//! modelled:
+//! - `$4100` PRG mode (bits 0-2): 0 = one 32 KiB window, 1 = two 16 KiB,
+//!   2 = 16 KiB + two 8 KiB, 3 = four 8 KiB, 4 = eight 4 KiB windows. Window
+//!   `n` of the 4 KiB grid at `$8000 + n * $1000` takes its bank from
+//!   `$4108+n` (upper bits) and `$4118+n` (lower bits); larger windows use the
+//!   register of their first 4 KiB slot, counted in their own size. The board
+//!   powers on in 32 KiB mode with the last 32 KiB selected. Bit 7 of the
+//!   upper register (PRG RAM instead of ROM) is ignored.
+//! - 8 KiB PRG RAM at `$6000-$7FFF` (unbanked) and unbanked 8 KiB CHR.
+//! - 2 KiB of FPGA RAM at `$4800-$4FFF`, split into eight 256-byte pages that
+//!   hold ESP messages: a length byte followed by that many payload bytes.
+//! - ESP registers: `$4190` enable (bit 0) / IRQ enable (bit 1), `$4191` RX
+//!   status (bit 7 = message waiting, write to acknowledge), `$4192` TX status
+//!   (bit 7 = sent, write to send), `$4193`/`$4194` RX/TX page.
+//!
+//! The ESP itself lives on the host: sent messages queue up for
+//! [`Mapper682::take_outgoing`], and [`Mapper682::push_incoming`] feeds
+//! replies back one at a time as the game acknowledges them.
+
+use std::{borrow::Cow, collections::VecDeque};
+
+use crate::cartridge::mapper::{MapperMemoryMut, MapperMemoryRef};
+
+use crate::{
+    cartridge::{
+        ChrRom, Mapper, PrgRom, TrainerBytes,
+        header::{Header, Mirroring},
+        mapper::{ChrStorage, allocate_prg_ram_with_trainer, select_chr_storage},
+    },
+    memory::cpu as cpu_mem,
+    reset_kind::ResetKind,
+};
+
+const PRG_BANK_SIZE_4K: usize = 4 * 1024;
+const FPGA_RAM_SIZE: usize = 2 * 1024;
+const FPGA_RAM_PAGE_SIZE: usize = 256;
+
+/// CPU `$4100`: PRG banking mode.
+const PRG_MODE: u16 = 0x4100;
+/// CPU `$4108-$410F`: upper bits of the eight PRG ROM bank registers.
+const PRG_BANK_HI_START: u16 = 0x4108;
+const PRG_BANK_HI_END: u16 = 0x410F;
+/// CPU `$4118-$411F`: lower bits of the eight PRG ROM bank registers.
+const PRG_BANK_LO_START: u16 = 0x4118;
+const PRG_BANK_LO_END: u16 = 0x411F;
+/// CPU `$4800-$4FFF`: FPGA RAM window.
+const FPGA_RAM_START: u16 = 0x4800;
+const FPGA_RAM_END: u16 = 0x4FFF;
+
+const ESP_CONFIG: u16 = 0x4190;
+const ESP_RX: u16 = 0x4191;
+const ESP_TX: u16 = 0x4192;
+const ESP_RX_PAGE: u16 = 0x4193;
+const ESP_TX_PAGE: u16 = 0x4194;
+
+const PRG_MODE_MASK: u8 = 0x07;
+/// Bit 7 of an upper bank register selects PRG RAM, which is not modelled.
+const PRG_BANK_HI_MASK: u8 = 0x7F;
+
+const ESP_ENABLE: u8 = 0x01;
+const ESP_IRQ_ENABLE: u8 = 0x02;
+const ESP_STATUS_FLAG: u8 = 0x80;
+
+#[derive(Debug, Clone)]
+pub struct Mapper682 {
+    prg_rom: PrgRom,
+    prg_ram: Box<[u8]>,
+    chr: ChrStorage,
+    mirroring: Mirroring,
+    prg_mode: u8,
+    prg_banks_hi: [u8; 8],
+    prg_banks_lo: [u8; 8],
+    fpga_ram: Box<[u8]>,
+
+    esp_config: u8,
+    rx_page: u8,
+    tx_page: u8,
+    /// A received message sits in the RX page until the game acknowledges it.
+    rx_ready: bool,
+    /// Messages from the host not yet copied into FPGA RAM.
+    inbox: VecDeque<Vec<u8>>,
+    /// Messages sent by the game, waiting for the host to collect them.
+    outbox: VecDeque<Vec<u8>>,
+}
+
+impl Mapper682 {
+    pub fn new(header: Header, prg_rom: PrgRom, chr_rom: ChrRom, trainer: TrainerBytes) -> Self {
+        let prg_ram = allocate_prg_ram_with_trainer(&header, trainer);
+        let chr = select_chr_storage(&header, chr_rom);
+
+        Self {
+            prg_rom,
+            prg_ram,
+            chr,
+            mirroring: header.mirroring(),
+            prg_mode: 0,
+            prg_banks_hi: [0; 8],
+            prg_banks_lo: [0; 8],
+            fpga_ram: vec![0; FPGA_RAM_SIZE].into_boxed_slice(),
+            esp_config: 0,
+            rx_page: 0,
+            tx_page: 0,
+            rx_ready: false,
+            inbox: VecDeque::new(),
+            outbox: VecDeque::new(),
+        }
+    }
+
+    /// Messages the game sent to the ESP since the last call, oldest first.
+    pub fn take_outgoing(&mut self) -> Vec<Vec<u8>> {
+        self.outbox.drain(..).collect()
+    }
+
+    /// Queues a message from the ESP. Messages longer than 255 bytes are
+    /// truncated to fit the length byte.
+    pub fn push_incoming(&mut self, mut message: Vec<u8>) {
+        message.truncate(u8::MAX as usize);
+        self.inbox.push_back(message);
+        self.deliver_next();
+    }
+
+    fn esp_enabled(&self) -> bool {
+        self.esp_config & ESP_ENABLE != 0
+    }
+
+    fn page_offset(page: u8) -> usize {
+        (page as usize % (FPGA_RAM_SIZE / FPGA_RAM_PAGE_SIZE)) * FPGA_RAM_PAGE_SIZE
+    }
+
+    /// Copies the next inbox message into the RX page once the previous one
+    /// has been acknowledged.
+    fn deliver_next(&mut self) {
+        if self.rx_ready || !self.esp_enabled() {
+            return;
+        }
+        let Some(message) = self.inbox.pop_front() else {
+            return;
+        };
+        let base = Self::page_offset(self.rx_page);
+        self.fpga_ram[base] = message.len() as u8;
+        self.fpga_ram[base + 1..base + 1 + message.len()].copy_from_slice(&message);
+        self.rx_ready = true;
+    }
+
+    fn send_tx_page(&mut self) {
+        if !self.esp_enabled() {
+            return;
+        }
+        let base = Self::page_offset(self.tx_page);
+        let len = self.fpga_ram[base] as usize;
+        self.outbox
+            .push_back(self.fpga_ram[base + 1..base + 1 + len].to_vec());
+    }
+
+    fn read_prg_rom(&self, addr: u16) -> u8 {
+        if self.prg_rom.is_empty() {
+            return 0;
+        }
+        // Window size in 4 KiB slots for each mode; unused modes act as 4 KiB.
+        let slots: usize = match self.prg_mode {
+            0 => 8,
+            1 => 4,
+            2 if addr < 0xC000 => 4,
+            2 | 3 => 2,
+            _ => 1,
+        };
+        let window_size = slots * PRG_BANK_SIZE_4K;
+        let rel = (addr - cpu_mem::PRG_ROM_START) as usize;
+        let reg = rel / window_size * slots;
+        let bank = (self.prg_banks_hi[reg] & PRG_BANK_HI_MASK) as usize * 256
+            + self.prg_banks_lo[reg] as usize;
+        let bank_count = (self.prg_rom.len() / window_size).max(1);
+        self.prg_rom
+            .get(bank % bank_count * window_size + rel % window_size)
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+impl Mapper for Mapper682 {
+    fn cpu_read(&self, addr: u16, _open_bus: u8) -> Option<u8> {
+        match addr {
+            PRG_MODE => Some(self.prg_mode),
+            PRG_BANK_HI_START..=PRG_BANK_HI_END => {
+                Some(self.prg_banks_hi[(addr - PRG_BANK_HI_START) as usize])
+            }
+            PRG_BANK_LO_START..=PRG_BANK_LO_END => {
+                Some(self.prg_banks_lo[(addr - PRG_BANK_LO_START) as usize])
+            }
+            ESP_CONFIG => Some(self.esp_config),
+            ESP_RX => Some(if self.rx_ready { ESP_STATUS_FLAG } else { 0 }),
+            // Messages go out immediately, so TX is always done.
+            ESP_TX => Some(ESP_STATUS_FLAG),
+            ESP_RX_PAGE => Some(self.rx_page),
+            ESP_TX_PAGE => Some(self.tx_page),
+            FPGA_RAM_START..=FPGA_RAM_END => {
+                Some(self.fpga_ram[(addr - FPGA_RAM_START) as usize % FPGA_RAM_SIZE])
+            }
+            cpu_mem::PRG_RAM_START..=cpu_mem::PRG_RAM_END if !self.prg_ram.is_empty() => {
+                let offset = (addr - cpu_mem::PRG_RAM_START) as usize % self.prg_ram.len();
+                Some(self.prg_ram[offset])
+            }
+            cpu_mem::PRG_ROM_START..=cpu_mem::CPU_ADDR_END => Some(self.read_prg_rom(addr)),
+            _ => None,
+        }
+    }
+
+    fn cpu_write(&mut self, addr: u16, data: u8, _cpu_cycle: u64) {
+        match addr {
+            PRG_MODE => self.prg_mode = data & PRG_MODE_MASK,
+            PRG_BANK_HI_START..=PRG_BANK_HI_END => {
+                self.prg_banks_hi[(addr - PRG_BANK_HI_START) as usize] = data;
+            }
+            PRG_BANK_LO_START..=PRG_BANK_LO_END => {
+                self.prg_banks_lo[(addr - PRG_BANK_LO_START) as usize] = data;
+            }
+            ESP_CONFIG => {
+                self.esp_config = data & (ESP_ENABLE | ESP_IRQ_ENABLE);
+                self.deliver_next();
+            }
+            ESP_RX => {
+                self.rx_ready = false;
+                self.deliver_next();
+            }
+            ESP_TX => self.send_tx_page(),
+            ESP_RX_PAGE => self.rx_page = data,
+            ESP_TX_PAGE => self.tx_page = data,
+            FPGA_RAM_START..=FPGA_RAM_END => {
+                self.fpga_ram[(addr - FPGA_RAM_START) as usize % FPGA_RAM_SIZE] = data;
+            }
+            cpu_mem::PRG_RAM_START..=cpu_mem::PRG_RAM_END if !self.prg_ram.is_empty() => {
+                let offset = (addr - cpu_mem::PRG_RAM_START) as usize % self.prg_ram.len();
+                self.prg_ram[offset] = data;
+            }
+            _ => {}
+        }
+    }
+
+    fn reset(&mut self, kind: ResetKind) {
+        // 32 KiB mode on the last bank, wherever the ROM ends.
+        self.prg_mode = 0;
+        self.prg_banks_hi = [PRG_BANK_HI_MASK; 8];
+        self.prg_banks_lo = [u8::MAX; 8];
+        self.esp_config = 0;
+        self.rx_ready = false;
+        if matches!(kind, ResetKind::PowerOn) {
+            self.rx_page = 0;
+            self.tx_page = 0;
+            self.inbox.clear();
+            self.outbox.clear();
+        }
+    }
+
+    fn ppu_read(&self, addr: u16) -> Option<u8> {
+        Some(self.chr.read_indexed(0, addr as usize & 0x1FFF))
+    }
+
+    fn ppu_write(&mut self, addr: u16, data: u8) {
+        self.chr.write_indexed(0, addr as usize & 0x1FFF, data);
+    }
+
+    fn irq_pending(&self) -> bool {
+        self.rx_ready && self.esp_config & ESP_IRQ_ENABLE != 0
+    }
+
+    fn memory_ref(&self) -> MapperMemoryRef<'_> {
+        MapperMemoryRef {
+            prg_rom: Some(self.prg_rom.as_ref()),
+            prg_ram: (!self.prg_ram.is_empty()).then_some(self.prg_ram.as_ref()),
+            prg_work_ram: None,
+            mapper_ram: Some(self.fpga_ram.as_ref()),
+            chr_rom: self.chr.as_rom(),
+            chr_ram: self.chr.as_ram(),
+            chr_battery_ram: None,
+        }
+    }
+
+    fn memory_mut(&mut self) -> MapperMemoryMut<'_> {
+        MapperMemoryMut {
+            prg_ram: (!self.prg_ram.is_empty()).then_some(self.prg_ram.as_mut()),
+            prg_work_ram: None,
+            mapper_ram: Some(self.fpga_ram.as_mut()),
+            chr_ram: self.chr.as_ram_mut(),
+            chr_battery_ram: None,
+        }
+    }
+
+    fn mirroring(&self) -> Mirroring {
+        self.mirroring
+    }
+
+    fn mapper_id(&self) -> u16 {
+        682
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("Rainbow")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_mapper() -> Mapper682 {
+        let mut rom = [0u8; 16];
+        rom[0..4].copy_from_slice(b"NES\x1A");
+        rom[4] = 2;
+        rom[6] = 0xA0;
+        rom[7] = 0xA8;
+        rom[8] = 0x02;
+        rom[10] = 0x07;
+        let header = Header::parse(&rom).expect("valid NES 2.0 header");
+        // 32 KiB; each 4 KiB block is filled with its index.
+        let prg: Vec<u8> = (0..8u8)
+            .flat_map(|block| std::iter::repeat_n(block, PRG_BANK_SIZE_4K))
+            .collect();
+        let mut mapper = Mapper682::new(header, prg.into(), Vec::new().into(), None);
+        mapper.reset(ResetKind::PowerOn);
+        mapper
+    }
+
+    #[test]
+    fn powers_on_with_the_last_32k() {
+        let mapper = test_mapper();
+        assert_eq!(mapper.mapper_id(), 682);
+        assert_eq!(mapper.cpu_read(0x8000, 0), Some(0));
+        assert_eq!(mapper.cpu_read(0xFFFF, 0), Some(7));
+    }
+
+    #[test]
+    fn prg_windows_follow_the_banking_mode() {
+        let mut mapper = test_mapper();
+        for reg in 0..8 {
+            mapper.cpu_write(0x4108 + reg, 0, 0);
+            mapper.cpu_write(0x4118 + reg, 0, 0);
+        }
+
+        // Four 8 KiB windows: registers 0/2/4/6, banks in 8 KiB units.
+        mapper.cpu_write(0x4100, 3, 0);
+        mapper.cpu_write(0x411A, 2, 0);
+        mapper.cpu_write(0x411E, 3, 0);
+        assert_eq!(mapper.cpu_read(0x4100, 0), Some(3));
+        assert_eq!(mapper.cpu_read(0xA000, 0), Some(4));
+        assert_eq!(mapper.cpu_read(0xB000, 0), Some(5));
+        assert_eq!(mapper.cpu_read(0xE000, 0), Some(6));
+
+        // 16 KiB + 8 KiB + 8 KiB: register 0 in 16 KiB units.
+        mapper.cpu_write(0x4100, 2, 0);
+        mapper.cpu_write(0x4118, 1, 0);
+        assert_eq!(mapper.cpu_read(0x9000, 0), Some(5));
+        assert_eq!(mapper.cpu_read(0xE000, 0), Some(6));
+
+        // Eight 4 KiB windows, upper and lower bits combined.
+        mapper.cpu_write(0x4100, 4, 0);
+        mapper.cpu_write(0x4109, 1, 0);
+        mapper.cpu_write(0x4119, 2, 0);
+        assert_eq!(mapper.cpu_read(0x9000, 0), Some(2));
+    }
+
+    #[test]
+    fn messages_round_trip_through_fpga_ram() {
+        let mut mapper = test_mapper();
+        mapper.cpu_write(ESP_CONFIG, ESP_ENABLE | ESP_IRQ_ENABLE, 0);
+        mapper.cpu_write(ESP_RX_PAGE, 1, 0);
+        mapper.cpu_write(ESP_TX_PAGE, 2, 0);
+
+        // Game -> host.
+        for (i, byte) in [2, 0x10, 0x20].into_iter().enumerate() {
+            mapper.cpu_write(0x4A00 + i as u16, byte, 0);
+        }
+        mapper.cpu_write(ESP_TX, 0, 0);
+        assert_eq!(mapper.take_outgoing(), vec![vec![0x10, 0x20]]);
+        assert!(mapper.take_outgoing().is_empty());
+
+        // Host -> game, one message at a time.
+        mapper.push_incoming(vec![0xAA]);
+        mapper.push_incoming(vec![0xBB, 0xCC]);
+        assert!(mapper.irq_pending());
+        assert_eq!(mapper.cpu_read(ESP_RX, 0), Some(ESP_STATUS_FLAG));
+        assert_eq!(mapper.cpu_read(0x4900, 0), Some(1));
+        assert_eq!(mapper.cpu_read(0x4901, 0), Some(0xAA));
+
+        mapper.cpu_write(ESP_RX, 0, 0);
+        assert_eq!(mapper.cpu_read(0x4900, 0), Some(2));
+        assert_eq!(mapper.cpu_read(0x4902, 0), Some(0xCC));
+        mapper.cpu_write(ESP_RX, 0, 0);
+        assert!(!mapper.irq_pending());
+        assert_eq!(mapper.cpu_read(ESP_RX, 0), Some(0));
+    }
+
+    #[test]
+    fn disabled_esp_holds_incoming_messages() {
+        let mut mapper = test_mapper();
+        mapper.push_incoming(vec![0x01]);
+        assert_eq!(mapper.cpu_read(ESP_RX, 0), Some(0));
+        mapper.cpu_write(ESP_CONFIG, ESP_ENABLE, 0);
+        assert_eq!(mapper.cpu_read(ESP_RX, 0), Some(ESP_STATUS_FLAG));
+        assert!(!mapper.irq_pending());
+    }
+}
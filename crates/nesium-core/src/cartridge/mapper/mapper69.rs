@@ -122,19 +122,22 @@ impl Mapper69 {
         if self.prg_ram.is_empty() {
             return 0;
         }
-        let base = bank.saturating_mul(PRG_BANK_SIZE_8K);
-        let offset = (addr as usize) & (PRG_BANK_SIZE_8K - 1);
-        self.prg_ram[(base + offset) % self.prg_ram.len()]
+        self.prg_ram[self.prg_ram_bank_offset(bank, addr)]
     }
 
     fn write_prg_ram_bank(&mut self, bank: usize, addr: u16, value: u8) {
         if self.prg_ram.is_empty() {
             return;
         }
+        let index = self.prg_ram_bank_offset(bank, addr);
+        self.prg_ram[index] = value;
+    }
+
+    /// Offset into `prg_ram` for `addr` in 8 KiB bank `bank`.
+    fn prg_ram_bank_offset(&self, bank: usize, addr: u16) -> usize {
         let base = bank.saturating_mul(PRG_BANK_SIZE_8K);
         let offset = (addr as usize) & (PRG_BANK_SIZE_8K - 1);
-        let index = (base + offset) % self.prg_ram.len();
-        self.prg_ram[index] = value;
+        (base + offset) % self.prg_ram.len()
     }
 
     #[inline]
@@ -308,6 +311,14 @@ impl Mapper for Mapper69 {
         Some(self)
     }
 
+    fn prg_ram_offset(&self, addr: u16) -> Option<usize> {
+        if !self.ram_mode() {
+            return None;
+        }
+        let bank = self.prg_ram_bank_index(self.ram_select & 0x3F)?;
+        Some(self.prg_ram_bank_offset(bank, addr))
+    }
+
     fn memory_ref(&self) -> MapperMemoryRef<'_> {
         MapperMemoryRef {
             prg_rom: Some(self.prg_rom.as_ref()),
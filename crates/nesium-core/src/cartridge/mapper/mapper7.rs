@@ -6,7 +6,9 @@ use crate::{
     cartridge::{
         ChrRom, Mapper, PrgRom, TrainerBytes,
         header::{Header, Mirroring},
-        mapper::{ChrStorage, allocate_prg_ram_with_trainer, select_chr_storage},
+        mapper::{
+            ChrStorage, allocate_prg_ram_with_trainer, has_bus_conflicts, select_chr_storage,
+        },
     },
     memory::cpu as cpu_mem,
 };
@@ -19,6 +21,9 @@ use crate::{
 // | CPU  | `$8000-$FFFF`     | 32 KiB switchable PRG-ROM bank (AxROM style)   | None      |
 // | PPU  | `$0000-$1FFF`     | CHR ROM/RAM (no mapper-side CHR banking)       | None      |
 // | PPU  | `$2000-$3EFF`     | Single-screen mirroring (lower/upper via data) | None      |
+//
+// AMROM boards have bus conflicts (ANxROM/AOROM do not); they are emulated
+// when the submapper says so.
 
 const PRG_BANK_SIZE: usize = 32 * 1024;
 
@@ -30,6 +35,8 @@ pub struct Mapper7 {
     selected_bank: usize,
     mirroring: Mirroring,
     bank_count: usize,
+    /// Register writes see the PRG-ROM byte on the data bus as well.
+    bus_conflicts: bool,
 }
 
 impl Mapper7 {
@@ -45,6 +52,7 @@ impl Mapper7 {
             selected_bank: 0,
             mirroring: header.mirroring(),
             bank_count,
+            bus_conflicts: has_bus_conflicts(&header),
         }
     }
 
@@ -107,7 +115,14 @@ impl Mapper for Mapper7 {
     fn cpu_write(&mut self, addr: u16, data: u8, _cpu_cycle: u64) {
         match addr {
             cpu_mem::PRG_RAM_START..=cpu_mem::PRG_RAM_END => self.write_prg_ram(addr, data),
-            cpu_mem::PRG_ROM_START..=cpu_mem::CPU_ADDR_END => self.write_bank_select(data),
+            cpu_mem::PRG_ROM_START..=cpu_mem::CPU_ADDR_END => {
+                let data = if self.bus_conflicts {
+                    data & self.read_prg_rom(addr)
+                } else {
+                    data
+                };
+                self.write_bank_select(data)
+            }
             _ => {}
         }
     }
@@ -8,6 +8,9 @@ use crate::cartridge::{ChrRom, Mapper, PrgRom, TrainerBytes, header::Header};
 /// built-in registry. Returning `None` defers to the core's default
 /// `UnsupportedMapper` error.
 ///
+/// [`MapperRegistry`](super::MapperRegistry) is a ready-made provider that
+/// dispatches on mapper/submapper to constructors registered at runtime.
+///
 /// # Example
 /// ```ignore
 /// use nesium_core::{
@@ -73,6 +76,13 @@ pub trait Provider: Debug + Send {
     /// does not implement.
     fn supports_mapper(&self, mapper_id: u16) -> bool;
 
+    /// Header-level variant of [`supports_mapper`](Self::supports_mapper),
+    /// which is what the loader actually calls. Override it to claim only
+    /// specific submappers and leave the rest to the core.
+    fn supports_header(&self, header: &Header) -> bool {
+        self.supports_mapper(header.mapper())
+    }
+
     fn get_mapper(
         &self,
         header: Header,
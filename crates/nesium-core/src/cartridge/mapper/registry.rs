@@ -0,0 +1,117 @@
+use std::{collections::HashMap, fmt};
+
+use crate::cartridge::{ChrRom, Mapper, PrgRom, Provider, TrainerBytes, header::Header};
+
+/// Constructor registered for a mapper/submapper pair.
+pub type MapperFactory =
+    Box<dyn Fn(Header, PrgRom, ChrRom, TrainerBytes<'_>) -> Box<dyn Mapper> + Send>;
+
+/// [`Provider`] that dispatches to mapper constructors registered at runtime.
+///
+/// External crates register boards keyed by mapper ID and, optionally, NES 2.0
+/// submapper. A submapper-specific entry wins over a catch-all entry for the
+/// same mapper ID. Registered boards take precedence over the core's built-in
+/// implementation of the same ID, so the registry can also be used to swap in
+/// an experimental version of an existing board.
+///
+/// # Example
+/// ```ignore
+/// use nesium_core::{Nes, cartridge::MapperRegistry};
+///
+/// let mut registry = MapperRegistry::new();
+/// registry.register(1234, |header, prg_rom, chr_rom, trainer| {
+///     Box::new(MyBoard::new(header, prg_rom, chr_rom, trainer))
+/// });
+/// // Only used when the header carries submapper 2.
+/// registry.register_submapper(4, 2, |header, prg_rom, chr_rom, trainer| {
+///     Box::new(MyMmc3Variant::new(header, prg_rom, chr_rom, trainer))
+/// });
+///
+/// let mut nes = Nes::default();
+/// nes.set_mapper_provider(Some(Box::new(registry)));
+/// ```
+#[derive(Default)]
+pub struct MapperRegistry {
+    /// `None` submapper entries match any submapper of that mapper ID.
+    factories: HashMap<(u16, Option<u8>), MapperFactory>,
+}
+
+impl MapperRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `factory` for every submapper of `mapper_id`, replacing any
+    /// previous catch-all entry for that ID.
+    pub fn register<F>(&mut self, mapper_id: u16, factory: F) -> &mut Self
+    where
+        F: Fn(Header, PrgRom, ChrRom, TrainerBytes<'_>) -> Box<dyn Mapper> + Send + 'static,
+    {
+        self.factories.insert((mapper_id, None), Box::new(factory));
+        self
+    }
+
+    /// Registers `factory` for one `mapper_id`/`submapper` pair, replacing any
+    /// previous entry for that pair.
+    pub fn register_submapper<F>(&mut self, mapper_id: u16, submapper: u8, factory: F) -> &mut Self
+    where
+        F: Fn(Header, PrgRom, ChrRom, TrainerBytes<'_>) -> Box<dyn Mapper> + Send + 'static,
+    {
+        self.factories
+            .insert((mapper_id, Some(submapper)), Box::new(factory));
+        self
+    }
+
+    /// Removes the entry for `mapper_id` and `submapper` (`None` for the
+    /// catch-all entry). Returns `true` when something was registered.
+    pub fn unregister(&mut self, mapper_id: u16, submapper: Option<u8>) -> bool {
+        self.factories.remove(&(mapper_id, submapper)).is_some()
+    }
+
+    /// Returns `true` when no boards are registered.
+    pub fn is_empty(&self) -> bool {
+        self.factories.is_empty()
+    }
+
+    /// Registered `(mapper, submapper)` keys, sorted.
+    pub fn entries(&self) -> Vec<(u16, Option<u8>)> {
+        let mut keys: Vec<_> = self.factories.keys().copied().collect();
+        keys.sort_unstable();
+        keys
+    }
+
+    fn factory(&self, mapper_id: u16, submapper: u8) -> Option<&MapperFactory> {
+        self.factories
+            .get(&(mapper_id, Some(submapper)))
+            .or_else(|| self.factories.get(&(mapper_id, None)))
+    }
+}
+
+impl fmt::Debug for MapperRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapperRegistry")
+            .field("entries", &self.entries())
+            .finish()
+    }
+}
+
+impl Provider for MapperRegistry {
+    fn supports_mapper(&self, mapper_id: u16) -> bool {
+        self.factories.keys().any(|&(id, _)| id == mapper_id)
+    }
+
+    fn supports_header(&self, header: &Header) -> bool {
+        self.factory(header.mapper(), header.submapper()).is_some()
+    }
+
+    fn get_mapper(
+        &self,
+        header: Header,
+        prg_rom: PrgRom,
+        chr_rom: ChrRom,
+        trainer: TrainerBytes,
+    ) -> Option<Box<dyn Mapper>> {
+        let factory = self.factory(header.mapper(), header.submapper())?;
+        Some(factory(header, prg_rom, chr_rom, trainer))
+    }
+}
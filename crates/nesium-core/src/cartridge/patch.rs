@@ -0,0 +1,97 @@
+//! Soft-patching of ROM images.
+//!
+//! Only the IPS format is supported: a `PATCH` magic, then records of a
+//! 24-bit big-endian offset and a 16-bit length followed by that many bytes
+//! (or, for length 0, a 16-bit run length and a fill byte), terminated by
+//! `EOF` and an optional 24-bit truncation size.
+
+use crate::error::Error;
+
+const IPS_MAGIC: &[u8] = b"PATCH";
+const IPS_EOF: &[u8] = b"EOF";
+
+/// Applies an IPS patch to `rom` in place, growing it when records write past
+/// the end.
+pub fn apply_ips(rom: &mut Vec<u8>, patch: &[u8]) -> Result<(), Error> {
+    let mut rest = patch.strip_prefix(IPS_MAGIC).ok_or(Error::InvalidPatch {
+        reason: "missing PATCH header",
+    })?;
+
+    loop {
+        let (offset, tail) = take(rest, 3)?;
+        if offset == IPS_EOF {
+            if let Ok((size, _)) = take(tail, 3) {
+                rom.truncate(be(size));
+            }
+            return Ok(());
+        }
+        let offset = be(offset);
+        let (len, tail) = take(tail, 2)?;
+        let (data, tail): (Vec<u8>, _) = match be(len) {
+            0 => {
+                let (run, tail) = take(tail, 2)?;
+                let (fill, tail) = take(tail, 1)?;
+                (vec![fill[0]; be(run)], tail)
+            }
+            len => {
+                let (data, tail) = take(tail, len)?;
+                (data.to_vec(), tail)
+            }
+        };
+
+        let end = offset + data.len();
+        if rom.len() < end {
+            rom.resize(end, 0);
+        }
+        rom[offset..end].copy_from_slice(&data);
+        rest = tail;
+    }
+}
+
+fn take(bytes: &[u8], n: usize) -> Result<(&[u8], &[u8]), Error> {
+    if bytes.len() < n {
+        return Err(Error::InvalidPatch {
+            reason: "truncated record",
+        });
+    }
+    Ok(bytes.split_at(n))
+}
+
+fn be(bytes: &[u8]) -> usize {
+    bytes.iter().fold(0, |acc, &b| acc << 8 | b as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_plain_rle_and_growing_records() {
+        let mut rom = vec![0u8; 8];
+        let mut patch = b"PATCH".to_vec();
+        patch.extend_from_slice(&[0, 0, 1, 0, 2, 0xAA, 0xBB]); // plain
+        patch.extend_from_slice(&[0, 0, 4, 0, 0, 0, 3, 0x11]); // RLE
+        patch.extend_from_slice(&[0, 0, 9, 0, 1, 0x99]); // past the end
+        patch.extend_from_slice(b"EOF");
+
+        apply_ips(&mut rom, &patch).expect("valid patch");
+        assert_eq!(rom, [0, 0xAA, 0xBB, 0, 0x11, 0x11, 0x11, 0, 0, 0x99]);
+
+        patch.extend_from_slice(&[0, 0, 2]);
+        apply_ips(&mut rom, &patch).expect("valid patch");
+        assert_eq!(rom, [0, 0xAA]);
+    }
+
+    #[test]
+    fn rejects_bad_magic_and_truncation() {
+        let mut rom = vec![0u8; 4];
+        assert!(matches!(
+            apply_ips(&mut rom, b"PATCX"),
+            Err(Error::InvalidPatch { .. })
+        ));
+        assert!(matches!(
+            apply_ips(&mut rom, b"PATCH\x00\x00\x01\x00\x04\x01"),
+            Err(Error::InvalidPatch { .. })
+        ));
+    }
+}
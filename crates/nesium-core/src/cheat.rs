@@ -0,0 +1,452 @@
+//! RAM cheats and a RAM search engine for building cheat finders.
+//!
+//! Cheats target the CPU address space and are limited to writable RAM: the
+//! internal 2 KiB (`$0000-$1FFF`, mirrored) and cartridge work RAM
+//! (`$6000-$7FFF`). They are applied by [`Nes`](crate::Nes) at every frame
+//! boundary, the same point FCEUX applies its periodic RAM cheats.
+//!
+//! [`RamSearch`] is independent of the console: frontends feed it snapshots
+//! taken with [`Nes::peek_cpu_slice`](crate::Nes::peek_cpu_slice) and narrow
+//! the candidate list with successive filters.
+
+use crate::memory::cpu as cpu_mem;
+
+/// How a cheat is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CheatKind {
+    /// Rewrites the value at every frame boundary.
+    #[default]
+    Freeze,
+    /// Writes the value once at the next frame boundary, then disables itself.
+    Write,
+}
+
+/// A single-byte RAM cheat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cheat {
+    pub address: u16,
+    pub value: u8,
+    /// When set, the cheat only applies while memory currently holds this value.
+    pub compare: Option<u8>,
+    pub kind: CheatKind,
+    pub enabled: bool,
+}
+
+impl Cheat {
+    /// Enabled freeze cheat without a compare value.
+    pub fn freeze(address: u16, value: u8) -> Self {
+        Self {
+            address,
+            value,
+            compare: None,
+            kind: CheatKind::Freeze,
+            enabled: true,
+        }
+    }
+
+    /// Enabled one-shot write cheat without a compare value.
+    pub fn write(address: u16, value: u8) -> Self {
+        Self {
+            kind: CheatKind::Write,
+            ..Self::freeze(address, value)
+        }
+    }
+
+    /// Returns `true` when `address` falls in RAM that cheats may patch.
+    pub fn is_ram_address(address: u16) -> bool {
+        address <= cpu_mem::INTERNAL_RAM_MIRROR_END
+            || (cpu_mem::PRG_RAM_START..=cpu_mem::PRG_RAM_END).contains(&address)
+    }
+}
+
+/// Memory the cheat engine patches.
+pub(crate) trait CheatTarget {
+    fn read(&self, addr: u16) -> u8;
+    fn write(&mut self, addr: u16, value: u8);
+}
+
+/// Handle returned by [`CheatEngine::add`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CheatId(u32);
+
+/// Ordered set of RAM cheats owned by the console.
+#[derive(Debug, Clone, Default)]
+pub struct CheatEngine {
+    cheats: Vec<(CheatId, Cheat)>,
+    next_id: u32,
+}
+
+impl CheatEngine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a cheat, or returns `None` when its address is not RAM.
+    pub fn add(&mut self, cheat: Cheat) -> Option<CheatId> {
+        if !Cheat::is_ram_address(cheat.address) {
+            return None;
+        }
+        let id = CheatId(self.next_id);
+        self.next_id = self.next_id.wrapping_add(1);
+        self.cheats.push((id, cheat));
+        Some(id)
+    }
+
+    pub fn remove(&mut self, id: CheatId) -> Option<Cheat> {
+        let pos = self.cheats.iter().position(|(cid, _)| *cid == id)?;
+        Some(self.cheats.remove(pos).1)
+    }
+
+    pub fn get(&self, id: CheatId) -> Option<&Cheat> {
+        self.cheats
+            .iter()
+            .find(|(cid, _)| *cid == id)
+            .map(|(_, cheat)| cheat)
+    }
+
+    /// Enables or disables a cheat. Returns `false` for unknown ids.
+    pub fn set_enabled(&mut self, id: CheatId, enabled: bool) -> bool {
+        match self.cheats.iter_mut().find(|(cid, _)| *cid == id) {
+            Some((_, cheat)) => {
+                cheat.enabled = enabled;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.cheats.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cheats.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (CheatId, &Cheat)> {
+        self.cheats.iter().map(|(id, cheat)| (*id, cheat))
+    }
+
+    /// Applies enabled cheats to `target` and retires fired one-shot writes.
+    pub(crate) fn apply(&mut self, target: &mut impl CheatTarget) {
+        for (_, cheat) in self.cheats.iter_mut().filter(|(_, c)| c.enabled) {
+            if cheat
+                .compare
+                .is_some_and(|cmp| target.read(cheat.address) != cmp)
+            {
+                continue;
+            }
+            target.write(cheat.address, cheat.value);
+            if cheat.kind == CheatKind::Write {
+                cheat.enabled = false;
+            }
+        }
+    }
+}
+
+/// Width of the values compared by [`RamSearch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DataSize {
+    #[default]
+    Byte,
+    /// Little-endian 16-bit word.
+    Word,
+    /// Little-endian 32-bit double word.
+    DWord,
+}
+
+impl DataSize {
+    pub fn bytes(self) -> usize {
+        match self {
+            DataSize::Byte => 1,
+            DataSize::Word => 2,
+            DataSize::DWord => 4,
+        }
+    }
+
+    fn read(self, memory: &[u8], offset: usize) -> u32 {
+        memory[offset..offset + self.bytes()]
+            .iter()
+            .rev()
+            .fold(0, |acc, &b| (acc << 8) | b as u32)
+    }
+}
+
+/// Relation a candidate's current value must satisfy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    Greater,
+    LessOrEqual,
+    GreaterOrEqual,
+    /// Differs from the reference by exactly this amount (wrapping, either direction).
+    DifferentBy(u32),
+}
+
+/// Right-hand side of a search filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchTarget {
+    /// Each candidate's value from the previous snapshot.
+    Previous,
+    /// A fixed value.
+    Value(u32),
+}
+
+/// One surviving search result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SearchCandidate {
+    pub address: u16,
+    pub previous: u32,
+    pub current: u32,
+    /// Number of times the value changed across filtered snapshots.
+    pub change_count: u32,
+}
+
+/// Incremental RAM search over snapshots of a CPU address range.
+#[derive(Debug, Clone)]
+pub struct RamSearch {
+    base: u16,
+    size: DataSize,
+    signed: bool,
+    previous: Vec<u8>,
+    current: Vec<u8>,
+    candidates: Vec<Candidate>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Candidate {
+    offset: usize,
+    change_count: u32,
+}
+
+impl RamSearch {
+    /// Starts a search where every offset in `snapshot` is a candidate.
+    ///
+    /// `base` is the CPU address of `snapshot[0]`.
+    pub fn new(base: u16, snapshot: &[u8], size: DataSize) -> Self {
+        let mut search = Self {
+            base,
+            size,
+            signed: false,
+            previous: Vec::new(),
+            current: Vec::new(),
+            candidates: Vec::new(),
+        };
+        search.reset(snapshot);
+        search
+    }
+
+    /// Restarts the search from `snapshot`, keeping base and data size.
+    pub fn reset(&mut self, snapshot: &[u8]) {
+        self.previous = snapshot.to_vec();
+        self.current = snapshot.to_vec();
+        let width = self.size.bytes();
+        self.candidates = (0..snapshot.len().saturating_sub(width - 1))
+            .map(|offset| Candidate {
+                offset,
+                change_count: 0,
+            })
+            .collect();
+    }
+
+    /// Changes the data size; candidates too close to the end are dropped.
+    pub fn set_data_size(&mut self, size: DataSize) {
+        self.size = size;
+        let limit = self.current.len().saturating_sub(size.bytes() - 1);
+        self.candidates.retain(|c| c.offset < limit);
+    }
+
+    pub fn data_size(&self) -> DataSize {
+        self.size
+    }
+
+    /// Treats values as two's complement for ordered comparisons.
+    pub fn set_signed(&mut self, signed: bool) {
+        self.signed = signed;
+    }
+
+    /// Takes a new snapshot and keeps candidates whose value satisfies `cmp` against `target`.
+    ///
+    /// `snapshot` must cover the same range as the one the search started with.
+    pub fn filter(&mut self, snapshot: &[u8], cmp: Comparison, target: SearchTarget) {
+        assert_eq!(
+            snapshot.len(),
+            self.current.len(),
+            "RAM search snapshot length changed"
+        );
+        std::mem::swap(&mut self.previous, &mut self.current);
+        self.current.copy_from_slice(snapshot);
+
+        let size = self.size;
+        let signed = self.signed;
+        let (previous, current) = (&self.previous, &self.current);
+        self.candidates.retain_mut(|c| {
+            let old = size.read(previous, c.offset);
+            let new = size.read(current, c.offset);
+            if old != new {
+                c.change_count = c.change_count.saturating_add(1);
+            }
+            let reference = match target {
+                SearchTarget::Previous => old,
+                SearchTarget::Value(v) => v,
+            };
+            compare(size, signed, new, cmp, reference)
+        });
+    }
+
+    /// Drops a single address from the candidate list.
+    pub fn exclude(&mut self, address: u16) {
+        let offset = address.wrapping_sub(self.base) as usize;
+        self.candidates.retain(|c| c.offset != offset);
+    }
+
+    pub fn len(&self) -> usize {
+        self.candidates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.candidates.is_empty()
+    }
+
+    pub fn candidates(&self) -> impl Iterator<Item = SearchCandidate> + '_ {
+        self.candidates.iter().map(|c| SearchCandidate {
+            address: self.base.wrapping_add(c.offset as u16),
+            previous: self.size.read(&self.previous, c.offset),
+            current: self.size.read(&self.current, c.offset),
+            change_count: c.change_count,
+        })
+    }
+}
+
+fn compare(size: DataSize, signed: bool, value: u32, cmp: Comparison, reference: u32) -> bool {
+    let bits = size.bytes() as u32 * 8;
+    let mask = u32::MAX >> (32 - bits);
+    let (value, reference) = (value & mask, reference & mask);
+    let ordering = if signed {
+        let shift = 32 - bits;
+        let a = ((value << shift) as i32) >> shift;
+        let b = ((reference << shift) as i32) >> shift;
+        a.cmp(&b)
+    } else {
+        value.cmp(&reference)
+    };
+    match cmp {
+        Comparison::Equal => ordering.is_eq(),
+        Comparison::NotEqual => ordering.is_ne(),
+        Comparison::Less => ordering.is_lt(),
+        Comparison::Greater => ordering.is_gt(),
+        Comparison::LessOrEqual => ordering.is_le(),
+        Comparison::GreaterOrEqual => ordering.is_ge(),
+        Comparison::DifferentBy(delta) => {
+            let delta = delta & mask;
+            value == reference.wrapping_add(delta) & mask
+                || value == reference.wrapping_sub(delta) & mask
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Ram(Vec<u8>);
+
+    impl CheatTarget for Ram {
+        fn read(&self, addr: u16) -> u8 {
+            self.0[addr as usize]
+        }
+
+        fn write(&mut self, addr: u16, value: u8) {
+            self.0[addr as usize] = value;
+        }
+    }
+
+    #[test]
+    fn freeze_reapplies_and_write_fires_once() {
+        let mut engine = CheatEngine::new();
+        let mut ram = Ram(vec![0; 4]);
+        engine
+            .add(Cheat::freeze(0x0001, 0x63))
+            .expect("RAM address");
+        engine.add(Cheat::write(0x0002, 0x10)).expect("RAM address");
+        assert!(engine.add(Cheat::freeze(0x2000, 0)).is_none());
+
+        engine.apply(&mut ram);
+        assert_eq!(ram.0, [0, 0x63, 0x10, 0]);
+
+        ram.0.fill(0);
+        engine.apply(&mut ram);
+        assert_eq!(ram.0, [0, 0x63, 0, 0]);
+    }
+
+    #[test]
+    fn compare_value_gates_cheat() {
+        let mut engine = CheatEngine::new();
+        let id = engine
+            .add(Cheat {
+                compare: Some(5),
+                ..Cheat::freeze(0x0000, 9)
+            })
+            .expect("RAM address");
+        let mut ram = Ram(vec![4]);
+        engine.apply(&mut ram);
+        assert_eq!(ram.0[0], 4);
+        ram.0[0] = 5;
+        engine.apply(&mut ram);
+        assert_eq!(ram.0[0], 9);
+
+        assert!(engine.set_enabled(id, false));
+        assert!(engine.remove(id).is_some());
+        assert!(engine.is_empty());
+    }
+
+    #[test]
+    fn search_narrows_by_previous_and_value() {
+        let mut search = RamSearch::new(0x0300, &[3, 3, 7, 1], DataSize::Byte);
+        assert_eq!(search.len(), 4);
+
+        search.filter(&[2, 3, 8, 1], Comparison::NotEqual, SearchTarget::Previous);
+        let addrs: Vec<u16> = search.candidates().map(|c| c.address).collect();
+        assert_eq!(addrs, [0x0300, 0x0302]);
+
+        search.filter(&[1, 3, 9, 1], Comparison::Less, SearchTarget::Previous);
+        let hit: Vec<_> = search.candidates().collect();
+        assert_eq!(hit.len(), 1);
+        assert_eq!(hit[0].address, 0x0300);
+        assert_eq!((hit[0].previous, hit[0].current), (2, 1));
+        assert_eq!(hit[0].change_count, 2);
+
+        search.reset(&[0x34, 0x12, 0, 0]);
+        search.set_data_size(DataSize::Word);
+        assert_eq!(search.len(), 3);
+        search.filter(
+            &[0x34, 0x12, 0, 0],
+            Comparison::Equal,
+            SearchTarget::Value(0x1234),
+        );
+        assert_eq!(
+            search.candidates().next().expect("candidate").address,
+            0x0300
+        );
+        assert_eq!(search.len(), 1);
+    }
+
+    #[test]
+    fn signed_and_different_by_comparisons() {
+        let mut search = RamSearch::new(0, &[0x01, 0xFF], DataSize::Byte);
+        search.set_signed(true);
+        search.filter(&[0x01, 0xFF], Comparison::Less, SearchTarget::Value(0));
+        assert_eq!(search.candidates().next().expect("candidate").address, 1);
+
+        let mut search = RamSearch::new(0, &[0xFF, 10], DataSize::Byte);
+        search.filter(
+            &[0x01, 13],
+            Comparison::DifferentBy(2),
+            SearchTarget::Previous,
+        );
+        let addrs: Vec<u16> = search.candidates().map(|c| c.address).collect();
+        assert_eq!(addrs, [0]);
+    }
+}
@@ -1 +1,3 @@
+pub mod overclock;
+pub mod ram_init;
 pub mod region;
@@ -0,0 +1,38 @@
+/// Mesen-style overclocking through extra idle scanlines.
+///
+/// The PPU repeats the post-render line `extra_scanlines_before_nmi` times
+/// before vblank starts, and the last vblank line `extra_scanlines_after_nmi`
+/// times before the prerender line. The CPU keeps running during these lines
+/// while the APU and expansion audio are paused, so a game gets more CPU time
+/// per frame without the music changing speed or pitch.
+///
+/// Lines before NMI help games that run their logic in the main loop; lines
+/// after NMI help games that do the work in the NMI handler. Neither exists on
+/// hardware, so code that counts CPU cycles across a frame (e.g. some raster
+/// effects or timing loops) may behave differently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Overclock {
+    pub extra_scanlines_before_nmi: u16,
+    pub extra_scanlines_after_nmi: u16,
+}
+
+impl Overclock {
+    /// Upper bound for each field (matches Mesen2's settings range).
+    pub const MAX_EXTRA_SCANLINES: u16 = 1000;
+
+    pub fn is_enabled(&self) -> bool {
+        self.extra_scanlines_before_nmi != 0 || self.extra_scanlines_after_nmi != 0
+    }
+
+    /// Returns a copy with both fields limited to [`Self::MAX_EXTRA_SCANLINES`].
+    pub fn clamped(self) -> Self {
+        Self {
+            extra_scanlines_before_nmi: self
+                .extra_scanlines_before_nmi
+                .min(Self::MAX_EXTRA_SCANLINES),
+            extra_scanlines_after_nmi: self
+                .extra_scanlines_after_nmi
+                .min(Self::MAX_EXTRA_SCANLINES),
+        }
+    }
+}
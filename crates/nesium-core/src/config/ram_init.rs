@@ -0,0 +1,58 @@
+use crate::rng::SplitMix64;
+
+/// Contents of the 2 KiB CPU RAM after a power cycle.
+///
+/// Real consoles power on with whatever the SRAM settles to, which varies by
+/// chip and temperature. Most games clear RAM before using it, but some read
+/// uninitialised memory (often by accident) and a few test ROMs check for a
+/// particular pattern. Soft resets never touch RAM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RamInit {
+    /// All bytes `$00`.
+    #[default]
+    Zeros,
+    /// All bytes `$FF`.
+    Ones,
+    /// Alternating runs of four `$00` and four `$FF` bytes, as FCEUX does.
+    Stripes,
+    /// Pseudo-random bytes. The same seed always produces the same contents,
+    /// so this stays reproducible for movies and netplay.
+    Random { seed: u64 },
+}
+
+impl RamInit {
+    pub fn fill(self, ram: &mut [u8]) {
+        match self {
+            RamInit::Zeros => ram.fill(0x00),
+            RamInit::Ones => ram.fill(0xFF),
+            RamInit::Stripes => {
+                for (i, byte) in ram.iter_mut().enumerate() {
+                    *byte = if i & 4 == 0 { 0x00 } else { 0xFF };
+                }
+            }
+            RamInit::Random { seed } => SplitMix64::new(seed).fill_bytes(ram),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RamInit;
+
+    #[test]
+    fn fills_patterns() {
+        let mut ram = [0x55; 16];
+        RamInit::Stripes.fill(&mut ram);
+        assert_eq!(ram[..9], [0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF, 0]);
+
+        RamInit::Ones.fill(&mut ram);
+        assert!(ram.iter().all(|&b| b == 0xFF));
+
+        let mut other = [0; 16];
+        RamInit::Random { seed: 7 }.fill(&mut ram);
+        RamInit::Random { seed: 7 }.fill(&mut other);
+        assert_eq!(ram, other);
+        RamInit::Random { seed: 8 }.fill(&mut other);
+        assert_ne!(ram, other);
+    }
+}
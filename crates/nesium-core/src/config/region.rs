@@ -19,6 +19,7 @@ pub enum Region {
     /// Dendy-style hybrid timing used by some Famiclones.
     Dendy,
     /// Japanese Famicom NTSC timing (used to split out JP-specific quirks).
+    /// Also selects Famicom controller port behavior during DMA.
     NtscJp,
 }
 
@@ -1,6 +1,17 @@
 //! Minimal NES controller (joypad) model.
 //!
-//! Implements the standard 8-button pad readable through `$4016/$4017`.
+//! Implements the standard 8-button pad readable through `$4016/$4017`, plus
+//! the [`Zapper`] light gun which can replace a pad on either port and the
+//! [`Multitap`] adapters that expose pads 3 and 4. [`ButtonRemap`] tables
+//! translate player input before it reaches the pads.
+
+mod multitap;
+mod remap;
+mod zapper;
+
+pub use multitap::{Multitap, MultitapKind};
+pub use remap::ButtonRemap;
+pub use zapper::{Zapper, ZapperCalibration, ZapperTiming};
 
 use crate::mem_block::MemBlock;
 
@@ -140,9 +151,14 @@ mod tests {
     }
 }
 
-/// Two NES controller ports backed by a `MemBlock`, enabling boxed or stack
-/// allocation depending on the active feature set.
-pub type ControllerPorts = MemBlock<Controller, 2>;
+/// Four NES pads backed by a `MemBlock`, enabling boxed or stack allocation
+/// depending on the active feature set. Pads 3 and 4 are only visible to the
+/// console through a [`Multitap`].
+pub type ControllerPorts = MemBlock<Controller, 4>;
+
+/// Zapper attached to each port, if any. A connected Zapper takes over the
+/// port's data bits from the standard pad.
+pub type ZapperPorts = [Option<Zapper>; 2];
 
 /// Captures the serial stream some blargg test ROMs emit via `$4016` writes.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
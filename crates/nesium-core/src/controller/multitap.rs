@@ -0,0 +1,141 @@
+//! Four-player adapters.
+//!
+//! Both adapters chain two pads behind each port and append an 8-bit
+//! signature so games can detect them: every port read returns 8 bits of the
+//! first pad, 8 bits of the second pad, then the signature (24 reads total).
+//!
+//! - NES Four Score: data on D0; `$4016` chains pads 1+3, `$4017` pads 2+4.
+//! - Hori 4 Players Adapter (Famicom expansion port): same layout on D1 with
+//!   different signatures. D0 keeps serving the console's own pads.
+
+use super::Controller;
+
+#[cfg_attr(
+    feature = "savestate-serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultitapKind {
+    FourScore,
+    Hori,
+}
+
+impl MultitapKind {
+    /// Signature bits shifted out after the two pads, indexed by port.
+    fn signatures(self) -> [u8; 2] {
+        match self {
+            MultitapKind::FourScore => [0x08, 0x04],
+            MultitapKind::Hori => [0x20, 0x10],
+        }
+    }
+
+    /// Data line the adapter drives on `$4016/$4017`.
+    fn data_shift(self) -> u8 {
+        match self {
+            MultitapKind::FourScore => 0,
+            MultitapKind::Hori => 1,
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "savestate-serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Multitap {
+    kind: MultitapKind,
+    strobe: bool,
+    shift: [u32; 2],
+}
+
+impl Multitap {
+    pub fn new(kind: MultitapKind) -> Self {
+        Self {
+            kind,
+            strobe: false,
+            shift: [0; 2],
+        }
+    }
+
+    pub fn kind(&self) -> MultitapKind {
+        self.kind
+    }
+
+    /// Handles a `$4016` write; the shift registers reload while strobe is high.
+    pub(crate) fn write_strobe(&mut self, data: u8, pads: &[Controller]) {
+        self.strobe = (data & 0x01) != 0;
+        if self.strobe {
+            self.reload(pads);
+        }
+    }
+
+    /// Returns the adapter's contribution to a read of `port` (0 = `$4016`).
+    pub(crate) fn read(&mut self, port: usize, pads: &[Controller]) -> u8 {
+        if self.strobe {
+            self.reload(pads);
+        }
+        let bit = (self.shift[port] & 0x01) as u8;
+        self.shift[port] >>= 1;
+        bit << self.kind.data_shift()
+    }
+
+    /// Whether D0 still carries the console's standard pad for this adapter.
+    pub(crate) fn passes_standard_pads(&self) -> bool {
+        self.kind == MultitapKind::Hori
+    }
+
+    fn reload(&mut self, pads: &[Controller]) {
+        let state = |i: usize| pads.get(i).map_or(0, |c| c.state()) as u32;
+        for (port, signature) in self.kind.signatures().into_iter().enumerate() {
+            self.shift[port] = (signature as u32) << 16 | state(port + 2) << 8 | state(port);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Multitap, MultitapKind};
+    use crate::controller::Controller;
+
+    fn pads(states: [u8; 4]) -> [Controller; 4] {
+        states.map(|s| {
+            let mut c = Controller::new();
+            c.set_state(s);
+            c
+        })
+    }
+
+    fn read_bits(tap: &mut Multitap, port: usize, pads: &[Controller], n: usize) -> u32 {
+        (0..n).fold(0, |acc, i| {
+            acc | ((tap.read(port, pads) >> tap.kind.data_shift()) as u32 & 1) << i
+        })
+    }
+
+    #[test]
+    fn four_score_chains_pads_and_signature() {
+        let pads = pads([0x01, 0x02, 0x81, 0x40]);
+        let mut tap = Multitap::new(MultitapKind::FourScore);
+        tap.write_strobe(1, &pads);
+        tap.write_strobe(0, &pads);
+
+        assert_eq!(read_bits(&mut tap, 0, &pads, 24), 0x08_81_01);
+        assert_eq!(read_bits(&mut tap, 1, &pads, 24), 0x04_40_02);
+        assert_eq!(tap.read(0, &pads), 0);
+    }
+
+    #[test]
+    fn hori_uses_d1_and_its_own_signatures() {
+        let pads = pads([0x00, 0x00, 0x10, 0x00]);
+        let mut tap = Multitap::new(MultitapKind::Hori);
+        tap.write_strobe(1, &pads);
+        tap.write_strobe(0, &pads);
+
+        for _ in 0..12 {
+            assert_eq!(tap.read(0, &pads) & 0x01, 0);
+        }
+        // Bit 4 of pad 3 lands on read 13, on D1.
+        assert_eq!(tap.read(0, &pads), 0x02);
+        assert_eq!(read_bits(&mut tap, 1, &pads, 24), 0x10_00_00);
+    }
+}
@@ -0,0 +1,97 @@
+//! Per-pad button remapping.
+//!
+//! A [`ButtonRemap`] is a permutation from the buttons the player presses
+//! (logical) to the buttons the console latches. It is applied when input
+//! enters the core, so anything that records what the console saw (movies,
+//! netplay input frames) stays valid regardless of the local remap.
+
+use super::Button;
+
+const BUTTONS: [Button; 8] = [
+    Button::A,
+    Button::B,
+    Button::Select,
+    Button::Start,
+    Button::Up,
+    Button::Down,
+    Button::Left,
+    Button::Right,
+];
+
+#[cfg_attr(
+    feature = "savestate-serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ButtonRemap {
+    /// Console button for each logical button, indexed by `Button as usize`.
+    targets: [Button; 8],
+}
+
+impl ButtonRemap {
+    pub const IDENTITY: Self = Self { targets: BUTTONS };
+
+    /// A↔B swap (common for pads whose face buttons are mirrored).
+    pub fn swap_ab() -> Self {
+        Self::IDENTITY.with_swap(Button::A, Button::B)
+    }
+
+    /// Select↔Start swap (Japanese-layout controllers).
+    pub fn swap_select_start() -> Self {
+        Self::IDENTITY.with_swap(Button::Select, Button::Start)
+    }
+
+    /// Returns a copy with the console targets of `a` and `b` exchanged.
+    ///
+    /// Built only from swaps, a remap always stays a permutation, so every
+    /// console button remains reachable.
+    pub fn with_swap(mut self, a: Button, b: Button) -> Self {
+        self.targets.swap(a as usize, b as usize);
+        self
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// Console button latched when `button` is pressed.
+    pub fn target(&self, button: Button) -> Button {
+        self.targets[button as usize]
+    }
+
+    /// Maps a logical button mask (bit `Button as u8`) to the console mask.
+    pub fn apply(&self, mask: u8) -> u8 {
+        BUTTONS.iter().fold(0, |out, &button| {
+            if mask & (1 << button as u8) != 0 {
+                out | 1 << self.target(button) as u8
+            } else {
+                out
+            }
+        })
+    }
+}
+
+impl Default for ButtonRemap {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Button, ButtonRemap};
+
+    #[test]
+    fn swaps_compose_and_preserve_other_buttons() {
+        let remap = ButtonRemap::swap_ab().with_swap(Button::Select, Button::Start);
+        assert_eq!(remap.apply(0x01), 0x02); // A -> B
+        assert_eq!(remap.apply(0x04), 0x08); // Select -> Start
+        assert_eq!(remap.apply(0x13), 0x13); // A+B+Up unchanged as a set
+        assert!(!remap.is_identity());
+        assert!(
+            ButtonRemap::swap_ab()
+                .with_swap(Button::A, Button::B)
+                .is_identity()
+        );
+    }
+}
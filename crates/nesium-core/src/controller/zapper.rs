@@ -0,0 +1,307 @@
+//! NES Zapper light gun.
+//!
+//! The Zapper reports two bits on its port: D4 is the trigger (1 = pulled)
+//! and D3 is the photodiode (0 = light sensed). The photodiode only fires
+//! after the CRT beam has drawn a bright pixel under the aim point and keeps
+//! responding for a short while afterwards, so light is derived from the
+//! frame currently being rendered and the PPU's beam position, matching
+//! Mesen2's `Zapper::IsLightFound`.
+
+use crate::ppu::{Ppu, SCREEN_HEIGHT, SCREEN_WIDTH};
+
+/// Photodiode response window. The defaults match Mesen2 and an NTSC CRT;
+/// widening them makes hits register more reliably on displays that smear
+/// or scale the picture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZapperTiming {
+    /// Scanlines after the beam passes the aim point during which the
+    /// sensor still reports light.
+    pub persistence_scanlines: u16,
+    /// Minimum average RGB level of a pixel the sensor recognises as light.
+    pub brightness_threshold: u8,
+    /// Extra pixels around the aim point that are sampled (0 = single pixel).
+    pub light_radius: u8,
+}
+
+impl Default for ZapperTiming {
+    fn default() -> Self {
+        Self {
+            persistence_scanlines: 20,
+            brightness_threshold: 85,
+            light_radius: 0,
+        }
+    }
+}
+
+/// Correction applied to the pointer position before it is used as the aim
+/// point, for displays where touch or mouse input does not line up with the
+/// picture (overscan cropping, bezels, touch offsets).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ZapperCalibration {
+    /// Added to the pointer's X coordinate, in NES pixels.
+    pub offset_x: i32,
+    /// Added to the pointer's Y coordinate, in NES pixels.
+    pub offset_y: i32,
+}
+
+impl ZapperCalibration {
+    /// Calibration that maps `pointer`, where the player actually pointed, to
+    /// `target`, where they were asked to point.
+    pub fn from_points(target: (i32, i32), pointer: (i32, i32)) -> Self {
+        Self {
+            offset_x: target.0 - pointer.0,
+            offset_y: target.1 - pointer.1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Zapper {
+    /// Uncalibrated pointer position in NES pixels; `None` when pointing
+    /// away from the screen.
+    pointer: Option<(i32, i32)>,
+    trigger: bool,
+    timing: ZapperTiming,
+    calibration: ZapperCalibration,
+    crosshair_visible: bool,
+}
+
+impl Zapper {
+    pub fn new() -> Self {
+        Self::with_timing(ZapperTiming::default())
+    }
+
+    pub fn with_timing(timing: ZapperTiming) -> Self {
+        Self {
+            pointer: None,
+            trigger: false,
+            timing,
+            calibration: ZapperCalibration::default(),
+            crosshair_visible: false,
+        }
+    }
+
+    /// Aims at `(x, y)` in NES screen pixels, before calibration. Points
+    /// that fall outside the 256x240 picture once calibrated count as
+    /// pointing away from the screen.
+    pub fn set_aim(&mut self, x: i32, y: i32) {
+        self.pointer = Some((x, y));
+    }
+
+    /// Points the gun away from the screen (always reads as dark).
+    pub fn clear_aim(&mut self) {
+        self.pointer = None;
+    }
+
+    /// Calibrated aim point, or `None` when pointing off-screen.
+    pub fn aim(&self) -> Option<(u16, u16)> {
+        let (x, y) = self.pointer?;
+        let x = x.saturating_add(self.calibration.offset_x);
+        let y = y.saturating_add(self.calibration.offset_y);
+        ((0..SCREEN_WIDTH as i32).contains(&x) && (0..SCREEN_HEIGHT as i32).contains(&y))
+            .then_some((x as u16, y as u16))
+    }
+
+    pub fn calibration(&self) -> ZapperCalibration {
+        self.calibration
+    }
+
+    pub fn set_calibration(&mut self, calibration: ZapperCalibration) {
+        self.calibration = calibration;
+    }
+
+    /// Whether the core draws a crosshair at the aim point (see
+    /// [`Nes::run_frame`](crate::Nes::run_frame)). Display only; the gun
+    /// senses the frame without it.
+    pub fn crosshair_visible(&self) -> bool {
+        self.crosshair_visible
+    }
+
+    pub fn set_crosshair_visible(&mut self, visible: bool) {
+        self.crosshair_visible = visible;
+    }
+
+    pub fn set_trigger(&mut self, pulled: bool) {
+        self.trigger = pulled;
+    }
+
+    pub fn trigger(&self) -> bool {
+        self.trigger
+    }
+
+    pub fn timing(&self) -> ZapperTiming {
+        self.timing
+    }
+
+    pub fn set_timing(&mut self, timing: ZapperTiming) {
+        self.timing = timing;
+    }
+
+    /// Port value for a `$4016/$4017` read (D3 light, D4 trigger).
+    pub(crate) fn read(&self, ppu: &Ppu) -> u8 {
+        let light = if self.light_sensed(ppu) { 0x00 } else { 0x08 };
+        let trigger = if self.trigger { 0x10 } else { 0x00 };
+        light | trigger
+    }
+
+    fn light_sensed(&self, ppu: &Ppu) -> bool {
+        let Some((x, y)) = self.aim() else {
+            return false;
+        };
+        let scanline = ppu.scanline as i32;
+        let dot = ppu.cycle as i32;
+        let radius = self.timing.light_radius as i32;
+        let persistence = self.timing.persistence_scanlines as i32;
+
+        for y_pos in (y as i32 - radius)..=(y as i32 + radius) {
+            if !(0..SCREEN_HEIGHT as i32).contains(&y_pos) {
+                continue;
+            }
+            let lines_since = scanline - y_pos;
+            if !(0..=persistence).contains(&lines_since) {
+                continue;
+            }
+            for x_pos in (x as i32 - radius)..=(x as i32 + radius) {
+                if !(0..SCREEN_WIDTH as i32).contains(&x_pos) {
+                    continue;
+                }
+                // On the aim scanline itself the beam must already have drawn the pixel.
+                if lines_since == 0 && dot <= x_pos {
+                    continue;
+                }
+                if ppu.pixel_brightness(x_pos as usize, y_pos as usize)
+                    >= self.timing.brightness_threshold
+                {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+impl Default for Zapper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Zapper, ZapperCalibration, ZapperTiming};
+    use crate::ppu::{
+        Ppu,
+        palette::{Color, Palette},
+    };
+
+    const WHITE: u8 = 0x30;
+    const BLACK: u8 = 0x0F;
+
+    fn ppu_at(scanline: i16, cycle: u16) -> Ppu {
+        Ppu {
+            scanline,
+            cycle,
+            ..Ppu::default()
+        }
+    }
+
+    #[test]
+    fn trigger_sets_d4() {
+        let ppu = ppu_at(0, 0);
+        let mut zapper = Zapper::new();
+        assert_eq!(zapper.read(&ppu), 0x08);
+        zapper.set_trigger(true);
+        assert_eq!(zapper.read(&ppu), 0x18);
+    }
+
+    #[test]
+    fn senses_light_only_after_beam_passes() {
+        let mut zapper = Zapper::new();
+        zapper.set_aim(100, 50);
+
+        let mut ppu = ppu_at(50, 100);
+        ppu.framebuffer.write_index(100, 50, WHITE);
+        // Beam has not drawn the pixel yet.
+        assert_eq!(zapper.read(&ppu), 0x08);
+
+        ppu.cycle = 101;
+        assert_eq!(zapper.read(&ppu), 0x00);
+
+        ppu.scanline = 70;
+        assert_eq!(zapper.read(&ppu), 0x00);
+
+        // Sensor stops responding once the persistence window has passed.
+        ppu.scanline = 71;
+        assert_eq!(zapper.read(&ppu), 0x08);
+    }
+
+    #[test]
+    fn dark_pixels_and_off_screen_aim_read_no_light() {
+        let mut zapper = Zapper::new();
+        zapper.set_aim(10, 10);
+
+        let mut ppu = ppu_at(12, 0);
+        ppu.framebuffer.write_index(10, 10, BLACK);
+        assert_eq!(zapper.read(&ppu), 0x08);
+
+        ppu.framebuffer.write_index(10, 10, WHITE);
+        assert_eq!(zapper.read(&ppu), 0x00);
+
+        zapper.set_aim(-1, 10);
+        assert_eq!(zapper.aim(), None);
+        assert_eq!(zapper.read(&ppu), 0x08);
+    }
+
+    #[test]
+    fn calibration_offsets_the_pointer() {
+        let mut zapper = Zapper::new();
+        zapper.set_aim(95, 48);
+        zapper.set_calibration(ZapperCalibration::from_points((100, 50), (95, 48)));
+        assert_eq!(zapper.aim(), Some((100, 50)));
+
+        let mut ppu = ppu_at(52, 0);
+        ppu.framebuffer.write_index(100, 50, WHITE);
+        assert_eq!(zapper.read(&ppu), 0x00);
+
+        // A pointer inside the picture can be calibrated off it, and back.
+        zapper.set_aim(254, 10);
+        assert_eq!(zapper.aim(), None);
+        zapper.set_calibration(ZapperCalibration {
+            offset_x: -10,
+            offset_y: 0,
+        });
+        assert_eq!(zapper.aim(), Some((244, 10)));
+    }
+
+    #[test]
+    fn timing_window_is_configurable() {
+        let mut zapper = Zapper::with_timing(ZapperTiming {
+            persistence_scanlines: 40,
+            brightness_threshold: 20,
+            light_radius: 2,
+        });
+        zapper.set_aim(50, 50);
+
+        // Dim pixel two columns away, 30 scanlines back.
+        let mut ppu = ppu_at(80, 0);
+        ppu.framebuffer.write_index(52, 50, 0x00);
+        assert_eq!(zapper.read(&ppu), 0x00);
+
+        zapper.set_timing(ZapperTiming::default());
+        assert_eq!(zapper.read(&ppu), 0x08);
+    }
+
+    #[test]
+    fn reference_light_palette_ignores_display_palette() {
+        let mut zapper = Zapper::new();
+        zapper.set_aim(10, 10);
+
+        let mut ppu = ppu_at(12, 0);
+        ppu.palette = Palette::Dynamic(Box::new([Color::BLACK; 64]));
+        ppu.framebuffer.write_index(10, 10, WHITE);
+        assert_eq!(zapper.read(&ppu), 0x08);
+
+        ppu.set_reference_light_palette(true);
+        assert_eq!(zapper.read(&ppu), 0x00);
+    }
+}
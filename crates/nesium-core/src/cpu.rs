@@ -51,6 +51,14 @@ pub struct CpuSnapshot {
     pub p: u8,
 }
 
+/// A CPU stopped by a JAM (KIL) opcode. Only a reset resumes execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CpuJam {
+    /// Address of the JAM opcode.
+    pub pc: u16,
+    pub opcode: u8,
+}
+
 /// Minimal opcode metadata for debugging / disassembly helpers.
 #[derive(Debug, Clone)]
 pub struct OpcodeMeta {
@@ -176,6 +184,9 @@ pub struct Cpu {
 
     // ===== DMA =====
     pub(crate) dma: DmaController,
+
+    /// Set when a JAM opcode halts the CPU; cleared by reset.
+    pub(crate) jam: Option<CpuJam>,
 }
 
 impl Cpu {
@@ -201,6 +212,7 @@ impl Cpu {
             tmp: 0,
             effective_addr: 0,
             dma: DmaController::default(),
+            jam: None,
         }
     }
 
@@ -256,6 +268,7 @@ impl Cpu {
         self.tmp = 0;
         self.effective_addr = 0;
         self.dma = DmaController::default();
+        self.jam = None;
 
         // The CPU takes 8 cycles before it starts executing the ROM's code
         // after a reset/power-up (Mesen does this via 8 Start/End cycles).
@@ -298,9 +311,13 @@ impl Cpu {
         bus.bump_master_clock(start_delta, self, ctx);
         bus.notify_mapper_cpu_clock();
 
-        bus.clock_mapper_expansion_audio();
-        // Run one APU CPU-cycle tick; DMA requests are queued on the bus.
-        Apu::step(bus, self, ctx);
+        // Overclock scanlines give the CPU extra time without speeding up
+        // the APU, so audio keeps its stock tempo.
+        if !bus.ppu.in_overclock_scanline() {
+            bus.clock_mapper_expansion_audio();
+            // Run one APU CPU-cycle tick; DMA requests are queued on the bus.
+            Apu::step(bus, self, ctx);
+        }
     }
 
     pub(crate) fn end_cycle(&mut self, read_phase: bool, bus: &mut CpuBus, ctx: &mut Context) {
@@ -530,12 +547,17 @@ impl Cpu {
     /// - DMA bus accesses MUST bypass `mem_read/mem_write` (otherwise we'd recurse back into DMA).
     ///   Use `bus.dma_read/dma_write`.
     ///
-    /// TODO parity with Mesen:
-    /// - PAL gating: DMA can only start on opcode fetch (`ExecOpCode`) on PAL.
-    /// - 4016/4017 input timing quirks: `skipFirstInputClock`, `skipDummyReads`, and HVC001 behavior.
-    /// - Internal-reg conflict glitch: open bus masks + merging for 4016/4017, and 4015 side effects.
-    /// - Exact address used for dummy reads (should be the CPU's pending read address / opType-specific).
-    /// - DMC buffer delivery: feed the DMC read byte into APU (SetDmcReadBuffer).
+    /// Read conflicts follow Mesen's `dmc_dma_during_read4` handling:
+    /// - halt/dummy cycles re-read the address the CPU is waiting on, so a DMA
+    ///   landing on `$4016/$4017` clocks the pads (bit deletion) and one
+    ///   landing on `$2007` advances the VRAM read buffer;
+    /// - on NES consoles only the first of those reads reaches the pads; on
+    ///   the Famicom each one does;
+    /// - while halted on `$4000-$401F`, DMA reads also hit the internal
+    ///   APU/input registers selected by the low address bits.
+    ///
+    /// TODO parity with Mesen: PAL gating (DMA can only start on an opcode
+    /// fetch on PAL).
     pub(crate) fn handle_dma(&mut self, addr: u16, bus: &mut CpuBus, ctx: &mut Context) {
         // Drain bus mailbox once at entry.
         if let Some(evt) = bus.take_dmc_dma_event() {
@@ -572,9 +594,8 @@ impl Cpu {
             cpu.end_cycle(true, bus, ctx);
         }
 
-        // TODO: plumb console type + region via bus/config. This controls Famicom vs NES behavior.
         // Mesen: `isNesBehavior = ConsoleType != Hvc001`.
-        let is_nes_behavior: bool = true;
+        let is_nes_behavior: bool = !bus.famicom;
 
         // Mesen: skipFirstInputClock
         // If the CPU is halted while reading $4016/$4017 and DMC DMA reads the same *internal* input reg,
@@ -813,7 +834,8 @@ impl Cpu {
 
                     if !is_same_address {
                         let external_value = bus.read(dma_addr, self, ctx);
-                        let open_bus_mask = Self::controller_open_bus_mask(internal_addr - 0x4016);
+                        let open_bus_mask =
+                            bus.controller_open_bus_mask((internal_addr - 0x4016) as usize);
                         read_value = (external_value & open_bus_mask)
                             | ((read_value & !open_bus_mask) & (external_value & !open_bus_mask));
                     }
@@ -833,17 +855,6 @@ impl Cpu {
         value
     }
 
-    #[inline]
-    fn controller_open_bus_mask(port: u16) -> u8 {
-        // Match Mesen's default NES-001 behavior for now.
-        // TODO: plumb console model and use console-specific masks.
-        match port {
-            0 => 0xE0,
-            1 => 0xE0,
-            _ => 0xE0,
-        }
-    }
-
     fn perform_interrupt(&mut self, bus: &mut CpuBus, ctx: &mut Context) {
         self.dummy_read(bus, ctx);
         self.dummy_read(bus, ctx);
@@ -989,6 +1000,12 @@ impl Cpu {
         self.opcode_in_flight.is_some()
     }
 
+    /// Returns `true` when the next `step` will fetch a new opcode (no
+    /// instruction in flight and no interrupt sequence about to start).
+    pub(crate) fn at_instruction_boundary(&self) -> bool {
+        self.opcode_in_flight.is_none() && !self.prev_irq_active && !self.prev_nmi_latch
+    }
+
     pub(crate) fn stack_addr(&self) -> u16 {
         STACK_ADDR | self.s as u16
     }
@@ -1,4 +1,8 @@
-use crate::{bus::CpuBus, context::Context, cpu::Cpu};
+use crate::{
+    bus::CpuBus,
+    context::Context,
+    cpu::{Cpu, CpuJam},
+};
 
 /// N V - B D I Z C
 /// - - - - - - - -
@@ -30,6 +34,9 @@ pub fn exec_jam(cpu: &mut Cpu, _: &mut CpuBus, _: &mut Context, step: u8) {
     match step {
         0 => {
             cpu.pc -= 1;
+            cpu.jam = cpu
+                .opcode_in_flight
+                .map(|opcode| CpuJam { pc: cpu.pc, opcode });
             // Prevent IRQ/NMI
             cpu.prev_irq_active = false;
             cpu.prev_nmi_latch = false;
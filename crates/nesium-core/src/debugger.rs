@@ -0,0 +1,411 @@
+//! CPU breakpoints and memory watchpoints for debugger frontends.
+//!
+//! A [`Debugger`] attached to the console (see
+//! [`Nes::enable_debugger`](crate::Nes::enable_debugger)) observes every CPU
+//! opcode fetch, read and write, and records the first enabled
+//! [`Breakpoint`] that matches. [`Watchpoint`]s additionally cover the PPU
+//! address space and filter on the data byte, and report the instruction that
+//! caused the access. [`Nes::run_until_break`](crate::Nes::run_until_break)
+//! drives the console one CPU cycle at a time and returns as soon as a hit is
+//! recorded; frontends that run whole frames can poll
+//! [`Debugger::take_break`] / [`Debugger::take_watch`] or install callbacks
+//! instead.
+//!
+//! Dummy reads/writes, operand fetches and DMA cycles never trigger
+//! breakpoints, matching Mesen2's defaults.
+//!
+//! With [`Debugger::enable_event_log`] the debugger also keeps an
+//! [`EventLog`] of PPU/APU/mapper register accesses and interrupts, stamped
+//! with the PPU beam position, for an event viewer.
+
+use std::fmt;
+
+use crate::{
+    cartridge::CpuBusAccessKind,
+    cpu::Cpu,
+    memory::cpu::{IRQ_VECTOR_LO, NMI_VECTOR_LO},
+};
+
+mod breakpoint;
+mod event;
+mod watchpoint;
+
+pub use breakpoint::{
+    AccessKind, BreakEvent, BreakOn, Breakpoint, BreakpointId, BreakpointTarget, Condition,
+    MemoryAccess,
+};
+pub use event::{DebugEvent, EventLog, EventSource, FramePosition};
+pub use watchpoint::{ValueMatch, WatchEvent, WatchSpace, Watchpoint, WatchpointId};
+
+/// Why [`Nes::run_until_break`](crate::Nes::run_until_break) returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    Break(BreakEvent),
+    Watch(WatchEvent),
+    /// A step-over / step-out / run-to target was reached.
+    StepComplete,
+    CycleLimit,
+}
+
+/// Called synchronously from the CPU bus whenever a breakpoint fires.
+pub type BreakCallback = Box<dyn FnMut(&BreakEvent) + Send>;
+
+/// Called synchronously from the CPU bus whenever a watchpoint fires.
+pub type WatchCallback = Box<dyn FnMut(&WatchEvent) + Send>;
+
+#[derive(Default)]
+pub struct Debugger {
+    breakpoints: Vec<(BreakpointId, Breakpoint)>,
+    next_id: u32,
+    /// First hit since the last [`Debugger::take_break`].
+    pending: Option<BreakEvent>,
+    callback: Option<BreakCallback>,
+    watchpoints: Vec<(WatchpointId, Watchpoint)>,
+    next_watch_id: u32,
+    pending_watch: Option<WatchEvent>,
+    watch_callback: Option<WatchCallback>,
+    /// Address and opcode of the instruction currently executing.
+    instr_pc: u16,
+    instr_opcode: u8,
+    /// Subroutine/interrupt nesting seen since the debugger was attached:
+    /// JSR, BRK and hardware interrupts enter a frame; RTS and RTI leave one.
+    depth: i32,
+    /// `depth` before the current instruction's own adjustment.
+    instr_depth: i32,
+    /// Register access timeline; `None` until enabled.
+    events: Option<EventLog>,
+}
+
+impl fmt::Debug for Debugger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Debugger")
+            .field("breakpoints", &self.breakpoints)
+            .field("pending", &self.pending)
+            .field("callback", &self.callback.is_some())
+            .field("watchpoints", &self.watchpoints)
+            .field("pending_watch", &self.pending_watch)
+            .field("watch_callback", &self.watch_callback.is_some())
+            .field("events", &self.events.as_ref().map(EventLog::len))
+            .finish()
+    }
+}
+
+impl Debugger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> BreakpointId {
+        let id = BreakpointId(self.next_id);
+        self.next_id = self.next_id.wrapping_add(1);
+        self.breakpoints.push((id, breakpoint));
+        id
+    }
+
+    pub fn remove_breakpoint(&mut self, id: BreakpointId) -> Option<Breakpoint> {
+        let pos = self.breakpoints.iter().position(|(bid, _)| *bid == id)?;
+        Some(self.breakpoints.remove(pos).1)
+    }
+
+    pub fn breakpoint(&self, id: BreakpointId) -> Option<&Breakpoint> {
+        self.breakpoints
+            .iter()
+            .find(|(bid, _)| *bid == id)
+            .map(|(_, bp)| bp)
+    }
+
+    pub fn breakpoint_mut(&mut self, id: BreakpointId) -> Option<&mut Breakpoint> {
+        self.breakpoints
+            .iter_mut()
+            .find(|(bid, _)| *bid == id)
+            .map(|(_, bp)| bp)
+    }
+
+    /// Enables or disables a breakpoint. Returns `false` for unknown ids.
+    pub fn set_enabled(&mut self, id: BreakpointId, enabled: bool) -> bool {
+        match self.breakpoint_mut(id) {
+            Some(bp) => {
+                bp.enabled = enabled;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn clear_breakpoints(&mut self) {
+        self.breakpoints.clear();
+    }
+
+    pub fn breakpoints(&self) -> impl Iterator<Item = (BreakpointId, &Breakpoint)> {
+        self.breakpoints.iter().map(|(id, bp)| (*id, bp))
+    }
+
+    pub fn set_break_callback(&mut self, callback: Option<BreakCallback>) {
+        self.callback = callback;
+    }
+
+    /// Returns and clears the pending hit, if any.
+    pub fn take_break(&mut self) -> Option<BreakEvent> {
+        self.pending.take()
+    }
+
+    pub fn has_pending_break(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    pub fn add_watchpoint(&mut self, watchpoint: Watchpoint) -> WatchpointId {
+        let id = WatchpointId(self.next_watch_id);
+        self.next_watch_id = self.next_watch_id.wrapping_add(1);
+        self.watchpoints.push((id, watchpoint));
+        id
+    }
+
+    pub fn remove_watchpoint(&mut self, id: WatchpointId) -> Option<Watchpoint> {
+        let pos = self.watchpoints.iter().position(|(wid, _)| *wid == id)?;
+        Some(self.watchpoints.remove(pos).1)
+    }
+
+    pub fn watchpoint_mut(&mut self, id: WatchpointId) -> Option<&mut Watchpoint> {
+        self.watchpoints
+            .iter_mut()
+            .find(|(wid, _)| *wid == id)
+            .map(|(_, wp)| wp)
+    }
+
+    pub fn clear_watchpoints(&mut self) {
+        self.watchpoints.clear();
+    }
+
+    pub fn watchpoints(&self) -> impl Iterator<Item = (WatchpointId, &Watchpoint)> {
+        self.watchpoints.iter().map(|(id, wp)| (*id, wp))
+    }
+
+    pub fn set_watch_callback(&mut self, callback: Option<WatchCallback>) {
+        self.watch_callback = callback;
+    }
+
+    /// Returns and clears the pending watchpoint hit, if any.
+    pub fn take_watch(&mut self) -> Option<WatchEvent> {
+        self.pending_watch.take()
+    }
+
+    /// Starts recording register accesses into a ring buffer holding the last
+    /// `capacity` events. Re-enabling keeps the existing history only if the
+    /// capacity is unchanged.
+    pub fn enable_event_log(&mut self, capacity: usize) {
+        if self.events.as_ref().map(EventLog::capacity) != Some(capacity) {
+            self.events = Some(EventLog::new(capacity));
+        }
+    }
+
+    pub fn disable_event_log(&mut self) -> Option<EventLog> {
+        self.events.take()
+    }
+
+    pub fn event_log(&self) -> Option<&EventLog> {
+        self.events.as_ref()
+    }
+
+    pub fn clear_events(&mut self) {
+        if let Some(events) = self.events.as_mut() {
+            events.clear();
+        }
+    }
+
+    /// Returns the next pending stop, breakpoints first.
+    pub(crate) fn take_stop(&mut self) -> Option<StopReason> {
+        self.take_break()
+            .map(StopReason::Break)
+            .or_else(|| self.take_watch().map(StopReason::Watch))
+    }
+
+    /// Observes one CPU bus access.
+    pub(crate) fn on_cpu_access(
+        &mut self,
+        cpu: &Cpu,
+        kind: CpuBusAccessKind,
+        addr: u16,
+        value: u8,
+        cycle: u64,
+    ) {
+        let kind = match kind {
+            CpuBusAccessKind::ExecOpcode => {
+                self.instr_pc = addr;
+                self.instr_opcode = value;
+                self.instr_depth = self.depth;
+                self.depth = self.depth.wrapping_add(match value {
+                    0x20 | 0x00 => 1,  // JSR, BRK
+                    0x60 | 0x40 => -1, // RTS, RTI
+                    _ => 0,
+                });
+                AccessKind::Execute
+            }
+            CpuBusAccessKind::Read => {
+                // A vector fetch outside any instruction is an NMI/IRQ entry
+                // (BRK reads its vector with the opcode still in flight).
+                if (addr == NMI_VECTOR_LO || addr == IRQ_VECTOR_LO) && !cpu.opcode_active() {
+                    self.depth = self.depth.wrapping_add(1);
+                }
+                AccessKind::Read
+            }
+            CpuBusAccessKind::Write => AccessKind::Write,
+            _ => return,
+        };
+        let access = MemoryAccess { addr, value, kind };
+        self.check_watchpoints(WatchSpace::Cpu, access, cycle);
+        if self.breakpoints.is_empty() {
+            return;
+        }
+
+        let mut event = BreakEvent {
+            id: BreakpointId(0),
+            access,
+            cpu: cpu.snapshot(),
+        };
+        for (id, bp) in &self.breakpoints {
+            event.id = *id;
+            if bp.hits(&event) {
+                if let Some(callback) = self.callback.as_mut() {
+                    callback(&event);
+                }
+                self.pending.get_or_insert(event);
+                return;
+            }
+        }
+    }
+
+    /// Records a CPU bus access on the event timeline when logging is on.
+    pub(crate) fn log_event(
+        &mut self,
+        cpu: &Cpu,
+        kind: CpuBusAccessKind,
+        addr: u16,
+        value: u8,
+        at: FramePosition,
+    ) {
+        let Some(events) = self.events.as_mut() else {
+            return;
+        };
+        let classified = match addr {
+            NMI_VECTOR_LO | IRQ_VECTOR_LO
+                if kind == CpuBusAccessKind::Read && !cpu.opcode_active() =>
+            {
+                let source = if addr == NMI_VECTOR_LO {
+                    EventSource::Nmi
+                } else {
+                    EventSource::Irq
+                };
+                Some((source, AccessKind::Read))
+            }
+            _ => event::classify(kind, addr),
+        };
+        if let Some((source, kind)) = classified {
+            events.push(DebugEvent {
+                at,
+                source,
+                access: MemoryAccess { addr, value, kind },
+                pc: self.instr_pc,
+            });
+        }
+    }
+
+    /// Call depth the current instruction started at, or the current depth
+    /// when the CPU sits on an instruction boundary.
+    pub(crate) fn frame_depth(&self, at_boundary: bool) -> i32 {
+        if at_boundary {
+            self.depth
+        } else {
+            self.instr_depth
+        }
+    }
+
+    pub(crate) fn depth(&self) -> i32 {
+        self.depth
+    }
+
+    /// Observes a CPU-driven PPU memory access through `$2007`.
+    pub(crate) fn on_ppu_access(&mut self, access: MemoryAccess, cycle: u64) {
+        self.check_watchpoints(WatchSpace::Ppu, access, cycle);
+    }
+
+    fn check_watchpoints(&mut self, space: WatchSpace, access: MemoryAccess, cycle: u64) {
+        let Some(&(id, _)) = self
+            .watchpoints
+            .iter()
+            .find(|(_, wp)| wp.hits(space, &access))
+        else {
+            return;
+        };
+        let event = WatchEvent {
+            id,
+            space,
+            access,
+            pc: self.instr_pc,
+            opcode: self.instr_opcode,
+            cycle,
+        };
+        if let Some(callback) = self.watch_callback.as_mut() {
+            callback(&event);
+        }
+        self.pending_watch.get_or_insert(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::ppu::Register as PpuRegister;
+
+    fn access(dbg: &mut Debugger, kind: CpuBusAccessKind, addr: u16, value: u8) {
+        dbg.on_cpu_access(&Cpu::new(), kind, addr, value, 0);
+    }
+
+    #[test]
+    fn matches_kind_range_and_ppu_mirrors() {
+        let mut dbg = Debugger::new();
+        let write = dbg.add_breakpoint(Breakpoint::write(0x0200..=0x02FF));
+        let status =
+            dbg.add_breakpoint(Breakpoint::ppu_register(PpuRegister::Status, BreakOn::READ));
+
+        access(&mut dbg, CpuBusAccessKind::Read, 0x0210, 0);
+        access(&mut dbg, CpuBusAccessKind::DummyWrite, 0x0210, 0);
+        assert_eq!(dbg.take_break(), None);
+
+        access(&mut dbg, CpuBusAccessKind::Write, 0x0210, 7);
+        let hit = dbg.take_break().expect("write hit");
+        assert_eq!((hit.id, hit.access.value), (write, 7));
+
+        access(&mut dbg, CpuBusAccessKind::Read, 0x3FFA, 0x80);
+        assert_eq!(dbg.take_break().map(|e| e.id), Some(status));
+    }
+
+    #[test]
+    fn tracks_call_depth_through_calls_and_interrupts() {
+        let mut dbg = Debugger::new();
+        access(&mut dbg, CpuBusAccessKind::ExecOpcode, 0x8000, 0x20); // JSR
+        assert_eq!((dbg.depth(), dbg.frame_depth(false)), (1, 0));
+        // NMI vector fetch with no opcode in flight.
+        access(&mut dbg, CpuBusAccessKind::Read, NMI_VECTOR_LO, 0x00);
+        assert_eq!(dbg.depth(), 2);
+        access(&mut dbg, CpuBusAccessKind::ExecOpcode, 0x9000, 0x40); // RTI
+        access(&mut dbg, CpuBusAccessKind::ExecOpcode, 0x8010, 0x60); // RTS
+        assert_eq!(dbg.frame_depth(true), 0);
+    }
+
+    #[test]
+    fn conditions_and_disabled_breakpoints_are_skipped() {
+        let mut dbg = Debugger::new();
+        let id = dbg.add_breakpoint(
+            Breakpoint::read(0x0010..=0x0010).with_condition(|e| e.access.value == 0x42),
+        );
+
+        access(&mut dbg, CpuBusAccessKind::Read, 0x0010, 0x41);
+        assert!(!dbg.has_pending_break());
+        access(&mut dbg, CpuBusAccessKind::Read, 0x0010, 0x42);
+        assert!(dbg.take_break().is_some());
+
+        assert!(dbg.set_enabled(id, false));
+        access(&mut dbg, CpuBusAccessKind::Read, 0x0010, 0x42);
+        assert!(!dbg.has_pending_break());
+    }
+}
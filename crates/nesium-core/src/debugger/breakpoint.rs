@@ -0,0 +1,163 @@
+use std::{fmt, ops::RangeInclusive, sync::Arc};
+
+use bitflags::bitflags;
+
+use crate::{
+    cpu::CpuSnapshot,
+    memory::{cpu as cpu_mem, ppu::Register as PpuRegister},
+};
+
+bitflags! {
+    /// CPU bus operations a breakpoint reacts to.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct BreakOn: u8 {
+        /// Opcode fetch at the address (the instruction is about to run).
+        const EXECUTE = 0b001;
+        const READ = 0b010;
+        const WRITE = 0b100;
+    }
+}
+
+/// Kind of a single CPU bus access seen by the debugger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AccessKind {
+    Execute,
+    Read,
+    Write,
+}
+
+impl AccessKind {
+    fn flag(self) -> BreakOn {
+        match self {
+            AccessKind::Execute => BreakOn::EXECUTE,
+            AccessKind::Read => BreakOn::READ,
+            AccessKind::Write => BreakOn::WRITE,
+        }
+    }
+}
+
+/// A CPU bus access: the address, the byte on the bus and what the CPU did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MemoryAccess {
+    pub addr: u16,
+    pub value: u8,
+    pub kind: AccessKind,
+}
+
+/// Address space a breakpoint watches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BreakpointTarget {
+    /// Inclusive CPU address range.
+    Cpu { start: u16, end: u16 },
+    /// A PPU register through any of its mirrors in `$2000-$3FFF`.
+    PpuRegister(PpuRegister),
+}
+
+impl BreakpointTarget {
+    fn contains(self, addr: u16) -> bool {
+        match self {
+            BreakpointTarget::Cpu { start, end } => (start..=end).contains(&addr),
+            BreakpointTarget::PpuRegister(reg) => {
+                (cpu_mem::PPU_REGISTER_BASE..=cpu_mem::PPU_REGISTER_END).contains(&addr)
+                    && PpuRegister::from_cpu_addr(addr) == reg
+            }
+        }
+    }
+}
+
+/// Description of a breakpoint hit, also handed to conditions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BreakEvent {
+    pub id: BreakpointId,
+    pub access: MemoryAccess,
+    /// CPU registers when the access happened. For execute hits `pc` is the
+    /// address of the instruction.
+    pub cpu: CpuSnapshot,
+}
+
+/// Predicate evaluated on every matching access; the breakpoint only fires
+/// when it returns `true`.
+#[derive(Clone)]
+pub struct Condition(Arc<dyn Fn(&BreakEvent) -> bool + Send + Sync>);
+
+impl Condition {
+    pub fn new(f: impl Fn(&BreakEvent) -> bool + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    fn eval(&self, event: &BreakEvent) -> bool {
+        (self.0)(event)
+    }
+}
+
+impl fmt::Debug for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Condition(..)")
+    }
+}
+
+/// Handle returned by [`Debugger::add_breakpoint`](super::Debugger::add_breakpoint).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BreakpointId(pub(super) u32);
+
+#[derive(Debug, Clone)]
+pub struct Breakpoint {
+    pub target: BreakpointTarget,
+    pub on: BreakOn,
+    pub condition: Option<Condition>,
+    pub enabled: bool,
+}
+
+impl Breakpoint {
+    /// Enabled, unconditional breakpoint on a CPU address range.
+    pub fn cpu(range: RangeInclusive<u16>, on: BreakOn) -> Self {
+        Self {
+            target: BreakpointTarget::Cpu {
+                start: *range.start(),
+                end: *range.end(),
+            },
+            on,
+            condition: None,
+            enabled: true,
+        }
+    }
+
+    /// Breaks before the instruction at `addr` executes.
+    pub fn execute(addr: u16) -> Self {
+        Self::cpu(addr..=addr, BreakOn::EXECUTE)
+    }
+
+    pub fn read(range: RangeInclusive<u16>) -> Self {
+        Self::cpu(range, BreakOn::READ)
+    }
+
+    pub fn write(range: RangeInclusive<u16>) -> Self {
+        Self::cpu(range, BreakOn::WRITE)
+    }
+
+    /// Breaks on CPU reads and/or writes of a PPU register.
+    pub fn ppu_register(register: PpuRegister, on: BreakOn) -> Self {
+        Self {
+            target: BreakpointTarget::PpuRegister(register),
+            on: on - BreakOn::EXECUTE,
+            condition: None,
+            enabled: true,
+        }
+    }
+
+    pub fn with_condition(
+        mut self,
+        condition: impl Fn(&BreakEvent) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.condition = Some(Condition::new(condition));
+        self
+    }
+
+    /// Returns `true` when this breakpoint fires for `event`.
+    pub(super) fn hits(&self, event: &BreakEvent) -> bool {
+        self.enabled
+            && self.on.contains(event.access.kind.flag())
+            && self.target.contains(event.access.addr)
+            && self.condition.as_ref().is_none_or(|c| c.eval(event))
+    }
+}
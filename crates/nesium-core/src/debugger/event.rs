@@ -0,0 +1,199 @@
+use std::collections::VecDeque;
+
+use super::{AccessKind, MemoryAccess};
+use crate::cartridge::CpuBusAccessKind;
+
+/// Device an [`DebugEvent`] belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventSource {
+    /// `$2000-$3FFF` and the `$4014` OAM DMA register.
+    Ppu,
+    /// `$4000-$4013`, `$4015` and `$4017` writes.
+    Apu,
+    /// `$4016` and `$4017` reads, `$4016` writes.
+    Controller,
+    /// Reads of `$4020-$5FFF` and writes to `$4020-$5FFF` / `$8000-$FFFF`.
+    /// PRG RAM at `$6000-$7FFF` is not logged.
+    Mapper,
+    /// NMI entry (vector fetch); `access.addr` is the vector address.
+    Nmi,
+    /// IRQ entry (vector fetch); BRK is not reported.
+    Irq,
+}
+
+/// PPU beam position at the time of an event.
+///
+/// The PPU's frame counter advances when scanline 240 starts, so a frame's
+/// timeline runs from the post-render line through vblank and the prerender
+/// line into the visible scanlines; see [`FramePosition::frame_offset`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FramePosition {
+    pub frame: u32,
+    /// `-1` for the prerender line.
+    pub scanline: i16,
+    pub dot: u16,
+}
+
+impl FramePosition {
+    /// Dots elapsed since the start of `frame` (scanline 240, dot 0).
+    pub fn frame_offset(&self) -> u32 {
+        let line =
+            (i32::from(self.scanline) - POST_RENDER_SCANLINE).rem_euclid(SCANLINES_PER_FRAME);
+        line as u32 * DOTS_PER_SCANLINE + u32::from(self.dot)
+    }
+}
+
+const POST_RENDER_SCANLINE: i32 = 240;
+const SCANLINES_PER_FRAME: i32 = 262;
+const DOTS_PER_SCANLINE: u32 = 341;
+
+/// One register access or interrupt on the event timeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DebugEvent {
+    pub at: FramePosition,
+    pub source: EventSource,
+    pub access: MemoryAccess,
+    /// Address of the instruction that caused the event.
+    pub pc: u16,
+}
+
+/// Bounded event history; the oldest events are dropped once `capacity` is
+/// reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventLog {
+    events: VecDeque<DebugEvent>,
+    capacity: usize,
+}
+
+impl EventLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            events: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    /// All retained events, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &DebugEvent> {
+        self.events.iter()
+    }
+
+    /// Events recorded during PPU frame `frame`, in timeline order.
+    pub fn frame(&self, frame: u32) -> impl Iterator<Item = &DebugEvent> {
+        self.events.iter().filter(move |e| e.at.frame == frame)
+    }
+
+    /// The latest event at or before `at` within the same frame, e.g. for
+    /// hovering over the event viewer overlay.
+    pub fn nearest(&self, at: FramePosition) -> Option<&DebugEvent> {
+        let offset = at.frame_offset();
+        self.frame(at.frame)
+            .take_while(|e| e.at.frame_offset() <= offset)
+            .last()
+    }
+
+    pub(super) fn push(&mut self, event: DebugEvent) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+}
+
+/// Classifies a CPU bus access as a loggable register access. Dummy
+/// accesses count: they have the same side effects on registers.
+pub(super) fn classify(kind: CpuBusAccessKind, addr: u16) -> Option<(EventSource, AccessKind)> {
+    let access = match kind {
+        CpuBusAccessKind::Read | CpuBusAccessKind::DummyRead => AccessKind::Read,
+        CpuBusAccessKind::Write | CpuBusAccessKind::DummyWrite => AccessKind::Write,
+        _ => return None,
+    };
+    let write = access == AccessKind::Write;
+    let source = match addr {
+        0x2000..=0x3FFF | 0x4014 => EventSource::Ppu,
+        0x4016 => EventSource::Controller,
+        0x4017 if !write => EventSource::Controller,
+        0x4000..=0x4017 => EventSource::Apu,
+        0x4020..=0x5FFF => EventSource::Mapper,
+        0x8000..=0xFFFF if write => EventSource::Mapper,
+        _ => return None,
+    };
+    Some((source, access))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(frame: u32, scanline: i16, dot: u16) -> DebugEvent {
+        DebugEvent {
+            at: FramePosition {
+                frame,
+                scanline,
+                dot,
+            },
+            source: EventSource::Ppu,
+            access: MemoryAccess {
+                addr: 0x2000,
+                value: 0,
+                kind: AccessKind::Write,
+            },
+            pc: 0,
+        }
+    }
+
+    #[test]
+    fn ring_buffer_drops_oldest_and_queries_by_frame() {
+        let mut log = EventLog::new(3);
+        log.push(event(1, 10, 5));
+        log.push(event(2, 241, 1));
+        log.push(event(2, -1, 20));
+        log.push(event(2, 200, 7));
+
+        assert_eq!(log.len(), 3);
+        assert_eq!(log.frame(1).count(), 0);
+        assert_eq!(log.frame(2).count(), 3);
+        assert_eq!(log.nearest(event(2, 150, 0).at), Some(&event(2, -1, 20)));
+        assert_eq!(log.nearest(event(2, 240, 0).at), None);
+    }
+
+    #[test]
+    fn classifies_register_ranges() {
+        use CpuBusAccessKind::*;
+        assert_eq!(
+            classify(DummyRead, 0x2007),
+            Some((EventSource::Ppu, AccessKind::Read))
+        );
+        assert_eq!(classify(Write, 0x4017).map(|c| c.0), Some(EventSource::Apu));
+        assert_eq!(
+            classify(Read, 0x4017).map(|c| c.0),
+            Some(EventSource::Controller)
+        );
+        assert_eq!(
+            classify(Write, 0xC000).map(|c| c.0),
+            Some(EventSource::Mapper)
+        );
+        assert_eq!(classify(Read, 0xC000), None);
+        assert_eq!(classify(Write, 0x6000), None);
+        assert_eq!(classify(DmaRead, 0x2007), None);
+    }
+}
@@ -0,0 +1,94 @@
+use std::ops::RangeInclusive;
+
+use super::{AccessKind, BreakOn, MemoryAccess};
+
+/// Address space a watchpoint observes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WatchSpace {
+    /// CPU bus (`$0000-$FFFF`).
+    Cpu,
+    /// PPU bus (`$0000-$3FFF`) as seen through `$2007`. Rendering fetches are
+    /// not watched.
+    Ppu,
+}
+
+/// Value filter: the access matches when `data & mask == value & mask`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValueMatch {
+    pub value: u8,
+    pub mask: u8,
+}
+
+impl ValueMatch {
+    fn matches(self, data: u8) -> bool {
+        (data ^ self.value) & self.mask == 0
+    }
+}
+
+/// Handle returned by [`Debugger::add_watchpoint`](super::Debugger::add_watchpoint).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WatchpointId(pub(super) u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Watchpoint {
+    pub space: WatchSpace,
+    pub start: u16,
+    pub end: u16,
+    /// `READ` and/or `WRITE`; `EXECUTE` is ignored.
+    pub on: BreakOn,
+    pub value: Option<ValueMatch>,
+    pub enabled: bool,
+}
+
+impl Watchpoint {
+    pub fn cpu(range: RangeInclusive<u16>, on: BreakOn) -> Self {
+        Self::new(WatchSpace::Cpu, range, on)
+    }
+
+    pub fn ppu(range: RangeInclusive<u16>, on: BreakOn) -> Self {
+        Self::new(WatchSpace::Ppu, range, on)
+    }
+
+    fn new(space: WatchSpace, range: RangeInclusive<u16>, on: BreakOn) -> Self {
+        Self {
+            space,
+            start: *range.start(),
+            end: *range.end(),
+            on: on - BreakOn::EXECUTE,
+            value: None,
+            enabled: true,
+        }
+    }
+
+    /// Only fire when the byte read or written matches `value` under `mask`.
+    pub fn with_value(mut self, value: u8, mask: u8) -> Self {
+        self.value = Some(ValueMatch { value, mask });
+        self
+    }
+
+    pub(super) fn hits(&self, space: WatchSpace, access: &MemoryAccess) -> bool {
+        let flag = match access.kind {
+            AccessKind::Read => BreakOn::READ,
+            AccessKind::Write => BreakOn::WRITE,
+            AccessKind::Execute => return false,
+        };
+        self.enabled
+            && self.space == space
+            && self.on.contains(flag)
+            && (self.start..=self.end).contains(&access.addr)
+            && self.value.is_none_or(|v| v.matches(access.value))
+    }
+}
+
+/// Description of a watchpoint hit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WatchEvent {
+    pub id: WatchpointId,
+    pub space: WatchSpace,
+    pub access: MemoryAccess,
+    /// Address of the instruction that performed the access.
+    pub pc: u16,
+    pub opcode: u8,
+    /// CPU cycle counter at the time of the access.
+    pub cycle: u64,
+}
@@ -21,8 +21,19 @@ pub enum Error {
     UnsupportedMapper(u16),
     /// PPU model id read from state/save data is not recognized.
     UnsupportedPpuModel(u8),
-    /// Palette files must contain either 192 or 256 bytes.
+    /// Palette files must hold 64 or 512 RGB/RGBA colors (192, 256, 1536 or
+    /// 2048 bytes).
     InvalidPaletteSize { actual: usize },
+    /// An IPS patch is truncated or malformed.
+    InvalidPatch { reason: &'static str },
+    /// A zip archive could not be read.
+    InvalidArchive { reason: String },
+    /// The archive holds no ROM entry, or not the requested one.
+    RomNotInArchive { entry: Option<String> },
+    /// An HD pack manifest or one of its images is malformed.
+    InvalidHdPack { reason: String },
+    /// A barcode could not be fed to the cartridge's reader.
+    InvalidBarcode { reason: &'static str },
     /// Wrapper for I/O errors raised while reading ROMs from disk.
     Io(std::io::Error),
 }
@@ -52,8 +63,21 @@ impl fmt::Display for Error {
                 write!(f, "unsupported PPU model id {id}")
             }
             Self::InvalidPaletteSize { actual } => {
-                write!(f, "palette blobs must be 192 or 256 bytes (got {actual})")
+                write!(
+                    f,
+                    "palette blobs must be 192, 256, 1536 or 2048 bytes (got {actual})"
+                )
             }
+            Self::InvalidPatch { reason } => write!(f, "invalid IPS patch: {reason}"),
+            Self::InvalidArchive { reason } => write!(f, "invalid archive: {reason}"),
+            Self::RomNotInArchive { entry: Some(entry) } => {
+                write!(f, "archive has no entry named {entry:?}")
+            }
+            Self::RomNotInArchive { entry: None } => {
+                write!(f, "archive contains no .nes/.fds/.nsf file")
+            }
+            Self::InvalidHdPack { reason } => write!(f, "invalid HD pack: {reason}"),
+            Self::InvalidBarcode { reason } => write!(f, "invalid barcode: {reason}"),
             Self::Io(err) => write!(f, "i/o error: {err}"),
         }
     }
@@ -7,6 +7,7 @@ use std::{
 use crate::{bus::CpuBus, cpu::Cpu};
 
 pub mod capture_point;
+pub mod hook_interceptor;
 pub mod log_interceptor;
 pub mod palette_interceptor;
 pub mod sprite_interceptor;
@@ -20,6 +21,9 @@ pub trait Interceptor: Any + Send + Debug + 'static {
 
     fn on_ppu_vblank_start(&mut self, _cpu: &mut Cpu, _bus: &mut CpuBus) {}
 
+    /// The last visible scanline finished and the frame was presented.
+    fn on_ppu_frame_end(&mut self, _cpu: &mut Cpu, _bus: &mut CpuBus) {}
+
     fn on_ppu_scanline_dot(
         &mut self,
         _cpu: &mut Cpu,
@@ -103,6 +107,12 @@ impl Interceptor for EmuInterceptor {
         }
     }
 
+    fn on_ppu_frame_end(&mut self, cpu: &mut Cpu, bus: &mut CpuBus) {
+        for interceptor in self.layers.values_mut() {
+            interceptor.on_ppu_frame_end(cpu, bus);
+        }
+    }
+
     fn on_ppu_scanline_dot(&mut self, cpu: &mut Cpu, bus: &mut CpuBus, scanline: i16, dot: u16) {
         for interceptor in self.layers.values_mut() {
             interceptor.on_ppu_scanline_dot(cpu, bus, scanline, dot);
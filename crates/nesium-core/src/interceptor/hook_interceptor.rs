@@ -0,0 +1,125 @@
+//! User callbacks fired from inside the emulation loop.
+//!
+//! Scripting, conditional debugger breaks and late input polling need to run
+//! code at an exact PPU position without driving the core one cycle at a time.
+//! Hooks registered here run synchronously on the emulation thread, so they
+//! should stay short.
+
+use crate::{bus::CpuBus, cpu::Cpu, interceptor::Interceptor};
+
+/// Handle returned when registering a hook, used to remove it again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HookId(u64);
+
+/// When a hook fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HookTrigger {
+    /// On the given PPU dot. Scanline -1 is the pre-render line, 241 starts
+    /// vblank; dots run 0..=340.
+    ScanlineDot { scanline: i16, dot: u16 },
+    /// When the last visible scanline is done and the frame is presented,
+    /// i.e. where [`Nes::run_frame`](crate::Nes::run_frame) returns.
+    FrameComplete,
+}
+
+/// Position reported to a hook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HookEvent {
+    pub trigger: HookTrigger,
+    pub scanline: i16,
+    pub dot: u16,
+    /// PPU frame counter at the time of the call.
+    pub frame: u32,
+}
+
+/// Callback signature; the CPU and bus give access to registers, memory and
+/// controllers.
+pub type HookFn = Box<dyn FnMut(&mut Cpu, &mut CpuBus, HookEvent) + Send>;
+
+struct Hook {
+    id: HookId,
+    trigger: HookTrigger,
+    callback: HookFn,
+}
+
+/// Interceptor layer holding registered hooks, in registration order.
+#[derive(Default)]
+pub struct HookInterceptor {
+    hooks: Vec<Hook>,
+    next_id: u64,
+}
+
+impl std::fmt::Debug for HookInterceptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HookInterceptor")
+            .field(
+                "hooks",
+                &self
+                    .hooks
+                    .iter()
+                    .map(|hook| (hook.id, hook.trigger))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl HookInterceptor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, trigger: HookTrigger, callback: HookFn) -> HookId {
+        let id = HookId(self.next_id);
+        self.next_id += 1;
+        self.hooks.push(Hook {
+            id,
+            trigger,
+            callback,
+        });
+        id
+    }
+
+    /// Removes a hook; returns false if `id` was not registered.
+    pub fn remove(&mut self, id: HookId) -> bool {
+        let before = self.hooks.len();
+        self.hooks.retain(|hook| hook.id != id);
+        self.hooks.len() != before
+    }
+
+    pub fn len(&self) -> usize {
+        self.hooks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    fn fire(&mut self, cpu: &mut Cpu, bus: &mut CpuBus, trigger: HookTrigger) {
+        if !self.hooks.iter().any(|hook| hook.trigger == trigger) {
+            return;
+        }
+        let ppu = bus.devices().ppu;
+        let event = HookEvent {
+            trigger,
+            scanline: ppu.scanline,
+            dot: ppu.cycle,
+            frame: ppu.frame_count(),
+        };
+        for hook in &mut self.hooks {
+            if hook.trigger == trigger {
+                (hook.callback)(cpu, bus, event);
+            }
+        }
+    }
+}
+
+impl Interceptor for HookInterceptor {
+    fn on_ppu_frame_end(&mut self, cpu: &mut Cpu, bus: &mut CpuBus) {
+        self.fire(cpu, bus, HookTrigger::FrameComplete);
+    }
+
+    fn on_ppu_scanline_dot(&mut self, cpu: &mut Cpu, bus: &mut CpuBus, scanline: i16, dot: u16) {
+        self.fire(cpu, bus, HookTrigger::ScanlineDot { scanline, dot });
+    }
+}
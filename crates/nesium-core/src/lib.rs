@@ -1,26 +1,45 @@
 use core::ffi::c_void;
-use std::path::Path;
+use std::{collections::BTreeMap, path::Path};
 
 use crate::{
     apu::Apu,
-    audio::{CPU_CLOCK_NTSC, NesSoundMixer, SoundMixerBus, bus::AudioBusConfig},
+    audio::{
+        CPU_CLOCK_NTSC, NesSoundMixer, RecordSource, SoundMixerBus, bus::AudioBusConfig,
+        wav::AudioRecorder,
+    },
     bus::{OpenBus, PendingDma, cpu::CpuBus},
-    cartridge::{Cartridge, Provider},
-    config::region::Region,
+    cartridge::{
+        Cartridge, CartridgeLoader, Mmc3Revision, Provider,
+        mapper::{
+            Mapper16, Mapper105, Mapper682, mapper_downcast_mut,
+            mapper105::NWC_DEFAULT_DIP_SWITCHES,
+        },
+    },
+    cheat::{CheatEngine, CheatTarget},
+    config::{overclock::Overclock, ram_init::RamInit, region::Region},
     context::Context,
-    controller::{Button, ControllerPorts},
+    controller::{
+        Button, ButtonRemap, ControllerPorts, Multitap, MultitapKind, Zapper, ZapperPorts,
+    },
     cpu::Cpu,
+    debugger::{Debugger, StopReason},
     error::Error,
     interceptor::palette_interceptor::{PaletteInterceptor, PaletteSnapshot},
     interceptor::sprite_interceptor::{SpriteInterceptor, SpriteSnapshot},
     interceptor::tile_viewer_interceptor::{TileViewerInterceptor, TileViewerSnapshot},
     interceptor::tilemap_interceptor::{TilemapInterceptor, TilemapSnapshot},
-    interceptor::{EmuInterceptor, log_interceptor::LogInterceptor},
+    interceptor::{
+        EmuInterceptor,
+        hook_interceptor::{HookEvent, HookFn, HookId, HookInterceptor, HookTrigger},
+        log_interceptor::LogInterceptor,
+    },
     mem_block::cpu as cpu_ram,
+    movie::{InputMovie, MovieDriver, MovieMode},
     ppu::{
         Ppu,
-        buffer::{ColorFormat, FrameBuffer, FrameReadyCallback},
-        palette::{Palette, PaletteKind},
+        buffer::{ColorFormat, FrameBuffer, FrameReadyCallback, HdFrame},
+        hd_pack::HdPack,
+        palette::{EmphasizedPalette, Palette, PaletteKind, emphasized_index},
     },
     reset_kind::ResetKind,
 };
@@ -29,20 +48,24 @@ pub mod apu;
 pub mod audio;
 pub mod bus;
 pub mod cartridge;
+pub mod cheat;
 pub mod config;
 pub mod context;
 pub mod controller;
 pub mod cpu;
+pub mod debugger;
 pub mod error;
 pub mod interceptor;
 pub mod mem_block;
 pub mod memory;
+pub mod movie;
 pub mod ppu;
 pub mod reset_kind;
 pub mod rng;
 pub mod state;
+pub mod trace;
 
-pub use cpu::CpuSnapshot;
+pub use cpu::{CpuJam, CpuSnapshot};
 
 #[derive(Debug)]
 pub struct Nes {
@@ -52,7 +75,17 @@ pub struct Nes {
     ram: cpu_ram::Ram,
     cartridge: Option<Cartridge>,
     mapper_provider: Option<Box<dyn Provider>>,
+    /// Forced MMC3 IRQ revision applied by `cartridge_loader`.
+    mmc3_revision: Option<Mmc3Revision>,
+    /// Forced bus-conflict emulation applied by `cartridge_loader`.
+    bus_conflicts: Option<bool>,
+    /// Timer DIP switches for Nintendo World Championships carts (mapper 105).
+    nwc_dip_switches: u8,
     pub controllers: ControllerPorts,
+    zappers: ZapperPorts,
+    multitap: Option<Multitap>,
+    /// Logical -> console button mapping per pad, applied by `set_button`/`set_pad_state`.
+    button_remaps: [ButtonRemap; 4],
     last_frame: u32,
     /// Master PPU dot counter used to drive CPU/PPU/APU in lockstep (3 dots per CPU cycle).
     dot_counter: u64,
@@ -78,8 +111,24 @@ pub struct Nes {
     mixer_frame_buffer: Vec<f32>,
     /// Absolute APU-cycle timestamp for the next Mesen-style audio mixer flush.
     next_audio_chunk_clock: i64,
+    /// Active WAV capture of the internal-rate mixer output.
+    audio_recorder: Option<AudioRecorder>,
     pub region: Region,
     pub interceptor: EmuInterceptor,
+    /// RAM cheats applied at every frame boundary.
+    cheats: CheatEngine,
+    /// Breakpoint engine, attached on demand so normal runs skip the bus hook.
+    debugger: Option<Debugger>,
+    /// Writes nothing responded to, by address; `None` while tracking is off.
+    unmapped_writes: Option<BTreeMap<u16, u32>>,
+    /// Host settings are kept out of emulation (see [`Nes::set_deterministic`]).
+    deterministic: bool,
+    /// Fixed-point mixing requested via [`Nes::set_fixed_point_audio`].
+    fixed_point_audio: bool,
+    /// CPU RAM contents after a power cycle.
+    ram_init: RamInit,
+    /// Active input recording or playback.
+    movie: Option<MovieDriver>,
 }
 
 /// Internal mixer output sample rate (matches Mesen2's fixed 96 kHz path).
@@ -98,6 +147,7 @@ pub struct NesBuilder {
     sample_rate: u32,
     region: Region,
     interceptor: Option<EmuInterceptor>,
+    ram_init: RamInit,
     power_on_reset: bool,
 }
 
@@ -109,6 +159,7 @@ impl Default for NesBuilder {
             sample_rate: 48_000,
             region: Region::Auto,
             interceptor: None,
+            ram_init: RamInit::default(),
             power_on_reset: true,
         }
     }
@@ -154,6 +205,12 @@ impl NesBuilder {
         self
     }
 
+    /// Sets the CPU RAM contents used on power-on (see [`Nes::set_ram_init`]).
+    pub fn ram_init(mut self, ram_init: RamInit) -> Self {
+        self.ram_init = ram_init;
+        self
+    }
+
     /// Enables/disables the initial power-on reset performed after construction.
     ///
     /// Most frontends want this enabled. Tests or special setups may disable it.
@@ -181,7 +238,13 @@ impl NesBuilder {
             ram: cpu_ram::Ram::new(),
             cartridge: None,
             mapper_provider: None,
+            mmc3_revision: None,
+            bus_conflicts: None,
+            nwc_dip_switches: NWC_DEFAULT_DIP_SWITCHES,
             controllers: ControllerPorts::new(),
+            zappers: ZapperPorts::default(),
+            multitap: None,
+            button_remaps: [ButtonRemap::IDENTITY; 4],
             last_frame: 0,
             dot_counter: 0,
             master_clock: 0,
@@ -197,8 +260,16 @@ impl NesBuilder {
             audio_sample_rate: self.sample_rate,
             mixer_frame_buffer: Vec::new(),
             next_audio_chunk_clock: MIXER_CHUNK_CYCLES,
+            audio_recorder: None,
             region: self.region,
             interceptor,
+            cheats: CheatEngine::new(),
+            debugger: None,
+            unmapped_writes: None,
+            deterministic: false,
+            fixed_point_audio: false,
+            ram_init: self.ram_init,
+            movie: None,
         };
 
         nes.ppu.set_palette(PaletteKind::NesdevNtsc.palette());
@@ -213,6 +284,48 @@ impl NesBuilder {
     }
 }
 
+/// CPU-side view of the RAM regions cheats are allowed to patch.
+///
+/// Work RAM is accessed in the mapper's PRG-RAM buffer rather than through
+/// the cartridge bus, so cheats never trigger register writes or honour
+/// write protection. The buffer offset follows the mapper's current RAM
+/// bank, so a cheat patches the same byte the CPU sees at its address.
+struct CheatMemory<'a> {
+    ram: &'a mut cpu_ram::Ram,
+    cartridge: Option<&'a mut Cartridge>,
+}
+
+impl CheatTarget for CheatMemory<'_> {
+    fn read(&self, addr: u16) -> u8 {
+        if addr <= memory::cpu::INTERNAL_RAM_MIRROR_END {
+            self.ram[(addr & memory::cpu::INTERNAL_RAM_MASK) as usize]
+        } else {
+            self.cartridge
+                .as_ref()
+                .and_then(|cart| {
+                    let offset = cart.mapper().prg_ram_offset(addr)?;
+                    cart.mapper().memory_ref().prg_ram?.get(offset).copied()
+                })
+                .unwrap_or(0)
+        }
+    }
+
+    fn write(&mut self, addr: u16, value: u8) {
+        if addr <= memory::cpu::INTERNAL_RAM_MIRROR_END {
+            self.ram[(addr & memory::cpu::INTERNAL_RAM_MASK) as usize] = value;
+        } else if let Some(cart) = self.cartridge.as_mut()
+            && let Some(offset) = cart.mapper().prg_ram_offset(addr)
+            && let Some(byte) = cart
+                .mapper_mut()
+                .memory_mut()
+                .prg_ram
+                .and_then(|prg_ram| prg_ram.get_mut(offset))
+        {
+            *byte = value;
+        }
+    }
+}
+
 macro_rules! nes_cpu_bus {
     ($nes:ident, mixer: $with_mixer:expr, serial: $with_serial:expr) => {{
         let __with_mixer = $with_mixer;
@@ -233,15 +346,20 @@ macro_rules! nes_cpu_bus {
             apu: &mut $nes.apu,
             cartridge: $nes.cartridge.as_mut(),
             controllers: &mut $nes.controllers,
+            zappers: &$nes.zappers,
+            multitap: &mut $nes.multitap,
             serial_log: __serial_log,
             open_bus: &mut $nes.open_bus,
             mixer: __mixer,
+            debugger: $nes.debugger.as_mut(),
+            unmapped_writes: $nes.unmapped_writes.as_mut(),
             cycles: &mut $nes.cycles,
             master_clock: &mut $nes.master_clock,
             ppu_offset: $nes.ppu_offset,
             clock_start_count: $nes.clock_start_count,
             clock_end_count: $nes.clock_end_count,
             pending_dma: &mut $nes.pending_dma,
+            famicom: $nes.region == Region::NtscJp,
         }
     }};
 }
@@ -287,19 +405,124 @@ impl Nes {
         self.mapper_provider.as_deref()
     }
 
+    /// Forces the MMC3 IRQ revision for cartridges loaded through
+    /// [`cartridge_loader`](Self::cartridge_loader); `None` derives it from
+    /// the NES 2.0 submapper. Takes effect on the next load.
+    pub fn set_mmc3_revision(&mut self, revision: Option<Mmc3Revision>) {
+        self.mmc3_revision = revision;
+    }
+
+    pub fn mmc3_revision(&self) -> Option<Mmc3Revision> {
+        self.mmc3_revision
+    }
+
+    /// Forces bus conflicts on or off for discrete-logic boards loaded through
+    /// [`cartridge_loader`](Self::cartridge_loader); `None` follows the
+    /// header and cartridge DB. Takes effect on the next load.
+    pub fn set_bus_conflicts(&mut self, bus_conflicts: Option<bool>) {
+        self.bus_conflicts = bus_conflicts;
+    }
+
+    pub fn bus_conflicts(&self) -> Option<bool> {
+        self.bus_conflicts
+    }
+
+    /// Sets the four timer DIP switches of Nintendo World Championships
+    /// cartridges (mapper 105). The round lasts `(16 + dip) * 2^25` CPU
+    /// cycles, about 5:00 at `0` up to 9:42 at `15`; the default of `4` is the
+    /// competition setting. Applies to the inserted cartridge immediately.
+    pub fn set_nwc_dip_switches(&mut self, dip_switches: u8) {
+        self.nwc_dip_switches = dip_switches & 0x0F;
+        self.apply_nwc_dip_switches();
+    }
+
+    pub fn nwc_dip_switches(&self) -> u8 {
+        self.nwc_dip_switches
+    }
+
+    fn apply_nwc_dip_switches(&mut self) {
+        if let Some(mapper) = self
+            .cartridge
+            .as_mut()
+            .and_then(|cart| mapper_downcast_mut::<Mapper105>(cart.mapper_mut()))
+        {
+            mapper.set_dip_switches(self.nwc_dip_switches);
+        }
+    }
+
+    /// Swipes a barcode through the reader of the inserted Datach Joint ROM
+    /// System cartridge (mapper 157). `barcode` holds the 13 (EAN-13) or 8
+    /// (EAN-8) digits printed under the bars.
+    pub fn insert_barcode(&mut self, barcode: &str) -> Result<(), Error> {
+        self.cartridge
+            .as_mut()
+            .and_then(|cart| mapper_downcast_mut::<Mapper16>(cart.mapper_mut()))
+            .ok_or(Error::InvalidBarcode {
+                reason: "cartridge has no barcode reader",
+            })?
+            .insert_barcode(barcode)
+    }
+
+    /// Messages the inserted Rainbow cartridge (mapper 682) sent to its WiFi
+    /// module since the last call. Empty for other boards.
+    pub fn take_rainbow_messages(&mut self) -> Vec<Vec<u8>> {
+        self.rainbow_mapper_mut()
+            .map(Mapper682::take_outgoing)
+            .unwrap_or_default()
+    }
+
+    /// Delivers a message from the host-side WiFi module to the inserted
+    /// Rainbow cartridge. Returns `false` when the cartridge is not a Rainbow
+    /// board.
+    pub fn push_rainbow_message(&mut self, message: Vec<u8>) -> bool {
+        let Some(mapper) = self.rainbow_mapper_mut() else {
+            return false;
+        };
+        mapper.push_incoming(message);
+        true
+    }
+
+    fn rainbow_mapper_mut(&mut self) -> Option<&mut Mapper682> {
+        self.cartridge
+            .as_mut()
+            .and_then(|cart| mapper_downcast_mut::<Mapper682>(cart.mapper_mut()))
+    }
+
+    /// A [`CartridgeLoader`] configured with this console's mapper provider
+    /// and board overrides, for callers that want progress reporting or a
+    /// patch while still honouring those settings.
+    pub fn cartridge_loader(&self) -> CartridgeLoader<'_> {
+        CartridgeLoader::new()
+            .provider(self.mapper_provider.as_deref())
+            .mmc3_revision(self.mmc3_revision)
+            .bus_conflicts(self.bus_conflicts)
+    }
+
     /// Loads a cartridge from disk, inserts it, and performs a reset sequence.
     pub fn load_cartridge_from_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
-        let cartridge = cartridge::load_cartridge_from_file_with_provider(
-            path,
-            self.mapper_provider.as_deref(),
-        )?;
+        let cartridge = self.cartridge_loader().load_file(path)?;
+        self.insert_cartridge(cartridge);
+        Ok(())
+    }
+
+    /// Loads a ROM image (or a zip holding one) from memory, inserts it, and
+    /// performs a reset sequence.
+    pub fn load_cartridge_from_bytes(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        let cartridge = self.cartridge_loader().load(bytes.to_vec())?;
         self.insert_cartridge(cartridge);
         Ok(())
     }
 
     /// Inserts a cartridge that has already been constructed.
     pub fn insert_cartridge(&mut self, cartridge: Cartridge) {
+        let region = Region::resolve(self.region, cartridge.header().tv_system());
+        self.ppu
+            .set_swapped_emphasis(matches!(region, Region::Pal | Region::Dendy));
+        self.apu.set_region(region);
         self.cartridge = Some(cartridge);
+        self.apply_nwc_dip_switches();
+        // HD packs are per game and keyed against the old CHR ROM.
+        self.ppu.set_hd_tiles(None);
         // Inserting a new cartridge is effectively a power cycle for the
         // console, so apply a full power-on reset rather than a warm reset.
         self.reset(ResetKind::PowerOn);
@@ -313,6 +536,19 @@ impl Nes {
         self.reset(ResetKind::PowerOn);
     }
 
+    /// Battery-backed RAM of the inserted cartridge, for writing a save file.
+    pub fn battery_ram(&self) -> Option<Vec<u8>> {
+        self.cartridge.as_ref().and_then(Cartridge::battery_ram)
+    }
+
+    /// Restores a save file into the inserted cartridge; `false` if it
+    /// doesn't fit the board.
+    pub fn load_battery_ram(&mut self, data: &[u8]) -> bool {
+        self.cartridge
+            .as_mut()
+            .is_some_and(|cart| cart.load_battery_ram(data))
+    }
+
     pub fn get_cartridge(&self) -> Option<&Cartridge> {
         self.cartridge.as_ref()
     }
@@ -321,11 +557,14 @@ impl Nes {
     /// depending on `kind`. This drives CPU/PPU/APU, RAM, mixer, and mapper state
     /// in a way that mirrors Mesen2's reset sequencing.
     pub fn reset(&mut self, kind: ResetKind) {
+        if let Some(movie) = self.movie.as_mut() {
+            movie.note_reset(kind);
+        }
         match kind {
             ResetKind::PowerOn => {
-                // Full console power cycle: clear CPU RAM, fully reinitialize APU
-                // and cartridge, and treat this as a cold boot.
-                self.ram.fill(0);
+                // Full console power cycle: refill CPU RAM, fully reinitialize
+                // APU and cartridge, and treat this as a cold boot.
+                self.ram_init.fill(self.ram.as_mut_slice());
                 self.ppu.reset(kind);
                 self.apu.reset(kind);
                 if let Some(cart) = self.cartridge.as_mut() {
@@ -408,16 +647,60 @@ impl Nes {
     }
 
     /// Runs CPU/PPU/APU ticks until the PPU completes the next frame.
+    ///
+    /// Also places the crosshair of the first Zapper with
+    /// [`Zapper::set_crosshair_visible`] enabled at its current aim point.
     pub fn run_frame(&mut self, emit_audio: bool) -> Vec<f32> {
+        self.run_frame_inner(emit_audio, None::<(u16, fn(&mut Self))>)
+    }
+
+    /// Like [`run_frame`](Self::run_frame), but calls `poll` once when the
+    /// PPU reaches `poll_scanline`, so the host can sample controllers just
+    /// before the game reads them (usually in the NMI handler at scanline
+    /// 241) instead of a frame ahead.
+    ///
+    /// `poll` is skipped while a movie is recording or playing, since movies
+    /// store input per frame.
+    pub fn run_frame_polled(
+        &mut self,
+        emit_audio: bool,
+        poll_scanline: u16,
+        poll: impl FnOnce(&mut Self),
+    ) -> Vec<f32> {
+        let poll = self.movie.is_none().then_some((poll_scanline, poll));
+        self.run_frame_inner(emit_audio, poll)
+    }
+
+    fn run_frame_inner(
+        &mut self,
+        emit_audio: bool,
+        mut poll: Option<(u16, impl FnOnce(&mut Self))>,
+    ) -> Vec<f32> {
+        self.advance_movie();
+        let crosshair = self
+            .zappers
+            .iter()
+            .flatten()
+            .find(|zapper| zapper.crosshair_visible())
+            .and_then(Zapper::aim);
+        self.ppu.set_crosshair(crosshair);
         let mut samples = vec![];
         let target_frame = self.ppu.frame_count().wrapping_add(1);
         while self.ppu.frame_count() < target_frame {
+            if poll
+                .as_ref()
+                .is_some_and(|&(scanline, _)| self.ppu.scanline >= scanline as i16)
+                && let Some((_, poll)) = poll.take()
+            {
+                poll(self);
+            }
             let _ = self.step_cpu_cycle(emit_audio);
             let apu_clock = self.apu_cycles() as i64;
             while apu_clock >= self.next_audio_chunk_clock {
                 self.mixer_frame_buffer.clear();
                 self.mixer
                     .end_frame(self.next_audio_chunk_clock, &mut self.mixer_frame_buffer);
+                self.record_audio_chunk();
 
                 if emit_audio {
                     self.sound_bus
@@ -437,6 +720,7 @@ impl Nes {
             self.mixer_frame_buffer.clear();
             self.mixer
                 .end_frame(apu_clock, &mut self.mixer_frame_buffer);
+            self.record_audio_chunk();
 
             if emit_audio {
                 self.sound_bus
@@ -448,9 +732,90 @@ impl Nes {
 
         self.last_frame = self.ppu.frame_count();
         self.dot_counter = self.ppu.total_dots();
+        self.apply_cheats();
         samples
     }
 
+    /// Active RAM cheats.
+    pub fn cheats(&self) -> &CheatEngine {
+        &self.cheats
+    }
+
+    /// Mutable access to the RAM cheats; changes take effect at the next frame boundary.
+    pub fn cheats_mut(&mut self) -> &mut CheatEngine {
+        &mut self.cheats
+    }
+
+    /// Power cycles the console and starts recording input from there.
+    ///
+    /// Replaces any recording or playback in progress.
+    pub fn start_movie_recording(&mut self) {
+        self.movie = None;
+        self.reset(ResetKind::PowerOn);
+        self.movie = Some(MovieDriver::record(self.ram_init));
+    }
+
+    /// Power cycles the console with the movie's RAM pattern and replays its
+    /// frames from the next [`Nes::run_frame`]. Playback stops by itself after
+    /// the last frame; pads then keep their last state until changed.
+    pub fn play_movie(&mut self, movie: InputMovie) {
+        self.movie = None;
+        self.ram_init = movie.ram_init;
+        self.reset(ResetKind::PowerOn);
+        self.movie = Some(MovieDriver::play(movie));
+    }
+
+    /// Stops recording or playback and returns the movie.
+    pub fn stop_movie(&mut self) -> Option<InputMovie> {
+        self.movie.take().map(MovieDriver::into_movie)
+    }
+
+    pub fn movie_mode(&self) -> Option<MovieMode> {
+        self.movie.as_ref().map(MovieDriver::mode)
+    }
+
+    /// Frames recorded so far, or frames played back so far.
+    pub fn movie_position(&self) -> Option<usize> {
+        self.movie.as_ref().map(MovieDriver::position)
+    }
+
+    /// Records the pads for the frame about to run, or applies the next
+    /// movie frame during playback.
+    fn advance_movie(&mut self) {
+        let Some(movie) = self.movie.as_mut() else {
+            return;
+        };
+        match movie.mode() {
+            MovieMode::Recording => {
+                let pads = std::array::from_fn(|pad| self.controllers[pad].state());
+                movie.record_frame(pads);
+            }
+            MovieMode::Playing => {
+                let Some(frame) = movie.next_frame() else {
+                    self.movie = None;
+                    return;
+                };
+                if let Some(kind) = frame.command {
+                    self.reset(kind);
+                }
+                for (pad, &mask) in frame.pads.iter().enumerate() {
+                    self.set_pad_state_raw(pad, mask);
+                }
+            }
+        }
+    }
+
+    fn apply_cheats(&mut self) {
+        if self.cheats.is_empty() {
+            return;
+        }
+        let mut target = CheatMemory {
+            ram: &mut self.ram,
+            cartridge: self.cartridge.as_mut(),
+        };
+        self.cheats.apply(&mut target);
+    }
+
     /// Latest audio sample from the APU mixer plus any cartridge expansion audio.
     pub fn audio_sample(&self) -> f32 {
         let base = self.apu.sample();
@@ -471,7 +836,8 @@ impl Nes {
         base + expansion_mono
     }
 
-    /// Update the mixer to a new host sample rate (resets mixer state).
+    /// Update the mixer to a new host sample rate. Buffered audio is carried
+    /// over and crossfaded rather than reset, so this is safe mid-game.
     pub fn set_audio_sample_rate(&mut self, sample_rate: u32) {
         self.audio_sample_rate = sample_rate;
         self.sound_bus.set_output_rate(sample_rate);
@@ -498,9 +864,46 @@ impl Nes {
         self.sound_bus.reset_resample_input_rate();
     }
 
-    /// Apply per-channel mixer settings (volume / panning).
+    /// Apply per-channel mixer settings (volume / panning / pop reduction).
     pub fn set_mixer_settings(&mut self, settings: &crate::audio::MixerSettings) {
         self.mixer.apply_mixer_settings(settings);
+        self.apu.set_pop_reduction(apu::PopReduction {
+            silence_triangle_ultrasonic: settings.silence_triangle_ultrasonic,
+            reduce_dmc_popping: settings.reduce_dmc_popping,
+        });
+    }
+
+    /// Mixes audio with integer arithmetic so resimulated frames (rollback,
+    /// netplay) produce bit-identical PCM on every platform.
+    pub fn set_fixed_point_audio(&mut self, enabled: bool) {
+        self.fixed_point_audio = enabled;
+        self.mixer
+            .set_fixed_point(self.fixed_point_audio || self.deterministic);
+    }
+
+    /// Makes emulation depend only on the ROM, the reset history and the
+    /// inputs, for TAS playback, netplay and other replays that must match
+    /// bit for bit across runs and machines.
+    ///
+    /// While enabled:
+    /// - audio is mixed in fixed point (see [`Nes::set_fixed_point_audio`]),
+    ///   so mixer levels don't depend on the platform's float behaviour;
+    /// - light guns sense brightness through a fixed reference palette
+    ///   instead of the user's display palette.
+    ///
+    /// Power-on RAM, VRAM and register contents are always fixed patterns
+    /// ([`RamInit::Random`] is seeded), and the core never reads the host
+    /// clock. Use [`Nes::state_hash`] to
+    /// compare runs.
+    pub fn set_deterministic(&mut self, enabled: bool) {
+        self.deterministic = enabled;
+        self.ppu.set_reference_light_palette(enabled);
+        self.mixer
+            .set_fixed_point(self.fixed_point_audio || self.deterministic);
+    }
+
+    pub fn deterministic(&self) -> bool {
+        self.deterministic
     }
 
     /// Current audio sample rate used by the internal mixer.
@@ -513,6 +916,61 @@ impl Nes {
         self.sound_bus.set_config(config);
     }
 
+    pub fn audio_bus_config(&self) -> AudioBusConfig {
+        self.sound_bus.config()
+    }
+
+    /// Starts writing audio to a 16-bit WAV file at `path`, replacing any
+    /// recording in progress.
+    ///
+    /// Audio is captured at the internal mixer rate (96 kHz) before host
+    /// resampling and bus volume. [`RecordSource::Mix`] records the stereo
+    /// mix as configured by [`Nes::set_mixer_settings`];
+    /// [`RecordSource::Channel`] records one channel in mono at full volume.
+    /// Only frames run with `emit_audio` produce sound.
+    pub fn start_audio_recording(
+        &mut self,
+        path: impl AsRef<Path>,
+        source: RecordSource,
+    ) -> Result<(), Error> {
+        self.stop_audio_recording()?;
+        self.audio_recorder = Some(AudioRecorder::start(
+            path.as_ref(),
+            source,
+            INTERNAL_MIXER_SAMPLE_RATE,
+            &mut self.mixer,
+        )?);
+        Ok(())
+    }
+
+    /// Finalizes the current recording, if any. Returns the first write
+    /// error that occurred while recording.
+    pub fn stop_audio_recording(&mut self) -> Result<(), Error> {
+        match self.audio_recorder.take() {
+            Some(recorder) => Ok(recorder.finish(&mut self.mixer)?),
+            None => Ok(()),
+        }
+    }
+
+    pub fn is_recording_audio(&self) -> bool {
+        self.audio_recorder.is_some()
+    }
+
+    fn record_audio_chunk(&mut self) {
+        if let Some(recorder) = self.audio_recorder.as_mut() {
+            recorder.record(&mut self.mixer, &self.mixer_frame_buffer);
+        }
+    }
+
+    /// Snapshot of all APU channels and expansion audio levels.
+    pub fn apu_channel_state(&self) -> apu::debug::ApuChannelState {
+        let mut state = self.apu.channel_state();
+        if let Some(cart) = self.cartridge.as_ref() {
+            state.expansion = cart.expansion_audio_snapshot();
+        }
+        state
+    }
+
     /// Current APU cycle counter (CPU-rate ticks since power-on/reset).
     pub fn apu_cycles(&self) -> u64 {
         self.apu.cycle_count()
@@ -549,6 +1007,32 @@ impl Nes {
         self.ppu.copy_render_emphasis_buffer(dst);
     }
 
+    /// CRC-32 of the latest frame's palette indices and emphasis bits.
+    ///
+    /// Only depends on what the PPU drew, not on the palette, color format or
+    /// post-processor, so it works as a stored reference in regression tests.
+    pub fn frame_crc(&self) -> u32 {
+        let mut hasher = crc32fast::Hasher::new();
+        hasher.update(self.render_index_buffer());
+        hasher.update(self.render_emphasis_buffer());
+        hasher.finalize()
+    }
+
+    /// 64-bit FNV-1a hash of the latest frame's RGB colors, looked up in
+    /// [`Nes::emphasized_palette`]. Unlike [`Nes::frame_crc`] this changes
+    /// with the palette, but not with the color format or post-processor.
+    pub fn frame_hash(&self) -> u64 {
+        let palette = self.emphasized_palette();
+        self.render_index_buffer()
+            .iter()
+            .zip(self.render_emphasis_buffer())
+            .map(|(&index, &emphasis)| palette[emphasized_index(index, emphasis)])
+            .flat_map(|color| [color.r, color.g, color.b])
+            .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            })
+    }
+
     pub fn set_frame_ready_callback(
         &mut self,
         cb: Option<FrameReadyCallback>,
@@ -588,6 +1072,14 @@ impl Nes {
         self.ppu.rebuild_video_output();
     }
 
+    /// Renders the latest frame as tightly packed RGB888, returning
+    /// `(width, height, pixels)`. With `filtered` it goes through the active
+    /// post-processor at the video output size, otherwise it is the raw
+    /// 256x240 picture. Meant for screenshots; presentation is unaffected.
+    pub fn render_frame_rgb(&self, filtered: bool) -> (usize, usize, Vec<u8>) {
+        self.ppu.render_frame_rgb(filtered)
+    }
+
     /// Presents a canonical index frame immediately.
     ///
     /// Returns `false` when `indices.len() != 256 * 240`.
@@ -605,18 +1097,206 @@ impl Nes {
         self.ppu.palette()
     }
 
-    /// Updates the pressed state of a controller button (0 = port 1).
+    /// 512-color table (palette index plus emphasis) used for video output;
+    /// see [`Ppu::emphasized_palette`].
+    pub fn emphasized_palette(&self) -> &EmphasizedPalette {
+        self.ppu.emphasized_palette()
+    }
+
+    /// Enables famiclone PPU behavior (see [`Ppu::set_clone_mode`]).
+    pub fn set_clone_ppu(&mut self, enabled: bool) {
+        self.ppu.set_clone_mode(enabled);
+    }
+
+    pub fn clone_ppu(&self) -> bool {
+        self.ppu.clone_mode()
+    }
+
+    /// Shows or hides the background in the video output only (see
+    /// [`Ppu::set_background_layer_enabled`]).
+    pub fn set_background_layer_enabled(&mut self, enabled: bool) {
+        self.ppu.set_background_layer_enabled(enabled);
+    }
+
+    pub fn background_layer_enabled(&self) -> bool {
+        self.ppu.background_layer_enabled()
+    }
+
+    /// Shows or hides sprites in the video output only.
+    pub fn set_sprite_layer_enabled(&mut self, enabled: bool) {
+        self.ppu.set_sprite_layer_enabled(enabled);
+    }
+
+    pub fn sprite_layer_enabled(&self) -> bool {
+        self.ppu.sprite_layer_enabled()
+    }
+
+    /// Draws more than eight sprites per scanline to remove flicker, while
+    /// evaluation and the overflow flag keep the hardware limit (see
+    /// [`Ppu::set_sprite_limit_removed`]).
+    pub fn set_sprite_limit_removed(&mut self, removed: bool) {
+        self.ppu.set_sprite_limit_removed(removed);
+    }
+
+    pub fn sprite_limit_removed(&self) -> bool {
+        self.ppu.sprite_limit_removed()
+    }
+
+    /// Skips presenting frames while `true`, to save video work when running
+    /// faster than the display (fast-forward frame skipping).
+    ///
+    /// Emulation is unaffected. Completed frames are simply dropped: the
+    /// frame-ready callback does not fire, and the render buffers (and
+    /// [`Nes::frame_crc`]) keep showing the last presented frame.
+    pub fn set_frame_skip(&mut self, skip: bool) {
+        self.ppu.set_frame_skip(skip);
+    }
+
+    pub fn frame_skip(&self) -> bool {
+        self.ppu.frame_skip()
+    }
+
+    /// Activates an HD pack for the inserted cartridge, or turns HD output
+    /// off with `None`. Tile-number rules are matched against this
+    /// cartridge's CHR ROM, so load the pack after the ROM; inserting another
+    /// cartridge turns it off again.
+    pub fn set_hd_pack(&mut self, pack: Option<&HdPack>) {
+        let chr_rom = self
+            .cartridge
+            .as_ref()
+            .and_then(|cart| cart.mapper().memory_ref().chr_rom)
+            .unwrap_or_default();
+        self.ppu
+            .set_hd_tiles(pack.map(|pack| pack.resolve(chr_rom)));
+    }
+
+    /// Upscaled RGBA frame with HD pack graphics, updated alongside the
+    /// regular output while a pack is active.
+    pub fn hd_frame(&self) -> Option<&HdFrame> {
+        self.ppu.hd_frame()
+    }
+
+    /// Emulates OAM decay and the rarer `$2004` read quirks (see
+    /// [`Ppu::set_accurate_oam`]).
+    pub fn set_accurate_oam(&mut self, enabled: bool) {
+        self.ppu.set_accurate_oam(enabled);
+    }
+
+    pub fn accurate_oam(&self) -> bool {
+        self.ppu.accurate_oam()
+    }
+
+    /// Frames an undriven PPU open-bus bit keeps its value (see
+    /// [`Ppu::set_open_bus_decay_frames`]).
+    pub fn set_open_bus_decay_frames(&mut self, frames: u32) {
+        self.ppu.set_open_bus_decay_frames(frames);
+    }
+
+    pub fn open_bus_decay_frames(&self) -> u32 {
+        self.ppu.open_bus_decay_frames()
+    }
+
+    /// Inserts extra CPU-only scanlines around vblank each frame (see
+    /// [`Overclock`]). Values above [`Overclock::MAX_EXTRA_SCANLINES`] are
+    /// clamped.
+    pub fn set_overclock(&mut self, overclock: Overclock) {
+        self.ppu.set_overclock(overclock);
+    }
+
+    pub fn overclock(&self) -> Overclock {
+        self.ppu.overclock()
+    }
+
+    /// Selects the CPU RAM pattern for power-on resets, including the one
+    /// performed by [`Nes::insert_cartridge`]. The current RAM is untouched.
+    pub fn set_ram_init(&mut self, ram_init: RamInit) {
+        self.ram_init = ram_init;
+    }
+
+    pub fn ram_init(&self) -> RamInit {
+        self.ram_init
+    }
+
+    /// Updates the pressed state of a controller button (0 = pad 1).
+    ///
+    /// `button` is the logical button; the pad's [`ButtonRemap`] decides which
+    /// console button it presses. Pads 3 and 4 are only read by games when a
+    /// multitap is connected.
     pub fn set_button(&mut self, pad: usize, button: Button, pressed: bool) {
+        let Some(remap) = self.button_remaps.get(pad) else {
+            return;
+        };
+        let target = remap.target(button);
+        self.controllers[pad].set_button(target, pressed);
+    }
+
+    /// Sets all logical buttons of `pad` at once (bit `Button as u8`), applying its remap.
+    pub fn set_pad_state(&mut self, pad: usize, mask: u8) {
+        if let Some(remap) = self.button_remaps.get(pad) {
+            self.controllers[pad].set_state(remap.apply(mask));
+        }
+    }
+
+    /// Sets the console-visible button mask of `pad`, bypassing its remap.
+    ///
+    /// Used for input that was already remapped when captured, such as movie
+    /// frames and netplay input.
+    pub fn set_pad_state_raw(&mut self, pad: usize, mask: u8) {
         if let Some(ctrl) = self.controllers.get_mut(pad) {
-            ctrl.set_button(button, pressed);
+            ctrl.set_state(mask);
+        }
+    }
+
+    /// Sets the logical -> console button mapping for `pad`.
+    pub fn set_button_remap(&mut self, pad: usize, remap: ButtonRemap) {
+        if let Some(slot) = self.button_remaps.get_mut(pad) {
+            *slot = remap;
+        }
+    }
+
+    pub fn button_remap(&self, pad: usize) -> ButtonRemap {
+        self.button_remaps.get(pad).copied().unwrap_or_default()
+    }
+
+    /// Connects a four-player adapter, or removes it with `None`.
+    pub fn set_multitap(&mut self, kind: Option<MultitapKind>) {
+        if self.multitap.map(|tap| tap.kind()) != kind {
+            self.multitap = kind.map(Multitap::new);
+        }
+    }
+
+    pub fn multitap(&self) -> Option<MultitapKind> {
+        self.multitap.map(|tap| tap.kind())
+    }
+
+    /// Connects a Zapper to `port` (0 = port 1), or reconnects the standard pad with `None`.
+    pub fn set_zapper(&mut self, port: usize, zapper: Option<Zapper>) {
+        if let Some(slot) = self.zappers.get_mut(port) {
+            *slot = zapper;
         }
     }
 
+    /// Zapper connected to `port`, if any.
+    pub fn zapper(&self, port: usize) -> Option<&Zapper> {
+        self.zappers.get(port)?.as_ref()
+    }
+
+    /// Mutable access to the Zapper on `port` for feeding aim/trigger input.
+    pub fn zapper_mut(&mut self, port: usize) -> Option<&mut Zapper> {
+        self.zappers.get_mut(port)?.as_mut()
+    }
+
     /// Snapshot of the current CPU registers for tracing/debugging.
     pub fn cpu_snapshot(&self) -> CpuSnapshot {
         self.cpu.snapshot()
     }
 
+    /// The JAM opcode the CPU is halted on, if any. The CPU stays halted,
+    /// ignoring interrupts, until the next reset.
+    pub fn cpu_jam(&self) -> Option<CpuJam> {
+        self.cpu.jam
+    }
+
     /// Returns `true` when the CPU is mid-instruction (opcode + micro-ops still in flight).
     pub fn cpu_opcode_active(&self) -> bool {
         self.cpu.opcode_active()
@@ -637,6 +1317,86 @@ impl Nes {
         self.cycles
     }
 
+    /// Attaches a breakpoint engine (if not already attached) and returns it.
+    pub fn enable_debugger(&mut self) -> &mut Debugger {
+        self.debugger.get_or_insert_with(Debugger::new)
+    }
+
+    /// Detaches the breakpoint engine, returning it with its breakpoints.
+    pub fn disable_debugger(&mut self) -> Option<Debugger> {
+        self.debugger.take()
+    }
+
+    pub fn debugger(&self) -> Option<&Debugger> {
+        self.debugger.as_ref()
+    }
+
+    pub fn debugger_mut(&mut self) -> Option<&mut Debugger> {
+        self.debugger.as_mut()
+    }
+
+    /// Steps CPU cycles until a breakpoint or watchpoint fires or `max_cycles`
+    /// have run.
+    ///
+    /// The call returns at the end of the CPU cycle that triggered the hit, so
+    /// after an execute break the opcode has been fetched but the rest of the
+    /// instruction has not run yet. Calling this again resumes from there.
+    /// A hit left pending by [`Nes::run_frame`] is returned immediately.
+    pub fn run_until_break(&mut self, max_cycles: u64) -> StopReason {
+        self.run_debug(max_cycles, |_, _| false)
+    }
+
+    /// Runs the current instruction to completion, treating JSR/BRK and any
+    /// interrupt taken on the way as a single step.
+    ///
+    /// Attaches a debugger if none is present, since call depth is tracked
+    /// from the bus. Stops early on breakpoints and watchpoints.
+    pub fn step_over(&mut self, max_cycles: u64) -> StopReason {
+        let at_boundary = self.cpu.at_instruction_boundary();
+        let start = self.enable_debugger().frame_depth(at_boundary);
+        self.run_debug(max_cycles, |_, dbg| dbg.depth() <= start)
+    }
+
+    /// Runs until the current subroutine or interrupt handler returns.
+    pub fn step_out(&mut self, max_cycles: u64) -> StopReason {
+        let at_boundary = self.cpu.at_instruction_boundary();
+        let start = self.enable_debugger().frame_depth(at_boundary);
+        self.run_debug(max_cycles, |_, dbg| dbg.depth() < start)
+    }
+
+    /// Runs until the CPU is about to execute the instruction at `pc`
+    /// (run-to-cursor). At least one instruction always executes.
+    pub fn run_to(&mut self, pc: u16, max_cycles: u64) -> StopReason {
+        self.enable_debugger();
+        self.run_debug(max_cycles, |cpu, _| cpu.pc == pc)
+    }
+
+    /// Shared driver for the debugger run modes: steps CPU cycles until a
+    /// pending hit, until `done` holds at an instruction boundary, or until
+    /// `max_cycles` have run.
+    fn run_debug(&mut self, max_cycles: u64, done: impl Fn(&Cpu, &Debugger) -> bool) -> StopReason {
+        for _ in 0..max_cycles {
+            if let Some(stop) = self.debugger.as_mut().and_then(Debugger::take_stop) {
+                return stop;
+            }
+            self.step_cpu_cycle(false);
+            if let Some(debugger) = self.debugger.as_ref()
+                && self.cpu.at_instruction_boundary()
+                && done(&self.cpu, debugger)
+            {
+                return self
+                    .debugger
+                    .as_mut()
+                    .and_then(Debugger::take_stop)
+                    .unwrap_or(StopReason::StepComplete);
+            }
+        }
+        self.debugger
+            .as_mut()
+            .and_then(Debugger::take_stop)
+            .unwrap_or(StopReason::CycleLimit)
+    }
+
     /// Executes the next instruction (advancing CPU/PPU/APU as needed).
     pub fn step_instruction(&mut self) {
         let mut seen_active = false;
@@ -650,6 +1410,21 @@ impl Nes {
         }
     }
 
+    /// Executes CPU cycles until the CPU is about to fetch the next opcode.
+    ///
+    /// Unlike [`Nes::step_instruction`], interrupt sequences are folded into
+    /// the preceding instruction, so each call lands where Mesen2 would emit
+    /// the next trace log line. A jammed CPU never fetches again, so this
+    /// returns after one cycle once [`Nes::cpu_jam`] is set.
+    pub fn step_to_instruction_boundary(&mut self) {
+        loop {
+            self.step_cpu_cycle(false);
+            if self.cpu.at_instruction_boundary() || self.cpu.jam.is_some() {
+                break;
+            }
+        }
+    }
+
     /// Reads a byte from the CPU address space without mutating CPU state.
     pub fn peek_cpu_byte(&mut self, addr: u16) -> u8 {
         let mut bus = nes_cpu_bus!(self, mixer: false, serial: true);
@@ -675,6 +1450,28 @@ impl Nes {
         self.serial_log.drain()
     }
 
+    /// Starts or stops counting CPU writes that reach no register or memory:
+    /// the `$4018-$401F` test-mode range, and cartridge-space addresses that
+    /// float on read. Meant for compatibility triage; write-only mapper
+    /// registers below `$6000` show up as well. Disabling drops the counts.
+    pub fn set_unmapped_write_tracking(&mut self, enabled: bool) {
+        if enabled {
+            self.unmapped_writes.get_or_insert_with(BTreeMap::new);
+        } else {
+            self.unmapped_writes = None;
+        }
+    }
+
+    /// Addresses written while unmapped and how many times, sorted by
+    /// address. Empty unless [`Nes::set_unmapped_write_tracking`] is on.
+    pub fn unmapped_writes(&self) -> Vec<(u16, u32)> {
+        self.unmapped_writes
+            .iter()
+            .flatten()
+            .map(|(&addr, &count)| (addr, count))
+            .collect()
+    }
+
     /// Returns a snapshot of CPU and PPU state for debugging.
     pub fn debug_state(&self) -> CpuSnapshot {
         CpuSnapshot {
@@ -703,6 +1500,63 @@ impl Nes {
         )
     }
 
+    /// Decodes all four nametables with their attribute colors for a
+    /// nametable viewer. See [`ppu::debug::nametables`].
+    pub fn nametable_view(&self) -> ppu::debug::NametableView {
+        ppu::debug::nametables(&self.ppu, self.cartridge.as_ref())
+    }
+
+    /// Renders both pattern tables for a CHR viewer. See
+    /// [`ppu::debug::pattern_tables`].
+    pub fn pattern_table_view(&self, palette: ppu::debug::ChrPalette) -> Vec<u8> {
+        ppu::debug::pattern_tables(&self.ppu, self.cartridge.as_ref(), palette)
+    }
+
+    /// Decodes primary OAM for a sprite viewer. See [`ppu::debug::oam_entries`].
+    pub fn oam_entries(&self) -> Vec<ppu::debug::OamEntry> {
+        ppu::debug::oam_entries(&self.ppu)
+    }
+
+    // =========================================================================
+    // Scanline / frame hooks
+    // =========================================================================
+
+    /// Calls `hook` every time the PPU reaches `scanline`/`dot`, from inside
+    /// [`run_frame`](Self::run_frame) and the other stepping methods.
+    pub fn add_scanline_hook(
+        &mut self,
+        scanline: i16,
+        dot: u16,
+        hook: impl FnMut(&mut Cpu, &mut CpuBus, HookEvent) + Send + 'static,
+    ) -> HookId {
+        self.add_hook(HookTrigger::ScanlineDot { scanline, dot }, Box::new(hook))
+    }
+
+    /// Calls `hook` once per frame, right after the frame is presented.
+    pub fn add_frame_hook(
+        &mut self,
+        hook: impl FnMut(&mut Cpu, &mut CpuBus, HookEvent) + Send + 'static,
+    ) -> HookId {
+        self.add_hook(HookTrigger::FrameComplete, Box::new(hook))
+    }
+
+    fn add_hook(&mut self, trigger: HookTrigger, hook: HookFn) -> HookId {
+        if self.interceptor.layer::<HookInterceptor>().is_none() {
+            self.interceptor.add(HookInterceptor::new());
+        }
+        self.interceptor
+            .layer_mut::<HookInterceptor>()
+            .expect("hook layer was just added")
+            .add(trigger, hook)
+    }
+
+    /// Unregisters a hook; returns false if it was already gone.
+    pub fn remove_hook(&mut self, id: HookId) -> bool {
+        self.interceptor
+            .layer_mut::<HookInterceptor>()
+            .is_some_and(|layer| layer.remove(id))
+    }
+
     // =========================================================================
     // Tilemap capture point / snapshot
     // =========================================================================
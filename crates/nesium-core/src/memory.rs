@@ -4,6 +4,10 @@
 //! location, prevents magic numbers from sneaking into other modules, and makes
 //! it easier to reference the original console documentation while reading the
 //! code base.
+//!
+//! [`domain`] builds on it to expose named memory regions to tooling.
+
+pub mod domain;
 
 /// CPU memory map details.
 pub mod cpu {
@@ -0,0 +1,128 @@
+//! Named memory regions for tooling.
+//!
+//! Hex editors, cheat search, scripting and achievement runtimes want to
+//! address "offset `$12` of work RAM" rather than a CPU or PPU address whose
+//! meaning depends on mirroring and banking. A [`MemoryDomain`] names one
+//! backing store; offsets start at 0 and never go through a bus, so reads have
+//! no side effects and writes bypass mapper registers.
+
+use crate::Nes;
+
+/// A memory region exposed through [`Nes::peek_memory`] and friends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MemoryDomain {
+    /// 2 KiB CPU internal RAM (`$0000-$07FF`, unmirrored).
+    CpuRam,
+    /// Cartridge PRG-RAM, battery-backed or not.
+    PrgRam,
+    /// The whole PRG-ROM image. Read-only.
+    PrgRom,
+    /// Cartridge CHR-RAM.
+    ChrRam,
+    /// 256 bytes of primary sprite OAM.
+    Oam,
+    /// 32 bytes of palette RAM (`$3F00-$3F1F`, before mirroring).
+    PaletteRam,
+    /// The console's 2 KiB nametable RAM (CIRAM). Extra nametable RAM on the
+    /// cartridge is not included.
+    Nametables,
+}
+
+impl MemoryDomain {
+    /// Every domain, in display order.
+    pub const ALL: [MemoryDomain; 7] = [
+        MemoryDomain::CpuRam,
+        MemoryDomain::PrgRam,
+        MemoryDomain::PrgRom,
+        MemoryDomain::ChrRam,
+        MemoryDomain::Oam,
+        MemoryDomain::PaletteRam,
+        MemoryDomain::Nametables,
+    ];
+
+    /// Human readable name for domain pickers.
+    pub fn name(self) -> &'static str {
+        match self {
+            MemoryDomain::CpuRam => "RAM",
+            MemoryDomain::PrgRam => "PRG RAM",
+            MemoryDomain::PrgRom => "PRG ROM",
+            MemoryDomain::ChrRam => "CHR RAM",
+            MemoryDomain::Oam => "OAM",
+            MemoryDomain::PaletteRam => "Palette RAM",
+            MemoryDomain::Nametables => "Nametables",
+        }
+    }
+
+    /// Returns `true` for domains that [`Nes::write_memory`] refuses to modify.
+    pub fn is_read_only(self) -> bool {
+        matches!(self, MemoryDomain::PrgRom)
+    }
+}
+
+impl Nes {
+    fn memory_domain(&self, domain: MemoryDomain) -> Option<&[u8]> {
+        let cart_memory = || {
+            self.cartridge
+                .as_ref()
+                .map(|cart| cart.mapper().memory_ref())
+        };
+        match domain {
+            MemoryDomain::CpuRam => Some(self.ram.as_slice()),
+            MemoryDomain::PrgRam => cart_memory()?.prg_ram,
+            MemoryDomain::PrgRom => cart_memory()?.prg_rom,
+            MemoryDomain::ChrRam => cart_memory()?.chr_ram,
+            MemoryDomain::Oam => Some(self.ppu.registers.oam.as_slice()),
+            MemoryDomain::PaletteRam => Some(self.ppu.palette_ram.as_slice()),
+            MemoryDomain::Nametables => Some(self.ppu.ciram.as_slice()),
+        }
+    }
+
+    fn memory_domain_mut(&mut self, domain: MemoryDomain) -> Option<&mut [u8]> {
+        match domain {
+            MemoryDomain::CpuRam => Some(self.ram.as_mut_slice()),
+            MemoryDomain::PrgRam => self.cartridge.as_mut()?.mapper_mut().memory_mut().prg_ram,
+            MemoryDomain::PrgRom => None,
+            MemoryDomain::ChrRam => self.cartridge.as_mut()?.mapper_mut().memory_mut().chr_ram,
+            MemoryDomain::Oam => Some(self.ppu.registers.oam.as_mut_slice()),
+            MemoryDomain::PaletteRam => Some(self.ppu.palette_ram.as_mut_slice()),
+            MemoryDomain::Nametables => Some(self.ppu.ciram.as_mut_slice()),
+        }
+    }
+
+    /// Size of `domain` in bytes; 0 when the loaded cartridge (if any) does
+    /// not have it.
+    pub fn memory_domain_size(&self, domain: MemoryDomain) -> usize {
+        self.memory_domain(domain).map_or(0, <[u8]>::len)
+    }
+
+    /// Reads the byte at `offset` in `domain`, or `None` past its end.
+    pub fn peek_memory(&self, domain: MemoryDomain, offset: usize) -> Option<u8> {
+        self.memory_domain(domain)?.get(offset).copied()
+    }
+
+    /// Copies bytes starting at `offset` into `buffer` and returns how many
+    /// were available.
+    pub fn read_memory(&self, domain: MemoryDomain, offset: usize, buffer: &mut [u8]) -> usize {
+        let Some(src) = self.memory_domain(domain).and_then(|mem| mem.get(offset..)) else {
+            return 0;
+        };
+        let len = src.len().min(buffer.len());
+        buffer[..len].copy_from_slice(&src[..len]);
+        len
+    }
+
+    /// Writes `value` at `offset` in `domain`. Returns `false` when the
+    /// domain is read-only or missing, or `offset` is past its end.
+    pub fn write_memory(&mut self, domain: MemoryDomain, offset: usize, value: u8) -> bool {
+        match self
+            .memory_domain_mut(domain)
+            .and_then(|mem| mem.get_mut(offset))
+        {
+            Some(byte) => {
+                *byte = value;
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Frame-by-frame input recording and playback.
+//!
+//! [`Nes::start_movie_recording`](crate::Nes::start_movie_recording) power
+//! cycles the console and then logs, for every
+//! [`Nes::run_frame`](crate::Nes::run_frame), the console-visible pad masks
+//! plus any reset or power cycle issued since the previous frame.
+//! [`Nes::play_movie`](crate::Nes::play_movie) repeats the same power-on and
+//! feeds the frames back, so a movie replays identically as long as the
+//! cartridge and emulation settings match (see
+//! [`Nes::set_deterministic`](crate::Nes::set_deterministic)).
+//!
+//! File formats (FM2 and friends) live in `nesium-support`, which converts its
+//! `Movie` IR to and from [`InputMovie`].
+
+use crate::{config::ram_init::RamInit, reset_kind::ResetKind};
+
+/// Input for a single frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MovieFrame {
+    /// Reset or power cycle applied before the frame's input.
+    pub command: Option<ResetKind>,
+    /// Console-visible button masks for pads 1-4, after button remapping.
+    pub pads: [u8; 4],
+}
+
+/// An input recording that starts from a power-on.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct InputMovie {
+    /// CPU RAM power-on pattern in effect when recording started.
+    pub ram_init: RamInit,
+    pub frames: Vec<MovieFrame>,
+}
+
+impl InputMovie {
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MovieMode {
+    Recording,
+    Playing,
+}
+
+/// Recording or playback attached to a [`Nes`](crate::Nes).
+#[derive(Debug, Clone)]
+pub(crate) struct MovieDriver {
+    movie: InputMovie,
+    mode: MovieMode,
+    /// Next frame to play back.
+    cursor: usize,
+    /// Command to attach to the next recorded frame.
+    pending_command: Option<ResetKind>,
+}
+
+impl MovieDriver {
+    pub(crate) fn record(ram_init: RamInit) -> Self {
+        Self {
+            movie: InputMovie {
+                ram_init,
+                frames: Vec::new(),
+            },
+            mode: MovieMode::Recording,
+            cursor: 0,
+            pending_command: None,
+        }
+    }
+
+    pub(crate) fn play(movie: InputMovie) -> Self {
+        Self {
+            movie,
+            mode: MovieMode::Playing,
+            cursor: 0,
+            pending_command: None,
+        }
+    }
+
+    pub(crate) fn mode(&self) -> MovieMode {
+        self.mode
+    }
+
+    /// Frames recorded so far, or frames already played back.
+    pub(crate) fn position(&self) -> usize {
+        match self.mode {
+            MovieMode::Recording => self.movie.frames.len(),
+            MovieMode::Playing => self.cursor,
+        }
+    }
+
+    /// Notes a console reset while recording. A power cycle supersedes a
+    /// soft reset issued during the same frame.
+    pub(crate) fn note_reset(&mut self, kind: ResetKind) {
+        if self.mode == MovieMode::Recording && self.pending_command != Some(ResetKind::PowerOn) {
+            self.pending_command = Some(kind);
+        }
+    }
+
+    pub(crate) fn record_frame(&mut self, pads: [u8; 4]) {
+        let command = self.pending_command.take();
+        self.movie.frames.push(MovieFrame { command, pads });
+    }
+
+    /// Returns the next frame to play back, or `None` once the movie ends.
+    pub(crate) fn next_frame(&mut self) -> Option<MovieFrame> {
+        let frame = self.movie.frames.get(self.cursor).copied()?;
+        self.cursor += 1;
+        Some(frame)
+    }
+
+    pub(crate) fn into_movie(self) -> InputMovie {
+        self.movie
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorded_commands_attach_to_the_next_frame() {
+        let mut driver = MovieDriver::record(RamInit::Ones);
+        driver.record_frame([1, 0, 0, 0]);
+        driver.note_reset(ResetKind::PowerOn);
+        driver.note_reset(ResetKind::Soft);
+        driver.record_frame([2, 0, 0, 0]);
+        driver.record_frame([3, 0, 0, 0]);
+        assert_eq!(driver.position(), 3);
+
+        let movie = driver.into_movie();
+        assert_eq!(movie.ram_init, RamInit::Ones);
+        let commands: Vec<_> = movie.frames.iter().map(|f| f.command).collect();
+        assert_eq!(commands, [None, Some(ResetKind::PowerOn), None]);
+
+        let mut player = MovieDriver::play(movie);
+        player.note_reset(ResetKind::Soft);
+        assert_eq!(player.next_frame().map(|f| f.pads[0]), Some(1));
+        assert_eq!(
+            player.next_frame().map(|f| f.command),
+            Some(Some(ResetKind::PowerOn))
+        );
+        assert_eq!(player.next_frame().map(|f| f.command), Some(None));
+        assert_eq!(player.next_frame(), None);
+        assert_eq!(player.position(), 3);
+    }
+}
@@ -29,6 +29,8 @@ pub mod palette;
 
 mod background_pipeline;
 pub mod buffer;
+pub mod debug;
+pub mod hd_pack;
 mod open_bus;
 mod pending_vram_increment;
 pub(crate) mod ppu_bus;
@@ -45,7 +47,8 @@ pub(crate) use sprite_state::SpriteLineBuffers;
 
 use self::{
     background_pipeline::BgPipeline,
-    sprite_pipeline::SpritePipeline,
+    hd_pack::{HdCapture, HdFetchedTile, HdSample, HdTileKey, HdTiles},
+    sprite_pipeline::{ExtraSprites, SpritePipeline},
     sprite_state::{SpriteEvalState, SpriteFetchState},
 };
 
@@ -58,8 +61,10 @@ use crate::{
         NametableTarget, PpuRenderFetchInfo, PpuRenderFetchTarget, PpuRenderFetchType,
         PpuVramAccessContext, PpuVramAccessKind, PpuVramAccessSource,
     },
+    config::overclock::Overclock,
     context::Context,
     cpu::Cpu,
+    debugger::{AccessKind, MemoryAccess},
     interceptor::Interceptor,
     mem_block::ppu::{Ciram, SecondaryOamRam},
     memory::ppu::{self as ppu_mem, Register as PpuRegister},
@@ -68,10 +73,10 @@ use crate::{
         buffer::FrameReadyCallback,
         buffer::VideoPostProcessor,
         open_bus::PpuOpenBus,
-        palette::{Palette, PaletteRam},
+        palette::{EmphasizedPalette, Palette, PaletteKind, PaletteRam},
         ppu_bus::PpuBus,
         registers::{Registers, VramAddr},
-        sprite::SpriteView,
+        sprite::{SpriteAttributes, SpriteView},
     },
     reset_kind::ResetKind,
 };
@@ -81,6 +86,14 @@ pub const SCREEN_HEIGHT: usize = 240;
 const CYCLES_PER_SCANLINE: u16 = 341;
 const SCANLINES_PER_FRAME: i16 = 262; // -1 (prerender) + 0..239 visible + post + vblank (241..260)
 const SCREEN_PIXEL_COUNT: i32 = (SCREEN_WIDTH * SCREEN_HEIGHT) as i32;
+/// An OAM row left unaccessed for ~3000 CPU cycles (12 master clocks each)
+/// decays (Mesen2's `OamDecayCycleCount`).
+const OAM_DECAY_MASTER_CLOCKS: u64 = 3_000 * 12;
+/// Value decayed OAM bytes read back as.
+const OAM_DECAY_VALUE: u8 = 0x10;
+/// Default number of frames an undriven PPU open-bus bit holds its value
+/// (Mesen2's decay window; real hardware holds it for roughly 600 ms).
+pub const OPEN_BUS_DECAY_FRAMES: u32 = 3;
 
 /// Entry points for the CPU PPU register mirror.
 #[derive(Clone)]
@@ -102,6 +115,12 @@ pub struct Ppu {
     pub(crate) scanline: i16,
     /// Total number of frames produced so far.
     pub(crate) frame: u32,
+    /// Extra idle scanlines inserted around vblank.
+    pub(crate) overclock: Overclock,
+    /// Extra scanlines already run in the current frame.
+    pub(crate) overclock_line: u16,
+    /// The current scanline is an inserted repeat of line 240 or 260.
+    pub(crate) in_overclock_scanline: bool,
     /// Master clock in PPU master cycles (4 master cycles per dot).
     pub(crate) master_clock: u64,
     /// Background pixel pipeline (pattern and attribute shifters).
@@ -119,6 +138,14 @@ pub struct Ppu {
     pub(crate) bg_next_tile_addr: u16,
     /// Sprite pixel pipeline for the current scanline.
     pub(crate) sprite_pipeline: SpritePipeline,
+    /// Show sprites past the eighth per line (see
+    /// [`Ppu::set_sprite_limit_removed`]).
+    pub(crate) sprite_limit_removed: bool,
+    /// Display-only sprites past the eighth, when the limit is removed.
+    pub(crate) extra_sprites: ExtraSprites,
+    /// Tiles behind the pixel pipelines, tracked only while an HD pack is
+    /// active.
+    pub(crate) hd_capture: HdCapture,
     /// Current level of the NMI output line (true = asserted).
     pub(crate) nmi_level: bool,
     /// When true, suppresses the upcoming VBlank flag/NMI edge for this frame.
@@ -146,6 +173,23 @@ pub struct Ppu {
     pub(crate) sprite_line_next: SpriteLineBuffers,
     /// Master system palette used to map palette indices to RGB colors.
     pub(crate) palette: Palette,
+    /// 512-color expansion of the output palette, rebuilt whenever the
+    /// palette or clone mode changes.
+    pub(crate) output_colors: Box<EmphasizedPalette>,
+    /// Emulate common famiclone PPU behavior instead of the 2C02 (see
+    /// [`Ppu::set_clone_mode`]).
+    pub(crate) clone_mode: bool,
+    /// `$2001` bits 5/6 select green/red instead of red/green (see
+    /// [`Ppu::set_swapped_emphasis`]).
+    pub(crate) swapped_emphasis: bool,
+    /// Light sensing ignores the configured palette (see
+    /// [`Ppu::set_reference_light_palette`]).
+    pub(crate) reference_light_palette: bool,
+    /// Display-only layer switches (see [`Ppu::set_background_layer_enabled`]).
+    pub(crate) background_layer: bool,
+    pub(crate) sprite_layer: bool,
+    /// Memory access made by the last `$2007` read/write, for watchpoints.
+    pub(crate) data_access: Option<MemoryAccess>,
     /// Effective rendering enable latch (Mesen-style), true when either
     /// background or sprites are enabled.
     pub(crate) render_enabled: bool,
@@ -156,6 +200,11 @@ pub struct Ppu {
     pub(crate) oam_addr_disable_glitch_pending: bool,
     /// OAM row corruption flags (Mesen2 `SetOamCorruptionFlags` / `ProcessOamCorruption`).
     pub(crate) corrupt_oam_row: [bool; 32],
+    /// OAM decay and pre-render/attribute `$2004` quirks (see
+    /// [`Ppu::set_accurate_oam`]).
+    pub(crate) accurate_oam: bool,
+    /// Master clock of the last access to each 8-byte OAM row.
+    pub(crate) oam_row_access: [u64; 32],
     /// Pending state update request from $2001/$2006/$2007/VRAM-related
     /// side effects. Mirrors Mesen's `_needStateUpdate` latch.
     pub(crate) state_update_pending: bool,
@@ -216,6 +265,9 @@ impl Ppu {
             scanline: -1,
             frame: 1,
             master_clock: 0,
+            overclock: Overclock::default(),
+            overclock_line: 0,
+            in_overclock_scanline: false,
             bg_pipeline: BgPipeline::new(),
             bg_next_tile_index: 0,
             bg_next_attr_byte: 0,
@@ -223,6 +275,9 @@ impl Ppu {
             bg_next_pattern_high: 0,
             bg_next_tile_addr: 0,
             sprite_pipeline: SpritePipeline::new(),
+            sprite_limit_removed: false,
+            extra_sprites: ExtraSprites::default(),
+            hd_capture: HdCapture::default(),
             nmi_level: false,
             prevent_vblank_flag: false,
             open_bus: PpuOpenBus::new(),
@@ -235,10 +290,19 @@ impl Ppu {
             sprite_fetch: SpriteFetchState::default(),
             sprite_line_next: SpriteLineBuffers::new(),
             palette: Palette::default(),
+            output_colors: Palette::default().emphasized(),
+            clone_mode: false,
+            swapped_emphasis: false,
+            reference_light_palette: false,
+            background_layer: true,
+            sprite_layer: true,
+            data_access: None,
             render_enabled: false,
             prev_render_enabled: false,
             oam_addr_disable_glitch_pending: false,
             corrupt_oam_row: [false; 32],
+            accurate_oam: false,
+            oam_row_access: [0; 32],
             state_update_pending: false,
             output_grayscale: false,
             output_emphasis: 0,
@@ -307,6 +371,8 @@ impl Ppu {
         self.scanline = -1;
         self.frame = 1;
         self.master_clock = 0;
+        self.overclock_line = 0;
+        self.in_overclock_scanline = false;
 
         // Clear delayed $2006 write and pending VRAM increment.
         self.pending_vram_delay = 0;
@@ -323,6 +389,7 @@ impl Ppu {
         self.bg_next_pattern_high = 0;
         self.bg_next_tile_addr = 0;
         self.sprite_pipeline.clear();
+        self.extra_sprites.clear();
         self.sprite_eval = SpriteEvalState::default();
         self.sprite_fetch = SpriteFetchState::default();
         self.sprite_line_next.clear();
@@ -336,6 +403,8 @@ impl Ppu {
         self.oam_copybuffer = 0;
         self.oam_addr_disable_glitch_pending = false;
         self.corrupt_oam_row = [false; 32];
+        // `master_clock` restarts from zero, so restart the decay timers too.
+        self.oam_row_access = [0; 32];
 
         // Sprite-0 debug info is per-frame; drop it on reset.
 
@@ -346,7 +415,7 @@ impl Ppu {
         self.prev_render_enabled = false;
         self.state_update_pending = true;
         self.output_grayscale = self.registers.mask.contains(Mask::GRAYSCALE);
-        self.output_emphasis = (self.registers.mask.bits() >> 5) & 0x07;
+        self.output_emphasis = self.mask_emphasis();
         self.color_mask_last_updated_pixel = -1;
         self.raw_output_indices.fill(0);
 
@@ -390,6 +459,24 @@ impl Ppu {
         self.framebuffer.copy_render_emphasis_buffer(dst);
     }
 
+    /// Average RGB level of a pixel in the frame currently being rendered.
+    ///
+    /// Used by light-sensing peripherals, which react to what the beam has
+    /// just drawn rather than the last presented frame.
+    pub(crate) fn pixel_brightness(&self, x: usize, y: usize) -> u8 {
+        let plane = self
+            .framebuffer
+            .index_plane(self.framebuffer.active_plane_index());
+        static REFERENCE_PALETTE: Palette = PaletteKind::NesdevNtsc.palette();
+        let palette = if self.reference_light_palette && !self.clone_mode {
+            &REFERENCE_PALETTE
+        } else {
+            self.output_palette()
+        };
+        let color = palette.color(plane[y * SCREEN_WIDTH + x]);
+        ((color.r as u16 + color.g as u16 + color.b as u16) / 3) as u8
+    }
+
     pub fn set_frame_ready_callback(
         &mut self,
         cb: Option<FrameReadyCallback>,
@@ -419,6 +506,7 @@ impl Ppu {
     /// Replaces the master system palette used for color conversion.
     pub fn set_palette(&mut self, palette: Palette) {
         self.palette = palette;
+        self.output_colors = self.output_palette().emphasized();
     }
 
     /// Master system palette used to map palette indices to RGB colors.
@@ -426,6 +514,183 @@ impl Ppu {
         &self.palette
     }
 
+    /// Switches between 2C02 behavior and a generic famiclone PPU.
+    ///
+    /// Clone mode ignores the `$2001` emphasis bits, renders with the
+    /// approximate [`palette::FAMICLONE_APPROX_PALETTE`] instead of the
+    /// configured palette, and evaluates sprite overflow correctly (clones
+    /// lack the 2C02's diagonal OAM scan after the eighth sprite).
+    pub fn set_clone_mode(&mut self, enabled: bool) {
+        self.clone_mode = enabled;
+        self.output_emphasis = self.mask_emphasis();
+        self.output_colors = self.output_palette().emphasized();
+    }
+
+    /// Swaps the red and green emphasis bits, as wired on the PAL 2C07 and
+    /// Dendy PPUs (`$2001` bit 5 emphasizes green, bit 6 red).
+    pub fn set_swapped_emphasis(&mut self, enabled: bool) {
+        self.swapped_emphasis = enabled;
+        self.output_emphasis = self.mask_emphasis();
+    }
+
+    pub fn swapped_emphasis(&self) -> bool {
+        self.swapped_emphasis
+    }
+
+    /// The 512-color table the framebuffer converts pixels with, indexed by
+    /// [`palette::emphasized_index`] of the index and emphasis planes.
+    pub fn emphasized_palette(&self) -> &EmphasizedPalette {
+        &self.output_colors
+    }
+
+    /// Makes light gun brightness sensing use the Nesdev NTSC palette rather
+    /// than the configured display palette, so a user's palette choice can't
+    /// change whether a shot hits. Clone mode keeps its own fixed palette.
+    pub fn set_reference_light_palette(&mut self, enabled: bool) {
+        self.reference_light_palette = enabled;
+    }
+
+    /// Hides the background from the video output without touching `$2001`.
+    ///
+    /// Only the displayed pixels change: sprite 0 hits, `$2002` and all other
+    /// emulated behaviour still see the layer, so games run unchanged.
+    pub fn set_background_layer_enabled(&mut self, enabled: bool) {
+        self.background_layer = enabled;
+    }
+
+    pub fn background_layer_enabled(&self) -> bool {
+        self.background_layer
+    }
+
+    /// Sprite counterpart of [`Ppu::set_background_layer_enabled`].
+    pub fn set_sprite_layer_enabled(&mut self, enabled: bool) {
+        self.sprite_layer = enabled;
+    }
+
+    pub fn sprite_layer_enabled(&self) -> bool {
+        self.sprite_layer
+    }
+
+    /// Draws every sprite on a scanline instead of only the first eight,
+    /// removing the flicker games use to cycle sprites past the limit.
+    ///
+    /// Evaluation, the overflow flag and the mapper-visible CHR fetches are
+    /// unchanged; the extra sprites are looked up separately and only drawn
+    /// behind the eight real ones, so game logic sees the hardware limit.
+    pub fn set_sprite_limit_removed(&mut self, removed: bool) {
+        self.sprite_limit_removed = removed;
+        if !removed {
+            self.extra_sprites.clear();
+        }
+    }
+
+    pub fn sprite_limit_removed(&self) -> bool {
+        self.sprite_limit_removed
+    }
+
+    /// Turns on the HD frame path for a pack resolved against the loaded
+    /// cartridge, or off with `None`.
+    pub(crate) fn set_hd_tiles(&mut self, tiles: Option<HdTiles>) {
+        self.hd_capture = HdCapture::default();
+        self.framebuffer.set_hd_tiles(tiles);
+    }
+
+    /// Discards completed frames instead of presenting them (see
+    /// [`Nes::set_frame_skip`](crate::Nes::set_frame_skip)).
+    pub(crate) fn set_frame_skip(&mut self, skip: bool) {
+        self.framebuffer.set_skip_present(skip);
+    }
+
+    pub(crate) fn frame_skip(&self) -> bool {
+        self.framebuffer.skip_present()
+    }
+
+    /// Shows a light gun crosshair at `position` in presented frames, or
+    /// hides it with `None`.
+    pub(crate) fn set_crosshair(&mut self, position: Option<(u16, u16)>) {
+        self.framebuffer.set_crosshair(position);
+    }
+
+    /// Upscaled frame with HD pack replacements, when a pack is active.
+    pub fn hd_frame(&self) -> Option<&buffer::HdFrame> {
+        self.framebuffer.hd_frame()
+    }
+
+    pub fn clone_mode(&self) -> bool {
+        self.clone_mode
+    }
+
+    /// Enables OAM details that only accuracy tests and a few demos rely on.
+    ///
+    /// - OAM is DRAM that rendering refreshes. An 8-byte row that goes
+    ///   ~3000 CPU cycles without being read or written decays, reading back
+    ///   as `$10` from then on.
+    /// - The unimplemented bits of sprite byte 2 are dropped on write, so
+    ///   they also read as 0 when secondary OAM is exposed through `$2004`.
+    /// - `$2004` reads on the pre-render line see the sprite evaluation and
+    ///   fetch bus, as on visible lines.
+    pub fn set_accurate_oam(&mut self, enabled: bool) {
+        self.accurate_oam = enabled;
+        self.oam_row_access = [self.master_clock; 32];
+    }
+
+    pub fn accurate_oam(&self) -> bool {
+        self.accurate_oam
+    }
+
+    /// Sets how many frames a PPU open-bus bit keeps its value after it was
+    /// last driven by a register access (default [`OPEN_BUS_DECAY_FRAMES`]).
+    ///
+    /// Reads of write-only registers, and code executed from `$2000-$3FFF`,
+    /// see the latched value until it decays to 0. `u32::MAX` disables decay.
+    pub fn set_open_bus_decay_frames(&mut self, frames: u32) {
+        self.open_bus.set_decay_frames(frames);
+    }
+
+    pub fn open_bus_decay_frames(&self) -> u32 {
+        self.open_bus.decay_frames()
+    }
+
+    /// Reads primary OAM, refreshing (or first decaying) its row.
+    fn read_oam(&mut self, addr: u8) -> u8 {
+        self.refresh_oam_row(addr);
+        self.registers.oam[addr as usize]
+    }
+
+    fn refresh_oam_row(&mut self, addr: u8) {
+        if !self.accurate_oam {
+            return;
+        }
+        let row = (addr >> 3) as usize;
+        if self.master_clock.wrapping_sub(self.oam_row_access[row]) > OAM_DECAY_MASTER_CLOCKS {
+            self.registers.oam[row * 8..row * 8 + 8].fill(OAM_DECAY_VALUE);
+        }
+        self.oam_row_access[row] = self.master_clock;
+    }
+
+    /// Palette actually used for RGB output.
+    fn output_palette(&self) -> &Palette {
+        if self.clone_mode {
+            &palette::FAMICLONE_APPROX_PALETTE
+        } else {
+            &self.palette
+        }
+    }
+
+    /// Emphasis bits (`$2001` bits 5-7) as seen by the video output, in
+    /// red/green/blue order regardless of how the PPU wires them.
+    fn mask_emphasis(&self) -> u8 {
+        if self.clone_mode {
+            return 0;
+        }
+        let bits = (self.registers.mask.bits() >> 5) & 0x07;
+        if self.swapped_emphasis {
+            (bits & 0x04) | ((bits & 0x01) << 1) | ((bits & 0x02) >> 1)
+        } else {
+            bits
+        }
+    }
+
     /// Applies a new video output size to the internal framebuffer pipeline.
     pub fn set_video_output_config(&mut self, width: usize, height: usize) {
         self.framebuffer.set_output_config(width, height);
@@ -438,8 +703,13 @@ impl Ppu {
 
     /// Rebuilds the currently presented packed frame from the front canonical plane.
     pub fn rebuild_video_output(&mut self) {
-        let palette = *self.palette.as_colors();
-        self.framebuffer.rebuild_packed(&palette);
+        self.framebuffer.rebuild_packed(&self.output_colors);
+    }
+
+    /// Renders the presented frame as packed RGB888 for screenshots, returning
+    /// `(width, height, pixels)`.
+    pub fn render_frame_rgb(&self, filtered: bool) -> (usize, usize, Vec<u8>) {
+        self.framebuffer.render_rgb(&self.output_colors, filtered)
     }
 
     /// Presents a full canonical index frame as the next output frame.
@@ -453,8 +723,7 @@ impl Ppu {
         back_indices.copy_from_slice(indices);
         self.framebuffer
             .apply_color_mask_range(0, (SCREEN_WIDTH * SCREEN_HEIGHT) - 1, false, 0);
-        let palette = *self.palette.as_colors();
-        self.framebuffer.present(&palette);
+        self.framebuffer.present(&self.output_colors);
         true
     }
 
@@ -463,6 +732,12 @@ impl Ppu {
         self.framebuffer.clear_and_present();
     }
 
+    /// Returns and clears the PPU memory access made by the last `$2007`
+    /// read or write.
+    pub(crate) fn take_data_access(&mut self) -> Option<MemoryAccess> {
+        self.data_access.take()
+    }
+
     /// Handles CPU writes to the mirrored PPU register space (`$2000-$3FFF`).
     ///
     /// Mirrors open-bus semantics by latching the last value written; the
@@ -485,7 +760,7 @@ impl Ppu {
                 self.registers.mask = Mask::from_bits_retain(value);
                 self.apply_color_mask_change(
                     self.registers.mask.contains(Mask::GRAYSCALE),
-                    (self.registers.mask.bits() >> 5) & 0x07,
+                    self.mask_emphasis(),
                 );
 
                 let mask = self.registers.mask;
@@ -555,7 +830,7 @@ impl Ppu {
         // This ensures scanline/cycle reflect the state *after* this cycle is processed
         // for synchronization purposes, aligning with Mesen's interpretation.
         let cpu_cycle = bus.cycles();
-        let (scanline, dot, at_frame_start, at_vblank_start) = {
+        let (scanline, dot, at_frame_start, at_frame_end, at_vblank_start) = {
             let mut devices = bus.devices_mut();
             let mut ppu_bus = PpuBus::new(devices.cartridge.as_deref_mut(), cpu_cycle);
             let ppu = &mut devices.ppu;
@@ -589,12 +864,15 @@ impl Ppu {
                     pats_lo,
                     pats_hi,
                 );
+                ppu.extra_sprites.start_line();
+                ppu.hd_capture.start_line();
             }
 
             // If rendering is disabled, keep pipelines idle to avoid stale data.
             if !rendering_enabled {
                 ppu.bg_pipeline.clear();
                 ppu.sprite_pipeline.clear();
+                ppu.extra_sprites.clear();
                 ppu.sprite_line_next.clear();
             }
 
@@ -636,7 +914,9 @@ impl Ppu {
                             let src_base = ppu.registers.oam_addr & 0xF8;
                             let dst = (ppu.cycle - 1) as u8;
                             let src = src_base.wrapping_add(dst);
-                            ppu.registers.oam[dst as usize] = ppu.registers.oam[src as usize];
+                            let value = ppu.read_oam(src);
+                            ppu.refresh_oam_row(dst);
+                            ppu.registers.oam[dst as usize] = value;
                         }
 
                         // Pre-render warmup fetches.
@@ -843,6 +1123,7 @@ impl Ppu {
                 ppu.scanline,
                 ppu.cycle,
                 ppu.scanline == 0 && ppu.cycle == 0,
+                ppu.scanline == 240 && ppu.cycle == 0 && !ppu.in_overclock_scanline,
                 ppu.scanline == 241 && ppu.cycle == 1,
             )
         };
@@ -851,6 +1132,9 @@ impl Ppu {
             if at_frame_start {
                 interceptor.on_ppu_frame_start(cpu, bus);
             }
+            if at_frame_end {
+                interceptor.on_ppu_frame_end(cpu, bus);
+            }
             if at_vblank_start {
                 interceptor.on_ppu_vblank_start(cpu, bus);
             }
@@ -992,25 +1276,61 @@ impl Ppu {
             // Finished processing the last visible scanline for this frame; present the
             // freshly rendered back buffer before moving into post-render/vblank.
             if self.scanline == (SCREEN_HEIGHT as i16 - 1) {
-                self.framebuffer.present(self.palette.as_colors());
+                self.framebuffer.present(&self.output_colors);
             }
 
+            if self.repeats_scanline() {
+                self.overclock_line += 1;
+                self.in_overclock_scanline = true;
+                return;
+            }
+            self.in_overclock_scanline = false;
             self.scanline += 1;
 
             if self.scanline == 240 {
                 self.frame = self.frame.wrapping_add(1);
             } else if self.scanline > 260 {
                 self.scanline = -1;
+                self.overclock_line = 0;
                 // New frame: visible output starts from the currently latched
                 // $2001 grayscale/emphasis bits and an empty raw/output history.
                 self.output_grayscale = self.registers.mask.contains(Mask::GRAYSCALE);
-                self.output_emphasis = (self.registers.mask.bits() >> 5) & 0x07;
+                self.output_emphasis = self.mask_emphasis();
                 self.color_mask_last_updated_pixel = -1;
                 self.raw_output_indices.fill(0);
             }
         }
     }
 
+    /// Whether the line that just ended is run again as an overclock line:
+    /// post-render line 240 before NMI, vblank line 260 after it.
+    fn repeats_scanline(&self) -> bool {
+        let before = u32::from(self.overclock.extra_scanlines_before_nmi);
+        let after = u32::from(self.overclock.extra_scanlines_after_nmi);
+        let done = u32::from(self.overclock_line);
+        match self.scanline {
+            240 => done < before,
+            260 => done < before + after,
+            _ => false,
+        }
+    }
+
+    /// Sets the number of extra scanlines inserted each frame; takes effect
+    /// from the next scanline boundary.
+    pub fn set_overclock(&mut self, overclock: Overclock) {
+        self.overclock = overclock.clamped();
+    }
+
+    pub fn overclock(&self) -> Overclock {
+        self.overclock
+    }
+
+    /// True while running an inserted overclock scanline, during which the
+    /// APU is not clocked.
+    pub fn in_overclock_scanline(&self) -> bool {
+        self.in_overclock_scanline
+    }
+
     /// Recomputes the NMI output line based on VBlank and control register,
     /// latching a pending NMI on rising edges.
     fn update_nmi_level(&mut self) {
@@ -1073,15 +1393,40 @@ impl Ppu {
 
         // Sprite pixel sample.
         let mut sprite_pixel = self.sprite_pipeline.sample_and_shift();
+        let extra_sprite_pixel = self.extra_sprites.sample_and_shift();
         let sprite_visible =
             mask.contains(Mask::SHOW_SPRITES) && (x >= 8 || mask.contains(Mask::SHOW_SPRITES_LEFT));
         if !sprite_visible {
             sprite_pixel.color = 0;
         }
 
-        let sprite_opaque = sprite_pixel.color != 0;
+        let mut sprite_opaque = sprite_pixel.color != 0;
         let bg_opaque = bg_color != 0;
 
+        // Sprite 0 hit occurs when both layers are opaque and sprite 0 contributes.
+        if bg_opaque
+            && sprite_opaque
+            && sprite_pixel.is_sprite0
+            && sprite_visible
+            && bg_visible
+            && self.cycle != 256
+        {
+            self.registers.status.insert(Status::SPRITE_ZERO_HIT);
+        }
+
+        // Sprites past the limit show through where the first eight are
+        // transparent; like the toggles below, this is display only.
+        let mut extra_sprite = false;
+        if !sprite_opaque && sprite_visible && extra_sprite_pixel.color != 0 {
+            sprite_pixel = extra_sprite_pixel;
+            sprite_opaque = true;
+            extra_sprite = true;
+        }
+
+        // Layer toggles only affect what is drawn, after the hit check above.
+        let bg_opaque = bg_opaque && self.background_layer;
+        let sprite_opaque = sprite_opaque && self.sprite_layer;
+
         // Resolve priority between background and sprite.
         let mut final_palette = 0u8;
         let mut final_color = 0u8;
@@ -1109,17 +1454,6 @@ impl Ppu {
             }
         }
 
-        // Sprite 0 hit occurs when both layers are opaque and sprite 0 contributes.
-        if bg_opaque
-            && sprite_opaque
-            && sprite_pixel.is_sprite0
-            && sprite_visible
-            && bg_visible
-            && self.cycle != 256
-        {
-            self.registers.status.insert(Status::SPRITE_ZERO_HIT);
-        }
-
         // Resolve palette RAM address (color 0 always uses universal background).
         let palette_addr = if final_color == 0 {
             ppu_mem::PALETTE_BASE
@@ -1140,6 +1474,67 @@ impl Ppu {
 
         self.framebuffer
             .write_index_with_emphasis(x, y, color_index, self.output_emphasis);
+
+        if self.framebuffer.hd_enabled() {
+            let sample = if from_sprite {
+                (!extra_sprite)
+                    .then(|| self.hd_sprite_sample(x, sprite_pixel.slot))
+                    .flatten()
+            } else if bg_visible && self.background_layer {
+                self.hd_bg_sample(x, fine_x)
+            } else {
+                None
+            };
+            self.framebuffer.record_hd_sample(x, y, sample);
+        }
+    }
+
+    /// Background tile under pixel `x`, picked from the shifter half that
+    /// the fine-X-offset sample came from.
+    fn hd_bg_sample(&self, x: usize, fine_x: u8) -> Option<HdSample> {
+        let offset = (x & 0x07) + (fine_x & 0x07) as usize;
+        let tile = self.hd_capture.bg[offset / 8]?;
+        Some(HdSample {
+            key: HdTileKey {
+                pattern: tile.pattern,
+                palette: self.hd_palette(ppu_mem::PALETTE_BASE + u16::from(tile.attributes) * 4),
+            },
+            column: (offset & 0x07) as u8,
+            row: tile.row,
+            flip_h: false,
+            flip_v: false,
+        })
+    }
+
+    /// Sprite tile drawn by pipeline slot `slot` at pixel `x`.
+    fn hd_sprite_sample(&self, x: usize, slot: u8) -> Option<HdSample> {
+        let tile = self.hd_capture.sprites[usize::from(slot)]?;
+        let attributes = SpriteAttributes::from_bits_retain(tile.attributes);
+        let flip_h = attributes.contains(SpriteAttributes::FLIP_HORIZONTAL);
+        let column = (x.checked_sub(usize::from(tile.x))? & 0x07) as u8;
+        Some(HdSample {
+            key: HdTileKey {
+                pattern: tile.pattern,
+                palette: self.hd_palette(
+                    ppu_mem::PALETTE_BASE + 0x10 + u16::from(tile.attributes & 0b11) * 4,
+                ),
+            },
+            column: if flip_h { 7 - column } else { column },
+            row: tile.row,
+            flip_h,
+            flip_v: attributes.contains(SpriteAttributes::FLIP_VERTICAL),
+        })
+    }
+
+    /// Palette entries used in HD tile keys: the universal backdrop followed
+    /// by colors 1-3 of the palette at `base`.
+    fn hd_palette(&self, base: u16) -> [u8; 4] {
+        [
+            self.palette_ram.read(ppu_mem::PALETTE_BASE),
+            self.palette_ram.read(base + 1),
+            self.palette_ram.read(base + 2),
+            self.palette_ram.read(base + 3),
+        ]
     }
 
     /// Applies a `$2001` grayscale/emphasis update with Mesen2-like pixel cutoff.
@@ -1302,6 +1697,15 @@ impl Ppu {
                     [self.bg_next_pattern_low, self.bg_next_pattern_high],
                     palette_index,
                 );
+                if self.framebuffer.hd_enabled() {
+                    let addr = self.bg_next_tile_addr;
+                    self.hd_capture.reload_bg(HdFetchedTile {
+                        pattern: hd_pack::peek_tile(addr, |a| ppu_bus.chr_peek(a)),
+                        row: (addr & 0x07) as u8,
+                        attributes: palette_index,
+                        x: 0,
+                    });
+                }
                 // Match Mesen2: coarse-X increments are driven by the
                 // one-dot-delayed render latch (`_prevRenderingEnabled`).
                 if self.prev_render_enabled {
@@ -1365,6 +1769,94 @@ impl Ppu {
             // dot 257 already sets it in `clock`).
             self.registers.oam_addr = 0;
             self.fetch_sprites_for_dot(ppu_bus);
+            if self.cycle == 320 && self.sprite_limit_removed {
+                self.fetch_extra_sprites(ppu_bus);
+            }
+        }
+    }
+
+    /// Finds the in-range sprites after the first eight and fetches their
+    /// patterns for the next line, without going through the mapper.
+    ///
+    /// Assumes evaluation started at OAM address 0, which is what games that
+    /// rely on sprite cycling do.
+    fn fetch_extra_sprites(&mut self, ppu_bus: &PpuBus<'_>) {
+        self.extra_sprites.clear_next();
+        if self.scanline < 0 || self.sprite_eval.count < 8 {
+            return;
+        }
+
+        let sprite_height: i16 = if self.registers.control.contains(Control::SPRITE_SIZE_16) {
+            16
+        } else {
+            8
+        };
+        let scanline = self.scanline;
+        let in_range = self
+            .registers
+            .oam
+            .chunks_exact(4)
+            .filter(|sprite| {
+                let y = i16::from(sprite[0]);
+                scanline >= y && scanline < y + sprite_height
+            })
+            .skip(8)
+            .map(|sprite| [sprite[0], sprite[1], sprite[2], sprite[3]])
+            .collect::<Vec<_>>();
+
+        for [y, tile, attr, x] in in_range {
+            let addr = self.sprite_pattern_addr(tile, self.sprite_row(y, attr));
+            self.extra_sprites.push_next(
+                ppu_bus.chr_peek(addr),
+                ppu_bus.chr_peek(addr.wrapping_add(8)),
+                attr,
+                x,
+            );
+        }
+    }
+
+    /// Row of a sprite fetched on the current line for the next one,
+    /// accounting for vertical flip.
+    ///
+    /// Mesen2 relies on the NES sprite Y-off-by-one behavior: sprites are drawn
+    /// at Y+1, so using the current scanline here produces the correct row for
+    /// the upcoming scanline. The offset is computed in u8 space (wrapping),
+    /// even when the selected sprite data is garbage/out-of-range.
+    fn sprite_row(&self, y: u8, attr: u8) -> u8 {
+        let delta = (self.scanline as u8).wrapping_sub(y);
+        if (attr & 0x80) != 0 {
+            let last_row = if self.registers.control.contains(Control::SPRITE_SIZE_16) {
+                15u8
+            } else {
+                7u8
+            };
+            last_row.wrapping_sub(delta)
+        } else {
+            delta
+        }
+    }
+
+    /// Low-plane pattern address of `row` in sprite `tile`, using Mesen2's
+    /// line-offset formula.
+    fn sprite_pattern_addr(&self, tile: u8, row: u8) -> u16 {
+        if self.registers.control.contains(Control::SPRITE_SIZE_16) {
+            // 8x16: bit0 selects pattern table and tile index is even.
+            let base = if (tile & 0x01) != 0 {
+                ppu_mem::PATTERN_TABLE_1
+            } else {
+                ppu_mem::PATTERN_TABLE_0
+            };
+            let tile_base = ((tile & 0xFE) as u16) * 16;
+            let row = if row >= 8 { row.wrapping_add(8) } else { row };
+            base + tile_base + row as u16
+        } else {
+            // 8x8: PPUCTRL bit 3 selects sprite pattern table.
+            let base = if (self.registers.control.bits() & 0x08) != 0 {
+                ppu_mem::PATTERN_TABLE_1
+            } else {
+                ppu_mem::PATTERN_TABLE_0
+            };
+            base + (tile as u16) * 16 + row as u16
         }
     }
 
@@ -1386,8 +1878,7 @@ impl Ppu {
             if self.cycle == 65 {
                 self.sprite_eval.start(self.registers.oam_addr);
             }
-            let addr = self.registers.oam_addr as usize;
-            self.oam_copybuffer = self.registers.oam[addr];
+            self.oam_copybuffer = self.read_oam(self.registers.oam_addr);
             return;
         }
 
@@ -1475,6 +1966,11 @@ impl Ppu {
                 self.sprite_eval.sprite_addr_h =
                     (self.sprite_eval.sprite_addr_h.wrapping_add(1)) & 0x3F;
                 self.sprite_eval.sprite_addr_l = 0;
+            } else if self.sprite_eval.sprite_in_range && self.clone_mode {
+                // Clones compare real Y bytes, so the first hit is a true overflow.
+                self.registers.status.insert(Status::SPRITE_OVERFLOW);
+                self.sprite_eval.oam_copy_done = true;
+                self.sprite_eval.sprite_addr_l = 0;
             } else if self.sprite_eval.sprite_in_range {
                 self.registers.status.insert(Status::SPRITE_OVERFLOW);
 
@@ -1497,11 +1993,15 @@ impl Ppu {
                     }
                 }
             } else {
-                // Sprite isn't on this scanline: increment both H & L.
+                // Sprite isn't on this scanline: the 2C02 increments both H & L,
+                // clones only step to the next sprite's Y byte.
                 self.sprite_eval.sprite_addr_h =
                     (self.sprite_eval.sprite_addr_h.wrapping_add(1)) & 0x3F;
-                self.sprite_eval.sprite_addr_l =
-                    (self.sprite_eval.sprite_addr_l.wrapping_add(1)) & 0x03;
+                self.sprite_eval.sprite_addr_l = if self.clone_mode {
+                    0
+                } else {
+                    (self.sprite_eval.sprite_addr_l.wrapping_add(1)) & 0x03
+                };
                 if self.sprite_eval.sprite_addr_h == 0 {
                     self.sprite_eval.oam_copy_done = true;
                 }
@@ -1566,55 +2066,13 @@ impl Ppu {
         }
 
         // Compute which row of the sprite to fetch for the next scanline.
-        // Mesen2 relies on the NES sprite Y-off-by-one behavior: sprites are drawn at Y+1,
-        // so using the current scanline here produces the correct row for the upcoming scanline.
-        let sprite_height: i16 = if (self.registers.control.bits() & 0x20) != 0 {
-            16
-        } else {
-            8
-        }; // PPUCTRL bit 5
         let active_sprites = self.sprite_eval.count.min(8);
         let fetch_last_sprite = (i as u8) >= active_sprites || y >= 240;
-
-        // Mesen2 behavior: line offset is computed in u8 space (wrapping),
-        // even when the selected sprite data is garbage/out-of-range.
-        let (effective_tile, line_offset): (u8, u8) = if fetch_last_sprite {
+        let addr = if fetch_last_sprite {
             // Dummy fetches for hidden/unused sprites use tile $FF row 0.
-            (0xFF, 0)
+            self.sprite_pattern_addr(0xFF, 0)
         } else {
-            let delta = (self.scanline as u8).wrapping_sub(y);
-            let line_offset = if (attr & 0x80) != 0 {
-                // Vertical flip.
-                (if sprite_height == 16 { 15u8 } else { 7u8 }).wrapping_sub(delta)
-            } else {
-                delta
-            };
-            (tile, line_offset)
-        };
-
-        // Build sprite pattern address using Mesen2's line-offset formula.
-        let addr = if sprite_height == 16 {
-            // 8x16: bit0 selects pattern table and tile index is even.
-            let base = if (effective_tile & 0x01) != 0 {
-                ppu_mem::PATTERN_TABLE_1
-            } else {
-                ppu_mem::PATTERN_TABLE_0
-            };
-            let tile_base = ((effective_tile & 0xFE) as u16) * 16;
-            let row = if line_offset >= 8 {
-                line_offset.wrapping_add(8)
-            } else {
-                line_offset
-            };
-            base + tile_base + row as u16
-        } else {
-            // 8x8: PPUCTRL bit 3 selects sprite pattern table.
-            let base = if (self.registers.control.bits() & 0x08) != 0 {
-                ppu_mem::PATTERN_TABLE_1
-            } else {
-                ppu_mem::PATTERN_TABLE_0
-            };
-            base + (effective_tile as u16) * 16 + line_offset as u16
+            self.sprite_pattern_addr(tile, self.sprite_row(y, attr))
         };
 
         // Mesen2: perform both pattern reads during the same sub-step (case 4),
@@ -1644,6 +2102,14 @@ impl Ppu {
             );
             self.sprite_line_next.set_pattern_low(i, pattern_low);
             self.sprite_line_next.set_pattern_high(i, pattern_high);
+            if self.framebuffer.hd_enabled() {
+                self.hd_capture.sprites_next[i] = (!fetch_last_sprite).then(|| HdFetchedTile {
+                    pattern: hd_pack::peek_tile(addr, |a| ppu_bus.chr_peek(a)),
+                    row: (addr & 0x07) as u8,
+                    attributes: attr,
+                    x,
+                });
+            }
         }
     }
 
@@ -1695,8 +2161,14 @@ impl Ppu {
         } else {
             let idx = self.registers.oam_addr as usize;
             if idx < ppu_mem::OAM_RAM_SIZE {
+                let value = if self.accurate_oam && (idx & 0x03) == 0x02 {
+                    value & 0xE3
+                } else {
+                    value
+                };
                 // Outside rendering, writes go directly into primary OAM and
                 // auto-increment OAMADDR.
+                self.refresh_oam_row(self.registers.oam_addr);
                 self.registers.oam[idx] = value;
                 self.registers.oam_addr = self.registers.oam_addr.wrapping_add(1);
             }
@@ -1705,7 +2177,8 @@ impl Ppu {
 
     fn read_oam_data(&mut self) -> u8 {
         let rendering = self.render_enabled;
-        let during_render = rendering && (0..=239).contains(&self.scanline);
+        let first_line = if self.accurate_oam { -1 } else { 0 };
+        let during_render = rendering && (first_line..=239).contains(&self.scanline);
 
         if during_render {
             // While the screen is being drawn, $2004 exposes the internal OAM
@@ -1726,7 +2199,7 @@ impl Ppu {
         } else {
             let idx = self.registers.oam_addr as usize;
             if idx < ppu_mem::OAM_RAM_SIZE {
-                let mut v = self.registers.oam[idx];
+                let mut v = self.read_oam(self.registers.oam_addr);
                 // Mask off the 3 unimplemented bits of sprite byte 2 so they
                 // read back as 0, mirroring hardware behaviour.
                 if (self.registers.oam_addr & 0x03) == 0x02 {
@@ -1745,6 +2218,11 @@ impl Ppu {
     fn write_vram_data(&mut self, value: u8, ppu_bus: &mut PpuBus<'_>) {
         let addr = self.effective_vram_addr() & ppu_mem::VRAM_MIRROR_MASK;
         self.write_vram(ppu_bus, addr, value);
+        self.data_access = Some(MemoryAccess {
+            addr,
+            value,
+            kind: AccessKind::Write,
+        });
         // Delay VRAM increment by one PPU dot to match Mesen2 / hardware
         // behaviour (used by some test ROMs to observe transient colours).
         self.pending_vram_increment = PendingVramIncrement::from_control(self.registers.control);
@@ -1796,6 +2274,11 @@ impl Ppu {
 
             // Mesen2 / hardware: palette reads drive the low 6 bits from
             // palette RAM, while the upper 2 bits come from the open bus.
+            self.data_access = Some(MemoryAccess {
+                addr,
+                value: palette_value,
+                kind: AccessKind::Read,
+            });
             self.open_bus.apply(0xC0, low, self.frame)
         } else {
             // Nametable / CHR / general VRAM area read.
@@ -1804,6 +2287,11 @@ impl Ppu {
             // read buffer and then refreshes that buffer with the new VRAM
             // data fetched from the mapper.
             let data = self.read_vram(ppu_bus, addr, PpuVramAccessKind::CpuRead, None);
+            self.data_access = Some(MemoryAccess {
+                addr,
+                value: data,
+                kind: AccessKind::Read,
+            });
             let buffered = self.registers.vram_buffer;
             self.registers.vram_buffer = data;
 
@@ -2160,7 +2648,7 @@ mod tests {
     use crate::{
         apu::Apu,
         bus::{OpenBus, PendingDma, cpu::CpuBus},
-        controller::{ControllerPorts, SerialLogger},
+        controller::{ControllerPorts, SerialLogger, ZapperPorts},
         mem_block::cpu as cpu_ram,
         ppu::ppu_bus::PpuBus,
     };
@@ -2216,6 +2704,31 @@ mod tests {
         assert_eq!(value, 0x99);
     }
 
+    #[test]
+    fn overclock_repeats_post_render_and_last_vblank_lines() {
+        let mut ppu = Ppu::default();
+        ppu.set_overclock(Overclock {
+            extra_scanlines_before_nmi: 3,
+            extra_scanlines_after_nmi: 2,
+        });
+        ppu.scanline = 239;
+        ppu.cycle = CYCLES_PER_SCANLINE - 1;
+
+        let mut lines = Vec::new();
+        while ppu.scanline != -1 {
+            ppu.advance_cycle();
+            if ppu.cycle == 0 {
+                lines.push((ppu.scanline, ppu.in_overclock_scanline()));
+            }
+        }
+
+        let mut expected = vec![(240, false), (240, true), (240, true), (240, true)];
+        expected.extend((241..=260).map(|line| (line, false)));
+        expected.extend([(260, true), (260, true), (-1, false)]);
+        assert_eq!(lines, expected);
+        assert_eq!(ppu.overclock_line, 0);
+    }
+
     #[test]
     fn palette_reads_mix_high_bits_from_open_bus() {
         let mut ppu = Ppu::default();
@@ -2237,6 +2750,64 @@ mod tests {
         assert_eq!(value, 0xD5);
     }
 
+    #[test]
+    fn layer_toggles_hide_output_but_keep_sprite_zero_hit() {
+        let mut ppu = Ppu {
+            scanline: 0,
+            cycle: 11, // x = 10
+            ..Ppu::default()
+        };
+        ppu.registers.mask = Mask::SHOW_BACKGROUND | Mask::SHOW_SPRITES;
+        ppu.palette_ram.write(0x3F00, 0x0F);
+        ppu.palette_ram.write(0x3F03, 0x16);
+        ppu.palette_ram.write(0x3F11, 0x2A);
+        let back = ppu.framebuffer.active_plane_index();
+
+        let draw = |ppu: &mut Ppu, background: bool, sprites: bool| {
+            ppu.set_background_layer_enabled(background);
+            ppu.set_sprite_layer_enabled(sprites);
+            ppu.registers.status.remove(Status::SPRITE_ZERO_HIT);
+            // Opaque background (color 3) under an opaque sprite 0 (color 1).
+            ppu.bg_pipeline.load_state(savestate::BgPipelineState {
+                pattern: [0xFFFF; 2],
+                palette: [0; 2],
+            });
+            ppu.sprite_pipeline
+                .load_scanline(1, true, &[0], &[0], &[0xFF], &[0]);
+            ppu.render_pixel();
+            assert!(ppu.registers.status.contains(Status::SPRITE_ZERO_HIT));
+            ppu.framebuffer.index_plane(back)[10] & 0x3F
+        };
+
+        assert_eq!(draw(&mut ppu, true, true), 0x2A);
+        assert_eq!(draw(&mut ppu, true, false), 0x16);
+        assert_eq!(draw(&mut ppu, false, true), 0x2A);
+        assert_eq!(draw(&mut ppu, false, false), 0x0F);
+    }
+
+    #[test]
+    fn emphasis_is_looked_up_in_the_512_color_table() {
+        let mut pal = vec![0u8; 1536];
+        for (block, colors) in pal.chunks_exact_mut(192).enumerate() {
+            colors.fill(block as u8 * 0x10);
+        }
+        let mut ppu = Ppu::default();
+        ppu.set_palette(Palette::from_pal_data(&pal).expect("512-color palette"));
+
+        // `$2001` bit 5 emphasizes red on the 2C02 and green on the 2C07.
+        ppu.registers.mask = Mask::from_bits_retain(0x20);
+        assert_eq!(ppu.mask_emphasis(), 0b001);
+        ppu.set_swapped_emphasis(true);
+        assert_eq!(ppu.mask_emphasis(), 0b010);
+
+        ppu.framebuffer
+            .write_index_with_emphasis(0, 0, 0x21, ppu.mask_emphasis());
+        ppu.framebuffer.present(&ppu.output_colors);
+        let mut rgba = vec![0; SCREEN_WIDTH * SCREEN_HEIGHT * 4];
+        ppu.framebuffer.copy_render_buffer(&mut rgba);
+        assert_eq!(rgba[..4], [0x20, 0x20, 0x20, 0xFF]);
+    }
+
     #[test]
     fn forced_blank_uses_pre_commit_v_on_commit_dot() {
         let mut ppu = Ppu::default();
@@ -2287,6 +2858,77 @@ mod tests {
         assert_eq!(value, 0x00);
     }
 
+    #[test]
+    fn open_bus_holds_written_opcode_for_the_decay_window() {
+        // cpu_exec_space_ppuio executes from write-only PPU registers right
+        // after writing them, so the written byte must still be on the bus.
+        let mut ppu = Ppu::default();
+        let mut ppu_bus = PpuBus::default();
+        ppu.frame = 10;
+        ppu.cpu_write(PpuRegister::OamAddr.addr(), 0x60, &mut ppu_bus);
+
+        ppu.frame = 10 + OPEN_BUS_DECAY_FRAMES;
+        let value = ppu.cpu_read(PpuRegister::Scroll.addr(), &mut ppu_bus);
+        assert_eq!(value, 0x60);
+
+        ppu.frame += 1;
+        let value = ppu.cpu_read(PpuRegister::Scroll.addr(), &mut ppu_bus);
+        assert_eq!(value, 0x00);
+    }
+
+    #[test]
+    fn open_bus_decay_window_is_configurable() {
+        let mut ppu = Ppu::default();
+        let mut ppu_bus = PpuBus::default();
+        ppu.set_open_bus_decay_frames(36);
+        ppu.frame = 10;
+        ppu.cpu_write(PpuRegister::OamAddr.addr(), 0x60, &mut ppu_bus);
+
+        ppu.frame = 46;
+        let value = ppu.cpu_read(PpuRegister::Addr.addr(), &mut ppu_bus);
+        assert_eq!(value, 0x60);
+
+        ppu.frame = 47;
+        let value = ppu.cpu_read(PpuRegister::Addr.addr(), &mut ppu_bus);
+        assert_eq!(value, 0x00);
+
+        // The setting is host configuration and survives a reset.
+        ppu.reset(ResetKind::Soft);
+        assert_eq!(ppu.open_bus_decay_frames(), 36);
+    }
+
+    #[test]
+    fn unrefreshed_oam_rows_decay_when_accurate_oam_is_enabled() {
+        let mut ppu = Ppu::default();
+        let mut ppu_bus = PpuBus::default();
+        ppu.set_accurate_oam(true);
+
+        ppu.cpu_write(PpuRegister::OamAddr.addr(), 0x00, &mut ppu_bus);
+        for value in [0x40, 0x41, 0xFF, 0x43] {
+            ppu.cpu_write(PpuRegister::OamData.addr(), value, &mut ppu_bus);
+        }
+        ppu.cpu_write(PpuRegister::OamAddr.addr(), 0x08, &mut ppu_bus);
+        ppu.cpu_write(PpuRegister::OamData.addr(), 0x50, &mut ppu_bus);
+        assert_eq!(ppu.registers.oam[2], 0xE3);
+
+        // Row 1 is touched just before the deadline, row 0 is left alone.
+        ppu.master_clock = OAM_DECAY_MASTER_CLOCKS;
+        ppu.cpu_write(PpuRegister::OamAddr.addr(), 0x08, &mut ppu_bus);
+        assert_eq!(
+            ppu.cpu_read(PpuRegister::OamData.addr(), &mut ppu_bus),
+            0x50
+        );
+
+        ppu.master_clock += 4;
+        ppu.cpu_write(PpuRegister::OamAddr.addr(), 0x01, &mut ppu_bus);
+        assert_eq!(
+            ppu.cpu_read(PpuRegister::OamData.addr(), &mut ppu_bus),
+            OAM_DECAY_VALUE
+        );
+        assert_eq!(ppu.registers.oam[..8], [OAM_DECAY_VALUE; 8]);
+        assert_eq!(ppu.registers.oam[8], 0x50);
+    }
+
     #[test]
     fn status_read_resets_scroll_latch() {
         let mut ppu = Ppu::default();
@@ -2323,6 +2965,8 @@ mod tests {
         let mut apu = Apu::new();
         let mut ram = cpu_ram::Ram::new();
         let mut controllers = ControllerPorts::new();
+        let zappers = ZapperPorts::default();
+        let mut multitap = None;
         let mut serial_log = SerialLogger::default();
         let mut pending_dma = PendingDma::default();
         let mut open_bus = OpenBus::new();
@@ -2335,15 +2979,20 @@ mod tests {
             apu: &mut apu,
             cartridge: None,
             controllers: &mut controllers,
+            zappers: &zappers,
+            multitap: &mut multitap,
             serial_log: Some(&mut serial_log),
             open_bus: &mut open_bus,
             mixer: None,
+            debugger: None,
+            unmapped_writes: None,
             cycles: &mut cpu_bus_cycle,
             master_clock: &mut master_clock,
             ppu_offset: 1,
             clock_start_count: 6,
             clock_end_count: 6,
             pending_dma: &mut pending_dma,
+            famicom: false,
         };
 
         // Run until scanline 241, cycle 1 (accounting for prerender line).
@@ -2506,4 +3155,34 @@ mod tests {
         run_sprite_evaluation_scanline(&mut ppu, 0);
         assert!(!ppu.registers.status.contains(Status::SPRITE_OVERFLOW));
     }
+
+    #[test]
+    fn clone_mode_evaluates_overflow_on_y_bytes() {
+        let mut ppu = Ppu::default();
+        ppu.registers.oam.fill(200);
+        for i in 0..8 {
+            ppu.registers.oam[i * 4] = 0;
+        }
+        // Sprite #10 (index 9) is the real ninth sprite on the line. The 2C02
+        // misses it because the diagonal scan reads its tile byte instead.
+        ppu.registers.oam[9 * 4] = 0;
+
+        run_sprite_evaluation_scanline(&mut ppu, 0);
+        assert!(!ppu.registers.status.contains(Status::SPRITE_OVERFLOW));
+
+        ppu.set_clone_mode(true);
+        run_sprite_evaluation_scanline(&mut ppu, 0);
+        assert!(ppu.registers.status.contains(Status::SPRITE_OVERFLOW));
+    }
+
+    #[test]
+    fn clone_mode_ignores_emphasis() {
+        let mut ppu = Ppu::default();
+        ppu.registers.mask = Mask::from_bits_retain(0xE0);
+        ppu.set_clone_mode(true);
+        assert_eq!(ppu.mask_emphasis(), 0);
+        assert_eq!(ppu.output_emphasis, 0);
+        ppu.set_clone_mode(false);
+        assert_eq!(ppu.output_emphasis, 0x07);
+    }
 }
@@ -4,7 +4,11 @@
 /// - The canonical representation is palette indices (1 byte per pixel, 256x240).
 /// - Packed pixel buffers (RGBA, RGB565, etc.) are DERIVED from indices only at presentation.
 /// - The PPU writes raw indices; conversion happens once per frame.
-use crate::ppu::{SCREEN_HEIGHT, SCREEN_WIDTH, palette::Color};
+use crate::ppu::{
+    SCREEN_HEIGHT, SCREEN_WIDTH,
+    hd_pack::{HdSample, HdTiles},
+    palette::{Color, EmphasizedPalette, emphasized_index},
+};
 use core::{ffi::c_void, fmt};
 use std::{
     slice,
@@ -14,7 +18,12 @@ use std::{
     },
 };
 
+mod crosshair;
+mod hd;
 mod post_process;
+use crosshair::CrosshairOverlay;
+pub use hd::HdFrame;
+use hd::HdOutput;
 pub use post_process::{NearestPostProcessor, SourceFrame, TargetFrameMut, VideoPostProcessor};
 
 pub const SCREEN_SIZE: usize = SCREEN_WIDTH * SCREEN_HEIGHT;
@@ -62,10 +71,18 @@ impl FrameReadyHook {
 /// Describes how a logical RGB color is packed into the underlying byte buffer.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ColorFormat {
+    /// One byte per pixel holding the raw NES color index (`0x00..=0x3F`,
+    /// grayscale applied). Emphasis is not included; read it from the
+    /// emphasis plane when needed. Lets sinks with their own 64-entry
+    /// palette skip color conversion entirely.
+    Index8,
     /// 16-bit packed RGB using 5 bits per channel (0RRRRRGGGGGBBBBB).
     Rgb555,
-    /// 16-bit packed RGB using 5/6/5 bits (RRRRRGGGGGGBBBBB).
+    /// 16-bit packed RGB using 5/6/5 bits (RRRRRGGGGGGBBBBB), little-endian.
     Rgb565,
+    /// RGB565 stored high byte first, the order SPI LCD controllers
+    /// (ST7789, ILI9341, ...) take on the wire, so frames can be sent as-is.
+    Rgb565Be,
     /// Packed 24-bit RGB, 3 bytes per pixel in R, G, B order.
     Rgb888,
     /// Packed 32-bit RGBA, 4 bytes per pixel in R, G, B, A order.
@@ -81,7 +98,8 @@ impl ColorFormat {
     #[inline]
     pub const fn bytes_per_pixel(self) -> usize {
         match self {
-            ColorFormat::Rgb555 | ColorFormat::Rgb565 => 2,
+            ColorFormat::Index8 => 1,
+            ColorFormat::Rgb555 | ColorFormat::Rgb565 | ColorFormat::Rgb565Be => 2,
             ColorFormat::Rgb888 => 3,
             ColorFormat::Rgba8888 | ColorFormat::Bgra8888 | ColorFormat::Argb8888 => 4,
         }
@@ -126,6 +144,12 @@ pub struct FrameBuffer {
     canonical: CanonicalFrameStore,
     pipeline: PostProcessPipeline,
     backend: PresentBackend,
+    /// High-resolution output, present while an HD pack is active.
+    hd: Option<Box<HdOutput>>,
+    /// Light gun crosshair drawn into the packed output, when enabled.
+    crosshair: Option<Box<CrosshairOverlay>>,
+    /// Drop completed frames instead of presenting them.
+    skip_present: bool,
 }
 
 /// Backing storage for the derived packed pixel planes.
@@ -178,6 +202,9 @@ impl Clone for FrameBuffer {
             canonical: self.canonical.clone(),
             pipeline: self.pipeline.clone(),
             backend: self.backend.clone(),
+            hd: self.hd.clone(),
+            crosshair: self.crosshair.clone(),
+            skip_present: self.skip_present,
         }
     }
 }
@@ -535,6 +562,9 @@ impl FrameBuffer {
                 storage: FrameBufferStorage::Owned { planes, handle },
                 frame_ready_hook: None,
             },
+            hd: None,
+            crosshair: None,
+            skip_present: false,
         }
     }
 
@@ -569,13 +599,23 @@ impl FrameBuffer {
                 )),
                 frame_ready_hook: None,
             },
+            hd: None,
+            crosshair: None,
+            skip_present: false,
         }
     }
 
     /// Primary entry point for presenting a completed frame.
     ///
     /// This converts the active index plane into packed pixels and performs the swap.
-    pub(crate) fn present(&mut self, palette: &[Color; 64]) {
+    pub(crate) fn present(&mut self, palette: &EmphasizedPalette) {
+        if self.skip_present {
+            // Start the next frame on a blank back plane, as after a swap.
+            let active = self.canonical.active_index;
+            self.canonical.index_planes[active].fill(0);
+            self.canonical.emphasis_planes[active].fill(0);
+            return;
+        }
         let finished_back = self.canonical.active_index;
         let format = self.pipeline.color_format;
         let indices = &self.canonical.index_planes[finished_back];
@@ -595,6 +635,13 @@ impl FrameBuffer {
             }
             return;
         }
+        if let Some(hd) = &mut self.hd {
+            hd.compose(indices, emphasis, palette);
+        }
+        let (indices, emphasis) = match &mut self.crosshair {
+            Some(crosshair) => crosshair.compose(indices, emphasis),
+            None => (&indices[..], &emphasis[..]),
+        };
         let source = SourceFrame::new(indices, emphasis, SCREEN_WIDTH, SCREEN_HEIGHT);
 
         // Convert indices to packed pixels for the entire frame.
@@ -650,7 +697,7 @@ impl FrameBuffer {
     /// Rebuilds the current front packed buffer from the current front index plane.
     ///
     /// Useful after a rewind restore to ensure the display matches the restored state.
-    pub(crate) fn rebuild_packed(&mut self, palette: &[Color; 64]) {
+    pub(crate) fn rebuild_packed(&mut self, palette: &EmphasizedPalette) {
         let front_idx = 1 - self.canonical.active_index;
         let indices = &self.canonical.index_planes[front_idx];
         let emphasis = &self.canonical.emphasis_planes[front_idx];
@@ -674,6 +721,10 @@ impl FrameBuffer {
             }
             return;
         }
+        let (indices, emphasis) = match &mut self.crosshair {
+            Some(crosshair) => crosshair.compose(indices, emphasis),
+            None => (&indices[..], &emphasis[..]),
+        };
         let source = SourceFrame::new(indices, emphasis, SCREEN_WIDTH, SCREEN_HEIGHT);
 
         match &mut self.backend.storage {
@@ -699,6 +750,43 @@ impl FrameBuffer {
         // Owned storage does not need explicit unlock.
     }
 
+    /// Renders the front frame as tightly packed RGB888, returning
+    /// `(width, height, pixels)`.
+    ///
+    /// With `filtered`, a copy of the active post-processor scales it to the
+    /// output size; otherwise it is converted 1:1 at 256x240. Neither the
+    /// presented planes nor the live post-processor state are touched.
+    pub(crate) fn render_rgb(
+        &self,
+        palette: &EmphasizedPalette,
+        filtered: bool,
+    ) -> (usize, usize, Vec<u8>) {
+        let front_idx = 1 - self.canonical.active_index;
+        let source = SourceFrame::new(
+            &self.canonical.index_planes[front_idx],
+            &self.canonical.emphasis_planes[front_idx],
+            SCREEN_WIDTH,
+            SCREEN_HEIGHT,
+        );
+        let (width, height, mut processor): (_, _, Box<dyn VideoPostProcessor>) = if filtered {
+            (
+                self.pipeline.output_width,
+                self.pipeline.output_height,
+                self.pipeline.post_processor.clone(),
+            )
+        } else {
+            (SCREEN_WIDTH, SCREEN_HEIGHT, Box::new(NearestPostProcessor))
+        };
+        let pitch = width * ColorFormat::Rgb888.bytes_per_pixel();
+        let mut rgb = vec![0; pitch * height];
+        processor.process(
+            source,
+            palette,
+            TargetFrameMut::new(&mut rgb, pitch, width, height, ColorFormat::Rgb888),
+        );
+        (width, height, rgb)
+    }
+
     /// Writes a single pixel at `(x, y)` using a palette index.
     #[inline]
     pub(crate) fn write_index(&mut self, x: usize, y: usize, index: u8) {
@@ -719,6 +807,55 @@ impl FrameBuffer {
         self.canonical.emphasis_planes[self.canonical.active_index][pos] = emphasis & 0x07;
     }
 
+    /// While `true`, completed frames are discarded: nothing is converted to
+    /// packed pixels, the frame-ready callback does not fire and the front
+    /// planes keep the last presented frame.
+    pub(crate) fn set_skip_present(&mut self, skip: bool) {
+        self.skip_present = skip;
+    }
+
+    pub(crate) fn skip_present(&self) -> bool {
+        self.skip_present
+    }
+
+    /// Draws a light gun crosshair centred on `position` (NES pixels) into the
+    /// packed output from the next presented frame on, or removes it with
+    /// `None`. The canonical index planes never contain the crosshair.
+    pub(crate) fn set_crosshair(&mut self, position: Option<(u16, u16)>) {
+        match (position, &mut self.crosshair) {
+            (Some(position), Some(crosshair)) => crosshair.set_position(position),
+            (Some(position), None) => {
+                self.crosshair = Some(Box::new(CrosshairOverlay::new(position)));
+            }
+            (None, _) => self.crosshair = None,
+        }
+    }
+
+    /// Whether an HD pack is active and the PPU should record tile samples.
+    #[inline]
+    pub(crate) fn hd_enabled(&self) -> bool {
+        self.hd.is_some()
+    }
+
+    /// Records which tile produced the pixel at `(x, y)` for the HD frame.
+    #[inline]
+    pub(crate) fn record_hd_sample(&mut self, x: usize, y: usize, sample: Option<HdSample>) {
+        if let Some(hd) = &mut self.hd {
+            hd.record(y * SCREEN_WIDTH + x, sample);
+        }
+    }
+
+    /// Enables the high-resolution output for a resolved HD pack, or turns
+    /// it off with `None`.
+    pub(crate) fn set_hd_tiles(&mut self, tiles: Option<HdTiles>) {
+        self.hd = tiles.map(|tiles| Box::new(HdOutput::new(tiles)));
+    }
+
+    /// The latest HD frame, when an HD pack is active.
+    pub fn hd_frame(&self) -> Option<&HdFrame> {
+        self.hd.as_deref().map(HdOutput::frame)
+    }
+
     /// Applies grayscale/emphasis bit masks over an inclusive linear pixel range
     /// on the current back buffer.
     #[inline]
@@ -882,6 +1019,32 @@ impl FrameBuffer {
         &mut self.canonical.index_planes[self.canonical.active_index]
     }
 
+    /// Returns the **back** index and emphasis planes, i.e. the frame the PPU
+    /// is currently drawing.
+    pub(crate) fn back_planes(&self) -> (&[u8], &[u8]) {
+        let active = self.canonical.active_index;
+        (
+            &self.canonical.index_planes[active],
+            &self.canonical.emphasis_planes[active],
+        )
+    }
+
+    /// Copies `index` and `emphasis` to the start of the back planes and clears
+    /// the remainder, restoring a partially drawn frame.
+    ///
+    /// Both slices must be at most `SCREEN_WIDTH * SCREEN_HEIGHT` bytes.
+    pub(crate) fn restore_back_planes(&mut self, index: &[u8], emphasis: &[u8]) {
+        let active = self.canonical.active_index;
+        for (plane, src) in [
+            (&mut self.canonical.index_planes[active], index),
+            (&mut self.canonical.emphasis_planes[active], emphasis),
+        ] {
+            let (drawn, rest) = plane.split_at_mut(src.len());
+            drawn.copy_from_slice(src);
+            rest.fill(0);
+        }
+    }
+
     /// Clears both index planes and any accessible packed planes.
     pub fn clear(&mut self) {
         for plane in &mut self.canonical.index_planes {
@@ -890,6 +1053,9 @@ impl FrameBuffer {
         for plane in &mut self.canonical.emphasis_planes {
             plane.fill(0);
         }
+        if let Some(hd) = &mut self.hd {
+            hd.clear();
+        }
         match &mut self.backend.storage {
             FrameBufferStorage::Owned { planes, handle } => {
                 for (i, plane) in planes.iter_mut().enumerate() {
@@ -1014,9 +1180,28 @@ impl FrameBuffer {
 }
 
 #[inline]
-unsafe fn pack_pixel(color: Color, dst: *mut u8, format: ColorFormat) {
+fn rgb565(color: Color) -> u16 {
+    let r5 = (color.r as u16) >> 3;
+    let g6 = (color.g as u16) >> 2;
+    let b5 = (color.b as u16) >> 3;
+    (r5 << 11) | (g6 << 5) | b5
+}
+
+/// Writes the pixel with palette `index` and `emphasis` bits at `dst`.
+#[inline]
+unsafe fn pack_pixel(
+    palette: &EmphasizedPalette,
+    index: u8,
+    emphasis: u8,
+    dst: *mut u8,
+    format: ColorFormat,
+) {
+    let color = palette[emphasized_index(index, emphasis)];
     unsafe {
         match format {
+            ColorFormat::Index8 => {
+                *dst = index & 0x3F;
+            }
             ColorFormat::Rgb555 => {
                 let r5 = (color.r as u16) >> 3;
                 let g5 = (color.g as u16) >> 3;
@@ -1027,11 +1212,12 @@ unsafe fn pack_pixel(color: Color, dst: *mut u8, format: ColorFormat) {
                 *dst.add(1) = bytes[1];
             }
             ColorFormat::Rgb565 => {
-                let r5 = (color.r as u16) >> 3;
-                let g6 = (color.g as u16) >> 2;
-                let b5 = (color.b as u16) >> 3;
-                let packed = (r5 << 11) | (g6 << 5) | b5;
-                let bytes = packed.to_le_bytes();
+                let bytes = rgb565(color).to_le_bytes();
+                *dst = bytes[0];
+                *dst.add(1) = bytes[1];
+            }
+            ColorFormat::Rgb565Be => {
+                let bytes = rgb565(color).to_be_bytes();
                 *dst = bytes[0];
                 *dst.add(1) = bytes[1];
             }
@@ -1062,95 +1248,26 @@ unsafe fn pack_pixel(color: Color, dst: *mut u8, format: ColorFormat) {
     }
 }
 
-pub(super) fn apply_emphasis(color: Color, color_index: u8, emphasis: u8) -> Color {
-    let emphasis = emphasis & 0x07;
-    if emphasis == 0 || (color_index & 0x0F) > 0x0D {
-        return color;
-    }
-
-    // Match Mesen2 default filter behavior: emphasis de-intensifies the
-    // non-selected channels by 0.84 and leaves selected channels unchanged.
-    let mut r = color.r as f64;
-    let mut g = color.g as f64;
-    let mut b = color.b as f64;
-    if (emphasis & 0x01) != 0 {
-        g *= 0.84;
-        b *= 0.84;
-    }
-    if (emphasis & 0x02) != 0 {
-        r *= 0.84;
-        b *= 0.84;
-    }
-    if (emphasis & 0x04) != 0 {
-        r *= 0.84;
-        g *= 0.84;
-    }
-
-    Color::new(
-        r.clamp(0.0, 255.0) as u8,
-        g.clamp(0.0, 255.0) as u8,
-        b.clamp(0.0, 255.0) as u8,
-    )
-}
-
 /// Helper to pack a single line of indices into a destination buffer.
 pub unsafe fn pack_line(
     indices: &[u8],
     emphasis: &[u8],
     dst: *mut u8,
     format: ColorFormat,
-    palette: &[Color; 64],
+    palette: &EmphasizedPalette,
 ) {
     debug_assert_eq!(indices.len(), emphasis.len());
+    if format == ColorFormat::Index8 {
+        // Indices are already the output: no palette lookup per pixel.
+        let dst = unsafe { slice::from_raw_parts_mut(dst, indices.len()) };
+        for (out, &idx) in dst.iter_mut().zip(indices) {
+            *out = idx & 0x3F;
+        }
+        return;
+    }
     let bpp = format.bytes_per_pixel();
     for (x, &idx) in indices.iter().enumerate() {
-        let color = apply_emphasis(palette[(idx & 0x3F) as usize], idx, emphasis[x]);
-        unsafe {
-            let p = dst.add(x * bpp);
-            match format {
-                ColorFormat::Rgb555 => {
-                    let r5 = (color.r as u16) >> 3;
-                    let g5 = (color.g as u16) >> 3;
-                    let b5 = (color.b as u16) >> 3;
-                    let packed = (r5 << 10) | (g5 << 5) | b5;
-                    let bytes = packed.to_le_bytes();
-                    *p = bytes[0];
-                    *p.add(1) = bytes[1];
-                }
-                ColorFormat::Rgb565 => {
-                    let r5 = (color.r as u16) >> 3;
-                    let g6 = (color.g as u16) >> 2;
-                    let b5 = (color.b as u16) >> 3;
-                    let packed = (r5 << 11) | (g6 << 5) | b5;
-                    let bytes = packed.to_le_bytes();
-                    *p = bytes[0];
-                    *p.add(1) = bytes[1];
-                }
-                ColorFormat::Rgb888 => {
-                    *p = color.r;
-                    *p.add(1) = color.g;
-                    *p.add(2) = color.b;
-                }
-                ColorFormat::Rgba8888 => {
-                    *p = color.r;
-                    *p.add(1) = color.g;
-                    *p.add(2) = color.b;
-                    *p.add(3) = 0xFF;
-                }
-                ColorFormat::Bgra8888 => {
-                    *p = color.b;
-                    *p.add(1) = color.g;
-                    *p.add(2) = color.r;
-                    *p.add(3) = 0xFF;
-                }
-                ColorFormat::Argb8888 => {
-                    *p = 0xFF;
-                    *p.add(1) = color.r;
-                    *p.add(2) = color.g;
-                    *p.add(3) = color.b;
-                }
-            }
-        }
+        unsafe { pack_pixel(palette, idx, emphasis[x], dst.add(x * bpp), format) };
     }
 }
 
@@ -0,0 +1,90 @@
+use crate::ppu::{SCREEN_HEIGHT, SCREEN_WIDTH};
+
+use super::SCREEN_SIZE;
+
+/// Pixels each crosshair arm extends from the centre.
+const ARM_LENGTH: i32 = 4;
+/// Palette index of the crosshair (white).
+const CROSSHAIR_COLOR: u8 = 0x30;
+/// Palette index of the one-pixel outline that keeps it visible on bright
+/// backgrounds (black).
+const OUTLINE_COLOR: u8 = 0x0F;
+
+/// Light gun crosshair drawn over the presented frame.
+///
+/// Only the packed output sees it: the frame is copied into scratch planes
+/// before drawing, so the canonical index planes (and with them frame hashes,
+/// save states and light sensing) are untouched.
+#[derive(Debug, Clone)]
+pub(crate) struct CrosshairOverlay {
+    position: (u16, u16),
+    indices: Box<[u8]>,
+    emphasis: Box<[u8]>,
+}
+
+impl CrosshairOverlay {
+    pub(crate) fn new(position: (u16, u16)) -> Self {
+        Self {
+            position,
+            indices: vec![0; SCREEN_SIZE].into_boxed_slice(),
+            emphasis: vec![0; SCREEN_SIZE].into_boxed_slice(),
+        }
+    }
+
+    pub(crate) fn set_position(&mut self, position: (u16, u16)) {
+        self.position = position;
+    }
+
+    /// Returns `indices`/`emphasis` with the crosshair drawn on top.
+    pub(crate) fn compose(&mut self, indices: &[u8], emphasis: &[u8]) -> (&[u8], &[u8]) {
+        self.indices.copy_from_slice(indices);
+        self.emphasis.copy_from_slice(emphasis);
+
+        let (cx, cy) = (self.position.0 as i32, self.position.1 as i32);
+        let on_arm =
+            |dx: i32, dy: i32| (dx == 0 || dy == 0) && dx.abs().max(dy.abs()) <= ARM_LENGTH;
+        let reach = ARM_LENGTH + 1;
+        for dy in -reach..=reach {
+            for dx in -reach..=reach {
+                let color = if on_arm(dx, dy) {
+                    CROSSHAIR_COLOR
+                } else if (-1..=1).any(|oy| (-1..=1).any(|ox| on_arm(dx + ox, dy + oy))) {
+                    OUTLINE_COLOR
+                } else {
+                    continue;
+                };
+                let (x, y) = (cx + dx, cy + dy);
+                if (0..SCREEN_WIDTH as i32).contains(&x) && (0..SCREEN_HEIGHT as i32).contains(&y) {
+                    let pos = y as usize * SCREEN_WIDTH + x as usize;
+                    self.indices[pos] = color;
+                    self.emphasis[pos] = 0;
+                }
+            }
+        }
+        (&self.indices, &self.emphasis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crosshair_is_drawn_over_a_copy_of_the_frame() {
+        let frame = vec![0x21; SCREEN_SIZE];
+        let emphasis = vec![3; SCREEN_SIZE];
+        let mut overlay = CrosshairOverlay::new((0, 100));
+        let (indices, out_emphasis) = overlay.compose(&frame, &emphasis);
+
+        let at = |x: usize, y: usize| indices[y * SCREEN_WIDTH + x];
+        assert_eq!(at(0, 100), CROSSHAIR_COLOR);
+        assert_eq!(at(4, 100), CROSSHAIR_COLOR);
+        assert_eq!(at(0, 96), CROSSHAIR_COLOR);
+        assert_eq!(at(5, 100), OUTLINE_COLOR);
+        assert_eq!(at(1, 99), OUTLINE_COLOR);
+        assert_eq!(at(3, 97), 0x21);
+        assert_eq!(at(6, 100), 0x21);
+        assert_eq!(out_emphasis[100 * SCREEN_WIDTH], 0);
+        assert_eq!(out_emphasis[100 * SCREEN_WIDTH + 6], 3);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::ppu::{
+    SCREEN_HEIGHT, SCREEN_WIDTH,
+    hd_pack::{HdSample, HdTiles},
+    palette::{EmphasizedPalette, emphasized_index},
+};
+
+use super::SCREEN_SIZE;
+
+/// Upscaled RGBA8888 frame with HD pack replacements applied.
+///
+/// Produced alongside the regular packed output whenever an HD pack is
+/// active; it is `SCREEN_WIDTH * scale` by `SCREEN_HEIGHT * scale` pixels
+/// with no row padding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HdFrame {
+    width: usize,
+    height: usize,
+    pixels: Box<[u8]>,
+}
+
+impl HdFrame {
+    fn new(scale: usize) -> Self {
+        let width = SCREEN_WIDTH * scale;
+        let height = SCREEN_HEIGHT * scale;
+        Self {
+            width,
+            height,
+            pixels: vec![0; width * height * 4].into_boxed_slice(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Bytes per row (`width * 4`).
+    pub fn pitch(&self) -> usize {
+        self.width * 4
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+}
+
+/// Secondary high-resolution path: per-pixel tile samples recorded by the
+/// PPU for the frame being drawn, and the composed [`HdFrame`].
+#[derive(Debug, Clone)]
+pub(crate) struct HdOutput {
+    tiles: HdTiles,
+    samples: Box<[Option<HdSample>]>,
+    frame: HdFrame,
+}
+
+impl HdOutput {
+    pub(crate) fn new(tiles: HdTiles) -> Self {
+        let frame = HdFrame::new(tiles.scale as usize);
+        Self {
+            tiles,
+            samples: vec![None; SCREEN_SIZE].into_boxed_slice(),
+            frame,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn record(&mut self, pos: usize, sample: Option<HdSample>) {
+        self.samples[pos] = sample;
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.samples.fill(None);
+        self.frame.pixels.fill(0);
+    }
+
+    pub(crate) fn frame(&self) -> &HdFrame {
+        &self.frame
+    }
+
+    /// Upscales the finished frame, replacing tiles the pack knows about and
+    /// blending their alpha over the original pixel color.
+    pub(crate) fn compose(&mut self, indices: &[u8], emphasis: &[u8], palette: &EmphasizedPalette) {
+        let scale = self.tiles.scale as usize;
+        let pitch = self.frame.pitch();
+
+        for (pos, sample) in self.samples.iter_mut().enumerate() {
+            let base = palette[emphasized_index(indices[pos], emphasis[pos])];
+            let (x, y) = (pos % SCREEN_WIDTH, pos / SCREEN_WIDTH);
+            let replacement = sample
+                .take()
+                .and_then(|sample| Some((sample, self.tiles.get(&sample.key)?)));
+
+            for sy in 0..scale {
+                let row = &mut self.frame.pixels[(y * scale + sy) * pitch..][..pitch];
+                for sx in 0..scale {
+                    let out = &mut row[(x * scale + sx) * 4..][..4];
+                    let [r, g, b, a] = match replacement {
+                        Some((sample, placement)) => {
+                            let texel = |pixel: u8, sub: usize, flip: bool| {
+                                let sub = if flip { scale - 1 - sub } else { sub };
+                                (pixel as usize * scale + sub) as u32
+                            };
+                            self.tiles.images[placement.image].pixel(
+                                placement.x + texel(sample.column, sx, sample.flip_h),
+                                placement.y + texel(sample.row, sy, sample.flip_v),
+                            )
+                        }
+                        None => [base.r, base.g, base.b, 0xFF],
+                    };
+                    let blend = |hd: u8, nes: u8| {
+                        ((hd as u16 * a as u16 + nes as u16 * (255 - a as u16)) / 255) as u8
+                    };
+                    out.copy_from_slice(&[
+                        blend(r, base.r),
+                        blend(g, base.g),
+                        blend(b, base.b),
+                        0xFF,
+                    ]);
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::ppu::palette::Color;
+use crate::ppu::palette::EmphasizedPalette;
 use core::fmt;
 use dyn_clone::DynClone;
 
@@ -92,9 +92,17 @@ pub trait VideoPostProcessor: fmt::Debug + DynClone + Send {
     /// Convert a canonical source frame into packed pixels in `dst`.
     ///
     /// - `src.indices`/`src.emphasis` length must be `src.width * src.height`.
+    /// - `palette` already includes the emphasis colors; look pixels up with
+    ///   [`emphasized_index`](crate::ppu::palette::emphasized_index) rather
+    ///   than tinting the base color.
     /// - `dst.buffer` length must be at least `dst.pitch * dst.height`.
     /// - `dst.pitch` is in bytes and may be larger than `dst.width * bytes_per_pixel`.
-    fn process(&mut self, src: SourceFrame<'_>, palette: &[Color; 64], dst: TargetFrameMut<'_>);
+    fn process(
+        &mut self,
+        src: SourceFrame<'_>,
+        palette: &EmphasizedPalette,
+        dst: TargetFrameMut<'_>,
+    );
 }
 
 dyn_clone::clone_trait_object!(VideoPostProcessor);
@@ -103,7 +111,12 @@ dyn_clone::clone_trait_object!(VideoPostProcessor);
 pub struct NearestPostProcessor;
 
 impl VideoPostProcessor for NearestPostProcessor {
-    fn process(&mut self, src: SourceFrame<'_>, palette: &[Color; 64], dst: TargetFrameMut<'_>) {
+    fn process(
+        &mut self,
+        src: SourceFrame<'_>,
+        palette: &EmphasizedPalette,
+        dst: TargetFrameMut<'_>,
+    ) {
         src.debug_assert_valid();
         dst.debug_assert_valid();
 
@@ -145,11 +158,13 @@ impl VideoPostProcessor for NearestPostProcessor {
                     for x_out in 0..dst_width {
                         let src_x = (x_out * src_width) / dst_width;
                         let src_idx = src_y * src_width + src_x;
-                        let idx = src_indices[src_idx];
-                        let emphasis = src_emphasis[src_idx];
-                        let color =
-                            super::apply_emphasis(palette[(idx & 0x3F) as usize], idx, emphasis);
-                        pack_pixel(color, dst_row.add(x_out * bpp), dst_format);
+                        pack_pixel(
+                            palette,
+                            src_indices[src_idx],
+                            src_emphasis[src_idx],
+                            dst_row.add(x_out * bpp),
+                            dst_format,
+                        );
                     }
                 }
             }
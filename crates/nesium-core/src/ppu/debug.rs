@@ -0,0 +1,458 @@
+//! Decoded views of PPU memory for debugger tooling.
+//!
+//! The interceptor snapshots hand frontends raw CIRAM/CHR/palette bytes and
+//! leave the decoding to them. The functions here do that decoding in the
+//! core instead. They read VRAM through the cartridge's `&self` accessors, so
+//! no mapper observes the fetches (no A12 edges, no CHR latch flips) and they
+//! are safe to call while paused or between frames.
+
+use crate::{
+    cartridge::{Cartridge, mapper::NametableTarget},
+    memory::ppu as ppu_mem,
+    ppu::{Ppu, SCREEN_HEIGHT, SCREEN_WIDTH, sprite::SpriteAttributes},
+};
+
+/// Width of the 2x2 nametable view in pixels.
+pub const NAMETABLE_VIEW_WIDTH: usize = SCREEN_WIDTH * 2;
+/// Height of the 2x2 nametable view in pixels.
+pub const NAMETABLE_VIEW_HEIGHT: usize = SCREEN_HEIGHT * 2;
+
+/// Width of the pattern table view (both tables side by side) in pixels.
+pub const PATTERN_VIEW_WIDTH: usize = 256;
+/// Height of the pattern table view in pixels.
+pub const PATTERN_VIEW_HEIGHT: usize = 128;
+
+/// Number of sprites in primary OAM.
+pub const OAM_SPRITES: usize = 64;
+
+/// Shades used by [`ChrPalette::Grayscale`], from color 0 to color 3.
+const GRAYSCALE: [[u8; 3]; 4] = [
+    [0, 0, 0],
+    [0x55, 0x55, 0x55],
+    [0xAA, 0xAA, 0xAA],
+    [0xFF, 0xFF, 0xFF],
+];
+
+const TILES_PER_ROW: u16 = 32;
+const TILE_ROWS: u16 = 30;
+const ATTRIBUTE_OFFSET: u16 = 0x3C0;
+
+/// One background tile of the nametable view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NametableTile {
+    /// Logical nametable (0-3, `$2000`/`$2400`/`$2800`/`$2C00`).
+    pub nametable: u8,
+    /// Tile column (0-31) and row (0-29) within the nametable.
+    pub column: u8,
+    pub row: u8,
+    /// PPU address of the tile index byte.
+    pub addr: u16,
+    pub tile_index: u8,
+    /// PPU address of the attribute byte covering this tile.
+    pub attr_addr: u16,
+    pub attr: u8,
+    /// Background palette (0-3) selected by the attribute byte.
+    pub palette: u8,
+    /// Pattern table address of the tile's first plane.
+    pub chr_addr: u16,
+}
+
+/// Visible 256x240 window at the current scroll position, in view
+/// coordinates. The window wraps at the view's right and bottom edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ScrollWindow {
+    pub x: u16,
+    pub y: u16,
+}
+
+impl ScrollWindow {
+    /// Splits the window into up to four non-wrapping rectangles
+    /// `(x, y, width, height)` suitable for drawing an overlay.
+    pub fn rects(&self) -> Vec<(u16, u16, u16, u16)> {
+        let spans = |start: u16, len: u16, limit: u16| {
+            let first = len.min(limit - start);
+            let mut spans = vec![(start, first)];
+            if first < len {
+                spans.push((0, len - first));
+            }
+            spans
+        };
+        let xs = spans(self.x, SCREEN_WIDTH as u16, NAMETABLE_VIEW_WIDTH as u16);
+        let ys = spans(self.y, SCREEN_HEIGHT as u16, NAMETABLE_VIEW_HEIGHT as u16);
+        ys.iter()
+            .flat_map(|&(y, h)| xs.iter().map(move |&(x, w)| (x, y, w, h)))
+            .collect()
+    }
+}
+
+/// All four nametables laid out 2x2, decoded to RGB.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NametableView {
+    /// `NAMETABLE_VIEW_WIDTH * NAMETABLE_VIEW_HEIGHT` pixels, packed RGB888.
+    pub rgb: Vec<u8>,
+    /// 4 * 32 * 30 tiles, nametable-major then row-major.
+    pub tiles: Vec<NametableTile>,
+    /// Scroll position latched in the `t` register and fine X.
+    pub scroll: ScrollWindow,
+    /// Background pattern table in use (`$0000` or `$1000`).
+    pub bg_pattern_base: u16,
+}
+
+impl NametableView {
+    /// Tile metadata under a view pixel, e.g. for a hover tooltip.
+    pub fn tile_at(&self, x: usize, y: usize) -> Option<&NametableTile> {
+        if x >= NAMETABLE_VIEW_WIDTH || y >= NAMETABLE_VIEW_HEIGHT {
+            return None;
+        }
+        let nametable = (y / SCREEN_HEIGHT) * 2 + x / SCREEN_WIDTH;
+        let column = (x % SCREEN_WIDTH) / 8;
+        let row = (y % SCREEN_HEIGHT) / 8;
+        self.tiles.get(
+            nametable * usize::from(TILES_PER_ROW * TILE_ROWS)
+                + row * usize::from(TILES_PER_ROW)
+                + column,
+        )
+    }
+}
+
+/// Decodes the four logical nametables as the PPU currently maps them.
+pub fn nametables(ppu: &Ppu, cartridge: Option<&Cartridge>) -> NametableView {
+    let bg_pattern_base = ppu.registers.control.background_pattern_table();
+    let mut rgb = vec![0; NAMETABLE_VIEW_WIDTH * NAMETABLE_VIEW_HEIGHT * 3];
+    let mut tiles = Vec::with_capacity(usize::from(4 * TILES_PER_ROW * TILE_ROWS));
+
+    for nametable in 0..4u16 {
+        let base = ppu_mem::NAMETABLE_BASE + nametable * ppu_mem::NAMETABLE_SIZE;
+        for row in 0..TILE_ROWS {
+            for column in 0..TILES_PER_ROW {
+                let addr = base + row * TILES_PER_ROW + column;
+                let tile_index = peek_nametable(ppu, cartridge, addr);
+                let attr_addr = base + ATTRIBUTE_OFFSET + (row / 4) * 8 + column / 4;
+                let attr = peek_nametable(ppu, cartridge, attr_addr);
+                let shift = ((row & 0x02) << 1) | (column & 0x02);
+                let palette = (attr >> shift) & 0x03;
+                let chr_addr = bg_pattern_base | u16::from(tile_index) << 4;
+
+                let origin_x = usize::from(nametable & 1) * SCREEN_WIDTH + usize::from(column) * 8;
+                let origin_y = usize::from(nametable >> 1) * SCREEN_HEIGHT + usize::from(row) * 8;
+                for fine_y in 0..8 {
+                    let pixels = tile_row(cartridge, chr_addr, fine_y);
+                    for (fine_x, &color) in pixels.iter().enumerate() {
+                        let rgb_color = palette_color(ppu, palette, color);
+                        let offset = ((origin_y + usize::from(fine_y)) * NAMETABLE_VIEW_WIDTH
+                            + origin_x
+                            + fine_x)
+                            * 3;
+                        rgb[offset..offset + 3].copy_from_slice(&rgb_color);
+                    }
+                }
+
+                tiles.push(NametableTile {
+                    nametable: nametable as u8,
+                    column: column as u8,
+                    row: row as u8,
+                    addr,
+                    tile_index,
+                    attr_addr,
+                    attr,
+                    palette,
+                    chr_addr,
+                });
+            }
+        }
+    }
+
+    NametableView {
+        rgb,
+        tiles,
+        scroll: scroll_window(ppu),
+        bg_pattern_base,
+    }
+}
+
+/// Colors used to render CHR data in the pattern table view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ChrPalette {
+    /// Fixed four-level grayscale, independent of palette RAM.
+    #[default]
+    Grayscale,
+    /// Palette RAM entry 0-3 (background) or 4-7 (sprites).
+    Ram(u8),
+}
+
+/// Renders both pattern tables (`$0000` left, `$1000` right) as a
+/// `PATTERN_VIEW_WIDTH * PATTERN_VIEW_HEIGHT` packed RGB888 buffer.
+pub fn pattern_tables(ppu: &Ppu, cartridge: Option<&Cartridge>, palette: ChrPalette) -> Vec<u8> {
+    let mut rgb = vec![0; PATTERN_VIEW_WIDTH * PATTERN_VIEW_HEIGHT * 3];
+    for tile in 0..512u16 {
+        let chr_addr = tile << 4;
+        let origin_x = usize::from(tile >> 8) * 128 + usize::from(tile & 0x0F) * 8;
+        let origin_y = usize::from((tile >> 4) & 0x0F) * 8;
+        for fine_y in 0..8 {
+            for (fine_x, &color) in tile_row(cartridge, chr_addr, fine_y).iter().enumerate() {
+                let rgb_color = match palette {
+                    ChrPalette::Grayscale => GRAYSCALE[usize::from(color)],
+                    ChrPalette::Ram(palette) => palette_color(ppu, palette, color),
+                };
+                let offset =
+                    ((origin_y + usize::from(fine_y)) * PATTERN_VIEW_WIDTH + origin_x + fine_x) * 3;
+                rgb[offset..offset + 3].copy_from_slice(&rgb_color);
+            }
+        }
+    }
+    rgb
+}
+
+/// One decoded primary OAM entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OamEntry {
+    /// Sprite number (0-63).
+    pub index: u8,
+    pub x: u8,
+    /// Raw OAM Y; the sprite's first line is `y + 1`.
+    pub y: u8,
+    pub tile: u8,
+    pub attr: u8,
+    /// Sprite palette (0-3, i.e. palette RAM entries 4-7).
+    pub palette: u8,
+    pub behind_background: bool,
+    pub flip_horizontal: bool,
+    pub flip_vertical: bool,
+    /// Pattern address of the (top) tile, honouring 8x16 mode.
+    pub chr_addr: u16,
+    /// Sprite height in pixels (8 or 16).
+    pub height: u8,
+    /// False for sprites parked below the visible area (`y >= 239`).
+    pub on_screen: bool,
+    /// Whether sprite evaluation on the current scanline selects this sprite
+    /// for the next one. At most eight are picked; evaluation is modelled from
+    /// sprite 0 without the overflow or misaligned-`OAMADDR` quirks.
+    pub next_scanline: bool,
+}
+
+/// Enumerates the 64 sprites in primary OAM.
+pub fn oam_entries(ppu: &Ppu) -> Vec<OamEntry> {
+    let control = ppu.registers.control;
+    let height: u8 = if control.use_8x16_sprites() { 16 } else { 8 };
+    let evaluating = ppu.render_enabled && (0..SCREEN_HEIGHT as i16).contains(&ppu.scanline);
+    let mut picked = 0;
+
+    ppu.registers
+        .oam
+        .chunks_exact(4)
+        .take(OAM_SPRITES)
+        .enumerate()
+        .map(|(index, bytes)| {
+            let [y, tile, attr, x] = [bytes[0], bytes[1], bytes[2], bytes[3]];
+            let attrs = SpriteAttributes::from_bits_truncate(attr);
+            let chr_addr = if height == 16 {
+                (u16::from(tile & 0x01) << 12) | (u16::from(tile & 0xFE) << 4)
+            } else {
+                control.sprite_pattern_table() | (u16::from(tile) << 4)
+            };
+            let in_range =
+                evaluating && (0..i16::from(height)).contains(&(ppu.scanline - i16::from(y)));
+            let next_scanline = in_range && picked < 8;
+            picked += u8::from(in_range);
+
+            OamEntry {
+                index: index as u8,
+                x,
+                y,
+                tile,
+                attr,
+                palette: attr & 0x03,
+                behind_background: attrs.contains(SpriteAttributes::PRIORITY_BEHIND_BACKGROUND),
+                flip_horizontal: attrs.contains(SpriteAttributes::FLIP_HORIZONTAL),
+                flip_vertical: attrs.contains(SpriteAttributes::FLIP_VERTICAL),
+                chr_addr,
+                height,
+                on_screen: y < (SCREEN_HEIGHT - 1) as u8,
+                next_scanline,
+            }
+        })
+        .collect()
+}
+
+fn scroll_window(ppu: &Ppu) -> ScrollWindow {
+    let t = ppu.registers.vram.t;
+    let nametable = u16::from(t.nametable());
+    ScrollWindow {
+        x: (nametable & 1) * SCREEN_WIDTH as u16
+            + u16::from(t.coarse_x()) * 8
+            + u16::from(ppu.registers.vram.x),
+        y: (nametable >> 1) * SCREEN_HEIGHT as u16
+            + u16::from(t.coarse_y()) * 8
+            + u16::from(t.fine_y()),
+    }
+}
+
+/// Nametable byte at `addr` (`$2000-$2FFF`) without notifying the mapper.
+fn peek_nametable(ppu: &Ppu, cartridge: Option<&Cartridge>, addr: u16) -> u8 {
+    let target = match cartridge {
+        Some(cart) => cart.map_nametable(addr),
+        None => NametableTarget::Ciram(addr & 0x07FF),
+    };
+    match (target, cartridge) {
+        (NametableTarget::Ciram(offset), _) => ppu.ciram[usize::from(offset & 0x07FF)],
+        (NametableTarget::MapperVram(offset), Some(cart)) => cart.mapper_nametable_read(offset),
+        _ => 0,
+    }
+}
+
+/// 2-bit color indices for one row of the 8x8 tile at `chr_addr`.
+fn tile_row(cartridge: Option<&Cartridge>, chr_addr: u16, fine_y: u8) -> [u8; 8] {
+    let Some(cart) = cartridge else {
+        return [0; 8];
+    };
+    let low = cart.chr_read(chr_addr + u16::from(fine_y));
+    let high = cart.chr_read(chr_addr + u16::from(fine_y) + 8);
+    std::array::from_fn(|x| {
+        let bit = 7 - x;
+        ((low >> bit) & 1) | (((high >> bit) & 1) << 1)
+    })
+}
+
+/// RGB for `color` of palette `palette` (0-3 background, 4-7 sprite); color
+/// 0 is the shared backdrop at `$3F00`.
+fn palette_color(ppu: &Ppu, palette: u8, color: u8) -> [u8; 3] {
+    let addr = if color == 0 {
+        ppu_mem::PALETTE_BASE
+    } else {
+        ppu_mem::PALETTE_BASE + u16::from(palette & 0x07) * 4 + u16::from(color)
+    };
+    let c = ppu.palette.color(ppu.palette_ram.read(addr) & 0x3F);
+    [c.r, c.g, c.b]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cartridge::load_cartridge, ppu::Control};
+
+    /// NROM with vertical mirroring; tile 1 is solid color 1, tile 2 solid
+    /// color 3.
+    fn cartridge() -> Cartridge {
+        let mut rom = vec![b'N', b'E', b'S', 0x1A, 1, 1, 0x01];
+        rom.resize(16 + 16 * 1024, 0);
+        let mut chr = vec![0u8; 8 * 1024];
+        chr[0x10..0x18].fill(0xFF);
+        chr[0x20..0x30].fill(0xFF);
+        rom.extend_from_slice(&chr);
+        load_cartridge(rom).expect("valid NROM")
+    }
+
+    #[test]
+    fn decodes_tiles_attributes_and_mirroring() {
+        let cart = cartridge();
+        let mut ppu = Ppu::default();
+        ppu.palette_ram.write(0x3F00, 0x0F);
+        ppu.palette_ram.write(0x3F05, 0x16);
+        ppu.palette_ram.write(0x3F0F, 0x2A);
+        // Nametable 0: tile (2, 0) = 1, (0, 2) = 2 with attribute quadrant
+        // palettes 1 (top-right) and 3 (bottom-left).
+        ppu.ciram[2] = 1;
+        ppu.ciram[64] = 2;
+        ppu.ciram[0x3C0] = 0b00_11_01_00;
+
+        let view = nametables(&ppu, Some(&cart));
+        let pixel = |x: usize, y: usize| {
+            let offset = (y * NAMETABLE_VIEW_WIDTH + x) * 3;
+            [view.rgb[offset], view.rgb[offset + 1], view.rgb[offset + 2]]
+        };
+        let color = |index| {
+            let c = ppu.palette.color(index);
+            [c.r, c.g, c.b]
+        };
+
+        let tile = view.tile_at(17, 3).expect("in bounds");
+        assert_eq!(
+            (tile.tile_index, tile.palette, tile.chr_addr),
+            (1, 1, 0x0010)
+        );
+        assert_eq!(pixel(17, 3), color(0x16));
+        assert_eq!(pixel(0, 17), color(0x2A));
+        assert_eq!(pixel(40, 40), color(0x0F));
+        // Vertical mirroring: $2800 shows the same data as $2000.
+        assert_eq!(pixel(17, SCREEN_HEIGHT + 3), color(0x16));
+        let mirrored = view.tile_at(17, SCREEN_HEIGHT + 3).expect("in bounds");
+        assert_eq!((mirrored.nametable, mirrored.addr), (2, 0x2802));
+    }
+
+    #[test]
+    fn scroll_window_follows_t_and_wraps() {
+        let mut ppu = Ppu::default();
+        ppu.registers.vram.t = t_addr(0b11, 31, 29, 7);
+        ppu.registers.vram.x = 4;
+
+        let view = nametables(&ppu, None);
+        assert_eq!(view.scroll, ScrollWindow { x: 508, y: 479 });
+        assert_eq!(
+            view.scroll.rects(),
+            [
+                (508, 479, 4, 1),
+                (0, 479, 252, 1),
+                (508, 0, 4, 239),
+                (0, 0, 252, 239)
+            ]
+        );
+    }
+
+    #[test]
+    fn pattern_tables_use_the_selected_palette() {
+        let cart = cartridge();
+        let mut ppu = Ppu::default();
+        ppu.palette_ram.write(0x3F00, 0x0F);
+        ppu.palette_ram.write(0x3F11, 0x21);
+
+        let pixel = |rgb: &[u8], x: usize, y: usize| {
+            let offset = (y * PATTERN_VIEW_WIDTH + x) * 3;
+            [rgb[offset], rgb[offset + 1], rgb[offset + 2]]
+        };
+        let gray = pattern_tables(&ppu, Some(&cart), ChrPalette::Grayscale);
+        assert_eq!(pixel(&gray, 8, 0), GRAYSCALE[1]);
+        assert_eq!(pixel(&gray, 16, 7), GRAYSCALE[3]);
+        assert_eq!(pixel(&gray, 128, 0), GRAYSCALE[0]);
+
+        let sprite = pattern_tables(&ppu, Some(&cart), ChrPalette::Ram(4));
+        let c = ppu.palette.color(0x21);
+        assert_eq!(pixel(&sprite, 8, 0), [c.r, c.g, c.b]);
+        let c = ppu.palette.color(0x0F);
+        assert_eq!(pixel(&sprite, 0, 0), [c.r, c.g, c.b]);
+    }
+
+    #[test]
+    fn oam_entries_decode_attributes_and_evaluation() {
+        let mut ppu = Ppu::default();
+        for sprite in 0..10 {
+            ppu.registers.oam[sprite * 4..sprite * 4 + 4].copy_from_slice(&[20, 0x03, 0xE1, 8]);
+        }
+        ppu.registers.oam[40..44].copy_from_slice(&[0xF0, 0, 0, 0]);
+        ppu.registers.control = Control::from_bits_truncate(0x20);
+        ppu.render_enabled = true;
+        ppu.scanline = 30;
+
+        let entries = oam_entries(&ppu);
+        assert_eq!(entries.len(), OAM_SPRITES);
+        let first = entries[0];
+        assert_eq!(
+            (first.palette, first.chr_addr, first.height),
+            (1, 0x1020, 16)
+        );
+        assert!(first.behind_background && first.flip_horizontal && first.flip_vertical);
+        assert_eq!(entries.iter().filter(|e| e.next_scanline).count(), 8);
+        assert!(!entries[8].next_scanline);
+        assert!(!entries[10].on_screen && entries[11].on_screen);
+
+        ppu.render_enabled = false;
+        assert!(oam_entries(&ppu).iter().all(|e| !e.next_scanline));
+    }
+
+    fn t_addr(nametable: u8, coarse_x: u8, coarse_y: u8, fine_y: u8) -> crate::ppu::VramAddr {
+        crate::ppu::VramAddr::default()
+            .with_nametable(nametable)
+            .with_coarse_x(coarse_x)
+            .with_coarse_y(coarse_y)
+            .with_fine_y(fine_y)
+    }
+}
@@ -0,0 +1,450 @@
+//! Mesen-style HD packs: higher-resolution replacements for CHR tiles.
+//!
+//! A pack is a directory holding a `hires.txt` manifest and the PNG sheets it
+//! references. The PPU hashes every tile it draws (its 16 pattern bytes plus
+//! the four palette entries in use) and, when the pack has a replacement,
+//! the framebuffer copies the matching `scale`×`scale` block per NES pixel
+//! into a separate high-resolution frame (see [`HdFrame`]).
+//!
+//! Only the directives needed for plain tile replacement are understood:
+//! `<ver>`, `<scale>`, `<img>` and `<tile>`. Conditional rules, backgrounds,
+//! sound replacements and the brightness column are ignored.
+//!
+//! [`HdFrame`]: super::buffer::HdFrame
+
+use std::{collections::HashMap, path::Path, sync::Arc};
+
+use crate::error::Error;
+
+/// Manifest file name inside an HD pack directory.
+pub const MANIFEST_FILE: &str = "hires.txt";
+
+/// Largest `<scale>` accepted; Mesen packs top out at 10x.
+pub const MAX_SCALE: u32 = 10;
+
+/// Bytes in one 8×8 CHR tile (two bitplanes).
+const TILE_BYTES: usize = 16;
+
+/// A decoded replacement sheet, 8-bit RGBA.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HdImage {
+    width: u32,
+    height: u32,
+    rgba: Vec<u8>,
+}
+
+impl HdImage {
+    /// Wraps already decoded RGBA pixels.
+    pub fn from_rgba(width: u32, height: u32, rgba: Vec<u8>) -> Result<Self, Error> {
+        if rgba.len() != width as usize * height as usize * 4 {
+            return Err(invalid(format!(
+                "{width}x{height} image needs {} RGBA bytes, got {}",
+                width as usize * height as usize * 4,
+                rgba.len()
+            )));
+        }
+        Ok(Self {
+            width,
+            height,
+            rgba,
+        })
+    }
+
+    /// Decodes a PNG sheet (any bit depth/color type) into RGBA.
+    #[cfg(feature = "hd-pack")]
+    pub fn decode_png(bytes: &[u8]) -> Result<Self, Error> {
+        use png::{BitDepth, ColorType, Decoder, Transformations};
+
+        let png_error = |err: png::DecodingError| invalid(format!("bad PNG: {err}"));
+        let mut decoder = Decoder::new(std::io::Cursor::new(bytes));
+        decoder
+            .set_transformations(Transformations::normalize_to_color8() | Transformations::ALPHA);
+        let mut reader = decoder.read_info().map_err(png_error)?;
+        let size = reader
+            .output_buffer_size()
+            .ok_or_else(|| invalid("PNG is too large".to_owned()))?;
+        let mut buf = vec![0; size];
+        let frame = reader.next_frame(&mut buf).map_err(png_error)?;
+        buf.truncate(frame.buffer_size());
+        debug_assert_eq!(frame.bit_depth, BitDepth::Eight);
+
+        let rgba = match frame.color_type {
+            ColorType::Rgba => buf,
+            ColorType::GrayscaleAlpha => buf
+                .chunks_exact(2)
+                .flat_map(|p| [p[0], p[0], p[0], p[1]])
+                .collect(),
+            other => return Err(invalid(format!("unexpected PNG color type {other:?}"))),
+        };
+        Self::from_rgba(frame.width, frame.height, rgba)
+    }
+
+    #[cfg(not(feature = "hd-pack"))]
+    pub fn decode_png(_bytes: &[u8]) -> Result<Self, Error> {
+        Err(invalid(
+            "PNG support is disabled (build with the `hd-pack` feature)".to_owned(),
+        ))
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// RGBA of the pixel at `(x, y)`; callers keep coordinates in bounds.
+    #[inline]
+    pub(crate) fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        let at = (y as usize * self.width as usize + x as usize) * 4;
+        [
+            self.rgba[at],
+            self.rgba[at + 1],
+            self.rgba[at + 2],
+            self.rgba[at + 3],
+        ]
+    }
+}
+
+/// How a `<tile>` rule names the CHR tile it replaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TileSource {
+    /// Tile number in CHR ROM (byte offset / 16), for CHR ROM games.
+    Index(u32),
+    /// Raw pattern bytes, for CHR RAM games.
+    Data([u8; TILE_BYTES]),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TileRule {
+    source: TileSource,
+    palette: [u8; 4],
+    placement: HdTilePlacement,
+}
+
+/// Where a replacement tile sits in the pack's sheets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct HdTilePlacement {
+    pub(crate) image: usize,
+    /// Top-left corner in sheet pixels.
+    pub(crate) x: u32,
+    pub(crate) y: u32,
+}
+
+/// A parsed HD pack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HdPack {
+    version: u32,
+    scale: u32,
+    images: Vec<HdImage>,
+    rules: Vec<TileRule>,
+}
+
+impl HdPack {
+    /// Loads `hires.txt` and its PNG sheets from a pack directory.
+    pub fn load_dir(dir: impl AsRef<Path>) -> Result<Self, Error> {
+        let dir = dir.as_ref();
+        let manifest = std::fs::read_to_string(dir.join(MANIFEST_FILE))?;
+        Self::parse(&manifest, |name| {
+            HdImage::decode_png(&std::fs::read(dir.join(name))?)
+        })
+    }
+
+    /// Parses a manifest, asking `load_image` for each `<img>` it names.
+    pub fn parse(
+        manifest: &str,
+        mut load_image: impl FnMut(&str) -> Result<HdImage, Error>,
+    ) -> Result<Self, Error> {
+        let mut pack = Self {
+            version: 0,
+            scale: 1,
+            images: Vec::new(),
+            rules: Vec::new(),
+        };
+
+        for (number, line) in manifest.lines().enumerate() {
+            let line = line.trim();
+            // Conditional rules start with `[...]`; without condition support
+            // they would replace tiles unconditionally, so skip them.
+            let Some(rest) = line.strip_prefix('<') else {
+                continue;
+            };
+            let Some((tag, value)) = rest.split_once('>') else {
+                continue;
+            };
+            let at_line = |reason: String| invalid(format!("line {}: {reason}", number + 1));
+            match tag {
+                "ver" => {
+                    pack.version = value
+                        .trim()
+                        .parse()
+                        .map_err(|_| at_line(format!("bad version {value:?}")))?;
+                }
+                "scale" => {
+                    pack.scale = value
+                        .trim()
+                        .parse()
+                        .ok()
+                        .filter(|scale| (1..=MAX_SCALE).contains(scale))
+                        .ok_or_else(|| at_line(format!("bad scale {value:?}")))?;
+                }
+                "img" => {
+                    let image = load_image(value.trim())?;
+                    pack.images.push(image);
+                }
+                "tile" => {
+                    let rule = parse_tile(value, &pack).map_err(at_line)?;
+                    pack.rules.push(rule);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(pack)
+    }
+
+    /// `<ver>` of the manifest, or 0 when missing.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Output pixels per NES pixel along each axis.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    pub fn images(&self) -> &[HdImage] {
+        &self.images
+    }
+
+    /// Number of `<tile>` rules.
+    pub fn tile_count(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Keys every rule by pattern bytes, reading index-based rules out of
+    /// `chr_rom`. Index rules past the end of CHR ROM are dropped.
+    pub(crate) fn resolve(&self, chr_rom: &[u8]) -> HdTiles {
+        let mut tiles = HashMap::with_capacity(self.rules.len());
+        for rule in &self.rules {
+            let pattern = match rule.source {
+                TileSource::Data(pattern) => pattern,
+                TileSource::Index(index) => {
+                    let start = index as usize * TILE_BYTES;
+                    let Some(bytes) = chr_rom.get(start..start + TILE_BYTES) else {
+                        continue;
+                    };
+                    let mut pattern = [0; TILE_BYTES];
+                    pattern.copy_from_slice(bytes);
+                    pattern
+                }
+            };
+            // Earlier rules win, as in Mesen.
+            tiles
+                .entry(HdTileKey {
+                    pattern,
+                    palette: rule.palette,
+                })
+                .or_insert(rule.placement);
+        }
+        HdTiles {
+            scale: self.scale,
+            images: self.images.clone().into(),
+            tiles,
+        }
+    }
+}
+
+/// `<tile>[image],[tile],[palette],[x],[y],...`
+fn parse_tile(value: &str, pack: &HdPack) -> Result<TileRule, String> {
+    let fields: Vec<&str> = value.split(',').map(str::trim).collect();
+    let [image, tile, palette, x, y, ..] = fields[..] else {
+        return Err(format!("<tile> needs at least 5 fields, got {value:?}"));
+    };
+    let number = |field: &str| {
+        field
+            .parse::<u32>()
+            .map_err(|_| format!("bad number {field:?}"))
+    };
+
+    let image = number(image)? as usize;
+    let sheet = pack
+        .images
+        .get(image)
+        .ok_or_else(|| format!("image {image} is not declared"))?;
+    let (x, y) = (number(x)?, number(y)?);
+    let span = 8 * pack.scale;
+    if x + span > sheet.width || y + span > sheet.height {
+        return Err(format!("tile at ({x}, {y}) lies outside image {image}"));
+    }
+
+    let source = if tile.len() == TILE_BYTES * 2 {
+        TileSource::Data(parse_hex(tile)?)
+    } else {
+        TileSource::Index(number(tile)?)
+    };
+    if palette.len() != 8 {
+        return Err(format!("palette must be 8 hex digits, got {palette:?}"));
+    }
+
+    Ok(TileRule {
+        source,
+        palette: parse_hex(palette)?,
+        placement: HdTilePlacement { image, x, y },
+    })
+}
+
+fn parse_hex<const N: usize>(text: &str) -> Result<[u8; N], String> {
+    let mut bytes = [0; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = text
+            .get(i * 2..i * 2 + 2)
+            .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+            .ok_or_else(|| format!("bad hex {text:?}"))?;
+    }
+    Ok(bytes)
+}
+
+fn invalid(reason: String) -> Error {
+    Error::InvalidHdPack { reason }
+}
+
+/// Identity of a drawn tile: its pattern bytes and the palette it used
+/// (backdrop color first, then colors 1-3).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct HdTileKey {
+    pub(crate) pattern: [u8; TILE_BYTES],
+    pub(crate) palette: [u8; 4],
+}
+
+/// A pack resolved against the loaded cartridge, ready for lookups.
+#[derive(Debug, Clone)]
+pub(crate) struct HdTiles {
+    pub(crate) scale: u32,
+    pub(crate) images: Arc<[HdImage]>,
+    tiles: HashMap<HdTileKey, HdTilePlacement>,
+}
+
+impl HdTiles {
+    #[inline]
+    pub(crate) fn get(&self, key: &HdTileKey) -> Option<HdTilePlacement> {
+        self.tiles.get(key).copied()
+    }
+}
+
+/// Which tile (and which texel of it) produced one NES pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct HdSample {
+    pub(crate) key: HdTileKey,
+    /// Pattern column/row (0..=7) before flipping.
+    pub(crate) column: u8,
+    pub(crate) row: u8,
+    pub(crate) flip_h: bool,
+    pub(crate) flip_v: bool,
+}
+
+/// A tile row latched by a pattern fetch, waiting to be drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct HdFetchedTile {
+    pub(crate) pattern: [u8; TILE_BYTES],
+    pub(crate) row: u8,
+    /// Background: attribute palette (0..=3). Sprites: raw attribute byte.
+    pub(crate) attributes: u8,
+    /// Sprite X; unused for background tiles.
+    pub(crate) x: u8,
+}
+
+/// Tile identities shadowing the PPU's pixel pipelines while an HD pack is
+/// active. Display only: never saved and rebuilt within a scanline.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct HdCapture {
+    /// Background tiles in the high and low halves of the shifters.
+    pub(crate) bg: [Option<HdFetchedTile>; 2],
+    /// Sprites fetched for the next line and those drawn on this one.
+    pub(crate) sprites_next: [Option<HdFetchedTile>; 8],
+    pub(crate) sprites: [Option<HdFetchedTile>; 8],
+}
+
+impl HdCapture {
+    /// Mirrors a background shifter reload.
+    pub(crate) fn reload_bg(&mut self, tile: HdFetchedTile) {
+        self.bg = [self.bg[1], Some(tile)];
+    }
+
+    /// Mirrors the sprite pipeline picking up the next line's sprites.
+    pub(crate) fn start_line(&mut self) {
+        self.sprites = std::mem::take(&mut self.sprites_next);
+    }
+}
+
+/// Reads the 16 pattern bytes of the tile containing `addr`.
+pub(crate) fn peek_tile(addr: u16, mut peek: impl FnMut(u16) -> u8) -> [u8; TILE_BYTES] {
+    let base = addr & !0x000F;
+    std::array::from_fn(|i| peek(base + i as u16))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sheet(size: u32) -> HdImage {
+        HdImage::from_rgba(size, size, vec![0; (size * size * 4) as usize]).expect("image")
+    }
+
+    #[test]
+    fn parses_index_and_data_rules() {
+        let manifest = "\
+<ver>106
+<scale>2
+<img>tiles.png
+<tile>0,1,0F162730,16,0,1,N
+<tile>0,FF00FF00FF00FF00FF00FF00FF00FF00,0F000000,0,16,1,N
+[cond]<tile>0,2,0F162730,0,0,1,N
+<background>bg.png,1
+";
+        let mut requested = Vec::new();
+        let pack = HdPack::parse(manifest, |name| {
+            requested.push(name.to_owned());
+            Ok(sheet(32))
+        })
+        .expect("pack");
+        assert_eq!(requested, ["tiles.png"]);
+        assert_eq!(
+            (pack.version(), pack.scale(), pack.tile_count()),
+            (106, 2, 2)
+        );
+
+        let mut chr_rom = vec![0; 32];
+        chr_rom[16..].copy_from_slice(&[0xAA; 16]);
+        let tiles = pack.resolve(&chr_rom);
+        let by_index = HdTileKey {
+            pattern: [0xAA; 16],
+            palette: [0x0F, 0x16, 0x27, 0x30],
+        };
+        assert_eq!(
+            tiles.get(&by_index),
+            Some(HdTilePlacement {
+                image: 0,
+                x: 16,
+                y: 0
+            })
+        );
+        let by_data = HdTileKey {
+            pattern: std::array::from_fn(|i| if i % 2 == 0 { 0xFF } else { 0x00 }),
+            palette: [0x0F, 0, 0, 0],
+        };
+        assert_eq!(tiles.get(&by_data).map(|p| (p.x, p.y)), Some((0, 16)));
+    }
+
+    #[test]
+    fn rejects_tiles_outside_their_sheet() {
+        let manifest = "<scale>2\n<img>a.png\n<tile>0,0,0F0F0F0F,8,0,1,N\n";
+        let err = HdPack::parse(manifest, |_| Ok(sheet(16))).expect_err("out of bounds");
+        assert!(err.to_string().contains("line 3"), "{err}");
+
+        let err = HdPack::parse("<tile>0,0,0F0F0F0F,0,0\n", |_| Ok(sheet(16)))
+            .expect_err("undeclared image");
+        assert!(err.to_string().contains("not declared"), "{err}");
+    }
+}
@@ -8,6 +8,9 @@ use super::savestate::PpuOpenBusState;
 pub(crate) struct PpuOpenBus {
     value: u8,
     decay_stamp: [u32; 8],
+    /// Frames a bit survives without being driven (host configuration, not
+    /// part of save states).
+    decay_frames: u32,
 }
 
 impl PpuOpenBus {
@@ -15,9 +18,18 @@ impl PpuOpenBus {
         Self {
             value: 0,
             decay_stamp: [0; 8],
+            decay_frames: super::OPEN_BUS_DECAY_FRAMES,
         }
     }
 
+    pub(crate) fn decay_frames(&self) -> u32 {
+        self.decay_frames
+    }
+
+    pub(crate) fn set_decay_frames(&mut self, frames: u32) {
+        self.decay_frames = frames;
+    }
+
     pub(crate) fn reset(&mut self) {
         self.value = 0;
         self.decay_stamp = [0; 8];
@@ -52,9 +64,9 @@ impl PpuOpenBus {
                 self.decay_stamp[bit] = frame;
             } else {
                 // This bit is coming from the existing open-bus state; if
-                // it hasn't been refreshed for more than ~3 frames, it
-                // decays to 0.
-                if frame.wrapping_sub(self.decay_stamp[bit]) > 3 {
+                // it hasn't been refreshed for more than `decay_frames`
+                // frames, it decays to 0.
+                if frame.wrapping_sub(self.decay_stamp[bit]) > self.decay_frames {
                     open_bus &= 0xFF7F;
                 }
             }
@@ -36,13 +36,34 @@
 //! documentation and debugging tools.
 //!
 //! In addition to the canonical Nesdev palette the module also ships a handful
-//! of alternative presets (`[`PaletteKind`]`) and helpers to import external
-//! `.pal` files.
+//! of alternative presets (`[`PaletteKind`]`), helpers to import external
+//! `.pal` files and a [`PaletteLibrary`] to keep track of them.
 
 use std::{fs, path::Path};
 
 use crate::{error::Error, mem_block::ppu::PaletteRam as PaletteStorage, memory::ppu as ppu_mem};
 
+mod library;
+
+pub use library::{PaletteEntry, PaletteId, PaletteLibrary, PaletteSource};
+
+/// Colors in a basic palette, one per 6-bit palette index.
+pub const PALETTE_COLORS: usize = 64;
+/// Colors in an emphasis-expanded palette: the basic 64 for each of the eight
+/// `$2001` emphasis bit combinations.
+pub const EXTENDED_PALETTE_COLORS: usize = 8 * PALETTE_COLORS;
+
+/// Flat 512-color lookup table indexed by [`emphasized_index`]. This is what
+/// the framebuffer and video post-processors convert pixels with.
+pub type EmphasizedPalette = [Color; EXTENDED_PALETTE_COLORS];
+
+/// Position of a pixel in an [`EmphasizedPalette`]: emphasis bits (red,
+/// green, blue from bit 0) above the 6-bit palette index.
+#[inline]
+pub const fn emphasized_index(index: u8, emphasis: u8) -> usize {
+    (((emphasis & 0x07) as usize) << 6) | ((index & 0x3F) as usize)
+}
+
 /// Encodes a palette selection and color index as used by the NES PPU.
 ///
 /// Layout (5 bits):
@@ -267,6 +288,10 @@ macro_rules! rgb {
 pub enum Palette {
     Static(&'static [Color; 64]),
     Dynamic(Box<[Color; 64]>),
+    /// 512-color palette with a block of 64 colors per emphasis combination,
+    /// indexed by `$2001` bits 5-7 (`emphasis << 6 | index`). Block 0 is the
+    /// unemphasized palette.
+    Extended(Box<[[Color; 64]; 8]>),
 }
 
 impl Default for Palette {
@@ -278,37 +303,69 @@ impl Default for Palette {
 impl Palette {
     /// Returns the RGB triple for the provided palette index (`$00-$3F`).
     pub fn color(&self, index: u8) -> Color {
-        let idx = (index as usize) & 0x3F;
+        self.as_colors()[(index as usize) & 0x3F]
+    }
+
+    /// Returns the RGB triple for `index` with `$2001` emphasis bits
+    /// `emphasis` (0-7) applied.
+    ///
+    /// Extended palettes look the color up in the matching emphasis block;
+    /// 64-color palettes approximate emphasis by dimming the other channels.
+    pub fn color_with_emphasis(&self, index: u8, emphasis: u8) -> Color {
         match self {
-            Palette::Static(colors) => colors[idx],
-            Palette::Dynamic(colors) => colors[idx],
+            Palette::Extended(blocks) => {
+                blocks[(emphasis & 0x07) as usize][(index as usize) & 0x3F]
+            }
+            _ => approximate_emphasis(self.color(index), index, emphasis),
         }
     }
 
-    /// Provides direct access to the underlying color array.
+    /// Expands the palette into the 512-color table used for video output.
+    pub fn emphasized(&self) -> Box<EmphasizedPalette> {
+        let mut table = Box::new([Color::BLACK; EXTENDED_PALETTE_COLORS]);
+        for (i, color) in table.iter_mut().enumerate() {
+            *color = self.color_with_emphasis(i as u8 & 0x3F, (i >> 6) as u8);
+        }
+        table
+    }
+
+    /// Provides direct access to the underlying color array (the unemphasized
+    /// block for extended palettes).
     pub fn as_colors(&self) -> &[Color; 64] {
         match self {
             Palette::Static(colors) => colors,
             Palette::Dynamic(colors) => colors,
+            Palette::Extended(blocks) => &blocks[0],
         }
     }
 
-    /// Builds a palette from a raw `.pal` blob. Accepts both 192-byte (RGB) and
-    /// 256-byte (RGBA) layouts.
+    /// Whether the palette carries its own emphasis colors.
+    pub fn is_extended(&self) -> bool {
+        matches!(self, Palette::Extended(_))
+    }
+
+    /// Builds a palette from a raw `.pal` blob.
+    ///
+    /// Accepts 64 or 512 colors, each stored as RGB (192/1536 bytes) or RGBA
+    /// (256/2048 bytes, alpha ignored). 512-color files hold the emphasis
+    /// blocks in `$2001` bit order and load as [`Palette::Extended`].
     pub fn from_pal_data(data: &[u8]) -> Result<Self, Error> {
-        let stride = match data.len() {
-            192 => 3,
-            256 => 4,
+        let (count, stride) = match data.len() {
+            192 => (PALETTE_COLORS, 3),
+            256 => (PALETTE_COLORS, 4),
+            1536 => (EXTENDED_PALETTE_COLORS, 3),
+            2048 => (EXTENDED_PALETTE_COLORS, 4),
             actual => return Err(Error::InvalidPaletteSize { actual }),
         };
-        let mut colors = Box::new([Color::BLACK; 64]);
-        let mut idx = 0;
-        while idx < 64 {
-            let base = idx * stride;
-            colors[idx] = Color::new(data[base], data[base + 1], data[base + 2]);
-            idx += 1;
+        let mut blocks = Box::new([[Color::BLACK; 64]; 8]);
+        for (idx, rgb) in data.chunks_exact(stride).take(count).enumerate() {
+            blocks[idx / PALETTE_COLORS][idx % PALETTE_COLORS] = Color::new(rgb[0], rgb[1], rgb[2]);
+        }
+        if count == EXTENDED_PALETTE_COLORS {
+            Ok(Palette::Extended(blocks))
+        } else {
+            Ok(Palette::Dynamic(Box::new(blocks[0])))
         }
-        Ok(Palette::Dynamic(colors))
     }
 
     /// Loads a `.pal` file from disk and parses it using [`Palette::from_pal_data`].
@@ -318,6 +375,39 @@ impl Palette {
     }
 }
 
+/// Derives an emphasized color from a 64-color palette entry.
+///
+/// Matches Mesen2's default filter: each emphasis bit de-intensifies the two
+/// other channels by 0.84. `$xE/$xF` are black and unaffected.
+fn approximate_emphasis(color: Color, color_index: u8, emphasis: u8) -> Color {
+    let emphasis = emphasis & 0x07;
+    if emphasis == 0 || (color_index & 0x0F) > 0x0D {
+        return color;
+    }
+
+    let mut r = color.r as f64;
+    let mut g = color.g as f64;
+    let mut b = color.b as f64;
+    if (emphasis & 0x01) != 0 {
+        g *= 0.84;
+        b *= 0.84;
+    }
+    if (emphasis & 0x02) != 0 {
+        r *= 0.84;
+        b *= 0.84;
+    }
+    if (emphasis & 0x04) != 0 {
+        r *= 0.84;
+        g *= 0.84;
+    }
+
+    Color::new(
+        r.clamp(0.0, 255.0) as u8,
+        g.clamp(0.0, 255.0) as u8,
+        b.clamp(0.0, 255.0) as u8,
+    )
+}
+
 /// Collection of built-in palettes that can be selected without loading a file.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 pub enum PaletteKind {
@@ -384,6 +474,45 @@ impl PaletteKind {
     }
 }
 
+/// Output palette used by [`Ppu::set_clone_mode`](crate::ppu::Ppu::set_clone_mode).
+///
+/// This is an approximation, not a measured palette. Clone PPUs (UMC
+/// UA6527/UA6538 and similar) decode color with their own circuitry and
+/// typically look more saturated than a 2C02, but captures differ between
+/// clone revisions and none is used here. The table is the Mesen 2C02 palette
+/// with its chroma boosted by 25%.
+pub static FAMICLONE_APPROX_PALETTE: Palette = Palette::Static(&FAMICLONE_APPROX_DATA);
+
+const FAMICLONE_APPROX_DATA: [Color; 64] = boost_chroma(&MESEN_2C02_DATA, 5, 4);
+
+/// Scales each color's distance from its luma by `num / den`.
+const fn boost_chroma(src: &[Color; 64], num: i32, den: i32) -> [Color; 64] {
+    const fn scale(channel: u8, luma: i32, num: i32, den: i32) -> u8 {
+        let value = luma + (channel as i32 - luma) * num / den;
+        if value < 0 {
+            0
+        } else if value > 255 {
+            255
+        } else {
+            value as u8
+        }
+    }
+
+    let mut out = [Color::BLACK; 64];
+    let mut idx = 0;
+    while idx < 64 {
+        let c = src[idx];
+        let luma = (c.r as i32 * 299 + c.g as i32 * 587 + c.b as i32 * 114) / 1000;
+        out[idx] = Color::new(
+            scale(c.r, luma, num, den),
+            scale(c.g, luma, num, den),
+            scale(c.b, luma, num, den),
+        );
+        idx += 1;
+    }
+    out
+}
+
 #[rustfmt::skip]
 const MESEN_2C02_DATA: [Color; 64] = [
     // L0 ($00-$0F)
@@ -607,6 +736,35 @@ mod tests {
         assert_eq!(ram.read(ppu_mem::PALETTE_BASE + 0x04), 0xAB & 0x3F);
     }
 
+    #[test]
+    fn pal_data_accepts_64_and_512_color_layouts() {
+        let rgb: Vec<u8> = (0..192).map(|i| i as u8).collect();
+        let rgba: Vec<u8> = rgb
+            .chunks_exact(3)
+            .flat_map(|c| [c[0], c[1], c[2], 0xFF])
+            .collect();
+        let basic = Palette::from_pal_data(&rgb).expect("rgb palette");
+        assert_eq!(Palette::from_pal_data(&rgba).expect("rgba palette"), basic);
+        assert!(!basic.is_extended());
+
+        let mut extended = vec![0u8; 1536];
+        for (block, chunk) in extended.chunks_exact_mut(192).enumerate() {
+            chunk.fill(block as u8 * 0x10);
+        }
+        let extended = Palette::from_pal_data(&extended).expect("512-color palette");
+        assert!(extended.is_extended());
+        assert_eq!(extended.color(0x21), Color::BLACK);
+        assert_eq!(
+            extended.color_with_emphasis(0x21, 5),
+            Color::new(0x50, 0x50, 0x50)
+        );
+
+        assert!(matches!(
+            Palette::from_pal_data(&[0; 1535]),
+            Err(Error::InvalidPaletteSize { actual: 1535 })
+        ));
+    }
+
     #[test]
     fn nesdev_palette_hash_matches_reference() {
         assert_eq!(
@@ -0,0 +1,171 @@
+//! Registry of selectable palettes: the built-in presets plus any `.pal`
+//! files loaded at runtime.
+
+use std::path::{Path, PathBuf};
+
+use super::{Palette, PaletteKind};
+use crate::error::Error;
+
+/// Stable handle to an entry in a [`PaletteLibrary`].
+///
+/// Entries are never removed, so an id stays valid for the lifetime of the
+/// library that issued it. Reloading a file keeps its id.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PaletteId(usize);
+
+impl PaletteId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Where a library entry came from.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PaletteSource {
+    Builtin(PaletteKind),
+    File(PathBuf),
+    /// Added from an in-memory blob via [`PaletteLibrary::add`].
+    Memory,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PaletteEntry {
+    pub id: PaletteId,
+    /// Display name: the preset name or the file stem.
+    pub name: String,
+    pub source: PaletteSource,
+    pub palette: Palette,
+}
+
+#[derive(Clone, Debug)]
+pub struct PaletteLibrary {
+    entries: Vec<PaletteEntry>,
+}
+
+impl Default for PaletteLibrary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PaletteLibrary {
+    /// Creates a library holding every [`PaletteKind`] preset, in
+    /// [`PaletteKind::all`] order.
+    pub fn new() -> Self {
+        let mut library = Self {
+            entries: Vec::new(),
+        };
+        for &kind in PaletteKind::all() {
+            library.push(
+                kind.as_str().to_owned(),
+                PaletteSource::Builtin(kind),
+                kind.palette(),
+            );
+        }
+        library
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &PaletteEntry> {
+        self.entries.iter()
+    }
+
+    pub fn get(&self, id: PaletteId) -> Option<&PaletteEntry> {
+        self.entries.get(id.0)
+    }
+
+    /// Looks an entry up by display name (case-insensitive).
+    pub fn find(&self, name: &str) -> Option<&PaletteEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Id of the entry for a built-in preset.
+    pub fn builtin(&self, kind: PaletteKind) -> Option<PaletteId> {
+        self.entries
+            .iter()
+            .find(|entry| entry.source == PaletteSource::Builtin(kind))
+            .map(|entry| entry.id)
+    }
+
+    /// Loads a 64- or 512-color `.pal` file. Loading a path that is already
+    /// in the library replaces its colors in place and returns the same id.
+    pub fn load_file(&mut self, path: impl AsRef<Path>) -> Result<PaletteId, Error> {
+        let path = path.as_ref();
+        let palette = Palette::from_pal_file(path)?;
+        let source = PaletteSource::File(path.to_path_buf());
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.source == source) {
+            entry.palette = palette;
+            return Ok(entry.id);
+        }
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+        Ok(self.push(name, source, palette))
+    }
+
+    /// Validates and adds a raw `.pal` blob under `name`.
+    pub fn add(&mut self, name: impl Into<String>, data: &[u8]) -> Result<PaletteId, Error> {
+        let palette = Palette::from_pal_data(data)?;
+        Ok(self.push(name.into(), PaletteSource::Memory, palette))
+    }
+
+    fn push(&mut self, name: String, source: PaletteSource, palette: Palette) -> PaletteId {
+        let id = PaletteId(self.entries.len());
+        self.entries.push(PaletteEntry {
+            id,
+            name,
+            source,
+            palette,
+        });
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_builtins_and_reloads_files_in_place() {
+        let mut library = PaletteLibrary::new();
+        assert_eq!(library.len(), PaletteKind::all().len());
+        let nesdev = library
+            .builtin(PaletteKind::NesdevNtsc)
+            .expect("builtin entry");
+        assert_eq!(
+            library.get(nesdev).map(|e| &e.palette),
+            Some(&PaletteKind::NesdevNtsc.palette())
+        );
+
+        let path = std::env::temp_dir().join(format!("nesium-library-{}.pal", std::process::id()));
+        std::fs::write(&path, [0x10; 192]).expect("write palette");
+        let first = library.load_file(&path).expect("load");
+        std::fs::write(&path, [0x20; 1536]).expect("rewrite palette");
+        let second = library.load_file(&path).expect("reload");
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(first, second);
+        let entry = library.get(first).expect("file entry");
+        assert!(entry.palette.is_extended());
+        assert_eq!(
+            library.find(&entry.name.to_uppercase()).map(|e| e.id),
+            Some(first)
+        );
+
+        assert!(matches!(
+            library.add("broken", &[0; 100]),
+            Err(Error::InvalidPaletteSize { actual: 100 })
+        ));
+        assert_eq!(library.len(), PaletteKind::all().len() + 1);
+    }
+}
@@ -54,6 +54,14 @@ impl<'a> PpuBus<'a> {
         }
     }
 
+    /// Reads CHR without notifying the mapper, for display-only fetches that
+    /// must not disturb A12-based IRQ counters or CHR latches.
+    pub fn chr_peek(&self, addr: u16) -> u8 {
+        self.cartridge
+            .as_deref()
+            .map_or(0, |cart| cart.chr_read(addr))
+    }
+
     /// CHR bus write convenience method for CHR RAM mappers.
     pub fn chr_write(&mut self, addr: u16, value: u8, ctx: PpuVramAccessContext) {
         if let Some(cart) = self.cartridge.as_deref_mut() {
@@ -17,6 +17,28 @@ struct SpriteSlot {
     sprite0: bool,
 }
 
+impl SpriteSlot {
+    /// Builds a slot from fetched sprite data, pre-flipping the bitplanes when
+    /// horizontal flip is set so that shifting left always walks pixels
+    /// left-to-right on output.
+    fn new(pattern_low: u8, pattern_high: u8, attributes: u8, x: u8, sprite0: bool) -> Self {
+        let attributes = SpriteAttributes::from_bits_retain(attributes);
+        let (pattern_low, pattern_high) = if attributes.contains(SpriteAttributes::FLIP_HORIZONTAL)
+        {
+            (pattern_low.reverse_bits(), pattern_high.reverse_bits())
+        } else {
+            (pattern_low, pattern_high)
+        };
+        Self {
+            pattern_low,
+            pattern_high,
+            attributes,
+            x_counter: x,
+            sprite0,
+        }
+    }
+}
+
 impl Default for SpriteSlot {
     fn default() -> Self {
         Self {
@@ -40,6 +62,8 @@ pub(crate) struct SpritePixel {
     pub(crate) priority_behind_bg: bool,
     /// Whether this pixel came from sprite 0.
     pub(crate) is_sprite0: bool,
+    /// Pipeline slot (OAM order on the line) that produced the pixel.
+    pub(crate) slot: u8,
 }
 
 /// Sprite pixel pipeline for the current scanline.
@@ -80,9 +104,6 @@ impl SpritePipeline {
     ///
     /// `count` is the number of sprites that were found for the scanline (0..=8).
     /// `sprite0_in_range` indicates whether sprite 0 was one of them.
-    ///
-    /// Pattern bytes are optionally bit-reversed when horizontal flip is set so
-    /// that shifting left always walks pixels left-to-right on output.
     pub(crate) fn load_scanline(
         &mut self,
         count: u8,
@@ -96,62 +117,19 @@ impl SpritePipeline {
         self.active_count = count.min(8);
 
         for i in 0..self.active_count as usize {
-            let mut low = pattern_low[i];
-            let mut high = pattern_high[i];
-            let attributes = SpriteAttributes::from_bits_retain(attrs[i]);
-
-            // Pre-flip bitplanes when horizontal flip is set.
-            if attributes.contains(SpriteAttributes::FLIP_HORIZONTAL) {
-                low = low.reverse_bits();
-                high = high.reverse_bits();
-            }
-
-            self.slots[i] = SpriteSlot {
-                pattern_low: low,
-                pattern_high: high,
-                attributes,
-                x_counter: xs[i],
-                sprite0: sprite0_in_range && i == 0,
-            };
+            self.slots[i] = SpriteSlot::new(
+                pattern_low[i],
+                pattern_high[i],
+                attrs[i],
+                xs[i],
+                sprite0_in_range && i == 0,
+            );
         }
     }
 
     /// Samples the current sprite pixel and advances active shifters by one dot.
     pub(crate) fn sample_and_shift(&mut self) -> SpritePixel {
-        let mut chosen: Option<SpritePixel> = None;
-
-        for slot in self.slots.iter_mut().take(self.active_count as usize) {
-            // Hardware order: decrement X counter first; when it transitions to
-            // zero, the shifter starts outputting on the *next* dot.
-            if slot.x_counter > 0 {
-                slot.x_counter = slot.x_counter.saturating_sub(1);
-                continue;
-            }
-
-            // Extract the current pixel from the MSB of each bitplane.
-            let bit0 = (slot.pattern_low >> 7) & 1;
-            let bit1 = (slot.pattern_high >> 7) & 1;
-            let color = (bit1 << 1) | bit0;
-
-            if chosen.is_none() && color != 0 {
-                let palette = slot.attributes.bits() & 0b11;
-                let priority_behind_bg = slot
-                    .attributes
-                    .contains(SpriteAttributes::PRIORITY_BEHIND_BACKGROUND);
-                chosen = Some(SpritePixel {
-                    palette,
-                    color,
-                    priority_behind_bg,
-                    is_sprite0: slot.sprite0,
-                });
-            }
-
-            // Advance shifters once per dot after the delay has expired.
-            slot.pattern_low <<= 1;
-            slot.pattern_high <<= 1;
-        }
-
-        chosen.unwrap_or_default()
+        sample_and_shift(self.slots.iter_mut().take(self.active_count as usize))
     }
 
     pub(crate) fn save_state(&self) -> SpritePipelineState {
@@ -184,3 +162,88 @@ impl SpritePipeline {
         }
     }
 }
+
+/// Sprites beyond the eighth on a scanline, shown when the sprite limit is
+/// removed (see [`Ppu::set_sprite_limit_removed`](super::Ppu::set_sprite_limit_removed)).
+///
+/// These never take part in emulation: they are not saved in save states,
+/// cannot trigger sprite 0 hits and only fill in pixels where the regular
+/// eight sprites are transparent, matching their lower OAM priority.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub(crate) struct ExtraSprites {
+    /// Fetched at the end of the current line for the next one.
+    next: Vec<SpriteSlot>,
+    /// Shifting out on the current line.
+    current: Vec<SpriteSlot>,
+}
+
+impl ExtraSprites {
+    pub(crate) fn clear(&mut self) {
+        self.next.clear();
+        self.current.clear();
+    }
+
+    pub(crate) fn clear_next(&mut self) {
+        self.next.clear();
+    }
+
+    pub(crate) fn push_next(&mut self, pattern_low: u8, pattern_high: u8, attributes: u8, x: u8) {
+        self.next.push(SpriteSlot::new(
+            pattern_low,
+            pattern_high,
+            attributes,
+            x,
+            false,
+        ));
+    }
+
+    /// Makes the sprites fetched on the previous line active.
+    pub(crate) fn start_line(&mut self) {
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.next.clear();
+    }
+
+    pub(crate) fn sample_and_shift(&mut self) -> SpritePixel {
+        sample_and_shift(self.current.iter_mut())
+    }
+}
+
+/// Returns the first opaque pixel among `slots` (in OAM order) and advances
+/// every slot by one dot.
+fn sample_and_shift<'a>(slots: impl Iterator<Item = &'a mut SpriteSlot>) -> SpritePixel {
+    let mut chosen: Option<SpritePixel> = None;
+
+    for (index, slot) in slots.enumerate() {
+        // Hardware order: decrement X counter first; when it transitions to
+        // zero, the shifter starts outputting on the *next* dot.
+        if slot.x_counter > 0 {
+            slot.x_counter = slot.x_counter.saturating_sub(1);
+            continue;
+        }
+
+        // Extract the current pixel from the MSB of each bitplane.
+        let bit0 = (slot.pattern_low >> 7) & 1;
+        let bit1 = (slot.pattern_high >> 7) & 1;
+        let color = (bit1 << 1) | bit0;
+
+        if chosen.is_none() && color != 0 {
+            let palette = slot.attributes.bits() & 0b11;
+            let priority_behind_bg = slot
+                .attributes
+                .contains(SpriteAttributes::PRIORITY_BEHIND_BACKGROUND);
+            chosen = Some(SpritePixel {
+                palette,
+                color,
+                priority_behind_bg,
+                is_sprite0: slot.sprite0,
+                slot: index as u8,
+            });
+        }
+
+        // Advance shifters once per dot after the delay has expired.
+        slot.pattern_low <<= 1;
+        slot.pattern_high <<= 1;
+    }
+
+    chosen.unwrap_or_default()
+}
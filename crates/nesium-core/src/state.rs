@@ -6,8 +6,13 @@
 //! Incremental/typed deltas are intentionally *not* part of this API.
 //! For rewind and rollback use-cases, prefer storing serialized snapshot bytes
 //! using external compression/diffing (e.g., XOR + LZ4) at the runtime layer.
+//!
+//! With `savestate-postcard`, [`format`](mod@format) defines the versioned byte layout
+//! used to persist NES snapshots.
 
 pub mod cpu;
+#[cfg(feature = "savestate-postcard")]
+pub mod format;
 pub mod nes;
 pub mod ppu;
 
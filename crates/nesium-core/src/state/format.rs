@@ -0,0 +1,507 @@
+//! Versioned byte layout for [`NesSnapshot`].
+//!
+//! A save state is a short header followed by one chunk per component:
+//!
+//! ```text
+//! "NSST" | layout version: u16 LE
+//! chunk  = tag: [u8; 4] | chunk version: u16 LE | length: u32 LE | payload
+//! ```
+//!
+//! Payloads are postcard-encoded component states. Readers are tolerant so
+//! states keep loading across builds:
+//! - chunks with an unknown tag are skipped, so newer builds can add
+//!   components without breaking older readers;
+//! - bytes left after a payload are ignored, so fields appended to the end of
+//!   a component state stay readable by older builds;
+//! - each component decodes every chunk version it has ever written and
+//!   migrates it forward.
+//!
+//! When a component state changes in a way older readers cannot skip over,
+//! bump its `Chunk::VERSION`, keep the previous struct and convert from it
+//! in `Chunk::decode`.
+//!
+//! Data without the magic is the single postcard-encoded snapshot written
+//! before this layout existed. It is the component payloads back to back;
+//! those of the last released version (format version 3) are read through
+//! the old layouts in [`legacy`] and migrated.
+
+mod legacy;
+
+use std::{collections::HashMap, fmt};
+
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use crate::{
+    apu::Apu,
+    audio::mixer::MixerState,
+    bus::{PendingDma, savestate::OpenBusState},
+    controller::{ControllerPorts, Multitap},
+    state::{
+        Snapshot, SnapshotMeta,
+        nes::{CartridgeState, CpuState, NesSnapshot, NesState, PartialFrameState, PpuState},
+    },
+};
+
+const MAGIC: [u8; 4] = *b"NSST";
+const LAYOUT_VERSION: u16 = 1;
+/// `SnapshotMeta::format_version` of the last release before the chunked
+/// layout.
+const LEGACY_FORMAT_VERSION: u32 = 3;
+
+/// Errors raised when encoding or decoding save state bytes.
+#[derive(Debug)]
+pub enum StateFormatError {
+    Postcard(postcard::Error),
+    /// The data ends in the middle of a header or chunk.
+    Truncated,
+    UnsupportedLayout(u16),
+    /// Pre-chunked data from a format version that cannot be migrated.
+    UnsupportedLegacyVersion(u32),
+    /// The chunk was written by a newer build, or a version never released.
+    UnsupportedChunkVersion {
+        tag: [u8; 4],
+        version: u16,
+    },
+    MissingChunk([u8; 4]),
+}
+
+impl fmt::Display for StateFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StateFormatError::Postcard(err) => write!(f, "malformed state data: {err}"),
+            StateFormatError::Truncated => write!(f, "state data is truncated"),
+            StateFormatError::UnsupportedLayout(version) => {
+                write!(f, "unsupported state layout version {version}")
+            }
+            StateFormatError::UnsupportedLegacyVersion(version) => {
+                write!(f, "unsupported state format version {version}")
+            }
+            StateFormatError::UnsupportedChunkVersion { tag, version } => write!(
+                f,
+                "unsupported version {version} of state chunk {}",
+                tag.escape_ascii()
+            ),
+            StateFormatError::MissingChunk(tag) => {
+                write!(f, "state chunk {} is missing", tag.escape_ascii())
+            }
+        }
+    }
+}
+
+impl std::error::Error for StateFormatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StateFormatError::Postcard(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<postcard::Error> for StateFormatError {
+    fn from(err: postcard::Error) -> Self {
+        StateFormatError::Postcard(err)
+    }
+}
+
+/// A component stored as its own chunk.
+trait Chunk: Serialize + DeserializeOwned {
+    const TAG: [u8; 4];
+    /// Version written by this build.
+    const VERSION: u16 = 1;
+
+    /// Decodes a payload written as chunk `version`, returning the bytes
+    /// after it.
+    fn decode(version: u16, payload: &[u8]) -> Result<(Self, &[u8]), StateFormatError> {
+        if version == Self::VERSION {
+            Ok(postcard::take_from_bytes(payload)?)
+        } else {
+            Err(StateFormatError::UnsupportedChunkVersion {
+                tag: Self::TAG,
+                version,
+            })
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct Ram(Vec<u8>);
+
+/// Pads plus the four-player adapter, if one is connected.
+#[derive(Serialize, Deserialize)]
+struct Controllers {
+    ports: ControllerPorts,
+    multitap: Option<Multitap>,
+}
+
+/// Bus timing and DMA bookkeeping kept on `Nes` itself.
+#[derive(Serialize, Deserialize)]
+struct BusState {
+    last_frame: u32,
+    dot_counter: u64,
+    master_clock: u64,
+    ppu_offset: u8,
+    clock_start_count: u8,
+    clock_end_count: u8,
+    pending_dma: PendingDma,
+    open_bus: OpenBusState,
+    cycles: u64,
+}
+
+impl Chunk for SnapshotMeta {
+    const TAG: [u8; 4] = *b"META";
+}
+
+impl Chunk for CpuState {
+    const TAG: [u8; 4] = *b"CPU ";
+}
+
+impl Chunk for PpuState {
+    const TAG: [u8; 4] = *b"PPU ";
+}
+
+impl Chunk for PartialFrameState {
+    const TAG: [u8; 4] = *b"FRAM";
+}
+
+impl Chunk for Apu {
+    const TAG: [u8; 4] = *b"APU ";
+}
+
+impl Chunk for Ram {
+    const TAG: [u8; 4] = *b"RAM ";
+}
+
+impl Chunk for CartridgeState {
+    const TAG: [u8; 4] = *b"CART";
+}
+
+impl Chunk for Controllers {
+    const TAG: [u8; 4] = *b"CTRL";
+
+    fn decode(version: u16, payload: &[u8]) -> Result<(Self, &[u8]), StateFormatError> {
+        if version != Self::VERSION {
+            return Err(StateFormatError::UnsupportedChunkVersion {
+                tag: Self::TAG,
+                version,
+            });
+        }
+        // The multitap was appended to the chunk in format version 11;
+        // earlier payloads end after the pads.
+        let (ports, rest) = postcard::take_from_bytes(payload)?;
+        let (multitap, rest) = if rest.is_empty() {
+            (None, rest)
+        } else {
+            postcard::take_from_bytes(rest)?
+        };
+        Ok((Self { ports, multitap }, rest))
+    }
+}
+
+impl Chunk for BusState {
+    const TAG: [u8; 4] = *b"BUS ";
+}
+
+impl Chunk for MixerState {
+    const TAG: [u8; 4] = *b"MIXR";
+}
+
+fn write_chunk<C: Chunk>(out: &mut Vec<u8>, chunk: &C) -> Result<(), StateFormatError> {
+    let payload = postcard::to_stdvec(chunk)?;
+    let len = u32::try_from(payload.len()).map_err(|_| postcard::Error::SerializeBufferFull)?;
+    out.extend_from_slice(&C::TAG);
+    out.extend_from_slice(&C::VERSION.to_le_bytes());
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(&payload);
+    Ok(())
+}
+
+fn split<const N: usize>(bytes: &[u8]) -> Result<([u8; N], &[u8]), StateFormatError> {
+    let (head, rest) = bytes
+        .split_first_chunk::<N>()
+        .ok_or(StateFormatError::Truncated)?;
+    Ok((*head, rest))
+}
+
+fn decode_chunked(bytes: &[u8]) -> Result<NesSnapshot, StateFormatError> {
+    let (layout, mut rest) = split::<2>(bytes)?;
+    let layout = u16::from_le_bytes(layout);
+    if layout != LAYOUT_VERSION {
+        return Err(StateFormatError::UnsupportedLayout(layout));
+    }
+
+    let mut chunks = HashMap::new();
+    while !rest.is_empty() {
+        let (tag, after_tag) = split::<4>(rest)?;
+        let (version, after_version) = split::<2>(after_tag)?;
+        let (len, after_len) = split::<4>(after_version)?;
+        let len = u32::from_le_bytes(len) as usize;
+        if after_len.len() < len {
+            return Err(StateFormatError::Truncated);
+        }
+        let (payload, next) = after_len.split_at(len);
+        chunks
+            .entry(tag)
+            .or_insert((u16::from_le_bytes(version), payload));
+        rest = next;
+    }
+
+    fn take<C: Chunk>(chunks: &HashMap<[u8; 4], (u16, &[u8])>) -> Result<C, StateFormatError> {
+        let &(version, payload) = chunks
+            .get(&C::TAG)
+            .ok_or(StateFormatError::MissingChunk(C::TAG))?;
+        Ok(C::decode(version, payload)?.0)
+    }
+
+    let meta = take(&chunks)?;
+    let data = assemble(
+        take(&chunks)?,
+        take(&chunks)?,
+        // Format version 9 states were always taken between frames.
+        if chunks.contains_key(&PartialFrameState::TAG) {
+            take(&chunks)?
+        } else {
+            PartialFrameState::default()
+        },
+        take(&chunks)?,
+        take(&chunks)?,
+        take(&chunks)?,
+        take(&chunks)?,
+        take(&chunks)?,
+        take(&chunks)?,
+    );
+    Ok(Snapshot { meta, data })
+}
+
+fn decode_legacy(bytes: &[u8]) -> Result<NesSnapshot, StateFormatError> {
+    fn next<T: DeserializeOwned>(bytes: &mut &[u8]) -> Result<T, StateFormatError> {
+        let (value, rest) = postcard::take_from_bytes(bytes)?;
+        *bytes = rest;
+        Ok(value)
+    }
+
+    let mut bytes = bytes;
+    let meta: SnapshotMeta = next(&mut bytes)?;
+    if meta.format_version != LEGACY_FORMAT_VERSION {
+        return Err(StateFormatError::UnsupportedLegacyVersion(
+            meta.format_version,
+        ));
+    }
+    // Same field order as the old `NesState`.
+    let data = assemble(
+        next(&mut bytes)?,
+        next::<legacy::PpuStateV3>(&mut bytes)?.into(),
+        PartialFrameState::default(),
+        next(&mut bytes)?,
+        next(&mut bytes)?,
+        next::<legacy::CartridgeStateV3>(&mut bytes)?.into(),
+        Controllers {
+            ports: next::<legacy::ControllerPortsV3>(&mut bytes)?.into(),
+            multitap: None,
+        },
+        next(&mut bytes)?,
+        next(&mut bytes)?,
+    );
+    Ok(Snapshot { meta, data })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn assemble(
+    cpu: CpuState,
+    ppu: PpuState,
+    partial_frame: PartialFrameState,
+    apu: Apu,
+    ram: Ram,
+    cartridge: CartridgeState,
+    controllers: Controllers,
+    bus: BusState,
+    mixer: MixerState,
+) -> NesState {
+    NesState {
+        cpu,
+        ppu,
+        partial_frame,
+        apu,
+        ram: ram.0,
+        cartridge,
+        controllers: controllers.ports,
+        multitap: controllers.multitap,
+        last_frame: bus.last_frame,
+        dot_counter: bus.dot_counter,
+        master_clock: bus.master_clock,
+        ppu_offset: bus.ppu_offset,
+        clock_start_count: bus.clock_start_count,
+        clock_end_count: bus.clock_end_count,
+        pending_dma: bus.pending_dma,
+        open_bus: bus.open_bus,
+        cycles: bus.cycles,
+        mixer,
+    }
+}
+
+impl Snapshot<NesState, SnapshotMeta> {
+    /// Encodes the snapshot in the chunked layout described in the
+    /// [module docs](self).
+    pub fn to_postcard_bytes(&self) -> Result<Vec<u8>, StateFormatError> {
+        let state = &self.data;
+        let mut out = Vec::with_capacity(80 * 1024);
+        out.extend_from_slice(&MAGIC);
+        out.extend_from_slice(&LAYOUT_VERSION.to_le_bytes());
+        write_chunk(&mut out, &self.meta)?;
+        write_chunk(&mut out, &state.cpu)?;
+        write_chunk(&mut out, &state.ppu)?;
+        write_chunk(&mut out, &state.partial_frame)?;
+        write_chunk(&mut out, &state.apu)?;
+        write_chunk(&mut out, &Ram(state.ram.clone()))?;
+        write_chunk(&mut out, &state.cartridge)?;
+        write_chunk(
+            &mut out,
+            &Controllers {
+                ports: state.controllers.clone(),
+                multitap: state.multitap,
+            },
+        )?;
+        write_chunk(
+            &mut out,
+            &BusState {
+                last_frame: state.last_frame,
+                dot_counter: state.dot_counter,
+                master_clock: state.master_clock,
+                ppu_offset: state.ppu_offset,
+                clock_start_count: state.clock_start_count,
+                clock_end_count: state.clock_end_count,
+                pending_dma: state.pending_dma,
+                open_bus: state.open_bus,
+                cycles: state.cycles,
+            },
+        )?;
+        write_chunk(&mut out, &state.mixer)?;
+        Ok(out)
+    }
+
+    /// Decodes a snapshot written by this or any earlier supported build,
+    /// migrating older component layouts.
+    pub fn from_postcard_bytes(bytes: &[u8]) -> Result<Self, StateFormatError> {
+        match bytes.strip_prefix(&MAGIC) {
+            Some(rest) => decode_chunked(rest),
+            None => decode_legacy(bytes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nrom() -> Vec<u8> {
+        let mut rom = Vec::with_capacity(16 + 16 * 1024 + 8 * 1024);
+        rom.extend_from_slice(b"NES\x1A");
+        rom.extend_from_slice(&[1, 1]);
+        rom.extend_from_slice(&[0; 10]);
+        rom.extend_from_slice(&[0; 24 * 1024]);
+        rom
+    }
+
+    fn snapshot_bytes() -> Vec<u8> {
+        let mut nes = crate::Nes::default();
+        nes.load_cartridge_from_bytes(&nrom())
+            .expect("load cartridge");
+        nes.run_frame(false);
+        nes.save_snapshot(SnapshotMeta::default())
+            .expect("save snapshot")
+            .to_postcard_bytes()
+            .expect("encode snapshot")
+    }
+
+    /// Offset of the chunk header with `tag`.
+    fn chunk_at(bytes: &[u8], tag: [u8; 4]) -> usize {
+        let mut at = MAGIC.len() + 2;
+        while bytes[at..at + 4] != tag {
+            let len = u32::from_le_bytes(bytes[at + 6..at + 10].try_into().expect("len"));
+            at += 10 + len as usize;
+        }
+        at
+    }
+
+    #[test]
+    fn unknown_chunks_and_trailing_payload_bytes_are_skipped() {
+        let bytes = snapshot_bytes();
+        let expected = NesSnapshot::from_postcard_bytes(&bytes).expect("decode");
+
+        let mut extended = bytes.clone();
+        extended.extend_from_slice(b"NEW!\x01\x00\x03\x00\x00\x00abc");
+        // Grow the CPU chunk as if a newer build appended a field.
+        let len_at = chunk_at(&bytes, CpuState::TAG) + 6;
+        let len = u32::from_le_bytes(extended[len_at..len_at + 4].try_into().expect("len"));
+        extended[len_at..len_at + 4].copy_from_slice(&(len + 1).to_le_bytes());
+        extended.insert(len_at + 4 + len as usize, 0xAA);
+
+        let decoded = NesSnapshot::from_postcard_bytes(&extended).expect("decode extended");
+        assert_eq!(decoded.meta, expected.meta);
+        assert_eq!(decoded.data.cpu, expected.data.cpu);
+        assert_eq!(decoded.data.ppu, expected.data.ppu);
+        assert_eq!(decoded.data.ram, expected.data.ram);
+    }
+
+    #[test]
+    fn controller_chunks_keep_the_multitap() {
+        use crate::controller::MultitapKind;
+
+        let rom = nrom();
+        let mut nes = crate::Nes::default();
+        nes.load_cartridge_from_bytes(&rom).expect("load cartridge");
+        nes.set_multitap(Some(MultitapKind::FourScore));
+        nes.run_frame(false);
+        let snapshot = nes.save_snapshot(SnapshotMeta::default()).expect("save");
+
+        let bytes = snapshot.to_postcard_bytes().expect("encode");
+        let decoded = NesSnapshot::from_postcard_bytes(&bytes).expect("decode");
+        assert!(decoded.data.multitap.is_some());
+        assert_eq!(decoded.data.multitap, snapshot.data.multitap);
+
+        let mut restored = crate::Nes::default();
+        restored
+            .load_cartridge_from_bytes(&rom)
+            .expect("load cartridge");
+        restored.load_snapshot(&decoded).expect("load snapshot");
+        assert_eq!(restored.multitap(), Some(MultitapKind::FourScore));
+    }
+
+    #[test]
+    fn controller_chunks_without_a_multitap_still_decode() {
+        let mut bytes = snapshot_bytes();
+        // Drop the trailing `None` tag, as written before the multitap was
+        // part of the chunk.
+        let ctrl_at = chunk_at(&bytes, Controllers::TAG);
+        let len_at = ctrl_at + 6;
+        let len = u32::from_le_bytes(bytes[len_at..len_at + 4].try_into().expect("len"));
+        bytes[len_at..len_at + 4].copy_from_slice(&(len - 1).to_le_bytes());
+        bytes.remove(len_at + 4 + len as usize - 1);
+
+        let decoded = NesSnapshot::from_postcard_bytes(&bytes).expect("decode");
+        assert_eq!(decoded.data.multitap, None);
+    }
+
+    #[test]
+    fn newer_chunk_versions_and_missing_chunks_are_rejected() {
+        let bytes = snapshot_bytes();
+        let cpu_at = chunk_at(&bytes, CpuState::TAG);
+
+        let mut newer = bytes.clone();
+        newer[cpu_at + 4..cpu_at + 6].copy_from_slice(&2u16.to_le_bytes());
+        assert!(matches!(
+            NesSnapshot::from_postcard_bytes(&newer),
+            Err(StateFormatError::UnsupportedChunkVersion {
+                tag: CpuState::TAG,
+                version: 2
+            })
+        ));
+
+        let mut renamed = bytes;
+        renamed[cpu_at..cpu_at + 4].copy_from_slice(b"XCPU");
+        assert!(matches!(
+            NesSnapshot::from_postcard_bytes(&renamed),
+            Err(StateFormatError::MissingChunk(CpuState::TAG))
+        ));
+    }
+}
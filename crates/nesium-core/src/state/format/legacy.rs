@@ -0,0 +1,194 @@
+//! Component layouts of the last released pre-chunked snapshots (format
+//! version 3), migrated to the current component states.
+//!
+//! Only components whose serialized layout changed since then are listed;
+//! the rest decode through the current structs.
+
+use serde::Deserialize;
+
+use crate::{
+    cartridge::mapper::{
+        mapper1::Mapper1State, mapper2::Mapper2State, mapper3::Mapper3State, mapper4::Mapper4State,
+    },
+    controller::{Controller, ControllerPorts},
+    mem_block::MemBlock,
+    ppu::savestate::{
+        BgPipelineState, PendingVramIncrementState, PpuOpenBusState, SpriteEvalState,
+        SpriteFetchState, SpriteLineBuffersState, SpritePipelineState,
+    },
+    state::nes::{CartridgeState, MapperState, PpuState},
+};
+
+/// Declares an old layout of `$target` with the fields it had, in order, and
+/// a conversion that sets the fields added since to the given defaults.
+macro_rules! migrated {
+    (
+        $name:ident => $target:ident { $($field:ident: $ty:ty,)* }
+        defaults { $($new:ident: $value:expr,)* }
+    ) => {
+        #[derive(Deserialize)]
+        pub(super) struct $name {
+            $($field: $ty,)*
+        }
+
+        impl From<$name> for $target {
+            fn from(old: $name) -> Self {
+                Self {
+                    $($new: $value,)*
+                    $($field: old.$field,)*
+                }
+            }
+        }
+    };
+}
+
+migrated! {
+    PpuStateV3 => PpuState {
+        control: u8,
+        mask: u8,
+        status: u8,
+        oam_addr: u8,
+        oam: Vec<u8>,
+        vram_v: u16,
+        vram_t: u16,
+        vram_x: u8,
+        vram_w: bool,
+        vram_buffer: u8,
+        pending_vram_addr: u16,
+        pending_vram_delay: u8,
+        ciram: Vec<u8>,
+        palette_ram: Vec<u8>,
+        cycle: u16,
+        scanline: i16,
+        frame: u32,
+        master_clock: u64,
+        bg_pipeline: BgPipelineState,
+        bg_next_tile_index: u8,
+        bg_next_attr_byte: u8,
+        bg_next_pattern_low: u8,
+        bg_next_pattern_high: u8,
+        bg_next_tile_addr: u16,
+        sprite_pipeline: SpritePipelineState,
+        nmi_level: bool,
+        prevent_vblank_flag: bool,
+        open_bus: PpuOpenBusState,
+        ignore_vram_read: u8,
+        oam_copybuffer: u8,
+        pending_vram_increment: PendingVramIncrementState,
+        pending_vram_increment_kind: u8,
+        secondary_oam: Vec<u8>,
+        sprite_eval: SpriteEvalState,
+        sprite_fetch: SpriteFetchState,
+        sprite_line_next: SpriteLineBuffersState,
+        render_enabled: bool,
+        prev_render_enabled: bool,
+        oam_addr_disable_glitch_pending: bool,
+        corrupt_oam_row: [bool; 32],
+        state_update_pending: bool,
+        output_grayscale: bool,
+        output_emphasis: u8,
+        color_mask_last_updated_pixel: i32,
+        raw_output_indices: Vec<u8>,
+    }
+    defaults {
+        overclock_line: 0,
+        in_overclock_scanline: false,
+        oam_row_access: [0; 32],
+    }
+}
+
+migrated! {
+    Mapper1StateV3 => Mapper1State {
+        control: u8,
+        chr_bank0: u8,
+        chr_bank1: u8,
+        prg_bank: u8,
+        shift_reg: u8,
+        shift_count: u8,
+        last_serial_cycle: Option<u64>,
+    }
+    defaults {
+        last_chr_bank1: false,
+    }
+}
+
+migrated! {
+    Mapper4StateV3 => Mapper4State {
+        base_mirroring: u8,
+        mirroring: u8,
+        bank_select: u8,
+        bank_regs: [u8; 8],
+        prg_ram_enable: bool,
+        prg_ram_write_protect: bool,
+        irq_latch: u8,
+        irq_counter: u8,
+        irq_reload: bool,
+        irq_enabled: bool,
+        irq_pending: bool,
+        a12_low_start_master_clock: Option<u64>,
+    }
+    defaults {
+        irq_prescaler: 0,
+    }
+}
+
+#[derive(Deserialize)]
+enum MapperStateV3 {
+    Mapper0,
+    Mapper1(Mapper1StateV3),
+    Mapper2(Mapper2State),
+    Mapper3(Mapper3State),
+    Mapper4(Mapper4StateV3),
+}
+
+impl From<MapperStateV3> for MapperState {
+    fn from(old: MapperStateV3) -> Self {
+        match old {
+            MapperStateV3::Mapper0 => MapperState::Mapper0,
+            MapperStateV3::Mapper1(state) => MapperState::Mapper1(state.into()),
+            MapperStateV3::Mapper2(state) => MapperState::Mapper2(state),
+            MapperStateV3::Mapper3(state) => MapperState::Mapper3(state),
+            MapperStateV3::Mapper4(state) => MapperState::Mapper4(state.into()),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub(super) struct CartridgeStateV3 {
+    mapper_id: u16,
+    submapper: u8,
+    prg_ram: Option<Vec<u8>>,
+    prg_work_ram: Option<Vec<u8>>,
+    chr_ram: Option<Vec<u8>>,
+    chr_battery_ram: Option<Vec<u8>>,
+    mapper_ram: Option<Vec<u8>>,
+    mapper: MapperStateV3,
+}
+
+impl From<CartridgeStateV3> for CartridgeState {
+    fn from(old: CartridgeStateV3) -> Self {
+        Self {
+            mapper_id: old.mapper_id,
+            submapper: old.submapper,
+            prg_ram: old.prg_ram,
+            prg_work_ram: old.prg_work_ram,
+            chr_ram: old.chr_ram,
+            chr_battery_ram: old.chr_battery_ram,
+            mapper_ram: old.mapper_ram,
+            mapper: old.mapper.into(),
+        }
+    }
+}
+
+/// Version 3 had two pads; pads 3 and 4 start released.
+#[derive(Deserialize)]
+#[serde(transparent)]
+pub(super) struct ControllerPortsV3(MemBlock<Controller, 2>);
+
+impl From<ControllerPortsV3> for ControllerPorts {
+    fn from(old: ControllerPortsV3) -> Self {
+        let mut ports = ControllerPorts::new();
+        ports.as_mut_slice()[..2].copy_from_slice(old.0.as_slice());
+        ports
+    }
+}
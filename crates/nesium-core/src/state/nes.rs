@@ -10,10 +10,11 @@ use crate::{
         Cartridge,
         mapper::{Mapper1, Mapper2, Mapper3, Mapper4, mapper_downcast_mut, mapper_downcast_ref},
     },
-    controller::ControllerPorts,
+    controller::{ControllerPorts, Multitap},
     cpu::{Cpu, IrqSource, Status as CpuStatus},
     ppu::{
         Control, Mask, PendingVramIncrement, Ppu, SpriteLineBuffers, Status,
+        buffer::SCREEN_SIZE,
         savestate::{
             BgPipelineState, PendingVramIncrementState, PpuOpenBusState, SpriteEvalState,
             SpriteFetchState, SpriteLineBuffersState, SpritePipelineState,
@@ -109,6 +110,8 @@ pub struct PpuState {
     pub scanline: i16,
     pub frame: u32,
     pub master_clock: u64,
+    pub overclock_line: u16,
+    pub in_overclock_scanline: bool,
     pub bg_pipeline: BgPipelineState,
     pub bg_next_tile_index: u8,
     pub bg_next_attr_byte: u8,
@@ -131,6 +134,7 @@ pub struct PpuState {
     pub prev_render_enabled: bool,
     pub oam_addr_disable_glitch_pending: bool,
     pub corrupt_oam_row: [bool; 32],
+    pub oam_row_access: [u64; 32],
     pub state_update_pending: bool,
     pub output_grayscale: bool,
     pub output_emphasis: u8,
@@ -138,6 +142,21 @@ pub struct PpuState {
     pub raw_output_indices: Vec<u8>,
 }
 
+/// Pixels of the frame the PPU is drawing when the snapshot is taken.
+///
+/// Lets a state captured mid-frame finish that frame exactly as the original
+/// run would, including what light guns sense. Only the drawn prefix of the
+/// back planes is stored (the rest is always blank), so this is empty for
+/// states taken at a frame boundary.
+#[cfg_attr(feature = "savestate-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartialFrameState {
+    /// Palette indices, one byte per pixel in raster order.
+    pub index: Vec<u8>,
+    /// Emphasis bits (`0..=7`), same length as `index`.
+    pub emphasis: Vec<u8>,
+}
+
 /// Save-state snapshot of the cartridge and mapper (ROM data not included).
 #[cfg_attr(feature = "savestate-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -166,16 +185,20 @@ pub enum MapperState {
 ///
 /// Notes:
 /// - This does not include the ROM image. Callers must ensure the same ROM is loaded.
-/// - PPU framebuffer configuration/planes are preserved; only PPU internal state is restored.
+/// - PPU framebuffer configuration and the last presented frame are preserved; the frame
+///   being drawn is restored from [`PartialFrameState`].
 #[cfg_attr(feature = "savestate-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct NesState {
     pub cpu: CpuState,
     pub ppu: PpuState,
+    pub partial_frame: PartialFrameState,
     pub apu: Apu,
     pub ram: Vec<u8>,
     pub cartridge: CartridgeState,
     pub controllers: ControllerPorts,
+    /// Four-player adapter shift registers, when one is connected.
+    pub multitap: Option<Multitap>,
     pub last_frame: u32,
     pub dot_counter: u64,
     pub master_clock: u64,
@@ -202,13 +225,100 @@ impl Nes {
     pub fn load_snapshot(&mut self, snapshot: &NesSnapshot) -> Result<(), NesSaveStateError> {
         <Self as SaveState>::load(self, snapshot)
     }
+
+    /// 64-bit FNV-1a hash of the emulated machine state, for checking that two
+    /// runs (or two netplay peers) stayed in sync.
+    ///
+    /// Hashes the postcard encoding of every component a save state restores
+    /// except the audio mixer, whose output depends on host volume settings.
+    /// PRG/CHR ROM is never hashed. Host configuration such as the palette or
+    /// APU pop reduction does not affect the result, and the value is stable
+    /// across platforms and builds of the same version, but not across
+    /// versions. For mappers without save state support only the mapper id,
+    /// IRQ line and cartridge RAM are hashed; their bank registers show up
+    /// through the CPU and PPU state once they matter.
+    #[cfg(feature = "savestate-postcard")]
+    pub fn state_hash(&self) -> u64 {
+        let mut hasher = StateHasher::new();
+        hasher.update(&cpu_to_state(&self.cpu));
+        hasher.update(&ppu_to_state(&self.ppu));
+        hasher.update(&partial_frame_to_state(&self.ppu));
+        hasher.update(&self.apu);
+        hasher.update(&self.ram.as_slice());
+        if let Some(cart) = self.cartridge.as_ref() {
+            match cartridge_to_state(cart) {
+                Ok(state) => hasher.update(&state),
+                Err(_) => {
+                    let mapper = cart.mapper();
+                    let memory = mapper.memory_ref();
+                    hasher.update(&(
+                        mapper.mapper_id(),
+                        mapper.irq_pending(),
+                        memory.prg_ram,
+                        memory.prg_work_ram,
+                        memory.chr_ram,
+                        memory.chr_battery_ram,
+                        memory.mapper_ram,
+                    ));
+                }
+            }
+        }
+        hasher.update(&self.controllers);
+        hasher.update(&self.multitap);
+        hasher.update(&(
+            self.last_frame,
+            self.dot_counter,
+            self.master_clock,
+            self.ppu_offset,
+            self.clock_start_count,
+            self.clock_end_count,
+            self.pending_dma,
+            OpenBusState::from_open_bus(self.open_bus),
+            self.cycles,
+        ));
+        hasher.0
+    }
+}
+
+/// FNV-1a over the bytes written to it.
+#[cfg(feature = "savestate-postcard")]
+struct StateHasher(u64);
+
+#[cfg(feature = "savestate-postcard")]
+impl StateHasher {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn update<T: Serialize + ?Sized>(&mut self, value: &T) {
+        // Writing into `StateHasher` cannot fail, and every component state
+        // is plain data.
+        let _ = postcard::to_io(value, self);
+    }
+}
+
+#[cfg(feature = "savestate-postcard")]
+impl std::io::Write for StateHasher {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        for &byte in buf {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
 }
 
 impl SaveState for Nes {
     type State = NesState;
     type Error = NesSaveStateError;
     type Meta = SnapshotMeta;
-    const FORMAT_VERSION: u32 = 3;
+    /// 9: chunked byte layout, see [`super::format`].
+    /// 10: partially drawn frame for mid-frame snapshots.
+    /// 11: multitap state in the controller chunk.
+    const FORMAT_VERSION: u32 = 11;
 
     fn save(&self, mut meta: Self::Meta) -> Result<Snapshot<Self::State, Self::Meta>, Self::Error> {
         if let Some(cart) = self.cartridge.as_ref() {
@@ -216,10 +326,12 @@ impl SaveState for Nes {
             let state = NesState {
                 cpu: cpu_to_state(&self.cpu),
                 ppu: ppu_to_state(&self.ppu),
+                partial_frame: partial_frame_to_state(&self.ppu),
                 apu: self.apu.clone(),
                 ram: self.ram.as_slice().to_vec(),
                 cartridge: cartridge_to_state(cart)?,
                 controllers: self.controllers.clone(),
+                multitap: self.multitap,
                 last_frame: self.last_frame,
                 dot_counter: self.dot_counter,
                 master_clock: self.master_clock,
@@ -256,7 +368,14 @@ impl SaveState for Nes {
 
         state_to_cpu(&mut self.cpu, &state.cpu);
         state_to_ppu(&mut self.ppu, &state.ppu)?;
+        state_to_partial_frame(&mut self.ppu, &state.partial_frame)?;
+        // Pop-reduction options are host configuration, and the APU region
+        // follows the inserted cartridge; neither comes from the snapshot.
+        let pop_reduction = self.apu.pop_reduction();
+        let region = self.apu.region();
         self.apu = state.apu.clone();
+        self.apu.set_pop_reduction(pop_reduction);
+        self.apu.set_region(region);
         if self.ram.as_slice().len() != state.ram.len() {
             return Err(NesSaveStateError::CorruptState("ram size mismatch"));
         }
@@ -267,6 +386,7 @@ impl SaveState for Nes {
         }
 
         self.controllers = state.controllers.clone();
+        self.multitap = state.multitap;
         self.last_frame = state.last_frame;
         self.dot_counter = state.dot_counter;
         self.master_clock = state.master_clock;
@@ -305,6 +425,8 @@ fn ppu_to_state(ppu: &Ppu) -> PpuState {
         scanline: ppu.scanline,
         frame: ppu.frame,
         master_clock: ppu.master_clock,
+        overclock_line: ppu.overclock_line,
+        in_overclock_scanline: ppu.in_overclock_scanline,
         bg_pipeline: ppu.bg_pipeline.save_state(),
         bg_next_tile_index: ppu.bg_next_tile_index,
         bg_next_attr_byte: ppu.bg_next_attr_byte,
@@ -343,6 +465,7 @@ fn ppu_to_state(ppu: &Ppu) -> PpuState {
         prev_render_enabled: ppu.prev_render_enabled,
         oam_addr_disable_glitch_pending: ppu.oam_addr_disable_glitch_pending,
         corrupt_oam_row: ppu.corrupt_oam_row,
+        oam_row_access: ppu.oam_row_access,
         state_update_pending: ppu.state_update_pending,
         output_grayscale: ppu.output_grayscale,
         output_emphasis: ppu.output_emphasis,
@@ -386,6 +509,8 @@ fn state_to_ppu(ppu: &mut Ppu, state: &PpuState) -> Result<(), NesSaveStateError
     ppu.scanline = state.scanline;
     ppu.frame = state.frame;
     ppu.master_clock = state.master_clock;
+    ppu.overclock_line = state.overclock_line;
+    ppu.in_overclock_scanline = state.in_overclock_scanline;
     ppu.bg_pipeline.load_state(state.bg_pipeline);
     ppu.bg_next_tile_index = state.bg_next_tile_index;
     ppu.bg_next_attr_byte = state.bg_next_attr_byte;
@@ -394,6 +519,8 @@ fn state_to_ppu(ppu: &mut Ppu, state: &PpuState) -> Result<(), NesSaveStateError
     ppu.bg_next_tile_addr = state.bg_next_tile_addr;
     ppu.sprite_pipeline
         .load_state(state.sprite_pipeline.clone());
+    // Display-only, refetched at the end of the next line.
+    ppu.extra_sprites.clear();
     ppu.nmi_level = state.nmi_level;
     ppu.prevent_vblank_flag = state.prevent_vblank_flag;
     ppu.open_bus.load_state(state.open_bus);
@@ -434,6 +561,7 @@ fn state_to_ppu(ppu: &mut Ppu, state: &PpuState) -> Result<(), NesSaveStateError
     ppu.prev_render_enabled = state.prev_render_enabled;
     ppu.oam_addr_disable_glitch_pending = state.oam_addr_disable_glitch_pending;
     ppu.corrupt_oam_row = state.corrupt_oam_row;
+    ppu.oam_row_access = state.oam_row_access;
     ppu.state_update_pending = state.state_update_pending;
     ppu.output_grayscale = state.output_grayscale;
     ppu.output_emphasis = state.output_emphasis;
@@ -449,6 +577,35 @@ fn state_to_ppu(ppu: &mut Ppu, state: &PpuState) -> Result<(), NesSaveStateError
     Ok(())
 }
 
+fn partial_frame_to_state(ppu: &Ppu) -> PartialFrameState {
+    let (index, emphasis) = ppu.framebuffer.back_planes();
+    // Undrawn pixels are cleared when the previous frame is presented, so
+    // everything after the last non-zero pixel can be left out.
+    let drawn = index
+        .iter()
+        .zip(emphasis)
+        .rposition(|(&index, &emphasis)| index != 0 || emphasis != 0)
+        .map_or(0, |last| last + 1);
+    PartialFrameState {
+        index: index[..drawn].to_vec(),
+        emphasis: emphasis[..drawn].to_vec(),
+    }
+}
+
+fn state_to_partial_frame(
+    ppu: &mut Ppu,
+    state: &PartialFrameState,
+) -> Result<(), NesSaveStateError> {
+    if state.index.len() != state.emphasis.len() || state.index.len() > SCREEN_SIZE {
+        return Err(NesSaveStateError::CorruptState(
+            "partial frame size mismatch",
+        ));
+    }
+    ppu.framebuffer
+        .restore_back_planes(&state.index, &state.emphasis);
+    Ok(())
+}
+
 fn sprite_line_buffers_to_state(buffers: &SpriteLineBuffers) -> SpriteLineBuffersState {
     let mut state = SpriteLineBuffersState::default();
     state.y.copy_from_slice(buffers.y.as_slice());
@@ -508,17 +665,6 @@ impl NesState {
     }
 }
 
-#[cfg(feature = "savestate-postcard")]
-impl Snapshot<NesState, SnapshotMeta> {
-    pub fn to_postcard_bytes(&self) -> Result<Vec<u8>, postcard::Error> {
-        postcard::to_stdvec(self)
-    }
-
-    pub fn from_postcard_bytes(bytes: &[u8]) -> Result<Self, postcard::Error> {
-        postcard::from_bytes(bytes)
-    }
-}
-
 #[cfg(all(test, feature = "savestate-postcard"))]
 mod tests {
     use super::*;
@@ -538,6 +684,77 @@ mod tests {
         rom
     }
 
+    /// NROM image that leaves rendering off with emphasis set and keeps
+    /// writing a counter through $2007 into palette RAM, so the forced-blank
+    /// backdrop changes while the frame is drawn.
+    fn palette_cycling_rom() -> Vec<u8> {
+        let mut rom = dummy_nrom_rom();
+        let prg = &mut rom[16..16 + 16 * 1024];
+        prg[..24].copy_from_slice(&[
+            0xA9, 0xE0, 0x8D, 0x01, 0x20, // LDA #$E0; STA $2001
+            0xA9, 0x3F, 0x8D, 0x06, 0x20, // LDA #$3F; STA $2006
+            0xA9, 0x00, 0x8D, 0x06, 0x20, // LDA #$00; STA $2006
+            0xE6, 0x00, 0xA5, 0x00, // loop: INC $00; LDA $00
+            0x8D, 0x07, 0x20, // STA $2007
+            0x4C, 0x0F, // JMP loop
+        ]);
+        prg[24] = 0x80;
+        prg[0x3FFC] = 0x00;
+        prg[0x3FFD] = 0x80;
+        rom
+    }
+
+    #[test]
+    fn savestate_mid_frame_finishes_the_same_frame() {
+        let cart = cartridge::load_cartridge(palette_cycling_rom()).expect("load cartridge");
+
+        let mut nes = crate::Nes::new(ColorFormat::Rgb555);
+        nes.insert_cartridge(cart.clone());
+        nes.run_frame(false);
+        nes.run_frame(false);
+        for _ in 0..15_000 {
+            nes.step_cpu_cycle(false);
+        }
+        assert!((0..240).contains(&nes.ppu.scanline), "not mid-frame");
+
+        let snap = nes
+            .save_snapshot(SnapshotMeta::default())
+            .expect("save snapshot");
+        assert!(!snap.data.partial_frame.index.is_empty());
+        let bytes = snap.to_postcard_bytes().expect("encode snapshot");
+        nes.run_frame(false);
+
+        let mut nes2 = crate::Nes::new(ColorFormat::Rgb555);
+        nes2.insert_cartridge(cart);
+        nes2.run_frame(false);
+        nes2.run_frame(false);
+        nes2.run_frame(false);
+        let decoded = NesSnapshot::from_postcard_bytes(&bytes).expect("decode");
+        nes2.load_snapshot(&decoded).expect("load snapshot");
+        nes2.run_frame(false);
+
+        assert_eq!(nes2.frame_crc(), nes.frame_crc());
+        assert_eq!(nes2.state_hash(), nes.state_hash());
+    }
+
+    #[test]
+    fn state_hash_leaves_the_rom_out() {
+        let mut rom = dummy_nrom_rom();
+        let mut nes = crate::Nes::new(ColorFormat::Rgb555);
+        nes.insert_cartridge(cartridge::load_cartridge(rom.clone()).expect("load cartridge"));
+        nes.run_frame(false);
+
+        // Rendering stays off, so this CHR byte is never fetched.
+        *rom.last_mut().unwrap() = 0xFF;
+        let mut other = crate::Nes::new(ColorFormat::Rgb555);
+        other.insert_cartridge(cartridge::load_cartridge(rom).expect("load cartridge"));
+        other.run_frame(false);
+        assert_eq!(other.state_hash(), nes.state_hash());
+
+        other.run_frame(false);
+        assert_ne!(other.state_hash(), nes.state_hash());
+    }
+
     #[test]
     fn savestate_postcard_roundtrip() {
         let cart = cartridge::load_cartridge(dummy_nrom_rom()).expect("load dummy cartridge");
@@ -680,6 +897,8 @@ fn state_to_cpu(cpu: &mut Cpu, state: &CpuState) {
     cpu.prev_nmi_level = state.prev_nmi_level;
     cpu.nmi_latch = state.nmi_latch;
     cpu.prev_nmi_latch = state.prev_nmi_latch;
+    // Not saved: a jammed CPU re-executes its JAM within two cycles.
+    cpu.jam = None;
     cpu.dma.halt_needed = state.dma.halt_needed;
     cpu.dma.dummy_read_needed = state.dma.dummy_read_needed;
     cpu.dma.dmc_active = state.dma.dmc_active;
@@ -0,0 +1,512 @@
+//! Execution trace capture and A/B comparison against reference traces.
+//!
+//! Accuracy work on this core is mostly done by diffing against Mesen2. This
+//! module parses Mesen2/nestest-style CPU trace logs, steps the core one
+//! instruction at a time alongside them, and reports the first line where the
+//! two disagree together with the preceding lines for context.
+//!
+//! The parser is intentionally lenient: it only needs the leading program
+//! counter and whichever `KEY:value` register fields are present, so traces
+//! produced with custom Mesen2 format strings still work. Fields missing from
+//! the reference are simply not compared.
+//!
+//! The core's side of the comparison is produced by the same [`TraceRow`] /
+//! [`TraceFormat`] machinery as the standalone [`TraceLogger`], so divergence
+//! reports and optional logs read like the reference.
+
+use std::{collections::VecDeque, fmt, io};
+
+use crate::Nes;
+
+mod logger;
+
+pub use logger::{
+    DisassemblyLine, MESEN_TRACE_FORMAT, TraceFormat, TraceFormatError, TraceLogger, TraceRow,
+    TraceSink, WriterSink, disassemble_around,
+};
+
+/// Status bits that differ between trace conventions (B and the unused bit).
+const STATUS_COMPARE_MASK: u8 = 0b1100_1111;
+
+/// CPU/PPU state captured at an instruction boundary (before the opcode fetch).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TraceState {
+    pub pc: u16,
+    pub a: u8,
+    pub x: u8,
+    pub y: u8,
+    pub s: u8,
+    pub p: u8,
+    pub scanline: i16,
+    pub dot: u16,
+    pub frame: u32,
+    pub cycle: u64,
+}
+
+impl TraceState {
+    /// Captures the current CPU registers and PPU position.
+    pub fn capture(nes: &Nes) -> Self {
+        let cpu = nes.cpu_snapshot();
+        Self {
+            pc: cpu.pc,
+            a: cpu.a,
+            x: cpu.x,
+            y: cpu.y,
+            s: cpu.s,
+            p: cpu.p,
+            scanline: nes.ppu.scanline,
+            dot: nes.ppu.cycle,
+            frame: nes.ppu.frame,
+            cycle: nes.cpu_cycles(),
+        }
+    }
+}
+
+impl fmt::Display for TraceState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04X}  A:{:02X} X:{:02X} Y:{:02X} S:{:02X} P:{:02X} V:{:<3} H:{:<3} Fr:{} Cyc:{}",
+            self.pc,
+            self.a,
+            self.x,
+            self.y,
+            self.s,
+            self.p,
+            self.scanline,
+            self.dot,
+            self.frame,
+            self.cycle
+        )
+    }
+}
+
+/// One parsed line of a reference trace. Absent fields are not compared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceLine {
+    /// 1-based line number within the source file.
+    pub line_no: usize,
+    pub pc: u16,
+    pub a: Option<u8>,
+    pub x: Option<u8>,
+    pub y: Option<u8>,
+    pub s: Option<u8>,
+    pub p: Option<u8>,
+    pub scanline: Option<i16>,
+    pub dot: Option<u16>,
+    pub frame: Option<u32>,
+    pub cycle: Option<u64>,
+    /// The original text, kept for divergence reports.
+    pub raw: String,
+}
+
+impl ReferenceLine {
+    /// Parses a Mesen2 or nestest-style trace line.
+    ///
+    /// Returns `None` for blank lines, comments, and lines that do not start
+    /// with a 4-digit hex program counter.
+    pub fn parse(line_no: usize, line: &str) -> Option<Self> {
+        let trimmed = line.trim();
+        let pc_text = trimmed.get(..4)?;
+        if trimmed.len() > 4 && !trimmed[4..].starts_with(char::is_whitespace) {
+            return None;
+        }
+        let pc = u16::from_str_radix(pc_text, 16).ok()?;
+
+        let mut parsed = Self {
+            line_no,
+            pc,
+            a: None,
+            x: None,
+            y: None,
+            s: None,
+            p: None,
+            scanline: None,
+            dot: None,
+            frame: None,
+            cycle: None,
+            raw: trimmed.to_string(),
+        };
+
+        // nestest-style `PPU:  0, 21` (scanline, dot) spans two tokens.
+        if let Some(idx) = trimmed.find("PPU:") {
+            let mut parts = trimmed[idx + 4..].split(',');
+            parsed.scanline = parts.next().and_then(|v| v.trim().parse().ok());
+            parsed.dot = parts
+                .next()
+                .and_then(|v| v.split_whitespace().next())
+                .and_then(|v| v.parse().ok());
+        }
+
+        for token in trimmed.split_whitespace() {
+            let Some((key, value)) = token.split_once(':') else {
+                continue;
+            };
+            if value.is_empty() {
+                continue;
+            }
+            match key {
+                "A" => parsed.a = u8::from_str_radix(value, 16).ok(),
+                "X" => parsed.x = u8::from_str_radix(value, 16).ok(),
+                "Y" => parsed.y = u8::from_str_radix(value, 16).ok(),
+                "S" | "SP" => parsed.s = u8::from_str_radix(value, 16).ok(),
+                "P" => parsed.p = parse_status(value),
+                "V" | "SL" => parsed.scanline = value.parse().ok(),
+                "H" => parsed.dot = value.parse().ok(),
+                "Fr" | "FC" => parsed.frame = value.parse().ok(),
+                "Cyc" | "CYC" => parsed.cycle = value.parse().ok(),
+                _ => {}
+            }
+        }
+
+        Some(parsed)
+    }
+
+    /// Names of the fields that disagree with `actual`.
+    pub fn mismatches(&self, actual: &TraceState) -> Vec<&'static str> {
+        let mut fields = Vec::new();
+        if self.pc != actual.pc {
+            fields.push("PC");
+        }
+        let mut check = |name, differs: Option<bool>| {
+            if differs == Some(true) {
+                fields.push(name);
+            }
+        };
+        check("A", self.a.map(|v| v != actual.a));
+        check("X", self.x.map(|v| v != actual.x));
+        check("Y", self.y.map(|v| v != actual.y));
+        check("S", self.s.map(|v| v != actual.s));
+        check(
+            "P",
+            self.p
+                .map(|v| v & STATUS_COMPARE_MASK != actual.p & STATUS_COMPARE_MASK),
+        );
+        check("V", self.scanline.map(|v| v != actual.scanline));
+        check("H", self.dot.map(|v| v != actual.dot));
+        check("Fr", self.frame.map(|v| v != actual.frame));
+        check("Cyc", self.cycle.map(|v| v != actual.cycle));
+        fields
+    }
+}
+
+/// Parses `P:` either as two hex digits or as an `NV-BDIZC` flag string where
+/// uppercase letters mean "set" (Mesen2's `[P,8]` format).
+fn parse_status(value: &str) -> Option<u8> {
+    if value.len() == 8 {
+        let mut bits = 0u8;
+        for (idx, ch) in value.chars().enumerate() {
+            if ch.is_ascii_uppercase() {
+                bits |= 0x80 >> idx;
+            }
+        }
+        return Some(bits);
+    }
+    u8::from_str_radix(value, 16).ok()
+}
+
+/// First point where the core disagreed with the reference trace.
+#[derive(Debug, Clone)]
+pub struct TraceDivergence {
+    /// Number of instructions that matched before the divergence.
+    pub instruction_index: usize,
+    pub expected: ReferenceLine,
+    pub actual: TraceRow,
+    /// `actual` rendered with the comparer's [`TraceFormat`].
+    pub actual_line: String,
+    pub fields: Vec<&'static str>,
+    /// Preceding matched instructions (oldest first), with the core's line.
+    pub context: Vec<(ReferenceLine, String)>,
+}
+
+impl fmt::Display for TraceDivergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "divergence at instruction {} (reference line {}): {}",
+            self.instruction_index,
+            self.expected.line_no,
+            self.fields.join(", ")
+        )?;
+        for (reference, actual) in &self.context {
+            writeln!(f, "  ref {:>7}: {}", reference.line_no, reference.raw)?;
+            writeln!(f, "  nes        : {actual}")?;
+        }
+        writeln!(
+            f,
+            "> ref {:>7}: {}",
+            self.expected.line_no, self.expected.raw
+        )?;
+        write!(f, "> nes        : {}", self.actual_line)
+    }
+}
+
+/// Outcome of a completed comparison run.
+#[derive(Debug, Clone)]
+pub enum TraceCompareOutcome {
+    /// Every reference line matched.
+    Matched {
+        instructions: usize,
+    },
+    Diverged(Box<TraceDivergence>),
+}
+
+/// Steps a [`Nes`] in lockstep with a reference trace.
+pub struct TraceComparer {
+    context_len: usize,
+    history: VecDeque<(ReferenceLine, String)>,
+    compared: usize,
+    format: TraceFormat,
+    /// Optional copy of the core's trace, written as it runs.
+    log: Option<TraceLogger<Box<dyn TraceSink>>>,
+    log_error: Option<io::Error>,
+}
+
+impl fmt::Debug for TraceComparer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TraceComparer")
+            .field("context_len", &self.context_len)
+            .field("compared", &self.compared)
+            .field("format", &self.format)
+            .field("log", &self.log.is_some())
+            .finish()
+    }
+}
+
+impl TraceComparer {
+    /// Creates a comparer that keeps `context_len` matched lines for reports.
+    pub fn new(context_len: usize) -> Self {
+        Self {
+            context_len,
+            history: VecDeque::with_capacity(context_len),
+            compared: 0,
+            format: TraceFormat::default(),
+            log: None,
+            log_error: None,
+        }
+    }
+
+    /// Format used for the core's lines in reports and logs.
+    pub fn with_format(mut self, format: TraceFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Also writes every compared instruction to `sink`.
+    pub fn with_log(mut self, sink: Box<dyn TraceSink>) -> Self {
+        self.log = Some(TraceLogger::new(self.format.clone(), sink));
+        self
+    }
+
+    /// First error returned by the log sink; logging stops after it.
+    pub fn take_log_error(&mut self) -> Option<io::Error> {
+        self.log_error.take()
+    }
+
+    /// Number of instructions compared so far.
+    pub fn compared(&self) -> usize {
+        self.compared
+    }
+
+    /// Compares the current state of `nes` against `expected`, then executes
+    /// one instruction so the next call lines up with the next reference line.
+    pub fn compare_step(
+        &mut self,
+        nes: &mut Nes,
+        expected: ReferenceLine,
+    ) -> Result<(), Box<TraceDivergence>> {
+        let actual = TraceRow::capture(nes);
+        if let Some(log) = self.log.as_mut()
+            && let Err(err) = log.write(&actual)
+        {
+            self.log = None;
+            self.log_error = Some(err);
+        }
+
+        let fields = expected.mismatches(&actual.state);
+        if !fields.is_empty() {
+            return Err(Box::new(TraceDivergence {
+                instruction_index: self.compared,
+                expected,
+                actual_line: self.format.render(&actual),
+                actual,
+                fields,
+                context: self.history.iter().cloned().collect(),
+            }));
+        }
+
+        if self.context_len > 0 {
+            if self.history.len() == self.context_len {
+                self.history.pop_front();
+            }
+            self.history
+                .push_back((expected, self.format.render(&actual)));
+        }
+        self.compared += 1;
+        nes.step_to_instruction_boundary();
+        Ok(())
+    }
+
+    /// Runs the whole reference trace, stopping at the first divergence.
+    pub fn run<I>(&mut self, nes: &mut Nes, reference: I) -> TraceCompareOutcome
+    where
+        I: IntoIterator<Item = ReferenceLine>,
+    {
+        for expected in reference {
+            if let Err(divergence) = self.compare_step(nes, expected) {
+                return TraceCompareOutcome::Diverged(divergence);
+            }
+        }
+        TraceCompareOutcome::Matched {
+            instructions: self.compared,
+        }
+    }
+}
+
+/// Parses every trace line in `text`, skipping lines that are not instructions.
+pub fn parse_reference_trace(text: &str) -> Vec<ReferenceLine> {
+    text.lines()
+        .enumerate()
+        .filter_map(|(idx, line)| ReferenceLine::parse(idx + 1, line))
+        .collect()
+}
+
+/// First audio sample that differs from a reference recording.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AudioDivergence {
+    /// Index into the interleaved sample stream.
+    pub sample_index: usize,
+    /// Frame during which the sample was produced.
+    pub frame: u64,
+    pub expected: i16,
+    pub actual: i16,
+}
+
+impl fmt::Display for AudioDivergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "audio divergence at sample {} (frame {}): expected {}, got {}",
+            self.sample_index, self.frame, self.expected, self.actual
+        )
+    }
+}
+
+/// Compares produced PCM against a reference 16-bit recording.
+#[derive(Debug)]
+pub struct AudioComparer<'a> {
+    reference: &'a [i16],
+    position: usize,
+    tolerance: u16,
+}
+
+impl<'a> AudioComparer<'a> {
+    /// `tolerance` is the maximum allowed absolute difference per sample.
+    pub fn new(reference: &'a [i16], tolerance: u16) -> Self {
+        Self {
+            reference,
+            position: 0,
+            tolerance,
+        }
+    }
+
+    /// `true` once every reference sample has been compared.
+    pub fn finished(&self) -> bool {
+        self.position >= self.reference.len()
+    }
+
+    /// Samples compared so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Compares one frame of interleaved `f32` output (as returned by
+    /// [`Nes::run_frame`]) against the next slice of the reference.
+    pub fn compare_frame(&mut self, frame: u64, samples: &[f32]) -> Result<(), AudioDivergence> {
+        for &sample in samples {
+            let Some(&expected) = self.reference.get(self.position) else {
+                return Ok(());
+            };
+            let actual = (sample * 32_768.0).clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+            if expected.abs_diff(actual) > self.tolerance {
+                return Err(AudioDivergence {
+                    sample_index: self.position,
+                    frame,
+                    expected,
+                    actual,
+                });
+            }
+            self.position += 1;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_mesen_trace_line() {
+        let line =
+            "C000  4C F5 C5  JMP $C5F5   A:00 X:00 Y:00 S:FD P:nvUbdIzc V:0   H:21  Fr:0 Cyc:7";
+        let parsed = ReferenceLine::parse(1, line).expect("trace line");
+        assert_eq!(parsed.pc, 0xC000);
+        assert_eq!(parsed.s, Some(0xFD));
+        assert_eq!(parsed.p, Some(0x24));
+        assert_eq!(parsed.scanline, Some(0));
+        assert_eq!(parsed.dot, Some(21));
+        assert_eq!(parsed.frame, Some(0));
+        assert_eq!(parsed.cycle, Some(7));
+    }
+
+    #[test]
+    fn parses_nestest_trace_line() {
+        let line = "C72A  D0 E0     BNE $C70C                       A:00 X:00 Y:00 P:26 SP:FB PPU:  3, 89 CYC:317";
+        let parsed = ReferenceLine::parse(1, line).expect("trace line");
+        assert_eq!(parsed.pc, 0xC72A);
+        assert_eq!(parsed.p, Some(0x26));
+        assert_eq!(parsed.s, Some(0xFB));
+        assert_eq!(parsed.scanline, Some(3));
+        assert_eq!(parsed.dot, Some(89));
+        assert_eq!(parsed.cycle, Some(317));
+    }
+
+    #[test]
+    fn skips_non_instruction_lines() {
+        assert!(ReferenceLine::parse(1, "").is_none());
+        assert!(ReferenceLine::parse(1, "-- NMI --").is_none());
+        assert!(ReferenceLine::parse(1, "C000X").is_none());
+    }
+
+    #[test]
+    fn mismatches_ignore_break_and_unused_bits() {
+        let parsed = ReferenceLine::parse(1, "8000 A:01 P:34").expect("trace line");
+        let actual = TraceState {
+            pc: 0x8000,
+            a: 0x02,
+            x: 0,
+            y: 0,
+            s: 0xFD,
+            p: 0x04,
+            scanline: 0,
+            dot: 0,
+            frame: 0,
+            cycle: 0,
+        };
+        assert_eq!(parsed.mismatches(&actual), vec!["A"]);
+    }
+
+    #[test]
+    fn audio_comparer_reports_first_out_of_tolerance_sample() {
+        let reference = [0i16, 100, 200, 300];
+        let mut comparer = AudioComparer::new(&reference, 2);
+        let samples = [0.0, 101.0 / 32_768.0, 250.0 / 32_768.0, 0.0];
+        let err = comparer
+            .compare_frame(3, &samples)
+            .expect_err("sample 2 diverges");
+        assert_eq!(err.sample_index, 2);
+        assert_eq!(err.frame, 3);
+        assert_eq!(err.expected, 200);
+    }
+}
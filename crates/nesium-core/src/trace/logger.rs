@@ -0,0 +1,475 @@
+//! Mesen2-compatible execution trace logging.
+//!
+//! A [`TraceFormat`] is parsed from a Mesen2 trace-logger format string
+//! (`[PC,4]  [ByteCode,10][Disassembly,11] A:[A,2h] ...`) and renders one
+//! [`TraceRow`] per instruction. [`TraceLogger`] captures rows at instruction
+//! boundaries and streams them to a [`TraceSink`], so traces can be written to
+//! a file, kept in memory, or diffed on the fly.
+
+use std::{fmt, io};
+
+use super::TraceState;
+use crate::{
+    Nes,
+    cpu::{addressing::Addressing, opcode_meta},
+};
+
+/// Mesen2's default NES trace layout (minus the effective address column).
+pub const MESEN_TRACE_FORMAT: &str = "[PC,4]  [ByteCode,10][Disassembly,11] A:[A,2h] X:[X,2h] Y:[Y,2h] S:[SP,2h] P:[P,8] V:[Scanline,3] H:[Cycle,3] Fr:[FrameCount] Cyc:[CycleCount]";
+
+/// One instruction about to execute: register/PPU state plus its bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TraceRow {
+    pub state: TraceState,
+    bytes: [u8; 3],
+    len: u8,
+}
+
+impl TraceRow {
+    /// Captures the instruction at the current program counter. Call at an
+    /// instruction boundary (see [`Nes::step_to_instruction_boundary`]).
+    pub fn capture(nes: &mut Nes) -> Self {
+        let state = TraceState::capture(nes);
+        let opcode = nes.peek_cpu_byte(state.pc);
+        let len = 1 + opcode_meta(opcode).addressing.operand_len();
+        let mut bytes = [opcode, 0, 0];
+        nes.peek_cpu_slice(state.pc.wrapping_add(1), &mut bytes[1..len]);
+        Self {
+            state,
+            bytes,
+            len: len as u8,
+        }
+    }
+
+    /// Opcode followed by its operand bytes.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+
+    /// Instruction text in Mesen2/nestest style, e.g. `LDA ($44),Y`.
+    pub fn disassembly(&self) -> String {
+        format_instruction(self.state.pc, self.bytes)
+    }
+}
+
+/// One instruction decoded from CPU memory by [`disassemble_around`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DisassemblyLine {
+    pub addr: u16,
+    bytes: [u8; 3],
+    len: u8,
+}
+
+impl DisassemblyLine {
+    fn read(nes: &mut Nes, addr: u16) -> Self {
+        let opcode = nes.peek_cpu_byte(addr);
+        let len = 1 + opcode_meta(opcode).addressing.operand_len();
+        let mut bytes = [opcode, 0, 0];
+        nes.peek_cpu_slice(addr.wrapping_add(1), &mut bytes[1..len]);
+        Self {
+            addr,
+            bytes,
+            len: len as u8,
+        }
+    }
+
+    /// Opcode followed by its operand bytes.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+
+    /// Instruction text, as in [`TraceRow::disassembly`].
+    pub fn text(&self) -> String {
+        format_instruction(self.addr, self.bytes)
+    }
+}
+
+impl fmt::Display for DisassemblyLine {
+    /// `$8000  A9 05     LDA #$05`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes: Vec<_> = self.bytes().iter().map(|b| format!("{b:02X}")).collect();
+        write!(
+            f,
+            "${:04X}  {:<10}{}",
+            self.addr,
+            bytes.join(" "),
+            self.text()
+        )
+    }
+}
+
+/// Disassembles the instruction at `pc` with up to `before` instructions
+/// leading into it and `after` instructions following it.
+///
+/// 6502 code can't be decoded backwards reliably, so the leading lines come
+/// from the farthest start address (at most three bytes per instruction back)
+/// whose decoding lands exactly on `pc`. Reads use
+/// [`Nes::peek_cpu_byte`] and have no side effects.
+pub fn disassemble_around(
+    nes: &mut Nes,
+    pc: u16,
+    before: usize,
+    after: usize,
+) -> Vec<DisassemblyLine> {
+    let mut lines = Vec::with_capacity(before + 1 + after);
+    for distance in (1..=before * 3).rev() {
+        let mut offset = 0;
+        let mut lead = Vec::new();
+        while offset < distance {
+            let line = DisassemblyLine::read(nes, pc.wrapping_sub((distance - offset) as u16));
+            offset += line.len as usize;
+            lead.push(line);
+        }
+        if offset == distance {
+            lines.extend_from_slice(&lead[lead.len().saturating_sub(before)..]);
+            break;
+        }
+    }
+
+    let mut addr = pc;
+    for _ in 0..=after {
+        let line = DisassemblyLine::read(nes, addr);
+        addr = addr.wrapping_add(u16::from(line.len));
+        lines.push(line);
+    }
+    lines
+}
+
+fn format_instruction(pc: u16, bytes: [u8; 3]) -> String {
+    let meta = opcode_meta(bytes[0]);
+    let lo = bytes[1];
+    let word = u16::from_le_bytes([lo, bytes[2]]);
+    let operand = match meta.addressing {
+        Addressing::Implied => return meta.mnemonic,
+        Addressing::Accumulator => "A".to_string(),
+        Addressing::Immediate => format!("#${lo:02X}"),
+        Addressing::ZeroPage => format!("${lo:02X}"),
+        Addressing::ZeroPageX => format!("${lo:02X},X"),
+        Addressing::ZeroPageY => format!("${lo:02X},Y"),
+        Addressing::Absolute => format!("${word:04X}"),
+        Addressing::AbsoluteX => format!("${word:04X},X"),
+        Addressing::AbsoluteY => format!("${word:04X},Y"),
+        Addressing::Indirect => format!("(${word:04X})"),
+        Addressing::IndirectX => format!("(${lo:02X},X)"),
+        Addressing::IndirectY => format!("(${lo:02X}),Y"),
+        Addressing::Relative => {
+            let target = pc.wrapping_add(2).wrapping_add(lo as i8 as u16);
+            format!("${target:04X}")
+        }
+    };
+    format!("{} {operand}", meta.mnemonic)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tag {
+    Pc,
+    ByteCode,
+    Disassembly,
+    A,
+    X,
+    Y,
+    Sp,
+    P,
+    Scanline,
+    Cycle,
+    FrameCount,
+    CycleCount,
+    Align,
+}
+
+impl Tag {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "PC" => Tag::Pc,
+            "ByteCode" => Tag::ByteCode,
+            "Disassembly" => Tag::Disassembly,
+            "A" => Tag::A,
+            "X" => Tag::X,
+            "Y" => Tag::Y,
+            "SP" => Tag::Sp,
+            "P" => Tag::P,
+            "Scanline" => Tag::Scanline,
+            "Cycle" => Tag::Cycle,
+            "FrameCount" => Tag::FrameCount,
+            "CycleCount" => Tag::CycleCount,
+            "Align" => Tag::Align,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Text(String),
+    Field { tag: Tag, width: Option<usize> },
+}
+
+/// Format string rejected by [`TraceFormat::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceFormatError {
+    UnknownTag(String),
+    Unterminated,
+}
+
+impl fmt::Display for TraceFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceFormatError::UnknownTag(tag) => write!(f, "unknown trace tag [{tag}]"),
+            TraceFormatError::Unterminated => write!(f, "unterminated '[' in trace format"),
+        }
+    }
+}
+
+impl std::error::Error for TraceFormatError {}
+
+/// Parsed Mesen2-style trace format string.
+///
+/// Supported tags: `PC`, `ByteCode`, `Disassembly`, `A`, `X`, `Y`, `SP`, `P`,
+/// `Scanline`, `Cycle`, `FrameCount`, `CycleCount` and `Align`. An optional
+/// `,N` sets the field width; registers are always hex, so a trailing `h` is
+/// accepted and ignored. `[P,8]` renders flags as `NV-BDIZC` letters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceFormat {
+    segments: Vec<Segment>,
+}
+
+impl TraceFormat {
+    pub fn parse(format: &str) -> Result<Self, TraceFormatError> {
+        let mut segments = Vec::new();
+        let mut rest = format;
+        while let Some(open) = rest.find('[') {
+            if open > 0 {
+                segments.push(Segment::Text(rest[..open].to_string()));
+            }
+            let close = rest[open..]
+                .find(']')
+                .ok_or(TraceFormatError::Unterminated)?
+                + open;
+            let body = &rest[open + 1..close];
+            let (name, width) = match body.split_once(',') {
+                Some((name, width)) => (name, width.trim_end_matches('h').parse().ok()),
+                None => (body, None),
+            };
+            let tag =
+                Tag::parse(name.trim()).ok_or_else(|| TraceFormatError::UnknownTag(body.into()))?;
+            segments.push(Segment::Field { tag, width });
+            rest = &rest[close + 1..];
+        }
+        if !rest.is_empty() {
+            segments.push(Segment::Text(rest.to_string()));
+        }
+        Ok(Self { segments })
+    }
+
+    /// Renders one row.
+    pub fn render(&self, row: &TraceRow) -> String {
+        let s = &row.state;
+        let mut out = String::with_capacity(96);
+        for segment in &self.segments {
+            let (tag, width) = match segment {
+                Segment::Text(text) => {
+                    out.push_str(text);
+                    continue;
+                }
+                Segment::Field { tag, width } => (*tag, *width),
+            };
+            let hex =
+                |value: u64, default: usize| format!("{value:0w$X}", w = width.unwrap_or(default));
+            let text = match tag {
+                Tag::Pc => hex(s.pc.into(), 4),
+                Tag::A => hex(s.a.into(), 2),
+                Tag::X => hex(s.x.into(), 2),
+                Tag::Y => hex(s.y.into(), 2),
+                Tag::Sp => hex(s.s.into(), 2),
+                Tag::P if width == Some(8) => status_flags(s.p),
+                Tag::P => hex(s.p.into(), 2),
+                Tag::ByteCode => row
+                    .bytes()
+                    .iter()
+                    .map(|b| format!("{b:02X}"))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                Tag::Disassembly => row.disassembly(),
+                Tag::Scanline => s.scanline.to_string(),
+                Tag::Cycle => s.dot.to_string(),
+                Tag::FrameCount => s.frame.to_string(),
+                Tag::CycleCount => s.cycle.to_string(),
+                Tag::Align => {
+                    let column = width.unwrap_or(0);
+                    while out.len() < column {
+                        out.push(' ');
+                    }
+                    continue;
+                }
+            };
+            out.push_str(&format!("{text:<w$}", w = width.unwrap_or(0)));
+        }
+        out
+    }
+}
+
+impl Default for TraceFormat {
+    fn default() -> Self {
+        Self::parse(MESEN_TRACE_FORMAT).expect("built-in trace format is valid")
+    }
+}
+
+/// `NV-BDIZC` with set flags uppercase, as in Mesen2's `[P,8]`.
+fn status_flags(p: u8) -> String {
+    b"NVUBDIZC"
+        .iter()
+        .enumerate()
+        .map(|(idx, &letter)| {
+            let letter = letter as char;
+            if p & (0x80 >> idx) != 0 {
+                letter
+            } else {
+                letter.to_ascii_lowercase()
+            }
+        })
+        .collect()
+}
+
+/// Destination for formatted trace rows.
+pub trait TraceSink {
+    fn write_row(&mut self, row: &str) -> io::Result<()>;
+}
+
+impl TraceSink for Vec<String> {
+    fn write_row(&mut self, row: &str) -> io::Result<()> {
+        self.push(row.to_string());
+        Ok(())
+    }
+}
+
+impl<T: TraceSink + ?Sized> TraceSink for Box<T> {
+    fn write_row(&mut self, row: &str) -> io::Result<()> {
+        (**self).write_row(row)
+    }
+}
+
+/// Writes one row per line to any [`io::Write`].
+#[derive(Debug)]
+pub struct WriterSink<W>(pub W);
+
+impl<W: io::Write> TraceSink for WriterSink<W> {
+    fn write_row(&mut self, row: &str) -> io::Result<()> {
+        writeln!(self.0, "{row}")
+    }
+}
+
+/// Captures and formats one row per executed instruction.
+#[derive(Debug)]
+pub struct TraceLogger<S> {
+    format: TraceFormat,
+    sink: S,
+    rows: u64,
+}
+
+impl<S: TraceSink> TraceLogger<S> {
+    pub fn new(format: TraceFormat, sink: S) -> Self {
+        Self {
+            format,
+            sink,
+            rows: 0,
+        }
+    }
+
+    pub fn format(&self) -> &TraceFormat {
+        &self.format
+    }
+
+    /// Rows written so far.
+    pub fn rows(&self) -> u64 {
+        self.rows
+    }
+
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    pub fn into_sink(self) -> S {
+        self.sink
+    }
+
+    /// Formats `row` and hands it to the sink.
+    pub fn write(&mut self, row: &TraceRow) -> io::Result<()> {
+        self.sink.write_row(&self.format.render(row))?;
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Logs the instruction at the current boundary and executes it.
+    pub fn step(&mut self, nes: &mut Nes) -> io::Result<TraceRow> {
+        let row = TraceRow::capture(nes);
+        self.write(&row)?;
+        nes.step_to_instruction_boundary();
+        Ok(row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(pc: u16, bytes: &[u8], p: u8) -> TraceRow {
+        let mut raw = [0; 3];
+        raw[..bytes.len()].copy_from_slice(bytes);
+        TraceRow {
+            state: TraceState {
+                pc,
+                a: 0,
+                x: 0,
+                y: 0,
+                s: 0xFD,
+                p,
+                scanline: 0,
+                dot: 21,
+                frame: 0,
+                cycle: 7,
+            },
+            bytes: raw,
+            len: bytes.len() as u8,
+        }
+    }
+
+    #[test]
+    fn default_format_matches_mesen_layout() {
+        let line = TraceFormat::default().render(&row(0xC000, &[0x4C, 0xF5, 0xC5], 0x24));
+        assert_eq!(
+            line,
+            "C000  4C F5 C5  JMP $C5F5   A:00 X:00 Y:00 S:FD P:nvUbdIzc V:0   H:21  Fr:0 Cyc:7"
+        );
+        let parsed = super::super::ReferenceLine::parse(1, &line).expect("round trip");
+        assert!(
+            parsed
+                .mismatches(&row(0xC000, &[0x4C], 0x24).state)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn disassembles_addressing_modes() {
+        assert_eq!(row(0xC72A, &[0xD0, 0xE0], 0).disassembly(), "BNE $C70C");
+        assert_eq!(row(0, &[0xB1, 0x44], 0).disassembly(), "LDA ($44),Y");
+        assert_eq!(row(0, &[0x0A], 0).disassembly(), "ASL A");
+        assert_eq!(row(0, &[0x18], 0).disassembly(), "CLC");
+    }
+
+    #[test]
+    fn custom_formats_and_errors() {
+        let format = TraceFormat::parse("[PC,4h] [A,2h][Align,12]P:[P,h]").expect("format");
+        assert_eq!(
+            format.render(&row(0x8000, &[0xEA], 0x34)),
+            "8000 00     P:34"
+        );
+        assert_eq!(
+            TraceFormat::parse("[Nope]"),
+            Err(TraceFormatError::UnknownTag("Nope".into()))
+        );
+        assert_eq!(
+            TraceFormat::parse("[PC"),
+            Err(TraceFormatError::Unterminated)
+        );
+    }
+}
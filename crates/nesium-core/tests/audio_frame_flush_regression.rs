@@ -1,6 +1,17 @@
-use nesium_core::{Nes, cartridge, ppu::buffer::ColorFormat};
+use nesium_core::{
+    Nes,
+    audio::{AudioChannel, MixerSettings, RecordSource},
+    cartridge,
+    config::overclock::Overclock,
+    ppu::buffer::ColorFormat,
+};
 
 fn dummy_loop_rom() -> Vec<u8> {
+    loop_rom(&[])
+}
+
+/// NROM image that runs `program` from `$8000` and then spins in place.
+fn loop_rom(program: &[u8]) -> Vec<u8> {
     let mut rom = Vec::with_capacity(16 + 16 * 1024 + 8 * 1024);
     rom.extend_from_slice(b"NES\x1A");
     rom.push(1); // 16 KiB PRG
@@ -10,10 +21,10 @@ fn dummy_loop_rom() -> Vec<u8> {
     rom.extend_from_slice(&[0; 8]);
 
     let mut prg = vec![0xEA; 16 * 1024];
-    // $8000: JMP $8000
-    prg[0] = 0x4C;
-    prg[1] = 0x00;
-    prg[2] = 0x80;
+    prg[..program.len()].copy_from_slice(program);
+    // JMP to itself after the program.
+    let spin = 0x8000 + program.len() as u16;
+    prg[program.len()..program.len() + 3].copy_from_slice(&[0x4C, spin as u8, (spin >> 8) as u8]);
     // Vectors
     prg[0x3FFA] = 0x00;
     prg[0x3FFB] = 0x80;
@@ -39,3 +50,94 @@ fn audio_frame_flush_regression_counts() {
     // frames and changes this sequence.
     assert_eq!(counts, vec![1464, 1598, 1598, 1596, 1598, 1598, 1596, 1598]);
 }
+
+#[test]
+fn overclock_adds_cpu_time_without_changing_audio_rate() {
+    let cart = cartridge::load_cartridge(dummy_loop_rom()).expect("load dummy cartridge");
+    let mut nes = Nes::new(ColorFormat::Rgb555);
+    nes.insert_cartridge(cart);
+    nes.set_overclock(Overclock {
+        extra_scanlines_before_nmi: 100,
+        extra_scanlines_after_nmi: 31,
+    });
+
+    nes.run_frame(true);
+    let cpu_before = nes.cpu_cycles();
+    let apu_before = nes.apu_cycles();
+    let mut samples = 0;
+    for _ in 0..4 {
+        samples += nes.run_frame(true).len();
+    }
+    let cpu_per_frame = (nes.cpu_cycles() - cpu_before) / 4;
+    let apu_per_frame = (nes.apu_cycles() - apu_before) / 4;
+
+    // 131 extra scanlines is ~50% more CPU time; the APU still sees a stock
+    // NTSC frame, so the host gets the usual ~800 stereo samples per frame.
+    assert!((44_600..44_750).contains(&cpu_per_frame), "{cpu_per_frame}");
+    assert!((29_700..29_800).contains(&apu_per_frame), "{apu_per_frame}");
+    assert!((6_300..6_450).contains(&samples), "{samples}");
+}
+
+#[test]
+fn records_soloed_mix_and_isolated_channel_to_wav() {
+    // Pulse 1 at constant volume 15, 50% duty, period $0FF.
+    let program = [
+        0xA9, 0x01, 0x8D, 0x15, 0x40, // LDA #$01; STA $4015
+        0xA9, 0xBF, 0x8D, 0x00, 0x40, // LDA #$BF; STA $4000
+        0xA9, 0xFF, 0x8D, 0x02, 0x40, // LDA #$FF; STA $4002
+        0xA9, 0x00, 0x8D, 0x03, 0x40, // LDA #$00; STA $4003
+    ];
+    let cart = cartridge::load_cartridge(loop_rom(&program)).expect("load tone cartridge");
+    let mut nes = Nes::new(ColorFormat::Rgb555);
+    nes.insert_cartridge(cart);
+
+    let dir = std::env::temp_dir();
+    let pid = std::process::id();
+    let channel_path = dir.join(format!("nesium-record-pulse-{pid}.wav"));
+    let mix_path = dir.join(format!("nesium-record-mix-{pid}.wav"));
+
+    // Soloing the triangle silences the mix, but the pulse tap is unaffected.
+    let mut settings = MixerSettings::default();
+    settings.solo[AudioChannel::Triangle.idx()] = true;
+    nes.set_mixer_settings(&settings);
+
+    nes.start_audio_recording(&channel_path, RecordSource::Channel(AudioChannel::Pulse1))
+        .expect("start channel recording");
+    for _ in 0..4 {
+        nes.run_frame(true);
+    }
+    nes.start_audio_recording(&mix_path, RecordSource::Mix)
+        .expect("switch to mix recording");
+    for _ in 0..2 {
+        nes.run_frame(true);
+    }
+    nes.stop_audio_recording().expect("stop recording");
+    assert!(!nes.is_recording_audio());
+
+    let pcm = |path: &std::path::Path| {
+        let bytes = std::fs::read(path).expect("read wav");
+        std::fs::remove_file(path).ok();
+        let channels = u16::from_le_bytes([bytes[22], bytes[23]]);
+        let data_len = u32::from_le_bytes([bytes[40], bytes[41], bytes[42], bytes[43]]);
+        assert_eq!(data_len as usize, bytes.len() - 44);
+        let samples: Vec<i16> = bytes[44..]
+            .chunks_exact(2)
+            .map(|b| i16::from_le_bytes([b[0], b[1]]))
+            .collect();
+        (channels, samples)
+    };
+
+    let (channels, pulse) = pcm(&channel_path);
+    assert_eq!(channels, 1);
+    // Four NTSC frames at 96 kHz.
+    assert!((6_000..6_600).contains(&pulse.len()), "{}", pulse.len());
+    assert!(pulse.iter().any(|&s| s.unsigned_abs() > 1_000));
+
+    let (channels, mix) = pcm(&mix_path);
+    assert_eq!(channels, 2);
+    assert!(!mix.is_empty());
+    assert!(
+        mix.iter().all(|&s| s.unsigned_abs() < 64),
+        "soloed-out pulse leaked"
+    );
+}
@@ -0,0 +1,36 @@
+use nesium_core::{
+    Nes,
+    ppu::{SCREEN_HEIGHT, SCREEN_WIDTH, buffer::ColorFormat},
+};
+
+/// Every NES color index, repeated across the frame.
+fn index_frame() -> Vec<u8> {
+    (0..SCREEN_WIDTH * SCREEN_HEIGHT)
+        .map(|i| (i % 64) as u8)
+        .collect()
+}
+
+fn present(format: ColorFormat) -> Vec<u8> {
+    let mut nes = Nes::new(format);
+    assert!(nes.present_index_frame(&index_frame()));
+    nes.try_render_buffer().expect("packed output").to_vec()
+}
+
+#[test]
+fn rgb565_be_is_byte_swapped_rgb565() {
+    let le = present(ColorFormat::Rgb565);
+    let be = present(ColorFormat::Rgb565Be);
+    assert_eq!(le.len(), SCREEN_WIDTH * SCREEN_HEIGHT * 2);
+    assert!(
+        le.chunks_exact(2)
+            .zip(be.chunks_exact(2))
+            .all(|(le, be)| le == [be[1], be[0]])
+    );
+    assert!(le.chunks_exact(2).any(|px| px[0] != px[1]));
+}
+
+#[test]
+fn index8_outputs_the_palette_indices() {
+    let out = present(ColorFormat::Index8);
+    assert_eq!(out, index_frame());
+}
@@ -1,12 +1,14 @@
 #![allow(dead_code)]
 
-use std::{path::Path, time::Instant};
+use std::{env, fs, path::Path, time::Instant};
 
 use anyhow::{Context, Result, bail};
 use base64::{Engine as _, engine::general_purpose};
+use nesium_core::cartridge::Mmc3Revision;
 use nesium_core::memory::cpu as cpu_mem;
 use nesium_core::ppu::buffer::{ColorFormat, FrameBuffer};
-use nesium_core::ppu::palette::PaletteKind;
+use nesium_core::ppu::palette::{PaletteKind, emphasized_index};
+use nesium_core::ppu::{SCREEN_HEIGHT, SCREEN_WIDTH};
 use nesium_core::{Nes, reset_kind::ResetKind};
 use sha1::{Digest, Sha1};
 
@@ -16,6 +18,9 @@ pub const STATUS_MESSAGE_ADDR: u16 = 0x6004;
 pub const STATUS_MAX_BYTES: usize = 256;
 /// Many test ROMs that don't use the Blargg $6000 protocol store their result in ZP.
 pub const RESULT_ZP_ADDR: u16 = 0x00F8;
+/// Set to rewrite reference images in [`compare_frame_image`] instead of
+/// failing on a mismatch.
+pub const BLESS_ENV: &str = "NESIUM_BLESS_FRAMES";
 const TV_HASH_DEFAULT_FRAMES: usize = 1800;
 const TV_HASH_MAX_FRAMES: usize = 5000;
 
@@ -160,7 +165,28 @@ pub fn run_rom_status(rom_rel_path: &str, frames: usize) -> Result<Option<String
 
 /// Runs a ROM with the standard $6000/$6004 status handshake, then invokes `verify`
 /// to allow extra per-ROM assertions once the ROM reports success.
-pub fn run_rom_custom<F>(rom_rel_path: &str, frames: usize, mut verify: F) -> Result<Option<String>>
+pub fn run_rom_custom<F>(rom_rel_path: &str, frames: usize, verify: F) -> Result<Option<String>>
+where
+    F: FnMut(&mut Nes) -> Result<()>,
+{
+    run_rom_custom_with_mmc3(rom_rel_path, frames, None, verify)
+}
+
+/// [`run_rom_status`] with the MMC3 IRQ revision forced to `revision`.
+pub fn run_rom_status_mmc3(
+    rom_rel_path: &str,
+    frames: usize,
+    revision: Mmc3Revision,
+) -> Result<Option<String>> {
+    run_rom_custom_with_mmc3(rom_rel_path, frames, Some(revision), |_| Ok(()))
+}
+
+fn run_rom_custom_with_mmc3<F>(
+    rom_rel_path: &str,
+    frames: usize,
+    mmc3_revision: Option<Mmc3Revision>,
+    mut verify: F,
+) -> Result<Option<String>>
 where
     F: FnMut(&mut Nes) -> Result<()>,
 {
@@ -170,6 +196,7 @@ where
     }
 
     let mut nes = Nes::default();
+    nes.set_mmc3_revision(mmc3_revision);
     nes.load_cartridge_from_file(&path)
         .with_context(|| format!("loading {}", path.display()))?;
 
@@ -568,6 +595,33 @@ pub fn run_rom_zeropage_result(
     frames: usize,
     result_addr: u16,
     pass_value: u8,
+) -> Result<u8> {
+    run_rom_zeropage_result_with_mmc3(rom_rel_path, frames, result_addr, pass_value, None)
+}
+
+/// [`run_rom_zeropage_result`] with the MMC3 IRQ revision forced to `revision`.
+pub fn run_rom_zeropage_result_mmc3(
+    rom_rel_path: &str,
+    frames: usize,
+    result_addr: u16,
+    pass_value: u8,
+    revision: Mmc3Revision,
+) -> Result<u8> {
+    run_rom_zeropage_result_with_mmc3(
+        rom_rel_path,
+        frames,
+        result_addr,
+        pass_value,
+        Some(revision),
+    )
+}
+
+fn run_rom_zeropage_result_with_mmc3(
+    rom_rel_path: &str,
+    frames: usize,
+    result_addr: u16,
+    pass_value: u8,
+    mmc3_revision: Option<Mmc3Revision>,
 ) -> Result<u8> {
     const SETTLE_FRAMES: usize = 4;
 
@@ -577,6 +631,7 @@ pub fn run_rom_zeropage_result(
     }
 
     let mut nes = Nes::default();
+    nes.set_mmc3_revision(mmc3_revision);
     nes.load_cartridge_from_file(&path)
         .with_context(|| format!("loading {}", path.display()))?;
 
@@ -666,6 +721,140 @@ pub fn require_color_diversity(nes: &Nes, min_unique: usize) -> Result<()> {
     Ok(())
 }
 
+/// Builds an NROM-128 image with `program` at `$8000` (all vectors point
+/// there) and the given CHR data, zero-padded to 8 KiB.
+pub fn nrom_image(program: &[u8], chr: &[u8]) -> Vec<u8> {
+    let mut rom = Vec::with_capacity(16 + 16 * 1024 + 8 * 1024);
+    rom.extend_from_slice(b"NES\x1A");
+    rom.push(1); // 16 KiB PRG
+    rom.push(1); // 8 KiB CHR
+    rom.extend_from_slice(&[0; 10]);
+
+    let mut prg = vec![0xEA; 16 * 1024];
+    prg[..program.len()].copy_from_slice(program);
+    for vector in [0x3FFA, 0x3FFC, 0x3FFE] {
+        prg[vector] = 0x00;
+        prg[vector + 1] = 0x80;
+    }
+    rom.extend_from_slice(&prg);
+
+    let mut chr_rom = vec![0; 8 * 1024];
+    chr_rom[..chr.len()].copy_from_slice(chr);
+    rom.extend_from_slice(&chr_rom);
+    rom
+}
+
+/// Runs `nes` forward and checks [`Nes::frame_crc`] once `frame` frames have
+/// been run, for each `(frame, crc)` reference in ascending frame order.
+pub fn check_frame_crcs(nes: &mut Nes, references: &[(usize, u32)]) -> Result<()> {
+    let mut ran = 0usize;
+    for &(frame, expected) in references {
+        if frame < ran {
+            bail!("frame references must be sorted (frame {frame} after {ran})");
+        }
+        for _ in ran..frame {
+            nes.run_frame(false);
+        }
+        ran = frame;
+        let actual = nes.frame_crc();
+        if actual != expected {
+            bail!("frame {frame}: crc mismatch: expected {expected:#010X}, got {actual:#010X}");
+        }
+    }
+    Ok(())
+}
+
+/// Loads a ROM from [`ROM_ROOT`] and checks its frame CRCs; see
+/// [`check_frame_crcs`].
+pub fn run_rom_frame_crcs(rom_rel_path: &str, references: &[(usize, u32)]) -> Result<()> {
+    let path = Path::new(ROM_ROOT).join(rom_rel_path);
+    if !path.exists() {
+        bail!("ROM not found: {}", path.display());
+    }
+
+    let mut nes = Nes::default();
+    nes.load_cartridge_from_file(&path)
+        .with_context(|| format!("loading {}", path.display()))?;
+    check_frame_crcs(&mut nes, references).with_context(|| rom_rel_path.to_string())
+}
+
+/// The latest frame as RGB24, converted with the active emphasized palette.
+pub fn frame_rgb24(nes: &Nes) -> Vec<u8> {
+    let palette = nes.emphasized_palette();
+    nes.render_index_buffer()
+        .iter()
+        .zip(nes.render_emphasis_buffer())
+        .flat_map(|(&index, &emphasis)| {
+            let color = palette[emphasized_index(index, emphasis)];
+            [color.r, color.g, color.b]
+        })
+        .collect()
+}
+
+fn ppm_header() -> String {
+    format!("P6\n{SCREEN_WIDTH} {SCREEN_HEIGHT}\n255\n")
+}
+
+/// Writes the latest frame to `path` as a binary PPM.
+pub fn write_frame_image(nes: &Nes, path: &Path) -> Result<()> {
+    let mut data = ppm_header().into_bytes();
+    data.extend(frame_rgb24(nes));
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+    }
+    fs::write(path, data).with_context(|| format!("writing {}", path.display()))
+}
+
+/// Compares the latest frame against a PPM written by [`write_frame_image`].
+///
+/// On a mismatch the actual frame is saved next to the reference as
+/// `<name>.actual.ppm`. With [`BLESS_ENV`] set, missing or mismatching
+/// references are overwritten instead.
+pub fn compare_frame_image(nes: &Nes, reference: &Path) -> Result<()> {
+    let bless = env::var_os(BLESS_ENV).is_some();
+    let expected = match fs::read(reference) {
+        Ok(data) => data,
+        Err(_) if bless => return write_frame_image(nes, reference),
+        Err(err) => {
+            return Err(err).with_context(|| format!("reading {}", reference.display()));
+        }
+    };
+    let header = ppm_header();
+    let Some(expected) = expected.strip_prefix(header.as_bytes()) else {
+        bail!(
+            "{}: not a {SCREEN_WIDTH}x{SCREEN_HEIGHT} binary PPM",
+            reference.display()
+        );
+    };
+
+    let actual = frame_rgb24(nes);
+    let mismatched: Vec<usize> = actual
+        .chunks_exact(3)
+        .zip(expected.chunks_exact(3))
+        .enumerate()
+        .filter(|(_, (a, e))| a != e)
+        .map(|(pixel, _)| pixel)
+        .collect();
+    if mismatched.is_empty() && actual.len() == expected.len() {
+        return Ok(());
+    }
+    if bless {
+        return write_frame_image(nes, reference);
+    }
+
+    let actual_path = reference.with_extension("actual.ppm");
+    write_frame_image(nes, &actual_path)?;
+    let first = mismatched.first().copied().unwrap_or(0);
+    bail!(
+        "{}: {} pixels differ (first at {},{}); actual frame written to {}",
+        reference.display(),
+        mismatched.len(),
+        first % SCREEN_WIDTH,
+        first / SCREEN_WIDTH,
+        actual_path.display()
+    )
+}
+
 fn poll_status(nes: &mut Nes) -> Progress {
     let status = nes.peek_cpu_byte(STATUS_ADDR);
     let message = read_status_message(nes);
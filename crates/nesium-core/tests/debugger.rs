@@ -0,0 +1,242 @@
+mod common;
+
+use common::nrom_image;
+use nesium_core::{
+    CpuJam, Nes, cartridge,
+    debugger::{AccessKind, BreakOn, Breakpoint, EventSource, StopReason, WatchSpace, Watchpoint},
+    memory::ppu::Register as PpuRegister,
+    ppu::buffer::ColorFormat,
+    reset_kind::ResetKind,
+    trace::disassemble_around,
+};
+
+/// `LDA #$05; STA $0200; LDA $2002; JMP $8000` forever.
+const LOOP: &[u8] = &[
+    0xA9, 0x05, // $8000 LDA #$05
+    0x8D, 0x00, 0x02, // $8002 STA $0200
+    0xAD, 0x02, 0x20, // $8005 LDA $2002
+    0x4C, 0x00, 0x80, // $8008 JMP $8000
+];
+
+fn nes() -> Nes {
+    nes_with(LOOP)
+}
+
+fn nes_with(program: &[u8]) -> Nes {
+    let cart = cartridge::load_cartridge(nrom_image(program, &[])).expect("load cartridge");
+    let mut nes = Nes::new(ColorFormat::Rgb555);
+    nes.insert_cartridge(cart);
+    nes
+}
+
+fn expect_break(reason: StopReason) -> nesium_core::debugger::BreakEvent {
+    match reason {
+        StopReason::Break(event) => event,
+        other => panic!("expected a breakpoint hit, got {other:?}"),
+    }
+}
+
+#[test]
+fn run_until_break_stops_on_write_execute_and_ppu_register() {
+    let mut nes = nes();
+    let debugger = nes.enable_debugger();
+    let write = debugger.add_breakpoint(Breakpoint::write(0x0200..=0x0200));
+    let exec = debugger.add_breakpoint(Breakpoint::execute(0x8008));
+    let status =
+        debugger.add_breakpoint(Breakpoint::ppu_register(PpuRegister::Status, BreakOn::READ));
+
+    let hit = expect_break(nes.run_until_break(1_000));
+    assert_eq!(hit.id, write);
+    assert_eq!(hit.access.kind, AccessKind::Write);
+    assert_eq!(
+        (hit.access.value, hit.cpu.a, hit.cpu.pc),
+        (0x05, 0x05, 0x8005)
+    );
+
+    let hit = expect_break(nes.run_until_break(1_000));
+    assert_eq!((hit.id, hit.access.addr), (status, 0x2002));
+
+    let hit = expect_break(nes.run_until_break(1_000));
+    assert_eq!((hit.id, hit.cpu.pc), (exec, 0x8008));
+
+    // Resuming continues the loop and hits the write again.
+    let hit = expect_break(nes.run_until_break(1_000));
+    assert_eq!(hit.id, write);
+}
+
+#[test]
+fn conditions_gate_hits_and_limit_is_reported() {
+    let mut nes = nes();
+    nes.enable_debugger()
+        .add_breakpoint(Breakpoint::execute(0x8002).with_condition(|e| e.cpu.a == 0x06));
+
+    assert_eq!(nes.run_until_break(1_000), StopReason::CycleLimit);
+    assert!(nes.disable_debugger().is_some());
+    assert_eq!(nes.run_until_break(10), StopReason::CycleLimit);
+}
+
+#[test]
+fn watchpoints_filter_values_and_report_the_instruction() {
+    let mut nes = nes();
+    let debugger = nes.enable_debugger();
+    debugger
+        .add_watchpoint(Watchpoint::cpu(0x0200..=0x0200, BreakOn::WRITE).with_value(0x06, 0xFF));
+    let id = debugger
+        .add_watchpoint(Watchpoint::cpu(0x0200..=0x02FF, BreakOn::WRITE).with_value(0x04, 0x0C));
+
+    let StopReason::Watch(hit) = nes.run_until_break(1_000) else {
+        panic!("expected a watchpoint hit");
+    };
+    assert_eq!(hit.id, id);
+    assert_eq!(hit.space, WatchSpace::Cpu);
+    assert_eq!((hit.access.addr, hit.access.value), (0x0200, 0x05));
+    assert_eq!((hit.pc, hit.opcode), (0x8002, 0x8D));
+    assert!(hit.cycle > 0);
+}
+
+#[test]
+fn ppu_watchpoints_see_ppudata_writes() {
+    let mut nes = nes_with(&[
+        0xA9, 0x21, // $8000 LDA #$21
+        0x8D, 0x06, 0x20, // $8002 STA $2006
+        0xA9, 0x08, // $8005 LDA #$08
+        0x8D, 0x06, 0x20, // $8007 STA $2006
+        0xA9, 0x42, // $800A LDA #$42
+        0x8D, 0x07, 0x20, // $800C STA $2007
+        0x4C, 0x0F, 0x80, // $800F JMP $800F
+    ]);
+    nes.enable_debugger()
+        .add_watchpoint(Watchpoint::ppu(0x2000..=0x23FF, BreakOn::WRITE));
+
+    let StopReason::Watch(hit) = nes.run_until_break(1_000) else {
+        panic!("expected a watchpoint hit");
+    };
+    assert_eq!(hit.space, WatchSpace::Ppu);
+    assert_eq!(
+        (hit.access.addr, hit.access.value, hit.access.kind),
+        (0x2108, 0x42, AccessKind::Write)
+    );
+    assert_eq!((hit.pc, hit.opcode), (0x800C, 0x8D));
+}
+
+/// `$8000 JSR $8010; INX; JMP $8000`, `$8010 JSR $8020; RTS`, `$8020 INY; RTS`.
+fn nested_calls() -> Nes {
+    let mut program = vec![0xEA; 0x22];
+    program[0x00..0x07].copy_from_slice(&[0x20, 0x10, 0x80, 0xE8, 0x4C, 0x00, 0x80]);
+    program[0x10..0x14].copy_from_slice(&[0x20, 0x20, 0x80, 0x60]);
+    program[0x20..0x22].copy_from_slice(&[0xC8, 0x60]);
+    nes_with(&program)
+}
+
+#[test]
+fn step_over_step_out_and_run_to_follow_call_frames() {
+    let mut nes = nested_calls();
+
+    assert_eq!(nes.step_over(1_000), StopReason::StepComplete);
+    let cpu = nes.cpu_snapshot();
+    assert_eq!((cpu.pc, cpu.y), (0x8003, 1));
+
+    assert_eq!(nes.step_over(1_000), StopReason::StepComplete);
+    assert_eq!(nes.cpu_snapshot().pc, 0x8004);
+
+    assert_eq!(nes.run_to(0x8020, 1_000), StopReason::StepComplete);
+    assert_eq!(nes.cpu_snapshot().pc, 0x8020);
+
+    assert_eq!(nes.step_out(1_000), StopReason::StepComplete);
+    assert_eq!(nes.cpu_snapshot().pc, 0x8013);
+    assert_eq!(nes.step_out(1_000), StopReason::StepComplete);
+    let cpu = nes.cpu_snapshot();
+    assert_eq!((cpu.pc, cpu.y), (0x8003, 2));
+}
+
+#[test]
+fn stepping_stops_on_breakpoints_inside_calls() {
+    let mut nes = nested_calls();
+    let id = nes
+        .enable_debugger()
+        .add_breakpoint(Breakpoint::execute(0x8020));
+
+    let hit = expect_break(nes.step_over(1_000));
+    assert_eq!(hit.id, id);
+
+    // Resuming mid-instruction still steps out to the JSR's caller.
+    assert_eq!(nes.step_out(1_000), StopReason::StepComplete);
+    assert_eq!(nes.cpu_snapshot().pc, 0x8013);
+    assert_eq!(nes.run_to(0x9000, 20), StopReason::CycleLimit);
+}
+
+#[test]
+fn event_log_records_register_accesses_and_nmi_timing() {
+    // `LDA #$80; STA $2000; LDA $2002; STA $4015; JMP $8005`, NMI -> $8000.
+    let mut nes = nes_with(&[
+        0xA9, 0x80, 0x8D, 0x00, 0x20, 0xAD, 0x02, 0x20, 0x8D, 0x15, 0x40, 0x4C, 0x05, 0x80,
+    ]);
+    nes.enable_debugger().enable_event_log(100_000);
+    nes.run_frame(false);
+    nes.run_frame(false);
+
+    let log = nes.debugger().and_then(|d| d.event_log()).expect("log");
+    let frame = log.iter().last().expect("events").at.frame;
+    let events: Vec<_> = log.frame(frame).collect();
+    let nmi = events
+        .iter()
+        .find(|e| e.source == EventSource::Nmi)
+        .expect("NMI entry");
+    assert_eq!(nmi.at.scanline, 241);
+
+    let sources = |addr: u16| {
+        events
+            .iter()
+            .filter(|e| e.access.addr == addr)
+            .map(|e| (e.source, e.access.kind))
+            .next()
+    };
+    assert_eq!(sources(0x2002), Some((EventSource::Ppu, AccessKind::Read)));
+    assert_eq!(sources(0x4015), Some((EventSource::Apu, AccessKind::Write)));
+    assert!(events.iter().all(|e| e.access.addr != 0x8005));
+    assert!(
+        events
+            .windows(2)
+            .all(|w| w[0].at.frame_offset() <= w[1].at.frame_offset())
+    );
+}
+
+#[test]
+fn jam_halts_the_cpu_until_reset() {
+    let mut nes = nes_with(&[
+        0xA9, 0x05, // $8000 LDA #$05
+        0x8D, 0x00, 0x02, // $8002 STA $0200
+        0x02, // $8005 JAM
+        0xEA, // $8006 NOP
+    ]);
+    assert_eq!(nes.cpu_jam(), None);
+    nes.run_frame(false);
+    assert_eq!(
+        nes.cpu_jam(),
+        Some(CpuJam {
+            pc: 0x8005,
+            opcode: 0x02
+        })
+    );
+
+    let lines: Vec<_> = disassemble_around(&mut nes, 0x8005, 2, 1)
+        .iter()
+        .map(|line| (line.addr, line.text()))
+        .collect();
+    assert_eq!(
+        lines,
+        [
+            (0x8000, "LDA #$05".to_string()),
+            (0x8002, "STA $0200".to_string()),
+            (0x8005, "JAM".to_string()),
+            (0x8006, "NOP".to_string()),
+        ]
+    );
+
+    // Stepping a jammed CPU must return instead of waiting for a fetch.
+    nes.step_to_instruction_boundary();
+    assert_eq!(nes.cpu_snapshot().pc, 0x8005);
+
+    nes.reset(ResetKind::Soft);
+    assert_eq!(nes.cpu_jam(), None);
+}
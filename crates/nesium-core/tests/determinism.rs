@@ -0,0 +1,163 @@
+mod common;
+
+use common::nrom_image;
+use nesium_core::{
+    Nes, cartridge,
+    config::ram_init::RamInit,
+    movie::{MovieFrame, MovieMode},
+    ppu::{buffer::ColorFormat, palette::PaletteKind},
+    reset_kind::ResetKind,
+};
+
+/// NROM image that enables rendering, then keeps adding controller 1's A
+/// button to `$00`.
+fn input_rom() -> Vec<u8> {
+    #[rustfmt::skip]
+    let program = [
+        0xA9, 0x1E,       // LDA #$1E
+        0x8D, 0x01, 0x20, // STA $2001
+        0xA9, 0x01,       // loop: LDA #1
+        0x8D, 0x16, 0x40, // STA $4016
+        0xA9, 0x00,       // LDA #0
+        0x8D, 0x16, 0x40, // STA $4016
+        0xAD, 0x16, 0x40, // LDA $4016
+        0x29, 0x01,       // AND #1
+        0x18,             // CLC
+        0x65, 0x00,       // ADC $00
+        0x85, 0x00,       // STA $00
+        0x4C, 0x05, 0x80, // JMP loop
+    ];
+
+    let chr: Vec<u8> = (0..8 * 1024).map(|i| i as u8).collect();
+    nrom_image(&program, &chr)
+}
+
+/// Runs a deterministic session pressing A on every third frame and returns
+/// the final state hash and frame.
+fn run(palette: PaletteKind, fixed_point: bool) -> (u64, Vec<u8>) {
+    let mut nes = Nes::new(ColorFormat::Rgba8888);
+    nes.load_cartridge_from_bytes(&input_rom())
+        .expect("load cartridge");
+    nes.set_palette(palette.palette());
+    nes.set_fixed_point_audio(fixed_point);
+    nes.set_deterministic(true);
+
+    for frame in 0..30 {
+        nes.set_pad_state(0, u8::from(frame % 3 == 0));
+        nes.run_frame(true);
+    }
+    let mut frame = vec![0; 256 * 240 * 4];
+    nes.copy_render_buffer(&mut frame);
+    (nes.state_hash(), frame)
+}
+
+#[test]
+fn identical_inputs_give_identical_state_hashes() {
+    let (hash, frame) = run(PaletteKind::NesdevNtsc, false);
+    let (again, again_frame) = run(PaletteKind::NesdevNtsc, false);
+    assert_eq!(hash, again);
+    assert_eq!(frame, again_frame);
+
+    // Display and mixer settings are host-side and must not leak into the hash.
+    assert_eq!(run(PaletteKind::Mesen2C02, true).0, hash);
+}
+
+#[test]
+fn state_hash_tracks_emulated_state() {
+    let cart = cartridge::load_cartridge(input_rom()).expect("load cartridge");
+    let mut nes = Nes::new(ColorFormat::Rgba8888);
+    nes.insert_cartridge(cart);
+    nes.set_deterministic(true);
+    assert!(nes.deterministic());
+
+    nes.run_frame(false);
+    let idle = nes.state_hash();
+    assert_eq!(nes.state_hash(), idle);
+
+    nes.set_pad_state(0, 0x01);
+    nes.run_frame(false);
+    let pressed = nes.state_hash();
+    assert_ne!(pressed, idle);
+    assert_ne!(nes.peek_cpu_byte(0x0000), 0);
+}
+
+#[test]
+fn polled_input_is_sampled_mid_frame() {
+    let mut nes = Nes::new(ColorFormat::Rgba8888);
+    nes.insert_cartridge(cartridge::load_cartridge(input_rom()).expect("load cartridge"));
+    nes.run_frame(false);
+    assert_eq!(nes.peek_cpu_byte(0x0000), 0);
+
+    let mut polls = 0;
+    nes.run_frame_polled(false, 241, |nes| {
+        polls += 1;
+        nes.set_pad_state(0, 0x01);
+    });
+    assert_eq!(polls, 1);
+    assert_ne!(nes.peek_cpu_byte(0x0000), 0);
+
+    // Movies record input per frame, so polling is bypassed while one runs.
+    nes.start_movie_recording();
+    nes.run_frame_polled(false, 241, |_| polls += 1);
+    assert_eq!(polls, 1);
+}
+
+#[test]
+fn ram_init_pattern_applies_on_power_on_only() {
+    let mut nes = Nes::new(ColorFormat::Rgba8888);
+    nes.set_ram_init(RamInit::Stripes);
+    nes.insert_cartridge(cartridge::load_cartridge(input_rom()).expect("load cartridge"));
+    let mut ram = [0; 8];
+    nes.peek_cpu_slice(0x0100, &mut ram);
+    assert_eq!(ram, [0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF]);
+
+    nes.set_ram_init(RamInit::Random { seed: 1 });
+    nes.reset(ResetKind::Soft);
+    nes.peek_cpu_slice(0x0100, &mut ram);
+    assert_eq!(ram, [0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF]);
+
+    nes.reset(ResetKind::PowerOn);
+    let random = nes.state_hash();
+    nes.reset(ResetKind::PowerOn);
+    assert_eq!(nes.state_hash(), random);
+}
+
+#[test]
+fn recorded_movie_replays_to_the_same_state() {
+    let mut nes = Nes::new(ColorFormat::Rgba8888);
+    nes.insert_cartridge(cartridge::load_cartridge(input_rom()).expect("load cartridge"));
+    nes.set_ram_init(RamInit::Random { seed: 7 });
+    nes.set_deterministic(true);
+    nes.start_movie_recording();
+    for frame in 0..40 {
+        if frame == 25 {
+            nes.reset(ResetKind::Soft);
+        }
+        nes.set_pad_state(0, u8::from(frame % 4 == 1));
+        nes.run_frame(false);
+    }
+    assert_eq!(nes.movie_position(), Some(40));
+    let recorded = nes.state_hash();
+    let movie = nes.stop_movie().expect("recording");
+    assert_eq!(movie.ram_init, RamInit::Random { seed: 7 });
+    assert_eq!(
+        movie.frames[25],
+        MovieFrame {
+            command: Some(ResetKind::Soft),
+            pads: [1, 0, 0, 0],
+        }
+    );
+
+    let mut replay = Nes::new(ColorFormat::Rgba8888);
+    replay.insert_cartridge(cartridge::load_cartridge(input_rom()).expect("load cartridge"));
+    replay.set_deterministic(true);
+    replay.play_movie(movie);
+    assert_eq!(replay.movie_mode(), Some(MovieMode::Playing));
+    for _ in 0..40 {
+        replay.run_frame(false);
+    }
+    assert_eq!(replay.state_hash(), recorded);
+
+    replay.run_frame(false);
+    assert_eq!(replay.movie_mode(), None);
+}
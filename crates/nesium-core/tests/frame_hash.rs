@@ -0,0 +1,122 @@
+mod common;
+
+use common::{check_frame_crcs, compare_frame_image, nrom_image, write_frame_image};
+use nesium_core::{
+    Nes, cartridge,
+    ppu::{SCREEN_HEIGHT, SCREEN_WIDTH, buffer::ColorFormat, palette::PaletteKind},
+};
+
+/// Sets the backdrop to `$21`, points `v` back at the nametables and turns
+/// on red emphasis with rendering disabled, so every later frame is a flat
+/// emphasized backdrop.
+fn emphasized_backdrop_nes() -> Nes {
+    #[rustfmt::skip]
+    let program = [
+        0x2C, 0x02, 0x20, // vb1: BIT $2002
+        0x10, 0xFB,       // BPL vb1
+        0x2C, 0x02, 0x20, // vb2: BIT $2002
+        0x10, 0xFB,       // BPL vb2
+        0xA9, 0x3F,       // LDA #$3F
+        0x8D, 0x06, 0x20, // STA $2006
+        0xA9, 0x00,       // LDA #0
+        0x8D, 0x06, 0x20, // STA $2006
+        0xA9, 0x21,       // LDA #$21
+        0x8D, 0x07, 0x20, // STA $2007
+        0xA9, 0x00,       // LDA #0
+        0x8D, 0x06, 0x20, // STA $2006
+        0x8D, 0x06, 0x20, // STA $2006
+        0xA9, 0x20,       // LDA #$20
+        0x8D, 0x01, 0x20, // STA $2001
+        0x4C, 0x26, 0x80, // spin: JMP spin
+    ];
+    let cart = cartridge::load_cartridge(nrom_image(&program, &[])).expect("load cartridge");
+    let mut nes = Nes::new(ColorFormat::Rgba8888);
+    nes.insert_cartridge(cart);
+    nes
+}
+
+#[test]
+fn frame_crc_covers_indices_and_emphasis_only() {
+    let pixels = SCREEN_WIDTH * SCREEN_HEIGHT;
+    let mut expected = crc32fast::Hasher::new();
+    expected.update(&vec![0x21; pixels]);
+    expected.update(&vec![0b001; pixels]);
+    let expected = expected.finalize();
+
+    let mut nes = emphasized_backdrop_nes();
+    check_frame_crcs(&mut nes, &[(5, expected), (8, expected)]).expect("frame crcs");
+
+    let hash = nes.frame_hash();
+    nes.set_palette(PaletteKind::Mesen2C02.palette());
+    assert_eq!(nes.frame_crc(), expected);
+    assert_ne!(nes.frame_hash(), hash);
+}
+
+#[test]
+fn frame_image_comparison_reports_palette_changes() {
+    let reference = std::env::temp_dir()
+        .join(format!("nesium-frame-{}", std::process::id()))
+        .join("backdrop.ppm");
+    let mut nes = emphasized_backdrop_nes();
+    for _ in 0..5 {
+        nes.run_frame(false);
+    }
+    write_frame_image(&nes, &reference).expect("write reference");
+    compare_frame_image(&nes, &reference).expect("identical frame");
+
+    nes.set_palette(PaletteKind::Mesen2C02.palette());
+    let err = compare_frame_image(&nes, &reference).expect_err("palette changed");
+    assert!(err.to_string().contains("61440 pixels differ"), "{err:#}");
+    assert!(reference.with_extension("actual.ppm").exists());
+
+    if let Some(dir) = reference.parent() {
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
+
+#[test]
+fn skipped_frames_are_not_presented() {
+    let mut emphasized = crc32fast::Hasher::new();
+    emphasized.update(&vec![0x21; SCREEN_WIDTH * SCREEN_HEIGHT]);
+    emphasized.update(&vec![0b001; SCREEN_WIDTH * SCREEN_HEIGHT]);
+    let emphasized = emphasized.finalize();
+
+    let mut nes = emphasized_backdrop_nes();
+    nes.run_frame(false);
+    let first = nes.frame_crc();
+    assert_ne!(first, emphasized);
+
+    nes.set_frame_skip(true);
+    for _ in 0..7 {
+        nes.run_frame(false);
+    }
+    assert_eq!(nes.frame_crc(), first);
+
+    nes.set_frame_skip(false);
+    nes.run_frame(false);
+    assert_eq!(nes.frame_crc(), emphasized);
+}
+
+#[test]
+fn rgb_frames_match_the_packed_output() {
+    let mut nes = emphasized_backdrop_nes();
+    for _ in 0..8 {
+        nes.run_frame(false);
+    }
+
+    let (width, height, rgb) = nes.render_frame_rgb(false);
+    assert_eq!((width, height), (SCREEN_WIDTH, SCREEN_HEIGHT));
+    let mut rgba = vec![0; SCREEN_WIDTH * SCREEN_HEIGHT * 4];
+    nes.copy_render_buffer(&mut rgba);
+    let packed: Vec<u8> = rgba
+        .chunks_exact(4)
+        .flat_map(|pixel| pixel[..3].to_vec())
+        .collect();
+    assert_eq!(rgb, packed);
+
+    nes.set_video_output_config(SCREEN_WIDTH * 2, SCREEN_HEIGHT * 2);
+    let (width, height, scaled) = nes.render_frame_rgb(true);
+    assert_eq!((width, height), (SCREEN_WIDTH * 2, SCREEN_HEIGHT * 2));
+    assert_eq!(&scaled[..3], &rgb[..3]);
+    assert_eq!(nes.render_frame_rgb(false).2, rgb);
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use common::nrom_image;
+use nesium_core::{
+    Nes,
+    ppu::{
+        SCREEN_WIDTH,
+        buffer::ColorFormat,
+        hd_pack::{HdImage, HdPack},
+    },
+};
+
+/// NROM image showing tile 1 (solid color 1) as two sprites at Y=20: one at
+/// X=16 and a horizontally flipped one at X=40, with sprite palette 0 set to
+/// `$0F $16 $27 $30`.
+fn two_sprites_rom() -> Vec<u8> {
+    #[rustfmt::skip]
+    let program = [
+        0x2C, 0x02, 0x20, // vb1: BIT $2002
+        0x10, 0xFB,       // BPL vb1
+        0x2C, 0x02, 0x20, // vb2: BIT $2002
+        0x10, 0xFB,       // BPL vb2
+        0xA9, 0x3F,       // LDA #$3F
+        0x8D, 0x06, 0x20, // STA $2006
+        0xA9, 0x10,       // LDA #$10
+        0x8D, 0x06, 0x20, // STA $2006
+        0xA9, 0x0F,       // LDA #$0F
+        0x8D, 0x07, 0x20, // STA $2007
+        0xA9, 0x16,       // LDA #$16
+        0x8D, 0x07, 0x20, // STA $2007
+        0xA9, 0x27,       // LDA #$27
+        0x8D, 0x07, 0x20, // STA $2007
+        0xA9, 0x30,       // LDA #$30
+        0x8D, 0x07, 0x20, // STA $2007
+        0xA9, 0x00,       // LDA #0
+        0x8D, 0x03, 0x20, // STA $2003
+        0xA9, 0x14,       // LDA #20
+        0x8D, 0x04, 0x20, // STA $2004 (Y)
+        0xA9, 0x01,       // LDA #1
+        0x8D, 0x04, 0x20, // STA $2004 (tile)
+        0xA9, 0x00,       // LDA #0
+        0x8D, 0x04, 0x20, // STA $2004 (attributes)
+        0xA9, 0x10,       // LDA #16
+        0x8D, 0x04, 0x20, // STA $2004 (X)
+        0xA9, 0x14,       // LDA #20
+        0x8D, 0x04, 0x20, // STA $2004 (Y)
+        0xA9, 0x01,       // LDA #1
+        0x8D, 0x04, 0x20, // STA $2004 (tile)
+        0xA9, 0x40,       // LDA #$40
+        0x8D, 0x04, 0x20, // STA $2004 (attributes: flip X)
+        0xA9, 0x28,       // LDA #40
+        0x8D, 0x04, 0x20, // STA $2004 (X)
+        0xA0, 0xF8,       // LDY #248
+        0xA9, 0xFF,       // LDA #$FF
+        0x8D, 0x04, 0x20, // hide: STA $2004
+        0x88,             // DEY
+        0xD0, 0xFA,       // BNE hide
+        0xA9, 0x14,       // LDA #$14
+        0x8D, 0x01, 0x20, // STA $2001
+        0x4C, 0x64, 0x80, // spin: JMP spin
+    ];
+
+    let mut chr = [0; 24];
+    chr[16..24].fill(0xFF);
+    nrom_image(&program, &chr)
+}
+
+/// 2x pack replacing tile 1 with a 16x16 gradient: texel `(x, y)` is
+/// `(x * 16, y * 16, 0x80)`.
+fn gradient_pack() -> HdPack {
+    let rgba: Vec<u8> = (0..16u8)
+        .flat_map(|y| (0..16u8).flat_map(move |x| [x * 16, y * 16, 0x80, 0xFF]))
+        .collect();
+    let manifest = "<ver>106\n<scale>2\n<img>tiles.png\n<tile>0,1,0F162730,0,0,1,N\n";
+    HdPack::parse(manifest, |_| HdImage::from_rgba(16, 16, rgba.clone())).expect("parse HD pack")
+}
+
+#[test]
+fn sprites_are_replaced_in_the_hd_frame() {
+    let mut nes = Nes::new(ColorFormat::Rgba8888);
+    nes.load_cartridge_from_bytes(&two_sprites_rom())
+        .expect("load cartridge");
+    nes.set_hd_pack(Some(&gradient_pack()));
+    for _ in 0..6 {
+        nes.run_frame(false);
+    }
+
+    let hd = nes.hd_frame().expect("HD frame");
+    assert_eq!((hd.width(), hd.height()), (512, 480));
+    let hd_pixel = |x: usize, y: usize| {
+        let at = y * hd.pitch() + x * 4;
+        &hd.pixels()[at..at + 4]
+    };
+
+    // Sprites appear one line below their Y, so tile row 1 is on line 22.
+    // Column 1, sub-pixel (1, 0) -> texel (3, 2).
+    assert_eq!(hd_pixel(17 * 2 + 1, 22 * 2), [48, 32, 0x80, 0xFF]);
+    // Flipped: column 1 on screen is pattern column 6, sub-pixel 1 of 2
+    // mirrors to 0 -> texel (12, 2).
+    assert_eq!(hd_pixel(41 * 2 + 1, 22 * 2), [192, 32, 0x80, 0xFF]);
+
+    // Untouched pixels match the regular output.
+    let normal = nes.try_render_buffer().expect("packed output");
+    let at = (100 * SCREEN_WIDTH + 100) * 4;
+    assert_eq!(hd_pixel(200, 200), &normal[at..at + 4]);
+    assert_eq!(hd_pixel(201, 201), &normal[at..at + 4]);
+
+    nes.set_hd_pack(None);
+    assert!(nes.hd_frame().is_none());
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicU32, Ordering},
+};
+
+use common::nrom_image;
+use nesium_core::{
+    Nes,
+    interceptor::hook_interceptor::{HookEvent, HookTrigger},
+    ppu::buffer::ColorFormat,
+};
+
+/// NROM image whose reset vector spins on `JMP $8000`.
+fn idle_rom() -> Vec<u8> {
+    nrom_image(&[0x4C, 0x00, 0x80], &[])
+}
+
+fn nes() -> Nes {
+    let mut nes = Nes::new(ColorFormat::Rgba8888);
+    nes.load_cartridge_from_bytes(&idle_rom())
+        .expect("load cartridge");
+    nes
+}
+
+#[test]
+fn scanline_hooks_fire_at_their_dot_once_per_frame() {
+    let mut nes = nes();
+    let events = Arc::new(Mutex::new(Vec::<HookEvent>::new()));
+    let sink = Arc::clone(&events);
+    let id = nes.add_scanline_hook(100, 5, move |_, _, event| {
+        sink.lock().expect("events").push(event);
+    });
+
+    for _ in 0..3 {
+        nes.run_frame(false);
+    }
+    {
+        let events = events.lock().expect("events");
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().all(|event| {
+            event.trigger
+                == HookTrigger::ScanlineDot {
+                    scanline: 100,
+                    dot: 5,
+                }
+                && (event.scanline, event.dot) == (100, 5)
+        }));
+        assert_eq!(events[2].frame, events[0].frame + 2);
+    }
+
+    assert!(nes.remove_hook(id));
+    assert!(!nes.remove_hook(id));
+    nes.run_frame(false);
+    assert_eq!(events.lock().expect("events").len(), 3);
+}
+
+#[test]
+fn frame_hooks_fire_where_run_frame_returns() {
+    let mut nes = nes();
+    let calls = Arc::new(AtomicU32::new(0));
+    let last_frame = Arc::new(AtomicU32::new(0));
+    let (count, frame) = (Arc::clone(&calls), Arc::clone(&last_frame));
+    nes.add_frame_hook(move |_, _, event| {
+        assert_eq!((event.scanline, event.dot), (240, 0));
+        count.fetch_add(1, Ordering::Relaxed);
+        frame.store(event.frame, Ordering::Relaxed);
+    });
+
+    for _ in 0..4 {
+        nes.run_frame(false);
+        assert_eq!(last_frame.load(Ordering::Relaxed), nes.ppu.frame_count());
+    }
+    assert_eq!(calls.load(Ordering::Relaxed), 4);
+}
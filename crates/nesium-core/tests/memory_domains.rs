@@ -0,0 +1,131 @@
+mod common;
+
+use common::nrom_image;
+use nesium_core::{Nes, cheat::Cheat, memory::domain::MemoryDomain, ppu::buffer::ColorFormat};
+
+/// NROM image with CHR-RAM that stores `$42` to `$0010` and `$6000`, then
+/// spins.
+fn store_rom() -> Vec<u8> {
+    #[rustfmt::skip]
+    let program = [
+        0xA9, 0x42,       // LDA #$42
+        0x85, 0x10,       // STA $10
+        0x8D, 0x00, 0x60, // STA $6000
+        0x4C, 0x07, 0x80, // spin: JMP spin
+    ];
+
+    // Drop the CHR-ROM bank so the board gets CHR-RAM.
+    let mut rom = nrom_image(&program, &[]);
+    rom[5] = 0;
+    rom.truncate(16 + 16 * 1024);
+    rom
+}
+
+fn nes() -> Nes {
+    let mut nes = Nes::new(ColorFormat::Rgba8888);
+    nes.load_cartridge_from_bytes(&store_rom())
+        .expect("load cartridge");
+    nes.run_frame(false);
+    nes
+}
+
+#[test]
+fn domains_expose_the_backing_stores() {
+    let nes = nes();
+    let sizes = MemoryDomain::ALL.map(|domain| nes.memory_domain_size(domain));
+    assert_eq!(sizes, [2048, 8192, 16 * 1024, 8192, 256, 32, 2048]);
+
+    assert_eq!(nes.peek_memory(MemoryDomain::CpuRam, 0x10), Some(0x42));
+    assert_eq!(nes.peek_memory(MemoryDomain::PrgRam, 0), Some(0x42));
+    assert_eq!(nes.peek_memory(MemoryDomain::PrgRom, 0), Some(0xA9));
+    assert_eq!(nes.peek_memory(MemoryDomain::PrgRom, 0x3FFD), Some(0x80));
+    assert_eq!(nes.peek_memory(MemoryDomain::Oam, 256), None);
+
+    let mut buffer = [0xFF; 4];
+    assert_eq!(nes.read_memory(MemoryDomain::PrgRom, 0, &mut buffer), 4);
+    assert_eq!(buffer, [0xA9, 0x42, 0x85, 0x10]);
+    assert_eq!(
+        nes.read_memory(MemoryDomain::PaletteRam, 30, &mut buffer),
+        2
+    );
+    assert_eq!(nes.read_memory(MemoryDomain::CpuRam, 4096, &mut buffer), 0);
+}
+
+#[test]
+fn writes_reach_the_console_except_for_rom() {
+    let mut nes = nes();
+
+    assert!(nes.write_memory(MemoryDomain::CpuRam, 0x20, 7));
+    // Visible through the CPU RAM mirror.
+    assert_eq!(nes.peek_cpu_byte(0x0820), 7);
+    assert!(nes.write_memory(MemoryDomain::PrgRam, 1, 9));
+    assert_eq!(nes.peek_cpu_byte(0x6001), 9);
+    assert!(nes.write_memory(MemoryDomain::ChrRam, 0x1FFF, 3));
+    assert_eq!(nes.peek_memory(MemoryDomain::ChrRam, 0x1FFF), Some(3));
+
+    assert!(MemoryDomain::PrgRom.is_read_only());
+    assert!(!nes.write_memory(MemoryDomain::PrgRom, 0, 0));
+    assert_eq!(nes.peek_memory(MemoryDomain::PrgRom, 0), Some(0xA9));
+    assert!(!nes.write_memory(MemoryDomain::Nametables, 2048, 0));
+}
+
+#[test]
+fn cheats_patch_prg_ram_directly() {
+    let mut nes = nes();
+    nes.cheats_mut()
+        .add(Cheat::freeze(0x6001, 0x24))
+        .expect("PRG-RAM address");
+    nes.run_frame(false);
+    assert_eq!(nes.peek_memory(MemoryDomain::PrgRam, 1), Some(0x24));
+    assert_eq!(nes.peek_cpu_byte(0x6001), 0x24);
+}
+
+/// SXROM (MMC1, 32 KiB PRG-RAM) image that selects PRG-RAM bank 2 through
+/// CHR register 0, then spins.
+fn sxrom_bank2_rom() -> Vec<u8> {
+    #[rustfmt::skip]
+    let program = [
+        0xA9, 0x08,       // LDA #$08 (CHR bits 2-3 = RAM bank 2)
+        0x8D, 0x00, 0xA0, // STA $A000
+        0x4A,             // LSR
+        0x8D, 0x00, 0xA0, // STA $A000
+        0x4A,             // LSR
+        0x8D, 0x00, 0xA0, // STA $A000
+        0x4A,             // LSR
+        0x8D, 0x00, 0xA0, // STA $A000
+        0x4A,             // LSR
+        0x8D, 0x00, 0xA0, // STA $A000
+        0x4C, 0x17, 0x80, // spin: JMP spin
+    ];
+
+    let mut rom = nrom_image(&program, &[]);
+    rom[6] = 0x10; // mapper 1
+    rom[8] = 4; // 4 x 8 KiB PRG-RAM
+    rom
+}
+
+#[test]
+fn cheats_follow_the_current_prg_ram_bank() {
+    let mut nes = Nes::new(ColorFormat::Rgba8888);
+    nes.load_cartridge_from_bytes(&sxrom_bank2_rom())
+        .expect("load cartridge");
+    nes.cheats_mut()
+        .add(Cheat::freeze(0x6001, 0x24))
+        .expect("PRG-RAM address");
+    nes.run_frame(false);
+
+    assert_eq!(nes.memory_domain_size(MemoryDomain::PrgRam), 32 * 1024);
+    assert_eq!(
+        nes.peek_memory(MemoryDomain::PrgRam, 2 * 8192 + 1),
+        Some(0x24)
+    );
+    assert_eq!(nes.peek_memory(MemoryDomain::PrgRam, 1), Some(0));
+}
+
+#[test]
+fn cartridge_domains_are_empty_without_a_cartridge() {
+    let nes = Nes::new(ColorFormat::Rgba8888);
+    assert_eq!(nes.memory_domain_size(MemoryDomain::PrgRam), 0);
+    assert_eq!(nes.peek_memory(MemoryDomain::PrgRom, 0), None);
+    assert_eq!(nes.memory_domain_size(MemoryDomain::CpuRam), 2048);
+}
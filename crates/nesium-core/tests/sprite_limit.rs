@@ -0,0 +1,83 @@
+mod common;
+
+use common::nrom_image;
+use nesium_core::{
+    Nes, cartridge,
+    ppu::{SCREEN_HEIGHT, SCREEN_WIDTH, buffer::ColorFormat},
+};
+
+/// NROM image that lines up ten 8x8 sprites at Y=20 (16 pixels apart) and
+/// enables sprite rendering. Tile 1 is solid color 1, drawn as `$16`.
+fn ten_sprites_rom() -> Vec<u8> {
+    #[rustfmt::skip]
+    let program = [
+        0x2C, 0x02, 0x20, // vb1: BIT $2002
+        0x10, 0xFB,       // BPL vb1
+        0x2C, 0x02, 0x20, // vb2: BIT $2002
+        0x10, 0xFB,       // BPL vb2
+        0xA9, 0x3F,       // LDA #$3F
+        0x8D, 0x06, 0x20, // STA $2006
+        0xA9, 0x11,       // LDA #$11
+        0x8D, 0x06, 0x20, // STA $2006
+        0xA9, 0x16,       // LDA #$16
+        0x8D, 0x07, 0x20, // STA $2007
+        0xA9, 0x00,       // LDA #0
+        0x8D, 0x03, 0x20, // STA $2003
+        0xA2, 0x00,       // LDX #0
+        0xA9, 0x14,       // sprite: LDA #20
+        0x8D, 0x04, 0x20, // STA $2004 (Y)
+        0xA9, 0x01,       // LDA #1
+        0x8D, 0x04, 0x20, // STA $2004 (tile)
+        0xA9, 0x00,       // LDA #0
+        0x8D, 0x04, 0x20, // STA $2004 (attributes)
+        0x8A,             // TXA
+        0x8D, 0x04, 0x20, // STA $2004 (X)
+        0x18,             // CLC
+        0x69, 0x10,       // ADC #16
+        0xAA,             // TAX
+        0xE0, 0xA0,       // CPX #160
+        0xD0, 0xE5,       // BNE sprite
+        0xA0, 0xD8,       // LDY #216
+        0xA9, 0xFF,       // LDA #$FF
+        0x8D, 0x04, 0x20, // hide: STA $2004
+        0x88,             // DEY
+        0xD0, 0xFA,       // BNE hide
+        0xA9, 0x14,       // LDA #$14
+        0x8D, 0x01, 0x20, // STA $2001
+        0x4C, 0x4A, 0x80, // spin: JMP spin
+    ];
+
+    let mut chr = [0; 24];
+    chr[16..24].fill(0xFF);
+    nrom_image(&program, &chr)
+}
+
+/// Output color of the middle of each sprite's row on scanline 24.
+fn sprite_row_colors(remove_limit: bool) -> Vec<u8> {
+    let cart = cartridge::load_cartridge(ten_sprites_rom()).expect("load cartridge");
+    let mut nes = Nes::new(ColorFormat::Rgba8888);
+    nes.insert_cartridge(cart);
+    nes.set_sprite_limit_removed(remove_limit);
+    for _ in 0..6 {
+        nes.run_frame(false);
+    }
+
+    let mut indices = vec![0; SCREEN_WIDTH * SCREEN_HEIGHT];
+    nes.copy_render_index_buffer(&mut indices);
+    (0..10)
+        .map(|sprite| indices[24 * SCREEN_WIDTH + sprite * 16 + 4] & 0x3F)
+        .collect()
+}
+
+#[test]
+fn hardware_limit_drops_sprites_past_the_eighth() {
+    let colors = sprite_row_colors(false);
+    assert!(colors[..8].iter().all(|&c| c == 0x16), "{colors:02X?}");
+    assert!(colors[8..].iter().all(|&c| c != 0x16), "{colors:02X?}");
+}
+
+#[test]
+fn removed_limit_draws_every_sprite() {
+    let colors = sprite_row_colors(true);
+    assert!(colors.iter().all(|&c| c == 0x16), "{colors:02X?}");
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::nrom_image;
+use nesium_core::{Nes, ppu::buffer::ColorFormat, reset_kind::ResetKind};
+
+/// NROM image that writes once to test-mode, expansion, RAM and ROM space,
+/// then spins.
+fn writer_rom() -> Vec<u8> {
+    #[rustfmt::skip]
+    let program = [
+        0xA9, 0x12,       // LDA #$12
+        0x8D, 0x18, 0x40, // STA $4018
+        0x8D, 0x00, 0x50, // STA $5000
+        0x8D, 0x00, 0x02, // STA $0200
+        0x8D, 0x00, 0x80, // STA $8000
+        0x4C, 0x0E, 0x80, // JMP $800E
+    ];
+    nrom_image(&program, &[])
+}
+
+#[test]
+fn tracks_writes_that_reach_nothing() {
+    let mut nes = Nes::new(ColorFormat::Rgba8888);
+    nes.load_cartridge_from_bytes(&writer_rom())
+        .expect("load cartridge");
+
+    nes.run_frame(false);
+    assert!(
+        nes.unmapped_writes().is_empty(),
+        "tracking is off by default"
+    );
+
+    nes.set_unmapped_write_tracking(true);
+    nes.reset(ResetKind::Soft);
+    nes.run_frame(false);
+    assert_eq!(nes.unmapped_writes(), vec![(0x4018, 1), (0x5000, 1)]);
+
+    nes.set_unmapped_write_tracking(false);
+    assert!(nes.unmapped_writes().is_empty());
+}
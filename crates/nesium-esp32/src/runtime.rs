@@ -16,14 +16,24 @@ use nesium_core::{
 /// Abstraction for a frame output backend (LCD, framebuffer, etc.).
 ///
 /// - One frame is `SCREEN_WIDTH x SCREEN_HEIGHT` pixels;
-/// - Pixel format is RGB565, little-endian, row-major;
+/// - Pixel format is chosen by [`FrameSink::color_format`] (RGB565
+///   little-endian by default), row-major;
 /// - Typical implementations:
 ///   - SPI LCDs (e.g. ILI9341, ST7789) that accept full-frame writes;
 ///   - memory-mapped LCDs where you memcpy into VRAM;
 ///   - GUI libraries (LVGL, etc.) where you blit into an image widget.
 pub trait FrameSink {
-    /// Present a fully rendered RGB565 frame buffer.
-    fn present_frame(&mut self, frame_rgb565_le: &[u8]);
+    /// Output format the core should render in.
+    ///
+    /// SPI panels usually want [`ColorFormat::Rgb565Be`] so the frame can be
+    /// DMA'd without swapping bytes; sinks with their own palette LUT can use
+    /// [`ColorFormat::Index8`].
+    fn color_format(&self) -> ColorFormat {
+        ColorFormat::Rgb565
+    }
+
+    /// Present a fully rendered frame buffer in [`FrameSink::color_format`].
+    fn present_frame(&mut self, frame: &[u8]);
 }
 
 /// Abstraction for an audio output backend.
@@ -70,7 +80,7 @@ impl NullFrameSink {
 }
 
 impl FrameSink for NullFrameSink {
-    fn present_frame(&mut self, _frame_rgb565_le: &[u8]) {
+    fn present_frame(&mut self, _frame: &[u8]) {
         // Intentionally no-op: no display attached.
     }
 }
@@ -161,7 +171,7 @@ where
         // Ask the audio backend for the host sample rate.
         let sample_rate = audio.sample_rate();
         let mut nes = Nes::builder()
-            .format(ColorFormat::Rgb565)
+            .format(display.color_format())
             .sample_rate(sample_rate)
             .build();
 
@@ -197,11 +207,11 @@ where
             self.audio.push_samples(&self.audio_buffer);
         }
 
-        // 3) Fetch the current framebuffer (RGB565) and present it.
+        // 3) Fetch the current framebuffer and present it.
         if let Some(frame) = self.nes.try_render_buffer() {
             debug_assert_eq!(
                 frame.len(),
-                SCREEN_WIDTH * SCREEN_HEIGHT * ColorFormat::Rgb565.bytes_per_pixel()
+                SCREEN_WIDTH * SCREEN_HEIGHT * self.display.color_format().bytes_per_pixel()
             );
             self.display.present_frame(frame);
         }
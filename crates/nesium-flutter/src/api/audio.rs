@@ -0,0 +1,54 @@
+use flutter_rust_bridge::frb;
+
+use nesium_core::audio::{AudioChannel, MixerSettings, StereoPreset as CoreStereoPreset};
+
+// NOTE: FRB cannot directly generate Dart enums for types defined in other crates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StereoPreset {
+    Mono,
+    Light,
+    MesenSpread,
+}
+
+impl From<StereoPreset> for CoreStereoPreset {
+    fn from(value: StereoPreset) -> Self {
+        match value {
+            StereoPreset::Mono => CoreStereoPreset::Mono,
+            StereoPreset::Light => CoreStereoPreset::Light,
+            StereoPreset::MesenSpread => CoreStereoPreset::MesenSpread,
+        }
+    }
+}
+
+/// Expansion chip levels against the 2A03, each in `[-1.0, 1.0]`
+/// (-1 = silent, 0 = default, 1 = twice as loud).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExpansionBalance {
+    pub fds: f32,
+    pub mmc5: f32,
+    pub vrc6: f32,
+    pub vrc7: f32,
+    pub namco163: f32,
+    pub sunsoft5b: f32,
+}
+
+/// Applies a stereo preset and per-chip expansion balance. Channel volumes
+/// are left at full scale.
+#[frb]
+pub fn set_audio_stereo(preset: StereoPreset, balance: ExpansionBalance) -> Result<(), String> {
+    let mut settings = MixerSettings::default();
+    settings.apply_stereo_preset(preset.into());
+    for (channel, value) in [
+        (AudioChannel::Fds, balance.fds),
+        (AudioChannel::Mmc5, balance.mmc5),
+        (AudioChannel::Vrc6, balance.vrc6),
+        (AudioChannel::Vrc7, balance.vrc7),
+        (AudioChannel::Namco163, balance.namco163),
+        (AudioChannel::Sunsoft5B, balance.sunsoft5b),
+    ] {
+        settings.expansion_balance[channel.idx()] = value;
+    }
+    crate::runtime_handle()
+        .set_mixer_settings(settings)
+        .map_err(|e| e.to_string())
+}
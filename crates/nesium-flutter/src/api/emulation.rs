@@ -8,6 +8,15 @@ pub fn set_integer_fps_mode(enabled: bool) -> Result<(), String> {
     Ok(())
 }
 
+/// Emulates a famiclone PPU (no color emphasis, approximate clone palette,
+/// true sprite overflow).
+#[frb]
+pub fn set_clone_ppu(enabled: bool) -> Result<(), String> {
+    crate::runtime_handle()
+        .set_clone_ppu(enabled)
+        .map_err(|e| e.to_string())
+}
+
 #[frb]
 pub fn save_state(path: String) -> Result<(), String> {
     crate::runtime_handle()
@@ -36,6 +45,15 @@ pub async fn load_state_from_memory(data: Vec<u8>) -> Result<(), String> {
         .map_err(|e| e.to_string())
 }
 
+/// Restores the emergency state offered by
+/// `RuntimeNotificationKind::EmergencyStateAvailable`.
+#[frb]
+pub fn load_emergency_state() -> Result<(), String> {
+    crate::runtime_handle()
+        .load_emergency_state()
+        .map_err(|e| e.to_string())
+}
+
 #[frb]
 pub fn set_rewind_config(enabled: bool, capacity: u64) -> Result<(), String> {
     crate::runtime_handle().set_rewind_config(enabled, capacity);
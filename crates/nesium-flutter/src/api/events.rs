@@ -50,6 +50,12 @@ pub async fn aux_texture_ids() -> AuxTextureIds {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RuntimeNotificationKind {
     AudioInitFailed,
+    /// The runtime thread crashed or the app is being killed; battery RAM and
+    /// an emergency state were written. `error` holds the reason.
+    EmergencySaved,
+    /// The game just loaded has an emergency state from a session that did
+    /// not end cleanly; restore it with `load_emergency_state`.
+    EmergencyStateAvailable,
 }
 
 #[derive(Debug, Clone)]
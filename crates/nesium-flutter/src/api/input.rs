@@ -1,4 +1,5 @@
 use flutter_rust_bridge::frb;
+use nesium_core::controller::ZapperCalibration;
 use std::sync::atomic::{AtomicU8, Ordering};
 
 use crate::runtime_handle;
@@ -64,3 +65,58 @@ pub fn set_turbo_timing(on_frames: u8, off_frames: u8) -> Result<(), String> {
     runtime_handle().set_turbo_timing(on_frames, off_frames);
     Ok(())
 }
+
+/// Plugs the Zapper light gun into `port` (0/1), or restores the pad with `None`.
+#[frb]
+pub fn set_zapper_port(port: Option<u8>) -> Result<(), String> {
+    let port = port.map(|p| p as usize);
+    if port.is_some_and(|p| p >= MAX_PORTS) {
+        return Err(format!("invalid Zapper port {port:?}"));
+    }
+    runtime_handle().set_zapper_port(port);
+    Ok(())
+}
+
+/// Aims the Zapper at `(x, y)` in NES pixels (`256x240`). The Dart side maps
+/// pointer/touch positions from the game view into this space.
+#[frb]
+pub fn set_zapper_pointer(x: i32, y: i32) -> Result<(), String> {
+    runtime_handle().set_zapper_pointer(Some((x, y)));
+    Ok(())
+}
+
+/// Aims the Zapper away from the screen (pointer left the view / reload shot).
+#[frb]
+pub fn clear_zapper_pointer() -> Result<(), String> {
+    runtime_handle().set_zapper_pointer(None);
+    Ok(())
+}
+
+/// Pulls (`true`) or releases the Zapper trigger.
+#[frb]
+pub fn set_zapper_trigger(pulled: bool) -> Result<(), String> {
+    runtime_handle().set_zapper_trigger(pulled);
+    Ok(())
+}
+
+/// Shifts the Zapper aim by `(offset_x, offset_y)` NES pixels to line the
+/// pointer up with the picture; `(0, 0)` disables calibration.
+#[frb]
+pub fn set_zapper_calibration(offset_x: i32, offset_y: i32) -> Result<(), String> {
+    runtime_handle().set_zapper_calibration(ZapperCalibration { offset_x, offset_y });
+    Ok(())
+}
+
+/// Shows or hides the crosshair the core draws at the Zapper aim point.
+#[frb]
+pub fn set_zapper_crosshair(visible: bool) -> Result<(), String> {
+    runtime_handle().set_zapper_crosshair(visible);
+    Ok(())
+}
+
+/// Rolls the Zapper trigger back by `frames` to offset display lag (0 disables).
+#[frb]
+pub fn set_zapper_lag_compensation(frames: u8) -> Result<(), String> {
+    runtime_handle().set_zapper_lag_compensation(frames);
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! Runtime instances by id.
+//!
+//! Instance 0 is the default runtime every other API talks to. Extra
+//! instances (comparison views, spectating several netplay rooms) are started
+//! here and driven with the `instance_*` functions, which accept the default
+//! instance as well.
+
+use std::path::PathBuf;
+
+use flutter_rust_bridge::frb;
+use nesium_core::reset_kind::ResetKind;
+use nesium_runtime::{AudioMode, RuntimeHandle};
+
+fn handle(instance: u32) -> Result<RuntimeHandle, String> {
+    crate::instance_handle(instance).ok_or_else(|| format!("no runtime instance {instance}"))
+}
+
+/// Starts a runtime next to the default one and returns its instance id.
+/// Pass `audio = false` for instances that should stay silent.
+#[frb]
+pub fn start_instance(audio: bool) -> Result<u32, String> {
+    let mode = if audio {
+        AudioMode::Auto
+    } else {
+        AudioMode::Disabled
+    };
+    crate::start_instance(mode).map_err(|e| e.to_string())
+}
+
+/// Stops an instance started with [`start_instance`]. The default instance
+/// cannot be stopped.
+#[frb]
+pub fn stop_instance(instance: u32) -> Result<(), String> {
+    if crate::stop_instance(instance) {
+        Ok(())
+    } else {
+        Err(format!("cannot stop runtime instance {instance}"))
+    }
+}
+
+#[frb]
+pub fn instance_load_rom(instance: u32, path: String) -> Result<(), String> {
+    handle(instance)?
+        .load_rom(PathBuf::from(path))
+        .map_err(|e| e.to_string())
+}
+
+#[frb]
+pub fn instance_load_rom_from_bytes(instance: u32, bytes: Vec<u8>) -> Result<(), String> {
+    handle(instance)?
+        .load_rom_from_memory(bytes)
+        .map_err(|e| e.to_string())
+}
+
+#[frb]
+pub fn instance_reset_console(instance: u32) -> Result<(), String> {
+    handle(instance)?
+        .reset(ResetKind::Soft)
+        .map_err(|e| e.to_string())
+}
+
+#[frb]
+pub fn instance_set_paused(instance: u32, paused: bool) -> Result<(), String> {
+    handle(instance)?.set_paused(paused);
+    Ok(())
+}
+
+/// Sets the pressed buttons of `pad` directly, bypassing the keyboard and
+/// gamepad state tracked for the default instance.
+#[frb]
+pub fn instance_set_pad_mask(instance: u32, pad: u8, mask: u8) -> Result<(), String> {
+    handle(instance)?.set_pad_mask(pad as usize, mask);
+    Ok(())
+}
+
+#[frb]
+pub async fn instance_save_state_to_memory(instance: u32) -> Result<Vec<u8>, String> {
+    handle(instance)?
+        .save_state_to_memory()
+        .map_err(|e| e.to_string())
+}
+
+#[frb]
+pub async fn instance_load_state_from_memory(instance: u32, data: Vec<u8>) -> Result<(), String> {
+    handle(instance)?
+        .load_state_from_memory(data)
+        .map_err(|e| e.to_string())
+}
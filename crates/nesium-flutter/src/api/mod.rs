@@ -1,7 +1,9 @@
+pub mod audio;
 pub mod emulation;
 pub mod events;
 pub mod gamepad;
 pub mod input;
+pub mod instance;
 pub mod load_rom;
 pub mod net_utils;
 pub mod netplay;
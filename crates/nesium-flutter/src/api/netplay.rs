@@ -11,12 +11,15 @@ use nesium_netplay::{
     SharedInputProvider,
 };
 use nesium_netproto::codec_tcp::{encode_tcp_frame, try_decode_tcp_frames};
+use nesium_netproto::constants::{PING_INTERVAL_MS, STATE_HASH_INTERVAL_FRAMES};
 use nesium_netproto::header::Header;
+use nesium_netproto::messages::chat::ChatBody;
+use nesium_netproto::messages::lobby::{RoomFilter, RoomInfo, RoomSummary};
 use nesium_netproto::messages::session::P2PFallbackNotice;
 use nesium_netproto::messages::session::{
     ErrorMsg, P2PCreateRoom, P2PJoinAck, P2PJoinRoom, P2PRoomCreated, Welcome,
 };
-use nesium_netproto::messages::session::{Hello, TransportKind};
+use nesium_netproto::messages::session::{Hello, RomHash, TransportKind, UserIdentity, UserToken};
 use nesium_netproto::msg_id::MsgId;
 use parking_lot::Mutex;
 use std::net::SocketAddr;
@@ -55,6 +58,7 @@ pub enum NetplayTransport {
     Unknown,
     Tcp,
     Quic,
+    WebRtc,
 }
 
 /// Netplay status snapshot streamed to Flutter.
@@ -111,6 +115,67 @@ pub enum NetplayGameEvent {
         relay_room_code: u32,
         reason: String,
     },
+    /// State hashes diverged from another room member at `frame`.
+    Desync {
+        frame: u32,
+    },
+    /// Chat message from a room member; exactly one of `text`/`emote` is set.
+    Chat {
+        client_id: u32,
+        name: String,
+        text: Option<String>,
+        emote: Option<u8>,
+    },
+    /// Link quality to the server (peer id 0) and each room member.
+    NetworkStats {
+        peers: Vec<NetplayPeerStats>,
+    },
+    /// Recommended lockstep input delay changed (already applied).
+    InputDelayRecommended {
+        frames: u32,
+    },
+    /// Public rooms, in answer to `netplay_list_rooms`.
+    RoomList {
+        rooms: Vec<NetplayRoomSummary>,
+    },
+    /// Spectators watching the room and their delay behind live, in frames.
+    SpectatorInfo {
+        count: u16,
+        delay_frames: u32,
+    },
+    /// Persistent identity issued by the server; store `user_token` and hand
+    /// it back through `netplay_set_user_token` before connecting again.
+    Identity {
+        user_id: u64,
+        user_token: Vec<u8>,
+    },
+    /// The server is shutting down and drops the connection after `grace_ms`.
+    ServerClosing {
+        grace_ms: u32,
+    },
+}
+
+#[frb]
+#[derive(Debug, Clone)]
+pub struct NetplayPeerStats {
+    pub peer_client_id: u32,
+    pub rtt_ms: u32,
+    pub jitter_ms: u32,
+    pub loss_percent: u8,
+}
+
+#[frb]
+#[derive(Debug, Clone)]
+pub struct NetplayRoomSummary {
+    pub room_code: u32,
+    pub name: String,
+    pub region: String,
+    /// Cartridge CRC32, 0 if the host hasn't picked a game.
+    pub game_hash: u32,
+    pub player_count: u8,
+    pub spectator_count: u8,
+    pub open_slots: u8,
+    pub started: bool,
 }
 
 #[frb]
@@ -157,6 +222,11 @@ async fn start_netplay_session_with_client(
         transport,
         spectator: false,
         room_code,
+        state_hash_interval: STATE_HASH_INTERVAL_FRAMES,
+        auto_resync: false,
+        ping_interval_ms: PING_INTERVAL_MS,
+        auto_input_delay: true,
+        user_token: *lock_unpoison(&mgr.user_token),
     };
 
     let (mut handler, cmd_tx) = SessionHandler::new(
@@ -205,6 +275,32 @@ async fn start_netplay_session_with_client(
                         relay_room_code,
                         reason,
                     },
+                    nesium_netplay::NetplayEvent::Desync { frame } => {
+                        NetplayGameEvent::Desync { frame }
+                    }
+                    nesium_netplay::NetplayEvent::Chat {
+                        client_id,
+                        name,
+                        body,
+                    } => chat_event(client_id, name, body),
+                    nesium_netplay::NetplayEvent::NetworkStats { peers } => {
+                        network_stats_event(peers)
+                    }
+                    nesium_netplay::NetplayEvent::InputDelayRecommended { frames } => {
+                        NetplayGameEvent::InputDelayRecommended { frames }
+                    }
+                    nesium_netplay::NetplayEvent::RoomList { rooms } => room_list_event(rooms),
+                    nesium_netplay::NetplayEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    } => NetplayGameEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    },
+                    nesium_netplay::NetplayEvent::Identity(identity) => identity_event(identity),
+                    nesium_netplay::NetplayEvent::ServerClosing { grace_ms } => {
+                        NetplayGameEvent::ServerClosing { grace_ms }
+                    }
                 };
                 let _ = sink.add(frb_event);
             }
@@ -242,6 +338,8 @@ pub struct NetplayManager {
     pub game_event_sink: Arc<Mutex<Option<StreamSink<NetplayGameEvent>>>>,
     pub polling_task: Mutex<Option<tokio::task::JoinHandle<()>>>,
     pub p2p_watch_task: Mutex<Option<tokio::task::JoinHandle<()>>>,
+    /// Identity token sent in Hello; see `netplay_set_user_token`.
+    pub user_token: Mutex<Option<UserToken>>,
 }
 
 static MANAGER: OnceLock<NetplayManager> = OnceLock::new();
@@ -258,6 +356,7 @@ pub fn get_manager() -> &'static NetplayManager {
             game_event_sink: Arc::new(Mutex::new(None)),
             polling_task: Mutex::new(None),
             p2p_watch_task: Mutex::new(None),
+            user_token: Mutex::new(None),
         }
     })
 }
@@ -291,6 +390,11 @@ pub async fn netplay_connect(server_addr: String, player_name: String) -> Result
         transport: nesium_netproto::messages::session::TransportKind::Tcp,
         spectator: false,
         room_code: 0,
+        state_hash_interval: STATE_HASH_INTERVAL_FRAMES,
+        auto_resync: false,
+        ping_interval_ms: PING_INTERVAL_MS,
+        auto_input_delay: true,
+        user_token: *lock_unpoison(&mgr.user_token),
     };
 
     let (mut handler, cmd_tx) = SessionHandler::new(
@@ -340,6 +444,32 @@ pub async fn netplay_connect(server_addr: String, player_name: String) -> Result
                         relay_room_code,
                         reason,
                     },
+                    nesium_netplay::NetplayEvent::Desync { frame } => {
+                        NetplayGameEvent::Desync { frame }
+                    }
+                    nesium_netplay::NetplayEvent::Chat {
+                        client_id,
+                        name,
+                        body,
+                    } => chat_event(client_id, name, body),
+                    nesium_netplay::NetplayEvent::NetworkStats { peers } => {
+                        network_stats_event(peers)
+                    }
+                    nesium_netplay::NetplayEvent::InputDelayRecommended { frames } => {
+                        NetplayGameEvent::InputDelayRecommended { frames }
+                    }
+                    nesium_netplay::NetplayEvent::RoomList { rooms } => room_list_event(rooms),
+                    nesium_netplay::NetplayEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    } => NetplayGameEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    },
+                    nesium_netplay::NetplayEvent::Identity(identity) => identity_event(identity),
+                    nesium_netplay::NetplayEvent::ServerClosing { grace_ms } => {
+                        NetplayGameEvent::ServerClosing { grace_ms }
+                    }
                 };
                 let _ = sink.add(frb_event);
             }
@@ -401,6 +531,11 @@ pub async fn netplay_connect_auto(
         transport: chosen_transport,
         spectator: false,
         room_code: 0,
+        state_hash_interval: STATE_HASH_INTERVAL_FRAMES,
+        auto_resync: false,
+        ping_interval_ms: PING_INTERVAL_MS,
+        auto_input_delay: true,
+        user_token: *lock_unpoison(&mgr.user_token),
     };
 
     let (mut handler, cmd_tx) = SessionHandler::new(
@@ -449,6 +584,32 @@ pub async fn netplay_connect_auto(
                         relay_room_code,
                         reason,
                     },
+                    nesium_netplay::NetplayEvent::Desync { frame } => {
+                        NetplayGameEvent::Desync { frame }
+                    }
+                    nesium_netplay::NetplayEvent::Chat {
+                        client_id,
+                        name,
+                        body,
+                    } => chat_event(client_id, name, body),
+                    nesium_netplay::NetplayEvent::NetworkStats { peers } => {
+                        network_stats_event(peers)
+                    }
+                    nesium_netplay::NetplayEvent::InputDelayRecommended { frames } => {
+                        NetplayGameEvent::InputDelayRecommended { frames }
+                    }
+                    nesium_netplay::NetplayEvent::RoomList { rooms } => room_list_event(rooms),
+                    nesium_netplay::NetplayEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    } => NetplayGameEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    },
+                    nesium_netplay::NetplayEvent::Identity(identity) => identity_event(identity),
+                    nesium_netplay::NetplayEvent::ServerClosing { grace_ms } => {
+                        NetplayGameEvent::ServerClosing { grace_ms }
+                    }
                 };
                 let _ = sink.add(frb_event);
             }
@@ -513,6 +674,11 @@ pub async fn netplay_connect_auto_pinned(
         transport: chosen_transport,
         spectator: false,
         room_code: 0,
+        state_hash_interval: STATE_HASH_INTERVAL_FRAMES,
+        auto_resync: false,
+        ping_interval_ms: PING_INTERVAL_MS,
+        auto_input_delay: true,
+        user_token: *lock_unpoison(&mgr.user_token),
     };
 
     let (mut handler, cmd_tx) = SessionHandler::new(
@@ -561,6 +727,32 @@ pub async fn netplay_connect_auto_pinned(
                         relay_room_code,
                         reason,
                     },
+                    nesium_netplay::NetplayEvent::Desync { frame } => {
+                        NetplayGameEvent::Desync { frame }
+                    }
+                    nesium_netplay::NetplayEvent::Chat {
+                        client_id,
+                        name,
+                        body,
+                    } => chat_event(client_id, name, body),
+                    nesium_netplay::NetplayEvent::NetworkStats { peers } => {
+                        network_stats_event(peers)
+                    }
+                    nesium_netplay::NetplayEvent::InputDelayRecommended { frames } => {
+                        NetplayGameEvent::InputDelayRecommended { frames }
+                    }
+                    nesium_netplay::NetplayEvent::RoomList { rooms } => room_list_event(rooms),
+                    nesium_netplay::NetplayEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    } => NetplayGameEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    },
+                    nesium_netplay::NetplayEvent::Identity(identity) => identity_event(identity),
+                    nesium_netplay::NetplayEvent::ServerClosing { grace_ms } => {
+                        NetplayGameEvent::ServerClosing { grace_ms }
+                    }
                 };
                 let _ = sink.add(frb_event);
             }
@@ -618,6 +810,11 @@ pub async fn netplay_connect_quic(
         transport: nesium_netproto::messages::session::TransportKind::Quic,
         spectator: false,
         room_code: 0,
+        state_hash_interval: STATE_HASH_INTERVAL_FRAMES,
+        auto_resync: false,
+        ping_interval_ms: PING_INTERVAL_MS,
+        auto_input_delay: true,
+        user_token: *lock_unpoison(&mgr.user_token),
     };
 
     let (mut handler, cmd_tx) = SessionHandler::new(
@@ -666,6 +863,32 @@ pub async fn netplay_connect_quic(
                         relay_room_code,
                         reason,
                     },
+                    nesium_netplay::NetplayEvent::Desync { frame } => {
+                        NetplayGameEvent::Desync { frame }
+                    }
+                    nesium_netplay::NetplayEvent::Chat {
+                        client_id,
+                        name,
+                        body,
+                    } => chat_event(client_id, name, body),
+                    nesium_netplay::NetplayEvent::NetworkStats { peers } => {
+                        network_stats_event(peers)
+                    }
+                    nesium_netplay::NetplayEvent::InputDelayRecommended { frames } => {
+                        NetplayGameEvent::InputDelayRecommended { frames }
+                    }
+                    nesium_netplay::NetplayEvent::RoomList { rooms } => room_list_event(rooms),
+                    nesium_netplay::NetplayEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    } => NetplayGameEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    },
+                    nesium_netplay::NetplayEvent::Identity(identity) => identity_event(identity),
+                    nesium_netplay::NetplayEvent::ServerClosing { grace_ms } => {
+                        NetplayGameEvent::ServerClosing { grace_ms }
+                    }
                 };
                 let _ = sink.add(frb_event);
             }
@@ -729,6 +952,289 @@ pub async fn netplay_connect_quic_pinned(
         transport: nesium_netproto::messages::session::TransportKind::Quic,
         spectator: false,
         room_code: 0,
+        state_hash_interval: STATE_HASH_INTERVAL_FRAMES,
+        auto_resync: false,
+        ping_interval_ms: PING_INTERVAL_MS,
+        auto_input_delay: true,
+        user_token: *lock_unpoison(&mgr.user_token),
+    };
+
+    let (mut handler, cmd_tx) = SessionHandler::new(
+        client,
+        config,
+        mgr.input_provider.clone(),
+        event_rx,
+        game_event_tx,
+    );
+
+    *lock_unpoison(&mgr.command_tx) = Some(cmd_tx);
+
+    let status_sink = mgr.status_sink.clone();
+    let game_event_sink = mgr.game_event_sink.clone();
+    let input_provider = mgr.input_provider.clone();
+
+    tokio::spawn(async move {
+        while let Some(event) = game_event_rx.recv().await {
+            if let Some(sink) = lock_unpoison(&game_event_sink).as_ref() {
+                let frb_event = match event {
+                    nesium_netplay::NetplayEvent::LoadRom(data) => {
+                        NetplayGameEvent::LoadRom { data }
+                    }
+                    nesium_netplay::NetplayEvent::StartGame => NetplayGameEvent::StartGame,
+                    nesium_netplay::NetplayEvent::PauseSync { paused } => {
+                        NetplayGameEvent::PauseSync { paused }
+                    }
+                    nesium_netplay::NetplayEvent::ResetSync(kind) => {
+                        NetplayGameEvent::ResetSync { kind }
+                    }
+                    nesium_netplay::NetplayEvent::SyncState(frame, data) => {
+                        NetplayGameEvent::SyncState { frame, data }
+                    }
+                    nesium_netplay::NetplayEvent::PlayerLeft { player_index } => {
+                        NetplayGameEvent::PlayerLeft { player_index }
+                    }
+                    nesium_netplay::NetplayEvent::Error { code } => NetplayGameEvent::Error {
+                        error_code: code as u16,
+                    },
+                    nesium_netplay::NetplayEvent::FallbackToRelay {
+                        relay_addr,
+                        relay_room_code,
+                        reason,
+                    } => NetplayGameEvent::FallbackToRelay {
+                        relay_addr: relay_addr.to_string(),
+                        relay_room_code,
+                        reason,
+                    },
+                    nesium_netplay::NetplayEvent::Desync { frame } => {
+                        NetplayGameEvent::Desync { frame }
+                    }
+                    nesium_netplay::NetplayEvent::Chat {
+                        client_id,
+                        name,
+                        body,
+                    } => chat_event(client_id, name, body),
+                    nesium_netplay::NetplayEvent::NetworkStats { peers } => {
+                        network_stats_event(peers)
+                    }
+                    nesium_netplay::NetplayEvent::InputDelayRecommended { frames } => {
+                        NetplayGameEvent::InputDelayRecommended { frames }
+                    }
+                    nesium_netplay::NetplayEvent::RoomList { rooms } => room_list_event(rooms),
+                    nesium_netplay::NetplayEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    } => NetplayGameEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    },
+                    nesium_netplay::NetplayEvent::Identity(identity) => identity_event(identity),
+                    nesium_netplay::NetplayEvent::ServerClosing { grace_ms } => {
+                        NetplayGameEvent::ServerClosing { grace_ms }
+                    }
+                };
+                let _ = sink.add(frb_event);
+            }
+        }
+    });
+
+    let task = tokio::spawn(async move {
+        notify_status(&status_sink, &input_provider, None);
+
+        if let Err(e) = handler.run().await {
+            let err_msg = e.to_string();
+            notify_status(&status_sink, &input_provider, Some(err_msg.clone()));
+            return Err(err_msg);
+        }
+
+        notify_status(&status_sink, &input_provider, None);
+        Ok(())
+    });
+
+    *lock_unpoison(&mgr.session_task) = Some(task);
+
+    crate::runtime_handle()
+        .enable_netplay(mgr.input_provider.clone())
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Connect to netplay server over TLS (platform root store) and perform handshake.
+#[frb]
+pub async fn netplay_connect_tls(
+    server_addr: String,
+    server_name: String,
+    player_name: String,
+) -> Result<(), String> {
+    let mgr = get_manager();
+
+    let _ = netplay_disconnect().await;
+
+    let addr = resolve_addr(&server_addr).await?;
+
+    let (event_tx, event_rx) = mpsc::channel(256);
+    let (game_event_tx, mut game_event_rx) = mpsc::channel(32);
+
+    let client = nesium_netplay::connect_tls(addr, &server_name, event_tx)
+        .await
+        .map_err(|e| format!("Failed to connect (tls): {}", e))?;
+
+    mgr.input_provider.with_session(|s| {
+        s.tcp_fallback_from_quic = false;
+    });
+
+    let config = NetplayConfig {
+        name: player_name,
+        transport: nesium_netproto::messages::session::TransportKind::Tcp,
+        spectator: false,
+        room_code: 0,
+        state_hash_interval: STATE_HASH_INTERVAL_FRAMES,
+        auto_resync: false,
+        ping_interval_ms: PING_INTERVAL_MS,
+        auto_input_delay: true,
+        user_token: *lock_unpoison(&mgr.user_token),
+    };
+
+    let (mut handler, cmd_tx) = SessionHandler::new(
+        client,
+        config,
+        mgr.input_provider.clone(),
+        event_rx,
+        game_event_tx,
+    );
+
+    *lock_unpoison(&mgr.command_tx) = Some(cmd_tx);
+
+    let status_sink = mgr.status_sink.clone();
+    let game_event_sink = mgr.game_event_sink.clone();
+    let input_provider = mgr.input_provider.clone();
+
+    tokio::spawn(async move {
+        while let Some(event) = game_event_rx.recv().await {
+            if let Some(sink) = lock_unpoison(&game_event_sink).as_ref() {
+                let frb_event = match event {
+                    nesium_netplay::NetplayEvent::LoadRom(data) => {
+                        NetplayGameEvent::LoadRom { data }
+                    }
+                    nesium_netplay::NetplayEvent::StartGame => NetplayGameEvent::StartGame,
+                    nesium_netplay::NetplayEvent::PauseSync { paused } => {
+                        NetplayGameEvent::PauseSync { paused }
+                    }
+                    nesium_netplay::NetplayEvent::ResetSync(kind) => {
+                        NetplayGameEvent::ResetSync { kind }
+                    }
+                    nesium_netplay::NetplayEvent::SyncState(frame, data) => {
+                        NetplayGameEvent::SyncState { frame, data }
+                    }
+                    nesium_netplay::NetplayEvent::PlayerLeft { player_index } => {
+                        NetplayGameEvent::PlayerLeft { player_index }
+                    }
+                    nesium_netplay::NetplayEvent::Error { code } => NetplayGameEvent::Error {
+                        error_code: code as u16,
+                    },
+                    nesium_netplay::NetplayEvent::FallbackToRelay {
+                        relay_addr,
+                        relay_room_code,
+                        reason,
+                    } => NetplayGameEvent::FallbackToRelay {
+                        relay_addr: relay_addr.to_string(),
+                        relay_room_code,
+                        reason,
+                    },
+                    nesium_netplay::NetplayEvent::Desync { frame } => {
+                        NetplayGameEvent::Desync { frame }
+                    }
+                    nesium_netplay::NetplayEvent::Chat {
+                        client_id,
+                        name,
+                        body,
+                    } => chat_event(client_id, name, body),
+                    nesium_netplay::NetplayEvent::NetworkStats { peers } => {
+                        network_stats_event(peers)
+                    }
+                    nesium_netplay::NetplayEvent::InputDelayRecommended { frames } => {
+                        NetplayGameEvent::InputDelayRecommended { frames }
+                    }
+                    nesium_netplay::NetplayEvent::RoomList { rooms } => room_list_event(rooms),
+                    nesium_netplay::NetplayEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    } => NetplayGameEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    },
+                    nesium_netplay::NetplayEvent::Identity(identity) => identity_event(identity),
+                    nesium_netplay::NetplayEvent::ServerClosing { grace_ms } => {
+                        NetplayGameEvent::ServerClosing { grace_ms }
+                    }
+                };
+                let _ = sink.add(frb_event);
+            }
+        }
+    });
+
+    let task = tokio::spawn(async move {
+        notify_status(&status_sink, &input_provider, None);
+
+        if let Err(e) = handler.run().await {
+            let err_msg = e.to_string();
+            notify_status(&status_sink, &input_provider, Some(err_msg.clone()));
+            return Err(err_msg);
+        }
+
+        notify_status(&status_sink, &input_provider, None);
+        Ok(())
+    });
+
+    *lock_unpoison(&mgr.session_task) = Some(task);
+
+    crate::runtime_handle()
+        .enable_netplay(mgr.input_provider.clone())
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Connect to netplay server over TLS (pinned SHA-256 fingerprint) and perform handshake.
+#[frb]
+pub async fn netplay_connect_tls_pinned(
+    server_addr: String,
+    server_name: String,
+    pinned_sha256_fingerprint: String,
+    player_name: String,
+) -> Result<(), String> {
+    let mgr = get_manager();
+
+    let _ = netplay_disconnect().await;
+
+    let addr = resolve_addr(&server_addr).await?;
+
+    let (event_tx, event_rx) = mpsc::channel(256);
+    let (game_event_tx, mut game_event_rx) = mpsc::channel(32);
+
+    let client = nesium_netplay::connect_tls_pinned(
+        addr,
+        &server_name,
+        &pinned_sha256_fingerprint,
+        event_tx,
+    )
+    .await
+    .map_err(|e| format!("Failed to connect (tls pinned): {}", e))?;
+
+    mgr.input_provider.with_session(|s| {
+        s.tcp_fallback_from_quic = false;
+    });
+
+    let config = NetplayConfig {
+        name: player_name,
+        transport: nesium_netproto::messages::session::TransportKind::Tcp,
+        spectator: false,
+        room_code: 0,
+        state_hash_interval: STATE_HASH_INTERVAL_FRAMES,
+        auto_resync: false,
+        ping_interval_ms: PING_INTERVAL_MS,
+        auto_input_delay: true,
+        user_token: *lock_unpoison(&mgr.user_token),
     };
 
     let (mut handler, cmd_tx) = SessionHandler::new(
@@ -777,6 +1283,32 @@ pub async fn netplay_connect_quic_pinned(
                         relay_room_code,
                         reason,
                     },
+                    nesium_netplay::NetplayEvent::Desync { frame } => {
+                        NetplayGameEvent::Desync { frame }
+                    }
+                    nesium_netplay::NetplayEvent::Chat {
+                        client_id,
+                        name,
+                        body,
+                    } => chat_event(client_id, name, body),
+                    nesium_netplay::NetplayEvent::NetworkStats { peers } => {
+                        network_stats_event(peers)
+                    }
+                    nesium_netplay::NetplayEvent::InputDelayRecommended { frames } => {
+                        NetplayGameEvent::InputDelayRecommended { frames }
+                    }
+                    nesium_netplay::NetplayEvent::RoomList { rooms } => room_list_event(rooms),
+                    nesium_netplay::NetplayEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    } => NetplayGameEvent::SpectatorInfo {
+                        count,
+                        delay_frames,
+                    },
+                    nesium_netplay::NetplayEvent::Identity(identity) => identity_event(identity),
+                    nesium_netplay::NetplayEvent::ServerClosing { grace_ms } => {
+                        NetplayGameEvent::ServerClosing { grace_ms }
+                    }
                 };
                 let _ = sink.add(frb_event);
             }
@@ -805,13 +1337,13 @@ pub async fn netplay_connect_quic_pinned(
     Ok(())
 }
 
-/// Create a new netplay room.
+/// Create a new netplay room. A non-empty `password` makes it private.
 #[frb]
-pub async fn netplay_create_room() -> Result<(), String> {
+pub async fn netplay_create_room(password: Option<String>) -> Result<(), String> {
     let mgr = get_manager();
     let tx = lock_unpoison(&mgr.command_tx).clone();
     if let Some(tx) = tx {
-        tx.send(NetplayCommand::CreateRoom)
+        tx.send(NetplayCommand::CreateRoom(password))
             .await
             .map_err(|e| format!("Failed to send command: {}", e))?;
         Ok(())
@@ -820,13 +1352,13 @@ pub async fn netplay_create_room() -> Result<(), String> {
     }
 }
 
-/// Join an existing netplay room by code.
+/// Join an existing netplay room by code, with its password if private.
 #[frb]
-pub async fn netplay_join_room(room_code: u32) -> Result<(), String> {
+pub async fn netplay_join_room(room_code: u32, password: Option<String>) -> Result<(), String> {
     let mgr = get_manager();
     let tx = lock_unpoison(&mgr.command_tx).clone();
     if let Some(tx) = tx {
-        tx.send(NetplayCommand::JoinRoom(room_code))
+        tx.send(NetplayCommand::JoinRoom(room_code, password))
             .await
             .map_err(|e| format!("Failed to send command: {}", e))?;
         Ok(())
@@ -965,9 +1497,11 @@ async fn signaling_connect_and_handshake(
     let hello = Hello {
         client_nonce: 0,
         transport: TransportKind::Tcp,
-        proto_min: nesium_netproto::constants::VERSION,
-        proto_max: nesium_netproto::constants::VERSION,
+        proto_min: nesium_netproto::constants::MIN_PROTOCOL_VERSION,
+        proto_max: nesium_netproto::constants::PROTOCOL_VERSION,
         name: name.to_string(),
+        capabilities: nesium_netproto::messages::session::capability::SUPPORTED,
+        user_token: *lock_unpoison(&get_manager().user_token),
     };
     let h = Header::new(MsgId::Hello as u8);
     let frame = encode_tcp_frame(h, MsgId::Hello, &hello, 4096)
@@ -997,6 +1531,13 @@ async fn signaling_connect_and_handshake(
         let (packets, consumed) =
             try_decode_tcp_frames(&buf).map_err(|e| format!("Protocol decode error: {e}"))?;
 
+        // e.g. `IncompatibleVersion` when the server speaks another protocol version.
+        if let Some(pkt) = packets.iter().find(|p| p.msg_id == MsgId::Error) {
+            let msg: ErrorMsg =
+                postcard::from_bytes(pkt.payload).map_err(|e| format!("Bad error msg: {e}"))?;
+            return Err(format!("Signaling server error: {:?}", msg.code));
+        }
+
         if let Some(pkt) = packets.iter().find(|p| p.msg_id == MsgId::Welcome) {
             let _: Welcome =
                 postcard::from_bytes(pkt.payload).map_err(|e| format!("Bad Welcome: {e}"))?;
@@ -1458,7 +1999,7 @@ pub async fn netplay_p2p_connect_join_auto(
             Ok((client, transport)) => {
                 let tcp_fallback_from_quic = match transport {
                     TransportKind::Tcp => ack.host_quic_server_name.is_some(),
-                    TransportKind::Quic => false,
+                    TransportKind::Quic | TransportKind::WebRtc => false,
                 };
                 start_netplay_session_with_client(
                     client,
@@ -1553,7 +2094,7 @@ pub async fn netplay_send_rom(data: Vec<u8>) -> Result<(), String> {
 
     let tx = lock_unpoison(&mgr.command_tx).clone();
     if let Some(tx) = tx {
-        tx.send(NetplayCommand::SendRom(data))
+        tx.send(NetplayCommand::SendRom(data, loaded_rom_hash()))
             .await
             .map_err(|e| format!("Failed to send command: {}", e))?;
         Ok(())
@@ -1562,13 +2103,23 @@ pub async fn netplay_send_rom(data: Vec<u8>) -> Result<(), String> {
     }
 }
 
+/// Identity of the ROM currently loaded in the emulator, sent along with
+/// `LoadRom`/`RomLoaded` so the server can refuse mismatched revisions.
+fn loaded_rom_hash() -> Option<RomHash> {
+    let info = crate::runtime_handle().cartridge_info()?;
+    Some(RomHash {
+        crc32: info.crc32,
+        sha1: info.sha1,
+    })
+}
+
 /// Confirm ROM loaded to server.
 #[frb]
 pub async fn netplay_send_rom_loaded() -> Result<(), String> {
     let mgr = get_manager();
     let tx = lock_unpoison(&mgr.command_tx).clone();
     if let Some(tx) = tx {
-        tx.send(NetplayCommand::RomLoaded)
+        tx.send(NetplayCommand::RomLoaded(loaded_rom_hash()))
             .await
             .map_err(|e| format!("Failed to send command: {}", e))?;
         Ok(())
@@ -1592,6 +2143,143 @@ pub async fn netplay_send_pause(paused: bool) -> Result<(), String> {
     }
 }
 
+/// Send a chat message to the room.
+#[frb]
+pub async fn netplay_send_chat(text: String) -> Result<(), String> {
+    send_chat(ChatBody::Text(text)).await
+}
+
+/// Send a quick emote to the room.
+#[frb]
+pub async fn netplay_send_emote(emote: u8) -> Result<(), String> {
+    send_chat(ChatBody::Emote(emote)).await
+}
+
+async fn send_chat(body: ChatBody) -> Result<(), String> {
+    send_command(NetplayCommand::SendChat(body)).await
+}
+
+/// Ask the server for public rooms; the answer arrives as a `RoomList` event.
+/// `None` filters match any game or region.
+#[frb]
+pub async fn netplay_list_rooms(
+    game_hash: Option<u32>,
+    region: Option<String>,
+) -> Result<(), String> {
+    send_command(NetplayCommand::ListRooms(RoomFilter { game_hash, region })).await
+}
+
+/// Join a matching public room with a free player slot, or create one.
+#[frb]
+pub async fn netplay_quick_match(
+    game_hash: Option<u32>,
+    region: Option<String>,
+) -> Result<(), String> {
+    send_command(NetplayCommand::QuickMatch(RoomFilter { game_hash, region })).await
+}
+
+/// Host-only: describe the current room in the lobby.
+#[frb]
+pub async fn netplay_set_room_info(
+    name: String,
+    region: String,
+    game_hash: u32,
+) -> Result<(), String> {
+    let info = RoomInfo {
+        name,
+        region,
+        game_hash,
+    };
+    send_command(NetplayCommand::SetRoomInfo(info)).await
+}
+
+/// Host-only: let spectators watch `frames` behind live (e.g. 180 for three
+/// seconds), 0 for live.
+#[frb]
+pub async fn netplay_set_spectator_delay(frames: u32) -> Result<(), String> {
+    send_command(NetplayCommand::SetSpectatorDelay(frames)).await
+}
+
+/// Set the identity token sent on future connections, e.g. one persisted
+/// from an earlier `NetplayGameEvent::Identity`. `None` connects anonymously
+/// and gets a new identity from servers that keep them.
+#[frb]
+pub fn netplay_set_user_token(token: Option<Vec<u8>>) -> Result<(), String> {
+    let token = token
+        .map(|t| UserToken::try_from(t.as_slice()))
+        .transpose()
+        .map_err(|_| "User token must be 16 bytes".to_string())?;
+    *lock_unpoison(&get_manager().user_token) = token;
+    Ok(())
+}
+
+async fn send_command(cmd: NetplayCommand) -> Result<(), String> {
+    let mgr = get_manager();
+    let tx = lock_unpoison(&mgr.command_tx).clone();
+    if let Some(tx) = tx {
+        tx.send(cmd)
+            .await
+            .map_err(|e| format!("Failed to send command: {}", e))?;
+        Ok(())
+    } else {
+        Err("Not connected".to_string())
+    }
+}
+
+fn network_stats_event(peers: Vec<nesium_netplay::PeerNetworkStats>) -> NetplayGameEvent {
+    NetplayGameEvent::NetworkStats {
+        peers: peers
+            .into_iter()
+            .map(|p| NetplayPeerStats {
+                peer_client_id: p.peer_client_id,
+                rtt_ms: p.rtt_ms,
+                jitter_ms: p.jitter_ms,
+                loss_percent: p.loss_percent,
+            })
+            .collect(),
+    }
+}
+
+fn room_list_event(rooms: Vec<RoomSummary>) -> NetplayGameEvent {
+    NetplayGameEvent::RoomList {
+        rooms: rooms
+            .into_iter()
+            .map(|r| NetplayRoomSummary {
+                room_code: r.room_code,
+                name: r.info.name,
+                region: r.info.region,
+                game_hash: r.info.game_hash,
+                player_count: r.player_count,
+                spectator_count: r.spectator_count,
+                open_slots: r.open_slots,
+                started: r.started,
+            })
+            .collect(),
+    }
+}
+
+fn identity_event(identity: UserIdentity) -> NetplayGameEvent {
+    // Later connections in this process reuse it without a round-trip to Dart.
+    *lock_unpoison(&get_manager().user_token) = Some(identity.token);
+    NetplayGameEvent::Identity {
+        user_id: identity.user_id,
+        user_token: identity.token.to_vec(),
+    }
+}
+
+fn chat_event(client_id: u32, name: String, body: ChatBody) -> NetplayGameEvent {
+    let (text, emote) = match body {
+        ChatBody::Text(text) => (Some(text), None),
+        ChatBody::Emote(emote) => (None, Some(emote)),
+    };
+    NetplayGameEvent::Chat {
+        client_id,
+        name,
+        text,
+        emote,
+    }
+}
+
 /// Send reset to other players.
 #[frb]
 pub async fn netplay_send_reset(kind: u8) -> Result<(), String> {
@@ -1661,6 +2349,9 @@ fn notify_status(
                     Some(nesium_netproto::messages::session::TransportKind::Quic) => {
                         NetplayTransport::Quic
                     }
+                    Some(nesium_netproto::messages::session::TransportKind::WebRtc) => {
+                        NetplayTransport::WebRtc
+                    }
                     None => NetplayTransport::Unknown,
                 };
 
@@ -1,16 +1,8 @@
 use flutter_rust_bridge::frb;
-use nesium_core::ppu::buffer::{NearestPostProcessor, VideoPostProcessor};
-use nesium_core::ppu::{SCREEN_HEIGHT, SCREEN_WIDTH};
-use nesium_support::video::filters::NesNtscPostProcessor;
-use nesium_support::video::filters::NesNtscTuning;
-use nesium_support::video::filters::{
-    HqxPostProcessor, LcdGridPostProcessor, NtscBisqwitOptions as SupportNtscBisqwitOptions,
-    NtscBisqwitPostProcessor, SaiPostProcessor, SaiVariant, ScanlinePostProcessor,
-    XbrzPostProcessor,
+use nesium_runtime::runtime::{
+    HqxScale, NesNtscPreset, NesNtscTuning, NtscBisqwitOptions as RuntimeNtscBisqwitOptions,
+    SaiVariant, VideoFilter as RuntimeVideoFilter,
 };
-use nesium_support::video::hqx::HqxScale;
-use nesium_support::video::ntsc::NesNtscPreset;
-use nesium_support::video::ntsc::nes_ntsc_out_width;
 use std::sync::{Mutex, OnceLock};
 
 /// Single-select video filter configuration, modeled after Mesen's `VideoFilterType`.
@@ -208,56 +200,71 @@ fn ntsc_bisqwit_options() -> NtscBisqwitOptions {
 }
 
 impl VideoFilter {
-    fn scale_factor(self) -> u32 {
-        match self {
-            VideoFilter::None => 1,
-            VideoFilter::Prescale2x => 2,
-            VideoFilter::Prescale3x => 3,
-            VideoFilter::Prescale4x => 4,
-            VideoFilter::Hq2x => 2,
-            VideoFilter::Hq3x => 3,
-            VideoFilter::Hq4x => 4,
-            VideoFilter::Sai2x | VideoFilter::Super2xSai | VideoFilter::SuperEagle => 2,
-            VideoFilter::NtscComposite
-            | VideoFilter::NtscSVideo
-            | VideoFilter::NtscRgb
-            | VideoFilter::NtscMonochrome => 0,
-            VideoFilter::LcdGrid | VideoFilter::Scanlines => 2,
-            VideoFilter::Xbrz2x => 2,
-            VideoFilter::Xbrz3x => 3,
-            VideoFilter::Xbrz4x => 4,
-            VideoFilter::Xbrz5x => 5,
-            VideoFilter::Xbrz6x => 6,
-            VideoFilter::NtscBisqwit2x => 2,
-            VideoFilter::NtscBisqwit4x => 4,
-            VideoFilter::NtscBisqwit8x => 8,
-        }
-    }
-
-    fn output_size(self) -> Result<(u32, u32), String> {
-        match self {
-            VideoFilter::NtscComposite
-            | VideoFilter::NtscSVideo
-            | VideoFilter::NtscRgb
-            | VideoFilter::NtscMonochrome => {
-                let w = nes_ntsc_out_width(SCREEN_WIDTH)
-                    .try_into()
-                    .map_err(|_| "output_width overflow".to_string())?;
-                let h = (SCREEN_HEIGHT as u32)
-                    .checked_mul(2)
-                    .ok_or_else(|| "output_height overflow".to_string())?;
-                Ok((w, h))
+    /// Resolves the selection and the current option values into the runtime's filter.
+    fn to_runtime(self) -> RuntimeVideoFilter {
+        let ntsc = |preset| {
+            let o = ntsc_options();
+            RuntimeVideoFilter::Ntsc {
+                preset,
+                tuning: NesNtscTuning {
+                    hue: o.hue,
+                    saturation: o.saturation,
+                    contrast: o.contrast,
+                    brightness: o.brightness,
+                    sharpness: o.sharpness,
+                    gamma: o.gamma,
+                    resolution: o.resolution,
+                    artifacts: o.artifacts,
+                    fringing: o.fringing,
+                    bleed: o.bleed,
+                    merge_fields: o.merge_fields,
+                },
             }
-            _ => {
-                let scale = self.scale_factor();
-                let output_width = (SCREEN_WIDTH as u32)
-                    .checked_mul(scale)
-                    .ok_or_else(|| "output_width overflow".to_string())?;
-                let output_height = (SCREEN_HEIGHT as u32)
-                    .checked_mul(scale)
-                    .ok_or_else(|| "output_height overflow".to_string())?;
-                Ok((output_width, output_height))
+        };
+        let ntsc_bisqwit = |scale| {
+            let o = ntsc_bisqwit_options();
+            RuntimeVideoFilter::NtscBisqwit {
+                scale,
+                options: RuntimeNtscBisqwitOptions {
+                    brightness: o.brightness,
+                    contrast: o.contrast,
+                    hue: o.hue,
+                    saturation: o.saturation,
+                    y_filter_length: o.y_filter_length,
+                    i_filter_length: o.i_filter_length,
+                    q_filter_length: o.q_filter_length,
+                },
             }
+        };
+        match self {
+            VideoFilter::None => RuntimeVideoFilter::None,
+            VideoFilter::Prescale2x => RuntimeVideoFilter::Prescale(2),
+            VideoFilter::Prescale3x => RuntimeVideoFilter::Prescale(3),
+            VideoFilter::Prescale4x => RuntimeVideoFilter::Prescale(4),
+            VideoFilter::Hq2x => RuntimeVideoFilter::Hqx(HqxScale::X2),
+            VideoFilter::Hq3x => RuntimeVideoFilter::Hqx(HqxScale::X3),
+            VideoFilter::Hq4x => RuntimeVideoFilter::Hqx(HqxScale::X4),
+            VideoFilter::Sai2x => RuntimeVideoFilter::Sai(SaiVariant::Sai2x),
+            VideoFilter::Super2xSai => RuntimeVideoFilter::Sai(SaiVariant::Super2xSai),
+            VideoFilter::SuperEagle => RuntimeVideoFilter::Sai(SaiVariant::SuperEagle),
+            VideoFilter::NtscComposite => ntsc(NesNtscPreset::Composite),
+            VideoFilter::NtscSVideo => ntsc(NesNtscPreset::SVideo),
+            VideoFilter::NtscRgb => ntsc(NesNtscPreset::Rgb),
+            VideoFilter::NtscMonochrome => ntsc(NesNtscPreset::Monochrome),
+            VideoFilter::LcdGrid => RuntimeVideoFilter::LcdGrid {
+                strength: lcd_grid_options().strength,
+            },
+            VideoFilter::Scanlines => RuntimeVideoFilter::Scanlines {
+                intensity: scanline_options().intensity,
+            },
+            VideoFilter::Xbrz2x => RuntimeVideoFilter::Xbrz(2),
+            VideoFilter::Xbrz3x => RuntimeVideoFilter::Xbrz(3),
+            VideoFilter::Xbrz4x => RuntimeVideoFilter::Xbrz(4),
+            VideoFilter::Xbrz5x => RuntimeVideoFilter::Xbrz(5),
+            VideoFilter::Xbrz6x => RuntimeVideoFilter::Xbrz(6),
+            VideoFilter::NtscBisqwit2x => ntsc_bisqwit(2),
+            VideoFilter::NtscBisqwit4x => ntsc_bisqwit(4),
+            VideoFilter::NtscBisqwit8x => ntsc_bisqwit(8),
         }
     }
 }
@@ -280,126 +287,16 @@ fn is_ntsc_bisqwit_filter(filter: VideoFilter) -> bool {
 }
 
 fn apply_video_filter(filter: VideoFilter) -> Result<VideoOutputInfo, String> {
-    let (output_width, output_height) = filter.output_size()?;
+    let filter = filter.to_runtime();
 
     #[cfg(target_os = "android")]
-    crate::android::resize_ahb_swapchain(output_width, output_height)?;
-
-    let processor: Box<dyn VideoPostProcessor> = match filter {
-        VideoFilter::Hq2x => Box::new(HqxPostProcessor::new(HqxScale::X2)),
-        VideoFilter::Hq3x => Box::new(HqxPostProcessor::new(HqxScale::X3)),
-        VideoFilter::Hq4x => Box::new(HqxPostProcessor::new(HqxScale::X4)),
-        VideoFilter::Sai2x => Box::new(SaiPostProcessor::new(SaiVariant::Sai2x)),
-        VideoFilter::Super2xSai => Box::new(SaiPostProcessor::new(SaiVariant::Super2xSai)),
-        VideoFilter::SuperEagle => Box::new(SaiPostProcessor::new(SaiVariant::SuperEagle)),
-        VideoFilter::LcdGrid => {
-            let o = lcd_grid_options();
-            Box::new(LcdGridPostProcessor::new(o.strength))
-        }
-        VideoFilter::Scanlines => {
-            let o = scanline_options();
-            Box::new(ScanlinePostProcessor::new(2, o.intensity))
-        }
-        VideoFilter::Xbrz2x => Box::new(XbrzPostProcessor::new(2)),
-        VideoFilter::Xbrz3x => Box::new(XbrzPostProcessor::new(3)),
-        VideoFilter::Xbrz4x => Box::new(XbrzPostProcessor::new(4)),
-        VideoFilter::Xbrz5x => Box::new(XbrzPostProcessor::new(5)),
-        VideoFilter::Xbrz6x => Box::new(XbrzPostProcessor::new(6)),
-        VideoFilter::NtscBisqwit2x | VideoFilter::NtscBisqwit4x | VideoFilter::NtscBisqwit8x => {
-            let o = ntsc_bisqwit_options();
-            let support_o = SupportNtscBisqwitOptions {
-                brightness: o.brightness,
-                contrast: o.contrast,
-                hue: o.hue,
-                saturation: o.saturation,
-                y_filter_length: o.y_filter_length,
-                i_filter_length: o.i_filter_length,
-                q_filter_length: o.q_filter_length,
-            };
-            let scale = filter.scale_factor() as u8;
-            Box::new(NtscBisqwitPostProcessor::new(scale, support_o))
-        }
-        VideoFilter::NtscComposite => {
-            let o = ntsc_options();
-            Box::new(NesNtscPostProcessor::new_with_tuning(
-                NesNtscPreset::Composite,
-                NesNtscTuning {
-                    hue: o.hue,
-                    saturation: o.saturation,
-                    contrast: o.contrast,
-                    brightness: o.brightness,
-                    sharpness: o.sharpness,
-                    gamma: o.gamma,
-                    resolution: o.resolution,
-                    artifacts: o.artifacts,
-                    fringing: o.fringing,
-                    bleed: o.bleed,
-                    merge_fields: o.merge_fields,
-                },
-            ))
-        }
-        VideoFilter::NtscSVideo => {
-            let o = ntsc_options();
-            Box::new(NesNtscPostProcessor::new_with_tuning(
-                NesNtscPreset::SVideo,
-                NesNtscTuning {
-                    hue: o.hue,
-                    saturation: o.saturation,
-                    contrast: o.contrast,
-                    brightness: o.brightness,
-                    sharpness: o.sharpness,
-                    gamma: o.gamma,
-                    resolution: o.resolution,
-                    artifacts: o.artifacts,
-                    fringing: o.fringing,
-                    bleed: o.bleed,
-                    merge_fields: o.merge_fields,
-                },
-            ))
-        }
-        VideoFilter::NtscRgb => {
-            let o = ntsc_options();
-            Box::new(NesNtscPostProcessor::new_with_tuning(
-                NesNtscPreset::Rgb,
-                NesNtscTuning {
-                    hue: o.hue,
-                    saturation: o.saturation,
-                    contrast: o.contrast,
-                    brightness: o.brightness,
-                    sharpness: o.sharpness,
-                    gamma: o.gamma,
-                    resolution: o.resolution,
-                    artifacts: o.artifacts,
-                    fringing: o.fringing,
-                    bleed: o.bleed,
-                    merge_fields: o.merge_fields,
-                },
-            ))
-        }
-        VideoFilter::NtscMonochrome => {
-            let o = ntsc_options();
-            Box::new(NesNtscPostProcessor::new_with_tuning(
-                NesNtscPreset::Monochrome,
-                NesNtscTuning {
-                    hue: o.hue,
-                    saturation: o.saturation,
-                    contrast: o.contrast,
-                    brightness: o.brightness,
-                    sharpness: o.sharpness,
-                    gamma: o.gamma,
-                    resolution: o.resolution,
-                    artifacts: o.artifacts,
-                    fringing: o.fringing,
-                    bleed: o.bleed,
-                    merge_fields: o.merge_fields,
-                },
-            ))
-        }
-        _ => Box::new(NearestPostProcessor::default()),
-    };
+    {
+        let (output_width, output_height) = filter.output_size().map_err(|e| e.to_string())?;
+        crate::android::resize_ahb_swapchain(output_width, output_height)?;
+    }
 
-    crate::runtime_handle()
-        .set_video_pipeline(output_width, output_height, processor)
+    let (output_width, output_height) = crate::runtime_handle()
+        .set_video_filter(filter)
         .map_err(|e| e.to_string())?;
 
     Ok(VideoOutputInfo {
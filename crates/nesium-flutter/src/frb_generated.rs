@@ -38,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.12.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1438646929;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -582103215;
 
 // Section: executor
 
@@ -116,6 +116,38 @@ fn wire__crate__api__gamepad__bind_gamepad_impl(
         },
     )
 }
+fn wire__crate__api__input__clear_zapper_pointer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "clear_zapper_pointer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::input::clear_zapper_pointer()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__events__debug_state_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -195,6 +227,39 @@ fn wire__crate__api__events__emulation_status_stream_impl(
         },
     )
 }
+fn wire__crate__api__audio__expansion_balance_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "expansion_balance_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::audio::ExpansionBalance::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__gamepad__gamepad_actions_ffi_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -585,7 +650,7 @@ fn wire__crate__api__gamepad__init_gamepad_impl(
         },
     )
 }
-fn wire__crate__api__pause__is_paused_impl(
+fn wire__crate__api__instance__instance_load_rom_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -593,7 +658,7 @@ fn wire__crate__api__pause__is_paused_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_paused",
+            debug_name: "instance_load_rom",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -607,17 +672,20 @@ fn wire__crate__api__pause__is_paused_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance = <u32>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::pause::is_paused())?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::instance::instance_load_rom(api_instance, api_path)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__video__lcd_grid_options_default_impl(
+fn wire__crate__api__instance__instance_load_rom_from_bytes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -625,7 +693,7 @@ fn wire__crate__api__video__lcd_grid_options_default_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "lcd_grid_options_default",
+            debug_name: "instance_load_rom_from_bytes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -639,26 +707,30 @@ fn wire__crate__api__video__lcd_grid_options_default_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance = <u32>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::video::LcdGridOptions::default())?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::instance::instance_load_rom_from_bytes(
+                        api_instance,
+                        api_bytes,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__gamepad__list_gamepads_impl(
+fn wire__crate__api__instance__instance_load_state_from_memory_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_gamepads",
+            debug_name: "instance_load_state_from_memory",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -672,17 +744,26 @@ fn wire__crate__api__gamepad__list_gamepads_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance = <u32>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::gamepad::list_gamepads()?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::instance::instance_load_state_from_memory(
+                            api_instance,
+                            api_data,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__load_rom__load_rom_impl(
+fn wire__crate__api__instance__instance_reset_console_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -690,7 +771,7 @@ fn wire__crate__api__load_rom__load_rom_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_rom",
+            debug_name: "instance_reset_console",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -704,26 +785,26 @@ fn wire__crate__api__load_rom__load_rom_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_instance = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::load_rom::load_rom(api_path)?;
+                    let output_ok = crate::api::instance::instance_reset_console(api_instance)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__load_rom__load_rom_from_bytes_impl(
+fn wire__crate__api__instance__instance_save_state_to_memory_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_rom_from_bytes",
+            debug_name: "instance_save_state_to_memory",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -737,18 +818,23 @@ fn wire__crate__api__load_rom__load_rom_from_bytes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_instance = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::load_rom::load_rom_from_bytes(api_bytes)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::instance::instance_save_state_to_memory(api_instance)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__emulation__load_state_impl(
+fn wire__crate__api__instance__instance_set_pad_mask_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -756,7 +842,7 @@ fn wire__crate__api__emulation__load_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_state",
+            debug_name: "instance_set_pad_mask",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -770,26 +856,32 @@ fn wire__crate__api__emulation__load_state_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_instance = <u32>::sse_decode(&mut deserializer);
+            let api_pad = <u8>::sse_decode(&mut deserializer);
+            let api_mask = <u8>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::emulation::load_state(api_path)?;
+                    let output_ok = crate::api::instance::instance_set_pad_mask(
+                        api_instance,
+                        api_pad,
+                        api_mask,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__emulation__load_state_from_memory_impl(
+fn wire__crate__api__instance__instance_set_paused_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_state_from_memory",
+            debug_name: "instance_set_paused",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -803,22 +895,20 @@ fn wire__crate__api__emulation__load_state_from_memory_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_instance = <u32>::sse_decode(&mut deserializer);
+            let api_paused = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::emulation::load_state_from_memory(api_data).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::instance::instance_set_paused(api_instance, api_paused)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__emulation__load_tas_movie_impl(
+fn wire__crate__api__pause__is_paused_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -826,7 +916,7 @@ fn wire__crate__api__emulation__load_tas_movie_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_tas_movie",
+            debug_name: "is_paused",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -840,26 +930,25 @@ fn wire__crate__api__emulation__load_tas_movie_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::emulation::load_tas_movie(api_data)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::pause::is_paused())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__netplay__netplay_connect_impl(
+fn wire__crate__api__video__lcd_grid_options_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_connect",
+            debug_name: "lcd_grid_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -873,32 +962,26 @@ fn wire__crate__api__netplay__netplay_connect_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_server_addr = <String>::sse_decode(&mut deserializer);
-            let api_player_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::netplay::netplay_connect(api_server_addr, api_player_name)
-                                .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::video::LcdGridOptions::default())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__netplay__netplay_connect_auto_impl(
+fn wire__crate__api__gamepad__list_gamepads_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_connect_auto",
+            debug_name: "list_gamepads",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -912,36 +995,25 @@ fn wire__crate__api__netplay__netplay_connect_auto_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_server_addr = <String>::sse_decode(&mut deserializer);
-            let api_server_name = <String>::sse_decode(&mut deserializer);
-            let api_player_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::netplay::netplay_connect_auto(
-                            api_server_addr,
-                            api_server_name,
-                            api_player_name,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::gamepad::list_gamepads()?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__netplay__netplay_connect_auto_pinned_impl(
+fn wire__crate__api__emulation__load_emergency_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_connect_auto_pinned",
+            debug_name: "load_emergency_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -955,38 +1027,25 @@ fn wire__crate__api__netplay__netplay_connect_auto_pinned_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_server_addr = <String>::sse_decode(&mut deserializer);
-            let api_server_name = <String>::sse_decode(&mut deserializer);
-            let api_pinned_sha256_fingerprint = <String>::sse_decode(&mut deserializer);
-            let api_player_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::netplay::netplay_connect_auto_pinned(
-                            api_server_addr,
-                            api_server_name,
-                            api_pinned_sha256_fingerprint,
-                            api_player_name,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::emulation::load_emergency_state()?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__netplay__netplay_connect_quic_impl(
+fn wire__crate__api__load_rom__load_rom_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_connect_quic",
+            debug_name: "load_rom",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1000,28 +1059,84 @@ fn wire__crate__api__netplay__netplay_connect_quic_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_server_addr = <String>::sse_decode(&mut deserializer);
-            let api_server_name = <String>::sse_decode(&mut deserializer);
-            let api_player_name = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::netplay::netplay_connect_quic(
-                            api_server_addr,
-                            api_server_name,
-                            api_player_name,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__netplay__netplay_connect_quic_pinned_impl(
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::load_rom::load_rom(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__load_rom__load_rom_from_bytes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_rom_from_bytes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::load_rom::load_rom_from_bytes(api_bytes)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__emulation__load_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_state",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::emulation::load_state(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__emulation__load_state_from_memory_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1029,7 +1144,116 @@ fn wire__crate__api__netplay__netplay_connect_quic_pinned_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_connect_quic_pinned",
+            debug_name: "load_state_from_memory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::emulation::load_state_from_memory(api_data).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__emulation__load_tas_movie_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_tas_movie",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::emulation::load_tas_movie(api_data)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__netplay__netplay_connect_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "netplay_connect",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_server_addr = <String>::sse_decode(&mut deserializer);
+            let api_player_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::netplay::netplay_connect(api_server_addr, api_player_name)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__netplay__netplay_connect_auto_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "netplay_connect_auto",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1045,16 +1269,14 @@ fn wire__crate__api__netplay__netplay_connect_quic_pinned_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_server_addr = <String>::sse_decode(&mut deserializer);
             let api_server_name = <String>::sse_decode(&mut deserializer);
-            let api_pinned_sha256_fingerprint = <String>::sse_decode(&mut deserializer);
             let api_player_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::netplay::netplay_connect_quic_pinned(
+                        let output_ok = crate::api::netplay::netplay_connect_auto(
                             api_server_addr,
                             api_server_name,
-                            api_pinned_sha256_fingerprint,
                             api_player_name,
                         )
                         .await?;
@@ -1066,7 +1288,7 @@ fn wire__crate__api__netplay__netplay_connect_quic_pinned_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_create_room_impl(
+fn wire__crate__api__netplay__netplay_connect_auto_pinned_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1074,7 +1296,7 @@ fn wire__crate__api__netplay__netplay_create_room_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_create_room",
+            debug_name: "netplay_connect_auto_pinned",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1088,11 +1310,21 @@ fn wire__crate__api__netplay__netplay_create_room_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_server_addr = <String>::sse_decode(&mut deserializer);
+            let api_server_name = <String>::sse_decode(&mut deserializer);
+            let api_pinned_sha256_fingerprint = <String>::sse_decode(&mut deserializer);
+            let api_player_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::netplay::netplay_create_room().await?;
+                        let output_ok = crate::api::netplay::netplay_connect_auto_pinned(
+                            api_server_addr,
+                            api_server_name,
+                            api_pinned_sha256_fingerprint,
+                            api_player_name,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1101,7 +1333,7 @@ fn wire__crate__api__netplay__netplay_create_room_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_disconnect_impl(
+fn wire__crate__api__netplay__netplay_connect_quic_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1109,7 +1341,7 @@ fn wire__crate__api__netplay__netplay_disconnect_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_disconnect",
+            debug_name: "netplay_connect_quic",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1123,11 +1355,19 @@ fn wire__crate__api__netplay__netplay_disconnect_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_server_addr = <String>::sse_decode(&mut deserializer);
+            let api_server_name = <String>::sse_decode(&mut deserializer);
+            let api_player_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::netplay::netplay_disconnect().await?;
+                        let output_ok = crate::api::netplay::netplay_connect_quic(
+                            api_server_addr,
+                            api_server_name,
+                            api_player_name,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1136,7 +1376,7 @@ fn wire__crate__api__netplay__netplay_disconnect_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_game_event_stream_impl(
+fn wire__crate__api__netplay__netplay_connect_quic_pinned_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1144,7 +1384,7 @@ fn wire__crate__api__netplay__netplay_game_event_stream_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_game_event_stream",
+            debug_name: "netplay_connect_quic_pinned",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1158,16 +1398,21 @@ fn wire__crate__api__netplay__netplay_game_event_stream_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink = <StreamSink<
-                crate::api::netplay::NetplayGameEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_server_addr = <String>::sse_decode(&mut deserializer);
+            let api_server_name = <String>::sse_decode(&mut deserializer);
+            let api_pinned_sha256_fingerprint = <String>::sse_decode(&mut deserializer);
+            let api_player_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::netplay::netplay_game_event_stream(api_sink).await?;
+                        let output_ok = crate::api::netplay::netplay_connect_quic_pinned(
+                            api_server_addr,
+                            api_server_name,
+                            api_pinned_sha256_fingerprint,
+                            api_player_name,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1176,7 +1421,7 @@ fn wire__crate__api__netplay__netplay_game_event_stream_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_is_connected_impl(
+fn wire__crate__api__netplay__netplay_connect_tls_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1184,7 +1429,7 @@ fn wire__crate__api__netplay__netplay_is_connected_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_is_connected",
+            debug_name: "netplay_connect_tls",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1198,12 +1443,212 @@ fn wire__crate__api__netplay__netplay_is_connected_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_server_addr = <String>::sse_decode(&mut deserializer);
+            let api_server_name = <String>::sse_decode(&mut deserializer);
+            let api_player_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::netplay::netplay_is_connected().await)?;
+                        let output_ok = crate::api::netplay::netplay_connect_tls(
+                            api_server_addr,
+                            api_server_name,
+                            api_player_name,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__netplay__netplay_connect_tls_pinned_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "netplay_connect_tls_pinned",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_server_addr = <String>::sse_decode(&mut deserializer);
+            let api_server_name = <String>::sse_decode(&mut deserializer);
+            let api_pinned_sha256_fingerprint = <String>::sse_decode(&mut deserializer);
+            let api_player_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::netplay::netplay_connect_tls_pinned(
+                            api_server_addr,
+                            api_server_name,
+                            api_pinned_sha256_fingerprint,
+                            api_player_name,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__netplay__netplay_create_room_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "netplay_create_room",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::netplay::netplay_create_room(api_password).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__netplay__netplay_disconnect_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "netplay_disconnect",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::netplay::netplay_disconnect().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__netplay__netplay_game_event_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "netplay_game_event_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::netplay::NetplayGameEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::netplay::netplay_game_event_stream(api_sink).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__netplay__netplay_is_connected_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "netplay_is_connected",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::netplay::netplay_is_connected().await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1220,7 +1665,319 @@ fn wire__crate__api__netplay__netplay_join_room_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_join_room",
+            debug_name: "netplay_join_room",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_code = <u32>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::netplay::netplay_join_room(api_room_code, api_password)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__netplay__netplay_list_rooms_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "netplay_list_rooms",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_game_hash = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_region = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::netplay::netplay_list_rooms(api_game_hash, api_region)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__netplay__netplay_p2p_connect_join_auto_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "netplay_p2p_connect_join_auto",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_signaling_addr = <String>::sse_decode(&mut deserializer);
+            let api_relay_addr = <String>::sse_decode(&mut deserializer);
+            let api_room_code = <u32>::sse_decode(&mut deserializer);
+            let api_player_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::netplay::netplay_p2p_connect_join_auto(
+                            api_signaling_addr,
+                            api_relay_addr,
+                            api_room_code,
+                            api_player_name,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__netplay__netplay_p2p_create_room_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "netplay_p2p_create_room",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_signaling_addr = <String>::sse_decode(&mut deserializer);
+            let api_host_addrs = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_host_room_code = <u32>::sse_decode(&mut deserializer);
+            let api_host_quic_cert_sha256_fingerprint =
+                <Option<String>>::sse_decode(&mut deserializer);
+            let api_host_quic_server_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::netplay::netplay_p2p_create_room(
+                            api_signaling_addr,
+                            api_host_addrs,
+                            api_host_room_code,
+                            api_host_quic_cert_sha256_fingerprint,
+                            api_host_quic_server_name,
+                            api_name,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__netplay__netplay_p2p_host_create_and_watch_fallback_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "netplay_p2p_host_create_and_watch_fallback",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_signaling_addr = <String>::sse_decode(&mut deserializer);
+            let api_relay_addr = <String>::sse_decode(&mut deserializer);
+            let api_host_addrs = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_host_room_code = <u32>::sse_decode(&mut deserializer);
+            let api_host_quic_cert_sha256_fingerprint =
+                <Option<String>>::sse_decode(&mut deserializer);
+            let api_host_quic_server_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_player_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::netplay::netplay_p2p_host_create_and_watch_fallback(
+                                api_signaling_addr,
+                                api_relay_addr,
+                                api_host_addrs,
+                                api_host_room_code,
+                                api_host_quic_cert_sha256_fingerprint,
+                                api_host_quic_server_name,
+                                api_player_name,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__netplay__netplay_p2p_host_start_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "netplay_p2p_host_start",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_signaling_addr = <String>::sse_decode(&mut deserializer);
+            let api_relay_addr = <String>::sse_decode(&mut deserializer);
+            let api_player_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::netplay::netplay_p2p_host_start(
+                            api_signaling_addr,
+                            api_relay_addr,
+                            api_player_name,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__netplay__netplay_p2p_join_room_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "netplay_p2p_join_room",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_signaling_addr = <String>::sse_decode(&mut deserializer);
+            let api_room_code = <u32>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::netplay::netplay_p2p_join_room(
+                            api_signaling_addr,
+                            api_room_code,
+                            api_name,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__netplay__netplay_p2p_request_fallback_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "netplay_p2p_request_fallback",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1234,13 +1991,21 @@ fn wire__crate__api__netplay__netplay_join_room_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_signaling_addr = <String>::sse_decode(&mut deserializer);
             let api_room_code = <u32>::sse_decode(&mut deserializer);
+            let api_reason = <String>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::netplay::netplay_join_room(api_room_code).await?;
+                        let output_ok = crate::api::netplay::netplay_p2p_request_fallback(
+                            api_signaling_addr,
+                            api_room_code,
+                            api_reason,
+                            api_name,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1249,7 +2014,7 @@ fn wire__crate__api__netplay__netplay_join_room_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_p2p_connect_join_auto_impl(
+fn wire__crate__api__netplay__netplay_provide_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1257,7 +2022,7 @@ fn wire__crate__api__netplay__netplay_p2p_connect_join_auto_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_p2p_connect_join_auto",
+            debug_name: "netplay_provide_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1271,21 +2036,14 @@ fn wire__crate__api__netplay__netplay_p2p_connect_join_auto_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_signaling_addr = <String>::sse_decode(&mut deserializer);
-            let api_relay_addr = <String>::sse_decode(&mut deserializer);
-            let api_room_code = <u32>::sse_decode(&mut deserializer);
-            let api_player_name = <String>::sse_decode(&mut deserializer);
+            let api_frame = <u32>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::netplay::netplay_p2p_connect_join_auto(
-                            api_signaling_addr,
-                            api_relay_addr,
-                            api_room_code,
-                            api_player_name,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::netplay::netplay_provide_state(api_frame, api_data).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1294,7 +2052,7 @@ fn wire__crate__api__netplay__netplay_p2p_connect_join_auto_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_p2p_create_room_impl(
+fn wire__crate__api__netplay__netplay_quick_match_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1302,7 +2060,7 @@ fn wire__crate__api__netplay__netplay_p2p_create_room_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_p2p_create_room",
+            debug_name: "netplay_quick_match",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1316,26 +2074,15 @@ fn wire__crate__api__netplay__netplay_p2p_create_room_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_signaling_addr = <String>::sse_decode(&mut deserializer);
-            let api_host_addrs = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_host_room_code = <u32>::sse_decode(&mut deserializer);
-            let api_host_quic_cert_sha256_fingerprint =
-                <Option<String>>::sse_decode(&mut deserializer);
-            let api_host_quic_server_name = <Option<String>>::sse_decode(&mut deserializer);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_game_hash = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_region = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::netplay::netplay_p2p_create_room(
-                            api_signaling_addr,
-                            api_host_addrs,
-                            api_host_room_code,
-                            api_host_quic_cert_sha256_fingerprint,
-                            api_host_quic_server_name,
-                            api_name,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::netplay::netplay_quick_match(api_game_hash, api_region)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1344,7 +2091,7 @@ fn wire__crate__api__netplay__netplay_p2p_create_room_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_p2p_host_create_and_watch_fallback_impl(
+fn wire__crate__api__netplay__netplay_request_fallback_relay_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1352,7 +2099,7 @@ fn wire__crate__api__netplay__netplay_p2p_host_create_and_watch_fallback_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_p2p_host_create_and_watch_fallback",
+            debug_name: "netplay_request_fallback_relay",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1366,29 +2113,19 @@ fn wire__crate__api__netplay__netplay_p2p_host_create_and_watch_fallback_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_signaling_addr = <String>::sse_decode(&mut deserializer);
             let api_relay_addr = <String>::sse_decode(&mut deserializer);
-            let api_host_addrs = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_host_room_code = <u32>::sse_decode(&mut deserializer);
-            let api_host_quic_cert_sha256_fingerprint =
-                <Option<String>>::sse_decode(&mut deserializer);
-            let api_host_quic_server_name = <Option<String>>::sse_decode(&mut deserializer);
-            let api_player_name = <String>::sse_decode(&mut deserializer);
+            let api_relay_room_code = <u32>::sse_decode(&mut deserializer);
+            let api_reason = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::netplay::netplay_p2p_host_create_and_watch_fallback(
-                                api_signaling_addr,
-                                api_relay_addr,
-                                api_host_addrs,
-                                api_host_room_code,
-                                api_host_quic_cert_sha256_fingerprint,
-                                api_host_quic_server_name,
-                                api_player_name,
-                            )
-                            .await?;
+                        let output_ok = crate::api::netplay::netplay_request_fallback_relay(
+                            api_relay_addr,
+                            api_relay_room_code,
+                            api_reason,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1397,7 +2134,7 @@ fn wire__crate__api__netplay__netplay_p2p_host_create_and_watch_fallback_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_p2p_host_start_impl(
+fn wire__crate__api__netplay__netplay_request_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1405,7 +2142,7 @@ fn wire__crate__api__netplay__netplay_p2p_host_start_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_p2p_host_start",
+            debug_name: "netplay_request_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1419,19 +2156,11 @@ fn wire__crate__api__netplay__netplay_p2p_host_start_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_signaling_addr = <String>::sse_decode(&mut deserializer);
-            let api_relay_addr = <String>::sse_decode(&mut deserializer);
-            let api_player_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::netplay::netplay_p2p_host_start(
-                            api_signaling_addr,
-                            api_relay_addr,
-                            api_player_name,
-                        )
-                        .await?;
+                        let output_ok = crate::api::netplay::netplay_request_state().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1440,7 +2169,7 @@ fn wire__crate__api__netplay__netplay_p2p_host_start_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_p2p_join_room_impl(
+fn wire__crate__api__netplay__netplay_send_chat_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1448,7 +2177,7 @@ fn wire__crate__api__netplay__netplay_p2p_join_room_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_p2p_join_room",
+            debug_name: "netplay_send_chat",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1462,19 +2191,12 @@ fn wire__crate__api__netplay__netplay_p2p_join_room_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_signaling_addr = <String>::sse_decode(&mut deserializer);
-            let api_room_code = <u32>::sse_decode(&mut deserializer);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::netplay::netplay_p2p_join_room(
-                            api_signaling_addr,
-                            api_room_code,
-                            api_name,
-                        )
-                        .await?;
+                        let output_ok = crate::api::netplay::netplay_send_chat(api_text).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1483,7 +2205,7 @@ fn wire__crate__api__netplay__netplay_p2p_join_room_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_p2p_request_fallback_impl(
+fn wire__crate__api__netplay__netplay_send_emote_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1491,7 +2213,7 @@ fn wire__crate__api__netplay__netplay_p2p_request_fallback_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_p2p_request_fallback",
+            debug_name: "netplay_send_emote",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1505,21 +2227,12 @@ fn wire__crate__api__netplay__netplay_p2p_request_fallback_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_signaling_addr = <String>::sse_decode(&mut deserializer);
-            let api_room_code = <u32>::sse_decode(&mut deserializer);
-            let api_reason = <String>::sse_decode(&mut deserializer);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_emote = <u8>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::netplay::netplay_p2p_request_fallback(
-                            api_signaling_addr,
-                            api_room_code,
-                            api_reason,
-                            api_name,
-                        )
-                        .await?;
+                        let output_ok = crate::api::netplay::netplay_send_emote(api_emote).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1528,7 +2241,7 @@ fn wire__crate__api__netplay__netplay_p2p_request_fallback_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_provide_state_impl(
+fn wire__crate__api__netplay__netplay_send_pause_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1536,7 +2249,7 @@ fn wire__crate__api__netplay__netplay_provide_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_provide_state",
+            debug_name: "netplay_send_pause",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1550,14 +2263,12 @@ fn wire__crate__api__netplay__netplay_provide_state_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_frame = <u32>::sse_decode(&mut deserializer);
-            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_paused = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::netplay::netplay_provide_state(api_frame, api_data).await?;
+                        let output_ok = crate::api::netplay::netplay_send_pause(api_paused).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1566,7 +2277,7 @@ fn wire__crate__api__netplay__netplay_provide_state_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_request_fallback_relay_impl(
+fn wire__crate__api__netplay__netplay_send_reset_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1574,7 +2285,7 @@ fn wire__crate__api__netplay__netplay_request_fallback_relay_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_request_fallback_relay",
+            debug_name: "netplay_send_reset",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1588,19 +2299,12 @@ fn wire__crate__api__netplay__netplay_request_fallback_relay_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_relay_addr = <String>::sse_decode(&mut deserializer);
-            let api_relay_room_code = <u32>::sse_decode(&mut deserializer);
-            let api_reason = <String>::sse_decode(&mut deserializer);
+            let api_kind = <u8>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::netplay::netplay_request_fallback_relay(
-                            api_relay_addr,
-                            api_relay_room_code,
-                            api_reason,
-                        )
-                        .await?;
+                        let output_ok = crate::api::netplay::netplay_send_reset(api_kind).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1609,7 +2313,7 @@ fn wire__crate__api__netplay__netplay_request_fallback_relay_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_request_state_impl(
+fn wire__crate__api__netplay__netplay_send_rom_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1617,7 +2321,7 @@ fn wire__crate__api__netplay__netplay_request_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_request_state",
+            debug_name: "netplay_send_rom",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1631,11 +2335,12 @@ fn wire__crate__api__netplay__netplay_request_state_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::netplay::netplay_request_state().await?;
+                        let output_ok = crate::api::netplay::netplay_send_rom(api_data).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1644,7 +2349,7 @@ fn wire__crate__api__netplay__netplay_request_state_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_send_pause_impl(
+fn wire__crate__api__netplay__netplay_send_rom_loaded_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1652,7 +2357,7 @@ fn wire__crate__api__netplay__netplay_send_pause_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_send_pause",
+            debug_name: "netplay_send_rom_loaded",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1666,12 +2371,11 @@ fn wire__crate__api__netplay__netplay_send_pause_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_paused = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::netplay::netplay_send_pause(api_paused).await?;
+                        let output_ok = crate::api::netplay::netplay_send_rom_loaded().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1680,7 +2384,7 @@ fn wire__crate__api__netplay__netplay_send_pause_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_send_reset_impl(
+fn wire__crate__api__netplay__netplay_set_room_info_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1688,7 +2392,7 @@ fn wire__crate__api__netplay__netplay_send_reset_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_send_reset",
+            debug_name: "netplay_set_room_info",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1702,12 +2406,19 @@ fn wire__crate__api__netplay__netplay_send_reset_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_kind = <u8>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_region = <String>::sse_decode(&mut deserializer);
+            let api_game_hash = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::netplay::netplay_send_reset(api_kind).await?;
+                        let output_ok = crate::api::netplay::netplay_set_room_info(
+                            api_name,
+                            api_region,
+                            api_game_hash,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1716,7 +2427,7 @@ fn wire__crate__api__netplay__netplay_send_reset_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_send_rom_impl(
+fn wire__crate__api__netplay__netplay_set_spectator_delay_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1724,7 +2435,7 @@ fn wire__crate__api__netplay__netplay_send_rom_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_send_rom",
+            debug_name: "netplay_set_spectator_delay",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1738,12 +2449,13 @@ fn wire__crate__api__netplay__netplay_send_rom_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_frames = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::netplay::netplay_send_rom(api_data).await?;
+                        let output_ok =
+                            crate::api::netplay::netplay_set_spectator_delay(api_frames).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1752,15 +2464,15 @@ fn wire__crate__api__netplay__netplay_send_rom_impl(
         },
     )
 }
-fn wire__crate__api__netplay__netplay_send_rom_loaded_impl(
+fn wire__crate__api__netplay__netplay_set_user_token_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "netplay_send_rom_loaded",
+            debug_name: "netplay_set_user_token",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1774,15 +2486,13 @@ fn wire__crate__api__netplay__netplay_send_rom_loaded_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <Option<Vec<u8>>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::netplay::netplay_send_rom_loaded().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::netplay::netplay_set_user_token(api_token)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
@@ -2424,7 +3134,75 @@ fn wire__crate__api__emulation__save_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "save_state",
+            debug_name: "save_state",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::emulation::save_state(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__emulation__save_state_to_memory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_state_to_memory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::emulation::save_state_to_memory().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__video__scanline_options_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scanline_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2438,26 +3216,26 @@ fn wire__crate__api__emulation__save_state_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::emulation::save_state(api_path)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::video::ScanlineOptions::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__emulation__save_state_to_memory_impl(
+fn wire__crate__api__audio__set_audio_stereo_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "save_state_to_memory",
+            debug_name: "set_audio_stereo",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2471,20 +3249,19 @@ fn wire__crate__api__emulation__save_state_to_memory_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_preset = <crate::api::audio::StereoPreset>::sse_decode(&mut deserializer);
+            let api_balance = <crate::api::audio::ExpansionBalance>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::emulation::save_state_to_memory().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio::set_audio_stereo(api_preset, api_balance)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__video__scanline_options_default_impl(
+fn wire__crate__api__emulation__set_clone_ppu_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2492,7 +3269,7 @@ fn wire__crate__api__video__scanline_options_default_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "scanline_options_default",
+            debug_name: "set_clone_ppu",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2506,11 +3283,11 @@ fn wire__crate__api__video__scanline_options_default_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::video::ScanlineOptions::default())?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::emulation::set_clone_ppu(api_enabled)?;
                     Ok(output_ok)
                 })())
             }
@@ -3639,7 +4416,232 @@ fn wire__crate__api__events__set_tile_viewer_layout_impl(
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::events::set_tile_viewer_layout(api_layout).await?;
+                            crate::api::events::set_tile_viewer_layout(api_layout).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__events__set_tile_viewer_palette_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_tile_viewer_palette",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_palette_index = <u8>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::events::set_tile_viewer_palette(api_palette_index).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__events__set_tile_viewer_size_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_tile_viewer_size",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_columns = <u16>::sse_decode(&mut deserializer);
+            let api_rows = <u16>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::events::set_tile_viewer_size(api_columns, api_rows).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__events__set_tile_viewer_source_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_tile_viewer_source",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source = <u8>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::events::set_tile_viewer_source(api_source).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__events__set_tile_viewer_start_address_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_tile_viewer_start_address",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_start_address = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::events::set_tile_viewer_start_address(api_start_address)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__events__set_tilemap_capture_frame_start_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_tilemap_capture_frame_start",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::events::set_tilemap_capture_frame_start().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__events__set_tilemap_capture_scanline_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_tilemap_capture_scanline",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scanline = <i32>::sse_decode(&mut deserializer);
+            let api_dot = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::events::set_tilemap_capture_scanline(api_scanline, api_dot)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3648,7 +4650,7 @@ fn wire__crate__api__events__set_tile_viewer_layout_impl(
         },
     )
 }
-fn wire__crate__api__events__set_tile_viewer_palette_impl(
+fn wire__crate__api__events__set_tilemap_capture_vblank_start_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3656,7 +4658,7 @@ fn wire__crate__api__events__set_tile_viewer_palette_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_tile_viewer_palette",
+            debug_name: "set_tilemap_capture_vblank_start",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3670,13 +4672,12 @@ fn wire__crate__api__events__set_tile_viewer_palette_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_palette_index = <u8>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::events::set_tile_viewer_palette(api_palette_index).await?;
+                            crate::api::events::set_tilemap_capture_vblank_start().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3685,7 +4686,7 @@ fn wire__crate__api__events__set_tile_viewer_palette_impl(
         },
     )
 }
-fn wire__crate__api__events__set_tile_viewer_size_impl(
+fn wire__crate__api__events__set_tilemap_display_mode_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3693,7 +4694,7 @@ fn wire__crate__api__events__set_tile_viewer_size_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_tile_viewer_size",
+            debug_name: "set_tilemap_display_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3707,14 +4708,13 @@ fn wire__crate__api__events__set_tile_viewer_size_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_columns = <u16>::sse_decode(&mut deserializer);
-            let api_rows = <u16>::sse_decode(&mut deserializer);
+            let api_mode = <u8>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::events::set_tile_viewer_size(api_columns, api_rows).await?;
+                            crate::api::events::set_tilemap_display_mode(api_mode).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3723,15 +4723,15 @@ fn wire__crate__api__events__set_tile_viewer_size_impl(
         },
     )
 }
-fn wire__crate__api__events__set_tile_viewer_source_impl(
+fn wire__crate__api__input__set_turbo_frames_per_toggle_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_tile_viewer_source",
+            debug_name: "set_turbo_frames_per_toggle",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3745,30 +4745,26 @@ fn wire__crate__api__events__set_tile_viewer_source_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_source = <u8>::sse_decode(&mut deserializer);
+            let api_frames = <u8>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::events::set_tile_viewer_source(api_source).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::input::set_turbo_frames_per_toggle(api_frames)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__events__set_tile_viewer_start_address_impl(
+fn wire__crate__api__input__set_turbo_mask_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_tile_viewer_start_address",
+            debug_name: "set_turbo_mask",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3782,31 +4778,27 @@ fn wire__crate__api__events__set_tile_viewer_start_address_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_start_address = <u32>::sse_decode(&mut deserializer);
+            let api_pad = <u8>::sse_decode(&mut deserializer);
+            let api_mask = <u8>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::events::set_tile_viewer_start_address(api_start_address)
-                                .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::input::set_turbo_mask(api_pad, api_mask)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__events__set_tilemap_capture_frame_start_impl(
+fn wire__crate__api__input__set_turbo_timing_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_tilemap_capture_frame_start",
+            debug_name: "set_turbo_timing",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3820,29 +4812,28 @@ fn wire__crate__api__events__set_tilemap_capture_frame_start_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_on_frames = <u8>::sse_decode(&mut deserializer);
+            let api_off_frames = <u8>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::events::set_tilemap_capture_frame_start().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::input::set_turbo_timing(api_on_frames, api_off_frames)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__events__set_tilemap_capture_scanline_impl(
+fn wire__crate__api__video__set_video_filter_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_tilemap_capture_scanline",
+            debug_name: "set_video_filter",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3856,32 +4847,26 @@ fn wire__crate__api__events__set_tilemap_capture_scanline_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_scanline = <i32>::sse_decode(&mut deserializer);
-            let api_dot = <i32>::sse_decode(&mut deserializer);
+            let api_filter = <crate::api::video::VideoFilter>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::events::set_tilemap_capture_scanline(api_scanline, api_dot)
-                                .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::video::set_video_filter(api_filter)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__events__set_tilemap_capture_vblank_start_impl(
+fn wire__crate__api__input__set_zapper_calibration_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_tilemap_capture_vblank_start",
+            debug_name: "set_zapper_calibration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3895,29 +4880,28 @@ fn wire__crate__api__events__set_tilemap_capture_vblank_start_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_offset_x = <i32>::sse_decode(&mut deserializer);
+            let api_offset_y = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::events::set_tilemap_capture_vblank_start().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::input::set_zapper_calibration(api_offset_x, api_offset_y)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__events__set_tilemap_display_mode_impl(
+fn wire__crate__api__input__set_zapper_crosshair_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_tilemap_display_mode",
+            debug_name: "set_zapper_crosshair",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3931,22 +4915,18 @@ fn wire__crate__api__events__set_tilemap_display_mode_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mode = <u8>::sse_decode(&mut deserializer);
+            let api_visible = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::events::set_tilemap_display_mode(api_mode).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::input::set_zapper_crosshair(api_visible)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__input__set_turbo_frames_per_toggle_impl(
+fn wire__crate__api__input__set_zapper_lag_compensation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3954,7 +4934,7 @@ fn wire__crate__api__input__set_turbo_frames_per_toggle_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_turbo_frames_per_toggle",
+            debug_name: "set_zapper_lag_compensation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3972,14 +4952,14 @@ fn wire__crate__api__input__set_turbo_frames_per_toggle_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::input::set_turbo_frames_per_toggle(api_frames)?;
+                    let output_ok = crate::api::input::set_zapper_lag_compensation(api_frames)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__input__set_turbo_mask_impl(
+fn wire__crate__api__input__set_zapper_pointer_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3987,7 +4967,7 @@ fn wire__crate__api__input__set_turbo_mask_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_turbo_mask",
+            debug_name: "set_zapper_pointer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4001,19 +4981,19 @@ fn wire__crate__api__input__set_turbo_mask_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pad = <u8>::sse_decode(&mut deserializer);
-            let api_mask = <u8>::sse_decode(&mut deserializer);
+            let api_x = <i32>::sse_decode(&mut deserializer);
+            let api_y = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::input::set_turbo_mask(api_pad, api_mask)?;
+                    let output_ok = crate::api::input::set_zapper_pointer(api_x, api_y)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__input__set_turbo_timing_impl(
+fn wire__crate__api__input__set_zapper_port_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4021,7 +5001,7 @@ fn wire__crate__api__input__set_turbo_timing_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_turbo_timing",
+            debug_name: "set_zapper_port",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4035,20 +5015,18 @@ fn wire__crate__api__input__set_turbo_timing_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_on_frames = <u8>::sse_decode(&mut deserializer);
-            let api_off_frames = <u8>::sse_decode(&mut deserializer);
+            let api_port = <Option<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::input::set_turbo_timing(api_on_frames, api_off_frames)?;
+                    let output_ok = crate::api::input::set_zapper_port(api_port)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__video__set_video_filter_impl(
+fn wire__crate__api__input__set_zapper_trigger_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4056,7 +5034,7 @@ fn wire__crate__api__video__set_video_filter_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_video_filter",
+            debug_name: "set_zapper_trigger",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4070,11 +5048,11 @@ fn wire__crate__api__video__set_video_filter_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_filter = <crate::api::video::VideoFilter>::sse_decode(&mut deserializer);
+            let api_pulled = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::video::set_video_filter(api_filter)?;
+                    let output_ok = crate::api::input::set_zapper_trigger(api_pulled)?;
                     Ok(output_ok)
                 })())
             }
@@ -4154,6 +5132,39 @@ fn wire__crate__api__events__sprite_state_stream_impl(
         },
     )
 }
+fn wire__crate__api__instance__start_instance_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_instance",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_audio = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::instance::start_instance(api_audio)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__load_rom__start_nes_runtime_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -4186,6 +5197,39 @@ fn wire__crate__api__load_rom__start_nes_runtime_impl(
         },
     )
 }
+fn wire__crate__api__instance__stop_instance_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_instance",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::instance::stop_instance(api_instance)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__events__subscribe_tilemap_texture_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -4763,6 +5807,26 @@ impl SseDecode for crate::api::events::EmulationStatusNotification {
     }
 }
 
+impl SseDecode for crate::api::audio::ExpansionBalance {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fds = <f32>::sse_decode(deserializer);
+        let mut var_mmc5 = <f32>::sse_decode(deserializer);
+        let mut var_vrc6 = <f32>::sse_decode(deserializer);
+        let mut var_vrc7 = <f32>::sse_decode(deserializer);
+        let mut var_namco163 = <f32>::sse_decode(deserializer);
+        let mut var_sunsoft5B = <f32>::sse_decode(deserializer);
+        return crate::api::audio::ExpansionBalance {
+            fds: var_fds,
+            mmc5: var_mmc5,
+            vrc6: var_vrc6,
+            vrc7: var_vrc7,
+            namco163: var_namco163,
+            sunsoft5b: var_sunsoft5B,
+        };
+    }
+}
+
 impl SseDecode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4974,13 +6038,41 @@ impl SseDecode for Vec<crate::api::gamepad::GamepadInfoFfi> {
     }
 }
 
+impl SseDecode for Vec<crate::api::netplay::NetplayPeerStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::netplay::NetplayPeerStats>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::netplay::NetplayPlayer> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = Vec::with_capacity(len_ as usize);
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::netplay::NetplayPlayer>::sse_decode(
+            ans_.push(<crate::api::netplay::NetplayPlayer>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::netplay::NetplayRoomSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::netplay::NetplayRoomSummary>::sse_decode(
                 deserializer,
             ));
         }
@@ -5090,6 +6182,60 @@ impl SseDecode for crate::api::netplay::NetplayGameEvent {
                     reason: var_reason,
                 };
             }
+            8 => {
+                let mut var_frame = <u32>::sse_decode(deserializer);
+                return crate::api::netplay::NetplayGameEvent::Desync { frame: var_frame };
+            }
+            9 => {
+                let mut var_clientId = <u32>::sse_decode(deserializer);
+                let mut var_name = <String>::sse_decode(deserializer);
+                let mut var_text = <Option<String>>::sse_decode(deserializer);
+                let mut var_emote = <Option<u8>>::sse_decode(deserializer);
+                return crate::api::netplay::NetplayGameEvent::Chat {
+                    client_id: var_clientId,
+                    name: var_name,
+                    text: var_text,
+                    emote: var_emote,
+                };
+            }
+            10 => {
+                let mut var_peers =
+                    <Vec<crate::api::netplay::NetplayPeerStats>>::sse_decode(deserializer);
+                return crate::api::netplay::NetplayGameEvent::NetworkStats { peers: var_peers };
+            }
+            11 => {
+                let mut var_frames = <u32>::sse_decode(deserializer);
+                return crate::api::netplay::NetplayGameEvent::InputDelayRecommended {
+                    frames: var_frames,
+                };
+            }
+            12 => {
+                let mut var_rooms =
+                    <Vec<crate::api::netplay::NetplayRoomSummary>>::sse_decode(deserializer);
+                return crate::api::netplay::NetplayGameEvent::RoomList { rooms: var_rooms };
+            }
+            13 => {
+                let mut var_count = <u16>::sse_decode(deserializer);
+                let mut var_delayFrames = <u32>::sse_decode(deserializer);
+                return crate::api::netplay::NetplayGameEvent::SpectatorInfo {
+                    count: var_count,
+                    delay_frames: var_delayFrames,
+                };
+            }
+            14 => {
+                let mut var_userId = <u64>::sse_decode(deserializer);
+                let mut var_userToken = <Vec<u8>>::sse_decode(deserializer);
+                return crate::api::netplay::NetplayGameEvent::Identity {
+                    user_id: var_userId,
+                    user_token: var_userToken,
+                };
+            }
+            15 => {
+                let mut var_graceMs = <u32>::sse_decode(deserializer);
+                return crate::api::netplay::NetplayGameEvent::ServerClosing {
+                    grace_ms: var_graceMs,
+                };
+            }
             _ => {
                 unimplemented!("");
             }
@@ -5097,6 +6243,22 @@ impl SseDecode for crate::api::netplay::NetplayGameEvent {
     }
 }
 
+impl SseDecode for crate::api::netplay::NetplayPeerStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerClientId = <u32>::sse_decode(deserializer);
+        let mut var_rttMs = <u32>::sse_decode(deserializer);
+        let mut var_jitterMs = <u32>::sse_decode(deserializer);
+        let mut var_lossPercent = <u8>::sse_decode(deserializer);
+        return crate::api::netplay::NetplayPeerStats {
+            peer_client_id: var_peerClientId,
+            rtt_ms: var_rttMs,
+            jitter_ms: var_jitterMs,
+            loss_percent: var_lossPercent,
+        };
+    }
+}
+
 impl SseDecode for crate::api::netplay::NetplayPlayer {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5111,6 +6273,30 @@ impl SseDecode for crate::api::netplay::NetplayPlayer {
     }
 }
 
+impl SseDecode for crate::api::netplay::NetplayRoomSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_roomCode = <u32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_region = <String>::sse_decode(deserializer);
+        let mut var_gameHash = <u32>::sse_decode(deserializer);
+        let mut var_playerCount = <u8>::sse_decode(deserializer);
+        let mut var_spectatorCount = <u8>::sse_decode(deserializer);
+        let mut var_openSlots = <u8>::sse_decode(deserializer);
+        let mut var_started = <bool>::sse_decode(deserializer);
+        return crate::api::netplay::NetplayRoomSummary {
+            room_code: var_roomCode,
+            name: var_name,
+            region: var_region,
+            game_hash: var_gameHash,
+            player_count: var_playerCount,
+            spectator_count: var_spectatorCount,
+            open_slots: var_openSlots,
+            started: var_started,
+        };
+    }
+}
+
 impl SseDecode for crate::api::netplay::NetplayState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5157,6 +6343,7 @@ impl SseDecode for crate::api::netplay::NetplayTransport {
             0 => crate::api::netplay::NetplayTransport::Unknown,
             1 => crate::api::netplay::NetplayTransport::Tcp,
             2 => crate::api::netplay::NetplayTransport::Quic,
+            3 => crate::api::netplay::NetplayTransport::WebRtc,
             _ => unreachable!("Invalid variant for NetplayTransport: {}", inner),
         };
     }
@@ -5238,6 +6425,17 @@ impl SseDecode for Option<crate::api::gamepad::GamepadButtonFfi> {
     }
 }
 
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5367,6 +6565,8 @@ impl SseDecode for crate::api::events::RuntimeNotificationKind {
         let mut inner = <i32>::sse_decode(deserializer);
         return match inner {
             0 => crate::api::events::RuntimeNotificationKind::AudioInitFailed,
+            1 => crate::api::events::RuntimeNotificationKind::EmergencySaved,
+            2 => crate::api::events::RuntimeNotificationKind::EmergencyStateAvailable,
             _ => unreachable!("Invalid variant for RuntimeNotificationKind: {}", inner),
         };
     }
@@ -5485,6 +6685,19 @@ impl SseDecode for crate::api::events::SpriteSnapshot {
     }
 }
 
+impl SseDecode for crate::api::audio::StereoPreset {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::audio::StereoPreset::Mono,
+            1 => crate::api::audio::StereoPreset::Light,
+            2 => crate::api::audio::StereoPreset::MesenSpread,
+            _ => unreachable!("Invalid variant for StereoPreset: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::api::events::TileSnapshot {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5657,411 +6870,515 @@ fn pde_ffi_dispatcher_primary_impl(
     match func_id {
         1 => wire__crate__api__events__aux_texture_ids_impl(port, ptr, rust_vec_len, data_len),
         2 => wire__crate__api__gamepad__bind_gamepad_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__events__debug_state_stream_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__events__emulation_status_stream_impl(
+        3 => wire__crate__api__input__clear_zapper_pointer_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__events__debug_state_stream_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__events__emulation_status_stream_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => wire__crate__api__audio__expansion_balance_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        7 => wire__crate__api__gamepad__gamepad_actions_ffi_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__gamepad__gamepad_poll_result_ffi_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__gamepad__get_gamepad_mapping_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__gamepad__get_gamepad_pressed_buttons_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        11 => wire__crate__api__net_utils__get_local_addrs_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__net_utils__get_public_ip_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__load_rom__get_rom_hash_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__server__get_server_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__gamepad__init_gamepad_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__instance__instance_load_rom_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__instance__instance_load_rom_from_bytes_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__instance__instance_load_state_from_memory_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        22 => wire__crate__api__instance__instance_reset_console_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        5 => wire__crate__api__gamepad__gamepad_actions_ffi_default_impl(
+        23 => wire__crate__api__instance__instance_save_state_to_memory_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        6 => wire__crate__api__gamepad__gamepad_poll_result_ffi_default_impl(
+        24 => wire__crate__api__instance__instance_set_pad_mask_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        7 => wire__crate__api__gamepad__get_gamepad_mapping_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__gamepad__get_gamepad_pressed_buttons_impl(
+        25 => {
+            wire__crate__api__instance__instance_set_paused_impl(port, ptr, rust_vec_len, data_len)
+        }
+        26 => wire__crate__api__pause__is_paused_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__video__lcd_grid_options_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        9 => wire__crate__api__net_utils__get_local_addrs_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__net_utils__get_public_ip_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__load_rom__get_rom_hash_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__server__get_server_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__gamepad__init_gamepad_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__pause__is_paused_impl(port, ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__video__lcd_grid_options_default_impl(
+        28 => wire__crate__api__gamepad__list_gamepads_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__emulation__load_emergency_state_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        19 => wire__crate__api__gamepad__list_gamepads_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__load_rom__load_rom_impl(port, ptr, rust_vec_len, data_len),
-        21 => {
+        30 => wire__crate__api__load_rom__load_rom_impl(port, ptr, rust_vec_len, data_len),
+        31 => {
             wire__crate__api__load_rom__load_rom_from_bytes_impl(port, ptr, rust_vec_len, data_len)
         }
-        22 => wire__crate__api__emulation__load_state_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__emulation__load_state_from_memory_impl(
+        32 => wire__crate__api__emulation__load_state_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__emulation__load_state_from_memory_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        24 => wire__crate__api__emulation__load_tas_movie_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__netplay__netplay_connect_impl(port, ptr, rust_vec_len, data_len),
-        26 => {
+        34 => wire__crate__api__emulation__load_tas_movie_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__netplay__netplay_connect_impl(port, ptr, rust_vec_len, data_len),
+        36 => {
             wire__crate__api__netplay__netplay_connect_auto_impl(port, ptr, rust_vec_len, data_len)
         }
-        27 => wire__crate__api__netplay__netplay_connect_auto_pinned_impl(
+        37 => wire__crate__api__netplay__netplay_connect_auto_pinned_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        28 => {
+        38 => {
             wire__crate__api__netplay__netplay_connect_quic_impl(port, ptr, rust_vec_len, data_len)
         }
-        29 => wire__crate__api__netplay__netplay_connect_quic_pinned_impl(
+        39 => wire__crate__api__netplay__netplay_connect_quic_pinned_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        40 => {
+            wire__crate__api__netplay__netplay_connect_tls_impl(port, ptr, rust_vec_len, data_len)
+        }
+        41 => wire__crate__api__netplay__netplay_connect_tls_pinned_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        30 => {
+        42 => {
             wire__crate__api__netplay__netplay_create_room_impl(port, ptr, rust_vec_len, data_len)
         }
-        31 => wire__crate__api__netplay__netplay_disconnect_impl(port, ptr, rust_vec_len, data_len),
-        32 => wire__crate__api__netplay__netplay_game_event_stream_impl(
+        43 => wire__crate__api__netplay__netplay_disconnect_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__netplay__netplay_game_event_stream_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        33 => {
+        45 => {
             wire__crate__api__netplay__netplay_is_connected_impl(port, ptr, rust_vec_len, data_len)
         }
-        34 => wire__crate__api__netplay__netplay_join_room_impl(port, ptr, rust_vec_len, data_len),
-        35 => wire__crate__api__netplay__netplay_p2p_connect_join_auto_impl(
+        46 => wire__crate__api__netplay__netplay_join_room_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__netplay__netplay_list_rooms_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__netplay__netplay_p2p_connect_join_auto_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        36 => wire__crate__api__netplay__netplay_p2p_create_room_impl(
+        49 => wire__crate__api__netplay__netplay_p2p_create_room_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        37 => wire__crate__api__netplay__netplay_p2p_host_create_and_watch_fallback_impl(
+        50 => wire__crate__api__netplay__netplay_p2p_host_create_and_watch_fallback_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        38 => wire__crate__api__netplay__netplay_p2p_host_start_impl(
+        51 => wire__crate__api__netplay__netplay_p2p_host_start_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        39 => {
+        52 => {
             wire__crate__api__netplay__netplay_p2p_join_room_impl(port, ptr, rust_vec_len, data_len)
         }
-        40 => wire__crate__api__netplay__netplay_p2p_request_fallback_impl(
+        53 => wire__crate__api__netplay__netplay_p2p_request_fallback_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        41 => {
+        54 => {
             wire__crate__api__netplay__netplay_provide_state_impl(port, ptr, rust_vec_len, data_len)
         }
-        42 => wire__crate__api__netplay__netplay_request_fallback_relay_impl(
+        55 => {
+            wire__crate__api__netplay__netplay_quick_match_impl(port, ptr, rust_vec_len, data_len)
+        }
+        56 => wire__crate__api__netplay__netplay_request_fallback_relay_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        43 => {
+        57 => {
             wire__crate__api__netplay__netplay_request_state_impl(port, ptr, rust_vec_len, data_len)
         }
-        44 => wire__crate__api__netplay__netplay_send_pause_impl(port, ptr, rust_vec_len, data_len),
-        45 => wire__crate__api__netplay__netplay_send_reset_impl(port, ptr, rust_vec_len, data_len),
-        46 => wire__crate__api__netplay__netplay_send_rom_impl(port, ptr, rust_vec_len, data_len),
-        47 => wire__crate__api__netplay__netplay_send_rom_loaded_impl(
+        58 => wire__crate__api__netplay__netplay_send_chat_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__netplay__netplay_send_emote_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__netplay__netplay_send_pause_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__netplay__netplay_send_reset_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__netplay__netplay_send_rom_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__netplay__netplay_send_rom_loaded_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        64 => {
+            wire__crate__api__netplay__netplay_set_room_info_impl(port, ptr, rust_vec_len, data_len)
+        }
+        65 => wire__crate__api__netplay__netplay_set_spectator_delay_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        66 => wire__crate__api__netplay__netplay_set_user_token_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        48 => {
+        67 => {
             wire__crate__api__netplay__netplay_status_stream_impl(port, ptr, rust_vec_len, data_len)
         }
-        49 => {
+        68 => {
             wire__crate__api__netplay__netplay_switch_role_impl(port, ptr, rust_vec_len, data_len)
         }
-        50 => wire__crate__api__server__netserver_get_port_impl(port, ptr, rust_vec_len, data_len),
-        51 => {
+        69 => wire__crate__api__server__netserver_get_port_impl(port, ptr, rust_vec_len, data_len),
+        70 => {
             wire__crate__api__server__netserver_is_running_impl(port, ptr, rust_vec_len, data_len)
         }
-        52 => wire__crate__api__server__netserver_start_impl(port, ptr, rust_vec_len, data_len),
-        53 => wire__crate__api__server__netserver_status_stream_impl(
+        71 => wire__crate__api__server__netserver_start_impl(port, ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__server__netserver_status_stream_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        54 => wire__crate__api__server__netserver_stop_impl(port, ptr, rust_vec_len, data_len),
-        55 => wire__crate__api__video__ntsc_bisqwit_options_default_impl(
+        73 => wire__crate__api__server__netserver_stop_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__video__ntsc_bisqwit_options_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        56 => wire__crate__api__video__ntsc_options_default_impl(port, ptr, rust_vec_len, data_len),
-        57 => wire__crate__api__palette__palette_presets_impl(port, ptr, rust_vec_len, data_len),
-        58 => {
+        75 => wire__crate__api__video__ntsc_options_default_impl(port, ptr, rust_vec_len, data_len),
+        76 => wire__crate__api__palette__palette_presets_impl(port, ptr, rust_vec_len, data_len),
+        77 => {
             wire__crate__api__events__palette_state_stream_impl(port, ptr, rust_vec_len, data_len)
         }
-        59 => wire__crate__api__gamepad__poll_gamepads_impl(port, ptr, rust_vec_len, data_len),
-        60 => wire__crate__api__load_rom__power_off_console_impl(port, ptr, rust_vec_len, data_len),
-        61 => {
+        78 => wire__crate__api__gamepad__poll_gamepads_impl(port, ptr, rust_vec_len, data_len),
+        79 => wire__crate__api__load_rom__power_off_console_impl(port, ptr, rust_vec_len, data_len),
+        80 => {
             wire__crate__api__load_rom__power_reset_console_impl(port, ptr, rust_vec_len, data_len)
         }
-        62 => wire__crate__api__events__replay_event_stream_impl(port, ptr, rust_vec_len, data_len),
-        63 => wire__crate__api__load_rom__reset_console_impl(port, ptr, rust_vec_len, data_len),
-        64 => wire__crate__api__gamepad__rumble_gamepad_impl(port, ptr, rust_vec_len, data_len),
-        65 => {
+        81 => wire__crate__api__events__replay_event_stream_impl(port, ptr, rust_vec_len, data_len),
+        82 => wire__crate__api__load_rom__reset_console_impl(port, ptr, rust_vec_len, data_len),
+        83 => wire__crate__api__gamepad__rumble_gamepad_impl(port, ptr, rust_vec_len, data_len),
+        84 => {
             wire__crate__api__events__runtime_notifications_impl(port, ptr, rust_vec_len, data_len)
         }
-        66 => wire__crate__api__emulation__save_state_impl(port, ptr, rust_vec_len, data_len),
-        67 => wire__crate__api__emulation__save_state_to_memory_impl(
+        85 => wire__crate__api__emulation__save_state_impl(port, ptr, rust_vec_len, data_len),
+        86 => wire__crate__api__emulation__save_state_to_memory_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        68 => wire__crate__api__video__scanline_options_default_impl(
+        87 => wire__crate__api__video__scanline_options_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        69 => wire__crate__api__emulation__set_fast_forward_speed_impl(
+        88 => wire__crate__api__audio__set_audio_stereo_impl(port, ptr, rust_vec_len, data_len),
+        89 => wire__crate__api__emulation__set_clone_ppu_impl(port, ptr, rust_vec_len, data_len),
+        90 => wire__crate__api__emulation__set_fast_forward_speed_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        70 => {
+        91 => {
             wire__crate__api__emulation__set_fast_forwarding_impl(port, ptr, rust_vec_len, data_len)
         }
-        71 => {
+        92 => {
             wire__crate__api__gamepad__set_gamepad_mapping_impl(port, ptr, rust_vec_len, data_len)
         }
-        72 => wire__crate__api__emulation__set_high_priority_enabled_impl(
+        93 => wire__crate__api__emulation__set_high_priority_enabled_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        73 => wire__crate__api__emulation__set_integer_fps_mode_impl(
+        94 => wire__crate__api__emulation__set_integer_fps_mode_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        74 => wire__crate__api__video__set_lcd_grid_options_impl(port, ptr, rust_vec_len, data_len),
-        75 => wire__crate__api__video__set_ntsc_bisqwit_options_impl(
+        95 => wire__crate__api__video__set_lcd_grid_options_impl(port, ptr, rust_vec_len, data_len),
+        96 => wire__crate__api__video__set_ntsc_bisqwit_options_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        76 => wire__crate__api__video__set_ntsc_options_impl(port, ptr, rust_vec_len, data_len),
-        77 => wire__crate__api__input__set_pad_mask_impl(port, ptr, rust_vec_len, data_len),
-        78 => wire__crate__api__events__set_palette_capture_frame_start_impl(
+        97 => wire__crate__api__video__set_ntsc_options_impl(port, ptr, rust_vec_len, data_len),
+        98 => wire__crate__api__input__set_pad_mask_impl(port, ptr, rust_vec_len, data_len),
+        99 => wire__crate__api__events__set_palette_capture_frame_start_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        79 => wire__crate__api__events__set_palette_capture_scanline_impl(
+        100 => wire__crate__api__events__set_palette_capture_scanline_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        80 => wire__crate__api__events__set_palette_capture_vblank_start_impl(
+        101 => wire__crate__api__events__set_palette_capture_vblank_start_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        81 => {
+        102 => {
             wire__crate__api__palette__set_palette_pal_data_impl(port, ptr, rust_vec_len, data_len)
         }
-        82 => wire__crate__api__palette__set_palette_preset_impl(port, ptr, rust_vec_len, data_len),
-        83 => wire__crate__api__pause__set_paused_impl(port, ptr, rust_vec_len, data_len),
-        84 => {
+        103 => {
+            wire__crate__api__palette__set_palette_preset_impl(port, ptr, rust_vec_len, data_len)
+        }
+        104 => wire__crate__api__pause__set_paused_impl(port, ptr, rust_vec_len, data_len),
+        105 => {
             wire__crate__api__emulation__set_rewind_config_impl(port, ptr, rust_vec_len, data_len)
         }
-        85 => wire__crate__api__emulation__set_rewind_speed_impl(port, ptr, rust_vec_len, data_len),
-        86 => wire__crate__api__emulation__set_rewinding_impl(port, ptr, rust_vec_len, data_len),
-        87 => wire__crate__api__video__set_scanline_options_impl(port, ptr, rust_vec_len, data_len),
-        88 => wire__crate__api__video__set_shader_config_impl(port, ptr, rust_vec_len, data_len),
-        89 => wire__crate__api__video__set_shader_enabled_impl(port, ptr, rust_vec_len, data_len),
-        90 => wire__crate__api__video__set_shader_parameter_impl(port, ptr, rust_vec_len, data_len),
-        91 => {
+        106 => {
+            wire__crate__api__emulation__set_rewind_speed_impl(port, ptr, rust_vec_len, data_len)
+        }
+        107 => wire__crate__api__emulation__set_rewinding_impl(port, ptr, rust_vec_len, data_len),
+        108 => {
+            wire__crate__api__video__set_scanline_options_impl(port, ptr, rust_vec_len, data_len)
+        }
+        109 => wire__crate__api__video__set_shader_config_impl(port, ptr, rust_vec_len, data_len),
+        110 => wire__crate__api__video__set_shader_enabled_impl(port, ptr, rust_vec_len, data_len),
+        111 => {
+            wire__crate__api__video__set_shader_parameter_impl(port, ptr, rust_vec_len, data_len)
+        }
+        112 => {
             wire__crate__api__video__set_shader_preset_path_impl(port, ptr, rust_vec_len, data_len)
         }
-        92 => wire__crate__api__events__set_sprite_capture_frame_start_impl(
+        113 => wire__crate__api__events__set_sprite_capture_frame_start_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        93 => wire__crate__api__events__set_sprite_capture_scanline_impl(
+        114 => wire__crate__api__events__set_sprite_capture_scanline_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        94 => wire__crate__api__events__set_sprite_capture_vblank_start_impl(
+        115 => wire__crate__api__events__set_sprite_capture_vblank_start_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        95 => wire__crate__api__events__set_tile_viewer_background_impl(
+        116 => wire__crate__api__events__set_tile_viewer_background_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        96 => wire__crate__api__events__set_tile_viewer_capture_frame_start_impl(
+        117 => wire__crate__api__events__set_tile_viewer_capture_frame_start_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        97 => wire__crate__api__events__set_tile_viewer_capture_scanline_impl(
+        118 => wire__crate__api__events__set_tile_viewer_capture_scanline_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        98 => wire__crate__api__events__set_tile_viewer_capture_vblank_start_impl(
+        119 => wire__crate__api__events__set_tile_viewer_capture_vblank_start_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        99 => wire__crate__api__events__set_tile_viewer_display_mode_impl(
+        120 => wire__crate__api__events__set_tile_viewer_display_mode_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        100 => {
+        121 => {
             wire__crate__api__events__set_tile_viewer_layout_impl(port, ptr, rust_vec_len, data_len)
         }
-        101 => wire__crate__api__events__set_tile_viewer_palette_impl(
+        122 => wire__crate__api__events__set_tile_viewer_palette_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        102 => {
+        123 => {
             wire__crate__api__events__set_tile_viewer_size_impl(port, ptr, rust_vec_len, data_len)
         }
-        103 => {
+        124 => {
             wire__crate__api__events__set_tile_viewer_source_impl(port, ptr, rust_vec_len, data_len)
         }
-        104 => wire__crate__api__events__set_tile_viewer_start_address_impl(
+        125 => wire__crate__api__events__set_tile_viewer_start_address_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        126 => wire__crate__api__events__set_tilemap_capture_frame_start_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        105 => wire__crate__api__events__set_tilemap_capture_frame_start_impl(
+        127 => wire__crate__api__events__set_tilemap_capture_scanline_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        106 => wire__crate__api__events__set_tilemap_capture_scanline_impl(
+        128 => wire__crate__api__events__set_tilemap_capture_vblank_start_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        107 => wire__crate__api__events__set_tilemap_capture_vblank_start_impl(
+        129 => wire__crate__api__events__set_tilemap_display_mode_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        108 => wire__crate__api__events__set_tilemap_display_mode_impl(
+        130 => wire__crate__api__input__set_turbo_frames_per_toggle_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        109 => wire__crate__api__input__set_turbo_frames_per_toggle_impl(
+        131 => wire__crate__api__input__set_turbo_mask_impl(port, ptr, rust_vec_len, data_len),
+        132 => wire__crate__api__input__set_turbo_timing_impl(port, ptr, rust_vec_len, data_len),
+        133 => wire__crate__api__video__set_video_filter_impl(port, ptr, rust_vec_len, data_len),
+        134 => {
+            wire__crate__api__input__set_zapper_calibration_impl(port, ptr, rust_vec_len, data_len)
+        }
+        135 => {
+            wire__crate__api__input__set_zapper_crosshair_impl(port, ptr, rust_vec_len, data_len)
+        }
+        136 => wire__crate__api__input__set_zapper_lag_compensation_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        110 => wire__crate__api__input__set_turbo_mask_impl(port, ptr, rust_vec_len, data_len),
-        111 => wire__crate__api__input__set_turbo_timing_impl(port, ptr, rust_vec_len, data_len),
-        112 => wire__crate__api__video__set_video_filter_impl(port, ptr, rust_vec_len, data_len),
-        113 => wire__crate__api__gamepad__shutdown_gamepad_impl(port, ptr, rust_vec_len, data_len),
-        114 => {
+        137 => wire__crate__api__input__set_zapper_pointer_impl(port, ptr, rust_vec_len, data_len),
+        138 => wire__crate__api__input__set_zapper_port_impl(port, ptr, rust_vec_len, data_len),
+        139 => wire__crate__api__input__set_zapper_trigger_impl(port, ptr, rust_vec_len, data_len),
+        140 => wire__crate__api__gamepad__shutdown_gamepad_impl(port, ptr, rust_vec_len, data_len),
+        141 => {
             wire__crate__api__events__sprite_state_stream_impl(port, ptr, rust_vec_len, data_len)
         }
-        115 => {
+        142 => wire__crate__api__instance__start_instance_impl(port, ptr, rust_vec_len, data_len),
+        143 => {
             wire__crate__api__load_rom__start_nes_runtime_impl(port, ptr, rust_vec_len, data_len)
         }
-        116 => wire__crate__api__events__subscribe_tilemap_texture_impl(
+        144 => wire__crate__api__instance__stop_instance_impl(port, ptr, rust_vec_len, data_len),
+        145 => wire__crate__api__events__subscribe_tilemap_texture_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        117 => wire__crate__api__events__tile_state_stream_impl(port, ptr, rust_vec_len, data_len),
-        118 => {
+        146 => wire__crate__api__events__tile_state_stream_impl(port, ptr, rust_vec_len, data_len),
+        147 => {
             wire__crate__api__events__tilemap_state_stream_impl(port, ptr, rust_vec_len, data_len)
         }
-        119 => wire__crate__api__pause__toggle_pause_impl(port, ptr, rust_vec_len, data_len),
-        120 => {
+        148 => wire__crate__api__pause__toggle_pause_impl(port, ptr, rust_vec_len, data_len),
+        149 => {
             wire__crate__api__net_utils__try_upnp_mapping_impl(port, ptr, rust_vec_len, data_len)
         }
-        121 => wire__crate__api__events__unsubscribe_debug_state_impl(
+        150 => wire__crate__api__events__unsubscribe_debug_state_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        122 => wire__crate__api__events__unsubscribe_palette_state_impl(
+        151 => wire__crate__api__events__unsubscribe_palette_state_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        123 => wire__crate__api__events__unsubscribe_sprite_state_impl(
+        152 => wire__crate__api__events__unsubscribe_sprite_state_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        124 => {
+        153 => {
             wire__crate__api__events__unsubscribe_tile_state_impl(port, ptr, rust_vec_len, data_len)
         }
-        125 => wire__crate__api__events__unsubscribe_tilemap_texture_impl(
+        154 => wire__crate__api__events__unsubscribe_tilemap_texture_impl(
             port,
             ptr,
             rust_vec_len,
@@ -6079,8 +7396,8 @@ fn pde_ffi_dispatcher_sync_impl(
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        13 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__simple__init_app_paths_impl(ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__simple__init_app_paths_impl(ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -6165,6 +7482,31 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::events::EmulationStatusNotifi
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio::ExpansionBalance {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.fds.into_into_dart().into_dart(),
+            self.mmc5.into_into_dart().into_dart(),
+            self.vrc6.into_into_dart().into_dart(),
+            self.vrc7.into_into_dart().into_dart(),
+            self.namco163.into_into_dart().into_dart(),
+            self.sunsoft5b.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio::ExpansionBalance
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio::ExpansionBalance>
+    for crate::api::audio::ExpansionBalance
+{
+    fn into_into_dart(self) -> crate::api::audio::ExpansionBalance {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::gamepad::GamepadActionsFfi {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -6362,6 +7704,52 @@ impl flutter_rust_bridge::IntoDart for crate::api::netplay::NetplayGameEvent {
                 reason.into_into_dart().into_dart(),
             ]
             .into_dart(),
+            crate::api::netplay::NetplayGameEvent::Desync { frame } => {
+                [8.into_dart(), frame.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::netplay::NetplayGameEvent::Chat {
+                client_id,
+                name,
+                text,
+                emote,
+            } => [
+                9.into_dart(),
+                client_id.into_into_dart().into_dart(),
+                name.into_into_dart().into_dart(),
+                text.into_into_dart().into_dart(),
+                emote.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::netplay::NetplayGameEvent::NetworkStats { peers } => {
+                [10.into_dart(), peers.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::netplay::NetplayGameEvent::InputDelayRecommended { frames } => {
+                [11.into_dart(), frames.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::netplay::NetplayGameEvent::RoomList { rooms } => {
+                [12.into_dart(), rooms.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::netplay::NetplayGameEvent::SpectatorInfo {
+                count,
+                delay_frames,
+            } => [
+                13.into_dart(),
+                count.into_into_dart().into_dart(),
+                delay_frames.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::netplay::NetplayGameEvent::Identity {
+                user_id,
+                user_token,
+            } => [
+                14.into_dart(),
+                user_id.into_into_dart().into_dart(),
+                user_token.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::netplay::NetplayGameEvent::ServerClosing { grace_ms } => {
+                [15.into_dart(), grace_ms.into_into_dart().into_dart()].into_dart()
+            }
             _ => {
                 unimplemented!("");
             }
@@ -6380,6 +7768,29 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::netplay::NetplayGameEvent>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::netplay::NetplayPeerStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_client_id.into_into_dart().into_dart(),
+            self.rtt_ms.into_into_dart().into_dart(),
+            self.jitter_ms.into_into_dart().into_dart(),
+            self.loss_percent.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::netplay::NetplayPeerStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::netplay::NetplayPeerStats>
+    for crate::api::netplay::NetplayPeerStats
+{
+    fn into_into_dart(self) -> crate::api::netplay::NetplayPeerStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::netplay::NetplayPlayer {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -6402,6 +7813,33 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::netplay::NetplayPlayer>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::netplay::NetplayRoomSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.room_code.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.region.into_into_dart().into_dart(),
+            self.game_hash.into_into_dart().into_dart(),
+            self.player_count.into_into_dart().into_dart(),
+            self.spectator_count.into_into_dart().into_dart(),
+            self.open_slots.into_into_dart().into_dart(),
+            self.started.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::netplay::NetplayRoomSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::netplay::NetplayRoomSummary>
+    for crate::api::netplay::NetplayRoomSummary
+{
+    fn into_into_dart(self) -> crate::api::netplay::NetplayRoomSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::netplay::NetplayState {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -6458,6 +7896,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::netplay::NetplayTransport {
             Self::Unknown => 0.into_dart(),
             Self::Tcp => 1.into_dart(),
             Self::Quic => 2.into_dart(),
+            Self::WebRtc => 3.into_dart(),
             _ => unreachable!(),
         }
     }
@@ -6694,6 +8133,8 @@ impl flutter_rust_bridge::IntoDart for crate::api::events::RuntimeNotificationKi
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
             Self::AudioInitFailed => 0.into_dart(),
+            Self::EmergencySaved => 1.into_dart(),
+            Self::EmergencyStateAvailable => 2.into_dart(),
             _ => unreachable!(),
         }
     }
@@ -6855,6 +8296,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::events::SpriteSnapshot>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio::StereoPreset {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Mono => 0.into_dart(),
+            Self::Light => 1.into_dart(),
+            Self::MesenSpread => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio::StereoPreset
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio::StereoPreset>
+    for crate::api::audio::StereoPreset
+{
+    fn into_into_dart(self) -> crate::api::audio::StereoPreset {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::events::TileSnapshot {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -7186,6 +8649,18 @@ impl SseEncode for crate::api::events::EmulationStatusNotification {
     }
 }
 
+impl SseEncode for crate::api::audio::ExpansionBalance {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.fds, serializer);
+        <f32>::sse_encode(self.mmc5, serializer);
+        <f32>::sse_encode(self.vrc6, serializer);
+        <f32>::sse_encode(self.vrc7, serializer);
+        <f32>::sse_encode(self.namco163, serializer);
+        <f32>::sse_encode(self.sunsoft5b, serializer);
+    }
+}
+
 impl SseEncode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7338,6 +8813,16 @@ impl SseEncode for Vec<crate::api::gamepad::GamepadInfoFfi> {
     }
 }
 
+impl SseEncode for Vec<crate::api::netplay::NetplayPeerStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::netplay::NetplayPeerStats>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::netplay::NetplayPlayer> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7348,6 +8833,16 @@ impl SseEncode for Vec<crate::api::netplay::NetplayPlayer> {
     }
 }
 
+impl SseEncode for Vec<crate::api::netplay::NetplayRoomSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::netplay::NetplayRoomSummary>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::palette::PalettePresetInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7430,6 +8925,54 @@ impl SseEncode for crate::api::netplay::NetplayGameEvent {
                 <u32>::sse_encode(relay_room_code, serializer);
                 <String>::sse_encode(reason, serializer);
             }
+            crate::api::netplay::NetplayGameEvent::Desync { frame } => {
+                <i32>::sse_encode(8, serializer);
+                <u32>::sse_encode(frame, serializer);
+            }
+            crate::api::netplay::NetplayGameEvent::Chat {
+                client_id,
+                name,
+                text,
+                emote,
+            } => {
+                <i32>::sse_encode(9, serializer);
+                <u32>::sse_encode(client_id, serializer);
+                <String>::sse_encode(name, serializer);
+                <Option<String>>::sse_encode(text, serializer);
+                <Option<u8>>::sse_encode(emote, serializer);
+            }
+            crate::api::netplay::NetplayGameEvent::NetworkStats { peers } => {
+                <i32>::sse_encode(10, serializer);
+                <Vec<crate::api::netplay::NetplayPeerStats>>::sse_encode(peers, serializer);
+            }
+            crate::api::netplay::NetplayGameEvent::InputDelayRecommended { frames } => {
+                <i32>::sse_encode(11, serializer);
+                <u32>::sse_encode(frames, serializer);
+            }
+            crate::api::netplay::NetplayGameEvent::RoomList { rooms } => {
+                <i32>::sse_encode(12, serializer);
+                <Vec<crate::api::netplay::NetplayRoomSummary>>::sse_encode(rooms, serializer);
+            }
+            crate::api::netplay::NetplayGameEvent::SpectatorInfo {
+                count,
+                delay_frames,
+            } => {
+                <i32>::sse_encode(13, serializer);
+                <u16>::sse_encode(count, serializer);
+                <u32>::sse_encode(delay_frames, serializer);
+            }
+            crate::api::netplay::NetplayGameEvent::Identity {
+                user_id,
+                user_token,
+            } => {
+                <i32>::sse_encode(14, serializer);
+                <u64>::sse_encode(user_id, serializer);
+                <Vec<u8>>::sse_encode(user_token, serializer);
+            }
+            crate::api::netplay::NetplayGameEvent::ServerClosing { grace_ms } => {
+                <i32>::sse_encode(15, serializer);
+                <u32>::sse_encode(grace_ms, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -7437,6 +8980,16 @@ impl SseEncode for crate::api::netplay::NetplayGameEvent {
     }
 }
 
+impl SseEncode for crate::api::netplay::NetplayPeerStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.peer_client_id, serializer);
+        <u32>::sse_encode(self.rtt_ms, serializer);
+        <u32>::sse_encode(self.jitter_ms, serializer);
+        <u8>::sse_encode(self.loss_percent, serializer);
+    }
+}
+
 impl SseEncode for crate::api::netplay::NetplayPlayer {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7446,6 +8999,20 @@ impl SseEncode for crate::api::netplay::NetplayPlayer {
     }
 }
 
+impl SseEncode for crate::api::netplay::NetplayRoomSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.room_code, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.region, serializer);
+        <u32>::sse_encode(self.game_hash, serializer);
+        <u8>::sse_encode(self.player_count, serializer);
+        <u8>::sse_encode(self.spectator_count, serializer);
+        <u8>::sse_encode(self.open_slots, serializer);
+        <bool>::sse_encode(self.started, serializer);
+    }
+}
+
 impl SseEncode for crate::api::netplay::NetplayState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7486,6 +9053,7 @@ impl SseEncode for crate::api::netplay::NetplayTransport {
                 crate::api::netplay::NetplayTransport::Unknown => 0,
                 crate::api::netplay::NetplayTransport::Tcp => 1,
                 crate::api::netplay::NetplayTransport::Quic => 2,
+                crate::api::netplay::NetplayTransport::WebRtc => 3,
                 _ => {
                     unimplemented!("");
                 }
@@ -7545,6 +9113,16 @@ impl SseEncode for Option<crate::api::gamepad::GamepadButtonFfi> {
     }
 }
 
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7662,6 +9240,8 @@ impl SseEncode for crate::api::events::RuntimeNotificationKind {
         <i32>::sse_encode(
             match self {
                 crate::api::events::RuntimeNotificationKind::AudioInitFailed => 0,
+                crate::api::events::RuntimeNotificationKind::EmergencySaved => 1,
+                crate::api::events::RuntimeNotificationKind::EmergencyStateAvailable => 2,
                 _ => {
                     unimplemented!("");
                 }
@@ -7739,6 +9319,23 @@ impl SseEncode for crate::api::events::SpriteSnapshot {
     }
 }
 
+impl SseEncode for crate::api::audio::StereoPreset {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::audio::StereoPreset::Mono => 0,
+                crate::api::audio::StereoPreset::Light => 1,
+                crate::api::audio::StereoPreset::MesenSpread => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::events::TileSnapshot {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
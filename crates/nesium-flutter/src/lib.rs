@@ -33,20 +33,27 @@ pub mod windows;
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
 use std::{
+    collections::HashMap,
     os::raw::{c_uint, c_void},
-    sync::{Arc, OnceLock},
+    sync::{
+        Arc, OnceLock,
+        atomic::{AtomicU32, Ordering},
+    },
 };
 
 use nesium_core::{
+    config::{overclock::Overclock, ram_init::RamInit},
     controller::Button as CoreButton,
     ppu::{
         SCREEN_HEIGHT, SCREEN_WIDTH,
         buffer::{ColorFormat, ExternalFrameHandle, FrameReadyCallback},
     },
 };
+use nesium_runtime::audio::AudioLatencyConfig;
 use nesium_runtime::{
-    AudioMode, Runtime, RuntimeConfig, RuntimeHandle, VideoBackendConfig, VideoConfig,
+    AudioMode, Runtime, RuntimeConfig, RuntimeError, RuntimeHandle, VideoBackendConfig, VideoConfig,
 };
+use parking_lot::Mutex;
 
 pub const FRAME_WIDTH: usize = SCREEN_WIDTH;
 pub const FRAME_HEIGHT: usize = SCREEN_HEIGHT;
@@ -65,7 +72,20 @@ struct RuntimeHolder {
     _video: VideoBacking,
 }
 
-static RUNTIME: OnceLock<RuntimeHolder> = OnceLock::new();
+/// Instance id of the primary runtime. Every API without an explicit
+/// instance id talks to it; it starts on first use and is never stopped.
+pub const DEFAULT_INSTANCE: u32 = 0;
+
+/// Every running runtime by instance id: [`DEFAULT_INSTANCE`] plus those
+/// started next to it (comparison views, spectating several netplay rooms).
+static INSTANCES: OnceLock<Mutex<HashMap<u32, Arc<RuntimeHolder>>>> = OnceLock::new();
+
+/// The [`DEFAULT_INSTANCE`] entry of [`INSTANCES`], kept for `'static`
+/// access from platform code.
+static DEFAULT_RUNTIME: OnceLock<Arc<RuntimeHolder>> = OnceLock::new();
+
+/// Source of instance ids for [`start_instance`]; never reused.
+static NEXT_INSTANCE_ID: AtomicU32 = AtomicU32::new(DEFAULT_INSTANCE + 1);
 
 /// Returns the platform-specific pixel format for Flutter textures.
 ///
@@ -83,80 +103,159 @@ pub fn platform_color_format() -> ColorFormat {
     }
 }
 
+/// Starts [`DEFAULT_INSTANCE`] if needed.
 fn ensure_runtime() -> &'static RuntimeHolder {
-    RUNTIME.get_or_init(|| {
-        let color_format = platform_color_format();
-        let video_cfg = VideoConfig {
-            color_format,
-            output_width: FRAME_WIDTH as u32,
-            output_height: FRAME_HEIGHT as u32,
-            backend: VideoBackendConfig::Owned,
-        };
+    DEFAULT_RUNTIME.get_or_init(|| {
+        // Flush battery RAM and write an emergency state if the app is
+        // killed while a game is running.
+        nesium_runtime::runtime::install_termination_watchdog();
+        let holder = Arc::new(start_default_runtime());
+        instances()
+            .lock()
+            .insert(DEFAULT_INSTANCE, Arc::clone(&holder));
+        holder
+    })
+}
 
-        #[cfg(target_os = "android")]
-        let (runtime, video_backing) = if android::use_ahb_video_backend() {
-            let mut video_cfg = video_cfg;
-            let swapchain = Arc::new(
-                android::AhbSwapchain::new(video_cfg.output_width, video_cfg.output_height)
-                    .expect("failed to initialize AHB swapchain"),
-            );
-            let user_data = Arc::as_ptr(&swapchain) as *mut c_void;
-            video_cfg.backend = VideoBackendConfig::Swapchain {
-                lock: android::ahb_lock_plane,
-                unlock: android::ahb_unlock_plane,
-                user_data,
-            };
-            let runtime = Runtime::start(RuntimeConfig {
-                video: video_cfg,
-                audio: AudioMode::Auto,
-            })
-            .expect("failed to start nesium runtime");
-            (runtime, VideoBacking::Ahb(swapchain))
-        } else {
-            let runtime = Runtime::start(RuntimeConfig {
-                video: video_cfg,
-                audio: AudioMode::Auto,
-            })
-            .expect("failed to start nesium runtime");
-            (runtime, VideoBacking::Upload)
-        };
+fn start_default_runtime() -> RuntimeHolder {
+    let color_format = platform_color_format();
+    let video_cfg = VideoConfig {
+        color_format,
+        output_width: FRAME_WIDTH as u32,
+        output_height: FRAME_HEIGHT as u32,
+        backend: VideoBackendConfig::Owned,
+    };
 
-        #[cfg(not(target_os = "android"))]
+    #[cfg(target_os = "android")]
+    let (runtime, video_backing) = if android::use_ahb_video_backend() {
+        let mut video_cfg = video_cfg;
+        let swapchain = Arc::new(
+            android::AhbSwapchain::new(video_cfg.output_width, video_cfg.output_height)
+                .expect("failed to initialize AHB swapchain"),
+        );
+        let user_data = Arc::as_ptr(&swapchain) as *mut c_void;
+        video_cfg.backend = VideoBackendConfig::Swapchain {
+            lock: android::ahb_lock_plane,
+            unlock: android::ahb_unlock_plane,
+            user_data,
+        };
         let runtime = Runtime::start(RuntimeConfig {
             video: video_cfg,
             audio: AudioMode::Auto,
+            audio_latency: AudioLatencyConfig::default(),
+            overclock: Overclock::default(),
+            ram_init: RamInit::default(),
         })
         .expect("failed to start nesium runtime");
+        (runtime, VideoBacking::Ahb(swapchain))
+    } else {
+        let runtime = Runtime::start(RuntimeConfig {
+            video: video_cfg,
+            audio: AudioMode::Auto,
+            audio_latency: AudioLatencyConfig::default(),
+            overclock: Overclock::default(),
+            ram_init: RamInit::default(),
+        })
+        .expect("failed to start nesium runtime");
+        (runtime, VideoBacking::Upload)
+    };
 
-        let handle = runtime.handle();
-        #[cfg(target_os = "android")]
-        {
-            // Default frame-ready callback for Android, used by both backends:
-            // - Upload backend: wakes Kotlin GL uploader (pipe)
-            // - AHB backend: wakes Rust renderer (condvar) + pipe (optional)
-            handle
-                .set_frame_ready_callback(
-                    Some(android::android_frame_ready_cb),
-                    std::ptr::null_mut(),
-                )
-                .expect("failed to set android frame ready callback");
-        }
-        let frame_handle = handle.frame_handle().cloned();
-
-        RuntimeHolder {
-            handle,
-            frame_handle,
-            _runtime: runtime,
-            #[cfg(target_os = "android")]
-            _video: video_backing,
-        }
+    #[cfg(not(target_os = "android"))]
+    let runtime = Runtime::start(RuntimeConfig {
+        video: video_cfg,
+        audio: AudioMode::Auto,
+        audio_latency: AudioLatencyConfig::default(),
+        overclock: Overclock::default(),
+        ram_init: RamInit::default(),
     })
+    .expect("failed to start nesium runtime");
+
+    let handle = runtime.handle();
+    #[cfg(target_os = "android")]
+    {
+        // Default frame-ready callback for Android, used by both backends:
+        // - Upload backend: wakes Kotlin GL uploader (pipe)
+        // - AHB backend: wakes Rust renderer (condvar) + pipe (optional)
+        handle
+            .set_frame_ready_callback(Some(android::android_frame_ready_cb), std::ptr::null_mut())
+            .expect("failed to set android frame ready callback");
+    }
+    let frame_handle = handle.frame_handle().cloned();
+
+    RuntimeHolder {
+        handle,
+        frame_handle,
+        _runtime: runtime,
+        #[cfg(target_os = "android")]
+        _video: video_backing,
+    }
 }
 
+/// Handle of [`DEFAULT_INSTANCE`].
 pub(crate) fn runtime_handle() -> &'static RuntimeHandle {
     &ensure_runtime().handle
 }
 
+fn instances() -> &'static Mutex<HashMap<u32, Arc<RuntimeHolder>>> {
+    INSTANCES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Looks up a running instance; [`DEFAULT_INSTANCE`] is started on demand.
+fn instance(id: u32) -> Option<Arc<RuntimeHolder>> {
+    if id == DEFAULT_INSTANCE {
+        ensure_runtime();
+    }
+    instances().lock().get(&id).cloned()
+}
+
+/// Starts an additional runtime and returns its instance id.
+///
+/// Extra instances render into an owned framebuffer in the platform color
+/// format; platform runners read it with `nesium_instance_copy_frame`. No
+/// frame-ready callback is installed until one is set for the instance.
+pub(crate) fn start_instance(audio: AudioMode) -> Result<u32, RuntimeError> {
+    let runtime = Runtime::start(RuntimeConfig {
+        video: VideoConfig {
+            color_format: platform_color_format(),
+            output_width: FRAME_WIDTH as u32,
+            output_height: FRAME_HEIGHT as u32,
+            backend: VideoBackendConfig::Owned,
+        },
+        audio,
+        audio_latency: AudioLatencyConfig::default(),
+        overclock: Overclock::default(),
+        ram_init: RamInit::default(),
+    })?;
+    let handle = runtime.handle();
+    let holder = RuntimeHolder {
+        frame_handle: handle.frame_handle().cloned(),
+        handle,
+        _runtime: runtime,
+        #[cfg(target_os = "android")]
+        _video: VideoBacking::Upload,
+    };
+    let id = NEXT_INSTANCE_ID.fetch_add(1, Ordering::Relaxed);
+    instances().lock().insert(id, Arc::new(holder));
+    Ok(id)
+}
+
+/// Stops an instance started with [`start_instance`], joining its thread.
+/// Returns `false` for unknown ids and for [`DEFAULT_INSTANCE`], which runs
+/// for the life of the process.
+pub(crate) fn stop_instance(id: u32) -> bool {
+    if id == DEFAULT_INSTANCE {
+        return false;
+    }
+    // Drop outside the lock: shutting the runtime down joins its thread.
+    let holder = instances().lock().remove(&id);
+    holder.is_some()
+}
+
+/// Handle of instance `id`, or `None` if it is not running.
+pub(crate) fn instance_handle(id: u32) -> Option<RuntimeHandle> {
+    instance(id).map(|holder| holder.handle.clone())
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PadButton {
     A,
@@ -214,8 +313,7 @@ pub extern "C" fn nesium_set_frame_ready_callback(
     cb: Option<FrameReadyCallback>,
     user_data: *mut c_void,
 ) {
-    let handle = runtime_handle();
-    let _ = handle.set_frame_ready_callback(cb, user_data);
+    nesium_instance_set_frame_ready_callback(DEFAULT_INSTANCE, cb, user_data);
 }
 
 /// Copy the current NES frame into a destination buffer.
@@ -230,14 +328,79 @@ pub unsafe extern "C" fn nesium_copy_frame(
     dst: *mut u8,
     dst_pitch: c_uint,
     dst_height: c_uint,
+) {
+    unsafe {
+        nesium_instance_copy_frame(DEFAULT_INSTANCE, _buffer_index, dst, dst_pitch, dst_height)
+    };
+}
+
+/// Starts an additional runtime instance next to [`DEFAULT_INSTANCE`].
+///
+/// Returns its id, or `u32::MAX` if it could not be started. Pass `audio =
+/// false` for instances that should stay silent (e.g. the second game of a
+/// comparison view).
+#[unsafe(no_mangle)]
+pub extern "C" fn nesium_instance_start(audio: bool) -> c_uint {
+    let mode = if audio {
+        AudioMode::Auto
+    } else {
+        AudioMode::Disabled
+    };
+    start_instance(mode).unwrap_or(u32::MAX)
+}
+
+/// Stops an instance started with `nesium_instance_start`. The default
+/// instance keeps running.
+#[unsafe(no_mangle)]
+pub extern "C" fn nesium_instance_stop(id: c_uint) {
+    stop_instance(id);
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn nesium_instance_set_frame_ready_callback(
+    id: c_uint,
+    cb: Option<FrameReadyCallback>,
+    user_data: *mut c_void,
+) {
+    if let Some(handle) = instance_handle(id) {
+        let _ = handle.set_frame_ready_callback(cb, user_data);
+    }
+}
+
+/// Copies the current frame of instance `id`; `nesium_copy_frame` copies
+/// [`DEFAULT_INSTANCE`].
+///
+/// # Safety
+/// - `dst` must be null or point to at least `dst_pitch * dst_height` writable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nesium_instance_copy_frame(
+    id: c_uint,
+    _buffer_index: c_uint,
+    dst: *mut u8,
+    dst_pitch: c_uint,
+    dst_height: c_uint,
 ) {
     if dst.is_null() {
         return;
     }
+    // Clone the handle so the copy runs without holding the instance lock.
+    let frame_handle = instance(id).and_then(|holder| holder.frame_handle.clone());
+    if let Some(frame_handle) = frame_handle {
+        unsafe { copy_front_frame(&frame_handle, dst, dst_pitch, dst_height) };
+    }
+}
 
-    let Some(frame_handle) = ensure_runtime().frame_handle.as_deref() else {
-        return;
-    };
+/// Copies the front plane of `frame_handle` into `dst`, row by row when the
+/// pitches differ.
+///
+/// # Safety
+/// - `dst` must point to at least `dst_pitch * dst_height` writable bytes.
+unsafe fn copy_front_frame(
+    frame_handle: &ExternalFrameHandle,
+    dst: *mut u8,
+    dst_pitch: c_uint,
+    dst_height: c_uint,
+) {
     let idx = frame_handle.begin_front_copy();
     let src_slice = frame_handle.plane_slice(idx);
 
@@ -292,3 +455,64 @@ pub unsafe extern "C" fn nesium_copy_frame(
 
     frame_handle.end_front_copy();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    /// NROM image whose reset vector points at a `JMP $8000` loop.
+    fn spinning_nrom() -> Vec<u8> {
+        let mut rom = b"NES\x1A\x01\x00".to_vec();
+        rom.extend_from_slice(&[0; 10]);
+        let mut prg = vec![0xEA; 16 * 1024];
+        prg[..3].copy_from_slice(&[0x4C, 0x00, 0x80]);
+        for vector in [0x3FFA, 0x3FFC, 0x3FFE] {
+            prg[vector..vector + 2].copy_from_slice(&[0x00, 0x80]);
+        }
+        rom.extend_from_slice(&prg);
+        rom
+    }
+
+    fn wait_until(what: &str, mut done: impl FnMut() -> bool) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !done() {
+            assert!(Instant::now() < deadline, "timed out waiting for {what}");
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn instances_run_side_by_side() {
+        let a = start_instance(AudioMode::Disabled).expect("start a");
+        let b = start_instance(AudioMode::Disabled).expect("start b");
+        assert_ne!(a, b);
+        assert_ne!(a, DEFAULT_INSTANCE);
+
+        let handle_a = instance_handle(a).expect("handle a");
+        let handle_b = instance_handle(b).expect("handle b");
+        handle_a
+            .load_rom_from_memory(spinning_nrom())
+            .expect("load a");
+        handle_b
+            .load_rom_from_memory(spinning_nrom())
+            .expect("load b");
+
+        // Pausing one instance leaves the other running.
+        handle_a.pause().expect("pause a");
+        let paused_at = handle_a.frame_seq();
+        let start_b = handle_b.frame_seq();
+        wait_until("instance b to run", || handle_b.frame_seq() > start_b + 5);
+        assert_eq!(handle_a.frame_seq(), paused_at);
+
+        // Stopping one instance leaves the other alive.
+        assert!(stop_instance(b));
+        assert!(instance_handle(b).is_none());
+        assert!(!stop_instance(b));
+        handle_a.resume().expect("resume a");
+        wait_until("instance a to resume", || handle_a.frame_seq() > paused_at);
+
+        assert!(!stop_instance(DEFAULT_INSTANCE));
+        assert!(stop_instance(a));
+    }
+}
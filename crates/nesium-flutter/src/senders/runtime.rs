@@ -25,6 +25,33 @@ impl RuntimeEventSender for FlutterRuntimeEventSender {
                     kind: RuntimeNotificationKind::AudioInitFailed,
                     error: Some(error),
                 },
+                NotificationEvent::EmergencySaved { reason, .. } => RuntimeNotification {
+                    kind: RuntimeNotificationKind::EmergencySaved,
+                    error: Some(reason),
+                },
+                NotificationEvent::EmergencyStateAvailable { .. } => RuntimeNotification {
+                    kind: RuntimeNotificationKind::EmergencyStateAvailable,
+                    error: None,
+                },
+                // Load failures already reach Dart through the `load_rom` result.
+                NotificationEvent::RomLoadProgress { .. }
+                | NotificationEvent::RomLoadWarning { .. }
+                | NotificationEvent::RomLoadFailed { .. }
+                | NotificationEvent::CartridgeLoaded { .. }
+                | NotificationEvent::RomReloaded { .. }
+                | NotificationEvent::ReplayCheckFinished { .. }
+                // Not surfaced to Dart yet; the runtime still auto-pauses if asked to.
+                | NotificationEvent::CpuJammed { .. }
+                | NotificationEvent::RainbowBridgeClosed { .. }
+                | NotificationEvent::StateSaved { .. }
+                | NotificationEvent::StateLoaded { .. }
+                | NotificationEvent::StateLoadUndone
+                | NotificationEvent::ScreenshotSaved { .. }
+                | NotificationEvent::ScreenshotFailed { .. }
+                | NotificationEvent::BatteryRamSaved { .. }
+                | NotificationEvent::StateFileSaved { .. }
+                | NotificationEvent::StateFileLoaded { .. }
+                | NotificationEvent::OperationFailed { .. } => return true,
             };
             let _ = self.sink.add(notification);
             return true;
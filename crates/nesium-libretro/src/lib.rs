@@ -10,7 +10,6 @@ use libretro_bridge::{
 };
 use nesium_core::{
     Nes,
-    cartridge::load_cartridge,
     controller::Button,
     ppu::{SCREEN_HEIGHT, SCREEN_WIDTH, buffer::ColorFormat},
     reset_kind::ResetKind,
@@ -138,12 +137,10 @@ impl LibretroCore for NesiumCore {
 
     fn load_game(&mut self, game: &GameInfo<'_>) -> Result<(), LoadGameError> {
         match game.data {
-            Some(data) => {
-                let cartridge =
-                    load_cartridge(data).map_err(|e| LoadGameError::Message(e.to_string()))?;
-                self.nes.insert_cartridge(cartridge);
-                Ok(())
-            }
+            Some(data) => self
+                .nes
+                .load_cartridge_from_bytes(data)
+                .map_err(|e| LoadGameError::Message(e.to_string())),
             None => Err(LoadGameError::MissingContent),
         }
     }
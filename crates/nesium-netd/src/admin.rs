@@ -0,0 +1,489 @@
+//! Token-protected HTTP admin endpoint.
+//!
+//! Lets operators inspect and manage a running server without restarting it:
+//!
+//! - `GET /rooms`: open rooms with their players, spectators and relayed bytes
+//! - `GET /connections`: every connection, the client it belongs to and the
+//!   bytes that client relayed
+//! - `GET /rate-limits`: messages dropped by rate limiters and relay quotas
+//!   since startup
+//! - `POST /clients/{client_id}/kick`: drop all connections of a client
+//! - `POST /rooms/{room_id}/close`: drop everyone in a room
+//! - `GET /bans`: bans in effect
+//! - `POST /bans/{target}`: ban `ip:<addr>` or `user:<id>` and drop its connections
+//! - `DELETE /bans/{target}`: lift a ban
+//!
+//! Every request must carry `Authorization: Bearer <token>`. The listener only
+//! parses requests; commands are executed by the server main loop, which owns
+//! all connection and room state.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+
+use ring::digest;
+use serde::Serialize;
+use serde_json::{Value, json};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, oneshot};
+use tracing::{debug, info, warn};
+
+use crate::http::{read_request_head, request_target, write_response};
+use crate::net::inbound::ConnId;
+use crate::observability::metrics::rate_limited_counts;
+use crate::room::state::RoomManager;
+use crate::room::store::{Ban, BanTarget};
+use crate::{ConnCtx, ConnRole};
+
+/// Admin requests waiting for the server loop.
+const QUEUE_CAPACITY: usize = 16;
+
+/// Sender half used by the admin listener to reach the server loop.
+pub type AdminTx = mpsc::Sender<AdminRequest>;
+/// Receiver half handed to the server loop (see [`crate::ServerOptions::admin`]).
+pub type AdminRx = mpsc::Receiver<AdminRequest>;
+
+/// Creates the channel between the admin listener and the server loop.
+pub fn channel() -> (AdminTx, AdminRx) {
+    mpsc::channel(QUEUE_CAPACITY)
+}
+
+/// Operation requested through the admin API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdminCommand {
+    ListRooms,
+    ListConnections,
+    RateLimits,
+    Kick { client_id: u32 },
+    CloseRoom { room_id: u32 },
+    ListBans,
+    Ban { target: BanTarget },
+    Unban { target: BanTarget },
+}
+
+/// Admin command awaiting execution by the server loop.
+#[derive(Debug)]
+pub struct AdminRequest {
+    pub command: AdminCommand,
+    reply: oneshot::Sender<AdminReply>,
+}
+
+/// Outcome of an admin command: JSON body on success, or `404` with a message.
+pub type AdminReply = Result<Value, &'static str>;
+
+#[derive(Serialize)]
+struct RoomEntry {
+    room_id: u32,
+    room_code: u32,
+    name: String,
+    private: bool,
+    started: bool,
+    paused: bool,
+    current_frame: u32,
+    relayed_bytes: u64,
+    players: Vec<MemberEntry>,
+    spectators: Vec<MemberEntry>,
+}
+
+#[derive(Serialize)]
+struct MemberEntry {
+    client_id: u32,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    player_index: Option<u8>,
+}
+
+#[derive(Serialize)]
+struct ConnectionEntry {
+    conn_id: ConnId,
+    peer: SocketAddr,
+    transport: String,
+    role: String,
+    client_id: u32,
+    name: String,
+    room_id: Option<u32>,
+    relayed_bytes: u64,
+    rate_limited: u64,
+}
+
+#[derive(Serialize)]
+struct BanEntry {
+    target: String,
+    reason: String,
+    expires_at: Option<u64>,
+}
+
+/// Runs `request` against the server state and sends back the reply.
+pub(crate) fn execute(
+    request: AdminRequest,
+    conns: &HashMap<ConnId, ConnCtx>,
+    room_mgr: &mut RoomManager,
+) {
+    let reply = match request.command {
+        AdminCommand::ListRooms => Ok(list_rooms(room_mgr)),
+        AdminCommand::ListConnections => Ok(list_connections(conns, room_mgr)),
+        AdminCommand::RateLimits => Ok(rate_limits()),
+        AdminCommand::Kick { client_id } => kick(conns, client_id),
+        AdminCommand::CloseRoom { room_id } => close_room(conns, room_mgr, room_id),
+        AdminCommand::ListBans => Ok(list_bans(room_mgr)),
+        AdminCommand::Ban { target } => Ok(ban(conns, room_mgr, target)),
+        AdminCommand::Unban { target } => unban(room_mgr, target),
+    };
+    let _ = request.reply.send(reply);
+}
+
+fn list_rooms(room_mgr: &RoomManager) -> Value {
+    let mut rooms: Vec<RoomEntry> = room_mgr
+        .rooms()
+        .map(|room| {
+            let mut players: Vec<MemberEntry> = room
+                .players
+                .values()
+                .map(|p| MemberEntry {
+                    client_id: p.client_id,
+                    name: p.name.clone(),
+                    player_index: Some(p.player_index),
+                })
+                .collect();
+            players.sort_by_key(|p| p.player_index);
+            RoomEntry {
+                room_id: room.id,
+                room_code: room.code,
+                name: room.info.name.clone(),
+                private: room.password.is_some(),
+                started: room.started,
+                paused: room.paused,
+                current_frame: room.current_frame,
+                relayed_bytes: room.relay.total(),
+                players,
+                spectators: room
+                    .spectators
+                    .iter()
+                    .map(|s| MemberEntry {
+                        client_id: s.client_id,
+                        name: s.name.clone(),
+                        player_index: None,
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+    rooms.sort_by_key(|r| r.room_id);
+    json!(rooms)
+}
+
+fn list_connections(conns: &HashMap<ConnId, ConnCtx>, room_mgr: &RoomManager) -> Value {
+    let mut entries: Vec<ConnectionEntry> = conns
+        .iter()
+        .map(|(conn_id, ctx)| ConnectionEntry {
+            conn_id: *conn_id,
+            peer: ctx.peer,
+            transport: format!("{:?}", ctx.transport).to_ascii_lowercase(),
+            role: match ctx.role {
+                ConnRole::Unbound => "unbound".to_string(),
+                ConnRole::Control => "control".to_string(),
+                ConnRole::Channel(ch) => format!("{ch:?}").to_ascii_lowercase(),
+            },
+            client_id: ctx.assigned_client_id,
+            name: ctx.name.clone(),
+            room_id: room_mgr.get_client_room(ctx.assigned_client_id),
+            relayed_bytes: room_mgr.client_relayed_bytes(ctx.assigned_client_id),
+            rate_limited: ctx.rate_limited,
+        })
+        .collect();
+    entries.sort_by_key(|e| e.conn_id);
+    json!(entries)
+}
+
+fn rate_limits() -> Value {
+    let counts: serde_json::Map<String, Value> = rate_limited_counts()
+        .into_iter()
+        .map(|(kind, count)| (kind.to_string(), json!(count)))
+        .collect();
+    Value::Object(counts)
+}
+
+/// Cancels every connection of `client_ids`; the usual disconnect handling
+/// then removes them from their rooms.
+fn close_clients(conns: &HashMap<ConnId, ConnCtx>, client_ids: &[u32]) -> usize {
+    let mut closed = 0;
+    for ctx in conns.values() {
+        if ctx.assigned_client_id != 0 && client_ids.contains(&ctx.assigned_client_id) {
+            ctx.close.cancel();
+            closed += 1;
+        }
+    }
+    closed
+}
+
+fn kick(conns: &HashMap<ConnId, ConnCtx>, client_id: u32) -> AdminReply {
+    let closed = close_clients(conns, &[client_id]);
+    if closed == 0 {
+        return Err("client not connected");
+    }
+    info!(client_id, connections = closed, "Admin kicked client");
+    Ok(json!({ "client_id": client_id, "closed_connections": closed }))
+}
+
+fn close_room(
+    conns: &HashMap<ConnId, ConnCtx>,
+    room_mgr: &mut RoomManager,
+    room_id: u32,
+) -> AdminReply {
+    let Some(room) = room_mgr.get_room_mut(room_id) else {
+        return Err("room not found");
+    };
+    let members: Vec<u32> = room
+        .players
+        .values()
+        .map(|p| p.client_id)
+        .chain(room.spectators.iter().map(|s| s.client_id))
+        .chain(room.p2p_watchers.keys().copied())
+        .collect();
+
+    let closed = close_clients(conns, &members);
+    if closed == 0 {
+        // Nobody left to disconnect, so nothing else will remove it.
+        room_mgr.remove_room(room_id);
+    }
+    info!(room_id, connections = closed, "Admin closed room");
+    Ok(json!({ "room_id": room_id, "closed_connections": closed }))
+}
+
+fn list_bans(room_mgr: &RoomManager) -> Value {
+    let mut bans: Vec<BanEntry> = room_mgr
+        .bans()
+        .map(|ban| BanEntry {
+            target: ban.target.to_string(),
+            reason: ban.reason.clone(),
+            expires_at: ban.expires_at,
+        })
+        .collect();
+    bans.sort_by(|a, b| a.target.cmp(&b.target));
+    json!(bans)
+}
+
+fn ban(conns: &HashMap<ConnId, ConnCtx>, room_mgr: &mut RoomManager, target: BanTarget) -> Value {
+    room_mgr.ban(Ban {
+        target,
+        reason: "admin".to_string(),
+        expires_at: None,
+    });
+
+    let mut closed = 0;
+    for ctx in conns.values() {
+        let matches = match target {
+            BanTarget::Ip(ip) => ctx.peer.ip() == ip,
+            BanTarget::User(id) => ctx.user_id != 0 && ctx.user_id == id,
+        };
+        if matches {
+            ctx.close.cancel();
+            closed += 1;
+        }
+    }
+    info!(%target, connections = closed, "Admin banned");
+    json!({ "target": target.to_string(), "closed_connections": closed })
+}
+
+fn unban(room_mgr: &mut RoomManager, target: BanTarget) -> AdminReply {
+    if !room_mgr.unban(target) {
+        return Err("not banned");
+    }
+    info!(%target, "Admin lifted ban");
+    Ok(json!({ "target": target.to_string() }))
+}
+
+/// Start the admin listener on `bind`.
+pub async fn run_admin_listener(bind: SocketAddr, token: &str, tx: AdminTx) -> anyhow::Result<()> {
+    let listener = TcpListener::bind(bind).await?;
+    run_admin_listener_with_listener(listener, token, tx).await
+}
+
+/// Run the admin accept loop on an existing listener.
+pub async fn run_admin_listener_with_listener(
+    listener: TcpListener,
+    token: &str,
+    tx: AdminTx,
+) -> anyhow::Result<()> {
+    anyhow::ensure!(!token.is_empty(), "admin token must not be empty");
+    // Requests are checked against the digest so comparisons don't leak the token.
+    let token_digest = digest::digest(&digest::SHA256, token.as_bytes());
+
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_http(stream, token_digest, tx).await {
+                debug!(%peer, error = %e, "Admin request failed");
+            }
+        });
+    }
+}
+
+async fn handle_http(
+    mut stream: TcpStream,
+    token_digest: digest::Digest,
+    tx: AdminTx,
+) -> anyhow::Result<()> {
+    let head = read_request_head(&mut stream).await?;
+    let (status, body) = match parse_request(&head, token_digest.as_ref()) {
+        Ok(command) => match dispatch(command, &tx).await {
+            Ok(body) => (200, body),
+            Err(msg) => (404, json!({ "error": msg })),
+        },
+        Err((status, msg)) => {
+            if status == 401 {
+                warn!(peer = ?stream.peer_addr().ok(), "Rejected admin request with bad token");
+            }
+            (status, json!({ "error": msg }))
+        }
+    };
+    let body = serde_json::to_string(&body)?;
+    write_response(&mut stream, status, "application/json", &body).await
+}
+
+async fn dispatch(command: AdminCommand, tx: &AdminTx) -> AdminReply {
+    let (reply, rx) = oneshot::channel();
+    if tx.send(AdminRequest { command, reply }).await.is_err() {
+        return Err("server loop stopped");
+    }
+    rx.await.unwrap_or(Err("server loop stopped"))
+}
+
+/// Authenticates and routes a request head, or returns the error status.
+fn parse_request(head: &str, token_digest: &[u8]) -> Result<AdminCommand, (u16, &'static str)> {
+    let Some((method, path)) = request_target(head) else {
+        return Err((400, "malformed request line"));
+    };
+
+    let token = head
+        .split("\r\n")
+        .skip(1)
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("authorization"))
+        .and_then(|(_, value)| value.trim().strip_prefix("Bearer "));
+    let authorized = token.is_some_and(|t| {
+        digest::digest(&digest::SHA256, t.trim().as_bytes()).as_ref() == token_digest
+    });
+    if !authorized {
+        return Err((401, "missing or invalid token"));
+    }
+
+    route(method, path)
+}
+
+fn route(method: &str, path: &str) -> Result<AdminCommand, (u16, &'static str)> {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let id = |s: &str| s.parse::<u32>().map_err(|_| (400, "invalid id"));
+
+    let (expected, command) = match segments.as_slice() {
+        ["rooms"] => ("GET", AdminCommand::ListRooms),
+        ["connections"] => ("GET", AdminCommand::ListConnections),
+        ["rate-limits"] => ("GET", AdminCommand::RateLimits),
+        ["clients", client_id, "kick"] => (
+            "POST",
+            AdminCommand::Kick {
+                client_id: id(client_id)?,
+            },
+        ),
+        ["rooms", room_id, "close"] => (
+            "POST",
+            AdminCommand::CloseRoom {
+                room_id: id(room_id)?,
+            },
+        ),
+        ["bans"] => ("GET", AdminCommand::ListBans),
+        ["bans", target] => {
+            let target = target.parse().map_err(|_| (400, "invalid ban target"))?;
+            // User id 0 stands for "no identity" and would match every guest.
+            if target == BanTarget::User(0) {
+                return Err((400, "invalid ban target"));
+            }
+            match method {
+                "DELETE" => ("DELETE", AdminCommand::Unban { target }),
+                _ => ("POST", AdminCommand::Ban { target }),
+            }
+        }
+        _ => return Err((404, "unknown endpoint")),
+    };
+    if method != expected {
+        return Err((405, "method not allowed"));
+    }
+    Ok(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn head(request_line: &str, auth: Option<&str>) -> String {
+        let mut head = format!("{request_line}\r\nHost: localhost");
+        if let Some(auth) = auth {
+            head.push_str(&format!("\r\nAuthorization: {auth}"));
+        }
+        head
+    }
+
+    #[test]
+    fn requests_need_the_bearer_token() {
+        let digest = digest::digest(&digest::SHA256, b"s3cret");
+        let digest = digest.as_ref();
+
+        assert_eq!(
+            parse_request(
+                &head("GET /rooms?all HTTP/1.1", Some("Bearer s3cret")),
+                digest
+            ),
+            Ok(AdminCommand::ListRooms)
+        );
+        for auth in [None, Some("Bearer wrong"), Some("Basic s3cret")] {
+            assert_eq!(
+                parse_request(&head("GET /rooms HTTP/1.1", auth), digest),
+                Err((401, "missing or invalid token"))
+            );
+        }
+    }
+
+    #[test]
+    fn routes_by_path_and_method() {
+        assert_eq!(
+            route("GET", "/connections"),
+            Ok(AdminCommand::ListConnections)
+        );
+        assert_eq!(route("GET", "/rate-limits/"), Ok(AdminCommand::RateLimits));
+        assert_eq!(
+            route("POST", "/clients/7/kick"),
+            Ok(AdminCommand::Kick { client_id: 7 })
+        );
+        assert_eq!(
+            route("POST", "/rooms/3/close"),
+            Ok(AdminCommand::CloseRoom { room_id: 3 })
+        );
+        assert_eq!(
+            route("GET", "/rooms/3/close"),
+            Err((405, "method not allowed"))
+        );
+        assert_eq!(
+            route("DELETE", "/bans/ip:::1"),
+            Ok(AdminCommand::Unban {
+                target: BanTarget::Ip("::1".parse().expect("ip"))
+            })
+        );
+        assert_eq!(
+            route("POST", "/bans/user:9"),
+            Ok(AdminCommand::Ban {
+                target: BanTarget::User(9)
+            })
+        );
+        assert_eq!(
+            route("POST", "/bans/user:0"),
+            Err((400, "invalid ban target"))
+        );
+        assert_eq!(
+            route("PUT", "/bans/user:9"),
+            Err((405, "method not allowed"))
+        );
+        assert_eq!(route("POST", "/bans/9"), Err((400, "invalid ban target")));
+        assert_eq!(route("POST", "/clients/x/kick"), Err((400, "invalid id")));
+        assert_eq!(route("GET", "/"), Err((404, "unknown endpoint")));
+    }
+}
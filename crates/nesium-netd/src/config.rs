@@ -0,0 +1,285 @@
+//! TOML configuration file.
+//!
+//! Every key is optional and falls back to the built-in default, so an empty
+//! file behaves like no file at all; command-line flags override the file.
+//! [`ServerSettings`] (the `[limits]` table) can be reloaded while the server
+//! runs; the other tables are only read at startup.
+//!
+//! ```toml
+//! db = "/var/lib/nesium/netd.db"
+//!
+//! [listen]
+//! tcp = "0.0.0.0:5233"
+//! quic = "0.0.0.0:5234"
+//! udp = "0.0.0.0:5235"
+//! admin = "127.0.0.1:5240"
+//! metrics = "127.0.0.1:9233"
+//!
+//! [tls]
+//! cert = "/etc/nesium/cert.pem"
+//! key = "/etc/nesium/key.pem"
+//!
+//! [admin]
+//! token = "change-me"
+//!
+//! [limits]
+//! chat_burst = 5
+//! chat_refill_ms = 1000
+//! max_rooms = 500
+//! max_connections_per_ip = 16
+//! handshake_failures_allowed = 3
+//! handshake_backoff_ms = 1000
+//! handshake_backoff_max_secs = 300
+//! room_relay_quota_bytes = 67108864
+//! client_relay_quota_bytes = 33554432
+//! relay_quota_window_secs = 60
+//!
+//! [cleanup]
+//! datagram_idle_timeout_secs = 30
+//! saved_room_ttl_days = 30
+//!
+//! [shutdown]
+//! drain_timeout_secs = 10
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::Context;
+use nesium_netproto::constants::DATAGRAM_IDLE_TIMEOUT_SECS;
+use serde::Deserialize;
+
+use crate::rate_limit::RateLimiter;
+use crate::room::store::SAVED_ROOM_TTL;
+
+/// Default TCP bind address.
+pub const DEFAULT_TCP_BIND: &str = "0.0.0.0:5233";
+
+/// How long a shutdown waits for connections to close on their own.
+pub const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Chat messages a client may send in a burst.
+const DEFAULT_CHAT_BURST: u32 = 5;
+/// Time to regain one chat message after the burst is used up.
+const DEFAULT_CHAT_REFILL_MS: u64 = 1000;
+/// Connections one address may hold at once.
+const DEFAULT_MAX_CONNECTIONS_PER_IP: usize = 16;
+/// Failed handshakes from one address before it is refused for a while.
+const DEFAULT_HANDSHAKE_FAILURES_ALLOWED: u32 = 3;
+const DEFAULT_HANDSHAKE_BACKOFF_MS: u64 = 1000;
+const DEFAULT_HANDSHAKE_BACKOFF_MAX_SECS: u64 = 300;
+const DEFAULT_RELAY_QUOTA_WINDOW_SECS: u64 = 60;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub listen: ListenConfig,
+    pub tls: TlsConfig,
+    pub admin: AdminConfig,
+    pub webhook: WebhookFileConfig,
+    /// SQLite database for persistent rooms, bans and identities.
+    pub db: Option<PathBuf>,
+    pub limits: ServerSettings,
+    pub cleanup: CleanupConfig,
+    pub shutdown: ShutdownConfig,
+}
+
+impl Config {
+    /// Read and parse the file at `path`.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("invalid config {}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        Ok(toml::from_str(text)?)
+    }
+}
+
+/// Bind addresses; transports other than TCP are disabled when omitted.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ListenConfig {
+    pub tcp: String,
+    /// QUIC listener (UDP); set `enable_quic = false` to turn it off.
+    pub enable_quic: bool,
+    /// Defaults to the TCP address.
+    pub quic: Option<String>,
+    /// Datagram input channel and P2P hole punching.
+    pub udp: Option<String>,
+    pub admin: Option<String>,
+    pub metrics: Option<String>,
+}
+
+impl Default for ListenConfig {
+    fn default() -> Self {
+        Self {
+            tcp: DEFAULT_TCP_BIND.to_string(),
+            enable_quic: true,
+            quic: None,
+            udp: None,
+            admin: None,
+            metrics: None,
+        }
+    }
+}
+
+/// Certificate for QUIC and TLS-over-TCP; auto-generated in `data_dir` when
+/// `cert` and `key` are omitted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TlsConfig {
+    pub cert: Option<PathBuf>,
+    pub key: Option<PathBuf>,
+    pub data_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AdminConfig {
+    /// Bearer token for the admin API; required when it listens.
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WebhookFileConfig {
+    pub url: Option<String>,
+    pub secret: Option<String>,
+}
+
+/// Limits applied by the server loop; reloadable at runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerSettings {
+    /// Chat messages a client may send in a burst.
+    pub chat_burst: u32,
+    /// Milliseconds to regain one chat message.
+    pub chat_refill_ms: u64,
+    /// Rooms open at once; 0 is unlimited.
+    pub max_rooms: usize,
+    /// Stream connections one IP address may hold; 0 is unlimited.
+    pub max_connections_per_ip: usize,
+    /// Failed handshakes an address may make before it is refused.
+    pub handshake_failures_allowed: u32,
+    /// First refusal period, doubled on every further failure.
+    pub handshake_backoff_ms: u64,
+    /// Longest refusal period.
+    pub handshake_backoff_max_secs: u64,
+    /// Bytes a room may relay per quota window; 0 is unlimited.
+    pub room_relay_quota_bytes: u64,
+    /// Bytes a client may relay per quota window; 0 is unlimited.
+    pub client_relay_quota_bytes: u64,
+    pub relay_quota_window_secs: u64,
+}
+
+impl Default for ServerSettings {
+    fn default() -> Self {
+        Self {
+            chat_burst: DEFAULT_CHAT_BURST,
+            chat_refill_ms: DEFAULT_CHAT_REFILL_MS,
+            max_rooms: 0,
+            max_connections_per_ip: DEFAULT_MAX_CONNECTIONS_PER_IP,
+            handshake_failures_allowed: DEFAULT_HANDSHAKE_FAILURES_ALLOWED,
+            handshake_backoff_ms: DEFAULT_HANDSHAKE_BACKOFF_MS,
+            handshake_backoff_max_secs: DEFAULT_HANDSHAKE_BACKOFF_MAX_SECS,
+            room_relay_quota_bytes: 0,
+            client_relay_quota_bytes: 0,
+            relay_quota_window_secs: DEFAULT_RELAY_QUOTA_WINDOW_SECS,
+        }
+    }
+}
+
+impl ServerSettings {
+    /// Fresh chat limiter for a connection.
+    pub fn chat_limiter(&self) -> RateLimiter {
+        RateLimiter::new(self.chat_burst, Duration::from_millis(self.chat_refill_ms))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CleanupConfig {
+    /// Silence after which a UDP peer is dropped.
+    pub datagram_idle_timeout_secs: u64,
+    /// Saved rooms unused for this long are forgotten on startup.
+    pub saved_room_ttl_days: u64,
+}
+
+impl Default for CleanupConfig {
+    fn default() -> Self {
+        Self {
+            datagram_idle_timeout_secs: DATAGRAM_IDLE_TIMEOUT_SECS,
+            saved_room_ttl_days: SAVED_ROOM_TTL.as_secs() / (24 * 60 * 60),
+        }
+    }
+}
+
+impl CleanupConfig {
+    pub fn datagram_idle_timeout(&self) -> Duration {
+        Duration::from_secs(self.datagram_idle_timeout_secs)
+    }
+
+    pub fn saved_room_ttl(&self) -> Duration {
+        Duration::from_secs(self.saved_room_ttl_days * 24 * 60 * 60)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShutdownConfig {
+    /// Time rooms get to finish after the server-closing notice before the
+    /// remaining connections are dropped.
+    pub drain_timeout_secs: u64,
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> Self {
+        Self {
+            drain_timeout_secs: DEFAULT_DRAIN_TIMEOUT.as_secs(),
+        }
+    }
+}
+
+impl ShutdownConfig {
+    pub fn drain_timeout(&self) -> Duration {
+        Duration::from_secs(self.drain_timeout_secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_keys_fall_back_to_defaults() {
+        assert_eq!(Config::parse("").expect("empty"), Config::default());
+
+        let config = Config::parse(
+            r#"
+            db = "netd.db"
+
+            [listen]
+            udp = "0.0.0.0:5235"
+            enable_quic = false
+
+            [limits]
+            max_rooms = 10
+            "#,
+        )
+        .expect("parse");
+        assert_eq!(config.listen.tcp, DEFAULT_TCP_BIND);
+        assert_eq!(config.listen.udp.as_deref(), Some("0.0.0.0:5235"));
+        assert!(!config.listen.enable_quic);
+        assert_eq!(config.db, Some(PathBuf::from("netd.db")));
+        assert_eq!(config.limits.max_rooms, 10);
+        assert_eq!(config.limits.chat_burst, DEFAULT_CHAT_BURST);
+        assert_eq!(config.cleanup.saved_room_ttl(), SAVED_ROOM_TTL);
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        assert!(Config::parse("[limits]\nmax_room = 10").is_err());
+    }
+}
@@ -0,0 +1,64 @@
+//! Minimal HTTP/1.1 plumbing for the operator endpoints (admin API, metrics).
+//!
+//! Each connection serves a single request without a body and is closed after
+//! the response.
+
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// Largest request head (request line and headers) accepted.
+const MAX_REQUEST_LEN: usize = 8 * 1024;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Reads up to the blank line ending the request head; bodies are ignored.
+pub(crate) async fn read_request_head(stream: &mut TcpStream) -> anyhow::Result<String> {
+    tokio::time::timeout(REQUEST_TIMEOUT, async {
+        let mut buf = Vec::with_capacity(1024);
+        let mut chunk = [0u8; 1024];
+        loop {
+            let n = stream.read(&mut chunk).await?;
+            anyhow::ensure!(n > 0, "connection closed before request head");
+            buf.extend_from_slice(&chunk[..n]);
+            if let Some(end) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                buf.truncate(end);
+                return Ok(String::from_utf8(buf)?);
+            }
+            anyhow::ensure!(buf.len() <= MAX_REQUEST_LEN, "request head too large");
+        }
+    })
+    .await?
+}
+
+/// Method and path (without query) of a request head.
+pub(crate) fn request_target(head: &str) -> Option<(&str, &str)> {
+    let mut request_line = head.lines().next()?.split(' ');
+    let method = request_line.next()?;
+    let target = request_line.next()?;
+    Some((method, target.split('?').next().unwrap_or_default()))
+}
+
+/// Writes a complete response and closes the connection.
+pub(crate) async fn write_response(
+    stream: &mut TcpStream,
+    status: u16,
+    content_type: &str,
+    body: &str,
+) -> anyhow::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Error",
+    };
+    let response = format!(
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
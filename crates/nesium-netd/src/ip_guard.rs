@@ -0,0 +1,222 @@
+//! Per-IP abuse controls applied when a connection is accepted.
+//!
+//! Complements the per-connection [`RateLimiter`](crate::rate_limit::RateLimiter):
+//! a host may hold only so many connections at once, and one that keeps
+//! failing the `Hello` handshake is turned away for exponentially longer
+//! periods. Banned addresses are checked by the caller (see
+//! [`RoomManager::is_banned`](crate::room::state::RoomManager::is_banned)).
+
+use std::collections::HashMap;
+use std::fmt;
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+
+use crate::config::ServerSettings;
+
+/// Failure records kept before stale ones are pruned.
+const PRUNE_THRESHOLD: usize = 4096;
+
+/// Why a connection was turned away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    Banned,
+    PerIpLimit,
+    HandshakeBackoff,
+}
+
+impl Rejection {
+    /// Label used in logs and metrics.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Rejection::Banned => "banned",
+            Rejection::PerIpLimit => "per_ip_limit",
+            Rejection::HandshakeBackoff => "handshake_backoff",
+        }
+    }
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Failures {
+    count: u32,
+    last: Instant,
+    blocked_until: Option<Instant>,
+}
+
+#[derive(Debug, Clone)]
+struct Limits {
+    max_connections: usize,
+    failures_allowed: u32,
+    backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl From<&ServerSettings> for Limits {
+    fn from(settings: &ServerSettings) -> Self {
+        Self {
+            max_connections: settings.max_connections_per_ip,
+            failures_allowed: settings.handshake_failures_allowed,
+            backoff: Duration::from_millis(settings.handshake_backoff_ms),
+            max_backoff: Duration::from_secs(settings.handshake_backoff_max_secs),
+        }
+    }
+}
+
+/// Connection counts and handshake failures per remote address.
+#[derive(Debug)]
+pub struct IpGuard {
+    limits: Limits,
+    connections: HashMap<IpAddr, usize>,
+    failures: HashMap<IpAddr, Failures>,
+}
+
+impl IpGuard {
+    pub fn new(settings: &ServerSettings) -> Self {
+        Self {
+            limits: settings.into(),
+            connections: HashMap::new(),
+            failures: HashMap::new(),
+        }
+    }
+
+    /// Apply reloaded limits; connections already admitted are kept.
+    pub fn set_limits(&mut self, settings: &ServerSettings) {
+        self.limits = settings.into();
+    }
+
+    /// Count a new connection from `ip`, unless a limit forbids it.
+    pub fn admit(&mut self, ip: IpAddr) -> Result<(), Rejection> {
+        self.admit_at(ip, Instant::now())
+    }
+
+    fn admit_at(&mut self, ip: IpAddr, now: Instant) -> Result<(), Rejection> {
+        if let Some(f) = self.failures.get(&ip)
+            && f.blocked_until.is_some_and(|t| now < t)
+        {
+            return Err(Rejection::HandshakeBackoff);
+        }
+        let count = self.connections.entry(ip).or_default();
+        if self.limits.max_connections != 0 && *count >= self.limits.max_connections {
+            return Err(Rejection::PerIpLimit);
+        }
+        *count += 1;
+        Ok(())
+    }
+
+    /// Forget a connection counted by [`admit`](Self::admit).
+    pub fn release(&mut self, ip: IpAddr) {
+        if let Some(count) = self.connections.get_mut(&ip) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                self.connections.remove(&ip);
+            }
+        }
+    }
+
+    /// Connections currently counted for `ip`.
+    pub fn connections(&self, ip: IpAddr) -> usize {
+        self.connections.get(&ip).copied().unwrap_or(0)
+    }
+
+    /// Record a connection that closed without completing `Hello`.
+    /// Returns how long `ip` is now refused, if at all.
+    pub fn record_failure(&mut self, ip: IpAddr) -> Option<Duration> {
+        self.record_failure_at(ip, Instant::now())
+    }
+
+    fn record_failure_at(&mut self, ip: IpAddr, now: Instant) -> Option<Duration> {
+        let limits = &self.limits;
+        if self.failures.len() >= PRUNE_THRESHOLD {
+            self.failures
+                .retain(|_, f| now.saturating_duration_since(f.last) < limits.max_backoff);
+        }
+
+        let f = self.failures.entry(ip).or_insert(Failures {
+            count: 0,
+            last: now,
+            blocked_until: None,
+        });
+        // A host that behaved for a full backoff period starts over.
+        if now.saturating_duration_since(f.last) >= limits.max_backoff {
+            f.count = 0;
+        }
+        f.count = f.count.saturating_add(1);
+        f.last = now;
+
+        let excess = f
+            .count
+            .checked_sub(limits.failures_allowed)?
+            .checked_sub(1)?;
+        let backoff = limits
+            .backoff
+            .saturating_mul(1 << excess.min(31))
+            .min(limits.max_backoff);
+        f.blocked_until = Some(now + backoff);
+        Some(backoff)
+    }
+
+    /// A completed handshake clears the failure history of `ip`.
+    pub fn record_success(&mut self, ip: IpAddr) {
+        self.failures.remove(&ip);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guard(max_connections: usize) -> IpGuard {
+        IpGuard::new(&ServerSettings {
+            max_connections_per_ip: max_connections,
+            handshake_failures_allowed: 2,
+            handshake_backoff_ms: 1000,
+            handshake_backoff_max_secs: 5,
+            ..ServerSettings::default()
+        })
+    }
+
+    #[test]
+    fn caps_connections_per_ip() {
+        let mut guard = guard(2);
+        let a: IpAddr = "10.0.0.1".parse().expect("ip");
+        let b: IpAddr = "10.0.0.2".parse().expect("ip");
+        assert_eq!(guard.admit(a), Ok(()));
+        assert_eq!(guard.admit(a), Ok(()));
+        assert_eq!(guard.admit(a), Err(Rejection::PerIpLimit));
+        assert_eq!(guard.admit(b), Ok(()));
+
+        guard.release(a);
+        assert_eq!(guard.connections(a), 1);
+        assert_eq!(guard.admit(a), Ok(()));
+    }
+
+    #[test]
+    fn repeated_handshake_failures_back_off_exponentially() {
+        let mut guard = guard(0);
+        let ip: IpAddr = "10.0.0.1".parse().expect("ip");
+        let start = Instant::now();
+
+        assert_eq!(guard.record_failure_at(ip, start), None);
+        assert_eq!(guard.record_failure_at(ip, start), None);
+        assert_eq!(
+            guard.record_failure_at(ip, start),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(guard.admit_at(ip, start), Err(Rejection::HandshakeBackoff));
+        assert_eq!(guard.admit_at(ip, start + Duration::from_secs(1)), Ok(()));
+
+        let t = start + Duration::from_secs(1);
+        assert_eq!(guard.record_failure_at(ip, t), Some(Duration::from_secs(2)));
+        assert_eq!(guard.record_failure_at(ip, t), Some(Duration::from_secs(4)));
+        assert_eq!(guard.record_failure_at(ip, t), Some(Duration::from_secs(5)));
+
+        guard.record_success(ip);
+        assert_eq!(guard.admit_at(ip, t), Ok(()));
+        assert_eq!(guard.record_failure_at(ip, t), None);
+    }
+}
@@ -1,28 +1,50 @@
 //! Server library - main loop logic extracted for testing.
 
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicU32, AtomicU64};
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::time::Duration;
 
 use nesium_netproto::{
     channel::ChannelKind,
     header::Header,
-    messages::session::{AttachChannel, P2PHostDisconnected, PlayerLeft},
+    messages::session::{
+        AttachChannel, P2PHostDisconnected, P2PPunchRegister, PlayerLeft, ServerClosing, capability,
+    },
     msg_id::MsgId,
 };
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
+use tokio::time::Sleep;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
 
-use crate::net::inbound::{ConnId, InboundEvent};
-use crate::net::outbound::{OutboundTx, send_msg_tcp};
-use crate::proto_dispatch::handlers::dispatch_packet;
+use crate::admin::{AdminRequest, AdminRx};
+use crate::config::{DEFAULT_DRAIN_TIMEOUT, ServerSettings};
+use crate::ip_guard::{IpGuard, Rejection};
+use crate::net::inbound::{ConnId, InboundEvent, TransportKind};
+use crate::net::outbound::{CompressionFlag, OutboundTx, send_msg_tcp};
+use crate::observability::metrics;
+use crate::proto_dispatch::decode::decode_payload;
+use crate::proto_dispatch::handlers::{dispatch_packet, p2p_punch_register};
+use crate::rate_limit::RateLimiter;
+use crate::room::broadcast::broadcast_spectator_info;
 use crate::room::state::RoomManager;
+use crate::room::store::{BanTarget, RoomStore};
+use crate::webhook::{WebhookEvent, WebhookNotifier};
 
 // Export modules publicly for testing
+pub mod admin;
+pub mod config;
+mod http;
+pub mod ip_guard;
 pub mod net;
 pub mod observability;
 pub mod proto_dispatch;
+pub mod rate_limit;
 pub mod room;
 pub mod session;
+pub mod webhook;
 
 /// Monotonically increasing IDs.
 static NEXT_CLIENT_ID: AtomicU32 = AtomicU32::new(1);
@@ -41,55 +63,215 @@ enum ConnRole {
 
 /// Per-connection server-side context.
 struct ConnCtx {
+    peer: SocketAddr,
+    transport: TransportKind,
     outbound: OutboundTx,
+    /// Cancelled to drop the connection (admin kicks).
+    close: CancellationToken,
+    /// Compresses bulk frames written to this connection once negotiated.
+    compress: CompressionFlag,
     assigned_client_id: u32,
     name: String,
     role: ConnRole,
     /// Assigned on `Hello` and used to attach secondary channels.
     session_token: u64,
+    /// Capability flags negotiated on `Hello`.
+    capabilities: u32,
+    /// Persistent user id, 0 if the server keeps no identities.
+    user_id: u64,
     /// Secondary channel outbounds (stored on the control connection).
     channels: HashMap<ChannelKind, OutboundTx>,
+    /// Limits how fast this connection can send chat messages.
+    chat_limiter: RateLimiter,
+    /// Messages from this connection dropped by rate limiters.
+    rate_limited: u64,
+}
+
+/// Optional services the server main loop reports to.
+#[derive(Default)]
+pub struct ServerOptions {
+    /// Receives room lifecycle events.
+    pub webhooks: WebhookNotifier,
+    /// Persists named rooms, bans and user identities.
+    pub store: RoomStore,
+    /// Commands from the admin HTTP API (see [`admin`]).
+    pub admin: Option<AdminRx>,
+    /// Initial limits.
+    pub settings: ServerSettings,
+    /// Replaces `settings` whenever a new value is sent (e.g. on SIGHUP).
+    pub reload: Option<watch::Receiver<ServerSettings>>,
+    /// Cancelled to shut the server down gracefully.
+    pub shutdown: CancellationToken,
+    /// How long a shutdown waits for connections to close on their own;
+    /// [`DEFAULT_DRAIN_TIMEOUT`] when `None`.
+    pub drain_timeout: Option<Duration>,
 }
 
 /// Run the server main loop.
 ///
 /// This is the core server logic, extracted for testability.
-pub async fn run_server(mut rx: mpsc::Receiver<InboundEvent>) -> anyhow::Result<()> {
+pub async fn run_server(rx: mpsc::Receiver<InboundEvent>) -> anyhow::Result<()> {
+    run_server_with_options(rx, ServerOptions::default()).await
+}
+
+/// Run the server main loop, reporting room events to `webhooks`.
+pub async fn run_server_with_webhooks(
+    rx: mpsc::Receiver<InboundEvent>,
+    webhooks: WebhookNotifier,
+) -> anyhow::Result<()> {
+    let options = ServerOptions {
+        webhooks,
+        ..Default::default()
+    };
+    run_server_with_options(rx, options).await
+}
+
+/// Run the server main loop with the given optional services.
+///
+/// Returns once `options.shutdown` is cancelled and the server has drained:
+/// every session is sent [`ServerClosing`], persistent state is flushed, new
+/// connections are refused, and rooms keep being relayed until all clients
+/// have left or the drain timeout passes, at which point the remaining
+/// connections are dropped.
+pub async fn run_server_with_options(
+    mut rx: mpsc::Receiver<InboundEvent>,
+    options: ServerOptions,
+) -> anyhow::Result<()> {
+    let mut admin_rx = options.admin;
+    let mut reload_rx = options.reload;
+    let mut settings = options.settings;
+    let shutdown = options.shutdown;
+    let drain_timeout = options.drain_timeout.unwrap_or(DEFAULT_DRAIN_TIMEOUT);
+    let mut drain_deadline: Option<Pin<Box<Sleep>>> = None;
     let mut conns: HashMap<ConnId, ConnCtx> = HashMap::new();
-    let mut room_mgr = RoomManager::new();
+    let mut room_mgr = RoomManager::with_webhooks(options.webhooks).with_store(options.store);
+    room_mgr.set_max_rooms(settings.max_rooms);
+    room_mgr.set_relay_quota((&settings).into());
+    let mut ip_guard = IpGuard::new(&settings);
     let mut token_to_control_conn: HashMap<u64, ConnId> = HashMap::new();
 
     info!("Server main loop started");
 
-    while let Some(ev) = rx.recv().await {
+    loop {
+        if drain_deadline.is_some() && !has_stream_connections(&conns) {
+            info!("All connections drained");
+            break;
+        }
+
+        let ev = tokio::select! {
+            ev = rx.recv() => match ev {
+                Some(ev) => ev,
+                None => break,
+            },
+            _ = shutdown.cancelled(), if drain_deadline.is_none() => {
+                info!(?drain_timeout, "Shutting down, draining connections");
+                notify_server_closing(&conns, drain_timeout).await;
+                room_mgr.flush();
+                drain_deadline = Some(Box::pin(tokio::time::sleep(drain_timeout)));
+                continue;
+            }
+            _ = drain_expired(&mut drain_deadline) => {
+                warn!(remaining = conns.len(), "Drain timed out, dropping remaining connections");
+                for ctx in conns.values() {
+                    ctx.close.cancel();
+                }
+                break;
+            }
+            Some(request) = recv_admin(&mut admin_rx) => {
+                admin::execute(request, &conns, &mut room_mgr);
+                continue;
+            }
+            Some(new_settings) = recv_reload(&mut reload_rx) => {
+                if new_settings != settings {
+                    info!(?new_settings, "Reloaded server settings");
+                    room_mgr.set_max_rooms(new_settings.max_rooms);
+                    room_mgr.set_relay_quota((&new_settings).into());
+                    ip_guard.set_limits(&new_settings);
+                    for ctx in conns.values_mut() {
+                        ctx.chat_limiter = new_settings.chat_limiter();
+                    }
+                    settings = new_settings;
+                }
+                continue;
+            }
+        };
+
         match ev {
             InboundEvent::Connected {
                 conn_id,
                 peer,
+                transport,
                 outbound,
-                ..
+                compress,
+                close,
             } => {
+                if drain_deadline.is_some() {
+                    debug!(conn_id, %peer, "Refusing connection while shutting down");
+                    close.cancel();
+                    continue;
+                }
+
+                // Datagram peers can't be refused (`close` is unused for them)
+                // and only register by attaching to an existing session.
+                if transport != TransportKind::Udp {
+                    let ip = peer.ip();
+                    let admitted = if room_mgr.is_banned(BanTarget::Ip(ip)) {
+                        Err(Rejection::Banned)
+                    } else {
+                        ip_guard.admit(ip)
+                    };
+                    if let Err(reason) = admitted {
+                        metrics::record_connection_rejected(reason);
+                        debug!(conn_id, %peer, %reason, "Connection refused");
+                        close.cancel();
+                        continue;
+                    }
+                }
+
                 conns.insert(
                     conn_id,
                     ConnCtx {
+                        peer,
+                        transport,
                         outbound,
+                        close,
+                        compress,
                         assigned_client_id: 0,
                         name: String::new(),
                         role: ConnRole::Unbound,
                         session_token: 0,
+                        capabilities: 0,
+                        user_id: 0,
                         channels: HashMap::new(),
+                        chat_limiter: settings.chat_limiter(),
+                        rate_limited: 0,
                     },
                 );
+                metrics::record_connection_opened(transport);
                 debug!(conn_id, %peer, "Client connected");
             }
 
             InboundEvent::Disconnected {
                 conn_id,
                 peer,
+                transport,
                 reason,
-                ..
             } => {
                 if let Some(ctx) = conns.get(&conn_id) {
+                    metrics::record_connection_closed(transport);
+                    if transport != TransportKind::Udp {
+                        ip_guard.release(peer.ip());
+                        if ctx.role == ConnRole::Unbound
+                            && let Some(backoff) = ip_guard.record_failure(peer.ip())
+                        {
+                            info!(
+                                ip = %peer.ip(),
+                                ?backoff,
+                                "Refusing connections after repeated failed handshakes"
+                            );
+                        }
+                    }
+
                     match ctx.role {
                         ConnRole::Control => {
                             if ctx.session_token != 0 {
@@ -104,6 +286,8 @@ pub async fn run_server(mut rx: mpsc::Receiver<InboundEvent>) -> anyhow::Result<
                                         continue;
                                     };
 
+                                    let mut left = true;
+                                    let mut spectator_left = false;
                                     let player_index = if let Some(player) =
                                         room.remove_player(ctx.assigned_client_id)
                                     {
@@ -124,8 +308,10 @@ pub async fn run_server(mut rx: mpsc::Receiver<InboundEvent>) -> anyhow::Result<
                                             role = "spectator",
                                             "Client left room"
                                         );
+                                        spectator_left = true;
                                         None // Spectators don't have a player_index
                                     } else {
+                                        left = false;
                                         None
                                     };
 
@@ -134,8 +320,20 @@ pub async fn run_server(mut rx: mpsc::Receiver<InboundEvent>) -> anyhow::Result<
                                     } else {
                                         Vec::new()
                                     };
+                                    let now_empty = room.is_empty();
+                                    if spectator_left && !now_empty {
+                                        broadcast_spectator_info(room).await;
+                                    }
 
-                                    if room.is_empty() {
+                                    if left {
+                                        room_mgr.webhooks().notify(WebhookEvent::PlayerLeft {
+                                            room_id,
+                                            client_id: ctx.assigned_client_id,
+                                            player_index,
+                                        });
+                                    }
+
+                                    if now_empty {
                                         room_mgr.remove_room(room_id);
                                         info!(room_id, "Removed empty room");
                                     }
@@ -211,11 +409,13 @@ pub async fn run_server(mut rx: mpsc::Receiver<InboundEvent>) -> anyhow::Result<
             InboundEvent::Packet {
                 conn_id,
                 peer,
+                transport,
                 packet,
-                ..
             } => {
+                metrics::record_packet(packet.msg_id);
+
                 if packet.msg_id == MsgId::AttachChannel {
-                    let Ok(msg) = postcard::from_bytes::<AttachChannel>(&packet.payload) else {
+                    let Ok(msg) = decode_payload::<AttachChannel>(&packet.payload) else {
                         warn!(conn_id, %peer, "Bad AttachChannel message");
                         continue;
                     };
@@ -225,6 +425,17 @@ pub async fn run_server(mut rx: mpsc::Receiver<InboundEvent>) -> anyhow::Result<
                         continue;
                     }
 
+                    if (msg.channel == ChannelKind::Datagram) != (transport == TransportKind::Udp) {
+                        warn!(
+                            conn_id,
+                            %peer,
+                            channel = ?msg.channel,
+                            ?transport,
+                            "AttachChannel: channel does not match transport"
+                        );
+                        continue;
+                    }
+
                     let Some(control_conn_id) =
                         token_to_control_conn.get(&msg.session_token).copied()
                     else {
@@ -237,8 +448,9 @@ pub async fn run_server(mut rx: mpsc::Receiver<InboundEvent>) -> anyhow::Result<
                         continue;
                     };
 
-                    let Some(control_client_id) =
-                        conns.get(&control_conn_id).map(|c| c.assigned_client_id)
+                    let Some((control_client_id, capabilities)) = conns
+                        .get(&control_conn_id)
+                        .map(|c| (c.assigned_client_id, c.capabilities))
                     else {
                         continue;
                     };
@@ -248,6 +460,13 @@ pub async fn run_server(mut rx: mpsc::Receiver<InboundEvent>) -> anyhow::Result<
                         continue;
                     }
 
+                    if msg.channel == ChannelKind::Datagram
+                        && capabilities & capability::DATAGRAM_INPUT == 0
+                    {
+                        warn!(conn_id, %peer, "AttachChannel: datagram input not negotiated");
+                        continue;
+                    }
+
                     let Some(outbound) = conns.get(&conn_id).map(|c| c.outbound.clone()) else {
                         continue;
                     };
@@ -256,6 +475,10 @@ pub async fn run_server(mut rx: mpsc::Receiver<InboundEvent>) -> anyhow::Result<
                         ctx.assigned_client_id = control_client_id;
                         ctx.role = ConnRole::Channel(msg.channel);
                         ctx.session_token = msg.session_token;
+                        ctx.compress.store(
+                            capabilities & capability::COMPRESSION != 0,
+                            Ordering::Relaxed,
+                        );
                     }
 
                     if let Some(control_ctx) = conns.get_mut(&control_conn_id) {
@@ -266,7 +489,18 @@ pub async fn run_server(mut rx: mpsc::Receiver<InboundEvent>) -> anyhow::Result<
                     if let Some(room_id) = room_mgr.get_client_room(control_client_id)
                         && let Some(room) = room_mgr.get_room_mut(room_id)
                     {
-                        room.set_client_channel_outbound(control_client_id, msg.channel, outbound);
+                        room.set_client_channel_outbound(
+                            control_client_id,
+                            msg.channel,
+                            outbound.clone(),
+                        );
+                    }
+
+                    // Datagrams may be lost, so clients resend `AttachChannel`
+                    // until it is echoed back.
+                    if msg.channel == ChannelKind::Datagram {
+                        let h = Header::new(MsgId::AttachChannel as u8);
+                        let _ = send_msg_tcp(&outbound, h, MsgId::AttachChannel, &msg).await;
                     }
 
                     debug!(
@@ -279,10 +513,41 @@ pub async fn run_server(mut rx: mpsc::Receiver<InboundEvent>) -> anyhow::Result<
                     continue;
                 }
 
+                if packet.msg_id == MsgId::P2PPunchRegister {
+                    // Only meaningful over UDP: the source address is the point.
+                    if transport != TransportKind::Udp {
+                        continue;
+                    }
+                    let Ok(msg) = decode_payload::<P2PPunchRegister>(&packet.payload) else {
+                        warn!(conn_id, %peer, "Bad P2PPunchRegister message");
+                        continue;
+                    };
+                    let Some(client_id) = token_to_control_conn
+                        .get(&msg.session_token)
+                        .and_then(|id| conns.get(id))
+                        .map(|c| c.assigned_client_id)
+                        .filter(|id| *id != 0)
+                    else {
+                        warn!(conn_id, %peer, "P2PPunchRegister: session token not found");
+                        continue;
+                    };
+                    p2p_punch_register::handle(client_id, &peer, msg, &mut room_mgr).await;
+                    continue;
+                }
+
                 let Some(ctx) = conns.get_mut(&conn_id) else {
                     continue;
                 };
 
+                // Unauthenticated datagrams are easy to spoof; only inputs from an
+                // attached datagram channel are accepted over UDP.
+                if transport == TransportKind::Udp
+                    && (ctx.role != ConnRole::Channel(ChannelKind::Datagram)
+                        || packet.msg_id != MsgId::InputBatch)
+                {
+                    continue;
+                }
+
                 dispatch_packet(ctx, conn_id, &peer, &packet, &mut room_mgr).await;
 
                 if packet.msg_id == MsgId::Hello
@@ -290,10 +555,60 @@ pub async fn run_server(mut rx: mpsc::Receiver<InboundEvent>) -> anyhow::Result<
                     && ctx.session_token != 0
                 {
                     token_to_control_conn.insert(ctx.session_token, conn_id);
+                    ip_guard.record_success(peer.ip());
                 }
             }
         }
     }
 
+    room_mgr.flush();
     Ok(())
 }
+
+/// Whether any connection other than a datagram peer is open; datagram
+/// peers have no session of their own and only expire once idle.
+fn has_stream_connections(conns: &HashMap<ConnId, ConnCtx>) -> bool {
+    conns.values().any(|c| c.transport != TransportKind::Udp)
+}
+
+/// Tell every session that the server is going away in `grace`.
+async fn notify_server_closing(conns: &HashMap<ConnId, ConnCtx>, grace: Duration) {
+    let msg = ServerClosing {
+        grace_ms: grace.as_millis().try_into().unwrap_or(u32::MAX),
+    };
+    let h = Header::new(MsgId::ServerClosing as u8);
+    for ctx in conns.values().filter(|c| c.role == ConnRole::Control) {
+        let _ = send_msg_tcp(&ctx.outbound, h, MsgId::ServerClosing, &msg).await;
+    }
+}
+
+/// Completes once a started drain runs out of time, or never before a shutdown.
+async fn drain_expired(deadline: &mut Option<Pin<Box<Sleep>>>) {
+    match deadline {
+        Some(sleep) => sleep.as_mut().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Next reloaded settings, or never when reloading is disabled or its sender
+/// is gone.
+async fn recv_reload(
+    reload_rx: &mut Option<watch::Receiver<ServerSettings>>,
+) -> Option<ServerSettings> {
+    let Some(rx) = reload_rx else {
+        return std::future::pending().await;
+    };
+    if rx.changed().await.is_err() {
+        *reload_rx = None;
+        return std::future::pending().await;
+    }
+    Some(rx.borrow_and_update().clone())
+}
+
+/// Next admin command, or never when the admin API is disabled.
+async fn recv_admin(admin_rx: &mut Option<AdminRx>) -> Option<AdminRequest> {
+    match admin_rx {
+        Some(rx) => rx.recv().await,
+        None => std::future::pending().await,
+    }
+}
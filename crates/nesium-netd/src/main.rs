@@ -1,12 +1,19 @@
 use clap::Parser;
+use nesium_netd::admin::run_admin_listener;
+use nesium_netd::config::{Config, ServerSettings};
 use nesium_netd::net::quic::run_quic_listener;
-use nesium_netd::net::tcp::run_tcp_listener;
-use tokio::sync::mpsc;
-use tracing::{Level, info};
+use nesium_netd::net::tcp::{build_server_tls_acceptor, run_tcp_listener_with_acceptor};
+use nesium_netd::net::udp::run_udp_listener;
+use nesium_netd::observability::prometheus::run_metrics_listener;
+use tokio::sync::{mpsc, watch};
+use tokio_util::sync::CancellationToken;
+use tracing::{Level, info, warn};
 use tracing_subscriber::FmtSubscriber;
 
 use nesium_netd::net::quic_config;
-use nesium_netd::run_server;
+use nesium_netd::room::store::RoomStore;
+use nesium_netd::webhook::{WebhookConfig, WebhookNotifier};
+use nesium_netd::{ServerOptions, run_server_with_options};
 use std::net::SocketAddr;
 use std::path::PathBuf;
 
@@ -15,45 +22,169 @@ use std::path::PathBuf;
 #[command(name = "nesium-netd")]
 #[command(about = "NES emulator netplay relay server", long_about = None)]
 struct Args {
-    /// TCP bind address
-    #[arg(short, long, default_value = "0.0.0.0:5233")]
-    bind: String,
+    /// TOML config file; flags given on the command line override it.
+    ///
+    /// The `[limits]` table is reloaded on SIGHUP.
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+
+    /// TCP bind address [default: 0.0.0.0:5233]
+    #[arg(short, long)]
+    bind: Option<String>,
 
     /// Enable QUIC listener (UDP) alongside TCP.
     ///
     /// Default is enabled for convenient LAN testing. Disable with `--enable-quic=false`.
-    #[arg(long, default_value_t = true, action = clap::ArgAction::Set)]
-    enable_quic: bool,
+    #[arg(long, action = clap::ArgAction::Set)]
+    enable_quic: Option<bool>,
 
     /// QUIC bind address (defaults to `--bind` when omitted)
     #[arg(long)]
     quic_bind: Option<String>,
 
-    /// QUIC TLS certificate (PEM)
+    /// TLS certificate (PEM) for QUIC and TLS over TCP
     #[arg(long)]
     quic_cert: Option<PathBuf>,
 
-    /// QUIC TLS private key (PEM)
+    /// TLS private key (PEM) for QUIC and TLS over TCP
     #[arg(long)]
     quic_key: Option<PathBuf>,
 
-    /// Directory used to store auto-generated QUIC cert/key (when `--quic-bind` is set and
+    /// Directory used to store the auto-generated cert/key (when
     /// `--quic-cert/--quic-key` are not provided).
     #[arg(long)]
     quic_data_dir: Option<PathBuf>,
 
+    /// UDP bind address for the datagram input channel and P2P hole punching
+    /// (disabled when omitted).
+    ///
+    /// Must differ from the QUIC address, which also uses UDP.
+    #[arg(long)]
+    udp_bind: Option<String>,
+
     /// Log level (trace, debug, info, warn, error)
     #[arg(short, long, default_value = "info")]
     log_level: Level,
 
+    /// URL that receives room events (created/closed, player joined/left, match finished)
+    /// as JSON POST requests.
+    #[arg(long)]
+    webhook_url: Option<String>,
+
+    /// Shared secret used to sign webhook bodies (`X-Nesium-Signature: sha256=<hex hmac>`).
+    #[arg(long, requires = "webhook_url")]
+    webhook_secret: Option<String>,
+
+    /// SQLite database that keeps named rooms, bans and user identities
+    /// across restarts (kept in memory only when omitted).
+    #[arg(long)]
+    db: Option<PathBuf>,
+
+    /// Bind address of the HTTP admin API (disabled when omitted).
+    #[arg(long)]
+    admin_bind: Option<String>,
+
+    /// Bearer token required by every admin API request.
+    #[arg(long)]
+    admin_token: Option<String>,
+
+    /// Bind address of the Prometheus metrics exporter (`GET /metrics`,
+    /// disabled when omitted).
+    #[arg(long)]
+    metrics_bind: Option<String>,
+
     /// Maximum payload size in bytes
     #[arg(long, default_value = "4096")]
     max_payload: usize,
 }
 
+impl Args {
+    /// The config file (or defaults) with command-line overrides applied.
+    fn load_config(&self) -> anyhow::Result<Config> {
+        let mut config = match &self.config {
+            Some(path) => Config::load(path)?,
+            None => Config::default(),
+        };
+
+        let listen = &mut config.listen;
+        override_with(&mut listen.tcp, self.bind.clone());
+        override_with(&mut listen.enable_quic, self.enable_quic);
+        override_opt(&mut listen.quic, &self.quic_bind);
+        override_opt(&mut listen.udp, &self.udp_bind);
+        override_opt(&mut listen.admin, &self.admin_bind);
+        override_opt(&mut listen.metrics, &self.metrics_bind);
+        override_opt(&mut config.tls.cert, &self.quic_cert);
+        override_opt(&mut config.tls.key, &self.quic_key);
+        override_opt(&mut config.tls.data_dir, &self.quic_data_dir);
+        override_opt(&mut config.admin.token, &self.admin_token);
+        override_opt(&mut config.webhook.url, &self.webhook_url);
+        override_opt(&mut config.webhook.secret, &self.webhook_secret);
+        override_opt(&mut config.db, &self.db);
+        Ok(config)
+    }
+}
+
+fn override_with<T>(value: &mut T, flag: Option<T>) {
+    if let Some(flag) = flag {
+        *value = flag;
+    }
+}
+
+fn override_opt<T: Clone>(value: &mut Option<T>, flag: &Option<T>) {
+    if flag.is_some() {
+        value.clone_from(flag);
+    }
+}
+
+/// Re-read the config file on SIGHUP and publish its `[limits]` table.
+#[cfg(unix)]
+fn spawn_reload_on_sighup(
+    path: PathBuf,
+    reload_tx: watch::Sender<ServerSettings>,
+) -> anyhow::Result<()> {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    let mut hangup = signal(SignalKind::hangup())?;
+    tokio::spawn(async move {
+        while hangup.recv().await.is_some() {
+            match Config::load(&path) {
+                Ok(config) => {
+                    info!(
+                        "Reloaded {}; only [limits] applies without a restart",
+                        path.display()
+                    );
+                    reload_tx.send_replace(config.limits);
+                }
+                Err(e) => warn!("Keeping current settings: {e:#}"),
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Cancel `shutdown` on Ctrl-C or SIGTERM.
+fn spawn_shutdown_on_signal(shutdown: CancellationToken) -> anyhow::Result<()> {
+    #[cfg(unix)]
+    let mut terminate = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())?;
+    tokio::spawn(async move {
+        #[cfg(unix)]
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {}
+            _ = terminate.recv() => {}
+        }
+        #[cfg(not(unix))]
+        let _ = tokio::signal::ctrl_c().await;
+
+        info!("Shutdown requested");
+        shutdown.cancel();
+    });
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+    let config = args.load_config()?;
 
     // Initialize tracing
     let subscriber = FmtSubscriber::builder()
@@ -70,41 +201,55 @@ async fn main() -> anyhow::Result<()> {
     // Network layer -> upper layer events.
     let (tx, rx) = mpsc::channel(1024);
 
+    // Listeners stop accepting once a shutdown starts; the server loop drains.
+    let shutdown = CancellationToken::new();
+    spawn_shutdown_on_signal(shutdown.clone())?;
+
+    let listen = &config.listen;
+    let (cert, key, auto_generated) = match (&config.tls.cert, &config.tls.key) {
+        (Some(cert), Some(key)) => (cert.clone(), key.clone(), false),
+        (None, None) => {
+            let dir = config
+                .tls
+                .data_dir
+                .clone()
+                .unwrap_or_else(|| quic_config::default_quic_data_dir("nesium-netd"));
+            let (cert, key) = quic_config::ensure_quic_cert_pair(&dir)?;
+            (cert, key, true)
+        }
+        _ => {
+            anyhow::bail!("Must provide both a TLS cert and key, or neither (auto-generate)");
+        }
+    };
+
     // Start TCP listener.
-    let bind_addr: SocketAddr = args.bind.parse()?;
+    let bind_addr: SocketAddr = listen.tcp.parse()?;
+    let listener = tokio::net::TcpListener::bind(bind_addr).await?;
+    let tls_acceptor = build_server_tls_acceptor(&cert, &key)?;
     let tx_tcp = tx.clone();
-    tokio::spawn(async move {
-        let _ = run_tcp_listener(bind_addr, tx_tcp, "nesium-netd").await;
-    });
+    tokio::spawn(
+        shutdown
+            .clone()
+            .run_until_cancelled_owned(run_tcp_listener_with_acceptor(
+                listener,
+                tx_tcp,
+                tls_acceptor,
+            )),
+    );
 
     // Start QUIC listener (optional).
-    if args.enable_quic {
-        let quic_bind = args.quic_bind.clone().unwrap_or_else(|| args.bind.clone());
+    if listen.enable_quic {
+        let quic_bind = listen.quic.clone().unwrap_or_else(|| listen.tcp.clone());
         let quic_addr: SocketAddr = quic_bind.parse()?;
 
-        let (cert, key, auto_generated) = match (&args.quic_cert, &args.quic_key) {
-            (Some(cert), Some(key)) => (cert.clone(), key.clone(), false),
-            (None, None) => {
-                let dir = args
-                    .quic_data_dir
-                    .clone()
-                    .unwrap_or_else(|| quic_config::default_quic_data_dir("nesium-netd"));
-                let (cert, key) = quic_config::ensure_quic_cert_pair(&dir)?;
-                (cert, key, true)
-            }
-            _ => {
-                anyhow::bail!(
-                    "Must provide both --quic-cert and --quic-key, or neither (auto-generate)"
-                );
-            }
-        };
-
         let server_config = quic_config::build_quic_server_config(&cert, &key)?;
 
         let tx_quic = tx.clone();
-        tokio::spawn(async move {
-            let _ = run_quic_listener(quic_addr, server_config, tx_quic).await;
-        });
+        tokio::spawn(
+            shutdown
+                .clone()
+                .run_until_cancelled_owned(run_quic_listener(quic_addr, server_config, tx_quic)),
+        );
 
         info!("QUIC enabled on {}", quic_bind);
         if auto_generated {
@@ -120,9 +265,91 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
-    info!("Netplay server started on {}", args.bind);
+    // Start the datagram input listener (optional).
+    if let Some(udp_bind) = &listen.udp {
+        let udp_addr: SocketAddr = udp_bind.parse()?;
+        let idle_timeout = config.cleanup.datagram_idle_timeout();
+        let tx_udp = tx.clone();
+        tokio::spawn(shutdown.clone().run_until_cancelled_owned(run_udp_listener(
+            udp_addr,
+            idle_timeout,
+            tx_udp,
+        )));
+        info!("UDP input channel enabled on {}", udp_bind);
+    }
+
+    info!("Netplay server started on {}", listen.tcp);
     info!("Log level: {}", args.log_level);
 
+    let webhooks = match &config.webhook.url {
+        Some(url) => {
+            info!("Room event webhooks enabled: {}", url);
+            WebhookNotifier::spawn(WebhookConfig {
+                secret: config.webhook.secret.clone(),
+                ..WebhookConfig::new(url)
+            })
+        }
+        None => WebhookNotifier::disabled(),
+    };
+
+    let store = match &config.db {
+        Some(path) => {
+            info!("Persistent state in {}", path.display());
+            RoomStore::open(path)?.with_saved_room_ttl(config.cleanup.saved_room_ttl())
+        }
+        None => RoomStore::disabled(),
+    };
+
+    // Start the metrics exporter (optional).
+    if let Some(metrics_bind) = &listen.metrics {
+        let metrics_addr: SocketAddr = metrics_bind.parse()?;
+        tokio::spawn(async move {
+            if let Err(e) = run_metrics_listener(metrics_addr).await {
+                tracing::error!("Metrics exporter stopped: {e}");
+            }
+        });
+        info!("Prometheus metrics on http://{}/metrics", metrics_bind);
+    }
+
+    // Start the admin API (optional).
+    let admin = match (&listen.admin, &config.admin.token) {
+        (Some(admin_bind), Some(token)) => {
+            let admin_addr: SocketAddr = admin_bind.parse()?;
+            let token = token.clone();
+            let (admin_tx, admin_rx) = nesium_netd::admin::channel();
+            tokio::spawn(async move {
+                if let Err(e) = run_admin_listener(admin_addr, &token, admin_tx).await {
+                    tracing::error!("Admin API stopped: {e}");
+                }
+            });
+            info!("Admin API enabled on {}", admin_bind);
+            Some(admin_rx)
+        }
+        (Some(_), None) => anyhow::bail!("The admin API needs a token (--admin-token)"),
+        (None, _) => None,
+    };
+
+    // Reload limits on SIGHUP (optional).
+    let (reload_tx, reload_rx) = watch::channel(config.limits.clone());
+    #[cfg(unix)]
+    if let Some(path) = &args.config {
+        spawn_reload_on_sighup(path.clone(), reload_tx)?;
+    }
+    #[cfg(not(unix))]
+    drop(reload_tx);
+
     // Run server loop
-    run_server(rx).await
+    run_server_with_options(
+        rx,
+        ServerOptions {
+            webhooks,
+            store,
+            admin,
+            settings: config.limits,
+            reload: Some(reload_rx),
+            shutdown,
+            drain_timeout: Some(config.shutdown.drain_timeout()),
+        },
+    )
+    .await
 }
@@ -1,3 +1,4 @@
+pub mod datachannel;
 pub mod framing;
 pub mod inbound;
 pub mod kcp;
@@ -0,0 +1,48 @@
+use std::net::{Ipv4Addr, SocketAddr};
+
+use bytes::Bytes;
+use nesium_netproto::constants::DATA_CHANNEL_MAX_MESSAGE;
+use tokio::sync::mpsc;
+
+use super::inbound::{InboundEvent, TransportKind, next_conn_id};
+use super::stream_adapter::DataChannelStream;
+use super::tcp::handle_connection_inner;
+
+/// Serve a browser client connected over a WebRTC data channel, e.g. on a
+/// host-as-server that negotiated it through `RtcSignal`. All decoded packets
+/// and connection events are sent to `tx`.
+///
+/// The WebRTC stack is the embedder's: it pumps received messages into
+/// `incoming` and sends everything from `outgoing` on the channel. The channel
+/// carries the TCP byte stream; frames are split into messages of at most
+/// [`DATA_CHANNEL_MAX_MESSAGE`] bytes. Returns when `incoming` closes.
+pub async fn handle_data_channel(
+    incoming: mpsc::Receiver<Bytes>,
+    outgoing: mpsc::Sender<Bytes>,
+    tx: mpsc::Sender<InboundEvent>,
+) {
+    // The remote address is hidden behind ICE; report an unspecified one.
+    let peer = SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0));
+    let sink = Box::pin(futures_util::sink::unfold(
+        outgoing,
+        |outgoing, frame: Bytes| async move {
+            for chunk in frame.chunks(DATA_CHANNEL_MAX_MESSAGE) {
+                outgoing
+                    .send(frame.slice_ref(chunk))
+                    .await
+                    .map_err(std::io::Error::other)?;
+            }
+            Ok::<_, std::io::Error>(outgoing)
+        },
+    ));
+
+    handle_connection_inner(
+        DataChannelStream::new(incoming),
+        sink,
+        peer,
+        next_conn_id(),
+        TransportKind::WebRtc,
+        tx,
+    )
+    .await;
+}
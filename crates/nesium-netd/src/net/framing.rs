@@ -55,9 +55,8 @@ impl TcpFramer {
         let mut out = Vec::with_capacity(views.len());
 
         for v in views {
-            // If your netproto returns (Header, MsgId, &[u8]) instead of PacketView:
-            // just adjust field access here.
-            let payload = Bytes::copy_from_slice(v.payload);
+            // Compressed bulk frames are inflated here, so handlers only see plain payloads.
+            let payload = Bytes::from(v.decompressed_payload()?.into_owned());
 
             out.push(PacketOwned {
                 header: v.header,
@@ -86,7 +85,11 @@ mod tests {
 
         // Build a valid TCP frame using netproto encoder.
         let h = Header::new(MsgId::Ping as u8);
-        let payload = Ping { t_ms: 123 };
+        let payload = Ping {
+            seq: 0,
+            t_ms: 123,
+            peer_client_id: 0,
+        };
 
         let bytes = encode_tcp_frame(h, MsgId::Ping, &payload, 4096).unwrap();
 
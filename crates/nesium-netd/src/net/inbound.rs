@@ -2,8 +2,10 @@ use std::sync::atomic::{AtomicU64, Ordering};
 
 use std::net::SocketAddr;
 use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 
 use super::framing::PacketOwned;
+use super::outbound::CompressionFlag;
 
 /// Unique connection identifier assigned by the server.
 pub type ConnId = u64;
@@ -19,6 +21,10 @@ pub fn next_conn_id() -> ConnId {
 pub enum TransportKind {
     Tcp,
     Quic,
+    /// Datagram input channel; see [`super::udp`].
+    Udp,
+    /// Browser peer on a WebRTC data channel; see [`super::datachannel`].
+    WebRtc,
 }
 
 /// Sender used by upper layers to write bytes to this connection.
@@ -29,7 +35,9 @@ pub type OutboundTx = mpsc::Sender<bytes::Bytes>;
 ///
 /// Current behavior:
 /// - `Connected` is emitted once per accepted connection, with an `OutboundTx`
-///   that upper layers can use to send bytes back.
+///   that upper layers can use to send bytes back, the connection's
+///   `CompressionFlag` and a `close` token that drops the connection when
+///   cancelled.
 /// - `Packet` is emitted for every decoded packet.
 /// - `Disconnected` is emitted when the connection handler exits.
 #[derive(Debug)]
@@ -39,6 +47,9 @@ pub enum InboundEvent {
         peer: SocketAddr,
         transport: TransportKind,
         outbound: OutboundTx,
+        compress: CompressionFlag,
+        /// Cancel to drop the connection; `Disconnected` follows as usual.
+        close: CancellationToken,
     },
 
     Packet {
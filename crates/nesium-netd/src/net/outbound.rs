@@ -1,30 +1,56 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use bytes::Bytes;
 use futures_util::{Sink, SinkExt};
-use nesium_netproto::codec_tcp::encode_tcp_frame_auto;
+use nesium_netproto::codec_tcp::{compress_tcp_frame, encode_tcp_frame_auto};
 use nesium_netproto::error::ProtoError;
 use nesium_netproto::header::Header;
 use nesium_netproto::msg_id::MsgId;
 use tokio::sync::mpsc;
 
+use super::inbound::TransportKind;
+use crate::observability::metrics::record_bytes_sent;
+
 /// Outbound channel sender type.
 /// Data must already be framed for TCP (length prefix + header + payload).
 pub type OutboundTx = mpsc::Sender<bytes::Bytes>;
 
+/// Per-connection switch for compressing bulk frames on their way out.
+///
+/// Set once the peer negotiates `capability::COMPRESSION`.
+pub type CompressionFlag = Arc<AtomicBool>;
+
+/// Compresses `frame` if the connection negotiated it and it's worth it.
+pub fn maybe_compress(compress: &CompressionFlag, frame: Bytes) -> Bytes {
+    if !compress.load(Ordering::Relaxed) {
+        return frame;
+    }
+    compress_tcp_frame(&frame).map_or(frame, Bytes::from)
+}
+
 /// Spawn a writer task that writes framed bytes to the TCP stream.
 ///
 /// Current behavior:
+/// - Compresses bulk frames while `compress` is set.
+/// - Counts written bytes against `transport`.
 /// - Exits when the channel is closed.
 /// - Returns an error if socket write fails.
 pub fn spawn_tcp_writer<S>(
     mut write: S,
     mut rx: mpsc::Receiver<bytes::Bytes>,
+    compress: CompressionFlag,
+    transport: TransportKind,
 ) -> tokio::task::JoinHandle<anyhow::Result<()>>
 where
     S: Sink<bytes::Bytes, Error = std::io::Error> + Unpin + Send + 'static,
 {
     tokio::spawn(async move {
         while let Some(frame) = rx.recv().await {
+            let frame = maybe_compress(&compress, frame);
+            let len = frame.len();
             write.send(frame).await?;
+            record_bytes_sent(transport, len);
         }
         Ok(())
     })
@@ -5,10 +5,16 @@ use nesium_netproto::limits::TCP_RX_BUFFER_SIZE;
 use quinn::{Endpoint, ServerConfig};
 use tokio::io::AsyncReadExt;
 use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, warn};
 
+use crate::observability::metrics::{
+    record_bytes_received, record_bytes_sent, record_decode_error,
+};
+
 use super::framing::TcpFramer;
 use super::inbound::{ConnId, InboundEvent, TransportKind, next_conn_id};
+use super::outbound::{CompressionFlag, maybe_compress};
 
 /// Start a QUIC listener. All decoded packets and connection events are sent to `tx`.
 ///
@@ -63,21 +69,27 @@ async fn handle_quic_stream(
     tx: mpsc::Sender<InboundEvent>,
 ) {
     let (out_tx, mut out_rx) = mpsc::channel::<Bytes>(1024);
+    let compress = CompressionFlag::default();
+    let close = CancellationToken::new();
 
     tx.send(InboundEvent::Connected {
         conn_id,
         peer,
         transport: TransportKind::Quic,
         outbound: out_tx.clone(),
+        compress: compress.clone(),
+        close: close.clone(),
     })
     .await
     .ok();
 
     let writer = tokio::spawn(async move {
         while let Some(frame) = out_rx.recv().await {
+            let frame = maybe_compress(&compress, frame);
             if send.write_all(&frame).await.is_err() {
                 break;
             }
+            record_bytes_sent(TransportKind::Quic, frame.len());
         }
         let _ = send.finish();
     });
@@ -92,12 +104,19 @@ async fn handle_quic_stream(
         }
 
         framer.buf_mut().reserve(4096);
-        match recv.read_buf(framer.buf_mut()).await {
+        let read = tokio::select! {
+            read = recv.read_buf(framer.buf_mut()) => read,
+            _ = close.cancelled() => {
+                disconnect_reason = "closed by server".to_string();
+                break;
+            }
+        };
+        match read {
             Ok(0) => {
                 disconnect_reason = "eof".to_string();
                 break;
             }
-            Ok(_) => {}
+            Ok(n) => record_bytes_received(TransportKind::Quic, n),
             Err(e) => {
                 disconnect_reason = format!("read error: {}", e);
                 break;
@@ -128,6 +147,7 @@ async fn handle_quic_stream(
                 }
             }
             Err(e) => {
+                record_decode_error(&e);
                 disconnect_reason = format!("protocol error: {}", e);
                 break;
             }
@@ -2,9 +2,10 @@ use std::io;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
-use bytes::{Buf, BytesMut};
+use bytes::{Buf, Bytes, BytesMut};
 use futures_util::Stream;
 use tokio::io::AsyncRead;
+use tokio::sync::mpsc;
 use tokio_tungstenite::tungstenite::{self, Message};
 use tracing::warn;
 
@@ -66,3 +67,43 @@ where
         }
     }
 }
+
+/// Adapts the messages of a WebRTC data channel, pumped into an mpsc channel
+/// by the platform glue, to an `AsyncRead` byte stream. EOF once the sender is
+/// dropped.
+pub struct DataChannelStream {
+    inner: mpsc::Receiver<Bytes>,
+    read_buf: Bytes,
+}
+
+impl DataChannelStream {
+    pub fn new(inner: mpsc::Receiver<Bytes>) -> Self {
+        Self {
+            inner,
+            read_buf: Bytes::new(),
+        }
+    }
+}
+
+impl AsyncRead for DataChannelStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        while this.read_buf.is_empty() {
+            match this.inner.poll_recv(cx) {
+                Poll::Ready(Some(msg)) => this.read_buf = msg,
+                Poll::Ready(None) => return Poll::Ready(Ok(())),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        let n = std::cmp::min(buf.remaining(), this.read_buf.len());
+        buf.put_slice(&this.read_buf[..n]);
+        this.read_buf.advance(n);
+        Poll::Ready(Ok(()))
+    }
+}
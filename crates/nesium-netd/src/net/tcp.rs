@@ -1,20 +1,28 @@
 use std::net::SocketAddr;
+use std::time::Duration;
 
 use futures_util::{SinkExt, StreamExt};
+use nesium_netproto::constants::TLS_ALPN;
 use nesium_netproto::limits::TCP_RX_BUFFER_SIZE;
 use tokio::io::AsyncReadExt;
 use tokio::net::{TcpListener, TcpStream};
 use tokio::sync::mpsc;
 use tokio_tungstenite::{accept_async, tungstenite};
 use tokio_util::codec::{BytesCodec, FramedWrite};
+use tokio_util::sync::CancellationToken;
 use tracing::warn;
 
 use crate::net::quic_config;
 use crate::net::stream_adapter::WebSocketStream;
+use crate::observability::metrics::{record_bytes_received, record_decode_error};
 
 use super::framing::TcpFramer;
 use super::inbound::{ConnId, InboundEvent, TransportKind, next_conn_id};
-use super::outbound::spawn_tcp_writer;
+use super::outbound::{CompressionFlag, spawn_tcp_writer};
+
+/// How long a new connection may stay silent before it is dropped; silent
+/// sockets never reach the server loop and its per-IP limits.
+const SNIFF_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub use tokio_rustls::TlsAcceptor;
 
@@ -35,7 +43,15 @@ pub async fn run_tcp_listener_with_listener(
     app_name: &str,
 ) -> anyhow::Result<()> {
     let tls_acceptor = get_or_create_server_tls_acceptor(app_name)?;
+    run_tcp_listener_with_acceptor(listener, tx, tls_acceptor).await
+}
 
+/// Run the accept loop on an existing listener, terminating TLS with `tls_acceptor`.
+pub async fn run_tcp_listener_with_acceptor(
+    listener: TcpListener,
+    tx: mpsc::Sender<InboundEvent>,
+    tls_acceptor: std::sync::Arc<tokio_rustls::TlsAcceptor>,
+) -> anyhow::Result<()> {
     loop {
         let (stream, peer) = listener.accept().await?;
         let conn_id = next_conn_id();
@@ -53,20 +69,28 @@ pub fn get_or_create_server_tls_acceptor(
 ) -> anyhow::Result<std::sync::Arc<tokio_rustls::TlsAcceptor>> {
     let dir = quic_config::default_quic_data_dir(app_name);
     let (cert_path, key_path) = quic_config::ensure_quic_cert_pair(&dir)?;
+    build_server_tls_acceptor(&cert_path, &key_path)
+}
 
+/// TLS acceptor for the PEM certificate chain and private key at the given paths.
+pub fn build_server_tls_acceptor(
+    cert_path: &std::path::Path,
+    key_path: &std::path::Path,
+) -> anyhow::Result<std::sync::Arc<tokio_rustls::TlsAcceptor>> {
     let certs = rustls_pemfile::certs(&mut std::io::BufReader::new(std::fs::File::open(
-        &cert_path,
+        cert_path,
     )?))
     .collect::<Result<Vec<_>, _>>()?;
-    let key = rustls_pemfile::private_key(&mut std::io::BufReader::new(std::fs::File::open(
-        &key_path,
-    )?))?
-    .ok_or_else(|| anyhow::anyhow!("No private key found in {}", key_path.display()))?;
+    let key =
+        rustls_pemfile::private_key(&mut std::io::BufReader::new(std::fs::File::open(key_path)?))?
+            .ok_or_else(|| anyhow::anyhow!("No private key found in {}", key_path.display()))?;
 
-    let server_config = rustls::ServerConfig::builder()
+    let mut server_config = rustls::ServerConfig::builder()
         .with_no_client_auth()
         .with_single_cert(certs, key)
         .map_err(|e| anyhow::anyhow!("Failed to build server config: {}", e))?;
+    // Native clients ask for `TLS_ALPN`; browsers open WSS over HTTP/1.1.
+    server_config.alpn_protocols = vec![TLS_ALPN.to_vec(), b"http/1.1".to_vec()];
 
     Ok(std::sync::Arc::new(tokio_rustls::TlsAcceptor::from(
         std::sync::Arc::new(server_config),
@@ -85,7 +109,14 @@ pub async fn handle_tcp_connection(
 
     // Protocol Sniffing via Peeking
     let mut peek_buf = [0u8; 4];
-    let protocol = match stream.peek(&mut peek_buf).await {
+    let peeked = match tokio::time::timeout(SNIFF_TIMEOUT, stream.peek(&mut peek_buf)).await {
+        Ok(peeked) => peeked,
+        Err(_) => {
+            warn!(%peer, "Dropping connection that sent nothing");
+            return;
+        }
+    };
+    let protocol = match peeked {
         Ok(n) if n >= 2 => {
             if &peek_buf[0..2] == b"NS" {
                 ParsedProtocol::Native
@@ -118,7 +149,15 @@ pub async fn handle_tcp_connection(
                             });
 
                     let adapted_read = WebSocketStream::new(read);
-                    handle_connection_inner(adapted_read, sink, peer, conn_id, tx).await;
+                    handle_connection_inner(
+                        adapted_read,
+                        sink,
+                        peer,
+                        conn_id,
+                        TransportKind::Tcp,
+                        tx,
+                    )
+                    .await;
                 }
                 Err(e) => {
                     warn!("WebSocket handshake failed: {}", e);
@@ -127,8 +166,14 @@ pub async fn handle_tcp_connection(
         }
         ParsedProtocol::Tls => {
             match tls_acceptor.accept(stream).await {
+                Ok(tls_stream) if tls_stream.get_ref().1.alpn_protocol() == Some(TLS_ALPN) => {
+                    let (read, write) = tokio::io::split(tls_stream);
+                    let sink = FramedWrite::new(write, BytesCodec::new());
+                    handle_connection_inner(read, sink, peer, conn_id, TransportKind::Tcp, tx)
+                        .await;
+                }
                 Ok(tls_stream) => {
-                    // Inside TLS, we assume usage of WebSocket (WSS).
+                    // Without our ALPN id, TLS carries a WebSocket (WSS).
                     match accept_async(tls_stream).await {
                         Ok(ws_stream) => {
                             let (write, read) = ws_stream.split();
@@ -139,7 +184,15 @@ pub async fn handle_tcp_connection(
                             );
 
                             let adapted_read = WebSocketStream::new(read);
-                            handle_connection_inner(adapted_read, sink, peer, conn_id, tx).await;
+                            handle_connection_inner(
+                                adapted_read,
+                                sink,
+                                peer,
+                                conn_id,
+                                TransportKind::Tcp,
+                                tx,
+                            )
+                            .await;
                         }
                         Err(e) => {
                             warn!("WSS handshake failed: {}", e);
@@ -156,7 +209,7 @@ pub async fn handle_tcp_connection(
             // Adapt the AsyncWrite to a Bytes sink using FramedWrite
             // bytes::BytesCodec handles writing Bytes trait to AsyncWrite
             let sink = FramedWrite::new(write, BytesCodec::new());
-            handle_connection_inner(read, sink, peer, conn_id, tx).await;
+            handle_connection_inner(read, sink, peer, conn_id, TransportKind::Tcp, tx).await;
         }
     }
 }
@@ -167,11 +220,14 @@ enum ParsedProtocol {
     Tls,
 }
 
-async fn handle_connection_inner<R, S>(
+/// Serve one framed byte stream until it closes. Shared by every stream-based
+/// transport.
+pub(crate) async fn handle_connection_inner<R, S>(
     mut read: R,
     write: S,
     peer: SocketAddr,
     conn_id: ConnId,
+    transport: TransportKind,
     tx: mpsc::Sender<InboundEvent>,
 ) where
     R: tokio::io::AsyncRead + Unpin,
@@ -179,14 +235,18 @@ async fn handle_connection_inner<R, S>(
 {
     // Outbound queue (framed bytes).
     let (out_tx, out_rx) = mpsc::channel::<bytes::Bytes>(1024);
-    let writer = spawn_tcp_writer(write, out_rx);
+    let compress = CompressionFlag::default();
+    let writer = spawn_tcp_writer(write, out_rx, compress.clone(), transport);
+    let close = CancellationToken::new();
 
     // Notify upper layer that a connection is established.
     tx.send(InboundEvent::Connected {
         conn_id,
         peer,
-        transport: TransportKind::Tcp,
+        transport,
         outbound: out_tx.clone(),
+        compress,
+        close: close.clone(),
     })
     .await
     .ok();
@@ -204,12 +264,20 @@ async fn handle_connection_inner<R, S>(
         }
 
         framer.buf_mut().reserve(4096);
-        match read.read_buf(framer.buf_mut()).await {
+        let read = tokio::select! {
+            read = read.read_buf(framer.buf_mut()) => read,
+            _ = close.cancelled() => {
+                disconnect_reason = "closed by server".to_string();
+                break;
+            }
+        };
+        match read {
             Ok(n) => {
                 if n == 0 {
                     disconnect_reason = "eof".to_string();
                     break;
                 }
+                record_bytes_received(transport, n);
             }
             Err(e) => {
                 disconnect_reason = format!("read error: {}", e);
@@ -226,7 +294,7 @@ async fn handle_connection_inner<R, S>(
                         .send(InboundEvent::Packet {
                             conn_id,
                             peer,
-                            transport: TransportKind::Tcp,
+                            transport,
                             packet,
                         })
                         .await
@@ -244,6 +312,7 @@ async fn handle_connection_inner<R, S>(
             }
             Err(e) => {
                 // Protocol error -> close connection.
+                record_decode_error(&e);
                 disconnect_reason = format!("protocol error: {}", e);
                 break;
             }
@@ -255,7 +324,7 @@ async fn handle_connection_inner<R, S>(
         .send(InboundEvent::Disconnected {
             conn_id,
             peer,
-            transport: TransportKind::Tcp,
+            transport,
             reason: disconnect_reason.clone(),
         })
         .await;
@@ -1 +1,186 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use bytes::Bytes;
+use nesium_netproto::codec_udp::decode_udp;
+use nesium_netproto::constants::{DATAGRAM_IDLE_TIMEOUT_SECS, HEADER_LEN, TCP_LEN_PREFIX};
+use nesium_netproto::limits::MAX_UDP_PAYLOAD;
+use nesium_netproto::msg_id::MsgId;
+use tokio::net::UdpSocket;
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+use crate::observability::metrics::{
+    record_bytes_received, record_bytes_sent, record_decode_error,
+};
+
+use super::framing::PacketOwned;
+use super::inbound::{ConnId, InboundEvent, TransportKind, next_conn_id};
+
+/// A UDP peer tracked as a connection.
+struct UdpPeer {
+    conn_id: ConnId,
+    last_seen: Instant,
+}
+
+/// Start a UDP listener for the datagram input channel. All decoded packets
+/// and peer events are sent to `tx`.
+///
+/// Each remote address is treated as one connection. A peer is only
+/// registered once it sends an `AttachChannel` or `P2PPunchRegister`, so
+/// stray datagrams can't fill the connection table, and it is disconnected after
+/// `idle_timeout` of silence (normally [`DATAGRAM_IDLE_TIMEOUT_SECS`]).
+///
+/// Outbound bytes use the same TCP framing as every other transport; the
+/// writer strips the length prefix, so each frame becomes one datagram.
+pub async fn run_udp_listener(
+    bind: SocketAddr,
+    idle_timeout: Duration,
+    tx: mpsc::Sender<InboundEvent>,
+) -> anyhow::Result<()> {
+    let socket = UdpSocket::bind(bind).await?;
+    run_udp_loop(socket, idle_timeout, tx).await
+}
+
+/// Run the receive loop on an existing socket with the default idle timeout.
+pub async fn run_udp_listener_with_socket(
+    socket: UdpSocket,
+    tx: mpsc::Sender<InboundEvent>,
+) -> anyhow::Result<()> {
+    run_udp_loop(socket, Duration::from_secs(DATAGRAM_IDLE_TIMEOUT_SECS), tx).await
+}
+
+async fn run_udp_loop(
+    socket: UdpSocket,
+    idle_timeout: Duration,
+    tx: mpsc::Sender<InboundEvent>,
+) -> anyhow::Result<()> {
+    let socket = Arc::new(socket);
+    let mut peers: HashMap<SocketAddr, UdpPeer> = HashMap::new();
+    let mut buf = vec![0u8; HEADER_LEN + MAX_UDP_PAYLOAD + 1];
+    let mut sweep = tokio::time::interval(idle_timeout / 2);
+
+    loop {
+        let (len, peer) = tokio::select! {
+            received = socket.recv_from(&mut buf) => match received {
+                Ok(v) => v,
+                Err(e) => {
+                    // ICMP errors from earlier sends surface here; keep serving.
+                    debug!(error = %e, "UDP receive error");
+                    continue;
+                }
+            },
+            _ = sweep.tick() => {
+                let now = Instant::now();
+                let expired: Vec<SocketAddr> = peers
+                    .iter()
+                    .filter(|(_, p)| now.duration_since(p.last_seen) >= idle_timeout)
+                    .map(|(addr, _)| *addr)
+                    .collect();
+                for addr in expired {
+                    if let Some(p) = peers.remove(&addr) {
+                        let _ = tx
+                            .send(InboundEvent::Disconnected {
+                                conn_id: p.conn_id,
+                                peer: addr,
+                                transport: TransportKind::Udp,
+                                reason: "idle timeout".to_string(),
+                            })
+                            .await;
+                    }
+                }
+                continue;
+            }
+        };
+
+        record_bytes_received(TransportKind::Udp, len);
+        let view = match decode_udp(&buf[..len]) {
+            Ok(v) => v,
+            Err(e) => {
+                record_decode_error(&e);
+                continue;
+            }
+        };
+        let packet = PacketOwned {
+            header: view.header,
+            msg_id: view.msg_id,
+            payload: Bytes::copy_from_slice(view.payload),
+        };
+
+        let conn_id = match peers.get_mut(&peer) {
+            Some(p) => {
+                p.last_seen = Instant::now();
+                p.conn_id
+            }
+            None if matches!(
+                packet.msg_id,
+                MsgId::AttachChannel | MsgId::P2PPunchRegister
+            ) =>
+            {
+                let conn_id = next_conn_id();
+                let outbound = spawn_udp_writer(socket.clone(), peer);
+                peers.insert(
+                    peer,
+                    UdpPeer {
+                        conn_id,
+                        last_seen: Instant::now(),
+                    },
+                );
+                debug!(conn_id, %peer, "UDP peer registered");
+                if tx
+                    .send(InboundEvent::Connected {
+                        conn_id,
+                        peer,
+                        transport: TransportKind::Udp,
+                        outbound,
+                        // Datagrams carry inputs only, which are never compressed.
+                        compress: Default::default(),
+                        // Unused: datagram peers expire once they go quiet.
+                        close: Default::default(),
+                    })
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+                conn_id
+            }
+            None => continue,
+        };
+
+        if tx
+            .send(InboundEvent::Packet {
+                conn_id,
+                peer,
+                transport: TransportKind::Udp,
+                packet,
+            })
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Spawn the writer for one UDP peer. It exits once every sender is dropped.
+fn spawn_udp_writer(socket: Arc<UdpSocket>, peer: SocketAddr) -> mpsc::Sender<Bytes> {
+    // Datagrams are best effort: a small queue, and callers use `try_send`.
+    let (out_tx, mut out_rx) = mpsc::channel::<Bytes>(64);
+    tokio::spawn(async move {
+        while let Some(frame) = out_rx.recv().await {
+            let Some(datagram) = frame.get(TCP_LEN_PREFIX..) else {
+                continue;
+            };
+            match socket.send_to(datagram, peer).await {
+                Ok(n) => record_bytes_sent(TransportKind::Udp, n),
+                Err(e) => warn!(%peer, error = %e, "UDP send failed"),
+            }
+        }
+    });
+    out_tx
+}
@@ -1,2 +1,3 @@
 pub(crate) mod logging;
-pub(crate) mod metrics;
+pub mod metrics;
+pub mod prometheus;
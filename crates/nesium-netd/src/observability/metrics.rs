@@ -1 +1,221 @@
+//! Process-wide counters for protocol health and load.
+//!
+//! Counters are plain atomics so the hot receive paths never take a lock.
+//! They are exported in the Prometheus text format by [`super::prometheus`].
 
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use nesium_netproto::error::ProtoError;
+use nesium_netproto::msg_id::MsgId;
+
+use crate::ip_guard::Rejection;
+use crate::net::inbound::TransportKind;
+
+/// Every label [`ProtoError::kind`] can return, in reporting order.
+const DECODE_ERROR_KINDS: [&str; 11] = [
+    "bad_magic",
+    "unsupported_version",
+    "too_short",
+    "length_mismatch",
+    "payload_too_large",
+    "frame_too_large",
+    "payload_exceeds_limit",
+    "unknown_msg_id",
+    "trailing_bytes",
+    "decompress",
+    "postcard",
+];
+
+static DECODE_ERRORS: [AtomicU64; DECODE_ERROR_KINDS.len()] =
+    [const { AtomicU64::new(0) }; DECODE_ERROR_KINDS.len()];
+
+/// Counts a framing or payload decode failure.
+pub fn record_decode_error(err: &ProtoError) {
+    let kind = err.kind();
+    if let Some(idx) = DECODE_ERROR_KINDS.iter().position(|k| *k == kind) {
+        DECODE_ERRORS[idx].fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Decode failures observed since startup, keyed by error kind.
+pub fn decode_error_counts() -> Vec<(&'static str, u64)> {
+    DECODE_ERROR_KINDS
+        .iter()
+        .zip(DECODE_ERRORS.iter())
+        .map(|(kind, count)| (*kind, count.load(Ordering::Relaxed)))
+        .collect()
+}
+
+/// Every message kind a rate limiter can drop, in reporting order.
+const RATE_LIMIT_KINDS: [&str; 2] = ["chat", "relay"];
+
+static RATE_LIMITED: [AtomicU64; RATE_LIMIT_KINDS.len()] =
+    [const { AtomicU64::new(0) }; RATE_LIMIT_KINDS.len()];
+
+/// Counts a message dropped by a rate limiter.
+pub fn record_rate_limited(kind: &str) {
+    if let Some(idx) = RATE_LIMIT_KINDS.iter().position(|k| *k == kind) {
+        RATE_LIMITED[idx].fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Rate-limited messages since startup, keyed by message kind.
+pub fn rate_limited_counts() -> Vec<(&'static str, u64)> {
+    RATE_LIMIT_KINDS
+        .iter()
+        .zip(RATE_LIMITED.iter())
+        .map(|(kind, count)| (*kind, count.load(Ordering::Relaxed)))
+        .collect()
+}
+
+/// Every reason a connection can be turned away, in reporting order.
+const REJECTIONS: [Rejection; 3] = [
+    Rejection::Banned,
+    Rejection::PerIpLimit,
+    Rejection::HandshakeBackoff,
+];
+
+static REJECTED: [AtomicU64; REJECTIONS.len()] = [const { AtomicU64::new(0) }; REJECTIONS.len()];
+
+/// Counts a connection refused by the per-IP abuse controls.
+pub fn record_connection_rejected(reason: Rejection) {
+    if let Some(idx) = REJECTIONS.iter().position(|r| *r == reason) {
+        REJECTED[idx].fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Refused connections since startup, keyed by reason.
+pub fn rejected_connection_counts() -> Vec<(&'static str, u64)> {
+    REJECTIONS
+        .iter()
+        .zip(REJECTED.iter())
+        .map(|(reason, count)| (reason.as_str(), count.load(Ordering::Relaxed)))
+        .collect()
+}
+
+/// Every transport label, indexed by [`transport_index`].
+pub(crate) const TRANSPORTS: [&str; 4] = ["tcp", "quic", "udp", "webrtc"];
+
+fn transport_index(transport: TransportKind) -> usize {
+    match transport {
+        TransportKind::Tcp => 0,
+        TransportKind::Quic => 1,
+        TransportKind::Udp => 2,
+        TransportKind::WebRtc => 3,
+    }
+}
+
+static CONNECTIONS: [AtomicU64; TRANSPORTS.len()] = [const { AtomicU64::new(0) }; TRANSPORTS.len()];
+static BYTES_RECEIVED: [AtomicU64; TRANSPORTS.len()] =
+    [const { AtomicU64::new(0) }; TRANSPORTS.len()];
+static BYTES_SENT: [AtomicU64; TRANSPORTS.len()] = [const { AtomicU64::new(0) }; TRANSPORTS.len()];
+static ROOMS: AtomicU64 = AtomicU64::new(0);
+/// Packets handled by the server loop, indexed by raw message id.
+static PACKETS: [AtomicU64; 256] = [const { AtomicU64::new(0) }; 256];
+
+pub fn record_connection_opened(transport: TransportKind) {
+    CONNECTIONS[transport_index(transport)].fetch_add(1, Ordering::Relaxed);
+}
+
+pub fn record_connection_closed(transport: TransportKind) {
+    // Saturate rather than wrap if a close is ever seen without its open.
+    let _ = CONNECTIONS[transport_index(transport)].fetch_update(
+        Ordering::Relaxed,
+        Ordering::Relaxed,
+        |n| n.checked_sub(1),
+    );
+}
+
+/// Open connections, keyed by transport.
+pub fn connection_counts() -> Vec<(&'static str, u64)> {
+    counts_by_transport(&CONNECTIONS)
+}
+
+/// Counts bytes read off the wire, before decompression.
+pub fn record_bytes_received(transport: TransportKind, bytes: usize) {
+    BYTES_RECEIVED[transport_index(transport)].fetch_add(bytes as u64, Ordering::Relaxed);
+}
+
+/// Counts bytes written to the wire, after compression.
+pub fn record_bytes_sent(transport: TransportKind, bytes: usize) {
+    BYTES_SENT[transport_index(transport)].fetch_add(bytes as u64, Ordering::Relaxed);
+}
+
+/// Bytes received since startup, keyed by transport.
+pub fn bytes_received_counts() -> Vec<(&'static str, u64)> {
+    counts_by_transport(&BYTES_RECEIVED)
+}
+
+/// Bytes sent since startup, keyed by transport.
+pub fn bytes_sent_counts() -> Vec<(&'static str, u64)> {
+    counts_by_transport(&BYTES_SENT)
+}
+
+fn counts_by_transport(counters: &[AtomicU64; TRANSPORTS.len()]) -> Vec<(&'static str, u64)> {
+    TRANSPORTS
+        .iter()
+        .zip(counters.iter())
+        .map(|(transport, count)| (*transport, count.load(Ordering::Relaxed)))
+        .collect()
+}
+
+/// Updates the number of open rooms.
+pub fn set_room_count(rooms: usize) {
+    ROOMS.store(rooms as u64, Ordering::Relaxed);
+}
+
+pub fn room_count() -> u64 {
+    ROOMS.load(Ordering::Relaxed)
+}
+
+/// Counts a packet handled by the server loop.
+pub fn record_packet(msg_id: MsgId) {
+    PACKETS[msg_id as usize].fetch_add(1, Ordering::Relaxed);
+}
+
+/// Packets handled since startup, keyed by message id; ids never seen are omitted.
+pub fn packet_counts() -> Vec<(MsgId, u64)> {
+    PACKETS
+        .iter()
+        .enumerate()
+        .filter_map(|(id, count)| {
+            let count = count.load(Ordering::Relaxed);
+            let msg_id = MsgId::from_repr(id as u8)?;
+            (count > 0).then_some((msg_id, count))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_error_kind_has_a_counter() {
+        let errors = [
+            ProtoError::BadMagic,
+            ProtoError::UnsupportedVersion(0),
+            ProtoError::TooShort,
+            ProtoError::LengthMismatch,
+            ProtoError::PayloadTooLarge(0),
+            ProtoError::FrameTooLarge(0),
+            ProtoError::PayloadExceedsLimit {
+                msg_id: nesium_netproto::msg_id::MsgId::Ping,
+                len: 0,
+                max: 0,
+            },
+            ProtoError::UnknownMsgId(0),
+            ProtoError::TrailingBytes(0),
+            ProtoError::Decompress(String::new()),
+            ProtoError::Postcard(postcard::Error::DeserializeUnexpectedEnd),
+        ];
+        for err in &errors {
+            assert!(DECODE_ERROR_KINDS.contains(&err.kind()), "{}", err.kind());
+        }
+        assert_eq!(errors.len(), DECODE_ERROR_KINDS.len());
+
+        let before = decode_error_counts()[0].1;
+        record_decode_error(&ProtoError::BadMagic);
+        assert!(decode_error_counts()[0].1 > before);
+    }
+}
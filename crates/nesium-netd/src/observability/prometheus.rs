@@ -0,0 +1,155 @@
+//! Prometheus exporter for the counters in [`super::metrics`].
+//!
+//! Serves `GET /metrics` in the text exposition format on its own port, so
+//! it can stay reachable by the scraper without exposing the admin API.
+
+use std::fmt::Write;
+use std::net::SocketAddr;
+
+use tokio::net::{TcpListener, TcpStream};
+use tracing::debug;
+
+use super::metrics;
+use crate::http::{read_request_head, request_target, write_response};
+
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Start the metrics exporter on `bind`.
+pub async fn run_metrics_listener(bind: SocketAddr) -> anyhow::Result<()> {
+    let listener = TcpListener::bind(bind).await?;
+    run_metrics_listener_with_listener(listener).await
+}
+
+/// Run the exporter accept loop on an existing listener.
+pub async fn run_metrics_listener_with_listener(listener: TcpListener) -> anyhow::Result<()> {
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        tokio::spawn(async move {
+            if let Err(e) = handle_http(stream).await {
+                debug!(%peer, error = %e, "Metrics request failed");
+            }
+        });
+    }
+}
+
+async fn handle_http(mut stream: TcpStream) -> anyhow::Result<()> {
+    let head = read_request_head(&mut stream).await?;
+    match request_target(&head) {
+        Some(("GET", "/metrics")) => {
+            write_response(&mut stream, 200, CONTENT_TYPE, &render()).await
+        }
+        _ => write_response(&mut stream, 404, CONTENT_TYPE, "not found\n").await,
+    }
+}
+
+/// Renders every metric in the Prometheus text format.
+pub fn render() -> String {
+    let mut out = String::new();
+    family(
+        &mut out,
+        "nesium_netd_connections",
+        "gauge",
+        "Open client connections.",
+        "transport",
+        metrics::connection_counts(),
+    );
+    family(
+        &mut out,
+        "nesium_netd_rooms",
+        "gauge",
+        "Open rooms.",
+        "",
+        [("", metrics::room_count())],
+    );
+    family(
+        &mut out,
+        "nesium_netd_packets_total",
+        "counter",
+        "Packets handled by the server, by message id.",
+        "msg_id",
+        metrics::packet_counts()
+            .into_iter()
+            .map(|(msg_id, count)| (format!("{msg_id:?}"), count)),
+    );
+    family(
+        &mut out,
+        "nesium_netd_rate_limited_total",
+        "counter",
+        "Messages dropped by rate limiters.",
+        "kind",
+        metrics::rate_limited_counts(),
+    );
+    family(
+        &mut out,
+        "nesium_netd_rejected_connections_total",
+        "counter",
+        "Connections refused by the per-IP abuse controls.",
+        "reason",
+        metrics::rejected_connection_counts(),
+    );
+    family(
+        &mut out,
+        "nesium_netd_decode_errors_total",
+        "counter",
+        "Frames or payloads that failed to decode.",
+        "kind",
+        metrics::decode_error_counts(),
+    );
+    family(
+        &mut out,
+        "nesium_netd_received_bytes_total",
+        "counter",
+        "Bytes read from clients.",
+        "transport",
+        metrics::bytes_received_counts(),
+    );
+    family(
+        &mut out,
+        "nesium_netd_sent_bytes_total",
+        "counter",
+        "Bytes relayed or sent to clients.",
+        "transport",
+        metrics::bytes_sent_counts(),
+    );
+    out
+}
+
+/// Writes one metric family; samples are unlabeled when `label` is empty.
+fn family<L: AsRef<str>>(
+    out: &mut String,
+    name: &str,
+    kind: &str,
+    help: &str,
+    label: &str,
+    samples: impl IntoIterator<Item = (L, u64)>,
+) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+    for (value, count) in samples {
+        if label.is_empty() {
+            let _ = writeln!(out, "{name} {count}");
+        } else {
+            let _ = writeln!(out, "{name}{{{label}=\"{}\"}} {count}", value.as_ref());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nesium_netproto::msg_id::MsgId;
+
+    #[test]
+    fn renders_labeled_samples_in_text_format() {
+        metrics::record_packet(MsgId::Chat);
+        let text = render();
+        assert!(text.contains("# TYPE nesium_netd_packets_total counter\n"));
+        assert!(text.contains("nesium_netd_packets_total{msg_id=\"Chat\"} "));
+        assert!(text.contains("nesium_netd_connections{transport=\"quic\"} "));
+        assert!(text.contains("\nnesium_netd_rooms "));
+        assert!(
+            text.lines()
+                .all(|l| l.starts_with('#') || l.split(' ').count() == 2)
+        );
+    }
+}
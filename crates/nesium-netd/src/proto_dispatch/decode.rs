@@ -1 +1,12 @@
+//! Payload decoding shared by the message handlers.
 
+use nesium_netproto::{error::ProtoError, packet::decode_payload_strict};
+
+use crate::observability::metrics::record_decode_error;
+
+/// Strictly decodes a handler payload, counting failures in the decode metrics.
+pub(crate) fn decode_payload<'a, T: serde::Deserialize<'a>>(
+    payload: &'a [u8],
+) -> Result<T, ProtoError> {
+    decode_payload_strict(payload).inspect_err(record_decode_error)
+}
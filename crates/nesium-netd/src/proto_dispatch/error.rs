@@ -56,6 +56,54 @@ impl HandlerError {
             code: ErrorCode::HostNotAvailable,
         }
     }
+
+    pub fn rate_limited() -> Self {
+        Self {
+            code: ErrorCode::RateLimited,
+        }
+    }
+
+    pub fn password_required() -> Self {
+        Self {
+            code: ErrorCode::PasswordRequired,
+        }
+    }
+
+    pub fn wrong_password() -> Self {
+        Self {
+            code: ErrorCode::WrongPassword,
+        }
+    }
+
+    pub fn rom_mismatch() -> Self {
+        Self {
+            code: ErrorCode::RomMismatch,
+        }
+    }
+
+    pub fn incompatible_version() -> Self {
+        Self {
+            code: ErrorCode::IncompatibleVersion,
+        }
+    }
+
+    pub fn banned() -> Self {
+        Self {
+            code: ErrorCode::Banned,
+        }
+    }
+
+    pub fn server_full() -> Self {
+        Self {
+            code: ErrorCode::ServerFull,
+        }
+    }
+
+    pub fn quota_exceeded() -> Self {
+        Self {
+            code: ErrorCode::QuotaExceeded,
+        }
+    }
 }
 
 /// Convenient Result type for handlers.
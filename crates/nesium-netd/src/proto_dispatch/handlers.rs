@@ -1,28 +1,39 @@
 use std::net::SocketAddr;
 
-use nesium_netproto::{header::Header, messages::session::ErrorMsg, msg_id::MsgId};
-use tracing::warn;
+use nesium_netproto::{
+    constants::HEADER_LEN, header::Header, messages::session::ErrorMsg, msg_id::MsgId,
+};
+use tracing::{debug, warn};
 
 use super::error::HandlerError;
 use crate::ConnCtx;
 use crate::net::framing::PacketOwned;
 use crate::net::inbound::ConnId;
 use crate::net::outbound::send_msg_tcp;
+use crate::observability::metrics::record_rate_limited;
+use crate::room::bandwidth::is_relayed;
 use crate::room::state::RoomManager;
 
+mod chat;
 mod hello;
 mod input_batch;
 mod join_room;
 mod load_rom;
+mod lobby;
 mod p2p_create_room;
 mod p2p_join_room;
+pub(crate) mod p2p_punch_register;
 mod p2p_request_fallback;
 mod pause_game;
+mod ping;
 mod provide_state;
 mod request_fallback_relay;
 mod request_state;
 mod reset_game;
 mod rom_loaded;
+mod rtc_signal;
+mod spectators;
+mod state_hash;
 mod switch_role;
 mod sync_state;
 
@@ -40,15 +51,32 @@ pub(crate) async fn dispatch_packet(
     packet: &PacketOwned,
     room_mgr: &mut RoomManager,
 ) {
+    if is_relayed(packet.msg_id) {
+        let bytes = (HEADER_LEN + packet.payload.len()) as u64;
+        if let Err(exceeded) = room_mgr.charge_relay(ctx.assigned_client_id, bytes) {
+            ctx.rate_limited += 1;
+            record_rate_limited("relay");
+            debug!(
+                client_id = ctx.assigned_client_id,
+                msg_id = ?packet.msg_id,
+                "Relay quota exceeded"
+            );
+            if exceeded.notify {
+                send_error_response(ctx, HandlerError::quota_exceeded()).await;
+            }
+            return;
+        }
+    }
+
     let result = match packet.msg_id {
-        MsgId::Hello => hello::handle(ctx, peer, &packet.payload).await,
+        MsgId::Hello => hello::handle(ctx, peer, &packet.payload, room_mgr).await,
         MsgId::JoinRoom => join_room::handle(ctx, conn_id, peer, &packet.payload, room_mgr).await,
         MsgId::InputBatch => input_batch::handle(ctx, peer, &packet.payload, room_mgr).await,
         MsgId::SwitchRole => {
             switch_role::handle(ctx, conn_id, peer, &packet.payload, room_mgr).await
         }
         MsgId::LoadRom => load_rom::handle(ctx, peer, &packet.payload, room_mgr).await,
-        MsgId::RomLoaded => rom_loaded::handle(ctx, room_mgr).await,
+        MsgId::RomLoaded => rom_loaded::handle(ctx, &packet.payload, room_mgr).await,
         MsgId::PauseGame => pause_game::handle(ctx, &packet.payload, room_mgr).await,
         MsgId::ResetGame => reset_game::handle(ctx, &packet.payload, room_mgr).await,
         MsgId::RequestState => request_state::handle(ctx, room_mgr).await,
@@ -62,6 +90,19 @@ pub(crate) async fn dispatch_packet(
         MsgId::RequestFallbackRelay => {
             request_fallback_relay::handle(ctx, peer, &packet.payload, room_mgr).await
         }
+        MsgId::RtcSignal => rtc_signal::handle(ctx, peer, &packet.payload, room_mgr).await,
+        MsgId::StateHash => state_hash::handle(ctx, &packet.payload, room_mgr).await,
+        MsgId::Chat => chat::handle(ctx, &packet.payload, room_mgr).await,
+        MsgId::ListRooms => lobby::handle_list_rooms(ctx, &packet.payload, room_mgr).await,
+        MsgId::QuickMatch => {
+            lobby::handle_quick_match(ctx, conn_id, peer, &packet.payload, room_mgr).await
+        }
+        MsgId::SetRoomInfo => lobby::handle_set_room_info(ctx, &packet.payload, room_mgr).await,
+        MsgId::SetSpectatorDelay => {
+            spectators::handle_set_spectator_delay(ctx, &packet.payload, room_mgr).await
+        }
+        MsgId::Ping => ping::handle_ping(ctx, &packet.payload, room_mgr).await,
+        MsgId::Pong => ping::handle_pong(ctx, &packet.payload, room_mgr).await,
         _ => {
             warn!(
                 conn_id,
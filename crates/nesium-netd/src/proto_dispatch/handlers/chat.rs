@@ -0,0 +1,56 @@
+use nesium_netproto::{
+    header::Header,
+    messages::chat::{CHAT_MAX_TEXT_LEN, Chat, ChatBody},
+    msg_id::MsgId,
+};
+use tracing::{debug, warn};
+
+use crate::ConnCtx;
+use crate::net::outbound::send_msg_tcp;
+use crate::observability::metrics::record_rate_limited;
+use crate::proto_dispatch::decode::decode_payload;
+use crate::proto_dispatch::error::{HandlerError, HandlerResult};
+use crate::room::state::RoomManager;
+
+pub(crate) async fn handle(
+    ctx: &mut ConnCtx,
+    payload: &[u8],
+    room_mgr: &mut RoomManager,
+) -> HandlerResult {
+    let mut msg: Chat = match decode_payload(payload) {
+        Ok(v) => v,
+        Err(_) => return Err(HandlerError::bad_message()),
+    };
+
+    if let ChatBody::Text(text) = &msg.body {
+        let text = text.trim();
+        if text.is_empty() || text.len() > CHAT_MAX_TEXT_LEN {
+            return Err(HandlerError::bad_message());
+        }
+        msg.body = ChatBody::Text(text.to_string());
+    }
+
+    let Some(room_id) = room_mgr.get_client_room(ctx.assigned_client_id) else {
+        return Err(HandlerError::not_in_room());
+    };
+    let Some(room) = room_mgr.get_room_mut(room_id) else {
+        return Err(HandlerError::not_in_room());
+    };
+
+    if !ctx.chat_limiter.try_acquire() {
+        ctx.rate_limited += 1;
+        record_rate_limited("chat");
+        debug!(client_id = ctx.assigned_client_id, "Chat rate limited");
+        return Err(HandlerError::rate_limited());
+    }
+
+    msg.client_id = ctx.assigned_client_id;
+    let recipients = room.all_outbounds_msg(MsgId::Chat);
+    let h = Header::new(MsgId::Chat as u8);
+    for recipient in &recipients {
+        if let Err(e) = send_msg_tcp(recipient, h, MsgId::Chat, &msg).await {
+            warn!(error = %e, "Failed to broadcast Chat");
+        }
+    }
+    Ok(())
+}
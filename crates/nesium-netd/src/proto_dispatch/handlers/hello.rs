@@ -2,19 +2,62 @@ use std::net::SocketAddr;
 use std::sync::atomic::Ordering;
 
 use nesium_netproto::{
+    constants::{MIN_PROTOCOL_VERSION, PROTOCOL_VERSION},
     header::Header,
-    messages::session::{Hello, Welcome},
+    messages::session::{Hello, TransportKind, Welcome, capability},
     msg_id::MsgId,
 };
+use serde::Deserialize;
 use tracing::{error, info, warn};
 
+use crate::proto_dispatch::decode::decode_payload;
 use crate::proto_dispatch::error::{HandlerError, HandlerResult};
+use crate::room::state::RoomManager;
+use crate::room::store::BanTarget;
 use crate::{
     ConnCtx, NEXT_CLIENT_ID, NEXT_SERVER_NONCE, NEXT_SESSION_TOKEN, net::outbound::send_msg_tcp,
 };
 
-pub(crate) async fn handle(ctx: &mut ConnCtx, peer: &SocketAddr, payload: &[u8]) -> HandlerResult {
-    let hello: Hello = match postcard::from_bytes(payload) {
+/// The version-stable prefix of [`Hello`].
+#[derive(Deserialize)]
+struct HelloVersion {
+    _client_nonce: u32,
+    _transport: TransportKind,
+    proto_min: u8,
+    proto_max: u8,
+}
+
+/// Highest session protocol version within both our and the client's range.
+fn negotiate_version(proto_min: u8, proto_max: u8) -> Option<u8> {
+    let version = proto_max.min(PROTOCOL_VERSION);
+    (version >= proto_min.max(MIN_PROTOCOL_VERSION)).then_some(version)
+}
+
+pub(crate) async fn handle(
+    ctx: &mut ConnCtx,
+    peer: &SocketAddr,
+    payload: &[u8],
+    room_mgr: &mut RoomManager,
+) -> HandlerResult {
+    // Check the version first: other versions may lay out the rest differently.
+    let version = match postcard::take_from_bytes::<HelloVersion>(payload) {
+        Ok((v, _)) => v,
+        Err(e) => {
+            warn!(%peer, error = %e, "Bad Hello message");
+            return Err(HandlerError::bad_message());
+        }
+    };
+    let Some(proto_version) = negotiate_version(version.proto_min, version.proto_max) else {
+        warn!(
+            %peer,
+            proto_min = version.proto_min,
+            proto_max = version.proto_max,
+            "Hello with incompatible protocol version"
+        );
+        return Err(HandlerError::incompatible_version());
+    };
+
+    let hello: Hello = match decode_payload(payload) {
         Ok(v) => v,
         Err(e) => {
             warn!(%peer, error = %e, "Bad Hello message");
@@ -22,10 +65,29 @@ pub(crate) async fn handle(ctx: &mut ConnCtx, peer: &SocketAddr, payload: &[u8])
         }
     };
 
+    if room_mgr.is_banned(BanTarget::Ip(peer.ip())) {
+        warn!(%peer, "Rejecting Hello from banned address");
+        return Err(HandlerError::banned());
+    }
+    let identity = room_mgr.identify(hello.user_token, &hello.name);
+    if let Some(identity) = identity {
+        if room_mgr.is_banned(BanTarget::User(identity.user_id)) {
+            warn!(%peer, user_id = identity.user_id, "Rejecting Hello from banned user");
+            return Err(HandlerError::banned());
+        }
+        ctx.user_id = identity.user_id;
+    }
+
     if ctx.assigned_client_id == 0 {
         ctx.assigned_client_id = NEXT_CLIENT_ID.fetch_add(1, Ordering::Relaxed);
         ctx.name = hello.name.clone();
     }
+    ctx.capabilities = hello.capabilities & capability::SUPPORTED;
+    // Welcome itself is a control message, so it goes out uncompressed either way.
+    ctx.compress.store(
+        ctx.capabilities & capability::COMPRESSION != 0,
+        Ordering::Relaxed,
+    );
 
     ctx.role = crate::ConnRole::Control;
 
@@ -46,6 +108,9 @@ pub(crate) async fn handle(ctx: &mut ConnCtx, peer: &SocketAddr, payload: &[u8])
         input_delay_frames: 2,
         max_payload: 4096,
         rewind_capacity: 600,
+        proto_version,
+        capabilities: ctx.capabilities,
+        identity,
     };
 
     let h = Header::new(MsgId::Welcome as u8);
@@ -55,6 +120,9 @@ pub(crate) async fn handle(ctx: &mut ConnCtx, peer: &SocketAddr, payload: &[u8])
             info!(
                 client_id = ctx.assigned_client_id,
                 name = %hello.name,
+                proto_version,
+                capabilities = ctx.capabilities,
+                user_id = ctx.user_id,
                 "Hello/Welcome handshake completed"
             );
         }
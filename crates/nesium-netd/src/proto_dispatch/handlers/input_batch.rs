@@ -1,12 +1,17 @@
 use std::net::SocketAddr;
 
+use nesium_netproto::constants::DATAGRAM_INPUT_REDUNDANCY;
 use nesium_netproto::messages::input::InputBatch;
 use nesium_netproto::msg_id::MsgId;
 use tracing::warn;
 
 use crate::ConnCtx;
+use crate::proto_dispatch::decode::decode_payload;
 use crate::proto_dispatch::error::{HandlerError, HandlerResult};
-use crate::room::broadcast::{broadcast_inputs_best_effort, broadcast_inputs_required};
+use crate::room::broadcast::{
+    broadcast_inputs_best_effort, broadcast_inputs_datagram, broadcast_inputs_required,
+};
+use crate::room::spectator_delay::DelayedInputs;
 use crate::room::state::RoomManager;
 
 pub(crate) async fn handle(
@@ -15,7 +20,7 @@ pub(crate) async fn handle(
     payload: &[u8],
     room_mgr: &mut RoomManager,
 ) -> HandlerResult {
-    let batch: InputBatch = match postcard::from_bytes(payload) {
+    let batch: InputBatch = match decode_payload(payload) {
         Ok(v) => v,
         Err(e) => {
             warn!(%peer, error = %e, "Bad InputBatch message");
@@ -42,9 +47,39 @@ pub(crate) async fn handle(
         return Err(HandlerError::permission_denied());
     };
 
-    room.record_inputs(player_index, batch.start_frame, &batch.buttons);
+    // Clients with a datagram channel send each batch twice; only the first
+    // copy to arrive is relayed.
+    if !room.record_inputs(player_index, batch.start_frame, &batch.buttons) {
+        return Ok(());
+    }
 
-    // Players are required recipients for lockstep; spectators are best-effort.
+    let end_frame = batch
+        .start_frame
+        .wrapping_add(batch.buttons.len() as u32 - 1);
+    let (redundant_start, redundant_buttons) =
+        room.recent_inputs(player_index, end_frame, DATAGRAM_INPUT_REDUNDANCY);
+    let mut datagram_recipients: Vec<_> = room
+        .players
+        .values()
+        .filter_map(|p| p.outbounds.datagram.clone())
+        .collect();
+    // Delayed spectators only get the held-back reliable relay.
+    if !room.spectator_delay.is_active() {
+        datagram_recipients.extend(
+            room.spectators
+                .iter()
+                .filter_map(|s| s.outbounds.datagram.clone()),
+        );
+    }
+    broadcast_inputs_datagram(
+        &datagram_recipients,
+        player_index,
+        redundant_start,
+        &redundant_buttons,
+    );
+
+    // Players are required recipients for lockstep; spectators are best-effort
+    // and may watch behind live.
     let player_recipients: Vec<_> = room
         .players
         .values()
@@ -63,11 +98,25 @@ pub(crate) async fn handle(
         &batch.buttons,
     )
     .await;
-    broadcast_inputs_best_effort(
-        &spectator_recipients,
+
+    let inputs = DelayedInputs {
         player_index,
-        batch.start_frame,
-        &batch.buttons,
-    );
+        start_frame: batch.start_frame,
+        buttons: batch.buttons,
+    };
+    let released = if room.spectator_delay.is_active() {
+        room.spectator_delay.push(inputs);
+        room.spectator_delay.release(room.current_frame)
+    } else {
+        vec![inputs]
+    };
+    for inputs in released {
+        broadcast_inputs_best_effort(
+            &spectator_recipients,
+            inputs.player_index,
+            inputs.start_frame,
+            &inputs.buttons,
+        );
+    }
     Ok(())
 }
@@ -1,10 +1,9 @@
 use std::net::SocketAddr;
 
 use nesium_netproto::{
-    channel::ChannelKind,
     constants::SPECTATOR_PLAYER_INDEX,
     header::Header,
-    messages::session::{JoinAck, JoinRoom, LoadRom, PlayerJoined},
+    messages::session::{JoinAck, JoinRoom, LoadRom, PlayerJoined, ROOM_PASSWORD_MAX_LEN},
     msg_id::MsgId,
 };
 use tracing::{error, info, warn};
@@ -12,8 +11,12 @@ use tracing::{error, info, warn};
 use crate::ConnCtx;
 use crate::net::inbound::ConnId;
 use crate::net::outbound::send_msg_tcp;
+use crate::proto_dispatch::decode::decode_payload;
 use crate::proto_dispatch::error::{HandlerError, HandlerResult};
-use crate::room::state::{ClientOutbounds, Player, RoomManager, Spectator};
+use crate::room::broadcast::broadcast_spectator_info;
+use crate::room::password::RoomPassword;
+use crate::room::state::{ClientOutbounds, JOIN_PLAYER_SLOTS, Player, RoomManager, Spectator};
+use crate::webhook::WebhookEvent;
 
 pub(crate) async fn handle(
     ctx: &mut ConnCtx,
@@ -22,14 +25,28 @@ pub(crate) async fn handle(
     payload: &[u8],
     room_mgr: &mut RoomManager,
 ) -> HandlerResult {
-    let join: JoinRoom = match postcard::from_bytes(payload) {
+    let join: JoinRoom = match decode_payload(payload) {
         Ok(v) => v,
         Err(e) => {
             warn!(%peer, error = %e, "Bad JoinRoom message");
             return Err(HandlerError::bad_message());
         }
     };
+    join_room(ctx, conn_id, peer, join, room_mgr).await
+}
 
+/// Join (or create, for room code 0) a room; shared with quick matching.
+pub(super) async fn join_room(
+    ctx: &mut ConnCtx,
+    conn_id: ConnId,
+    peer: &SocketAddr,
+    join: JoinRoom,
+    room_mgr: &mut RoomManager,
+) -> HandlerResult {
+    // No Hello yet (or it was rejected, e.g. for a ban).
+    if ctx.assigned_client_id == 0 {
+        return Err(HandlerError::invalid_state());
+    }
     if room_mgr.get_client_room(ctx.assigned_client_id).is_some() {
         warn!(
             client_id = ctx.assigned_client_id,
@@ -38,42 +55,66 @@ pub(crate) async fn handle(
         return Err(HandlerError::already_in_room());
     }
 
+    // An empty password is the same as none.
+    let password = join.password.as_deref().filter(|p| !p.is_empty());
+    if password.is_some_and(|p| p.len() > ROOM_PASSWORD_MAX_LEN) {
+        warn!(%peer, "Room password too long");
+        return Err(HandlerError::bad_message());
+    }
+
     let room_id = if join.room_code == 0 {
+        if room_mgr.at_room_limit() {
+            warn!(client_id = ctx.assigned_client_id, "Room limit reached");
+            return Err(HandlerError::server_full());
+        }
         let id = room_mgr.create_room(ctx.assigned_client_id);
         info!(
             room_id = id,
             client_id = ctx.assigned_client_id,
             "Room created"
         );
-        // The room's sync mode is decided at creation time by the host.
-        if let Some(room) = room_mgr.get_room_mut(id)
-            && let Some(preferred) = join.preferred_sync_mode
-        {
-            room.sync_mode = preferred;
+        // The room's sync mode and password are decided at creation time by the host.
+        if let Some(room) = room_mgr.get_room_mut(id) {
+            if let Some(preferred) = join.preferred_sync_mode {
+                room.sync_mode = preferred;
+            }
+            if let Some(password) = password {
+                room.password = Some(RoomPassword::new(password));
+                info!(room_id = id, "Room is private");
+            }
         }
         id
     } else {
-        match room_mgr.find_by_code(join.room_code) {
-            Some(r) => r.id,
-            None => {
-                warn!(room_code = join.room_code, "Room code not found");
-                return Err(HandlerError::room_not_found());
+        let Some(room) = room_mgr.find_or_reopen(join.room_code, ctx.assigned_client_id) else {
+            warn!(room_code = join.room_code, "Room code not found");
+            return Err(HandlerError::room_not_found());
+        };
+        if let Some(expected) = &room.password {
+            let Some(password) = password else {
+                warn!(room_id = room.id, "Private room joined without password");
+                return Err(HandlerError::password_required());
+            };
+            if !expected.verify(password) {
+                warn!(
+                    room_id = room.id,
+                    client_id = ctx.assigned_client_id,
+                    "Wrong room password"
+                );
+                return Err(HandlerError::wrong_password());
             }
         }
+        room.id
     };
 
     let (ok, player_index) = {
         let room = room_mgr
             .get_room_mut(room_id)
             .expect("room should exist as we either just created it or found it by code");
-        let is_spectator = room.player_count() >= 2;
+        let is_spectator = room.player_count() >= JOIN_PLAYER_SLOTS;
 
         let mut outbounds = ClientOutbounds::new(ctx.outbound.clone());
-        if let Some(tx) = ctx.channels.get(&ChannelKind::Input) {
-            outbounds.set_channel(ChannelKind::Input, tx.clone());
-        }
-        if let Some(tx) = ctx.channels.get(&ChannelKind::Bulk) {
-            outbounds.set_channel(ChannelKind::Bulk, tx.clone());
+        for (&channel, tx) in &ctx.channels {
+            outbounds.set_channel(channel, tx.clone());
         }
 
         if is_spectator {
@@ -115,6 +156,12 @@ pub(crate) async fn handle(
 
     if ok {
         room_mgr.set_client_room(ctx.assigned_client_id, room_id);
+        room_mgr.webhooks().notify(WebhookEvent::PlayerJoined {
+            room_id,
+            client_id: ctx.assigned_client_id,
+            name: ctx.name.clone(),
+            player_index: (player_index != SPECTATOR_PLAYER_INDEX).then_some(player_index),
+        });
     }
 
     // Determine start_frame for late joiners: use cached state frame if available
@@ -200,6 +247,19 @@ pub(crate) async fn handle(
         }
     }
 
+    // A new spectator changes the count players see; a new player only needs
+    // to learn it, unless nobody watches.
+    if ok {
+        let room = room_mgr.get_room_mut(room_id).expect("room should exist");
+        let info = room.spectator_info();
+        if player_index == SPECTATOR_PLAYER_INDEX {
+            broadcast_spectator_info(room).await;
+        } else if info.count > 0 || info.delay_frames > 0 {
+            let h = Header::new(MsgId::SpectatorInfo as u8);
+            let _ = send_msg_tcp(&ctx.outbound, h, MsgId::SpectatorInfo, &info).await;
+        }
+    }
+
     // Late joiners: if there's a cached ROM, send it immediately.
     let Some(room) = room_mgr.get_room_mut(room_id) else {
         return Ok(());
@@ -210,7 +270,10 @@ pub(crate) async fn handle(
             client_id = ctx.assigned_client_id,
             "Sending cached ROM to joiner"
         );
-        let load_rom = LoadRom { data: rom_data };
+        let load_rom = LoadRom {
+            data: rom_data,
+            hash: room.rom_hash,
+        };
         let h = Header::new(MsgId::LoadRom as u8);
         let tx = room
             .outbound_for_client_msg(ctx.assigned_client_id, MsgId::LoadRom)
@@ -5,6 +5,7 @@ use tracing::{info, warn};
 
 use crate::ConnCtx;
 use crate::net::outbound::send_msg_tcp;
+use crate::proto_dispatch::decode::decode_payload;
 use crate::proto_dispatch::error::{HandlerError, HandlerResult};
 use crate::room::state::RoomManager;
 
@@ -14,7 +15,7 @@ pub(crate) async fn handle(
     payload: &[u8],
     room_mgr: &mut RoomManager,
 ) -> HandlerResult {
-    let msg: LoadRom = match postcard::from_bytes(payload) {
+    let msg: LoadRom = match decode_payload(payload) {
         Ok(v) => v,
         Err(e) => {
             warn!(%peer, error = %e, "Bad LoadRom message");
@@ -38,7 +39,7 @@ pub(crate) async fn handle(
                 room_id, "Host loaded ROM, forwarding..."
             );
 
-            room.cache_rom(msg.data.clone());
+            room.cache_rom(msg.data.clone(), msg.hash);
 
             let h = Header::new(MsgId::LoadRom as u8);
 
@@ -0,0 +1,113 @@
+use std::net::SocketAddr;
+
+use nesium_netproto::{
+    header::Header,
+    messages::lobby::{
+        ListRooms, QuickMatch, ROOM_NAME_MAX_LEN, ROOM_REGION_MAX_LEN, RoomInfo, RoomList,
+        SetRoomInfo,
+    },
+    messages::session::JoinRoom,
+    msg_id::MsgId,
+};
+use tracing::{info, warn};
+
+use super::join_room::join_room;
+use crate::ConnCtx;
+use crate::net::inbound::ConnId;
+use crate::net::outbound::send_msg_tcp;
+use crate::proto_dispatch::decode::decode_payload;
+use crate::proto_dispatch::error::{HandlerError, HandlerResult};
+use crate::room::state::RoomManager;
+
+pub(crate) async fn handle_list_rooms(
+    ctx: &mut ConnCtx,
+    payload: &[u8],
+    room_mgr: &mut RoomManager,
+) -> HandlerResult {
+    let req: ListRooms = match decode_payload(payload) {
+        Ok(v) => v,
+        Err(_) => return Err(HandlerError::bad_message()),
+    };
+
+    let list = RoomList {
+        rooms: room_mgr.list_rooms(&req.filter),
+    };
+    let h = Header::new(MsgId::RoomList as u8);
+    if let Err(e) = send_msg_tcp(&ctx.outbound, h, MsgId::RoomList, &list).await {
+        warn!(error = %e, "Failed to send RoomList");
+    }
+    Ok(())
+}
+
+pub(crate) async fn handle_quick_match(
+    ctx: &mut ConnCtx,
+    conn_id: ConnId,
+    peer: &SocketAddr,
+    payload: &[u8],
+    room_mgr: &mut RoomManager,
+) -> HandlerResult {
+    let req: QuickMatch = match decode_payload(payload) {
+        Ok(v) => v,
+        Err(_) => return Err(HandlerError::bad_message()),
+    };
+    let region = req.filter.region.as_deref().map(str::trim).unwrap_or("");
+    if region.len() > ROOM_REGION_MAX_LEN {
+        return Err(HandlerError::bad_message());
+    }
+
+    let matched = room_mgr.quick_match(&req.filter);
+    let join = JoinRoom {
+        room_code: matched.unwrap_or(0),
+        preferred_sync_mode: req.preferred_sync_mode,
+        password: None,
+    };
+    join_room(ctx, conn_id, peer, join, room_mgr).await?;
+
+    // No match: describe the new room by the filter so the next request finds it.
+    if matched.is_none()
+        && let Some(room_id) = room_mgr.get_client_room(ctx.assigned_client_id)
+        && let Some(room) = room_mgr.get_room_mut(room_id)
+    {
+        room.info.region = region.to_string();
+        room.info.game_hash = req.filter.game_hash.unwrap_or(0);
+    }
+    info!(
+        client_id = ctx.assigned_client_id,
+        room_code = ?matched,
+        "Quick match"
+    );
+    Ok(())
+}
+
+pub(crate) async fn handle_set_room_info(
+    ctx: &mut ConnCtx,
+    payload: &[u8],
+    room_mgr: &mut RoomManager,
+) -> HandlerResult {
+    let msg: SetRoomInfo = match decode_payload(payload) {
+        Ok(v) => v,
+        Err(_) => return Err(HandlerError::bad_message()),
+    };
+    let info = RoomInfo {
+        name: msg.info.name.trim().to_string(),
+        region: msg.info.region.trim().to_string(),
+        game_hash: msg.info.game_hash,
+    };
+    if info.name.len() > ROOM_NAME_MAX_LEN || info.region.len() > ROOM_REGION_MAX_LEN {
+        return Err(HandlerError::bad_message());
+    }
+
+    let Some(room_id) = room_mgr.get_client_room(ctx.assigned_client_id) else {
+        return Err(HandlerError::not_in_room());
+    };
+    let Some(room) = room_mgr.get_room_mut(room_id) else {
+        return Err(HandlerError::not_in_room());
+    };
+    if room.host_client_id != ctx.assigned_client_id {
+        return Err(HandlerError::permission_denied());
+    }
+
+    room.info = info;
+    room_mgr.save_room(room_id);
+    Ok(())
+}
@@ -10,6 +10,7 @@ use tracing::{info, warn};
 use crate::{
     ConnCtx,
     net::outbound::send_msg_tcp,
+    proto_dispatch::decode::decode_payload,
     proto_dispatch::error::{HandlerError, HandlerResult},
     room::state::{P2PHostInfo, RoomManager},
 };
@@ -20,7 +21,7 @@ pub(crate) async fn handle(
     payload: &[u8],
     room_mgr: &mut RoomManager,
 ) -> HandlerResult {
-    let msg: P2PCreateRoom = match postcard::from_bytes(payload) {
+    let msg: P2PCreateRoom = match decode_payload(payload) {
         Ok(v) => v,
         Err(e) => {
             warn!(%peer, error = %e, "Bad P2PCreateRoom message");
@@ -38,6 +39,11 @@ pub(crate) async fn handle(
         return Err(HandlerError::bad_message());
     }
 
+    if room_mgr.at_room_limit() {
+        warn!(client_id = ctx.assigned_client_id, "Room limit reached");
+        return Err(HandlerError::server_full());
+    }
+
     let room_id = room_mgr.create_room(ctx.assigned_client_id);
     let Some(room) = room_mgr.get_room_mut(room_id) else {
         return Err(HandlerError::invalid_state());
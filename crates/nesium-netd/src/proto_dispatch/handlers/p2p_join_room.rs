@@ -10,6 +10,7 @@ use tracing::warn;
 use crate::{
     ConnCtx,
     net::outbound::send_msg_tcp,
+    proto_dispatch::decode::decode_payload,
     proto_dispatch::error::{HandlerError, HandlerResult},
     room::state::RoomManager,
 };
@@ -20,7 +21,7 @@ pub(crate) async fn handle(
     payload: &[u8],
     room_mgr: &mut RoomManager,
 ) -> HandlerResult {
-    let join: P2PJoinRoom = match postcard::from_bytes(payload) {
+    let join: P2PJoinRoom = match decode_payload(payload) {
         Ok(v) => v,
         Err(e) => {
             warn!(%peer, error = %e, "Bad P2PJoinRoom message");
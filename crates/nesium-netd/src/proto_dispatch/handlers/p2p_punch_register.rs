@@ -0,0 +1,86 @@
+use std::net::SocketAddr;
+
+use nesium_netproto::{
+    header::Header,
+    messages::session::{P2P_MAX_LOCAL_ADDRS, P2PPunchRegister, P2PPunchStart},
+    msg_id::MsgId,
+};
+use tracing::{debug, info, warn};
+
+use crate::{
+    net::outbound::send_msg_tcp,
+    room::state::{P2PPunchEndpoint, RoomManager},
+};
+
+/// Handles a `P2PPunchRegister` datagram from `peer`, already resolved to the
+/// session's `client_id`. There is no reply on UDP; the client keeps
+/// registering until `P2PPunchStart` arrives on its control connection.
+pub(crate) async fn handle(
+    client_id: u32,
+    peer: &SocketAddr,
+    msg: P2PPunchRegister,
+    room_mgr: &mut RoomManager,
+) {
+    if msg.local_addrs.len() > P2P_MAX_LOCAL_ADDRS {
+        warn!(%peer, addrs_len = msg.local_addrs.len(), "Too many punch local addresses");
+        return;
+    }
+
+    let Some(room) = room_mgr.find_by_code_mut(msg.room_code) else {
+        debug!(%peer, room_code = msg.room_code, "P2PPunchRegister: room not found");
+        return;
+    };
+    let Some(host_id) = room.p2p_host.as_ref().map(|h| h.host_signal_client_id) else {
+        return;
+    };
+
+    let ready = room.register_p2p_punch(
+        client_id,
+        P2PPunchEndpoint {
+            public_addr: *peer,
+            local_addrs: msg.local_addrs,
+            started: false,
+        },
+    );
+
+    for joiner_id in ready {
+        let (Some(host), Some(joiner)) =
+            (room.p2p_punch.get(&host_id), room.p2p_punch.get(&joiner_id))
+        else {
+            continue;
+        };
+        let (Some(host_tx), Some(joiner_tx)) = (
+            room.p2p_watchers.get(&host_id),
+            room.p2p_watchers.get(&joiner_id),
+        ) else {
+            continue;
+        };
+
+        let nonce: u64 = rand::random();
+        let addrs = |e: &P2PPunchEndpoint| {
+            std::iter::once(e.public_addr)
+                .chain(e.local_addrs.iter().copied())
+                .collect::<Vec<_>>()
+        };
+        let h = Header::new(MsgId::P2PPunchStart as u8);
+        let to_host = P2PPunchStart {
+            room_code: room.code,
+            peer_client_id: joiner_id,
+            peer_addrs: addrs(joiner),
+            nonce,
+        };
+        let to_joiner = P2PPunchStart {
+            room_code: room.code,
+            peer_client_id: host_id,
+            peer_addrs: addrs(host),
+            nonce,
+        };
+        let _ = send_msg_tcp(host_tx, h, MsgId::P2PPunchStart, &to_host).await;
+        let _ = send_msg_tcp(joiner_tx, h, MsgId::P2PPunchStart, &to_joiner).await;
+
+        info!(
+            room_code = room.code,
+            host_id, joiner_id, "Started P2P hole punching"
+        );
+    }
+}
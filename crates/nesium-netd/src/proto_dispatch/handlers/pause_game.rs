@@ -7,6 +7,7 @@ use tracing::{info, warn};
 
 use crate::ConnCtx;
 use crate::net::outbound::send_msg_tcp;
+use crate::proto_dispatch::decode::decode_payload;
 use crate::proto_dispatch::error::{HandlerError, HandlerResult};
 use crate::room::state::RoomManager;
 
@@ -15,7 +16,7 @@ pub(crate) async fn handle(
     payload: &[u8],
     room_mgr: &mut RoomManager,
 ) -> HandlerResult {
-    let msg: PauseGame = match postcard::from_bytes(payload) {
+    let msg: PauseGame = match decode_payload(payload) {
         Ok(v) => v,
         Err(_) => return Err(HandlerError::bad_message()),
     };
@@ -0,0 +1,87 @@
+use nesium_netproto::{
+    header::Header,
+    messages::sync::{Ping, Pong},
+    msg_id::MsgId,
+};
+use serde::Serialize;
+use tracing::warn;
+
+use crate::ConnCtx;
+use crate::net::outbound::send_msg_tcp;
+use crate::proto_dispatch::decode::decode_payload;
+use crate::proto_dispatch::error::{HandlerError, HandlerResult};
+use crate::room::state::RoomManager;
+
+/// Answers pings addressed to the server and relays the rest to the target
+/// room member, so clients can measure RTT both to us and to each peer.
+pub(crate) async fn handle_ping(
+    ctx: &mut ConnCtx,
+    payload: &[u8],
+    room_mgr: &mut RoomManager,
+) -> HandlerResult {
+    let mut ping: Ping = match decode_payload(payload) {
+        Ok(v) => v,
+        Err(_) => return Err(HandlerError::bad_message()),
+    };
+
+    if ping.peer_client_id == 0 {
+        let pong = Pong {
+            seq: ping.seq,
+            t_ms: ping.t_ms,
+            peer_client_id: 0,
+        };
+        let h = Header::new(MsgId::Pong as u8);
+        let _ = send_msg_tcp(&ctx.outbound, h, MsgId::Pong, &pong).await;
+        return Ok(());
+    }
+
+    let target = ping.peer_client_id;
+    ping.peer_client_id = ctx.assigned_client_id;
+    relay_to_peer(ctx, room_mgr, target, MsgId::Ping, &ping).await
+}
+
+/// Relays a peer's answer back to the client that sent the ping.
+pub(crate) async fn handle_pong(
+    ctx: &mut ConnCtx,
+    payload: &[u8],
+    room_mgr: &mut RoomManager,
+) -> HandlerResult {
+    let mut pong: Pong = match decode_payload(payload) {
+        Ok(v) => v,
+        Err(_) => return Err(HandlerError::bad_message()),
+    };
+    if pong.peer_client_id == 0 {
+        return Err(HandlerError::bad_message());
+    }
+
+    let target = pong.peer_client_id;
+    pong.peer_client_id = ctx.assigned_client_id;
+    relay_to_peer(ctx, room_mgr, target, MsgId::Pong, &pong).await
+}
+
+async fn relay_to_peer<T: Serialize>(
+    ctx: &ConnCtx,
+    room_mgr: &mut RoomManager,
+    target: u32,
+    msg_id: MsgId,
+    msg: &T,
+) -> HandlerResult {
+    let Some(room_id) = room_mgr.get_client_room(ctx.assigned_client_id) else {
+        return Err(HandlerError::not_in_room());
+    };
+    // Only route within the sender's own room.
+    if room_mgr.get_client_room(target) != Some(room_id) {
+        return Err(HandlerError::permission_denied());
+    }
+    let Some(room) = room_mgr.get_room_mut(room_id) else {
+        return Err(HandlerError::not_in_room());
+    };
+    let Some(outbound) = room.outbound_for_client_msg(target, msg_id) else {
+        return Err(HandlerError::permission_denied());
+    };
+
+    if let Err(e) = send_msg_tcp(&outbound, Header::new(msg_id as u8), msg_id, msg).await {
+        warn!(error = %e, ?msg_id, "Failed to relay RTT probe");
+    }
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use nesium_netproto::messages::session::ProvideState;
 use tracing::{debug, warn};
 
+use super::rom_loaded::send_catch_up;
 use crate::ConnCtx;
+use crate::proto_dispatch::decode::decode_payload;
 use crate::proto_dispatch::error::{HandlerError, HandlerResult};
 use crate::room::state::RoomManager;
 
@@ -10,7 +12,7 @@ pub(crate) async fn handle(
     payload: &[u8],
     room_mgr: &mut RoomManager,
 ) -> HandlerResult {
-    let msg: ProvideState = match postcard::from_bytes(payload) {
+    let msg: ProvideState = match decode_payload(payload) {
         Ok(v) => v,
         Err(e) => {
             warn!(error = %e, "Bad ProvideState message");
@@ -27,5 +29,10 @@ pub(crate) async fn handle(
 
     room.cache_state(msg.frame, msg.data);
     debug!(room_id, frame = msg.frame, "Cached game state");
+
+    // Late joiners waiting for a fresh snapshot can start catching up now.
+    for client_id in std::mem::take(&mut room.pending_catch_up) {
+        send_catch_up(room, client_id).await;
+    }
     Ok(())
 }
@@ -8,6 +8,7 @@ use nesium_netproto::{
 use tracing::{error, info, warn};
 
 use crate::ConnCtx;
+use crate::proto_dispatch::decode::decode_payload;
 use crate::proto_dispatch::error::{HandlerError, HandlerResult};
 use crate::room::state::RoomManager;
 
@@ -16,7 +17,7 @@ pub(crate) async fn handle(
     payload: &[u8],
     room_mgr: &mut RoomManager,
 ) -> HandlerResult {
-    let msg: ResetGame = match postcard::from_bytes(payload) {
+    let msg: ResetGame = match decode_payload(payload) {
         Ok(v) => v,
         Err(e) => {
             warn!(error = %e, "Bad ResetGame message");
@@ -1,17 +1,27 @@
 use nesium_netproto::{
     header::Header,
-    messages::session::{BeginCatchUp, StartGame, SyncState},
+    messages::session::{BeginCatchUp, CaptureState, RomLoaded, StartGame, SyncState},
     msg_id::MsgId,
 };
 use tracing::{debug, info, warn};
 
 use crate::ConnCtx;
 use crate::net::outbound::send_msg_tcp;
-use crate::proto_dispatch::error::HandlerResult;
+use crate::proto_dispatch::decode::decode_payload;
+use crate::proto_dispatch::error::{HandlerError, HandlerResult};
 use crate::room::broadcast::broadcast_inputs_required;
-use crate::room::state::RoomManager;
+use crate::room::state::{Room, RoomManager};
+
+pub(crate) async fn handle(
+    ctx: &mut ConnCtx,
+    payload: &[u8],
+    room_mgr: &mut RoomManager,
+) -> HandlerResult {
+    let msg: RomLoaded = match decode_payload(payload) {
+        Ok(v) => v,
+        Err(_) => return Err(HandlerError::bad_message()),
+    };
 
-pub(crate) async fn handle(ctx: &mut ConnCtx, room_mgr: &mut RoomManager) -> HandlerResult {
     let Some(room_id) = room_mgr.get_client_room(ctx.assigned_client_id) else {
         // Not an error - just ignore if not in room
         return Ok(());
@@ -20,6 +30,24 @@ pub(crate) async fn handle(ctx: &mut ConnCtx, room_mgr: &mut RoomManager) -> Han
         return Ok(());
     };
 
+    match (room.rom_hash, msg.hash) {
+        (Some(expected), Some(actual)) if expected != actual => {
+            warn!(
+                client_id = ctx.assigned_client_id,
+                room_id,
+                expected_crc32 = format_args!("{:08X}", expected.crc32),
+                actual_crc32 = format_args!("{:08X}", actual.crc32),
+                "Client loaded a different ROM, refusing"
+            );
+            return Err(HandlerError::rom_mismatch());
+        }
+        (Some(_), Some(_)) => {}
+        _ => warn!(
+            client_id = ctx.assigned_client_id,
+            room_id, "ROM hash unknown, cannot verify the client's ROM"
+        ),
+    }
+
     info!(
         client_id = ctx.assigned_client_id,
         room_id, "Client confirmed ROM loaded"
@@ -28,8 +56,6 @@ pub(crate) async fn handle(ctx: &mut ConnCtx, room_mgr: &mut RoomManager) -> Han
     let sender_id = ctx.assigned_client_id;
     let was_started = room.started;
     let sender_was_loaded = room.loaded_players.contains(&sender_id);
-    let sender_state_outbound = room.outbound_for_client_msg(sender_id, MsgId::SyncState);
-    let sender_input_outbound = room.outbound_for_client_msg(sender_id, MsgId::RelayInputs);
 
     let start_recipients = room.handle_rom_loaded(sender_id);
     if !start_recipients.is_empty() {
@@ -49,60 +75,101 @@ pub(crate) async fn handle(ctx: &mut ConnCtx, room_mgr: &mut RoomManager) -> Han
     }
 
     // Late joiner: room already started, but this client just finished loading the ROM.
-    // Send them the latest cached state + input history, then BeginCatchUp to activate lockstep.
+    // Catch-up starts from the host's next snapshot, so ask the host to take one
+    // now instead of waiting for its periodic one. Without a host, use the cache.
     if was_started && !sender_was_loaded {
-        let Some(state_outbound) = sender_state_outbound else {
-            return Ok(());
-        };
+        let host_outbound = room
+            .players
+            .get(&0)
+            .filter(|host| host.client_id != sender_id)
+            .map(|host| host.outbounds.outbound_for_msg(MsgId::CaptureState));
+        match host_outbound {
+            Some(host_outbound) => {
+                room.pending_catch_up.push(sender_id);
+                info!(
+                    client_id = sender_id,
+                    room_id, "Requesting fresh state from host for late joiner"
+                );
+                let h = Header::new(MsgId::CaptureState as u8);
+                if let Err(e) =
+                    send_msg_tcp(&host_outbound, h, MsgId::CaptureState, &CaptureState).await
+                {
+                    warn!(error = %e, "Failed to send CaptureState");
+                }
+            }
+            None => send_catch_up(room, sender_id).await,
+        }
+    }
+    Ok(())
+}
 
-        if let Some((frame, state_data)) = room.cached_state.clone() {
-            info!(
-                client_id = sender_id,
-                frame, "Sending cached state to late joiner"
-            );
-            let sync_state = SyncState {
-                frame,
-                data: state_data,
-            };
-            let h = Header::new(MsgId::SyncState as u8);
-            let _ = send_msg_tcp(&state_outbound, h, MsgId::SyncState, &sync_state).await;
+/// Sends a late joiner the cached state and the input history since it, then
+/// `BeginCatchUp` to activate lockstep. State and history go over the
+/// client's bulk and input channels.
+pub(super) async fn send_catch_up(room: &Room, client_id: u32) {
+    let Some((frame, state_data)) = room.cached_state.clone() else {
+        debug!(
+            client_id,
+            room_id = room.id,
+            "No cached state available for late joiner"
+        );
+        return;
+    };
+    let Some(state_outbound) = room.outbound_for_client_msg(client_id, MsgId::SyncState) else {
+        return;
+    };
 
-            let history = room.get_input_history(frame);
-            info!(
-                client_id = sender_id,
-                chunks = history.len(),
-                "Sending input history to late joiner"
-            );
+    info!(client_id, frame, "Sending cached state to late joiner");
+    let sync_state = SyncState {
+        frame,
+        data: state_data,
+    };
+    let h = Header::new(MsgId::SyncState as u8);
+    let _ = send_msg_tcp(&state_outbound, h, MsgId::SyncState, &sync_state).await;
 
-            let Some(input_outbound) = sender_input_outbound else {
-                return Ok(());
-            };
-            let recipients = vec![input_outbound.clone()];
-            for (p_idx, base, buttons) in history {
-                broadcast_inputs_required(&recipients, p_idx, base, &buttons).await;
-            }
+    // Delayed spectators get history only up to what the others already see;
+    // the rest follows from the delay backlog.
+    let is_spectator = room.spectators.iter().any(|s| s.client_id == client_id);
+    let visible_frame = if is_spectator && room.spectator_delay.is_active() {
+        room.spectator_delay.release_frame(room.current_frame)
+    } else {
+        Some(room.current_frame)
+    };
+    let mut history = room.get_input_history(frame);
+    history.retain_mut(|(_, base, buttons)| match visible_frame {
+        Some(last) if *base <= last => {
+            buttons.truncate((last - *base) as usize + 1);
+            true
+        }
+        _ => false,
+    });
+    info!(
+        client_id,
+        chunks = history.len(),
+        "Sending input history to late joiner"
+    );
 
-            // After state + inputs are in flight, tell the joiner to begin catch-up.
-            let mut active_ports_mask: u8 = 0;
-            for idx in room.players.keys() {
-                if *idx < 8 {
-                    active_ports_mask |= 1u8 << *idx;
-                }
-            }
-            let target_frame = room.current_frame.max(frame);
-            let msg = BeginCatchUp {
-                snapshot_frame: frame,
-                target_frame,
-                active_ports_mask,
-            };
-            let h = Header::new(MsgId::BeginCatchUp as u8);
-            let _ = send_msg_tcp(&state_outbound, h, MsgId::BeginCatchUp, &msg).await;
-        } else {
-            debug!(
-                client_id = sender_id,
-                room_id, "No cached state available for late joiner"
-            );
+    let Some(input_outbound) = room.outbound_for_client_msg(client_id, MsgId::RelayInputs) else {
+        return;
+    };
+    let recipients = vec![input_outbound];
+    for (p_idx, base, buttons) in history {
+        broadcast_inputs_required(&recipients, p_idx, base, &buttons).await;
+    }
+
+    // After state + inputs are in flight, tell the joiner to begin catch-up.
+    let mut active_ports_mask: u8 = 0;
+    for idx in room.players.keys() {
+        if *idx < 8 {
+            active_ports_mask |= 1u8 << *idx;
         }
     }
-    Ok(())
+    let target_frame = visible_frame.unwrap_or(0).max(frame);
+    let msg = BeginCatchUp {
+        snapshot_frame: frame,
+        target_frame,
+        active_ports_mask,
+    };
+    let h = Header::new(MsgId::BeginCatchUp as u8);
+    let _ = send_msg_tcp(&state_outbound, h, MsgId::BeginCatchUp, &msg).await;
 }
@@ -0,0 +1,66 @@
+use std::net::SocketAddr;
+
+use nesium_netproto::{
+    header::Header,
+    messages::session::{RTC_MAX_SIGNAL_LEN, RtcSignal},
+    msg_id::MsgId,
+};
+use tracing::{debug, warn};
+
+use crate::{
+    ConnCtx,
+    net::outbound::send_msg_tcp,
+    proto_dispatch::decode::decode_payload,
+    proto_dispatch::error::{HandlerError, HandlerResult},
+    room::state::RoomManager,
+};
+
+pub(crate) async fn handle(
+    ctx: &mut ConnCtx,
+    peer: &SocketAddr,
+    payload: &[u8],
+    room_mgr: &mut RoomManager,
+) -> HandlerResult {
+    let mut signal: RtcSignal = match decode_payload(payload) {
+        Ok(v) => v,
+        Err(e) => {
+            warn!(%peer, error = %e, "Bad RtcSignal message");
+            return Err(HandlerError::bad_message());
+        }
+    };
+
+    if ctx.assigned_client_id == 0 {
+        return Err(HandlerError::invalid_state());
+    }
+
+    if signal.data.len() > RTC_MAX_SIGNAL_LEN {
+        warn!(%peer, data_len = signal.data.len(), "RtcSignal data too long");
+        return Err(HandlerError::bad_message());
+    }
+
+    let Some(room) = room_mgr.find_by_code_mut(signal.room_code) else {
+        return Err(HandlerError::room_not_found());
+    };
+
+    // Both ends must be watching the room; this keeps signaling scoped to it.
+    if !room.p2p_watchers.contains_key(&ctx.assigned_client_id) {
+        return Err(HandlerError::permission_denied());
+    }
+    let Some(target) = room.p2p_watchers.get(&signal.peer_client_id).cloned() else {
+        return Err(HandlerError::host_not_available());
+    };
+
+    let to_client_id = signal.peer_client_id;
+    signal.peer_client_id = ctx.assigned_client_id;
+    let h = Header::new(MsgId::RtcSignal as u8);
+    let _ = send_msg_tcp(&target, h, MsgId::RtcSignal, &signal).await;
+
+    debug!(
+        room_code = signal.room_code,
+        from_client_id = ctx.assigned_client_id,
+        to_client_id,
+        kind = ?signal.kind,
+        "Relayed RtcSignal"
+    );
+    Ok(())
+}
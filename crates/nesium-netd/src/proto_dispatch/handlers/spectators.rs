@@ -0,0 +1,58 @@
+use nesium_netproto::messages::session::{SPECTATOR_DELAY_MAX_FRAMES, SetSpectatorDelay};
+use nesium_netproto::msg_id::MsgId;
+use tracing::info;
+
+use crate::ConnCtx;
+use crate::proto_dispatch::decode::decode_payload;
+use crate::proto_dispatch::error::{HandlerError, HandlerResult};
+use crate::room::broadcast::{broadcast_inputs_best_effort, broadcast_spectator_info};
+use crate::room::state::RoomManager;
+
+pub(crate) async fn handle_set_spectator_delay(
+    ctx: &mut ConnCtx,
+    payload: &[u8],
+    room_mgr: &mut RoomManager,
+) -> HandlerResult {
+    let msg: SetSpectatorDelay = match decode_payload(payload) {
+        Ok(v) => v,
+        Err(_) => return Err(HandlerError::bad_message()),
+    };
+    if msg.frames > SPECTATOR_DELAY_MAX_FRAMES {
+        return Err(HandlerError::bad_message());
+    }
+
+    let Some(room_id) = room_mgr.get_client_room(ctx.assigned_client_id) else {
+        return Err(HandlerError::not_in_room());
+    };
+    let Some(room) = room_mgr.get_room_mut(room_id) else {
+        return Err(HandlerError::not_in_room());
+    };
+    if room.host_client_id != ctx.assigned_client_id {
+        return Err(HandlerError::permission_denied());
+    }
+
+    room.spectator_delay.set_frames(msg.frames);
+    info!(room_id, frames = msg.frames, "Spectator delay set");
+
+    // A shorter delay may free inputs held back so far.
+    let released = room.spectator_delay.release(room.current_frame);
+    if !released.is_empty() {
+        let recipients: Vec<_> = room
+            .spectators
+            .iter()
+            .map(|s| s.outbounds.outbound_for_msg(MsgId::RelayInputs))
+            .collect();
+        for inputs in released {
+            broadcast_inputs_best_effort(
+                &recipients,
+                inputs.player_index,
+                inputs.start_frame,
+                &inputs.buttons,
+            );
+        }
+    }
+
+    broadcast_spectator_info(room).await;
+    room_mgr.save_room(room_id);
+    Ok(())
+}
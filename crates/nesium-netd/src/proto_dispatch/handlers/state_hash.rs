@@ -0,0 +1,45 @@
+use nesium_netproto::{header::Header, messages::sync::StateHash, msg_id::MsgId};
+use tracing::{debug, warn};
+
+use crate::ConnCtx;
+use crate::net::outbound::send_msg_tcp;
+use crate::proto_dispatch::decode::decode_payload;
+use crate::proto_dispatch::error::{HandlerError, HandlerResult};
+use crate::room::state::RoomManager;
+
+/// Relays a member's state hash to the rest of the room. The server does not
+/// compare hashes itself; every client checks them against its own.
+pub(crate) async fn handle(
+    ctx: &mut ConnCtx,
+    payload: &[u8],
+    room_mgr: &mut RoomManager,
+) -> HandlerResult {
+    let msg: StateHash = match decode_payload(payload) {
+        Ok(v) => v,
+        Err(_) => return Err(HandlerError::bad_message()),
+    };
+
+    let Some(room_id) = room_mgr.get_client_room(ctx.assigned_client_id) else {
+        return Err(HandlerError::not_in_room());
+    };
+    let Some(room) = room_mgr.get_room_mut(room_id) else {
+        return Err(HandlerError::not_in_room());
+    };
+
+    let recipients = room.state_hash_recipients(ctx.assigned_client_id);
+    debug!(
+        client_id = ctx.assigned_client_id,
+        room_id,
+        frame = msg.frame,
+        recipients = recipients.len(),
+        "Relaying StateHash"
+    );
+
+    let h = Header::new(MsgId::StateHash as u8);
+    for recipient in &recipients {
+        if let Err(e) = send_msg_tcp(recipient, h, MsgId::StateHash, &msg).await {
+            warn!(error = %e, "Failed to relay StateHash");
+        }
+    }
+    Ok(())
+}
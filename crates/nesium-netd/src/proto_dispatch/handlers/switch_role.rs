@@ -10,7 +10,9 @@ use tracing::{info, warn};
 use crate::ConnCtx;
 use crate::net::inbound::ConnId;
 use crate::net::outbound::send_msg_tcp;
+use crate::proto_dispatch::decode::decode_payload;
 use crate::proto_dispatch::error::{HandlerError, HandlerResult};
+use crate::room::broadcast::broadcast_spectator_info;
 use crate::room::state::RoomManager;
 
 pub(crate) async fn handle(
@@ -20,7 +22,7 @@ pub(crate) async fn handle(
     payload: &[u8],
     room_mgr: &mut RoomManager,
 ) -> HandlerResult {
-    let msg: SwitchRole = match postcard::from_bytes(payload) {
+    let msg: SwitchRole = match decode_payload(payload) {
         Ok(v) => v,
         Err(e) => {
             warn!(%peer, error = %e, "Bad SwitchRole message");
@@ -44,6 +46,7 @@ pub(crate) async fn handle(
         return Err(HandlerError::game_already_started());
     }
 
+    let spectators_before = room.spectators.len();
     match room.switch_player_role(ctx.assigned_client_id, msg.new_role) {
         Ok(changes) => {
             let recipients = room.all_outbounds_msg(MsgId::RoleChanged);
@@ -63,6 +66,9 @@ pub(crate) async fn handle(
 
                 info!(client_id = cid, room_id, new_role = role, "Role changed");
             }
+            if room.spectators.len() != spectators_before {
+                broadcast_spectator_info(room).await;
+            }
         }
         Err(e) => {
             warn!(%peer, error = %e, "Failed to switch role");
@@ -0,0 +1,70 @@
+//! Token-bucket rate limiting for client messages.
+
+use std::time::{Duration, Instant};
+
+/// Token bucket allowing bursts of `burst` messages, refilled by one token
+/// every `refill`.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    burst: u32,
+    refill: Duration,
+    tokens: u32,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(burst: u32, refill: Duration) -> Self {
+        Self {
+            burst,
+            refill,
+            tokens: burst,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Takes a token if one is available.
+    pub fn try_acquire(&mut self) -> bool {
+        self.try_acquire_at(Instant::now())
+    }
+
+    fn try_acquire_at(&mut self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        let refilled = (elapsed.as_nanos() / self.refill.as_nanos().max(1)) as u32;
+        if refilled > 0 {
+            self.tokens = self.tokens.saturating_add(refilled).min(self.burst);
+            self.last_refill += self.refill * refilled;
+        }
+        if self.tokens == self.burst {
+            // A full bucket doesn't bank time towards the next refill.
+            self.last_refill = now;
+        }
+
+        if self.tokens == 0 {
+            return false;
+        }
+        self.tokens -= 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn burst_then_refill() {
+        let mut limiter = RateLimiter::new(2, Duration::from_secs(1));
+        let start = limiter.last_refill;
+        assert!(limiter.try_acquire_at(start));
+        assert!(limiter.try_acquire_at(start));
+        assert!(!limiter.try_acquire_at(start + Duration::from_millis(900)));
+        assert!(limiter.try_acquire_at(start + Duration::from_millis(1000)));
+        assert!(!limiter.try_acquire_at(start + Duration::from_millis(1500)));
+
+        // A long pause refills only up to the burst size.
+        let later = start + Duration::from_secs(60);
+        assert!(limiter.try_acquire_at(later));
+        assert!(limiter.try_acquire_at(later));
+        assert!(!limiter.try_acquire_at(later));
+    }
+}
@@ -1,2 +1,6 @@
+pub(crate) mod bandwidth;
 pub(crate) mod broadcast;
+pub(crate) mod password;
+pub(crate) mod spectator_delay;
 pub(crate) mod state;
+pub mod store;
@@ -0,0 +1,187 @@
+//! Relay bandwidth accounting and quotas.
+//!
+//! Every relayed packet is charged to the sending client and to its room.
+//! Quotas cap how many bytes each may relay per window; packets over quota
+//! are dropped until the window rolls over.
+
+use std::time::{Duration, Instant};
+
+use nesium_netproto::msg_id::MsgId;
+
+use crate::config::ServerSettings;
+
+/// Whether the server forwards `msg_id` to other room members, so its
+/// bytes count towards relay quotas.
+pub(crate) fn is_relayed(msg_id: MsgId) -> bool {
+    matches!(
+        msg_id,
+        MsgId::InputBatch
+            | MsgId::StateHash
+            | MsgId::LoadRom
+            | MsgId::SyncState
+            | MsgId::ProvideState
+            | MsgId::Chat
+            | MsgId::RtcSignal
+    )
+}
+
+/// Byte limits per window; 0 is unlimited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RelayQuota {
+    pub room_bytes: u64,
+    pub client_bytes: u64,
+    pub window: Duration,
+}
+
+impl Default for RelayQuota {
+    fn default() -> Self {
+        (&ServerSettings::default()).into()
+    }
+}
+
+impl From<&ServerSettings> for RelayQuota {
+    fn from(settings: &ServerSettings) -> Self {
+        Self {
+            room_bytes: settings.room_relay_quota_bytes,
+            client_bytes: settings.client_relay_quota_bytes,
+            window: Duration::from_secs(settings.relay_quota_window_secs.max(1)),
+        }
+    }
+}
+
+/// A packet dropped for going over quota.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct QuotaExceeded {
+    /// First drop in this window; the sender is told once per window.
+    pub notify: bool,
+}
+
+/// Bytes relayed by a room or client.
+#[derive(Debug, Clone)]
+pub(crate) struct RelayUsage {
+    total: u64,
+    window_start: Instant,
+    window_bytes: u64,
+    notified: bool,
+}
+
+impl Default for RelayUsage {
+    fn default() -> Self {
+        Self {
+            total: 0,
+            window_start: Instant::now(),
+            window_bytes: 0,
+            notified: false,
+        }
+    }
+}
+
+impl RelayUsage {
+    /// Bytes relayed since tracking started.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Roll over to a new window if the current one has ended.
+    fn refresh(&mut self, window: Duration, now: Instant) {
+        if now.saturating_duration_since(self.window_start) >= window {
+            self.window_start = now;
+            self.window_bytes = 0;
+            self.notified = false;
+        }
+    }
+
+    /// Whether `bytes` more fit in `quota` (0 is unlimited).
+    fn fits(&self, bytes: u64, quota: u64) -> bool {
+        quota == 0 || self.window_bytes.saturating_add(bytes) <= quota
+    }
+
+    fn reject(&mut self) -> QuotaExceeded {
+        let notify = !self.notified;
+        self.notified = true;
+        QuotaExceeded { notify }
+    }
+
+    fn add(&mut self, bytes: u64) {
+        self.total = self.total.saturating_add(bytes);
+        self.window_bytes = self.window_bytes.saturating_add(bytes);
+    }
+}
+
+/// Charge `bytes` to both `room` and `client`, or neither if either is over
+/// its quota.
+pub(crate) fn charge(
+    room: &mut RelayUsage,
+    client: &mut RelayUsage,
+    bytes: u64,
+    quota: &RelayQuota,
+    now: Instant,
+) -> Result<(), QuotaExceeded> {
+    room.refresh(quota.window, now);
+    client.refresh(quota.window, now);
+    if !client.fits(bytes, quota.client_bytes) || !room.fits(bytes, quota.room_bytes) {
+        // Notices are tracked per client, so every sender of a room over
+        // quota is told once.
+        return Err(client.reject());
+    }
+    room.add(bytes);
+    client.add(bytes);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quotas_drop_until_the_window_rolls_over() {
+        let quota = RelayQuota {
+            room_bytes: 150,
+            client_bytes: 100,
+            window: Duration::from_secs(60),
+        };
+        let mut room = RelayUsage::default();
+        let mut a = RelayUsage::default();
+        let mut b = RelayUsage::default();
+        let start = Instant::now();
+
+        assert_eq!(charge(&mut room, &mut a, 100, &quota, start), Ok(()));
+        assert_eq!(
+            charge(&mut room, &mut a, 1, &quota, start),
+            Err(QuotaExceeded { notify: true })
+        );
+        assert_eq!(
+            charge(&mut room, &mut a, 1, &quota, start),
+            Err(QuotaExceeded { notify: false })
+        );
+
+        // The room quota is shared between its members.
+        assert_eq!(charge(&mut room, &mut b, 50, &quota, start), Ok(()));
+        assert_eq!(
+            charge(&mut room, &mut b, 1, &quota, start),
+            Err(QuotaExceeded { notify: true })
+        );
+        assert_eq!((room.total(), a.total(), b.total()), (150, 100, 50));
+
+        let later = start + quota.window;
+        assert_eq!(charge(&mut room, &mut a, 100, &quota, later), Ok(()));
+        assert_eq!(room.total(), 250);
+    }
+
+    #[test]
+    fn zero_is_unlimited() {
+        let quota = RelayQuota {
+            room_bytes: 0,
+            client_bytes: 0,
+            window: Duration::from_secs(1),
+        };
+        let mut room = RelayUsage::default();
+        let mut client = RelayUsage::default();
+        let now = Instant::now();
+        assert_eq!(
+            charge(&mut room, &mut client, u64::MAX, &quota, now),
+            Ok(())
+        );
+        assert_eq!(charge(&mut room, &mut client, 1, &quota, now), Ok(()));
+    }
+}
@@ -1,14 +1,16 @@
 //! Input broadcast utilities.
 //!
-//! Broadcasts input data to room participants.
+//! Broadcasts input data, and the spectator status that governs its relay,
+//! to room participants.
 
 use bytes::Bytes;
 use nesium_netproto::{
     codec_tcp::encode_tcp_frame, header::Header, messages::input::RelayInputs, msg_id::MsgId,
 };
-use tracing::error;
+use tracing::{error, warn};
 
-use crate::net::outbound::OutboundTx;
+use crate::net::outbound::{OutboundTx, send_msg_tcp};
+use crate::room::state::Room;
 
 fn build_relay_inputs_frame(player_index: u8, start_frame: u32, buttons: &[u16]) -> Option<Bytes> {
     let relay = RelayInputs {
@@ -63,3 +65,30 @@ pub fn broadcast_inputs_best_effort(
         let _ = tx.try_send(frame.clone());
     }
 }
+
+/// Send redundant input to datagram recipients.
+///
+/// `buttons` should already cover the last
+/// [`DATAGRAM_INPUT_REDUNDANCY`](nesium_netproto::constants::DATAGRAM_INPUT_REDUNDANCY)
+/// frames. Lost or full-queue datagrams are simply dropped; the next one
+/// repeats these frames, and the reliable channels still deliver them.
+pub fn broadcast_inputs_datagram(
+    recipients: &[OutboundTx],
+    player_index: u8,
+    start_frame: u32,
+    buttons: &[u16],
+) {
+    broadcast_inputs_best_effort(recipients, player_index, start_frame, buttons);
+}
+
+/// Tell the players how many spectators watch and with what delay.
+pub async fn broadcast_spectator_info(room: &Room) {
+    let info = room.spectator_info();
+    let h = Header::new(MsgId::SpectatorInfo as u8);
+    for player in room.players.values() {
+        let tx = player.outbounds.outbound_for_msg(MsgId::SpectatorInfo);
+        if let Err(e) = send_msg_tcp(&tx, h, MsgId::SpectatorInfo, &info).await {
+            warn!(error = %e, "Failed to broadcast SpectatorInfo");
+        }
+    }
+}
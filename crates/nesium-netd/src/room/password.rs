@@ -0,0 +1,87 @@
+//! Salted password hashes for private rooms.
+//!
+//! Only the PBKDF2 hash is kept; the plain password never outlives the
+//! JoinRoom handler.
+
+use std::fmt;
+use std::num::NonZeroU32;
+
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+
+const ALGORITHM: pbkdf2::Algorithm = pbkdf2::PBKDF2_HMAC_SHA256;
+const ITERATIONS: NonZeroU32 = NonZeroU32::new(10_000).unwrap();
+const SALT_LEN: usize = 16;
+const HASH_LEN: usize = 32;
+
+/// Hashed room password.
+#[derive(Clone)]
+pub struct RoomPassword {
+    salt: [u8; SALT_LEN],
+    hash: [u8; HASH_LEN],
+}
+
+impl RoomPassword {
+    /// Hash `password` with a fresh random salt.
+    pub fn new(password: &str) -> Self {
+        let mut salt = [0u8; SALT_LEN];
+        SystemRandom::new()
+            .fill(&mut salt)
+            .expect("system RNG should be available");
+        let mut hash = [0u8; HASH_LEN];
+        pbkdf2::derive(ALGORITHM, ITERATIONS, &salt, password.as_bytes(), &mut hash);
+        Self { salt, hash }
+    }
+
+    /// Salt followed by hash, for persisting.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [self.salt.as_slice(), &self.hash].concat()
+    }
+
+    /// Inverse of [`Self::to_bytes`]; `None` if `bytes` has the wrong length.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (salt, hash) = bytes.split_at_checked(SALT_LEN)?;
+        Some(Self {
+            salt: salt.try_into().ok()?,
+            hash: hash.try_into().ok()?,
+        })
+    }
+
+    /// Check `password` against the stored hash in constant time.
+    pub fn verify(&self, password: &str) -> bool {
+        pbkdf2::verify(
+            ALGORITHM,
+            ITERATIONS,
+            &self.salt,
+            password.as_bytes(),
+            &self.hash,
+        )
+        .is_ok()
+    }
+}
+
+impl fmt::Debug for RoomPassword {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RoomPassword").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verifies_only_the_original_password() {
+        let pw = RoomPassword::new("hunter2");
+        assert!(pw.verify("hunter2"));
+        assert!(!pw.verify("hunter3"));
+        assert!(!pw.verify(""));
+    }
+
+    #[test]
+    fn salts_differ_between_rooms() {
+        let a = RoomPassword::new("same");
+        let b = RoomPassword::new("same");
+        assert_ne!(a.hash, b.hash);
+    }
+}
@@ -0,0 +1,107 @@
+//! Delayed input relay for spectators.
+//!
+//! With a delay set, relayed inputs are held back until the room's newest
+//! input frame is that many frames ahead of them, so spectators watch the
+//! match behind live and can't ghost it for a player.
+
+use std::collections::VecDeque;
+
+/// One `RelayInputs` worth of inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DelayedInputs {
+    pub player_index: u8,
+    pub start_frame: u32,
+    pub buttons: Vec<u16>,
+}
+
+impl DelayedInputs {
+    fn end_frame(&self) -> u32 {
+        self.start_frame
+            .wrapping_add(self.buttons.len().saturating_sub(1) as u32)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SpectatorDelay {
+    frames: u32,
+    /// Inputs not yet shown to spectators, in arrival order.
+    backlog: VecDeque<DelayedInputs>,
+}
+
+impl SpectatorDelay {
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    /// Change the delay; call [`Self::release`] afterwards, as a shorter
+    /// delay may free buffered inputs.
+    pub fn set_frames(&mut self, frames: u32) {
+        self.frames = frames;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.frames > 0
+    }
+
+    /// Newest frame spectators may see while players are at `live_frame`.
+    pub fn release_frame(&self, live_frame: u32) -> Option<u32> {
+        live_frame.checked_sub(self.frames)
+    }
+
+    pub fn push(&mut self, inputs: DelayedInputs) {
+        self.backlog.push_back(inputs);
+    }
+
+    /// Pop buffered inputs that are now far enough behind `live_frame`.
+    pub fn release(&mut self, live_frame: u32) -> Vec<DelayedInputs> {
+        let Some(release_frame) = self.release_frame(live_frame) else {
+            return Vec::new();
+        };
+        let mut released = Vec::new();
+        while let Some(front) = self.backlog.front()
+            && front.end_frame() <= release_frame
+        {
+            released.extend(self.backlog.pop_front());
+        }
+        released
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs(player_index: u8, start_frame: u32, len: usize) -> DelayedInputs {
+        DelayedInputs {
+            player_index,
+            start_frame,
+            buttons: vec![1; len],
+        }
+    }
+
+    #[test]
+    fn holds_inputs_until_far_enough_behind_live() {
+        let mut delay = SpectatorDelay::default();
+        delay.set_frames(10);
+        delay.push(inputs(0, 0, 4));
+        delay.push(inputs(1, 0, 4));
+        delay.push(inputs(0, 4, 4));
+
+        assert!(delay.release(12).is_empty());
+        assert_eq!(delay.release(13), vec![inputs(0, 0, 4), inputs(1, 0, 4)]);
+        assert!(delay.release(16).is_empty());
+        assert_eq!(delay.release(17), vec![inputs(0, 4, 4)]);
+    }
+
+    #[test]
+    fn shortening_the_delay_frees_the_backlog() {
+        let mut delay = SpectatorDelay::default();
+        delay.set_frames(180);
+        delay.push(inputs(0, 0, 8));
+        assert!(delay.release(7).is_empty());
+
+        delay.set_frames(0);
+        assert!(!delay.is_active());
+        assert_eq!(delay.release(7), vec![inputs(0, 0, 8)]);
+    }
+}
@@ -8,14 +8,24 @@ use std::net::SocketAddr;
 
 use crate::net::inbound::ConnId;
 use crate::net::outbound::OutboundTx;
+use crate::observability::metrics;
+use crate::room::bandwidth::{self, QuotaExceeded, RelayQuota, RelayUsage};
+use crate::room::password::RoomPassword;
+use crate::room::spectator_delay::SpectatorDelay;
+use crate::room::store::{Ban, BanTarget, RoomStore, SavedRoom, unix_now};
+use crate::webhook::{WebhookEvent, WebhookNotifier};
 use nesium_netproto::{
     channel::{ChannelKind, channel_for_msg},
     constants::SPECTATOR_PLAYER_INDEX,
+    messages::lobby::{ROOM_LIST_MAX_LEN, RoomFilter, RoomInfo, RoomSummary},
+    messages::session::{RomHash, SpectatorInfo, UserIdentity, UserToken},
     msg_id::MsgId,
 };
 
 /// Maximum number of players per room.
 pub const MAX_PLAYERS: usize = 4;
+/// Player slots handed out on join; later joiners become spectators.
+pub const JOIN_PLAYER_SLOTS: usize = 2;
 
 /// Direct-connect information for Host-as-server P2P mode (netd as signaling).
 #[derive(Debug, Clone)]
@@ -33,11 +43,23 @@ pub struct P2PFallbackState {
     pub requested_by_client_id: u32,
 }
 
+/// UDP endpoint registered for hole punching.
+#[derive(Debug, Clone)]
+pub struct P2PPunchEndpoint {
+    /// Source address of the registration datagram, as seen by the server.
+    pub public_addr: SocketAddr,
+    pub local_addrs: Vec<SocketAddr>,
+    /// Whether `P2PPunchStart` was already sent for this client.
+    pub started: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct ClientOutbounds {
     pub control: OutboundTx,
     pub input: Option<OutboundTx>,
     pub bulk: Option<OutboundTx>,
+    /// UDP datagram channel, if attached. Only used for redundant input relay.
+    pub datagram: Option<OutboundTx>,
 }
 
 impl ClientOutbounds {
@@ -46,6 +68,7 @@ impl ClientOutbounds {
             control,
             input: None,
             bulk: None,
+            datagram: None,
         }
     }
 
@@ -54,6 +77,10 @@ impl ClientOutbounds {
             ChannelKind::Control => self.control.clone(),
             ChannelKind::Input => self.input.clone().unwrap_or_else(|| self.control.clone()),
             ChannelKind::Bulk => self.bulk.clone().unwrap_or_else(|| self.control.clone()),
+            ChannelKind::Datagram => self
+                .datagram
+                .clone()
+                .unwrap_or_else(|| self.outbound_for_channel(ChannelKind::Input)),
         }
     }
 
@@ -66,6 +93,7 @@ impl ClientOutbounds {
             ChannelKind::Control => self.control = outbound,
             ChannelKind::Input => self.input = Some(outbound),
             ChannelKind::Bulk => self.bulk = Some(outbound),
+            ChannelKind::Datagram => self.datagram = Some(outbound),
         }
     }
 
@@ -74,6 +102,7 @@ impl ClientOutbounds {
             ChannelKind::Control => {}
             ChannelKind::Input => self.input = None,
             ChannelKind::Bulk => self.bulk = None,
+            ChannelKind::Datagram => self.datagram = None,
         }
     }
 }
@@ -124,14 +153,28 @@ pub struct Room {
     pub cached_state: Option<(u32, Vec<u8>)>,
     /// ROM data for late joiners.
     pub rom_data: Option<Vec<u8>>,
+    /// Hash of `rom_data` as reported by its sender; `RomLoaded` is checked against it.
+    pub rom_hash: Option<RomHash>,
+    /// Late joiners waiting for the host's next state snapshot.
+    pub pending_catch_up: Vec<u32>,
     /// Host-as-server P2P direct-connect info (when this room code is used for P2P matchmaking).
     pub p2p_host: Option<P2PHostInfo>,
     /// Clients currently watching this room via P2P signaling (client_id -> outbound).
     pub p2p_watchers: HashMap<u32, OutboundTx>,
     /// Whether relay fallback was requested for this room code.
     pub p2p_fallback: Option<P2PFallbackState>,
+    /// Hole punching endpoints (client_id -> endpoint), host included.
+    pub p2p_punch: HashMap<u32, P2PPunchEndpoint>,
     /// Synchronization mode for this room (Lockstep or Rollback).
     pub sync_mode: nesium_netproto::messages::session::SyncMode,
+    /// Password required to join, set by the host at creation (private room).
+    pub password: Option<RoomPassword>,
+    /// Lobby description, set by the host.
+    pub info: RoomInfo,
+    /// How far behind live spectators receive inputs, set by the host.
+    pub spectator_delay: SpectatorDelay,
+    /// Bytes relayed for the room's members.
+    pub(crate) relay: RelayUsage,
 }
 
 impl Room {
@@ -150,10 +193,17 @@ impl Room {
             paused: false,
             cached_state: None,
             rom_data: None,
+            rom_hash: None,
+            pending_catch_up: Vec::new(),
             p2p_host: None,
             p2p_watchers: HashMap::new(),
             p2p_fallback: None,
+            p2p_punch: HashMap::new(),
             sync_mode: Default::default(),
+            password: None,
+            info: RoomInfo::default(),
+            spectator_delay: SpectatorDelay::default(),
+            relay: RelayUsage::default(),
         }
     }
 
@@ -167,6 +217,46 @@ impl Room {
 
     pub fn remove_p2p_watcher(&mut self, client_id: u32) {
         self.p2p_watchers.remove(&client_id);
+        self.p2p_punch.remove(&client_id);
+    }
+
+    /// Record a hole punching endpoint for a P2P host or watcher.
+    ///
+    /// Returns the joiners that should start punching with the host now: every
+    /// registered joiner that hasn't started yet, once the host is registered.
+    pub fn register_p2p_punch(&mut self, client_id: u32, endpoint: P2PPunchEndpoint) -> Vec<u32> {
+        let Some(host_id) = self.p2p_host.as_ref().map(|h| h.host_signal_client_id) else {
+            return Vec::new();
+        };
+        if client_id != host_id && !self.p2p_watchers.contains_key(&client_id) {
+            return Vec::new();
+        }
+
+        // A repeated registration keeps its `started` flag.
+        let started = self.p2p_punch.get(&client_id).is_some_and(|e| e.started);
+        self.p2p_punch.insert(
+            client_id,
+            P2PPunchEndpoint {
+                started,
+                ..endpoint
+            },
+        );
+
+        if !self.p2p_punch.contains_key(&host_id) {
+            return Vec::new();
+        }
+        let ready: Vec<u32> = self
+            .p2p_punch
+            .iter()
+            .filter(|(id, e)| **id != host_id && !e.started)
+            .map(|(id, _)| *id)
+            .collect();
+        for id in &ready {
+            if let Some(e) = self.p2p_punch.get_mut(id) {
+                e.started = true;
+            }
+        }
+        ready
     }
 
     pub fn request_p2p_fallback(&mut self, requested_by_client_id: u32, reason: String) {
@@ -206,6 +296,7 @@ impl Room {
     /// Remove a player from the room.
     /// Also clears their input buffer to prevent lockstep deadlock.
     pub fn remove_player(&mut self, client_id: u32) -> Option<Player> {
+        self.pending_catch_up.retain(|&id| id != client_id);
         let key = self
             .players
             .iter()
@@ -224,6 +315,7 @@ impl Room {
 
     /// Remove a spectator from the room.
     pub fn remove_spectator(&mut self, client_id: u32) -> Option<Spectator> {
+        self.pending_catch_up.retain(|&id| id != client_id);
         if let Some(pos) = self
             .spectators
             .iter()
@@ -282,6 +374,55 @@ impl Room {
         self.players.len()
     }
 
+    /// Player slots a new joiner could still take.
+    pub fn open_player_slots(&self) -> usize {
+        JOIN_PLAYER_SLOTS.saturating_sub(self.player_count())
+    }
+
+    /// Whether the room shows up in the lobby: public, occupied and joinable
+    /// with `JoinRoom` (P2P signaling rooms are not).
+    pub fn is_listed(&self) -> bool {
+        self.password.is_none() && self.p2p_host.is_none() && !self.is_empty()
+    }
+
+    pub fn matches(&self, filter: &RoomFilter) -> bool {
+        filter
+            .game_hash
+            .is_none_or(|hash| hash == self.info.game_hash)
+            && filter
+                .region
+                .as_deref()
+                .is_none_or(|region| region.eq_ignore_ascii_case(&self.info.region))
+    }
+
+    /// Lobby entry for this room. An unnamed room is shown with the host's name.
+    pub fn summary(&self) -> RoomSummary {
+        let mut info = self.info.clone();
+        if info.name.is_empty()
+            && let Some(host) = self
+                .players
+                .values()
+                .find(|p| p.client_id == self.host_client_id)
+        {
+            info.name = host.name.clone();
+        }
+        RoomSummary {
+            room_code: self.code,
+            info,
+            player_count: self.player_count() as u8,
+            spectator_count: self.spectators.len().min(u8::MAX as usize) as u8,
+            open_slots: self.open_player_slots() as u8,
+            started: self.started,
+        }
+    }
+
+    pub fn spectator_info(&self) -> SpectatorInfo {
+        SpectatorInfo {
+            count: self.spectators.len().min(u16::MAX as usize) as u16,
+            delay_frames: self.spectator_delay.frames(),
+        }
+    }
+
     /// Returns a bitmask of active player ports.
     /// Bit N is set if player index N is present.
     pub fn get_active_ports_mask(&self) -> u8 {
@@ -299,8 +440,9 @@ impl Room {
         self.prune_inputs_before(frame);
     }
 
-    pub fn cache_rom(&mut self, data: Vec<u8>) {
+    pub fn cache_rom(&mut self, data: Vec<u8>, hash: Option<RomHash>) {
         self.rom_data = Some(data);
+        self.rom_hash = hash;
     }
 
     pub fn outbound_for_client_channel(
@@ -354,30 +496,41 @@ impl Room {
         }
     }
 
-    pub fn record_inputs(&mut self, player_index: u8, start_frame: u32, buttons: &[u16]) {
+    /// Record a player's inputs in the room's input history.
+    ///
+    /// Returns `false` when every frame was already recorded with the same
+    /// buttons, e.g. a batch that arrived on both the datagram and a reliable
+    /// channel.
+    pub fn record_inputs(&mut self, player_index: u8, start_frame: u32, buttons: &[u16]) -> bool {
         let Some(buffer) = self.input_buffers.get_mut(player_index as usize) else {
-            return;
+            return false;
         };
 
+        let mut changed = false;
         for (offset, &mask) in buttons.iter().enumerate() {
             let frame = start_frame.wrapping_add(offset as u32);
             match buffer.back() {
                 None => {
                     buffer.push_back((frame, mask));
+                    changed = true;
                 }
                 Some((last_frame, _)) if frame > *last_frame => {
                     buffer.push_back((frame, mask));
+                    changed = true;
                 }
                 _ => {
                     // Out-of-order or duplicate frame: insert in sorted order, overwriting if present.
                     if let Some(pos) = buffer.iter().position(|(f, _)| *f >= frame) {
                         if buffer[pos].0 == frame {
+                            changed |= buffer[pos].1 != mask;
                             buffer[pos].1 = mask;
                         } else {
                             buffer.insert(pos, (frame, mask));
+                            changed = true;
                         }
                     } else {
                         buffer.push_back((frame, mask));
+                        changed = true;
                     }
                 }
             }
@@ -391,6 +544,26 @@ impl Room {
             let end_frame = start_frame.wrapping_add(buttons.len().saturating_sub(1) as u32);
             self.current_frame = self.current_frame.max(end_frame);
         }
+        changed
+    }
+
+    /// Up to `count` consecutive recorded frames of `player_index` ending at
+    /// `end_frame`, as `(start_frame, buttons)`. Used to build redundant input
+    /// datagrams.
+    pub fn recent_inputs(&self, player_index: u8, end_frame: u32, count: usize) -> (u32, Vec<u16>) {
+        let Some(buffer) = self.input_buffers.get(player_index as usize) else {
+            return (end_frame, Vec::new());
+        };
+        let Some(end) = buffer.iter().rposition(|(f, _)| *f == end_frame) else {
+            return (end_frame, Vec::new());
+        };
+
+        let mut start = end;
+        while start > 0 && end - start + 1 < count && buffer[start - 1].0 + 1 == buffer[start].0 {
+            start -= 1;
+        }
+        let buttons = buffer.range(start..=end).map(|(_, b)| *b).collect();
+        (buffer[start].0, buttons)
     }
 
     fn prune_inputs_before(&mut self, frame: u32) {
@@ -420,6 +593,30 @@ impl Room {
         result
     }
 
+    /// Outbound channels of every room member except `sender_id`, for
+    /// relaying a `StateHash`. Empty if the sender is not in the room.
+    pub fn state_hash_recipients(&self, sender_id: u32) -> Vec<OutboundTx> {
+        let is_member = self.players.values().any(|p| p.client_id == sender_id)
+            || self.spectators.iter().any(|s| s.client_id == sender_id);
+        if !is_member {
+            return Vec::new();
+        }
+
+        let mut result: Vec<_> = self
+            .players
+            .values()
+            .filter(|p| p.client_id != sender_id)
+            .map(|p| p.outbounds.outbound_for_msg(MsgId::StateHash))
+            .collect();
+        result.extend(
+            self.spectators
+                .iter()
+                .filter(|s| s.client_id != sender_id)
+                .map(|s| s.outbounds.outbound_for_msg(MsgId::StateHash)),
+        );
+        result
+    }
+
     /// Switch player role.
     ///
     /// Returns a list of (client_id, new_role) for broadcast.
@@ -666,6 +863,16 @@ pub struct RoomManager {
     next_room_id: u32,
     /// Map client_id -> room_id for quick lookup.
     client_rooms: HashMap<u32, u32>,
+    webhooks: WebhookNotifier,
+    store: RoomStore,
+    /// Named rooms kept in `store`, by code; reopened when joined while empty.
+    saved_rooms: HashMap<u32, SavedRoom>,
+    bans: HashMap<BanTarget, Ban>,
+    /// Limit on open rooms; 0 is unlimited.
+    max_rooms: usize,
+    relay_quota: RelayQuota,
+    /// Bytes relayed per client, across the rooms it has been in.
+    client_relay: HashMap<u32, RelayUsage>,
 }
 
 impl RoomManager {
@@ -674,7 +881,133 @@ impl RoomManager {
             rooms: HashMap::new(),
             next_room_id: 1,
             client_rooms: HashMap::new(),
+            webhooks: WebhookNotifier::disabled(),
+            store: RoomStore::disabled(),
+            saved_rooms: HashMap::new(),
+            bans: HashMap::new(),
+            max_rooms: 0,
+            relay_quota: RelayQuota::default(),
+            client_relay: HashMap::new(),
+        }
+    }
+
+    /// Create a room manager that reports room lifecycle events to `webhooks`.
+    pub fn with_webhooks(webhooks: WebhookNotifier) -> Self {
+        Self {
+            webhooks,
+            ..Self::new()
+        }
+    }
+
+    /// Persist named rooms, bans and user identities in `store`, restoring
+    /// what it already holds.
+    pub fn with_store(mut self, store: RoomStore) -> Self {
+        for saved in store.load_rooms() {
+            // New rooms take their code from the id counter; keep it clear of saved codes.
+            self.next_room_id = self.next_room_id.max(saved.code.saturating_add(1));
+            self.saved_rooms.insert(saved.code, saved);
+        }
+        for ban in store.load_bans() {
+            self.bans.insert(ban.target, ban);
         }
+        tracing::info!(
+            rooms = self.saved_rooms.len(),
+            bans = self.bans.len(),
+            "Loaded persistent state"
+        );
+        self.store = store;
+        self
+    }
+
+    pub fn webhooks(&self) -> &WebhookNotifier {
+        &self.webhooks
+    }
+
+    /// Persistent identity for a client presenting `token` (see [`RoomStore::identify`]).
+    pub fn identify(&self, token: Option<UserToken>, name: &str) -> Option<UserIdentity> {
+        self.store.identify(token, name)
+    }
+
+    /// Whether `target` is under a ban that hasn't expired.
+    pub fn is_banned(&mut self, target: BanTarget) -> bool {
+        match self.bans.get(&target) {
+            Some(ban) if ban.is_expired(unix_now()) => {
+                self.bans.remove(&target);
+                self.store.delete_ban(target);
+                false
+            }
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    /// Ban `ban.target`, replacing any earlier ban on it.
+    pub fn ban(&mut self, ban: Ban) {
+        self.store.save_ban(&ban);
+        self.bans.insert(ban.target, ban);
+    }
+
+    /// Lift the ban on `target`; returns whether there was one.
+    pub fn unban(&mut self, target: BanTarget) -> bool {
+        let lifted = self.bans.remove(&target).is_some();
+        if lifted {
+            self.store.delete_ban(target);
+        }
+        lifted
+    }
+
+    /// Bans in effect (expired ones may linger until next checked).
+    pub fn bans(&self) -> impl Iterator<Item = &Ban> {
+        self.bans.values()
+    }
+
+    /// Persist the settings of a named room, or forget it once it has no name.
+    /// Does nothing without a store.
+    pub fn save_room(&mut self, room_id: u32) {
+        if !self.store.is_enabled() {
+            return;
+        }
+        let Some(room) = self.rooms.get(&room_id) else {
+            return;
+        };
+        if room.p2p_host.is_some() {
+            return;
+        }
+        if room.info.name.is_empty() {
+            if self.saved_rooms.remove(&room.code).is_some() {
+                self.store.delete_room(room.code);
+            }
+            return;
+        }
+        let saved = SavedRoom {
+            code: room.code,
+            info: room.info.clone(),
+            sync_mode: room.sync_mode,
+            password: room.password.clone(),
+            spectator_delay_frames: room.spectator_delay.frames(),
+        };
+        self.store.save_room(&saved);
+        self.saved_rooms.insert(saved.code, saved);
+    }
+
+    /// Save every open named room and checkpoint the store, before shutting down.
+    pub fn flush(&mut self) {
+        let room_ids: Vec<u32> = self.rooms.keys().copied().collect();
+        for room_id in room_ids {
+            self.save_room(room_id);
+        }
+        self.store.checkpoint();
+    }
+
+    /// Limit how many rooms may be open at once (0 is unlimited). Rooms
+    /// already open are kept; saved rooms can still be reopened.
+    pub fn set_max_rooms(&mut self, max_rooms: usize) {
+        self.max_rooms = max_rooms;
+    }
+
+    /// Whether the room limit forbids creating another room.
+    pub fn at_room_limit(&self) -> bool {
+        self.max_rooms != 0 && self.rooms.len() >= self.max_rooms
     }
 
     /// Create a new room.
@@ -684,6 +1017,12 @@ impl RoomManager {
         let code = id; // Simple: room code = room id for now
         let room = Room::new(id, code, host_client_id);
         self.rooms.insert(id, room);
+        metrics::set_room_count(self.rooms.len());
+        self.webhooks.notify(WebhookEvent::RoomCreated {
+            room_id: id,
+            room_code: code,
+            host_client_id,
+        });
         id
     }
 
@@ -692,11 +1031,72 @@ impl RoomManager {
         self.rooms.get_mut(&room_id)
     }
 
+    /// All open rooms, in no particular order.
+    pub fn rooms(&self) -> impl Iterator<Item = &Room> {
+        self.rooms.values()
+    }
+
     /// Find room by code.
     pub fn find_by_code(&self, code: u32) -> Option<&Room> {
         self.rooms.values().find(|r| r.code == code)
     }
 
+    /// Find room by code, reopening a saved named room with `host_client_id`
+    /// as host if nobody is in it.
+    pub fn find_or_reopen(&mut self, code: u32, host_client_id: u32) -> Option<&Room> {
+        if self.find_by_code(code).is_none() {
+            let saved = self.saved_rooms.get(&code)?.clone();
+            // Like new rooms, the id is the code; `next_room_id` stays past
+            // saved codes so it is free.
+            let id = code;
+            let mut room = Room::new(id, code, host_client_id);
+            room.info = saved.info;
+            room.sync_mode = saved.sync_mode;
+            room.password = saved.password;
+            room.spectator_delay
+                .set_frames(saved.spectator_delay_frames);
+            self.rooms.insert(id, room);
+            metrics::set_room_count(self.rooms.len());
+            self.store.touch_room(code);
+            self.webhooks.notify(WebhookEvent::RoomCreated {
+                room_id: id,
+                room_code: code,
+                host_client_id,
+            });
+            tracing::info!(room_id = id, room_code = code, "Reopened saved room");
+        }
+        self.find_by_code(code)
+    }
+
+    /// Listed rooms matching `filter`, best quick-match candidates first:
+    /// rooms with open slots, then not yet started, then the fullest.
+    pub fn list_rooms(&self, filter: &RoomFilter) -> Vec<RoomSummary> {
+        let mut rooms: Vec<RoomSummary> = self
+            .rooms
+            .values()
+            .filter(|r| r.is_listed() && r.matches(filter))
+            .map(Room::summary)
+            .collect();
+        rooms.sort_by_key(|r| {
+            (
+                r.open_slots == 0,
+                r.started,
+                std::cmp::Reverse(r.player_count),
+                r.room_code,
+            )
+        });
+        rooms.truncate(ROOM_LIST_MAX_LEN);
+        rooms
+    }
+
+    /// Code of the room a quick-match request should join, if any.
+    pub fn quick_match(&self, filter: &RoomFilter) -> Option<u32> {
+        self.list_rooms(filter)
+            .first()
+            .filter(|r| r.open_slots > 0)
+            .map(|r| r.room_code)
+    }
+
     /// Find room by code (mutable).
     pub fn find_by_code_mut(&mut self, code: u32) -> Option<&mut Room> {
         self.rooms.values_mut().find(|r| r.code == code)
@@ -715,11 +1115,54 @@ impl RoomManager {
     /// Remove client from tracking.
     pub fn remove_client(&mut self, client_id: u32) {
         self.client_rooms.remove(&client_id);
+        self.client_relay.remove(&client_id);
+    }
+
+    /// Limit how many bytes rooms and clients may relay per window.
+    pub(crate) fn set_relay_quota(&mut self, quota: RelayQuota) {
+        self.relay_quota = quota;
+    }
+
+    /// Charge a relayed packet of `bytes` to `client_id` and its room, or
+    /// refuse it if either is over quota. Clients outside a room relay nothing.
+    pub(crate) fn charge_relay(&mut self, client_id: u32, bytes: u64) -> Result<(), QuotaExceeded> {
+        let Some(room) = self
+            .client_rooms
+            .get(&client_id)
+            .and_then(|room_id| self.rooms.get_mut(room_id))
+        else {
+            return Ok(());
+        };
+        let client = self.client_relay.entry(client_id).or_default();
+        bandwidth::charge(
+            &mut room.relay,
+            client,
+            bytes,
+            &self.relay_quota,
+            std::time::Instant::now(),
+        )
+    }
+
+    /// Bytes `client_id` has relayed since it connected.
+    pub fn client_relayed_bytes(&self, client_id: u32) -> u64 {
+        self.client_relay
+            .get(&client_id)
+            .map_or(0, RelayUsage::total)
     }
 
     /// Remove empty room.
     pub fn remove_room(&mut self, room_id: u32) {
-        self.rooms.remove(&room_id);
+        let Some(room) = self.rooms.remove(&room_id) else {
+            return;
+        };
+        metrics::set_room_count(self.rooms.len());
+        if room.started {
+            self.webhooks.notify(WebhookEvent::MatchFinished {
+                room_id,
+                frames: room.current_frame,
+            });
+        }
+        self.webhooks.notify(WebhookEvent::RoomClosed { room_id });
     }
 
     /// Remove a client from all P2P signaling watcher lists.
@@ -733,7 +1176,7 @@ impl RoomManager {
             }
         }
         for room_id in to_remove {
-            self.rooms.remove(&room_id);
+            self.remove_room(room_id);
         }
     }
 
@@ -750,6 +1193,7 @@ impl RoomManager {
                 let room_code = room.code;
                 let watchers: Vec<OutboundTx> = room.p2p_watchers.values().cloned().collect();
                 room.p2p_host = None;
+                room.p2p_punch.clear();
                 if !watchers.is_empty() {
                     result.push((room_code, watchers));
                 }
@@ -793,4 +1237,102 @@ mod tests {
         let history = room.get_input_history(0);
         assert_eq!(history, vec![(0, 0, vec![1, 2, 99, 4, 5])]);
     }
+
+    #[test]
+    fn p2p_punch_pairs_joiners_once_host_registers() {
+        let (tx, _rx) = tokio::sync::mpsc::channel(1);
+        let mut room = Room::new(1, 1, 1);
+        room.set_p2p_host(P2PHostInfo {
+            host_signal_client_id: 1,
+            host_addrs: Vec::new(),
+            host_room_code: 0,
+            host_quic_cert_sha256_fingerprint: None,
+            host_quic_server_name: None,
+        });
+        room.upsert_p2p_watcher(1, tx.clone());
+        room.upsert_p2p_watcher(2, tx.clone());
+        let endpoint = |port| P2PPunchEndpoint {
+            public_addr: SocketAddr::from(([203, 0, 113, 1], port)),
+            local_addrs: Vec::new(),
+            started: false,
+        };
+
+        // Not a watcher of this room.
+        assert!(room.register_p2p_punch(3, endpoint(3)).is_empty());
+        // Joiner first: waits for the host.
+        assert!(room.register_p2p_punch(2, endpoint(2)).is_empty());
+        assert_eq!(room.register_p2p_punch(1, endpoint(1)), vec![2]);
+        // Retries don't restart the pair.
+        assert!(room.register_p2p_punch(2, endpoint(2)).is_empty());
+        assert!(room.register_p2p_punch(1, endpoint(1)).is_empty());
+    }
+
+    #[test]
+    fn lobby_lists_public_rooms_best_match_first() {
+        let (tx, _rx) = tokio::sync::mpsc::channel(1);
+        let player = |client_id| Player {
+            conn_id: client_id as ConnId,
+            client_id,
+            player_index: 0,
+            name: format!("P{client_id}"),
+            outbounds: ClientOutbounds::new(tx.clone()),
+        };
+        let mut mgr = RoomManager::new();
+
+        let full = mgr.create_room(1);
+        let half = mgr.create_room(3);
+        let private = mgr.create_room(4);
+        let other_game = mgr.create_room(5);
+        for (room_id, clients) in [
+            (full, &[1, 2][..]),
+            (half, &[3][..]),
+            (private, &[4][..]),
+            (other_game, &[5][..]),
+        ] {
+            let room = mgr.get_room_mut(room_id).expect("room exists");
+            room.info.game_hash = 0xAAAA;
+            for &id in clients {
+                room.add_player(player(id));
+            }
+        }
+        mgr.get_room_mut(private).expect("room exists").password = Some(RoomPassword::new("pw"));
+        mgr.get_room_mut(other_game)
+            .expect("room exists")
+            .info
+            .game_hash = 0xBBBB;
+        // Never occupied: not listed.
+        mgr.create_room(6);
+
+        let filter = RoomFilter {
+            game_hash: Some(0xAAAA),
+            region: None,
+        };
+        let rooms = mgr.list_rooms(&filter);
+        let codes: Vec<u32> = rooms.iter().map(|r| r.room_code).collect();
+        assert_eq!(codes, vec![half, full]);
+        assert_eq!(rooms[0].info.name, "P3");
+        assert_eq!((rooms[0].open_slots, rooms[1].open_slots), (1, 0));
+        assert_eq!(mgr.quick_match(&filter), Some(half));
+
+        mgr.get_room_mut(half)
+            .expect("room exists")
+            .add_player(player(7));
+        assert_eq!(mgr.quick_match(&filter), None);
+        assert_eq!(mgr.list_rooms(&RoomFilter::default()).len(), 3);
+    }
+
+    #[test]
+    fn record_inputs_reports_duplicates_and_recent_inputs_stop_at_gaps() {
+        let mut room = Room::new(1, 1, 1);
+
+        assert!(room.record_inputs(0, 0, &[1, 2, 3]));
+        // Same frames again (e.g. the datagram copy of a batch).
+        assert!(!room.record_inputs(0, 1, &[2, 3]));
+        assert!(room.record_inputs(0, 2, &[30]));
+
+        room.record_inputs(0, 5, &[6, 7]); // gap at frames 3,4
+        assert_eq!(room.recent_inputs(0, 6, 8), (5, vec![6, 7]));
+        assert_eq!(room.recent_inputs(0, 2, 2), (1, vec![2, 30]));
+        assert_eq!(room.recent_inputs(0, 4, 8), (4, vec![]));
+    }
 }
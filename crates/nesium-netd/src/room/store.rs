@@ -0,0 +1,440 @@
+//! Optional SQLite persistence for named rooms, bans and user identities.
+//!
+//! Without a database the server keeps everything in memory, as before.
+//! With one, a room whose host gave it a name keeps its code, description and
+//! password across restarts (and while nobody is in it), bans outlive the
+//! process, and clients that present the token from an earlier `Welcome` get
+//! the same user id back.
+//!
+//! Writes are small and synchronous; failures are logged and otherwise
+//! ignored so a broken disk never takes the relay down.
+
+use std::fmt;
+use std::net::IpAddr;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use nesium_netproto::messages::lobby::RoomInfo;
+use nesium_netproto::messages::session::{SyncMode, UserIdentity, UserToken};
+use ring::digest;
+use rusqlite::{Connection, OptionalExtension, params};
+use tracing::warn;
+
+use crate::room::password::RoomPassword;
+
+/// Named rooms nobody reopened for this long are forgotten on startup.
+pub const SAVED_ROOM_TTL: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS rooms (
+    code INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    region TEXT NOT NULL,
+    game_hash INTEGER NOT NULL,
+    sync_mode INTEGER NOT NULL,
+    password BLOB,
+    spectator_delay INTEGER NOT NULL,
+    last_used INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS bans (
+    target TEXT PRIMARY KEY,
+    reason TEXT NOT NULL,
+    expires_at INTEGER
+);
+CREATE TABLE IF NOT EXISTS users (
+    user_id INTEGER PRIMARY KEY AUTOINCREMENT,
+    token_hash BLOB NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    last_seen INTEGER NOT NULL
+);
+";
+
+/// Settings of a named room that survive restarts.
+#[derive(Debug, Clone)]
+pub struct SavedRoom {
+    pub code: u32,
+    pub info: RoomInfo,
+    pub sync_mode: SyncMode,
+    pub password: Option<RoomPassword>,
+    pub spectator_delay_frames: u32,
+}
+
+/// Who a ban applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BanTarget {
+    Ip(IpAddr),
+    User(u64),
+}
+
+impl fmt::Display for BanTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BanTarget::Ip(ip) => write!(f, "ip:{ip}"),
+            BanTarget::User(id) => write!(f, "user:{id}"),
+        }
+    }
+}
+
+impl FromStr for BanTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = match s.split_once(':') {
+            Some(("ip", ip)) => ip.parse().ok().map(BanTarget::Ip),
+            Some(("user", id)) => id.parse().ok().map(BanTarget::User),
+            _ => None,
+        };
+        parsed.ok_or_else(|| format!("invalid ban target `{s}`"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ban {
+    pub target: BanTarget,
+    pub reason: String,
+    /// Unix time in seconds; `None` bans for good.
+    pub expires_at: Option<u64>,
+}
+
+impl Ban {
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|t| t <= now)
+    }
+}
+
+/// Handle to the database. The default value is disabled and stores nothing.
+#[derive(Debug, Default)]
+pub struct RoomStore {
+    conn: Option<Connection>,
+    /// Overrides [`SAVED_ROOM_TTL`].
+    saved_room_ttl: Option<Duration>,
+}
+
+impl RoomStore {
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// Open (or create) the database at `path`.
+    pub fn open(path: &Path) -> rusqlite::Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        Self::init(conn)
+    }
+
+    /// A throwaway database, for tests.
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> rusqlite::Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self {
+            conn: Some(conn),
+            saved_room_ttl: None,
+        })
+    }
+
+    /// Forget saved rooms after `ttl` of disuse instead of [`SAVED_ROOM_TTL`].
+    pub fn with_saved_room_ttl(mut self, ttl: Duration) -> Self {
+        self.saved_room_ttl = Some(ttl);
+        self
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.conn.is_some()
+    }
+
+    /// Saved rooms, after dropping those unused for the saved-room TTL.
+    pub fn load_rooms(&self) -> Vec<SavedRoom> {
+        let Some(conn) = &self.conn else {
+            return Vec::new();
+        };
+        let ttl = self.saved_room_ttl.unwrap_or(SAVED_ROOM_TTL);
+        let cutoff = unix_now().saturating_sub(ttl.as_secs());
+        let result = conn
+            .execute("DELETE FROM rooms WHERE last_used < ?1", [cutoff])
+            .and_then(|_| {
+                let mut stmt = conn.prepare(
+                    "SELECT code, name, region, game_hash, sync_mode, password, spectator_delay
+                     FROM rooms",
+                )?;
+                let rows = stmt.query_map([], |row| {
+                    let password: Option<Vec<u8>> = row.get(5)?;
+                    Ok(SavedRoom {
+                        code: row.get(0)?,
+                        info: RoomInfo {
+                            name: row.get(1)?,
+                            region: row.get(2)?,
+                            game_hash: row.get(3)?,
+                        },
+                        sync_mode: match row.get::<_, u8>(4)? {
+                            1 => SyncMode::Rollback,
+                            _ => SyncMode::Lockstep,
+                        },
+                        password: password.as_deref().and_then(RoomPassword::from_bytes),
+                        spectator_delay_frames: row.get(6)?,
+                    })
+                })?;
+                rows.collect()
+            });
+        result.unwrap_or_else(|e| {
+            warn!(error = %e, "Failed to load saved rooms");
+            Vec::new()
+        })
+    }
+
+    pub fn save_room(&self, room: &SavedRoom) {
+        let Some(conn) = &self.conn else {
+            return;
+        };
+        let result = conn.execute(
+            "INSERT OR REPLACE INTO rooms
+             (code, name, region, game_hash, sync_mode, password, spectator_delay, last_used)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                room.code,
+                room.info.name,
+                room.info.region,
+                room.info.game_hash,
+                room.sync_mode as u8,
+                room.password.as_ref().map(RoomPassword::to_bytes),
+                room.spectator_delay_frames,
+                unix_now(),
+            ],
+        );
+        if let Err(e) = result {
+            warn!(room_code = room.code, error = %e, "Failed to save room");
+        }
+    }
+
+    /// Mark a saved room as used, so it doesn't expire.
+    pub fn touch_room(&self, code: u32) {
+        self.run(
+            "touch room",
+            "UPDATE rooms SET last_used = ?1 WHERE code = ?2",
+            params![unix_now(), code],
+        );
+    }
+
+    pub fn delete_room(&self, code: u32) {
+        self.run(
+            "delete room",
+            "DELETE FROM rooms WHERE code = ?1",
+            params![code],
+        );
+    }
+
+    /// Bans that haven't expired yet.
+    pub fn load_bans(&self) -> Vec<Ban> {
+        let Some(conn) = &self.conn else {
+            return Vec::new();
+        };
+        let result = conn
+            .execute(
+                "DELETE FROM bans WHERE expires_at IS NOT NULL AND expires_at <= ?1",
+                [unix_now()],
+            )
+            .and_then(|_| {
+                let mut stmt = conn.prepare("SELECT target, reason, expires_at FROM bans")?;
+                let rows = stmt.query_map([], |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, Option<u64>>(2)?,
+                    ))
+                })?;
+                rows.collect::<rusqlite::Result<Vec<_>>>()
+            });
+        match result {
+            Ok(rows) => rows
+                .into_iter()
+                .filter_map(|(target, reason, expires_at)| match target.parse() {
+                    Ok(target) => Some(Ban {
+                        target,
+                        reason,
+                        expires_at,
+                    }),
+                    Err(e) => {
+                        warn!(error = %e, "Skipping saved ban");
+                        None
+                    }
+                })
+                .collect(),
+            Err(e) => {
+                warn!(error = %e, "Failed to load bans");
+                Vec::new()
+            }
+        }
+    }
+
+    pub fn save_ban(&self, ban: &Ban) {
+        self.run(
+            "save ban",
+            "INSERT OR REPLACE INTO bans (target, reason, expires_at) VALUES (?1, ?2, ?3)",
+            params![ban.target.to_string(), ban.reason, ban.expires_at],
+        );
+    }
+
+    pub fn delete_ban(&self, target: BanTarget) {
+        self.run(
+            "delete ban",
+            "DELETE FROM bans WHERE target = ?1",
+            params![target.to_string()],
+        );
+    }
+
+    /// Look up the user holding `token`, or register a new one if there is
+    /// no token or it is unknown. `None` if identities aren't persisted.
+    pub fn identify(&self, token: Option<UserToken>, name: &str) -> Option<UserIdentity> {
+        let conn = self.conn.as_ref()?;
+        let now = unix_now();
+
+        if let Some(token) = token {
+            let found = conn
+                .query_row(
+                    "UPDATE users SET name = ?1, last_seen = ?2 WHERE token_hash = ?3
+                     RETURNING user_id",
+                    params![name, now, hash_token(&token)],
+                    |row| row.get(0),
+                )
+                .optional();
+            match found {
+                Ok(Some(user_id)) => return Some(UserIdentity { user_id, token }),
+                Ok(None) => {}
+                Err(e) => {
+                    warn!(error = %e, "Failed to look up user");
+                    return None;
+                }
+            }
+        }
+
+        let token: UserToken = rand::random();
+        let inserted = conn.execute(
+            "INSERT INTO users (token_hash, name, created_at, last_seen) VALUES (?1, ?2, ?3, ?3)",
+            params![hash_token(&token), name, now],
+        );
+        match inserted {
+            Ok(_) => Some(UserIdentity {
+                user_id: conn.last_insert_rowid() as u64,
+                token,
+            }),
+            Err(e) => {
+                warn!(error = %e, "Failed to register user");
+                None
+            }
+        }
+    }
+
+    /// Move everything written so far from the write-ahead log into the
+    /// database file, e.g. before shutting down.
+    pub fn checkpoint(&self) {
+        let Some(conn) = &self.conn else {
+            return;
+        };
+        if let Err(e) = conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(())) {
+            warn!(error = %e, "Failed to checkpoint database");
+        }
+    }
+
+    fn run(&self, what: &str, sql: &str, params: impl rusqlite::Params) {
+        let Some(conn) = &self.conn else {
+            return;
+        };
+        if let Err(e) = conn.execute(sql, params) {
+            warn!(error = %e, "Failed to {what}");
+        }
+    }
+}
+
+/// Only a hash of each token is stored, so a leaked database can't be used
+/// to impersonate users.
+fn hash_token(token: &UserToken) -> Vec<u8> {
+    digest::digest(&digest::SHA256, token).as_ref().to_vec()
+}
+
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rooms_round_trip() {
+        let store = RoomStore::open_in_memory().expect("in-memory db");
+        let room = SavedRoom {
+            code: 42,
+            info: RoomInfo {
+                name: "Friday".into(),
+                region: "eu".into(),
+                game_hash: 0xDEAD_BEEF,
+            },
+            sync_mode: SyncMode::Rollback,
+            password: Some(RoomPassword::new("hunter2")),
+            spectator_delay_frames: 180,
+        };
+        store.save_room(&room);
+
+        let loaded = store.load_rooms();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].code, 42);
+        assert_eq!(loaded[0].info, room.info);
+        assert_eq!(loaded[0].sync_mode, SyncMode::Rollback);
+        assert_eq!(loaded[0].spectator_delay_frames, 180);
+        assert!(
+            loaded[0]
+                .password
+                .as_ref()
+                .expect("password")
+                .verify("hunter2")
+        );
+
+        store.delete_room(42);
+        assert!(store.load_rooms().is_empty());
+    }
+
+    #[test]
+    fn expired_bans_are_dropped() {
+        let store = RoomStore::open_in_memory().expect("in-memory db");
+        let ip = BanTarget::Ip("10.0.0.1".parse().expect("ip"));
+        let user = BanTarget::User(7);
+        store.save_ban(&Ban {
+            target: ip,
+            reason: "flood".into(),
+            expires_at: None,
+        });
+        store.save_ban(&Ban {
+            target: user,
+            reason: "old".into(),
+            expires_at: Some(1),
+        });
+
+        let bans = store.load_bans();
+        assert_eq!(bans.len(), 1);
+        assert_eq!(bans[0].target, ip);
+
+        store.delete_ban(ip);
+        assert!(store.load_bans().is_empty());
+    }
+
+    #[test]
+    fn tokens_keep_their_user_id() {
+        let store = RoomStore::open_in_memory().expect("in-memory db");
+        let first = store.identify(None, "a").expect("identity");
+        let again = store.identify(Some(first.token), "a2").expect("identity");
+        assert_eq!(again, first);
+
+        let other = store.identify(Some([0; 16]), "b").expect("identity");
+        assert_ne!(other.user_id, first.user_id);
+        assert_ne!(other.token, [0; 16]);
+
+        assert!(RoomStore::disabled().identify(None, "c").is_none());
+    }
+}
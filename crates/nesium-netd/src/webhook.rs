@@ -0,0 +1,253 @@
+//! Outbound room event webhooks.
+//!
+//! When configured, the server POSTs a JSON document for each room lifecycle
+//! event to a single URL so external services (Discord bots, matchmaking
+//! sites) can follow activity without scraping logs. Delivery is best-effort:
+//! events are queued to a dedicated worker thread and dropped if the queue is
+//! full, so a slow endpoint never stalls the server loop.
+
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ring::hmac;
+use serde::Serialize;
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Maximum number of undelivered events buffered before new ones are dropped.
+const QUEUE_CAPACITY: usize = 256;
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Header carrying the hex HMAC-SHA256 of the body when a secret is set.
+pub const SIGNATURE_HEADER: &str = "X-Nesium-Signature";
+
+/// Room lifecycle event delivered to the webhook endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum WebhookEvent {
+    RoomCreated {
+        room_id: u32,
+        room_code: u32,
+        host_client_id: u32,
+    },
+    RoomClosed {
+        room_id: u32,
+    },
+    PlayerJoined {
+        room_id: u32,
+        client_id: u32,
+        name: String,
+        /// `None` for spectators.
+        player_index: Option<u8>,
+    },
+    PlayerLeft {
+        room_id: u32,
+        client_id: u32,
+        player_index: Option<u8>,
+    },
+    /// A room that had started a game was closed.
+    MatchFinished {
+        room_id: u32,
+        /// Last frame the server relayed inputs for.
+        frames: u32,
+    },
+}
+
+#[derive(Serialize)]
+struct Envelope<'a> {
+    /// Milliseconds since the Unix epoch at the time the event was queued.
+    timestamp_ms: u64,
+    #[serde(flatten)]
+    event: &'a WebhookEvent,
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookConfig {
+    pub url: String,
+    /// Shared secret used to sign request bodies (see [`SIGNATURE_HEADER`]).
+    pub secret: Option<String>,
+    pub timeout: Duration,
+}
+
+impl WebhookConfig {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            secret: None,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+/// Cheap handle used by the server to emit webhook events.
+///
+/// The default value is disabled and ignores all events.
+#[derive(Debug, Clone, Default)]
+pub struct WebhookNotifier {
+    tx: Option<mpsc::Sender<(u64, WebhookEvent)>>,
+}
+
+impl WebhookNotifier {
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// Starts the delivery worker for `config`.
+    pub fn spawn(config: WebhookConfig) -> Self {
+        let (tx, rx) = mpsc::channel(QUEUE_CAPACITY);
+        thread::Builder::new()
+            .name("netd-webhook".into())
+            .spawn(move || deliver_loop(config, rx))
+            .expect("failed to spawn webhook thread");
+        Self { tx: Some(tx) }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.tx.is_some()
+    }
+
+    pub fn notify(&self, event: WebhookEvent) {
+        let Some(tx) = &self.tx else {
+            return;
+        };
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        if let Err(e) = tx.try_send((timestamp_ms, event)) {
+            warn!(error = %e, "Dropping webhook event");
+        }
+    }
+}
+
+fn deliver_loop(config: WebhookConfig, mut rx: mpsc::Receiver<(u64, WebhookEvent)>) {
+    let agent = ureq::Agent::new_with_config(
+        ureq::Agent::config_builder()
+            .timeout_global(Some(config.timeout))
+            .build(),
+    );
+    let key = config
+        .secret
+        .as_ref()
+        .map(|s| hmac::Key::new(hmac::HMAC_SHA256, s.as_bytes()));
+
+    while let Some((timestamp_ms, event)) = rx.blocking_recv() {
+        let body = match encode_body(timestamp_ms, &event) {
+            Ok(body) => body,
+            Err(e) => {
+                warn!(error = %e, "Failed to encode webhook event");
+                continue;
+            }
+        };
+
+        let mut request = agent
+            .post(&config.url)
+            .header("Content-Type", "application/json");
+        if let Some(key) = &key {
+            request = request.header(SIGNATURE_HEADER, sign(key, body.as_bytes()));
+        }
+
+        match request.send(body.as_bytes()) {
+            Ok(resp) => debug!(status = %resp.status(), ?event, "Webhook delivered"),
+            Err(e) => warn!(error = %e, url = %config.url, "Webhook delivery failed"),
+        }
+    }
+}
+
+fn encode_body(timestamp_ms: u64, event: &WebhookEvent) -> serde_json::Result<String> {
+    serde_json::to_string(&Envelope {
+        timestamp_ms,
+        event,
+    })
+}
+
+fn sign(key: &hmac::Key, body: &[u8]) -> String {
+    format!("sha256={}", hex::encode(hmac::sign(key, body).as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn body_is_flat_json_tagged_by_event() {
+        let body = encode_body(
+            42,
+            &WebhookEvent::PlayerJoined {
+                room_id: 3,
+                client_id: 7,
+                name: "p1".into(),
+                player_index: Some(0),
+            },
+        )
+        .expect("encode");
+        assert_eq!(
+            body,
+            r#"{"timestamp_ms":42,"event":"player_joined","room_id":3,"client_id":7,"name":"p1","player_index":0}"#
+        );
+    }
+
+    #[test]
+    fn signature_is_hex_hmac_sha256() {
+        let key = hmac::Key::new(hmac::HMAC_SHA256, b"key");
+        assert_eq!(
+            sign(&key, b"The quick brown fox jumps over the lazy dog"),
+            "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+
+    #[test]
+    fn delivers_signed_post_to_endpoint() {
+        use std::io::{BufRead, BufReader, Read, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let url = format!("http://{}/hook", listener.local_addr().expect("addr"));
+        let notifier = WebhookNotifier::spawn(WebhookConfig {
+            secret: Some("s3cret".into()),
+            ..WebhookConfig::new(url)
+        });
+        notifier.notify(WebhookEvent::RoomClosed { room_id: 9 });
+
+        let (stream, _) = listener.accept().expect("accept");
+        let mut reader = BufReader::new(stream);
+        let mut headers = Vec::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).expect("header line");
+            if line == "\r\n" {
+                break;
+            }
+            headers.push(line.trim_end().to_ascii_lowercase());
+        }
+        let len: usize = headers
+            .iter()
+            .find_map(|h| h.strip_prefix("content-length: "))
+            .and_then(|v| v.parse().ok())
+            .expect("content-length");
+        let mut body = vec![0; len];
+        reader.read_exact(&mut body).expect("body");
+        reader
+            .get_mut()
+            .write_all(b"HTTP/1.1 204 No Content\r\nContent-Length: 0\r\n\r\n")
+            .expect("respond");
+
+        assert!(headers[0].starts_with("post /hook "));
+        let body = String::from_utf8(body).expect("utf8");
+        assert!(body.ends_with(r#""event":"room_closed","room_id":9}"#));
+        let key = hmac::Key::new(hmac::HMAC_SHA256, b"s3cret");
+        let expected = format!(
+            "{}: {}",
+            SIGNATURE_HEADER.to_ascii_lowercase(),
+            sign(&key, body.as_bytes())
+        );
+        assert!(headers.contains(&expected));
+    }
+
+    #[test]
+    fn disabled_notifier_ignores_events() {
+        let notifier = WebhookNotifier::disabled();
+        assert!(!notifier.is_enabled());
+        notifier.notify(WebhookEvent::RoomClosed { room_id: 1 });
+    }
+}
@@ -5,16 +5,18 @@ use std::time::Duration;
 
 use nesium_netproto::{
     codec_tcp::{encode_tcp_frame, try_decode_tcp_frames},
+    codec_udp::encode_udp,
     header::Header,
     messages::session::{
         FallbackToRelay, Hello, P2PCreateRoom, P2PFallbackNotice, P2PJoinAck, P2PJoinRoom,
-        P2PRequestFallback, P2PRoomCreated, RequestFallbackRelay, TransportKind, Welcome,
+        P2PPunchRegister, P2PPunchStart, P2PRequestFallback, P2PRoomCreated, RequestFallbackRelay,
+        RtcSignal, RtcSignalKind, TransportKind, Welcome,
     },
     msg_id::MsgId,
 };
 use tokio::{
     io::{AsyncReadExt, AsyncWriteExt},
-    net::TcpStream,
+    net::{TcpStream, UdpSocket},
     sync::mpsc,
     time::timeout,
 };
@@ -38,9 +40,11 @@ impl RawClient {
         let hello = Hello {
             client_nonce: 123,
             transport: TransportKind::Tcp,
-            proto_min: nesium_netproto::constants::VERSION,
-            proto_max: nesium_netproto::constants::VERSION,
+            proto_min: nesium_netproto::constants::MIN_PROTOCOL_VERSION,
+            proto_max: nesium_netproto::constants::PROTOCOL_VERSION,
             name: name.to_string(),
+            capabilities: nesium_netproto::messages::session::capability::SUPPORTED,
+            user_token: None,
         };
         let h = Header::new(MsgId::Hello as u8);
         let frame = encode_tcp_frame(h, MsgId::Hello, &hello, 4096)?;
@@ -84,6 +88,12 @@ impl RawClient {
 }
 
 async fn spawn_server(app_name: &str) -> anyhow::Result<SocketAddr> {
+    Ok(spawn_server_with_udp(app_name).await?.0)
+}
+
+/// Like `spawn_server`, plus a UDP listener for hole punching. Returns the TCP
+/// and UDP addresses.
+async fn spawn_server_with_udp(app_name: &str) -> anyhow::Result<(SocketAddr, SocketAddr)> {
     let addr: SocketAddr = "127.0.0.1:0".parse().unwrap();
     let (event_tx, event_rx) = mpsc::channel(1024);
 
@@ -112,13 +122,19 @@ async fn spawn_server(app_name: &str) -> anyhow::Result<SocketAddr> {
         }
     });
 
+    let udp = UdpSocket::bind(addr).await?;
+    let udp_addr = udp.local_addr()?;
+    tokio::spawn(nesium_netd::net::udp::run_udp_listener_with_socket(
+        udp, event_tx,
+    ));
+
     tokio::spawn(async move {
         let _ = nesium_netd::run_server(event_rx).await;
     });
 
     // Validated: listener is active immediately, but give a tiny slack for spawn
     tokio::time::sleep(Duration::from_millis(50)).await;
-    Ok(server_addr)
+    Ok((server_addr, udp_addr))
 }
 
 #[tokio::test]
@@ -248,6 +264,7 @@ async fn host_can_broadcast_fallback_to_direct_clients() -> anyhow::Result<()> {
         &nesium_netproto::messages::session::JoinRoom {
             room_code: 0,
             preferred_sync_mode: None,
+            password: None,
         },
     )
     .await?;
@@ -263,6 +280,7 @@ async fn host_can_broadcast_fallback_to_direct_clients() -> anyhow::Result<()> {
             &nesium_netproto::messages::session::JoinRoom {
                 room_code: room_id,
                 preferred_sync_mode: None,
+                password: None,
             },
         )
         .await?;
@@ -288,3 +306,115 @@ async fn host_can_broadcast_fallback_to_direct_clients() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn punch_start_exchanges_public_endpoints() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let (server_addr, udp_addr) = spawn_server_with_udp("test_p2p_sig_4").await?;
+
+    let mut host = RawClient::connect(server_addr).await?;
+    let host_welcome = host.hello("host").await?;
+    host.send(
+        MsgId::P2PCreateRoom,
+        &P2PCreateRoom {
+            host_addrs: vec!["127.0.0.1:9999".parse().unwrap()],
+            host_room_code: 1,
+            host_quic_cert_sha256_fingerprint: None,
+            host_quic_server_name: None,
+        },
+    )
+    .await?;
+    let created: P2PRoomCreated = host.recv_one(MsgId::P2PRoomCreated).await?;
+
+    let mut joiner = RawClient::connect(server_addr).await?;
+    let joiner_welcome = joiner.hello("joiner").await?;
+    joiner
+        .send(
+            MsgId::P2PJoinRoom,
+            &P2PJoinRoom {
+                room_code: created.room_code,
+            },
+        )
+        .await?;
+    let _ack: P2PJoinAck = joiner.recv_one(MsgId::P2PJoinAck).await?;
+
+    // Both register a UDP socket with the signaling server.
+    let host_udp = UdpSocket::bind("127.0.0.1:0").await?;
+    let joiner_udp = UdpSocket::bind("127.0.0.1:0").await?;
+    for (socket, token) in [
+        (&host_udp, host_welcome.session_token),
+        (&joiner_udp, joiner_welcome.session_token),
+    ] {
+        let register = P2PPunchRegister {
+            session_token: token,
+            room_code: created.room_code,
+            local_addrs: Vec::new(),
+        };
+        let h = Header::new(MsgId::P2PPunchRegister as u8);
+        let datagram = encode_udp(h, MsgId::P2PPunchRegister, &register, 1200)?;
+        socket.send_to(&datagram, udp_addr).await?;
+    }
+
+    // Each side learns the other's observed endpoint and the shared nonce.
+    let to_host: P2PPunchStart = host.recv_one(MsgId::P2PPunchStart).await?;
+    let to_joiner: P2PPunchStart = joiner.recv_one(MsgId::P2PPunchStart).await?;
+    assert_eq!(to_host.peer_client_id, joiner_welcome.assigned_client_id);
+    assert_eq!(to_joiner.peer_client_id, host_welcome.assigned_client_id);
+    assert_eq!(to_host.peer_addrs, vec![joiner_udp.local_addr()?]);
+    assert_eq!(to_joiner.peer_addrs, vec![host_udp.local_addr()?]);
+    assert_eq!(to_host.nonce, to_joiner.nonce);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rtc_signal_is_relayed_between_watchers() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let server_addr = spawn_server("test_p2p_sig_5").await?;
+
+    let mut host = RawClient::connect(server_addr).await?;
+    let host_welcome = host.hello("host").await?;
+    host.send(
+        MsgId::P2PCreateRoom,
+        &P2PCreateRoom {
+            host_addrs: Vec::new(),
+            host_room_code: 1,
+            host_quic_cert_sha256_fingerprint: None,
+            host_quic_server_name: None,
+        },
+    )
+    .await?;
+    let created: P2PRoomCreated = host.recv_one(MsgId::P2PRoomCreated).await?;
+
+    let mut browser = RawClient::connect(server_addr).await?;
+    let browser_welcome = browser.hello("browser").await?;
+    browser
+        .send(
+            MsgId::P2PJoinRoom,
+            &P2PJoinRoom {
+                room_code: created.room_code,
+            },
+        )
+        .await?;
+    let _ack: P2PJoinAck = browser.recv_one(MsgId::P2PJoinAck).await?;
+
+    browser
+        .send(
+            MsgId::RtcSignal,
+            &RtcSignal {
+                room_code: created.room_code,
+                peer_client_id: host_welcome.assigned_client_id,
+                kind: RtcSignalKind::Offer,
+                data: "v=0".to_string(),
+            },
+        )
+        .await?;
+
+    // The host sees the offer with the sender's id.
+    let offer: RtcSignal = host.recv_one(MsgId::RtcSignal).await?;
+    assert_eq!(offer.peer_client_id, browser_welcome.assigned_client_id);
+    assert_eq!(offer.kind, RtcSignalKind::Offer);
+    assert_eq!(offer.data, "v=0");
+
+    Ok(())
+}
@@ -8,7 +8,8 @@ use nesium_netproto::{
     codec_tcp::{encode_tcp_frame, try_decode_tcp_frames},
     header::Header,
     messages::session::{
-        Hello, JoinAck, JoinRoom, LoadRom, RomLoaded, StartGame, TransportKind, Welcome,
+        ErrorCode, ErrorMsg, Hello, JoinAck, JoinRoom, LoadRom, RomHash, RomLoaded, StartGame,
+        TransportKind, Welcome,
     },
     msg_id::MsgId,
 };
@@ -40,9 +41,11 @@ impl TestClient {
         let hello = Hello {
             client_nonce: 12345,
             transport: TransportKind::Tcp,
-            proto_min: nesium_netproto::constants::VERSION,
-            proto_max: nesium_netproto::constants::VERSION,
+            proto_min: nesium_netproto::constants::MIN_PROTOCOL_VERSION,
+            proto_max: nesium_netproto::constants::PROTOCOL_VERSION,
             name: name.to_string(),
+            capabilities: nesium_netproto::messages::session::capability::SUPPORTED,
+            user_token: None,
         };
 
         let header = Header::new(MsgId::Hello as u8);
@@ -70,6 +73,7 @@ impl TestClient {
         let join = JoinRoom {
             room_code,
             preferred_sync_mode: None,
+            password: None,
         };
 
         let header = Header::new(MsgId::JoinRoom as u8);
@@ -103,8 +107,8 @@ impl TestClient {
         Ok(ack)
     }
 
-    async fn send_load_rom(&mut self, data: Vec<u8>) -> anyhow::Result<()> {
-        let msg = LoadRom { data };
+    async fn send_load_rom(&mut self, data: Vec<u8>, hash: Option<RomHash>) -> anyhow::Result<()> {
+        let msg = LoadRom { data, hash };
         let header = Header::new(MsgId::LoadRom as u8);
 
         let frame = encode_tcp_frame(header, MsgId::LoadRom, &msg, 4096)?;
@@ -112,7 +116,7 @@ impl TestClient {
         Ok(())
     }
 
-    async fn recv_load_rom(&mut self) -> anyhow::Result<Vec<u8>> {
+    async fn recv_load_rom(&mut self) -> anyhow::Result<LoadRom> {
         let mut buf = vec![0u8; 65536]; // Larger buffer for ROM
         let n = timeout(Duration::from_secs(2), self.stream.read(&mut buf)).await??;
         buf.truncate(n);
@@ -122,12 +126,11 @@ impl TestClient {
         let packet = &packets[0];
         assert_eq!(packet.msg_id, MsgId::LoadRom);
 
-        let msg: LoadRom = postcard::from_bytes(packet.payload)?;
-        Ok(msg.data)
+        Ok(postcard::from_bytes(packet.payload)?)
     }
 
-    async fn send_rom_loaded(&mut self) -> anyhow::Result<()> {
-        let msg = RomLoaded {};
+    async fn send_rom_loaded(&mut self, hash: Option<RomHash>) -> anyhow::Result<()> {
+        let msg = RomLoaded { hash };
         let header = Header::new(MsgId::RomLoaded as u8);
 
         let frame = encode_tcp_frame(header, MsgId::RomLoaded, &msg, 4096)?;
@@ -136,10 +139,19 @@ impl TestClient {
     }
 
     async fn recv_start_game(&mut self) -> anyhow::Result<()> {
+        let _: StartGame = self.recv_msg(MsgId::StartGame).await?;
+        Ok(())
+    }
+
+    /// Wait for the first packet with `msg_id`, ignoring others (e.g. PlayerJoined).
+    async fn recv_msg<T: serde::de::DeserializeOwned>(
+        &mut self,
+        msg_id: MsgId,
+    ) -> anyhow::Result<T> {
         let start = std::time::Instant::now();
         loop {
             if start.elapsed() > Duration::from_secs(5) {
-                anyhow::bail!("Timeout waiting for StartGame");
+                anyhow::bail!("Timeout waiting for {:?}", msg_id);
             }
 
             let mut buf = vec![0u8; 4096];
@@ -156,12 +168,10 @@ impl TestClient {
             }
             buf.truncate(n);
 
-            if let Ok((packets, _)) = try_decode_tcp_frames(&buf) {
-                if let Some(packet) = packets.iter().find(|p| p.msg_id == MsgId::StartGame) {
-                    let _: StartGame = postcard::from_bytes(packet.payload)?;
-                    return Ok(());
-                }
-                // If not StartGame, ignore and continue reading (e.g. PlayerJoined)
+            if let Ok((packets, _)) = try_decode_tcp_frames(&buf)
+                && let Some(packet) = packets.iter().find(|p| p.msg_id == msg_id)
+            {
+                return Ok(postcard::from_bytes(packet.payload)?);
             }
         }
     }
@@ -232,15 +242,15 @@ async fn test_rom_sync_flow() -> anyhow::Result<()> {
 
     // 3. Host Sends LoadRom
     let rom_data = vec![0xDE, 0xAD, 0xBE, 0xEF];
-    c1.send_load_rom(rom_data.clone()).await?;
-    c1.send_rom_loaded().await?;
+    c1.send_load_rom(rom_data.clone(), None).await?;
+    c1.send_rom_loaded(None).await?;
 
     // 4. Joiner Receives LoadRom
     let received_rom = c2.recv_load_rom().await?;
-    assert_eq!(received_rom, rom_data);
+    assert_eq!(received_rom.data, rom_data);
 
     // 5. Joiner Sends RomLoaded
-    c2.send_rom_loaded().await?;
+    c2.send_rom_loaded(None).await?;
 
     // 6. Both Receive StartGame
     // Order depends on broadcast loop, but both should get it.
@@ -250,3 +260,45 @@ async fn test_rom_sync_flow() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_rom_hash_mismatch_refused() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let (addr, _shutdown) = spawn_test_server("test_rom_hash_mismatch").await;
+
+    let mut c1 = TestClient::connect(addr).await?;
+    c1.send_hello("Host").await?;
+    c1.recv_welcome().await?;
+    c1.send_join_room(0).await?;
+    c1.recv_join_ack().await?;
+    let room_code = c1.room_id;
+
+    let mut c2 = TestClient::connect(addr).await?;
+    c2.send_hello("Joiner").await?;
+    c2.recv_welcome().await?;
+    c2.send_join_room(room_code).await?;
+    c2.recv_join_ack().await?;
+
+    let hash = RomHash {
+        crc32: 0x1234_5678,
+        sha1: [0xAB; 20],
+    };
+    c1.send_load_rom(vec![0xDE, 0xAD], Some(hash)).await?;
+    c1.send_rom_loaded(Some(hash)).await?;
+    assert_eq!(c2.recv_load_rom().await?.hash, Some(hash));
+
+    // A different revision is refused and does not start the game.
+    let other = RomHash {
+        crc32: 0x8765_4321,
+        ..hash
+    };
+    c2.send_rom_loaded(Some(other)).await?;
+    let err: ErrorMsg = c2.recv_msg(MsgId::Error).await?;
+    assert_eq!(err.code, ErrorCode::RomMismatch);
+
+    c2.send_rom_loaded(Some(hash)).await?;
+    c1.recv_start_game().await?;
+    c2.recv_start_game().await?;
+
+    Ok(())
+}
@@ -1,22 +1,45 @@
 //! End-to-end integration tests for netplay server.
 //!
 //! Tests the full flow with mock clients:
-//! - Hello/Welcome handshake
+//! - Hello/Welcome handshake and version negotiation
 //! - Room creation and joining
 //! - Input batch relay
+//! - State hash relay
+//! - Room chat
+//! - Lobby listing and quick match
+//! - Spectator delay and count
+//! - Persisted rooms, bans and identities
+//! - Admin HTTP API
+//! - Prometheus metrics
+//! - Room limit and settings reload
+//! - Per-IP connection caps, bans and handshake backoff
+//! - Graceful shutdown
+//! - Relay bandwidth quotas
 //! - Multiple clients
 
 use std::net::SocketAddr;
 use std::time::Duration;
 
+use nesium_netd::ServerOptions;
+use nesium_netd::admin::run_admin_listener_with_listener;
+use nesium_netd::config::ServerSettings;
 use nesium_netd::net::{quic_config, tcp::run_tcp_listener_with_listener};
+use nesium_netd::observability::prometheus::run_metrics_listener_with_listener;
+use nesium_netd::room::store::{Ban, BanTarget, RoomStore};
 use nesium_netproto::{
     codec_tcp::{encode_tcp_frame, try_decode_tcp_frames},
-    constants::SPECTATOR_PLAYER_INDEX,
+    constants::{HEADER_LEN, SPECTATOR_PLAYER_INDEX},
     header::Header,
     messages::{
-        input::InputBatch,
-        session::{Hello, JoinAck, JoinRoom, RoleChanged, SwitchRole, TransportKind, Welcome},
+        chat::{Chat, ChatBody},
+        input::{InputBatch, RelayInputs},
+        lobby::{ListRooms, QuickMatch, RoomFilter, RoomInfo, RoomList, SetRoomInfo},
+        session::{
+            ErrorCode, ErrorMsg, Hello, JoinAck, JoinRoom, PlayerLeft, RoleChanged, ServerClosing,
+            SetSpectatorDelay, SpectatorInfo, SwitchRole, TransportKind, UserToken, Welcome,
+            capability,
+        },
+        sync::{Ping, Pong, StateHash},
     },
     msg_id::MsgId,
 };
@@ -26,6 +49,7 @@ use tokio::{
     sync::mpsc,
     time::timeout,
 };
+use tokio_util::sync::CancellationToken;
 
 /// Mock test client.
 struct TestClient {
@@ -45,12 +69,22 @@ impl TestClient {
     }
 
     async fn send_hello(&mut self, name: &str) -> anyhow::Result<()> {
+        self.send_hello_with_token(name, None).await
+    }
+
+    async fn send_hello_with_token(
+        &mut self,
+        name: &str,
+        user_token: Option<UserToken>,
+    ) -> anyhow::Result<()> {
         let hello = Hello {
             client_nonce: 12345,
             transport: TransportKind::Tcp,
-            proto_min: nesium_netproto::constants::VERSION,
-            proto_max: nesium_netproto::constants::VERSION,
+            proto_min: nesium_netproto::constants::MIN_PROTOCOL_VERSION,
+            proto_max: nesium_netproto::constants::PROTOCOL_VERSION,
             name: name.to_string(),
+            capabilities: nesium_netproto::messages::session::capability::SUPPORTED,
+            user_token,
         };
 
         let header = Header::new(MsgId::Hello as u8);
@@ -75,9 +109,18 @@ impl TestClient {
     }
 
     async fn send_join_room(&mut self, room_code: u32) -> anyhow::Result<()> {
+        self.send_join_room_with_password(room_code, None).await
+    }
+
+    async fn send_join_room_with_password(
+        &mut self,
+        room_code: u32,
+        password: Option<&str>,
+    ) -> anyhow::Result<()> {
         let join = JoinRoom {
             room_code,
             preferred_sync_mode: None,
+            password: password.map(str::to_string),
         };
 
         let header = Header::new(MsgId::JoinRoom as u8);
@@ -190,6 +233,129 @@ impl TestClient {
             // PlayerJoined or other messages, continue reading
         }
     }
+
+    async fn send_state_hash(&mut self, msg: &StateHash) -> anyhow::Result<()> {
+        let header = Header::new(MsgId::StateHash as u8);
+        let frame = encode_tcp_frame(header, MsgId::StateHash, msg, 4096)?;
+        self.stream.write_all(&frame).await?;
+        Ok(())
+    }
+
+    async fn recv_state_hash(&mut self) -> anyhow::Result<StateHash> {
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(2);
+
+        loop {
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                anyhow::bail!("Timeout waiting for StateHash");
+            }
+            let remaining = deadline - now;
+
+            let mut buf = vec![0u8; 4096];
+            let n = timeout(remaining, self.stream.read(&mut buf)).await??;
+            buf.truncate(n);
+
+            let (packets, _) = try_decode_tcp_frames(&buf)?;
+            if let Some(packet) = packets.iter().find(|p| p.msg_id == MsgId::StateHash) {
+                return Ok(postcard::from_bytes(packet.payload)?);
+            }
+        }
+    }
+
+    async fn send_chat(&mut self, body: ChatBody) -> anyhow::Result<()> {
+        self.send_msg(MsgId::Chat, &Chat { client_id: 0, body })
+            .await
+    }
+
+    async fn send_msg<T: serde::Serialize>(
+        &mut self,
+        msg_id: MsgId,
+        msg: &T,
+    ) -> anyhow::Result<()> {
+        let header = Header::new(msg_id as u8);
+        let frame = encode_tcp_frame(header, msg_id, msg, 4096)?;
+        self.stream.write_all(&frame).await?;
+        Ok(())
+    }
+
+    /// Round-trip a server ping so everything sent before it has been handled.
+    async fn sync_with_server(&mut self) -> anyhow::Result<()> {
+        let ping = Ping {
+            seq: 0,
+            t_ms: 0,
+            peer_client_id: 0,
+        };
+        self.send_msg(MsgId::Ping, &ping).await?;
+        self.recv_msg::<Pong>(MsgId::Pong).await?;
+        Ok(())
+    }
+
+    /// Collect every `msg_id` packet the server sent before answering a ping.
+    async fn drain_msgs<T: serde::de::DeserializeOwned>(
+        &mut self,
+        msg_id: MsgId,
+    ) -> anyhow::Result<Vec<T>> {
+        let ping = Ping {
+            seq: 0,
+            t_ms: 0,
+            peer_client_id: 0,
+        };
+        self.send_msg(MsgId::Ping, &ping).await?;
+
+        let mut msgs = Vec::new();
+        let mut buf = Vec::new();
+        loop {
+            let mut chunk = vec![0u8; 4096];
+            let n = timeout(Duration::from_secs(2), self.stream.read(&mut chunk)).await??;
+            buf.extend_from_slice(&chunk[..n]);
+
+            let (packets, consumed) = try_decode_tcp_frames(&buf)?;
+            let mut ponged = false;
+            for packet in packets {
+                if packet.msg_id == msg_id {
+                    msgs.push(postcard::from_bytes(packet.payload)?);
+                } else if packet.msg_id == MsgId::Pong {
+                    ponged = true;
+                }
+            }
+            if ponged {
+                return Ok(msgs);
+            }
+            buf.drain(..consumed);
+        }
+    }
+
+    /// Wait until the server closes the connection, skipping anything it sends first.
+    async fn recv_eof(&mut self) -> anyhow::Result<()> {
+        let mut buf = vec![0u8; 4096];
+        while timeout(Duration::from_secs(2), self.stream.read(&mut buf)).await?? > 0 {}
+        Ok(())
+    }
+
+    /// Wait for the first packet with `msg_id`, skipping anything else.
+    async fn recv_msg<T: serde::de::DeserializeOwned>(
+        &mut self,
+        msg_id: MsgId,
+    ) -> anyhow::Result<T> {
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(2);
+
+        loop {
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                anyhow::bail!("Timeout waiting for {:?}", msg_id);
+            }
+            let remaining = deadline - now;
+
+            let mut buf = vec![0u8; 4096];
+            let n = timeout(remaining, self.stream.read(&mut buf)).await??;
+            buf.truncate(n);
+
+            let (packets, _) = try_decode_tcp_frames(&buf)?;
+            if let Some(packet) = packets.iter().find(|p| p.msg_id == msg_id) {
+                return Ok(postcard::from_bytes(packet.payload)?);
+            }
+        }
+    }
 }
 
 /// Spawn test server on a given address.
@@ -199,6 +365,13 @@ fn install_crypto_provider() {
 
 /// Spawn test server on a given address.
 async fn spawn_test_server(app_name: &str) -> (SocketAddr, mpsc::Sender<()>) {
+    spawn_test_server_with_options(app_name, ServerOptions::default()).await
+}
+
+async fn spawn_test_server_with_options(
+    app_name: &str,
+    options: ServerOptions,
+) -> (SocketAddr, mpsc::Sender<()>) {
     let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
     let (event_tx, event_rx) = mpsc::channel(1024);
 
@@ -223,7 +396,7 @@ async fn spawn_test_server(app_name: &str) -> (SocketAddr, mpsc::Sender<()>) {
     // Spawn server loop
     tokio::spawn(async move {
         tokio::select! {
-             _ = nesium_netd::run_server(event_rx) => {},
+             _ = nesium_netd::run_server_with_options(event_rx, options) => {},
              _ = shutdown_rx.recv() => {},
         }
     });
@@ -246,6 +419,58 @@ async fn test_handshake() -> anyhow::Result<()> {
 
     assert!(welcome.assigned_client_id > 0);
     assert_eq!(welcome.tick_hz, 60);
+    assert_eq!(
+        welcome.proto_version,
+        nesium_netproto::constants::PROTOCOL_VERSION
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_protocol_version_negotiation() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let (addr, _shutdown) = spawn_test_server("test_smoke_version").await;
+
+    // A version-1 client, whose `Hello` predates the capability flags.
+    #[derive(serde::Serialize)]
+    struct HelloV1 {
+        client_nonce: u32,
+        transport: TransportKind,
+        proto_min: u8,
+        proto_max: u8,
+        name: String,
+    }
+    let mut old = TestClient::connect(addr).await?;
+    let hello = HelloV1 {
+        client_nonce: 1,
+        transport: TransportKind::Tcp,
+        proto_min: 1,
+        proto_max: 1,
+        name: "Old".to_string(),
+    };
+    old.send_msg(MsgId::Hello, &hello).await?;
+    let err: ErrorMsg = old.recv_msg(MsgId::Error).await?;
+    assert_eq!(err.code, ErrorCode::IncompatibleVersion);
+
+    // Unknown capability bits are masked off; newer max versions settle on ours.
+    let mut new = TestClient::connect(addr).await?;
+    let hello = Hello {
+        client_nonce: 2,
+        transport: TransportKind::Tcp,
+        proto_min: nesium_netproto::constants::MIN_PROTOCOL_VERSION,
+        proto_max: u8::MAX,
+        name: "New".to_string(),
+        capabilities: u32::MAX,
+        user_token: None,
+    };
+    new.send_msg(MsgId::Hello, &hello).await?;
+    let welcome = new.recv_welcome().await?;
+    assert_eq!(
+        welcome.proto_version,
+        nesium_netproto::constants::PROTOCOL_VERSION
+    );
+    assert_eq!(welcome.capabilities, capability::SUPPORTED);
 
     Ok(())
 }
@@ -312,6 +537,195 @@ async fn test_input_relay() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_state_hash_relay() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let (addr, _shutdown) = spawn_test_server("test_smoke_state_hash").await;
+
+    let mut client1 = TestClient::connect(addr).await?;
+    client1.send_hello("Player1").await?;
+    client1.recv_welcome().await?;
+    client1.send_join_room(0).await?;
+    client1.recv_join_ack().await?;
+    let room_code = client1.room_id;
+
+    let mut client2 = TestClient::connect(addr).await?;
+    client2.send_hello("Player2").await?;
+    client2.recv_welcome().await?;
+    client2.send_join_room(room_code).await?;
+    client2.recv_join_ack().await?;
+
+    // Client 2 reports a hash; client 1 receives it unchanged.
+    let sent = StateHash {
+        frame: 120,
+        hash: 0x0123_4567_89ab_cdef,
+    };
+    client2.send_state_hash(&sent).await?;
+    assert_eq!(client1.recv_state_hash().await?, sent);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_broadcast_and_rate_limit() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let (addr, _shutdown) = spawn_test_server("test_smoke_chat").await;
+
+    let mut p1 = TestClient::connect(addr).await?;
+    p1.send_hello("P1").await?;
+    p1.recv_welcome().await?;
+    p1.send_join_room(0).await?;
+    p1.recv_join_ack().await?;
+    let room_code = p1.room_id;
+
+    let mut p2 = TestClient::connect(addr).await?;
+    p2.send_hello("P2").await?;
+    p2.recv_welcome().await?;
+    p2.send_join_room(room_code).await?;
+    p2.recv_join_ack().await?;
+
+    let mut spectator = TestClient::connect(addr).await?;
+    spectator.send_hello("Spectator").await?;
+    spectator.recv_welcome().await?;
+    spectator.send_join_room(room_code).await?;
+    spectator.recv_join_ack().await?;
+
+    // The server stamps the sender id and trims the text.
+    p2.send_chat(ChatBody::Text("  gg  ".into())).await?;
+    let expected = Chat {
+        client_id: p2.client_id,
+        body: ChatBody::Text("gg".into()),
+    };
+    assert_eq!(p1.recv_msg::<Chat>(MsgId::Chat).await?, expected);
+    assert_eq!(spectator.recv_msg::<Chat>(MsgId::Chat).await?, expected);
+    assert_eq!(p2.recv_msg::<Chat>(MsgId::Chat).await?, expected);
+
+    // Exhaust the burst allowance; the next message is rejected.
+    for _ in 0..5 {
+        p2.send_chat(ChatBody::Emote(1)).await?;
+    }
+    let err: ErrorMsg = p2.recv_msg(MsgId::Error).await?;
+    assert_eq!(err.code, ErrorCode::RateLimited);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_private_room_password() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let (addr, _shutdown) = spawn_test_server("test_smoke_private_room").await;
+
+    let mut host = TestClient::connect(addr).await?;
+    host.send_hello("Host").await?;
+    host.recv_welcome().await?;
+    host.send_join_room_with_password(0, Some("open sesame"))
+        .await?;
+    assert!(host.recv_join_ack().await?.ok);
+    let room_code = host.room_id;
+
+    let mut guest = TestClient::connect(addr).await?;
+    guest.send_hello("Guest").await?;
+    guest.recv_welcome().await?;
+
+    guest.send_join_room(room_code).await?;
+    let err: ErrorMsg = guest.recv_msg(MsgId::Error).await?;
+    assert_eq!(err.code, ErrorCode::PasswordRequired);
+
+    guest
+        .send_join_room_with_password(room_code, Some("open sesame!"))
+        .await?;
+    let err: ErrorMsg = guest.recv_msg(MsgId::Error).await?;
+    assert_eq!(err.code, ErrorCode::WrongPassword);
+
+    guest
+        .send_join_room_with_password(room_code, Some("open sesame"))
+        .await?;
+    let ack = guest.recv_join_ack().await?;
+    assert!(ack.ok);
+    assert_eq!(ack.player_index, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lobby_list_and_quick_match() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let (addr, _shutdown) = spawn_test_server("test_smoke_lobby").await;
+    let filter = RoomFilter {
+        game_hash: Some(0x1234_5678),
+        region: None,
+    };
+
+    let mut host = TestClient::connect(addr).await?;
+    host.send_hello("Host").await?;
+    host.recv_welcome().await?;
+    host.send_join_room(0).await?;
+    host.recv_join_ack().await?;
+    let room_code = host.room_id;
+    let info = RoomInfo {
+        name: "Friday night".into(),
+        region: "eu".into(),
+        game_hash: 0x1234_5678,
+    };
+    host.send_msg(MsgId::SetRoomInfo, &SetRoomInfo { info: info.clone() })
+        .await?;
+    host.sync_with_server().await?;
+
+    let mut guest = TestClient::connect(addr).await?;
+    guest.send_hello("Guest").await?;
+    guest.recv_welcome().await?;
+    guest
+        .send_msg(
+            MsgId::ListRooms,
+            &ListRooms {
+                filter: filter.clone(),
+            },
+        )
+        .await?;
+    let list: RoomList = guest.recv_msg(MsgId::RoomList).await?;
+    assert_eq!(list.rooms.len(), 1);
+    assert_eq!(list.rooms[0].room_code, room_code);
+    assert_eq!(list.rooms[0].info, info);
+    assert_eq!(list.rooms[0].open_slots, 1);
+
+    let quick_match = QuickMatch {
+        filter: filter.clone(),
+        preferred_sync_mode: None,
+    };
+    guest.send_msg(MsgId::QuickMatch, &quick_match).await?;
+    let ack = guest.recv_join_ack().await?;
+    assert_eq!((ack.room_id, ack.player_index), (room_code, 1));
+
+    // Only the host may describe the room.
+    guest
+        .send_msg(
+            MsgId::SetRoomInfo,
+            &SetRoomInfo {
+                info: RoomInfo::default(),
+            },
+        )
+        .await?;
+    let err: ErrorMsg = guest.recv_msg(MsgId::Error).await?;
+    assert_eq!(err.code, ErrorCode::PermissionDenied);
+
+    // The room is full now, so a new one is created for the same game.
+    let mut late = TestClient::connect(addr).await?;
+    late.send_hello("Late").await?;
+    late.recv_welcome().await?;
+    late.send_msg(MsgId::QuickMatch, &quick_match).await?;
+    let ack = late.recv_join_ack().await?;
+    assert_ne!(ack.room_id, room_code);
+    assert_eq!(ack.player_index, 0);
+
+    late.send_msg(MsgId::ListRooms, &ListRooms { filter })
+        .await?;
+    let list: RoomList = late.recv_msg(MsgId::RoomList).await?;
+    let codes: Vec<u32> = list.rooms.iter().map(|r| r.room_code).collect();
+    assert_eq!(codes, vec![ack.room_id, room_code]);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_spectator_mode() -> anyhow::Result<()> {
     install_crypto_provider();
@@ -344,6 +758,514 @@ async fn test_spectator_mode() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_spectator_delay_and_count() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let (addr, _shutdown) = spawn_test_server("test_smoke_spectator_delay").await;
+
+    let mut p1 = TestClient::connect(addr).await?;
+    p1.send_hello("P1").await?;
+    p1.recv_welcome().await?;
+    p1.send_join_room(0).await?;
+    p1.recv_join_ack().await?;
+    let room_code = p1.room_id;
+
+    p1.send_msg(MsgId::SetSpectatorDelay, &SetSpectatorDelay { frames: 4 })
+        .await?;
+    let info: SpectatorInfo = p1.recv_msg(MsgId::SpectatorInfo).await?;
+    assert_eq!(
+        info,
+        SpectatorInfo {
+            count: 0,
+            delay_frames: 4
+        }
+    );
+
+    let mut p2 = TestClient::connect(addr).await?;
+    p2.send_hello("P2").await?;
+    p2.recv_welcome().await?;
+    p2.send_join_room(room_code).await?;
+    p2.recv_join_ack().await?;
+    p2.sync_with_server().await?;
+
+    let mut spectator = TestClient::connect(addr).await?;
+    spectator.send_hello("Spectator").await?;
+    spectator.recv_welcome().await?;
+    spectator.send_join_room(room_code).await?;
+    spectator.recv_join_ack().await?;
+    let expected = SpectatorInfo {
+        count: 1,
+        delay_frames: 4,
+    };
+    assert_eq!(
+        p1.recv_msg::<SpectatorInfo>(MsgId::SpectatorInfo).await?,
+        expected
+    );
+    assert_eq!(
+        p2.recv_msg::<SpectatorInfo>(MsgId::SpectatorInfo).await?,
+        expected
+    );
+
+    // Frames 0..=3: live is not yet 4 frames ahead of anything.
+    for start_frame in [0, 2] {
+        let batch = InputBatch {
+            start_frame,
+            buttons: vec![1, 1],
+        };
+        p1.send_msg(MsgId::InputBatch, &batch).await?;
+    }
+    p1.sync_with_server().await?;
+    assert!(
+        spectator
+            .drain_msgs::<RelayInputs>(MsgId::RelayInputs)
+            .await?
+            .is_empty()
+    );
+
+    // Live frame 7 releases frames up to 3.
+    let batch = InputBatch {
+        start_frame: 4,
+        buttons: vec![2; 4],
+    };
+    p1.send_msg(MsgId::InputBatch, &batch).await?;
+    p1.sync_with_server().await?;
+    let relayed = spectator
+        .drain_msgs::<RelayInputs>(MsgId::RelayInputs)
+        .await?;
+    let frames: Vec<_> = relayed.iter().map(|r| r.base_frame).collect();
+    assert_eq!(frames, [0, 2]);
+
+    // Dropping the delay flushes the rest.
+    p1.send_msg(MsgId::SetSpectatorDelay, &SetSpectatorDelay { frames: 0 })
+        .await?;
+    p1.sync_with_server().await?;
+    let relayed = spectator
+        .drain_msgs::<RelayInputs>(MsgId::RelayInputs)
+        .await?;
+    assert_eq!(relayed.len(), 1);
+    assert_eq!(relayed[0].base_frame, 4);
+    assert_eq!(relayed[0].buttons, [2; 4]);
+
+    // Only the host sets the delay.
+    p2.send_msg(MsgId::SetSpectatorDelay, &SetSpectatorDelay { frames: 60 })
+        .await?;
+    let err: ErrorMsg = p2.recv_msg(MsgId::Error).await?;
+    assert!(matches!(err.code, ErrorCode::PermissionDenied));
+
+    drop(spectator);
+    let info: SpectatorInfo = p1.recv_msg(MsgId::SpectatorInfo).await?;
+    assert_eq!(info.count, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_persistent_store() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let db_path = std::env::temp_dir().join(format!("nesium_smoke_{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&db_path);
+    let options = || -> anyhow::Result<ServerOptions> {
+        Ok(ServerOptions {
+            store: RoomStore::open(&db_path)?,
+            ..Default::default()
+        })
+    };
+
+    let (addr, shutdown) = spawn_test_server_with_options("test_smoke_store", options()?).await;
+
+    // Fresh clients are issued identities; a named room is saved.
+    let mut host = TestClient::connect(addr).await?;
+    host.send_hello("Host").await?;
+    let host_identity = host.recv_welcome().await?.identity.expect("identity");
+    host.send_join_room(0).await?;
+    host.recv_join_ack().await?;
+    let room_code = host.room_id;
+    let info = RoomInfo {
+        name: "Weekly".into(),
+        ..Default::default()
+    };
+    host.send_msg(MsgId::SetRoomInfo, &SetRoomInfo { info })
+        .await?;
+    host.sync_with_server().await?;
+
+    let mut troll = TestClient::connect(addr).await?;
+    troll.send_hello("Troll").await?;
+    let troll_identity = troll.recv_welcome().await?.identity.expect("identity");
+    assert_ne!(troll_identity.user_id, host_identity.user_id);
+
+    drop(host);
+    drop(troll);
+    shutdown.send(()).await?;
+    RoomStore::open(&db_path)?.save_ban(&Ban {
+        target: BanTarget::User(troll_identity.user_id),
+        reason: "griefing".into(),
+        expires_at: None,
+    });
+
+    // After a restart the token maps to the same user and the room reopens.
+    let (addr, _shutdown) = spawn_test_server_with_options("test_smoke_store", options()?).await;
+    let mut host = TestClient::connect(addr).await?;
+    host.send_hello_with_token("Host", Some(host_identity.token))
+        .await?;
+    let identity = host.recv_welcome().await?.identity.expect("identity");
+    assert_eq!(identity.user_id, host_identity.user_id);
+    host.send_join_room(room_code).await?;
+    let ack = host.recv_join_ack().await?;
+    assert!(ack.ok);
+    assert_eq!(ack.room_id, room_code);
+    assert_eq!(ack.player_index, 0);
+
+    let mut troll = TestClient::connect(addr).await?;
+    troll
+        .send_hello_with_token("Troll", Some(troll_identity.token))
+        .await?;
+    let err: ErrorMsg = troll.recv_msg(MsgId::Error).await?;
+    assert_eq!(err.code, ErrorCode::Banned);
+
+    let _ = std::fs::remove_file(&db_path);
+    Ok(())
+}
+
+/// Send one admin API request and return the status code and JSON body.
+async fn admin_request(
+    addr: SocketAddr,
+    method: &str,
+    path: &str,
+    token: &str,
+) -> anyhow::Result<(u16, serde_json::Value)> {
+    let mut stream = TcpStream::connect(addr).await?;
+    let request = format!(
+        "{method} {path} HTTP/1.1\r\nHost: localhost\r\nAuthorization: Bearer {token}\r\n\r\n"
+    );
+    stream.write_all(request.as_bytes()).await?;
+    let mut response = String::new();
+    timeout(Duration::from_secs(2), stream.read_to_string(&mut response)).await??;
+
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| anyhow::anyhow!("malformed response"))?;
+    let status = head
+        .split(' ')
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| anyhow::anyhow!("missing status"))?;
+    Ok((status, serde_json::from_str(body)?))
+}
+
+#[tokio::test]
+async fn test_admin_api() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let admin_addr = listener.local_addr()?;
+    let (admin_tx, admin_rx) = nesium_netd::admin::channel();
+    tokio::spawn(async move {
+        let _ = run_admin_listener_with_listener(listener, "t0ken", admin_tx).await;
+    });
+    let options = ServerOptions {
+        admin: Some(admin_rx),
+        ..Default::default()
+    };
+    let (addr, _shutdown) = spawn_test_server_with_options("test_smoke_admin", options).await;
+
+    let mut host = TestClient::connect(addr).await?;
+    host.send_hello("Host").await?;
+    host.recv_welcome().await?;
+    host.send_join_room(0).await?;
+    host.recv_join_ack().await?;
+    let room_id = host.room_id;
+
+    let mut guest = TestClient::connect(addr).await?;
+    guest.send_hello("Guest").await?;
+    guest.recv_welcome().await?;
+    guest.send_join_room(room_id).await?;
+    guest.recv_join_ack().await?;
+
+    let (status, _) = admin_request(admin_addr, "GET", "/rooms", "wrong").await?;
+    assert_eq!(status, 401);
+
+    let (status, rooms) = admin_request(admin_addr, "GET", "/rooms", "t0ken").await?;
+    assert_eq!(status, 200);
+    assert_eq!(rooms[0]["room_id"], room_id);
+    let players: Vec<&str> = rooms[0]["players"]
+        .as_array()
+        .expect("players")
+        .iter()
+        .filter_map(|p| p["name"].as_str())
+        .collect();
+    assert_eq!(players, ["Host", "Guest"]);
+
+    // Per-connection and total rate-limit drops.
+    for _ in 0..6 {
+        guest.send_chat(ChatBody::Emote(1)).await?;
+    }
+    let err: ErrorMsg = guest.recv_msg(MsgId::Error).await?;
+    assert_eq!(err.code, ErrorCode::RateLimited);
+    let (_, conns) = admin_request(admin_addr, "GET", "/connections", "t0ken").await?;
+    let guest_conn = conns
+        .as_array()
+        .expect("connections")
+        .iter()
+        .find(|c| c["client_id"] == guest.client_id)
+        .expect("guest connection");
+    assert_eq!(guest_conn["role"], "control");
+    assert_eq!(guest_conn["room_id"], room_id);
+    assert_eq!(guest_conn["rate_limited"], 1);
+    let (_, limits) = admin_request(admin_addr, "GET", "/rate-limits", "t0ken").await?;
+    assert!(limits["chat"].as_u64().expect("chat count") >= 1);
+
+    // Kicking drops the connection and the room sees the player leave.
+    let path = format!("/clients/{}/kick", guest.client_id);
+    let (status, _) = admin_request(admin_addr, "POST", &path, "t0ken").await?;
+    assert_eq!(status, 200);
+    guest.recv_eof().await?;
+    let left: PlayerLeft = host.recv_msg(MsgId::PlayerLeft).await?;
+    assert_eq!(left.client_id, guest.client_id);
+    let (status, _) = admin_request(admin_addr, "POST", &path, "t0ken").await?;
+    assert_eq!(status, 404);
+
+    // Closing the room drops everyone in it.
+    let path = format!("/rooms/{room_id}/close");
+    let (status, _) = admin_request(admin_addr, "POST", &path, "t0ken").await?;
+    assert_eq!(status, 200);
+    host.recv_eof().await?;
+    let (_, rooms) = admin_request(admin_addr, "GET", "/rooms", "t0ken").await?;
+    assert_eq!(rooms, serde_json::json!([]));
+
+    // Banned addresses are refused on connect until the ban is lifted.
+    let path = "/bans/ip:127.0.0.1";
+    let (status, _) = admin_request(admin_addr, "POST", path, "t0ken").await?;
+    assert_eq!(status, 200);
+    let (_, bans) = admin_request(admin_addr, "GET", "/bans", "t0ken").await?;
+    assert_eq!(bans[0]["target"], "ip:127.0.0.1");
+    let mut refused = TestClient::connect(addr).await?;
+    refused.send_hello("Banned").await?;
+    refused.recv_eof().await?;
+    let (status, _) = admin_request(admin_addr, "DELETE", path, "t0ken").await?;
+    assert_eq!(status, 200);
+    let (status, _) = admin_request(admin_addr, "DELETE", path, "t0ken").await?;
+    assert_eq!(status, 404);
+    let mut client = TestClient::connect(addr).await?;
+    client.send_hello("Forgiven").await?;
+    client.recv_welcome().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ip_abuse_controls() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let options = ServerOptions {
+        settings: ServerSettings {
+            max_connections_per_ip: 2,
+            handshake_failures_allowed: 1,
+            handshake_backoff_ms: 60_000,
+            ..ServerSettings::default()
+        },
+        ..ServerOptions::default()
+    };
+    let (addr, _shutdown) = spawn_test_server_with_options("test_smoke_ip_guard", options).await;
+
+    // One address may only hold so many connections.
+    let mut first = TestClient::connect(addr).await?;
+    first.send_hello("First").await?;
+    first.recv_welcome().await?;
+    let mut second = TestClient::connect(addr).await?;
+    second.send_hello("Second").await?;
+    second.recv_welcome().await?;
+    let mut refused = TestClient::connect(addr).await?;
+    refused.send_hello("Third").await?;
+    refused.recv_eof().await?;
+
+    // Connections dropped before `Hello` count as failed handshakes; past the
+    // allowance the address is refused for a while.
+    drop(first);
+    drop(second);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    for _ in 0..2 {
+        drop(TestClient::connect(addr).await?);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    let mut refused = TestClient::connect(addr).await?;
+    refused.send_hello("Retry").await?;
+    refused.recv_eof().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_graceful_shutdown() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let shutdown = CancellationToken::new();
+    let options = ServerOptions {
+        shutdown: shutdown.clone(),
+        drain_timeout: Some(Duration::from_millis(500)),
+        ..ServerOptions::default()
+    };
+    let (addr, _shutdown) = spawn_test_server_with_options("test_smoke_shutdown", options).await;
+
+    let mut p1 = TestClient::connect(addr).await?;
+    p1.send_hello("P1").await?;
+    p1.recv_welcome().await?;
+    p1.send_join_room(0).await?;
+    p1.recv_join_ack().await?;
+    let mut p2 = TestClient::connect(addr).await?;
+    p2.send_hello("P2").await?;
+    p2.recv_welcome().await?;
+    p2.send_join_room(p1.room_id).await?;
+    p2.recv_join_ack().await?;
+
+    // Everyone is warned, and the game keeps being relayed while draining.
+    shutdown.cancel();
+    for client in [&mut p1, &mut p2] {
+        let notice: ServerClosing = client.recv_msg(MsgId::ServerClosing).await?;
+        assert_eq!(notice.grace_ms, 500);
+    }
+    p1.send_input(0x01).await?;
+    assert_eq!(p2.recv_relay_inputs().await?, MsgId::RelayInputs);
+
+    // New connections are refused, and stragglers are dropped after the timeout.
+    let mut late = TestClient::connect(addr).await?;
+    late.send_hello("Late").await?;
+    late.recv_eof().await?;
+    p1.recv_eof().await?;
+    p2.recv_eof().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_relay_quota() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let hash = StateHash {
+        frame: 60,
+        hash: 0xfeed,
+    };
+    let packet_len = HEADER_LEN + postcard::to_allocvec(&hash)?.len();
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let admin_addr = listener.local_addr()?;
+    let (admin_tx, admin_rx) = nesium_netd::admin::channel();
+    tokio::spawn(async move {
+        let _ = run_admin_listener_with_listener(listener, "t0ken", admin_tx).await;
+    });
+    let options = ServerOptions {
+        admin: Some(admin_rx),
+        settings: ServerSettings {
+            client_relay_quota_bytes: packet_len as u64,
+            ..ServerSettings::default()
+        },
+        ..ServerOptions::default()
+    };
+    let (addr, _shutdown) = spawn_test_server_with_options("test_smoke_relay_quota", options).await;
+
+    let mut p1 = TestClient::connect(addr).await?;
+    p1.send_hello("P1").await?;
+    p1.recv_welcome().await?;
+    p1.send_join_room(0).await?;
+    p1.recv_join_ack().await?;
+    let mut p2 = TestClient::connect(addr).await?;
+    p2.send_hello("P2").await?;
+    p2.recv_welcome().await?;
+    p2.send_join_room(p1.room_id).await?;
+    p2.recv_join_ack().await?;
+
+    // The first hash uses up P1's quota; the second is dropped.
+    p1.send_state_hash(&hash).await?;
+    p1.send_state_hash(&hash).await?;
+    let err: ErrorMsg = p1.recv_msg(MsgId::Error).await?;
+    assert_eq!(err.code, ErrorCode::QuotaExceeded);
+    let relayed: Vec<StateHash> = p2.drain_msgs(MsgId::StateHash).await?;
+    assert_eq!(relayed, [hash]);
+
+    let (_, rooms) = admin_request(admin_addr, "GET", "/rooms", "t0ken").await?;
+    assert_eq!(rooms[0]["relayed_bytes"], packet_len);
+    let (_, conns) = admin_request(admin_addr, "GET", "/connections", "t0ken").await?;
+    let p1_conn = conns
+        .as_array()
+        .expect("connections")
+        .iter()
+        .find(|c| c["client_id"] == p1.client_id)
+        .expect("p1 connection");
+    assert_eq!(p1_conn["relayed_bytes"], packet_len);
+    assert_eq!(p1_conn["rate_limited"], 1);
+    let (_, limits) = admin_request(admin_addr, "GET", "/rate-limits", "t0ken").await?;
+    assert!(limits["relay"].as_u64().expect("relay count") >= 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_prometheus_metrics() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let metrics_addr = listener.local_addr()?;
+    tokio::spawn(run_metrics_listener_with_listener(listener));
+    let (addr, _shutdown) = spawn_test_server("test_smoke_metrics").await;
+
+    let mut client = TestClient::connect(addr).await?;
+    client.send_hello("Scraped").await?;
+    client.recv_welcome().await?;
+
+    let mut stream = TcpStream::connect(metrics_addr).await?;
+    stream
+        .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+        .await?;
+    let mut response = String::new();
+    timeout(Duration::from_secs(2), stream.read_to_string(&mut response)).await??;
+
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    let sample = |prefix: &str| -> Option<u64> {
+        response
+            .lines()
+            .find_map(|l| l.strip_prefix(prefix)?.trim().parse().ok())
+    };
+    assert!(sample("nesium_netd_packets_total{msg_id=\"Hello\"}").expect("Hello count") >= 1);
+    assert!(sample("nesium_netd_connections{transport=\"tcp\"}").expect("tcp count") >= 1);
+    assert!(sample("nesium_netd_sent_bytes_total{transport=\"tcp\"}").expect("sent") > 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_room_limit_and_reload() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let settings = ServerSettings {
+        max_rooms: 1,
+        ..ServerSettings::default()
+    };
+    let (reload_tx, reload_rx) = tokio::sync::watch::channel(settings.clone());
+    let options = ServerOptions {
+        settings,
+        reload: Some(reload_rx),
+        ..ServerOptions::default()
+    };
+    let (addr, _shutdown) = spawn_test_server_with_options("test_smoke_room_limit", options).await;
+
+    let mut first = TestClient::connect(addr).await?;
+    first.send_hello("First").await?;
+    first.recv_welcome().await?;
+    first.send_join_room(0).await?;
+    first.recv_join_ack().await?;
+
+    let mut second = TestClient::connect(addr).await?;
+    second.send_hello("Second").await?;
+    second.recv_welcome().await?;
+    second.send_join_room(0).await?;
+    let err: ErrorMsg = second.recv_msg(MsgId::Error).await?;
+    assert_eq!(err.code, ErrorCode::ServerFull);
+
+    // Raising the limit takes effect without a restart.
+    reload_tx.send_replace(ServerSettings {
+        max_rooms: 2,
+        ..ServerSettings::default()
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    second.send_join_room(0).await?;
+    assert!(second.recv_join_ack().await?.ok);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_unique_client_ids() -> anyhow::Result<()> {
     install_crypto_provider();
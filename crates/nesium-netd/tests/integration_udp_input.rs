@@ -0,0 +1,183 @@
+//! Integration tests for the UDP datagram input channel.
+//!
+//! Tests:
+//! - Attaching a datagram channel with the session token
+//! - Redundant RelayInputs datagrams carrying recent input history
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use nesium_netd::net::{
+    quic_config, tcp::run_tcp_listener_with_listener, udp::run_udp_listener_with_socket,
+};
+use nesium_netproto::{
+    channel::ChannelKind,
+    codec_tcp::{encode_tcp_frame, try_decode_tcp_frames},
+    codec_udp::{decode_udp, encode_udp},
+    header::Header,
+    messages::{
+        input::{InputBatch, RelayInputs},
+        session::{AttachChannel, Hello, JoinAck, JoinRoom, TransportKind, Welcome},
+    },
+    msg_id::MsgId,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpStream, UdpSocket},
+    sync::mpsc,
+    time::timeout,
+};
+
+fn install_crypto_provider() {
+    let _ = tokio_rustls::rustls::crypto::ring::default_provider().install_default();
+}
+
+/// Spawn a server with TCP and UDP listeners; returns both addresses.
+async fn spawn_test_server(app_name: &str) -> (SocketAddr, SocketAddr) {
+    let (event_tx, event_rx) = mpsc::channel(1024);
+
+    let cert_dir = quic_config::default_quic_data_dir(app_name);
+    if cert_dir.exists() {
+        let _ = std::fs::remove_dir_all(&cert_dir);
+    }
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let tcp_addr = listener.local_addr().unwrap();
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let udp_addr = socket.local_addr().unwrap();
+
+    let tx_tcp = event_tx.clone();
+    let app_name_owned = app_name.to_string();
+    tokio::spawn(async move {
+        let _ = run_tcp_listener_with_listener(listener, tx_tcp, &app_name_owned).await;
+    });
+    tokio::spawn(async move {
+        let _ = run_udp_listener_with_socket(socket, event_tx).await;
+    });
+    tokio::spawn(nesium_netd::run_server(event_rx));
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    (tcp_addr, udp_addr)
+}
+
+async fn send<T: serde::Serialize>(
+    stream: &mut TcpStream,
+    msg_id: MsgId,
+    msg: &T,
+) -> anyhow::Result<()> {
+    let frame = encode_tcp_frame(Header::new(msg_id as u8), msg_id, msg, 4096)?;
+    stream.write_all(&frame).await?;
+    Ok(())
+}
+
+async fn recv<T: serde::de::DeserializeOwned>(
+    stream: &mut TcpStream,
+    msg_id: MsgId,
+) -> anyhow::Result<T> {
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(2);
+    loop {
+        let mut buf = vec![0u8; 4096];
+        let n = timeout(
+            deadline.saturating_duration_since(tokio::time::Instant::now()),
+            stream.read(&mut buf),
+        )
+        .await??;
+        let (packets, _) = try_decode_tcp_frames(&buf[..n])?;
+        if let Some(packet) = packets.iter().find(|p| p.msg_id == msg_id) {
+            return Ok(postcard::from_bytes(packet.payload)?);
+        }
+    }
+}
+
+/// Handshake and join `room_code` (0 creates a room). Returns the session
+/// token and the joined room code.
+async fn join(stream: &mut TcpStream, name: &str, room_code: u32) -> anyhow::Result<(u64, u32)> {
+    let hello = Hello {
+        client_nonce: 1,
+        transport: TransportKind::Tcp,
+        proto_min: nesium_netproto::constants::MIN_PROTOCOL_VERSION,
+        proto_max: nesium_netproto::constants::PROTOCOL_VERSION,
+        name: name.to_string(),
+        capabilities: nesium_netproto::messages::session::capability::SUPPORTED,
+        user_token: None,
+    };
+    send(stream, MsgId::Hello, &hello).await?;
+    let welcome: Welcome = recv(stream, MsgId::Welcome).await?;
+
+    let join = JoinRoom {
+        room_code,
+        preferred_sync_mode: None,
+        password: None,
+    };
+    send(stream, MsgId::JoinRoom, &join).await?;
+    let ack: JoinAck = recv(stream, MsgId::JoinAck).await?;
+    assert!(ack.ok);
+    Ok((welcome.session_token, ack.room_id))
+}
+
+async fn recv_datagram(socket: &UdpSocket, msg_id: MsgId) -> anyhow::Result<Vec<u8>> {
+    let mut buf = vec![0u8; 2048];
+    loop {
+        let n = timeout(Duration::from_secs(2), socket.recv(&mut buf)).await??;
+        let view = decode_udp(&buf[..n])?;
+        if view.msg_id == msg_id {
+            return Ok(view.payload.to_vec());
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_datagram_relay_repeats_recent_inputs() -> anyhow::Result<()> {
+    install_crypto_provider();
+    let (tcp_addr, udp_addr) = spawn_test_server("test_udp_input_relay").await;
+
+    let mut p1 = TcpStream::connect(tcp_addr).await?;
+    let (_, room_code) = join(&mut p1, "P1", 0).await?;
+    let mut p2 = TcpStream::connect(tcp_addr).await?;
+    let (p2_token, _) = join(&mut p2, "P2", room_code).await?;
+
+    // P2 attaches a datagram channel and waits for the echo.
+    let udp = UdpSocket::bind("127.0.0.1:0").await?;
+    udp.connect(udp_addr).await?;
+    let attach = AttachChannel {
+        session_token: p2_token,
+        channel: ChannelKind::Datagram,
+    };
+    let datagram = encode_udp(
+        Header::new(MsgId::AttachChannel as u8),
+        MsgId::AttachChannel,
+        &attach,
+        1200,
+    )?;
+    udp.send(&datagram).await?;
+    let echo: AttachChannel =
+        postcard::from_bytes(&recv_datagram(&udp, MsgId::AttachChannel).await?)?;
+    assert_eq!(echo.channel, ChannelKind::Datagram);
+
+    // P1 sends two batches over TCP.
+    for (start_frame, buttons) in [(0, vec![1, 2, 3]), (3, vec![4])] {
+        send(
+            &mut p1,
+            MsgId::InputBatch,
+            &InputBatch {
+                start_frame,
+                buttons,
+            },
+        )
+        .await?;
+    }
+
+    // The second datagram repeats the first batch as well.
+    let first: RelayInputs = postcard::from_bytes(&recv_datagram(&udp, MsgId::RelayInputs).await?)?;
+    assert_eq!((first.base_frame, first.buttons), (0, vec![1, 2, 3]));
+    let second: RelayInputs =
+        postcard::from_bytes(&recv_datagram(&udp, MsgId::RelayInputs).await?)?;
+    assert_eq!(second.player_index, 0);
+    assert_eq!((second.base_frame, second.buttons), (0, vec![1, 2, 3, 4]));
+
+    // The reliable channel still carries every batch.
+    let relay: RelayInputs = recv(&mut p2, MsgId::RelayInputs).await?;
+    assert_eq!(relay.base_frame, 0);
+
+    Ok(())
+}
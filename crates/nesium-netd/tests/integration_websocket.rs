@@ -4,7 +4,10 @@ use std::sync::Arc;
 use bytes::Bytes;
 use futures_util::{SinkExt, StreamExt};
 use nesium_netd::net::tcp::run_tcp_listener_with_listener;
-use nesium_netd::net::{inbound::InboundEvent, quic_config};
+use nesium_netd::net::{
+    inbound::{InboundEvent, TransportKind},
+    quic_config,
+};
 use nesium_netproto::{
     codec_tcp::encode_tcp_frame, header::Header, messages::sync::Ping, msg_id::MsgId,
 };
@@ -44,7 +47,11 @@ async fn test_ws_connection() {
         .expect("Connect failed");
 
     // 1. Send data from Client to Server
-    let ping = Ping { t_ms: 42 };
+    let ping = Ping {
+        seq: 0,
+        t_ms: 42,
+        peer_client_id: 0,
+    };
     let header = Header::new(MsgId::Ping as u8);
     let frame_bytes = encode_tcp_frame(header, MsgId::Ping, &ping, 1024).unwrap();
 
@@ -121,7 +128,11 @@ async fn test_wss_connection() {
             .expect("Connect failed");
 
     // 1. Send
-    let ping = Ping { t_ms: 99 };
+    let ping = Ping {
+        seq: 0,
+        t_ms: 99,
+        peer_client_id: 0,
+    };
     let header = Header::new(MsgId::Ping as u8);
     let frame_bytes = encode_tcp_frame(header, MsgId::Ping, &ping, 1024).unwrap();
 
@@ -158,3 +169,54 @@ async fn test_wss_connection() {
         panic!("Expected binary, got {:?}", msg);
     }
 }
+
+#[tokio::test]
+async fn test_data_channel_connection() {
+    let (tx, mut rx) = mpsc::channel(100);
+    let (to_server, incoming) = mpsc::channel(16);
+    let (outgoing, mut from_server) = mpsc::channel(16);
+    tokio::spawn(nesium_netd::net::datachannel::handle_data_channel(
+        incoming, outgoing, tx,
+    ));
+
+    // 1. A frame split across two data channel messages.
+    let ping = Ping {
+        seq: 0,
+        t_ms: 7,
+        peer_client_id: 0,
+    };
+    let header = Header::new(MsgId::Ping as u8);
+    let frame_bytes = Bytes::from(encode_tcp_frame(header, MsgId::Ping, &ping, 1024).unwrap());
+    to_server.send(frame_bytes.slice(..3)).await.unwrap();
+    to_server.send(frame_bytes.slice(3..)).await.unwrap();
+
+    // 2. Server sees a WebRTC connection and the reassembled packet.
+    let Some(InboundEvent::Connected {
+        outbound,
+        transport,
+        ..
+    }) = rx.recv().await
+    else {
+        panic!("Expected Connected event");
+    };
+    assert_eq!(transport, TransportKind::WebRtc);
+    let Some(InboundEvent::Packet { packet, .. }) = rx.recv().await else {
+        panic!("Expected Packet event");
+    };
+    let received_ping: Ping = postcard::from_bytes(&packet.payload).unwrap();
+    assert_eq!(received_ping.t_ms, 7);
+
+    // 3. Replies come out as data channel messages.
+    outbound.send(Bytes::from_static(b"reply")).await.unwrap();
+    assert_eq!(
+        from_server.recv().await.unwrap(),
+        Bytes::from_static(b"reply")
+    );
+
+    // 4. Closing the channel disconnects.
+    drop(to_server);
+    assert!(matches!(
+        rx.recv().await,
+        Some(InboundEvent::Disconnected { .. })
+    ));
+}
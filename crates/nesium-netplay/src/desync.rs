@@ -0,0 +1,126 @@
+//! Desync detection from periodic state hashes.
+//!
+//! Every room member reports `Nes::state_hash()` for the same confirmed
+//! frames (see `NetplayConfig::state_hash_interval`). [`StateHashLog`] pairs
+//! the local hash of a frame with the ones relayed from other members, in
+//! whichever order they arrive.
+
+use std::collections::BTreeMap;
+
+/// Frames kept per side. Peers run at most a few frames apart, so older
+/// hashes will never find a partner.
+const MAX_TRACKED_FRAMES: usize = 64;
+
+/// Local and remote state hashes of recent frames.
+#[derive(Debug, Default)]
+pub struct StateHashLog {
+    local: BTreeMap<u32, u64>,
+    remote: BTreeMap<u32, u64>,
+    /// First frame found to differ. Later mismatches are expected until the
+    /// state is resynchronized, so they are not reported again.
+    desynced_at: Option<u32>,
+}
+
+impl StateHashLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records our own hash for `frame`. Returns the frame if this reveals a
+    /// new desync.
+    pub fn record_local(&mut self, frame: u32, hash: u64) -> Option<u32> {
+        Self::insert(&mut self.local, frame, hash);
+        let remote = self.remote.get(&frame).copied();
+        remote.and_then(|remote| self.check(frame, hash, remote))
+    }
+
+    /// Records a hash relayed from another room member. Returns the frame if
+    /// it differs from ours, or from another member's hash for the same
+    /// frame when ours isn't known yet.
+    pub fn record_remote(&mut self, frame: u32, hash: u64) -> Option<u32> {
+        if let Some(&local) = self.local.get(&frame) {
+            return self.check(frame, local, hash);
+        }
+        match self.remote.get(&frame).copied() {
+            Some(other) => self.check(frame, other, hash),
+            None => {
+                Self::insert(&mut self.remote, frame, hash);
+                None
+            }
+        }
+    }
+
+    /// Frame of the detected desync, if any.
+    pub fn desynced_at(&self) -> Option<u32> {
+        self.desynced_at
+    }
+
+    /// Forgets all hashes, e.g. after a state load or reset changed the
+    /// timeline.
+    pub fn clear(&mut self) {
+        self.local.clear();
+        self.remote.clear();
+        self.desynced_at = None;
+    }
+
+    fn check(&mut self, frame: u32, a: u64, b: u64) -> Option<u32> {
+        if a == b || self.desynced_at.is_some() {
+            return None;
+        }
+        self.desynced_at = Some(frame);
+        Some(frame)
+    }
+
+    fn insert(map: &mut BTreeMap<u32, u64>, frame: u32, hash: u64) {
+        map.insert(frame, hash);
+        while map.len() > MAX_TRACKED_FRAMES {
+            map.pop_first();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matching_hashes_in_either_order() {
+        let mut log = StateHashLog::new();
+        assert_eq!(log.record_local(120, 7), None);
+        assert_eq!(log.record_remote(120, 7), None);
+        assert_eq!(log.record_remote(240, 9), None);
+        assert_eq!(log.record_local(240, 9), None);
+        assert_eq!(log.desynced_at(), None);
+    }
+
+    #[test]
+    fn mismatch_is_reported_once_until_cleared() {
+        let mut log = StateHashLog::new();
+        log.record_remote(120, 1);
+        assert_eq!(log.record_local(120, 2), Some(120));
+        log.record_local(240, 3);
+        assert_eq!(log.record_remote(240, 4), None);
+        assert_eq!(log.desynced_at(), Some(120));
+
+        log.clear();
+        log.record_local(360, 5);
+        assert_eq!(log.record_remote(360, 6), Some(360));
+    }
+
+    #[test]
+    fn remote_members_disagreeing_is_a_desync() {
+        let mut log = StateHashLog::new();
+        assert_eq!(log.record_remote(120, 1), None);
+        assert_eq!(log.record_remote(120, 2), Some(120));
+    }
+
+    #[test]
+    fn old_frames_are_dropped() {
+        let mut log = StateHashLog::new();
+        for frame in 0..=MAX_TRACKED_FRAMES as u32 {
+            log.record_remote(frame, 1);
+        }
+        assert_eq!(log.record_local(0, 2), None);
+        assert_eq!(log.record_local(1, 2), Some(1));
+    }
+}
@@ -42,4 +42,12 @@ pub enum NetplayError {
 
     #[error("session already active")]
     AlreadyConnected,
+
+    #[error("NAT traversal failed: {0}")]
+    PunchFailed(String),
+
+    #[error(
+        "protocol version mismatch: the server speaks version {server}, this client supports {min}-{max}"
+    )]
+    VersionMismatch { server: u8, min: u8, max: u8 },
 }
@@ -5,37 +5,62 @@
 //! - Room joining
 //! - Input relay
 //! - State synchronization
+//! - Desync detection
+//! - Room chat
+//! - RTT measurement and input delay tuning
 
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use nesium_netproto::{
     channel::ChannelKind,
-    constants::SPECTATOR_PLAYER_INDEX,
+    constants::{MIN_PROTOCOL_VERSION, PROTOCOL_VERSION, SPECTATOR_PLAYER_INDEX},
     header::Header,
     messages::{
+        chat::{Chat, ChatBody},
         input::{InputBatch, RelayInputs},
+        lobby::{ListRooms, QuickMatch, RoomFilter, RoomInfo, RoomList, RoomSummary, SetRoomInfo},
         session::{
-            BeginCatchUp, ErrorCode, ErrorMsg, FallbackToRelay, Hello, JoinAck, JoinRoom, LoadRom,
-            PauseGame, PauseSync, ProvideState, RequestFallbackRelay, RequestState, ResetGame,
-            ResetSync, RomLoaded, StartGame, SyncMode as ProtoSyncMode, SyncState, TransportKind,
-            Welcome,
+            BeginCatchUp, CaptureState, ErrorCode, ErrorMsg, FallbackToRelay, Hello, JoinAck,
+            JoinRoom, LoadRom, PauseGame, PauseSync, ProvideState, RequestFallbackRelay,
+            RequestState, ResetGame, ResetSync, RomHash, RomLoaded, ServerClosing,
+            SetSpectatorDelay, SpectatorInfo, StartGame, SyncMode as ProtoSyncMode, SyncState,
+            TransportKind, UserIdentity, UserToken, Welcome, capability,
         },
-        sync::{Ping, Pong},
+        sync::{Ping, Pong, StateHash},
     },
     msg_id::MsgId,
 };
-use tokio::sync::mpsc;
+use serde::Deserialize;
+use tokio::{sync::mpsc, time::MissedTickBehavior};
 use tracing::{debug, error, info, warn};
 
 use crate::{
+    desync::StateHashLog,
     error::NetplayError,
     input_provider::SharedInputProvider,
-    session::SessionState,
-    sync::SyncMode as ClientSyncMode,
+    session::{PeerNetworkStats, SessionState},
+    sync::{SyncMode as ClientSyncMode, delay::InputDelayTuner},
     tcp_client::{PacketOwned, TcpClientEvent, TcpClientHandle},
 };
 
+/// The prefix of [`Welcome`] up to `proto_version`, decoded first so a
+/// server on another protocol version is reported as such rather than as a
+/// malformed packet.
+#[derive(Deserialize)]
+struct WelcomeVersion {
+    _server_nonce: u32,
+    _session_token: u64,
+    _assigned_client_id: u32,
+    _room_id: u32,
+    _tick_hz: u16,
+    _input_delay_frames: u8,
+    _max_payload: u16,
+    _rewind_capacity: u32,
+    proto_version: u8,
+}
+
 /// Configuration for starting a netplay session.
 #[derive(Debug, Clone)]
 pub struct NetplayConfig {
@@ -47,6 +72,21 @@ pub struct NetplayConfig {
     pub spectator: bool,
     /// Room code to join (0 = create new room).
     pub room_code: u32,
+    /// Confirmed frames between state hash exchanges (0 = no desync
+    /// detection). `STATE_HASH_INTERVAL_FRAMES` is a sensible default.
+    pub state_hash_interval: u32,
+    /// On desync, automatically request the host's latest state. Has no
+    /// effect on the host itself.
+    pub auto_resync: bool,
+    /// Milliseconds between RTT probes to the server and each room member
+    /// (0 = no network stats). `PING_INTERVAL_MS` is a sensible default.
+    pub ping_interval_ms: u32,
+    /// Apply the RTT-based input delay recommendation automatically while
+    /// playing in lockstep. Needs `ping_interval_ms` to be non-zero.
+    pub auto_input_delay: bool,
+    /// Token from an earlier `NetplayEvent::Identity`, to be recognised as
+    /// the same user by servers that persist identities.
+    pub user_token: Option<UserToken>,
 }
 
 #[derive(Debug, Clone)]
@@ -72,21 +112,74 @@ pub enum NetplayEvent {
         relay_room_code: u32,
         reason: String,
     },
+    /// Our state hash for `frame` differs from another room member's.
+    Desync {
+        frame: u32,
+    },
+    /// Link quality to the server (peer id 0) and each room member, sent
+    /// every `ping_interval_ms`.
+    NetworkStats {
+        peers: Vec<PeerNetworkStats>,
+    },
+    /// The recommended lockstep input delay changed. Already applied if
+    /// `auto_input_delay` is set.
+    InputDelayRecommended {
+        frames: u32,
+    },
+    /// Chat message from a room member (our own messages are echoed back).
+    Chat {
+        client_id: u32,
+        /// Sender's name, empty if unknown.
+        name: String,
+        body: ChatBody,
+    },
+    /// Public rooms, in answer to `NetplayCommand::ListRooms`.
+    RoomList {
+        rooms: Vec<RoomSummary>,
+    },
+    /// Our persistent identity on this server; keep `token` for
+    /// `NetplayConfig::user_token` on the next connection.
+    Identity(UserIdentity),
+    /// How many spectators watch our room, and how far behind live (players only).
+    SpectatorInfo {
+        count: u16,
+        delay_frames: u32,
+    },
+    /// The server is shutting down and drops the connection after `grace_ms`.
+    ServerClosing {
+        grace_ms: u32,
+    },
 }
 
 #[derive(Debug)]
 pub enum NetplayCommand {
-    CreateRoom,
-    JoinRoom(u32),
+    /// Create a room, private if a password is given.
+    CreateRoom(Option<String>),
+    /// Join a room by code, with its password if private.
+    JoinRoom(u32, Option<String>),
     SwitchRole(u8),
-    SendRom(Vec<u8>),
-    RomLoaded,
+    /// Broadcast a ROM with the hash of our own loaded copy, if known.
+    SendRom(Vec<u8>, Option<RomHash>),
+    /// Confirm the room's ROM is loaded, with its hash for verification.
+    RomLoaded(Option<RomHash>),
     SendPause(bool),
     SendReset(u8),
     RequestState,
     ProvideState(u32, Vec<u8>),
     /// Send local input for a frame: (frame_number, buttons)
     SendInput(u32, u16),
+    /// Report the local state hash for a frame: (frame_number, hash)
+    SendStateHash(u32, u64),
+    /// Send a chat message or emote to the room.
+    SendChat(ChatBody),
+    /// Ask the server for public rooms (answered with `NetplayEvent::RoomList`).
+    ListRooms(RoomFilter),
+    /// Join or create a public room matching the filter.
+    QuickMatch(RoomFilter),
+    /// Host-only: describe the current room in the lobby.
+    SetRoomInfo(RoomInfo),
+    /// Host-only: relay inputs to spectators this many frames behind live.
+    SetSpectatorDelay(u32),
     /// Host-only: ask the server to instruct all clients to reconnect to a relay server.
     RequestFallbackRelay {
         relay_addr: SocketAddr,
@@ -109,6 +202,12 @@ pub struct SessionHandler {
     command_rx: mpsc::Receiver<NetplayCommand>,
     /// Channel to send game events up to the runtime/UI.
     game_event_tx: mpsc::Sender<NetplayEvent>,
+    /// Sequence number for the next outgoing ping.
+    next_ping_seq: u32,
+    /// Input delay recommendation from the latest network stats.
+    delay_tuner: InputDelayTuner,
+    /// Recent state hashes for desync detection.
+    state_hashes: StateHashLog,
 }
 
 impl SessionHandler {
@@ -133,8 +232,14 @@ impl SessionHandler {
             let _ = tx_clone_state.try_send(NetplayCommand::ProvideState(frame, data.to_vec()));
         }));
 
+        let tx_clone_hash = tx.clone();
+        input_provider.set_on_state_hash(Box::new(move |frame, hash| {
+            let _ = tx_clone_hash.try_send(NetplayCommand::SendStateHash(frame, hash));
+        }));
+
         input_provider.with_session_mut(|s| {
             s.local_name = config.name.clone();
+            s.state_hash_interval = config.state_hash_interval;
         });
 
         (
@@ -145,6 +250,9 @@ impl SessionHandler {
                 event_rx,
                 command_rx: rx,
                 game_event_tx,
+                next_ping_seq: 0,
+                delay_tuner: InputDelayTuner::new(2),
+                state_hashes: StateHashLog::new(),
             },
             tx,
         )
@@ -154,6 +262,12 @@ impl SessionHandler {
     ///
     /// This processes events from the TCP client and updates session state accordingly.
     pub async fn run(&mut self) -> Result<(), NetplayError> {
+        let ping_enabled = self.config.ping_interval_ms != 0;
+        let mut ping_timer = tokio::time::interval(Duration::from_millis(
+            self.config.ping_interval_ms.max(1).into(),
+        ));
+        ping_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
         loop {
             // We use biased select to prioritize incoming network packets over outgoing commands.
             // This is crucial for lockstep netplay to reduce input latency.
@@ -185,20 +299,20 @@ impl SessionHandler {
                 }
                 cmd = self.command_rx.recv() => {
                     match cmd {
-                        Some(NetplayCommand::CreateRoom) => {
-                            self.send_join_room(0).await?;
+                        Some(NetplayCommand::CreateRoom(password)) => {
+                            self.send_join_room(0, password).await?;
                         }
-                        Some(NetplayCommand::JoinRoom(code)) => {
-                            self.send_join_room(code).await?;
+                        Some(NetplayCommand::JoinRoom(code, password)) => {
+                            self.send_join_room(code, password).await?;
                         }
                         Some(NetplayCommand::SwitchRole(role)) => {
                             self.send_switch_role(role).await?;
                         }
-                        Some(NetplayCommand::SendRom(data)) => {
-                            self.send_load_rom(data).await?;
+                        Some(NetplayCommand::SendRom(data, hash)) => {
+                            self.send_load_rom(data, hash).await?;
                         }
-                        Some(NetplayCommand::RomLoaded) => {
-                            self.send_rom_loaded().await?;
+                        Some(NetplayCommand::RomLoaded(hash)) => {
+                            self.send_rom_loaded(hash).await?;
                         }
                         Some(NetplayCommand::SendPause(paused)) => {
                             self.send_pause_game(paused).await?;
@@ -215,6 +329,24 @@ impl SessionHandler {
                         Some(NetplayCommand::SendInput(frame, buttons)) => {
                             self.send_input(frame, buttons).await?;
                         }
+                        Some(NetplayCommand::SendStateHash(frame, hash)) => {
+                            self.send_state_hash(frame, hash).await?;
+                        }
+                        Some(NetplayCommand::SendChat(body)) => {
+                            self.send_chat(body).await?;
+                        }
+                        Some(NetplayCommand::ListRooms(filter)) => {
+                            self.send_list_rooms(filter).await?;
+                        }
+                        Some(NetplayCommand::QuickMatch(filter)) => {
+                            self.send_quick_match(filter).await?;
+                        }
+                        Some(NetplayCommand::SetRoomInfo(info)) => {
+                            self.send_set_room_info(info).await?;
+                        }
+                        Some(NetplayCommand::SetSpectatorDelay(frames)) => {
+                            self.send_set_spectator_delay(frames).await?;
+                        }
                         Some(NetplayCommand::RequestFallbackRelay { relay_addr, relay_room_code, reason }) => {
                             self.send_request_fallback_relay(relay_addr, relay_room_code, reason).await?;
                         }
@@ -223,6 +355,9 @@ impl SessionHandler {
                         }
                     }
                 }
+                _ = ping_timer.tick(), if ping_enabled => {
+                    self.tick_network_stats().await?;
+                }
             }
         }
     }
@@ -238,9 +373,11 @@ impl SessionHandler {
         let hello = Hello {
             client_nonce: rand_nonce(),
             transport: self.config.transport,
-            proto_min: nesium_netproto::constants::VERSION,
-            proto_max: nesium_netproto::constants::VERSION,
+            proto_min: nesium_netproto::constants::MIN_PROTOCOL_VERSION,
+            proto_max: nesium_netproto::constants::PROTOCOL_VERSION,
             name: self.config.name.clone(),
+            capabilities: capability::SUPPORTED,
+            user_token: self.config.user_token,
         };
 
         let header = Header::new(MsgId::Hello as u8);
@@ -277,14 +414,21 @@ impl SessionHandler {
             MsgId::RoleChanged => self.handle_role_changed(&packet).await?,
             MsgId::PlayerJoined => self.handle_player_joined(&packet).await?,
             MsgId::RelayInputs => self.handle_relay_inputs(&packet)?,
+            MsgId::Ping => self.handle_ping(&packet).await?,
             MsgId::Pong => self.handle_pong(&packet)?,
             MsgId::LoadRom => self.handle_load_rom(&packet).await?,
             MsgId::StartGame => self.handle_start_game(&packet).await?,
             MsgId::BeginCatchUp => self.handle_begin_catch_up(&packet).await?,
+            MsgId::CaptureState => self.handle_capture_state(&packet)?,
             MsgId::PauseSync => self.handle_pause_sync(&packet).await?,
             MsgId::ResetSync => self.handle_reset_sync(&packet).await?,
             MsgId::SyncState => self.handle_sync_state(&packet).await?,
+            MsgId::StateHash => self.handle_state_hash(&packet).await?,
+            MsgId::Chat => self.handle_chat(&packet).await?,
+            MsgId::RoomList => self.handle_room_list(&packet).await?,
+            MsgId::SpectatorInfo => self.handle_spectator_info(&packet).await?,
             MsgId::PlayerLeft => self.handle_player_left(&packet).await?,
+            MsgId::ServerClosing => self.handle_server_closing(&packet).await?,
             MsgId::FallbackToRelay => self.handle_fallback_to_relay(&packet).await?,
             MsgId::Error => self.handle_error(&packet).await?,
             msg => {
@@ -297,14 +441,40 @@ impl SessionHandler {
 
     /// Handle Welcome message - server accepted our Hello.
     async fn handle_welcome(&mut self, packet: &PacketOwned) -> Result<(), NetplayError> {
+        let version: WelcomeVersion =
+            postcard::from_bytes(&packet.payload).map_err(|e| NetplayError::Protocol(e.into()))?;
+        if !(MIN_PROTOCOL_VERSION..=PROTOCOL_VERSION).contains(&version.proto_version) {
+            return Err(NetplayError::VersionMismatch {
+                server: version.proto_version,
+                min: MIN_PROTOCOL_VERSION,
+                max: PROTOCOL_VERSION,
+            });
+        }
+
         let welcome: Welcome =
             postcard::from_bytes(&packet.payload).map_err(|e| NetplayError::Protocol(e.into()))?;
 
         info!(
-            "Received Welcome: client_id={}, room_id={}, input_delay={}",
-            welcome.assigned_client_id, welcome.room_id, welcome.input_delay_frames
+            "Received Welcome: client_id={}, room_id={}, input_delay={}, proto={}, caps={:#x}",
+            welcome.assigned_client_id,
+            welcome.room_id,
+            welcome.input_delay_frames,
+            welcome.proto_version,
+            welcome.capabilities
         );
 
+        self.client
+            .set_compression(welcome.capabilities & capability::COMPRESSION != 0);
+
+        if let Some(identity) = welcome.identity {
+            // Reconnects on this handler keep the identity.
+            self.config.user_token = Some(identity.token);
+            let _ = self
+                .game_event_tx
+                .send(NetplayEvent::Identity(identity))
+                .await;
+        }
+
         // Best-effort: attach secondary channels to avoid HOL blocking on large transfers.
         if let Err(e) = self
             .client
@@ -327,36 +497,37 @@ impl SessionHandler {
             session.server_nonce = welcome.server_nonce;
             session.input_delay_frames = welcome.input_delay_frames;
             session.rewind_capacity = welcome.rewind_capacity;
+            session.proto_version = welcome.proto_version;
+            session.capabilities = welcome.capabilities;
             session.state = SessionState::WaitingForRoom;
         });
         // Keep sync strategy's delay in sync with negotiated session delay.
         self.input_provider
             .set_input_delay(welcome.input_delay_frames as u32);
+        self.delay_tuner = InputDelayTuner::new(welcome.input_delay_frames as u32);
 
         // If we have a room code, join it; otherwise wait for assignment
         if self.config.room_code != 0 {
-            self.send_join_room(self.config.room_code).await?;
+            self.send_join_room(self.config.room_code, None).await?;
         }
 
         Ok(())
     }
 
     /// Send JoinRoom request.
-    async fn send_join_room(&mut self, room_code: u32) -> Result<(), NetplayError> {
+    async fn send_join_room(
+        &mut self,
+        room_code: u32,
+        password: Option<String>,
+    ) -> Result<(), NetplayError> {
         // Sync mode is decided by the room at creation time (host sets it once).
         // When joining an existing room, do not send any preference.
-        let preferred_sync_mode = if room_code == 0 {
-            Some(match self.input_provider.sync_mode() {
-                ClientSyncMode::Lockstep => ProtoSyncMode::Lockstep,
-                ClientSyncMode::Rollback => ProtoSyncMode::Rollback,
-            })
-        } else {
-            None
-        };
+        let preferred_sync_mode = (room_code == 0).then(|| self.proto_sync_mode());
 
         let join = JoinRoom {
             room_code,
             preferred_sync_mode,
+            password,
         };
 
         let header = Header::new(MsgId::JoinRoom as u8);
@@ -368,6 +539,59 @@ impl SessionHandler {
         Ok(())
     }
 
+    /// Our sync mode, as sent with a room creating request.
+    fn proto_sync_mode(&self) -> ProtoSyncMode {
+        match self.input_provider.sync_mode() {
+            ClientSyncMode::Lockstep => ProtoSyncMode::Lockstep,
+            ClientSyncMode::Rollback => ProtoSyncMode::Rollback,
+        }
+    }
+
+    /// Send ListRooms request.
+    async fn send_list_rooms(&mut self, filter: RoomFilter) -> Result<(), NetplayError> {
+        let header = Header::new(MsgId::ListRooms as u8);
+        self.client
+            .send_message(header, MsgId::ListRooms, &ListRooms { filter })
+            .await?;
+        Ok(())
+    }
+
+    /// Send QuickMatch request; answered with a `JoinAck` like `JoinRoom`.
+    async fn send_quick_match(&mut self, filter: RoomFilter) -> Result<(), NetplayError> {
+        let req = QuickMatch {
+            filter,
+            // Only used if the server has to create a room for us.
+            preferred_sync_mode: Some(self.proto_sync_mode()),
+        };
+        let header = Header::new(MsgId::QuickMatch as u8);
+        self.client
+            .send_message(header, MsgId::QuickMatch, &req)
+            .await?;
+        Ok(())
+    }
+
+    /// Send SetRoomInfo request.
+    async fn send_set_room_info(&mut self, info: RoomInfo) -> Result<(), NetplayError> {
+        let header = Header::new(MsgId::SetRoomInfo as u8);
+        self.client
+            .send_message(header, MsgId::SetRoomInfo, &SetRoomInfo { info })
+            .await?;
+        Ok(())
+    }
+
+    /// Send SetSpectatorDelay request.
+    async fn send_set_spectator_delay(&mut self, frames: u32) -> Result<(), NetplayError> {
+        let header = Header::new(MsgId::SetSpectatorDelay as u8);
+        self.client
+            .send_message(
+                header,
+                MsgId::SetSpectatorDelay,
+                &SetSpectatorDelay { frames },
+            )
+            .await?;
+        Ok(())
+    }
+
     /// Send SwitchRole request.
     async fn send_switch_role(&mut self, new_role: u8) -> Result<(), NetplayError> {
         let req = nesium_netproto::messages::session::SwitchRole { new_role };
@@ -601,20 +825,104 @@ impl SessionHandler {
         Ok(())
     }
 
+    /// Handle Ping relayed from a peer - echo it straight back.
+    async fn handle_ping(&mut self, packet: &PacketOwned) -> Result<(), NetplayError> {
+        let ping: Ping =
+            postcard::from_bytes(&packet.payload).map_err(|e| NetplayError::Protocol(e.into()))?;
+
+        let pong = Pong {
+            seq: ping.seq,
+            t_ms: ping.t_ms,
+            peer_client_id: ping.peer_client_id,
+        };
+        let header = Header::new(MsgId::Pong as u8);
+        self.client.send_message(header, MsgId::Pong, &pong).await?;
+        Ok(())
+    }
+
     /// Handle Pong - RTT measurement response.
     fn handle_pong(&mut self, packet: &PacketOwned) -> Result<(), NetplayError> {
         let pong: Pong =
             postcard::from_bytes(&packet.payload).map_err(|e| NetplayError::Protocol(e.into()))?;
 
         let now_ms = current_time_ms();
-        let rtt = now_ms.saturating_sub(pong.t_ms);
-        debug!("Ping RTT: {}ms", rtt);
+        let rtt = self.input_provider.with_session(|session| {
+            session
+                .peer_links
+                .get_mut(&pong.peer_client_id)
+                .and_then(|link| link.on_pong(pong.seq, now_ms))
+        });
+        if let Some(rtt) = rtt {
+            debug!(peer = pong.peer_client_id, rtt_ms = rtt, "Ping RTT");
+        }
+
+        Ok(())
+    }
 
-        // TODO: Update adaptive input delay based on RTT
+    /// Expire lost pings, report link stats and ping the server and every
+    /// room member again.
+    async fn tick_network_stats(&mut self) -> Result<(), NetplayError> {
+        let now_ms = current_time_ms();
+        let Some((peers, stats, player_stats)) = self.input_provider.with_session(|session| {
+            if session.client_id == 0 {
+                return None;
+            }
+            let own_id = session.client_id;
+            let peers: Vec<u32> = std::iter::once(0)
+                .chain(session.players.keys().copied().filter(|&id| id != own_id))
+                .collect();
+            session.peer_links.retain(|id, _| peers.contains(id));
+            for link in session.peer_links.values_mut() {
+                link.expire(now_ms);
+            }
+            let stats = session.network_stats();
+            // Only players' links matter for input delay; spectators never send input.
+            let player_stats: Vec<PeerNetworkStats> = stats
+                .iter()
+                .filter(|s| {
+                    session
+                        .players
+                        .get(&s.peer_client_id)
+                        .is_some_and(|p| p.player_index != SPECTATOR_PLAYER_INDEX)
+                })
+                .copied()
+                .collect();
+            Some((peers, stats, player_stats))
+        }) else {
+            return Ok(());
+        };
 
+        if let Some(frames) = self.delay_tuner.update(&player_stats) {
+            self.apply_input_delay_recommendation(frames).await;
+        }
+        if !stats.is_empty() {
+            let _ = self
+                .game_event_tx
+                .send(NetplayEvent::NetworkStats { peers: stats })
+                .await;
+        }
+        for peer in peers {
+            self.send_ping(peer).await?;
+        }
         Ok(())
     }
 
+    async fn apply_input_delay_recommendation(&mut self, frames: u32) {
+        let applies = self.config.auto_input_delay
+            && self.input_provider.local_player().is_some()
+            && self.input_provider.sync_mode() == ClientSyncMode::Lockstep;
+        if applies {
+            info!(frames, "Auto-applying input delay");
+            self.input_provider.set_input_delay(frames);
+        } else {
+            debug!(frames, "Recommended input delay changed");
+        }
+        let _ = self
+            .game_event_tx
+            .send(NetplayEvent::InputDelayRecommended { frames })
+            .await;
+    }
+
     /// Handle Error message from server.
     async fn handle_error(&mut self, packet: &PacketOwned) -> Result<(), NetplayError> {
         let msg: ErrorMsg = match postcard::from_bytes(&packet.payload) {
@@ -633,6 +941,21 @@ impl SessionHandler {
             .send(NetplayEvent::Error { code: msg.code })
             .await;
 
+        // The server rejected our Hello; nothing else on this session will work.
+        match msg.code {
+            ErrorCode::IncompatibleVersion => {
+                return Err(NetplayError::HandshakeFailed(
+                    "server speaks an incompatible protocol version".to_string(),
+                ));
+            }
+            ErrorCode::Banned => {
+                return Err(NetplayError::HandshakeFailed(
+                    "banned from this server".to_string(),
+                ));
+            }
+            _ => {}
+        }
+
         Ok(())
     }
 
@@ -662,11 +985,138 @@ impl SessionHandler {
         Ok(())
     }
 
-    /// Send a ping for RTT measurement.
-    pub async fn send_ping(&mut self) -> Result<(), NetplayError> {
+    /// Send our state hash for `frame` to the room and check it against the
+    /// hashes received so far.
+    async fn send_state_hash(&mut self, frame: u32, hash: u64) -> Result<(), NetplayError> {
+        let msg = StateHash { frame, hash };
+        let header = Header::new(MsgId::StateHash as u8);
+        self.client
+            .send_message(header, MsgId::StateHash, &msg)
+            .await?;
+
+        if let Some(frame) = self.state_hashes.record_local(frame, hash) {
+            self.handle_desync(frame).await?;
+        }
+        Ok(())
+    }
+
+    /// Handle StateHash relayed from another room member.
+    async fn handle_state_hash(&mut self, packet: &PacketOwned) -> Result<(), NetplayError> {
+        let msg: StateHash =
+            postcard::from_bytes(&packet.payload).map_err(|e| NetplayError::Protocol(e.into()))?;
+
+        if let Some(frame) = self.state_hashes.record_remote(msg.frame, msg.hash) {
+            self.handle_desync(frame).await?;
+        }
+        Ok(())
+    }
+
+    /// Report a detected desync and, if configured, ask for the host's state.
+    async fn handle_desync(&mut self, frame: u32) -> Result<(), NetplayError> {
+        warn!(frame, "State hash mismatch - session desynced");
+        let _ = self
+            .game_event_tx
+            .send(NetplayEvent::Desync { frame })
+            .await;
+
+        // The host's state is authoritative, so the host never resyncs.
+        if self.config.auto_resync && self.input_provider.local_player() != Some(0) {
+            info!(frame, "Requesting state from host to resynchronize");
+            self.send_request_state().await?;
+        }
+        Ok(())
+    }
+
+    /// Send a chat message; the server fills in our client id.
+    async fn send_chat(&mut self, body: ChatBody) -> Result<(), NetplayError> {
+        let msg = Chat { client_id: 0, body };
+        let header = Header::new(MsgId::Chat as u8);
+        self.client.send_message(header, MsgId::Chat, &msg).await?;
+        Ok(())
+    }
+
+    /// Handle RoomList response.
+    async fn handle_room_list(&mut self, packet: &PacketOwned) -> Result<(), NetplayError> {
+        let msg: RoomList =
+            postcard::from_bytes(&packet.payload).map_err(|e| NetplayError::Protocol(e.into()))?;
+        let _ = self
+            .game_event_tx
+            .send(NetplayEvent::RoomList { rooms: msg.rooms })
+            .await;
+        Ok(())
+    }
+
+    /// Handle SpectatorInfo broadcast by the server.
+    async fn handle_spectator_info(&mut self, packet: &PacketOwned) -> Result<(), NetplayError> {
+        let msg: SpectatorInfo =
+            postcard::from_bytes(&packet.payload).map_err(|e| NetplayError::Protocol(e.into()))?;
+        let _ = self
+            .game_event_tx
+            .send(NetplayEvent::SpectatorInfo {
+                count: msg.count,
+                delay_frames: msg.delay_frames,
+            })
+            .await;
+        Ok(())
+    }
+
+    /// Handle ServerClosing notice sent before the server shuts down.
+    async fn handle_server_closing(&mut self, packet: &PacketOwned) -> Result<(), NetplayError> {
+        let msg: ServerClosing =
+            postcard::from_bytes(&packet.payload).map_err(|e| NetplayError::Protocol(e.into()))?;
+        warn!(grace_ms = msg.grace_ms, "Server is shutting down");
+        let _ = self
+            .game_event_tx
+            .send(NetplayEvent::ServerClosing {
+                grace_ms: msg.grace_ms,
+            })
+            .await;
+        Ok(())
+    }
+
+    /// Handle Chat message broadcast by the server.
+    async fn handle_chat(&mut self, packet: &PacketOwned) -> Result<(), NetplayError> {
+        let msg: Chat =
+            postcard::from_bytes(&packet.payload).map_err(|e| NetplayError::Protocol(e.into()))?;
+
+        let name = self.input_provider.with_session(|session| {
+            if msg.client_id == session.client_id {
+                session.local_name.clone()
+            } else {
+                session
+                    .players
+                    .get(&msg.client_id)
+                    .map(|p| p.name.clone())
+                    .unwrap_or_default()
+            }
+        });
+        let _ = self
+            .game_event_tx
+            .send(NetplayEvent::Chat {
+                client_id: msg.client_id,
+                name,
+                body: msg.body,
+            })
+            .await;
+        Ok(())
+    }
+
+    /// Send a ping for RTT measurement (`peer_client_id` 0 = the server).
+    pub async fn send_ping(&mut self, peer_client_id: u32) -> Result<(), NetplayError> {
+        let seq = self.next_ping_seq;
+        self.next_ping_seq = self.next_ping_seq.wrapping_add(1);
         let ping = Ping {
+            seq,
             t_ms: current_time_ms(),
+            peer_client_id,
         };
+        self.input_provider.with_session(|session| {
+            session
+                .peer_links
+                .entry(peer_client_id)
+                .or_default()
+                .on_ping_sent(seq, ping.t_ms);
+        });
 
         let header = Header::new(MsgId::Ping as u8);
 
@@ -735,6 +1185,17 @@ impl SessionHandler {
             "Received BeginCatchUp - activating lockstep"
         );
 
+        // The snapshot may be newer than the one announced in JoinAck (the host
+        // captures a fresh one for us), so rebase our timeline onto it.
+        self.input_provider.with_session_mut(|session| {
+            match &mut session.state {
+                SessionState::Playing { start_frame, .. }
+                | SessionState::Spectating { start_frame } => *start_frame = msg.snapshot_frame,
+                _ => {}
+            }
+            session.current_frame = msg.snapshot_frame;
+        });
+
         for i in 0..8 {
             let active = (msg.active_ports_mask & (1u8 << i)) != 0;
             if active {
@@ -752,9 +1213,23 @@ impl SessionHandler {
         Ok(())
     }
 
+    /// Handle CaptureState - the server wants a fresh snapshot for a late joiner.
+    fn handle_capture_state(&mut self, packet: &PacketOwned) -> Result<(), NetplayError> {
+        let _: CaptureState =
+            postcard::from_bytes(&packet.payload).map_err(|e| NetplayError::Protocol(e.into()))?;
+
+        info!("Received CaptureState - sending state at the next frame");
+        self.input_provider.request_state_capture();
+        Ok(())
+    }
+
     /// Send LoadRom message.
-    async fn send_load_rom(&mut self, data: Vec<u8>) -> Result<(), NetplayError> {
-        let req = LoadRom { data };
+    async fn send_load_rom(
+        &mut self,
+        data: Vec<u8>,
+        hash: Option<RomHash>,
+    ) -> Result<(), NetplayError> {
+        let req = LoadRom { data, hash };
 
         let header = Header::new(MsgId::LoadRom as u8);
 
@@ -766,8 +1241,8 @@ impl SessionHandler {
     }
 
     /// Send RomLoaded message.
-    async fn send_rom_loaded(&mut self) -> Result<(), NetplayError> {
-        let req = RomLoaded {};
+    async fn send_rom_loaded(&mut self, hash: Option<RomHash>) -> Result<(), NetplayError> {
+        let req = RomLoaded { hash };
 
         let header = Header::new(MsgId::RomLoaded as u8);
 
@@ -796,6 +1271,7 @@ impl SessionHandler {
         info!("Received ResetSync");
         let msg: ResetSync =
             postcard::from_bytes(&packet.payload).map_err(|e| NetplayError::Protocol(e.into()))?;
+        self.state_hashes.clear();
 
         let _ = self
             .game_event_tx
@@ -814,6 +1290,8 @@ impl SessionHandler {
             frame = msg.frame,
             "Received SyncState"
         );
+        // The loaded state starts a new timeline; old hashes no longer apply.
+        self.state_hashes.clear();
         let _ = self
             .game_event_tx
             .send(NetplayEvent::SyncState(msg.frame, msg.data))
@@ -992,4 +1470,24 @@ mod tests {
         assert_eq!(batches[0].start_frame, 0);
         assert_eq!(batches[0].buttons, vec![1, 2, 21]);
     }
+
+    #[test]
+    fn welcome_version_prefix_matches_welcome() {
+        let welcome = Welcome {
+            server_nonce: 1,
+            session_token: 2,
+            assigned_client_id: 3,
+            room_id: 4,
+            tick_hz: 60,
+            input_delay_frames: 2,
+            max_payload: 1200,
+            rewind_capacity: 600,
+            proto_version: 0xA5,
+            capabilities: capability::SUPPORTED,
+            identity: None,
+        };
+        let bytes = postcard::to_allocvec(&welcome).expect("encode");
+        let version: WelcomeVersion = postcard::from_bytes(&bytes).expect("decode");
+        assert_eq!(version.proto_version, 0xA5);
+    }
 }
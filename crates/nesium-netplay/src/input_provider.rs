@@ -10,7 +10,11 @@ use std::sync::{
 };
 
 use crate::session::NetplaySession;
-use crate::sync::{SyncMode, SyncStrategy, lockstep::LockstepSync};
+use crate::sync::{
+    RollbackStats, SyncMode, SyncStrategy,
+    lockstep::LockstepSync,
+    rollback::{RollbackConfig, RollbackSync},
+};
 
 fn frame_offset(session: &NetplaySession) -> u32 {
     match session.state {
@@ -64,16 +68,36 @@ pub trait NetplayInputProvider: Send + Sync {
     /// Send a state snapshot to the server.
     fn send_state(&self, frame: u32, data: &[u8]);
 
+    /// Check (and clear) whether the server asked for a snapshot right away,
+    /// e.g. for a late joiner. Only meaningful on the host.
+    fn take_state_capture_request(&self) -> bool;
+
+    /// Frames between state hash reports; 0 disables desync detection.
+    fn state_hash_interval(&self) -> u32;
+
+    /// Report `Nes::state_hash()` after `frame`, which must have been run
+    /// with confirmed inputs.
+    fn report_state_hash(&self, frame: u32, hash: u64);
+
     /// Get current sync mode (Lockstep or Rollback).
     fn sync_mode(&self) -> SyncMode;
 
     /// Check if a rollback is pending (Rollback mode only).
+    ///
+    /// Frames are in the same numbering as [`Self::poll_inputs`].
     fn pending_rollback(&self) -> Option<crate::sync::RollbackRequest>;
 
     /// Clear the pending rollback after it has been processed.
     fn clear_rollback(&self);
+
+    /// Rollback window and snapshot cadence; the runtime should snapshot
+    /// frames where `frame % snapshot_interval == 0`.
+    fn rollback_config(&self) -> RollbackConfig;
 }
 
+/// Callback receiving `(frame, state_hash)`.
+type StateHashHook = Box<dyn Fn(u32, u64) + Send + Sync>;
+
 /// Shared netplay input provider implementation.
 ///
 /// This implementation wraps a `NetplaySession` and provides
@@ -85,12 +109,18 @@ pub struct SharedInputProvider {
     /// Synchronization strategy (lockstep or rollback).
     sync_strategy: Mutex<Box<dyn SyncStrategy>>,
 
+    /// Rollback settings used whenever rollback mode is selected.
+    rollback_config: Mutex<RollbackConfig>,
+
     /// Flag indicating we're waiting for remote input.
     waiting: AtomicBool,
 
     /// Flag indicating netplay is active.
     active: AtomicBool,
 
+    /// Flag indicating the server wants a state snapshot now.
+    state_capture_requested: AtomicBool,
+
     /// Current frame number.
     current_frame: AtomicU32,
 
@@ -105,6 +135,9 @@ pub struct SharedInputProvider {
 
     /// Callback to send state to server.
     on_send_state: Mutex<Option<Box<dyn Fn(u32, &[u8]) + Send + Sync>>>,
+
+    /// Callback to report a state hash.
+    on_state_hash: Mutex<Option<StateHashHook>>,
 }
 
 impl Default for SharedInputProvider {
@@ -123,18 +156,21 @@ impl SharedInputProvider {
     pub fn with_sync_mode(mode: SyncMode) -> Self {
         let strategy: Box<dyn SyncStrategy> = match mode {
             SyncMode::Lockstep => Box::new(LockstepSync::new(2)),
-            SyncMode::Rollback => Box::new(crate::sync::rollback::RollbackSync::new(2)),
+            SyncMode::Rollback => Box::new(RollbackSync::new(2)),
         };
         Self {
             session: Mutex::new(NetplaySession::new()),
             sync_strategy: Mutex::new(strategy),
+            rollback_config: Mutex::new(RollbackConfig::default()),
             waiting: AtomicBool::new(false),
             active: AtomicBool::new(false),
+            state_capture_requested: AtomicBool::new(false),
             current_frame: AtomicU32::new(0),
             local_player: Mutex::new(None),
             local_buttons: Default::default(),
             on_send_input: Mutex::new(None),
             on_send_state: Mutex::new(None),
+            on_state_hash: Mutex::new(None),
         }
     }
 
@@ -149,7 +185,10 @@ impl SharedInputProvider {
             self.with_session(|s| (s.input_delay_frames as u32, s.active_ports));
         let mut new_strategy: Box<dyn SyncStrategy> = match mode {
             SyncMode::Lockstep => Box::new(LockstepSync::new(input_delay)),
-            SyncMode::Rollback => Box::new(crate::sync::rollback::RollbackSync::new(input_delay)),
+            SyncMode::Rollback => Box::new(RollbackSync::with_config(
+                input_delay,
+                *self.rollback_config.lock(),
+            )),
         };
 
         // Transfer active ports to the new strategy
@@ -164,6 +203,17 @@ impl SharedInputProvider {
         session.clear_inputs();
     }
 
+    /// Set the rollback window and snapshot cadence. Takes effect the next
+    /// time rollback mode is selected.
+    pub fn set_rollback_config(&self, config: RollbackConfig) {
+        *self.rollback_config.lock() = config;
+    }
+
+    /// Rollback depth metrics for the current strategy.
+    pub fn rollback_stats(&self) -> RollbackStats {
+        self.sync_strategy.lock().rollback_stats()
+    }
+
     /// Set a port as active or inactive.
     pub fn set_port_active(&self, port: usize, active: bool) {
         self.sync_strategy.lock().set_port_active(port, active);
@@ -195,6 +245,11 @@ impl SharedInputProvider {
         self.active.store(active, Ordering::Release);
     }
 
+    /// Ask the runtime to send a state snapshot at the next frame.
+    pub fn request_state_capture(&self) {
+        self.state_capture_requested.store(true, Ordering::Release);
+    }
+
     /// Set the local player index.
     pub fn set_local_player(&self, player: Option<u8>) {
         let mut guard = self.local_player.lock();
@@ -239,11 +294,11 @@ impl SharedInputProvider {
         self.sync_strategy.lock().last_confirmed_frame()
     }
 
-    /// Set the input delay in frames.
+    /// Set the input delay in frames. Safe to call mid-session.
     pub fn set_input_delay(&self, delay: u32) {
         self.sync_strategy.lock().set_input_delay(delay);
         let mut session = self.session.lock();
-        session.input_delay_frames = delay as u8;
+        session.set_input_delay(delay as u8);
     }
 
     /// Set callback for sending inputs.
@@ -257,6 +312,12 @@ impl SharedInputProvider {
         let mut guard = self.on_send_state.lock();
         *guard = Some(cb);
     }
+
+    /// Set callback for reporting state hashes.
+    pub fn set_on_state_hash(&self, cb: StateHashHook) {
+        let mut guard = self.on_state_hash.lock();
+        *guard = Some(cb);
+    }
 }
 
 impl NetplayInputProvider for SharedInputProvider {
@@ -310,18 +371,25 @@ impl NetplayInputProvider for SharedInputProvider {
     }
 
     fn send_input_to_server(&self, frame: u32, buttons: u16) {
-        let (effective_frame, local_player) = self.with_session_mut(|session| {
+        let scheduled = self.with_session_mut(|session| {
             let effective = frame.wrapping_add(frame_offset(session));
+            let frames = session.schedule_local_input(effective)?;
             if let Some(idx) = session.local_player_index {
-                session.push_input(idx as usize, effective, buttons);
+                for f in frames.clone() {
+                    session.push_input(idx as usize, f, buttons);
+                }
             }
-            (effective, session.local_player_index)
+            Some((frames, session.local_player_index))
         });
+        let Some((frames, local_player)) = scheduled else {
+            return;
+        };
 
         if let Some(idx) = local_player {
-            self.sync_strategy
-                .lock()
-                .on_local_input(idx, effective_frame, buttons);
+            let mut sync = self.sync_strategy.lock();
+            for f in frames.clone() {
+                sync.on_local_input(idx, f, buttons);
+            }
         }
 
         // CRITICAL: Push to own queue immediately to prevent lockstep deadlock.
@@ -329,7 +397,9 @@ impl NetplayInputProvider for SharedInputProvider {
         // Then send to server for relay to other players
         let cb = self.on_send_input.lock();
         if let Some(f) = cb.as_ref() {
-            f(effective_frame, buttons);
+            for effective_frame in frames {
+                f(effective_frame, buttons);
+            }
         }
     }
 
@@ -356,17 +426,47 @@ impl NetplayInputProvider for SharedInputProvider {
         }
     }
 
+    fn take_state_capture_request(&self) -> bool {
+        self.state_capture_requested.swap(false, Ordering::AcqRel)
+    }
+
+    fn state_hash_interval(&self) -> u32 {
+        let session = self.session.lock();
+        session.state_hash_interval
+    }
+
+    fn report_state_hash(&self, frame: u32, hash: u64) {
+        let effective_frame =
+            self.with_session(|session| frame.wrapping_add(frame_offset(session)));
+        let cb = self.on_state_hash.lock();
+        if let Some(f) = cb.as_ref() {
+            f(effective_frame, hash);
+        }
+    }
+
     fn sync_mode(&self) -> SyncMode {
         self.sync_strategy.lock().mode()
     }
 
     fn pending_rollback(&self) -> Option<crate::sync::RollbackRequest> {
-        self.sync_strategy.lock().pending_rollback()
+        let offset = self.with_session(|session| frame_offset(session));
+        self.sync_strategy
+            .lock()
+            .pending_rollback()
+            .map(|rb| crate::sync::RollbackRequest {
+                target_frame: rb.target_frame.wrapping_sub(offset),
+                restore_frame: rb.restore_frame.wrapping_sub(offset),
+                current_frame: rb.current_frame.wrapping_sub(offset),
+            })
     }
 
     fn clear_rollback(&self) {
         self.sync_strategy.lock().clear_rollback();
     }
+
+    fn rollback_config(&self) -> RollbackConfig {
+        *self.rollback_config.lock()
+    }
 }
 
 /// Create a new shared input provider wrapped in an Arc.
@@ -410,4 +510,27 @@ mod tests {
         assert_eq!(inputs.unwrap(), [1, 2, 3, 4]);
         assert!(!provider.is_waiting());
     }
+
+    #[test]
+    fn pending_rollback_uses_runtime_frame_numbers() {
+        let provider = SharedInputProvider::with_sync_mode(SyncMode::Rollback);
+        provider.with_session(|s| {
+            s.state = crate::session::SessionState::Playing {
+                start_frame: 100,
+                player_index: 0,
+            };
+        });
+        provider.set_port_active(1, true);
+
+        for frame in 0..5 {
+            provider.poll_inputs(frame);
+        }
+        // Session frame 102 is runtime frame 2.
+        provider.push_remote_input(1, 102, 0x01);
+
+        let rb = provider.pending_rollback().unwrap();
+        assert_eq!(rb.target_frame, 2);
+        assert_eq!(rb.restore_frame, 2);
+        assert_eq!(rb.current_frame, 4);
+    }
 }
@@ -8,15 +8,19 @@
 //! # Architecture
 //!
 //! - [`sync`]: Synchronization strategies (lockstep, rollback)
+//! - [`desync`]: Desync detection from periodic state hashes
 //! - [`session`]: Session state machine and input queue management
-//! - [`tcp_client`]: Async TCP client for server communication
+//! - [`tcp_client`]: Async client for server communication (TCP, QUIC, WebRTC data channels)
 //! - [`input_provider`]: Interface for injecting network inputs into the NES runtime
+//! - [`punch`]: UDP hole punching for P2P sessions
 //! - [`handler`]: Session handler for protocol message processing
 //! - [`error`]: Error types
 
+pub mod desync;
 pub mod error;
 pub mod handler;
 pub mod input_provider;
+pub mod punch;
 pub mod session;
 pub mod sync;
 pub mod tcp_client;
@@ -26,9 +30,12 @@ pub use error::NetplayError;
 pub use handler::{NetplayCommand, NetplayConfig, NetplayEvent, SessionHandler};
 pub use input_provider::{NetplayInputProvider, SharedInputProvider, create_input_provider};
 pub use nesium_netproto::constants::SPECTATOR_PLAYER_INDEX;
-pub use session::{NetplaySession, SessionState};
-pub use sync::{RollbackRequest, SyncMode, SyncStrategy, snapshot::SnapshotBuffer};
+pub use session::{NetplaySession, PeerNetworkStats, SessionState};
+pub use sync::{
+    RollbackRequest, RollbackStats, SyncMode, SyncStrategy, rollback::RollbackConfig,
+    snapshot::SnapshotBuffer,
+};
 pub use tcp_client::{
-    TcpClientEvent, TcpClientHandle, connect, connect_auto, connect_auto_pinned, connect_quic,
-    connect_quic_pinned,
+    TcpClientEvent, TcpClientHandle, connect, connect_auto, connect_auto_pinned,
+    connect_data_channel, connect_quic, connect_quic_pinned, connect_tls, connect_tls_pinned,
 };
@@ -0,0 +1,184 @@
+//! UDP hole punching for P2P sessions.
+//!
+//! The signaling server (nesium-netd with `--udp-bind`) coordinates it:
+//!
+//! 1. After `P2PCreateRoom`/`P2PJoinRoom`, each peer runs [`register`] on the
+//!    UDP socket it wants to use for the session. The server learns the
+//!    socket's public endpoint from the registration datagrams.
+//! 2. Once host and joiner are registered, the server sends `P2PPunchStart`
+//!    to both over their control connections.
+//! 3. Both peers run [`punch`] at the same time, so each NAT sees outbound
+//!    traffic before the peer's probes arrive (simultaneous open).
+//! 4. On success, [`spawn_keepalive`] holds the mappings open. On
+//!    [`NetplayError::PunchFailed`] the caller falls back to relay with
+//!    `P2PRequestFallback`, just like a failed direct connect.
+
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use nesium_netproto::{
+    codec_udp::{decode_udp, encode_udp},
+    header::Header,
+    limits::MAX_UDP_PAYLOAD,
+    messages::session::{
+        P2P_PUNCH_INTERVAL_MS, P2P_PUNCH_KEEPALIVE_SECS, P2P_PUNCH_TIMEOUT_MS, P2PPunchProbe,
+        P2PPunchRegister, P2PPunchStart,
+    },
+    msg_id::MsgId,
+};
+use tokio::net::UdpSocket;
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, interval, sleep_until};
+use tracing::debug;
+
+use crate::error::NetplayError;
+
+async fn send_datagram<T: serde::Serialize>(
+    socket: &UdpSocket,
+    to: SocketAddr,
+    msg_id: MsgId,
+    msg: &T,
+) -> Result<(), NetplayError> {
+    let datagram = encode_udp(Header::new(msg_id as u8), msg_id, msg, MAX_UDP_PAYLOAD)?;
+    socket.send_to(&datagram, to).await?;
+    Ok(())
+}
+
+/// Registers `socket` with the signaling server's UDP address until `start`
+/// resolves with the server's `P2PPunchStart` (received on the control
+/// connection). Fails if it doesn't within `P2P_PUNCH_TIMEOUT_MS`.
+pub async fn register(
+    socket: &UdpSocket,
+    server: SocketAddr,
+    msg: &P2PPunchRegister,
+    start: impl Future<Output = Option<P2PPunchStart>>,
+) -> Result<P2PPunchStart, NetplayError> {
+    let deadline = sleep_until(Instant::now() + Duration::from_millis(P2P_PUNCH_TIMEOUT_MS));
+    let mut ticker = interval(Duration::from_millis(P2P_PUNCH_INTERVAL_MS));
+    tokio::pin!(deadline, start);
+
+    loop {
+        tokio::select! {
+            start = &mut start => {
+                return start.ok_or_else(|| {
+                    NetplayError::PunchFailed("signaling connection closed".to_string())
+                });
+            }
+            _ = &mut deadline => {
+                return Err(NetplayError::PunchFailed(
+                    "no P2PPunchStart from signaling server".to_string(),
+                ));
+            }
+            _ = ticker.tick() => {
+                send_datagram(socket, server, MsgId::P2PPunchRegister, msg).await?;
+            }
+        }
+    }
+}
+
+/// Probes every address in `start.peer_addrs` until the peer answers, and
+/// returns the address the path was established with.
+///
+/// Succeeds once a probe with the session nonce and `ack` set arrives, i.e.
+/// both directions are known to work. Other datagrams on the socket are
+/// ignored while punching.
+pub async fn punch(socket: &UdpSocket, start: &P2PPunchStart) -> Result<SocketAddr, NetplayError> {
+    let deadline = sleep_until(Instant::now() + Duration::from_millis(P2P_PUNCH_TIMEOUT_MS));
+    let mut ticker = interval(Duration::from_millis(P2P_PUNCH_INTERVAL_MS));
+    tokio::pin!(deadline);
+
+    let mut peer: Option<SocketAddr> = None;
+    let mut buf = vec![0u8; MAX_UDP_PAYLOAD + 64];
+
+    loop {
+        tokio::select! {
+            _ = &mut deadline => {
+                return Err(NetplayError::PunchFailed(format!(
+                    "no answer from peer {} at {:?}",
+                    start.peer_client_id, start.peer_addrs
+                )));
+            }
+            _ = ticker.tick() => {
+                let probe = P2PPunchProbe { nonce: start.nonce, ack: peer.is_some() };
+                // Once the peer answered, stick to the address that worked.
+                let targets = peer.map_or_else(|| start.peer_addrs.clone(), |p| vec![p]);
+                for addr in targets {
+                    // Some candidates (e.g. another LAN) may be unroutable.
+                    let sent = send_datagram(socket, addr, MsgId::P2PPunchProbe, &probe).await;
+                    if let Err(e) = sent {
+                        debug!(%addr, error = %e, "Punch probe send failed");
+                    }
+                }
+            }
+            received = socket.recv_from(&mut buf) => {
+                let Ok((len, from)) = received else {
+                    // ICMP port unreachable from a closed candidate; keep probing.
+                    continue;
+                };
+                let Ok(view) = decode_udp(&buf[..len]) else {
+                    continue;
+                };
+                if view.msg_id != MsgId::P2PPunchProbe {
+                    continue;
+                }
+                let Ok(probe) = postcard::from_bytes::<P2PPunchProbe>(view.payload) else {
+                    continue;
+                };
+                if probe.nonce != start.nonce {
+                    continue;
+                }
+                peer = Some(from);
+                let ack = P2PPunchProbe { nonce: start.nonce, ack: true };
+                send_datagram(socket, from, MsgId::P2PPunchProbe, &ack).await?;
+                if probe.ack {
+                    return Ok(from);
+                }
+            }
+        }
+    }
+}
+
+/// Sends an acknowledged probe to `peer` every `P2P_PUNCH_KEEPALIVE_SECS`
+/// (starting immediately, which also answers a peer still punching) until the
+/// task is aborted.
+pub fn spawn_keepalive(socket: Arc<UdpSocket>, peer: SocketAddr, nonce: u64) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = interval(Duration::from_secs(P2P_PUNCH_KEEPALIVE_SECS));
+        let probe = P2PPunchProbe { nonce, ack: true };
+        loop {
+            ticker.tick().await;
+            if let Err(e) = send_datagram(&socket, peer, MsgId::P2PPunchProbe, &probe).await {
+                debug!(%peer, error = %e, "Punch keepalive failed");
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start_for(peer: SocketAddr, nonce: u64) -> P2PPunchStart {
+        P2PPunchStart {
+            room_code: 1,
+            peer_client_id: 2,
+            // An unreachable candidate first, like a foreign LAN address.
+            peer_addrs: vec!["127.0.0.1:9".parse().unwrap(), peer],
+            nonce,
+        }
+    }
+
+    #[tokio::test]
+    async fn simultaneous_punch_finds_reachable_address() {
+        let a = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let b = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let (a_addr, b_addr) = (a.local_addr().unwrap(), b.local_addr().unwrap());
+
+        let (a_start, b_start) = (start_for(b_addr, 7), start_for(a_addr, 7));
+        let (a_peer, b_peer) = tokio::join!(punch(&a, &a_start), punch(&b, &b_start));
+        assert_eq!(a_peer.unwrap(), b_addr);
+        assert_eq!(b_peer.unwrap(), a_addr);
+    }
+}
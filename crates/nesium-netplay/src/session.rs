@@ -1,4 +1,5 @@
 use std::collections::{BTreeMap, VecDeque};
+use std::ops::RangeInclusive;
 
 use nesium_netproto::messages::session::TransportKind;
 
@@ -33,6 +34,96 @@ pub struct RemotePlayer {
     pub player_index: u8,
 }
 
+/// Pings older than this without a pong count as lost.
+pub const PING_TIMEOUT_MS: u32 = 2000;
+
+/// Number of recent pings used to compute packet loss.
+const LOSS_WINDOW: usize = 32;
+
+/// Link quality to one peer, as reported to frontends.
+///
+/// Peer id 0 is the server itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PeerNetworkStats {
+    pub peer_client_id: u32,
+    /// Smoothed round-trip time.
+    pub rtt_ms: u32,
+    /// Smoothed mean deviation of the round-trip time.
+    pub jitter_ms: u32,
+    /// Share of recent pings that went unanswered (0-100).
+    pub loss_percent: u8,
+}
+
+/// RTT, jitter and loss tracking for a single peer.
+///
+/// Smoothing follows the TCP estimator (RFC 6298): `srtt` moves 1/8 and
+/// `rttvar` 1/4 of the way towards each new sample.
+#[derive(Debug, Default)]
+pub struct PeerLink {
+    /// (seq, sent_at_ms) of pings still waiting for a pong.
+    outstanding: VecDeque<(u32, u32)>,
+    /// Outcome of recent pings (true = answered in time).
+    outcomes: VecDeque<bool>,
+    srtt_ms: Option<u32>,
+    rttvar_ms: u32,
+}
+
+impl PeerLink {
+    pub fn on_ping_sent(&mut self, seq: u32, now_ms: u32) {
+        self.outstanding.push_back((seq, now_ms));
+    }
+
+    /// Record a pong; returns the RTT sample, or None for unknown/late pongs.
+    pub fn on_pong(&mut self, seq: u32, now_ms: u32) -> Option<u32> {
+        let pos = self.outstanding.iter().position(|&(s, _)| s == seq)?;
+        let (_, sent_ms) = self.outstanding.remove(pos)?;
+        let rtt = now_ms.saturating_sub(sent_ms);
+
+        match self.srtt_ms {
+            None => {
+                self.srtt_ms = Some(rtt);
+                self.rttvar_ms = rtt / 2;
+            }
+            Some(srtt) => {
+                self.rttvar_ms = (3 * self.rttvar_ms + srtt.abs_diff(rtt)) / 4;
+                self.srtt_ms = Some((7 * srtt + rtt) / 8);
+            }
+        }
+        self.record_outcome(true);
+        Some(rtt)
+    }
+
+    /// Count pings older than [`PING_TIMEOUT_MS`] as lost.
+    pub fn expire(&mut self, now_ms: u32) {
+        while let Some(&(_, sent_ms)) = self.outstanding.front() {
+            if now_ms.saturating_sub(sent_ms) < PING_TIMEOUT_MS {
+                break;
+            }
+            self.outstanding.pop_front();
+            self.record_outcome(false);
+        }
+    }
+
+    /// Current stats, or None before the first RTT sample.
+    pub fn stats(&self, peer_client_id: u32) -> Option<PeerNetworkStats> {
+        let rtt_ms = self.srtt_ms?;
+        let lost = self.outcomes.iter().filter(|answered| !**answered).count();
+        Some(PeerNetworkStats {
+            peer_client_id,
+            rtt_ms,
+            jitter_ms: self.rttvar_ms,
+            loss_percent: (lost * 100 / self.outcomes.len().max(1)) as u8,
+        })
+    }
+
+    fn record_outcome(&mut self, answered: bool) {
+        if self.outcomes.len() == LOSS_WINDOW {
+            self.outcomes.pop_front();
+        }
+        self.outcomes.push_back(answered);
+    }
+}
+
 impl Default for SessionState {
     fn default() -> Self {
         Self::Disconnected
@@ -80,6 +171,12 @@ pub struct NetplaySession {
     /// Network-configured input delay in frames.
     pub input_delay_frames: u8,
 
+    /// Last frame a local input was scheduled for.
+    last_local_input_frame: Option<u32>,
+
+    /// Delay change (in frames) not yet reflected in the local input stream.
+    input_delay_shift: i32,
+
     /// Current frame number.
     pub current_frame: u32,
 
@@ -89,8 +186,20 @@ pub struct NetplaySession {
     /// Rewind capacity (frames) negotiated with server.
     pub rewind_capacity: u32,
 
+    /// Session protocol version negotiated with server.
+    pub proto_version: u8,
+
+    /// Capability flags negotiated with server (`capability::*`).
+    pub capabilities: u32,
+
+    /// Frames between state hash reports (0 = desync detection off).
+    pub state_hash_interval: u32,
+
     /// Remote players in the room (client_id -> RemotePlayer).
     pub players: BTreeMap<u32, RemotePlayer>,
+
+    /// Link quality per peer (client_id -> PeerLink, 0 = server).
+    pub peer_links: BTreeMap<u32, PeerLink>,
 }
 
 impl Default for NetplaySession {
@@ -119,10 +228,16 @@ impl NetplaySession {
             pending_local_inputs: VecDeque::new(),
             active_ports: [false; 4],
             input_delay_frames: 2,
+            last_local_input_frame: None,
+            input_delay_shift: 0,
             current_frame: 0,
             server_nonce: 0,
             rewind_capacity: 600,
+            proto_version: 0,
+            capabilities: 0,
+            state_hash_interval: 0,
             players: BTreeMap::new(),
+            peer_links: BTreeMap::new(),
         }
     }
 
@@ -253,6 +368,51 @@ impl NetplaySession {
             queue.clear();
         }
         self.pending_local_inputs.clear();
+        self.last_local_input_frame = None;
+        self.input_delay_shift = 0;
+    }
+
+    /// Stats for every peer with at least one RTT sample.
+    pub fn network_stats(&self) -> Vec<PeerNetworkStats> {
+        self.peer_links
+            .iter()
+            .filter_map(|(&id, link)| link.stats(id))
+            .collect()
+    }
+
+    /// Change the input delay mid-stream; see [`Self::schedule_local_input`].
+    pub fn set_input_delay(&mut self, delay: u8) {
+        if self.last_local_input_frame.is_some() {
+            self.input_delay_shift += delay as i32 - self.input_delay_frames as i32;
+        }
+        self.input_delay_frames = delay;
+    }
+
+    /// Frames to send for a local input scheduled at `frame`, or None if
+    /// there is nothing to send.
+    ///
+    /// Normally just `frame`. Right after the delay was raised, the frames
+    /// skipped over are filled with the same input; after it was lowered,
+    /// inputs for frames that were already sent are dropped. Either way every
+    /// frame gets exactly one local input, which lockstep relies on.
+    pub fn schedule_local_input(&mut self, frame: u32) -> Option<RangeInclusive<u32>> {
+        let Some(last) = self.last_local_input_frame else {
+            self.last_local_input_frame = Some(frame);
+            return Some(frame..=frame);
+        };
+
+        let shift = std::mem::take(&mut self.input_delay_shift);
+        if shift < 0 && frame <= last {
+            self.input_delay_shift = shift;
+            return None;
+        }
+        let start = if shift > 0 && frame > last.wrapping_add(1) {
+            last.wrapping_add(1)
+        } else {
+            frame
+        };
+        self.last_local_input_frame = Some(frame);
+        Some(start..=frame)
     }
 
     /// Clear a specific port's input queue and mark it inactive.
@@ -293,4 +453,48 @@ mod tests {
         assert_eq!(session.queue_depth(0), 0);
         assert_eq!(session.get_input(0, 100), None);
     }
+
+    #[test]
+    fn delay_change_keeps_local_inputs_contiguous() {
+        let mut session = NetplaySession::new();
+        assert_eq!(session.schedule_local_input(0), Some(0..=0));
+        assert_eq!(session.schedule_local_input(1), Some(1..=1));
+        assert_eq!(session.schedule_local_input(2), Some(2..=2));
+
+        // Raised 2 -> 4: the next input jumps ahead and fills the gap.
+        session.set_input_delay(4);
+        assert_eq!(session.schedule_local_input(5), Some(3..=5));
+        assert_eq!(session.schedule_local_input(6), Some(6..=6));
+
+        // Lowered 4 -> 2: frames already sent are skipped once.
+        session.set_input_delay(2);
+        assert_eq!(session.schedule_local_input(5), None);
+        assert_eq!(session.schedule_local_input(6), None);
+        assert_eq!(session.schedule_local_input(7), Some(7..=7));
+    }
+
+    #[test]
+    fn peer_link_smooths_rtt_and_counts_loss() {
+        let mut link = PeerLink::default();
+        assert!(link.stats(7).is_none());
+
+        link.on_ping_sent(1, 1000);
+        assert_eq!(link.on_pong(1, 1080), Some(80));
+        let stats = link.stats(7).unwrap();
+        assert_eq!((stats.rtt_ms, stats.jitter_ms), (80, 40));
+
+        link.on_ping_sent(2, 2000);
+        assert_eq!(link.on_pong(2, 2160), Some(160));
+        let stats = link.stats(7).unwrap();
+        assert_eq!((stats.rtt_ms, stats.jitter_ms), (90, 50));
+
+        // Duplicate pongs are ignored; an unanswered ping expires as lost.
+        assert_eq!(link.on_pong(2, 2200), None);
+        link.on_ping_sent(3, 3000);
+        link.expire(3000 + PING_TIMEOUT_MS - 1);
+        assert_eq!(link.stats(7).unwrap().loss_percent, 0);
+        link.expire(3000 + PING_TIMEOUT_MS);
+        assert_eq!(link.stats(7).unwrap().loss_percent, 33);
+        assert_eq!(link.on_pong(3, 5100), None);
+    }
 }
@@ -0,0 +1,131 @@
+//! Automatic lockstep input delay tuning.
+//!
+//! The recommended delay covers the one-way trip to the slowest player
+//! (half its round-trip time plus some jitter headroom). Raising the delay
+//! needs a couple of agreeing samples; lowering it needs several and only
+//! steps down one frame at a time, so a noisy link does not make the delay
+//! flap.
+
+use crate::session::PeerNetworkStats;
+
+/// Smallest delay the tuner will recommend.
+pub const MIN_INPUT_DELAY: u32 = 1;
+/// Largest delay the tuner will recommend.
+pub const MAX_INPUT_DELAY: u32 = 10;
+
+/// NES frame duration (NTSC, ~60.1 fps).
+const FRAME_MS: f32 = 1000.0 / 60.0988;
+/// Consecutive samples above the current delay before raising it.
+const RAISE_AFTER: u32 = 2;
+/// Consecutive samples below the current delay before lowering it.
+const LOWER_AFTER: u32 = 5;
+
+/// Turns RTT samples into a recommended input delay.
+#[derive(Debug, Clone)]
+pub struct InputDelayTuner {
+    current: u32,
+    above: u32,
+    below: u32,
+}
+
+impl InputDelayTuner {
+    pub fn new(initial: u32) -> Self {
+        Self {
+            current: initial.clamp(MIN_INPUT_DELAY, MAX_INPUT_DELAY),
+            above: 0,
+            below: 0,
+        }
+    }
+
+    /// Current recommendation in frames.
+    pub fn recommended(&self) -> u32 {
+        self.current
+    }
+
+    /// Delay needed to hide the given link, before hysteresis.
+    pub fn target_for(rtt_ms: u32, jitter_ms: u32) -> u32 {
+        let one_way_ms = rtt_ms as f32 / 2.0 + 2.0 * jitter_ms as f32;
+        // One extra frame for the input to be sampled and sent.
+        let frames = (one_way_ms / FRAME_MS).ceil() as u32 + 1;
+        frames.clamp(MIN_INPUT_DELAY, MAX_INPUT_DELAY)
+    }
+
+    /// Feed the latest stats for the other players. Returns the new
+    /// recommendation when it changes.
+    pub fn update(&mut self, players: &[PeerNetworkStats]) -> Option<u32> {
+        let target = players
+            .iter()
+            .map(|p| Self::target_for(p.rtt_ms, p.jitter_ms))
+            .max()?;
+
+        if target > self.current {
+            self.below = 0;
+            self.above += 1;
+            if self.above >= RAISE_AFTER {
+                self.above = 0;
+                self.current = target;
+                return Some(self.current);
+            }
+        } else if target < self.current {
+            self.above = 0;
+            self.below += 1;
+            if self.below >= LOWER_AFTER {
+                self.below = 0;
+                self.current -= 1;
+                return Some(self.current);
+            }
+        } else {
+            self.above = 0;
+            self.below = 0;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(rtt_ms: u32, jitter_ms: u32) -> [PeerNetworkStats; 1] {
+        [PeerNetworkStats {
+            peer_client_id: 2,
+            rtt_ms,
+            jitter_ms,
+            loss_percent: 0,
+        }]
+    }
+
+    #[test]
+    fn target_scales_with_latency() {
+        assert_eq!(InputDelayTuner::target_for(0, 0), MIN_INPUT_DELAY);
+        assert_eq!(InputDelayTuner::target_for(40, 2), 3);
+        assert_eq!(InputDelayTuner::target_for(100, 10), 6);
+        assert_eq!(InputDelayTuner::target_for(2000, 500), MAX_INPUT_DELAY);
+    }
+
+    #[test]
+    fn raises_quickly_and_lowers_slowly() {
+        let mut tuner = InputDelayTuner::new(2);
+        assert_eq!(tuner.update(&[]), None);
+
+        // One spike is not enough; a second sample confirms it.
+        assert_eq!(tuner.update(&stats(100, 10)), None);
+        assert_eq!(tuner.update(&stats(100, 10)), Some(6));
+
+        // Back to a fast link: one frame down per LOWER_AFTER samples.
+        for _ in 0..LOWER_AFTER - 1 {
+            assert_eq!(tuner.update(&stats(10, 0)), None);
+        }
+        assert_eq!(tuner.update(&stats(10, 0)), Some(5));
+        assert_eq!(tuner.recommended(), 5);
+    }
+
+    #[test]
+    fn matching_sample_resets_hysteresis() {
+        let mut tuner = InputDelayTuner::new(3);
+        assert_eq!(tuner.update(&stats(100, 10)), None);
+        assert_eq!(tuner.update(&stats(40, 2)), None);
+        assert_eq!(tuner.update(&stats(100, 10)), None);
+        assert_eq!(tuner.recommended(), 3);
+    }
+}
@@ -5,7 +5,7 @@
 
 use std::collections::BTreeMap;
 
-use super::{RollbackRequest, SyncMode, SyncStrategy};
+use super::{RollbackRequest, RollbackStats, SyncMode, SyncStrategy};
 
 /// Lockstep synchronization implementation.
 ///
@@ -103,6 +103,10 @@ impl SyncStrategy for LockstepSync {
         // No-op for lockstep
     }
 
+    fn rollback_stats(&self) -> RollbackStats {
+        RollbackStats::default()
+    }
+
     fn should_fast_forward(&self, current_frame: u32) -> bool {
         // Fast forward if we have inputs buffered significantly ahead
         let threshold = self.input_delay + 2;
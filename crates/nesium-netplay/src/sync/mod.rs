@@ -3,7 +3,10 @@
 //! This module provides two sync modes:
 //! - **Lockstep**: Wait for all players' inputs before advancing (low latency networks)
 //! - **Rollback**: Predict inputs and rollback on misprediction (high latency networks)
+//!
+//! [`delay`] recommends a lockstep input delay from measured RTT.
 
+pub mod delay;
 pub mod lockstep;
 pub mod rollback;
 pub mod snapshot;
@@ -25,12 +28,44 @@ pub enum SyncMode {
 /// Request to rollback to a specific frame and resimulate.
 #[derive(Debug, Clone)]
 pub struct RollbackRequest {
-    /// Target frame to restore snapshot from.
+    /// Earliest frame whose inputs were mispredicted.
     pub target_frame: u32,
+    /// Frame of the snapshot to restore; at or before `target_frame` when
+    /// snapshots are sparse. Resimulate from here to `current_frame`.
+    pub restore_frame: u32,
     /// Current frame before rollback.
     pub current_frame: u32,
 }
 
+/// Rollback depth metrics, accumulated over a session.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RollbackStats {
+    /// Rollbacks processed.
+    pub rollbacks: u64,
+    /// Depth (`current_frame - target_frame`) of the latest rollback.
+    pub last_depth: u32,
+    /// Deepest rollback so far.
+    pub max_depth: u32,
+    /// Sum of all rollback depths.
+    pub total_depth: u64,
+    /// Frames resimulated, counted from the restored snapshot.
+    pub resimulated_frames: u64,
+    /// Mispredictions older than the rollback window; these cannot be
+    /// corrected and will show up as a desync.
+    pub out_of_window: u64,
+}
+
+impl RollbackStats {
+    /// Mean rollback depth in frames.
+    pub fn average_depth(&self) -> f32 {
+        if self.rollbacks == 0 {
+            0.0
+        } else {
+            self.total_depth as f32 / self.rollbacks as f32
+        }
+    }
+}
+
 /// Strategy trait for different synchronization modes.
 ///
 /// Implementations handle input buffering, prediction, and rollback detection.
@@ -64,6 +99,9 @@ pub trait SyncStrategy: Send + Sync {
     /// Clear the pending rollback after it has been processed.
     fn clear_rollback(&mut self);
 
+    /// Rollback depth metrics (all zero for Lockstep).
+    fn rollback_stats(&self) -> RollbackStats;
+
     /// Check if we should fast-forward to catch up.
     fn should_fast_forward(&self, current_frame: u32) -> bool;
 
@@ -2,10 +2,50 @@
 //!
 //! This strategy predicts remote inputs and rolls back on misprediction.
 //! Best for high-latency networks where waiting would cause noticeable lag.
+//!
+//! Snapshots may be sparse (every K frames, see [`RollbackConfig`]): a
+//! rollback then restores the nearest snapshot at or before the mispredicted
+//! frame and resimulates forward from there.
 
 use std::collections::HashMap;
 
-use super::{RollbackRequest, SyncMode, SyncStrategy};
+use tracing::warn;
+
+use super::{RollbackRequest, RollbackStats, SyncMode, SyncStrategy, snapshot::SnapshotBuffer};
+
+/// Rollback window and snapshot cadence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RollbackConfig {
+    /// How far back (in frames) a misprediction can still be corrected.
+    pub max_rollback_frames: u32,
+    /// Take a snapshot every K frames (1 = every frame). Larger values make
+    /// frames cheaper but rollbacks resimulate up to K - 1 extra frames.
+    pub snapshot_interval: u32,
+}
+
+impl Default for RollbackConfig {
+    fn default() -> Self {
+        Self {
+            max_rollback_frames: 120,
+            snapshot_interval: 1,
+        }
+    }
+}
+
+impl RollbackConfig {
+    /// Frame of the snapshot a rollback to `frame` restores.
+    pub fn snapshot_frame_for(&self, frame: u32) -> u32 {
+        frame - frame % self.snapshot_interval.max(1)
+    }
+
+    /// Snapshot buffer sized to cover the rollback window.
+    pub fn snapshot_buffer(&self) -> SnapshotBuffer {
+        let interval = self.snapshot_interval.max(1);
+        // One extra slot so the window is covered even between snapshots.
+        let capacity = self.max_rollback_frames / interval + 2;
+        SnapshotBuffer::new(capacity as usize, interval)
+    }
+}
 
 /// Rollback synchronization implementation.
 ///
@@ -29,6 +69,10 @@ pub struct RollbackSync {
     local_player: Option<u8>,
     /// Input delay frames.
     input_delay: u32,
+    /// Rollback window and snapshot cadence.
+    config: RollbackConfig,
+    /// Rollback depth metrics.
+    stats: RollbackStats,
 }
 
 impl Default for RollbackSync {
@@ -40,6 +84,11 @@ impl Default for RollbackSync {
 impl RollbackSync {
     /// Create a new rollback sync with the given input delay.
     pub fn new(input_delay: u32) -> Self {
+        Self::with_config(input_delay, RollbackConfig::default())
+    }
+
+    /// Create a new rollback sync with a custom window and snapshot cadence.
+    pub fn with_config(input_delay: u32, config: RollbackConfig) -> Self {
         Self {
             confirmed_inputs: Default::default(),
             last_inputs: [0; 4],
@@ -49,9 +98,16 @@ impl RollbackSync {
             pending_rollback: None,
             local_player: None,
             input_delay,
+            config,
+            stats: RollbackStats::default(),
         }
     }
 
+    /// The rollback window and snapshot cadence in use.
+    pub fn config(&self) -> RollbackConfig {
+        self.config
+    }
+
     /// Set the local player index.
     pub fn set_local_player(&mut self, player: Option<u8>) {
         self.local_player = player;
@@ -101,14 +157,16 @@ impl RollbackSync {
     /// Prune old confirmed inputs.
     fn prune_old_inputs(&mut self, keep_from: u32) {
         for inputs in &mut self.confirmed_inputs {
-            inputs.retain(|&f, _| f >= keep_from.saturating_sub(120));
+            inputs.retain(|&f, _| f >= keep_from.saturating_sub(self.config.max_rollback_frames));
         }
     }
 }
 
 impl SyncStrategy for RollbackSync {
     fn inputs_for_frame(&mut self, frame: u32) -> Option<[u16; 4]> {
-        self.current_frame = frame;
+        // Resimulation polls earlier frames again; keep the horizon so
+        // inputs arriving meanwhile are still checked against predictions.
+        self.current_frame = self.current_frame.max(frame);
 
         let mut inputs = [0u16; 4];
         for (i, &active) in self.active_ports.iter().enumerate() {
@@ -137,7 +195,11 @@ impl SyncStrategy for RollbackSync {
         }
 
         // Ignore inputs for frames that are already finalized and pruned
-        if frame < self.last_confirmed_frame.saturating_sub(120) {
+        if frame
+            < self
+                .last_confirmed_frame
+                .saturating_sub(self.config.max_rollback_frames)
+        {
             return;
         }
 
@@ -153,7 +215,11 @@ impl SyncStrategy for RollbackSync {
         }
 
         // Ignore inputs for frames that are already finalized and pruned
-        if frame < self.last_confirmed_frame.saturating_sub(120) {
+        if frame
+            < self
+                .last_confirmed_frame
+                .saturating_sub(self.config.max_rollback_frames)
+        {
             return;
         }
 
@@ -166,10 +232,22 @@ impl SyncStrategy for RollbackSync {
                     Some(rb) => rb.target_frame.min(frame),
                     None => frame,
                 };
-                self.pending_rollback = Some(RollbackRequest {
-                    target_frame: target,
-                    current_frame: self.current_frame,
-                });
+                let restore_frame = self.config.snapshot_frame_for(target);
+                if self.current_frame - restore_frame > self.config.max_rollback_frames {
+                    // The snapshot we would need is already gone.
+                    warn!(
+                        frame,
+                        current_frame = self.current_frame,
+                        "Misprediction outside the rollback window"
+                    );
+                    self.stats.out_of_window += 1;
+                } else {
+                    self.pending_rollback = Some(RollbackRequest {
+                        target_frame: target,
+                        restore_frame,
+                        current_frame: self.current_frame,
+                    });
+                }
             }
         }
 
@@ -184,7 +262,18 @@ impl SyncStrategy for RollbackSync {
     }
 
     fn clear_rollback(&mut self) {
-        self.pending_rollback = None;
+        if let Some(rb) = self.pending_rollback.take() {
+            let depth = rb.current_frame - rb.target_frame;
+            self.stats.rollbacks += 1;
+            self.stats.last_depth = depth;
+            self.stats.max_depth = self.stats.max_depth.max(depth);
+            self.stats.total_depth += depth as u64;
+            self.stats.resimulated_frames += (rb.current_frame - rb.restore_frame) as u64;
+        }
+    }
+
+    fn rollback_stats(&self) -> RollbackStats {
+        self.stats
     }
 
     fn should_fast_forward(&self, current_frame: u32) -> bool {
@@ -471,6 +560,76 @@ mod tests {
         assert_eq!(rb.target_frame, 5);
     }
 
+    #[test]
+    fn sparse_snapshots_restore_from_nearest_snapshot() {
+        let config = RollbackConfig {
+            max_rollback_frames: 60,
+            snapshot_interval: 4,
+        };
+        let mut sync = RollbackSync::with_config(0, config);
+        sync.set_port_active(0, true);
+        sync.set_port_active(1, true);
+
+        for f in 0..=12 {
+            sync.on_local_input(0, f, 0x01);
+            sync.inputs_for_frame(f);
+        }
+
+        sync.on_remote_input(1, 7, 0x02);
+        let rb = sync.pending_rollback().unwrap();
+        assert_eq!(rb.target_frame, 7);
+        assert_eq!(rb.restore_frame, 4);
+        assert_eq!(rb.current_frame, 12);
+
+        sync.clear_rollback();
+        let stats = sync.rollback_stats();
+        assert_eq!(stats.rollbacks, 1);
+        assert_eq!(stats.last_depth, 5);
+        assert_eq!(stats.max_depth, 5);
+        assert_eq!(stats.resimulated_frames, 8);
+        assert_eq!(stats.average_depth(), 5.0);
+    }
+
+    #[test]
+    fn misprediction_beyond_window_is_counted_not_requested() {
+        let config = RollbackConfig {
+            max_rollback_frames: 8,
+            snapshot_interval: 1,
+        };
+        let mut sync = RollbackSync::with_config(0, config);
+        sync.set_port_active(0, true);
+        sync.set_port_active(1, true);
+
+        // Remote player is silent, so prediction runs far ahead.
+        for f in 0..=20 {
+            sync.on_local_input(0, f, 0x01);
+            sync.inputs_for_frame(f);
+        }
+
+        sync.on_remote_input(1, 5, 0x02);
+        assert!(sync.pending_rollback().is_none());
+        assert_eq!(sync.rollback_stats().out_of_window, 1);
+
+        sync.on_remote_input(1, 15, 0x03);
+        assert_eq!(sync.pending_rollback().unwrap().restore_frame, 15);
+    }
+
+    #[test]
+    fn snapshot_buffer_covers_window() {
+        let config = RollbackConfig {
+            max_rollback_frames: 60,
+            snapshot_interval: 4,
+        };
+        let mut buf = config.snapshot_buffer();
+        for f in 0..=200 {
+            if buf.should_save(f) {
+                buf.push(f, vec![]);
+            }
+        }
+        let oldest_needed = config.snapshot_frame_for(200 - 60);
+        assert!(buf.oldest_frame().unwrap() <= oldest_needed);
+    }
+
     #[test]
     fn rollback_pruning_safety() {
         let mut sync = RollbackSync::new(0);
@@ -4,14 +4,22 @@
 //! - Connection to server
 //! - Message framing and encoding/decoding
 //! - Async send/receive loops
+//!
+//! Besides TCP it runs over TLS, QUIC streams and WebRTC data channels; all of
+//! them carry the same framed byte stream.
 
 use std::net::SocketAddr;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll};
 
-use bytes::{Buf, BytesMut};
+use bytes::{Buf, Bytes, BytesMut};
 use nesium_netproto::{
     channel::{ChannelKind, channel_for_msg},
-    codec_tcp::{encode_tcp_frame_auto, try_decode_tcp_frames},
+    codec_tcp::{compress_tcp_frame, encode_tcp_frame_auto, try_decode_tcp_frames},
+    constants::{DATA_CHANNEL_MAX_MESSAGE, TLS_ALPN},
+    error::ProtoError,
     header::Header,
     messages::session::AttachChannel,
     messages::session::TransportKind,
@@ -19,11 +27,13 @@ use nesium_netproto::{
     packet::PacketView,
 };
 use ring::digest;
+use rustls::pki_types::ServerName;
 use tokio::{
-    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf},
     net::TcpStream,
     sync::{Mutex, mpsc},
 };
+use tokio_rustls::TlsConnector;
 use tracing::{debug, error, info, trace, warn};
 
 use crate::error::NetplayError;
@@ -36,13 +46,16 @@ pub struct PacketOwned {
     pub payload: bytes::Bytes,
 }
 
-impl<'a> From<PacketView<'a>> for PacketOwned {
-    fn from(view: PacketView<'a>) -> Self {
-        Self {
+/// Copies the payload out of the receive buffer, inflating compressed frames.
+impl<'a> TryFrom<PacketView<'a>> for PacketOwned {
+    type Error = ProtoError;
+
+    fn try_from(view: PacketView<'a>) -> Result<Self, ProtoError> {
+        Ok(Self {
             header: view.header,
             msg_id: view.msg_id,
-            payload: bytes::Bytes::copy_from_slice(view.payload),
-        }
+            payload: bytes::Bytes::from(view.decompressed_payload()?.into_owned()),
+        })
     }
 }
 
@@ -78,11 +91,19 @@ enum TransportBackend {
     Tcp {
         addr: SocketAddr,
     },
+    /// TCP wrapped in TLS; extra channels open their own TLS connections.
+    Tls {
+        addr: SocketAddr,
+        server_name: ServerName<'static>,
+        connector: TlsConnector,
+    },
     Quic {
         #[allow(dead_code)]
         endpoint: quinn::Endpoint,
         connection: quinn::Connection,
     },
+    /// A single WebRTC data channel; logical channels are not split.
+    DataChannel,
 }
 
 struct TcpClientInner {
@@ -91,6 +112,8 @@ struct TcpClientInner {
     control_cmd_tx: mpsc::Sender<TcpClientCommand>,
     input_cmd_tx: Mutex<Option<mpsc::Sender<TcpClientCommand>>>,
     bulk_cmd_tx: Mutex<Option<mpsc::Sender<TcpClientCommand>>>,
+    /// Compress bulk frames; set once the server negotiates it.
+    compress: AtomicBool,
 }
 
 impl TcpClientHandle {
@@ -103,13 +126,19 @@ impl TcpClientHandle {
         msg_id: MsgId,
         payload: &T,
     ) -> Result<(), NetplayError> {
-        let bytes = encode_tcp_frame_auto(header, msg_id, payload)?;
+        let mut bytes = encode_tcp_frame_auto(header, msg_id, payload)?;
+        if self.inner.compress.load(Ordering::Relaxed)
+            && let Some(compressed) = compress_tcp_frame(&bytes)
+        {
+            bytes = compressed;
+        }
         let raw = bytes::Bytes::from(bytes);
 
         let preferred = channel_for_msg(msg_id);
         let cmd_tx = match preferred {
             ChannelKind::Control => self.inner.control_cmd_tx.clone(),
-            ChannelKind::Input => self
+            // The datagram channel is unreliable; only the server relays on it.
+            ChannelKind::Input | ChannelKind::Datagram => self
                 .inner
                 .input_cmd_tx
                 .lock()
@@ -142,6 +171,12 @@ impl TcpClientHandle {
         Ok(())
     }
 
+    /// Compress bulk frames sent from now on. Only enable this once the server
+    /// has granted `capability::COMPRESSION`.
+    pub fn set_compression(&self, enabled: bool) {
+        self.inner.compress.store(enabled, Ordering::Relaxed);
+    }
+
     /// Attach a secondary logical channel by opening an additional TCP connection.
     ///
     /// This is used as a TCP fallback for transports that support true multiplexing (e.g. QUIC).
@@ -150,17 +185,39 @@ impl TcpClientHandle {
         session_token: u64,
         channel: ChannelKind,
     ) -> Result<(), NetplayError> {
-        if channel == ChannelKind::Control {
+        if channel == ChannelKind::Control
+            || matches!(self.inner.backend, TransportBackend::DataChannel)
+        {
             return Ok(());
         }
+        if channel == ChannelKind::Datagram {
+            return Err(NetplayError::ConnectionFailed(
+                "the datagram channel needs a UDP socket".to_string(),
+            ));
+        }
 
         let cmd_tx = match &self.inner.backend {
             TransportBackend::Tcp { addr } => {
                 spawn_tcp_connection(*addr, self.inner.event_tx.clone(), false).await?
             }
+            TransportBackend::Tls {
+                addr,
+                server_name,
+                connector,
+            } => {
+                spawn_tls_connection(
+                    *addr,
+                    connector,
+                    server_name.clone(),
+                    self.inner.event_tx.clone(),
+                    false,
+                )
+                .await?
+            }
             TransportBackend::Quic { connection, .. } => {
                 spawn_quic_stream(connection.clone(), self.inner.event_tx.clone(), false).await?
             }
+            TransportBackend::DataChannel => unreachable!("handled above"),
         };
 
         let msg = AttachChannel {
@@ -177,7 +234,7 @@ impl TcpClientHandle {
         match channel {
             ChannelKind::Input => *self.inner.input_cmd_tx.lock().await = Some(cmd_tx),
             ChannelKind::Bulk => *self.inner.bulk_cmd_tx.lock().await = Some(cmd_tx),
-            ChannelKind::Control => {}
+            ChannelKind::Control | ChannelKind::Datagram => {}
         }
 
         Ok(())
@@ -231,6 +288,38 @@ async fn spawn_tcp_connection(
     Ok(cmd_tx)
 }
 
+async fn spawn_tls_connection(
+    addr: SocketAddr,
+    connector: &TlsConnector,
+    server_name: ServerName<'static>,
+    event_tx: mpsc::Sender<TcpClientEvent>,
+    emit_lifecycle: bool,
+) -> Result<mpsc::Sender<TcpClientCommand>, NetplayError> {
+    let stream = TcpStream::connect(addr).await.map_err(|e| {
+        NetplayError::ConnectionFailed(format!("Failed to connect to {}: {}", addr, e))
+    })?;
+    let _ = stream.set_nodelay(true);
+
+    let stream = connector
+        .connect(server_name, stream)
+        .await
+        .map_err(|e| NetplayError::ConnectionFailed(format!("TLS handshake failed: {}", e)))?;
+    let (read_half, write_half) = tokio::io::split(stream);
+
+    let (cmd_tx, cmd_rx) = mpsc::channel::<TcpClientCommand>(256);
+
+    let event_tx_writer = event_tx.clone();
+    tokio::spawn(async move {
+        writer_loop(write_half, cmd_rx, event_tx_writer, emit_lifecycle).await;
+    });
+
+    tokio::spawn(async move {
+        reader_loop(read_half, event_tx, emit_lifecycle).await;
+    });
+
+    Ok(cmd_tx)
+}
+
 async fn spawn_quic_stream(
     connection: quinn::Connection,
     event_tx: mpsc::Sender<TcpClientEvent>,
@@ -254,6 +343,87 @@ async fn spawn_quic_stream(
     Ok(cmd_tx)
 }
 
+/// Adapts data channel messages to a byte stream for [`reader_loop`].
+struct DataChannelReader {
+    incoming: mpsc::Receiver<Bytes>,
+    pending: Bytes,
+}
+
+impl AsyncRead for DataChannelReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        while this.pending.is_empty() {
+            match this.incoming.poll_recv(cx) {
+                Poll::Ready(Some(msg)) => this.pending = msg,
+                // Channel closed: EOF.
+                Poll::Ready(None) => return Poll::Ready(Ok(())),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        let n = buf.remaining().min(this.pending.len());
+        buf.put_slice(&this.pending[..n]);
+        this.pending.advance(n);
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Start a client over an open WebRTC data channel (browser builds).
+///
+/// The platform's WebRTC stack owns the channel: it pumps received messages
+/// into `incoming` and sends every message from `outgoing`, closing the
+/// channel when `outgoing`'s receiver sees the end. Frames are split into
+/// messages of at most [`DATA_CHANNEL_MAX_MESSAGE`] bytes. Negotiate the
+/// channel with `RtcSignal` through the signaling server's P2P room, and use
+/// [`TransportKind::WebRtc`] in the `Hello`.
+pub fn connect_data_channel(
+    outgoing: mpsc::Sender<Bytes>,
+    incoming: mpsc::Receiver<Bytes>,
+    event_tx: mpsc::Sender<TcpClientEvent>,
+) -> TcpClientHandle {
+    info!("Connecting to netplay host over a WebRTC data channel");
+
+    let (cmd_tx, mut cmd_rx) = mpsc::channel::<TcpClientCommand>(256);
+
+    let event_tx_writer = event_tx.clone();
+    tokio::spawn(async move {
+        while let Some(TcpClientCommand::SendRaw(bytes)) = cmd_rx.recv().await {
+            for chunk in bytes.chunks(DATA_CHANNEL_MAX_MESSAGE) {
+                if outgoing.send(bytes.slice_ref(chunk)).await.is_err() {
+                    let _ = event_tx_writer
+                        .send(TcpClientEvent::Error("data channel closed".to_string()))
+                        .await;
+                    return;
+                }
+            }
+        }
+        // Disconnect or handle dropped: dropping `outgoing` closes the channel.
+    });
+
+    let reader = DataChannelReader {
+        incoming,
+        pending: Bytes::new(),
+    };
+    let event_tx_reader = event_tx.clone();
+    tokio::spawn(async move {
+        reader_loop(reader, event_tx_reader, true).await;
+    });
+
+    TcpClientHandle {
+        inner: Arc::new(TcpClientInner {
+            backend: TransportBackend::DataChannel,
+            event_tx,
+            control_cmd_tx: cmd_tx,
+            input_cmd_tx: Mutex::new(None),
+            bulk_cmd_tx: Mutex::new(None),
+            compress: AtomicBool::new(false),
+        }),
+    }
+}
+
 /// Start a TCP client connection to the given address.
 ///
 /// Returns a handle for sending commands and spawns background tasks
@@ -273,6 +443,7 @@ pub async fn connect(
             control_cmd_tx,
             input_cmd_tx: Mutex::new(None),
             bulk_cmd_tx: Mutex::new(None),
+            compress: AtomicBool::new(false),
         }),
     })
 }
@@ -295,14 +466,72 @@ pub async fn connect_quic_pinned(
     connect_quic_inner(addr, server_name, event_tx, Some(expected)).await
 }
 
-async fn connect_quic_inner(
+/// Start a TLS-encrypted TCP client connection, verifying the server
+/// certificate against the platform's root store.
+pub async fn connect_tls(
+    addr: SocketAddr,
+    server_name: &str,
+    event_tx: mpsc::Sender<TcpClientEvent>,
+) -> Result<TcpClientHandle, NetplayError> {
+    connect_tls_inner(addr, server_name, event_tx, None).await
+}
+
+/// Like [`connect_tls`], but only accepts the server certificate with the given
+/// SHA-256 fingerprint (hex or base64url), e.g. netd's self-signed one.
+pub async fn connect_tls_pinned(
+    addr: SocketAddr,
+    server_name: &str,
+    pinned_sha256_fingerprint: &str,
+    event_tx: mpsc::Sender<TcpClientEvent>,
+) -> Result<TcpClientHandle, NetplayError> {
+    let expected = parse_sha256_fingerprint(pinned_sha256_fingerprint)?;
+    connect_tls_inner(addr, server_name, event_tx, Some(expected)).await
+}
+
+async fn connect_tls_inner(
     addr: SocketAddr,
     server_name: &str,
     event_tx: mpsc::Sender<TcpClientEvent>,
     pinned_sha256: Option<[u8; 32]>,
 ) -> Result<TcpClientHandle, NetplayError> {
-    info!("Connecting to netplay server (QUIC) at {}", addr);
+    info!("Connecting to netplay server (TLS) at {}", addr);
+
+    let mut tls = client_tls_config(pinned_sha256)?;
+    tls.alpn_protocols = vec![TLS_ALPN.to_vec()];
+    let connector = TlsConnector::from(Arc::new(tls));
+    let server_name = ServerName::try_from(server_name.to_string())
+        .map_err(|e| NetplayError::ConnectionFailed(format!("Invalid TLS server name: {}", e)))?;
+
+    let control_cmd_tx = spawn_tls_connection(
+        addr,
+        &connector,
+        server_name.clone(),
+        event_tx.clone(),
+        true,
+    )
+    .await?;
+
+    Ok(TcpClientHandle {
+        inner: Arc::new(TcpClientInner {
+            backend: TransportBackend::Tls {
+                addr,
+                server_name,
+                connector,
+            },
+            event_tx,
+            control_cmd_tx,
+            input_cmd_tx: Mutex::new(None),
+            bulk_cmd_tx: Mutex::new(None),
+            compress: AtomicBool::new(false),
+        }),
+    })
+}
 
+/// Client TLS config shared by QUIC and TLS-over-TCP: either pinned to one
+/// certificate fingerprint or verified against the native root store.
+fn client_tls_config(
+    pinned_sha256: Option<[u8; 32]>,
+) -> Result<rustls::ClientConfig, NetplayError> {
     let tls = if let Some(expected) = pinned_sha256 {
         // Ensure a crypto provider is available for signature verification.
         // This is normally installed lazily by `ClientConfig::builder()`, but we depend on it here.
@@ -312,7 +541,7 @@ async fn connect_quic_inner(
             .map(|p| p.signature_verification_algorithms)
             .ok_or_else(|| {
                 NetplayError::ConnectionFailed(
-                    "No rustls CryptoProvider available for pinned QUIC".to_string(),
+                    "No rustls CryptoProvider available for pinned TLS".to_string(),
                 )
             })?;
 
@@ -343,7 +572,18 @@ async fn connect_quic_inner(
             .with_root_certificates(roots)
             .with_no_client_auth()
     };
+    Ok(tls)
+}
 
+async fn connect_quic_inner(
+    addr: SocketAddr,
+    server_name: &str,
+    event_tx: mpsc::Sender<TcpClientEvent>,
+    pinned_sha256: Option<[u8; 32]>,
+) -> Result<TcpClientHandle, NetplayError> {
+    info!("Connecting to netplay server (QUIC) at {}", addr);
+
+    let tls = client_tls_config(pinned_sha256)?;
     let crypto = quinn::crypto::rustls::QuicClientConfig::try_from(tls).map_err(|e| {
         NetplayError::ConnectionFailed(format!("Failed to build QUIC crypto config: {}", e))
     })?;
@@ -393,6 +633,7 @@ async fn connect_quic_inner(
             control_cmd_tx,
             input_cmd_tx: Mutex::new(None),
             bulk_cmd_tx: Mutex::new(None),
+            compress: AtomicBool::new(false),
         }),
     })
 }
@@ -559,14 +800,17 @@ pub async fn connect_auto_pinned(
     .await
     {
         Ok(Ok(handle)) => Ok((handle, TransportKind::Quic)),
+        // Keep the pin on the fallback path: plain TCP would bypass it.
         Ok(Err(e)) => {
-            warn!(error = %e, "Pinned QUIC connect failed; falling back to TCP");
-            let handle = connect(addr, event_tx).await?;
+            warn!(error = %e, "Pinned QUIC connect failed; falling back to TLS");
+            let handle =
+                connect_tls_pinned(addr, server_name, pinned_sha256_fingerprint, event_tx).await?;
             Ok((handle, TransportKind::Tcp))
         }
         Err(_) => {
-            warn!("Pinned QUIC connect timed out; falling back to TCP");
-            let handle = connect(addr, event_tx).await?;
+            warn!("Pinned QUIC connect timed out; falling back to TLS");
+            let handle =
+                connect_tls_pinned(addr, server_name, pinned_sha256_fingerprint, event_tx).await?;
             Ok((handle, TransportKind::Tcp))
         }
     }
@@ -643,10 +887,16 @@ async fn reader_loop(
                 trace!("Received {} bytes from server", n);
 
                 // Try to decode frames
-                match try_decode_tcp_frames(&buf) {
-                    Ok((views, consumed)) => {
-                        for view in views {
-                            let packet = PacketOwned::from(view);
+                let decoded = try_decode_tcp_frames(&buf).and_then(|(views, consumed)| {
+                    let packets = views
+                        .into_iter()
+                        .map(PacketOwned::try_from)
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok((packets, consumed))
+                });
+                match decoded {
+                    Ok((packets, consumed)) => {
+                        for packet in packets {
                             debug!("Received {:?} from server", packet.msg_id);
                             if event_tx.send(TcpClientEvent::Packet(packet)).await.is_err() {
                                 warn!("Event channel closed");
@@ -690,13 +940,53 @@ async fn reader_loop(
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn data_channel_splits_and_reassembles_frames() {
+        let (out_tx, mut out_rx) = mpsc::channel(64);
+        let (in_tx, in_rx) = mpsc::channel(64);
+        let (event_tx, mut event_rx) = mpsc::channel(16);
+        let client = connect_data_channel(out_tx, in_rx, event_tx);
+
+        // Larger than one data channel message.
+        let payload = nesium_netproto::messages::session::SyncState {
+            frame: 9,
+            data: vec![7; DATA_CHANNEL_MAX_MESSAGE * 2],
+        };
+        let h = Header::new(MsgId::SyncState as u8);
+        client
+            .send_message(h, MsgId::SyncState, &payload)
+            .await
+            .unwrap();
+
+        // Loop the sent messages back as received ones.
+        let frame_len = encode_tcp_frame_auto(h, MsgId::SyncState, &payload)
+            .unwrap()
+            .len();
+        let mut sizes = Vec::new();
+        while sizes.iter().sum::<usize>() < frame_len {
+            let msg = out_rx.recv().await.unwrap();
+            sizes.push(msg.len());
+            in_tx.send(msg).await.unwrap();
+        }
+        assert!(sizes.len() > 2 && sizes.iter().all(|&n| n <= DATA_CHANNEL_MAX_MESSAGE));
+
+        assert!(matches!(
+            event_rx.recv().await,
+            Some(TcpClientEvent::Connected)
+        ));
+        let Some(TcpClientEvent::Packet(packet)) = event_rx.recv().await else {
+            panic!("expected a packet");
+        };
+        assert_eq!(packet.msg_id, MsgId::SyncState);
+    }
+
     #[test]
     fn packet_owned_conversion() {
         let header = Header::new(MsgId::Ping as u8);
         let payload = &[1, 2, 3];
         let view = PacketView::new(header, MsgId::Ping, payload);
 
-        let owned = PacketOwned::from(view);
+        let owned = PacketOwned::try_from(view).unwrap();
         assert_eq!(owned.msg_id, MsgId::Ping);
         assert_eq!(owned.payload.as_ref(), &[1, 2, 3]);
     }
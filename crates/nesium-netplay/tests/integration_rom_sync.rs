@@ -2,7 +2,8 @@ use nesium_netd::net::quic_config;
 use nesium_netd::net::tcp::run_tcp_listener_with_listener;
 // use nesium_netd::run_server;
 use nesium_netplay::{
-    NetplayCommand, NetplayConfig, NetplayEvent, SessionHandler, connect, create_input_provider,
+    NetplayCommand, NetplayConfig, NetplayEvent, NetplayInputProvider, SessionHandler,
+    SessionState, connect, create_input_provider,
 };
 use nesium_netproto::messages::session::TransportKind;
 // use nesium_netproto::messages::session::LoadRom;
@@ -51,6 +52,11 @@ async fn test_rom_sync_flow() {
         transport: TransportKind::Tcp,
         spectator: false,
         room_code: 0, // Create room
+        state_hash_interval: 0,
+        auto_resync: false,
+        ping_interval_ms: 0,
+        auto_input_delay: false,
+        user_token: None,
     };
     let (mut c1_handler, c1_cmd) = SessionHandler::new(
         c1_handle,
@@ -65,7 +71,7 @@ async fn test_rom_sync_flow() {
     sleep(Duration::from_millis(50)).await;
 
     // Create Room
-    c1_cmd.send(NetplayCommand::CreateRoom).await.unwrap();
+    c1_cmd.send(NetplayCommand::CreateRoom(None)).await.unwrap();
 
     // Wait for room creation
     sleep(Duration::from_millis(100)).await;
@@ -84,6 +90,11 @@ async fn test_rom_sync_flow() {
         transport: nesium_netproto::messages::session::TransportKind::Tcp,
         spectator: false,
         room_code: room_id,
+        state_hash_interval: 0,
+        auto_resync: false,
+        ping_interval_ms: 0,
+        auto_input_delay: false,
+        user_token: None,
     };
     let (mut c2_handler, c2_cmd) = SessionHandler::new(
         c2_handle,
@@ -99,7 +110,7 @@ async fn test_rom_sync_flow() {
     // 5. Player 1 Loads ROM
     let rom_data = vec![0x1, 0x2, 0x3, 0x4];
     c1_cmd
-        .send(NetplayCommand::SendRom(rom_data.clone()))
+        .send(NetplayCommand::SendRom(rom_data.clone(), None))
         .await
         .unwrap();
 
@@ -111,8 +122,8 @@ async fn test_rom_sync_flow() {
     }
 
     // 7. Both Players Confirm Load
-    c1_cmd.send(NetplayCommand::RomLoaded).await.unwrap();
-    c2_cmd.send(NetplayCommand::RomLoaded).await.unwrap();
+    c1_cmd.send(NetplayCommand::RomLoaded(None)).await.unwrap();
+    c2_cmd.send(NetplayCommand::RomLoaded(None)).await.unwrap();
 
     // 8. Verify Both Players Receive StartGame
     // Note: C1 might receive it first or second depending on server order/network
@@ -173,6 +184,11 @@ async fn test_late_join_receives_cached_rom_and_state() {
         transport: nesium_netproto::messages::session::TransportKind::Tcp,
         spectator: false,
         room_code: 0, // Create room
+        state_hash_interval: 0,
+        auto_resync: false,
+        ping_interval_ms: 0,
+        auto_input_delay: false,
+        user_token: None,
     };
     let (mut c1_handler, c1_cmd) = SessionHandler::new(
         c1_handle,
@@ -184,7 +200,7 @@ async fn test_late_join_receives_cached_rom_and_state() {
     tokio::spawn(async move { c1_handler.run().await });
 
     sleep(Duration::from_millis(50)).await;
-    c1_cmd.send(NetplayCommand::CreateRoom).await.unwrap();
+    c1_cmd.send(NetplayCommand::CreateRoom(None)).await.unwrap();
     sleep(Duration::from_millis(100)).await;
 
     let room_id = c1_input.with_session(|s| s.room_id);
@@ -193,10 +209,10 @@ async fn test_late_join_receives_cached_rom_and_state() {
     // 3. Host sends ROM, then confirms loaded -> StartGame should be broadcast.
     let rom_data = vec![0xDE, 0xAD, 0xBE, 0xEF];
     c1_cmd
-        .send(NetplayCommand::SendRom(rom_data.clone()))
+        .send(NetplayCommand::SendRom(rom_data.clone(), None))
         .await
         .unwrap();
-    c1_cmd.send(NetplayCommand::RomLoaded).await.unwrap();
+    c1_cmd.send(NetplayCommand::RomLoaded(None)).await.unwrap();
 
     // Wait for host StartGame.
     let event = c1_game_rx
@@ -236,6 +252,11 @@ async fn test_late_join_receives_cached_rom_and_state() {
         transport: nesium_netproto::messages::session::TransportKind::Tcp,
         spectator: false,
         room_code: room_id,
+        state_hash_interval: 0,
+        auto_resync: false,
+        ping_interval_ms: 0,
+        auto_input_delay: false,
+        user_token: None,
     };
     let (mut c2_handler, c2_cmd) = SessionHandler::new(
         c2_handle,
@@ -255,17 +276,42 @@ async fn test_late_join_receives_cached_rom_and_state() {
         NetplayEvent::LoadRom(data) => assert_eq!(data, rom_data),
         other => panic!("Expected LoadRom, got {:?}", other),
     }
-    c2_cmd.send(NetplayCommand::RomLoaded).await.unwrap();
+    c2_cmd.send(NetplayCommand::RomLoaded(None)).await.unwrap();
+
+    // 7. The server asks the host for a fresh snapshot instead of using the cached one.
+    let mut requested = false;
+    for _ in 0..50 {
+        if c1_input.take_state_capture_request() {
+            requested = true;
+            break;
+        }
+        sleep(Duration::from_millis(20)).await;
+    }
+    assert!(requested, "Host should be asked to capture state");
+    assert!(
+        c2_game_rx.try_recv().is_err(),
+        "Catch-up should wait for the fresh snapshot"
+    );
 
-    // 7. Late joiner should receive SyncState + StartGame (order preserved by TCP).
+    let fresh_frame = 8u32;
+    let fresh_state = vec![0xDD, 0xEE];
+    c1_cmd
+        .send(NetplayCommand::ProvideState(
+            fresh_frame,
+            fresh_state.clone(),
+        ))
+        .await
+        .unwrap();
+
+    // 8. Late joiner should receive SyncState + StartGame (order preserved by TCP).
     let event = c2_game_rx
         .recv()
         .await
         .expect("Late joiner should receive SyncState");
     match event {
         NetplayEvent::SyncState(frame, data) => {
-            assert_eq!(frame, cached_frame);
-            assert_eq!(data, cached_state);
+            assert_eq!(frame, fresh_frame);
+            assert_eq!(data, fresh_state);
         }
         other => panic!("Expected SyncState, got {:?}", other),
     }
@@ -275,4 +321,12 @@ async fn test_late_join_receives_cached_rom_and_state() {
         .await
         .expect("Late joiner should receive StartGame (BeginCatchUp)");
     assert!(matches!(event, NetplayEvent::StartGame));
+
+    // JoinAck announced the cached frame; BeginCatchUp rebased onto the fresh one.
+    let start_frame = c2_input.with_session(|s| match s.state {
+        SessionState::Playing { start_frame, .. } => start_frame,
+        ref other => panic!("Expected Playing, got {:?}", other),
+    });
+    assert_eq!(start_frame, fresh_frame);
+    assert_ne!(start_frame, cached_frame);
 }
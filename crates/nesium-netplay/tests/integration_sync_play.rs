@@ -1,7 +1,8 @@
 use nesium_netplay::{
     NetplayCommand, NetplayConfig, NetplayEvent, NetplayInputProvider, SessionHandler, SyncMode,
-    connect, create_input_provider,
+    connect, connect_tls_pinned, create_input_provider,
 };
+use nesium_netproto::messages::lobby::{RoomFilter, RoomInfo};
 use nesium_netproto::messages::session::TransportKind;
 use std::net::SocketAddr;
 use tokio::sync::mpsc;
@@ -52,13 +53,18 @@ async fn test_lockstep_gameplay_sync() {
             transport: TransportKind::Tcp,
             spectator: false,
             room_code: 0,
+            state_hash_interval: 0,
+            auto_resync: false,
+            ping_interval_ms: 0,
+            auto_input_delay: false,
+            user_token: None,
         },
         c1_input.clone(),
         c1_event_rx,
         c1_game_tx,
     );
     tokio::spawn(async move { c1_handler.run().await });
-    c1_cmd.send(NetplayCommand::CreateRoom).await.unwrap();
+    c1_cmd.send(NetplayCommand::CreateRoom(None)).await.unwrap();
     sleep(Duration::from_millis(100)).await;
     let room_id = c1_input.with_session(|s| s.room_id);
 
@@ -74,6 +80,11 @@ async fn test_lockstep_gameplay_sync() {
             transport: TransportKind::Tcp,
             spectator: false,
             room_code: room_id,
+            state_hash_interval: 0,
+            auto_resync: false,
+            ping_interval_ms: 0,
+            auto_input_delay: false,
+            user_token: None,
         },
         c2_input.clone(),
         c2_event_rx,
@@ -83,10 +94,13 @@ async fn test_lockstep_gameplay_sync() {
     sleep(Duration::from_millis(100)).await;
 
     // Load ROM and start game in Lockstep (default)
-    c1_cmd.send(NetplayCommand::SendRom(vec![1])).await.unwrap();
+    c1_cmd
+        .send(NetplayCommand::SendRom(vec![1], None))
+        .await
+        .unwrap();
     let _ = c2_game_rx.recv().await; // Receive LoadRom
-    c1_cmd.send(NetplayCommand::RomLoaded).await.unwrap();
-    c2_cmd.send(NetplayCommand::RomLoaded).await.unwrap();
+    c1_cmd.send(NetplayCommand::RomLoaded(None)).await.unwrap();
+    c2_cmd.send(NetplayCommand::RomLoaded(None)).await.unwrap();
 
     // Both should receive StartGame
     assert!(matches!(
@@ -147,6 +161,11 @@ async fn test_rollback_gameplay_sync() {
             transport: TransportKind::Tcp,
             spectator: false,
             room_code: 0,
+            state_hash_interval: 0,
+            auto_resync: false,
+            ping_interval_ms: 0,
+            auto_input_delay: false,
+            user_token: None,
         },
         c1_input.clone(),
         c1_event_rx,
@@ -156,7 +175,7 @@ async fn test_rollback_gameplay_sync() {
 
     // Host decides the room sync mode at creation time.
     c1_input.set_sync_mode(SyncMode::Rollback);
-    c1_cmd.send(NetplayCommand::CreateRoom).await.unwrap();
+    c1_cmd.send(NetplayCommand::CreateRoom(None)).await.unwrap();
     sleep(Duration::from_millis(100)).await;
     let room_id = c1_input.with_session(|s| s.room_id);
 
@@ -172,6 +191,11 @@ async fn test_rollback_gameplay_sync() {
             transport: TransportKind::Tcp,
             spectator: false,
             room_code: room_id,
+            state_hash_interval: 0,
+            auto_resync: false,
+            ping_interval_ms: 0,
+            auto_input_delay: false,
+            user_token: None,
         },
         c2_input.clone(),
         c2_event_rx,
@@ -181,10 +205,13 @@ async fn test_rollback_gameplay_sync() {
     sleep(Duration::from_millis(100)).await;
 
     // Start game
-    c1_cmd.send(NetplayCommand::SendRom(vec![1])).await.unwrap();
+    c1_cmd
+        .send(NetplayCommand::SendRom(vec![1], None))
+        .await
+        .unwrap();
     let _ = c2_game_rx.recv().await;
-    c1_cmd.send(NetplayCommand::RomLoaded).await.unwrap();
-    c2_cmd.send(NetplayCommand::RomLoaded).await.unwrap();
+    c1_cmd.send(NetplayCommand::RomLoaded(None)).await.unwrap();
+    c2_cmd.send(NetplayCommand::RomLoaded(None)).await.unwrap();
     let _ = c1_game_rx.recv().await;
     let _ = c2_game_rx.recv().await;
 
@@ -235,6 +262,11 @@ async fn test_rollback_overlapping_rollbacks() {
             transport: TransportKind::Tcp,
             spectator: false,
             room_code: 0,
+            state_hash_interval: 0,
+            auto_resync: false,
+            ping_interval_ms: 0,
+            auto_input_delay: false,
+            user_token: None,
         },
         c1_input.clone(),
         c1_event_rx,
@@ -244,7 +276,7 @@ async fn test_rollback_overlapping_rollbacks() {
 
     // Host decides the room sync mode at creation time.
     c1_input.set_sync_mode(SyncMode::Rollback);
-    c1_cmd.send(NetplayCommand::CreateRoom).await.unwrap();
+    c1_cmd.send(NetplayCommand::CreateRoom(None)).await.unwrap();
     sleep(Duration::from_millis(100)).await;
     let room_id = c1_input.with_session(|s| s.room_id);
 
@@ -259,6 +291,11 @@ async fn test_rollback_overlapping_rollbacks() {
             transport: TransportKind::Tcp,
             spectator: false,
             room_code: room_id,
+            state_hash_interval: 0,
+            auto_resync: false,
+            ping_interval_ms: 0,
+            auto_input_delay: false,
+            user_token: None,
         },
         c2_input.clone(),
         c2_event_rx,
@@ -310,6 +347,11 @@ async fn test_rollback_input_holes() {
             transport: TransportKind::Tcp,
             spectator: false,
             room_code: 0,
+            state_hash_interval: 0,
+            auto_resync: false,
+            ping_interval_ms: 0,
+            auto_input_delay: false,
+            user_token: None,
         },
         c1_input.clone(),
         c1_event_rx,
@@ -319,7 +361,7 @@ async fn test_rollback_input_holes() {
 
     // Host decides the room sync mode at creation time.
     c1_input.set_sync_mode(SyncMode::Rollback);
-    c1_cmd.send(NetplayCommand::CreateRoom).await.unwrap();
+    c1_cmd.send(NetplayCommand::CreateRoom(None)).await.unwrap();
     sleep(Duration::from_millis(100)).await;
     let room_id = c1_input.with_session(|s| s.room_id);
 
@@ -334,6 +376,11 @@ async fn test_rollback_input_holes() {
             transport: TransportKind::Tcp,
             spectator: false,
             room_code: room_id,
+            state_hash_interval: 0,
+            auto_resync: false,
+            ping_interval_ms: 0,
+            auto_input_delay: false,
+            user_token: None,
         },
         c2_input.clone(),
         c2_event_rx,
@@ -388,13 +435,18 @@ async fn test_lockstep_dynamic_delay() {
             transport: TransportKind::Tcp,
             spectator: false,
             room_code: 0,
+            state_hash_interval: 0,
+            auto_resync: false,
+            ping_interval_ms: 0,
+            auto_input_delay: false,
+            user_token: None,
         },
         c1_input.clone(),
         c1_event_rx,
         c1_game_tx,
     );
     tokio::spawn(async move { c1_handler.run().await });
-    c1_cmd.send(NetplayCommand::CreateRoom).await.unwrap();
+    c1_cmd.send(NetplayCommand::CreateRoom(None)).await.unwrap();
     sleep(Duration::from_millis(100)).await;
 
     // Initial delay is 2
@@ -428,13 +480,18 @@ async fn test_lockstep_mid_session_dynamic_delay() {
             transport: TransportKind::Tcp,
             spectator: false,
             room_code: 0,
+            state_hash_interval: 0,
+            auto_resync: false,
+            ping_interval_ms: 0,
+            auto_input_delay: false,
+            user_token: None,
         },
         c1_input.clone(),
         c1_event_rx,
         c1_game_tx,
     );
     tokio::spawn(async move { c1_handler.run().await });
-    c1_cmd.send(NetplayCommand::CreateRoom).await.unwrap();
+    c1_cmd.send(NetplayCommand::CreateRoom(None)).await.unwrap();
     sleep(Duration::from_millis(100)).await;
     let room_id = c1_input.with_session(|s| s.room_id);
 
@@ -449,6 +506,11 @@ async fn test_lockstep_mid_session_dynamic_delay() {
             transport: TransportKind::Tcp,
             spectator: false,
             room_code: room_id,
+            state_hash_interval: 0,
+            auto_resync: false,
+            ping_interval_ms: 0,
+            auto_input_delay: false,
+            user_token: None,
         },
         c2_input.clone(),
         c2_event_rx,
@@ -458,10 +520,13 @@ async fn test_lockstep_mid_session_dynamic_delay() {
     sleep(Duration::from_millis(100)).await;
 
     // Start game
-    c1_cmd.send(NetplayCommand::SendRom(vec![1])).await.unwrap();
+    c1_cmd
+        .send(NetplayCommand::SendRom(vec![1], None))
+        .await
+        .unwrap();
     let _ = c2_game_rx.recv().await;
-    c1_cmd.send(NetplayCommand::RomLoaded).await.unwrap();
-    c2_cmd.send(NetplayCommand::RomLoaded).await.unwrap();
+    c1_cmd.send(NetplayCommand::RomLoaded(None)).await.unwrap();
+    c2_cmd.send(NetplayCommand::RomLoaded(None)).await.unwrap();
     let _ = c1_game_rx.recv().await;
     let _ = c2_game_rx.recv().await;
 
@@ -515,13 +580,18 @@ async fn test_spectator_late_join() {
             transport: TransportKind::Tcp,
             spectator: false,
             room_code: 0,
+            state_hash_interval: 0,
+            auto_resync: false,
+            ping_interval_ms: 0,
+            auto_input_delay: false,
+            user_token: None,
         },
         c1_input.clone(),
         c1_event_rx,
         c1_game_tx,
     );
     tokio::spawn(async move { c1_handler.run().await });
-    c1_cmd.send(NetplayCommand::CreateRoom).await.unwrap();
+    c1_cmd.send(NetplayCommand::CreateRoom(None)).await.unwrap();
     sleep(Duration::from_millis(100)).await;
     let room_id = c1_input.with_session(|s| s.room_id);
 
@@ -536,6 +606,11 @@ async fn test_spectator_late_join() {
             transport: TransportKind::Tcp,
             spectator: false,
             room_code: room_id,
+            state_hash_interval: 0,
+            auto_resync: false,
+            ping_interval_ms: 0,
+            auto_input_delay: false,
+            user_token: None,
         },
         c2_input.clone(),
         c2_event_rx,
@@ -545,10 +620,13 @@ async fn test_spectator_late_join() {
     sleep(Duration::from_millis(100)).await;
 
     // 2. Start game
-    c1_cmd.send(NetplayCommand::SendRom(vec![1])).await.unwrap();
+    c1_cmd
+        .send(NetplayCommand::SendRom(vec![1], None))
+        .await
+        .unwrap();
     let _ = c2_game_rx.recv().await;
-    c1_cmd.send(NetplayCommand::RomLoaded).await.unwrap();
-    c2_cmd.send(NetplayCommand::RomLoaded).await.unwrap();
+    c1_cmd.send(NetplayCommand::RomLoaded(None)).await.unwrap();
+    c2_cmd.send(NetplayCommand::RomLoaded(None)).await.unwrap();
     let _ = c1_game_rx.recv().await;
     let _ = c2_game_rx.recv().await;
 
@@ -575,6 +653,11 @@ async fn test_spectator_late_join() {
             transport: TransportKind::Tcp,
             spectator: true,
             room_code: room_id,
+            state_hash_interval: 0,
+            auto_resync: false,
+            ping_interval_ms: 0,
+            auto_input_delay: false,
+            user_token: None,
         },
         c3_input.clone(),
         c3_event_rx,
@@ -586,7 +669,19 @@ async fn test_spectator_late_join() {
     // P3 receives ROM
     let rom_event = c3_game_rx.recv().await.unwrap();
     assert!(matches!(rom_event, NetplayEvent::LoadRom(_)));
-    c3_cmd.send(NetplayCommand::RomLoaded).await.unwrap();
+    c3_cmd.send(NetplayCommand::RomLoaded(None)).await.unwrap();
+
+    // The server asks the host for a fresh snapshot; answer like the runtime would.
+    let mut requested = false;
+    for _ in 0..50 {
+        if c1_input.take_state_capture_request() {
+            requested = true;
+            break;
+        }
+        sleep(Duration::from_millis(20)).await;
+    }
+    assert!(requested, "Host should be asked to capture state");
+    c1_input.send_state(5, &[0xAA, 0xBB]);
 
     // 6. P3 should receive SyncState and StartGame (used for BeginCatchUp)
     let state_event = c3_game_rx.recv().await.unwrap();
@@ -627,3 +722,204 @@ async fn test_spectator_late_join() {
         );
     }
 }
+
+#[tokio::test]
+async fn test_network_stats_reported() {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+    let server_addr = setup_server("test_network_stats").await;
+
+    let config = |name: &str, room_code: u32| NetplayConfig {
+        name: name.to_string(),
+        transport: TransportKind::Tcp,
+        spectator: false,
+        room_code,
+        state_hash_interval: 0,
+        auto_resync: false,
+        ping_interval_ms: 50,
+        auto_input_delay: false,
+        user_token: None,
+    };
+
+    let (c1_event_tx, c1_event_rx) = mpsc::channel(100);
+    let (c1_game_tx, mut c1_game_rx) = mpsc::channel(100);
+    let c1_handle = connect(server_addr, c1_event_tx).await.unwrap();
+    let c1_input = create_input_provider();
+    let (mut c1_handler, c1_cmd) = SessionHandler::new(
+        c1_handle,
+        config("P1", 0),
+        c1_input.clone(),
+        c1_event_rx,
+        c1_game_tx,
+    );
+    tokio::spawn(async move { c1_handler.run().await });
+    c1_cmd.send(NetplayCommand::CreateRoom(None)).await.unwrap();
+    sleep(Duration::from_millis(100)).await;
+    let room_id = c1_input.with_session(|s| s.room_id);
+
+    let (c2_event_tx, c2_event_rx) = mpsc::channel(100);
+    let (c2_game_tx, _c2_game_rx) = mpsc::channel(100);
+    let c2_handle = connect(server_addr, c2_event_tx).await.unwrap();
+    let c2_input = create_input_provider();
+    let (mut c2_handler, _c2_cmd) = SessionHandler::new(
+        c2_handle,
+        config("P2", room_id),
+        c2_input.clone(),
+        c2_event_rx,
+        c2_game_tx,
+    );
+    tokio::spawn(async move { c2_handler.run().await });
+    sleep(Duration::from_millis(100)).await;
+    let c2_id = c2_input.with_session(|s| s.client_id);
+
+    // P1 eventually reports stats for both the server (0) and P2.
+    let stats = tokio::time::timeout(Duration::from_secs(3), async {
+        loop {
+            if let Some(NetplayEvent::NetworkStats { peers }) = c1_game_rx.recv().await {
+                let ids: Vec<u32> = peers.iter().map(|p| p.peer_client_id).collect();
+                if ids.contains(&0) && ids.contains(&c2_id) {
+                    return peers;
+                }
+            }
+        }
+    })
+    .await
+    .expect("P1 should report stats for the server and P2");
+
+    assert!(stats.iter().all(|p| p.loss_percent == 0));
+}
+
+#[tokio::test]
+async fn test_lobby_quick_match() {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+    let server_addr = setup_server("test_lobby_quick_match").await;
+
+    let config = |name: &str| NetplayConfig {
+        name: name.to_string(),
+        transport: TransportKind::Tcp,
+        spectator: false,
+        room_code: 0,
+        state_hash_interval: 0,
+        auto_resync: false,
+        ping_interval_ms: 0,
+        auto_input_delay: false,
+        user_token: None,
+    };
+
+    let (c1_event_tx, c1_event_rx) = mpsc::channel(100);
+    let (c1_game_tx, _c1_game_rx) = mpsc::channel(100);
+    let c1_handle = connect(server_addr, c1_event_tx).await.unwrap();
+    let c1_input = create_input_provider();
+    let (mut c1_handler, c1_cmd) = SessionHandler::new(
+        c1_handle,
+        config("P1"),
+        c1_input.clone(),
+        c1_event_rx,
+        c1_game_tx,
+    );
+    tokio::spawn(async move { c1_handler.run().await });
+    c1_cmd.send(NetplayCommand::CreateRoom(None)).await.unwrap();
+    sleep(Duration::from_millis(100)).await;
+    let info = RoomInfo {
+        name: "Lobby test".to_string(),
+        region: String::new(),
+        game_hash: 0xCAFE,
+    };
+    c1_cmd
+        .send(NetplayCommand::SetRoomInfo(info.clone()))
+        .await
+        .unwrap();
+    sleep(Duration::from_millis(100)).await;
+    let room_id = c1_input.with_session(|s| s.room_id);
+
+    let (c2_event_tx, c2_event_rx) = mpsc::channel(100);
+    let (c2_game_tx, mut c2_game_rx) = mpsc::channel(100);
+    let c2_handle = connect(server_addr, c2_event_tx).await.unwrap();
+    let c2_input = create_input_provider();
+    let (mut c2_handler, c2_cmd) = SessionHandler::new(
+        c2_handle,
+        config("P2"),
+        c2_input.clone(),
+        c2_event_rx,
+        c2_game_tx,
+    );
+    tokio::spawn(async move { c2_handler.run().await });
+
+    let filter = RoomFilter {
+        game_hash: Some(0xCAFE),
+        region: None,
+    };
+    c2_cmd
+        .send(NetplayCommand::ListRooms(filter.clone()))
+        .await
+        .unwrap();
+    let rooms = tokio::time::timeout(Duration::from_secs(2), async {
+        loop {
+            if let Some(NetplayEvent::RoomList { rooms }) = c2_game_rx.recv().await {
+                return rooms;
+            }
+        }
+    })
+    .await
+    .expect("P2 should receive the room list");
+    assert_eq!(rooms.len(), 1);
+    assert_eq!(rooms[0].room_code, room_id);
+    assert_eq!(rooms[0].info, info);
+
+    c2_cmd
+        .send(NetplayCommand::QuickMatch(filter))
+        .await
+        .unwrap();
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(c2_input.with_session(|s| s.room_id), room_id);
+}
+
+#[tokio::test]
+async fn test_tls_pinned_control_channel() {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+    let app_name = "test_tls_pinned";
+    let server_addr = setup_server(app_name).await;
+
+    let cert_dir = nesium_netd::net::quic_config::default_quic_data_dir(app_name);
+    let fingerprint = nesium_netd::net::quic_config::sha256_fingerprint_base64url_from_pem(
+        &cert_dir.join("cert.pem"),
+    )
+    .unwrap();
+
+    // A different pin must be rejected during the handshake.
+    let (bad_event_tx, _bad_event_rx) = mpsc::channel(100);
+    let wrong_pin = "00".repeat(32);
+    assert!(
+        connect_tls_pinned(server_addr, "localhost", &wrong_pin, bad_event_tx)
+            .await
+            .is_err()
+    );
+
+    let (event_tx, event_rx) = mpsc::channel(100);
+    let (game_tx, _game_rx) = mpsc::channel(100);
+    let handle = connect_tls_pinned(server_addr, "localhost", &fingerprint, event_tx)
+        .await
+        .unwrap();
+    let input = create_input_provider();
+    let (mut handler, cmd) = SessionHandler::new(
+        handle,
+        NetplayConfig {
+            name: "P1".to_string(),
+            transport: TransportKind::Tcp,
+            spectator: false,
+            room_code: 0,
+            state_hash_interval: 0,
+            auto_resync: false,
+            ping_interval_ms: 0,
+            auto_input_delay: false,
+            user_token: None,
+        },
+        input.clone(),
+        event_rx,
+        game_tx,
+    );
+    tokio::spawn(async move { handler.run().await });
+    cmd.send(NetplayCommand::CreateRoom(None)).await.unwrap();
+    sleep(Duration::from_millis(200)).await;
+
+    assert_ne!(input.with_session(|s| s.room_id), 0);
+}
@@ -0,0 +1,36 @@
+//! Feeds arbitrary bytes through every decode path netd exposes to peers.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nesium_netproto::{
+    codec_tcp::try_decode_tcp_frames,
+    codec_udp::decode_udp,
+    messages::{input::InputBatch, session::Hello},
+    msg_id::MsgId,
+    packet::PacketView,
+};
+
+fn decode_known(packet: &PacketView<'_>) {
+    match packet.msg_id {
+        MsgId::Hello => {
+            let _ = packet.decode_payload::<Hello>();
+        }
+        MsgId::InputBatch => {
+            let _ = packet.decode_payload::<InputBatch>();
+        }
+        _ => {}
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok((packets, consumed)) = try_decode_tcp_frames(data) {
+        assert!(consumed <= data.len());
+        for packet in &packets {
+            decode_known(packet);
+        }
+    }
+    if let Ok(packet) = decode_udp(data) {
+        decode_known(&packet);
+    }
+});
@@ -7,12 +7,17 @@ use crate::msg_id::MsgId;
 /// - `Control`: handshake/session control and small messages.
 /// - `Input`: time-sensitive inputs (minimize head-of-line blocking).
 /// - `Bulk`: large transfers (ROM/state/snapshots).
+/// - `Datagram`: unreliable input datagrams over UDP. Nothing is
+///   retransmitted, so every `InputBatch`/`RelayInputs` on it repeats the last
+///   [`DATAGRAM_INPUT_REDUNDANCY`](crate::constants::DATAGRAM_INPUT_REDUNDANCY)
+///   frames; the reliable channels keep carrying all inputs as well.
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ChannelKind {
     Control = 0,
     Input = 1,
     Bulk = 2,
+    Datagram = 3,
 }
 
 /// Map a message ID to its preferred logical channel.
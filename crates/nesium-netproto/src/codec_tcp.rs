@@ -1,14 +1,18 @@
 use crate::{
-    constants::{HEADER_LEN, TCP_LEN_PREFIX},
+    channel::{ChannelKind, channel_for_msg},
+    constants::{COMPRESSED_FLAG, HEADER_LEN, MAGIC, TCP_LEN_PREFIX, VERSION},
     error::ProtoError,
     header::Header,
-    limits::{MAX_TCP_FRAME, max_payload_for},
+    limits::{MAX_TCP_FRAME, MIN_COMPRESS_PAYLOAD, check_payload_len, max_payload_for},
     msg_id::MsgId,
     packet::PacketView,
 };
 
 /// Encode a TCP frame with explicit max payload size.
 ///
+/// `max_payload` can only tighten the per-message limit from
+/// [`max_payload_for`]; decoders reject anything above it.
+///
 /// For most use cases, prefer [`encode_tcp_frame_auto`] which automatically
 /// selects the appropriate limit based on message type.
 pub fn encode_tcp_frame<T: serde::Serialize>(
@@ -21,23 +25,56 @@ pub fn encode_tcp_frame<T: serde::Serialize>(
     if payload_bytes.len() > max_payload {
         return Err(ProtoError::PayloadTooLarge(payload_bytes.len()));
     }
+    check_payload_len(msg_id, payload_bytes.len())?;
 
     header.msg_id = msg_id as u8;
-    header.payload_len = payload_bytes.len() as u32;
 
     let frame_len = HEADER_LEN + payload_bytes.len();
     if frame_len > MAX_TCP_FRAME {
         return Err(ProtoError::FrameTooLarge(frame_len));
     }
 
+    Ok(write_frame(header, &payload_bytes))
+}
+
+/// Lays out `[u32 frame_len_le][Header][Payload]`, filling in `payload_len`.
+fn write_frame(mut header: Header, payload: &[u8]) -> Vec<u8> {
+    header.payload_len = payload.len() as u32;
+    let frame_len = HEADER_LEN + payload.len();
+
     let mut out = Vec::with_capacity(TCP_LEN_PREFIX + frame_len);
     out.extend_from_slice(&(frame_len as u32).to_le_bytes());
 
     let mut hbuf = [0u8; HEADER_LEN];
     header.encode_into(&mut hbuf);
     out.extend_from_slice(&hbuf);
-    out.extend_from_slice(&payload_bytes);
-    Ok(out)
+    out.extend_from_slice(payload);
+    out
+}
+
+/// Re-encodes a single encoded frame with an LZ4-compressed payload.
+///
+/// Only bulk-channel messages of at least [`MIN_COMPRESS_PAYLOAD`] bytes are
+/// compressed, and only when that makes them smaller; otherwise returns `None`
+/// and the frame should be sent as is. Only send the result to peers that
+/// negotiated [`capability::COMPRESSION`]; receivers undo it with
+/// [`PacketView::decompressed_payload`].
+///
+/// [`capability::COMPRESSION`]: crate::messages::session::capability::COMPRESSION
+pub fn compress_tcp_frame(frame: &[u8]) -> Option<Vec<u8>> {
+    let (mut header, payload) = Header::decode(frame.get(TCP_LEN_PREFIX..)?).ok()?;
+    let msg_id = MsgId::from_repr(header.msg_id)?;
+    if channel_for_msg(msg_id) != ChannelKind::Bulk || payload.len() < MIN_COMPRESS_PAYLOAD {
+        return None;
+    }
+
+    // The uncompressed size goes first so receivers can check it before inflating.
+    let compressed = lz4_flex::compress_prepend_size(payload);
+    if compressed.len() >= payload.len() {
+        return None;
+    }
+    header.msg_id |= COMPRESSED_FLAG;
+    Some(write_frame(header, &compressed))
 }
 
 /// Encode a TCP frame with automatic payload limit selection.
@@ -53,6 +90,12 @@ pub fn encode_tcp_frame_auto<T: serde::Serialize>(
     encode_tcp_frame(header, msg_id, payload, max_payload_for(msg_id))
 }
 
+/// Decode every complete frame in `in_buf`.
+///
+/// Returns the decoded packets and the number of bytes consumed; a trailing
+/// partial frame is left for the next call. Frame headers are validated as soon
+/// as they are buffered, so an oversized or malformed frame is rejected before
+/// its payload has to be received.
 pub fn try_decode_tcp_frames<'a>(
     in_buf: &'a [u8],
 ) -> Result<(Vec<PacketView<'a>>, usize), ProtoError> {
@@ -74,14 +117,21 @@ pub fn try_decode_tcp_frames<'a>(
             return Err(ProtoError::FrameTooLarge(frame_len));
         }
 
+        let header_start = offset + TCP_LEN_PREFIX;
+        let Some(header_bytes) = in_buf.get(header_start..header_start + HEADER_LEN) else {
+            break;
+        };
+        validate_frame_header(header_bytes, frame_len)?;
+
         let total_needed = TCP_LEN_PREFIX + frame_len;
         if in_buf.len().saturating_sub(offset) < total_needed {
             break;
         }
 
-        let frame = &in_buf[offset + 4..offset + total_needed];
+        let frame = &in_buf[header_start..offset + total_needed];
         let (h, payload) = Header::decode(frame)?;
-        let msg = MsgId::from_repr(h.msg_id).ok_or(ProtoError::UnknownMsgId(h.msg_id))?;
+        let msg = MsgId::from_repr(h.msg_id & !COMPRESSED_FLAG)
+            .ok_or(ProtoError::UnknownMsgId(h.msg_id))?;
 
         frames.push(PacketView::new(h, msg, payload));
         offset += total_needed;
@@ -89,3 +139,196 @@ pub fn try_decode_tcp_frames<'a>(
 
     Ok((frames, offset))
 }
+
+/// Checks a buffered frame header against the frame length prefix and the
+/// per-message payload limit.
+fn validate_frame_header(header: &[u8], frame_len: usize) -> Result<(), ProtoError> {
+    if header[0..2] != MAGIC {
+        return Err(ProtoError::BadMagic);
+    }
+    if header[2] != VERSION {
+        return Err(ProtoError::UnsupportedVersion(header[2]));
+    }
+    let msg = MsgId::from_repr(header[3] & !COMPRESSED_FLAG)
+        .ok_or(ProtoError::UnknownMsgId(header[3]))?;
+    // Only bulk messages are ever compressed.
+    if header[3] & COMPRESSED_FLAG != 0 && channel_for_msg(msg) != ChannelKind::Bulk {
+        return Err(ProtoError::UnknownMsgId(header[3]));
+    }
+    let payload_len = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+    if payload_len.checked_add(HEADER_LEN) != Some(frame_len) {
+        return Err(ProtoError::LengthMismatch);
+    }
+    check_payload_len(msg, payload_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{limits::MAX_CONTROL_PAYLOAD, messages::sync::Ping};
+
+    fn ping_frame() -> Vec<u8> {
+        encode_tcp_frame_auto(
+            Header::new(0),
+            MsgId::Ping,
+            &Ping {
+                seq: 0,
+                t_ms: 7,
+                peer_client_id: 0,
+            },
+        )
+        .expect("encode")
+    }
+
+    #[test]
+    fn decodes_back_to_back_frames_and_keeps_partial_tail() {
+        let frame = ping_frame();
+        let mut buf = frame.clone();
+        buf.extend_from_slice(&frame);
+        buf.extend_from_slice(&frame[..frame.len() - 1]);
+
+        let (packets, consumed) = try_decode_tcp_frames(&buf).expect("decode");
+        assert_eq!(packets.len(), 2);
+        assert_eq!(consumed, frame.len() * 2);
+        let ping: Ping = packets[0].decode_payload().expect("payload");
+        assert_eq!(ping.t_ms, 7);
+    }
+
+    #[test]
+    fn oversized_control_frame_rejected_from_header_alone() {
+        let payload_len = MAX_CONTROL_PAYLOAD + 1;
+        let mut header = Header::new(MsgId::Ping as u8);
+        header.payload_len = payload_len as u32;
+        let mut hbuf = [0u8; HEADER_LEN];
+        header.encode_into(&mut hbuf);
+
+        let mut buf = ((HEADER_LEN + payload_len) as u32).to_le_bytes().to_vec();
+        buf.extend_from_slice(&hbuf);
+
+        assert!(matches!(
+            try_decode_tcp_frames(&buf),
+            Err(ProtoError::PayloadExceedsLimit {
+                msg_id: MsgId::Ping,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn prefix_disagreeing_with_header_is_rejected() {
+        let mut frame = ping_frame();
+        let len = u32::from_le_bytes([frame[0], frame[1], frame[2], frame[3]]) + 1;
+        frame[0..4].copy_from_slice(&len.to_le_bytes());
+        assert!(matches!(
+            try_decode_tcp_frames(&frame),
+            Err(ProtoError::LengthMismatch)
+        ));
+    }
+
+    #[test]
+    fn encoder_respects_per_message_limit() {
+        let big = vec![0u8; MAX_CONTROL_PAYLOAD + 16];
+        assert!(matches!(
+            encode_tcp_frame(Header::new(0), MsgId::Ping, &big, usize::MAX),
+            Err(ProtoError::PayloadExceedsLimit { .. })
+        ));
+    }
+
+    fn sync_state_frame(len: usize) -> Vec<u8> {
+        let state = crate::messages::session::SyncState {
+            frame: 3,
+            data: (0..len).map(|i| (i / 64) as u8).collect(),
+        };
+        encode_tcp_frame_auto(Header::new(0), MsgId::SyncState, &state).expect("encode")
+    }
+
+    #[test]
+    fn bulk_frames_compress_and_round_trip() {
+        let frame = sync_state_frame(64 * 1024);
+        let compressed = compress_tcp_frame(&frame).expect("compressible");
+        assert!(compressed.len() < frame.len() / 4);
+
+        let (packets, consumed) = try_decode_tcp_frames(&compressed).expect("decode");
+        assert_eq!(consumed, compressed.len());
+        assert_eq!(packets[0].msg_id, MsgId::SyncState);
+        assert!(packets[0].is_compressed());
+
+        let (original, _) = try_decode_tcp_frames(&frame).expect("decode");
+        assert_eq!(
+            packets[0].decompressed_payload().expect("inflate").as_ref(),
+            original[0].payload
+        );
+    }
+
+    #[test]
+    fn control_and_small_frames_stay_uncompressed() {
+        assert!(compress_tcp_frame(&ping_frame()).is_none());
+        assert!(compress_tcp_frame(&sync_state_frame(MIN_COMPRESS_PAYLOAD / 2)).is_none());
+
+        let mut flagged = ping_frame();
+        flagged[TCP_LEN_PREFIX + 3] |= COMPRESSED_FLAG;
+        assert!(matches!(
+            try_decode_tcp_frames(&flagged),
+            Err(ProtoError::UnknownMsgId(_))
+        ));
+    }
+
+    #[test]
+    fn oversized_decompressed_size_is_rejected() {
+        let mut payload = ((crate::limits::MAX_DATA_PAYLOAD + 1) as u32)
+            .to_le_bytes()
+            .to_vec();
+        payload.extend_from_slice(&[0; 16]);
+        let mut header = Header::new(MsgId::SyncState as u8 | COMPRESSED_FLAG);
+        header.payload_len = payload.len() as u32;
+        let view = PacketView::new(header, MsgId::SyncState, &payload);
+        assert!(matches!(
+            view.decompressed_payload(),
+            Err(ProtoError::PayloadExceedsLimit { .. })
+        ));
+    }
+
+    #[test]
+    fn trailing_payload_bytes_are_rejected() {
+        let mut payload = postcard::to_stdvec(&Ping {
+            seq: 0,
+            t_ms: 1,
+            peer_client_id: 0,
+        })
+        .expect("encode");
+        payload.push(0);
+        assert!(matches!(
+            crate::packet::decode_payload_strict::<Ping>(&payload),
+            Err(ProtoError::TrailingBytes(1))
+        ));
+    }
+
+    #[test]
+    fn arbitrary_bytes_never_panic() {
+        // Cheap deterministic stand-in for the cargo-fuzz target.
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        let seed = ping_frame();
+        for _ in 0..20_000 {
+            let mut buf = seed.clone();
+            let flips = (next() % 4) as usize + 1;
+            for _ in 0..flips {
+                let idx = (next() as usize) % buf.len();
+                buf[idx] = next() as u8;
+            }
+            buf.truncate((next() as usize) % (buf.len() + 1));
+            if let Ok((packets, consumed)) = try_decode_tcp_frames(&buf) {
+                assert!(consumed <= buf.len());
+                for packet in packets {
+                    let _ = packet.decode_payload::<Ping>();
+                }
+            }
+            let _ = crate::codec_udp::decode_udp(&buf);
+        }
+    }
+}
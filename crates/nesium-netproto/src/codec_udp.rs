@@ -1,6 +1,10 @@
 use crate::{
-    constants::HEADER_LEN, error::ProtoError, header::Header, limits::MAX_UDP_PAYLOAD,
-    msg_id::MsgId, packet::PacketView,
+    constants::HEADER_LEN,
+    error::ProtoError,
+    header::Header,
+    limits::{MAX_UDP_PAYLOAD, check_payload_len},
+    msg_id::MsgId,
+    packet::PacketView,
 };
 
 pub fn encode_udp<T: serde::Serialize>(
@@ -10,6 +14,7 @@ pub fn encode_udp<T: serde::Serialize>(
     max_payload: usize,
 ) -> Result<Vec<u8>, ProtoError> {
     let payload_bytes = postcard::to_stdvec(payload)?;
+    check_payload_len(msg_id, payload_bytes.len())?;
     if payload_bytes.len() > max_payload || payload_bytes.len() > MAX_UDP_PAYLOAD {
         return Err(ProtoError::PayloadTooLarge(payload_bytes.len()));
     }
@@ -35,5 +40,6 @@ pub fn decode_udp<'a>(datagram: &'a [u8]) -> Result<PacketView<'a>, ProtoError>
 
     let (h, payload) = Header::decode(datagram)?;
     let msg = MsgId::from_repr(h.msg_id).ok_or(ProtoError::UnknownMsgId(h.msg_id))?;
+    check_payload_len(msg, payload.len())?;
     Ok(PacketView::new(h, msg, payload))
 }
@@ -10,6 +10,19 @@ pub const MAGIC: [u8; 2] = *b"NS";
 /// Bump this only for breaking changes to the header layout or message formats.
 pub const VERSION: u8 = 1;
 
+/// Session protocol version, negotiated in `Hello`/`Welcome`.
+/// Bump this when message payloads change incompatibly; the header
+/// [`VERSION`] stays put so older peers can still read the rejection.
+pub const PROTOCOL_VERSION: u8 = 4;
+
+/// Oldest session protocol version this build still speaks.
+pub const MIN_PROTOCOL_VERSION: u8 = 4;
+
+/// Set in the header `msg_id` byte when the payload is LZ4-compressed (see
+/// [`compress_tcp_frame`](crate::codec_tcp::compress_tcp_frame)). Message ids
+/// stay below this bit.
+pub const COMPRESSED_FLAG: u8 = 0x80;
+
 /// Fixed header length in bytes (wire format).
 /// Minimal header for stream transports:
 /// `[magic(2)][version(1)][msg_id(1)][payload_len(u32)]` = 8 bytes.
@@ -22,6 +35,31 @@ pub const HEADER_LEN: usize = 8;
 /// where `frame_len_le` is the length of `[Header][Payload]` in bytes.
 pub const TCP_LEN_PREFIX: usize = 4;
 
+/// Frames of input history repeated in every input datagram, so up to this
+/// many consecutive lost datagrams cost no input.
+pub const DATAGRAM_INPUT_REDUNDANCY: usize = 8;
+
+/// A peer on the datagram channel that sends nothing for this long is
+/// dropped. Clients keep the channel alive with their input datagrams (or a
+/// repeated `AttachChannel` while idle).
+pub const DATAGRAM_IDLE_TIMEOUT_SECS: u64 = 30;
+
+/// Largest message sent on a WebRTC data channel. Frames are split across
+/// messages and reassembled as a byte stream, like TCP; 16 KiB is the size all
+/// browsers accept.
+pub const DATA_CHANNEL_MAX_MESSAGE: usize = 16 * 1024;
+
+/// TLS ALPN protocol id for the native framed protocol over TLS. Without it
+/// a TLS connection to netd is treated as a secure WebSocket (WSS).
+pub const TLS_ALPN: &[u8] = b"nesium";
+
+/// Default number of frames between `StateHash` reports (two seconds at
+/// 60 fps).
+pub const STATE_HASH_INTERVAL_FRAMES: u32 = 120;
+
+/// Default interval between RTT probes (`Ping`) to the server and each peer.
+pub const PING_INTERVAL_MS: u32 = 1000;
+
 /// Player index marker used to represent a spectator on the wire.
 ///
 /// This value appears in session messages such as `JoinAck`, `PlayerJoined`, and `RoleChanged`.
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::msg_id::MsgId;
+
 #[derive(Debug, Error)]
 pub enum ProtoError {
     #[error("bad magic")]
@@ -14,8 +16,37 @@ pub enum ProtoError {
     PayloadTooLarge(usize),
     #[error("frame too large: {0}")]
     FrameTooLarge(usize),
+    #[error("payload of {len} bytes exceeds the {max} byte limit for {msg_id:?}")]
+    PayloadExceedsLimit {
+        msg_id: MsgId,
+        len: usize,
+        max: usize,
+    },
     #[error("unknown msg id: {0}")]
     UnknownMsgId(u8),
+    #[error("{0} trailing bytes after payload")]
+    TrailingBytes(usize),
+    #[error("decompression failed: {0}")]
+    Decompress(String),
     #[error("postcard decode error: {0}")]
     Postcard(#[from] postcard::Error),
 }
+
+impl ProtoError {
+    /// Stable, low-cardinality label for metrics.
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            ProtoError::BadMagic => "bad_magic",
+            ProtoError::UnsupportedVersion(_) => "unsupported_version",
+            ProtoError::TooShort => "too_short",
+            ProtoError::LengthMismatch => "length_mismatch",
+            ProtoError::PayloadTooLarge(_) => "payload_too_large",
+            ProtoError::FrameTooLarge(_) => "frame_too_large",
+            ProtoError::PayloadExceedsLimit { .. } => "payload_exceeds_limit",
+            ProtoError::UnknownMsgId(_) => "unknown_msg_id",
+            ProtoError::TrailingBytes(_) => "trailing_bytes",
+            ProtoError::Decompress(_) => "decompress",
+            ProtoError::Postcard(_) => "postcard",
+        }
+    }
+}
@@ -78,8 +78,8 @@ impl Header {
         let msg_id = buf[3];
         let payload_len = read_u32_le(buf, 4)?;
 
-        let payload_len_usize = payload_len as usize;
-        if buf.len() != HEADER_LEN + payload_len_usize {
+        let expected_len = (payload_len as usize).checked_add(HEADER_LEN);
+        if expected_len != Some(buf.len()) {
             return Err(ProtoError::LengthMismatch);
         }
 
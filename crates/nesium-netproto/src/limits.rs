@@ -3,7 +3,7 @@
 //! This module provides a single source of truth for all size limits,
 //! eliminating scattered constants and ensuring consistency across crates.
 
-use crate::msg_id::MsgId;
+use crate::{error::ProtoError, msg_id::MsgId};
 
 // ============================================================================
 // Core Size Limits
@@ -20,6 +20,16 @@ pub const MAX_CONTROL_PAYLOAD: usize = 4 * 1024;
 /// Data messages include: ROM data, state snapshots, and other large transfers.
 pub const MAX_DATA_PAYLOAD: usize = 2 * 1024 * 1024;
 
+/// Maximum number of frames in one `InputBatch`/`RelayInputs`.
+///
+/// Inputs are run-length encoded, so this bounds the decoded size rather than
+/// the payload size.
+pub const MAX_INPUT_FRAMES: usize = 1024;
+
+/// Smallest bulk payload worth compressing; below this LZ4's framing
+/// overhead eats most of the gain.
+pub const MIN_COMPRESS_PAYLOAD: usize = 1024;
+
 /// Maximum payload size for UDP packets (1200 bytes).
 ///
 /// Kept below typical path MTU to reduce fragmentation risk.
@@ -76,6 +86,16 @@ pub const fn max_payload_for(msg_id: MsgId) -> usize {
     }
 }
 
+/// Rejects payloads larger than [`max_payload_for`] allows for `msg_id`.
+#[inline]
+pub fn check_payload_len(msg_id: MsgId, len: usize) -> Result<(), ProtoError> {
+    let max = max_payload_for(msg_id);
+    if len > max {
+        return Err(ProtoError::PayloadExceedsLimit { msg_id, len, max });
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,6 +122,19 @@ mod tests {
         assert_eq!(max_payload_for(MsgId::Ping), MAX_CONTROL_PAYLOAD);
     }
 
+    #[test]
+    fn payload_len_checked_per_message() {
+        assert!(check_payload_len(MsgId::Ping, MAX_CONTROL_PAYLOAD).is_ok());
+        assert!(matches!(
+            check_payload_len(MsgId::Ping, MAX_CONTROL_PAYLOAD + 1),
+            Err(ProtoError::PayloadExceedsLimit {
+                msg_id: MsgId::Ping,
+                ..
+            })
+        ));
+        assert!(check_payload_len(MsgId::LoadRom, MAX_CONTROL_PAYLOAD + 1).is_ok());
+    }
+
     #[test]
     fn rx_buffer_larger_than_max_frame() {
         assert!(TCP_RX_BUFFER_SIZE > MAX_TCP_FRAME);
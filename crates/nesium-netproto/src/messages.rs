@@ -1,4 +1,6 @@
+pub mod chat;
 pub mod input;
+pub mod lobby;
 pub mod resync;
 pub mod session;
 pub mod sync;
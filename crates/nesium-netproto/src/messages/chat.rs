@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of `ChatBody::Text` (bytes).
+pub const CHAT_MAX_TEXT_LEN: usize = 256;
+
+/// Content of a chat message.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum ChatBody {
+    /// Free text, at most `CHAT_MAX_TEXT_LEN` bytes.
+    Text(String),
+    /// A quick emote. Ids are defined by the frontends; the server only relays them.
+    Emote(u8),
+}
+
+/// In-room chat message.
+///
+/// Clients send it with `client_id` 0; the server sets it to the sender and
+/// broadcasts the message to every room member, spectators and the sender
+/// included, so all of them see the same order.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Chat {
+    pub client_id: u32,
+    pub body: ChatBody,
+}
@@ -5,7 +5,9 @@ use serde::{Deserialize, Serialize};
 pub struct InputBatch {
     /// Starting frame number for this batch.
     pub start_frame: u32,
-    /// Sequential inputs for consecutive frames (one u16 per frame).
+    /// Sequential inputs for consecutive frames (one u16 per frame),
+    /// delta-encoded on the wire; see [`delta`].
+    #[serde(with = "delta")]
     pub buttons: Vec<u16>,
 }
 
@@ -16,7 +18,8 @@ pub struct RelayInputs {
     pub player_index: u8,
     /// Starting frame number.
     pub base_frame: u32,
-    /// Inputs (one u16 per frame).
+    /// Inputs (one u16 per frame), delta-encoded on the wire; see [`delta`].
+    #[serde(with = "delta")]
     pub buttons: Vec<u16>,
 }
 
@@ -24,3 +27,109 @@ pub struct RelayInputs {
 pub struct InputAck {
     pub last_server_frame: u32,
 }
+
+/// Wire encoding for runs of per-frame button states.
+///
+/// Frames are grouped into spans of identical buttons. Each span carries the
+/// bitmask of buttons that toggled since the previous span, plus how many more
+/// frames it is held for. The first span is relative to no buttons pressed, so
+/// every message starts with a keyframe and decodes on its own — a lost
+/// datagram never breaks the next one. Held buttons, the common case, cost a
+/// few bytes no matter how many frames they cover.
+pub mod delta {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+    use crate::limits::MAX_INPUT_FRAMES;
+
+    /// Buttons held for `1 + extra_frames` consecutive frames.
+    #[derive(Serialize, Deserialize)]
+    struct Span {
+        /// Buttons that changed since the previous span.
+        toggled: u16,
+        extra_frames: u16,
+    }
+
+    pub fn serialize<S: Serializer>(buttons: &[u16], serializer: S) -> Result<S::Ok, S::Error> {
+        let mut spans: Vec<Span> = Vec::new();
+        let mut prev = 0u16;
+        for &b in buttons {
+            match spans.last_mut() {
+                Some(span) if b == prev && span.extra_frames < u16::MAX => span.extra_frames += 1,
+                _ => spans.push(Span {
+                    toggled: b ^ prev,
+                    extra_frames: 0,
+                }),
+            }
+            prev = b;
+        }
+        spans.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u16>, D::Error> {
+        let spans = Vec::<Span>::deserialize(deserializer)?;
+        let mut buttons = Vec::new();
+        let mut prev = 0u16;
+        for span in spans {
+            let len = span.extra_frames as usize + 1;
+            // A few bytes can claim millions of frames; check before expanding.
+            if buttons.len() + len > MAX_INPUT_FRAMES {
+                return Err(D::Error::custom("too many input frames"));
+            }
+            prev ^= span.toggled;
+            buttons.resize(buttons.len() + len, prev);
+        }
+        Ok(buttons)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{limits::MAX_INPUT_FRAMES, packet::decode_payload_strict};
+
+    fn round_trip(buttons: Vec<u16>) -> (Vec<u16>, usize) {
+        let batch = InputBatch {
+            start_frame: 7,
+            buttons,
+        };
+        let bytes = postcard::to_stdvec(&batch).expect("encode");
+        let decoded: InputBatch = decode_payload_strict(&bytes).expect("decode");
+        assert_eq!(decoded.start_frame, 7);
+        (decoded.buttons, bytes.len())
+    }
+
+    #[test]
+    fn buttons_round_trip() {
+        for buttons in [
+            vec![],
+            vec![0],
+            vec![0x8001],
+            vec![1, 1, 3, 3, 3, 0, 0xFFFF, 0xFFFF, 2],
+        ] {
+            assert_eq!(round_trip(buttons.clone()).0, buttons);
+        }
+    }
+
+    #[test]
+    fn held_buttons_encode_in_constant_size() {
+        let (_, short) = round_trip(vec![0x0101; 2]);
+        let (buttons, long) = round_trip(vec![0x0101; 200]);
+        assert_eq!(buttons, vec![0x0101; 200]);
+        assert_eq!(short + 1, long);
+    }
+
+    #[test]
+    fn oversized_runs_are_rejected() {
+        #[derive(Serialize)]
+        struct Raw {
+            start_frame: u32,
+            spans: Vec<(u16, u16)>,
+        }
+        let raw = Raw {
+            start_frame: 0,
+            spans: vec![(1, u16::MAX); MAX_INPUT_FRAMES / u16::MAX as usize + 1],
+        };
+        let bytes = postcard::to_stdvec(&raw).expect("encode");
+        assert!(decode_payload_strict::<InputBatch>(&bytes).is_err());
+    }
+}
@@ -0,0 +1,77 @@
+//! Lobby browsing and matchmaking.
+//!
+//! Hosts describe their room with [`SetRoomInfo`]; any connected client can
+//! browse public rooms with [`ListRooms`] or let the server pick one with
+//! [`QuickMatch`]. Private (password-protected) rooms are never listed.
+
+use serde::{Deserialize, Serialize};
+
+use super::session::SyncMode;
+
+/// Maximum length of `RoomInfo::name` (bytes).
+pub const ROOM_NAME_MAX_LEN: usize = 64;
+/// Maximum length of `RoomInfo::region` (bytes).
+pub const ROOM_REGION_MAX_LEN: usize = 16;
+/// Maximum number of rooms in one `RoomList`.
+pub const ROOM_LIST_MAX_LEN: usize = 100;
+
+/// Host-provided description of a room.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoomInfo {
+    /// Display name; empty means the host's name.
+    pub name: String,
+    /// Free-form region tag (e.g. `eu`, `us-west`); empty if unknown.
+    pub region: String,
+    /// CRC32 of the loaded cartridge, 0 if no game is selected yet.
+    pub game_hash: u32,
+}
+
+/// Host-only: update the lobby description of the current room.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SetRoomInfo {
+    pub info: RoomInfo,
+}
+
+/// Filters shared by `ListRooms` and `QuickMatch`. `None` matches anything.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoomFilter {
+    pub game_hash: Option<u32>,
+    pub region: Option<String>,
+}
+
+/// Ask the server for public rooms; answered with `RoomList`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListRooms {
+    pub filter: RoomFilter,
+}
+
+/// One entry of the lobby.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RoomSummary {
+    pub room_code: u32,
+    pub info: RoomInfo,
+    pub player_count: u8,
+    pub spectator_count: u8,
+    /// Player slots a joiner would still get (otherwise they spectate).
+    pub open_slots: u8,
+    /// Whether the game already started (joiners catch up from a snapshot).
+    pub started: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoomList {
+    /// Rooms with open slots first, at most `ROOM_LIST_MAX_LEN`.
+    pub rooms: Vec<RoomSummary>,
+}
+
+/// Join a public room matching `filter` that still has an open player slot
+/// (preferring games that haven't started, then the fullest), or create a new
+/// public room described by the filter.
+///
+/// Answered like `JoinRoom`, with a `JoinAck`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuickMatch {
+    pub filter: RoomFilter,
+    /// Sync mode for a newly created room; ignored when joining one.
+    pub preferred_sync_mode: Option<SyncMode>,
+}
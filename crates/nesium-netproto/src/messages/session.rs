@@ -8,6 +8,8 @@ use crate::channel::ChannelKind;
 pub enum TransportKind {
     Tcp,
     Quic,
+    /// WebRTC data channel (browser clients).
+    WebRtc,
 }
 
 /// Synchronization mode for netplay sessions.
@@ -22,13 +24,49 @@ pub enum SyncMode {
     Rollback = 1,
 }
 
+/// Optional protocol features, as bits of `Hello::capabilities` and
+/// `Welcome::capabilities`.
+///
+/// The client advertises what it supports and the server answers with the
+/// subset both sides support; a feature outside that subset must not be used
+/// on the session.
+pub mod capability {
+    /// Redundant input relay over the UDP datagram channel.
+    pub const DATAGRAM_INPUT: u32 = 1 << 0;
+    /// LZ4-compressed bulk-channel frames, in both directions.
+    pub const COMPRESSION: u32 = 1 << 1;
+
+    /// Every capability this build supports.
+    pub const SUPPORTED: u32 = DATAGRAM_INPUT | COMPRESSION;
+}
+
+/// First message on a control connection.
+///
+/// The fields up to `proto_max` keep their layout across protocol versions,
+/// so the server can check the version before decoding the rest.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Hello {
     pub client_nonce: u32,
     pub transport: TransportKind,
+    /// Range of session protocol versions the client speaks.
     pub proto_min: u8,
     pub proto_max: u8,
     pub name: String,
+    /// Bitwise OR of [`capability`] flags the client supports.
+    pub capabilities: u32,
+    /// Identity token from an earlier `Welcome`, if the client kept one.
+    pub user_token: Option<UserToken>,
+}
+
+/// Secret a client presents to be recognised as the same user again.
+pub type UserToken = [u8; 16];
+
+/// Persistent user identity handed out by servers that keep an account store.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UserIdentity {
+    pub user_id: u64,
+    /// Send this in later `Hello`s to keep `user_id`.
+    pub token: UserToken,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -42,6 +80,12 @@ pub struct Welcome {
     pub input_delay_frames: u8,
     pub max_payload: u16,
     pub rewind_capacity: u32,
+    /// Session protocol version chosen by the server.
+    pub proto_version: u8,
+    /// [`capability`] flags enabled for this session.
+    pub capabilities: u32,
+    /// `None` if the server doesn't persist identities.
+    pub identity: Option<UserIdentity>,
 }
 
 /// Attach a secondary channel connection to an existing session.
@@ -59,8 +103,14 @@ pub struct JoinRoom {
     pub room_code: u32,
     /// Preferred sync mode (if None, server decides based on room settings).
     pub preferred_sync_mode: Option<SyncMode>,
+    /// Room password. When creating a room (`room_code == 0`), a non-empty
+    /// password makes the room private; when joining, it must match.
+    pub password: Option<String>,
 }
 
+/// Maximum room password length in bytes.
+pub const ROOM_PASSWORD_MAX_LEN: usize = 64;
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct JoinAck {
     pub ok: bool,
@@ -100,6 +150,22 @@ pub enum ErrorCode {
     InvalidState = 8,
     /// P2P host is not available (disconnected or never set)
     HostNotAvailable = 9,
+    /// Too many messages of this kind; try again later
+    RateLimited = 10,
+    /// Room is private and no password was given
+    PasswordRequired = 11,
+    /// Room password does not match
+    WrongPassword = 12,
+    /// Loaded ROM differs from the one the room is playing
+    RomMismatch = 13,
+    /// No session protocol version is supported by both client and server
+    IncompatibleVersion = 14,
+    /// Client address or identity is banned from this server
+    Banned = 15,
+    /// Server has reached its limit of open rooms
+    ServerFull = 16,
+    /// Relay bandwidth quota of the client or its room used up for now
+    QuotaExceeded = 17,
 }
 
 /// Server sends an error response to the client.
@@ -138,13 +204,56 @@ pub struct PlayerJoined {
     pub name: String,
 }
 
+/// Server is shutting down: games keep being relayed for `grace_ms`, after
+/// which every connection is dropped.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServerClosing {
+    pub grace_ms: u32,
+}
+
+/// Longest spectator delay a host may set (one minute at 60 fps).
+pub const SPECTATOR_DELAY_MAX_FRAMES: u32 = 60 * 60;
+
+/// Host-only: relay inputs to spectators `frames` behind live, so they can't
+/// feed a player what the opponent is doing. 0 disables the delay.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SetSpectatorDelay {
+    /// At most `SPECTATOR_DELAY_MAX_FRAMES`.
+    pub frames: u32,
+}
+
+/// Server tells the players how many spectators are watching, whenever that
+/// or the spectator delay changes.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpectatorInfo {
+    pub count: u16,
+    pub delay_frames: u32,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct LoadRom {
     pub data: Vec<u8>,
+    /// Hash of the ROM as loaded by the sender, if known.
+    pub hash: Option<RomHash>,
 }
 
+/// Cartridge identity (CRC32/SHA-1 of the ROM body, as in `CartridgeInfo`),
+/// used to catch players running different ROM revisions.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RomHash {
+    pub crc32: u32,
+    pub sha1: [u8; 20],
+}
+
+/// Client finished loading the room's ROM.
+///
+/// The server compares `hash` against the one from `LoadRom` and answers a
+/// mismatch with `ErrorCode::RomMismatch` instead of counting the client as
+/// loaded. Without both hashes the ROM is accepted unverified.
 #[derive(Serialize, Deserialize, Debug)]
-pub struct RomLoaded;
+pub struct RomLoaded {
+    pub hash: Option<RomHash>,
+}
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct StartGame {
@@ -211,6 +320,11 @@ pub struct BeginCatchUp {
     pub active_ports_mask: u8,
 }
 
+/// Server asks the host to capture its state at the next frame and send it
+/// with `ProvideState`, so a late joiner starts from a fresh snapshot.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CaptureState;
+
 // ---- P2P signaling (netd as signaling server) ----
 
 /// Host asks the signaling server to create a new relay room code and publish direct-connect info.
@@ -277,12 +391,92 @@ pub struct P2PHostDisconnected {
     pub room_code: u32,
 }
 
+/// Registers a UDP endpoint for hole punching, sent as a datagram to the
+/// signaling server's UDP socket (the one bound with `--udp-bind`).
+///
+/// The server uses the datagram's source address as the public endpoint. Once
+/// the host and a joiner of `room_code` are both registered, it sends
+/// [`P2PPunchStart`] to each. Datagrams can be lost, so clients repeat this
+/// every [`P2P_PUNCH_INTERVAL_MS`] until `P2PPunchStart` arrives.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct P2PPunchRegister {
+    pub session_token: u64,
+    pub room_code: u32,
+    /// LAN addresses of the same socket, for peers behind the same NAT.
+    pub local_addrs: Vec<SocketAddr>,
+}
+
+/// Signaling server tells a peer where to punch. Both peers receive it at the
+/// same time and send [`P2PPunchProbe`] to every address in `peer_addrs` until
+/// one answers; if none does within [`P2P_PUNCH_TIMEOUT_MS`], they fall back
+/// to relay with [`P2PRequestFallback`].
+#[derive(Serialize, Deserialize, Debug)]
+pub struct P2PPunchStart {
+    pub room_code: u32,
+    pub peer_client_id: u32,
+    /// Observed public endpoint first, then the peer's local addresses.
+    pub peer_addrs: Vec<SocketAddr>,
+    /// Shared by both sides; probes with another nonce are ignored.
+    pub nonce: u64,
+}
+
+/// Peer-to-peer datagram. `ack` is set once a probe from the peer has been
+/// received, so each side knows its own outbound path works too. After the
+/// handshake the peers keep exchanging probes every
+/// [`P2P_PUNCH_KEEPALIVE_SECS`] to hold the NAT mappings open.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct P2PPunchProbe {
+    pub nonce: u64,
+    pub ack: bool,
+}
+
 /// Maximum number of host addresses allowed in P2PCreateRoom.
 pub const P2P_MAX_HOST_ADDRS: usize = 8;
 
+/// Maximum number of local addresses allowed in P2PPunchRegister.
+pub const P2P_MAX_LOCAL_ADDRS: usize = 4;
+
+/// Interval between punch registrations and probes (milliseconds).
+pub const P2P_PUNCH_INTERVAL_MS: u64 = 100;
+
+/// How long peers probe before giving up and falling back to relay (milliseconds).
+pub const P2P_PUNCH_TIMEOUT_MS: u64 = 5000;
+
+/// Keepalive interval for an open punched path. Well below the ~30s UDP
+/// mapping timeout of common NATs.
+pub const P2P_PUNCH_KEEPALIVE_SECS: u64 = 15;
+
 /// Maximum length of reason strings in P2P messages (bytes).
 pub const P2P_MAX_REASON_LEN: usize = 256;
 
+// ---- WebRTC signaling (netd relays between P2P room members) ----
+
+/// Kind of [`RtcSignal`]. `data` holds the SDP for offers/answers and the
+/// JSON-encoded `RTCIceCandidateInit` for candidates.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RtcSignalKind {
+    Offer,
+    Answer,
+    IceCandidate,
+}
+
+/// WebRTC session negotiation between two watchers of a P2P room (host
+/// included), relayed by the signaling server.
+///
+/// Clients set `peer_client_id` to the recipient; the server rewrites it to the
+/// sender before forwarding. The browser side sends the offer; the host
+/// answers and bridges the data channel into its netplay server.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RtcSignal {
+    pub room_code: u32,
+    pub peer_client_id: u32,
+    pub kind: RtcSignalKind,
+    pub data: String,
+}
+
+/// Maximum length of `RtcSignal::data` (bytes). Fits a typical data-channel-only SDP.
+pub const RTC_MAX_SIGNAL_LEN: usize = 3 * 1024;
+
 // ---- Direct-session control (host server -> clients) ----
 
 /// Host asks its own server to broadcast a relay fallback instruction to all connected clients.
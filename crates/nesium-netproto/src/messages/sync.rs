@@ -1,12 +1,24 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug)]
+/// Round-trip probe.
+///
+/// `peer_client_id` is the target when sent (0 = the server answers itself);
+/// the server rewrites it to the sender's id before relaying to a peer.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Ping {
+    pub seq: u32,
     pub t_ms: u32,
+    pub peer_client_id: u32,
 }
-#[derive(Serialize, Deserialize, Debug)]
+
+/// Answer to a [`Ping`], echoing its `seq` and `t_ms`.
+///
+/// `peer_client_id` is routed the same way as for `Ping`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Pong {
+    pub seq: u32,
     pub t_ms: u32,
+    pub peer_client_id: u32,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -15,3 +27,12 @@ pub struct SyncHint {
     pub target_frame: u32,
     pub jitter_budget_ms: u16,
 }
+
+/// `Nes::state_hash()` after `frame` (a confirmed frame). Sent by every room
+/// member every few frames and relayed unchanged to the others, which compare
+/// it with their own hash for the same frame.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateHash {
+    pub frame: u32,
+    pub hash: u64,
+}
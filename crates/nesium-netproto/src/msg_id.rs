@@ -10,6 +10,8 @@ pub enum MsgId {
     Leave = 5,
     Error = 6,
     AttachChannel = 7,
+    /// Server is shutting down and will drop every connection shortly.
+    ServerClosing = 8,
 
     SwitchRole = 10,
     RoleChanged = 11,
@@ -23,6 +25,8 @@ pub enum MsgId {
     Ping = 30,
     Pong = 31,
     SyncHint = 32,
+    /// Periodic emulator state hash for desync detection; relayed to the room.
+    StateHash = 33,
 
     ResyncReq = 40,
     SnapshotFrag = 41,
@@ -40,6 +44,27 @@ pub enum MsgId {
     ProvideState = 66,
     /// Server tells a late joiner to begin catch-up from a snapshot.
     BeginCatchUp = 67,
+    /// Server asks the host for a fresh snapshot (sent back as `ProvideState`).
+    CaptureState = 68,
+
+    // --- Room chat ---
+    /// Text or emote; the server stamps the sender and broadcasts it to the room.
+    Chat = 70,
+
+    // --- Lobby ---
+    /// Client asks for public rooms; answered with `RoomList`.
+    ListRooms = 71,
+    RoomList = 72,
+    /// Join or create a matching public room; answered with `JoinAck`.
+    QuickMatch = 73,
+    /// Host updates the room's lobby description.
+    SetRoomInfo = 74,
+
+    // --- Spectators ---
+    /// Host sets how far behind live spectators watch.
+    SetSpectatorDelay = 75,
+    /// Server tells players the spectator count and delay.
+    SpectatorInfo = 76,
 
     // --- P2P signaling (netd as signaling server) ---
     P2PCreateRoom = 80,
@@ -52,10 +77,19 @@ pub enum MsgId {
     P2PFallbackNotice = 85,
     /// Server notifies watchers that the P2P host has disconnected.
     P2PHostDisconnected = 86,
+    /// Datagram to netd's UDP socket so it can observe the sender's public endpoint.
+    P2PPunchRegister = 87,
+    /// Server tells both peers to start hole punching towards each other.
+    P2PPunchStart = 88,
+    /// Peer-to-peer probe, also used as keepalive once the path is open.
+    P2PPunchProbe = 89,
 
     // --- Direct-session control (host server -> clients) ---
     /// Host requests the server to broadcast a relay fallback instruction.
     RequestFallbackRelay = 90,
     /// Server instructs clients to disconnect and reconnect to relay server.
     FallbackToRelay = 91,
+
+    // --- WebRTC signaling (netd relays between P2P room members) ---
+    RtcSignal = 100,
 }
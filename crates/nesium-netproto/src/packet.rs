@@ -1,4 +1,9 @@
-use crate::{header::Header, msg_id::MsgId};
+use std::borrow::Cow;
+
+use crate::{
+    constants::COMPRESSED_FLAG, error::ProtoError, header::Header, limits::check_payload_len,
+    msg_id::MsgId,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub struct PacketView<'a> {
@@ -16,3 +21,56 @@ impl<'a> PacketView<'a> {
         }
     }
 }
+
+impl<'a> PacketView<'a> {
+    /// Decodes the payload, rejecting any bytes left over after the message.
+    ///
+    /// Compressed frames must go through [`Self::decompressed_payload`] first.
+    pub fn decode_payload<T: serde::Deserialize<'a>>(&self) -> Result<T, ProtoError> {
+        decode_payload_strict(self.payload)
+    }
+
+    /// Whether the payload was compressed by
+    /// [`compress_tcp_frame`](crate::codec_tcp::compress_tcp_frame).
+    pub fn is_compressed(&self) -> bool {
+        self.header.msg_id & COMPRESSED_FLAG != 0
+    }
+
+    /// The payload with any frame compression undone.
+    ///
+    /// Borrowed unless the frame was compressed. The declared size is checked
+    /// against the message's payload limit before anything is inflated.
+    pub fn decompressed_payload(&self) -> Result<Cow<'a, [u8]>, ProtoError> {
+        if !self.is_compressed() {
+            return Ok(Cow::Borrowed(self.payload));
+        }
+
+        let (size, block) = self
+            .payload
+            .split_first_chunk::<4>()
+            .ok_or(ProtoError::TooShort)?;
+        let size = u32::from_le_bytes(*size) as usize;
+        check_payload_len(self.msg_id, size)?;
+
+        let out =
+            lz4_flex::decompress(block, size).map_err(|e| ProtoError::Decompress(e.to_string()))?;
+        if out.len() != size {
+            return Err(ProtoError::LengthMismatch);
+        }
+        Ok(Cow::Owned(out))
+    }
+}
+
+/// Decodes a postcard payload that must be consumed exactly.
+///
+/// Plain `postcard::from_bytes` ignores trailing garbage; handlers facing untrusted
+/// peers should go through this instead.
+pub fn decode_payload_strict<'a, T: serde::Deserialize<'a>>(
+    payload: &'a [u8],
+) -> Result<T, ProtoError> {
+    let (value, rest) = postcard::take_from_bytes(payload)?;
+    if !rest.is_empty() {
+        return Err(ProtoError::TrailingBytes(rest.len()));
+    }
+    Ok(value)
+}
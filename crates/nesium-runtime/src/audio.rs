@@ -1,19 +1,108 @@
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "audio-output")]
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+use std::time::Duration;
+#[cfg(any(test, feature = "audio-output"))]
+use std::time::Instant;
 
-use anyhow::{Context, Result};
+#[cfg(feature = "audio-output")]
+use anyhow::Context;
+use anyhow::Result;
+#[cfg(feature = "audio-output")]
 use cpal::{
-    SampleFormat,
+    BufferSize, SampleFormat, SupportedBufferSize,
     traits::{DeviceTrait, HostTrait, StreamTrait},
 };
+#[cfg(feature = "audio-output")]
 use ringbuf::{
     HeapRb,
-    traits::{Consumer, Producer, Split},
+    traits::{Consumer, Observer, Producer, Split},
 };
 
+/// Shortest queue the automatic latency mode settles on.
+#[cfg(any(test, feature = "audio-output"))]
+pub(crate) const AUTO_LATENCY_MIN: Duration = Duration::from_millis(30);
+/// Longest queue the automatic latency mode grows to; also the ring buffer
+/// size in automatic mode.
+#[cfg(any(test, feature = "audio-output"))]
+pub(crate) const AUTO_LATENCY_MAX: Duration = Duration::from_millis(250);
+/// Weight of each new callback interval in the jitter estimate.
+#[cfg(any(test, feature = "audio-output"))]
+const JITTER_SMOOTHING: f32 = 0.05;
+
+/// How much audio is queued ahead of the device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AudioLatency {
+    /// Adapt the queue depth to the observed device callback jitter.
+    #[default]
+    Auto,
+    /// Keep at most this much audio queued.
+    Fixed(Duration),
+}
+
+/// What the device hears when the queue runs dry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnderrunStrategy {
+    #[default]
+    Silence,
+    /// Hold the last sample, which avoids the click of dropping to zero.
+    RepeatLast,
+}
+
+/// Output latency settings, passed as `RuntimeConfig::audio_latency`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AudioLatencyConfig {
+    pub latency: AudioLatency,
+    /// Device callback size in frames, clamped to what the device supports;
+    /// `None` keeps the device default.
+    pub buffer_frames: Option<u32>,
+    pub underrun: UnderrunStrategy,
+}
+
+/// Tracks device callback timing in the audio thread and derives the queue
+/// depth for [`AudioLatency::Auto`].
+#[cfg(any(test, feature = "audio-output"))]
+pub(crate) struct JitterTracker {
+    last_callback: Option<Instant>,
+    /// Smoothed absolute deviation of the callback interval from the
+    /// duration of audio it consumed, in seconds.
+    jitter: f32,
+    /// Largest callback period seen, in seconds.
+    period: f32,
+}
+
+#[cfg(any(test, feature = "audio-output"))]
+impl JitterTracker {
+    pub(crate) fn new() -> Self {
+        Self {
+            last_callback: None,
+            jitter: 0.0,
+            period: 0.0,
+        }
+    }
+
+    /// Records a callback that consumed `period` seconds of audio and returns
+    /// the queue depth to aim for.
+    pub(crate) fn observe(&mut self, now: Instant, period: f32) -> Duration {
+        if let Some(last) = self.last_callback {
+            let deviation = ((now - last).as_secs_f32() - period).abs();
+            self.jitter += (deviation - self.jitter) * JITTER_SMOOTHING;
+        }
+        self.last_callback = Some(now);
+        self.period = self.period.max(period);
+        // Two callbacks of headroom plus a generous margin for scheduling
+        // noise keeps underruns rare without queueing more than needed.
+        Duration::from_secs_f32(self.period * 2.0 + self.jitter * 4.0)
+            .clamp(AUTO_LATENCY_MIN, AUTO_LATENCY_MAX)
+    }
+}
+
 /// Thin audio output wrapper that feeds interleaved stereo PCM samples from the
 /// emulator into cpal's default output stream, backed by a lock-free SPSC
 /// ring buffer.
+#[cfg(feature = "audio-output")]
 pub struct NesAudioPlayer {
     /// Single producer handle used by the emulator thread to push samples.
     producer: ringbuf::HeapProd<f32>,
@@ -23,13 +112,17 @@ pub struct NesAudioPlayer {
     _stream: cpal::Stream,
     /// Flag to request clearing any queued samples from the audio thread.
     clear_flag: Arc<AtomicBool>,
+    /// Queue depth in samples; pushes beyond it are dropped. Updated by the
+    /// audio thread in automatic latency mode.
+    queue_limit: Arc<AtomicUsize>,
 }
 
+#[cfg(feature = "audio-output")]
 impl NesAudioPlayer {
     /// Create a new audio player on the default output device.
     ///
     /// This currently assumes the default output config uses `f32` samples.
-    pub fn new() -> Result<Self> {
+    pub fn new(latency: AudioLatencyConfig) -> Result<Self> {
         let host = cpal::default_host();
         let device = host
             .default_output_device()
@@ -44,22 +137,38 @@ impl NesAudioPlayer {
             anyhow::bail!("only f32 output format is supported, got {sample_format:?}");
         }
 
-        let config: cpal::StreamConfig = supported_config.into();
+        let supported_buffer = *supported_config.buffer_size();
+        let mut config: cpal::StreamConfig = supported_config.into();
+        if let Some(frames) = latency.buffer_frames {
+            config.buffer_size = BufferSize::Fixed(match supported_buffer {
+                SupportedBufferSize::Range { min, max } => frames.clamp(min, max),
+                SupportedBufferSize::Unknown => frames,
+            });
+        }
         let sample_rate = config.sample_rate;
         let channels = config.channels as usize;
 
-        // Allocate ~0.2 seconds of audio in the ring buffer.
-        // We store raw samples (interleaved channels), so capacity is:
-        //   sample_rate * seconds * channels
-        let latency_seconds = 0.2_f32;
-        let capacity = (sample_rate as f32 * latency_seconds * channels as f32).ceil() as usize;
-        let capacity = capacity.max(1);
+        // The queue holds interleaved stereo samples, so a duration maps to
+        // `sample_rate * seconds * 2` entries.
+        let samples_for = move |duration: Duration| {
+            ((sample_rate as f32 * duration.as_secs_f32() * 2.0).ceil() as usize).max(2)
+        };
+        let (capacity, initial_limit) = match latency.latency {
+            AudioLatency::Auto => (samples_for(AUTO_LATENCY_MAX), samples_for(AUTO_LATENCY_MIN)),
+            AudioLatency::Fixed(target) => (samples_for(target), samples_for(target)),
+        };
 
         let rb = HeapRb::<f32>::new(capacity);
         let (producer, mut consumer) = rb.split();
 
         let clear_flag = Arc::new(AtomicBool::new(false));
         let clear_flag_for_cb = clear_flag.clone();
+        let queue_limit = Arc::new(AtomicUsize::new(initial_limit));
+        let queue_limit_for_cb = queue_limit.clone();
+
+        let mut jitter = (latency.latency == AudioLatency::Auto).then(JitterTracker::new);
+        let repeat_last = latency.underrun == UnderrunStrategy::RepeatLast;
+        let mut last = (0.0_f32, 0.0_f32);
 
         let err_fn = |err| eprintln!("Audio stream error: {err}");
 
@@ -70,9 +179,19 @@ impl NesAudioPlayer {
                     while consumer.try_pop().is_some() {}
                 }
 
+                if let Some(jitter) = jitter.as_mut() {
+                    let period = (data.len() / channels.max(1)) as f32 / sample_rate as f32;
+                    let target = jitter.observe(Instant::now(), period);
+                    queue_limit_for_cb.store(samples_for(target), Ordering::Relaxed);
+                }
+
                 for frame in data.chunks_mut(channels) {
-                    let left = consumer.try_pop().unwrap_or(0.0);
-                    let right = consumer.try_pop().unwrap_or(left);
+                    let (left, right) = match consumer.try_pop() {
+                        Some(left) => (left, consumer.try_pop().unwrap_or(left)),
+                        None if repeat_last => last,
+                        None => (0.0, 0.0),
+                    };
+                    last = (left, right);
 
                     match channels {
                         0 => {}
@@ -101,18 +220,25 @@ impl NesAudioPlayer {
             sample_rate,
             _stream: stream,
             clear_flag,
+            queue_limit,
         })
     }
 
     /// Pushes a batch of interleaved stereo samples into the output buffer.
     ///
-    /// If the buffer is full, newest samples are dropped rather than blocking.
+    /// Once the queue reaches the latency target, newest samples are dropped
+    /// rather than blocking.
     pub fn push_samples(&mut self, samples: &[f32]) {
         if samples.is_empty() {
             return;
         }
 
+        let limit = self.queue_limit.load(Ordering::Relaxed);
         for chunk in samples.chunks(2) {
+            if self.producer.occupied_len() + 2 > limit {
+                break;
+            }
+
             let l = *chunk.first().unwrap_or(&0.0);
             let r = *chunk.get(1).unwrap_or(&l);
 
@@ -129,8 +255,73 @@ impl NesAudioPlayer {
         self.clear_flag.store(true, Ordering::SeqCst);
     }
 
+    /// Fraction of the latency target currently holding queued samples.
+    pub fn buffer_fill(&self) -> f32 {
+        let limit = self.queue_limit.load(Ordering::Relaxed);
+        (self.producer.occupied_len() as f32 / limit as f32).min(1.0)
+    }
+
+    /// Current queue depth target.
+    pub fn latency(&self) -> Duration {
+        let limit = self.queue_limit.load(Ordering::Relaxed);
+        Duration::from_secs_f32(limit as f32 / (self.sample_rate as f32 * 2.0))
+    }
+
     /// Returns the output sample rate.
     pub fn sample_rate(&self) -> u32 {
         self.sample_rate
     }
 }
+
+/// Stand-in for builds without the `audio-output` feature. It can never be
+/// opened, so the runtime reports `AudioInitFailed` and runs silently.
+#[cfg(not(feature = "audio-output"))]
+pub struct NesAudioPlayer {
+    never: std::convert::Infallible,
+}
+
+#[cfg(not(feature = "audio-output"))]
+impl NesAudioPlayer {
+    pub fn new(_latency: AudioLatencyConfig) -> Result<Self> {
+        anyhow::bail!("built without audio output support")
+    }
+
+    pub fn push_samples(&mut self, _samples: &[f32]) {
+        match self.never {}
+    }
+
+    pub fn clear(&self) {
+        match self.never {}
+    }
+
+    pub fn buffer_fill(&self) -> f32 {
+        match self.never {}
+    }
+
+    pub fn latency(&self) -> Duration {
+        match self.never {}
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        match self.never {}
+    }
+}
+
+/// Stereo frames over which audio is blended back in after a rollback.
+const ROLLBACK_CROSSFADE_FRAMES: usize = 128;
+
+/// Blends the start of `samples` (interleaved stereo) from the last sample
+/// that was played, `from`.
+///
+/// After a netplay rollback the resimulated timeline continues from a
+/// different waveform than the one already heard; fading across the jump
+/// turns the click into an inaudible ~3 ms transition.
+pub(crate) fn crossfade_from(samples: &mut [f32], from: [f32; 2]) {
+    let frames = (samples.len() / 2).min(ROLLBACK_CROSSFADE_FRAMES);
+    for (i, frame) in samples.chunks_exact_mut(2).take(frames).enumerate() {
+        let t = (i + 1) as f32 / (frames + 1) as f32;
+        for (sample, held) in frame.iter_mut().zip(from) {
+            *sample = held + (*sample - held) * t;
+        }
+    }
+}
@@ -2,8 +2,9 @@ pub mod audio;
 pub mod runtime;
 
 pub use runtime::{
-    AudioMode, DebugState, Event, EventTopic, NotificationEvent, PaletteState, Receiver, Runtime,
-    RuntimeConfig, RuntimeError, RuntimeEventSender, RuntimeHandle, Sender, SpriteInfo,
-    SpriteState, TileState, TileViewerBackground, TileViewerConfig, TileViewerLayout,
-    TileViewerSource, TilemapState, VideoBackendConfig, VideoConfig,
+    AudioMode, DebugState, Event, EventFilter, EventTopic, FrameEvent, FrameStats, GameProfile,
+    NotificationEvent, PaletteState, Receiver, RomReloadMode, Runtime, RuntimeConfig, RuntimeError,
+    RuntimeEventSender, RuntimeHandle, Sender, SpriteInfo, SpriteState, TileState,
+    TileViewerBackground, TileViewerConfig, TileViewerLayout, TileViewerSource, TilemapState,
+    VideoBackendConfig, VideoConfig,
 };
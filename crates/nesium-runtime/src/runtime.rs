@@ -3,18 +3,38 @@ mod debug;
 mod debug_interceptor;
 mod handle;
 mod pubsub;
+mod rainbow;
+mod recorder;
+mod replay_check;
 mod runner;
+mod screenshot;
+mod slots;
 mod state;
+mod stats;
 mod types;
 mod util;
+mod video_filter;
+mod watchdog;
+
+#[cfg(test)]
+mod tests;
 
 pub use crossbeam_channel::{Receiver, Sender};
 pub use debug::{DebugCommand, DebugEvent, PauseReason};
 pub use handle::{Runtime, RuntimeHandle};
+pub use replay_check::{ReplayBaseline, ReplayBaselineError, ReplayOutcome};
+pub use screenshot::ScreenshotOptions;
+pub use slots::{SAVE_STATE_SLOTS, SaveSlotInfo, StateSlot, THUMBNAIL_HEIGHT, THUMBNAIL_WIDTH};
 pub use types::{
-    AudioMode, DebugState, EmulationStatus, Event, EventTopic, NotificationEvent, PaletteState,
-    ReplayEvent, RuntimeConfig, RuntimeError, RuntimeEventSender, SpriteInfo, SpriteState,
+    AudioMode, DebugState, EmulationStatus, Event, EventFilter, EventTopic, FrameEvent, FrameStats,
+    GameProfile, MovieInputWindow, NotificationEvent, PaletteState, PerformanceStats, ReplayEvent,
+    RomReloadMode, RuntimeConfig, RuntimeError, RuntimeEventSender, SpriteInfo, SpriteState,
     TileState, TileViewerBackground, TileViewerConfig, TileViewerLayout, TileViewerSource,
     TilemapState, VideoBackendConfig, VideoConfig,
 };
 pub use util::{is_high_priority_enabled, set_high_priority_enabled};
+pub use video_filter::{
+    HqxScale, NesNtscPreset, NesNtscTuning, NtscBisqwitOptions, PRESCALE_MAX, SaiVariant,
+    VideoFilter,
+};
+pub use watchdog::install_termination_watchdog;
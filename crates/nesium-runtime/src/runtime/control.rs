@@ -3,7 +3,8 @@ use std::{path::PathBuf, sync::Arc};
 
 use crossbeam_channel::{Receiver, Sender};
 use nesium_core::{
-    audio::bus::AudioBusConfig,
+    audio::{MixerSettings, bus::AudioBusConfig},
+    config::{overclock::Overclock, ram_init::RamInit},
     interceptor::{
         palette_interceptor::CapturePoint as PaletteCapturePoint,
         sprite_interceptor::CapturePoint as SpriteCapturePoint,
@@ -16,9 +17,12 @@ use nesium_core::{
 };
 
 use super::debug::{DebugCommand, DebugEvent};
+use super::replay_check::ReplayBaseline;
+use super::screenshot::ScreenshotOptions;
+use super::slots::StateSlot;
 use super::types::{
-    EventTopic, RuntimeError, RuntimeEventSender, TileViewerBackground, TileViewerLayout,
-    TileViewerSource,
+    EventFilter, EventTopic, RomReloadMode, RuntimeError, RuntimeEventSender, TileViewerBackground,
+    TileViewerLayout, TileViewerSource,
 };
 
 pub(crate) type ControlReplySender = Sender<Result<(), RuntimeError>>;
@@ -27,9 +31,14 @@ pub(crate) enum ControlMessage {
     Stop,
     LoadRom(PathBuf, ControlReplySender),
     LoadRomFromMemory(Vec<u8>, ControlReplySender),
+    ReloadRom(RomReloadMode, ControlReplySender),
     Reset(ResetKind, ControlReplySender),
     PowerOff(ControlReplySender),
     SetAudioConfig(AudioBusConfig, ControlReplySender),
+    /// Per-channel volume, panning and expansion balance.
+    SetMixerSettings(MixerSettings, ControlReplySender),
+    InsertBarcode(String, ControlReplySender),
+    SetRainbowBridge(Option<String>, ControlReplySender),
     SetFrameReadyCallback(Option<FrameReadyCallback>, *mut c_void, ControlReplySender),
     SetColorFormat(ColorFormat, ControlReplySender),
     SetVideoOutputConfig {
@@ -38,6 +47,9 @@ pub(crate) enum ControlMessage {
         reply: ControlReplySender,
     },
     SetVideoPostProcessor(Box<dyn VideoPostProcessor>, ControlReplySender),
+    CaptureScreenshot(PathBuf, ScreenshotOptions, ControlReplySender),
+    StartRecording(PathBuf, ControlReplySender),
+    StopRecording(ControlReplySender),
     SetVideoPipeline {
         width: u32,
         height: u32,
@@ -46,18 +58,57 @@ pub(crate) enum ControlMessage {
     },
     SetPaletteKind(PaletteKind, ControlReplySender),
     SetPalette(Palette, ControlReplySender),
+    /// Toggle famiclone PPU quirks (no emphasis, approximate clone palette,
+    /// true sprite overflow).
+    SetClonePpu(bool, ControlReplySender),
+    /// Show/hide the background or sprites in the video output only.
+    SetBackgroundLayer(bool, ControlReplySender),
+    SetSpriteLayer(bool, ControlReplySender),
+    /// Draw more than eight sprites per scanline (display only).
+    SetSpriteLimitRemoved(bool, ControlReplySender),
+    SetAccurateOam(bool, ControlReplySender),
+    /// Frames an undriven PPU open-bus bit keeps its value.
+    SetOpenBusDecayFrames(u32, ControlReplySender),
+    SetOverclock(Overclock, ControlReplySender),
+    /// Default power-on RAM pattern; takes effect on the next power cycle.
+    SetRamInit(RamInit, ControlReplySender),
+    /// Keep host settings out of emulation (see `Nes::set_deterministic`).
+    /// Netplay sessions enable this regardless.
+    SetDeterministic(bool, ControlReplySender),
     /// None = exact NTSC FPS, Some(60) = integer FPS (PAL reserved for future).
     SetIntegerFpsTarget(Option<u32>, ControlReplySender),
     SaveState(PathBuf, ControlReplySender),
     LoadState(PathBuf, ControlReplySender),
     SaveStateToMemory(Sender<Result<Vec<u8>, RuntimeError>>),
+    SaveSlot(StateSlot, ControlReplySender),
+    LoadSlot(StateSlot, ControlReplySender),
+    UndoLoadState(ControlReplySender),
     LoadStateFromMemory(Vec<u8>, ControlReplySender),
     SetRewinding(bool, ControlReplySender),
     SetFastForwarding(bool, ControlReplySender),
     SetFastForwardSpeed(u16, ControlReplySender),
     SetRewindSpeed(u16, ControlReplySender),
+    /// Frames dropped after each presented one while fast-forwarding.
+    SetFastForwardFrameSkip(u8, ControlReplySender),
+    SetSlowMotion(bool, ControlReplySender),
+    SetSlowMotionSpeed(u16, ControlReplySender),
+    /// Normal emulation speed in percent.
+    SetSpeed(u16, ControlReplySender),
+    Pause(ControlReplySender),
+    Resume(ControlReplySender),
+    /// Runs exactly one frame and leaves the runtime paused.
+    AdvanceFrame(ControlReplySender),
     LoadMovie(nesium_support::tas::Movie, ControlReplySender),
-    SubscribeEvent(EventTopic, Box<dyn RuntimeEventSender>, ControlReplySender),
+    /// Power cycle and record a core movie, hashing the state every N frames.
+    StartReplayRecording(u32, ControlReplySender),
+    FinishReplayRecording(Sender<Result<ReplayBaseline, RuntimeError>>),
+    StartReplayCheck(Box<ReplayBaseline>, ControlReplySender),
+    SubscribeEvent(
+        EventTopic,
+        Box<dyn RuntimeEventSender>,
+        Option<EventFilter>,
+        ControlReplySender,
+    ),
     UnsubscribeEvent(EventTopic, ControlReplySender),
     // Per-viewer capture points
     SetTilemapCapturePoint(TilemapCapturePoint, ControlReplySender),
@@ -189,7 +189,7 @@ impl DebugInterceptor {
                 self.paused = false;
                 let _ = self.ctrl_tx.send(msg);
             }
-            ControlMessage::LoadRom(_, _) => {
+            ControlMessage::LoadRom(_, _) | ControlMessage::ReloadRom(_, _) => {
                 // Forward and exit (will trigger reset anyway)
                 self.break_on_reset = true;
                 self.paused = false;
@@ -1,15 +1,21 @@
 use core::ffi::c_void;
 use std::{
+    panic::{self, AssertUnwindSafe},
     path::PathBuf,
-    sync::{Arc, atomic::Ordering},
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
     thread::{self, JoinHandle},
     time::Duration,
 };
 
 use crossbeam_channel::{Sender, bounded, unbounded};
 use nesium_core::{
-    audio::bus::AudioBusConfig,
-    controller::Button,
+    audio::{MixerSettings, bus::AudioBusConfig},
+    cartridge::CartridgeInfo,
+    config::{overclock::Overclock, ram_init::RamInit},
+    controller::{Button, ButtonRemap, MultitapKind, ZapperCalibration, ZapperTiming},
     interceptor::{
         palette_interceptor::CapturePoint as PaletteCapturePoint,
         sprite_interceptor::CapturePoint as SpriteCapturePoint,
@@ -18,24 +24,39 @@ use nesium_core::{
     },
     ppu::buffer::{ExternalFrameHandle, FrameBuffer},
     ppu::buffer::{FrameReadyCallback, VideoPostProcessor},
-    ppu::palette::{Palette, PaletteKind},
+    ppu::palette::{Palette, PaletteEntry, PaletteId, PaletteKind},
     reset_kind::ResetKind,
 };
 
 use super::{
     control::{ControlMessage, ControlReplySender},
     pubsub::RuntimePubSub,
+    replay_check::ReplayBaseline,
     runner::Runner,
-    state::RuntimeState,
+    screenshot::ScreenshotOptions,
+    slots::{SAVE_STATE_SLOTS, SaveSlotInfo, SlotPaths, StateSlot},
+    state::{
+        FAST_FORWARD_FRAME_SKIP_MAX, INPUT_POLL_FRAME_START, INPUT_POLL_SCANLINE_MAX,
+        MULTITAP_FOUR_SCORE, MULTITAP_HORI, MULTITAP_NONE, REWIND_INTERVAL_MAX, RuntimeState,
+        SPEED_PERCENT_MAX, SPEED_PERCENT_MIN, ZAPPER_DISCONNECTED, ZAPPER_MAX_LAG_FRAMES,
+        ZAPPER_OFF_SCREEN,
+    },
     types::{
-        CONTROL_REPLY_TIMEOUT, EventTopic, LOAD_ROM_REPLY_TIMEOUT, RuntimeConfig, RuntimeError,
-        RuntimeEventSender, SAVE_STATE_REPLY_TIMEOUT, TileViewerBackground, TileViewerLayout,
-        TileViewerSource, VideoBackendConfig,
+        CONTROL_REPLY_TIMEOUT, EventFilter, EventTopic, GameProfile, LOAD_ROM_REPLY_TIMEOUT,
+        MovieInputWindow, RomReloadMode, RuntimeConfig, RuntimeError, RuntimeEventSender,
+        SAVE_STATE_REPLY_TIMEOUT, TileViewerBackground, TileViewerLayout, TileViewerSource,
+        VideoBackendConfig,
     },
-    util::{button_bit, try_raise_current_thread_priority},
+    util::{button_bit, forget_current_runtime_thread, try_raise_current_thread_priority},
+    video_filter::VideoFilter,
+    watchdog::RunnerGuard,
 };
 
+/// Source of [`Runtime::id`]; ids are never reused within a process.
+static NEXT_RUNTIME_ID: AtomicU32 = AtomicU32::new(0);
+
 struct RuntimeInner {
+    id: u32,
     ctrl_tx: Sender<ControlMessage>,
     frame_handle: Option<Arc<ExternalFrameHandle>>,
     state: Arc<RuntimeState>,
@@ -91,28 +112,50 @@ impl Runtime {
         let state = Arc::new(RuntimeState::new());
         let thread_state = Arc::clone(&state);
         let audio_mode = config.audio;
+        let audio_latency = config.audio_latency;
+        let overclock = config.overclock;
+        let ram_init = config.ram_init;
 
         let mut pubsub = RuntimePubSub::new();
         if let Some(sender) = event_sender {
             // By default, a monolithic sender subscribes to everything we know about.
-            pubsub.subscribe(EventTopic::Notification, sender);
+            pubsub.subscribe(EventTopic::Notification, sender, None);
         }
 
+        let id = NEXT_RUNTIME_ID.fetch_add(1, Ordering::Relaxed);
         let ctrl_tx_clone = ctrl_tx.clone();
-        let join = thread::spawn(move || {
-            try_raise_current_thread_priority();
-            let mut runner = Runner::new(
-                audio_mode,
-                ctrl_rx,
-                ctrl_tx_clone,
-                pubsub,
-                framebuffer,
-                thread_state,
-            );
-            runner.run();
-        });
+        let spawned = thread::Builder::new()
+            .name(format!("nesium-runtime-{id}"))
+            .spawn(move || {
+                let _guard = RunnerGuard::new();
+                try_raise_current_thread_priority();
+                let mut runner = Runner::new(
+                    audio_mode,
+                    audio_latency,
+                    overclock,
+                    ram_init,
+                    ctrl_rx,
+                    ctrl_tx_clone,
+                    pubsub,
+                    framebuffer,
+                    thread_state,
+                );
+                if let Err(panic) = panic::catch_unwind(AssertUnwindSafe(|| runner.run())) {
+                    let reason = panic
+                        .downcast_ref::<&str>()
+                        .map(|s| s.to_string())
+                        .or_else(|| panic.downcast_ref::<String>().cloned())
+                        .unwrap_or_else(|| "runtime thread panicked".to_string());
+                    runner.emergency_save(&format!("panic: {reason}"));
+                }
+                forget_current_runtime_thread();
+            });
+        let join = spawned.map_err(|e| RuntimeError::SpawnFailed {
+            error: e.to_string(),
+        })?;
 
         let inner = Arc::new(RuntimeInner {
+            id,
             ctrl_tx,
             frame_handle,
             state,
@@ -129,6 +172,12 @@ impl Runtime {
             inner: Arc::clone(&self.inner),
         }
     }
+
+    /// Process-unique id of this instance. Each `Runtime` owns its NES
+    /// thread, framebuffer and audio stream, so several can run side by side.
+    pub fn id(&self) -> u32 {
+        self.inner.id
+    }
 }
 
 impl Drop for Runtime {
@@ -141,6 +190,11 @@ impl Drop for Runtime {
 }
 
 impl RuntimeHandle {
+    /// Id of the [`Runtime`] this handle controls.
+    pub fn id(&self) -> u32 {
+        self.inner.id
+    }
+
     fn send_with_reply(
         &self,
         op: &'static str,
@@ -169,7 +223,21 @@ impl RuntimeHandle {
         sender: Box<dyn RuntimeEventSender>,
     ) -> Result<(), RuntimeError> {
         self.send_with_reply("subscribe_event", CONTROL_REPLY_TIMEOUT, |reply| {
-            ControlMessage::SubscribeEvent(topic, sender, reply)
+            ControlMessage::SubscribeEvent(topic, sender, None, reply)
+        })
+    }
+
+    /// Like [`subscribe_event`](Self::subscribe_event), but only events
+    /// accepted by `filter` are sent. The filter runs on the runtime thread,
+    /// so it should be cheap.
+    pub fn subscribe_event_filtered(
+        &self,
+        topic: EventTopic,
+        sender: Box<dyn RuntimeEventSender>,
+        filter: EventFilter,
+    ) -> Result<(), RuntimeError> {
+        self.send_with_reply("subscribe_event", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SubscribeEvent(topic, sender, Some(filter), reply)
         })
     }
 
@@ -298,10 +366,61 @@ impl RuntimeHandle {
             .load(std::sync::atomic::Ordering::Acquire)
     }
 
+    /// Pauses emulation at the next frame boundary.
+    ///
+    /// Unlike [`set_paused`](Self::set_paused), this waits for the NES thread:
+    /// once it returns, no further frame runs until [`resume`](Self::resume)
+    /// or [`advance_frame`](Self::advance_frame).
+    pub fn pause(&self) -> Result<(), RuntimeError> {
+        self.send_with_reply("pause", CONTROL_REPLY_TIMEOUT, ControlMessage::Pause)
+    }
+
+    /// Resumes emulation paused by [`pause`](Self::pause), a frame advance or
+    /// [`set_pause_on_jam`](Self::set_pause_on_jam).
+    pub fn resume(&self) -> Result<(), RuntimeError> {
+        self.send_with_reply("resume", CONTROL_REPLY_TIMEOUT, ControlMessage::Resume)
+    }
+
+    /// Pauses emulation if needed and runs exactly one frame, returning after
+    /// it has been presented.
+    ///
+    /// Rewinding is cancelled; held fast-forward has no effect on the stepped
+    /// frame. Fails with [`RuntimeError::NoRomLoaded`] when nothing is loaded.
+    pub fn advance_frame(&self) -> Result<(), RuntimeError> {
+        self.send_with_reply(
+            "advance_frame",
+            CONTROL_REPLY_TIMEOUT,
+            ControlMessage::AdvanceFrame,
+        )
+    }
+
+    /// Automatically pauses emulation when the CPU executes a JAM opcode.
+    /// [`NotificationEvent::CpuJammed`](super::NotificationEvent::CpuJammed)
+    /// is sent either way.
+    pub fn set_pause_on_jam(&self, enabled: bool) {
+        self.inner
+            .state
+            .pause_on_jam
+            .store(enabled, std::sync::atomic::Ordering::Release);
+    }
+
+    pub fn pause_on_jam(&self) -> bool {
+        self.inner
+            .state
+            .pause_on_jam
+            .load(std::sync::atomic::Ordering::Acquire)
+    }
+
     pub fn rom_hash(&self) -> Option<[u8; 32]> {
         *self.inner.state.rom_hash.lock()
     }
 
+    /// Metadata of the loaded cartridge, also broadcast as
+    /// [`NotificationEvent::CartridgeLoaded`](super::NotificationEvent::CartridgeLoaded) when a ROM finishes loading.
+    pub fn cartridge_info(&self) -> Option<CartridgeInfo> {
+        self.inner.state.cartridge_info.lock().clone()
+    }
+
     pub fn set_pad_mask(&self, pad: usize, mask: u8) {
         if let Some(slot) = self.inner.state.pad_masks.get(pad) {
             slot.store(mask, std::sync::atomic::Ordering::Release);
@@ -337,6 +456,93 @@ impl RuntimeHandle {
             .store(off_frames.max(1), Ordering::Release);
     }
 
+    /// Sets the logical -> console button mapping for `pad` (A/B or
+    /// Select/Start swaps). Live input is remapped before it is sent to
+    /// netplay peers or applied, so recordings always hold console buttons.
+    /// A loaded game's [`GameProfile::button_remaps`] take precedence.
+    pub fn set_button_remap(&self, pad: usize, remap: ButtonRemap) {
+        if let Some(slot) = self.inner.state.button_remaps.lock().get_mut(pad) {
+            *slot = remap;
+        }
+    }
+
+    /// Connects a four-player adapter so pads 3/4 (`set_button(2..=3, ..)`)
+    /// become visible to games. Takes effect on the next frame.
+    pub fn set_multitap(&self, kind: Option<MultitapKind>) {
+        let value = match kind {
+            None => MULTITAP_NONE,
+            Some(MultitapKind::FourScore) => MULTITAP_FOUR_SCORE,
+            Some(MultitapKind::Hori) => MULTITAP_HORI,
+        };
+        self.inner
+            .state
+            .multitap
+            .store(value, std::sync::atomic::Ordering::Release);
+    }
+
+    /// Connects a Zapper light gun to `port` (0 or 1), or reconnects the
+    /// standard pad with `None`. Takes effect on the next frame.
+    pub fn set_zapper_port(&self, port: Option<usize>) {
+        let value = match port {
+            Some(port @ 0..=1) => port as u8,
+            _ => ZAPPER_DISCONNECTED,
+        };
+        self.inner
+            .state
+            .zapper_port
+            .store(value, std::sync::atomic::Ordering::Release);
+    }
+
+    /// Updates the Zapper aim point in NES pixels (`256x240`).
+    ///
+    /// Pass `None` when the pointer leaves the game view; the gun then reads
+    /// as aimed away from the screen, which games use for reload shots.
+    pub fn set_zapper_pointer(&self, position: Option<(i32, i32)>) {
+        use std::sync::atomic::Ordering;
+        let (x, y) = position.unwrap_or((ZAPPER_OFF_SCREEN, ZAPPER_OFF_SCREEN));
+        self.inner.state.zapper_x.store(x, Ordering::Release);
+        self.inner.state.zapper_y.store(y, Ordering::Release);
+    }
+
+    pub fn set_zapper_trigger(&self, pulled: bool) {
+        self.inner
+            .state
+            .zapper_trigger
+            .store(pulled, std::sync::atomic::Ordering::Release);
+    }
+
+    /// Adjusts the Zapper photodiode window (persistence, brightness, radius).
+    pub fn set_zapper_timing(&self, timing: ZapperTiming) {
+        *self.inner.state.zapper_timing.lock() = timing;
+    }
+
+    /// Offsets pointer positions passed to [`Self::set_zapper_pointer`], to
+    /// line aiming up with the picture on a particular display.
+    pub fn set_zapper_calibration(&self, calibration: ZapperCalibration) {
+        *self.inner.state.zapper_calibration.lock() = calibration;
+    }
+
+    /// Draws a crosshair at the calibrated aim point in the video output.
+    pub fn set_zapper_crosshair(&self, visible: bool) {
+        self.inner
+            .state
+            .zapper_crosshair
+            .store(visible, std::sync::atomic::Ordering::Release);
+    }
+
+    /// Compensates for display lag (e.g. LCD processing) of `frames` frames.
+    ///
+    /// When the trigger is pulled, emulation is rolled back by `frames` and
+    /// re-run with the trigger held, so the shot lands on the frame the
+    /// player was actually looking at. Clamped to 8 frames; 0 disables it.
+    /// Has no effect during netplay or movie playback.
+    pub fn set_zapper_lag_compensation(&self, frames: u8) {
+        self.inner.state.zapper_lag_frames.store(
+            frames.min(ZAPPER_MAX_LAG_FRAMES),
+            std::sync::atomic::Ordering::Release,
+        );
+    }
+
     pub fn set_button(&self, pad: usize, button: Button, pressed: bool) {
         let Some(slot) = self.inner.state.pad_masks.get(pad) else {
             return;
@@ -364,6 +570,16 @@ impl RuntimeHandle {
         })
     }
 
+    /// Reloads the current ROM from its file, e.g. after rebuilding a homebrew
+    /// project. The old game is closed like any other ROM load (battery RAM
+    /// and autosave are written first). Fails with
+    /// [`RuntimeError::RomNotReloadable`] for ROMs loaded from memory.
+    pub fn reload_rom(&self, mode: RomReloadMode) -> Result<(), RuntimeError> {
+        self.send_with_reply("reload_rom", LOAD_ROM_REPLY_TIMEOUT, |reply| {
+            ControlMessage::ReloadRom(mode, reply)
+        })
+    }
+
     pub fn reset(&self, kind: ResetKind) -> Result<(), RuntimeError> {
         self.send_with_reply("reset", CONTROL_REPLY_TIMEOUT, |reply| {
             ControlMessage::Reset(kind, reply)
@@ -380,6 +596,28 @@ impl RuntimeHandle {
         })
     }
 
+    pub fn set_mixer_settings(&self, settings: MixerSettings) -> Result<(), RuntimeError> {
+        self.send_with_reply("set_mixer_settings", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SetMixerSettings(settings, reply)
+        })
+    }
+
+    /// Feeds a barcode to the Datach reader of the loaded cartridge.
+    pub fn insert_barcode(&self, barcode: impl Into<String>) -> Result<(), RuntimeError> {
+        let barcode = barcode.into();
+        self.send_with_reply("insert_barcode", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::InsertBarcode(barcode, reply)
+        })
+    }
+
+    /// Connects the WiFi module of Rainbow (mapper 682) cartridges to a TCP
+    /// endpoint at `addr` (`host:port`), or disconnects it with `None`.
+    pub fn set_rainbow_bridge(&self, addr: Option<String>) -> Result<(), RuntimeError> {
+        self.send_with_reply("set_rainbow_bridge", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SetRainbowBridge(addr, reply)
+        })
+    }
+
     pub fn set_frame_ready_callback(
         &self,
         cb: Option<FrameReadyCallback>,
@@ -430,6 +668,48 @@ impl RuntimeHandle {
         })
     }
 
+    /// Saves the next presented frame as a PNG at `path` and returns once it
+    /// is written. While paused, the frame on screen is saved right away.
+    /// [`NotificationEvent::ScreenshotSaved`](super::NotificationEvent::ScreenshotSaved)
+    /// or `ScreenshotFailed` is sent either way.
+    pub fn capture_screenshot(
+        &self,
+        path: impl Into<PathBuf>,
+        options: ScreenshotOptions,
+    ) -> Result<(), RuntimeError> {
+        let path = path.into();
+        self.send_with_reply("capture_screenshot", SAVE_STATE_REPLY_TIMEOUT, |reply| {
+            ControlMessage::CaptureScreenshot(path, options, reply)
+        })
+    }
+
+    /// Records every emulated frame to `<path>.y4m` and its audio to
+    /// `<path>.wav`, replacing any recording in progress.
+    ///
+    /// Video is the raw 256x240 picture as lossless 4:4:4 Y4M at the exact
+    /// NTSC frame rate; audio is the stereo mix at the 96 kHz mixer rate,
+    /// before resampling and bus volume. Both advance only with emulated
+    /// frames, so they stay frame-exact through pauses, fast-forward and
+    /// slow motion. Rewound frames are not recorded, and fast-forward frame
+    /// skip is suspended while recording.
+    pub fn start_recording(&self, path: impl Into<PathBuf>) -> Result<(), RuntimeError> {
+        let path = path.into();
+        self.send_with_reply("start_recording", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::StartRecording(path, reply)
+        })
+    }
+
+    /// Finalizes the recording, returning the first write error it hit.
+    pub fn stop_recording(&self) -> Result<(), RuntimeError> {
+        self.send_with_reply("stop_recording", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::StopRecording(reply)
+        })
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.inner.state.recording.load(Ordering::Acquire)
+    }
+
     pub fn set_video_pipeline(
         &self,
         width: u32,
@@ -449,6 +729,15 @@ impl RuntimeHandle {
         })
     }
 
+    /// Switches the built-in video filter and resizes the packed output planes
+    /// to match. Returns the new output size; a frontend that owns the
+    /// swapchain should size it with [`VideoFilter::output_size`] first.
+    pub fn set_video_filter(&self, filter: VideoFilter) -> Result<(u32, u32), RuntimeError> {
+        let (width, height) = filter.output_size()?;
+        self.set_video_pipeline(width, height, filter.build_processor())?;
+        Ok((width, height))
+    }
+
     /// Enables an integer FPS pacing mode.
     ///
     /// - `None`: run at the NES's exact NTSC FPS (~60.0988Hz)
@@ -477,6 +766,93 @@ impl RuntimeHandle {
         })
     }
 
+    pub fn set_clone_ppu(&self, enabled: bool) -> Result<(), RuntimeError> {
+        self.send_with_reply("set_clone_ppu", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SetClonePpu(enabled, reply)
+        })
+    }
+
+    /// Shows or hides the background layer. Display only: games still see
+    /// `$2001` and sprite 0 hits as usual.
+    pub fn set_background_layer_enabled(&self, enabled: bool) -> Result<(), RuntimeError> {
+        self.send_with_reply("set_background_layer", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SetBackgroundLayer(enabled, reply)
+        })
+    }
+
+    /// Shows or hides the sprite layer (display only).
+    pub fn set_sprite_layer_enabled(&self, enabled: bool) -> Result<(), RuntimeError> {
+        self.send_with_reply("set_sprite_layer", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SetSpriteLayer(enabled, reply)
+        })
+    }
+
+    /// Toggles the "no sprite flicker" mode that draws every sprite on a
+    /// scanline. Games still see the 8-sprite limit and overflow flag.
+    pub fn set_sprite_limit_removed(&self, removed: bool) -> Result<(), RuntimeError> {
+        self.send_with_reply("set_sprite_limit_removed", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SetSpriteLimitRemoved(removed, reply)
+        })
+    }
+
+    /// Enables OAM decay and the rarer `$2004` read quirks. Off by default;
+    /// only accuracy test ROMs and a few demos depend on them.
+    pub fn set_accurate_oam(&self, enabled: bool) -> Result<(), RuntimeError> {
+        self.send_with_reply("set_accurate_oam", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SetAccurateOam(enabled, reply)
+        })
+    }
+
+    /// Sets how many frames an undriven PPU open-bus bit keeps its value.
+    /// Defaults to Mesen2's 3 frames; `u32::MAX` disables decay.
+    pub fn set_open_bus_decay_frames(&self, frames: u32) -> Result<(), RuntimeError> {
+        self.send_with_reply(
+            "set_open_bus_decay_frames",
+            CONTROL_REPLY_TIMEOUT,
+            |reply| ControlMessage::SetOpenBusDecayFrames(frames, reply),
+        )
+    }
+
+    /// Sets the extra scanlines inserted around vblank each frame.
+    pub fn set_overclock(&self, overclock: Overclock) -> Result<(), RuntimeError> {
+        self.send_with_reply("set_overclock", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SetOverclock(overclock, reply)
+        })
+    }
+
+    /// Sets the power-on RAM pattern used by ROMs without a profile
+    /// override. Applies from the next power cycle or ROM load.
+    pub fn set_ram_init(&self, ram_init: RamInit) -> Result<(), RuntimeError> {
+        self.send_with_reply("set_ram_init", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SetRamInit(ram_init, reply)
+        })
+    }
+
+    /// Registers (or with `None`, removes) the profile for the ROM whose
+    /// [`rom_hash`](Self::rom_hash) is `rom_hash`. The RAM pattern is applied
+    /// when that ROM is next loaded; button remaps take effect on the next
+    /// frame.
+    pub fn set_game_profile(&self, rom_hash: [u8; 32], profile: Option<GameProfile>) {
+        let mut profiles = self.inner.state.game_profiles.lock();
+        match profile {
+            Some(profile) => profiles.insert(rom_hash, profile),
+            None => profiles.remove(&rom_hash),
+        };
+    }
+
+    /// Profile registered for `rom_hash` with
+    /// [`set_game_profile`](Self::set_game_profile).
+    pub fn game_profile(&self, rom_hash: &[u8; 32]) -> Option<GameProfile> {
+        self.inner.state.game_profiles.lock().get(rom_hash).copied()
+    }
+
+    /// Toggles deterministic emulation for TAS recording and replay checks.
+    pub fn set_deterministic(&self, enabled: bool) -> Result<(), RuntimeError> {
+        self.send_with_reply("set_deterministic", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SetDeterministic(enabled, reply)
+        })
+    }
+
     pub fn set_palette_from_pal_data(&self, data: &[u8]) -> Result<(), RuntimeError> {
         let palette = Palette::from_pal_data(data).map_err(|e| match e {
             nesium_core::error::Error::InvalidPaletteSize { actual } => {
@@ -514,6 +890,54 @@ impl RuntimeHandle {
         })
     }
 
+    /// Snapshot of the palette library: the built-in presets followed by
+    /// every file loaded through [`load_palette_file`](Self::load_palette_file).
+    pub fn palettes(&self) -> Vec<PaletteEntry> {
+        self.inner
+            .state
+            .palette_library
+            .lock()
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Adds a 64- or 512-color `.pal` file to the palette library without
+    /// selecting it. Reloading the same path refreshes the entry in place.
+    pub fn load_palette_file(&self, path: impl Into<PathBuf>) -> Result<PaletteId, RuntimeError> {
+        let path = path.into();
+        self.inner
+            .state
+            .palette_library
+            .lock()
+            .load_file(&path)
+            .map_err(|e| match e {
+                nesium_core::error::Error::InvalidPaletteSize { actual } => {
+                    RuntimeError::InvalidPaletteSize { actual }
+                }
+                _ => RuntimeError::LoadPaletteFailed {
+                    path,
+                    error: e.to_string(),
+                },
+            })
+    }
+
+    /// Switches the active palette to a library entry.
+    pub fn select_palette(&self, id: PaletteId) -> Result<(), RuntimeError> {
+        let palette = self
+            .inner
+            .state
+            .palette_library
+            .lock()
+            .get(id)
+            .map(|entry| entry.palette.clone())
+            .ok_or(RuntimeError::UnknownPalette { id: id.index() })?;
+
+        self.send_with_reply("set_palette", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SetPalette(palette, reply)
+        })
+    }
+
     pub fn save_state(&self, path: impl Into<PathBuf>) -> Result<(), RuntimeError> {
         let path = path.into();
         self.send_with_reply("save_state", SAVE_STATE_REPLY_TIMEOUT, |reply| {
@@ -555,6 +979,88 @@ impl RuntimeHandle {
         )
     }
 
+    /// Overrides the directory save-state slots are stored under. Defaults to
+    /// `nesium/states` in the platform data directory; mobile frontends have
+    /// to set it before using slots.
+    pub fn set_save_state_dir(&self, dir: Option<PathBuf>) {
+        *self.inner.state.save_state_dir.lock() = dir;
+    }
+
+    pub fn save_state_dir(&self) -> Option<PathBuf> {
+        self.inner.state.save_state_dir.lock().clone()
+    }
+
+    /// Writes the current state and a thumbnail to slot `slot`
+    /// (`0..SAVE_STATE_SLOTS`) of the loaded game.
+    pub fn save_slot(&self, slot: u8) -> Result<(), RuntimeError> {
+        let slot = numbered_slot(slot)?;
+        self.send_with_reply("save_slot", SAVE_STATE_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SaveSlot(slot, reply)
+        })
+    }
+
+    /// Loads slot `slot` of the loaded game. The state it replaces can be
+    /// restored with [`undo_load_state`](Self::undo_load_state).
+    pub fn load_slot(&self, slot: u8) -> Result<(), RuntimeError> {
+        let slot = numbered_slot(slot)?;
+        self.send_with_reply("load_slot", SAVE_STATE_REPLY_TIMEOUT, |reply| {
+            ControlMessage::LoadSlot(slot, reply)
+        })
+    }
+
+    /// Loads the state written when the game was last closed.
+    pub fn load_autosave(&self) -> Result<(), RuntimeError> {
+        self.send_with_reply("load_autosave", SAVE_STATE_REPLY_TIMEOUT, |reply| {
+            ControlMessage::LoadSlot(StateSlot::Autosave, reply)
+        })
+    }
+
+    /// Restores the state written when the last session with this game
+    /// crashed or was killed.
+    pub fn load_emergency_state(&self) -> Result<(), RuntimeError> {
+        self.send_with_reply("load_emergency_state", SAVE_STATE_REPLY_TIMEOUT, |reply| {
+            ControlMessage::LoadSlot(StateSlot::Emergency, reply)
+        })
+    }
+
+    /// Restores the state from just before the last slot or file load.
+    pub fn undo_load_state(&self) -> Result<(), RuntimeError> {
+        self.send_with_reply(
+            "undo_load_state",
+            SAVE_STATE_REPLY_TIMEOUT,
+            ControlMessage::UndoLoadState,
+        )
+    }
+
+    /// Saves to [`StateSlot::Autosave`] whenever a game is closed: on
+    /// shutdown, power off, or when another ROM is loaded.
+    pub fn set_autosave_on_exit(&self, enabled: bool) {
+        self.inner
+            .state
+            .autosave_on_exit
+            .store(enabled, Ordering::Release);
+    }
+
+    pub fn autosave_on_exit(&self) -> bool {
+        self.inner.state.autosave_on_exit.load(Ordering::Acquire)
+    }
+
+    /// Lists the filled slots of the loaded game: emergency, autosave, then
+    /// the numbered slots.
+    pub fn save_slots(&self) -> Vec<SaveSlotInfo> {
+        let Some(rom_hash) = *self.inner.state.rom_hash.lock() else {
+            return Vec::new();
+        };
+        let Some(dir) = self.save_state_dir() else {
+            return Vec::new();
+        };
+        [StateSlot::Emergency, StateSlot::Autosave]
+            .into_iter()
+            .chain((0..SAVE_STATE_SLOTS).map(StateSlot::Numbered))
+            .filter_map(|slot| SlotPaths::new(&dir, &rom_hash, slot).info(slot))
+            .collect()
+    }
+
     pub fn set_rewind_config(&self, enabled: bool, capacity: u64) {
         self.inner
             .state
@@ -566,11 +1072,34 @@ impl RuntimeHandle {
             .store(capacity, Ordering::Release);
     }
 
+    /// Captures a rewind snapshot every `frames` frames (clamped to
+    /// `1..=60`). The history set by [`set_rewind_config`](Self::set_rewind_config)
+    /// then spans `capacity * frames` frames, and rewinding steps back one
+    /// snapshot every `frames` frames. Netplay always captures every frame.
+    pub fn set_rewind_interval(&self, frames: u8) {
+        self.inner
+            .state
+            .rewind_interval
+            .store(frames.clamp(1, REWIND_INTERVAL_MAX), Ordering::Release);
+    }
+
+    pub fn rewind_interval(&self) -> u8 {
+        self.inner.state.rewind_interval.load(Ordering::Acquire)
+    }
+
     pub fn set_rewinding(&self, rewinding: bool) -> Result<(), RuntimeError> {
         self.send_with_reply("set_rewinding", CONTROL_REPLY_TIMEOUT, |reply| {
             ControlMessage::SetRewinding(rewinding, reply)
         })
     }
+
+    /// Plays the rewind history backwards for as long as `hold` is set, at
+    /// the [rewind speed](Self::set_rewind_speed). Releasing resumes
+    /// emulation from the frame reached; any regular input also stops it.
+    pub fn rewind(&self, hold: bool) -> Result<(), RuntimeError> {
+        self.set_rewinding(hold)
+    }
+
     pub fn set_fast_forwarding(&self, fast_forwarding: bool) -> Result<(), RuntimeError> {
         self.send_with_reply("set_fast_forwarding", CONTROL_REPLY_TIMEOUT, |reply| {
             ControlMessage::SetFastForwarding(fast_forwarding, reply)
@@ -591,12 +1120,160 @@ impl RuntimeHandle {
         })
     }
 
+    /// Presents one frame, then drops `frames` frames, while fast-forwarding,
+    /// so high speeds are not held back by video output. Clamped to 9;
+    /// 0 presents every frame.
+    pub fn set_fast_forward_frame_skip(&self, frames: u8) -> Result<(), RuntimeError> {
+        let clamped = frames.min(FAST_FORWARD_FRAME_SKIP_MAX);
+        self.send_with_reply(
+            "set_fast_forward_frame_skip",
+            CONTROL_REPLY_TIMEOUT,
+            |reply| ControlMessage::SetFastForwardFrameSkip(clamped, reply),
+        )
+    }
+
+    /// Runs at the slow-motion speed while enabled. Fast-forward takes
+    /// precedence when both are held.
+    pub fn set_slow_motion(&self, slow_motion: bool) -> Result<(), RuntimeError> {
+        self.send_with_reply("set_slow_motion", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SetSlowMotion(slow_motion, reply)
+        })
+    }
+
+    pub fn set_slow_motion_speed(&self, speed_percent: u16) -> Result<(), RuntimeError> {
+        let clamped = speed_percent.clamp(SPEED_PERCENT_MIN, 100);
+        self.send_with_reply("set_slow_motion_speed", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SetSlowMotionSpeed(clamped, reply)
+        })
+    }
+
+    /// Sets the normal emulation speed as a multiple of real time (`1.0`),
+    /// clamped to `0.1..=10.0`. Fast-forward and slow motion override it
+    /// while active.
+    ///
+    /// Audio is time-stretched by the bus resampler to keep up, so its pitch
+    /// follows the speed; above normal speed it is also attenuated when
+    /// [`AudioBusConfig::reduce_in_fast_forward`] is set.
+    pub fn set_speed(&self, speed: f32) -> Result<(), RuntimeError> {
+        let percent = if speed.is_finite() {
+            (speed * 100.0)
+                .round()
+                .clamp(SPEED_PERCENT_MIN.into(), SPEED_PERCENT_MAX.into()) as u16
+        } else {
+            100
+        };
+        self.send_with_reply("set_speed", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::SetSpeed(percent, reply)
+        })
+    }
+
+    /// Enables dynamic rate control (on by default): the audio resampler
+    /// ratio is nudged by up to 0.5% to keep the output queue half full, so
+    /// frames can stay locked to a display that isn't exactly 60.1 Hz
+    /// without audio drifting into underruns or overflows.
+    pub fn set_dynamic_rate_control(&self, enabled: bool) {
+        self.inner
+            .state
+            .dynamic_rate_control
+            .store(enabled, Ordering::Release);
+    }
+
+    pub fn dynamic_rate_control(&self) -> bool {
+        self.inner
+            .state
+            .dynamic_rate_control
+            .load(Ordering::Acquire)
+    }
+
+    /// Samples live controller input again when the PPU reaches `scanline`
+    /// (clamped to `0..=260`), instead of only at frame start. Most games
+    /// read the pads in their NMI handler, so 241 (the first vblank line)
+    /// cuts input latency by most of a frame. `None` (the default) samples
+    /// once per frame.
+    ///
+    /// Only live input is affected; netplay, TAS playback and movie
+    /// recording keep per-frame input so they stay deterministic.
+    pub fn set_input_poll_scanline(&self, scanline: Option<u16>) {
+        let value = scanline.map_or(INPUT_POLL_FRAME_START, |scanline| {
+            scanline.min(INPUT_POLL_SCANLINE_MAX)
+        });
+        self.inner
+            .state
+            .input_poll_scanline
+            .store(value, Ordering::Release);
+    }
+
+    pub fn input_poll_scanline(&self) -> Option<u16> {
+        match self.inner.state.input_poll_scanline.load(Ordering::Acquire) {
+            INPUT_POLL_FRAME_START => None,
+            scanline => Some(scanline),
+        }
+    }
+
     pub fn load_movie(&self, movie: nesium_support::tas::Movie) -> Result<(), RuntimeError> {
         self.send_with_reply("load_movie", CONTROL_REPLY_TIMEOUT, |reply| {
             ControlMessage::LoadMovie(movie, reply)
         })
     }
 
+    /// Power cycles the game and records its input, together with a state
+    /// hash every `interval` frames (at least 1), as a baseline for
+    /// [`start_replay_check`](Self::start_replay_check). Deterministic mode
+    /// is forced on until the recording is finished.
+    pub fn start_replay_recording(&self, interval: u32) -> Result<(), RuntimeError> {
+        self.send_with_reply("start_replay_recording", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::StartReplayRecording(interval, reply)
+        })
+    }
+
+    pub fn finish_replay_recording(&self) -> Result<ReplayBaseline, RuntimeError> {
+        let (reply_tx, reply_rx) = bounded::<Result<ReplayBaseline, RuntimeError>>(1);
+        self.inner
+            .ctrl_tx
+            .send(ControlMessage::FinishReplayRecording(reply_tx))
+            .map_err(|_| RuntimeError::ControlChannelDisconnected)?;
+        match reply_rx.recv_timeout(CONTROL_REPLY_TIMEOUT) {
+            Ok(res) => res,
+            Err(crossbeam_channel::RecvTimeoutError::Timeout) => {
+                Err(RuntimeError::ControlTimeout {
+                    op: "finish_replay_recording",
+                })
+            }
+            Err(crossbeam_channel::RecvTimeoutError::Disconnected) => {
+                Err(RuntimeError::ControlChannelDisconnected)
+            }
+        }
+    }
+
+    /// Replays `baseline` from power-on and compares the state hashes as it
+    /// goes. The result arrives as `NotificationEvent::ReplayCheckFinished`,
+    /// at the first divergent checkpoint or the end of the movie. The
+    /// settings that affect emulation (overclock, RAM init profile, ...) must
+    /// match the recording for the check to pass.
+    pub fn start_replay_check(&self, baseline: ReplayBaseline) -> Result<(), RuntimeError> {
+        let baseline = Box::new(baseline);
+        self.send_with_reply("start_replay_check", CONTROL_REPLY_TIMEOUT, |reply| {
+            ControlMessage::StartReplayCheck(baseline, reply)
+        })
+    }
+
+    /// Returns up to `count` movie inputs starting at the frame that will be played next.
+    ///
+    /// The cursor is updated by the runtime thread after each emulated frame, so polling
+    /// this once per presented frame keeps an input overlay in lockstep with playback.
+    /// Returns `None` when no movie is playing.
+    pub fn upcoming_movie_inputs(&self, count: usize) -> Option<MovieInputWindow> {
+        let playback = self.inner.state.movie_playback.lock();
+        let playback = playback.as_ref()?;
+        let start = playback.cursor.min(playback.frames.len());
+        let end = start.saturating_add(count).min(playback.frames.len());
+        Some(MovieInputWindow {
+            cursor: playback.cursor,
+            total_frames: playback.frames.len(),
+            frames: playback.frames[start..end].to_vec(),
+        })
+    }
+
     /// Enables the debugger with the given debug channels.
     ///
     /// Returns the receiver for debug events that the UI should monitor.
@@ -658,6 +1335,9 @@ impl RuntimeHandle {
         })
     }
 
+    /// Raises or restores the priority of this runtime's thread. The setting
+    /// is process-wide for runtimes started later; on desktop platforms other
+    /// running instances keep their current priority until told as well.
     pub fn set_high_priority_enabled(&self, enabled: bool) -> Result<(), RuntimeError> {
         self.send_with_reply(
             "set_high_priority_enabled",
@@ -666,3 +1346,11 @@ impl RuntimeHandle {
         )
     }
 }
+
+fn numbered_slot(slot: u8) -> Result<StateSlot, RuntimeError> {
+    if slot < SAVE_STATE_SLOTS {
+        Ok(StateSlot::Numbered(slot))
+    } else {
+        Err(RuntimeError::InvalidSaveSlot { slot })
+    }
+}
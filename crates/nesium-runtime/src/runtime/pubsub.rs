@@ -1,9 +1,16 @@
 use std::collections::HashMap;
 
-use crate::runtime::types::{Event, EventTopic, RuntimeEventSender};
+use crate::runtime::types::{
+    Event, EventFilter, EventTopic, NotificationEvent, RuntimeEventSender,
+};
+
+struct Subscriber {
+    sender: Box<dyn RuntimeEventSender>,
+    filter: Option<EventFilter>,
+}
 
 pub struct RuntimePubSub {
-    subscribers: HashMap<EventTopic, Box<dyn RuntimeEventSender>>,
+    subscribers: HashMap<EventTopic, Subscriber>,
 }
 
 impl RuntimePubSub {
@@ -13,8 +20,14 @@ impl RuntimePubSub {
         }
     }
 
-    pub fn subscribe(&mut self, topic: EventTopic, sender: Box<dyn RuntimeEventSender>) {
-        self.subscribers.insert(topic, sender);
+    pub fn subscribe(
+        &mut self,
+        topic: EventTopic,
+        sender: Box<dyn RuntimeEventSender>,
+        filter: Option<EventFilter>,
+    ) {
+        self.subscribers
+            .insert(topic, Subscriber { sender, filter });
     }
 
     pub fn unsubscribe(&mut self, topic: EventTopic) {
@@ -27,11 +40,30 @@ impl RuntimePubSub {
 
     pub fn broadcast(&mut self, topic: EventTopic, event: Box<dyn Event>) {
         if let Some(subscriber) = self.subscribers.get(&topic) {
-            let active = subscriber.send(event);
+            if subscriber
+                .filter
+                .as_ref()
+                .is_some_and(|filter| !filter(event.as_ref()))
+            {
+                return;
+            }
+            let active = subscriber.sender.send(event);
             if !active {
                 // Remove the disconnected subscriber
                 self.subscribers.remove(&topic);
             }
         }
     }
+
+    /// Publishes a notification on its own topic, or on
+    /// [`EventTopic::Notification`] when that topic has no subscriber.
+    pub fn notify(&mut self, event: NotificationEvent) {
+        let topic = event.topic();
+        let topic = if self.has_subscriber(topic) {
+            topic
+        } else {
+            EventTopic::Notification
+        };
+        self.broadcast(topic, Box::new(event));
+    }
 }
@@ -0,0 +1,82 @@
+//! Host side of the Rainbow (mapper 682) WiFi module.
+//!
+//! The game sends and acknowledges messages through the ESP registers at
+//! `$4190-$4194` (see `nesium_core::cartridge::mapper::Mapper682`). They are
+//! relayed over a TCP socket as they appear in FPGA RAM: one length byte
+//! followed by that many bytes. Whatever listens on the other end plays the
+//! ESP firmware.
+
+use std::{
+    io::{self, ErrorKind, Read, Write},
+    net::TcpStream,
+};
+
+use nesium_core::Nes;
+
+pub(crate) struct RainbowBridge {
+    stream: TcpStream,
+    /// Bytes received but not yet forming a complete message.
+    read_buf: Vec<u8>,
+    /// Framed messages the socket could not take yet.
+    write_buf: Vec<u8>,
+}
+
+impl RainbowBridge {
+    pub(crate) fn connect(addr: &str) -> io::Result<Self> {
+        let stream = TcpStream::connect(addr)?;
+        stream.set_nonblocking(true)?;
+        stream.set_nodelay(true)?;
+        Ok(Self {
+            stream,
+            read_buf: Vec::new(),
+            write_buf: Vec::new(),
+        })
+    }
+
+    /// Forwards messages the cartridge sent since the last call and delivers
+    /// any complete replies. Never blocks.
+    pub(crate) fn pump(&mut self, nes: &mut Nes) -> io::Result<()> {
+        for message in nes.take_rainbow_messages() {
+            self.write_buf.push(message.len() as u8);
+            self.write_buf.extend_from_slice(&message);
+        }
+        self.flush()?;
+
+        let mut chunk = [0u8; 1024];
+        loop {
+            match self.stream.read(&mut chunk) {
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(n) => self.read_buf.extend_from_slice(&chunk[..n]),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        while let Some(&len) = self.read_buf.first() {
+            let end = 1 + len as usize;
+            if self.read_buf.len() < end {
+                break;
+            }
+            let message = self.read_buf[1..end].to_vec();
+            self.read_buf.drain(..end);
+            nes.push_rainbow_message(message);
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        while !self.write_buf.is_empty() {
+            match self.stream.write(&self.write_buf) {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(n) => {
+                    self.write_buf.drain(..n);
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+//! Lossless video recording: a Y4M video stream paired with a WAV file.
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use nesium_core::{
+    config::region::Region,
+    ppu::{SCREEN_HEIGHT, SCREEN_WIDTH},
+};
+
+/// NTSC frame rate as an exact fraction: the CPU clock (39375000/22 Hz)
+/// over 29780.5 CPU cycles per frame.
+const FRAME_RATE_NTSC: &str = "F39375000:655171";
+/// PAL and Dendy frame rate: the PPU clock (26601712.5/5 Hz) over
+/// 341x312 dots per frame.
+const FRAME_RATE_PAL: &str = "F322445:6448";
+/// NES pixels are 8:7 on an NTSC display.
+const PIXEL_ASPECT: &str = "A8:7";
+
+/// Streams raw 256x240 frames as 4:4:4 full-range YCbCr, so no chroma is
+/// subsampled away.
+///
+/// Like the core audio recorder, write errors are latched: the first one
+/// stops further writes and is returned by [`Y4mWriter::finish`].
+pub(crate) struct Y4mWriter {
+    out: BufWriter<File>,
+    planes: Vec<u8>,
+    error: Option<io::Error>,
+}
+
+impl Y4mWriter {
+    /// Creates the file and writes the stream header, timed for `region`.
+    pub(crate) fn create(path: &Path, region: Region) -> io::Result<Self> {
+        let frame_rate = match region {
+            Region::Pal | Region::Dendy => FRAME_RATE_PAL,
+            Region::Auto | Region::Ntsc | Region::NtscJp => FRAME_RATE_NTSC,
+        };
+        let mut out = BufWriter::new(File::create(path)?);
+        writeln!(
+            out,
+            "YUV4MPEG2 W{SCREEN_WIDTH} H{SCREEN_HEIGHT} {frame_rate} Ip {PIXEL_ASPECT} C444 XCOLORRANGE=FULL"
+        )?;
+        Ok(Self {
+            out,
+            planes: vec![0; SCREEN_WIDTH * SCREEN_HEIGHT * 3],
+            error: None,
+        })
+    }
+
+    /// Appends one frame of packed RGB888 pixels.
+    pub(crate) fn write_frame(&mut self, rgb: &[u8]) {
+        if self.error.is_some() {
+            return;
+        }
+        let pixels = SCREEN_WIDTH * SCREEN_HEIGHT;
+        let (y_plane, chroma) = self.planes.split_at_mut(pixels);
+        let (cb_plane, cr_plane) = chroma.split_at_mut(pixels);
+        for (i, pixel) in rgb.chunks_exact(3).take(pixels).enumerate() {
+            let [y, cb, cr] = rgb_to_ycbcr(pixel[0], pixel[1], pixel[2]);
+            y_plane[i] = y;
+            cb_plane[i] = cb;
+            cr_plane[i] = cr;
+        }
+        let result = self
+            .out
+            .write_all(b"FRAME\n")
+            .and_then(|()| self.out.write_all(&self.planes));
+        if let Err(err) = result {
+            self.error = Some(err);
+        }
+    }
+
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        self.out.flush()
+    }
+}
+
+/// BT.601 full-range conversion in 8.8 fixed point.
+fn rgb_to_ycbcr(r: u8, g: u8, b: u8) -> [u8; 3] {
+    let (r, g, b) = (i32::from(r), i32::from(g), i32::from(b));
+    let y = (77 * r + 150 * g + 29 * b + 128) >> 8;
+    let cb = ((-43 * r - 85 * g + 128 * b + 128) >> 8) + 128;
+    let cr = ((128 * r - 107 * g - 21 * b + 128) >> 8) + 128;
+    [y, cb, cr].map(|v| v.clamp(0, 255) as u8)
+}
@@ -0,0 +1,316 @@
+//! A/B replay checking: record an input movie together with periodic state
+//! hashes, then replay it (on another build, or with different settings) and
+//! report the first checkpoint whose state differs.
+
+use std::path::Path;
+
+use nesium_core::{
+    config::ram_init::RamInit,
+    movie::{InputMovie, MovieFrame},
+    reset_kind::ResetKind,
+};
+
+const MAGIC: [u8; 4] = *b"NSRB";
+/// Version written by this build.
+const FORMAT_VERSION: u16 = 1;
+
+/// An input movie and the state hashes it produced when recorded, from
+/// `RuntimeHandle::finish_replay_recording`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayBaseline {
+    pub movie: InputMovie,
+    /// Frames between recorded hashes.
+    pub interval: u32,
+    /// `hashes[i]` is `Nes::state_hash` after frame `(i + 1) * interval`.
+    pub hashes: Vec<u64>,
+}
+
+/// Errors raised when reading a [`ReplayBaseline`] file.
+#[derive(Debug, thiserror::Error)]
+pub enum ReplayBaselineError {
+    #[error("not a replay baseline file")]
+    BadMagic,
+    #[error("unsupported replay baseline version {0}")]
+    UnsupportedVersion(u16),
+    #[error("replay baseline data is truncated")]
+    Truncated,
+    #[error("invalid replay baseline: {0}")]
+    Invalid(&'static str),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+impl ReplayBaseline {
+    /// Encodes the baseline in the versioned file format: the `NSRB` magic, a
+    /// format version, then little-endian fields.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let frames = &self.movie.frames;
+        let mut out = Vec::with_capacity(32 + frames.len() * 5 + self.hashes.len() * 8);
+        out.extend_from_slice(&MAGIC);
+        out.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        out.extend_from_slice(&self.interval.to_le_bytes());
+        match self.movie.ram_init {
+            RamInit::Zeros => out.push(0),
+            RamInit::Ones => out.push(1),
+            RamInit::Stripes => out.push(2),
+            RamInit::Random { seed } => {
+                out.push(3);
+                out.extend_from_slice(&seed.to_le_bytes());
+            }
+        }
+        out.extend_from_slice(&(frames.len() as u32).to_le_bytes());
+        for frame in frames {
+            out.push(match frame.command {
+                None => 0,
+                Some(ResetKind::PowerOn) => 1,
+                Some(ResetKind::Soft) => 2,
+            });
+            out.extend_from_slice(&frame.pads);
+        }
+        out.extend_from_slice(&(self.hashes.len() as u32).to_le_bytes());
+        for hash in &self.hashes {
+            out.extend_from_slice(&hash.to_le_bytes());
+        }
+        out
+    }
+
+    /// Decodes data written by [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ReplayBaselineError> {
+        let mut reader = Reader(bytes);
+        if reader.take::<4>()? != MAGIC {
+            return Err(ReplayBaselineError::BadMagic);
+        }
+        let version = u16::from_le_bytes(reader.take()?);
+        if version != FORMAT_VERSION {
+            return Err(ReplayBaselineError::UnsupportedVersion(version));
+        }
+        let interval = u32::from_le_bytes(reader.take()?);
+        if interval == 0 {
+            return Err(ReplayBaselineError::Invalid("hash interval is zero"));
+        }
+        let ram_init = match reader.take::<1>()?[0] {
+            0 => RamInit::Zeros,
+            1 => RamInit::Ones,
+            2 => RamInit::Stripes,
+            3 => RamInit::Random {
+                seed: u64::from_le_bytes(reader.take()?),
+            },
+            _ => return Err(ReplayBaselineError::Invalid("unknown RAM init pattern")),
+        };
+        let frame_count = u32::from_le_bytes(reader.take()?) as usize;
+        let mut frames = Vec::with_capacity(frame_count.min(reader.0.len() / 5));
+        for _ in 0..frame_count {
+            let command = match reader.take::<1>()?[0] {
+                0 => None,
+                1 => Some(ResetKind::PowerOn),
+                2 => Some(ResetKind::Soft),
+                _ => return Err(ReplayBaselineError::Invalid("unknown reset command")),
+            };
+            frames.push(MovieFrame {
+                command,
+                pads: reader.take()?,
+            });
+        }
+        let hash_count = u32::from_le_bytes(reader.take()?) as usize;
+        let mut hashes = Vec::with_capacity(hash_count.min(reader.0.len() / 8));
+        for _ in 0..hash_count {
+            hashes.push(u64::from_le_bytes(reader.take()?));
+        }
+        if !reader.0.is_empty() {
+            return Err(ReplayBaselineError::Invalid("trailing data"));
+        }
+        Ok(Self {
+            movie: InputMovie { ram_init, frames },
+            interval,
+            hashes,
+        })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ReplayBaselineError> {
+        Ok(std::fs::write(path, self.to_bytes())?)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ReplayBaselineError> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+}
+
+/// Cursor over the bytes of a baseline file.
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], ReplayBaselineError> {
+        let (head, rest) = self
+            .0
+            .split_first_chunk::<N>()
+            .ok_or(ReplayBaselineError::Truncated)?;
+        self.0 = rest;
+        Ok(*head)
+    }
+}
+
+/// Result of `RuntimeHandle::start_replay_check`, sent as
+/// `NotificationEvent::ReplayCheckFinished`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayOutcome {
+    /// Every checkpoint of the `frames` replayed frames matched.
+    Matched { frames: usize },
+    /// The state after `frame` differs. With an interval above 1 the first
+    /// divergent frame lies in `last_match + 1..=frame`.
+    Diverged {
+        frame: usize,
+        last_match: usize,
+        expected: u64,
+        actual: u64,
+    },
+}
+
+/// Replay check in progress on the runtime thread.
+pub(crate) enum ReplayCheck {
+    Recording { interval: u32, hashes: Vec<u64> },
+    Verifying { baseline: ReplayBaseline },
+}
+
+impl ReplayCheck {
+    /// Called after each emulated frame with the number of movie frames run
+    /// so far. `state_hash` is only evaluated at checkpoints. Returns the
+    /// outcome once verification is done.
+    pub(crate) fn after_frame(
+        &mut self,
+        frame: usize,
+        state_hash: impl FnOnce() -> u64,
+    ) -> Option<ReplayOutcome> {
+        match self {
+            ReplayCheck::Recording { interval, hashes } => {
+                if frame > 0 && frame.is_multiple_of(*interval as usize) {
+                    hashes.push(state_hash());
+                }
+                None
+            }
+            ReplayCheck::Verifying { baseline } => {
+                let interval = baseline.interval as usize;
+                let checkpoint = (frame > 0 && frame.is_multiple_of(interval))
+                    .then(|| baseline.hashes.get(frame / interval - 1))
+                    .flatten();
+                if let Some(&expected) = checkpoint {
+                    let actual = state_hash();
+                    if actual != expected {
+                        return Some(ReplayOutcome::Diverged {
+                            frame,
+                            last_match: frame - interval,
+                            expected,
+                            actual,
+                        });
+                    }
+                }
+                (frame >= baseline.movie.len()).then_some(ReplayOutcome::Matched { frames: frame })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn baseline(frames: usize, interval: u32, hashes: Vec<u64>) -> ReplayBaseline {
+        ReplayBaseline {
+            movie: InputMovie {
+                ram_init: RamInit::Random { seed: 7 },
+                frames: (0..frames)
+                    .map(|i| MovieFrame {
+                        command: (i == 2).then_some(ResetKind::Soft),
+                        pads: [i as u8, 0, 0, 0],
+                    })
+                    .collect(),
+            },
+            interval,
+            hashes,
+        }
+    }
+
+    /// Runs `check` over `frames` frames with `hash(frame)` as the state
+    /// hash, returning the first outcome.
+    fn run(
+        check: &mut ReplayCheck,
+        frames: usize,
+        hash: impl Fn(usize) -> u64,
+    ) -> Option<ReplayOutcome> {
+        (1..=frames).find_map(|frame| check.after_frame(frame, || hash(frame)))
+    }
+
+    #[test]
+    fn recording_hashes_every_interval() {
+        let mut check = ReplayCheck::Recording {
+            interval: 3,
+            hashes: Vec::new(),
+        };
+        assert_eq!(run(&mut check, 10, |frame| frame as u64 * 10), None);
+        let ReplayCheck::Recording { hashes, .. } = check else {
+            unreachable!();
+        };
+        assert_eq!(hashes, [30, 60, 90]);
+    }
+
+    #[test]
+    fn matching_replay_reports_every_frame() {
+        let mut check = ReplayCheck::Verifying {
+            baseline: baseline(6, 2, vec![20, 40, 60]),
+        };
+        assert_eq!(
+            run(&mut check, 10, |frame| frame as u64 * 10),
+            Some(ReplayOutcome::Matched { frames: 6 })
+        );
+    }
+
+    #[test]
+    fn divergence_is_reported_at_the_first_bad_checkpoint() {
+        let mut check = ReplayCheck::Verifying {
+            baseline: baseline(8, 2, vec![20, 40, 60, 80]),
+        };
+        let hash = |frame: usize| if frame >= 5 { 1 } else { frame as u64 * 10 };
+        assert_eq!(
+            run(&mut check, 8, hash),
+            Some(ReplayOutcome::Diverged {
+                frame: 6,
+                last_match: 4,
+                expected: 60,
+                actual: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn movie_shorter_than_the_baseline_stops_at_its_end() {
+        // Hashes past the end of the movie are never checked.
+        let mut check = ReplayCheck::Verifying {
+            baseline: baseline(3, 2, vec![20, 40, 60]),
+        };
+        assert_eq!(
+            run(&mut check, 10, |frame| frame as u64 * 10),
+            Some(ReplayOutcome::Matched { frames: 3 })
+        );
+    }
+
+    #[test]
+    fn baseline_round_trips_through_the_file_format() {
+        let original = baseline(5, 2, vec![u64::MAX, 1]);
+        let bytes = original.to_bytes();
+        assert_eq!(ReplayBaseline::from_bytes(&bytes).expect("decode"), original);
+
+        assert!(matches!(
+            ReplayBaseline::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(ReplayBaselineError::Truncated)
+        ));
+        let mut newer = bytes.clone();
+        newer[4..6].copy_from_slice(&(FORMAT_VERSION + 1).to_le_bytes());
+        assert!(matches!(
+            ReplayBaseline::from_bytes(&newer),
+            Err(ReplayBaselineError::UnsupportedVersion(2))
+        ));
+        assert!(matches!(
+            ReplayBaseline::from_bytes(b"NSST"),
+            Err(ReplayBaselineError::BadMagic)
+        ));
+    }
+}
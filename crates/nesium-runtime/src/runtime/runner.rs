@@ -1,10 +1,12 @@
 use nesium_core::ppu::buffer::ColorFormat;
-use nesium_netplay::NetplayInputProvider;
+use nesium_netplay::{NetplayInputProvider, SyncMode};
 use nesium_support::rewind::RewindState;
 use nesium_support::tas::{self, FrameFlags, InputFrame};
 use sha1::{Digest, Sha1};
 use std::sync::atomic::Ordering;
 use std::{
+    collections::VecDeque,
+    panic::{self, AssertUnwindSafe},
     path::PathBuf,
     sync::Arc,
     thread,
@@ -14,13 +16,23 @@ use std::{
 use super::{
     control::{ControlMessage, ControlReplySender},
     pubsub::RuntimePubSub,
-    state::RuntimeState,
+    rainbow::RainbowBridge,
+    recorder::Y4mWriter,
+    replay_check::{ReplayBaseline, ReplayCheck},
+    screenshot::{ScreenshotOptions, write_png},
+    slots::{SlotPaths, StateSlot, battery_path, render_thumbnail, write_atomic},
+    state::{
+        FAST_FORWARD_FRAME_SKIP_MAX, INPUT_POLL_FRAME_START, MULTITAP_FOUR_SCORE, MULTITAP_HORI,
+        MoviePlayback, RuntimeState, SPEED_PERCENT_MAX, SPEED_PERCENT_MIN, ZAPPER_DISCONNECTED,
+    },
+    stats::PerfStatsTracker,
     types::{
-        AudioMode, CpuDebugState, DebugState, EmulationStatus, EventTopic, NTSC_FPS_EXACT,
-        NotificationEvent, PaletteState, PpuDebugState, RuntimeError, SpriteState, TileState,
-        TileViewerLayout, TileViewerSource, TilemapState,
+        AudioMode, CpuDebugState, DebugState, EmulationStatus, EventTopic, FrameEvent, FrameStats,
+        GameProfile, NTSC_FPS_EXACT, NotificationEvent, PaletteState, PpuDebugState, RomReloadMode,
+        RuntimeError, SpriteState, TileState, TileViewerLayout, TileViewerSource, TilemapState,
     },
     util::button_bit,
+    watchdog,
 };
 use crossbeam_channel::{Receiver, RecvTimeoutError, Sender, TryRecvError};
 use lz4_flex::{compress_prepend_size, decompress_size_prepended};
@@ -28,15 +40,18 @@ use nesium_core::state::SnapshotMeta;
 use nesium_core::state::nes::NesSnapshot;
 use nesium_core::{
     Nes,
-    audio::bus::AudioBusConfig,
-    controller::Button,
+    audio::{MixerSettings, RecordSource, bus::AudioBusConfig},
+    cartridge::{LoadError, LoadProgress, LoadStage},
+    config::{overclock::Overclock, ram_init::RamInit, region::Region},
+    controller::{Button, MultitapKind, Zapper},
     ppu::buffer::{FrameBuffer, FrameReadyCallback, SCREEN_SIZE, VideoPostProcessor},
     ppu::palette::{Palette, PaletteKind},
     reset_kind::ResetKind,
+    trace::disassemble_around,
 };
 use std::ffi::c_void;
 
-use crate::audio::NesAudioPlayer;
+use crate::audio::{AudioLatencyConfig, NesAudioPlayer, crossfade_from};
 
 enum WaitOutcome {
     /// Runtime thread should exit (channel disconnected or Stop received).
@@ -58,6 +73,16 @@ const SPIN_THRESHOLD: Duration = Duration::from_micros(300);
 const SPIN_YIELD_EVERY: u32 = 512;
 // Allow frames to start slightly early to reduce the chance of missing the deadline.
 const FRAME_LEAD: Duration = Duration::from_micros(50);
+// Dynamic rate control: the resampler ratio moves at most this far from the
+// nominal one, which is well below audible pitch change.
+const RATE_CONTROL_MAX_SKEW: f64 = 0.005;
+// Weight of each frame's buffer fill reading; callbacks drain the queue in
+// bursts, so single readings are noisy.
+const RATE_CONTROL_SMOOTHING: f32 = 0.1;
+/// Frames between checks for battery RAM changes to write out.
+const BATTERY_SAVE_INTERVAL: u64 = 60;
+/// Instructions shown before a JAM in [`NotificationEvent::CpuJammed`].
+const JAM_CONTEXT_LINES: usize = 6;
 
 /// The canonical NES controller button order used by the runtime.
 ///
@@ -74,6 +99,12 @@ const BUTTONS: [Button; 8] = [
     Button::Right,
 ];
 
+/// Start-of-frame state kept for Zapper display-lag compensation.
+struct ZapperRollbackFrame {
+    snapshot: NesSnapshot,
+    pad_masks: [u8; 4],
+}
+
 pub(crate) struct Runner {
     nes: Nes,
     audio: Option<NesAudioPlayer>,
@@ -85,17 +116,65 @@ pub(crate) struct Runner {
     frame_duration: Duration,
     integer_fps_target: Option<u32>,
     turbo_prev_masks: [u8; 4],
+    /// Turbo presses applied to each live pad this frame, reused when input
+    /// is polled again mid-frame.
+    live_turbo_bits: [u8; 4],
     turbo_start_frame: [[u64; 8]; 4],
     rewind: RewindState,
     movie: Option<tas::Movie>,
     movie_frame: usize,
     netplay_input: Option<Arc<dyn NetplayInputProvider>>,
     netplay_active: bool,
+    /// Deterministic mode requested by the frontend; netplay forces it on.
+    deterministic: bool,
+    /// RAM pattern for ROMs whose [`GameProfile`] doesn't override it.
+    ram_init: RamInit,
+    zapper_history: VecDeque<ZapperRollbackFrame>,
+    zapper_prev_trigger: bool,
+    /// Start-of-frame states for netplay rollback, oldest first.
+    netplay_snapshots: VecDeque<(u64, NesSnapshot)>,
+    /// Last stereo sample handed to the audio device.
+    last_audio_sample: [f32; 2],
+    /// Blend the next frame's audio in from `last_audio_sample` (set by a
+    /// netplay rollback).
+    audio_crossfade: bool,
+    /// The current CPU jam has already been announced.
+    jam_reported: bool,
+    perf_stats: PerfStatsTracker,
+    rainbow_bridge: Option<RainbowBridge>,
+    /// Frames dropped since the last presented one (fast-forward frame skip).
+    frames_skipped: u8,
+    /// Smoothed audio queue fill, relative to the latency target.
+    rate_control_fill: f32,
+    /// Dynamic rate control factor applied on top of the speed scale.
+    rate_adjust: f64,
+    /// Frames left until the next rewind snapshot is captured.
+    rewind_capture_countdown: u8,
+    /// Frames the current rewind snapshot stays on screen before stepping
+    /// back to the previous one.
+    rewind_hold_frames: u8,
+    /// State replaced by the last slot or file load.
+    undo_load: Option<Vec<u8>>,
+    /// A/B replay recording or verification in progress.
+    replay_check: Option<ReplayCheck>,
+    /// File the loaded ROM was read from, for `reload_rom`.
+    rom_path: Option<PathBuf>,
+    /// Battery RAM as last written to (or read from) the save file.
+    battery_ram_saved: Option<Vec<u8>>,
+    /// Base path and video stream of the AV recording in progress; the core
+    /// writes the audio half.
+    recorder: Option<(PathBuf, Y4mWriter)>,
+    /// Screenshot requests waiting for the next presented frame.
+    pending_screenshots: Vec<(PathBuf, ScreenshotOptions, ControlReplySender)>,
 }
 
 impl Runner {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         audio_mode: AudioMode,
+        audio_latency: AudioLatencyConfig,
+        overclock: Overclock,
+        ram_init: RamInit,
         ctrl_rx: Receiver<ControlMessage>,
         ctrl_tx: Sender<ControlMessage>,
         mut pubsub: RuntimePubSub,
@@ -104,27 +183,26 @@ impl Runner {
     ) -> Self {
         let (audio, runtime_sample_rate) = match audio_mode {
             AudioMode::Disabled => (None, 48_000),
-            AudioMode::Auto => match NesAudioPlayer::new() {
+            AudioMode::Auto => match NesAudioPlayer::new(audio_latency) {
                 Ok(player) => {
                     let sr = player.sample_rate();
                     (Some(player), sr)
                 }
                 Err(e) => {
-                    pubsub.broadcast(
-                        EventTopic::Notification,
-                        Box::new(NotificationEvent::AudioInitFailed {
-                            error: e.to_string(),
-                        }),
-                    );
+                    pubsub.notify(NotificationEvent::AudioInitFailed {
+                        error: e.to_string(),
+                    });
                     (None, 48_000)
                 }
             },
         };
 
-        let nes = Nes::builder()
+        let mut nes = Nes::builder()
             .framebuffer(framebuffer)
             .sample_rate(runtime_sample_rate)
+            .ram_init(ram_init)
             .build();
+        nes.set_overclock(overclock);
 
         Self {
             nes,
@@ -137,12 +215,34 @@ impl Runner {
             frame_duration: FRAME_DURATION_NTSC,
             integer_fps_target: None,
             turbo_prev_masks: [0; 4],
+            live_turbo_bits: [0; 4],
             turbo_start_frame: [[0; 8]; 4],
             rewind: RewindState::new(),
             movie: None,
             movie_frame: 0,
             netplay_input: None,
+            deterministic: false,
+            ram_init,
             netplay_active: false,
+            zapper_history: VecDeque::new(),
+            zapper_prev_trigger: false,
+            netplay_snapshots: VecDeque::new(),
+            last_audio_sample: [0.0; 2],
+            audio_crossfade: false,
+            jam_reported: false,
+            perf_stats: PerfStatsTracker::new(),
+            rainbow_bridge: None,
+            frames_skipped: 0,
+            rate_control_fill: 0.5,
+            rate_adjust: 1.0,
+            rewind_capture_countdown: 0,
+            rewind_hold_frames: 0,
+            undo_load: None,
+            replay_check: None,
+            rom_path: None,
+            battery_ram_saved: None,
+            recorder: None,
+            pending_screenshots: Vec::new(),
         }
     }
 
@@ -150,12 +250,15 @@ impl Runner {
         let mut last_paused = self.state.paused.load(Ordering::Acquire);
         let mut last_rewinding = self.state.rewinding.load(Ordering::Acquire);
         let mut last_fast_forwarding = self.state.fast_forwarding.load(Ordering::Acquire);
-        let mut last_fast_forward_speed = self
-            .state
-            .fast_forward_speed_percent
-            .load(Ordering::Acquire);
+        let mut last_slow_motion = self.state.slow_motion.load(Ordering::Acquire);
+        let mut last_speed = self.speed_percent();
+        self.apply_audio_speed(last_speed);
 
         loop {
+            if let Some(signal) = watchdog::termination_requested() {
+                self.emergency_save(&format!("terminated by signal {signal}"));
+                return;
+            }
             while let Ok(msg) = self.ctrl_rx.try_recv() {
                 if self.handle_control(msg) {
                     return;
@@ -165,22 +268,24 @@ impl Runner {
             let paused = self.state.paused.load(Ordering::Acquire);
             let rewinding = self.state.rewinding.load(Ordering::Acquire);
             let fast_forwarding = self.state.fast_forwarding.load(Ordering::Acquire);
-            let fast_forward_speed = self
-                .state
-                .fast_forward_speed_percent
-                .load(Ordering::Acquire);
+            let slow_motion = self.state.slow_motion.load(Ordering::Acquire);
+            let speed = self.speed_percent();
 
             if (paused != last_paused && !paused)
                 || (rewinding != last_rewinding)
                 || (fast_forwarding != last_fast_forwarding)
-                || (fast_forward_speed != last_fast_forward_speed)
+                || (speed != last_speed)
             {
                 self.next_frame_deadline = Instant::now();
             }
+            if speed != last_speed {
+                self.apply_audio_speed(speed);
+            }
 
             if paused != last_paused
                 || rewinding != last_rewinding
                 || fast_forwarding != last_fast_forwarding
+                || slow_motion != last_slow_motion
             {
                 self.pubsub.broadcast(
                     EventTopic::EmulationStatus,
@@ -188,6 +293,7 @@ impl Runner {
                         paused,
                         rewinding,
                         fast_forwarding,
+                        slow_motion,
                     }),
                 );
             }
@@ -195,7 +301,8 @@ impl Runner {
             last_paused = paused;
             last_rewinding = rewinding;
             last_fast_forwarding = fast_forwarding;
-            last_fast_forward_speed = fast_forward_speed;
+            last_slow_motion = slow_motion;
+            last_speed = speed;
 
             if self.nes.get_cartridge().is_none() || (paused && !rewinding) {
                 match self.ctrl_rx.recv_timeout(Duration::from_millis(10)) {
@@ -218,7 +325,23 @@ impl Runner {
 
             // Run exactly one frame per iteration to avoid jitter from catch-up frames.
             if rewinding || !paused {
+                let frame_start = Instant::now();
+                let cycles_before = self.nes.cpu_cycles();
                 self.step_frame();
+                let host_time = frame_start.elapsed();
+                let cpu_cycles = self.nes.cpu_cycles().saturating_sub(cycles_before);
+                self.perf_stats.record_frame(host_time, cpu_cycles);
+                if self.pubsub.has_subscriber(EventTopic::FrameStats) {
+                    self.pubsub.broadcast(
+                        EventTopic::FrameStats,
+                        Box::new(FrameStats {
+                            frame_seq: self.state.frame_seq.load(Ordering::Relaxed),
+                            host_time,
+                            cpu_cycles,
+                            presented: !self.nes.frame_skip(),
+                        }),
+                    );
+                }
                 self.next_frame_deadline += self.current_frame_duration();
             }
 
@@ -234,8 +357,14 @@ impl Runner {
             }
 
             if now > self.next_frame_deadline && !allow_catchup {
+                let behind = (now - self.next_frame_deadline).as_nanos();
+                let frame = self.current_frame_duration().as_nanos().max(1);
+                self.perf_stats
+                    .record_dropped((behind / frame).min(u32::MAX as u128) as u32);
                 self.next_frame_deadline = now;
             }
+
+            self.maybe_broadcast_perf_stats(now);
         }
     }
 
@@ -305,37 +434,122 @@ impl Runner {
     }
 
     fn current_frame_duration(&self) -> Duration {
-        let is_fast_forwarding = self.state.fast_forwarding.load(Ordering::Acquire);
-        let is_rewinding = self.state.rewinding.load(Ordering::Acquire);
-
-        if !is_fast_forwarding && !is_rewinding {
+        let speed = self.speed_percent();
+        if speed == 100 {
             return self.frame_duration;
         }
 
-        let speed = if is_fast_forwarding {
-            self.state
-                .fast_forward_speed_percent
-                .load(Ordering::Acquire)
+        let base = self.frame_duration.as_nanos();
+        let scaled = (base.saturating_mul(100) / u128::from(speed)).max(1);
+        Duration::from_nanos(scaled.min(u128::from(u64::MAX)) as u64)
+    }
+
+    /// Pacing speed in percent of normal speed. Fast-forward wins over
+    /// rewind, which wins over slow motion, which wins over the base speed.
+    fn speed_percent(&self) -> u16 {
+        let state = &self.state;
+        let speed = if state.fast_forwarding.load(Ordering::Acquire) {
+            state.fast_forward_speed_percent.load(Ordering::Acquire)
+        } else if state.rewinding.load(Ordering::Acquire) {
+            state.rewind_speed_percent.load(Ordering::Acquire)
+        } else if state.slow_motion.load(Ordering::Acquire) {
+            state.slow_motion_speed_percent.load(Ordering::Acquire)
+        } else {
+            state.speed_percent.load(Ordering::Acquire)
+        };
+        speed.clamp(SPEED_PERCENT_MIN, SPEED_PERCENT_MAX)
+    }
+
+    /// Time-stretches audio by the pacing speed so the output device neither
+    /// starves nor overflows, and flags fast-forward on the audio bus for its
+    /// optional volume reduction.
+    fn apply_audio_speed(&mut self, speed_percent: u16) {
+        let fps_scale = if self.integer_fps_target == Some(60) {
+            60.0 / NTSC_FPS_EXACT
+        } else {
+            1.0
+        };
+        let rate_adjust = if speed_percent == 100 {
+            self.rate_adjust
         } else {
-            self.state.rewind_speed_percent.load(Ordering::Acquire)
+            1.0
+        };
+        if speed_percent == 100
+            && self.integer_fps_target != Some(60)
+            && (rate_adjust - 1.0).abs() <= f64::EPSILON
+        {
+            self.nes.reset_audio_integer_fps_scale();
+        } else {
+            self.nes.set_audio_integer_fps_scale(
+                fps_scale * f64::from(speed_percent) / 100.0 * rate_adjust,
+            );
         }
-        .clamp(100, 1000) as u128;
+        let mut config = self.nes.audio_bus_config();
+        config.is_fast_forward = speed_percent > 100;
+        self.nes.set_audio_bus_config(config);
+    }
 
-        let base = self.frame_duration.as_nanos();
-        let scaled = (base.saturating_mul(100) / speed).max(1);
-        Duration::from_nanos(scaled.min(u128::from(u64::MAX)) as u64)
+    /// Dynamic rate control: frames are paced by the host clock (or vsync),
+    /// which never runs at exactly the NES rate, so the audio queue slowly
+    /// drains or fills. Compressing the audio slightly when the queue is more
+    /// than half full (and stretching it when less) keeps it centred without
+    /// the periodic pops of dropped or missing samples.
+    fn update_rate_control(&mut self) {
+        let Some(audio) = &self.audio else {
+            return;
+        };
+        let speed = self.speed_percent();
+        if speed != 100 || !self.state.dynamic_rate_control.load(Ordering::Acquire) {
+            self.rate_control_fill = 0.5;
+            if (self.rate_adjust - 1.0).abs() > f64::EPSILON {
+                self.rate_adjust = 1.0;
+                self.apply_audio_speed(speed);
+            }
+            return;
+        }
+        self.rate_adjust = rate_control_step(&mut self.rate_control_fill, audio.buffer_fill());
+        self.apply_audio_speed(speed);
+    }
+
+    /// While fast-forwarding with frame skip enabled, presents one frame and
+    /// then drops the configured number of frames.
+    fn update_frame_skip(&mut self) {
+        let skip = if self.state.fast_forwarding.load(Ordering::Acquire)
+            && !self.state.paused.load(Ordering::Acquire)
+            && self.recorder.is_none()
+        {
+            self.state.fast_forward_frame_skip.load(Ordering::Acquire)
+        } else {
+            0
+        };
+        let drop = self.frames_skipped < skip;
+        self.frames_skipped = if drop { self.frames_skipped + 1 } else { 0 };
+        self.nes.set_frame_skip(drop);
     }
 
     fn handle_control(&mut self, msg: ControlMessage) -> bool {
         match msg {
-            ControlMessage::Stop => return true,
+            ControlMessage::Stop => {
+                self.close_game();
+                return true;
+            }
             ControlMessage::LoadRom(path, reply) => self.handle_load_rom(path, reply),
+            ControlMessage::ReloadRom(mode, reply) => self.handle_reload_rom(mode, reply),
             ControlMessage::LoadRomFromMemory(bytes, reply) => {
                 self.handle_load_rom_from_memory(bytes, reply)
             }
             ControlMessage::Reset(kind, reply) => self.handle_reset(kind, reply),
             ControlMessage::PowerOff(reply) => self.handle_power_off(reply),
             ControlMessage::SetAudioConfig(cfg, reply) => self.handle_set_audio_config(cfg, reply),
+            ControlMessage::SetMixerSettings(settings, reply) => {
+                self.handle_set_mixer_settings(settings, reply)
+            }
+            ControlMessage::InsertBarcode(barcode, reply) => {
+                self.handle_insert_barcode(&barcode, reply)
+            }
+            ControlMessage::SetRainbowBridge(addr, reply) => {
+                self.handle_set_rainbow_bridge(addr, reply)
+            }
             ControlMessage::SetFrameReadyCallback(cb, user_data, reply) => {
                 self.handle_set_frame_ready_callback(cb, user_data, reply)
             }
@@ -347,6 +561,15 @@ impl Runner {
                 height,
                 reply,
             } => self.handle_set_video_output_config(width, height, reply),
+            ControlMessage::StartRecording(path, reply) => {
+                let _ = reply.send(self.start_recording(path));
+            }
+            ControlMessage::StopRecording(reply) => {
+                let _ = reply.send(self.stop_recording());
+            }
+            ControlMessage::CaptureScreenshot(path, options, reply) => {
+                self.handle_capture_screenshot(path, options, reply)
+            }
             ControlMessage::SetVideoPostProcessor(processor, reply) => {
                 self.handle_set_video_post_processor(processor, reply)
             }
@@ -363,9 +586,54 @@ impl Runner {
                 self.handle_set_palette_kind(kind, reply)
             }
             ControlMessage::SetPalette(palette, reply) => self.handle_set_palette(palette, reply),
+            ControlMessage::SetClonePpu(enabled, reply) => {
+                self.handle_set_clone_ppu(enabled, reply)
+            }
+            ControlMessage::SetBackgroundLayer(enabled, reply) => {
+                self.nes.set_background_layer_enabled(enabled);
+                let _ = reply.send(Ok(()));
+            }
+            ControlMessage::SetSpriteLayer(enabled, reply) => {
+                self.nes.set_sprite_layer_enabled(enabled);
+                let _ = reply.send(Ok(()));
+            }
+            ControlMessage::SetSpriteLimitRemoved(removed, reply) => {
+                self.nes.set_sprite_limit_removed(removed);
+                let _ = reply.send(Ok(()));
+            }
+            ControlMessage::SetAccurateOam(enabled, reply) => {
+                self.nes.set_accurate_oam(enabled);
+                let _ = reply.send(Ok(()));
+            }
+            ControlMessage::SetOpenBusDecayFrames(frames, reply) => {
+                self.nes.set_open_bus_decay_frames(frames);
+                let _ = reply.send(Ok(()));
+            }
+            ControlMessage::SetOverclock(overclock, reply) => {
+                self.nes.set_overclock(overclock);
+                let _ = reply.send(Ok(()));
+            }
+            ControlMessage::SetRamInit(ram_init, reply) => {
+                self.ram_init = ram_init;
+                let rom_hash = *self.state.rom_hash.lock();
+                let profile = rom_hash.and_then(|hash| self.game_profile(&hash));
+                self.nes
+                    .set_ram_init(profile.and_then(|p| p.ram_init).unwrap_or(ram_init));
+                let _ = reply.send(Ok(()));
+            }
+            ControlMessage::SetDeterministic(enabled, reply) => {
+                self.deterministic = enabled;
+                self.restore_deterministic();
+                let _ = reply.send(Ok(()));
+            }
             ControlMessage::SaveState(path, reply) => self.handle_save_state(path, reply),
             ControlMessage::LoadState(path, reply) => self.handle_load_state(path, reply),
             ControlMessage::SaveStateToMemory(reply) => self.handle_save_state_to_memory(reply),
+            ControlMessage::SaveSlot(slot, reply) => {
+                let _ = reply.send(self.save_slot(slot));
+            }
+            ControlMessage::LoadSlot(slot, reply) => self.handle_load_slot(slot, reply),
+            ControlMessage::UndoLoadState(reply) => self.handle_undo_load_state(reply),
             ControlMessage::LoadStateFromMemory(bytes, reply) => {
                 self.handle_load_state_from_memory(bytes, reply)
             }
@@ -390,9 +658,50 @@ impl Runner {
                     .store(speed.clamp(100, 1000), Ordering::Release);
                 let _ = reply.send(Ok(()));
             }
+            ControlMessage::SetFastForwardFrameSkip(frames, reply) => {
+                self.state
+                    .fast_forward_frame_skip
+                    .store(frames.min(FAST_FORWARD_FRAME_SKIP_MAX), Ordering::Release);
+                let _ = reply.send(Ok(()));
+            }
+            ControlMessage::SetSlowMotion(slow_motion, reply) => {
+                self.state.slow_motion.store(slow_motion, Ordering::Release);
+                let _ = reply.send(Ok(()));
+            }
+            ControlMessage::SetSlowMotionSpeed(speed, reply) => {
+                self.state
+                    .slow_motion_speed_percent
+                    .store(speed.clamp(SPEED_PERCENT_MIN, 100), Ordering::Release);
+                let _ = reply.send(Ok(()));
+            }
+            ControlMessage::Pause(reply) => {
+                self.state.paused.store(true, Ordering::Release);
+                let _ = reply.send(Ok(()));
+            }
+            ControlMessage::Resume(reply) => {
+                self.state.paused.store(false, Ordering::Release);
+                let _ = reply.send(Ok(()));
+            }
+            ControlMessage::AdvanceFrame(reply) => self.handle_advance_frame(reply),
+            ControlMessage::SetSpeed(speed, reply) => {
+                self.state.speed_percent.store(
+                    speed.clamp(SPEED_PERCENT_MIN, SPEED_PERCENT_MAX),
+                    Ordering::Release,
+                );
+                let _ = reply.send(Ok(()));
+            }
             ControlMessage::LoadMovie(movie, reply) => self.handle_load_movie(movie, reply),
-            ControlMessage::SubscribeEvent(topic, sender, reply) => {
-                self.pubsub.subscribe(topic, sender);
+            ControlMessage::StartReplayRecording(interval, reply) => {
+                self.handle_start_replay_recording(interval, reply)
+            }
+            ControlMessage::FinishReplayRecording(reply) => {
+                let _ = reply.send(self.finish_replay_recording());
+            }
+            ControlMessage::StartReplayCheck(baseline, reply) => {
+                self.handle_start_replay_check(*baseline, reply)
+            }
+            ControlMessage::SubscribeEvent(topic, sender, filter, reply) => {
+                self.pubsub.subscribe(topic, sender, filter);
                 self.after_subscribe_event(topic);
                 let _ = reply.send(Ok(()));
             }
@@ -505,10 +814,12 @@ impl Runner {
                 reply,
             } => {
                 self.netplay_input = Some(input_provider);
+                self.nes.set_deterministic(true);
                 let _ = reply.send(Ok(()));
             }
             ControlMessage::DisableNetplay(reply) => {
                 self.netplay_input = None;
+                self.restore_deterministic();
                 let _ = reply.send(Ok(()));
             }
             ControlMessage::SetHighPriorityEnabled(enabled, reply) => {
@@ -556,7 +867,36 @@ impl Runner {
         }
     }
 
+    fn maybe_broadcast_perf_stats(&mut self, now: Instant) {
+        let audio_fill = self.audio.as_ref().map(NesAudioPlayer::buffer_fill);
+        let Some(stats) = self.perf_stats.poll(now, audio_fill) else {
+            return;
+        };
+        if self.pubsub.has_subscriber(EventTopic::PerformanceStats) {
+            self.pubsub
+                .broadcast(EventTopic::PerformanceStats, Box::new(stats));
+        }
+    }
+
+    /// Frames between rewind snapshots. Netplay peers rewind in lockstep, so
+    /// it always captures every frame.
+    fn rewind_interval(&self) -> u8 {
+        if self.netplay_active {
+            1
+        } else {
+            self.state.rewind_interval.load(Ordering::Acquire).max(1)
+        }
+    }
+
     fn rewind_frame(&mut self) {
+        // With a capture interval, each snapshot stands for several frames:
+        // hold it on screen for that long so rewinding plays at real speed.
+        if self.rewind_hold_frames > 0 {
+            self.rewind_hold_frames -= 1;
+            return;
+        }
+        self.rewind_hold_frames = self.rewind_interval() - 1;
+
         if let Some((snapshot, indices)) = self.rewind.rewind_frame()
             && self.nes.load_snapshot(&snapshot).is_ok()
         {
@@ -578,12 +918,16 @@ impl Runner {
 
     fn step_frame(&mut self) {
         let frame_seq = self.state.frame_seq.load(Ordering::Relaxed);
-        let movie_frame = self.movie_frame_for_seq(frame_seq);
+        let movie_frame = self.current_movie_frame();
         self.maybe_apply_movie_frame(&movie_frame);
+        self.sync_button_remaps();
 
         let (turbo_on_frames, period) = self.turbo_params();
 
         let netplay_inputs = self.netplay_inputs_for_frame(frame_seq, turbo_on_frames, period);
+        if netplay_inputs.is_some() {
+            self.maybe_resolve_netplay_rollback(frame_seq);
+        }
         let (any_input, netplay_rewind) = self.apply_inputs_for_frame(
             frame_seq,
             turbo_on_frames,
@@ -598,6 +942,7 @@ impl Runner {
             || (netplay_inputs.is_none() && self.state.rewinding.load(Ordering::Acquire));
 
         if should_rewind {
+            self.nes.set_frame_skip(false);
             self.rewind_frame();
             // Skip run_frame
         } else {
@@ -609,18 +954,71 @@ impl Runner {
                 self.state.rewinding.store(false, Ordering::Release);
             }
 
-            let samples = self.nes.run_frame(self.audio.is_some());
+            self.sync_port_devices();
+            self.maybe_compensate_zapper_lag(movie_frame.is_none() && netplay_inputs.is_none());
+            self.update_frame_skip();
+            let emit_audio = self.audio.is_some() || self.recorder.is_some();
+            let poll_scanline = self.state.input_poll_scanline.load(Ordering::Acquire);
+            let mut samples = if poll_scanline != INPUT_POLL_FRAME_START
+                && movie_frame.is_none()
+                && netplay_inputs.is_none()
+            {
+                let state = Arc::clone(&self.state);
+                let turbo = self.live_turbo_bits;
+                self.nes.run_frame_polled(emit_audio, poll_scanline, |nes| {
+                    for (pad, turbo) in turbo.into_iter().enumerate() {
+                        let base = state.pad_masks[pad].load(Ordering::Acquire);
+                        let mask = nes.button_remap(pad).apply(base | turbo);
+                        nes.set_pad_state_raw(pad, mask);
+                    }
+                })
+            } else {
+                self.nes.run_frame(emit_audio)
+            };
             if let Some(audio) = &mut self.audio
                 && !samples.is_empty()
             {
+                if std::mem::take(&mut self.audio_crossfade) {
+                    crossfade_from(&mut samples, self.last_audio_sample);
+                }
                 audio.push_samples(&samples);
+                if let [.., l, r] = samples[..] {
+                    self.last_audio_sample = [l, r];
+                }
             }
+            self.update_rate_control();
+            self.check_replay_frame();
+            if netplay_inputs.is_some() {
+                self.maybe_report_netplay_state_hash(frame_seq + 1);
+            }
+            if let Some((_, video)) = &mut self.recorder {
+                video.write_frame(&self.nes.render_frame_rgb(false).2);
+            }
+            self.check_cpu_jam();
+            self.pump_rainbow_bridge();
 
             // Capture history for future rewind
             self.maybe_capture_rewind_history();
+            if frame_seq.is_multiple_of(BATTERY_SAVE_INTERVAL) {
+                self.save_battery_ram_if_changed();
+            }
         }
 
-        self.state.frame_seq.fetch_add(1, Ordering::Relaxed);
+        let frame_seq = self.state.frame_seq.fetch_add(1, Ordering::Relaxed) + 1;
+
+        if !self.pending_screenshots.is_empty() && !self.nes.frame_skip() {
+            self.take_screenshots();
+        }
+        if self.pubsub.has_subscriber(EventTopic::Frame) && !self.nes.frame_skip() {
+            let (_, _, rgb) = self.nes.render_frame_rgb(false);
+            self.pubsub.broadcast(
+                EventTopic::Frame,
+                Box::new(FrameEvent {
+                    frame_seq,
+                    rgb: rgb.into(),
+                }),
+            );
+        }
 
         // Advance the TAS timeline AFTER the frame has been executed.
         if movie_frame.is_some() {
@@ -634,11 +1032,183 @@ impl Runner {
         self.maybe_broadcast_tilemap_and_chr_state();
     }
 
-    fn movie_frame_for_seq(&mut self, frame_seq: u64) -> Option<InputFrame> {
-        if let Some(movie) = &mut self.movie {
-            movie.frames.get(frame_seq as usize).copied()
-        } else {
-            None
+    /// Mirrors the published multitap and Zapper port/aim/trigger onto the
+    /// core before a frame.
+    fn sync_port_devices(&mut self) {
+        let multitap = match self.state.multitap.load(Ordering::Acquire) {
+            MULTITAP_FOUR_SCORE => Some(MultitapKind::FourScore),
+            MULTITAP_HORI => Some(MultitapKind::Hori),
+            _ => None,
+        };
+        self.nes.set_multitap(multitap);
+
+        let port = self.state.zapper_port.load(Ordering::Acquire);
+        for p in 0..2 {
+            let connected = port != ZAPPER_DISCONNECTED && p == port as usize;
+            match (connected, self.nes.zapper(p).is_some()) {
+                (true, false) => self.nes.set_zapper(p, Some(Zapper::new())),
+                (false, true) => self.nes.set_zapper(p, None),
+                _ => {}
+            }
+        }
+
+        let x = self.state.zapper_x.load(Ordering::Acquire);
+        let y = self.state.zapper_y.load(Ordering::Acquire);
+        let trigger = self.state.zapper_trigger.load(Ordering::Acquire);
+        let timing = *self.state.zapper_timing.lock();
+        let calibration = *self.state.zapper_calibration.lock();
+        let crosshair = self.state.zapper_crosshair.load(Ordering::Acquire);
+        if let Some(zapper) = self.nes.zapper_mut(port as usize) {
+            zapper.set_aim(x, y);
+            zapper.set_trigger(trigger);
+            zapper.set_timing(timing);
+            zapper.set_calibration(calibration);
+            zapper.set_crosshair_visible(crosshair);
+        }
+    }
+
+    /// Rolls emulation back on a Zapper trigger pull to offset display lag.
+    ///
+    /// While compensation is enabled, the start-of-frame state of the last
+    /// `lag` frames is kept. On a trigger rising edge the oldest one is
+    /// restored and those frames are re-run with the trigger (and current
+    /// aim) applied, so the game reacts to the frame the player saw.
+    fn maybe_compensate_zapper_lag(&mut self, allowed: bool) {
+        let lag = self.state.zapper_lag_frames.load(Ordering::Acquire) as usize;
+        let port = self.state.zapper_port.load(Ordering::Acquire) as usize;
+        let Some(trigger) = self.nes.zapper(port).map(|z| z.trigger()) else {
+            self.zapper_history.clear();
+            self.zapper_prev_trigger = false;
+            return;
+        };
+        let rising = trigger && !self.zapper_prev_trigger;
+        self.zapper_prev_trigger = trigger;
+
+        if !allowed || lag == 0 {
+            self.zapper_history.clear();
+            return;
+        }
+
+        let current_masks: [u8; 4] = std::array::from_fn(|pad| self.nes.controllers[pad].state());
+        if rising && self.zapper_history.len() >= lag {
+            let start = self.zapper_history.len() - lag;
+            let frames: Vec<_> = self.zapper_history.drain(start..).collect();
+            self.zapper_history.clear();
+            if self.nes.load_snapshot(&frames[0].snapshot).is_ok() {
+                // Replayed frames only rebuild state; their audio is dropped.
+                for frame in &frames {
+                    for (pad, mask) in frame.pad_masks.into_iter().enumerate() {
+                        self.nes.set_pad_state_raw(pad, mask);
+                    }
+                    self.nes.run_frame(false);
+                }
+                for (pad, mask) in current_masks.into_iter().enumerate() {
+                    self.nes.set_pad_state_raw(pad, mask);
+                }
+            }
+            return;
+        }
+
+        let meta = SnapshotMeta {
+            tick: self.nes.master_clock(),
+            ..Default::default()
+        };
+        if let Ok(snapshot) = self.nes.save_snapshot(meta) {
+            self.zapper_history.push_back(ZapperRollbackFrame {
+                snapshot,
+                pad_masks: current_masks,
+            });
+            while self.zapper_history.len() > lag {
+                self.zapper_history.pop_front();
+            }
+        }
+    }
+
+    /// Netplay rollback mode: on a misprediction, restores the nearest
+    /// snapshot and re-runs the frames up to `frame_seq` with corrected
+    /// inputs, then keeps this frame's start state for later rollbacks.
+    ///
+    /// Resimulated frames are run silently. Their audio was already played
+    /// from the predicted timeline; pushing it again would repeat it, which
+    /// is the stutter heard on every misprediction. The next live frame is
+    /// crossfaded in instead.
+    fn maybe_resolve_netplay_rollback(&mut self, frame_seq: u64) {
+        let Some(np) = self.netplay_input.clone() else {
+            return;
+        };
+        if np.sync_mode() != SyncMode::Rollback {
+            self.netplay_snapshots.clear();
+            return;
+        }
+        let config = np.rollback_config();
+        let interval = config.snapshot_interval.max(1) as u64;
+
+        // A resync moved the timeline back; those snapshots are stale.
+        while self
+            .netplay_snapshots
+            .back()
+            .is_some_and(|(f, _)| *f >= frame_seq)
+        {
+            self.netplay_snapshots.pop_back();
+        }
+
+        if let Some(rb) = np.pending_rollback() {
+            np.clear_rollback();
+            let restore = self
+                .netplay_snapshots
+                .iter()
+                .rposition(|(f, _)| *f <= rb.restore_frame as u64);
+            match restore {
+                Some(idx)
+                    if self
+                        .nes
+                        .load_snapshot(&self.netplay_snapshots[idx].1)
+                        .is_ok() =>
+                {
+                    let from = self.netplay_snapshots[idx].0;
+                    self.netplay_snapshots.truncate(idx + 1);
+                    for f in from..frame_seq {
+                        if f > from && f.is_multiple_of(interval) {
+                            self.push_netplay_snapshot(f);
+                        }
+                        if let Some(inputs) = np.poll_inputs(f as u32) {
+                            for (pad, mask) in inputs.into_iter().enumerate() {
+                                self.apply_pad_mask(pad, (mask & 0xFF) as u8);
+                            }
+                        }
+                        self.nes.run_frame(false);
+                    }
+                    self.audio_crossfade = true;
+                }
+                _ => {
+                    eprintln!(
+                        "[Netplay] No snapshot to roll back to frame {}",
+                        rb.target_frame
+                    );
+                }
+            }
+        }
+
+        if frame_seq.is_multiple_of(interval) {
+            self.push_netplay_snapshot(frame_seq);
+        }
+        let keep_from = frame_seq.saturating_sub(config.max_rollback_frames as u64 + interval);
+        while self
+            .netplay_snapshots
+            .front()
+            .is_some_and(|(f, _)| *f < keep_from)
+        {
+            self.netplay_snapshots.pop_front();
+        }
+    }
+
+    fn push_netplay_snapshot(&mut self, frame_seq: u64) {
+        let meta = SnapshotMeta {
+            tick: self.nes.master_clock(),
+            ..Default::default()
+        };
+        if let Ok(snapshot) = self.nes.save_snapshot(meta) {
+            self.netplay_snapshots.push_back((frame_seq, snapshot));
         }
     }
 
@@ -706,13 +1276,14 @@ impl Runner {
                 }
 
                 let base = self.state.pad_masks[physical_pad].load(Ordering::Acquire);
-                let mut input = self.apply_turbo_to_mask(
+                let live = self.apply_turbo_to_mask(
                     physical_pad,
                     base as u8,
                     frame_seq,
                     turbo_on_frames,
                     period,
-                ) as u16;
+                );
+                let mut input = self.nes.button_remap(physical_pad).apply(live) as u16;
 
                 // Inject Rewind bit if requested locally.
                 if self.state.rewinding.load(Ordering::Acquire) {
@@ -762,7 +1333,9 @@ impl Runner {
                 frame.ports[pad] as u16
             } else {
                 let base = self.state.pad_masks[pad].load(Ordering::Acquire);
-                self.apply_turbo_to_mask(pad, base as u8, frame_seq, turbo_on_frames, period) as u16
+                let turbo = self.apply_turbo_to_mask(pad, 0, frame_seq, turbo_on_frames, period);
+                self.live_turbo_bits[pad] = turbo;
+                self.nes.button_remap(pad).apply(base | turbo) as u16
             };
 
             if (mask & 0xFF) != 0 {
@@ -779,7 +1352,8 @@ impl Runner {
         // Periodic Netplay State Sync (Host Only)
         // Every second (60 frames), the host sends a compressed state snapshot to the server.
         // This allows late joiners (and spectators) to catch up quickly without replaying the entire history.
-        if !self.netplay_active || frame_seq == 0 || frame_seq % 60 != 0 {
+        // The server can also ask for one right away when a late joiner is ready.
+        if !self.netplay_active {
             return;
         }
 
@@ -791,6 +1365,10 @@ impl Runner {
         if np.local_player() != Some(0) {
             return;
         }
+        let requested = np.take_state_capture_request();
+        if !requested && (frame_seq == 0 || !frame_seq.is_multiple_of(60)) {
+            return;
+        }
 
         match self.capture_compressed_snapshot() {
             Ok(bytes) => {
@@ -802,11 +1380,30 @@ impl Runner {
         }
     }
 
+    /// Reports the state hash to netplay every `state_hash_interval` frames so
+    /// peers can detect a desync. `frames_run` counts the netplay frames run
+    /// so far, all of them with confirmed inputs.
+    fn maybe_report_netplay_state_hash(&self, frames_run: u64) {
+        let Some(np) = &self.netplay_input else {
+            return;
+        };
+        let interval = np.state_hash_interval() as u64;
+        if interval == 0 || !frames_run.is_multiple_of(interval) {
+            return;
+        }
+        np.report_state_hash(frames_run as u32, self.nes.state_hash());
+    }
+
     /// Captures rewind history (snapshot + render index plane) when enabled.
     fn maybe_capture_rewind_history(&mut self) {
         if !self.state.rewind_enabled.load(Ordering::Acquire) {
             return;
         }
+        if self.rewind_capture_countdown > 0 {
+            self.rewind_capture_countdown -= 1;
+            return;
+        }
+        self.rewind_capture_countdown = self.rewind_interval() - 1;
 
         let meta = SnapshotMeta {
             tick: self.nes.master_clock(),
@@ -830,8 +1427,7 @@ impl Runner {
         if self.movie_frame >= len {
             // Do not silently fall back to live input when the movie ends.
             // A finished movie is an explicit state transition.
-            self.movie = None;
-            println!("[Runner] TAS playback finished");
+            self.stop_movie();
             return None;
         }
 
@@ -900,12 +1496,19 @@ impl Runner {
         mask
     }
 
-    /// Applies a resolved button mask to a controller port.
+    /// Applies a resolved (already remapped) button mask to a controller port.
     fn apply_pad_mask(&mut self, pad: usize, mask: u8) {
-        for button in BUTTONS {
-            let bit = 1u8 << button_bit(button);
-            let pressed = (mask & bit) != 0;
-            self.nes.set_button(pad, button, pressed);
+        self.nes.set_pad_state_raw(pad, mask);
+    }
+
+    /// Applies the loaded game's profile remaps, or the global ones.
+    fn sync_button_remaps(&mut self) {
+        let rom_hash = *self.state.rom_hash.lock();
+        let remaps = rom_hash
+            .and_then(|hash| self.game_profile(&hash)?.button_remaps)
+            .unwrap_or_else(|| *self.state.button_remaps.lock());
+        for (pad, remap) in remaps.into_iter().enumerate() {
+            self.nes.set_button_remap(pad, remap);
         }
     }
 
@@ -918,12 +1521,49 @@ impl Runner {
         let len = movie.frames.len();
         self.movie_frame = self.movie_frame.saturating_add(1);
         if self.movie_frame >= len {
-            self.movie = None;
-            println!("[Runner] TAS playback finished");
+            self.stop_movie();
+        } else if let Some(playback) = self.state.movie_playback.lock().as_mut() {
+            playback.cursor = self.movie_frame;
         }
     }
 
+    /// Ends TAS playback and withdraws the published input look-ahead.
+    fn stop_movie(&mut self) {
+        self.movie = None;
+        *self.state.movie_playback.lock() = None;
+    }
+
     /// Broadcasts debug state to subscribers if someone is listening.
+    /// Announces a newly jammed CPU and optionally pauses emulation.
+    fn check_cpu_jam(&mut self) {
+        let Some(jam) = self.nes.cpu_jam() else {
+            self.jam_reported = false;
+            return;
+        };
+        if self.jam_reported {
+            return;
+        }
+        self.jam_reported = true;
+
+        let disassembly = disassemble_around(&mut self.nes, jam.pc, JAM_CONTEXT_LINES, 2)
+            .iter()
+            .map(|line| {
+                let marker = if line.addr == jam.pc { '>' } else { ' ' };
+                format!("{marker} {line}")
+            })
+            .collect();
+        let paused = self.state.pause_on_jam.load(Ordering::Acquire);
+        if paused {
+            self.state.paused.store(true, Ordering::Release);
+        }
+        self.pubsub.notify(NotificationEvent::CpuJammed {
+            pc: jam.pc,
+            opcode: jam.opcode,
+            disassembly,
+            paused,
+        });
+    }
+
     fn maybe_broadcast_debug_state(&mut self) {
         if !self.pubsub.has_subscriber(EventTopic::DebugState) {
             return;
@@ -1101,61 +1741,133 @@ impl Runner {
         }
     }
 
-    /// Loads a ROM from the specified path, calculates its SHA-1 hash, and initializes the NES state.
     fn handle_load_rom(&mut self, path: PathBuf, reply: ControlReplySender) {
-        match std::fs::read(&path) {
+        let result = self.load_rom_file(path);
+        let _ = reply.send(result);
+    }
+
+    /// Loads a ROM from the specified path, applying a sibling `.ips` patch when
+    /// one exists.
+    fn load_rom_file(&mut self, path: PathBuf) -> Result<(), RuntimeError> {
+        self.close_game();
+        broadcast_load_progress(&mut self.pubsub, LoadProgress::Stage(LoadStage::Read));
+        let result = match std::fs::read(&path) {
             Ok(bytes) => {
-                let mut hasher = Sha1::new();
-                hasher.update(&bytes);
-                let hash: [u8; 20] = hasher.finalize().into();
-                // Pad to 32 bytes for the internal representation.
-                let mut full_hash = [0u8; 32];
-                full_hash[..20].copy_from_slice(&hash);
-
-                match self.nes.load_cartridge_from_file(&path) {
-                    Ok(_) => {
-                        *self.state.rom_hash.lock() = Some(full_hash);
-                        self.state.paused.store(false, Ordering::Release);
-                        self.next_frame_deadline = Instant::now();
-                        if let Some(audio) = &self.audio {
-                            audio.clear();
-                        }
-                        self.rewind.clear();
-                        self.state.rewinding.store(false, Ordering::Release);
-                        let _ = reply.send(Ok(()));
-                    }
-                    Err(e) => {
-                        *self.state.rom_hash.lock() = None;
-                        let error = e.to_string();
-                        let _ = reply.send(Err(RuntimeError::LoadRomFailed { path, error }));
-                    }
-                }
+                let patch = std::fs::read(path.with_extension("ips")).ok();
+                self.load_rom_bytes(bytes, patch.as_deref())
             }
-            Err(e) => {
-                *self.state.rom_hash.lock() = None;
-                let error = e.to_string();
-                let _ = reply.send(Err(RuntimeError::LoadRomFailed { path, error }));
+            Err(e) => Err(LoadError {
+                stage: LoadStage::Read,
+                error: e.into(),
+            }),
+        };
+        self.rom_path = result.is_ok().then(|| path.clone());
+        self.finish_rom_load(result, path)
+    }
+
+    fn handle_load_rom_from_memory(&mut self, bytes: Vec<u8>, reply: ControlReplySender) {
+        self.close_game();
+        let result = self.load_rom_bytes(bytes, None);
+        self.rom_path = None;
+        let _ = reply.send(self.finish_rom_load(result, PathBuf::from("memory")));
+    }
+
+    /// Reloads the ROM file currently loaded. With
+    /// [`RomReloadMode::KeepState`] the state captured just before the reload
+    /// is restored into the new build; if it no longer fits, the game is
+    /// powered on fresh and the failure is reported as `OperationFailed`.
+    fn handle_reload_rom(&mut self, mode: RomReloadMode, reply: ControlReplySender) {
+        if self.nes.get_cartridge().is_none() {
+            let _ = reply.send(Err(RuntimeError::NoRomLoaded));
+            return;
+        }
+        let Some(path) = self.rom_path.clone() else {
+            let _ = reply.send(Err(RuntimeError::RomNotReloadable));
+            return;
+        };
+        let carried = match mode {
+            RomReloadMode::PowerCycle => None,
+            RomReloadMode::KeepState => match self.capture_compressed_snapshot() {
+                Ok(state) => Some(state),
+                Err(error) => {
+                    self.pubsub.notify(NotificationEvent::OperationFailed {
+                        operation: "reload state",
+                        error,
+                    });
+                    None
+                }
+            },
+        };
+        if let Err(e) = self.load_rom_file(path) {
+            let _ = reply.send(Err(e));
+            return;
+        }
+        let mut state_restored = false;
+        if let Some(state) = carried {
+            // A rebuilt ROM has a new hash, so only the mapper is checked.
+            match self.apply_snapshot(state, false) {
+                Ok(()) => state_restored = true,
+                Err(error) => {
+                    self.nes.reset(ResetKind::PowerOn);
+                    self.pubsub.notify(NotificationEvent::OperationFailed {
+                        operation: "reload state",
+                        error,
+                    });
+                }
             }
         }
+        self.pubsub
+            .notify(NotificationEvent::RomReloaded { state_restored });
+        let _ = reply.send(Ok(()));
     }
 
-    fn handle_load_rom_from_memory(&mut self, bytes: Vec<u8>, reply: ControlReplySender) {
+    /// Runs the staged cartridge loader, forwarding its progress and warnings
+    /// to `Notification` subscribers, and inserts the cartridge on success.
+    fn load_rom_bytes(&mut self, bytes: Vec<u8>, patch: Option<&[u8]>) -> Result<(), LoadError> {
+        // The hash identifies the unpatched dump, matching what netplay peers
+        // compare.
         let mut hasher = Sha1::new();
         hasher.update(&bytes);
         let hash: [u8; 20] = hasher.finalize().into();
+        // Pad to 32 bytes for the internal representation.
         let mut full_hash = [0u8; 32];
         full_hash[..20].copy_from_slice(&hash);
 
-        match nesium_core::cartridge::load_cartridge(bytes) {
-            Ok(cart) => {
-                self.nes.insert_cartridge(cart);
-                *self.state.rom_hash.lock() = Some(full_hash);
+        let profile = self.game_profile(&full_hash).unwrap_or_default();
+        self.nes
+            .set_ram_init(profile.ram_init.unwrap_or(self.ram_init));
+
+        let pubsub = &mut self.pubsub;
+        let mut on_progress = |progress| broadcast_load_progress(pubsub, progress);
+        let cart = self
+            .nes
+            .cartridge_loader()
+            .patch(patch)
+            .on_progress(&mut on_progress)
+            .load(bytes)?;
+        self.nes.insert_cartridge(cart);
+        *self.state.rom_hash.lock() = Some(full_hash);
+        Ok(())
+    }
+
+    fn game_profile(&self, rom_hash: &[u8; 32]) -> Option<GameProfile> {
+        self.state.game_profiles.lock().get(rom_hash).copied()
+    }
+
+    fn finish_rom_load(
+        &mut self,
+        result: Result<(), LoadError>,
+        path: PathBuf,
+    ) -> Result<(), RuntimeError> {
+        match result {
+            Ok(()) => {
                 self.state.paused.store(false, Ordering::Release);
                 self.next_frame_deadline = Instant::now();
                 if let Some(audio) = &self.audio {
                     audio.clear();
                 }
                 self.rewind.clear();
+                self.undo_load = None;
                 self.state.rewinding.store(false, Ordering::Release);
                 // Reset frame sequence when netplay is active to prevent frame mismatch.
                 // Without this, after power off and reload, frame_seq stays high but
@@ -1163,15 +1875,35 @@ impl Runner {
                 if self.netplay_input.is_some() {
                     self.state.frame_seq.store(0, Ordering::Release);
                 }
-                let _ = reply.send(Ok(()));
+                self.restore_battery_ram();
+                let info = self.nes.get_cartridge().map(|cart| cart.info().clone());
+                *self.state.cartridge_info.lock() = info.clone();
+                if let Some(info) = info {
+                    self.pubsub.notify(NotificationEvent::CartridgeLoaded {
+                        info: Box::new(info),
+                    });
+                }
+                if let Some(info) = self
+                    .slot_paths(StateSlot::Emergency)
+                    .ok()
+                    .and_then(|paths| paths.info(StateSlot::Emergency))
+                {
+                    self.pubsub
+                        .notify(NotificationEvent::EmergencyStateAvailable {
+                            modified: info.modified,
+                        });
+                }
+                Ok(())
             }
             Err(e) => {
                 *self.state.rom_hash.lock() = None;
+                *self.state.cartridge_info.lock() = None;
+                self.pubsub.notify(NotificationEvent::RomLoadFailed {
+                    stage: e.stage,
+                    error: e.error.to_string(),
+                });
                 let error = e.to_string();
-                let _ = reply.send(Err(RuntimeError::LoadRomFailed {
-                    path: PathBuf::from("memory"),
-                    error,
-                }));
+                Err(RuntimeError::LoadRomFailed { path, error })
             }
         }
     }
@@ -1283,6 +2015,7 @@ impl Runner {
     ///
     /// This clears all NES state and displays a black screen.
     fn handle_power_off(&mut self, reply: ControlReplySender) {
+        self.close_game();
         self.nes.power_off();
         // Clear input state
         for mask in &self.state.pad_masks {
@@ -1300,8 +2033,10 @@ impl Runner {
         self.state.rewinding.store(false, Ordering::Release);
         // Reset frame sequence (important for netplay)
         self.state.frame_seq.store(0, Ordering::Release);
-        // Clear ROM hash
+        // Clear ROM hash and cartridge metadata
+        self.rom_path = None;
         *self.state.rom_hash.lock() = None;
+        *self.state.cartridge_info.lock() = None;
 
         // Clear framebuffer to display black screen and notify frontend.
         // clear_and_present() fills buffers with 0 bytes (black) and triggers the callback,
@@ -1318,12 +2053,70 @@ impl Runner {
     }
 
     /// Updates the audio bus configuration.
-    fn handle_set_audio_config(&mut self, cfg: AudioBusConfig, reply: ControlReplySender) {
+    fn handle_set_audio_config(&mut self, mut cfg: AudioBusConfig, reply: ControlReplySender) {
+        // Fast-forward is tracked by the runner, not the frontend.
+        cfg.is_fast_forward = self.nes.audio_bus_config().is_fast_forward;
         self.nes.set_audio_bus_config(cfg);
         let _ = reply.send(Ok(()));
     }
 
+    /// Updates per-channel mixer settings.
+    fn handle_set_mixer_settings(&mut self, settings: MixerSettings, reply: ControlReplySender) {
+        self.nes.set_mixer_settings(&settings);
+        let _ = reply.send(Ok(()));
+    }
+
+    fn handle_insert_barcode(&mut self, barcode: &str, reply: ControlReplySender) {
+        let result =
+            self.nes
+                .insert_barcode(barcode)
+                .map_err(|e| RuntimeError::InsertBarcodeFailed {
+                    error: e.to_string(),
+                });
+        let _ = reply.send(result);
+    }
+
+    fn handle_set_rainbow_bridge(&mut self, addr: Option<String>, reply: ControlReplySender) {
+        self.rainbow_bridge = None;
+        let result = match addr {
+            Some(addr) => RainbowBridge::connect(&addr)
+                .map(|bridge| self.rainbow_bridge = Some(bridge))
+                .map_err(|e| RuntimeError::RainbowBridgeFailed {
+                    addr,
+                    error: e.to_string(),
+                }),
+            None => Ok(()),
+        };
+        let _ = reply.send(result);
+    }
+
+    /// Relays ESP messages between a Rainbow cartridge and the bridge socket.
+    fn pump_rainbow_bridge(&mut self) {
+        let Some(bridge) = &mut self.rainbow_bridge else {
+            return;
+        };
+        if let Err(e) = bridge.pump(&mut self.nes) {
+            self.rainbow_bridge = None;
+            self.pubsub.notify(NotificationEvent::RainbowBridgeClosed {
+                error: e.to_string(),
+            });
+        }
+    }
+
     /// Configures the frame-ready callback for video presentation.
+    fn handle_advance_frame(&mut self, reply: ControlReplySender) {
+        if self.nes.get_cartridge().is_none() {
+            let _ = reply.send(Err(RuntimeError::NoRomLoaded));
+            return;
+        }
+        // Pause first so the stepped frame is always presented and the run
+        // loop does not carry on after it.
+        self.state.paused.store(true, Ordering::Release);
+        self.state.rewinding.store(false, Ordering::Release);
+        self.step_frame();
+        let _ = reply.send(Ok(()));
+    }
+
     fn handle_set_frame_ready_callback(
         &mut self,
         cb: Option<FrameReadyCallback>,
@@ -1366,6 +2159,87 @@ impl Runner {
         let _ = reply.send(Ok(()));
     }
 
+    fn handle_capture_screenshot(
+        &mut self,
+        path: PathBuf,
+        options: ScreenshotOptions,
+        reply: ControlReplySender,
+    ) {
+        if self.nes.get_cartridge().is_none() {
+            let _ = reply.send(Err(RuntimeError::NoRomLoaded));
+            return;
+        }
+        self.pending_screenshots.push((path, options, reply));
+        // No frame is coming while paused; the one on screen is the answer.
+        if self.state.paused.load(Ordering::Acquire)
+            && !self.state.rewinding.load(Ordering::Acquire)
+        {
+            self.take_screenshots();
+        }
+    }
+
+    /// Writes the presented frame for every pending screenshot request.
+    fn take_screenshots(&mut self) {
+        for (path, options, reply) in std::mem::take(&mut self.pending_screenshots) {
+            let (width, height, rgb) = self.nes.render_frame_rgb(options.filtered);
+            let result = write_png(&path, width, height, &rgb);
+            let event = match &result {
+                Ok(()) => NotificationEvent::ScreenshotSaved { path: path.clone() },
+                Err(error) => NotificationEvent::ScreenshotFailed {
+                    path: path.clone(),
+                    error: error.clone(),
+                },
+            };
+            self.pubsub.notify(event);
+            let _ =
+                reply.send(result.map_err(|error| RuntimeError::ScreenshotFailed { path, error }));
+        }
+    }
+
+    fn start_recording(&mut self, path: PathBuf) -> Result<(), RuntimeError> {
+        self.stop_recording()?;
+        let Some(cartridge) = self.nes.get_cartridge() else {
+            return Err(RuntimeError::NoRomLoaded);
+        };
+        let region = Region::resolve(self.nes.region, cartridge.header().tv_system());
+        let failed = |error: String| RuntimeError::RecordingFailed {
+            path: path.clone(),
+            error,
+        };
+        let video = Y4mWriter::create(&path.with_extension("y4m"), region)
+            .map_err(|e| failed(e.to_string()))?;
+        self.nes
+            .start_audio_recording(path.with_extension("wav"), RecordSource::Mix)
+            .map_err(|e| failed(e.to_string()))?;
+        self.recorder = Some((path, video));
+        self.state.recording.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    fn stop_recording(&mut self) -> Result<(), RuntimeError> {
+        let Some((path, video)) = self.recorder.take() else {
+            return Ok(());
+        };
+        self.state.recording.store(false, Ordering::Release);
+        let audio = self.nes.stop_audio_recording().map_err(|e| e.to_string());
+        video
+            .finish()
+            .map_err(|e| e.to_string())
+            .and(audio)
+            .map_err(|error| RuntimeError::RecordingFailed { path, error })
+    }
+
+    /// Finalizes a recording whose game is going away; there is no caller
+    /// left to hand a failure to.
+    fn finish_recording(&mut self) {
+        if let Err(e) = self.stop_recording() {
+            self.pubsub.notify(NotificationEvent::OperationFailed {
+                operation: "recording",
+                error: e.to_string(),
+            });
+        }
+    }
+
     fn handle_set_video_pipeline(
         &mut self,
         width: u32,
@@ -1386,13 +2260,12 @@ impl Runner {
     /// Configures the target FPS, allowing for integer 60Hz mode to reduce judder.
     fn handle_set_integer_fps_target(&mut self, fps: Option<u32>, reply: ControlReplySender) {
         self.integer_fps_target = fps;
-        if fps == Some(60) {
-            self.frame_duration = FRAME_DURATION_60HZ;
-            self.nes.set_audio_integer_fps_scale(60.0 / NTSC_FPS_EXACT);
+        self.frame_duration = if fps == Some(60) {
+            FRAME_DURATION_60HZ
         } else {
-            self.frame_duration = FRAME_DURATION_NTSC;
-            self.nes.reset_audio_integer_fps_scale();
-        }
+            FRAME_DURATION_NTSC
+        };
+        self.apply_audio_speed(self.speed_percent());
 
         // Re-anchor to avoid a big catch-up burst right after toggling.
         self.next_frame_deadline = Instant::now();
@@ -1411,11 +2284,19 @@ impl Runner {
         let _ = reply.send(Ok(()));
     }
 
+    /// Toggles famiclone PPU emulation.
+    fn handle_set_clone_ppu(&mut self, enabled: bool, reply: ControlReplySender) {
+        self.nes.set_clone_ppu(enabled);
+        let _ = reply.send(Ok(()));
+    }
+
     /// Captures the NES state, compresses it with LZ4, and saves it to a file.
     fn handle_save_state(&mut self, path: PathBuf, reply: ControlReplySender) {
         match self.capture_compressed_snapshot() {
             Ok(compressed) => match std::fs::write(&path, compressed) {
                 Ok(_) => {
+                    self.pubsub
+                        .notify(NotificationEvent::StateFileSaved { path: path.clone() });
                     let _ = reply.send(Ok(()));
                 }
                 Err(e) => {
@@ -1434,8 +2315,10 @@ impl Runner {
     /// Reads a save state from a file, decompresses it, and restores the machine state.
     fn handle_load_state(&mut self, path: PathBuf, reply: ControlReplySender) {
         match std::fs::read(&path) {
-            Ok(bytes) => match self.apply_compressed_snapshot(bytes) {
+            Ok(bytes) => match self.load_with_undo(bytes) {
                 Ok(_) => {
+                    self.pubsub
+                        .notify(NotificationEvent::StateFileLoaded { path: path.clone() });
                     let _ = reply.send(Ok(()));
                 }
                 Err(error) => {
@@ -1451,6 +2334,199 @@ impl Runner {
         }
     }
 
+    fn slot_paths(&self, slot: StateSlot) -> Result<SlotPaths, RuntimeError> {
+        let rom_hash = (*self.state.rom_hash.lock()).ok_or(RuntimeError::NoRomLoaded)?;
+        let dir = self
+            .state
+            .save_state_dir
+            .lock()
+            .clone()
+            .ok_or(RuntimeError::NoSaveStateDir)?;
+        Ok(SlotPaths::new(&dir, &rom_hash, slot))
+    }
+
+    /// Writes the state and a thumbnail of the last frame to `slot`.
+    fn save_slot(&mut self, slot: StateSlot) -> Result<(), RuntimeError> {
+        let paths = self.slot_paths(slot)?;
+        let failed = |error: String| RuntimeError::SaveStateFailed {
+            path: paths.state.clone(),
+            error,
+        };
+        let state = self.capture_compressed_snapshot().map_err(failed)?;
+        let thumbnail = render_thumbnail(self.nes.render_index_buffer(), self.nes.palette());
+        paths
+            .write(&state, &thumbnail)
+            .map_err(|e| failed(e.to_string()))?;
+        self.pubsub.notify(NotificationEvent::StateSaved {
+            slot,
+            thumbnail: Arc::from(thumbnail),
+        });
+        Ok(())
+    }
+
+    fn handle_load_slot(&mut self, slot: StateSlot, reply: ControlReplySender) {
+        let result = self.slot_paths(slot).and_then(|paths| {
+            let bytes = std::fs::read(&paths.state).map_err(|e| RuntimeError::LoadStateFailed {
+                path: paths.state.clone(),
+                error: e.to_string(),
+            })?;
+            self.load_with_undo(bytes)
+                .map_err(|error| RuntimeError::LoadStateFailed {
+                    path: paths.state,
+                    error,
+                })
+        });
+        if result.is_ok() {
+            self.pubsub.notify(NotificationEvent::StateLoaded { slot });
+        }
+        let _ = reply.send(result);
+    }
+
+    /// Applies a compressed state, keeping the one it replaces for
+    /// [`handle_undo_load_state`](Self::handle_undo_load_state).
+    fn load_with_undo(&mut self, bytes: Vec<u8>) -> Result<(), String> {
+        let previous = self.capture_compressed_snapshot()?;
+        self.apply_compressed_snapshot(bytes)?;
+        self.undo_load = Some(previous);
+        Ok(())
+    }
+
+    fn handle_undo_load_state(&mut self, reply: ControlReplySender) {
+        let result = match self.undo_load.take() {
+            Some(bytes) => self.apply_compressed_snapshot(bytes).map_err(|error| {
+                RuntimeError::LoadStateFailed {
+                    path: PathBuf::from("undo"),
+                    error,
+                }
+            }),
+            None => Err(RuntimeError::NothingToUndo),
+        };
+        if result.is_ok() {
+            self.pubsub.notify(NotificationEvent::StateLoadUndone);
+        }
+        let _ = reply.send(result);
+    }
+
+    /// Persists the game about to be closed: finishes any recording, writes
+    /// battery RAM and the autosave slot, and drops the emergency state that
+    /// this clean exit supersedes. Failures are reported as `OperationFailed`.
+    fn close_game(&mut self) {
+        self.finish_recording();
+        if self.replay_check.take().is_some() {
+            self.nes.stop_movie();
+            self.restore_deterministic();
+        }
+        if self.nes.get_cartridge().is_none() {
+            return;
+        }
+        if let Err(error) = self.flush_battery_ram() {
+            self.pubsub.notify(NotificationEvent::OperationFailed {
+                operation: "battery save",
+                error,
+            });
+        }
+        self.autosave();
+        if let Ok(paths) = self.slot_paths(StateSlot::Emergency) {
+            paths.remove();
+        }
+    }
+
+    /// Writes the cartridge's battery RAM to its save file if it changed
+    /// since the last write. Returns the number of bytes written.
+    fn flush_battery_ram(&mut self) -> Result<Option<usize>, String> {
+        let Some(ram) = self.nes.battery_ram() else {
+            return Ok(None);
+        };
+        if self.battery_ram_saved.as_ref() == Some(&ram) {
+            return Ok(None);
+        }
+        let Some(path) = self.battery_path() else {
+            return Ok(None);
+        };
+        let bytes = ram.len();
+        // Remember the contents even if the write fails, so a broken save
+        // directory is reported once per change rather than every check.
+        let result = write_atomic(&path, &ram).map_err(|e| format!("{}: {e}", path.display()));
+        self.battery_ram_saved = Some(ram);
+        result.map(|()| Some(bytes))
+    }
+
+    /// Writes battery RAM the game changed, so a crash loses at most
+    /// [`BATTERY_SAVE_INTERVAL`] frames of in-game saves.
+    fn save_battery_ram_if_changed(&mut self) {
+        let event = match self.flush_battery_ram() {
+            Ok(Some(bytes)) => NotificationEvent::BatteryRamSaved { bytes },
+            Ok(None) => return,
+            Err(error) => NotificationEvent::OperationFailed {
+                operation: "battery save",
+                error,
+            },
+        };
+        self.pubsub.notify(event);
+    }
+
+    /// Loads the save file of a freshly inserted battery-backed cartridge.
+    fn restore_battery_ram(&mut self) {
+        let Some(path) = self.battery_path() else {
+            return;
+        };
+        let Ok(ram) = std::fs::read(&path) else {
+            self.battery_ram_saved = self.nes.battery_ram();
+            return;
+        };
+        if !self.nes.load_battery_ram(&ram) {
+            tracing::warn!(
+                "ignoring battery save {} ({} bytes) that doesn't match the cartridge",
+                path.display(),
+                ram.len()
+            );
+        }
+        self.battery_ram_saved = self.nes.battery_ram();
+    }
+
+    fn battery_path(&self) -> Option<PathBuf> {
+        let rom_hash = (*self.state.rom_hash.lock())?;
+        let dir = self.state.save_state_dir.lock().clone()?;
+        Some(battery_path(&dir, &rom_hash))
+    }
+
+    /// Last-resort save after a panic on this thread or a termination signal.
+    /// The emulator may be mid-frame, so each step is isolated from a second
+    /// panic.
+    pub(crate) fn emergency_save(&mut self, reason: &str) {
+        if self.nes.get_cartridge().is_none() {
+            return;
+        }
+        let battery_saved = self.nes.battery_ram().is_some()
+            && panic::catch_unwind(AssertUnwindSafe(|| self.flush_battery_ram()))
+                .is_ok_and(|result| result.is_ok());
+        let state_saved =
+            panic::catch_unwind(AssertUnwindSafe(|| self.save_slot(StateSlot::Emergency)))
+                .is_ok_and(|result| result.is_ok());
+        tracing::error!("{reason}; emergency save: battery {battery_saved}, state {state_saved}");
+        self.pubsub.notify(NotificationEvent::EmergencySaved {
+            reason: reason.to_string(),
+            battery_saved,
+            state_saved,
+        });
+    }
+
+    /// Saves the autosave slot before the current game is closed, if enabled.
+    /// Failures are only logged: nothing is left to report them to.
+    fn autosave(&mut self) {
+        if !self.state.autosave_on_exit.load(Ordering::Acquire)
+            || self.nes.get_cartridge().is_none()
+        {
+            return;
+        }
+        if let Err(e) = self.save_slot(StateSlot::Autosave) {
+            self.pubsub.notify(NotificationEvent::OperationFailed {
+                operation: "autosave",
+                error: e.to_string(),
+            });
+        }
+    }
+
     /// Captures the NES state, compresses it, and sends the bytes via the reply channel.
     fn handle_save_state_to_memory(&mut self, reply: Sender<Result<Vec<u8>, RuntimeError>>) {
         match self.capture_compressed_snapshot() {
@@ -1495,6 +2571,94 @@ impl Runner {
     }
 
     /// Loads a TAS movie and initializes playback by resetting the console.
+    fn handle_start_replay_recording(&mut self, interval: u32, reply: ControlReplySender) {
+        if self.nes.get_cartridge().is_none() {
+            let _ = reply.send(Err(RuntimeError::NoRomLoaded));
+            return;
+        }
+        self.nes.set_deterministic(true);
+        self.nes.start_movie_recording();
+        self.replay_check = Some(ReplayCheck::Recording {
+            interval: interval.max(1),
+            hashes: Vec::new(),
+        });
+        self.restart_for_replay();
+        let _ = reply.send(Ok(()));
+    }
+
+    fn finish_replay_recording(&mut self) -> Result<ReplayBaseline, RuntimeError> {
+        let Some(ReplayCheck::Recording { interval, hashes }) = self.replay_check.take() else {
+            return Err(RuntimeError::NotRecordingReplay);
+        };
+        let movie = self.nes.stop_movie().unwrap_or_default();
+        self.restore_deterministic();
+        Ok(ReplayBaseline {
+            movie,
+            interval,
+            hashes,
+        })
+    }
+
+    fn handle_start_replay_check(
+        &mut self,
+        mut baseline: ReplayBaseline,
+        reply: ControlReplySender,
+    ) {
+        if self.nes.get_cartridge().is_none() {
+            let _ = reply.send(Err(RuntimeError::NoRomLoaded));
+            return;
+        }
+        baseline.interval = baseline.interval.max(1);
+        self.nes.set_deterministic(true);
+        self.nes.play_movie(baseline.movie.clone());
+        self.replay_check = Some(ReplayCheck::Verifying { baseline });
+        self.restart_for_replay();
+        let _ = reply.send(Ok(()));
+    }
+
+    /// Clears host-side state left over from before the power cycle that
+    /// starts a replay recording or check.
+    fn restart_for_replay(&mut self) {
+        if let Some(audio) = &self.audio {
+            audio.clear();
+        }
+        self.rewind.clear();
+        self.state.rewinding.store(false, Ordering::Release);
+        self.state.paused.store(false, Ordering::Release);
+        self.next_frame_deadline = Instant::now();
+    }
+
+    /// Applies the frontend's deterministic setting, unless netplay or a
+    /// replay check needs it on.
+    fn restore_deterministic(&mut self) {
+        self.nes.set_deterministic(
+            self.deterministic || self.netplay_input.is_some() || self.replay_check.is_some(),
+        );
+    }
+
+    /// Feeds the frame just run to the replay check, finishing it on the
+    /// first divergence or at the end of the movie.
+    fn check_replay_frame(&mut self) {
+        let Some(check) = &mut self.replay_check else {
+            return;
+        };
+        // The movie was replaced or stopped behind the check's back.
+        let Some(frame) = self.nes.movie_position() else {
+            self.replay_check = None;
+            self.restore_deterministic();
+            return;
+        };
+        let nes = &self.nes;
+        let Some(outcome) = check.after_frame(frame, || nes.state_hash()) else {
+            return;
+        };
+        self.replay_check = None;
+        self.nes.stop_movie();
+        self.restore_deterministic();
+        self.pubsub
+            .notify(NotificationEvent::ReplayCheckFinished { outcome });
+    }
+
     fn handle_load_movie(&mut self, movie: nesium_support::tas::Movie, reply: ControlReplySender) {
         if self.nes.get_cartridge().is_some() {
             // FCEUX logic: fully reload the game/power cycle before playing any movie.
@@ -1523,6 +2687,10 @@ impl Runner {
             self.state.paused.store(false, Ordering::Release);
             self.next_frame_deadline = Instant::now();
 
+            *self.state.movie_playback.lock() = Some(MoviePlayback {
+                frames: Arc::from(movie.frames.as_slice()),
+                cursor: 0,
+            });
             self.movie = Some(movie);
             self.movie_frame = 0;
         }
@@ -1553,6 +2721,12 @@ impl Runner {
 
     /// Decompresses and applies a snapshot buffer to the NES instance, validating ROM compatibility.
     fn apply_compressed_snapshot(&mut self, bytes: Vec<u8>) -> Result<(), String> {
+        self.apply_snapshot(bytes, true)
+    }
+
+    /// [`apply_compressed_snapshot`](Self::apply_compressed_snapshot), with
+    /// the ROM hash check optional.
+    fn apply_snapshot(&mut self, bytes: Vec<u8>, check_rom_hash: bool) -> Result<(), String> {
         let cartridge = self.nes.get_cartridge().ok_or("no cartridge loaded")?;
         let decoded = decompress_size_prepended(&bytes).unwrap_or(bytes);
         let snap = NesSnapshot::from_postcard_bytes(&decoded).map_err(|e| e.to_string())?;
@@ -1565,7 +2739,9 @@ impl Runner {
             return Err("ROM mapper mismatch: this save belongs to a different game".to_string());
         }
         // Validate ROM Hash.
-        if let Some(expected_hash) = snap.meta.rom_hash {
+        if let Some(expected_hash) = snap.meta.rom_hash
+            && check_rom_hash
+        {
             let current_hash = *self.state.rom_hash.lock();
             if Some(expected_hash) != current_hash {
                 return Err("ROM hash mismatch: this save belongs to a different game".to_string());
@@ -1578,3 +2754,19 @@ impl Runner {
         Ok(())
     }
 }
+
+fn broadcast_load_progress(pubsub: &mut RuntimePubSub, progress: LoadProgress) {
+    let event = match progress {
+        LoadProgress::Stage(stage) => NotificationEvent::RomLoadProgress { stage },
+        LoadProgress::Warning(warning) => NotificationEvent::RomLoadWarning { warning },
+    };
+    pubsub.notify(event);
+}
+
+/// Folds one audio queue `fill` reading into the smoothed `fill_estimate` and
+/// returns the resampler factor: above 1 compresses audio while the queue is
+/// more than half full, below 1 stretches it.
+pub(super) fn rate_control_step(fill_estimate: &mut f32, fill: f32) -> f64 {
+    *fill_estimate += (fill - *fill_estimate) * RATE_CONTROL_SMOOTHING;
+    1.0 + f64::from(2.0 * *fill_estimate - 1.0) * RATE_CONTROL_MAX_SKEW
+}
@@ -0,0 +1,27 @@
+//! PNG screenshots of presented frames.
+
+use std::{fs::File, io::BufWriter, path::Path};
+
+/// How `RuntimeHandle::capture_screenshot` renders the frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ScreenshotOptions {
+    /// Run the frame through the active video filter at the video output
+    /// size, as shown on screen. Otherwise the raw 256x240 picture is saved.
+    pub filtered: bool,
+}
+
+/// Encodes packed RGB888 pixels as a PNG file.
+pub(crate) fn write_png(
+    path: &Path,
+    width: usize,
+    height: usize,
+    rgb: &[u8],
+) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| e.to_string())?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+    writer.write_image_data(rgb).map_err(|e| e.to_string())?;
+    writer.finish().map_err(|e| e.to_string())
+}
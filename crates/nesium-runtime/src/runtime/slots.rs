@@ -0,0 +1,151 @@
+//! Numbered save-state slots and battery saves, stored per game under
+//! `<save state dir>/<rom hash>/`.
+
+use std::{
+    fmt::Write as _,
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
+
+use lz4_flex::{compress_prepend_size, decompress_size_prepended};
+use nesium_core::ppu::{SCREEN_HEIGHT, SCREEN_WIDTH, palette::Palette};
+
+/// Number of numbered slots per game (`0..SAVE_STATE_SLOTS`).
+pub const SAVE_STATE_SLOTS: u8 = 10;
+/// Thumbnails are the frame downscaled by two in each direction.
+pub const THUMBNAIL_WIDTH: usize = SCREEN_WIDTH / 2;
+pub const THUMBNAIL_HEIGHT: usize = SCREEN_HEIGHT / 2;
+
+/// Where a slot state lives for the current game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StateSlot {
+    Numbered(u8),
+    /// Written when the game is closed, if autosave on exit is enabled.
+    Autosave,
+    /// Written when the runtime thread panics or the process is terminated
+    /// by a signal; kept until the game is next closed cleanly.
+    Emergency,
+}
+
+impl StateSlot {
+    fn file_stem(self) -> String {
+        match self {
+            StateSlot::Numbered(n) => format!("slot{n}"),
+            StateSlot::Autosave => "auto".to_string(),
+            StateSlot::Emergency => "emergency".to_string(),
+        }
+    }
+}
+
+/// A filled slot, as listed by `RuntimeHandle::save_slots`.
+#[derive(Debug, Clone)]
+pub struct SaveSlotInfo {
+    pub slot: StateSlot,
+    pub modified: SystemTime,
+    /// RGBA8888, [`THUMBNAIL_WIDTH`] x [`THUMBNAIL_HEIGHT`]; `None` if the
+    /// thumbnail file is missing or unreadable.
+    pub thumbnail: Option<Arc<[u8]>>,
+}
+
+/// Default save-state root inside the platform data directory, or `None` on
+/// platforms where the frontend has to supply one (Android, iOS).
+pub(crate) fn default_save_state_dir() -> Option<PathBuf> {
+    let data_dir = if cfg!(windows) {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        std::env::var_os("HOME").map(|home| PathBuf::from(home).join("Library/Application Support"))
+    } else if cfg!(any(target_os = "android", target_os = "ios")) {
+        None
+    } else {
+        std::env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share"))
+            })
+    };
+    data_dir.map(|dir| dir.join("nesium").join("states"))
+}
+
+/// File locations of one slot for one game.
+pub(crate) struct SlotPaths {
+    pub(crate) state: PathBuf,
+    pub(crate) thumbnail: PathBuf,
+}
+
+fn game_dir(root: &Path, rom_hash: &[u8; 32]) -> PathBuf {
+    let mut game = String::with_capacity(64);
+    for byte in rom_hash {
+        let _ = write!(game, "{byte:02x}");
+    }
+    root.join(game)
+}
+
+/// Battery RAM save file of one game.
+pub(crate) fn battery_path(root: &Path, rom_hash: &[u8; 32]) -> PathBuf {
+    game_dir(root, rom_hash).join("battery.sav")
+}
+
+impl SlotPaths {
+    pub(crate) fn new(root: &Path, rom_hash: &[u8; 32], slot: StateSlot) -> Self {
+        let stem = slot.file_stem();
+        let dir = game_dir(root, rom_hash);
+        Self {
+            state: dir.join(format!("{stem}.state")),
+            thumbnail: dir.join(format!("{stem}.thumb")),
+        }
+    }
+
+    /// Writes the state and its thumbnail. Each file is written to a
+    /// temporary sibling first, so a crash never leaves a torn slot behind.
+    pub(crate) fn write(&self, state: &[u8], thumbnail: &[u8]) -> io::Result<()> {
+        write_atomic(&self.state, state)?;
+        write_atomic(&self.thumbnail, &compress_prepend_size(thumbnail))
+    }
+
+    pub(crate) fn remove(&self) {
+        let _ = std::fs::remove_file(&self.state);
+        let _ = std::fs::remove_file(&self.thumbnail);
+    }
+
+    /// Describes the slot, or returns `None` when it is empty.
+    pub(crate) fn info(&self, slot: StateSlot) -> Option<SaveSlotInfo> {
+        let modified = std::fs::metadata(&self.state).ok()?.modified().ok()?;
+        let thumbnail = std::fs::read(&self.thumbnail)
+            .ok()
+            .and_then(|bytes| decompress_size_prepended(&bytes).ok())
+            .filter(|rgba| rgba.len() == THUMBNAIL_WIDTH * THUMBNAIL_HEIGHT * 4)
+            .map(Arc::from);
+        Some(SaveSlotInfo {
+            slot,
+            modified,
+            thumbnail,
+        })
+    }
+}
+
+/// Writes `bytes` through a temporary sibling file, so readers never see a
+/// partially written file.
+pub(crate) fn write_atomic(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, bytes)?;
+    std::fs::rename(&tmp, path)
+}
+
+/// Downscales a palette-index frame into an RGBA8888 thumbnail.
+pub(crate) fn render_thumbnail(indices: &[u8], palette: &Palette) -> Vec<u8> {
+    let colors = palette.as_colors();
+    let mut rgba = Vec::with_capacity(THUMBNAIL_WIDTH * THUMBNAIL_HEIGHT * 4);
+    for y in 0..THUMBNAIL_HEIGHT {
+        for x in 0..THUMBNAIL_WIDTH {
+            let index = indices[y * 2 * SCREEN_WIDTH + x * 2] & 0x3F;
+            let color = colors[index as usize];
+            rgba.extend_from_slice(&[color.r, color.g, color.b, 0xFF]);
+        }
+    }
+    rgba
+}
@@ -1,47 +1,154 @@
+use nesium_core::{
+    cartridge::CartridgeInfo,
+    controller::{ButtonRemap, ZapperCalibration, ZapperTiming},
+    ppu::palette::PaletteLibrary,
+};
+use nesium_support::tas::InputFrame;
 use parking_lot::Mutex;
-use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU16, AtomicU64};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicI32, AtomicU8, AtomicU16, AtomicU64},
+    },
+};
 
-use super::types::TileViewerConfig;
+use super::slots::default_save_state_dir;
+use super::types::{GameProfile, TileViewerConfig};
 
 pub(crate) const TURBO_ON_FRAMES_DEFAULT: u8 = 2;
 pub(crate) const TURBO_OFF_FRAMES_DEFAULT: u8 = 2;
 pub(crate) const FAST_FORWARD_SPEED_PERCENT_DEFAULT: u16 = 300;
 pub(crate) const REWIND_SPEED_PERCENT_DEFAULT: u16 = 100;
+pub(crate) const SLOW_MOTION_SPEED_PERCENT_DEFAULT: u16 = 50;
+/// Bounds for every speed setting, in percent of normal speed.
+pub(crate) const SPEED_PERCENT_MIN: u16 = 10;
+pub(crate) const SPEED_PERCENT_MAX: u16 = 1000;
+/// Upper bound for frames dropped between presented ones while fast-forwarding.
+pub(crate) const FAST_FORWARD_FRAME_SKIP_MAX: u8 = 9;
+/// Upper bound for the frames between rewind snapshots.
+pub(crate) const REWIND_INTERVAL_MAX: u8 = 60;
+/// `zapper_port` value meaning no Zapper is connected.
+pub(crate) const ZAPPER_DISCONNECTED: u8 = u8::MAX;
+/// `zapper_x`/`zapper_y` value meaning the pointer is off the game view. Far
+/// enough out that no calibration offset brings it back on screen.
+pub(crate) const ZAPPER_OFF_SCREEN: i32 = i32::MIN;
+/// `multitap` values.
+pub(crate) const MULTITAP_NONE: u8 = 0;
+pub(crate) const MULTITAP_FOUR_SCORE: u8 = 1;
+pub(crate) const MULTITAP_HORI: u8 = 2;
+/// `input_poll_scanline` value meaning input is sampled at frame start.
+pub(crate) const INPUT_POLL_FRAME_START: u16 = u16::MAX;
+/// Last scanline of an NTSC frame (the pre-render line wraps to -1 after it).
+pub(crate) const INPUT_POLL_SCANLINE_MAX: u16 = 260;
+/// Upper bound for Zapper display-lag compensation.
+pub(crate) const ZAPPER_MAX_LAG_FRAMES: u8 = 8;
+
+/// TAS playback position published by the runtime thread for input look-ahead.
+pub(crate) struct MoviePlayback {
+    pub(crate) frames: Arc<[InputFrame]>,
+    /// Index of the movie frame applied on the next emulated frame.
+    pub(crate) cursor: usize,
+}
 
 pub(crate) struct RuntimeState {
     pub(crate) paused: AtomicBool,
+    /// Pause emulation when the CPU jams.
+    pub(crate) pause_on_jam: AtomicBool,
     pub(crate) pad_masks: [AtomicU8; 4],
     pub(crate) turbo_masks: [AtomicU8; 4],
     pub(crate) turbo_on_frames: AtomicU8,
     pub(crate) turbo_off_frames: AtomicU8,
+    pub(crate) multitap: AtomicU8,
+    pub(crate) button_remaps: Mutex<[ButtonRemap; 4]>,
+    pub(crate) zapper_port: AtomicU8,
+    /// Uncalibrated Zapper aim point in NES pixels; [`ZAPPER_OFF_SCREEN`]
+    /// when the pointer is off the game view.
+    pub(crate) zapper_x: AtomicI32,
+    pub(crate) zapper_y: AtomicI32,
+    pub(crate) zapper_trigger: AtomicBool,
+    pub(crate) zapper_timing: Mutex<ZapperTiming>,
+    pub(crate) zapper_calibration: Mutex<ZapperCalibration>,
+    pub(crate) zapper_crosshair: AtomicBool,
+    /// Frames of display lag the Zapper trigger is rolled back by.
+    pub(crate) zapper_lag_frames: AtomicU8,
     pub(crate) frame_seq: AtomicU64,
     pub(crate) rom_hash: Mutex<Option<[u8; 32]>>,
+    pub(crate) cartridge_info: Mutex<Option<CartridgeInfo>>,
+    /// Keyed by the padded ROM hash reported by `RuntimeHandle::rom_hash`.
+    pub(crate) game_profiles: Mutex<HashMap<[u8; 32], GameProfile>>,
+    pub(crate) palette_library: Mutex<PaletteLibrary>,
     pub(crate) tile_viewer: Mutex<TileViewerConfig>,
     pub(crate) rewind_enabled: AtomicBool,
     pub(crate) rewind_capacity: AtomicU64,
+    /// Frames between rewind snapshots.
+    pub(crate) rewind_interval: AtomicU8,
     pub(crate) rewinding: AtomicBool,
     pub(crate) fast_forwarding: AtomicBool,
     pub(crate) fast_forward_speed_percent: AtomicU16,
     pub(crate) rewind_speed_percent: AtomicU16,
+    /// Frames skipped after each presented one while fast-forwarding.
+    pub(crate) fast_forward_frame_skip: AtomicU8,
+    pub(crate) slow_motion: AtomicBool,
+    pub(crate) slow_motion_speed_percent: AtomicU16,
+    /// Normal speed, used while neither fast-forwarding nor in slow motion.
+    pub(crate) speed_percent: AtomicU16,
+    pub(crate) movie_playback: Mutex<Option<MoviePlayback>>,
+    /// Root of the per-game save-state slot directories.
+    pub(crate) save_state_dir: Mutex<Option<PathBuf>>,
+    pub(crate) autosave_on_exit: AtomicBool,
+    /// An AV recording is in progress.
+    pub(crate) recording: AtomicBool,
+    /// Nudge the resampler to keep the audio queue half full.
+    pub(crate) dynamic_rate_control: AtomicBool,
+    /// Scanline at which live input is sampled again mid-frame, or
+    /// [`INPUT_POLL_FRAME_START`].
+    pub(crate) input_poll_scanline: AtomicU16,
 }
 
 impl RuntimeState {
     pub(crate) fn new() -> Self {
         Self {
             paused: AtomicBool::new(false),
+            pause_on_jam: AtomicBool::new(false),
             pad_masks: std::array::from_fn(|_| AtomicU8::new(0)),
             turbo_masks: std::array::from_fn(|_| AtomicU8::new(0)),
             turbo_on_frames: AtomicU8::new(TURBO_ON_FRAMES_DEFAULT),
             turbo_off_frames: AtomicU8::new(TURBO_OFF_FRAMES_DEFAULT),
+            multitap: AtomicU8::new(MULTITAP_NONE),
+            button_remaps: Mutex::new([ButtonRemap::IDENTITY; 4]),
+            zapper_port: AtomicU8::new(ZAPPER_DISCONNECTED),
+            zapper_x: AtomicI32::new(ZAPPER_OFF_SCREEN),
+            zapper_y: AtomicI32::new(ZAPPER_OFF_SCREEN),
+            zapper_trigger: AtomicBool::new(false),
+            zapper_timing: Mutex::new(ZapperTiming::default()),
+            zapper_calibration: Mutex::new(ZapperCalibration::default()),
+            zapper_crosshair: AtomicBool::new(false),
+            zapper_lag_frames: AtomicU8::new(0),
             frame_seq: AtomicU64::new(0),
             rom_hash: Mutex::new(None),
+            cartridge_info: Mutex::new(None),
+            game_profiles: Mutex::new(HashMap::new()),
+            palette_library: Mutex::new(PaletteLibrary::new()),
             tile_viewer: Mutex::new(TileViewerConfig::default()),
             rewind_enabled: AtomicBool::new(false),
             rewind_capacity: AtomicU64::new(600), // Default 10s @ 60fps
+            rewind_interval: AtomicU8::new(1),
             rewinding: AtomicBool::new(false),
             fast_forwarding: AtomicBool::new(false),
             fast_forward_speed_percent: AtomicU16::new(FAST_FORWARD_SPEED_PERCENT_DEFAULT),
             rewind_speed_percent: AtomicU16::new(REWIND_SPEED_PERCENT_DEFAULT),
+            fast_forward_frame_skip: AtomicU8::new(0),
+            slow_motion: AtomicBool::new(false),
+            slow_motion_speed_percent: AtomicU16::new(SLOW_MOTION_SPEED_PERCENT_DEFAULT),
+            speed_percent: AtomicU16::new(100),
+            movie_playback: Mutex::new(None),
+            save_state_dir: Mutex::new(default_save_state_dir()),
+            autosave_on_exit: AtomicBool::new(false),
+            recording: AtomicBool::new(false),
+            dynamic_rate_control: AtomicBool::new(true),
+            input_poll_scanline: AtomicU16::new(INPUT_POLL_FRAME_START),
         }
     }
 }
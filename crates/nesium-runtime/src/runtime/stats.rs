@@ -0,0 +1,76 @@
+use std::time::{Duration, Instant};
+
+use super::types::PerformanceStats;
+
+/// Length of the window each [`PerformanceStats`] covers.
+const STATS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Accumulates per-frame timings on the runtime thread and rolls them into a
+/// [`PerformanceStats`] once per [`STATS_INTERVAL`].
+pub(crate) struct PerfStatsTracker {
+    window_start: Instant,
+    frame_times: Vec<Duration>,
+    dropped_frames: u32,
+    cpu_cycles: u64,
+}
+
+impl PerfStatsTracker {
+    pub(crate) fn new() -> Self {
+        Self {
+            window_start: Instant::now(),
+            frame_times: Vec::with_capacity(128),
+            dropped_frames: 0,
+            cpu_cycles: 0,
+        }
+    }
+
+    /// Records one frame that took `host_time` of wall-clock work and ran
+    /// `cpu_cycles` CPU cycles (zero for rewound frames).
+    pub(crate) fn record_frame(&mut self, host_time: Duration, cpu_cycles: u64) {
+        self.frame_times.push(host_time);
+        self.cpu_cycles += cpu_cycles;
+    }
+
+    /// Records frames skipped because the runtime fell behind its deadline.
+    pub(crate) fn record_dropped(&mut self, frames: u32) {
+        self.dropped_frames = self.dropped_frames.saturating_add(frames);
+    }
+
+    /// Closes the current window once it is at least [`STATS_INTERVAL`] old
+    /// and returns its stats; returns `None` otherwise.
+    pub(crate) fn poll(
+        &mut self,
+        now: Instant,
+        audio_fill: Option<f32>,
+    ) -> Option<PerformanceStats> {
+        let elapsed = now.saturating_duration_since(self.window_start);
+        if elapsed < STATS_INTERVAL {
+            return None;
+        }
+
+        let secs = elapsed.as_secs_f64();
+        self.frame_times.sort_unstable();
+        let percentile_ms = |p: usize| {
+            let len = self.frame_times.len();
+            if len == 0 {
+                return 0.0;
+            }
+            self.frame_times[(len - 1) * p / 100].as_secs_f64() * 1000.0
+        };
+        let stats = PerformanceStats {
+            emulated_fps: self.frame_times.len() as f64 / secs,
+            frame_time_p50_ms: percentile_ms(50),
+            frame_time_p95_ms: percentile_ms(95),
+            frame_time_p99_ms: percentile_ms(99),
+            audio_buffer_fill: audio_fill,
+            dropped_frames: self.dropped_frames,
+            cpu_cycles_per_second: (self.cpu_cycles as f64 / secs).round() as u64,
+        };
+
+        self.window_start = now;
+        self.frame_times.clear();
+        self.dropped_frames = 0;
+        self.cpu_cycles = 0;
+        Some(stats)
+    }
+}
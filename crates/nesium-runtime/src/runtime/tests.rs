@@ -0,0 +1,610 @@
+use super::*;
+use nesium_support::tas::{InputFrame, Movie};
+use std::time::{Duration, Instant};
+
+/// NROM image with CHR-RAM whose program is `program` followed by a spin
+/// loop; all vectors point at `$8000`.
+fn nrom(program: &[u8]) -> Vec<u8> {
+    let mut rom = Vec::with_capacity(16 + 16 * 1024);
+    rom.extend_from_slice(b"NES\x1A");
+    rom.push(1); // 16 KiB PRG
+    rom.push(0); // CHR-RAM
+    rom.extend_from_slice(&[0; 10]);
+
+    let mut prg = vec![0xEA; 16 * 1024];
+    prg[..program.len()].copy_from_slice(program);
+    let spin = 0x8000 + program.len() as u16;
+    let [lo, hi] = spin.to_le_bytes();
+    prg[program.len()..program.len() + 3].copy_from_slice(&[0x4C, lo, hi]);
+    for vector in [0x3FFA, 0x3FFC, 0x3FFE] {
+        prg[vector] = 0x00;
+        prg[vector + 1] = 0x80;
+    }
+    rom.extend_from_slice(&prg);
+    rom
+}
+
+/// Headless runtime with a spinning NROM game loaded and paused.
+fn paused_runtime() -> Runtime {
+    let runtime = Runtime::start(RuntimeConfig::headless()).expect("start runtime");
+    let handle = runtime.handle();
+    handle.load_rom_from_memory(nrom(&[])).expect("load rom");
+    handle.pause().expect("pause");
+    runtime
+}
+
+/// Polls `done` until it holds, failing the test after a few seconds.
+fn wait_until(what: &str, mut done: impl FnMut() -> bool) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !done() {
+        assert!(Instant::now() < deadline, "timed out waiting for {what}");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+fn movie(len: usize) -> Movie {
+    Movie {
+        frames: (0..len)
+            .map(|i| InputFrame {
+                ports: [i as u8, 0, 0, 0],
+                ..Default::default()
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// Forwards runtime events into a channel the test reads from.
+struct ChannelSender(Sender<Box<dyn Event>>);
+
+impl RuntimeEventSender for ChannelSender {
+    fn send(&self, event: Box<dyn Event>) -> bool {
+        self.0.send(event).is_ok()
+    }
+}
+
+fn subscribe(handle: &RuntimeHandle, topic: EventTopic) -> Receiver<Box<dyn Event>> {
+    let (tx, rx) = crossbeam_channel::unbounded();
+    handle
+        .subscribe_event(topic, Box::new(ChannelSender(tx)))
+        .expect("subscribe");
+    rx
+}
+
+/// Next event of type `T` on `rx`, skipping any others.
+fn next_event<T: Event>(rx: &Receiver<Box<dyn Event>>) -> T {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        let event: Box<dyn std::any::Any> = rx.recv_deadline(deadline).expect("event");
+        if let Ok(event) = event.downcast::<T>() {
+            return *event;
+        }
+    }
+}
+
+#[test]
+fn movie_playback_starts_at_its_first_frame_mid_session() {
+    let runtime = paused_runtime();
+    let handle = runtime.handle();
+    for _ in 0..5 {
+        handle.advance_frame().expect("advance");
+    }
+
+    let loaded_at = handle.frame_seq();
+    handle.load_movie(movie(600)).expect("load movie");
+    handle.pause().expect("pause");
+
+    // Loading resumes emulation, so some frames may already have played,
+    // but the cursor counts from the load rather than from power-on.
+    let window = handle.upcoming_movie_inputs(4).expect("movie playing");
+    let played = (handle.frame_seq() - loaded_at) as usize;
+    assert_eq!(window.cursor, played);
+    assert_eq!(window.total_frames, 600);
+    assert_eq!(window.frames.len(), 4);
+    assert_eq!(window.frames[0].ports[0], played as u8);
+
+    handle.advance_frame().expect("advance");
+    let window = handle.upcoming_movie_inputs(4).expect("movie playing");
+    assert_eq!(window.cursor, played + 1);
+    assert_eq!(window.frames[0].ports[0], (played + 1) as u8);
+}
+
+#[test]
+fn movie_playback_stops_at_the_end_of_the_movie() {
+    let runtime = paused_runtime();
+    let handle = runtime.handle();
+    handle.load_movie(movie(3)).expect("load movie");
+    handle.pause().expect("pause");
+    for _ in 0..3 {
+        if handle.upcoming_movie_inputs(1).is_none() {
+            break;
+        }
+        handle.advance_frame().expect("advance");
+    }
+    assert!(handle.upcoming_movie_inputs(1).is_none());
+}
+
+#[test]
+fn paused_runtime_only_moves_on_frame_advance() {
+    let runtime = paused_runtime();
+    let handle = runtime.handle();
+    assert!(handle.paused());
+
+    let paused_at = handle.frame_seq();
+    std::thread::sleep(Duration::from_millis(50));
+    assert_eq!(handle.frame_seq(), paused_at);
+
+    handle.advance_frame().expect("advance");
+    assert_eq!(handle.frame_seq(), paused_at + 1);
+    assert!(handle.paused());
+
+    handle.resume().expect("resume");
+    assert!(!handle.paused());
+    wait_until("frames after resume", || handle.frame_seq() > paused_at + 2);
+}
+
+#[test]
+fn runtimes_run_side_by_side() {
+    let first = paused_runtime();
+    let second = paused_runtime();
+    let (a, b) = (first.handle(), second.handle());
+
+    a.advance_frame().expect("advance");
+    let a_state = a.save_state_to_memory().expect("capture");
+    assert_ne!(b.save_state_to_memory().expect("capture"), a_state);
+
+    let paused_at = a.frame_seq();
+    b.resume().expect("resume");
+    let resumed_at = b.frame_seq();
+    wait_until("second runtime frames", || b.frame_seq() > resumed_at + 2);
+    assert_eq!(a.frame_seq(), paused_at);
+
+    drop(second);
+    a.advance_frame().expect("advance after the other stopped");
+    assert_eq!(a.frame_seq(), paused_at + 1);
+}
+
+#[test]
+fn save_slots_round_trip_and_undo_the_load() {
+    let dir = std::env::temp_dir().join(format!("nesium-runtime-slots-{}", std::process::id()));
+    let runtime = paused_runtime();
+    let handle = runtime.handle();
+    handle.set_save_state_dir(Some(dir.clone()));
+
+    handle.save_slot(1).expect("save slot");
+    let saved = handle.save_state_to_memory().expect("capture");
+    for _ in 0..3 {
+        handle.advance_frame().expect("advance");
+    }
+    let before_load = handle.save_state_to_memory().expect("capture");
+    assert_ne!(before_load, saved);
+
+    handle.load_slot(1).expect("load slot");
+    assert_eq!(handle.save_state_to_memory().expect("capture"), saved);
+    handle.undo_load_state().expect("undo load");
+    assert_eq!(handle.save_state_to_memory().expect("capture"), before_load);
+
+    let slots = handle.save_slots();
+    assert_eq!(slots.len(), 1);
+    assert_eq!(slots[0].slot, StateSlot::Numbered(1));
+    assert!(slots[0].thumbnail.is_some());
+
+    drop(runtime);
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[test]
+fn rewind_steps_back_through_interval_snapshots() {
+    let runtime = paused_runtime();
+    let handle = runtime.handle();
+    handle.set_rewind_config(true, 100);
+    handle.set_rewind_interval(2);
+
+    // Snapshots are taken after the 1st, 3rd and 5th frame.
+    let states: Vec<Vec<u8>> = (0..6)
+        .map(|_| {
+            handle.advance_frame().expect("advance");
+            handle.save_state_to_memory().expect("capture")
+        })
+        .collect();
+
+    handle.rewind(true).expect("start rewind");
+    wait_until("the first rewind step", || {
+        handle.save_state_to_memory().expect("capture") != states[5]
+    });
+    handle.rewind(false).expect("stop rewind");
+
+    let rewound = handle.save_state_to_memory().expect("capture");
+    let index = states.iter().position(|s| *s == rewound);
+    assert!(
+        matches!(index, Some(0 | 2 | 4)),
+        "rewound to frame {index:?}"
+    );
+}
+
+#[test]
+fn recording_writes_one_video_frame_per_emulated_frame() {
+    let base = std::env::temp_dir().join(format!("nesium-runtime-rec-{}", std::process::id()));
+    let runtime = paused_runtime();
+    let handle = runtime.handle();
+
+    handle.start_recording(&base).expect("start recording");
+    assert!(handle.is_recording());
+    for _ in 0..3 {
+        handle.advance_frame().expect("advance");
+    }
+    handle.stop_recording().expect("stop recording");
+    assert!(!handle.is_recording());
+
+    let video = std::fs::read(base.with_extension("y4m")).expect("read video");
+    let header_len = video.iter().position(|&b| b == b'\n').expect("header") + 1;
+    assert!(video.starts_with(b"YUV4MPEG2 W256 H240 F39375000:655171"));
+    let frame_len = b"FRAME\n".len() + 256 * 240 * 3;
+    assert_eq!(video.len(), header_len + 3 * frame_len);
+
+    // 96 kHz stereo 16-bit after a 44-byte header: about 1597 samples per
+    // NTSC frame.
+    let audio = std::fs::metadata(base.with_extension("wav")).expect("audio file");
+    let samples = (audio.len() - 44) / 4;
+    assert!(samples.abs_diff(3 * 1597) < 16, "{samples} samples");
+
+    let _ = std::fs::remove_file(base.with_extension("y4m"));
+    let _ = std::fs::remove_file(base.with_extension("wav"));
+}
+
+#[test]
+fn video_filter_resizes_the_output_planes() {
+    let runtime = paused_runtime();
+    let handle = runtime.handle();
+    let frame = handle.frame_handle().expect("owned frame").clone();
+
+    let size = handle
+        .set_video_filter(VideoFilter::Prescale(2))
+        .expect("prescale");
+    assert_eq!(size, (512, 480));
+    handle.advance_frame().expect("advance");
+    assert_eq!((frame.width(), frame.height()), (512, 480));
+    let front = frame.front_slice();
+    assert_eq!(front.len(), 512 * 480 * 4);
+    // Nearest-neighbour 2x: each source pixel fills a 2x2 block.
+    let pitch = frame.pitch_bytes();
+    assert_eq!(front[..8], front[pitch..pitch + 8]);
+    assert_eq!(front[..4], front[4..8]);
+
+    assert!(matches!(
+        handle.set_video_filter(VideoFilter::Xbrz(7)),
+        Err(RuntimeError::InvalidVideoFilter { .. })
+    ));
+    assert_eq!((frame.width(), frame.height()), (512, 480));
+
+    let size = handle
+        .set_video_filter(VideoFilter::None)
+        .expect("no filter");
+    assert_eq!(size, (256, 240));
+    handle.advance_frame().expect("advance");
+    assert_eq!((frame.width(), frame.height()), (256, 240));
+}
+
+#[test]
+fn automatic_audio_latency_follows_callback_jitter() {
+    use crate::audio::{AUTO_LATENCY_MAX, AUTO_LATENCY_MIN, JitterTracker};
+
+    // Steady 5 ms callbacks need less than the floor.
+    let mut steady = JitterTracker::new();
+    let mut now = Instant::now();
+    let mut target = Duration::ZERO;
+    for _ in 0..200 {
+        now += Duration::from_millis(5);
+        target = steady.observe(now, 0.005);
+    }
+    assert_eq!(target, AUTO_LATENCY_MIN);
+
+    // 10 ms callbacks arriving 10 ms early or late: two periods plus four
+    // times the ~10 ms jitter.
+    let mut jittery = JitterTracker::new();
+    for i in 0..400 {
+        now += Duration::from_millis(if i % 2 == 0 { 0 } else { 20 });
+        target = jittery.observe(now, 0.010);
+    }
+    assert!(
+        target > Duration::from_millis(55) && target <= Duration::from_millis(60),
+        "{target:?}"
+    );
+
+    // Huge device buffers are capped.
+    let mut slow = JitterTracker::new();
+    assert_eq!(slow.observe(now, 0.5), AUTO_LATENCY_MAX);
+}
+
+#[test]
+fn runtime_runs_with_a_custom_audio_latency_config() {
+    use crate::audio::{AudioLatency, AudioLatencyConfig, UnderrunStrategy};
+
+    // Without an output device the runtime falls back to running silently.
+    let runtime = Runtime::start(RuntimeConfig {
+        audio: AudioMode::Auto,
+        audio_latency: AudioLatencyConfig {
+            latency: AudioLatency::Fixed(Duration::from_millis(40)),
+            buffer_frames: Some(256),
+            underrun: UnderrunStrategy::RepeatLast,
+        },
+        ..RuntimeConfig::headless()
+    })
+    .expect("start runtime");
+    let handle = runtime.handle();
+    handle.load_rom_from_memory(nrom(&[])).expect("load rom");
+    let start = handle.frame_seq();
+    wait_until("frames", || handle.frame_seq() > start + 2);
+}
+
+#[test]
+fn dynamic_rate_control_centres_the_audio_queue() {
+    use super::runner::rate_control_step;
+
+    let settle = |fill: f32| {
+        let mut estimate = 0.5;
+        let mut ratio = 1.0;
+        for _ in 0..200 {
+            ratio = rate_control_step(&mut estimate, fill);
+        }
+        ratio
+    };
+    assert!((settle(0.5) - 1.0).abs() < 1e-9);
+    // A full queue compresses and an empty one stretches, by at most 0.5%.
+    assert!((settle(1.0) - 1.005).abs() < 1e-6);
+    assert!((settle(0.0) - 0.995).abs() < 1e-6);
+
+    // Single readings are smoothed rather than followed.
+    let mut estimate = 0.5;
+    let ratio = rate_control_step(&mut estimate, 1.0);
+    assert!(ratio > 1.0 && ratio < 1.001, "{ratio}");
+
+    let runtime = paused_runtime();
+    let handle = runtime.handle();
+    assert!(handle.dynamic_rate_control());
+    handle.set_dynamic_rate_control(false);
+    assert!(!handle.dynamic_rate_control());
+}
+
+#[test]
+fn headless_runtime_publishes_frames() {
+    let runtime = paused_runtime();
+    let handle = runtime.handle();
+    let frames = subscribe(&handle, EventTopic::Frame);
+
+    handle.advance_frame().expect("advance");
+    let frame: FrameEvent = next_event(&frames);
+    assert_eq!(frame.frame_seq, handle.frame_seq());
+    assert_eq!(frame.rgb.len(), 256 * 240 * 3);
+
+    handle.advance_frame().expect("advance");
+    assert_eq!(
+        next_event::<FrameEvent>(&frames).frame_seq,
+        frame.frame_seq + 1
+    );
+
+    handle
+        .unsubscribe_event(EventTopic::Frame)
+        .expect("unsubscribe");
+    handle.advance_frame().expect("advance");
+    assert!(frames.try_recv().is_err());
+}
+
+#[test]
+fn storage_events_fall_back_to_the_notification_topic() {
+    let path =
+        std::env::temp_dir().join(format!("nesium-runtime-topic-{}.sav", std::process::id()));
+    let runtime = paused_runtime();
+    let handle = runtime.handle();
+    let notifications = subscribe(&handle, EventTopic::Notification);
+    let storage = subscribe(&handle, EventTopic::Storage);
+
+    handle.save_state(&path).expect("save state");
+    match next_event::<NotificationEvent>(&storage) {
+        NotificationEvent::StateFileSaved { path: saved } => assert_eq!(saved, path),
+        other => panic!("unexpected {other:?}"),
+    }
+    assert!(notifications.try_recv().is_err());
+
+    handle
+        .unsubscribe_event(EventTopic::Storage)
+        .expect("unsubscribe");
+    handle.load_state(&path).expect("load state");
+    match next_event::<NotificationEvent>(&notifications) {
+        NotificationEvent::StateFileLoaded { path: loaded } => assert_eq!(loaded, path),
+        other => panic!("unexpected {other:?}"),
+    }
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn filtered_subscriptions_only_see_accepted_events() {
+    let runtime = paused_runtime();
+    let handle = runtime.handle();
+    let (tx, stats) = crossbeam_channel::unbounded();
+    handle
+        .subscribe_event_filtered(
+            EventTopic::FrameStats,
+            Box::new(ChannelSender(tx)),
+            Box::new(|event| {
+                let event: &dyn std::any::Any = event;
+                event
+                    .downcast_ref::<FrameStats>()
+                    .is_some_and(|stats| stats.frame_seq % 2 == 0)
+            }),
+        )
+        .expect("subscribe");
+
+    handle.resume().expect("resume");
+    let seen: Vec<u64> = (0..3)
+        .map(|_| next_event::<FrameStats>(&stats).frame_seq)
+        .collect();
+    assert!(seen.iter().all(|seq| seq % 2 == 0), "{seen:?}");
+    assert!(
+        seen.windows(2).all(|pair| pair[1] == pair[0] + 2),
+        "{seen:?}"
+    );
+}
+
+/// Program that waits for the PPU to warm up, sets the backdrop colour and
+/// then spins, leaving the whole picture that colour.
+fn backdrop_program(color: u8) -> Vec<u8> {
+    vec![
+        0x2C, 0x02, 0x20, // BIT $2002
+        0x10, 0xFB, // BPL $8000
+        0x2C, 0x02, 0x20, // BIT $2002
+        0x10, 0xFB, // BPL $8005
+        0xA9, 0x3F, 0x8D, 0x06, 0x20, // LDA #$3F / STA $2006
+        0xA9, 0x00, 0x8D, 0x06, 0x20, // LDA #$00 / STA $2006
+        0xA9, color, 0x8D, 0x07, 0x20, // LDA #color / STA $2007
+        0xA9, 0x00, 0x8D, 0x06, 0x20, 0x8D, 0x06, 0x20, // point VRAM away from the palette
+    ]
+}
+
+/// Colour of the top-left pixel of the next published frame.
+fn next_backdrop(frames: &Receiver<Box<dyn Event>>) -> [u8; 3] {
+    let frame: FrameEvent = next_event(frames);
+    [frame.rgb[0], frame.rgb[1], frame.rgb[2]]
+}
+
+/// `state_restored` of the next [`NotificationEvent::RomReloaded`], skipping
+/// the load notifications before it.
+fn next_reload(notifications: &Receiver<Box<dyn Event>>) -> bool {
+    loop {
+        if let NotificationEvent::RomReloaded { state_restored } = next_event(notifications) {
+            return state_restored;
+        }
+    }
+}
+
+#[test]
+fn reload_rom_keeps_or_drops_the_running_state() {
+    let path =
+        std::env::temp_dir().join(format!("nesium-runtime-reload-{}.nes", std::process::id()));
+    std::fs::write(&path, nrom(&backdrop_program(0x16))).expect("write rom");
+
+    let runtime = Runtime::start(RuntimeConfig::headless()).expect("start runtime");
+    let handle = runtime.handle();
+    handle.load_rom(&path).expect("load rom");
+    let notifications = subscribe(&handle, EventTopic::Notification);
+    let frames = subscribe(&handle, EventTopic::Frame);
+    let first = next_backdrop(&frames);
+    wait_until("the backdrop to be set", || next_backdrop(&frames) != first);
+    let red = next_backdrop(&frames);
+
+    // The rebuilt ROM would pick another colour, but with the state carried
+    // over its setup code never runs again.
+    std::fs::write(&path, nrom(&backdrop_program(0x12))).expect("rewrite rom");
+    handle.reload_rom(RomReloadMode::KeepState).expect("reload");
+    assert!(next_reload(&notifications));
+    while frames.try_recv().is_ok() {}
+    for _ in 0..5 {
+        assert_eq!(next_backdrop(&frames), red);
+    }
+
+    handle
+        .reload_rom(RomReloadMode::PowerCycle)
+        .expect("reload");
+    assert!(!next_reload(&notifications));
+    wait_until("the new backdrop", || {
+        let color = next_backdrop(&frames);
+        color != red && color != first
+    });
+
+    // ROMs loaded from memory have no file to reload.
+    handle.load_rom_from_memory(nrom(&[])).expect("load rom");
+    assert!(matches!(
+        handle.reload_rom(RomReloadMode::KeepState),
+        Err(RuntimeError::RomNotReloadable)
+    ));
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn emergency_saves_are_announced_and_offered_on_the_next_load() {
+    use super::{
+        control::ControlMessage, pubsub::RuntimePubSub, runner::Runner, state::RuntimeState,
+    };
+    use crate::audio::AudioLatencyConfig;
+    use nesium_core::{
+        config::{overclock::Overclock, ram_init::RamInit},
+        ppu::buffer::{ColorFormat, FrameBuffer},
+    };
+
+    let dir = std::env::temp_dir().join(format!("nesium-runtime-emergency-{}", std::process::id()));
+    let rom = nrom(&[]);
+
+    // Drive a runner on this thread so the emergency save can be triggered
+    // the way the panic and signal paths do, after the run loop has exited.
+    let (ctrl_tx, ctrl_rx) = crossbeam_channel::unbounded();
+    let (event_tx, events) = crossbeam_channel::unbounded();
+    let mut pubsub = RuntimePubSub::new();
+    pubsub.subscribe(
+        EventTopic::Notification,
+        Box::new(ChannelSender(event_tx)),
+        None,
+    );
+    let state = std::sync::Arc::new(RuntimeState::new());
+    *state.save_state_dir.lock() = Some(dir.clone());
+    let mut runner = Runner::new(
+        AudioMode::Disabled,
+        AudioLatencyConfig::default(),
+        Overclock::default(),
+        RamInit::default(),
+        ctrl_rx,
+        ctrl_tx.clone(),
+        pubsub,
+        FrameBuffer::new(ColorFormat::Rgba8888),
+        state,
+    );
+    let (reply_tx, reply_rx) = crossbeam_channel::bounded(1);
+    ctrl_tx
+        .send(ControlMessage::LoadRomFromMemory(rom.clone(), reply_tx))
+        .expect("send load");
+    ctrl_tx.send(ControlMessage::Stop).expect("send stop");
+    runner.run();
+    reply_rx.recv().expect("load reply").expect("load rom");
+
+    runner.emergency_save("terminated by signal 15");
+    let saved = loop {
+        if let NotificationEvent::EmergencySaved {
+            reason,
+            state_saved,
+            ..
+        } = next_event(&events)
+        {
+            break (reason, state_saved);
+        }
+    };
+    assert_eq!(saved, ("terminated by signal 15".to_string(), true));
+    drop(runner);
+
+    // The next session offers the state, and closing it cleanly drops it.
+    let runtime = Runtime::start(RuntimeConfig::headless()).expect("start runtime");
+    let handle = runtime.handle();
+    handle.set_save_state_dir(Some(dir.clone()));
+    let notifications = subscribe(&handle, EventTopic::Notification);
+    handle.load_rom_from_memory(rom.clone()).expect("load rom");
+    loop {
+        if let NotificationEvent::EmergencyStateAvailable { .. } = next_event(&notifications) {
+            break;
+        }
+    }
+    assert!(
+        handle
+            .save_slots()
+            .iter()
+            .any(|info| info.slot == StateSlot::Emergency)
+    );
+    handle.load_rom_from_memory(rom).expect("load rom again");
+    assert!(
+        handle
+            .save_slots()
+            .iter()
+            .all(|info| info.slot != StateSlot::Emergency)
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -1,10 +1,22 @@
-use std::{any::Any, path::PathBuf, time::Duration};
+use std::{
+    any::Any,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
 
-use nesium_core::cartridge::header::Mirroring;
+use nesium_core::cartridge::{CartridgeInfo, LoadStage, LoadWarning, header::Mirroring};
+use nesium_core::config::{overclock::Overclock, ram_init::RamInit};
+use nesium_core::controller::ButtonRemap;
 use nesium_core::ppu::{
     SCREEN_HEIGHT, SCREEN_WIDTH,
     buffer::{ColorFormat, SwapchainLockCallback, SwapchainUnlockCallback},
 };
+use nesium_support::tas::InputFrame;
+
+use super::replay_check::ReplayOutcome;
+use super::slots::StateSlot;
+use crate::audio::AudioLatencyConfig;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AudioMode {
@@ -51,6 +63,51 @@ impl Default for VideoConfig {
 pub struct RuntimeConfig {
     pub video: VideoConfig,
     pub audio: AudioMode,
+    /// Output queue depth, device buffer size and underrun behaviour; ignored
+    /// when audio is disabled.
+    pub audio_latency: AudioLatencyConfig,
+    /// Initial overclock; change it later with `RuntimeHandle::set_overclock`.
+    pub overclock: Overclock,
+    /// Power-on RAM pattern for ROMs without a [`GameProfile`] override.
+    pub ram_init: RamInit,
+}
+
+impl RuntimeConfig {
+    /// Configuration for machines without an audio or video stack: no audio
+    /// device is opened and frames go to an owned framebuffer, read through
+    /// the frame-ready callback or [`EventTopic::Frame`].
+    pub fn headless() -> Self {
+        Self {
+            video: VideoConfig::default(),
+            audio: AudioMode::Disabled,
+            audio_latency: AudioLatencyConfig::default(),
+            overclock: Overclock::default(),
+            ram_init: RamInit::default(),
+        }
+    }
+}
+
+/// Per-ROM settings, registered with `RuntimeHandle::set_game_profile` and
+/// applied whenever a ROM with the matching hash is loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GameProfile {
+    /// Overrides [`RuntimeConfig::ram_init`] for this game.
+    pub ram_init: Option<RamInit>,
+    /// Overrides the remaps set with `RuntimeHandle::set_button_remap`
+    /// while this game is loaded, indexed by pad.
+    pub button_remaps: Option<[ButtonRemap; 4]>,
+}
+
+/// How `RuntimeHandle::reload_rom` treats the running game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RomReloadMode {
+    /// Boot the reloaded ROM from power-on.
+    #[default]
+    PowerCycle,
+    /// Capture the machine state before reloading and restore it into the
+    /// new build, so iteration can continue from the same spot. The state is
+    /// only restored if the mapper is unchanged.
+    KeepState,
 }
 
 pub trait Event: Any + Send + Sync + std::fmt::Debug {}
@@ -60,6 +117,69 @@ pub enum NotificationEvent {
     /// Out-of-band notification emitted by the runtime thread (not a direct response
     /// to a control command).
     AudioInitFailed { error: String },
+    /// A ROM load entered the given stage.
+    RomLoadProgress { stage: LoadStage },
+    /// The ROM loaded (or is loading) with a non-fatal issue.
+    RomLoadWarning { warning: LoadWarning },
+    /// A ROM load failed; `stage` tells frontends which step rejected it.
+    RomLoadFailed { stage: LoadStage, error: String },
+    /// A ROM finished loading; `info` describes the inserted cartridge.
+    CartridgeLoaded { info: Box<CartridgeInfo> },
+    /// `RuntimeHandle::reload_rom` reloaded the ROM from disk.
+    /// `state_restored` is set when the previous state carried over.
+    RomReloaded { state_restored: bool },
+    /// The CPU hit a JAM (KIL) opcode and stays halted until reset. Sent once
+    /// per jam; `disassembly` lists the code around `pc`, one instruction per
+    /// line. `paused` is set when the runtime auto-paused emulation.
+    CpuJammed {
+        pc: u16,
+        opcode: u8,
+        disassembly: Vec<String>,
+        paused: bool,
+    },
+    /// The Rainbow WiFi bridge socket failed and was closed.
+    RainbowBridgeClosed { error: String },
+    /// A slot was written; `thumbnail` is the RGBA8888 image stored with it.
+    StateSaved {
+        slot: StateSlot,
+        thumbnail: Arc<[u8]>,
+    },
+    /// A slot was loaded; the previous state can be restored with
+    /// `RuntimeHandle::undo_load_state`.
+    StateLoaded { slot: StateSlot },
+    /// The state from before the last load was restored.
+    StateLoadUndone,
+    /// A screenshot requested with `RuntimeHandle::capture_screenshot` was
+    /// written to `path`.
+    ScreenshotSaved { path: PathBuf },
+    /// Writing the screenshot to `path` failed.
+    ScreenshotFailed { path: PathBuf, error: String },
+    /// The runtime thread panicked or the process was terminated by a signal,
+    /// and the current game was saved on the way down. The emergency state
+    /// can be restored with `RuntimeHandle::load_emergency_state`.
+    EmergencySaved {
+        reason: String,
+        battery_saved: bool,
+        state_saved: bool,
+    },
+    /// The game just loaded has an emergency state from a session that did
+    /// not end cleanly; frontends can offer to restore it.
+    EmergencyStateAvailable { modified: SystemTime },
+    /// The game changed its battery RAM and the save file was rewritten.
+    BatteryRamSaved { bytes: usize },
+    /// `RuntimeHandle::save_state` finished writing `path`.
+    StateFileSaved { path: PathBuf },
+    /// `RuntimeHandle::load_state` restored `path`.
+    StateFileLoaded { path: PathBuf },
+    /// A replay check started with `RuntimeHandle::start_replay_check`
+    /// finished; the movie is stopped either way.
+    ReplayCheckFinished { outcome: ReplayOutcome },
+    /// Background work with no caller to report to failed (battery saves,
+    /// autosave, finishing a recording).
+    OperationFailed {
+        operation: &'static str,
+        error: String,
+    },
 }
 
 impl Event for NotificationEvent {}
@@ -373,10 +493,59 @@ pub struct EmulationStatus {
     pub paused: bool,
     pub rewinding: bool,
     pub fast_forwarding: bool,
+    pub slow_motion: bool,
 }
 
 impl Event for EmulationStatus {}
 
+/// Runtime performance over the last second, published on
+/// [`EventTopic::PerformanceStats`] for frontend HUDs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PerformanceStats {
+    /// Frames emulated (or rewound) per wall-clock second.
+    pub emulated_fps: f64,
+    /// Host time spent running a frame, in milliseconds, by percentile.
+    pub frame_time_p50_ms: f64,
+    pub frame_time_p95_ms: f64,
+    pub frame_time_p99_ms: f64,
+    /// Fraction of the audio output buffer holding queued samples, in
+    /// `[0.0, 1.0]`. `None` when audio output is disabled.
+    pub audio_buffer_fill: Option<f32>,
+    /// Frames skipped because the runtime fell behind its frame deadline.
+    pub dropped_frames: u32,
+    /// Emulated CPU cycles per wall-clock second.
+    pub cpu_cycles_per_second: u64,
+}
+
+impl Event for PerformanceStats {}
+
+/// A presented frame as raw 256x240 RGB888, published on [`EventTopic::Frame`]
+/// so consumers without a framebuffer (headless servers, tests) can read the
+/// picture. Frames dropped by fast-forward frame skip are not published.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameEvent {
+    /// Frame counter after this frame, as reported by `RuntimeHandle::frame_seq`.
+    pub frame_seq: u64,
+    pub rgb: Arc<[u8]>,
+}
+
+impl Event for FrameEvent {}
+
+/// Timing of one emulated (or rewound) frame, published on
+/// [`EventTopic::FrameStats`]; [`PerformanceStats`] is the per-second rollup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameStats {
+    pub frame_seq: u64,
+    /// Host time spent running the frame.
+    pub host_time: Duration,
+    /// CPU cycles emulated (zero for rewound frames).
+    pub cpu_cycles: u64,
+    /// `false` when fast-forward frame skip dropped the frame.
+    pub presented: bool,
+}
+
+impl Event for FrameStats {}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ReplayEvent {
     QuickSave,
@@ -385,6 +554,18 @@ pub enum ReplayEvent {
 
 impl Event for ReplayEvent {}
 
+/// Window of TAS inputs starting at the playback cursor, for piano-roll / input
+/// overlay rendering during movie replay.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MovieInputWindow {
+    /// Movie frame index applied on the next emulated frame.
+    pub cursor: usize,
+    /// Total number of frames in the movie.
+    pub total_frames: usize,
+    /// Inputs for `cursor..`; shorter than requested near the end of the movie.
+    pub frames: Vec<InputFrame>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EventTopic {
     Notification,
@@ -395,12 +576,42 @@ pub enum EventTopic {
     Palette,
     EmulationStatus,
     Replay,
+    PerformanceStats,
+    Frame,
+    /// Battery RAM writes and save-state completion ([`NotificationEvent`]).
+    Storage,
+    /// Failures reported outside of a command reply ([`NotificationEvent`]).
+    Error,
+    /// Per-frame [`FrameStats`].
+    FrameStats,
 }
 
 impl NotificationEvent {
+    /// Topic the event is published on. Events whose topic has no subscriber
+    /// fall back to [`EventTopic::Notification`], so a single notification
+    /// subscriber still sees everything.
     pub fn topic(&self) -> EventTopic {
         match self {
-            NotificationEvent::AudioInitFailed { .. } => EventTopic::Notification,
+            NotificationEvent::RomLoadProgress { .. }
+            | NotificationEvent::RomLoadWarning { .. }
+            | NotificationEvent::CartridgeLoaded { .. }
+            | NotificationEvent::RomReloaded { .. }
+            | NotificationEvent::ReplayCheckFinished { .. }
+            | NotificationEvent::CpuJammed { .. }
+            | NotificationEvent::RainbowBridgeClosed { .. }
+            | NotificationEvent::ScreenshotSaved { .. } => EventTopic::Notification,
+            NotificationEvent::StateSaved { .. }
+            | NotificationEvent::StateLoaded { .. }
+            | NotificationEvent::StateLoadUndone
+            | NotificationEvent::EmergencySaved { .. }
+            | NotificationEvent::EmergencyStateAvailable { .. }
+            | NotificationEvent::BatteryRamSaved { .. }
+            | NotificationEvent::StateFileSaved { .. }
+            | NotificationEvent::StateFileLoaded { .. } => EventTopic::Storage,
+            NotificationEvent::AudioInitFailed { .. }
+            | NotificationEvent::RomLoadFailed { .. }
+            | NotificationEvent::ScreenshotFailed { .. }
+            | NotificationEvent::OperationFailed { .. } => EventTopic::Error,
         }
     }
 }
@@ -419,16 +630,42 @@ pub enum RuntimeError {
     UnsupportedIntegerFpsTarget { fps: u32 },
     #[error("failed to load ROM: {path}: {error}")]
     LoadRomFailed { path: PathBuf, error: String },
-    #[error("palette blobs must be 192 or 256 bytes (got {actual})")]
+    #[error("palette blobs must be 192, 256, 1536 or 2048 bytes (got {actual})")]
     InvalidPaletteSize { actual: usize },
     #[error("invalid palette data: {error}")]
     InvalidPaletteData { error: String },
     #[error("failed to load palette: {path}: {error}")]
     LoadPaletteFailed { path: PathBuf, error: String },
+    #[error("unknown palette id {id}")]
+    UnknownPalette { id: usize },
     #[error("failed to save state: {path}: {error}")]
     SaveStateFailed { path: PathBuf, error: String },
     #[error("failed to load state: {path}: {error}")]
     LoadStateFailed { path: PathBuf, error: String },
+    #[error("failed to connect Rainbow bridge to {addr}: {error}")]
+    RainbowBridgeFailed { addr: String, error: String },
+    #[error("failed to insert barcode: {error}")]
+    InsertBarcodeFailed { error: String },
+    #[error("no ROM is loaded")]
+    NoRomLoaded,
+    #[error("save state slot {slot} is out of range")]
+    InvalidSaveSlot { slot: u8 },
+    #[error("no save state directory is configured")]
+    NoSaveStateDir,
+    #[error("there is no state load to undo")]
+    NothingToUndo,
+    #[error("failed to save screenshot: {path}: {error}")]
+    ScreenshotFailed { path: PathBuf, error: String },
+    #[error("recording to {path} failed: {error}")]
+    RecordingFailed { path: PathBuf, error: String },
+    #[error("failed to spawn the runtime thread: {error}")]
+    SpawnFailed { error: String },
+    #[error("unsupported video filter: {filter}")]
+    InvalidVideoFilter { filter: String },
+    #[error("the loaded ROM was not read from a file and cannot be reloaded")]
+    RomNotReloadable,
+    #[error("no replay recording is in progress")]
+    NotRecordingReplay,
 }
 
 pub(crate) const NTSC_FPS_EXACT: f64 = 60.098_811_862_348_4;
@@ -436,6 +673,10 @@ pub(crate) const CONTROL_REPLY_TIMEOUT: Duration = Duration::from_secs(2);
 pub(crate) const LOAD_ROM_REPLY_TIMEOUT: Duration = Duration::from_secs(10);
 pub(crate) const SAVE_STATE_REPLY_TIMEOUT: Duration = Duration::from_secs(5);
 
+/// Per-subscription predicate: events it rejects are not sent to the
+/// subscriber. Downcast through `&dyn Any` to inspect the event.
+pub type EventFilter = Box<dyn Fn(&dyn Event) -> bool + Send + Sync>;
+
 pub trait RuntimeEventSender: Send + Sync + 'static {
     fn send(&self, event: Box<dyn Event>) -> bool;
 }
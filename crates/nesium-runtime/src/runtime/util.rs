@@ -3,12 +3,14 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use tracing;
 
 #[cfg(target_os = "android")]
-use std::sync::atomic::AtomicI32;
+use parking_lot::Mutex;
 
 static HIGH_PRIORITY_ENABLED: AtomicBool = AtomicBool::new(false);
 
+/// Thread ids of every live runtime thread, so a priority change reaches all
+/// instances.
 #[cfg(target_os = "android")]
-static RUNTIME_THREAD_TID: AtomicI32 = AtomicI32::new(-1);
+static RUNTIME_THREAD_TIDS: Mutex<Vec<i32>> = Mutex::new(Vec::new());
 
 #[cfg(any(target_os = "macos", target_os = "ios"))]
 const QOS_CLASS_USER_INTERACTIVE: u32 = 0x21;
@@ -25,11 +27,8 @@ pub fn set_high_priority_enabled(enabled: bool) {
     HIGH_PRIORITY_ENABLED.store(enabled, Ordering::Release);
 
     #[cfg(target_os = "android")]
-    {
-        let tid = RUNTIME_THREAD_TID.load(Ordering::Acquire);
-        if tid > 0 {
-            try_set_thread_nice(tid, if enabled { -2 } else { 0 });
-        }
+    for &tid in RUNTIME_THREAD_TIDS.lock().iter() {
+        try_set_thread_nice(tid, if enabled { -2 } else { 0 });
     }
 
     #[cfg(not(target_os = "android"))]
@@ -57,7 +56,10 @@ pub(crate) fn apply_priority_to_current_thread(enabled: bool) {
     #[cfg(target_os = "android")]
     {
         let tid = unsafe { libc::gettid() as i32 };
-        RUNTIME_THREAD_TID.store(tid, Ordering::Release);
+        let mut tids = RUNTIME_THREAD_TIDS.lock();
+        if !tids.contains(&tid) {
+            tids.push(tid);
+        }
         try_set_thread_nice(tid, if enabled { -2 } else { 0 });
     }
 
@@ -167,6 +169,15 @@ pub(crate) fn try_raise_current_thread_priority() {
     apply_priority_to_current_thread(is_high_priority_enabled());
 }
 
+/// Called as a runtime thread exits so later priority changes skip it.
+pub(crate) fn forget_current_runtime_thread() {
+    #[cfg(target_os = "android")]
+    {
+        let tid = unsafe { libc::gettid() as i32 };
+        RUNTIME_THREAD_TIDS.lock().retain(|&t| t != tid);
+    }
+}
+
 #[cfg(target_os = "android")]
 fn try_set_thread_nice(tid: i32, nice: i32) {
     let nice = nice.clamp(-20, 19) as libc::c_int;
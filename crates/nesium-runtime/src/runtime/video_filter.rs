@@ -0,0 +1,115 @@
+//! Built-in CPU video filters, run by the runtime as the frame post-processor.
+
+use nesium_core::ppu::{
+    SCREEN_HEIGHT, SCREEN_WIDTH,
+    buffer::{NearestPostProcessor, VideoPostProcessor},
+};
+use nesium_support::video::filters::{
+    HqxPostProcessor, LcdGridPostProcessor, NesNtscPostProcessor, NtscBisqwitPostProcessor,
+    SaiPostProcessor, ScanlinePostProcessor, XbrzPostProcessor,
+};
+pub use nesium_support::video::filters::{NesNtscTuning, NtscBisqwitOptions, SaiVariant};
+use nesium_support::video::ntsc::nes_ntsc_out_width;
+pub use nesium_support::video::{hqx::HqxScale, ntsc::NesNtscPreset};
+
+use super::types::RuntimeError;
+
+/// Largest integer scale accepted by [`VideoFilter::Prescale`].
+pub const PRESCALE_MAX: u8 = 8;
+
+/// Video filter applied to every presented frame, passed to
+/// `RuntimeHandle::set_video_filter`.
+///
+/// The runtime sizes the packed output planes from the filter, so frontends
+/// only need to follow the reported output size.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum VideoFilter {
+    /// Canonical 256x240 output.
+    #[default]
+    None,
+    /// Nearest-neighbour integer upscale (`1..=PRESCALE_MAX`).
+    Prescale(u8),
+    Hqx(HqxScale),
+    /// 2xSaI family, always 2x.
+    Sai(SaiVariant),
+    /// Blargg's `nes_ntsc`; the output is wider than 2x and double height.
+    Ntsc {
+        preset: NesNtscPreset,
+        tuning: NesNtscTuning,
+    },
+    /// 2x LCD grid; `strength` in `0.0..=1.0`.
+    LcdGrid {
+        strength: f64,
+    },
+    /// 2x scanlines; `intensity` in `0.0..=1.0`.
+    Scanlines {
+        intensity: f64,
+    },
+    /// xBRZ, `2..=6`.
+    Xbrz(u8),
+    /// Bisqwit's NTSC decoder at 2x, 4x or 8x.
+    NtscBisqwit {
+        scale: u8,
+        options: NtscBisqwitOptions,
+    },
+}
+
+impl VideoFilter {
+    /// Size of the packed output planes the filter renders into.
+    pub fn output_size(&self) -> Result<(u32, u32), RuntimeError> {
+        let (width, height) = match *self {
+            VideoFilter::None => (SCREEN_WIDTH, SCREEN_HEIGHT),
+            VideoFilter::Ntsc { .. } => (nes_ntsc_out_width(SCREEN_WIDTH), SCREEN_HEIGHT * 2),
+            _ => {
+                let scale = self.scale() as usize;
+                (SCREEN_WIDTH * scale, SCREEN_HEIGHT * scale)
+            }
+        };
+        let invalid = || RuntimeError::InvalidVideoFilter {
+            filter: format!("{self:?}"),
+        };
+        let valid = match *self {
+            VideoFilter::Prescale(scale) => (1..=PRESCALE_MAX).contains(&scale),
+            VideoFilter::Xbrz(scale) => (2..=6).contains(&scale),
+            VideoFilter::NtscBisqwit { scale, .. } => matches!(scale, 2 | 4 | 8),
+            _ => true,
+        };
+        if !valid {
+            return Err(invalid());
+        }
+        Ok((
+            width.try_into().map_err(|_| invalid())?,
+            height.try_into().map_err(|_| invalid())?,
+        ))
+    }
+
+    fn scale(&self) -> u8 {
+        match *self {
+            VideoFilter::None | VideoFilter::Ntsc { .. } => 1,
+            VideoFilter::Prescale(scale)
+            | VideoFilter::Xbrz(scale)
+            | VideoFilter::NtscBisqwit { scale, .. } => scale,
+            VideoFilter::Hqx(scale) => scale as u8,
+            VideoFilter::Sai(_) | VideoFilter::LcdGrid { .. } | VideoFilter::Scanlines { .. } => 2,
+        }
+    }
+
+    pub(crate) fn build_processor(&self) -> Box<dyn VideoPostProcessor> {
+        match *self {
+            VideoFilter::None | VideoFilter::Prescale(_) => Box::new(NearestPostProcessor),
+            VideoFilter::Hqx(scale) => Box::new(HqxPostProcessor::new(scale)),
+            VideoFilter::Sai(variant) => Box::new(SaiPostProcessor::new(variant)),
+            VideoFilter::Ntsc { preset, tuning } => {
+                Box::new(NesNtscPostProcessor::new_with_tuning(preset, tuning))
+            }
+            VideoFilter::LcdGrid { strength } => Box::new(LcdGridPostProcessor::new(strength)),
+            VideoFilter::Scanlines { intensity } => {
+                Box::new(ScanlinePostProcessor::new(2, intensity))
+            }
+            VideoFilter::Xbrz(scale) => Box::new(XbrzPostProcessor::new(scale)),
+            VideoFilter::NtscBisqwit { scale, options } => {
+                Box::new(NtscBisqwitPostProcessor::new(scale, options))
+            }
+        }
+    }
+}
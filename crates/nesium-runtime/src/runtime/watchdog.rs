@@ -0,0 +1,106 @@
+//! Crash watchdog: gives runtime threads a chance to write battery RAM and an
+//! emergency save state before the process goes down.
+
+use std::sync::atomic::{AtomicI32, AtomicUsize, Ordering};
+
+/// Signal that requested termination, or 0.
+static TERMINATION_SIGNAL: AtomicI32 = AtomicI32::new(0);
+/// Runtime threads that haven't finished yet.
+static LIVE_RUNNERS: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "linux"
+))]
+const TERMINATION_SIGNALS: [libc::c_int; 3] = [libc::SIGTERM, libc::SIGINT, libc::SIGHUP];
+
+/// Installs SIGTERM/SIGINT/SIGHUP handlers so every running runtime flushes
+/// battery RAM and writes an emergency save state before the process exits.
+/// The signal is re-raised with its default action once all runtime threads
+/// are done.
+///
+/// This is process-wide and opt-in because it replaces any handlers the
+/// frontend's toolkit installed. Panics on the runtime thread are covered
+/// without it. No-op on platforms without POSIX signals.
+pub fn install_termination_watchdog() {
+    #[cfg(any(
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "linux"
+    ))]
+    for signal in TERMINATION_SIGNALS {
+        let handler = on_termination_signal as extern "C" fn(libc::c_int);
+        // SAFETY: the handler only touches atomics and calls the
+        // async-signal-safe `signal` and `raise`.
+        unsafe {
+            libc::signal(signal, handler as libc::sighandler_t);
+        }
+    }
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "linux"
+))]
+extern "C" fn on_termination_signal(signal: libc::c_int) {
+    TERMINATION_SIGNAL.store(signal, Ordering::SeqCst);
+    if LIVE_RUNNERS.load(Ordering::SeqCst) == 0 {
+        reraise(signal);
+    }
+}
+
+/// Restores the default action for `signal` and raises it again.
+fn reraise(signal: i32) {
+    #[cfg(any(
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "linux"
+    ))]
+    // SAFETY: both calls are async-signal-safe.
+    unsafe {
+        libc::signal(signal, libc::SIG_DFL);
+        libc::raise(signal);
+    }
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "linux"
+    )))]
+    let _ = signal;
+}
+
+/// The termination signal caught by the watchdog, if any.
+pub(crate) fn termination_requested() -> Option<i32> {
+    match TERMINATION_SIGNAL.load(Ordering::SeqCst) {
+        0 => None,
+        signal => Some(signal),
+    }
+}
+
+/// Held by each runtime thread while it runs. The last one to finish after a
+/// termination signal lets the signal take its course.
+pub(crate) struct RunnerGuard(());
+
+impl RunnerGuard {
+    pub(crate) fn new() -> Self {
+        LIVE_RUNNERS.fetch_add(1, Ordering::SeqCst);
+        Self(())
+    }
+}
+
+impl Drop for RunnerGuard {
+    fn drop(&mut self) {
+        if LIVE_RUNNERS.fetch_sub(1, Ordering::SeqCst) == 1
+            && let Some(signal) = termination_requested()
+        {
+            reraise(signal);
+        }
+    }
+}
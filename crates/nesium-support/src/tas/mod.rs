@@ -1,6 +1,11 @@
 pub mod fm2;
 
 use bitflags::bitflags;
+use nesium_core::{
+    config::ram_init::RamInit,
+    movie::{InputMovie, MovieFrame},
+    reset_kind::ResetKind,
+};
 
 /// Unified internal Movie IR used to drive the emulator.
 /// It is decoupled from specific file formats (FM2, BK2, etc.).
@@ -22,6 +27,43 @@ pub struct Movie {
     pub savestate: Option<Vec<u8>>,
 }
 
+impl Movie {
+    /// Wraps a recording made with `Nes::start_movie_recording`. Format
+    /// metadata is left empty.
+    pub fn from_recording(recording: &InputMovie) -> Self {
+        Self {
+            frames: recording.frames.iter().copied().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Converts the input frames for `Nes::play_movie`.
+    ///
+    /// FDS and VS System commands have no core equivalent and are dropped.
+    pub fn to_recording(&self) -> InputMovie {
+        InputMovie {
+            ram_init: self.ram_init(),
+            frames: self.frames.iter().copied().map(Into::into).collect(),
+        }
+    }
+
+    /// Power-on RAM pattern requested by the movie header.
+    fn ram_init(&self) -> RamInit {
+        match &self.data {
+            // FCEUX `RAMInitOption`: 0 = default pattern, 1 = $FF, 2 = $00, 3 = random.
+            TasData::Fm2(header) => match header.ram_init_option {
+                1 => RamInit::Ones,
+                2 => RamInit::Zeros,
+                3 => RamInit::Random {
+                    seed: header.ram_init_seed.into(),
+                },
+                _ => RamInit::Stripes,
+            },
+            TasData::Unknown => RamInit::default(),
+        }
+    }
+}
+
 /// Represents different TAS format data.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum TasData {
@@ -54,3 +96,33 @@ bitflags! {
         const VS_SERVICE = 1 << 6;
     }
 }
+
+impl From<MovieFrame> for InputFrame {
+    fn from(frame: MovieFrame) -> Self {
+        let commands = match frame.command {
+            Some(ResetKind::PowerOn) => FrameFlags::POWER,
+            Some(ResetKind::Soft) => FrameFlags::RESET,
+            None => FrameFlags::NONE,
+        };
+        Self {
+            commands,
+            ports: frame.pads,
+        }
+    }
+}
+
+impl From<InputFrame> for MovieFrame {
+    fn from(frame: InputFrame) -> Self {
+        let command = if frame.commands.contains(FrameFlags::POWER) {
+            Some(ResetKind::PowerOn)
+        } else if frame.commands.contains(FrameFlags::RESET) {
+            Some(ResetKind::Soft)
+        } else {
+            None
+        };
+        Self {
+            command,
+            pads: frame.ports,
+        }
+    }
+}
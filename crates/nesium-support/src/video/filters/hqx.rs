@@ -1,7 +1,7 @@
 use nesium_core::ppu::buffer::{
     ColorFormat, NearestPostProcessor, SourceFrame, TargetFrameMut, VideoPostProcessor,
 };
-use nesium_core::ppu::palette::Color;
+use nesium_core::ppu::palette::{EmphasizedPalette, emphasized_index};
 
 use crate::video::hqx::{HqxScale, hqx_scale_argb8888};
 
@@ -29,10 +29,15 @@ impl HqxPostProcessor {
 }
 
 impl VideoPostProcessor for HqxPostProcessor {
-    fn process(&mut self, src: SourceFrame<'_>, palette: &[Color; 64], dst: TargetFrameMut<'_>) {
+    fn process(
+        &mut self,
+        src: SourceFrame<'_>,
+        palette: &EmphasizedPalette,
+        dst: TargetFrameMut<'_>,
+    ) {
         let SourceFrame {
             indices: src_indices,
-            emphasis: _src_emphasis,
+            emphasis: src_emphasis,
             width: src_width,
             height: src_height,
         } = src;
@@ -122,7 +127,7 @@ impl VideoPostProcessor for HqxPostProcessor {
         scratch.output_argb.resize(expected_out, 0);
 
         for (i, &idx) in src_indices.iter().enumerate() {
-            let c = palette[(idx & 0x3F) as usize];
+            let c = palette[emphasized_index(idx, src_emphasis[i])];
             scratch.input_argb[i] =
                 0xFF00_0000 | ((c.r as u32) << 16) | ((c.g as u32) << 8) | (c.b as u32);
         }
@@ -3,11 +3,13 @@ use core::ffi::c_void;
 use nesium_core::ppu::buffer::{
     ColorFormat, NearestPostProcessor, SourceFrame, TargetFrameMut, VideoPostProcessor,
 };
-use nesium_core::ppu::palette::Color;
+use nesium_core::ppu::palette::{EXTENDED_PALETTE_COLORS, EmphasizedPalette, emphasized_index};
 
 use crate::video::ntsc::{NesNtsc, NesNtscPreset, nes_ntsc_out_width};
 
-const BASE_PALETTE_LEN: usize = 64 * 3;
+/// nes_ntsc is built with `NES_NTSC_EMPHASIS`, so it takes the full 512-color
+/// table and 9-bit (emphasis + index) input pixels.
+const PALETTE_LEN: usize = EXTENDED_PALETTE_COLORS * 3;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct NesNtscTuning {
@@ -49,8 +51,8 @@ pub struct NesNtscPostProcessor {
     burst_phase: i32,
     tuning: NesNtscTuning,
 
-    base_palette_bytes: Box<[u8; BASE_PALETTE_LEN]>,
-    last_palette_bytes: [u8; BASE_PALETTE_LEN],
+    palette_bytes: Box<[u8; PALETTE_LEN]>,
+    last_palette_bytes: Box<[u8; PALETTE_LEN]>,
 
     input: Vec<u16>,
     tmp_rgb: Vec<u32>,
@@ -68,8 +70,8 @@ impl NesNtscPostProcessor {
             ntsc: NesNtsc::new(preset),
             burst_phase: 0,
             tuning,
-            base_palette_bytes: Box::new([0; BASE_PALETTE_LEN]),
-            last_palette_bytes: [0xFF; BASE_PALETTE_LEN],
+            palette_bytes: Box::new([0; PALETTE_LEN]),
+            last_palette_bytes: Box::new([0xFF; PALETTE_LEN]),
             input: Vec::new(),
             tmp_rgb: Vec::new(),
             fallback: NearestPostProcessor,
@@ -106,21 +108,22 @@ impl NesNtscPostProcessor {
         setup.fringing = clamp_unit(setup.fringing + self.tuning.fringing);
         setup.bleed = clamp_unit(setup.bleed + self.tuning.bleed);
         setup.merge_fields = self.tuning.merge_fields as i32;
-        setup.palette = core::ptr::null();
-        setup.base_palette = self.base_palette_bytes.as_ptr();
+        setup.palette = self.palette_bytes.as_ptr();
+        setup.base_palette = core::ptr::null();
         self.ntsc.set_setup(setup);
     }
 
-    fn update_base_palette_if_needed(&mut self, palette: &[Color; 64]) {
+    fn update_palette_if_needed(&mut self, palette: &EmphasizedPalette) {
         for (i, c) in palette.iter().enumerate() {
             let off = i * 3;
-            self.base_palette_bytes[off] = c.r;
-            self.base_palette_bytes[off + 1] = c.g;
-            self.base_palette_bytes[off + 2] = c.b;
+            self.palette_bytes[off] = c.r;
+            self.palette_bytes[off + 1] = c.g;
+            self.palette_bytes[off + 2] = c.b;
         }
 
-        if *self.base_palette_bytes != self.last_palette_bytes {
-            self.last_palette_bytes = *self.base_palette_bytes;
+        if self.palette_bytes != self.last_palette_bytes {
+            self.last_palette_bytes
+                .copy_from_slice(&self.palette_bytes[..]);
             self.reinit_ntsc();
         }
     }
@@ -133,8 +136,8 @@ impl Clone for NesNtscPostProcessor {
             ntsc: NesNtsc::new(self.preset),
             burst_phase: self.burst_phase,
             tuning: self.tuning,
-            base_palette_bytes: Box::new(*self.base_palette_bytes),
-            last_palette_bytes: self.last_palette_bytes,
+            palette_bytes: self.palette_bytes.clone(),
+            last_palette_bytes: self.last_palette_bytes.clone(),
             input: Vec::new(),
             tmp_rgb: Vec::new(),
             fallback: self.fallback.clone(),
@@ -145,10 +148,15 @@ impl Clone for NesNtscPostProcessor {
 }
 
 impl VideoPostProcessor for NesNtscPostProcessor {
-    fn process(&mut self, src: SourceFrame<'_>, palette: &[Color; 64], dst: TargetFrameMut<'_>) {
+    fn process(
+        &mut self,
+        src: SourceFrame<'_>,
+        palette: &EmphasizedPalette,
+        dst: TargetFrameMut<'_>,
+    ) {
         let SourceFrame {
             indices: src_indices,
-            emphasis: _src_emphasis,
+            emphasis: src_emphasis,
             width: src_width,
             height: src_height,
         } = src;
@@ -217,11 +225,11 @@ impl VideoPostProcessor for NesNtscPostProcessor {
             return;
         }
 
-        self.update_base_palette_if_needed(palette);
+        self.update_palette_if_needed(palette);
 
         self.input.resize(expected_in, 0);
         for (i, &idx) in src_indices.iter().enumerate() {
-            self.input[i] = idx as u16;
+            self.input[i] = emphasized_index(idx, src_emphasis[i]) as u16;
         }
 
         let tmp_len = match out_w.checked_mul(src_height) {
@@ -1,7 +1,7 @@
 use nesium_core::ppu::buffer::{
     ColorFormat, NearestPostProcessor, SourceFrame, TargetFrameMut, VideoPostProcessor,
 };
-use nesium_core::ppu::palette::Color;
+use nesium_core::ppu::palette::{EmphasizedPalette, emphasized_index};
 
 use crate::video::ntsc_bisqwit::ntsc_bisqwit_apply_argb8888;
 
@@ -52,10 +52,15 @@ impl NtscBisqwitPostProcessor {
 }
 
 impl VideoPostProcessor for NtscBisqwitPostProcessor {
-    fn process(&mut self, src: SourceFrame<'_>, palette: &[Color; 64], dst: TargetFrameMut<'_>) {
+    fn process(
+        &mut self,
+        src: SourceFrame<'_>,
+        palette: &EmphasizedPalette,
+        dst: TargetFrameMut<'_>,
+    ) {
         let SourceFrame {
             indices: src_indices,
-            emphasis: _src_emphasis,
+            emphasis: src_emphasis,
             width: src_width,
             height: src_height,
         } = src;
@@ -147,7 +152,8 @@ impl VideoPostProcessor for NtscBisqwitPostProcessor {
 
         self.ppu.resize(expected_in, 0);
         for (i, &idx) in src_indices.iter().enumerate() {
-            self.ppu[i] = (idx & 0x3F) as u16;
+            // Bisqwit's decoder takes 9-bit pixels and models emphasis itself.
+            self.ppu[i] = emphasized_index(idx, src_emphasis[i]) as u16;
         }
 
         self.output_argb.resize(expected_out, 0);
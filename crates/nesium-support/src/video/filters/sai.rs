@@ -1,7 +1,7 @@
 use nesium_core::ppu::buffer::{
     ColorFormat, NearestPostProcessor, SourceFrame, TargetFrameMut, VideoPostProcessor,
 };
-use nesium_core::ppu::palette::Color;
+use nesium_core::ppu::palette::{EmphasizedPalette, emphasized_index};
 
 #[cfg(any(not(feature = "sai-cpp"), target_arch = "wasm32"))]
 use crate::video::sai::{
@@ -35,10 +35,15 @@ impl SaiPostProcessor {
 }
 
 impl VideoPostProcessor for SaiPostProcessor {
-    fn process(&mut self, src: SourceFrame<'_>, palette: &[Color; 64], dst: TargetFrameMut<'_>) {
+    fn process(
+        &mut self,
+        src: SourceFrame<'_>,
+        palette: &EmphasizedPalette,
+        dst: TargetFrameMut<'_>,
+    ) {
         let SourceFrame {
             indices: src_indices,
-            emphasis: _src_emphasis,
+            emphasis: src_emphasis,
             width: src_width,
             height: src_height,
         } = src;
@@ -116,7 +121,7 @@ impl VideoPostProcessor for SaiPostProcessor {
         self.output_xrgb.resize(expected_out, 0);
 
         for (i, &idx) in src_indices.iter().enumerate() {
-            let c = palette[(idx & 0x3F) as usize];
+            let c = palette[emphasized_index(idx, src_emphasis[i])];
             self.input_xrgb[i] =
                 0xFF00_0000 | ((c.r as u32) << 16) | ((c.g as u32) << 8) | (c.b as u32);
         }
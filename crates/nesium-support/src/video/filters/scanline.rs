@@ -1,7 +1,7 @@
 use nesium_core::ppu::buffer::{
     ColorFormat, NearestPostProcessor, SourceFrame, TargetFrameMut, VideoPostProcessor,
 };
-use nesium_core::ppu::palette::Color;
+use nesium_core::ppu::palette::{EmphasizedPalette, emphasized_index};
 
 use crate::video::scanline::scanline_apply_argb8888;
 
@@ -26,10 +26,15 @@ impl ScanlinePostProcessor {
 }
 
 impl VideoPostProcessor for ScanlinePostProcessor {
-    fn process(&mut self, src: SourceFrame<'_>, palette: &[Color; 64], dst: TargetFrameMut<'_>) {
+    fn process(
+        &mut self,
+        src: SourceFrame<'_>,
+        palette: &EmphasizedPalette,
+        dst: TargetFrameMut<'_>,
+    ) {
         let SourceFrame {
             indices: src_indices,
-            emphasis: _src_emphasis,
+            emphasis: src_emphasis,
             width: src_width,
             height: src_height,
         } = src;
@@ -126,8 +131,8 @@ impl VideoPostProcessor for ScanlinePostProcessor {
             let row = &mut self.output_argb[y_out * dst_width..(y_out + 1) * dst_width];
             for x_out in 0..dst_width {
                 let src_x = (x_out * src_width) / dst_width;
-                let idx = src_indices[src_y * src_width + src_x];
-                let c = palette[(idx & 0x3F) as usize];
+                let i = src_y * src_width + src_x;
+                let c = palette[emphasized_index(src_indices[i], src_emphasis[i])];
                 row[x_out] =
                     0xFF00_0000 | ((c.r as u32) << 16) | ((c.g as u32) << 8) | (c.b as u32);
             }
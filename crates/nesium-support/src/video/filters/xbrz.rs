@@ -1,7 +1,7 @@
 use nesium_core::ppu::buffer::{
     ColorFormat, NearestPostProcessor, SourceFrame, TargetFrameMut, VideoPostProcessor,
 };
-use nesium_core::ppu::palette::Color;
+use nesium_core::ppu::palette::{EmphasizedPalette, emphasized_index};
 use xbrz::scale_rgba;
 
 #[derive(Debug, Clone)]
@@ -22,10 +22,15 @@ impl XbrzPostProcessor {
 }
 
 impl VideoPostProcessor for XbrzPostProcessor {
-    fn process(&mut self, src: SourceFrame<'_>, palette: &[Color; 64], dst: TargetFrameMut<'_>) {
+    fn process(
+        &mut self,
+        src: SourceFrame<'_>,
+        palette: &EmphasizedPalette,
+        dst: TargetFrameMut<'_>,
+    ) {
         let SourceFrame {
             indices: src_indices,
-            emphasis: _src_emphasis,
+            emphasis: src_emphasis,
             width: src_width,
             height: src_height,
         } = src;
@@ -98,7 +103,7 @@ impl VideoPostProcessor for XbrzPostProcessor {
         self.input_argb.resize(expected_in, 0);
 
         for (i, &idx) in src_indices.iter().enumerate() {
-            let c = palette[(idx & 0x3F) as usize];
+            let c = palette[emphasized_index(idx, src_emphasis[i])];
             // xbrz-rs takes RGBA bytes (R, G, B, A).
             // We pack into u32.
             // On Little Endian: 0xAABBGGRR -> [RR, GG, BB, AA]
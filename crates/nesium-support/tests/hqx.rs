@@ -1,12 +1,12 @@
 #![cfg(feature = "hqx-cpp")]
 
 use nesium_core::ppu::buffer::{ColorFormat, SourceFrame, TargetFrameMut, VideoPostProcessor};
-use nesium_core::ppu::palette::Color;
+use nesium_core::ppu::palette::{Color, EXTENDED_PALETTE_COLORS, EmphasizedPalette};
 use nesium_support::video::filters::HqxPostProcessor;
 use nesium_support::video::hqx::{HqxError, HqxScale, hqx_scale_argb8888};
 
-fn solid_palette(color: Color) -> [Color; 64] {
-    let mut palette = [Color::BLACK; 64];
+fn solid_palette(color: Color) -> EmphasizedPalette {
+    let mut palette = [Color::BLACK; EXTENDED_PALETTE_COLORS];
     palette[0] = color;
     palette
 }
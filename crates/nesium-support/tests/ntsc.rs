@@ -1,7 +1,7 @@
 #![cfg(feature = "ntsc-cpp")]
 
 use nesium_core::ppu::buffer::{ColorFormat, SourceFrame, TargetFrameMut, VideoPostProcessor};
-use nesium_core::ppu::palette::Color;
+use nesium_core::ppu::palette::{Color, EXTENDED_PALETTE_COLORS};
 use nesium_support::video::filters::{NesNtscPostProcessor, NesNtscPreset};
 use nesium_support::video::ntsc::nes_ntsc_out_width;
 
@@ -19,7 +19,7 @@ fn ntsc_post_processor_outputs_doubled_height_and_respects_pitch() {
     let src = vec![0u8; src_w * src_h];
     let src_emphasis = vec![0u8; src_w * src_h];
 
-    let mut palette = [Color::BLACK; 64];
+    let mut palette = [Color::BLACK; EXTENDED_PALETTE_COLORS];
     palette[0] = Color::new(0x12, 0x34, 0x56);
 
     let dst_w = nes_ntsc_out_width(src_w);
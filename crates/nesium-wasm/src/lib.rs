@@ -10,7 +10,6 @@ use wasm_bindgen::prelude::*;
 
 use nesium_core::{
     Nes,
-    cartridge::load_cartridge,
     config::region::Region,
     ppu::buffer::{ColorFormat, NearestPostProcessor, VideoPostProcessor},
     ppu::palette::{Palette, PaletteKind},
@@ -339,8 +338,7 @@ impl WasmNes {
         let mut full_hash = [0u8; 32];
         full_hash[..hash.len()].copy_from_slice(&hash);
 
-        let cartridge = load_cartridge(rom).map_err(js_err)?;
-        self.nes.insert_cartridge(cartridge);
+        self.nes.load_cartridge_from_bytes(rom).map_err(js_err)?;
         self.rom_hash = Some(full_hash);
         Ok(())
     }